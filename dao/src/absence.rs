@@ -32,6 +32,19 @@ pub enum DayFractionEntity {
     Half,
 }
 
+/// Persistierter Antrags-Status einer Absence-Periode.
+///
+/// `Approved` ist Default für Bestandsdaten (Migration `NOT NULL DEFAULT
+/// 'approved'`). Nur `Approved` fließt in Stundenableitung und Reporting ein;
+/// `Rejected`/`Cancelled` blockieren auch den Self-Overlap-Check nicht mehr.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AbsenceStatusEntity {
+    Requested,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
 /// DAO-Repräsentation eines Absence-Period-Eintrags.
 ///
 /// Felder spiegeln die `absence_period`-Tabelle (Migration `20260501162017`).
@@ -51,6 +64,7 @@ pub struct AbsencePeriodEntity {
     pub deleted: Option<time::PrimitiveDateTime>,
     pub version: Uuid,
     pub day_fraction: DayFractionEntity,
+    pub status: AbsenceStatusEntity,
 }
 
 #[automock(type Transaction = crate::MockTransaction;)]
@@ -85,6 +99,9 @@ pub trait AbsenceDao {
     /// die `range` inklusiv überlappen (Allen-Algebra: `from_date <= probe.to`
     /// UND `to_date >= probe.from`).
     ///
+    /// Nur `Requested`- und `Approved`-Rows zählen — abgelehnte oder
+    /// zurückgezogene Anträge geben den Zeitraum wieder frei.
+    ///
     /// `exclude_logical_id` wird beim Update verwendet, damit die zu
     /// modifizierende Row nicht mit sich selbst kollidiert (D-15). `None` für
     /// den Create-Pfad, `Some(id)` für den Update-Pfad.
@@ -99,7 +116,8 @@ pub trait AbsenceDao {
 
     /// Findet aktive Absence-Periods derselben `sales_person_id`, die `range`
     /// inklusiv überlappen — **kategorie-frei** (alle 3 AbsenceCategory-Werte
-    /// werden zurückgegeben). Verwendet vom
+    /// werden zurückgegeben), nur `Approved` (offene Anträge blockieren keine
+    /// Buchungen). Verwendet vom
     /// `AbsenceService::find_overlapping_for_booking`-Pfad und vom
     /// `ShiftplanEditService::book_slot_with_conflict_check`-Pfad
     /// (Phase 3, D-Phase3-05).
@@ -164,6 +182,7 @@ mod tests {
             deleted: None,
             version: id,
             day_fraction: DayFractionEntity::Full,
+            status: AbsenceStatusEntity::Approved,
         };
         let cloned = entity.clone();
         assert_eq!(entity, cloned);
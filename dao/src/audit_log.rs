@@ -0,0 +1,64 @@
+//! Änderungsprotokoll aller schreibenden Service-Aufrufe. Einträge werden nur
+//! angehängt, nie geändert oder gelöscht.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::DaoError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogEntity {
+    pub id: Uuid,
+    pub entity_type: Arc<str>,
+    pub entity_id: Arc<str>,
+    pub action: AuditAction,
+    pub user_id: Option<Arc<str>>,
+    pub real_user_id: Option<Arc<str>>,
+    pub process: Arc<str>,
+    pub before: Option<Arc<str>>,
+    pub after: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+}
+
+/// Alle Felder sind optional und werden UND-verknüpft. `user_id` trifft
+/// sowohl den handelnden als auch den impersonierenden Benutzer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditLogQuery {
+    pub entity_type: Option<Arc<str>>,
+    pub entity_id: Option<Arc<str>>,
+    pub user_id: Option<Arc<str>>,
+    /// Inklusive.
+    pub from: Option<PrimitiveDateTime>,
+    /// Exklusive.
+    pub to: Option<PrimitiveDateTime>,
+    pub limit: u32,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait]
+pub trait AuditLogDao {
+    type Transaction: crate::Transaction;
+
+    /// Treffer nach `created` absteigend, höchstens `query.limit`.
+    async fn find(
+        &self,
+        query: &AuditLogQuery,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AuditLogEntity]>, DaoError>;
+    async fn create(
+        &self,
+        entity: &AuditLogEntity,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
@@ -53,6 +53,13 @@ pub trait BookingDao {
         year: u32,
         tx: Self::Transaction,
     ) -> Result<Arc<[BookingEntity]>, DaoError>;
+    /// Nur soft-gelöschte Buchungen der Woche, z.B. für Absagen im iCal-Feed.
+    async fn find_deleted_by_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BookingEntity]>, DaoError>;
     async fn create(
         &self,
         entity: &BookingEntity,
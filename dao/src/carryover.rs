@@ -1,5 +1,6 @@
 use crate::DaoError;
 use mockall::automock;
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -13,6 +14,15 @@ pub struct CarryoverEntity {
     pub version: Uuid,
 }
 
+/// Markierung: der Carryover von `sales_person_id` für `year` (ISO-Jahr) und
+/// damit auch der aller Folgejahre ist veraltet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarryoverDirtyEntity {
+    pub sales_person_id: Uuid,
+    pub year: u32,
+    pub version: Uuid,
+}
+
 #[automock(type Transaction = crate::MockTransaction;)]
 #[async_trait::async_trait]
 pub trait CarryoverDao {
@@ -31,4 +41,25 @@ pub trait CarryoverDao {
         process: &str,
         tx: Self::Transaction,
     ) -> Result<(), DaoError>;
+
+    /// Legt die Markierung an oder ersetzt die `version` einer bestehenden.
+    async fn mark_dirty(
+        &self,
+        entity: &CarryoverDirtyEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn all_dirty(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[CarryoverDirtyEntity]>, DaoError>;
+
+    /// Löscht die Markierung nur, wenn ihre `version` noch `entity.version`
+    /// ist — eine zwischenzeitlich erneuerte Markierung bleibt stehen.
+    async fn clear_dirty(
+        &self,
+        entity: &CarryoverDirtyEntity,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
 }
@@ -21,6 +21,7 @@ pub struct EmployeeWorkDetailsEntity {
     pub is_dynamic: bool,
     pub cap_planned_hours_to_expected: bool,
     pub committed_voluntary: f32,
+    pub use_actual_hours: bool,
 
     pub monday: bool,
     pub tuesday: bool,
@@ -0,0 +1,238 @@
+//! Benannte Export-Ziele und ihre Lauf-Historie.
+//!
+//! Neben der einzelnen Nextcloud-Konfiguration (`pdf_export_config`) kann der
+//! Admin beliebig viele Ziele anlegen. Jedes Ziel hat eine Zielart (WebDAV,
+//! lokales Verzeichnis, Abholordner), einen eigenen Cron-Ausdruck, ein
+//! Artefakt (Wochen-PDF pro Schichtplan, Abrechnungszeitraum als CSV,
+//! iCal-Schnappschuss) und ein Dateinamen-Muster. Jeder Lauf landet als Zeile
+//! in `export_target_run`.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportTargetKind {
+    WebDav,
+    LocalDirectory,
+    DropDirectory,
+}
+
+impl TryFrom<&str> for ExportTargetKind {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "webdav" => Ok(Self::WebDav),
+            "local-directory" => Ok(Self::LocalDirectory),
+            "drop-directory" => Ok(Self::DropDirectory),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for ExportTargetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WebDav => write!(f, "webdav"),
+            Self::LocalDirectory => write!(f, "local-directory"),
+            Self::DropDirectory => write!(f, "drop-directory"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportArtifactType {
+    ShiftplanWeekPdf,
+    BillingPeriodCsv,
+    IcalSnapshot,
+}
+
+impl TryFrom<&str> for ExportArtifactType {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "shiftplan-week-pdf" => Ok(Self::ShiftplanWeekPdf),
+            "billing-period-csv" => Ok(Self::BillingPeriodCsv),
+            "ical-snapshot" => Ok(Self::IcalSnapshot),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for ExportArtifactType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ShiftplanWeekPdf => write!(f, "shiftplan-week-pdf"),
+            Self::BillingPeriodCsv => write!(f, "billing-period-csv"),
+            Self::IcalSnapshot => write!(f, "ical-snapshot"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportRunState {
+    Success,
+    /// Ein Teil der Dateien wurde abgelegt, mindestens eine nicht.
+    Partial,
+    Failed,
+}
+
+impl TryFrom<&str> for ExportRunState {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "Success" => Ok(Self::Success),
+            "Partial" => Ok(Self::Partial),
+            "Failed" => Ok(Self::Failed),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for ExportRunState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Success => write!(f, "Success"),
+            Self::Partial => write!(f, "Partial"),
+            Self::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// Ein Export-Ziel. `secret` (WebDAV-App-Token) liegt wie bei
+/// `pdf_export_config` im Klartext in der DB; der Service gibt ihn nie heraus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportTargetEntity {
+    pub id: Uuid,
+    pub name: Arc<str>,
+    pub kind: ExportTargetKind,
+    pub enabled: bool,
+    /// WebDAV-Basis-URL; bei den Verzeichnis-Zielen leer.
+    pub url: Option<Arc<str>>,
+    pub username: Option<Arc<str>>,
+    pub secret: Option<Arc<str>>,
+    /// WebDAV: Ordner unterhalb der URL. Verzeichnis-Ziele: absoluter Pfad.
+    pub path: Arc<str>,
+    /// 6-Feld-Cron-Ausdruck (`sec min hour dom mon dow`), lokale Zeit.
+    pub cron_schedule: Arc<str>,
+    pub artifact_type: ExportArtifactType,
+    pub filename_pattern: Arc<str>,
+    /// Anzahl Wochen ab der laufenden Woche (nur Wochen-PDF).
+    pub weeks_horizon: u32,
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportTargetRunEntity {
+    pub id: Uuid,
+    pub target_id: Uuid,
+    pub started: PrimitiveDateTime,
+    pub duration_ms: u64,
+    pub state: ExportRunState,
+    pub files_written: u32,
+    /// Übersprungene Dateien, z. B. Wochen, die noch nicht `Planned` sind.
+    pub files_skipped: u32,
+    pub message: Option<Arc<str>>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait ExportTargetDao {
+    type Transaction: crate::Transaction;
+
+    /// Alle nicht gelöschten Ziele, auch deaktivierte.
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[ExportTargetEntity]>, DaoError>;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ExportTargetEntity>, DaoError>;
+
+    async fn create(
+        &self,
+        entity: &ExportTargetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update(
+        &self,
+        entity: &ExportTargetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn create_run(
+        &self,
+        entity: &ExportTargetRunEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    /// Die jüngsten Läufe eines Ziels, neueste zuerst.
+    async fn find_runs_by_target(
+        &self,
+        target_id: Uuid,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ExportTargetRunEntity]>, DaoError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_roundtrip() {
+        for kind in [
+            ExportTargetKind::WebDav,
+            ExportTargetKind::LocalDirectory,
+            ExportTargetKind::DropDirectory,
+        ] {
+            assert_eq!(
+                ExportTargetKind::try_from(kind.to_string().as_str()).unwrap(),
+                kind
+            );
+        }
+        assert!(ExportTargetKind::try_from("ftp").is_err());
+    }
+
+    #[test]
+    fn artifact_type_roundtrip() {
+        for artifact_type in [
+            ExportArtifactType::ShiftplanWeekPdf,
+            ExportArtifactType::BillingPeriodCsv,
+            ExportArtifactType::IcalSnapshot,
+        ] {
+            assert_eq!(
+                ExportArtifactType::try_from(artifact_type.to_string().as_str()).unwrap(),
+                artifact_type
+            );
+        }
+    }
+
+    #[test]
+    fn run_state_roundtrip() {
+        for state in [
+            ExportRunState::Success,
+            ExportRunState::Partial,
+            ExportRunState::Failed,
+        ] {
+            assert_eq!(
+                ExportRunState::try_from(state.to_string().as_str()).unwrap(),
+                state
+            );
+        }
+    }
+}
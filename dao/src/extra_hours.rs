@@ -99,13 +99,6 @@ pub trait ExtraHoursDao {
         process: &str,
         tx: Self::Transaction,
     ) -> Result<(), crate::DaoError>;
-    async fn delete(
-        &self,
-        id: Uuid,
-        process: &str,
-        tx: Self::Transaction,
-    ) -> Result<(), crate::DaoError>;
-
     /// Bulk soft-delete by id list (Phase 4 cutover, C-Phase4-04).
     /// Issues `UPDATE extra_hours SET deleted = ?, update_process = ?,
     /// update_timestamp = ?, update_version = ? WHERE deleted IS NULL AND id IN (...)`.
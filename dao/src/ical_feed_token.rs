@@ -0,0 +1,70 @@
+//! Geheime Feed-Tokens für iCal-Kalender: der eines Mitarbeiters, eines
+//! Schichtplans oder der Team-Abwesenheiten.
+//!
+//! Tokens werden nie gelöscht, sondern über `revoked` widerrufen.
+
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+/// Wessen Kalender das Token freigibt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IcalFeedScopeEntity {
+    SalesPerson(Uuid),
+    Shiftplan(Uuid),
+    TeamAbsences,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcalFeedTokenEntity {
+    pub id: Uuid,
+    pub scope: IcalFeedScopeEntity,
+    pub token: Uuid,
+    pub label: Arc<str>,
+    pub created: PrimitiveDateTime,
+    pub revoked: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait IcalFeedTokenDao {
+    type Transaction: crate::Transaction;
+
+    /// Alle Tokens des Scopes inklusive widerrufener, älteste zuerst.
+    async fn find_by_scope(
+        &self,
+        scope: IcalFeedScopeEntity,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[IcalFeedTokenEntity]>, DaoError>;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<IcalFeedTokenEntity>, DaoError>;
+
+    /// Sucht auch widerrufene Tokens; die Prüfung macht der Service.
+    async fn find_by_token(
+        &self,
+        token: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<IcalFeedTokenEntity>, DaoError>;
+
+    async fn create(
+        &self,
+        entity: &IcalFeedTokenEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update(
+        &self,
+        entity: &IcalFeedTokenEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
@@ -5,6 +5,7 @@ use mockall::automock;
 use thiserror::Error;
 
 pub mod absence;
+pub mod audit_log;
 pub mod billing_period;
 pub mod billing_period_sales_person;
 pub mod booking;
@@ -12,24 +13,34 @@ pub mod booking_log;
 pub mod carryover;
 pub mod custom_extra_hours;
 pub mod employee_work_details;
+pub mod export_target;
 pub mod extra_hours;
 pub mod feature_flag;
+pub mod ical_feed_token;
 pub mod migration_source;
+pub mod notification;
+pub mod payroll_wage_type;
 pub mod pdf_export_config;
 pub mod permission;
+pub mod qualification;
 pub mod rebooking_batch;
 pub mod sales_person;
+pub mod sales_person_availability;
 pub mod sales_person_shiftplan;
 pub mod sales_person_unavailable;
+pub mod scheduler_job;
 pub mod session;
+pub mod shift_swap;
 pub mod shiftplan;
 pub mod shiftplan_report;
 pub mod slot;
 pub mod special_day;
 pub mod text_template;
+pub mod time_entry;
 pub mod toggle;
 pub mod user_invitation;
 pub mod vacation_entitlement_offset;
+pub mod webhook;
 pub mod week_message;
 pub mod week_status;
 
@@ -0,0 +1,220 @@
+//! E-Mail-Benachrichtigungen: Einstellungen pro Benutzer und Outbox.
+//!
+//! Fachliche Services legen Nachrichten in derselben Transaktion wie die
+//! auslösende Änderung in der Outbox ab (`Pending`). Der Versand-Job stellt
+//! sie zu und setzt sie auf `Sent`; Fehlversuche werden mit wachsendem
+//! Abstand wiederholt, nach dem letzten Versuch bleibt die Nachricht `Failed`.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+/// Auslösendes Ereignis einer Benachrichtigung. Der Wert steckt zugleich
+/// im TextTemplate-Typ (`notification_<kind>`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    BookingDeleted,
+    WeekPlanned,
+    AbsenceCreated,
+    InvitationCreated,
+}
+
+impl TryFrom<&str> for NotificationKind {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "booking_deleted" => Ok(Self::BookingDeleted),
+            "week_planned" => Ok(Self::WeekPlanned),
+            "absence_created" => Ok(Self::AbsenceCreated),
+            "invitation_created" => Ok(Self::InvitationCreated),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BookingDeleted => write!(f, "booking_deleted"),
+            Self::WeekPlanned => write!(f, "week_planned"),
+            Self::AbsenceCreated => write!(f, "absence_created"),
+            Self::InvitationCreated => write!(f, "invitation_created"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationState {
+    Pending,
+    Sent,
+    Failed,
+}
+
+impl TryFrom<&str> for NotificationState {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "Pending" => Ok(Self::Pending),
+            "Sent" => Ok(Self::Sent),
+            "Failed" => Ok(Self::Failed),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for NotificationState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "Pending"),
+            Self::Sent => write!(f, "Sent"),
+            Self::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// Einstellungen eines Benutzers. Ohne Zeile wird nichts verschickt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationPreferenceEntity {
+    pub user_id: Arc<str>,
+    pub email: Arc<str>,
+    pub booking_deleted: bool,
+    pub week_planned: bool,
+    pub absence_created: bool,
+    pub invitation_created: bool,
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationOutboxEntity {
+    pub id: Uuid,
+    pub recipient_user_id: Arc<str>,
+    pub recipient_email: Arc<str>,
+    pub kind: NotificationKind,
+    pub subject: Arc<str>,
+    pub body: Arc<str>,
+    pub state: NotificationState,
+    pub attempts: u32,
+    /// Frühester Zeitpunkt für den nächsten Zustellversuch.
+    pub next_attempt: PrimitiveDateTime,
+    pub last_error: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub sent: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait NotificationDao {
+    type Transaction: crate::Transaction;
+
+    async fn find_preference_by_user_id(
+        &self,
+        user_id: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<NotificationPreferenceEntity>, DaoError>;
+
+    /// Alle aktiven Einstellungen, z. B. für Rundmails an alle Abonnenten.
+    async fn all_preferences(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationPreferenceEntity]>, DaoError>;
+
+    async fn create_preference(
+        &self,
+        entity: &NotificationPreferenceEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update_preference(
+        &self,
+        entity: &NotificationPreferenceEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn find_outbox_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<NotificationOutboxEntity>, DaoError>;
+
+    /// Die jüngsten Outbox-Einträge, neueste zuerst.
+    async fn recent_outbox(
+        &self,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationOutboxEntity]>, DaoError>;
+
+    /// `Pending`-Einträge mit `next_attempt <= now`, älteste zuerst.
+    async fn find_due_outbox(
+        &self,
+        now: PrimitiveDateTime,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationOutboxEntity]>, DaoError>;
+
+    async fn create_outbox(
+        &self,
+        entity: &NotificationOutboxEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update_outbox(
+        &self,
+        entity: &NotificationOutboxEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_roundtrip() {
+        for kind in [
+            NotificationKind::BookingDeleted,
+            NotificationKind::WeekPlanned,
+            NotificationKind::AbsenceCreated,
+            NotificationKind::InvitationCreated,
+        ] {
+            assert_eq!(
+                NotificationKind::try_from(kind.to_string().as_str()).unwrap(),
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn state_roundtrip() {
+        for state in [
+            NotificationState::Pending,
+            NotificationState::Sent,
+            NotificationState::Failed,
+        ] {
+            assert_eq!(
+                NotificationState::try_from(state.to_string().as_str()).unwrap(),
+                state
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_kind() {
+        match NotificationKind::try_from("Bogus") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Bogus"),
+            other => panic!("expected EnumValueNotFound(\"Bogus\"), got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Lohnarten-Zuordnung für den Lohnexport (DATEV LODAS).
+//!
+//! Jede Zeile ordnet einem Werttyp eines Abrechnungszeitraums
+//! (`billing_period_sales_person.value_type`) in einer Sicht eine Lohnart
+//! zu. Der Export schreibt pro Mitarbeiter und Zuordnung eine
+//! Bewegungsdaten-Zeile.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+/// Welcher der vier Werte eines `BillingPeriodValue` exportiert wird.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayrollValueView {
+    Delta,
+    YtdFrom,
+    YtdTo,
+    FullYear,
+}
+
+impl TryFrom<&str> for PayrollValueView {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "delta" => Ok(Self::Delta),
+            "ytd_from" => Ok(Self::YtdFrom),
+            "ytd_to" => Ok(Self::YtdTo),
+            "full_year" => Ok(Self::FullYear),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for PayrollValueView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delta => write!(f, "delta"),
+            Self::YtdFrom => write!(f, "ytd_from"),
+            Self::YtdTo => write!(f, "ytd_to"),
+            Self::FullYear => write!(f, "full_year"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayrollWageTypeEntity {
+    pub id: Uuid,
+    /// Schlüssel wie in `billing_period_sales_person.value_type`.
+    pub value_type: Arc<str>,
+    pub value_view: PayrollValueView,
+    /// Lohnart-Nummer im Lohnprogramm.
+    pub wage_type: Arc<str>,
+    /// DATEV-Bearbeitungsschlüssel der Lohnart.
+    pub processing_key: u8,
+    pub description: Arc<str>,
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait PayrollWageTypeDao {
+    type Transaction: crate::Transaction;
+
+    /// Alle nicht gelöschten Zuordnungen.
+    async fn all(&self, tx: Self::Transaction)
+        -> Result<Arc<[PayrollWageTypeEntity]>, DaoError>;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<PayrollWageTypeEntity>, DaoError>;
+
+    async fn create(
+        &self,
+        entity: &PayrollWageTypeEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update(
+        &self,
+        entity: &PayrollWageTypeEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
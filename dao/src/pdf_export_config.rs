@@ -21,12 +21,25 @@ pub struct PdfExportConfigEntity {
     pub target_folder: Option<Arc<str>>,
     pub weeks_horizon: u32,
     pub cron_schedule: Arc<str>,
+    pub include_planning_plans: bool,
+    pub combined_pdf: bool,
     pub last_success_at: Option<time::PrimitiveDateTime>,
     pub last_error_at: Option<time::PrimitiveDateTime>,
     pub last_error_message: Option<Arc<str>>,
     pub version: Uuid,
 }
 
+/// Export-Einstellung für genau einen Shiftplan (Tabelle
+/// `pdf_export_shiftplan`). Shiftplans ohne Zeile gelten als eingeschlossen,
+/// ohne Unterordner und ohne Präfix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PdfExportShiftplanEntity {
+    pub shiftplan_id: Uuid,
+    pub included: bool,
+    pub subfolder: Option<Arc<str>>,
+    pub filename_prefix: Option<Arc<str>>,
+}
+
 #[automock(type Transaction = crate::MockTransaction;)]
 #[async_trait::async_trait]
 pub trait PdfExportConfigDao {
@@ -37,7 +50,7 @@ pub trait PdfExportConfigDao {
     async fn get(&self, tx: Self::Transaction) -> Result<PdfExportConfigEntity, DaoError>;
 
     /// Überschreibt alle admin-editierbaren Felder (enabled, URL, User, Token,
-    /// Zielordner, Wochen-Horizont, Cron). Status-Felder (`last_success_at`,
+    /// Zielordner, Wochen-Horizont, Cron, Planungs-Plans, Sammel-PDF). Status-Felder (`last_success_at`,
     /// `last_error_at`, `last_error_message`) bleiben unangetastet — die
     /// setzt der Scheduler über [`record_success`] / [`record_error`].
     async fn update(
@@ -68,4 +81,20 @@ pub trait PdfExportConfigDao {
         version: Uuid,
         tx: Self::Transaction,
     ) -> Result<(), DaoError>;
+
+    /// Alle gespeicherten Shiftplan-Einstellungen (auch für inzwischen
+    /// gelöschte Shiftplans — der Scheduler ignoriert die).
+    async fn get_shiftplan_settings(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[PdfExportShiftplanEntity]>, DaoError>;
+
+    /// Ersetzt die komplette Shiftplan-Auswahl durch `settings`.
+    async fn replace_shiftplan_settings(
+        &self,
+        settings: &[PdfExportShiftplanEntity],
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
 }
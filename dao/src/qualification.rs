@@ -0,0 +1,105 @@
+//! Qualifikationen, ihre Zuordnung zu Mitarbeitern und die
+//! Qualifikations-Anforderungen pro Slot.
+//!
+//! Zuordnungen und Anforderungen werden pro Mitarbeiter bzw. Slot als Ganzes
+//! ersetzt. Die Lesemethoden liefern nur Zeilen, deren Qualifikation nicht
+//! gelöscht ist.
+
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QualificationEntity {
+    pub id: Uuid,
+    pub name: Arc<str>,
+    pub description: Arc<str>,
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SalesPersonQualificationEntity {
+    pub sales_person_id: Uuid,
+    pub qualification_id: Uuid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotQualificationRequirementEntity {
+    pub slot_id: Uuid,
+    pub qualification_id: Uuid,
+    pub min_count: u8,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait QualificationDao {
+    type Transaction: crate::Transaction;
+
+    /// Alle nicht gelöschten Qualifikationen.
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[QualificationEntity]>, DaoError>;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<QualificationEntity>, DaoError>;
+
+    async fn create(
+        &self,
+        entity: &QualificationEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update(
+        &self,
+        entity: &QualificationEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn all_sales_person_qualifications(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonQualificationEntity]>, DaoError>;
+
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonQualificationEntity]>, DaoError>;
+
+    /// Ersetzt alle Zuordnungen des Mitarbeiters durch `qualification_ids`.
+    async fn set_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        qualification_ids: &[Uuid],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn all_slot_requirements(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotQualificationRequirementEntity]>, DaoError>;
+
+    async fn find_requirements_by_slot(
+        &self,
+        slot_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotQualificationRequirementEntity]>, DaoError>;
+
+    /// Ersetzt alle Anforderungen des Slots durch `requirements`.
+    async fn set_requirements_for_slot(
+        &self,
+        slot_id: Uuid,
+        requirements: &[SlotQualificationRequirementEntity],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
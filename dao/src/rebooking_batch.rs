@@ -51,6 +51,10 @@ pub struct RebookingBatchEntity {
     pub created: time::PrimitiveDateTime,
     pub approved: Option<time::PrimitiveDateTime>,
     pub approved_by: Option<Arc<str>>,
+    /// Gesetzt, sobald state = Rejected.
+    pub rejected: Option<time::PrimitiveDateTime>,
+    pub rejected_by: Option<Arc<str>>,
+    pub reject_reason: Option<Arc<str>>,
     pub deleted: Option<time::PrimitiveDateTime>,
     pub version: Uuid,
 }
@@ -112,4 +116,27 @@ pub trait RebookingBatchDao {
         batch_id: Uuid,
         tx: Self::Transaction,
     ) -> Result<Arc<[RebookingBatchEntryEntity]>, DaoError>;
+
+    /// Alle aktiven Batches im angegebenen State, aelteste zuerst.
+    async fn find_by_state(
+        &self,
+        state: RebookingBatchState,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[RebookingBatchEntity]>, DaoError>;
+
+    /// Schreibt State, Genehmigungs- und Ablehnungsfelder sowie die Version.
+    async fn update_batch(
+        &self,
+        batch: &RebookingBatchEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    /// Schreibt die extra_hours-Referenzen sowie die Version eines Entries.
+    async fn update_entry(
+        &self,
+        entry: &RebookingBatchEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
 }
@@ -15,6 +15,8 @@ pub struct SalesPersonEntity {
     pub is_paid: bool,
     pub deleted: Option<time::PrimitiveDateTime>,
     pub inactive: bool,
+    /// Personalnummer im Lohnprogramm.
+    pub personnel_number: Option<Arc<str>>,
     pub version: Uuid,
 }
 
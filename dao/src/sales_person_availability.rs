@@ -0,0 +1,65 @@
+//! Wiederkehrende Verfügbarkeit pro Mitarbeiter (Wochentag + optionales
+//! Zeitfenster, mit Gültigkeitszeitraum). Ergänzt die tagesgenauen
+//! `sales_person_unavailable`-Einträge.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use shifty_utils::DayOfWeek;
+use time::{Date, PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+use crate::DaoError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvailabilityKind {
+    Preferred,
+    Available,
+    Unavailable,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SalesPersonAvailabilityEntity {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub day_of_week: DayOfWeek,
+    /// `None` zusammen mit `time_to = None` bedeutet ganzer Tag.
+    pub time_from: Option<Time>,
+    pub time_to: Option<Time>,
+    pub valid_from: Date,
+    pub valid_to: Option<Date>,
+    pub kind: AvailabilityKind,
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait]
+pub trait SalesPersonAvailabilityDao {
+    type Transaction: crate::Transaction;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonAvailabilityEntity>, DaoError>;
+    async fn find_all_by_sales_person_id(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonAvailabilityEntity]>, DaoError>;
+    async fn create(
+        &self,
+        entity: &SalesPersonAvailabilityEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+    async fn update(
+        &self,
+        entity: &SalesPersonAvailabilityEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
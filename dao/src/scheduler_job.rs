@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use uuid::Uuid;
+
+/// Ein periodischer Hintergrund-Job aus der Registry `scheduler_job`. Der
+/// `name` ist der fachliche Schlüssel; welche Arbeit dahinter steckt, legt
+/// der Scheduler im Code fest. Die Zeilen werden per Migration geseedet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchedulerJobEntity {
+    pub name: Arc<str>,
+    pub cron_schedule: Arc<str>,
+    pub enabled: bool,
+    pub last_run_at: Option<time::PrimitiveDateTime>,
+    pub last_duration_ms: Option<u64>,
+    pub last_error_at: Option<time::PrimitiveDateTime>,
+    pub last_error_message: Option<Arc<str>>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait SchedulerJobDao {
+    type Transaction: crate::Transaction;
+
+    /// Alle Jobs, sortiert nach Name.
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[SchedulerJobEntity]>, DaoError>;
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<SchedulerJobEntity>, DaoError>;
+
+    /// Überschreibt die admin-editierbaren Felder (`cron_schedule`,
+    /// `enabled`). Die Status-Felder setzt der Scheduler über
+    /// [`Self::record_run`].
+    async fn update(
+        &self,
+        entity: &SchedulerJobEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    /// Vom Scheduler nach jedem Lauf aufgerufen. Mit `error` werden
+    /// `last_error_at` und `last_error_message` gesetzt, ohne werden sie
+    /// geleert.
+    #[allow(clippy::too_many_arguments)]
+    async fn record_run(
+        &self,
+        name: &str,
+        started_at: time::PrimitiveDateTime,
+        duration_ms: u64,
+        error: Option<Arc<str>>,
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
@@ -0,0 +1,90 @@
+//! Schichttausch-/Abgabe-Börse: ein Mitarbeiter bietet ein eigenes Booking
+//! an, ein anderer übernimmt es, ein Shiftplanner genehmigt.
+//!
+//! Lebenszyklus: `Open` → `Accepted` → `Approved` | `Rejected`; `Open` und
+//! `Accepted` können vom Anbieter auf `Cancelled` gesetzt werden.
+
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShiftSwapState {
+    Open,
+    Accepted,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShiftSwapRequestEntity {
+    pub id: Uuid,
+    /// Das angebotene Booking (wird bei Genehmigung gelöscht).
+    pub booking_id: Uuid,
+    pub offering_sales_person_id: Uuid,
+    pub accepting_sales_person_id: Option<Uuid>,
+    /// Das bei Genehmigung neu angelegte Booking der übernehmenden Person.
+    pub new_booking_id: Option<Uuid>,
+    /// Denormalisiert aus dem Booking für Wochen-Sperre und Listen.
+    pub year: u32,
+    pub calendar_week: u8,
+    pub state: ShiftSwapState,
+    pub message: Arc<str>,
+    pub reject_reason: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub accepted: Option<PrimitiveDateTime>,
+    pub decided: Option<PrimitiveDateTime>,
+    pub decided_by: Option<Arc<str>>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait ShiftSwapRequestDao {
+    type Transaction: crate::Transaction;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftSwapRequestEntity>, DaoError>;
+
+    /// Alle nicht abgeschlossenen Anfragen (`Open` und `Accepted`).
+    async fn find_pending(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftSwapRequestEntity]>, DaoError>;
+
+    /// Die nicht abgeschlossene Anfrage für ein Booking, falls vorhanden.
+    async fn find_pending_by_booking_id(
+        &self,
+        booking_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftSwapRequestEntity>, DaoError>;
+
+    /// Alle Anfragen, bei denen die Person anbietet oder übernimmt.
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftSwapRequestEntity]>, DaoError>;
+
+    async fn create(
+        &self,
+        entity: &ShiftSwapRequestEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update(
+        &self,
+        entity: &ShiftSwapRequestEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
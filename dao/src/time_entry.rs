@@ -0,0 +1,79 @@
+//! Tatsächlich geleistete Arbeitszeit (Stempeluhr bzw. von HR erfasst).
+//! Ein Eintrag ohne `end` ist offen; der Mitarbeiter ist eingestempelt.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::DaoError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeEntrySource {
+    /// Vom Mitarbeiter ein- und ausgestempelt.
+    Clock,
+    /// Von HR nachgetragen oder korrigiert.
+    Manual,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeEntryEntity {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub booking_id: Option<Uuid>,
+    pub start: PrimitiveDateTime,
+    pub end: Option<PrimitiveDateTime>,
+    pub description: Arc<str>,
+    pub source: TimeEntrySource,
+    pub created: PrimitiveDateTime,
+    pub created_by: Arc<str>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait]
+pub trait TimeEntryDao {
+    type Transaction: crate::Transaction;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TimeEntryEntity>, DaoError>;
+    /// Der offene Eintrag (ohne `end`) des Mitarbeiters, falls vorhanden.
+    async fn find_open_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TimeEntryEntity>, DaoError>;
+    /// Einträge mit `from <= start < to`, nach `start` sortiert.
+    async fn find_by_sales_person_and_range(
+        &self,
+        sales_person_id: Uuid,
+        from: PrimitiveDateTime,
+        to: PrimitiveDateTime,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TimeEntryEntity]>, DaoError>;
+    /// Einträge aller Mitarbeiter mit `from <= start < to`.
+    async fn find_by_range(
+        &self,
+        from: PrimitiveDateTime,
+        to: PrimitiveDateTime,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TimeEntryEntity]>, DaoError>;
+    async fn create(
+        &self,
+        entity: &TimeEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+    async fn update(
+        &self,
+        entity: &TimeEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
@@ -0,0 +1,245 @@
+//! Ausgehende Webhooks: Abonnements und Zustell-Outbox.
+//!
+//! Fachliche Services legen pro passendem Abonnement eine Zustellung in
+//! derselben Transaktion wie die auslösende Änderung ab (`Pending`). Der
+//! Zustell-Job schickt sie signiert an die URL und setzt sie auf
+//! `Delivered`; transiente Fehler werden mit wachsendem Abstand wiederholt,
+//! nach dem letzten Versuch oder bei einem 4xx bleibt die Zustellung `Failed`.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::DaoError;
+use mockall::automock;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+/// Fachliches Ereignis, das ein Abonnement filtern kann. Der Wert steht als
+/// `event`-Feld im Payload und im Header `X-Shifty-Event`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WebhookEventType {
+    BookingCreated,
+    BookingDeleted,
+    AbsencePeriodCreated,
+    AbsencePeriodUpdated,
+    BillingPeriodCreated,
+    WeekStatusChanged,
+}
+
+impl TryFrom<&str> for WebhookEventType {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "booking.created" => Ok(Self::BookingCreated),
+            "booking.deleted" => Ok(Self::BookingDeleted),
+            "absence_period.created" => Ok(Self::AbsencePeriodCreated),
+            "absence_period.updated" => Ok(Self::AbsencePeriodUpdated),
+            "billing_period.created" => Ok(Self::BillingPeriodCreated),
+            "week_status.changed" => Ok(Self::WeekStatusChanged),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BookingCreated => write!(f, "booking.created"),
+            Self::BookingDeleted => write!(f, "booking.deleted"),
+            Self::AbsencePeriodCreated => write!(f, "absence_period.created"),
+            Self::AbsencePeriodUpdated => write!(f, "absence_period.updated"),
+            Self::BillingPeriodCreated => write!(f, "billing_period.created"),
+            Self::WeekStatusChanged => write!(f, "week_status.changed"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookDeliveryState {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl TryFrom<&str> for WebhookDeliveryState {
+    type Error = DaoError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "Pending" => Ok(Self::Pending),
+            "Delivered" => Ok(Self::Delivered),
+            "Failed" => Ok(Self::Failed),
+            other => Err(DaoError::EnumValueNotFound(other.into())),
+        }
+    }
+}
+
+impl fmt::Display for WebhookDeliveryState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "Pending"),
+            Self::Delivered => write!(f, "Delivered"),
+            Self::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookSubscriptionEntity {
+    pub id: Uuid,
+    pub url: Arc<str>,
+    /// Schlüssel für die HMAC-SHA256-Signatur.
+    pub secret: Arc<str>,
+    pub description: Arc<str>,
+    /// Abonnierte Ereignisse; leer heißt „nichts“.
+    pub event_types: Arc<[WebhookEventType]>,
+    pub active: bool,
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookDeliveryEntity {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_type: WebhookEventType,
+    /// Fertig serialisierter JSON-Body, wird unverändert signiert.
+    pub payload: Arc<str>,
+    pub state: WebhookDeliveryState,
+    pub attempts: u32,
+    /// Frühester Zeitpunkt für den nächsten Zustellversuch.
+    pub next_attempt: PrimitiveDateTime,
+    pub last_status: Option<u16>,
+    pub last_error: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub delivered: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+/// Serialisiert die Ereignis-Liste für die `event_types`-Spalte.
+pub fn join_event_types(event_types: &[WebhookEventType]) -> String {
+    event_types
+        .iter()
+        .map(|event_type| event_type.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Gegenstück zu [`join_event_types`].
+pub fn split_event_types(value: &str) -> Result<Arc<[WebhookEventType]>, DaoError> {
+    value
+        .split(',')
+        .filter(|event_type| !event_type.is_empty())
+        .map(WebhookEventType::try_from)
+        .collect()
+}
+
+#[automock(type Transaction = crate::MockTransaction;)]
+#[async_trait::async_trait]
+pub trait WebhookDao {
+    type Transaction: crate::Transaction;
+
+    /// Alle nicht gelöschten Abonnements, auch inaktive.
+    async fn all_subscriptions(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookSubscriptionEntity]>, DaoError>;
+
+    async fn find_subscription_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WebhookSubscriptionEntity>, DaoError>;
+
+    async fn create_subscription(
+        &self,
+        entity: &WebhookSubscriptionEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update_subscription(
+        &self,
+        entity: &WebhookSubscriptionEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn find_delivery_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WebhookDeliveryEntity>, DaoError>;
+
+    /// Die jüngsten Zustellungen eines Abonnements, neueste zuerst.
+    async fn find_deliveries_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookDeliveryEntity]>, DaoError>;
+
+    /// `Pending`-Zustellungen mit `next_attempt <= now`, älteste zuerst.
+    async fn find_due_deliveries(
+        &self,
+        now: PrimitiveDateTime,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookDeliveryEntity]>, DaoError>;
+
+    async fn create_delivery(
+        &self,
+        entity: &WebhookDeliveryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+
+    async fn update_delivery(
+        &self,
+        entity: &WebhookDeliveryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_types_roundtrip() {
+        let event_types = [
+            WebhookEventType::BookingCreated,
+            WebhookEventType::AbsencePeriodUpdated,
+            WebhookEventType::WeekStatusChanged,
+        ];
+        let joined = join_event_types(&event_types);
+        assert_eq!(
+            joined,
+            "booking.created,absence_period.updated,week_status.changed"
+        );
+        assert_eq!(split_event_types(&joined).unwrap().as_ref(), &event_types);
+        assert!(split_event_types("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn unknown_event_type() {
+        assert!(split_event_types("booking.created,booking.moved").is_err());
+    }
+
+    #[test]
+    fn state_roundtrip() {
+        for state in [
+            WebhookDeliveryState::Pending,
+            WebhookDeliveryState::Delivered,
+            WebhookDeliveryState::Failed,
+        ] {
+            assert_eq!(
+                WebhookDeliveryState::try_from(state.to_string().as_str()).unwrap(),
+                state
+            );
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    absence::{
+        AbsenceCategoryEntity, AbsenceDao, AbsencePeriodEntity, AbsenceStatusEntity,
+        DayFractionEntity,
+    },
+    DaoError,
+};
+use shifty_utils::DateRange;
+use sqlx::{query, query_as};
+use time::{Date, PrimitiveDateTime};
+use uuid::Uuid;
+
+struct AbsencePeriodDb {
+    id: Uuid,
+    logical_id: Uuid,
+    sales_person_id: Uuid,
+    category: String,
+    from_date: Date,
+    to_date: Date,
+    description: Option<String>,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+    day_fraction: String,
+    status: String,
+}
+
+impl TryFrom<&AbsencePeriodDb> for AbsencePeriodEntity {
+    type Error = DaoError;
+
+    fn try_from(row: &AbsencePeriodDb) -> Result<Self, DaoError> {
+        Ok(Self {
+            id: row.id,
+            logical_id: row.logical_id,
+            sales_person_id: row.sales_person_id,
+            category: match row.category.as_str() {
+                "Vacation" => AbsenceCategoryEntity::Vacation,
+                "SickLeave" => AbsenceCategoryEntity::SickLeave,
+                "UnpaidLeave" => AbsenceCategoryEntity::UnpaidLeave,
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            from_date: row.from_date,
+            to_date: row.to_date,
+            description: row
+                .description
+                .clone()
+                .unwrap_or_default()
+                .as_str()
+                .into(),
+            created: row.created,
+            deleted: row.deleted,
+            version: row.update_version,
+            // Phase 8.3 (Plan 02): Migration enforces NOT NULL + CHECK ('full'|'half')
+            // — legacy rows default to 'full' at the DB level (no-drift). The match
+            // mirrors the AbsenceCategory pattern above: unknown values become a
+            // typed DAO-Error rather than a panic.
+            day_fraction: match row.day_fraction.as_str() {
+                "full" => DayFractionEntity::Full,
+                "half" => DayFractionEntity::Half,
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            status: match row.status.as_str() {
+                "requested" => AbsenceStatusEntity::Requested,
+                "approved" => AbsenceStatusEntity::Approved,
+                "rejected" => AbsenceStatusEntity::Rejected,
+                "cancelled" => AbsenceStatusEntity::Cancelled,
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+        })
+    }
+}
+
+fn category_to_str(c: &AbsenceCategoryEntity) -> &'static str {
+    match c {
+        AbsenceCategoryEntity::Vacation => "Vacation",
+        AbsenceCategoryEntity::SickLeave => "SickLeave",
+        AbsenceCategoryEntity::UnpaidLeave => "UnpaidLeave",
+    }
+}
+
+/// Phase 8.3 — serialize the domain enum into the lowercase DB representation
+/// (matches the CHECK constraint in migration `20260517120000`). PascalCase
+/// is reserved for the wire (`DayFractionTO`) — DB storage uses lowercase to
+/// avoid accidental case-sensitive comparisons.
+fn day_fraction_to_str(f: &DayFractionEntity) -> &'static str {
+    match f {
+        DayFractionEntity::Full => "full",
+        DayFractionEntity::Half => "half",
+    }
+}
+
+/// Lowercase wie `day_fraction` (CHECK-Constraint in Migration
+/// `20261017000900`).
+fn status_to_str(s: &AbsenceStatusEntity) -> &'static str {
+    match s {
+        AbsenceStatusEntity::Requested => "requested",
+        AbsenceStatusEntity::Approved => "approved",
+        AbsenceStatusEntity::Rejected => "rejected",
+        AbsenceStatusEntity::Cancelled => "cancelled",
+    }
+}
+
+pub struct AbsenceDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl AbsenceDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl AbsenceDao for AbsenceDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<AbsencePeriodEntity>, DaoError> {
+        Ok(query_as!(
+            AbsencePeriodDb,
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE id = $1 AND deleted IS NULL",
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(AbsencePeriodEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_logical_id(
+        &self,
+        logical_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<AbsencePeriodEntity>, DaoError> {
+        Ok(query_as!(
+            AbsencePeriodDb,
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE logical_id = $1 AND deleted IS NULL",
+            logical_id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(AbsencePeriodEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AbsencePeriodEntity]>, DaoError> {
+        Ok(query_as!(
+            AbsencePeriodDb,
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE sales_person_id = $1 AND deleted IS NULL ORDER BY from_date",
+            sales_person_id,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(AbsencePeriodEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_all(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AbsencePeriodEntity]>, DaoError> {
+        Ok(query_as!(
+            AbsencePeriodDb,
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE deleted IS NULL ORDER BY sales_person_id, from_date",
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(AbsencePeriodEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_overlapping(
+        &self,
+        sales_person_id: Uuid,
+        category: AbsenceCategoryEntity,
+        range: DateRange,
+        exclude_logical_id: Option<Uuid>,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AbsencePeriodEntity]>, DaoError> {
+        let category_str = category_to_str(&category);
+        let from = range.from();
+        let to = range.to();
+
+        let rows = match exclude_logical_id {
+            Some(exclude) => {
+                query_as!(
+                    AbsencePeriodDb,
+                    "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE sales_person_id = $1 AND category = $2 AND from_date <= $3 AND to_date >= $4 AND logical_id != $5 AND status IN ('requested', 'approved') AND deleted IS NULL",
+                    sales_person_id,
+                    category_str,
+                    to,
+                    from,
+                    exclude,
+                )
+                .fetch_all(tx.tx.lock().await.as_mut())
+                .await
+                .map_db_error()?
+            }
+            None => {
+                query_as!(
+                    AbsencePeriodDb,
+                    "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE sales_person_id = $1 AND category = $2 AND from_date <= $3 AND to_date >= $4 AND status IN ('requested', 'approved') AND deleted IS NULL",
+                    sales_person_id,
+                    category_str,
+                    to,
+                    from,
+                )
+                .fetch_all(tx.tx.lock().await.as_mut())
+                .await
+                .map_db_error()?
+            }
+        };
+
+        Ok(rows
+            .iter()
+            .map(AbsencePeriodEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_overlapping_for_booking(
+        &self,
+        sales_person_id: Uuid,
+        range: DateRange,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AbsencePeriodEntity]>, DaoError> {
+        let from = range.from();
+        let to = range.to();
+
+        Ok(query_as!(
+            AbsencePeriodDb,
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status \
+             FROM absence_period \
+             WHERE sales_person_id = $1 \
+               AND from_date <= $2 \
+               AND to_date >= $3 \
+               AND status = 'approved' \
+               AND deleted IS NULL \
+             ORDER BY from_date",
+            sales_person_id,
+            to,   // range.to → from_date <= range.to
+            from, // range.from → to_date >= range.from
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(AbsencePeriodEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &AbsencePeriodEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let category_str = category_to_str(&entity.category);
+        let description = entity.description.as_ref();
+        let day_fraction_str = day_fraction_to_str(&entity.day_fraction);
+        let status_str = status_to_str(&entity.status);
+        query!(
+            "INSERT INTO absence_period (id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_process, update_version, day_fraction, status) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
+            entity.id,
+            entity.logical_id,
+            entity.sales_person_id,
+            category_str,
+            entity.from_date,
+            entity.to_date,
+            description,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+            day_fraction_str,
+            status_str,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &AbsencePeriodEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(
+            "UPDATE absence_period SET deleted = $1, update_version = $2, update_process = $3 WHERE id = $4",
+            entity.deleted,
+            entity.version,
+            process,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::date;
+
+    #[tokio::test]
+    async fn absence_period_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = AbsenceDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = AbsencePeriodEntity {
+            id: Uuid::new_v4(),
+            logical_id: Uuid::new_v4(),
+            sales_person_id,
+            category: AbsenceCategoryEntity::Vacation,
+            from_date: date!(2026 - 05 - 15),
+            to_date: date!(2026 - 05 - 20),
+            description: "Urlaub".into(),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+            day_fraction: DayFractionEntity::Half,
+            status: AbsenceStatusEntity::Requested,
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_logical_id(entity.logical_id, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_overlapping(
+                sales_person_id,
+                AbsenceCategoryEntity::Vacation,
+                DateRange::new(date!(2026 - 05 - 18), date!(2026 - 05 - 25)).unwrap(),
+                None,
+                tx.clone(),
+            )
+            .await
+            .unwrap()
+            .as_ref(),
+            &[entity.clone()]
+        );
+
+        // Änderungen sind neue Zeilen mit derselben logical_id; die DAO setzt
+        // an der alten nur `deleted` und die Version.
+        let changed = AbsencePeriodEntity {
+            id: Uuid::new_v4(),
+            to_date: date!(2026 - 05 - 22),
+            status: AbsenceStatusEntity::Approved,
+            version: Uuid::new_v4(),
+            ..entity.clone()
+        };
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        dao.create(&changed, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert_eq!(
+            dao.find_by_logical_id(entity.logical_id, tx.clone())
+                .await
+                .unwrap(),
+            Some(changed.clone())
+        );
+        assert_eq!(
+            dao.find_by_sales_person(sales_person_id, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[changed]
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
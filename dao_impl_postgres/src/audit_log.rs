@@ -0,0 +1,246 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    audit_log::{AuditAction, AuditLogDao, AuditLogEntity, AuditLogQuery},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+struct AuditLogDb {
+    id: Uuid,
+    entity_type: String,
+    entity_id: String,
+    action: String,
+    user_id: Option<String>,
+    real_user_id: Option<String>,
+    process: String,
+    before_json: Option<String>,
+    after_json: Option<String>,
+    created: PrimitiveDateTime,
+}
+
+impl TryFrom<&AuditLogDb> for AuditLogEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &AuditLogDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: db.id,
+            entity_type: db.entity_type.as_str().into(),
+            entity_id: db.entity_id.as_str().into(),
+            action: str_to_action(&db.action)?,
+            user_id: db.user_id.as_deref().map(Arc::from),
+            real_user_id: db.real_user_id.as_deref().map(Arc::from),
+            process: db.process.as_str().into(),
+            before: db.before_json.as_deref().map(Arc::from),
+            after: db.after_json.as_deref().map(Arc::from),
+            created: db.created,
+        })
+    }
+}
+
+fn action_to_str(action: AuditAction) -> &'static str {
+    match action {
+        AuditAction::Create => "Create",
+        AuditAction::Update => "Update",
+        AuditAction::Delete => "Delete",
+    }
+}
+
+fn str_to_action(value: &str) -> Result<AuditAction, DaoError> {
+    match value {
+        "Create" => Ok(AuditAction::Create),
+        "Update" => Ok(AuditAction::Update),
+        "Delete" => Ok(AuditAction::Delete),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct AuditLogDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl AuditLogDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl AuditLogDao for AuditLogDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find(
+        &self,
+        audit_query: &AuditLogQuery,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AuditLogEntity]>, DaoError> {
+        let entity_type = audit_query.entity_type.as_deref();
+        let entity_id = audit_query.entity_id.as_deref();
+        let user_id = audit_query.user_id.as_deref();
+        let limit = i64::from(audit_query.limit);
+        query_as!(
+            AuditLogDb,
+            r#"SELECT id, entity_type, entity_id, action, user_id, real_user_id, process,
+                      before_json, after_json, created
+               FROM audit_log
+               WHERE ($1::TEXT IS NULL OR entity_type = $1)
+                 AND ($2::TEXT IS NULL OR entity_id = $2)
+                 AND ($3::TEXT IS NULL OR user_id = $3 OR real_user_id = $3)
+                 AND ($4::TIMESTAMP IS NULL OR created >= $4)
+                 AND ($5::TIMESTAMP IS NULL OR created < $5)
+               ORDER BY created DESC, id
+               LIMIT $6"#,
+            entity_type,
+            entity_id,
+            user_id,
+            audit_query.from,
+            audit_query.to,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(AuditLogEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &AuditLogEntity,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let entity_type = entity.entity_type.as_ref();
+        let entity_id = entity.entity_id.as_ref();
+        let action = action_to_str(entity.action);
+        let user_id = entity.user_id.as_deref();
+        let real_user_id = entity.real_user_id.as_deref();
+        let process = entity.process.as_ref();
+        let before_json = entity.before.as_deref();
+        let after_json = entity.after.as_deref();
+        query!(
+            r#"INSERT INTO audit_log (id, entity_type, entity_id, action, user_id, real_user_id,
+                   process, before_json, after_json, created)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+            entity.id,
+            entity_type,
+            entity_id,
+            action,
+            user_id,
+            real_user_id,
+            process,
+            before_json,
+            after_json,
+            entity.created,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::setup_pool;
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    fn entry(
+        entity_id: &str,
+        user_id: &str,
+        real_user_id: Option<&str>,
+        created: PrimitiveDateTime,
+    ) -> AuditLogEntity {
+        AuditLogEntity {
+            id: Uuid::new_v4(),
+            entity_type: "slot".into(),
+            entity_id: entity_id.into(),
+            action: AuditAction::Update,
+            user_id: Some(user_id.into()),
+            real_user_id: real_user_id.map(Arc::from),
+            process: "test".into(),
+            before: Some(r#"{"min_resources":1}"#.into()),
+            after: Some(r#"{"min_resources":2}"#.into()),
+            created,
+        }
+    }
+
+    #[tokio::test]
+    async fn find_filters_and_orders_newest_first() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let audit_log_dao = AuditLogDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let first = entry("a", "anna", None, datetime!(2026-10-01 08:00));
+        let second = entry("a", "bob", Some("admin"), datetime!(2026-10-02 08:00));
+        let other = entry("b", "anna", None, datetime!(2026-10-03 08:00));
+        for entity in [&first, &second, &other] {
+            audit_log_dao.create(entity, tx.clone()).await.unwrap();
+        }
+
+        let all = audit_log_dao
+            .find(
+                &AuditLogQuery {
+                    limit: 10,
+                    ..Default::default()
+                },
+                tx.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(all.as_ref(), &[other.clone(), second.clone(), first.clone()]);
+
+        let by_entity = audit_log_dao
+            .find(
+                &AuditLogQuery {
+                    entity_type: Some("slot".into()),
+                    entity_id: Some("a".into()),
+                    from: Some(datetime!(2026-10-02 00:00)),
+                    limit: 10,
+                    ..Default::default()
+                },
+                tx.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(by_entity.as_ref(), std::slice::from_ref(&second));
+
+        let by_real_user = audit_log_dao
+            .find(
+                &AuditLogQuery {
+                    user_id: Some("admin".into()),
+                    to: Some(datetime!(2026-10-03 00:00)),
+                    limit: 10,
+                    ..Default::default()
+                },
+                tx.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(by_real_user.as_ref(), &[second]);
+        transaction_dao.rollback(tx).await.unwrap();
+    }
+
+    #[test]
+    fn action_roundtrip() {
+        for action in [AuditAction::Create, AuditAction::Update, AuditAction::Delete] {
+            assert_eq!(str_to_action(action_to_str(action)).unwrap(), action);
+        }
+    }
+
+    #[test]
+    fn unknown_action() {
+        match str_to_action("Patch") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Patch"),
+            other => panic!("expected EnumValueNotFound(\"Patch\"), got {other:?}"),
+        }
+    }
+}
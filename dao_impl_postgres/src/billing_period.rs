@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use crate::{now_utc, ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    billing_period::{BillingPeriodDao, BillingPeriodEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{Date, PrimitiveDateTime};
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct BillingPeriodDb {
+    id: Uuid,
+    from_date_time: Date,
+    to_date_time: Date,
+    snapshot_schema_version: i64,
+    created: PrimitiveDateTime,
+    created_by: String,
+    deleted: Option<PrimitiveDateTime>,
+    deleted_by: Option<String>,
+    _update_version: Uuid,
+}
+
+impl TryFrom<&BillingPeriodDb> for BillingPeriodEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &BillingPeriodDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: db.id,
+            start_date: db.from_date_time,
+            end_date: db.to_date_time,
+            snapshot_schema_version: db.snapshot_schema_version as u32,
+            created_at: db.created,
+            created_by: db.created_by.as_str().into(),
+            deleted_at: db.deleted,
+            deleted_by: db.deleted_by.as_ref().map(|s| s.as_str().into()),
+        })
+    }
+}
+
+pub struct BillingPeriodDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl BillingPeriodDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl BillingPeriodDao for BillingPeriodDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn dump_all(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BillingPeriodEntity]>, DaoError> {
+        Ok(query_as!(
+            BillingPeriodDb,
+            "SELECT id, from_date_time, to_date_time, snapshot_schema_version, created, created_by, deleted, deleted_by, update_version as _update_version FROM billing_period"
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(BillingPeriodEntity::try_from)
+        .collect::<Result<Arc<[BillingPeriodEntity]>, DaoError>>()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &BillingPeriodEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<BillingPeriodEntity, DaoError> {
+        let created_by = entity.created_by.as_ref();
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+        let version = Uuid::new_v4();
+        let snapshot_schema_version = entity.snapshot_schema_version as i64;
+
+        query!(
+            "INSERT INTO billing_period (id, from_date_time, to_date_time, snapshot_schema_version, created, created_by, deleted, deleted_by, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            entity.id,
+            entity.start_date,
+            entity.end_date,
+            snapshot_schema_version,
+            entity.created_at,
+            created_by,
+            entity.deleted_at,
+            deleted_by,
+            version,
+            process
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(entity.clone())
+    }
+
+    async fn update(
+        &self,
+        entity: &BillingPeriodEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<BillingPeriodEntity, DaoError> {
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+        let version = Uuid::new_v4();
+
+        query!(
+            "UPDATE billing_period SET from_date_time = $1, to_date_time = $2, deleted = $3, deleted_by = $4, update_version = $5, update_process = $6 WHERE id = $7",
+            entity.start_date,
+            entity.end_date,
+            entity.deleted_at,
+            deleted_by,
+            version,
+            process,
+            entity.id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(entity.clone())
+    }
+
+    async fn delete_by_id(
+        &self,
+        id: Uuid,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let now = now_utc();
+        let version = Uuid::new_v4();
+
+        query!(
+            "UPDATE billing_period SET deleted = $1, deleted_by = $2, update_version = $3, update_process = $4 WHERE id = $5 AND deleted IS NULL",
+            now,
+            process,
+            version,
+            process,
+            id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn clear_all(
+        &self,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let now = now_utc();
+        let version = Uuid::new_v4();
+
+        query!(
+            "UPDATE billing_period SET deleted = $1, deleted_by = $2, update_version = $3, update_process = $4 WHERE deleted IS NULL",
+            now,
+            process,
+            version,
+            process
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::date;
+
+    #[tokio::test]
+    async fn billing_period_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = BillingPeriodDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = BillingPeriodEntity {
+            id: Uuid::new_v4(),
+            start_date: date!(2026 - 01 - 01),
+            end_date: date!(2026 - 01 - 31),
+            snapshot_schema_version: 3,
+            created_at: timestamp(),
+            created_by: "anna".into(),
+            deleted_at: None,
+            deleted_by: None,
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.end_date = date!(2026 - 02 - 28);
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_latest_end_date(tx.clone()).await.unwrap(),
+            Some(date!(2026 - 02 - 28))
+        );
+
+        dao.delete_by_id(entity.id, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        let dumped = dao.dump_all(tx.clone()).await.unwrap();
+        assert_eq!(dumped.len(), 1);
+        assert!(dumped[0].deleted_at.is_some());
+        assert_eq!(dumped[0].deleted_by.as_deref(), Some(PROCESS));
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
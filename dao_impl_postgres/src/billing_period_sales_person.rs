@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use crate::{now_utc, ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    billing_period_sales_person::{BillingPeriodSalesPersonDao, BillingPeriodSalesPersonEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct BillingPeriodSalesPersonDb {
+    id: Uuid,
+    billing_period_id: Uuid,
+    sales_person_id: Uuid,
+    value_type: String,
+    value_delta: f64,
+    value_ytd_from: f64,
+    value_ytd_to: f64,
+    value_full_year: f64,
+    created_at: PrimitiveDateTime,
+    created_by: String,
+    deleted_at: Option<PrimitiveDateTime>,
+    deleted_by: Option<String>,
+    _update_version: Uuid,
+}
+
+impl TryFrom<&BillingPeriodSalesPersonDb> for BillingPeriodSalesPersonEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &BillingPeriodSalesPersonDb) -> Result<Self, Self::Error> {
+        let created_at = db.created_at;
+        let deleted_at = db.deleted_at;
+
+        Ok(Self {
+            id: db.id,
+            billing_period_id: db.billing_period_id,
+            sales_person_id: db.sales_person_id,
+            value_type: db.value_type.as_str().into(),
+            value_delta: db.value_delta as f32,
+            value_ytd_from: db.value_ytd_from as f32,
+            value_ytd_to: db.value_ytd_to as f32,
+            value_full_year: db.value_full_year as f32,
+            created_at,
+            created_by: db.created_by.as_str().into(),
+            deleted_at,
+            deleted_by: db.deleted_by.as_ref().map(|s| s.as_str().into()),
+        })
+    }
+}
+
+pub struct BillingPeriodSalesPersonDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl BillingPeriodSalesPersonDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl BillingPeriodSalesPersonDao for BillingPeriodSalesPersonDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn dump_all(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BillingPeriodSalesPersonEntity]>, DaoError> {
+        Ok(query_as!(
+            BillingPeriodSalesPersonDb,
+            "SELECT id, billing_period_id, sales_person_id, value_type, value_delta, value_ytd_from, value_ytd_to, value_full_year, created_at, created_by, deleted_at, deleted_by, update_version as _update_version FROM billing_period_sales_person"
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(BillingPeriodSalesPersonEntity::try_from)
+        .collect::<Result<Arc<[BillingPeriodSalesPersonEntity]>, DaoError>>()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &BillingPeriodSalesPersonEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<BillingPeriodSalesPersonEntity, DaoError> {
+        let value_type = entity.value_type.as_ref();
+        let created_by = entity.created_by.as_ref();
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+        let version = Uuid::new_v4();
+        let value_delta = entity.value_delta as f64;
+        let value_ytd_from = entity.value_ytd_from as f64;
+        let value_ytd_to = entity.value_ytd_to as f64;
+        let value_full_year = entity.value_full_year as f64;
+
+        query!(
+            "INSERT INTO billing_period_sales_person (id, billing_period_id, sales_person_id, value_type, value_delta, value_ytd_from, value_ytd_to, value_full_year, created_at, created_by, deleted_at, deleted_by, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+            entity.id,
+            entity.billing_period_id,
+            entity.sales_person_id,
+            value_type,
+            value_delta,
+            value_ytd_from,
+            value_ytd_to,
+            value_full_year,
+            entity.created_at,
+            created_by,
+            entity.deleted_at,
+            deleted_by,
+            version,
+            process
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(entity.clone())
+    }
+
+    async fn update(
+        &self,
+        entity: &BillingPeriodSalesPersonEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<BillingPeriodSalesPersonEntity, DaoError> {
+        let value_type = entity.value_type.as_ref();
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+        let version = Uuid::new_v4();
+        let value_delta = entity.value_delta as f64;
+        let value_ytd_from = entity.value_ytd_from as f64;
+        let value_ytd_to = entity.value_ytd_to as f64;
+        let value_full_year = entity.value_full_year as f64;
+
+        query!(
+            "UPDATE billing_period_sales_person SET value_type = $1, value_delta = $2, value_ytd_from = $3, value_ytd_to = $4, value_full_year = $5, deleted_at = $6, deleted_by = $7, update_version = $8, update_process = $9 WHERE id = $10",
+            value_type,
+            value_delta,
+            value_ytd_from,
+            value_ytd_to,
+            value_full_year,
+            entity.deleted_at,
+            deleted_by,
+            version,
+            process,
+            entity.id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(entity.clone())
+    }
+
+    async fn delete_by_billing_period_id(
+        &self,
+        billing_period_id: Uuid,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let now = now_utc();
+        let version = Uuid::new_v4();
+
+        query!(
+            "UPDATE billing_period_sales_person SET deleted_at = $1, deleted_by = $2, update_version = $3, update_process = $4 WHERE billing_period_id = $5 AND deleted_at IS NULL",
+            now,
+            process,
+            version,
+            process,
+            billing_period_id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn clear_all(
+        &self,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let now = now_utc();
+        let version = Uuid::new_v4();
+
+        query!(
+            "UPDATE billing_period_sales_person SET deleted_at = $1, deleted_by = $2, update_version = $3, update_process = $4 WHERE deleted_at IS NULL",
+            now,
+            process,
+            version,
+            process
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::billing_period::BillingPeriodDaoImpl;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::billing_period::{BillingPeriodDao, BillingPeriodEntity};
+    use dao::TransactionDao;
+    use time::macros::date;
+
+    #[tokio::test]
+    async fn billing_period_sales_person_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = BillingPeriodSalesPersonDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let billing_period = BillingPeriodEntity {
+            id: Uuid::new_v4(),
+            start_date: date!(2026 - 01 - 01),
+            end_date: date!(2026 - 01 - 31),
+            snapshot_schema_version: 3,
+            created_at: timestamp(),
+            created_by: "anna".into(),
+            deleted_at: None,
+            deleted_by: None,
+        };
+        BillingPeriodDaoImpl::new(pool.clone())
+            .create(&billing_period, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        let mut entity = BillingPeriodSalesPersonEntity {
+            id: Uuid::new_v4(),
+            billing_period_id: billing_period.id,
+            sales_person_id,
+            value_type: "balance".into(),
+            value_delta: 1.5,
+            value_ytd_from: 10.0,
+            value_ytd_to: 11.5,
+            value_full_year: 20.0,
+            created_at: timestamp(),
+            created_by: "anna".into(),
+            deleted_at: None,
+            deleted_by: None,
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.value_delta = -2.0;
+        entity.value_ytd_to = 8.0;
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_billing_period_and_sales_person(
+                billing_period.id,
+                sales_person_id,
+                tx.clone()
+            )
+            .await
+            .unwrap()
+            .as_ref(),
+            &[entity.clone()]
+        );
+
+        dao.delete_by_billing_period_id(billing_period.id, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        let dumped = dao.dump_all(tx.clone()).await.unwrap();
+        assert_eq!(dumped.len(), 1);
+        assert!(dumped[0].deleted_at.is_some());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    booking::{BookingDao, BookingEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct BookingDb {
+    id: Uuid,
+    sales_person_id: Uuid,
+    slot_id: Uuid,
+    calendar_week: i64,
+    year: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    created_by: Option<String>,
+    deleted_by: Option<String>,
+    update_version: Uuid,
+}
+impl TryFrom<&BookingDb> for BookingEntity {
+    type Error = DaoError;
+    fn try_from(booking: &BookingDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: booking.id,
+            sales_person_id: booking.sales_person_id,
+            slot_id: booking.slot_id,
+            calendar_week: booking.calendar_week as i32,
+            year: booking.year as u32,
+            created: booking.created,
+            deleted: booking.deleted,
+            created_by: booking.created_by.as_ref().map(|s| s.as_str().into()),
+            deleted_by: booking.deleted_by.as_ref().map(|s| s.as_str().into()),
+            version: booking.update_version,
+        })
+    }
+}
+
+pub struct BookingDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl BookingDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl BookingDao for BookingDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[BookingEntity]>, DaoError> {
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE deleted IS NULL"
+        )
+            .fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .iter()
+            .map(BookingEntity::try_from)
+            .collect::<Result<Arc<[BookingEntity]>, DaoError>>()?
+        )
+    }
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<BookingEntity>, DaoError> {
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE id = $1",
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(BookingEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_slot_id_from(
+        &self,
+        slot_id: Uuid,
+        year: u32,
+        week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BookingEntity]>, DaoError> {
+        let until = year * 100 + week as u32;
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE slot_id = $1 AND year * 100 + calendar_week >= $2 AND deleted IS NULL",
+            slot_id,
+            until as i64,
+        )
+        //.fetch_all(self.pool.as_ref())
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(BookingEntity::try_from)
+        .collect::<Result<Arc<[BookingEntity]>, DaoError>>()?
+        )
+    }
+
+    async fn find_by_booking_data(
+        &self,
+        sales_person_id: Uuid,
+        slot_id: Uuid,
+        calendar_week: i32,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Option<BookingEntity>, DaoError> {
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE sales_person_id = $1 AND slot_id = $2 AND calendar_week = $3 AND year = $4 AND deleted IS NULL",
+            sales_person_id,
+            slot_id,
+            calendar_week as i64,
+            year as i64,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(BookingEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BookingEntity]>, DaoError> {
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE calendar_week = $1 AND year = $2 AND deleted IS NULL",
+            calendar_week as i64,
+            year as i64,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(BookingEntity::try_from)
+        .collect::<Result<Arc<[BookingEntity]>, DaoError>>()?
+        )
+    }
+
+    async fn find_deleted_by_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BookingEntity]>, DaoError> {
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE calendar_week = $1 AND year = $2 AND deleted IS NOT NULL",
+            calendar_week as i64,
+            year as i64,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(BookingEntity::try_from)
+        .collect::<Result<Arc<[BookingEntity]>, DaoError>>()?
+        )
+    }
+
+    async fn create(
+        &self,
+        entity: &BookingEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let created_by = entity.created_by.as_ref().map(|s| s.as_ref());
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+        query!("INSERT INTO booking (id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+            entity.id, entity.sales_person_id, entity.slot_id, entity.calendar_week as i64, entity.year as i64, entity.created, entity.deleted, created_by, deleted_by, entity.version, process
+        ).execute(tx.tx.lock().await.as_mut()).await.map_db_error()?;
+        Ok(())
+    }
+    async fn update(
+        &self,
+        entity: &BookingEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+        query!(
+            "UPDATE booking SET deleted = $1, deleted_by = $2, update_version = $3, update_process = $4 WHERE id = $5",
+            entity.deleted,
+            deleted_by,
+            entity.version,
+            process,
+            entity.id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_sales_person, create_shiftplan, create_slot, setup_pool, timestamp, PROCESS,
+    };
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn booking_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = BookingDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let shiftplan_id = create_shiftplan(&pool, tx.clone()).await;
+        let slot_id = create_slot(&pool, shiftplan_id, tx.clone()).await;
+        let mut entity = BookingEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            slot_id,
+            calendar_week: 17,
+            year: 2026,
+            created: timestamp(),
+            deleted: None,
+            created_by: Some("anna".into()),
+            deleted_by: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_booking_data(sales_person_id, slot_id, 17, 2026, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_week(17, 2026, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        // Buchungen kennen nur das Löschen als Änderung.
+        entity.deleted = Some(timestamp());
+        entity.deleted_by = Some("planner".into());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert!(dao
+            .find_by_week(17, 2026, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            dao.find_deleted_by_week(17, 2026, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
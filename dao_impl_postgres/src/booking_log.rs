@@ -0,0 +1,169 @@
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::booking_log::{BookingLogDao, BookingLogEntity};
+use dao::DaoError;
+use shifty_utils::DayOfWeek;
+use sqlx::query_as;
+use std::sync::Arc;
+use time::{PrimitiveDateTime, Time};
+
+struct BookingLogDb {
+    name: Option<String>,
+    year: Option<i64>,
+    calendar_week: Option<i64>,
+    day_of_week: Option<i64>,
+    time_from: Option<Time>,
+    time_to: Option<Time>,
+    created: Option<PrimitiveDateTime>,
+    deleted: Option<PrimitiveDateTime>,
+    created_by: Option<String>,
+    deleted_by: Option<String>,
+}
+
+impl TryFrom<&BookingLogDb> for BookingLogEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &BookingLogDb) -> Result<Self, Self::Error> {
+        let year = db.year.ok_or_else(|| DaoError::EnumValueNotFound("year is NULL".into()))?;
+        let calendar_week = db.calendar_week.ok_or_else(|| DaoError::EnumValueNotFound("calendar_week is NULL".into()))?;
+        let day_of_week_num = db.day_of_week.ok_or_else(|| DaoError::EnumValueNotFound("day_of_week is NULL".into()))?;
+        let name = db.name.as_ref().ok_or_else(|| DaoError::EnumValueNotFound("name is NULL".into()))?;
+        let time_from = db.time_from.ok_or_else(|| DaoError::EnumValueNotFound("time_from is NULL".into()))?;
+        let time_to = db.time_to.ok_or_else(|| DaoError::EnumValueNotFound("time_to is NULL".into()))?;
+        let created = db.created.ok_or_else(|| DaoError::EnumValueNotFound("created is NULL".into()))?;
+        // created_by is intentionally nullable in the schema (Migration
+        // 20250115000000): pre-audit-tracking bookings carry NULL. Modelled as
+        // Option<Arc<str>> all the way up to the DTO; live write paths still
+        // populate it (BookingService::create + Authentication::Full callers
+        // pre-fill booking.created_by).
+
+        Ok(Self {
+            year: year as u32,
+            calendar_week: calendar_week as u8,
+            day_of_week: DayOfWeek::from_number(day_of_week_num as u8)
+                .ok_or_else(|| DaoError::InvalidDayOfWeek(day_of_week_num as u8))?,
+            name: name.clone().into(),
+            time_from,
+            time_to,
+            created,
+            deleted: db.deleted,
+            created_by: db.created_by.as_ref().map(|s| s.clone().into()),
+            deleted_by: db.deleted_by.as_ref().map(|s| s.clone().into()),
+        })
+    }
+}
+
+pub struct BookingLogDaoImpl;
+
+#[async_trait]
+impl BookingLogDao for BookingLogDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn get_booking_logs_for_week(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BookingLogEntity]>, DaoError> {
+        let result = query_as!(
+            BookingLogDb,
+            r#"
+            SELECT
+                name,
+                year,
+                calendar_week,
+                day_of_week,
+                time_from,
+                time_to,
+                created,
+                deleted,
+                created_by,
+                deleted_by
+            FROM bookings_view
+            WHERE year = $1 AND calendar_week = $2
+            ORDER BY day_of_week, time_from
+            "#,
+            year as i64,
+            calendar_week as i64
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(result
+            .iter()
+            .map(BookingLogEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::booking::BookingDaoImpl;
+    use crate::test_support::{
+        create_booking, create_sales_person, create_shiftplan, create_slot, setup_pool, timestamp,
+        PROCESS,
+    };
+    use crate::TransactionDaoImpl;
+    use dao::booking::BookingDao;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn booking_log_follows_bookings() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let booking_dao = BookingDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let shiftplan_id = create_shiftplan(&pool, tx.clone()).await;
+        let slot_id = create_slot(&pool, shiftplan_id, tx.clone()).await;
+        let booking_id = create_booking(&pool, sales_person_id, slot_id, tx.clone()).await;
+        let mut entry = BookingLogEntity {
+            year: 2026,
+            calendar_week: 17,
+            day_of_week: DayOfWeek::Monday,
+            name: "Anna".into(),
+            time_from: Time::from_hms(9, 0, 0).unwrap(),
+            time_to: Time::from_hms(13, 0, 0).unwrap(),
+            created: timestamp(),
+            deleted: None,
+            created_by: Some("anna".into()),
+            deleted_by: None,
+        };
+
+        assert_eq!(
+            BookingLogDaoImpl
+                .get_booking_logs_for_week(2026, 17, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entry.clone()]
+        );
+
+        let mut booking = booking_dao
+            .find_by_id(booking_id, tx.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        booking.deleted = Some(timestamp());
+        booking.deleted_by = Some("planner".into());
+        booking_dao
+            .update(&booking, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        entry.deleted = Some(timestamp());
+        entry.deleted_by = Some("planner".into());
+        assert_eq!(
+            BookingLogDaoImpl
+                .get_booking_logs_for_week(2026, 17, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entry]
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::{
+    carryover::{CarryoverDao, CarryoverDirtyEntity, CarryoverEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct CarryoverDb {
+    sales_person_id: Uuid,
+    year: i64,
+    carryover_hours: f64,
+    vacation: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+#[derive(Debug)]
+struct CarryoverDirtyDb {
+    sales_person_id: Uuid,
+    year: i64,
+    update_version: Uuid,
+}
+
+impl TryFrom<&CarryoverDirtyDb> for CarryoverDirtyEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &CarryoverDirtyDb) -> Result<Self, Self::Error> {
+        Ok(CarryoverDirtyEntity {
+            sales_person_id: db.sales_person_id,
+            year: db.year as u32,
+            version: db.update_version,
+        })
+    }
+}
+
+impl TryFrom<&CarryoverDb> for CarryoverEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &CarryoverDb) -> Result<Self, Self::Error> {
+        Ok(CarryoverEntity {
+            sales_person_id: db.sales_person_id,
+            year: db.year as u32,
+            carryover_hours: db.carryover_hours as f32,
+            vacation: db.vacation as i32,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct CarryoverDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl CarryoverDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl CarryoverDao for CarryoverDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn find_by_sales_person_id_and_year(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Option<CarryoverEntity>, DaoError> {
+        Ok(query_as!(
+            CarryoverDb,
+            r#"SELECT sales_person_id, year, carryover_hours, vacation, created, deleted, update_version
+               FROM employee_yearly_carryover
+               WHERE sales_person_id = $1 AND year = $2 AND deleted IS NULL"#,
+            sales_person_id,
+            year as i64,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(CarryoverEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn upsert(
+        &self,
+        entity: &CarryoverEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"INSERT INTO employee_yearly_carryover (sales_person_id, year, carryover_hours, vacation, created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+               ON CONFLICT(sales_person_id, year) DO UPDATE SET carryover_hours=excluded.carryover_hours, vacation=excluded.vacation, deleted=excluded.deleted, update_process=excluded.update_process, update_version=excluded.update_version"#,
+            entity.sales_person_id,
+            entity.year as i64,
+            entity.carryover_hours as f64,
+            entity.vacation as i64,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn mark_dirty(
+        &self,
+        entity: &CarryoverDirtyEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"INSERT INTO carryover_dirty (sales_person_id, year, update_process, update_version)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(sales_person_id, year) DO UPDATE SET update_process=excluded.update_process, update_version=excluded.update_version"#,
+            entity.sales_person_id,
+            entity.year as i64,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn all_dirty(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[CarryoverDirtyEntity]>, DaoError> {
+        Ok(query_as!(
+            CarryoverDirtyDb,
+            r#"SELECT sales_person_id, year, update_version
+               FROM carryover_dirty
+               ORDER BY year"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(CarryoverDirtyEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn clear_dirty(
+        &self,
+        entity: &CarryoverDirtyEntity,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"DELETE FROM carryover_dirty
+               WHERE sales_person_id = $1 AND year = $2 AND update_version = $3"#,
+            entity.sales_person_id,
+            entity.year as i64,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn carryover_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = CarryoverDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = CarryoverEntity {
+            sales_person_id,
+            year: 2025,
+            carryover_hours: 12.5,
+            vacation: 3,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.upsert(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_id_and_year(sales_person_id, 2025, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.carryover_hours = -4.0;
+        entity.vacation = 0;
+        entity.version = Uuid::new_v4();
+        dao.upsert(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_id_and_year(sales_person_id, 2025, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.upsert(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_id_and_year(sales_person_id, 2025, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn clear_dirty_keeps_renewed_marks() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = CarryoverDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let first = CarryoverDirtyEntity {
+            sales_person_id,
+            year: 2025,
+            version: Uuid::new_v4(),
+        };
+        let renewed = CarryoverDirtyEntity {
+            version: Uuid::new_v4(),
+            ..first.clone()
+        };
+
+        dao.mark_dirty(&first, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.all_dirty(tx.clone()).await.unwrap().as_ref(),
+            std::slice::from_ref(&first)
+        );
+        dao.mark_dirty(&renewed, PROCESS, tx.clone()).await.unwrap();
+
+        dao.clear_dirty(&first, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.all_dirty(tx.clone()).await.unwrap().as_ref(),
+            std::slice::from_ref(&renewed)
+        );
+        dao.clear_dirty(&renewed, tx.clone()).await.unwrap();
+        assert!(dao.all_dirty(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
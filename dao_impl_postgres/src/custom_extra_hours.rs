@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::{
+    custom_extra_hours::{CustomExtraHoursDao, CustomExtraHoursEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct CustomExtraHoursDb {
+    id: Uuid,
+    name: String,
+    description: Option<String>,
+    modifies_balance: bool,
+
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+#[derive(Debug)]
+struct CustomExtraHoursSalesPersonMappingDb {
+    custom_extra_hours_id: Uuid,
+    sales_person_id: Uuid,
+}
+
+fn combine(
+    custom_hours: Arc<[CustomExtraHoursDb]>,
+    sales_person_mapping: Arc<[CustomExtraHoursSalesPersonMappingDb]>,
+) -> Arc<[CustomExtraHoursEntity]> {
+    let mut result = Vec::new();
+
+    for custom_hours in custom_hours.iter() {
+        let id = custom_hours.id;
+        let name = Arc::from(custom_hours.name.as_str());
+        let description = custom_hours
+            .description
+            .as_ref()
+            .map(|d| Arc::from(d.as_str()));
+        let modifies_balance = custom_hours.modifies_balance;
+
+        let created = custom_hours.created;
+
+        let deleted = custom_hours.deleted;
+
+        let version = custom_hours.update_version;
+
+        let assigned_sales_person_ids: Vec<Uuid> = sales_person_mapping
+            .iter()
+            .filter(|mapping| mapping.custom_extra_hours_id == custom_hours.id)
+            .map(|mapping| mapping.sales_person_id)
+            .collect();
+
+        result.push(CustomExtraHoursEntity {
+            id,
+            name,
+            description,
+            modifies_balance,
+            assigned_sales_person_ids: Arc::from(assigned_sales_person_ids),
+            created,
+            deleted,
+            version,
+        });
+    }
+
+    Arc::from(result)
+}
+
+pub struct CustomExtraHoursDaoImpl;
+
+#[async_trait]
+impl CustomExtraHoursDao for CustomExtraHoursDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    /// Returns everything, including deleted items.
+    async fn dump(&self, tx: Self::Transaction) -> Result<Arc<[CustomExtraHoursEntity]>, DaoError> {
+        let custom_hours = query_as!(
+            CustomExtraHoursDb,
+            r#"
+            SELECT id, name, description, modifies_balance, created, deleted, update_version
+            FROM custom_extra_hours
+            "#
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        let sales_person_mapping = query_as!(
+            CustomExtraHoursSalesPersonMappingDb,
+            r#"
+            SELECT custom_extra_hours_id, sales_person_id
+            FROM custom_extra_hours_sales_person
+            "#
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(combine(
+            Arc::from(custom_hours),
+            Arc::from(sales_person_mapping),
+        ))
+    }
+
+    /// Create a new entity
+    async fn create(
+        &self,
+        entity: &CustomExtraHoursEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let description = entity.description.as_ref().map(|d| d.as_ref());
+        let modifies_balance = entity.modifies_balance;
+
+        query!(
+            r#"
+            INSERT INTO custom_extra_hours (id, name, description, modifies_balance, created, deleted, update_version, update_process)
+            VALUES ($1, $2, $3, $4, $5, NULL, $6, $7)
+            "#,
+            entity.id,
+            name,
+            description,
+            modifies_balance,
+            entity.created,
+            entity.version,
+            process
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        for sales_person_id in entity.assigned_sales_person_ids.iter() {
+            query!(
+                r#"
+                INSERT INTO custom_extra_hours_sales_person (custom_extra_hours_id, sales_person_id, created, update_process)
+                VALUES ($1, $2, $3, $4)
+                "#,
+                entity.id,
+                sales_person_id,
+                entity.created,
+                process
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+
+        Ok(())
+    }
+
+    /// Upates an entity
+    async fn update(
+        &self,
+        entity: CustomExtraHoursEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let description = entity.description.as_ref().map(|d| d.as_ref());
+        let modifies_balance = entity.modifies_balance;
+
+        query!(
+            r#"
+            UPDATE custom_extra_hours
+            SET name = $1, description = $2, modifies_balance = $3, created = $4, deleted = $5, update_version = $6, update_process = $7
+            WHERE id = $8
+            "#,
+            name,
+            description,
+            modifies_balance,
+            entity.created,
+            entity.deleted,
+            entity.version,
+            process,
+            entity.id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        // Insert all sales_person_ids
+        for sales_person_id in entity.assigned_sales_person_ids.iter() {
+            query!(
+                r#"
+                INSERT INTO custom_extra_hours_sales_person (custom_extra_hours_id, sales_person_id, created, update_process)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT DO NOTHING
+                "#,
+                entity.id,
+                sales_person_id,
+                entity.created,
+                process
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+
+        // Remove all sales_person_ids that are not in the new list
+        query!(
+            "DELETE FROM custom_extra_hours_sales_person WHERE custom_extra_hours_id = $1 AND NOT (sales_person_id = ANY($2))",
+            entity.id,
+            entity.assigned_sales_person_ids.as_ref(),
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::{TransactionDaoImpl, TransactionImpl};
+    use dao::TransactionDao;
+
+    async fn find(id: Uuid, tx: TransactionImpl) -> Option<CustomExtraHoursEntity> {
+        CustomExtraHoursDaoImpl.find_by_id(id, tx).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn custom_extra_hours_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = CustomExtraHoursDaoImpl;
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let anna = create_sales_person(&pool, tx.clone()).await;
+        let bert = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = CustomExtraHoursEntity {
+            id: Uuid::new_v4(),
+            name: "Schulung".into(),
+            description: Some("Extern".into()),
+            modifies_balance: true,
+            assigned_sales_person_ids: [anna].into(),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(find(entity.id, tx.clone()).await, Some(entity.clone()));
+
+        entity.name = "Fortbildung".into();
+        entity.description = None;
+        entity.assigned_sales_person_ids = [bert].into();
+        entity.version = Uuid::new_v4();
+        dao.update(entity.clone(), PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(find(entity.id, tx.clone()).await, Some(entity.clone()));
+        assert!(dao
+            .find_by_sales_person_id(anna, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(entity.clone(), PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(find(entity.id, tx.clone()).await, Some(entity.clone()));
+        assert!(dao.find_all(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
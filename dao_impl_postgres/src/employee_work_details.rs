@@ -0,0 +1,543 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    employee_work_details::{EmployeeWorkDetailsDao, EmployeeWorkDetailsEntity},
+    DaoError,
+};
+use shifty_utils::DayOfWeek;
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+pub struct EmployeeWorkDetailsDb {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub expected_hours: f64,
+    pub from_day_of_week: i64,
+    pub from_calendar_week: i64,
+    pub from_year: i64,
+    pub to_day_of_week: i64,
+    pub to_calendar_week: i64,
+    pub to_year: i64,
+    pub workdays_per_week: i64,
+    pub is_dynamic: i64,
+    pub cap_planned_hours_to_expected: i64,
+    pub committed_voluntary: f64,
+    pub use_actual_hours: i64,
+
+    pub monday: i64,
+    pub tuesday: i64,
+    pub wednesday: i64,
+    pub thursday: i64,
+    pub friday: i64,
+    pub saturday: i64,
+    pub sunday: i64,
+
+    pub vacation_days: i64,
+
+    pub created: PrimitiveDateTime,
+    pub deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&EmployeeWorkDetailsDb> for EmployeeWorkDetailsEntity {
+    type Error = DaoError;
+
+    fn try_from(working_hours: &EmployeeWorkDetailsDb) -> Result<Self, DaoError> {
+        Ok(Self {
+            id: working_hours.id,
+            sales_person_id: working_hours.sales_person_id,
+            expected_hours: working_hours.expected_hours as f32,
+            from_day_of_week: DayOfWeek::from_number(working_hours.from_day_of_week as u8).ok_or(
+                DaoError::InvalidDayOfWeek(working_hours.from_day_of_week as u8),
+            )?,
+            from_calendar_week: working_hours.from_calendar_week as u8,
+            from_year: working_hours.from_year as u32,
+            to_day_of_week: DayOfWeek::from_number(working_hours.to_day_of_week as u8).ok_or(
+                DaoError::InvalidDayOfWeek(working_hours.to_day_of_week as u8),
+            )?,
+            to_calendar_week: working_hours.to_calendar_week as u8,
+            to_year: working_hours.to_year as u32,
+            workdays_per_week: working_hours.workdays_per_week as u8,
+            is_dynamic: working_hours.is_dynamic != 0,
+            cap_planned_hours_to_expected: working_hours.cap_planned_hours_to_expected != 0,
+            committed_voluntary: working_hours.committed_voluntary as f32,
+            use_actual_hours: working_hours.use_actual_hours != 0,
+
+            monday: working_hours.monday != 0,
+            tuesday: working_hours.tuesday != 0,
+            wednesday: working_hours.wednesday != 0,
+            thursday: working_hours.thursday != 0,
+            friday: working_hours.friday != 0,
+            saturday: working_hours.saturday != 0,
+            sunday: working_hours.sunday != 0,
+
+            vacation_days: working_hours.vacation_days as u8,
+
+            created: working_hours.created,
+            deleted: working_hours.deleted,
+            version: working_hours.update_version,
+        })
+    }
+}
+
+pub struct EmployeeWorkDetailsDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl EmployeeWorkDetailsDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[EmployeeWorkDetailsEntity]>, DaoError> {
+        query_as!(
+            EmployeeWorkDetailsDb,
+            r#"
+            SELECT
+                id,
+                sales_person_id,
+                expected_hours,
+                from_day_of_week,
+                from_calendar_week,
+                from_year,
+                to_day_of_week,
+                to_calendar_week,
+                to_year,
+                workdays_per_week,
+                is_dynamic,
+                cap_planned_hours_to_expected,
+                committed_voluntary,
+                use_actual_hours,
+
+                monday,
+                tuesday,
+                wednesday,
+                thursday,
+                friday,
+                saturday,
+                sunday,
+
+                vacation_days,
+
+                created,
+                deleted,
+                update_version
+            FROM
+                employee_work_details
+            WHERE
+                deleted IS NULL
+            "#
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(EmployeeWorkDetailsEntity::try_from)
+        .collect::<Result<_, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<EmployeeWorkDetailsEntity>, DaoError> {
+        query_as!(
+            EmployeeWorkDetailsDb,
+            r#"
+            SELECT
+                id,
+                sales_person_id,
+                expected_hours,
+                from_day_of_week,
+                from_calendar_week,
+                from_year,
+                to_day_of_week,
+                to_calendar_week,
+                to_year,
+                workdays_per_week,
+                is_dynamic,
+                cap_planned_hours_to_expected,
+                committed_voluntary,
+                use_actual_hours,
+
+                monday,
+                tuesday,
+                wednesday,
+                thursday,
+                friday,
+                saturday,
+                sunday,
+
+                vacation_days,
+
+                created,
+                deleted,
+                update_version
+            FROM
+                employee_work_details
+            WHERE
+                id = $1
+                and deleted IS NULL
+            "#,
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(EmployeeWorkDetailsEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_sales_person_id(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[EmployeeWorkDetailsEntity]>, DaoError> {
+        query_as!(
+            EmployeeWorkDetailsDb,
+            r#"
+            SELECT
+                id,
+                sales_person_id,
+                expected_hours,
+                from_day_of_week,
+                from_calendar_week,
+                from_year,
+                to_day_of_week,
+                to_calendar_week,
+                to_year,
+                workdays_per_week,
+                is_dynamic,
+                cap_planned_hours_to_expected,
+                committed_voluntary,
+                use_actual_hours,
+
+                monday,
+                tuesday,
+                wednesday,
+                thursday,
+                friday,
+                saturday,
+                sunday,
+
+                vacation_days,
+
+
+
+                created,
+                deleted,
+                update_version
+            FROM
+                employee_work_details
+            WHERE
+                sales_person_id = $1
+                and deleted IS NULL
+            "#,
+            sales_person_id
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(EmployeeWorkDetailsEntity::try_from)
+        .collect::<Result<_, _>>()
+    }
+
+    async fn find_for_week(
+        &self,
+        calenar_week: u8,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[EmployeeWorkDetailsEntity]>, DaoError> {
+        query_as!(
+            EmployeeWorkDetailsDb,
+            r#"
+            SELECT
+                id,
+                sales_person_id,
+                expected_hours,
+                from_day_of_week,
+                from_calendar_week,
+                from_year,
+                to_day_of_week,
+                to_calendar_week,
+                to_year,
+                workdays_per_week,
+                is_dynamic,
+                cap_planned_hours_to_expected,
+                committed_voluntary,
+                use_actual_hours,
+
+                monday,
+                tuesday,
+                wednesday,
+                thursday,
+                friday,
+                saturday,
+                sunday,
+
+                vacation_days,
+
+                created,
+                deleted,
+                update_version
+            FROM
+                employee_work_details
+            WHERE
+                deleted IS NULL
+                AND
+                (from_year * 100 + from_calendar_week) <= ($1 * 100 + $2)
+                AND (to_year * 100 + to_calendar_week) >= ($3 * 100 + $4)
+            "#,
+            year as i32,
+            calenar_week as i32,
+            year as i32,
+            calenar_week as i32,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(EmployeeWorkDetailsEntity::try_from)
+        .collect::<Result<_, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &EmployeeWorkDetailsEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let expected_hours = entity.expected_hours as f64;
+        let from_day_of_week = entity.from_day_of_week.to_number() as i64;
+        let from_calendar_week = entity.from_calendar_week as i64;
+        let from_year = entity.from_year as i64;
+        let to_day_of_week = entity.to_day_of_week.to_number() as i64;
+        let to_calendar_week = entity.to_calendar_week as i64;
+        let to_year = entity.to_year as i64;
+        let is_dynamic = entity.is_dynamic as i64;
+        let cap_planned_hours_to_expected = entity.cap_planned_hours_to_expected as i64;
+        let committed_voluntary = entity.committed_voluntary as f64;
+        let use_actual_hours = entity.use_actual_hours as i64;
+        let monday = entity.monday as i64;
+        let tuesday = entity.tuesday as i64;
+        let wednesday = entity.wednesday as i64;
+        let thursday = entity.thursday as i64;
+        let friday = entity.friday as i64;
+        let saturday = entity.saturday as i64;
+        let sunday = entity.sunday as i64;
+        let vacation_days = entity.vacation_days as i64;
+        let workdays_per_week = entity.workdays_per_week as i64;
+        query!(
+            r#"
+            INSERT INTO employee_work_details (
+                id,
+                sales_person_id,
+                expected_hours,
+                from_day_of_week,
+                from_calendar_week,
+                from_year,
+                to_day_of_week,
+                to_calendar_week,
+                to_year,
+                workdays_per_week,
+                is_dynamic,
+                cap_planned_hours_to_expected,
+                committed_voluntary,
+                use_actual_hours,
+
+                monday,
+                tuesday,
+                wednesday,
+                thursday,
+                friday,
+                saturday,
+                sunday,
+
+                vacation_days,
+
+                created,
+                update_process,
+                update_version
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25)
+            "#,
+            entity.id,
+            entity.sales_person_id,
+            expected_hours,
+            from_day_of_week,
+            from_calendar_week,
+            from_year,
+            to_day_of_week,
+            to_calendar_week,
+            to_year,
+            workdays_per_week,
+            is_dynamic,
+            cap_planned_hours_to_expected,
+            committed_voluntary,
+            use_actual_hours,
+            monday,
+            tuesday,
+            wednesday,
+            thursday,
+            friday,
+            saturday,
+            sunday,
+            vacation_days,
+            entity.created,
+            process,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &EmployeeWorkDetailsEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let to_year = entity.to_year as i64;
+        let to_calendar_week = entity.to_calendar_week as i64;
+        let to_day_of_week = entity.to_day_of_week.to_number() as i64;
+        let expected_hours = entity.expected_hours as f64;
+        let vacation_days = entity.vacation_days as i64;
+        let workdays_per_week = entity.workdays_per_week as i64;
+        let is_dynamic = entity.is_dynamic as i64;
+        let cap_planned_hours_to_expected = entity.cap_planned_hours_to_expected as i64;
+        let committed_voluntary = entity.committed_voluntary as f64;
+        let use_actual_hours = entity.use_actual_hours as i64;
+        query!(
+            r#"
+            UPDATE employee_work_details SET
+                deleted = $1,
+                update_process = $2,
+                to_year = $3,
+                to_calendar_week = $4,
+                to_day_of_week = $5,
+                expected_hours = $6,
+                vacation_days = $7,
+                workdays_per_week = $8,
+                is_dynamic = $9,
+                cap_planned_hours_to_expected = $10,
+                committed_voluntary = $11,
+                use_actual_hours = $12
+            WHERE
+                id = $13
+            "#,
+            entity.deleted,
+            process,
+            to_year,
+            to_calendar_week,
+            to_day_of_week,
+            expected_hours,
+            vacation_days,
+            workdays_per_week,
+            is_dynamic,
+            cap_planned_hours_to_expected,
+            committed_voluntary,
+            use_actual_hours,
+            entity.id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn employee_work_details_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = EmployeeWorkDetailsDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let id = Uuid::new_v4();
+        let mut entity = EmployeeWorkDetailsEntity {
+            id,
+            sales_person_id,
+            expected_hours: 20.0,
+            from_day_of_week: DayOfWeek::Monday,
+            from_calendar_week: 1,
+            from_year: 2026,
+            to_day_of_week: DayOfWeek::Sunday,
+            to_calendar_week: 52,
+            to_year: 2026,
+            workdays_per_week: 4,
+            is_dynamic: false,
+            cap_planned_hours_to_expected: true,
+            committed_voluntary: 2.5,
+            use_actual_hours: false,
+            monday: true,
+            tuesday: true,
+            wednesday: false,
+            thursday: true,
+            friday: true,
+            saturday: false,
+            sunday: false,
+            vacation_days: 24,
+            created: timestamp(),
+            deleted: None,
+            // Wie im SQLite-Backend dient die ID als Version.
+            version: id,
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_for_week(17, 2026, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.expected_hours = 30.0;
+        entity.to_calendar_week = 26;
+        entity.to_day_of_week = DayOfWeek::Friday;
+        entity.is_dynamic = true;
+        entity.use_actual_hours = true;
+        entity.vacation_days = 28;
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_id(sales_person_id, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao.all(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,425 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    export_target::{
+        ExportArtifactType, ExportRunState, ExportTargetDao, ExportTargetEntity, ExportTargetKind,
+        ExportTargetRunEntity,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct ExportTargetDb {
+    id: Uuid,
+    name: String,
+    kind: String,
+    enabled: i64,
+    url: Option<String>,
+    username: Option<String>,
+    secret: Option<String>,
+    path: String,
+    cron_schedule: String,
+    artifact_type: String,
+    filename_pattern: String,
+    weeks_horizon: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&ExportTargetDb> for ExportTargetEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &ExportTargetDb) -> Result<Self, Self::Error> {
+        Ok(ExportTargetEntity {
+            id: db.id,
+            name: db.name.as_str().into(),
+            kind: ExportTargetKind::try_from(db.kind.as_str())?,
+            enabled: db.enabled != 0,
+            url: db.url.as_deref().map(Arc::from),
+            username: db.username.as_deref().map(Arc::from),
+            secret: db.secret.as_deref().map(Arc::from),
+            path: db.path.as_str().into(),
+            cron_schedule: db.cron_schedule.as_str().into(),
+            artifact_type: ExportArtifactType::try_from(db.artifact_type.as_str())?,
+            filename_pattern: db.filename_pattern.as_str().into(),
+            weeks_horizon: db.weeks_horizon as u32,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ExportTargetRunDb {
+    id: Uuid,
+    target_id: Uuid,
+    started: PrimitiveDateTime,
+    duration_ms: i64,
+    state: String,
+    files_written: i64,
+    files_skipped: i64,
+    message: Option<String>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&ExportTargetRunDb> for ExportTargetRunEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &ExportTargetRunDb) -> Result<Self, Self::Error> {
+        Ok(ExportTargetRunEntity {
+            id: db.id,
+            target_id: db.target_id,
+            started: db.started,
+            duration_ms: db.duration_ms as u64,
+            state: ExportRunState::try_from(db.state.as_str())?,
+            files_written: db.files_written as u32,
+            files_skipped: db.files_skipped as u32,
+            message: db.message.as_deref().map(Arc::from),
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct ExportTargetDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl ExportTargetDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExportTargetDao for ExportTargetDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[ExportTargetEntity]>, DaoError> {
+        query_as!(
+            ExportTargetDb,
+            r#"SELECT id, name, kind, enabled, url, username, secret, path, cron_schedule,
+                      artifact_type, filename_pattern, weeks_horizon, created, deleted,
+                      update_version
+               FROM export_target
+               WHERE deleted IS NULL
+               ORDER BY name, created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ExportTargetEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ExportTargetEntity>, DaoError> {
+        query_as!(
+            ExportTargetDb,
+            r#"SELECT id, name, kind, enabled, url, username, secret, path, cron_schedule,
+                      artifact_type, filename_pattern, weeks_horizon, created, deleted,
+                      update_version
+               FROM export_target
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(ExportTargetEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &ExportTargetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let kind = entity.kind.to_string();
+        let enabled = entity.enabled as i64;
+        let url = entity.url.as_deref();
+        let username = entity.username.as_deref();
+        let secret = entity.secret.as_deref();
+        let path = entity.path.as_ref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let artifact_type = entity.artifact_type.to_string();
+        let filename_pattern = entity.filename_pattern.as_ref();
+        let weeks_horizon = entity.weeks_horizon as i64;
+
+        query!(
+            r#"INSERT INTO export_target
+                 (id, name, kind, enabled, url, username, secret, path, cron_schedule,
+                  artifact_type, filename_pattern, weeks_horizon, created, deleted,
+                  update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)"#,
+            entity.id,
+            name,
+            kind,
+            enabled,
+            url,
+            username,
+            secret,
+            path,
+            cron_schedule,
+            artifact_type,
+            filename_pattern,
+            weeks_horizon,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &ExportTargetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let kind = entity.kind.to_string();
+        let enabled = entity.enabled as i64;
+        let url = entity.url.as_deref();
+        let username = entity.username.as_deref();
+        let secret = entity.secret.as_deref();
+        let path = entity.path.as_ref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let artifact_type = entity.artifact_type.to_string();
+        let filename_pattern = entity.filename_pattern.as_ref();
+        let weeks_horizon = entity.weeks_horizon as i64;
+
+        query!(
+            r#"UPDATE export_target
+               SET name = $1, kind = $2, enabled = $3, url = $4, username = $5, secret = $6, path = $7,
+                   cron_schedule = $8, artifact_type = $9, filename_pattern = $10, weeks_horizon = $11,
+                   deleted = $12, update_process = $13, update_version = $14
+               WHERE id = $15"#,
+            name,
+            kind,
+            enabled,
+            url,
+            username,
+            secret,
+            path,
+            cron_schedule,
+            artifact_type,
+            filename_pattern,
+            weeks_horizon,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn create_run(
+        &self,
+        entity: &ExportTargetRunEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let duration_ms = entity.duration_ms as i64;
+        let state = entity.state.to_string();
+        let files_written = entity.files_written as i64;
+        let files_skipped = entity.files_skipped as i64;
+        let message = entity.message.as_deref();
+
+        query!(
+            r#"INSERT INTO export_target_run
+                 (id, target_id, started, duration_ms, state, files_written, files_skipped,
+                  message, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+            entity.id,
+            entity.target_id,
+            entity.started,
+            duration_ms,
+            state,
+            files_written,
+            files_skipped,
+            message,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_runs_by_target(
+        &self,
+        target_id: Uuid,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ExportTargetRunEntity]>, DaoError> {
+        let limit = limit as i64;
+        query_as!(
+            ExportTargetRunDb,
+            r#"SELECT id, target_id, started, duration_ms, state, files_written, files_skipped,
+                      message, update_version
+               FROM export_target_run
+               WHERE target_id = $1
+               ORDER BY started DESC
+               LIMIT $2"#,
+            target_id,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ExportTargetRunEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn export_target_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ExportTargetDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = ExportTargetEntity {
+            id: Uuid::new_v4(),
+            name: "Nextcloud".into(),
+            kind: ExportTargetKind::WebDav,
+            enabled: true,
+            url: Some("https://cloud.example.com/remote.php/dav".into()),
+            username: Some("shifty".into()),
+            secret: Some("app-token".into()),
+            path: "/Schichtplan".into(),
+            cron_schedule: "0 0 6 * * *".into(),
+            artifact_type: ExportArtifactType::ShiftplanWeekPdf,
+            filename_pattern: "KW{week}.pdf".into(),
+            weeks_horizon: 4,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.kind = ExportTargetKind::DropDirectory;
+        entity.url = None;
+        entity.username = None;
+        entity.secret = None;
+        entity.artifact_type = ExportArtifactType::BillingPeriodCsv;
+        entity.enabled = false;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.all(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao.all(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn runs_are_returned_newest_first() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ExportTargetDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let target = ExportTargetEntity {
+            id: Uuid::new_v4(),
+            name: "Ablage".into(),
+            kind: ExportTargetKind::LocalDirectory,
+            enabled: true,
+            url: None,
+            username: None,
+            secret: None,
+            path: "/srv/export".into(),
+            cron_schedule: "0 0 6 * * *".into(),
+            artifact_type: ExportArtifactType::IcalSnapshot,
+            filename_pattern: "team.ics".into(),
+            weeks_horizon: 1,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+        dao.create(&target, PROCESS, tx.clone()).await.unwrap();
+        let older = ExportTargetRunEntity {
+            id: Uuid::new_v4(),
+            target_id: target.id,
+            started: datetime!(2026-10-01 06:00),
+            duration_ms: 1200,
+            state: ExportRunState::Success,
+            files_written: 1,
+            files_skipped: 0,
+            message: None,
+            version: Uuid::new_v4(),
+        };
+        let newer = ExportTargetRunEntity {
+            id: Uuid::new_v4(),
+            started: datetime!(2026-10-02 06:00),
+            state: ExportRunState::Failed,
+            files_written: 0,
+            message: Some("Verbindung abgelehnt".into()),
+            version: Uuid::new_v4(),
+            ..older.clone()
+        };
+
+        dao.create_run(&older, PROCESS, tx.clone()).await.unwrap();
+        dao.create_run(&newer, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_runs_by_target(target.id, 10, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[newer.clone(), older]
+        );
+        assert_eq!(
+            dao.find_runs_by_target(target.id, 1, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[newer]
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
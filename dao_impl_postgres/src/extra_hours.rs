@@ -0,0 +1,413 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    extra_hours::{ExtraHoursCategoryEntity, ExtraHoursDao, ExtraHoursEntity},
+    DaoError,
+};
+use sqlx::{query, query_as, QueryBuilder, Postgres};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+struct ExtraHoursDb {
+    id: Uuid,
+    logical_id: Uuid,
+    sales_person_id: Uuid,
+    amount: f64,
+
+    category: String,
+    description: Option<String>,
+    date_time: PrimitiveDateTime,
+    custom_extra_hours_id: Uuid,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+    /// Phase 54 (D-54-DM-02): 'manual' | 'rebooking'. Filter-Marker fuer
+    /// F1/F2-Aggregatoren; Bestandsrows landen per DEFAULT auf 'manual'.
+    source: String,
+}
+impl TryFrom<&ExtraHoursDb> for ExtraHoursEntity {
+    type Error = DaoError;
+
+    fn try_from(extra_hours: &ExtraHoursDb) -> Result<Self, DaoError> {
+        Ok(Self {
+            id: extra_hours.id,
+            logical_id: extra_hours.logical_id,
+            sales_person_id: extra_hours.sales_person_id,
+            amount: extra_hours.amount as f32,
+            category: match extra_hours.category.as_str() {
+                "ExtraWork" => ExtraHoursCategoryEntity::ExtraWork,
+                "Vacation" => ExtraHoursCategoryEntity::Vacation,
+                "SickLeave" => ExtraHoursCategoryEntity::SickLeave,
+                "Holiday" => ExtraHoursCategoryEntity::Holiday,
+                "Unavailable" => ExtraHoursCategoryEntity::Unavailable,
+                "UnpaidLeave" => ExtraHoursCategoryEntity::UnpaidLeave,
+                "VolunteerWork" => ExtraHoursCategoryEntity::VolunteerWork,
+                "Custom" => ExtraHoursCategoryEntity::Custom(extra_hours.custom_extra_hours_id),
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            description: extra_hours
+                .description
+                .clone()
+                .unwrap_or_default()
+                .as_str()
+                .into(),
+            date_time: extra_hours.date_time,
+            created: extra_hours.created,
+            deleted: extra_hours.deleted,
+            version: extra_hours.update_version,
+            source: extra_hours.source.clone(),
+        })
+    }
+}
+
+pub struct ExtraHoursDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl ExtraHoursDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl ExtraHoursDao for ExtraHoursDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ExtraHoursEntity>, crate::DaoError> {
+        Ok(query_as!(
+            ExtraHoursDb,
+            "SELECT id, logical_id, sales_person_id, amount, category, description, custom_extra_hours_id, date_time, created, deleted, update_version, source FROM extra_hours WHERE id = $1 AND deleted IS NULL",
+            id,
+        ).fetch_optional(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .as_ref()
+            .map(ExtraHoursEntity::try_from)
+
+            .transpose()?)
+    }
+
+    async fn find_by_logical_id(
+        &self,
+        logical_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ExtraHoursEntity>, crate::DaoError> {
+        Ok(query_as!(
+            ExtraHoursDb,
+            "SELECT id, logical_id, sales_person_id, amount, category, description, custom_extra_hours_id, date_time, created, deleted, update_version, source FROM extra_hours WHERE logical_id = $1 AND deleted IS NULL",
+            logical_id,
+        ).fetch_optional(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .as_ref()
+            .map(ExtraHoursEntity::try_from)
+            .transpose()?)
+    }
+
+    async fn find_by_sales_person_id_and_years(
+        &self,
+        sales_person_id: Uuid,
+        from_year: u32,
+        to_year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ExtraHoursEntity]>, crate::DaoError> {
+        Ok(query_as!(
+            ExtraHoursDb,
+            "SELECT id, logical_id, sales_person_id, amount, category, description, custom_extra_hours_id, date_time, created, deleted, update_version, source FROM extra_hours WHERE sales_person_id = $1 AND EXTRACT(YEAR FROM date_time)::BIGINT >= $2 AND EXTRACT(YEAR FROM date_time)::BIGINT <= $3 AND deleted IS NULL",
+            sales_person_id,
+            from_year as i64,
+            to_year as i64,
+        ).fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .iter()
+            .map(ExtraHoursEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_by_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ExtraHoursEntity]>, crate::DaoError> {
+        let monday = time::PrimitiveDateTime::new(
+            time::Date::from_iso_week_date(year as i32, calendar_week, time::Weekday::Monday)?,
+            time::Time::MIDNIGHT,
+        );
+        let next_monday = monday + time::Duration::weeks(1);
+        Ok(query_as!(
+            ExtraHoursDb,
+            "SELECT id, logical_id, sales_person_id, amount, category, description, custom_extra_hours_id, date_time, created, deleted, update_version, source FROM extra_hours WHERE date_time >= $1 and date_time < $2 AND deleted IS NULL",
+            monday,
+            next_monday,
+        ).fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .iter()
+            .map(ExtraHoursEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_by_iso_year(
+        &self,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ExtraHoursEntity]>, crate::DaoError> {
+        // Phase 52 Follow-up #3: ISO-Wochenjahr, nicht Kalender-Jahr.
+        // Range = `[ISO-Mo(year, 1), ISO-Su(year, weeks_in_year(year)) + 1d)`.
+        // Max ±3 Tage weiter als das Kalender-Jahr, aber deckt genau die ISO-
+        // Wochen ab, die zu `year` gehören — der Konsument in
+        // `assemble_weeks` bucketet Rows per `ShiftyDate` in ISO-Wochen, und
+        // ohne diese Range verschwinden Rows an KW 1 (die im Vorjahr startet)
+        // oder KW 53 (die ins Folgejahr reicht) aus dem Bulk-Load.
+        let weeks_in_year = time::util::weeks_in_year(year as i32);
+        let iso_start = time::PrimitiveDateTime::new(
+            time::Date::from_iso_week_date(year as i32, 1, time::Weekday::Monday)?,
+            time::Time::MIDNIGHT,
+        );
+        let iso_last_sunday =
+            time::Date::from_iso_week_date(year as i32, weeks_in_year, time::Weekday::Sunday)?;
+        let iso_end_exclusive = time::PrimitiveDateTime::new(
+            iso_last_sunday.next_day().ok_or_else(|| {
+                DaoError::EnumValueNotFound(
+                    format!("iso_year end overflow for year {}", year).into(),
+                )
+            })?,
+            time::Time::MIDNIGHT,
+        );
+        Ok(query_as!(
+            ExtraHoursDb,
+            "SELECT id, logical_id, sales_person_id, amount, category, description, custom_extra_hours_id, date_time, created, deleted, update_version, source FROM extra_hours WHERE date_time >= $1 and date_time < $2 AND deleted IS NULL",
+            iso_start,
+            iso_end_exclusive,
+        ).fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .iter()
+            .map(ExtraHoursEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &ExtraHoursEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), crate::DaoError> {
+        let category = match entity.category {
+            ExtraHoursCategoryEntity::ExtraWork => "ExtraWork",
+            ExtraHoursCategoryEntity::Vacation => "Vacation",
+            ExtraHoursCategoryEntity::SickLeave => "SickLeave",
+            ExtraHoursCategoryEntity::Holiday => "Holiday",
+            ExtraHoursCategoryEntity::Unavailable => "Unavailable",
+            ExtraHoursCategoryEntity::UnpaidLeave => "UnpaidLeave",
+            ExtraHoursCategoryEntity::VolunteerWork => "VolunteerWork",
+            ExtraHoursCategoryEntity::Custom(_) => "Custom",
+        };
+        let custom_extra_hours_id = match entity.category {
+            ExtraHoursCategoryEntity::Custom(id) => id,
+            _ => Uuid::nil(),
+        };
+        let description = entity.description.as_ref();
+        let source = entity.source.as_str();
+        query!(
+            "INSERT INTO extra_hours (id, logical_id, sales_person_id, amount, category, description, custom_extra_hours_id, date_time, created, deleted, update_process, update_version, source) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
+            entity.id,
+            entity.logical_id,
+            entity.sales_person_id,
+            entity.amount as f64,
+            category,
+            description,
+            custom_extra_hours_id,
+            entity.date_time,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+            source,
+        ).execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+    async fn update(
+        &self,
+        entity: &ExtraHoursEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), crate::DaoError> {
+        query!(
+            "UPDATE extra_hours SET deleted = $1, update_version = $2, update_process = $3 WHERE id = $4",
+            entity.deleted,
+            entity.version,
+            process,
+            entity.id,
+        )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+    /// Phase 4 / C-Phase4-04 — bulk soft-delete by id list.
+    ///
+    /// Single UPDATE with `WHERE deleted IS NULL AND id IN (...)`. The
+    /// `deleted IS NULL` clause makes re-runs idempotent: rows already
+    /// soft-deleted (e.g., by a prior cutover attempt that committed before
+    /// rolling back due to a later step failing) are silently skipped.
+    async fn soft_delete_bulk(
+        &self,
+        ids: &[Uuid],
+        deleted_at: PrimitiveDateTime,
+        update_process: &str,
+        new_version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), crate::DaoError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        // Schema reference: migrations/postgres/20261016000200_initial-schema.sql
+        // Columns: deleted TIMESTAMP, update_process TEXT, update_timestamp TIMESTAMP, update_version UUID.
+        let mut qb: QueryBuilder<Postgres> =
+            QueryBuilder::new("UPDATE extra_hours SET deleted = ");
+        qb.push_bind(deleted_at);
+        qb.push(", update_process = ");
+        qb.push_bind(update_process.to_string());
+        qb.push(", update_timestamp = ");
+        qb.push_bind(deleted_at);
+        qb.push(", update_version = ");
+        qb.push_bind(new_version);
+        qb.push(" WHERE deleted IS NULL AND id IN (");
+
+        let mut sep = qb.separated(", ");
+        for id in ids {
+            sep.push_bind(*id);
+        }
+        qb.push(")");
+
+        qb.build()
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn extra_hours_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ExtraHoursDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = ExtraHoursEntity {
+            id: Uuid::new_v4(),
+            logical_id: Uuid::new_v4(),
+            sales_person_id,
+            amount: 2.5,
+            category: ExtraHoursCategoryEntity::ExtraWork,
+            description: "Inventur".into(),
+            date_time: datetime!(2026-04-21 18:00),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+            source: "manual".into(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_week(17, 2026, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+        assert_eq!(
+            dao.find_by_sales_person_id_and_years(sales_person_id, 2026, 2026, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao
+            .find_by_iso_year(2026, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn soft_delete_bulk_skips_deleted_rows() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ExtraHoursDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let entity = ExtraHoursEntity {
+            id: Uuid::new_v4(),
+            logical_id: Uuid::new_v4(),
+            sales_person_id,
+            amount: 8.0,
+            category: ExtraHoursCategoryEntity::Vacation,
+            description: "".into(),
+            date_time: datetime!(2026-04-22 00:00),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+            source: "manual".into(),
+        };
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+
+        dao.soft_delete_bulk(
+            &[entity.id],
+            timestamp(),
+            PROCESS,
+            Uuid::new_v4(),
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        // Zweiter Lauf ist ein No-op.
+        dao.soft_delete_bulk(
+            &[entity.id],
+            timestamp(),
+            PROCESS,
+            Uuid::new_v4(),
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
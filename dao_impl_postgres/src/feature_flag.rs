@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use crate::{now_utc, ResultDbErrorExt};
+use async_trait::async_trait;
+use dao::{
+    feature_flag::{FeatureFlagDao, FeatureFlagEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+
+#[derive(Debug)]
+struct FeatureFlagDb {
+    key: String,
+    enabled: i64,
+    description: Option<String>,
+}
+
+impl From<&FeatureFlagDb> for FeatureFlagEntity {
+    fn from(db: &FeatureFlagDb) -> Self {
+        FeatureFlagEntity {
+            key: db.key.clone(),
+            enabled: db.enabled != 0,
+            description: db.description.clone(),
+        }
+    }
+}
+
+pub struct FeatureFlagDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl FeatureFlagDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagDao for FeatureFlagDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn is_enabled(&self, key: &str, tx: Self::Transaction) -> Result<bool, DaoError> {
+        let result = query!(
+            r#"SELECT enabled FROM feature_flag WHERE key = $1"#,
+            key,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        // Returns false for non-existent feature flags (fail-safe default,
+        // analog ToggleDaoImpl::is_enabled)
+        Ok(result.map(|row| row.enabled != 0).unwrap_or(false))
+    }
+
+    async fn get(
+        &self,
+        key: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<FeatureFlagEntity>, DaoError> {
+        Ok(query_as!(
+            FeatureFlagDb,
+            r#"SELECT key, enabled, description
+               FROM feature_flag
+               WHERE key = $1"#,
+            key,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(FeatureFlagEntity::from))
+    }
+
+    async fn set(
+        &self,
+        key: &str,
+        enabled: bool,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let enabled_int: i64 = if enabled { 1 } else { 0 };
+        let timestamp = now_utc();
+        // UPDATE-only: migration must seed all known keys.
+        query!(
+            r#"UPDATE feature_flag
+               SET enabled = $1, update_timestamp = $2, update_process = $3
+               WHERE key = $4"#,
+            enabled_int,
+            timestamp,
+            process,
+            key,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn feature_flag_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = FeatureFlagDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        // Flags werden nur per Migration angelegt.
+        sqlx::query(
+            "INSERT INTO feature_flag (key, enabled, description, update_process) VALUES ('beta', 0, 'Beta', 'test')",
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .unwrap();
+
+        assert!(!dao.is_enabled("beta", tx.clone()).await.unwrap());
+        dao.set("beta", true, PROCESS, tx.clone()).await.unwrap();
+        assert!(dao.is_enabled("beta", tx.clone()).await.unwrap());
+        assert_eq!(
+            dao.get("beta", tx.clone()).await.unwrap(),
+            Some(FeatureFlagEntity {
+                key: "beta".into(),
+                enabled: true,
+                description: Some("Beta".into()),
+            })
+        );
+
+        dao.set("unknown", true, PROCESS, tx.clone()).await.unwrap();
+        assert!(!dao.is_enabled("unknown", tx.clone()).await.unwrap());
+        assert_eq!(dao.get("unknown", tx.clone()).await.unwrap(), None);
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    ical_feed_token::{IcalFeedScopeEntity, IcalFeedTokenDao, IcalFeedTokenEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct IcalFeedTokenDb {
+    id: Uuid,
+    scope: String,
+    sales_person_id: Option<Uuid>,
+    shiftplan_id: Option<Uuid>,
+    token: Uuid,
+    label: String,
+    created: PrimitiveDateTime,
+    revoked: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&IcalFeedTokenDb> for IcalFeedTokenEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &IcalFeedTokenDb) -> Result<Self, Self::Error> {
+        Ok(IcalFeedTokenEntity {
+            id: db.id,
+            scope: match (db.scope.as_str(), db.sales_person_id, db.shiftplan_id) {
+                ("sales-person", Some(sales_person_id), _) => {
+                    IcalFeedScopeEntity::SalesPerson(sales_person_id)
+                }
+                ("shiftplan", _, Some(shiftplan_id)) => {
+                    IcalFeedScopeEntity::Shiftplan(shiftplan_id)
+                }
+                ("team-absences", _, _) => IcalFeedScopeEntity::TeamAbsences,
+                (value, _, _) => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            token: db.token,
+            label: db.label.as_str().into(),
+            created: db.created,
+            revoked: db.revoked,
+            version: db.update_version,
+        })
+    }
+}
+
+/// Spaltenwerte `(scope, sales_person_id, shiftplan_id)` eines Scopes.
+fn scope_columns(scope: &IcalFeedScopeEntity) -> (&'static str, Option<Uuid>, Option<Uuid>) {
+    match scope {
+        IcalFeedScopeEntity::SalesPerson(id) => ("sales-person", Some(*id), None),
+        IcalFeedScopeEntity::Shiftplan(id) => ("shiftplan", None, Some(*id)),
+        IcalFeedScopeEntity::TeamAbsences => ("team-absences", None, None),
+    }
+}
+
+pub struct IcalFeedTokenDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl IcalFeedTokenDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IcalFeedTokenDao for IcalFeedTokenDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_scope(
+        &self,
+        scope: IcalFeedScopeEntity,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[IcalFeedTokenEntity]>, DaoError> {
+        let (scope, sales_person_id, shiftplan_id) = scope_columns(&scope);
+        query_as!(
+            IcalFeedTokenDb,
+            r#"SELECT id, scope, sales_person_id, shiftplan_id, token, label, created, revoked, update_version
+               FROM ical_feed_token
+               WHERE scope = $1
+                 AND sales_person_id IS NOT DISTINCT FROM $2
+                 AND shiftplan_id IS NOT DISTINCT FROM $3
+               ORDER BY created"#,
+            scope,
+            sales_person_id,
+            shiftplan_id,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(IcalFeedTokenEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<IcalFeedTokenEntity>, DaoError> {
+        query_as!(
+            IcalFeedTokenDb,
+            r#"SELECT id, scope, sales_person_id, shiftplan_id, token, label, created, revoked, update_version
+               FROM ical_feed_token
+               WHERE id = $1"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(IcalFeedTokenEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_token(
+        &self,
+        token: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<IcalFeedTokenEntity>, DaoError> {
+        query_as!(
+            IcalFeedTokenDb,
+            r#"SELECT id, scope, sales_person_id, shiftplan_id, token, label, created, revoked, update_version
+               FROM ical_feed_token
+               WHERE token = $1"#,
+            token,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(IcalFeedTokenEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &IcalFeedTokenEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let (scope, sales_person_id, shiftplan_id) = scope_columns(&entity.scope);
+        let label = entity.label.as_ref();
+
+        query!(
+            r#"INSERT INTO ical_feed_token
+                 (id, scope, sales_person_id, shiftplan_id, token, label, created, revoked,
+                  update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+            entity.id,
+            scope,
+            sales_person_id,
+            shiftplan_id,
+            entity.token,
+            label,
+            entity.created,
+            entity.revoked,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &IcalFeedTokenEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let label = entity.label.as_ref();
+
+        query!(
+            r#"UPDATE ical_feed_token
+               SET label = $1, revoked = $2, update_process = $3, update_version = $4
+               WHERE id = $5"#,
+            label,
+            entity.revoked,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn ical_feed_token_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = IcalFeedTokenDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let scope = IcalFeedScopeEntity::SalesPerson(sales_person_id);
+        let mut entity = IcalFeedTokenEntity {
+            id: Uuid::new_v4(),
+            scope,
+            token: Uuid::new_v4(),
+            label: "Handy".into(),
+            created: timestamp(),
+            revoked: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_token(entity.token, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.label = "Tablet".into();
+        entity.revoked = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_scope(scope, tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+        assert!(dao
+            .find_by_scope(IcalFeedScopeEntity::TeamAbsences, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
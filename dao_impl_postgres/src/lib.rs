@@ -0,0 +1,650 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{BasicDao, DaoError, PrivilegeEntity, RoleEntity, Transaction};
+use sqlx::{query, query_as, PgPool};
+use time::{OffsetDateTime, PrimitiveDateTime};
+use tokio::sync::Mutex;
+
+pub mod absence;
+pub mod audit_log;
+pub mod billing_period;
+pub mod billing_period_sales_person;
+pub mod booking;
+pub mod booking_log;
+pub mod carryover;
+pub mod custom_extra_hours;
+pub mod employee_work_details;
+pub mod export_target;
+pub mod extra_hours;
+pub mod feature_flag;
+pub mod ical_feed_token;
+pub mod migration_source;
+pub mod notification;
+pub mod payroll_wage_type;
+pub mod pdf_export_config;
+pub mod qualification;
+pub mod rebooking_batch;
+pub mod sales_person;
+pub mod sales_person_availability;
+pub mod sales_person_shiftplan;
+pub mod sales_person_unavailable;
+pub mod scheduler_job;
+pub mod session;
+pub mod shift_swap;
+pub mod shiftplan;
+pub mod shiftplan_report;
+pub mod slot;
+pub mod special_day;
+pub mod text_template;
+pub mod time_entry;
+pub mod toggle;
+pub mod user_invitation;
+pub mod vacation_entitlement_offset;
+pub mod webhook;
+pub mod week_message;
+pub mod week_status;
+
+pub trait ResultDbErrorExt<T, E> {
+    fn map_db_error(self) -> Result<T, DaoError>;
+}
+impl<T, E: std::error::Error + Send + Sync + 'static> ResultDbErrorExt<T, E> for Result<T, E> {
+    fn map_db_error(self) -> Result<T, DaoError> {
+        self.map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))
+    }
+}
+
+/// Aktueller Zeitpunkt in UTC für die `TIMESTAMP`-Spalten.
+pub(crate) fn now_utc() -> PrimitiveDateTime {
+    let now = OffsetDateTime::now_utc();
+    PrimitiveDateTime::new(now.date(), now.time())
+}
+
+pub struct HelloDaoImpl {
+    pool: Arc<PgPool>,
+}
+
+impl HelloDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl dao::HelloDao for HelloDaoImpl {
+    async fn get_hello(&self) -> Result<Arc<str>, dao::DaoError> {
+        let result = query!(r#"SELECT 'Hello, world!' as "message!""#)
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))?;
+        let message: Arc<str> = result[0].message.clone().into();
+        Ok(message)
+    }
+}
+
+pub struct PermissionDaoImpl {
+    pool: Arc<PgPool>,
+}
+impl PermissionDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+#[async_trait]
+impl dao::PermissionDao for PermissionDaoImpl {
+    async fn has_privilege(&self, user: &str, privilege: &str) -> Result<bool, dao::DaoError> {
+        let result = query!(
+            r#"SELECT count(*) as "results!" FROM "user" 
+                                 INNER JOIN user_role ON "user".name = user_role.user_name 
+                                 INNER JOIN role ON user_role.role_name = role.name 
+                                 INNER JOIN role_privilege ON role.name = role_privilege.role_name 
+                                 WHERE role_privilege.privilege_name = $1 AND "user".name = $2"#,
+            privilege,
+            user,
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(result[0].results > 0)
+    }
+
+    async fn create_user(&self, user: &dao::UserEntity, process: &str) -> Result<(), DaoError> {
+        let name = user.name.as_ref();
+        query!(
+            r#"INSERT INTO "user" (name, update_process) VALUES ($1, $2)"#,
+            name,
+            process
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+    async fn all_users(&self) -> Result<Arc<[dao::UserEntity]>, DaoError> {
+        Ok(query_as!(dao::UserEntity, r#"SELECT name FROM "user""#)
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map(Arc::<[dao::UserEntity]>::from)
+            .map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))?)
+    }
+    async fn delete_user(&self, username: &str) -> Result<(), DaoError> {
+        query!(r#"DELETE FROM "user" WHERE name = $1"#, username)
+            .execute(self.pool.as_ref())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+    async fn find_user(&self, username: &str) -> Result<Option<dao::UserEntity>, DaoError> {
+        let result = query!(r#"SELECT name FROM "user" WHERE name = $1"#, username)
+            .fetch_optional(self.pool.as_ref())
+            .await
+            .map_db_error()?;
+        Ok(result.map(|row| dao::UserEntity {
+            name: row.name.clone().into(),
+        }))
+    }
+
+    async fn create_role(&self, role: &dao::RoleEntity, process: &str) -> Result<(), DaoError> {
+        let name = role.name.as_ref();
+        query!(
+            "INSERT INTO role (name, update_process) VALUES ($1, $2)",
+            name,
+            process
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+    async fn all_roles(&self) -> Result<Arc<[dao::RoleEntity]>, DaoError> {
+        Ok(query_as!(dao::RoleEntity, r"SELECT name FROM role")
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map(Arc::<[dao::RoleEntity]>::from)
+            .map_db_error()?)
+    }
+    async fn delete_role(&self, rolename: &str) -> Result<(), DaoError> {
+        query!(r"DELETE FROM role WHERE name = $1", rolename)
+            .execute(self.pool.as_ref())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+
+    async fn create_privilege(
+        &self,
+        privilege: &dao::PrivilegeEntity,
+        process: &str,
+    ) -> Result<(), DaoError> {
+        let name = privilege.name.as_ref();
+        query!(
+            r"INSERT INTO privilege (name, update_process) VALUES ($1, $2)",
+            name,
+            process,
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+    async fn all_privileges(&self) -> Result<Arc<[dao::PrivilegeEntity]>, DaoError> {
+        Ok(
+            query_as!(dao::PrivilegeEntity, r"SELECT name FROM privilege")
+                .fetch_all(self.pool.as_ref())
+                .await
+                .map(Arc::<[dao::PrivilegeEntity]>::from)
+                .map_db_error()?,
+        )
+    }
+    async fn delete_privilege(&self, privilege: &str) -> Result<(), DaoError> {
+        query!(r"DELETE FROM privilege WHERE name = $1", privilege)
+            .execute(self.pool.as_ref())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+
+    async fn add_user_role(&self, user: &str, role: &str, process: &str) -> Result<(), DaoError> {
+        query!(
+            r"INSERT INTO user_role (user_name, role_name, update_process) VALUES ($1, $2, $3)",
+            user,
+            role,
+            process,
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+    async fn add_role_privilege(
+        &self,
+        role: &str,
+        privilege: &str,
+        process: &str,
+    ) -> Result<(), DaoError> {
+        query!(
+            r"INSERT INTO role_privilege (role_name, privilege_name, update_process) VALUES ($1, $2, $3)",
+            role,
+            privilege,
+            process,
+        ).execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+    async fn delete_role_privilege(&self, role: &str, privilege: &str) -> Result<(), DaoError> {
+        query!(
+            r"DELETE FROM role_privilege WHERE role_name = $1 AND privilege_name = $2",
+            role,
+            privilege
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+    async fn delete_user_role(&self, user: &str, role: &str) -> Result<(), DaoError> {
+        query!(
+            r"DELETE FROM user_role WHERE user_name = $1 AND role_name = $2",
+            user,
+            role,
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn privileges_for_user(&self, user: &str) -> Result<Arc<[PrivilegeEntity]>, DaoError> {
+        Ok(
+            query_as!(PrivilegeEntity, r#"SELECT privilege.name FROM "user" 
+                                 INNER JOIN user_role ON "user".name = user_role.user_name 
+                                 INNER JOIN role ON user_role.role_name = role.name 
+                                 INNER JOIN role_privilege ON role.name = role_privilege.role_name 
+                                 INNER JOIN privilege ON role_privilege.privilege_name = privilege.name 
+                                 WHERE "user".name = $1"#,
+                                 user
+            )
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map(Arc::<[PrivilegeEntity]>::from)
+            .map_db_error()?,
+        )
+    }
+
+    async fn roles_for_user(&self, user: &str) -> Result<Arc<[RoleEntity]>, DaoError> {
+        Ok(query_as!(
+            RoleEntity,
+            r"SELECT user_role.role_name as name FROM user_role 
+                                 WHERE user_role.user_name = $1",
+            user
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map(Arc::<[RoleEntity]>::from)
+        .map_db_error()?)
+    }
+}
+
+pub struct BasicDaoImpl {
+    pool: Arc<PgPool>,
+}
+impl BasicDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BasicDao for BasicDaoImpl {
+    async fn clear_all(&self) -> Result<(), DaoError> {
+        let mut tx = self.pool.begin().await.map_db_error()?;
+        query!("DELETE FROM booking").execute(tx.as_mut()).await.map_db_error()?;
+        query!("DELETE FROM sales_person_user").execute(tx.as_mut()).await.map_db_error()?;
+        query!("DELETE FROM sales_person_unavailable").execute(tx.as_mut()).await.map_db_error()?;
+        query!("DELETE FROM extra_hours").execute(tx.as_mut()).await.map_db_error()?;
+        query!("DELETE FROM employee_work_details").execute(tx.as_mut()).await.map_db_error()?;
+        query!("DELETE FROM sales_person").execute(tx.as_mut()).await.map_db_error()?;
+        query!("DELETE FROM special_day").execute(tx.as_mut()).await.map_db_error()?;
+        tx.commit().await.map_db_error()?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionImpl {
+    tx: Arc<Mutex<sqlx::Transaction<'static, sqlx::Postgres>>>,
+}
+
+impl Transaction for TransactionImpl {}
+
+pub struct TransactionDaoImpl {
+    pool: Arc<PgPool>,
+}
+impl TransactionDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+#[async_trait]
+impl dao::TransactionDao for TransactionDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn new_transaction(&self) -> Result<Self::Transaction, DaoError> {
+        let tx = self.pool.begin().await.map_db_error()?;
+        Ok(TransactionImpl {
+            tx: Arc::new(tx.into()),
+        })
+    }
+
+    async fn use_transaction(
+        &self,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Self::Transaction, DaoError> {
+        match tx {
+            Some(tx) => Ok(tx),
+            None => self.new_transaction().await,
+        }
+    }
+
+    async fn commit(&self, transaction: Self::Transaction) -> Result<(), DaoError> {
+        if let Some(tx) = Arc::into_inner(transaction.tx) {
+            tx.into_inner().commit().await.map_db_error()?;
+        }
+        Ok(())
+    }
+
+    async fn rollback(&self, transaction: Self::Transaction) -> Result<(), DaoError> {
+        if let Some(tx) = Arc::into_inner(transaction.tx) {
+            tx.into_inner().rollback().await.map_db_error()?;
+        }
+        Ok(())
+    }
+}
+
+/// Testdatenbanken für die DAO-Tests. Ohne `SHIFTY_TEST_POSTGRES_URL` (z. B.
+/// `postgres://postgres@127.0.0.1:5432/postgres`) werden die Tests
+/// übersprungen, damit `cargo test --workspace` auch ohne Server durchläuft;
+/// die CI setzt die Variable und bringt einen Postgres-Service mit.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::ops::Deref;
+    use std::sync::Arc;
+
+    use dao::booking::{BookingDao, BookingEntity};
+    use dao::sales_person::{SalesPersonDao, SalesPersonEntity};
+    use dao::shiftplan::{ShiftplanDao, ShiftplanEntity};
+    use dao::slot::{SlotDao, SlotEntity};
+    use shifty_utils::DayOfWeek;
+    use sqlx::postgres::PgConnectOptions;
+    use sqlx::PgPool;
+    use time::macros::{date, datetime, time};
+    use time::PrimitiveDateTime;
+    use uuid::Uuid;
+
+    use crate::booking::BookingDaoImpl;
+    use crate::sales_person::SalesPersonDaoImpl;
+    use crate::shiftplan::ShiftplanDaoImpl;
+    use crate::slot::SlotDaoImpl;
+    use crate::TransactionImpl;
+
+    pub const TEST_POSTGRES_URL_VAR: &str = "SHIFTY_TEST_POSTGRES_URL";
+    pub const PROCESS: &str = "test";
+
+    /// Fester Zeitstempel für `created`/`deleted` in den Roundtrip-Tests.
+    pub fn timestamp() -> PrimitiveDateTime {
+        datetime!(2026-10-01 08:00)
+    }
+
+    /// Frische Testdatenbank; wird beim Drop wieder entfernt, auch wenn der
+    /// Test panict. Dereferenziert auf den Pool.
+    pub struct TestDatabase {
+        pool: Arc<PgPool>,
+        admin_url: String,
+        database: String,
+    }
+
+    impl Deref for TestDatabase {
+        type Target = Arc<PgPool>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.pool
+        }
+    }
+
+    impl Drop for TestDatabase {
+        fn drop(&mut self) {
+            // `Drop` ist synchron und läuft meist innerhalb der Test-Runtime,
+            // deshalb räumt ein eigener Thread mit eigener Runtime auf.
+            let admin_url = self.admin_url.clone();
+            let database = self.database.clone();
+            let cleanup = std::thread::spawn(move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("Could not build cleanup runtime");
+                runtime.block_on(async {
+                    let admin_pool = PgPool::connect(&admin_url).await?;
+                    sqlx::query(&format!("DROP DATABASE IF EXISTS {database} WITH (FORCE)"))
+                        .execute(&admin_pool)
+                        .await?;
+                    admin_pool.close().await;
+                    Ok::<_, sqlx::Error>(())
+                })
+            });
+            match cleanup.join() {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => eprintln!("Could not drop test database {}: {e}", self.database),
+                Err(_) => eprintln!("Could not drop test database {}", self.database),
+            }
+        }
+    }
+
+    /// Legt pro Aufruf eine frische Datenbank an und spielt alle Migrationen
+    /// aus `migrations/postgres` ein.
+    pub async fn setup_pool() -> Option<TestDatabase> {
+        let Ok(url) = std::env::var(TEST_POSTGRES_URL_VAR) else {
+            eprintln!("{TEST_POSTGRES_URL_VAR} not set, skipping Postgres test");
+            return None;
+        };
+        let admin_pool = PgPool::connect(&url)
+            .await
+            .expect("Could not connect to test Postgres");
+        let database = format!("shifty_test_{}", Uuid::new_v4().simple());
+        sqlx::query(&format!("CREATE DATABASE {database}"))
+            .execute(&admin_pool)
+            .await
+            .expect("Could not create test database");
+        admin_pool.close().await;
+
+        let options: PgConnectOptions = url.parse().expect("Invalid test Postgres URL");
+        let test_database = TestDatabase {
+            pool: Arc::new(
+                PgPool::connect_with(options.database(&database))
+                    .await
+                    .expect("Could not connect to test database"),
+            ),
+            admin_url: url,
+            database,
+        };
+        sqlx::migrate!("./../migrations/postgres")
+            .run(&*test_database.pool)
+            .await
+            .expect("Could not run migrations");
+        Some(test_database)
+    }
+
+    /// Legt einen Mitarbeiter an, auf den die Fremdschlüssel anderer Tabellen
+    /// zeigen können.
+    pub async fn create_sales_person(pool: &Arc<PgPool>, tx: TransactionImpl) -> Uuid {
+        let entity = SalesPersonEntity {
+            id: Uuid::new_v4(),
+            name: "Anna".into(),
+            background_color: "#ff0000".into(),
+            is_paid: true,
+            deleted: None,
+            inactive: false,
+            personnel_number: None,
+            version: Uuid::new_v4(),
+        };
+        SalesPersonDaoImpl::new(pool.clone())
+            .create(&entity, PROCESS, tx)
+            .await
+            .unwrap();
+        entity.id
+    }
+
+    pub async fn create_shiftplan(pool: &Arc<PgPool>, tx: TransactionImpl) -> Uuid {
+        let entity = ShiftplanEntity {
+            id: Uuid::new_v4(),
+            name: "Laden".into(),
+            is_planning: false,
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+        ShiftplanDaoImpl::new(pool.clone())
+            .create(&entity, PROCESS, tx)
+            .await
+            .unwrap();
+        entity.id
+    }
+
+    /// Montag 9–13 Uhr im Schichtplan `shiftplan_id`.
+    pub async fn create_slot(pool: &Arc<PgPool>, shiftplan_id: Uuid, tx: TransactionImpl) -> Uuid {
+        let entity = SlotEntity {
+            id: Uuid::new_v4(),
+            day_of_week: DayOfWeek::Monday,
+            from: time!(9:00),
+            to: time!(13:00),
+            min_resources: 1,
+            max_paid_employees: None,
+            valid_from: date!(2026-01-01),
+            valid_to: None,
+            deleted: None,
+            version: Uuid::new_v4(),
+            shiftplan_id: Some(shiftplan_id),
+        };
+        SlotDaoImpl::new(pool.clone())
+            .create_slot(&entity, PROCESS, tx)
+            .await
+            .unwrap();
+        entity.id
+    }
+
+    /// Buchung in KW 17/2026.
+    pub async fn create_booking(
+        pool: &Arc<PgPool>,
+        sales_person_id: Uuid,
+        slot_id: Uuid,
+        tx: TransactionImpl,
+    ) -> Uuid {
+        let entity = BookingEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            slot_id,
+            calendar_week: 17,
+            year: 2026,
+            created: timestamp(),
+            deleted: None,
+            created_by: Some("anna".into()),
+            deleted_by: None,
+            version: Uuid::new_v4(),
+        };
+        BookingDaoImpl::new(pool.clone())
+            .create(&entity, PROCESS, tx)
+            .await
+            .unwrap();
+        entity.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::setup_pool;
+    use dao::{BasicDao, PermissionDao, TransactionDao, UserEntity};
+
+    #[tokio::test]
+    async fn admin_role_has_seeded_privileges() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let permission_dao = PermissionDaoImpl::new(pool.clone());
+        permission_dao
+            .create_user(&UserEntity { name: "anna".into() }, "test")
+            .await
+            .unwrap();
+        permission_dao
+            .add_user_role("anna", "admin", "test")
+            .await
+            .unwrap();
+
+        for privilege in ["admin", "hr", "shiftplanner", "cutover_admin"] {
+            assert!(permission_dao.has_privilege("anna", privilege).await.unwrap());
+        }
+        assert!(!permission_dao.has_privilege("bob", "admin").await.unwrap());
+        assert_eq!(
+            permission_dao.roles_for_user("anna").await.unwrap().as_ref(),
+            &[RoleEntity {
+                name: "admin".into()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn new_privilege_is_granted_to_admin() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let permission_dao = PermissionDaoImpl::new(pool.clone());
+        permission_dao
+            .create_privilege(
+                &PrivilegeEntity {
+                    name: "reporting".into(),
+                },
+                "test",
+            )
+            .await
+            .unwrap();
+        permission_dao
+            .create_user(&UserEntity { name: "anna".into() }, "test")
+            .await
+            .unwrap();
+        permission_dao
+            .add_user_role("anna", "admin", "test")
+            .await
+            .unwrap();
+
+        assert!(permission_dao
+            .has_privilege("anna", "reporting")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn rollback_discards_changes() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        query!(r#"INSERT INTO "user" (name, update_process) VALUES ('anna', 'test')"#)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .unwrap();
+        transaction_dao.rollback(tx).await.unwrap();
+
+        let permission_dao = PermissionDaoImpl::new(pool.clone());
+        assert_eq!(permission_dao.find_user("anna").await.unwrap(), None);
+
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        query!(r#"INSERT INTO "user" (name, update_process) VALUES ('anna', 'test')"#)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .unwrap();
+        transaction_dao.commit(tx).await.unwrap();
+        assert!(permission_dao.find_user("anna").await.unwrap().is_some());
+
+        BasicDaoImpl::new(pool.clone()).clear_all().await.unwrap();
+    }
+}
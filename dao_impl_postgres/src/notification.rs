@@ -0,0 +1,481 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    notification::{
+        NotificationDao, NotificationKind, NotificationOutboxEntity, NotificationPreferenceEntity,
+        NotificationState,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct NotificationPreferenceDb {
+    user_id: String,
+    email: String,
+    booking_deleted: i64,
+    week_planned: i64,
+    absence_created: i64,
+    invitation_created: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&NotificationPreferenceDb> for NotificationPreferenceEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &NotificationPreferenceDb) -> Result<Self, Self::Error> {
+        Ok(NotificationPreferenceEntity {
+            user_id: db.user_id.as_str().into(),
+            email: db.email.as_str().into(),
+            booking_deleted: db.booking_deleted != 0,
+            week_planned: db.week_planned != 0,
+            absence_created: db.absence_created != 0,
+            invitation_created: db.invitation_created != 0,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct NotificationOutboxDb {
+    id: Uuid,
+    recipient_user_id: String,
+    recipient_email: String,
+    kind: String,
+    subject: String,
+    body: String,
+    state: String,
+    attempts: i64,
+    next_attempt: PrimitiveDateTime,
+    last_error: Option<String>,
+    created: PrimitiveDateTime,
+    sent: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&NotificationOutboxDb> for NotificationOutboxEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &NotificationOutboxDb) -> Result<Self, Self::Error> {
+        Ok(NotificationOutboxEntity {
+            id: db.id,
+            recipient_user_id: db.recipient_user_id.as_str().into(),
+            recipient_email: db.recipient_email.as_str().into(),
+            kind: NotificationKind::try_from(db.kind.as_str())?,
+            subject: db.subject.as_str().into(),
+            body: db.body.as_str().into(),
+            state: NotificationState::try_from(db.state.as_str())?,
+            attempts: db.attempts as u32,
+            next_attempt: db.next_attempt,
+            last_error: db.last_error.as_deref().map(Arc::from),
+            created: db.created,
+            sent: db.sent,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct NotificationDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl NotificationDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationDao for NotificationDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_preference_by_user_id(
+        &self,
+        user_id: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<NotificationPreferenceEntity>, DaoError> {
+        query_as!(
+            NotificationPreferenceDb,
+            r#"SELECT user_id, email, booking_deleted, week_planned, absence_created,
+                      invitation_created, created, deleted, update_version
+               FROM notification_preference
+               WHERE user_id = $1 AND deleted IS NULL"#,
+            user_id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(NotificationPreferenceEntity::try_from)
+        .transpose()
+    }
+
+    async fn all_preferences(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationPreferenceEntity]>, DaoError> {
+        query_as!(
+            NotificationPreferenceDb,
+            r#"SELECT user_id, email, booking_deleted, week_planned, absence_created,
+                      invitation_created, created, deleted, update_version
+               FROM notification_preference
+               WHERE deleted IS NULL
+               ORDER BY user_id"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(NotificationPreferenceEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create_preference(
+        &self,
+        entity: &NotificationPreferenceEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let user_id = entity.user_id.as_ref();
+        let email = entity.email.as_ref();
+        let booking_deleted = entity.booking_deleted as i64;
+        let week_planned = entity.week_planned as i64;
+        let absence_created = entity.absence_created as i64;
+        let invitation_created = entity.invitation_created as i64;
+
+        query!(
+            r#"INSERT INTO notification_preference
+                 (user_id, email, booking_deleted, week_planned, absence_created,
+                  invitation_created, created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+            user_id,
+            email,
+            booking_deleted,
+            week_planned,
+            absence_created,
+            invitation_created,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_preference(
+        &self,
+        entity: &NotificationPreferenceEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let user_id = entity.user_id.as_ref();
+        let email = entity.email.as_ref();
+        let booking_deleted = entity.booking_deleted as i64;
+        let week_planned = entity.week_planned as i64;
+        let absence_created = entity.absence_created as i64;
+        let invitation_created = entity.invitation_created as i64;
+
+        query!(
+            r#"UPDATE notification_preference
+               SET email = $1, booking_deleted = $2, week_planned = $3, absence_created = $4,
+                   invitation_created = $5, deleted = $6, update_process = $7, update_version = $8
+               WHERE user_id = $9"#,
+            email,
+            booking_deleted,
+            week_planned,
+            absence_created,
+            invitation_created,
+            entity.deleted,
+            process,
+            entity.version,
+            user_id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_outbox_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<NotificationOutboxEntity>, DaoError> {
+        query_as!(
+            NotificationOutboxDb,
+            r#"SELECT id, recipient_user_id, recipient_email, kind, subject, body, state,
+                      attempts, next_attempt, last_error, created, sent, update_version
+               FROM notification_outbox
+               WHERE id = $1"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(NotificationOutboxEntity::try_from)
+        .transpose()
+    }
+
+    async fn recent_outbox(
+        &self,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationOutboxEntity]>, DaoError> {
+        let limit = limit as i64;
+        query_as!(
+            NotificationOutboxDb,
+            r#"SELECT id, recipient_user_id, recipient_email, kind, subject, body, state,
+                      attempts, next_attempt, last_error, created, sent, update_version
+               FROM notification_outbox
+               ORDER BY created DESC
+               LIMIT $1"#,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(NotificationOutboxEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_due_outbox(
+        &self,
+        now: PrimitiveDateTime,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationOutboxEntity]>, DaoError> {
+        let limit = limit as i64;
+        query_as!(
+            NotificationOutboxDb,
+            r#"SELECT id, recipient_user_id, recipient_email, kind, subject, body, state,
+                      attempts, next_attempt, last_error, created, sent, update_version
+               FROM notification_outbox
+               WHERE state = 'Pending' AND next_attempt <= $1
+               ORDER BY next_attempt, created
+               LIMIT $2"#,
+            now,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(NotificationOutboxEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create_outbox(
+        &self,
+        entity: &NotificationOutboxEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let recipient_user_id = entity.recipient_user_id.as_ref();
+        let recipient_email = entity.recipient_email.as_ref();
+        let kind_str = entity.kind.to_string();
+        let subject = entity.subject.as_ref();
+        let body = entity.body.as_ref();
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let last_error = entity.last_error.as_deref();
+
+        query!(
+            r#"INSERT INTO notification_outbox
+                 (id, recipient_user_id, recipient_email, kind, subject, body, state,
+                  attempts, next_attempt, last_error, created, sent, update_process,
+                  update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)"#,
+            entity.id,
+            recipient_user_id,
+            recipient_email,
+            kind_str,
+            subject,
+            body,
+            state_str,
+            attempts,
+            entity.next_attempt,
+            last_error,
+            entity.created,
+            entity.sent,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_outbox(
+        &self,
+        entity: &NotificationOutboxEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let last_error = entity.last_error.as_deref();
+
+        query!(
+            r#"UPDATE notification_outbox
+               SET state = $1, attempts = $2, next_attempt = $3, last_error = $4, sent = $5,
+                   update_process = $6, update_version = $7
+               WHERE id = $8"#,
+            state_str,
+            attempts,
+            entity.next_attempt,
+            last_error,
+            entity.sent,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn notification_preference_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = NotificationDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = NotificationPreferenceEntity {
+            user_id: "anna".into(),
+            email: "anna@example.com".into(),
+            booking_deleted: true,
+            week_planned: false,
+            absence_created: true,
+            invitation_created: false,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create_preference(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_preference_by_user_id("anna", tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.email = "anna@example.org".into();
+        entity.week_planned = true;
+        entity.version = Uuid::new_v4();
+        dao.update_preference(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.all_preferences(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update_preference(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_preference_by_user_id("anna", tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(dao.all_preferences(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn notification_outbox_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = NotificationDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = NotificationOutboxEntity {
+            id: Uuid::new_v4(),
+            recipient_user_id: "anna".into(),
+            recipient_email: "anna@example.com".into(),
+            kind: NotificationKind::WeekPlanned,
+            subject: "KW 17 ist geplant".into(),
+            body: "Hallo Anna".into(),
+            state: NotificationState::Pending,
+            attempts: 0,
+            next_attempt: datetime!(2026-10-01 09:00),
+            last_error: None,
+            created: timestamp(),
+            sent: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create_outbox(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_outbox_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert!(dao
+            .find_due_outbox(datetime!(2026-10-01 08:59), 10, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            dao.find_due_outbox(datetime!(2026-10-01 09:00), 10, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.state = NotificationState::Sent;
+        entity.attempts = 1;
+        entity.last_error = Some("Timeout".into());
+        entity.sent = Some(datetime!(2026-10-01 09:01));
+        entity.version = Uuid::new_v4();
+        dao.update_outbox(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.recent_outbox(10, tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+        assert!(dao
+            .find_due_outbox(datetime!(2026-10-02 00:00), 10, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    payroll_wage_type::{PayrollValueView, PayrollWageTypeDao, PayrollWageTypeEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct PayrollWageTypeDb {
+    id: Uuid,
+    value_type: String,
+    value_view: String,
+    wage_type: String,
+    processing_key: i64,
+    description: String,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&PayrollWageTypeDb> for PayrollWageTypeEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &PayrollWageTypeDb) -> Result<Self, Self::Error> {
+        Ok(PayrollWageTypeEntity {
+            id: db.id,
+            value_type: db.value_type.as_str().into(),
+            value_view: PayrollValueView::try_from(db.value_view.as_str())?,
+            wage_type: db.wage_type.as_str().into(),
+            processing_key: db.processing_key as u8,
+            description: db.description.as_str().into(),
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct PayrollWageTypeDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl PayrollWageTypeDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PayrollWageTypeDao for PayrollWageTypeDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[PayrollWageTypeEntity]>, DaoError> {
+        query_as!(
+            PayrollWageTypeDb,
+            r#"SELECT id, value_type, value_view, wage_type, processing_key, description,
+                      created, deleted, update_version
+               FROM payroll_wage_type
+               WHERE deleted IS NULL
+               ORDER BY wage_type, created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(PayrollWageTypeEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<PayrollWageTypeEntity>, DaoError> {
+        query_as!(
+            PayrollWageTypeDb,
+            r#"SELECT id, value_type, value_view, wage_type, processing_key, description,
+                      created, deleted, update_version
+               FROM payroll_wage_type
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(PayrollWageTypeEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &PayrollWageTypeEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let value_type = entity.value_type.as_ref();
+        let value_view = entity.value_view.to_string();
+        let wage_type = entity.wage_type.as_ref();
+        let processing_key = entity.processing_key as i64;
+        let description = entity.description.as_ref();
+
+        query!(
+            r#"INSERT INTO payroll_wage_type
+                 (id, value_type, value_view, wage_type, processing_key, description,
+                  created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+            entity.id,
+            value_type,
+            value_view,
+            wage_type,
+            processing_key,
+            description,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &PayrollWageTypeEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let value_type = entity.value_type.as_ref();
+        let value_view = entity.value_view.to_string();
+        let wage_type = entity.wage_type.as_ref();
+        let processing_key = entity.processing_key as i64;
+        let description = entity.description.as_ref();
+
+        query!(
+            r#"UPDATE payroll_wage_type
+               SET value_type = $1, value_view = $2, wage_type = $3, processing_key = $4,
+                   description = $5, deleted = $6, update_process = $7, update_version = $8
+               WHERE id = $9"#,
+            value_type,
+            value_view,
+            wage_type,
+            processing_key,
+            description,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn payroll_wage_type_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = PayrollWageTypeDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = PayrollWageTypeEntity {
+            id: Uuid::new_v4(),
+            value_type: "overall".into(),
+            value_view: PayrollValueView::Delta,
+            wage_type: "1000".into(),
+            processing_key: 0,
+            description: "Stunden".into(),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.value_view = PayrollValueView::FullYear;
+        entity.wage_type = "1010".into();
+        entity.processing_key = 2;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.all(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao.all(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,399 @@
+use std::sync::Arc;
+
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::{
+    pdf_export_config::{PdfExportConfigDao, PdfExportConfigEntity, PdfExportShiftplanEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+/// Raw row shape; Nullable-Felder als `Option`.
+#[derive(Debug)]
+struct PdfExportConfigDb {
+    id: Uuid,
+    enabled: i64,
+    nextcloud_url: Option<String>,
+    webdav_user: Option<String>,
+    webdav_app_token: Option<String>,
+    target_folder: Option<String>,
+    weeks_horizon: i64,
+    cron_schedule: String,
+    include_planning_plans: i64,
+    combined_pdf: i64,
+    last_success_at: Option<PrimitiveDateTime>,
+    last_error_at: Option<PrimitiveDateTime>,
+    last_error_message: Option<String>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&PdfExportConfigDb> for PdfExportConfigEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &PdfExportConfigDb) -> Result<Self, Self::Error> {
+        Ok(PdfExportConfigEntity {
+            id: db.id,
+            enabled: db.enabled != 0,
+            nextcloud_url: db.nextcloud_url.as_deref().map(Arc::from),
+            webdav_user: db.webdav_user.as_deref().map(Arc::from),
+            webdav_app_token: db.webdav_app_token.as_deref().map(Arc::from),
+            target_folder: db.target_folder.as_deref().map(Arc::from),
+            weeks_horizon: db.weeks_horizon as u32,
+            cron_schedule: Arc::from(db.cron_schedule.as_str()),
+            include_planning_plans: db.include_planning_plans != 0,
+            combined_pdf: db.combined_pdf != 0,
+            last_success_at: db.last_success_at,
+            last_error_at: db.last_error_at,
+            last_error_message: db.last_error_message.as_deref().map(Arc::from),
+            version: db.update_version,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct PdfExportShiftplanDb {
+    shiftplan_id: Uuid,
+    included: i64,
+    subfolder: Option<String>,
+    filename_prefix: Option<String>,
+}
+
+impl TryFrom<&PdfExportShiftplanDb> for PdfExportShiftplanEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &PdfExportShiftplanDb) -> Result<Self, Self::Error> {
+        Ok(PdfExportShiftplanEntity {
+            shiftplan_id: db.shiftplan_id,
+            included: db.included != 0,
+            subfolder: db.subfolder.as_deref().map(Arc::from),
+            filename_prefix: db.filename_prefix.as_deref().map(Arc::from),
+        })
+    }
+}
+
+pub struct PdfExportConfigDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl PdfExportConfigDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl PdfExportConfigDao for PdfExportConfigDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn get(&self, tx: Self::Transaction) -> Result<PdfExportConfigEntity, DaoError> {
+        let row = query_as!(
+            PdfExportConfigDb,
+            r#"SELECT
+                id,
+                enabled,
+                nextcloud_url,
+                webdav_user,
+                webdav_app_token,
+                target_folder,
+                weeks_horizon,
+                cron_schedule,
+                include_planning_plans,
+                combined_pdf,
+                last_success_at,
+                last_error_at,
+                last_error_message,
+                update_version
+              FROM pdf_export_config
+              LIMIT 1"#,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .ok_or_else(|| {
+            DaoError::DatabaseQueryError(Box::new(std::io::Error::other(
+                "pdf_export_config seed row missing",
+            )))
+        })?;
+
+        PdfExportConfigEntity::try_from(&row)
+    }
+
+    async fn update(
+        &self,
+        entity: &PdfExportConfigEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let nextcloud_url = entity.nextcloud_url.as_deref();
+        let webdav_user = entity.webdav_user.as_deref();
+        let webdav_app_token = entity.webdav_app_token.as_deref();
+        let target_folder = entity.target_folder.as_deref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let weeks_horizon = entity.weeks_horizon as i64;
+        let include_planning_plans = entity.include_planning_plans as i64;
+        let combined_pdf = entity.combined_pdf as i64;
+
+        query!(
+            r#"UPDATE pdf_export_config
+               SET enabled = $1,
+                   nextcloud_url = $2,
+                   webdav_user = $3,
+                   webdav_app_token = $4,
+                   target_folder = $5,
+                   weeks_horizon = $6,
+                   cron_schedule = $7,
+                   include_planning_plans = $8,
+                   combined_pdf = $9,
+                   update_process = $10,
+                   update_version = $11
+               WHERE id = $12"#,
+            entity.enabled as i64,
+            nextcloud_url,
+            webdav_user,
+            webdav_app_token,
+            target_folder,
+            weeks_horizon,
+            cron_schedule,
+            include_planning_plans,
+            combined_pdf,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn record_success(
+        &self,
+        at: time::PrimitiveDateTime,
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"UPDATE pdf_export_config
+               SET last_success_at = $1,
+                   last_error_at = NULL,
+                   last_error_message = NULL,
+                   update_process = $2,
+                   update_version = $3"#,
+            at,
+            process,
+            version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn record_error(
+        &self,
+        at: time::PrimitiveDateTime,
+        message: &str,
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"UPDATE pdf_export_config
+               SET last_error_at = $1,
+                   last_error_message = $2,
+                   update_process = $3,
+                   update_version = $4"#,
+            at,
+            message,
+            process,
+            version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn get_shiftplan_settings(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[PdfExportShiftplanEntity]>, DaoError> {
+        let rows = query_as!(
+            PdfExportShiftplanDb,
+            r#"SELECT shiftplan_id, included, subfolder, filename_prefix
+              FROM pdf_export_shiftplan"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        rows.iter()
+            .map(PdfExportShiftplanEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn replace_shiftplan_settings(
+        &self,
+        settings: &[PdfExportShiftplanEntity],
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(r#"DELETE FROM pdf_export_shiftplan"#)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+
+        for setting in settings {
+            let included = setting.included as i64;
+            let subfolder = setting.subfolder.as_deref();
+            let filename_prefix = setting.filename_prefix.as_deref();
+            query!(
+                r#"INSERT INTO pdf_export_shiftplan
+                     (shiftplan_id, included, subfolder, filename_prefix, update_process, update_version)
+                   VALUES ($1, $2, $3, $4, $5, $6)"#,
+                setting.shiftplan_id,
+                included,
+                subfolder,
+                filename_prefix,
+                process,
+                version,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn pdf_export_config_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = PdfExportConfigDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let seeded = dao.get(tx.clone()).await.unwrap();
+        let mut entity = PdfExportConfigEntity {
+            enabled: true,
+            nextcloud_url: Some("https://cloud.example.com".into()),
+            webdav_user: Some("shifty".into()),
+            webdav_app_token: Some("app-token".into()),
+            target_folder: Some("/Schichtplan".into()),
+            weeks_horizon: 6,
+            cron_schedule: "0 0 5 * * *".into(),
+            include_planning_plans: false,
+            combined_pdf: true,
+            version: Uuid::new_v4(),
+            ..seeded
+        };
+
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.get(tx.clone()).await.unwrap(), entity);
+
+        let error_version = Uuid::new_v4();
+        dao.record_error(
+            datetime!(2026-10-01 05:00),
+            "WebDAV 401",
+            PROCESS,
+            error_version,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        entity.last_error_at = Some(datetime!(2026-10-01 05:00));
+        entity.last_error_message = Some("WebDAV 401".into());
+        entity.version = error_version;
+        assert_eq!(dao.get(tx.clone()).await.unwrap(), entity);
+
+        let success_version = Uuid::new_v4();
+        dao.record_success(
+            datetime!(2026-10-02 05:00),
+            PROCESS,
+            success_version,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        entity.last_success_at = Some(datetime!(2026-10-02 05:00));
+        entity.last_error_at = None;
+        entity.last_error_message = None;
+        entity.version = success_version;
+        assert_eq!(dao.get(tx.clone()).await.unwrap(), entity);
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn replace_shiftplan_settings_overwrites_selection() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = PdfExportConfigDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let first = PdfExportShiftplanEntity {
+            shiftplan_id: Uuid::new_v4(),
+            included: true,
+            subfolder: Some("Laden".into()),
+            filename_prefix: Some("laden-".into()),
+        };
+        let second = PdfExportShiftplanEntity {
+            shiftplan_id: Uuid::new_v4(),
+            included: false,
+            subfolder: None,
+            filename_prefix: None,
+        };
+
+        dao.replace_shiftplan_settings(
+            std::slice::from_ref(&first),
+            PROCESS,
+            Uuid::new_v4(),
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            dao.get_shiftplan_settings(tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[first]
+        );
+
+        dao.replace_shiftplan_settings(
+            std::slice::from_ref(&second),
+            PROCESS,
+            Uuid::new_v4(),
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            dao.get_shiftplan_settings(tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[second]
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
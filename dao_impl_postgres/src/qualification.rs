@@ -0,0 +1,459 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    qualification::{
+        QualificationDao, QualificationEntity, SalesPersonQualificationEntity,
+        SlotQualificationRequirementEntity,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct QualificationDb {
+    id: Uuid,
+    name: String,
+    description: String,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&QualificationDb> for QualificationEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &QualificationDb) -> Result<Self, Self::Error> {
+        Ok(QualificationEntity {
+            id: db.id,
+            name: db.name.as_str().into(),
+            description: db.description.as_str().into(),
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SalesPersonQualificationDb {
+    sales_person_id: Uuid,
+    qualification_id: Uuid,
+}
+
+impl TryFrom<&SalesPersonQualificationDb> for SalesPersonQualificationEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SalesPersonQualificationDb) -> Result<Self, Self::Error> {
+        Ok(SalesPersonQualificationEntity {
+            sales_person_id: db.sales_person_id,
+            qualification_id: db.qualification_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SlotQualificationRequirementDb {
+    slot_id: Uuid,
+    qualification_id: Uuid,
+    min_count: i64,
+}
+
+impl TryFrom<&SlotQualificationRequirementDb> for SlotQualificationRequirementEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SlotQualificationRequirementDb) -> Result<Self, Self::Error> {
+        Ok(SlotQualificationRequirementEntity {
+            slot_id: db.slot_id,
+            qualification_id: db.qualification_id,
+            min_count: db.min_count as u8,
+        })
+    }
+}
+
+pub struct QualificationDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl QualificationDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QualificationDao for QualificationDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[QualificationEntity]>, DaoError> {
+        query_as!(
+            QualificationDb,
+            r#"SELECT id, name, description, created, deleted, update_version
+               FROM qualification
+               WHERE deleted IS NULL
+               ORDER BY name"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(QualificationEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<QualificationEntity>, DaoError> {
+        query_as!(
+            QualificationDb,
+            r#"SELECT id, name, description, created, deleted, update_version
+               FROM qualification
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(QualificationEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &QualificationEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let description = entity.description.as_ref();
+
+        query!(
+            r#"INSERT INTO qualification
+                 (id, name, description, created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            entity.id,
+            name,
+            description,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &QualificationEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let description = entity.description.as_ref();
+
+        query!(
+            r#"UPDATE qualification
+               SET name = $1, description = $2, deleted = $3, update_process = $4,
+                   update_version = $5
+               WHERE id = $6"#,
+            name,
+            description,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn all_sales_person_qualifications(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonQualificationEntity]>, DaoError> {
+        query_as!(
+            SalesPersonQualificationDb,
+            r#"SELECT spq.sales_person_id, spq.qualification_id
+               FROM sales_person_qualification spq
+               JOIN qualification q ON q.id = spq.qualification_id
+               WHERE q.deleted IS NULL"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonQualificationEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonQualificationEntity]>, DaoError> {
+        query_as!(
+            SalesPersonQualificationDb,
+            r#"SELECT spq.sales_person_id, spq.qualification_id
+               FROM sales_person_qualification spq
+               JOIN qualification q ON q.id = spq.qualification_id
+               WHERE spq.sales_person_id = $1 AND q.deleted IS NULL
+               ORDER BY q.name"#,
+            sales_person_id,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonQualificationEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn set_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        qualification_ids: &[Uuid],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(
+            "DELETE FROM sales_person_qualification WHERE sales_person_id = $1",
+            sales_person_id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        for qualification_id in qualification_ids {
+            query!(
+                r#"INSERT INTO sales_person_qualification
+                     (sales_person_id, qualification_id, update_process)
+                   VALUES ($1, $2, $3)"#,
+                sales_person_id,
+                qualification_id,
+                process,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+        Ok(())
+    }
+
+    async fn all_slot_requirements(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotQualificationRequirementEntity]>, DaoError> {
+        query_as!(
+            SlotQualificationRequirementDb,
+            r#"SELECT sqr.slot_id, sqr.qualification_id, sqr.min_count
+               FROM slot_qualification_requirement sqr
+               JOIN qualification q ON q.id = sqr.qualification_id
+               WHERE q.deleted IS NULL"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SlotQualificationRequirementEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_requirements_by_slot(
+        &self,
+        slot_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotQualificationRequirementEntity]>, DaoError> {
+        query_as!(
+            SlotQualificationRequirementDb,
+            r#"SELECT sqr.slot_id, sqr.qualification_id, sqr.min_count
+               FROM slot_qualification_requirement sqr
+               JOIN qualification q ON q.id = sqr.qualification_id
+               WHERE sqr.slot_id = $1 AND q.deleted IS NULL
+               ORDER BY q.name"#,
+            slot_id,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SlotQualificationRequirementEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn set_requirements_for_slot(
+        &self,
+        slot_id: Uuid,
+        requirements: &[SlotQualificationRequirementEntity],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(
+            "DELETE FROM slot_qualification_requirement WHERE slot_id = $1",
+            slot_id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        for requirement in requirements {
+            let min_count = requirement.min_count as i64;
+            query!(
+                r#"INSERT INTO slot_qualification_requirement
+                     (slot_id, qualification_id, min_count, update_process)
+                   VALUES ($1, $2, $3, $4)"#,
+                slot_id,
+                requirement.qualification_id,
+                min_count,
+                process,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_sales_person, create_shiftplan, create_slot, setup_pool, timestamp, PROCESS,
+    };
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn qualification_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = QualificationDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = QualificationEntity {
+            id: Uuid::new_v4(),
+            name: "Kasse".into(),
+            description: "Darf kassieren".into(),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.name = "Kasse & Tresor".into();
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.all(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao.all(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn assignments_hide_deleted_qualifications() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = QualificationDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let shiftplan_id = create_shiftplan(&pool, tx.clone()).await;
+        let slot_id = create_slot(&pool, shiftplan_id, tx.clone()).await;
+        let mut qualification = QualificationEntity {
+            id: Uuid::new_v4(),
+            name: "Kasse".into(),
+            description: "".into(),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+        dao.create(&qualification, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        let assignment = SalesPersonQualificationEntity {
+            sales_person_id,
+            qualification_id: qualification.id,
+        };
+        let requirement = SlotQualificationRequirementEntity {
+            slot_id,
+            qualification_id: qualification.id,
+            min_count: 2,
+        };
+
+        dao.set_for_sales_person(sales_person_id, &[qualification.id], PROCESS, tx.clone())
+            .await
+            .unwrap();
+        dao.set_requirements_for_slot(
+            slot_id,
+            std::slice::from_ref(&requirement),
+            PROCESS,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            dao.find_by_sales_person(sales_person_id, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            std::slice::from_ref(&assignment)
+        );
+        assert_eq!(
+            dao.all_slot_requirements(tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            std::slice::from_ref(&requirement)
+        );
+
+        qualification.deleted = Some(timestamp());
+        qualification.version = Uuid::new_v4();
+        dao.update(&qualification, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert!(dao
+            .all_sales_person_qualifications(tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(dao
+            .find_requirements_by_slot(slot_id, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+
+        dao.set_for_sales_person(sales_person_id, &[], PROCESS, tx.clone())
+            .await
+            .unwrap();
+        dao.set_requirements_for_slot(slot_id, &[], PROCESS, tx.clone())
+            .await
+            .unwrap();
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,532 @@
+//! Phase 54 (VOL-ACCT / D-54-DM-01): SQLite-Implementierung des
+//! Basic-Tier `RebookingBatchDao`. Konsumenten folgen ab Phase 55/56.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    rebooking_batch::{
+        RebookingBatchDao, RebookingBatchEntity, RebookingBatchEntryEntity, RebookingBatchKind,
+        RebookingBatchState,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+// ---------------------------------------------------------------------------
+// Row-Layout
+// ---------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct RebookingBatchDb {
+    id: Uuid,
+    sales_person_id: Uuid,
+    iso_year: i64,
+    iso_week: i64,
+    kind: String,
+    state: String,
+    created: PrimitiveDateTime,
+    approved: Option<PrimitiveDateTime>,
+    approved_by: Option<String>,
+    rejected: Option<PrimitiveDateTime>,
+    rejected_by: Option<String>,
+    reject_reason: Option<String>,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&RebookingBatchDb> for RebookingBatchEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &RebookingBatchDb) -> Result<Self, Self::Error> {
+        Ok(RebookingBatchEntity {
+            id: db.id,
+            sales_person_id: db.sales_person_id,
+            iso_year: db.iso_year as u32,
+            iso_week: db.iso_week as u8,
+            kind: str_to_kind(&db.kind)?,
+            state: str_to_state(&db.state)?,
+            created: db.created,
+            approved: db.approved,
+            approved_by: db.approved_by.as_ref().map(|s| Arc::<str>::from(s.as_str())),
+            rejected: db.rejected,
+            rejected_by: db.rejected_by.as_ref().map(|s| Arc::<str>::from(s.as_str())),
+            reject_reason: db.reject_reason.as_ref().map(|s| Arc::<str>::from(s.as_str())),
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct RebookingBatchEntryDb {
+    id: Uuid,
+    batch_id: Uuid,
+    sales_person_id: Uuid,
+    hours: f64,
+    balance_before: f64,
+    voluntary_actual: f64,
+    voluntary_committed: f64,
+    extra_hours_out_id: Option<Uuid>,
+    extra_hours_in_id: Option<Uuid>,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&RebookingBatchEntryDb> for RebookingBatchEntryEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &RebookingBatchEntryDb) -> Result<Self, Self::Error> {
+        Ok(RebookingBatchEntryEntity {
+            id: db.id,
+            batch_id: db.batch_id,
+            sales_person_id: db.sales_person_id,
+            hours: db.hours as f32,
+            balance_before: db.balance_before as f32,
+            voluntary_actual: db.voluntary_actual as f32,
+            voluntary_committed: db.voluntary_committed as f32,
+            extra_hours_out_id: db.extra_hours_out_id,
+            extra_hours_in_id: db.extra_hours_in_id,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Enum <-> String Konversionen
+// ---------------------------------------------------------------------------
+
+fn kind_to_str(kind: &RebookingBatchKind) -> &'static str {
+    match kind {
+        RebookingBatchKind::Manual => "manual",
+        RebookingBatchKind::HrSuggestion => "hr_suggestion",
+        RebookingBatchKind::AutoCron => "auto_cron",
+        RebookingBatchKind::AutoCronBackfill => "auto_cron_backfill",
+    }
+}
+
+fn str_to_kind(s: &str) -> Result<RebookingBatchKind, DaoError> {
+    match s {
+        "manual" => Ok(RebookingBatchKind::Manual),
+        "hr_suggestion" => Ok(RebookingBatchKind::HrSuggestion),
+        "auto_cron" => Ok(RebookingBatchKind::AutoCron),
+        "auto_cron_backfill" => Ok(RebookingBatchKind::AutoCronBackfill),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+fn state_to_str(state: &RebookingBatchState) -> &'static str {
+    match state {
+        RebookingBatchState::Pending => "pending",
+        RebookingBatchState::Approved => "approved",
+        RebookingBatchState::Rejected => "rejected",
+        RebookingBatchState::SkippedLocked => "skipped_locked",
+    }
+}
+
+fn str_to_state(s: &str) -> Result<RebookingBatchState, DaoError> {
+    match s {
+        "pending" => Ok(RebookingBatchState::Pending),
+        "approved" => Ok(RebookingBatchState::Approved),
+        "rejected" => Ok(RebookingBatchState::Rejected),
+        "skipped_locked" => Ok(RebookingBatchState::SkippedLocked),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// DAO-Impl
+// ---------------------------------------------------------------------------
+
+pub struct RebookingBatchDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl RebookingBatchDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RebookingBatchDao for RebookingBatchDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<RebookingBatchEntity>, DaoError> {
+        Ok(query_as!(
+            RebookingBatchDb,
+            r#"SELECT id, sales_person_id, iso_year, iso_week, kind, state, created,
+                      approved, approved_by, rejected, rejected_by, reject_reason,
+                      deleted, update_version
+               FROM rebooking_batch
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(RebookingBatchEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_sales_person_year_week(
+        &self,
+        sales_person_id: Uuid,
+        iso_year: u32,
+        iso_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Option<RebookingBatchEntity>, DaoError> {
+        Ok(query_as!(
+            RebookingBatchDb,
+            r#"SELECT id, sales_person_id, iso_year, iso_week, kind, state, created,
+                      approved, approved_by, rejected, rejected_by, reject_reason,
+                      deleted, update_version
+               FROM rebooking_batch
+               WHERE sales_person_id = $1 AND iso_year = $2 AND iso_week = $3
+                 AND deleted IS NULL"#,
+            sales_person_id,
+            iso_year as i64,
+            iso_week as i64,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(RebookingBatchEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn create_batch_with_entries(
+        &self,
+        batch: &RebookingBatchEntity,
+        entries: &[RebookingBatchEntryEntity],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let kind_str = kind_to_str(&batch.kind);
+        let state_str = state_to_str(&batch.state);
+        let approved_by_str = batch.approved_by.as_ref().map(|s| s.to_string());
+        let rejected_by_str = batch.rejected_by.as_deref();
+        let reject_reason_str = batch.reject_reason.as_deref();
+
+        query!(
+            r#"INSERT INTO rebooking_batch
+                   (id, sales_person_id, iso_year, iso_week, kind, state, created,
+                    approved, approved_by, rejected, rejected_by, reject_reason,
+                    deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)"#,
+            batch.id,
+            batch.sales_person_id,
+            batch.iso_year as i64,
+            batch.iso_week as i64,
+            kind_str,
+            state_str,
+            batch.created,
+            batch.approved,
+            approved_by_str,
+            batch.rejected,
+            rejected_by_str,
+            reject_reason_str,
+            batch.deleted,
+            process,
+            batch.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        for entry in entries {
+            let e_hours = entry.hours as f64;
+            let e_balance = entry.balance_before as f64;
+            let e_va = entry.voluntary_actual as f64;
+            let e_vc = entry.voluntary_committed as f64;
+
+            query!(
+                r#"INSERT INTO rebooking_batch_entry
+                       (id, batch_id, sales_person_id, hours, balance_before,
+                        voluntary_actual, voluntary_committed,
+                        extra_hours_out_id, extra_hours_in_id,
+                        created, deleted, update_process, update_version)
+                   VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)"#,
+                entry.id,
+                entry.batch_id,
+                entry.sales_person_id,
+                e_hours,
+                e_balance,
+                e_va,
+                e_vc,
+                entry.extra_hours_out_id,
+                entry.extra_hours_in_id,
+                entry.created,
+                entry.deleted,
+                process,
+                entry.version,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+
+        Ok(())
+    }
+
+    async fn list_entries_for_batch(
+        &self,
+        batch_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[RebookingBatchEntryEntity]>, DaoError> {
+        Ok(query_as!(
+            RebookingBatchEntryDb,
+            r#"SELECT id, batch_id, sales_person_id, hours, balance_before,
+                      voluntary_actual, voluntary_committed,
+                      extra_hours_out_id, extra_hours_in_id,
+                      created, deleted, update_version
+               FROM rebooking_batch_entry
+               WHERE batch_id = $1 AND deleted IS NULL"#,
+            batch_id,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(RebookingBatchEntryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_by_state(
+        &self,
+        state: RebookingBatchState,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[RebookingBatchEntity]>, DaoError> {
+        let state_str = state_to_str(&state);
+        Ok(query_as!(
+            RebookingBatchDb,
+            r#"SELECT id, sales_person_id, iso_year, iso_week, kind, state, created,
+                      approved, approved_by, rejected, rejected_by, reject_reason,
+                      deleted, update_version
+               FROM rebooking_batch
+               WHERE state = $1 AND deleted IS NULL
+               ORDER BY created, iso_year, iso_week"#,
+            state_str,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(RebookingBatchEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn update_batch(
+        &self,
+        batch: &RebookingBatchEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let state_str = state_to_str(&batch.state);
+        let approved_by_str = batch.approved_by.as_deref();
+        let rejected_by_str = batch.rejected_by.as_deref();
+        let reject_reason_str = batch.reject_reason.as_deref();
+        query!(
+            r#"UPDATE rebooking_batch
+               SET state = $1, approved = $2, approved_by = $3, rejected = $4,
+                   rejected_by = $5, reject_reason = $6, deleted = $7,
+                   update_process = $8, update_version = $9
+               WHERE id = $10"#,
+            state_str,
+            batch.approved,
+            approved_by_str,
+            batch.rejected,
+            rejected_by_str,
+            reject_reason_str,
+            batch.deleted,
+            process,
+            batch.version,
+            batch.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_entry(
+        &self,
+        entry: &RebookingBatchEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(
+            r#"UPDATE rebooking_batch_entry
+               SET extra_hours_out_id = $1, extra_hours_in_id = $2, deleted = $3,
+                   update_process = $4, update_version = $5
+               WHERE id = $6"#,
+            entry.extra_hours_out_id,
+            entry.extra_hours_in_id,
+            entry.deleted,
+            process,
+            entry.version,
+            entry.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[test]
+    fn kind_roundtrip() {
+        for k in [
+            RebookingBatchKind::Manual,
+            RebookingBatchKind::HrSuggestion,
+            RebookingBatchKind::AutoCron,
+            RebookingBatchKind::AutoCronBackfill,
+        ] {
+            assert_eq!(str_to_kind(kind_to_str(&k)).unwrap(), k);
+        }
+    }
+
+    #[test]
+    fn state_roundtrip() {
+        for s in [
+            RebookingBatchState::Pending,
+            RebookingBatchState::Approved,
+            RebookingBatchState::Rejected,
+            RebookingBatchState::SkippedLocked,
+        ] {
+            assert_eq!(str_to_state(state_to_str(&s)).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn unknown_kind_is_enum_value_not_found() {
+        match str_to_kind("bogus") {
+            Err(DaoError::EnumValueNotFound(v)) => assert_eq!(&*v, "bogus"),
+            other => panic!("expected EnumValueNotFound(\"bogus\"), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_state_is_enum_value_not_found() {
+        match str_to_state("bogus") {
+            Err(DaoError::EnumValueNotFound(v)) => assert_eq!(&*v, "bogus"),
+            other => panic!("expected EnumValueNotFound(\"bogus\"), got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rebooking_batch_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = RebookingBatchDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut batch = RebookingBatchEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            iso_year: 2026,
+            iso_week: 17,
+            kind: RebookingBatchKind::Manual,
+            state: RebookingBatchState::Pending,
+            created: timestamp(),
+            approved: None,
+            approved_by: None,
+            rejected: None,
+            rejected_by: None,
+            reject_reason: None,
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+        let mut entry = RebookingBatchEntryEntity {
+            id: Uuid::new_v4(),
+            batch_id: batch.id,
+            sales_person_id,
+            hours: 2.0,
+            balance_before: -3.5,
+            voluntary_actual: 4.0,
+            voluntary_committed: 2.0,
+            extra_hours_out_id: None,
+            extra_hours_in_id: None,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create_batch_with_entries(&batch, &[entry.clone()], PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_by_id(batch.id, tx.clone()).await.unwrap(),
+            Some(batch.clone())
+        );
+        assert_eq!(
+            dao.list_entries_for_batch(batch.id, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entry.clone()]
+        );
+
+        batch.state = RebookingBatchState::Rejected;
+        batch.rejected = Some(timestamp());
+        batch.rejected_by = Some("anna".into());
+        batch.reject_reason = Some("Doppelt".into());
+        batch.version = Uuid::new_v4();
+        dao.update_batch(&batch, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_year_week(sales_person_id, 2026, 17, tx.clone())
+                .await
+                .unwrap(),
+            Some(batch.clone())
+        );
+        assert_eq!(
+            dao.find_by_state(RebookingBatchState::Rejected, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[batch.clone()]
+        );
+
+        entry.deleted = Some(timestamp());
+        entry.version = Uuid::new_v4();
+        dao.update_entry(&entry, PROCESS, tx.clone()).await.unwrap();
+        assert!(dao
+            .list_entries_for_batch(batch.id, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        batch.deleted = Some(timestamp());
+        batch.version = Uuid::new_v4();
+        dao.update_batch(&batch, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(batch.id, tx.clone()).await.unwrap(), None);
+        assert_eq!(
+            dao.find_by_sales_person_year_week(sales_person_id, 2026, 17, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
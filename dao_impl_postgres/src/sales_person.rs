@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    sales_person::{SalesPersonDao, SalesPersonEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+pub struct SalesPersonDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl SalesPersonDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+struct SalesPersonDb {
+    id: Uuid,
+    name: String,
+    background_color: String,
+    is_paid: bool,
+    inactive: bool,
+    personnel_number: Option<String>,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+impl TryFrom<&SalesPersonDb> for SalesPersonEntity {
+    type Error = DaoError;
+    fn try_from(sales_person: &SalesPersonDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: sales_person.id,
+            name: sales_person.name.as_str().into(),
+            background_color: sales_person.background_color.as_str().into(),
+            is_paid: sales_person.is_paid,
+            inactive: sales_person.inactive,
+            personnel_number: sales_person.personnel_number.as_deref().map(Arc::from),
+            deleted: sales_person.deleted,
+            version: sales_person.update_version,
+        })
+    }
+}
+
+#[async_trait]
+impl SalesPersonDao for SalesPersonDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[SalesPersonEntity]>, DaoError> {
+        Ok(query_as!(
+            SalesPersonDb,
+            "SELECT id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version FROM sales_person WHERE deleted IS NULL"
+        )
+            .fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .iter()
+            .map(SalesPersonEntity::try_from)
+            .collect::<Result<Arc<[SalesPersonEntity]>, DaoError>>()?
+        )
+    }
+
+    async fn all_paid(&self, tx: Self::Transaction) -> Result<Arc<[SalesPersonEntity]>, DaoError> {
+        Ok(query_as!(
+            SalesPersonDb,
+            "SELECT id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version FROM sales_person WHERE deleted IS NULL AND is_paid"
+        )
+            .fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .iter()
+            .map(SalesPersonEntity::try_from)
+            .collect::<Result<Arc<[SalesPersonEntity]>, DaoError>>()?
+        )
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonEntity>, DaoError> {
+        Ok(query_as!(
+            SalesPersonDb,
+            "SELECT id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version FROM sales_person WHERE id = $1",
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SalesPersonEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_user(
+        &self,
+        user_id: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonEntity>, DaoError> {
+        Ok(query_as!(
+            SalesPersonDb,
+            "SELECT sp.id, sp.name, sp.background_color, sp.is_paid, sp.inactive, sp.personnel_number, sp.deleted, sp.update_version FROM sales_person sp JOIN sales_person_user spu ON sp.id = spu.sales_person_id WHERE spu.user_id = $1",
+            user_id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SalesPersonEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &SalesPersonEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let background_color = entity.background_color.as_ref();
+        let is_paid = entity.is_paid;
+        let inactive = entity.inactive;
+        let personnel_number = entity.personnel_number.as_deref();
+        query!("INSERT INTO sales_person (id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)", entity.id, name, background_color, is_paid, inactive, personnel_number, entity.deleted, entity.version, process)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+    async fn update(
+        &self,
+        entity: &SalesPersonEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let background_color = entity.background_color.as_ref();
+        let is_paid = entity.is_paid;
+        let inactive = entity.inactive;
+        let personnel_number = entity.personnel_number.as_deref();
+        query!("UPDATE sales_person SET name = $1, background_color = $2, is_paid = $3, inactive = $4, personnel_number = $5, deleted = $6, update_version = $7, update_process = $8 WHERE id = $9", name, background_color, is_paid, inactive, personnel_number, entity.deleted, entity.version, process, entity.id)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+
+    async fn assign_to_user(
+        &self,
+        sales_person_id: Uuid,
+        user_id: &str,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!("INSERT INTO sales_person_user (user_id, sales_person_id, update_process) VALUES ($1, $2, $3)", user_id, sales_person_id, process)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+
+    async fn discard_assigned_user(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(
+            "DELETE FROM sales_person_user WHERE sales_person_id = $1",
+            sales_person_id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn get_assigned_user(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<Arc<str>>, DaoError> {
+        Ok(query!(
+            "SELECT user_id FROM sales_person_user WHERE sales_person_id = $1",
+            sales_person_id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .map(|result| result.user_id.into()))
+    }
+
+    async fn get_all_user_assignments(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<HashMap<Uuid, Arc<str>>, DaoError> {
+        let rows = query!(
+            "SELECT sales_person_id, user_id FROM sales_person_user"
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        rows.iter()
+            .map(|row| {
+                let sales_person_id = row.sales_person_id;
+                let user_id: Arc<str> = row.user_id.clone().into();
+                Ok((sales_person_id, user_id))
+            })
+            .collect::<Result<HashMap<_, _>, DaoError>>()
+    }
+
+    async fn find_sales_person_by_user_id(
+        &self,
+        user_id: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonEntity>, DaoError> {
+        Ok(query_as!(
+            SalesPersonDb,
+            "SELECT sp.id, sp.name, sp.background_color, sp.is_paid, sp.inactive, sp.personnel_number, sp.deleted, sp.update_version FROM sales_person sp JOIN sales_person_user spu ON sp.id = spu.sales_person_id WHERE spu.user_id = $1",
+            user_id
+        )
+            .fetch_optional(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?
+            .as_ref()
+            .map(SalesPersonEntity::try_from)
+            .transpose()?
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn sales_person_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SalesPersonDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = SalesPersonEntity {
+            id: Uuid::new_v4(),
+            name: "Anna".into(),
+            background_color: "#ff0000".into(),
+            is_paid: true,
+            deleted: None,
+            inactive: false,
+            personnel_number: Some("0815".into()),
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.all_paid(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.name = "Anna B.".into();
+        entity.is_paid = false;
+        entity.inactive = true;
+        entity.personnel_number = None;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert!(dao.all_paid(tx.clone()).await.unwrap().is_empty());
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert!(dao.all(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn user_assignment_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SalesPersonDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = crate::test_support::create_sales_person(&pool, tx.clone()).await;
+        query!(r#"INSERT INTO "user" (name, update_process) VALUES ('anna', 'test')"#)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .unwrap();
+
+        dao.assign_to_user(sales_person_id, "anna", PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.get_assigned_user(sales_person_id, tx.clone())
+                .await
+                .unwrap(),
+            Some("anna".into())
+        );
+        assert_eq!(
+            dao.find_by_user("anna", tx.clone())
+                .await
+                .unwrap()
+                .map(|entity| entity.id),
+            Some(sales_person_id)
+        );
+
+        dao.discard_assigned_user(sales_person_id, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.get_assigned_user(sales_person_id, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    sales_person_availability::{
+        AvailabilityKind, SalesPersonAvailabilityDao, SalesPersonAvailabilityEntity,
+    },
+    DaoError,
+};
+use shifty_utils::DayOfWeek;
+use sqlx::{query, query_as};
+use time::{Date, PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+struct SalesPersonAvailabilityDb {
+    id: Uuid,
+    sales_person_id: Uuid,
+    day_of_week: i64,
+    time_from: Option<Time>,
+    time_to: Option<Time>,
+    valid_from: Date,
+    valid_to: Option<Date>,
+    kind: String,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&SalesPersonAvailabilityDb> for SalesPersonAvailabilityEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SalesPersonAvailabilityDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: db.id,
+            sales_person_id: db.sales_person_id,
+            day_of_week: DayOfWeek::from_number(db.day_of_week as u8)
+                .ok_or(DaoError::InvalidDayOfWeek(db.day_of_week as u8))?,
+            time_from: db.time_from,
+            time_to: db.time_to,
+            valid_from: db.valid_from,
+            valid_to: db.valid_to,
+            kind: str_to_kind(&db.kind)?,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+fn kind_to_str(kind: AvailabilityKind) -> &'static str {
+    match kind {
+        AvailabilityKind::Preferred => "Preferred",
+        AvailabilityKind::Available => "Available",
+        AvailabilityKind::Unavailable => "Unavailable",
+    }
+}
+
+fn str_to_kind(value: &str) -> Result<AvailabilityKind, DaoError> {
+    match value {
+        "Preferred" => Ok(AvailabilityKind::Preferred),
+        "Available" => Ok(AvailabilityKind::Available),
+        "Unavailable" => Ok(AvailabilityKind::Unavailable),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct SalesPersonAvailabilityDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl SalesPersonAvailabilityDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl SalesPersonAvailabilityDao for SalesPersonAvailabilityDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonAvailabilityEntity>, DaoError> {
+        query_as!(
+            SalesPersonAvailabilityDb,
+            "SELECT id, sales_person_id, day_of_week, time_from, time_to, valid_from, valid_to, kind, created, deleted, update_version FROM sales_person_availability WHERE id = $1 AND deleted IS NULL",
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SalesPersonAvailabilityEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_all_by_sales_person_id(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonAvailabilityEntity]>, DaoError> {
+        query_as!(
+            SalesPersonAvailabilityDb,
+            "SELECT id, sales_person_id, day_of_week, time_from, time_to, valid_from, valid_to, kind, created, deleted, update_version FROM sales_person_availability WHERE sales_person_id = $1 AND deleted IS NULL ORDER BY day_of_week, time_from NULLS FIRST",
+            sales_person_id
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonAvailabilityEntity::try_from)
+        .collect::<Result<Arc<[SalesPersonAvailabilityEntity]>, DaoError>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &SalesPersonAvailabilityEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let day_of_week = entity.day_of_week.to_number() as i64;
+        let kind = kind_to_str(entity.kind);
+        query!(
+            r"INSERT INTO sales_person_availability (id, sales_person_id, day_of_week, time_from, time_to, valid_from, valid_to, kind, created, deleted, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+            entity.id,
+            entity.sales_person_id,
+            day_of_week,
+            entity.time_from,
+            entity.time_to,
+            entity.valid_from,
+            entity.valid_to,
+            kind,
+            entity.created,
+            entity.deleted,
+            entity.version,
+            process,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &SalesPersonAvailabilityEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let day_of_week = entity.day_of_week.to_number() as i64;
+        let kind = kind_to_str(entity.kind);
+        query!(
+            r"UPDATE sales_person_availability SET day_of_week = $1, time_from = $2, time_to = $3, valid_from = $4, valid_to = $5, kind = $6, deleted = $7, update_version = $8, update_process = $9 WHERE id = $10",
+            day_of_week,
+            entity.time_from,
+            entity.time_to,
+            entity.valid_from,
+            entity.valid_to,
+            kind,
+            entity.deleted,
+            entity.version,
+            process,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::{date, time};
+
+    #[test]
+    fn kind_roundtrip() {
+        for kind in [
+            AvailabilityKind::Preferred,
+            AvailabilityKind::Available,
+            AvailabilityKind::Unavailable,
+        ] {
+            assert_eq!(str_to_kind(kind_to_str(kind)).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn unknown_kind() {
+        match str_to_kind("Sometimes") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Sometimes"),
+            other => panic!("expected EnumValueNotFound(\"Sometimes\"), got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn sales_person_availability_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SalesPersonAvailabilityDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = SalesPersonAvailabilityEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            day_of_week: DayOfWeek::Tuesday,
+            time_from: Some(time!(8:00)),
+            time_to: Some(time!(12:00)),
+            valid_from: date!(2026 - 01 - 01),
+            valid_to: None,
+            kind: AvailabilityKind::Preferred,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.time_from = None;
+        entity.time_to = None;
+        entity.valid_to = Some(date!(2026 - 06 - 30));
+        entity.kind = AvailabilityKind::Unavailable;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_all_by_sales_person_id(sales_person_id, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao
+            .find_all_by_sales_person_id(sales_person_id, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::DaoError;
+use sqlx::{query, query_as, PgPool};
+use uuid::Uuid;
+
+use crate::ResultDbErrorExt;
+
+struct ShiftplanIdRow {
+    shiftplan_id: Uuid,
+    permission_level: String,
+}
+
+struct PermissionLevelRow {
+    permission_level: String,
+}
+
+struct SalesPersonIdRow {
+    sales_person_id: Uuid,
+}
+
+pub struct SalesPersonShiftplanDaoImpl {
+    pub _pool: Arc<PgPool>,
+}
+
+impl SalesPersonShiftplanDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl dao::sales_person_shiftplan::SalesPersonShiftplanDao for SalesPersonShiftplanDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn get_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Vec<(Uuid, String)>, DaoError> {
+        let rows = query_as!(
+            ShiftplanIdRow,
+            r"SELECT shiftplan_id, permission_level FROM sales_person_shiftplan WHERE sales_person_id = $1",
+            sales_person_id
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        rows.iter()
+            .map(|row| {
+                let uuid = row.shiftplan_id;
+                Ok((uuid, row.permission_level.clone()))
+            })
+            .collect()
+    }
+
+    async fn get_by_shiftplan(
+        &self,
+        shiftplan_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Vec<Uuid>, DaoError> {
+        let rows = query_as!(
+            SalesPersonIdRow,
+            r"SELECT sales_person_id FROM sales_person_shiftplan WHERE shiftplan_id = $1",
+            shiftplan_id
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(rows.iter().map(|row| row.sales_person_id).collect())
+    }
+
+    async fn set_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        assignments: &[(Uuid, String)],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        // Delete all existing assignments
+        query!(
+            r"DELETE FROM sales_person_shiftplan WHERE sales_person_id = $1",
+            sales_person_id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        // Insert new assignments with permission level
+        for (shiftplan_id, permission_level) in assignments {
+            query!(
+                r"INSERT INTO sales_person_shiftplan (sales_person_id, shiftplan_id, update_process, permission_level) VALUES ($1, $2, $3, $4)",
+                sales_person_id,
+                shiftplan_id,
+                process,
+                permission_level
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+
+        Ok(())
+    }
+
+    async fn has_any_assignment(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<bool, DaoError> {
+        let result = query!(
+            r#"SELECT count(*) as "cnt!" FROM sales_person_shiftplan WHERE sales_person_id = $1"#,
+            sales_person_id
+        )
+        .fetch_one(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(result.cnt > 0)
+    }
+
+    async fn is_assigned(
+        &self,
+        sales_person_id: Uuid,
+        shiftplan_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<bool, DaoError> {
+        let result = query!(
+            r#"SELECT count(*) as "cnt!" FROM sales_person_shiftplan WHERE sales_person_id = $1 AND shiftplan_id = $2"#,
+            sales_person_id,
+            shiftplan_id
+        )
+        .fetch_one(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(result.cnt > 0)
+    }
+
+    async fn get_permission_level(
+        &self,
+        sales_person_id: Uuid,
+        shiftplan_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<String>, DaoError> {
+        let result = query_as!(
+            PermissionLevelRow,
+            r"SELECT permission_level FROM sales_person_shiftplan WHERE sales_person_id = $1 AND shiftplan_id = $2",
+            sales_person_id,
+            shiftplan_id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(result.map(|row| row.permission_level))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, create_shiftplan, setup_pool, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::sales_person_shiftplan::SalesPersonShiftplanDao;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn sales_person_shiftplan_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SalesPersonShiftplanDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let shop = create_shiftplan(&pool, tx.clone()).await;
+        let cafe = create_shiftplan(&pool, tx.clone()).await;
+        assert!(!dao
+            .has_any_assignment(sales_person_id, tx.clone())
+            .await
+            .unwrap());
+
+        dao.set_for_sales_person(
+            sales_person_id,
+            &[(shop, "available".into())],
+            PROCESS,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            dao.get_by_sales_person(sales_person_id, tx.clone())
+                .await
+                .unwrap(),
+            vec![(shop, "available".to_string())]
+        );
+        assert_eq!(
+            dao.get_by_shiftplan(shop, tx.clone()).await.unwrap(),
+            vec![sales_person_id]
+        );
+        assert!(dao
+            .is_assigned(sales_person_id, shop, tx.clone())
+            .await
+            .unwrap());
+
+        dao.set_for_sales_person(
+            sales_person_id,
+            &[(cafe, "planner_only".into())],
+            PROCESS,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        assert!(!dao
+            .is_assigned(sales_person_id, shop, tx.clone())
+            .await
+            .unwrap());
+        assert_eq!(
+            dao.get_permission_level(sales_person_id, cafe, tx.clone())
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("planner_only")
+        );
+
+        dao.set_for_sales_person(sales_person_id, &[], PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert!(!dao
+            .has_any_assignment(sales_person_id, tx.clone())
+            .await
+            .unwrap());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::{
+    sales_person_unavailable::{SalesPersonUnavailableDao, SalesPersonUnavailableEntity},
+    DaoError,
+};
+use shifty_utils::DayOfWeek;
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+struct SalesPersonUnavailableDb {
+    id: Uuid,
+    sales_person_id: Uuid,
+    year: i64,
+    calendar_week: i64,
+    day_of_week: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&SalesPersonUnavailableDb> for SalesPersonUnavailableEntity {
+    type Error = DaoError;
+
+    fn try_from(entity: &SalesPersonUnavailableDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: entity.id,
+            sales_person_id: entity.sales_person_id,
+            year: entity.year as u32,
+            calendar_week: entity.calendar_week as u8,
+            day_of_week: DayOfWeek::from_number(entity.day_of_week as u8)
+                .ok_or(DaoError::InvalidDayOfWeek(entity.day_of_week as u8))?,
+            created: entity.created,
+            deleted: entity.deleted,
+            version: entity.update_version,
+        })
+    }
+}
+
+pub struct SalesPersonUnavailableDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl SalesPersonUnavailableDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl SalesPersonUnavailableDao for SalesPersonUnavailableDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonUnavailableEntity>, DaoError> {
+        Ok(query_as!(
+            SalesPersonUnavailableDb,
+            "SELECT id, sales_person_id, year, calendar_week, day_of_week, created, deleted, update_version FROM sales_person_unavailable WHERE id = $1",
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SalesPersonUnavailableEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_all_by_sales_person_id(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonUnavailableEntity]>, DaoError> {
+        query_as!(
+            SalesPersonUnavailableDb,
+            "SELECT id, sales_person_id, year, calendar_week, day_of_week, created, deleted, update_version FROM sales_person_unavailable WHERE sales_person_id = $1 AND deleted IS NULL", sales_person_id 
+        ).fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonUnavailableEntity::try_from)
+        .collect::<Result<Arc<[SalesPersonUnavailableEntity]>, DaoError>>()
+    }
+    async fn find_by_week_and_sales_person_id(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonUnavailableEntity]>, DaoError> {
+        query_as!(
+            SalesPersonUnavailableDb,
+            "SELECT id, sales_person_id, year, calendar_week, day_of_week, created, deleted, update_version FROM sales_person_unavailable WHERE sales_person_id = $1 AND year = $2 AND calendar_week = $3 AND deleted IS NULL",
+            sales_person_id,
+            year as i64,
+            calendar_week as i64
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonUnavailableEntity::try_from)
+        .collect::<Result<Arc<[SalesPersonUnavailableEntity]>, DaoError>>()
+    }
+
+    async fn find_by_week(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonUnavailableEntity]>, DaoError> {
+        query_as!(
+            SalesPersonUnavailableDb,
+            "SELECT id, sales_person_id, year, calendar_week, day_of_week, created, deleted, update_version FROM sales_person_unavailable WHERE year = $1 AND calendar_week = $2 AND deleted IS NULL",
+            year as i64,
+            calendar_week as i64
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonUnavailableEntity::try_from)
+        .collect::<Result<Arc<[SalesPersonUnavailableEntity]>, DaoError>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &SalesPersonUnavailableEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let day_of_week = entity.day_of_week.to_number() as i64;
+        query!(
+            r"INSERT INTO sales_person_unavailable (id, sales_person_id, year, calendar_week, day_of_week, created, deleted, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            entity.id,
+            entity.sales_person_id,
+            entity.year as i64,
+            entity.calendar_week as i64,
+            day_of_week,
+            entity.created,
+            entity.deleted,
+            entity.version,
+            process,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &SalesPersonUnavailableEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        query!(
+            r"UPDATE sales_person_unavailable SET deleted = $1, update_version = $2, update_process = $3 WHERE id = $4",
+            entity.deleted,
+            entity.version,
+            process,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use shifty_utils::DayOfWeek;
+
+    #[tokio::test]
+    async fn sales_person_unavailable_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SalesPersonUnavailableDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = SalesPersonUnavailableEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            year: 2026,
+            calendar_week: 17,
+            day_of_week: DayOfWeek::Wednesday,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_week_and_sales_person_id(sales_person_id, 2026, 17, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert!(dao
+            .find_by_week(2026, 17, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(dao
+            .find_all_by_sales_person_id(sales_person_id, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
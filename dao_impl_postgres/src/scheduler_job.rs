@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    scheduler_job::{SchedulerJobDao, SchedulerJobEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct SchedulerJobDb {
+    name: String,
+    cron_schedule: String,
+    enabled: i64,
+    last_run_at: Option<PrimitiveDateTime>,
+    last_duration_ms: Option<i64>,
+    last_error_at: Option<PrimitiveDateTime>,
+    last_error_message: Option<String>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&SchedulerJobDb> for SchedulerJobEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SchedulerJobDb) -> Result<Self, Self::Error> {
+        Ok(SchedulerJobEntity {
+            name: db.name.as_str().into(),
+            cron_schedule: db.cron_schedule.as_str().into(),
+            enabled: db.enabled != 0,
+            last_run_at: db.last_run_at,
+            last_duration_ms: db.last_duration_ms.map(|ms| ms as u64),
+            last_error_at: db.last_error_at,
+            last_error_message: db.last_error_message.as_deref().map(Arc::from),
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct SchedulerJobDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl SchedulerJobDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SchedulerJobDao for SchedulerJobDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[SchedulerJobEntity]>, DaoError> {
+        query_as!(
+            SchedulerJobDb,
+            r#"SELECT name, cron_schedule, enabled, last_run_at, last_duration_ms,
+                      last_error_at, last_error_message, update_version
+               FROM scheduler_job
+               ORDER BY name"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SchedulerJobEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<SchedulerJobEntity>, DaoError> {
+        query_as!(
+            SchedulerJobDb,
+            r#"SELECT name, cron_schedule, enabled, last_run_at, last_duration_ms,
+                      last_error_at, last_error_message, update_version
+               FROM scheduler_job
+               WHERE name = $1"#,
+            name,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SchedulerJobEntity::try_from)
+        .transpose()
+    }
+
+    async fn update(
+        &self,
+        entity: &SchedulerJobEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let enabled = entity.enabled as i64;
+        query!(
+            r#"UPDATE scheduler_job
+               SET cron_schedule = $1, enabled = $2, update_process = $3, update_version = $4
+               WHERE name = $5"#,
+            cron_schedule,
+            enabled,
+            process,
+            entity.version,
+            name,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn record_run(
+        &self,
+        name: &str,
+        started_at: PrimitiveDateTime,
+        duration_ms: u64,
+        error: Option<Arc<str>>,
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let error_at = error.as_ref().map(|_| started_at);
+        let error = error.as_deref();
+        let duration_ms = duration_ms as i64;
+        query!(
+            r#"UPDATE scheduler_job
+               SET last_run_at = $1, last_duration_ms = $2, last_error_at = $3,
+                   last_error_message = $4, update_process = $5, update_version = $6
+               WHERE name = $7"#,
+            started_at,
+            duration_ms,
+            error_at,
+            error,
+            process,
+            version,
+            name,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn scheduler_job_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SchedulerJobDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let seeded = dao
+            .find_by_name("carryover", tx.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(seeded.enabled);
+        let mut entity = SchedulerJobEntity {
+            cron_schedule: "0 30 * * * *".into(),
+            enabled: false,
+            version: Uuid::new_v4(),
+            ..seeded
+        };
+
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_name("carryover", tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        let failed_version = Uuid::new_v4();
+        dao.record_run(
+            "carryover",
+            datetime!(2026-10-01 08:30),
+            250,
+            Some("Datenbank gesperrt".into()),
+            PROCESS,
+            failed_version,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        entity.last_run_at = Some(datetime!(2026-10-01 08:30));
+        entity.last_duration_ms = Some(250);
+        entity.last_error_at = Some(datetime!(2026-10-01 08:30));
+        entity.last_error_message = Some("Datenbank gesperrt".into());
+        entity.version = failed_version;
+        assert_eq!(
+            dao.find_by_name("carryover", tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        let success_version = Uuid::new_v4();
+        dao.record_run(
+            "carryover",
+            datetime!(2026-10-01 09:30),
+            120,
+            None,
+            PROCESS,
+            success_version,
+            tx.clone(),
+        )
+        .await
+        .unwrap();
+        entity.last_run_at = Some(datetime!(2026-10-01 09:30));
+        entity.last_duration_ms = Some(120);
+        entity.last_error_at = None;
+        entity.last_error_message = None;
+        entity.version = success_version;
+        assert!(dao.all(tx.clone()).await.unwrap().contains(&entity));
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    session::{SessionDao, SessionEntity},
+    DaoError,
+};
+use sqlx::{query, query_as, PgPool};
+
+use crate::ResultDbErrorExt;
+
+struct SessionDb {
+    id: String,
+    user_id: String,
+    expires: i64,
+    created: i64,
+    impersonate_user_id: Option<String>,
+}
+
+pub struct SessionDaoImpl {
+    pool: Arc<PgPool>,
+}
+
+impl SessionDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionDao for SessionDaoImpl {
+    async fn create(&self, entity: &SessionEntity) -> Result<(), DaoError> {
+        let id = entity.id.to_string();
+        let user_id = entity.user_id.to_string();
+        let expires = entity.expires;
+        let created = entity.created;
+        query!(
+            r"INSERT INTO session (id, user_id, expires, created) VALUES ($1, $2, $3, $4)",
+            id,
+            user_id,
+            expires,
+            created,
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<SessionEntity>, DaoError> {
+        let id = id.to_string();
+        let session = query_as!(
+            SessionDb,
+            r"SELECT id, user_id, expires, created, impersonate_user_id FROM session WHERE id = $1",
+            id
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(session.map(|session| SessionEntity {
+            id: Arc::from(session.id),
+            user_id: Arc::from(session.user_id),
+            expires: session.expires,
+            created: session.created,
+            impersonate_user_id: session.impersonate_user_id.map(Arc::from),
+        }))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), DaoError> {
+        query!(r"DELETE FROM session WHERE id = $1", id,)
+            .execute(self.pool.as_ref())
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_impersonate(
+        &self,
+        session_id: &str,
+        impersonate_user_id: Option<Arc<str>>,
+    ) -> Result<(), DaoError> {
+        let session_id = session_id.to_string();
+        let impersonate = impersonate_user_id.map(|s| s.to_string());
+        query!(
+            r"UPDATE session SET impersonate_user_id = $1 WHERE id = $2",
+            impersonate,
+            session_id,
+        )
+        .execute(self.pool.as_ref())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, PROCESS};
+    use crate::PermissionDaoImpl;
+    use dao::{PermissionDao, UserEntity};
+
+    #[tokio::test]
+    async fn session_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        PermissionDaoImpl::new(pool.clone())
+            .create_user(
+                &UserEntity {
+                    name: "anna".into(),
+                },
+                PROCESS,
+            )
+            .await
+            .unwrap();
+        let dao = SessionDaoImpl::new(pool.clone());
+        let entity = SessionEntity {
+            id: "session-1".into(),
+            user_id: "anna".into(),
+            expires: 1_790_000_000,
+            created: 1_780_000_000,
+            impersonate_user_id: None,
+        };
+
+        dao.create(&entity).await.unwrap();
+        assert_eq!(
+            dao.find_by_id("session-1").await.unwrap(),
+            Some(entity.clone())
+        );
+
+        dao.update_impersonate("session-1", Some("bert".into()))
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_by_id("session-1").await.unwrap(),
+            Some(SessionEntity {
+                impersonate_user_id: Some("bert".into()),
+                ..entity
+            })
+        );
+
+        dao.delete("session-1").await.unwrap();
+        assert_eq!(dao.find_by_id("session-1").await.unwrap(), None);
+    }
+}
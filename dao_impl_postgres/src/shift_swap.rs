@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    shift_swap::{ShiftSwapRequestDao, ShiftSwapRequestEntity, ShiftSwapState},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct ShiftSwapRequestDb {
+    id: Uuid,
+    booking_id: Uuid,
+    offering_sales_person_id: Uuid,
+    accepting_sales_person_id: Option<Uuid>,
+    new_booking_id: Option<Uuid>,
+    year: i64,
+    calendar_week: i64,
+    state: String,
+    message: String,
+    reject_reason: Option<String>,
+    created: PrimitiveDateTime,
+    accepted: Option<PrimitiveDateTime>,
+    decided: Option<PrimitiveDateTime>,
+    decided_by: Option<String>,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&ShiftSwapRequestDb> for ShiftSwapRequestEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &ShiftSwapRequestDb) -> Result<Self, Self::Error> {
+        Ok(ShiftSwapRequestEntity {
+            id: db.id,
+            booking_id: db.booking_id,
+            offering_sales_person_id: db.offering_sales_person_id,
+            accepting_sales_person_id: db.accepting_sales_person_id,
+            new_booking_id: db.new_booking_id,
+            year: db.year as u32,
+            calendar_week: db.calendar_week as u8,
+            state: str_to_state(&db.state)?,
+            message: db.message.as_str().into(),
+            reject_reason: db.reject_reason.as_deref().map(Arc::from),
+            created: db.created,
+            accepted: db.accepted,
+            decided: db.decided,
+            decided_by: db.decided_by.as_deref().map(Arc::from),
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+fn state_to_str(state: ShiftSwapState) -> &'static str {
+    match state {
+        ShiftSwapState::Open => "Open",
+        ShiftSwapState::Accepted => "Accepted",
+        ShiftSwapState::Approved => "Approved",
+        ShiftSwapState::Rejected => "Rejected",
+        ShiftSwapState::Cancelled => "Cancelled",
+    }
+}
+
+fn str_to_state(value: &str) -> Result<ShiftSwapState, DaoError> {
+    match value {
+        "Open" => Ok(ShiftSwapState::Open),
+        "Accepted" => Ok(ShiftSwapState::Accepted),
+        "Approved" => Ok(ShiftSwapState::Approved),
+        "Rejected" => Ok(ShiftSwapState::Rejected),
+        "Cancelled" => Ok(ShiftSwapState::Cancelled),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct ShiftSwapRequestDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl ShiftSwapRequestDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShiftSwapRequestDao for ShiftSwapRequestDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftSwapRequestEntity>, DaoError> {
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(ShiftSwapRequestEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_pending(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftSwapRequestEntity]>, DaoError> {
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE state IN ('Open', 'Accepted') AND deleted IS NULL
+               ORDER BY year, calendar_week, created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftSwapRequestEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_pending_by_booking_id(
+        &self,
+        booking_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftSwapRequestEntity>, DaoError> {
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE booking_id = $1 AND state IN ('Open', 'Accepted') AND deleted IS NULL"#,
+            booking_id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(ShiftSwapRequestEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftSwapRequestEntity]>, DaoError> {
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE (offering_sales_person_id = $1 OR accepting_sales_person_id = $2)
+                 AND deleted IS NULL
+               ORDER BY year, calendar_week, created"#,
+            sales_person_id,
+            sales_person_id,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftSwapRequestEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &ShiftSwapRequestEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let state_str = state_to_str(entity.state);
+        let message = entity.message.as_ref();
+        let reject_reason = entity.reject_reason.as_deref();
+        let decided_by = entity.decided_by.as_deref();
+
+        query!(
+            r#"INSERT INTO shift_swap_request
+                 (id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                  new_booking_id, year, calendar_week, state, message, reject_reason,
+                  created, accepted, decided, decided_by, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)"#,
+            entity.id,
+            entity.booking_id,
+            entity.offering_sales_person_id,
+            entity.accepting_sales_person_id,
+            entity.new_booking_id,
+            entity.year as i64,
+            entity.calendar_week as i64,
+            state_str,
+            message,
+            reject_reason,
+            entity.created,
+            entity.accepted,
+            entity.decided,
+            decided_by,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &ShiftSwapRequestEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let state_str = state_to_str(entity.state);
+        let reject_reason = entity.reject_reason.as_deref();
+        let decided_by = entity.decided_by.as_deref();
+
+        query!(
+            r#"UPDATE shift_swap_request
+               SET accepting_sales_person_id = $1, new_booking_id = $2, state = $3,
+                   reject_reason = $4, accepted = $5, decided = $6, decided_by = $7,
+                   deleted = $8, update_process = $9, update_version = $10
+               WHERE id = $11"#,
+            entity.accepting_sales_person_id,
+            entity.new_booking_id,
+            state_str,
+            reject_reason,
+            entity.accepted,
+            entity.decided,
+            decided_by,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_booking, create_sales_person, create_shiftplan, create_slot, setup_pool, timestamp,
+        PROCESS,
+    };
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[test]
+    fn state_roundtrip() {
+        for state in [
+            ShiftSwapState::Open,
+            ShiftSwapState::Accepted,
+            ShiftSwapState::Approved,
+            ShiftSwapState::Rejected,
+            ShiftSwapState::Cancelled,
+        ] {
+            assert_eq!(str_to_state(state_to_str(state)).unwrap(), state);
+        }
+    }
+
+    #[test]
+    fn unknown_state() {
+        match str_to_state("Bogus") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Bogus"),
+            other => panic!("expected EnumValueNotFound(\"Bogus\"), got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn shift_swap_request_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ShiftSwapRequestDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let offering = create_sales_person(&pool, tx.clone()).await;
+        let accepting = create_sales_person(&pool, tx.clone()).await;
+        let shiftplan_id = create_shiftplan(&pool, tx.clone()).await;
+        let slot_id = create_slot(&pool, shiftplan_id, tx.clone()).await;
+        let booking_id = create_booking(&pool, offering, slot_id, tx.clone()).await;
+        let mut entity = ShiftSwapRequestEntity {
+            id: Uuid::new_v4(),
+            booking_id,
+            offering_sales_person_id: offering,
+            accepting_sales_person_id: None,
+            new_booking_id: None,
+            year: 2026,
+            calendar_week: 17,
+            state: ShiftSwapState::Open,
+            message: "Arzttermin".into(),
+            reject_reason: None,
+            created: timestamp(),
+            accepted: None,
+            decided: None,
+            decided_by: None,
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_pending_by_booking_id(booking_id, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.accepting_sales_person_id = Some(accepting);
+        entity.state = ShiftSwapState::Accepted;
+        entity.accepted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person(accepting, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+        assert_eq!(
+            dao.find_pending(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.state = ShiftSwapState::Rejected;
+        entity.reject_reason = Some("Zu kurzfristig".into());
+        entity.decided = Some(timestamp());
+        entity.decided_by = Some("anna".into());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert!(dao.find_pending(tx.clone()).await.unwrap().is_empty());
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao
+            .find_by_sales_person(offering, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
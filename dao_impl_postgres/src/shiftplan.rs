@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{shiftplan::ShiftplanEntity, DaoError};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::ResultDbErrorExt;
+
+pub struct ShiftplanDb {
+    pub id: Uuid,
+    pub name: String,
+    pub is_planning: i64,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub update_version: Uuid,
+}
+
+impl TryFrom<&ShiftplanDb> for ShiftplanEntity {
+    type Error = DaoError;
+    fn try_from(db: &ShiftplanDb) -> Result<Self, DaoError> {
+        Ok(Self {
+            id: db.id,
+            name: db.name.as_str().into(),
+            is_planning: db.is_planning != 0,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct ShiftplanDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl ShiftplanDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl dao::shiftplan::ShiftplanDao for ShiftplanDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[ShiftplanEntity]>, DaoError> {
+        Ok(query_as!(
+            ShiftplanDb,
+            r"SELECT id, name, is_planning, deleted, update_version FROM shiftplan WHERE deleted IS NULL"
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftplanEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftplanEntity>, DaoError> {
+        let result = query_as!(
+            ShiftplanDb,
+            r"SELECT id, name, is_planning, deleted, update_version FROM shiftplan WHERE id = $1 AND deleted IS NULL",
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        result
+            .as_ref()
+            .map(ShiftplanEntity::try_from)
+            .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &ShiftplanEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let is_planning = entity.is_planning as i32;
+        query!(
+            "INSERT INTO shiftplan (id, name, is_planning, deleted, update_process, update_version) VALUES ($1, $2, $3, NULL, $4, $5)",
+            entity.id,
+            name,
+            is_planning as i64,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &ShiftplanEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let is_planning = entity.is_planning as i32;
+        query!(
+            "UPDATE shiftplan SET name = $1, is_planning = $2, deleted = $3, update_process = $4, update_version = $5 WHERE id = $6",
+            name,
+            is_planning as i64,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::shiftplan::ShiftplanDao;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn shiftplan_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ShiftplanDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = ShiftplanEntity {
+            id: Uuid::new_v4(),
+            name: "Laden".into(),
+            is_planning: false,
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.name = "Planung".into();
+        entity.is_planning = true;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        // Die Migrationen legen bereits den Schichtplan "main" an.
+        assert!(dao.all(tx.clone()).await.unwrap().contains(&entity));
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(!dao
+            .all(tx.clone())
+            .await
+            .unwrap()
+            .iter()
+            .any(|shiftplan| shiftplan.id == entity.id));
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+use std::sync::Arc;
+
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::{
+    shiftplan_report::{ShiftplanReportDao, ShiftplanReportRawRow},
+    DaoError,
+};
+use shifty_utils::DayOfWeek;
+use sqlx::query_as;
+use time::Time;
+use uuid::Uuid;
+
+/// DB-Row-Struct für die drei raw-row Queries (Phase 51 Chain D, D-51-08).
+///
+/// Der SQL-Query liefert je Booking eine Row (kein `SUM`, kein `GROUP BY`).
+/// Der Service-Layer aggregiert + clippt + gatet.
+pub struct ShiftplanReportRawRowDb {
+    pub sales_person_id: Uuid,
+    pub booking_id: Uuid,
+    pub year: i64,
+    pub calendar_week: i64,
+    pub day_of_week: i64,
+    pub time_from: Time,
+    pub time_to: Time,
+}
+
+impl TryFrom<&ShiftplanReportRawRowDb> for ShiftplanReportRawRow {
+    type Error = DaoError;
+    fn try_from(entity: &ShiftplanReportRawRowDb) -> Result<Self, DaoError> {
+        Ok(Self {
+            sales_person_id: entity.sales_person_id,
+            booking_id: entity.booking_id,
+            year: entity.year as u32,
+            calendar_week: entity.calendar_week as u8,
+            day_of_week: DayOfWeek::from_number(entity.day_of_week as u8)
+                .ok_or(DaoError::InvalidDayOfWeek(entity.day_of_week as u8))?,
+            time_from: entity.time_from,
+            time_to: entity.time_to,
+        })
+    }
+}
+
+pub struct ShiftplanReportDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl ShiftplanReportDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl ShiftplanReportDao for ShiftplanReportDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn extract_raw_shiftplan_report(
+        &self,
+        sales_person_id: Uuid,
+        from_year: u32,
+        from_week: u8,
+        to_year: u32,
+        to_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftplanReportRawRow]>, DaoError> {
+        Ok(query_as!(
+            ShiftplanReportRawRowDb,
+            r#"
+                SELECT
+                  sales_person.id as sales_person_id,
+                  booking.id as booking_id,
+                  booking.year,
+                  booking.calendar_week,
+                  slot.day_of_week,
+                  slot.time_from,
+                  slot.time_to
+                FROM slot
+                INNER JOIN booking ON (booking.slot_id = slot.id AND booking.deleted IS NULL)
+                INNER JOIN sales_person ON booking.sales_person_id = sales_person.id
+                LEFT JOIN shiftplan ON slot.shiftplan_id = shiftplan.id
+                WHERE sales_person.id = $1
+                  AND booking.year * 100 + booking.calendar_week >= $2 * 100 + $3
+                  AND booking.year * 100 + booking.calendar_week <= $4 * 100 + $5
+                  AND (shiftplan.is_planning = 0 OR shiftplan.is_planning IS NULL)
+                        "#,
+            sales_person_id,
+            from_year as i32,
+            from_week as i32,
+            to_year as i32,
+            to_week as i32,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftplanReportRawRow::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn extract_raw_quick_shiftplan_report(
+        &self,
+        year: u32,
+        until_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftplanReportRawRow]>, DaoError> {
+        Ok(query_as!(
+            ShiftplanReportRawRowDb,
+            r#"
+                SELECT
+                  sales_person.id as sales_person_id,
+                  booking.id as booking_id,
+                  booking.year,
+                  booking.calendar_week,
+                  slot.day_of_week,
+                  slot.time_from,
+                  slot.time_to
+                FROM slot
+                INNER JOIN booking ON (booking.slot_id = slot.id AND booking.deleted IS NULL)
+                INNER JOIN sales_person ON booking.sales_person_id = sales_person.id
+                LEFT JOIN shiftplan ON slot.shiftplan_id = shiftplan.id
+                WHERE booking.year = $1
+                  AND booking.calendar_week <= $2
+                  AND (shiftplan.is_planning = 0 OR shiftplan.is_planning IS NULL)
+                        "#,
+            year as i64,
+            until_week as i64
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftplanReportRawRow::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn extract_raw_shiftplan_report_for_week(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftplanReportRawRow]>, DaoError> {
+        Ok(query_as!(
+            ShiftplanReportRawRowDb,
+            r#"
+                SELECT
+                  sales_person.id as sales_person_id,
+                  booking.id as booking_id,
+                  booking.year,
+                  booking.calendar_week,
+                  slot.day_of_week,
+                  slot.time_from,
+                  slot.time_to
+                FROM slot
+                INNER JOIN booking ON (booking.slot_id = slot.id AND booking.deleted IS NULL)
+                INNER JOIN sales_person ON booking.sales_person_id = sales_person.id
+                LEFT JOIN shiftplan ON slot.shiftplan_id = shiftplan.id
+                WHERE booking.year = $1
+                  AND booking.calendar_week = $2
+                  AND (shiftplan.is_planning = 0 OR shiftplan.is_planning IS NULL)
+                        "#,
+            year as i64,
+            calendar_week as i64
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftplanReportRawRow::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn extract_raw_shiftplan_report_for_iso_year(
+        &self,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftplanReportRawRow]>, DaoError> {
+        // Phase 52 (WOP-01 / Follow-up #3): ISO-Wochenjahr-Batch analog zu
+        // `_for_week`, nur ohne den `calendar_week = ?`-Filter. `booking.year`
+        // ist bereits das ISO-Wochenjahr (Booking-Create-Pfad benutzt
+        // `ShiftyDate::from_date`); der Filter `WHERE booking.year = ?`
+        // matched daher exakt die ISO-Wochen von `year`. `calendar_week`
+        // bleibt in der Projektion, damit der Service-Layer bzw. Konsumenten
+        // pro Woche filtern und aggregieren können.
+        Ok(query_as!(
+            ShiftplanReportRawRowDb,
+            r#"
+                SELECT
+                  sales_person.id as sales_person_id,
+                  booking.id as booking_id,
+                  booking.year,
+                  booking.calendar_week,
+                  slot.day_of_week,
+                  slot.time_from,
+                  slot.time_to
+                FROM slot
+                INNER JOIN booking ON (booking.slot_id = slot.id AND booking.deleted IS NULL)
+                INNER JOIN sales_person ON booking.sales_person_id = sales_person.id
+                LEFT JOIN shiftplan ON slot.shiftplan_id = shiftplan.id
+                WHERE booking.year = $1
+                  AND (shiftplan.is_planning = 0 OR shiftplan.is_planning IS NULL)
+                ORDER BY booking.calendar_week, slot.day_of_week
+                        "#,
+            year as i64,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftplanReportRawRow::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::booking::BookingDaoImpl;
+    use crate::test_support::{
+        create_booking, create_sales_person, create_shiftplan, create_slot, setup_pool, timestamp,
+        PROCESS,
+    };
+    use crate::TransactionDaoImpl;
+    use dao::booking::BookingDao;
+    use dao::TransactionDao;
+    use time::macros::time;
+
+    #[tokio::test]
+    async fn report_rows_follow_bookings() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = ShiftplanReportDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let shiftplan_id = create_shiftplan(&pool, tx.clone()).await;
+        let slot_id = create_slot(&pool, shiftplan_id, tx.clone()).await;
+        let booking_id = create_booking(&pool, sales_person_id, slot_id, tx.clone()).await;
+        let row = ShiftplanReportRawRow {
+            sales_person_id,
+            booking_id,
+            year: 2026,
+            calendar_week: 17,
+            day_of_week: DayOfWeek::Monday,
+            time_from: time!(9:00),
+            time_to: time!(13:00),
+        };
+
+        assert_eq!(
+            dao.extract_raw_shiftplan_report(sales_person_id, 2026, 1, 2026, 52, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            std::slice::from_ref(&row)
+        );
+        assert_eq!(
+            dao.extract_raw_shiftplan_report_for_week(2026, 17, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            std::slice::from_ref(&row)
+        );
+        assert_eq!(
+            dao.extract_raw_quick_shiftplan_report(2026, 17, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            std::slice::from_ref(&row)
+        );
+
+        let booking_dao = BookingDaoImpl::new(pool.clone());
+        let mut booking = booking_dao
+            .find_by_id(booking_id, tx.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        booking.deleted = Some(timestamp());
+        booking.version = Uuid::new_v4();
+        booking_dao
+            .update(&booking, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert!(dao
+            .extract_raw_shiftplan_report_for_iso_year(2026, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,280 @@
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use dao::{slot::SlotEntity, DaoError};
+use shifty_utils::DayOfWeek;
+use sqlx::{query, PgPool};
+use time::Date;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+pub struct SlotDaoImpl {
+    _pool: Arc<PgPool>,
+}
+impl SlotDaoImpl {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl dao::slot::SlotDao for SlotDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn get_slots(&self, tx: Self::Transaction) -> Result<Arc<[SlotEntity]>, DaoError> {
+        let result = query!(r"SELECT id, day_of_week, time_from, time_to, min_resources, max_paid_employees, valid_from, valid_to, deleted, update_version, shiftplan_id FROM slot WHERE deleted IS NULL")
+            .fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))?;
+        result
+            .iter()
+            .map(|row| {
+                Ok(SlotEntity {
+                    id: row.id,
+                    day_of_week: DayOfWeek::from_number(row.day_of_week as u8)
+                        .ok_or(DaoError::InvalidDayOfWeek(row.day_of_week as u8))?,
+                    from: row.time_from,
+                    to: row.time_to,
+                    min_resources: row.min_resources as u8,
+                    max_paid_employees: row.max_paid_employees.map(|n| n as u8),
+                    valid_from: row.valid_from,
+                    valid_to: row.valid_to,
+                    deleted: row.deleted,
+                    version: row.update_version,
+                    shiftplan_id: row.shiftplan_id,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_slot(
+        &self,
+        id: &Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<SlotEntity>, DaoError> {
+        let result = query!(r"SELECT id, day_of_week, time_from, time_to, min_resources, max_paid_employees, valid_from, valid_to, deleted, update_version, shiftplan_id FROM slot WHERE id = $1", id)
+            .fetch_optional(tx.tx.lock().await.as_mut())
+            .await
+            .map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))?;
+        result
+            .map(|row| {
+                Ok(SlotEntity {
+                    id: row.id,
+                    day_of_week: DayOfWeek::from_number(row.day_of_week as u8)
+                        .ok_or(DaoError::InvalidDayOfWeek(row.day_of_week as u8))?,
+                    from: row.time_from,
+                    to: row.time_to,
+                    min_resources: row.min_resources as u8,
+                    max_paid_employees: row.max_paid_employees.map(|n| n as u8),
+                    valid_from: row.valid_from,
+                    valid_to: row.valid_to,
+                    deleted: row.deleted,
+                    version: row.update_version,
+                    shiftplan_id: row.shiftplan_id,
+                })
+            })
+            .transpose()
+    }
+
+    async fn get_slots_for_week(
+        &self,
+        year: u32,
+        week: u8,
+        shiftplan_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotEntity]>, DaoError> {
+        let monday = Date::from_iso_week_date(year as i32, week, time::Weekday::Monday)?;
+        let sunday = Date::from_iso_week_date(year as i32, week, time::Weekday::Sunday)?;
+        let result = query!(r"
+                SELECT id, day_of_week, time_from, time_to, min_resources, max_paid_employees, valid_from, valid_to, deleted, update_version, shiftplan_id
+                FROM slot
+                WHERE deleted IS NULL
+                AND valid_from <= $1
+                AND (valid_to IS NULL OR valid_to >= $2)
+                AND shiftplan_id = $3", sunday, monday, shiftplan_id)
+            .fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))?;
+        result
+            .iter()
+            .map(|row| {
+                Ok(SlotEntity {
+                    id: row.id,
+                    day_of_week: DayOfWeek::from_number(row.day_of_week as u8)
+                        .ok_or(DaoError::InvalidDayOfWeek(row.day_of_week as u8))?,
+                    from: row.time_from,
+                    to: row.time_to,
+                    min_resources: row.min_resources as u8,
+                    max_paid_employees: row.max_paid_employees.map(|n| n as u8),
+                    valid_from: row.valid_from,
+                    valid_to: row.valid_to,
+                    deleted: row.deleted,
+                    version: row.update_version,
+                    shiftplan_id: row.shiftplan_id,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_slots_for_week_all_plans(
+        &self,
+        year: u32,
+        week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotEntity]>, DaoError> {
+        let monday = Date::from_iso_week_date(year as i32, week, time::Weekday::Monday)?;
+        let sunday = Date::from_iso_week_date(year as i32, week, time::Weekday::Sunday)?;
+        let result = query!(r"
+                SELECT slot.id, slot.day_of_week, slot.time_from, slot.time_to, slot.min_resources, slot.max_paid_employees, slot.valid_from, slot.valid_to, slot.deleted, slot.update_version, slot.shiftplan_id
+                FROM slot
+                LEFT JOIN shiftplan ON slot.shiftplan_id = shiftplan.id
+                WHERE slot.deleted IS NULL
+                AND slot.valid_from <= $1
+                AND (slot.valid_to IS NULL OR slot.valid_to >= $2)
+                AND (shiftplan.is_planning = 0 OR shiftplan.is_planning IS NULL)", sunday, monday)
+            .fetch_all(tx.tx.lock().await.as_mut())
+            .await
+            .map_err(|err| DaoError::DatabaseQueryError(Box::new(err)))?;
+        result
+            .iter()
+            .map(|row| {
+                Ok(SlotEntity {
+                    id: row.id,
+                    day_of_week: DayOfWeek::from_number(row.day_of_week as u8)
+                        .ok_or(DaoError::InvalidDayOfWeek(row.day_of_week as u8))?,
+                    from: row.time_from,
+                    to: row.time_to,
+                    min_resources: row.min_resources as u8,
+                    max_paid_employees: row.max_paid_employees.map(|n| n as u8),
+                    valid_from: row.valid_from,
+                    valid_to: row.valid_to,
+                    deleted: row.deleted,
+                    version: row.update_version,
+                    shiftplan_id: row.shiftplan_id,
+                })
+            })
+            .collect()
+    }
+
+    async fn create_slot(
+        &self,
+        slot: &SlotEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let day_of_week = slot.day_of_week.to_number();
+        let min_resources = slot.min_resources;
+        let max_paid_employees = slot.max_paid_employees;
+        query!("INSERT INTO slot (id, day_of_week, time_from, time_to, valid_from, valid_to, deleted, update_version, update_process, min_resources, max_paid_employees, shiftplan_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+            slot.id,
+            day_of_week as i64,
+            slot.from,
+            slot.to,
+            slot.valid_from,
+            slot.valid_to,
+            slot.deleted,
+            slot.version,
+            process,
+            min_resources as i64,
+            max_paid_employees.map(|value| value as i64),
+            slot.shiftplan_id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_slot(
+        &self,
+        slot: &SlotEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let max_paid_employees = slot.max_paid_employees;
+        query!("UPDATE slot SET valid_to = $1, deleted = $2, max_paid_employees = $3, update_version = $4, update_process = $5 WHERE id = $6",
+            slot.valid_to,
+            slot.deleted,
+            max_paid_employees.map(|value| value as i64),
+            slot.version,
+            process,
+            slot.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_shiftplan, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::slot::SlotDao;
+    use dao::TransactionDao;
+    use time::macros::{date, time};
+
+    #[tokio::test]
+    async fn slot_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = SlotDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let shiftplan_id = create_shiftplan(&pool, tx.clone()).await;
+        let mut entity = SlotEntity {
+            id: Uuid::new_v4(),
+            day_of_week: DayOfWeek::Tuesday,
+            from: time!(9:00),
+            to: time!(13:30),
+            min_resources: 2,
+            max_paid_employees: None,
+            valid_from: date!(2026 - 01 - 01),
+            valid_to: None,
+            deleted: None,
+            version: Uuid::new_v4(),
+            shiftplan_id: Some(shiftplan_id),
+        };
+
+        dao.create_slot(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.get_slot(&entity.id, tx.clone()).await.unwrap().as_ref(),
+            Some(&entity)
+        );
+        assert_eq!(
+            dao.get_slots_for_week(2026, 17, shiftplan_id, tx.clone())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        entity.valid_to = Some(date!(2026 - 12 - 31));
+        entity.max_paid_employees = Some(1);
+        entity.version = Uuid::new_v4();
+        dao.update_slot(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.get_slot(&entity.id, tx.clone()).await.unwrap().as_ref(),
+            Some(&entity)
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update_slot(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.get_slot(&entity.id, tx.clone()).await.unwrap().as_ref(),
+            Some(&entity)
+        );
+        assert!(!dao
+            .get_slots(tx.clone())
+            .await
+            .unwrap()
+            .iter()
+            .any(|slot| slot.id == entity.id));
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use crate::DaoError;
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::special_day::{SpecialDayDao, SpecialDayEntity, SpecialDayTypeEntity};
+use shifty_utils::DayOfWeek;
+use sqlx::query_as;
+use time::{PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+struct SpecialDayDb {
+    id: Uuid,
+    year: i64,
+    calendar_week: i64,
+    day_of_week: i64,
+    day_type: String,
+    time_of_day: Option<Time>,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&SpecialDayDb> for SpecialDayEntity {
+    type Error = DaoError;
+
+    fn try_from(entity: &SpecialDayDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: entity.id,
+            year: entity.year as u32,
+            calendar_week: entity.calendar_week as u8,
+            day_of_week: DayOfWeek::from_number(entity.day_of_week as u8)
+                .ok_or(DaoError::InvalidDayOfWeek(entity.day_of_week as u8))?,
+            day_type: match entity.day_type.as_str() {
+                "Holiday" => SpecialDayTypeEntity::Holiday,
+                "ShortDay" => SpecialDayTypeEntity::ShortDay,
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            time_of_day: entity.time_of_day,
+            created: entity.created,
+            deleted: entity.deleted,
+            version: entity.update_version,
+        })
+    }
+}
+
+pub struct SpecialDayDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+impl SpecialDayDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SpecialDayDao for SpecialDayDaoImpl {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<SpecialDayEntity>, DaoError> {
+        Ok(query_as!(
+            SpecialDayDb,
+            r#"
+            SELECT id, year, calendar_week, day_of_week, day_type, time_of_day, created, deleted, update_version
+            FROM special_day
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SpecialDayEntity::try_from)
+        .transpose()?)
+    }
+    async fn find_by_week(
+        &self,
+        year: u32,
+        calendar_week: u8,
+    ) -> Result<Arc<[SpecialDayEntity]>, DaoError> {
+        Ok(query_as!(
+            SpecialDayDb,
+            r#"
+            SELECT id, year, calendar_week, day_of_week, day_type, time_of_day, created, deleted, update_version
+            FROM special_day
+            WHERE year = $1 AND calendar_week = $2 AND deleted IS NULL
+            "#,
+            year as i64,
+            calendar_week as i64
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SpecialDayEntity::try_from)
+        .collect::<Result<_, _>>()?)
+    }
+    async fn find_by_year(&self, year: u32) -> Result<Arc<[SpecialDayEntity]>, DaoError> {
+        let year = year as i64;
+        Ok(query_as!(
+            SpecialDayDb,
+            r#"
+            SELECT id, year, calendar_week, day_of_week, day_type, time_of_day, created, deleted, update_version
+            FROM special_day
+            WHERE year = $1 AND deleted IS NULL
+            ORDER BY calendar_week ASC, day_of_week ASC
+            "#,
+            year
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SpecialDayEntity::try_from)
+        .collect::<Result<_, _>>()?)
+    }
+    async fn find_by_iso_year(&self, year: u32) -> Result<Arc<[SpecialDayEntity]>, DaoError> {
+        // Phase 52 Follow-up #3: DB-Spalte `year` speichert das ISO-Wochenjahr
+        // (siehe create-Pfad, `ShiftyDate::from_date(...)`). Ein direktes
+        // `WHERE year = ?` matched daher exakt das ISO-Wochenjahr — im
+        // Gegensatz zu `SpecialDayServiceImpl::get_by_year`, das eine
+        // Union(y, y-1)+Kalender-Jahr-Filter-Hackerei fürs Frontend fährt.
+        let year = year as i64;
+        Ok(query_as!(
+            SpecialDayDb,
+            r#"
+            SELECT id, year, calendar_week, day_of_week, day_type, time_of_day, created, deleted, update_version
+            FROM special_day
+            WHERE year = $1 AND deleted IS NULL
+            ORDER BY calendar_week ASC, day_of_week ASC
+            "#,
+            year
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SpecialDayEntity::try_from)
+        .collect::<Result<_, _>>()?)
+    }
+    async fn create(&self, entity: &SpecialDayEntity, process: &str) -> Result<(), DaoError> {
+        let year = entity.year as i64;
+        let calendar_week = entity.calendar_week as i64;
+        let day_of_week = entity.day_of_week.to_number() as i64;
+        let day_type = match entity.day_type {
+            SpecialDayTypeEntity::Holiday => "Holiday",
+            SpecialDayTypeEntity::ShortDay => "ShortDay",
+        }
+        .to_string();
+        query_as!(
+            SpecialDayDb,
+            r#"
+            INSERT INTO special_day (id, year, calendar_week, day_of_week, day_type, time_of_day, created, update_version, update_process)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+            entity.id,
+            year,
+            calendar_week,
+            day_of_week,
+            day_type,
+            entity.time_of_day,
+            entity.created,
+            entity.version,
+            process,
+        ).execute(&*self.pool)
+            .await
+            .map_db_error()?;
+        Ok(())
+    }
+    async fn update(&self, entity: &SpecialDayEntity, process: &str) -> Result<(), DaoError> {
+        let day_type = match entity.day_type {
+            SpecialDayTypeEntity::Holiday => "Holiday",
+            SpecialDayTypeEntity::ShortDay => "ShortDay",
+        }
+        .to_string();
+        query_as!(
+            SpecialDayDb,
+            r#"
+            UPDATE special_day
+            SET deleted = $1, update_version = $2, update_process = $3, day_type = $4, time_of_day = $5
+            WHERE id = $6
+            "#,
+            entity.deleted,
+            entity.version,
+            process,
+            day_type,
+            entity.time_of_day,
+            entity.id,
+        )
+        .execute(&*self.pool)
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use time::macros::time;
+
+    #[tokio::test]
+    async fn special_day_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let dao = SpecialDayDaoImpl::new(pool.clone());
+        let mut entity = SpecialDayEntity {
+            id: Uuid::new_v4(),
+            year: 2026,
+            calendar_week: 52,
+            day_of_week: DayOfWeek::Thursday,
+            day_type: SpecialDayTypeEntity::ShortDay,
+            time_of_day: Some(time!(14:00)),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_week(2026, 52).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.day_type = SpecialDayTypeEntity::Holiday;
+        entity.time_of_day = None;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS).await.unwrap();
+        assert_eq!(
+            dao.find_by_year(2026).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert!(dao.find_by_iso_year(2026).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use crate::{now_utc, ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    text_template::{TemplateEngineEntity, TextTemplateDao, TextTemplateEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+pub struct TextTemplateDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl TextTemplateDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+struct TextTemplateDb {
+    id: Uuid,
+    name: Option<String>,
+    template_type: String,
+    template_text: String,
+    template_engine: String,
+    created_at: Option<PrimitiveDateTime>,
+    created_by: Option<String>,
+    deleted: Option<PrimitiveDateTime>,
+    deleted_by: Option<String>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&TextTemplateDb> for TextTemplateEntity {
+    type Error = DaoError;
+    fn try_from(text_template: &TextTemplateDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: text_template.id,
+            name: text_template.name.as_ref().map(|s| s.as_str().into()),
+            template_type: text_template.template_type.as_str().into(),
+            template_text: text_template.template_text.as_str().into(),
+            template_engine: TemplateEngineEntity::try_from(text_template.template_engine.as_str())?,
+            created_at: text_template.created_at,
+            created_by: text_template.created_by.as_ref().map(|s| s.as_str().into()),
+            deleted: text_template.deleted,
+            deleted_by: text_template.deleted_by.as_ref().map(|s| s.as_str().into()),
+            version: text_template.update_version,
+        })
+    }
+}
+
+#[async_trait]
+impl TextTemplateDao for TextTemplateDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[TextTemplateEntity]>, DaoError> {
+        Ok(query_as!(
+            TextTemplateDb,
+            "SELECT id, name, template_type, template_text, template_engine, created_at, created_by, deleted, deleted_by, update_version FROM text_template WHERE deleted IS NULL"
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(TextTemplateEntity::try_from)
+        .collect::<Result<Arc<[TextTemplateEntity]>, DaoError>>()?)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TextTemplateEntity>, DaoError> {
+        Ok(query_as!(
+            TextTemplateDb,
+            "SELECT id, name, template_type, template_text, template_engine, created_at, created_by, deleted, deleted_by, update_version FROM text_template WHERE id = $1 AND deleted IS NULL",
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(TextTemplateEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_template_type(
+        &self,
+        template_type: &str,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TextTemplateEntity]>, DaoError> {
+        Ok(query_as!(
+            TextTemplateDb,
+            "SELECT id, name, template_type, template_text, template_engine, created_at, created_by, deleted, deleted_by, update_version FROM text_template WHERE template_type = $1 AND deleted IS NULL",
+            template_type
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(TextTemplateEntity::try_from)
+        .collect::<Result<Arc<[TextTemplateEntity]>, DaoError>>()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &TextTemplateEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref().map(|s| s.as_ref());
+        let template_type = entity.template_type.as_ref();
+        let template_text = entity.template_text.as_ref();
+        let template_engine = entity.template_engine.to_string();
+        let created_by = entity.created_by.as_ref().map(|s| s.as_ref());
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+
+        query!(
+            "INSERT INTO text_template (id, name, template_type, template_text, template_engine, created_at, created_by, deleted, deleted_by, update_version, update_process) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+            entity.id, name, template_type, template_text, template_engine, entity.created_at, created_by, entity.deleted, deleted_by, entity.version, process
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &TextTemplateEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref().map(|s| s.as_ref());
+        let template_type = entity.template_type.as_ref();
+        let template_text = entity.template_text.as_ref();
+        let template_engine = entity.template_engine.to_string();
+        let created_by = entity.created_by.as_ref().map(|s| s.as_ref());
+        let deleted_by = entity.deleted_by.as_ref().map(|s| s.as_ref());
+
+        query!(
+            "UPDATE text_template SET name = $1, template_type = $2, template_text = $3, template_engine = $4, created_at = $5, created_by = $6, deleted = $7, deleted_by = $8, update_version = $9, update_process = $10 WHERE id = $11",
+            name, template_type, template_text, template_engine, entity.created_at, created_by, entity.deleted, deleted_by, entity.version, process, entity.id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let now = now_utc();
+        let version = Uuid::new_v4();
+
+        query!(
+            "UPDATE text_template SET deleted = $1, deleted_by = $2, update_version = $3, update_process = $4 WHERE id = $5",
+            now, process, version, process, id
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn text_template_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = TextTemplateDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = TextTemplateEntity {
+            id: Uuid::new_v4(),
+            name: Some("Abrechnung".into()),
+            template_type: "billing-period".into(),
+            template_text: "{{ name }}".into(),
+            template_engine: TemplateEngineEntity::Tera,
+            created_at: Some(timestamp()),
+            created_by: Some("anna".into()),
+            deleted: None,
+            deleted_by: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.template_text = "{{ sales_person.name }}".into();
+        entity.template_engine = TemplateEngineEntity::MiniJinja;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_template_type("billing-period", tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        dao.delete(entity.id, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(!dao
+            .all(tx.clone())
+            .await
+            .unwrap()
+            .iter()
+            .any(|template| template.id == entity.id));
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
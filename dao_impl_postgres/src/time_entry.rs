@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    time_entry::{TimeEntryDao, TimeEntryEntity, TimeEntrySource},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+struct TimeEntryDb {
+    id: Uuid,
+    sales_person_id: Uuid,
+    booking_id: Option<Uuid>,
+    start_time: PrimitiveDateTime,
+    end_time: Option<PrimitiveDateTime>,
+    description: String,
+    source: String,
+    created: PrimitiveDateTime,
+    created_by: String,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&TimeEntryDb> for TimeEntryEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &TimeEntryDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: db.id,
+            sales_person_id: db.sales_person_id,
+            booking_id: db.booking_id,
+            start: db.start_time,
+            end: db.end_time,
+            description: db.description.as_str().into(),
+            source: str_to_source(&db.source)?,
+            created: db.created,
+            created_by: db.created_by.as_str().into(),
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+fn source_to_str(source: TimeEntrySource) -> &'static str {
+    match source {
+        TimeEntrySource::Clock => "Clock",
+        TimeEntrySource::Manual => "Manual",
+    }
+}
+
+fn str_to_source(value: &str) -> Result<TimeEntrySource, DaoError> {
+    match value {
+        "Clock" => Ok(TimeEntrySource::Clock),
+        "Manual" => Ok(TimeEntrySource::Manual),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct TimeEntryDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+impl TimeEntryDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl TimeEntryDao for TimeEntryDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TimeEntryEntity>, DaoError> {
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE id = $1 AND deleted IS NULL"#,
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(TimeEntryEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_open_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TimeEntryEntity>, DaoError> {
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE sales_person_id = $1 AND end_time IS NULL AND deleted IS NULL
+               ORDER BY start_time DESC
+               LIMIT 1"#,
+            sales_person_id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(TimeEntryEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_sales_person_and_range(
+        &self,
+        sales_person_id: Uuid,
+        from: PrimitiveDateTime,
+        to: PrimitiveDateTime,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TimeEntryEntity]>, DaoError> {
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE sales_person_id = $1 AND start_time >= $2 AND start_time < $3
+                 AND deleted IS NULL
+               ORDER BY start_time"#,
+            sales_person_id,
+            from,
+            to,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(TimeEntryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_range(
+        &self,
+        from: PrimitiveDateTime,
+        to: PrimitiveDateTime,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TimeEntryEntity]>, DaoError> {
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE start_time >= $1 AND start_time < $2 AND deleted IS NULL
+               ORDER BY start_time"#,
+            from,
+            to,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(TimeEntryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &TimeEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let description = entity.description.as_ref();
+        let source = source_to_str(entity.source);
+        let created_by = entity.created_by.as_ref();
+        query!(
+            r#"INSERT INTO time_entry (id, sales_person_id, booking_id, start_time, end_time,
+                   description, source, created, created_by, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)"#,
+            entity.id,
+            entity.sales_person_id,
+            entity.booking_id,
+            entity.start,
+            entity.end,
+            description,
+            source,
+            entity.created,
+            created_by,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &TimeEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let description = entity.description.as_ref();
+        let source = source_to_str(entity.source);
+        query!(
+            r#"UPDATE time_entry SET booking_id = $1, start_time = $2, end_time = $3, description = $4,
+                   source = $5, deleted = $6, update_process = $7, update_version = $8
+               WHERE id = $9"#,
+            entity.booking_id,
+            entity.start,
+            entity.end,
+            description,
+            source,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    #[test]
+    fn source_roundtrip() {
+        for source in [TimeEntrySource::Clock, TimeEntrySource::Manual] {
+            assert_eq!(str_to_source(source_to_str(source)).unwrap(), source);
+        }
+    }
+
+    #[test]
+    fn unknown_source() {
+        match str_to_source("Guess") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Guess"),
+            other => panic!("expected EnumValueNotFound(\"Guess\"), got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn time_entry_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = TimeEntryDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = TimeEntryEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            booking_id: None,
+            start: datetime!(2026-04-20 09:02),
+            end: None,
+            description: "".into(),
+            source: TimeEntrySource::Clock,
+            created: timestamp(),
+            created_by: "anna".into(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_open_by_sales_person(sales_person_id, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.end = Some(datetime!(2026-04-20 13:05));
+        entity.description = "Kasse".into();
+        entity.source = TimeEntrySource::Manual;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_open_by_sales_person(sales_person_id, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            dao.find_by_sales_person_and_range(
+                sales_person_id,
+                datetime!(2026-04-20 00:00),
+                datetime!(2026-04-21 00:00),
+                tx.clone()
+            )
+            .await
+            .unwrap()
+            .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao
+            .find_by_range(
+                datetime!(2026-04-20 00:00),
+                datetime!(2026-04-21 00:00),
+                tx.clone()
+            )
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::user_invitation::{UserInvitationDao, UserInvitationEntity};
+use dao::DaoError;
+use sqlx::{query_as, PgPool};
+use time::OffsetDateTime;
+use uuid::Uuid;
+use crate::ResultDbErrorExt;
+
+pub struct UserInvitationDaoImpl {
+    connection_pool: Arc<PgPool>,
+}
+
+impl UserInvitationDaoImpl {
+    pub fn new(connection_pool: Arc<PgPool>) -> Self {
+        Self { connection_pool }
+    }
+}
+
+struct UserInvitationDb {
+    id: Uuid,
+    username: String,
+    token: Uuid,
+    expiration_date: OffsetDateTime,
+    created_date: OffsetDateTime,
+    update_process: String,
+    redeemed_at: Option<OffsetDateTime>,
+    session_id: Option<String>,
+    session_revoked_at: Option<OffsetDateTime>,
+}
+
+impl TryFrom<&UserInvitationDb> for UserInvitationEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &UserInvitationDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: db.id,
+            username: Arc::from(db.username.as_str()),
+            token: db.token,
+            expiration_date: db.expiration_date,
+            created_date: db.created_date,
+            update_process: Arc::from(db.update_process.as_str()),
+            redeemed_at: db.redeemed_at,
+            session_id: db.session_id.as_ref().map(|s| Arc::from(s.as_str())),
+            session_revoked_at: db.session_revoked_at,
+        })
+    }
+}
+
+#[async_trait]
+impl UserInvitationDao for UserInvitationDaoImpl {
+    async fn create_invitation(&self, invitation: &UserInvitationEntity) -> Result<(), DaoError> {
+        let username_str = invitation.username.to_string();
+        let update_process_str = invitation.update_process.to_string();
+        let session_id_str = invitation.session_id.as_ref().map(|s| s.to_string());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_invitation (
+                id, username, token, expiration_date, created_date, update_process, redeemed_at, session_id, session_revoked_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+            invitation.id,
+            username_str,
+            invitation.token,
+            invitation.expiration_date,
+            invitation.created_date,
+            update_process_str,
+            invitation.redeemed_at,
+            session_id_str,
+            invitation.session_revoked_at
+        )
+        .execute(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<UserInvitationEntity>, DaoError> {
+        Ok(query_as!(
+            UserInvitationDb,
+            r#"
+            SELECT 
+                id, username, token, expiration_date, 
+                created_date, update_process, redeemed_at, session_id, session_revoked_at
+            FROM user_invitation
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&*self.connection_pool)
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(UserInvitationEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_token(&self, token: &Uuid) -> Result<Option<UserInvitationEntity>, DaoError> {
+        Ok(query_as!(
+            UserInvitationDb,
+            r#"
+            SELECT 
+                id, username, token, expiration_date, 
+                created_date, update_process, redeemed_at, session_id, session_revoked_at
+            FROM user_invitation
+            WHERE token = $1
+            "#,
+            token
+        )
+        .fetch_optional(&*self.connection_pool)
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(UserInvitationEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn mark_as_redeemed(&self, token: &Uuid, session_id: &str) -> Result<(), DaoError> {
+        let redeemed_at = OffsetDateTime::now_utc();
+
+        sqlx::query!(
+            r#"
+            UPDATE user_invitation
+            SET redeemed_at = $1, session_id = $2
+            WHERE token = $3
+            "#,
+            redeemed_at,
+            session_id,
+            token
+        )
+        .execute(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn delete_by_token(&self, token: &Uuid) -> Result<(), DaoError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_invitation
+            WHERE token = $1
+            "#,
+            token
+        )
+        .execute(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn delete_expired(&self, current_time: &OffsetDateTime) -> Result<u64, DaoError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM user_invitation
+            WHERE expiration_date < $1
+            "#,
+            current_time
+        )
+        .execute(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Vec<UserInvitationEntity>, DaoError> {
+        let rows = query_as!(
+            UserInvitationDb,
+            r#"
+            SELECT 
+                id, username, token, expiration_date, 
+                created_date, update_process, redeemed_at, session_id, session_revoked_at
+            FROM user_invitation
+            WHERE username = $1
+            ORDER BY created_date DESC
+            "#,
+            username
+        )
+        .fetch_all(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        rows.iter()
+            .map(UserInvitationEntity::try_from)
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    async fn find_by_session_id(&self, session_id: &str) -> Result<Option<UserInvitationEntity>, DaoError> {
+        Ok(query_as!(
+            UserInvitationDb,
+            r#"
+            SELECT 
+                id, username, token, expiration_date, 
+                created_date, update_process, redeemed_at, session_id, session_revoked_at
+            FROM user_invitation
+            WHERE session_id = $1
+            "#,
+            session_id
+        )
+        .fetch_optional(&*self.connection_pool)
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(UserInvitationEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn delete_by_id(&self, id: &Uuid) -> Result<(), DaoError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM user_invitation
+            WHERE id = $1
+            "#,
+            id
+        )
+        .execute(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn mark_session_revoked(&self, id: &Uuid) -> Result<(), DaoError> {
+        let session_revoked_at = OffsetDateTime::now_utc();
+
+        sqlx::query!(
+            r#"
+            UPDATE user_invitation
+            SET session_revoked_at = $1
+            WHERE id = $2
+            "#,
+            session_revoked_at,
+            id
+        )
+        .execute(&*self.connection_pool)
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::SessionDaoImpl;
+    use crate::test_support::{setup_pool, PROCESS};
+    use crate::PermissionDaoImpl;
+    use dao::session::{SessionDao, SessionEntity};
+    use dao::{PermissionDao, UserEntity};
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn user_invitation_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        PermissionDaoImpl::new(pool.clone())
+            .create_user(
+                &UserEntity {
+                    name: "anna".into(),
+                },
+                PROCESS,
+            )
+            .await
+            .unwrap();
+        SessionDaoImpl::new(pool.clone())
+            .create(&SessionEntity {
+                id: "session-1".into(),
+                user_id: "anna".into(),
+                expires: 1_790_000_000,
+                created: 1_780_000_000,
+                impersonate_user_id: None,
+            })
+            .await
+            .unwrap();
+        let dao = UserInvitationDaoImpl::new(pool.clone());
+        let entity = UserInvitationEntity {
+            id: Uuid::new_v4(),
+            username: "anna".into(),
+            token: Uuid::new_v4(),
+            expiration_date: datetime!(2026-10-02 08:00 UTC),
+            created_date: datetime!(2026-10-01 08:00 UTC),
+            update_process: PROCESS.into(),
+            redeemed_at: None,
+            session_id: None,
+            session_revoked_at: None,
+        };
+
+        dao.create_invitation(&entity).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(&entity.id).await.unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_by_username("anna").await.unwrap(),
+            vec![entity.clone()]
+        );
+
+        dao.mark_as_redeemed(&entity.token, "session-1")
+            .await
+            .unwrap();
+        let redeemed = dao.find_by_session_id("session-1").await.unwrap().unwrap();
+        assert_eq!(redeemed.id, entity.id);
+        assert!(redeemed.redeemed_at.is_some());
+        dao.mark_session_revoked(&entity.id).await.unwrap();
+        assert!(dao
+            .find_by_token(&entity.token)
+            .await
+            .unwrap()
+            .unwrap()
+            .session_revoked_at
+            .is_some());
+
+        dao.delete_by_id(&entity.id).await.unwrap();
+        assert_eq!(dao.find_by_id(&entity.id).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn delete_expired_keeps_valid_invitations() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        PermissionDaoImpl::new(pool.clone())
+            .create_user(
+                &UserEntity {
+                    name: "anna".into(),
+                },
+                PROCESS,
+            )
+            .await
+            .unwrap();
+        let dao = UserInvitationDaoImpl::new(pool.clone());
+        let expired = UserInvitationEntity {
+            id: Uuid::new_v4(),
+            username: "anna".into(),
+            token: Uuid::new_v4(),
+            expiration_date: datetime!(2026-10-01 08:00 UTC),
+            created_date: datetime!(2026-09-30 08:00 UTC),
+            update_process: PROCESS.into(),
+            redeemed_at: None,
+            session_id: None,
+            session_revoked_at: None,
+        };
+        let valid = UserInvitationEntity {
+            id: Uuid::new_v4(),
+            token: Uuid::new_v4(),
+            expiration_date: datetime!(2026-10-03 08:00 UTC),
+            ..expired.clone()
+        };
+        dao.create_invitation(&expired).await.unwrap();
+        dao.create_invitation(&valid).await.unwrap();
+
+        assert_eq!(
+            dao.delete_expired(&datetime!(2026-10-02 08:00 UTC))
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(dao.find_by_token(&expired.token).await.unwrap(), None);
+        assert_eq!(
+            dao.find_by_token(&valid.token).await.unwrap(),
+            Some(valid.clone())
+        );
+        dao.delete_by_token(&valid.token).await.unwrap();
+        assert!(dao.find_by_username("anna").await.unwrap().is_empty());
+    }
+}
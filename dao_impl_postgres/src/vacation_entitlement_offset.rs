@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use crate::ResultDbErrorExt;
+use async_trait::async_trait;
+use dao::{
+    vacation_entitlement_offset::{VacationEntitlementOffsetDao, VacationEntitlementOffsetEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct VacationEntitlementOffsetDb {
+    id: Uuid,
+    sales_person_id: Uuid,
+    year: i64,
+    offset_days: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&VacationEntitlementOffsetDb> for VacationEntitlementOffsetEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &VacationEntitlementOffsetDb) -> Result<Self, Self::Error> {
+        Ok(VacationEntitlementOffsetEntity {
+            id: db.id,
+            sales_person_id: db.sales_person_id,
+            year: db.year as u32,
+            offset_days: db.offset_days as i32,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct VacationEntitlementOffsetDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl VacationEntitlementOffsetDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl VacationEntitlementOffsetDao for VacationEntitlementOffsetDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn find_by_sales_person_id_and_year(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Option<VacationEntitlementOffsetEntity>, DaoError> {
+        Ok(query_as!(
+            VacationEntitlementOffsetDb,
+            r#"SELECT id, sales_person_id, year, offset_days, created, deleted, update_version
+               FROM vacation_entitlement_offset
+               WHERE sales_person_id = $1 AND year = $2 AND deleted IS NULL"#,
+            sales_person_id,
+            year as i64,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(VacationEntitlementOffsetEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<VacationEntitlementOffsetEntity>, DaoError> {
+        Ok(query_as!(
+            VacationEntitlementOffsetDb,
+            r#"SELECT id, sales_person_id, year, offset_days, created, deleted, update_version
+               FROM vacation_entitlement_offset
+               WHERE id = $1"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(VacationEntitlementOffsetEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &VacationEntitlementOffsetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"INSERT INTO vacation_entitlement_offset (id, sales_person_id, year, offset_days, created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            entity.id,
+            entity.sales_person_id,
+            entity.year as i64,
+            entity.offset_days as i64,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &VacationEntitlementOffsetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"UPDATE vacation_entitlement_offset
+               SET offset_days = $1, deleted = $2, update_process = $3, update_version = $4
+               WHERE id = $5"#,
+            entity.offset_days as i64,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_sales_person, setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn vacation_entitlement_offset_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = VacationEntitlementOffsetDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let sales_person_id = create_sales_person(&pool, tx.clone()).await;
+        let mut entity = VacationEntitlementOffsetEntity {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            year: 2026,
+            offset_days: 2,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_id_and_year(sales_person_id, 2026, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.offset_days = -3;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_sales_person_id_and_year(sales_person_id, 2026, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
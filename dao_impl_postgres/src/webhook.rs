@@ -0,0 +1,489 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    webhook::{
+        join_event_types, split_event_types, WebhookDao, WebhookDeliveryEntity,
+        WebhookDeliveryState, WebhookEventType, WebhookSubscriptionEntity,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct WebhookSubscriptionDb {
+    id: Uuid,
+    url: String,
+    secret: String,
+    description: String,
+    event_types: String,
+    active: i64,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&WebhookSubscriptionDb> for WebhookSubscriptionEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &WebhookSubscriptionDb) -> Result<Self, Self::Error> {
+        Ok(WebhookSubscriptionEntity {
+            id: db.id,
+            url: db.url.as_str().into(),
+            secret: db.secret.as_str().into(),
+            description: db.description.as_str().into(),
+            event_types: split_event_types(&db.event_types)?,
+            active: db.active != 0,
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct WebhookDeliveryDb {
+    id: Uuid,
+    subscription_id: Uuid,
+    event_type: String,
+    payload: String,
+    state: String,
+    attempts: i64,
+    next_attempt: PrimitiveDateTime,
+    last_status: Option<i64>,
+    last_error: Option<String>,
+    created: PrimitiveDateTime,
+    delivered: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&WebhookDeliveryDb> for WebhookDeliveryEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &WebhookDeliveryDb) -> Result<Self, Self::Error> {
+        Ok(WebhookDeliveryEntity {
+            id: db.id,
+            subscription_id: db.subscription_id,
+            event_type: WebhookEventType::try_from(db.event_type.as_str())?,
+            payload: db.payload.as_str().into(),
+            state: WebhookDeliveryState::try_from(db.state.as_str())?,
+            attempts: db.attempts as u32,
+            next_attempt: db.next_attempt,
+            last_status: db.last_status.map(|status| status as u16),
+            last_error: db.last_error.as_deref().map(Arc::from),
+            created: db.created,
+            delivered: db.delivered,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct WebhookDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl WebhookDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDao for WebhookDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all_subscriptions(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookSubscriptionEntity]>, DaoError> {
+        query_as!(
+            WebhookSubscriptionDb,
+            r#"SELECT id, url, secret, description, event_types, active, created, deleted,
+                      update_version
+               FROM webhook_subscription
+               WHERE deleted IS NULL
+               ORDER BY created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(WebhookSubscriptionEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_subscription_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WebhookSubscriptionEntity>, DaoError> {
+        query_as!(
+            WebhookSubscriptionDb,
+            r#"SELECT id, url, secret, description, event_types, active, created, deleted,
+                      update_version
+               FROM webhook_subscription
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WebhookSubscriptionEntity::try_from)
+        .transpose()
+    }
+
+    async fn create_subscription(
+        &self,
+        entity: &WebhookSubscriptionEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let url = entity.url.as_ref();
+        let secret = entity.secret.as_ref();
+        let description = entity.description.as_ref();
+        let event_types = join_event_types(&entity.event_types);
+        let active = entity.active as i64;
+
+        query!(
+            r#"INSERT INTO webhook_subscription
+                 (id, url, secret, description, event_types, active, created, deleted,
+                  update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+            entity.id,
+            url,
+            secret,
+            description,
+            event_types,
+            active,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_subscription(
+        &self,
+        entity: &WebhookSubscriptionEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let url = entity.url.as_ref();
+        let secret = entity.secret.as_ref();
+        let description = entity.description.as_ref();
+        let event_types = join_event_types(&entity.event_types);
+        let active = entity.active as i64;
+
+        query!(
+            r#"UPDATE webhook_subscription
+               SET url = $1, secret = $2, description = $3, event_types = $4, active = $5,
+                   deleted = $6, update_process = $7, update_version = $8
+               WHERE id = $9"#,
+            url,
+            secret,
+            description,
+            event_types,
+            active,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_delivery_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WebhookDeliveryEntity>, DaoError> {
+        query_as!(
+            WebhookDeliveryDb,
+            r#"SELECT id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                      last_status, last_error, created, delivered, update_version
+               FROM webhook_delivery
+               WHERE id = $1"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WebhookDeliveryEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_deliveries_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookDeliveryEntity]>, DaoError> {
+        let limit = limit as i64;
+        query_as!(
+            WebhookDeliveryDb,
+            r#"SELECT id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                      last_status, last_error, created, delivered, update_version
+               FROM webhook_delivery
+               WHERE subscription_id = $1
+               ORDER BY created DESC
+               LIMIT $2"#,
+            subscription_id,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(WebhookDeliveryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_due_deliveries(
+        &self,
+        now: PrimitiveDateTime,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookDeliveryEntity]>, DaoError> {
+        let limit = limit as i64;
+        query_as!(
+            WebhookDeliveryDb,
+            r#"SELECT id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                      last_status, last_error, created, delivered, update_version
+               FROM webhook_delivery
+               WHERE state = 'Pending' AND next_attempt <= $1
+               ORDER BY next_attempt, created
+               LIMIT $2"#,
+            now,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(WebhookDeliveryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create_delivery(
+        &self,
+        entity: &WebhookDeliveryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let event_type_str = entity.event_type.to_string();
+        let payload = entity.payload.as_ref();
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let last_status = entity.last_status.map(|status| status as i64);
+        let last_error = entity.last_error.as_deref();
+
+        query!(
+            r#"INSERT INTO webhook_delivery
+                 (id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                  last_status, last_error, created, delivered, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)"#,
+            entity.id,
+            entity.subscription_id,
+            event_type_str,
+            payload,
+            state_str,
+            attempts,
+            entity.next_attempt,
+            last_status,
+            last_error,
+            entity.created,
+            entity.delivered,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_delivery(
+        &self,
+        entity: &WebhookDeliveryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let last_status = entity.last_status.map(|status| status as i64);
+        let last_error = entity.last_error.as_deref();
+
+        query!(
+            r#"UPDATE webhook_delivery
+               SET state = $1, attempts = $2, next_attempt = $3, last_status = $4, last_error = $5,
+                   delivered = $6, update_process = $7, update_version = $8
+               WHERE id = $9"#,
+            state_str,
+            attempts,
+            entity.next_attempt,
+            last_status,
+            last_error,
+            entity.delivered,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    fn subscription() -> WebhookSubscriptionEntity {
+        WebhookSubscriptionEntity {
+            id: Uuid::new_v4(),
+            url: "https://hooks.example.com/shifty".into(),
+            secret: "geheim".into(),
+            description: "Kasse".into(),
+            event_types: [
+                WebhookEventType::BookingCreated,
+                WebhookEventType::BookingDeleted,
+            ]
+            .into(),
+            active: true,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn webhook_subscription_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = WebhookDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = subscription();
+
+        dao.create_subscription(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_subscription_by_id(entity.id, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.event_types = [WebhookEventType::WeekStatusChanged].into();
+        entity.active = false;
+        entity.version = Uuid::new_v4();
+        dao.update_subscription(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.all_subscriptions(tx.clone()).await.unwrap().as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.deleted = Some(timestamp());
+        entity.version = Uuid::new_v4();
+        dao.update_subscription(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_subscription_by_id(entity.id, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(dao.all_subscriptions(tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn webhook_delivery_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = WebhookDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let subscription = subscription();
+        dao.create_subscription(&subscription, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        let mut entity = WebhookDeliveryEntity {
+            id: Uuid::new_v4(),
+            subscription_id: subscription.id,
+            event_type: WebhookEventType::BookingCreated,
+            payload: r#"{"id":1}"#.into(),
+            state: WebhookDeliveryState::Pending,
+            attempts: 0,
+            next_attempt: datetime!(2026-10-01 09:00),
+            last_status: None,
+            last_error: None,
+            created: timestamp(),
+            delivered: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create_delivery(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_delivery_by_id(entity.id, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+        assert_eq!(
+            dao.find_due_deliveries(datetime!(2026-10-01 09:00), 10, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+
+        entity.state = WebhookDeliveryState::Delivered;
+        entity.attempts = 1;
+        entity.last_status = Some(204);
+        entity.delivered = Some(datetime!(2026-10-01 09:00));
+        entity.version = Uuid::new_v4();
+        dao.update_delivery(&entity, PROCESS, tx.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_deliveries_by_subscription(subscription.id, 10, tx.clone())
+                .await
+                .unwrap()
+                .as_ref(),
+            &[entity.clone()]
+        );
+        assert!(dao
+            .find_due_deliveries(datetime!(2026-10-02 00:00), 10, tx.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
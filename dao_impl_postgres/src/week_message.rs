@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use crate::{now_utc, ResultDbErrorExt};
+use async_trait::async_trait;
+use dao::{
+    week_message::{WeekMessageDao, WeekMessageEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct WeekMessageDb {
+    id: Uuid,
+    year: i64,
+    calendar_week: i64,
+    message: String,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&WeekMessageDb> for WeekMessageEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &WeekMessageDb) -> Result<Self, Self::Error> {
+        Ok(WeekMessageEntity {
+            id: db.id,
+            year: db.year as u32,
+            calendar_week: db.calendar_week as u8,
+            message: db.message.clone(),
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+pub struct WeekMessageDaoImpl {
+    pub _pool: Arc<sqlx::PgPool>,
+}
+
+impl WeekMessageDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl WeekMessageDao for WeekMessageDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WeekMessageEntity>, DaoError> {
+        Ok(query_as!(
+            WeekMessageDb,
+            r#"SELECT id, year, calendar_week, message, created, deleted, update_version
+               FROM week_message
+               WHERE id = $1 AND deleted IS NULL"#,
+            id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WeekMessageEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_year_and_week(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Option<WeekMessageEntity>, DaoError> {
+        Ok(query_as!(
+            WeekMessageDb,
+            r#"SELECT id, year, calendar_week, message, created, deleted, update_version
+               FROM week_message
+               WHERE year = $1 AND calendar_week = $2 AND deleted IS NULL"#,
+            year as i64,
+            calendar_week as i64,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WeekMessageEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn find_by_year(
+        &self,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Vec<WeekMessageEntity>, DaoError> {
+        let rows = query_as!(
+            WeekMessageDb,
+            r#"SELECT id, year, calendar_week, message, created, deleted, update_version
+               FROM week_message
+               WHERE year = $1 AND deleted IS NULL
+               ORDER BY calendar_week"#,
+            year as i64,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        let mut entities = Vec::new();
+        for row in &rows {
+            entities.push(WeekMessageEntity::try_from(row)?);
+        }
+        Ok(entities)
+    }
+
+    async fn create(
+        &self,
+        entity: &WeekMessageEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"INSERT INTO week_message (id, year, calendar_week, message, created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            entity.id,
+            entity.year as i64,
+            entity.calendar_week as i64,
+            entity.message,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &WeekMessageEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+
+        query!(
+            r#"UPDATE week_message 
+               SET message = $1, created = $2, deleted = $3, update_process = $4, update_version = $5
+               WHERE id = $6"#,
+            entity.message,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid, process: &str, tx: Self::Transaction) -> Result<(), DaoError> {
+        let now = now_utc();
+
+        query!(
+            r#"UPDATE week_message 
+               SET deleted = $1, update_process = $2
+               WHERE id = $3"#,
+            now,
+            process,
+            id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+
+    #[tokio::test]
+    async fn week_message_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = WeekMessageDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = WeekMessageEntity {
+            id: Uuid::new_v4(),
+            year: 2026,
+            calendar_week: 17,
+            message: "Inventur am Samstag".into(),
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_id(entity.id, tx.clone()).await.unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.message = "Inventur fällt aus".into();
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_year_and_week(2026, 17, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        dao.delete(entity.id, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(dao.find_by_id(entity.id, tx.clone()).await.unwrap(), None);
+        assert!(dao.find_by_year(2026, tx.clone()).await.unwrap().is_empty());
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+
+use crate::{now_utc, ResultDbErrorExt};
+use async_trait::async_trait;
+use dao::{
+    week_status::{WeekStatusDao, WeekStatusEntity, WeekStatusKind},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct WeekStatusDb {
+    id: Uuid,
+    year: i64,
+    calendar_week: i64,
+    status: String,
+    created: PrimitiveDateTime,
+    deleted: Option<PrimitiveDateTime>,
+    update_version: Uuid,
+}
+
+impl TryFrom<&WeekStatusDb> for WeekStatusEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &WeekStatusDb) -> Result<Self, Self::Error> {
+        Ok(WeekStatusEntity {
+            id: db.id,
+            year: db.year as u32,
+            calendar_week: db.calendar_week as u8,
+            status: match db.status.as_str() {
+                "InPlanning" => WeekStatusKind::InPlanning,
+                "Planned" => WeekStatusKind::Planned,
+                "Locked" => WeekStatusKind::Locked,
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            created: db.created,
+            deleted: db.deleted,
+            version: db.update_version,
+        })
+    }
+}
+
+/// Serialize the persisted discriminant. Explicit match (no `.to_string()`);
+/// `WeekStatusKind` structurally has no `Unset` variant, so `Unset` can never
+/// be written (D-39-04).
+fn status_to_str(status: &WeekStatusKind) -> &'static str {
+    match status {
+        WeekStatusKind::InPlanning => "InPlanning",
+        WeekStatusKind::Planned => "Planned",
+        WeekStatusKind::Locked => "Locked",
+    }
+}
+
+pub struct WeekStatusDaoImpl {
+    pub pool: Arc<sqlx::PgPool>,
+}
+
+impl WeekStatusDaoImpl {
+    pub fn new(pool: Arc<sqlx::PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WeekStatusDao for WeekStatusDaoImpl {
+    type Transaction = crate::TransactionImpl;
+
+    async fn find_by_year_and_week(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        tx: Self::Transaction,
+    ) -> Result<Option<WeekStatusEntity>, DaoError> {
+        Ok(query_as!(
+            WeekStatusDb,
+            r#"SELECT id, year, calendar_week, status, created, deleted, update_version
+               FROM week_status
+               WHERE year = $1 AND calendar_week = $2 AND deleted IS NULL"#,
+            year as i64,
+            calendar_week as i64,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WeekStatusEntity::try_from)
+        .transpose()?)
+    }
+
+    async fn create(
+        &self,
+        entity: &WeekStatusEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let status_str = status_to_str(&entity.status);
+
+        query!(
+            r#"INSERT INTO week_status (id, year, calendar_week, status, created, deleted, update_process, update_version)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            entity.id,
+            entity.year as i64,
+            entity.calendar_week as i64,
+            status_str,
+            entity.created,
+            entity.deleted,
+            process,
+            entity.version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &WeekStatusEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let status_str = status_to_str(&entity.status);
+
+        query!(
+            r#"UPDATE week_status
+               SET status = $1, update_process = $2, update_version = $3
+               WHERE id = $4"#,
+            status_str,
+            process,
+            entity.version,
+            entity.id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid, process: &str, tx: Self::Transaction) -> Result<(), DaoError> {
+        let now = now_utc();
+
+        query!(
+            r#"UPDATE week_status
+               SET deleted = $1, update_process = $2
+               WHERE id = $3"#,
+            now,
+            process,
+            id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{setup_pool, timestamp, PROCESS};
+    use crate::TransactionDaoImpl;
+    use dao::TransactionDao;
+    use time::macros::datetime;
+
+    fn sample_db(status: &str) -> WeekStatusDb {
+        WeekStatusDb {
+            id: Uuid::nil(),
+            year: 2026,
+            calendar_week: 27,
+            status: status.to_string(),
+            created: datetime!(2026-07-02 00:00:00),
+            deleted: None,
+            update_version: Uuid::nil(),
+        }
+    }
+
+    #[test]
+    fn unknown_discriminant() {
+        let db = sample_db("Bogus");
+        let result = WeekStatusEntity::try_from(&db);
+        match result {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Bogus"),
+            other => panic!("expected EnumValueNotFound(\"Bogus\"), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn roundtrip_discriminant() {
+        assert_eq!(
+            WeekStatusEntity::try_from(&sample_db("InPlanning"))
+                .unwrap()
+                .status,
+            WeekStatusKind::InPlanning
+        );
+        assert_eq!(
+            WeekStatusEntity::try_from(&sample_db("Planned"))
+                .unwrap()
+                .status,
+            WeekStatusKind::Planned
+        );
+        assert_eq!(
+            WeekStatusEntity::try_from(&sample_db("Locked"))
+                .unwrap()
+                .status,
+            WeekStatusKind::Locked
+        );
+    }
+
+    #[tokio::test]
+    async fn week_status_roundtrip() {
+        let Some(pool) = setup_pool().await else {
+            return;
+        };
+        let transaction_dao = TransactionDaoImpl::new(pool.clone());
+        let dao = WeekStatusDaoImpl::new(pool.clone());
+        let tx = transaction_dao.new_transaction().await.unwrap();
+        let mut entity = WeekStatusEntity {
+            id: Uuid::new_v4(),
+            year: 2026,
+            calendar_week: 17,
+            status: WeekStatusKind::InPlanning,
+            created: timestamp(),
+            deleted: None,
+            version: Uuid::new_v4(),
+        };
+
+        dao.create(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_year_and_week(2026, 17, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        entity.status = WeekStatusKind::Locked;
+        entity.version = Uuid::new_v4();
+        dao.update(&entity, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_year_and_week(2026, 17, tx.clone())
+                .await
+                .unwrap(),
+            Some(entity.clone())
+        );
+
+        dao.delete(entity.id, PROCESS, tx.clone()).await.unwrap();
+        assert_eq!(
+            dao.find_by_year_and_week(2026, 17, tx.clone())
+                .await
+                .unwrap(),
+            None
+        );
+        transaction_dao.commit(tx).await.unwrap();
+    }
+}
@@ -3,7 +3,10 @@ use std::sync::Arc;
 use crate::{ResultDbErrorExt, TransactionImpl};
 use async_trait::async_trait;
 use dao::{
-    absence::{AbsenceCategoryEntity, AbsenceDao, AbsencePeriodEntity, DayFractionEntity},
+    absence::{
+        AbsenceCategoryEntity, AbsenceDao, AbsencePeriodEntity, AbsenceStatusEntity,
+        DayFractionEntity,
+    },
     DaoError,
 };
 use shifty_utils::DateRange;
@@ -23,6 +26,7 @@ struct AbsencePeriodDb {
     deleted: Option<String>,
     update_version: Vec<u8>,
     day_fraction: String,
+    status: String,
 }
 
 impl TryFrom<&AbsencePeriodDb> for AbsencePeriodEntity {
@@ -63,6 +67,13 @@ impl TryFrom<&AbsencePeriodDb> for AbsencePeriodEntity {
                 "half" => DayFractionEntity::Half,
                 value => return Err(DaoError::EnumValueNotFound(value.into())),
             },
+            status: match row.status.as_str() {
+                "requested" => AbsenceStatusEntity::Requested,
+                "approved" => AbsenceStatusEntity::Approved,
+                "rejected" => AbsenceStatusEntity::Rejected,
+                "cancelled" => AbsenceStatusEntity::Cancelled,
+                value => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
         })
     }
 }
@@ -86,6 +97,17 @@ fn day_fraction_to_str(f: &DayFractionEntity) -> &'static str {
     }
 }
 
+/// Lowercase wie `day_fraction` (CHECK-Constraint in Migration
+/// `20261017000900`).
+fn status_to_str(s: &AbsenceStatusEntity) -> &'static str {
+    match s {
+        AbsenceStatusEntity::Requested => "requested",
+        AbsenceStatusEntity::Approved => "approved",
+        AbsenceStatusEntity::Rejected => "rejected",
+        AbsenceStatusEntity::Cancelled => "cancelled",
+    }
+}
+
 pub struct AbsenceDaoImpl {
     pub _pool: Arc<sqlx::SqlitePool>,
 }
@@ -108,7 +130,7 @@ impl AbsenceDao for AbsenceDaoImpl {
         let id_vec = id.as_bytes().to_vec();
         Ok(query_as!(
             AbsencePeriodDb,
-            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction FROM absence_period WHERE id = ? AND deleted IS NULL",
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE id = ? AND deleted IS NULL",
             id_vec,
         )
         .fetch_optional(tx.tx.lock().await.as_mut())
@@ -127,7 +149,7 @@ impl AbsenceDao for AbsenceDaoImpl {
         let logical_id_vec = logical_id.as_bytes().to_vec();
         Ok(query_as!(
             AbsencePeriodDb,
-            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction FROM absence_period WHERE logical_id = ? AND deleted IS NULL",
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE logical_id = ? AND deleted IS NULL",
             logical_id_vec,
         )
         .fetch_optional(tx.tx.lock().await.as_mut())
@@ -146,7 +168,7 @@ impl AbsenceDao for AbsenceDaoImpl {
         let sp_vec = sales_person_id.as_bytes().to_vec();
         Ok(query_as!(
             AbsencePeriodDb,
-            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction FROM absence_period WHERE sales_person_id = ? AND deleted IS NULL ORDER BY from_date",
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE sales_person_id = ? AND deleted IS NULL ORDER BY from_date",
             sp_vec,
         )
         .fetch_all(tx.tx.lock().await.as_mut())
@@ -163,7 +185,7 @@ impl AbsenceDao for AbsenceDaoImpl {
     ) -> Result<Arc<[AbsencePeriodEntity]>, DaoError> {
         Ok(query_as!(
             AbsencePeriodDb,
-            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction FROM absence_period WHERE deleted IS NULL ORDER BY sales_person_id, from_date",
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE deleted IS NULL ORDER BY sales_person_id, from_date",
         )
         .fetch_all(tx.tx.lock().await.as_mut())
         .await
@@ -192,7 +214,7 @@ impl AbsenceDao for AbsenceDaoImpl {
                 let exclude_vec = exclude.as_bytes().to_vec();
                 query_as!(
                     AbsencePeriodDb,
-                    "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction FROM absence_period WHERE sales_person_id = ? AND category = ? AND from_date <= ? AND to_date >= ? AND logical_id != ? AND deleted IS NULL",
+                    "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE sales_person_id = ? AND category = ? AND from_date <= ? AND to_date >= ? AND logical_id != ? AND status IN ('requested', 'approved') AND deleted IS NULL",
                     sp_vec,
                     category_str,
                     to_str,
@@ -206,7 +228,7 @@ impl AbsenceDao for AbsenceDaoImpl {
             None => {
                 query_as!(
                     AbsencePeriodDb,
-                    "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction FROM absence_period WHERE sales_person_id = ? AND category = ? AND from_date <= ? AND to_date >= ? AND deleted IS NULL",
+                    "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status FROM absence_period WHERE sales_person_id = ? AND category = ? AND from_date <= ? AND to_date >= ? AND status IN ('requested', 'approved') AND deleted IS NULL",
                     sp_vec,
                     category_str,
                     to_str,
@@ -237,11 +259,12 @@ impl AbsenceDao for AbsenceDaoImpl {
 
         Ok(query_as!(
             AbsencePeriodDb,
-            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction \
+            "SELECT id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_version, day_fraction, status \
              FROM absence_period \
              WHERE sales_person_id = ? \
                AND from_date <= ? \
                AND to_date >= ? \
+               AND status = 'approved' \
                AND deleted IS NULL \
              ORDER BY from_date",
             sp_vec,
@@ -276,8 +299,9 @@ impl AbsenceDao for AbsenceDaoImpl {
             .transpose()?;
         let version_vec = entity.version.as_bytes().to_vec();
         let day_fraction_str = day_fraction_to_str(&entity.day_fraction);
+        let status_str = status_to_str(&entity.status);
         query!(
-            "INSERT INTO absence_period (id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_process, update_version, day_fraction) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO absence_period (id, logical_id, sales_person_id, category, from_date, to_date, description, created, deleted, update_process, update_version, day_fraction, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             id_vec,
             logical_id_vec,
             sp_vec,
@@ -290,6 +314,7 @@ impl AbsenceDao for AbsenceDaoImpl {
             process,
             version_vec,
             day_fraction_str,
+            status_str,
         )
         .execute(tx.tx.lock().await.as_mut())
         .await
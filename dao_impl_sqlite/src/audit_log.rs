@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    audit_log::{AuditAction, AuditLogDao, AuditLogEntity, AuditLogQuery},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+struct AuditLogDb {
+    id: Vec<u8>,
+    entity_type: String,
+    entity_id: String,
+    action: String,
+    user_id: Option<String>,
+    real_user_id: Option<String>,
+    process: String,
+    before_json: Option<String>,
+    after_json: Option<String>,
+    created: String,
+}
+
+impl TryFrom<&AuditLogDb> for AuditLogEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &AuditLogDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::from_slice(&db.id)?,
+            entity_type: db.entity_type.as_str().into(),
+            entity_id: db.entity_id.as_str().into(),
+            action: str_to_action(&db.action)?,
+            user_id: db.user_id.as_deref().map(Arc::from),
+            real_user_id: db.real_user_id.as_deref().map(Arc::from),
+            process: db.process.as_str().into(),
+            before: db.before_json.as_deref().map(Arc::from),
+            after: db.after_json.as_deref().map(Arc::from),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+        })
+    }
+}
+
+fn action_to_str(action: AuditAction) -> &'static str {
+    match action {
+        AuditAction::Create => "Create",
+        AuditAction::Update => "Update",
+        AuditAction::Delete => "Delete",
+    }
+}
+
+fn str_to_action(value: &str) -> Result<AuditAction, DaoError> {
+    match value {
+        "Create" => Ok(AuditAction::Create),
+        "Update" => Ok(AuditAction::Update),
+        "Delete" => Ok(AuditAction::Delete),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct AuditLogDaoImpl {
+    pub _pool: Arc<sqlx::SqlitePool>,
+}
+impl AuditLogDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl AuditLogDao for AuditLogDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find(
+        &self,
+        audit_query: &AuditLogQuery,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[AuditLogEntity]>, DaoError> {
+        let entity_type = audit_query.entity_type.as_deref();
+        let entity_id = audit_query.entity_id.as_deref();
+        let user_id = audit_query.user_id.as_deref();
+        let from = audit_query
+            .from
+            .map(|from| from.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let to = audit_query
+            .to
+            .map(|to| to.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let limit = i64::from(audit_query.limit);
+        query_as!(
+            AuditLogDb,
+            r#"SELECT id, entity_type, entity_id, action, user_id, real_user_id, process,
+                      before_json, after_json, created
+               FROM audit_log
+               WHERE (? IS NULL OR entity_type = ?)
+                 AND (? IS NULL OR entity_id = ?)
+                 AND (? IS NULL OR user_id = ? OR real_user_id = ?)
+                 AND (? IS NULL OR created >= ?)
+                 AND (? IS NULL OR created < ?)
+               ORDER BY created DESC, id
+               LIMIT ?"#,
+            entity_type,
+            entity_type,
+            entity_id,
+            entity_id,
+            user_id,
+            user_id,
+            user_id,
+            from,
+            from,
+            to,
+            to,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(AuditLogEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &AuditLogEntity,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id = entity.id.as_bytes().to_vec();
+        let entity_type = entity.entity_type.as_ref();
+        let entity_id = entity.entity_id.as_ref();
+        let action = action_to_str(entity.action);
+        let user_id = entity.user_id.as_deref();
+        let real_user_id = entity.real_user_id.as_deref();
+        let process = entity.process.as_ref();
+        let before_json = entity.before.as_deref();
+        let after_json = entity.after.as_deref();
+        let created = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        query!(
+            r#"INSERT INTO audit_log (id, entity_type, entity_id, action, user_id, real_user_id,
+                   process, before_json, after_json, created)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id,
+            entity_type,
+            entity_id,
+            action,
+            user_id,
+            real_user_id,
+            process,
+            before_json,
+            after_json,
+            created,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn action_roundtrip() {
+        for action in [AuditAction::Create, AuditAction::Update, AuditAction::Delete] {
+            assert_eq!(str_to_action(action_to_str(action)).unwrap(), action);
+        }
+    }
+
+    #[test]
+    fn unknown_action() {
+        match str_to_action("Patch") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Patch"),
+            other => panic!("expected EnumValueNotFound(\"Patch\"), got {other:?}"),
+        }
+    }
+}
@@ -162,6 +162,27 @@ impl BookingDao for BookingDaoImpl {
         )
     }
 
+    async fn find_deleted_by_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[BookingEntity]>, DaoError> {
+        Ok(query_as!(
+            BookingDb,
+            "SELECT id, sales_person_id, slot_id, calendar_week, year, created, deleted, created_by, deleted_by, update_version FROM booking WHERE calendar_week = ? AND year = ? AND deleted IS NOT NULL",
+            calendar_week,
+            year,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(BookingEntity::try_from)
+        .collect::<Result<Arc<[BookingEntity]>, DaoError>>()?
+        )
+    }
+
     async fn create(
         &self,
         entity: &BookingEntity,
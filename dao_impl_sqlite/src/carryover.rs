@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::ResultDbErrorExt;
 use async_trait::async_trait;
 use dao::{
-    carryover::{CarryoverDao, CarryoverEntity},
+    carryover::{CarryoverDao, CarryoverDirtyEntity, CarryoverEntity},
     DaoError,
 };
 use sqlx::{query, query_as};
@@ -21,6 +21,25 @@ struct CarryoverDb {
     update_version: Vec<u8>,
 }
 
+#[derive(Debug)]
+struct CarryoverDirtyDb {
+    sales_person_id: Vec<u8>,
+    year: i64,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&CarryoverDirtyDb> for CarryoverDirtyEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &CarryoverDirtyDb) -> Result<Self, Self::Error> {
+        Ok(CarryoverDirtyEntity {
+            sales_person_id: Uuid::from_slice(&db.sales_person_id)?,
+            year: db.year as u32,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
 impl TryFrom<&CarryoverDb> for CarryoverEntity {
     type Error = DaoError;
 
@@ -112,4 +131,69 @@ impl CarryoverDao for CarryoverDaoImpl {
 
         Ok(())
     }
+
+    async fn mark_dirty(
+        &self,
+        entity: &CarryoverDirtyEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let sales_person_id_vec = entity.sales_person_id.as_bytes().to_vec();
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO carryover_dirty (sales_person_id, year, update_process, update_version)
+               VALUES (?, ?, ?, ?)
+               ON CONFLICT(sales_person_id, year) DO UPDATE SET update_process=excluded.update_process, update_version=excluded.update_version"#,
+            sales_person_id_vec,
+            entity.year,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
+
+    async fn all_dirty(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[CarryoverDirtyEntity]>, DaoError> {
+        Ok(query_as!(
+            CarryoverDirtyDb,
+            r#"SELECT sales_person_id, year, update_version
+               FROM carryover_dirty
+               ORDER BY year"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(CarryoverDirtyEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn clear_dirty(
+        &self,
+        entity: &CarryoverDirtyEntity,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let sales_person_id_vec = entity.sales_person_id.as_bytes().to_vec();
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"DELETE FROM carryover_dirty
+               WHERE sales_person_id = ? AND year = ? AND update_version = ?"#,
+            sales_person_id_vec,
+            entity.year,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        Ok(())
+    }
 }
@@ -169,18 +169,24 @@ impl CustomExtraHoursDao for CustomExtraHoursDaoImpl {
         let description = entity.description.as_ref().map(|d| d.as_ref());
         let modifies_balance = if entity.modifies_balance { 1 } else { 0 };
         let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
         let version = entity.version.as_bytes().to_vec();
 
         query!(
             r#"
             UPDATE custom_extra_hours
-            SET name = ?, description = ?, modifies_balance = ?, created = ?, deleted = NULL, update_version = ?, update_process = ?
+            SET name = ?, description = ?, modifies_balance = ?, created = ?, deleted = ?, update_version = ?, update_process = ?
             WHERE id = ?
             "#,
             name,
             description,
             modifies_balance,
             created_str,
+            deleted_str,
             version,
             process,
             id
@@ -25,6 +25,7 @@ pub struct EmployeeWorkDetailsDb {
     pub is_dynamic: i64,
     pub cap_planned_hours_to_expected: i64,
     pub committed_voluntary: f64,
+    pub use_actual_hours: i64,
 
     pub monday: i64,
     pub tuesday: i64,
@@ -63,6 +64,7 @@ impl TryFrom<&EmployeeWorkDetailsDb> for EmployeeWorkDetailsEntity {
             is_dynamic: working_hours.is_dynamic != 0,
             cap_planned_hours_to_expected: working_hours.cap_planned_hours_to_expected != 0,
             committed_voluntary: working_hours.committed_voluntary as f32,
+            use_actual_hours: working_hours.use_actual_hours != 0,
 
             monday: working_hours.monday != 0,
             tuesday: working_hours.tuesday != 0,
@@ -120,6 +122,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 is_dynamic,
                 cap_planned_hours_to_expected,
                 committed_voluntary,
+                use_actual_hours,
 
                 monday,
                 tuesday,
@@ -171,6 +174,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 is_dynamic,
                 cap_planned_hours_to_expected,
                 committed_voluntary,
+                use_actual_hours,
 
                 monday,
                 tuesday,
@@ -224,6 +228,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 is_dynamic,
                 cap_planned_hours_to_expected,
                 committed_voluntary,
+                use_actual_hours,
 
                 monday,
                 tuesday,
@@ -279,6 +284,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 is_dynamic,
                 cap_planned_hours_to_expected,
                 committed_voluntary,
+                use_actual_hours,
 
                 monday,
                 tuesday,
@@ -332,6 +338,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
         let is_dynamic = entity.is_dynamic as i64;
         let cap_planned_hours_to_expected = entity.cap_planned_hours_to_expected as i64;
         let committed_voluntary = entity.committed_voluntary as f64;
+        let use_actual_hours = entity.use_actual_hours as i64;
         let monday = entity.monday as i64;
         let tuesday = entity.tuesday as i64;
         let wednesday = entity.wednesday as i64;
@@ -359,6 +366,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 is_dynamic,
                 cap_planned_hours_to_expected,
                 committed_voluntary,
+                use_actual_hours,
 
                 monday,
                 tuesday,
@@ -373,7 +381,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 created,
                 update_process,
                 update_version
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             id,
             sales_person_id,
@@ -388,6 +396,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
             is_dynamic,
             cap_planned_hours_to_expected,
             committed_voluntary,
+            use_actual_hours,
             monday,
             tuesday,
             wednesday,
@@ -428,6 +437,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
         let is_dynamic = entity.is_dynamic as i64;
         let cap_planned_hours_to_expected = entity.cap_planned_hours_to_expected as i64;
         let committed_voluntary = entity.committed_voluntary as f64;
+        let use_actual_hours = entity.use_actual_hours as i64;
         query!(
             r#"
             UPDATE employee_work_details SET
@@ -441,7 +451,8 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
                 workdays_per_week = ?,
                 is_dynamic = ?,
                 cap_planned_hours_to_expected = ?,
-                committed_voluntary = ?
+                committed_voluntary = ?,
+                use_actual_hours = ?
             WHERE
                 id = ?
             "#,
@@ -456,6 +467,7 @@ impl EmployeeWorkDetailsDao for EmployeeWorkDetailsDaoImpl {
             is_dynamic,
             cap_planned_hours_to_expected,
             committed_voluntary,
+            use_actual_hours,
             id
         )
         .execute(tx.tx.lock().await.as_mut())
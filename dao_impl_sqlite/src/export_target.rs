@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    export_target::{
+        ExportArtifactType, ExportRunState, ExportTargetDao, ExportTargetEntity, ExportTargetKind,
+        ExportTargetRunEntity,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct ExportTargetDb {
+    id: Vec<u8>,
+    name: String,
+    kind: String,
+    enabled: i64,
+    url: Option<String>,
+    username: Option<String>,
+    secret: Option<String>,
+    path: String,
+    cron_schedule: String,
+    artifact_type: String,
+    filename_pattern: String,
+    weeks_horizon: i64,
+    created: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&ExportTargetDb> for ExportTargetEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &ExportTargetDb) -> Result<Self, Self::Error> {
+        Ok(ExportTargetEntity {
+            id: Uuid::from_slice(&db.id)?,
+            name: db.name.as_str().into(),
+            kind: ExportTargetKind::try_from(db.kind.as_str())?,
+            enabled: db.enabled != 0,
+            url: db.url.as_deref().map(Arc::from),
+            username: db.username.as_deref().map(Arc::from),
+            secret: db.secret.as_deref().map(Arc::from),
+            path: db.path.as_str().into(),
+            cron_schedule: db.cron_schedule.as_str().into(),
+            artifact_type: ExportArtifactType::try_from(db.artifact_type.as_str())?,
+            filename_pattern: db.filename_pattern.as_str().into(),
+            weeks_horizon: db.weeks_horizon as u32,
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            deleted: db
+                .deleted
+                .as_ref()
+                .map(|deleted| PrimitiveDateTime::parse(deleted, &Iso8601::DATE_TIME))
+                .transpose()?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ExportTargetRunDb {
+    id: Vec<u8>,
+    target_id: Vec<u8>,
+    started: String,
+    duration_ms: i64,
+    state: String,
+    files_written: i64,
+    files_skipped: i64,
+    message: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&ExportTargetRunDb> for ExportTargetRunEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &ExportTargetRunDb) -> Result<Self, Self::Error> {
+        Ok(ExportTargetRunEntity {
+            id: Uuid::from_slice(&db.id)?,
+            target_id: Uuid::from_slice(&db.target_id)?,
+            started: PrimitiveDateTime::parse(&db.started, &Iso8601::DATE_TIME)?,
+            duration_ms: db.duration_ms as u64,
+            state: ExportRunState::try_from(db.state.as_str())?,
+            files_written: db.files_written as u32,
+            files_skipped: db.files_skipped as u32,
+            message: db.message.as_deref().map(Arc::from),
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+pub struct ExportTargetDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl ExportTargetDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExportTargetDao for ExportTargetDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[ExportTargetEntity]>, DaoError> {
+        query_as!(
+            ExportTargetDb,
+            r#"SELECT id, name, kind, enabled, url, username, secret, path, cron_schedule,
+                      artifact_type, filename_pattern, weeks_horizon, created, deleted,
+                      update_version
+               FROM export_target
+               WHERE deleted IS NULL
+               ORDER BY name, created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ExportTargetEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ExportTargetEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            ExportTargetDb,
+            r#"SELECT id, name, kind, enabled, url, username, secret, path, cron_schedule,
+                      artifact_type, filename_pattern, weeks_horizon, created, deleted,
+                      update_version
+               FROM export_target
+               WHERE id = ? AND deleted IS NULL"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(ExportTargetEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &ExportTargetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let name = entity.name.as_ref();
+        let kind = entity.kind.to_string();
+        let enabled = entity.enabled as i64;
+        let url = entity.url.as_deref();
+        let username = entity.username.as_deref();
+        let secret = entity.secret.as_deref();
+        let path = entity.path.as_ref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let artifact_type = entity.artifact_type.to_string();
+        let filename_pattern = entity.filename_pattern.as_ref();
+        let weeks_horizon = entity.weeks_horizon as i64;
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO export_target
+                 (id, name, kind, enabled, url, username, secret, path, cron_schedule,
+                  artifact_type, filename_pattern, weeks_horizon, created, deleted,
+                  update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            name,
+            kind,
+            enabled,
+            url,
+            username,
+            secret,
+            path,
+            cron_schedule,
+            artifact_type,
+            filename_pattern,
+            weeks_horizon,
+            created_str,
+            deleted_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &ExportTargetEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let name = entity.name.as_ref();
+        let kind = entity.kind.to_string();
+        let enabled = entity.enabled as i64;
+        let url = entity.url.as_deref();
+        let username = entity.username.as_deref();
+        let secret = entity.secret.as_deref();
+        let path = entity.path.as_ref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let artifact_type = entity.artifact_type.to_string();
+        let filename_pattern = entity.filename_pattern.as_ref();
+        let weeks_horizon = entity.weeks_horizon as i64;
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE export_target
+               SET name = ?, kind = ?, enabled = ?, url = ?, username = ?, secret = ?, path = ?,
+                   cron_schedule = ?, artifact_type = ?, filename_pattern = ?, weeks_horizon = ?,
+                   deleted = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            name,
+            kind,
+            enabled,
+            url,
+            username,
+            secret,
+            path,
+            cron_schedule,
+            artifact_type,
+            filename_pattern,
+            weeks_horizon,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn create_run(
+        &self,
+        entity: &ExportTargetRunEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let target_id_vec = entity.target_id.as_bytes().to_vec();
+        let started_str = entity.started.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let duration_ms = entity.duration_ms as i64;
+        let state = entity.state.to_string();
+        let files_written = entity.files_written as i64;
+        let files_skipped = entity.files_skipped as i64;
+        let message = entity.message.as_deref();
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO export_target_run
+                 (id, target_id, started, duration_ms, state, files_written, files_skipped,
+                  message, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            target_id_vec,
+            started_str,
+            duration_ms,
+            state,
+            files_written,
+            files_skipped,
+            message,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_runs_by_target(
+        &self,
+        target_id: Uuid,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ExportTargetRunEntity]>, DaoError> {
+        let target_id_vec = target_id.as_bytes().to_vec();
+        let limit = limit as i64;
+        query_as!(
+            ExportTargetRunDb,
+            r#"SELECT id, target_id, started, duration_ms, state, files_written, files_skipped,
+                      message, update_version
+               FROM export_target_run
+               WHERE target_id = ?
+               ORDER BY started DESC
+               LIMIT ?"#,
+            target_id_vec,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ExportTargetRunEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+}
@@ -289,15 +289,6 @@ impl ExtraHoursDao for ExtraHoursDaoImpl {
             .map_db_error()?;
         Ok(())
     }
-    async fn delete(
-        &self,
-        _id: Uuid,
-        _process: &str,
-        _tx: Self::Transaction,
-    ) -> Result<(), crate::DaoError> {
-        unimplemented!()
-    }
-
     /// Phase 4 / C-Phase4-04 — bulk soft-delete by id list.
     ///
     /// Single UPDATE with `WHERE deleted IS NULL AND id IN (...)`. The
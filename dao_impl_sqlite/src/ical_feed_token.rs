@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    ical_feed_token::{IcalFeedScopeEntity, IcalFeedTokenDao, IcalFeedTokenEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct IcalFeedTokenDb {
+    id: Vec<u8>,
+    scope: String,
+    sales_person_id: Option<Vec<u8>>,
+    shiftplan_id: Option<Vec<u8>>,
+    token: Vec<u8>,
+    label: String,
+    created: String,
+    revoked: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&IcalFeedTokenDb> for IcalFeedTokenEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &IcalFeedTokenDb) -> Result<Self, Self::Error> {
+        Ok(IcalFeedTokenEntity {
+            id: Uuid::from_slice(&db.id)?,
+            scope: match (
+                db.scope.as_str(),
+                db.sales_person_id.as_deref(),
+                db.shiftplan_id.as_deref(),
+            ) {
+                ("sales-person", Some(sales_person_id), _) => {
+                    IcalFeedScopeEntity::SalesPerson(Uuid::from_slice(sales_person_id)?)
+                }
+                ("shiftplan", _, Some(shiftplan_id)) => {
+                    IcalFeedScopeEntity::Shiftplan(Uuid::from_slice(shiftplan_id)?)
+                }
+                ("team-absences", _, _) => IcalFeedScopeEntity::TeamAbsences,
+                (value, _, _) => return Err(DaoError::EnumValueNotFound(value.into())),
+            },
+            token: Uuid::from_slice(&db.token)?,
+            label: db.label.as_str().into(),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            revoked: db
+                .revoked
+                .as_ref()
+                .map(|revoked| PrimitiveDateTime::parse(revoked, &Iso8601::DATE_TIME))
+                .transpose()?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+/// Spaltenwerte `(scope, sales_person_id, shiftplan_id)` eines Scopes.
+fn scope_columns(scope: &IcalFeedScopeEntity) -> (&'static str, Option<Vec<u8>>, Option<Vec<u8>>) {
+    match scope {
+        IcalFeedScopeEntity::SalesPerson(id) => {
+            ("sales-person", Some(id.as_bytes().to_vec()), None)
+        }
+        IcalFeedScopeEntity::Shiftplan(id) => ("shiftplan", None, Some(id.as_bytes().to_vec())),
+        IcalFeedScopeEntity::TeamAbsences => ("team-absences", None, None),
+    }
+}
+
+pub struct IcalFeedTokenDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl IcalFeedTokenDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IcalFeedTokenDao for IcalFeedTokenDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_scope(
+        &self,
+        scope: IcalFeedScopeEntity,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[IcalFeedTokenEntity]>, DaoError> {
+        let (scope, sales_person_id_vec, shiftplan_id_vec) = scope_columns(&scope);
+        query_as!(
+            IcalFeedTokenDb,
+            r#"SELECT id, scope, sales_person_id, shiftplan_id, token, label, created, revoked, update_version
+               FROM ical_feed_token
+               WHERE scope = ? AND sales_person_id IS ? AND shiftplan_id IS ?
+               ORDER BY created"#,
+            scope,
+            sales_person_id_vec,
+            shiftplan_id_vec,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(IcalFeedTokenEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<IcalFeedTokenEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            IcalFeedTokenDb,
+            r#"SELECT id, scope, sales_person_id, shiftplan_id, token, label, created, revoked, update_version
+               FROM ical_feed_token
+               WHERE id = ?"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(IcalFeedTokenEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_token(
+        &self,
+        token: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<IcalFeedTokenEntity>, DaoError> {
+        let token_vec = token.as_bytes().to_vec();
+        query_as!(
+            IcalFeedTokenDb,
+            r#"SELECT id, scope, sales_person_id, shiftplan_id, token, label, created, revoked, update_version
+               FROM ical_feed_token
+               WHERE token = ?"#,
+            token_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(IcalFeedTokenEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &IcalFeedTokenEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let (scope, sales_person_id_vec, shiftplan_id_vec) = scope_columns(&entity.scope);
+        let token_vec = entity.token.as_bytes().to_vec();
+        let label = entity.label.as_ref();
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let revoked_str = entity
+            .revoked
+            .map(|revoked| revoked.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO ical_feed_token
+                 (id, scope, sales_person_id, shiftplan_id, token, label, created, revoked,
+                  update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            scope,
+            sales_person_id_vec,
+            shiftplan_id_vec,
+            token_vec,
+            label,
+            created_str,
+            revoked_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &IcalFeedTokenEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let label = entity.label.as_ref();
+        let revoked_str = entity
+            .revoked
+            .map(|revoked| revoked.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE ical_feed_token
+               SET label = ?, revoked = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            label,
+            revoked_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    notification::{
+        NotificationDao, NotificationKind, NotificationOutboxEntity, NotificationPreferenceEntity,
+        NotificationState,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct NotificationPreferenceDb {
+    user_id: String,
+    email: String,
+    booking_deleted: i64,
+    week_planned: i64,
+    absence_created: i64,
+    invitation_created: i64,
+    created: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&NotificationPreferenceDb> for NotificationPreferenceEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &NotificationPreferenceDb) -> Result<Self, Self::Error> {
+        Ok(NotificationPreferenceEntity {
+            user_id: db.user_id.as_str().into(),
+            email: db.email.as_str().into(),
+            booking_deleted: db.booking_deleted != 0,
+            week_planned: db.week_planned != 0,
+            absence_created: db.absence_created != 0,
+            invitation_created: db.invitation_created != 0,
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            deleted: parse_optional_date_time(&db.deleted)?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct NotificationOutboxDb {
+    id: Vec<u8>,
+    recipient_user_id: String,
+    recipient_email: String,
+    kind: String,
+    subject: String,
+    body: String,
+    state: String,
+    attempts: i64,
+    next_attempt: String,
+    last_error: Option<String>,
+    created: String,
+    sent: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&NotificationOutboxDb> for NotificationOutboxEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &NotificationOutboxDb) -> Result<Self, Self::Error> {
+        Ok(NotificationOutboxEntity {
+            id: Uuid::from_slice(&db.id)?,
+            recipient_user_id: db.recipient_user_id.as_str().into(),
+            recipient_email: db.recipient_email.as_str().into(),
+            kind: NotificationKind::try_from(db.kind.as_str())?,
+            subject: db.subject.as_str().into(),
+            body: db.body.as_str().into(),
+            state: NotificationState::try_from(db.state.as_str())?,
+            attempts: db.attempts as u32,
+            next_attempt: PrimitiveDateTime::parse(&db.next_attempt, &Iso8601::DATE_TIME)?,
+            last_error: db.last_error.as_deref().map(Arc::from),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            sent: parse_optional_date_time(&db.sent)?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+fn parse_optional_date_time(value: &Option<String>) -> Result<Option<PrimitiveDateTime>, DaoError> {
+    Ok(value
+        .as_ref()
+        .map(|s| PrimitiveDateTime::parse(s, &Iso8601::DATE_TIME))
+        .transpose()?)
+}
+
+fn format_optional_date_time(value: Option<PrimitiveDateTime>) -> Result<Option<String>, DaoError> {
+    value
+        .map(|d| d.format(&Iso8601::DATE_TIME))
+        .transpose()
+        .map_db_error()
+}
+
+pub struct NotificationDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl NotificationDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationDao for NotificationDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_preference_by_user_id(
+        &self,
+        user_id: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<NotificationPreferenceEntity>, DaoError> {
+        query_as!(
+            NotificationPreferenceDb,
+            r#"SELECT user_id, email, booking_deleted, week_planned, absence_created,
+                      invitation_created, created, deleted, update_version
+               FROM notification_preference
+               WHERE user_id = ? AND deleted IS NULL"#,
+            user_id,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(NotificationPreferenceEntity::try_from)
+        .transpose()
+    }
+
+    async fn all_preferences(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationPreferenceEntity]>, DaoError> {
+        query_as!(
+            NotificationPreferenceDb,
+            r#"SELECT user_id, email, booking_deleted, week_planned, absence_created,
+                      invitation_created, created, deleted, update_version
+               FROM notification_preference
+               WHERE deleted IS NULL
+               ORDER BY user_id"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(NotificationPreferenceEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create_preference(
+        &self,
+        entity: &NotificationPreferenceEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let user_id = entity.user_id.as_ref();
+        let email = entity.email.as_ref();
+        let booking_deleted = entity.booking_deleted as i64;
+        let week_planned = entity.week_planned as i64;
+        let absence_created = entity.absence_created as i64;
+        let invitation_created = entity.invitation_created as i64;
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted_str = format_optional_date_time(entity.deleted)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO notification_preference
+                 (user_id, email, booking_deleted, week_planned, absence_created,
+                  invitation_created, created, deleted, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            user_id,
+            email,
+            booking_deleted,
+            week_planned,
+            absence_created,
+            invitation_created,
+            created_str,
+            deleted_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_preference(
+        &self,
+        entity: &NotificationPreferenceEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let user_id = entity.user_id.as_ref();
+        let email = entity.email.as_ref();
+        let booking_deleted = entity.booking_deleted as i64;
+        let week_planned = entity.week_planned as i64;
+        let absence_created = entity.absence_created as i64;
+        let invitation_created = entity.invitation_created as i64;
+        let deleted_str = format_optional_date_time(entity.deleted)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE notification_preference
+               SET email = ?, booking_deleted = ?, week_planned = ?, absence_created = ?,
+                   invitation_created = ?, deleted = ?, update_process = ?, update_version = ?
+               WHERE user_id = ?"#,
+            email,
+            booking_deleted,
+            week_planned,
+            absence_created,
+            invitation_created,
+            deleted_str,
+            process,
+            version_vec,
+            user_id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_outbox_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<NotificationOutboxEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            NotificationOutboxDb,
+            r#"SELECT id, recipient_user_id, recipient_email, kind, subject, body, state,
+                      attempts, next_attempt, last_error, created, sent, update_version
+               FROM notification_outbox
+               WHERE id = ?"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(NotificationOutboxEntity::try_from)
+        .transpose()
+    }
+
+    async fn recent_outbox(
+        &self,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationOutboxEntity]>, DaoError> {
+        let limit = limit as i64;
+        query_as!(
+            NotificationOutboxDb,
+            r#"SELECT id, recipient_user_id, recipient_email, kind, subject, body, state,
+                      attempts, next_attempt, last_error, created, sent, update_version
+               FROM notification_outbox
+               ORDER BY created DESC
+               LIMIT ?"#,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(NotificationOutboxEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_due_outbox(
+        &self,
+        now: PrimitiveDateTime,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[NotificationOutboxEntity]>, DaoError> {
+        let now_str = now.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let limit = limit as i64;
+        query_as!(
+            NotificationOutboxDb,
+            r#"SELECT id, recipient_user_id, recipient_email, kind, subject, body, state,
+                      attempts, next_attempt, last_error, created, sent, update_version
+               FROM notification_outbox
+               WHERE state = 'Pending' AND next_attempt <= ?
+               ORDER BY next_attempt, created
+               LIMIT ?"#,
+            now_str,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(NotificationOutboxEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create_outbox(
+        &self,
+        entity: &NotificationOutboxEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let recipient_user_id = entity.recipient_user_id.as_ref();
+        let recipient_email = entity.recipient_email.as_ref();
+        let kind_str = entity.kind.to_string();
+        let subject = entity.subject.as_ref();
+        let body = entity.body.as_ref();
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let next_attempt_str = entity
+            .next_attempt
+            .format(&Iso8601::DATE_TIME)
+            .map_db_error()?;
+        let last_error = entity.last_error.as_deref();
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let sent_str = format_optional_date_time(entity.sent)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO notification_outbox
+                 (id, recipient_user_id, recipient_email, kind, subject, body, state,
+                  attempts, next_attempt, last_error, created, sent, update_process,
+                  update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            recipient_user_id,
+            recipient_email,
+            kind_str,
+            subject,
+            body,
+            state_str,
+            attempts,
+            next_attempt_str,
+            last_error,
+            created_str,
+            sent_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_outbox(
+        &self,
+        entity: &NotificationOutboxEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let next_attempt_str = entity
+            .next_attempt
+            .format(&Iso8601::DATE_TIME)
+            .map_db_error()?;
+        let last_error = entity.last_error.as_deref();
+        let sent_str = format_optional_date_time(entity.sent)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE notification_outbox
+               SET state = ?, attempts = ?, next_attempt = ?, last_error = ?, sent = ?,
+                   update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            state_str,
+            attempts,
+            next_attempt_str,
+            last_error,
+            sent_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
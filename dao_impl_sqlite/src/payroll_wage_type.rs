@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    payroll_wage_type::{PayrollValueView, PayrollWageTypeDao, PayrollWageTypeEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct PayrollWageTypeDb {
+    id: Vec<u8>,
+    value_type: String,
+    value_view: String,
+    wage_type: String,
+    processing_key: i64,
+    description: String,
+    created: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&PayrollWageTypeDb> for PayrollWageTypeEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &PayrollWageTypeDb) -> Result<Self, Self::Error> {
+        Ok(PayrollWageTypeEntity {
+            id: Uuid::from_slice(&db.id)?,
+            value_type: db.value_type.as_str().into(),
+            value_view: PayrollValueView::try_from(db.value_view.as_str())?,
+            wage_type: db.wage_type.as_str().into(),
+            processing_key: db.processing_key as u8,
+            description: db.description.as_str().into(),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            deleted: db
+                .deleted
+                .as_ref()
+                .map(|deleted| PrimitiveDateTime::parse(deleted, &Iso8601::DATE_TIME))
+                .transpose()?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+pub struct PayrollWageTypeDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl PayrollWageTypeDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PayrollWageTypeDao for PayrollWageTypeDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[PayrollWageTypeEntity]>, DaoError> {
+        query_as!(
+            PayrollWageTypeDb,
+            r#"SELECT id, value_type, value_view, wage_type, processing_key, description,
+                      created, deleted, update_version
+               FROM payroll_wage_type
+               WHERE deleted IS NULL
+               ORDER BY wage_type, created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(PayrollWageTypeEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<PayrollWageTypeEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            PayrollWageTypeDb,
+            r#"SELECT id, value_type, value_view, wage_type, processing_key, description,
+                      created, deleted, update_version
+               FROM payroll_wage_type
+               WHERE id = ? AND deleted IS NULL"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(PayrollWageTypeEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &PayrollWageTypeEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let value_type = entity.value_type.as_ref();
+        let value_view = entity.value_view.to_string();
+        let wage_type = entity.wage_type.as_ref();
+        let processing_key = entity.processing_key as i64;
+        let description = entity.description.as_ref();
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO payroll_wage_type
+                 (id, value_type, value_view, wage_type, processing_key, description,
+                  created, deleted, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            value_type,
+            value_view,
+            wage_type,
+            processing_key,
+            description,
+            created_str,
+            deleted_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &PayrollWageTypeEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let value_type = entity.value_type.as_ref();
+        let value_view = entity.value_view.to_string();
+        let wage_type = entity.wage_type.as_ref();
+        let processing_key = entity.processing_key as i64;
+        let description = entity.description.as_ref();
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE payroll_wage_type
+               SET value_type = ?, value_view = ?, wage_type = ?, processing_key = ?,
+                   description = ?, deleted = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            value_type,
+            value_view,
+            wage_type,
+            processing_key,
+            description,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::ResultDbErrorExt;
 use async_trait::async_trait;
 use dao::{
-    pdf_export_config::{PdfExportConfigDao, PdfExportConfigEntity},
+    pdf_export_config::{PdfExportConfigDao, PdfExportConfigEntity, PdfExportShiftplanEntity},
     DaoError,
 };
 use sqlx::{query, query_as};
@@ -22,6 +22,8 @@ struct PdfExportConfigDb {
     target_folder: Option<String>,
     weeks_horizon: i64,
     cron_schedule: String,
+    include_planning_plans: i64,
+    combined_pdf: i64,
     last_success_at: Option<String>,
     last_error_at: Option<String>,
     last_error_message: Option<String>,
@@ -41,6 +43,8 @@ impl TryFrom<&PdfExportConfigDb> for PdfExportConfigEntity {
             target_folder: db.target_folder.as_deref().map(Arc::from),
             weeks_horizon: db.weeks_horizon as u32,
             cron_schedule: Arc::from(db.cron_schedule.as_str()),
+            include_planning_plans: db.include_planning_plans != 0,
+            combined_pdf: db.combined_pdf != 0,
             last_success_at: db
                 .last_success_at
                 .as_ref()
@@ -57,6 +61,27 @@ impl TryFrom<&PdfExportConfigDb> for PdfExportConfigEntity {
     }
 }
 
+#[derive(Debug)]
+struct PdfExportShiftplanDb {
+    shiftplan_id: Vec<u8>,
+    included: i64,
+    subfolder: Option<String>,
+    filename_prefix: Option<String>,
+}
+
+impl TryFrom<&PdfExportShiftplanDb> for PdfExportShiftplanEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &PdfExportShiftplanDb) -> Result<Self, Self::Error> {
+        Ok(PdfExportShiftplanEntity {
+            shiftplan_id: Uuid::from_slice(&db.shiftplan_id)?,
+            included: db.included != 0,
+            subfolder: db.subfolder.as_deref().map(Arc::from),
+            filename_prefix: db.filename_prefix.as_deref().map(Arc::from),
+        })
+    }
+}
+
 pub struct PdfExportConfigDaoImpl {
     pub _pool: Arc<sqlx::SqlitePool>,
 }
@@ -83,6 +108,8 @@ impl PdfExportConfigDao for PdfExportConfigDaoImpl {
                 target_folder,
                 weeks_horizon,
                 cron_schedule,
+                include_planning_plans,
+                combined_pdf,
                 last_success_at,
                 last_error_at,
                 last_error_message,
@@ -116,6 +143,8 @@ impl PdfExportConfigDao for PdfExportConfigDaoImpl {
         let target_folder = entity.target_folder.as_deref();
         let cron_schedule = entity.cron_schedule.as_ref();
         let weeks_horizon = entity.weeks_horizon as i64;
+        let include_planning_plans = entity.include_planning_plans as i64;
+        let combined_pdf = entity.combined_pdf as i64;
 
         query!(
             r#"UPDATE pdf_export_config
@@ -126,6 +155,8 @@ impl PdfExportConfigDao for PdfExportConfigDaoImpl {
                    target_folder = ?,
                    weeks_horizon = ?,
                    cron_schedule = ?,
+                   include_planning_plans = ?,
+                   combined_pdf = ?,
                    update_process = ?,
                    update_version = ?
                WHERE id = ?"#,
@@ -136,6 +167,8 @@ impl PdfExportConfigDao for PdfExportConfigDaoImpl {
             target_folder,
             weeks_horizon,
             cron_schedule,
+            include_planning_plans,
+            combined_pdf,
             process,
             version_vec,
             id_vec,
@@ -203,4 +236,59 @@ impl PdfExportConfigDao for PdfExportConfigDaoImpl {
 
         Ok(())
     }
+
+    async fn get_shiftplan_settings(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[PdfExportShiftplanEntity]>, DaoError> {
+        let rows = query_as!(
+            PdfExportShiftplanDb,
+            r#"SELECT shiftplan_id, included, subfolder, filename_prefix
+              FROM pdf_export_shiftplan"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        rows.iter()
+            .map(PdfExportShiftplanEntity::try_from)
+            .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn replace_shiftplan_settings(
+        &self,
+        settings: &[PdfExportShiftplanEntity],
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let version_vec = version.as_bytes().to_vec();
+        query!(r#"DELETE FROM pdf_export_shiftplan"#)
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+
+        for setting in settings {
+            let shiftplan_id_vec = setting.shiftplan_id.as_bytes().to_vec();
+            let included = setting.included as i64;
+            let subfolder = setting.subfolder.as_deref();
+            let filename_prefix = setting.filename_prefix.as_deref();
+            query!(
+                r#"INSERT INTO pdf_export_shiftplan
+                     (shiftplan_id, included, subfolder, filename_prefix, update_process, update_version)
+                   VALUES (?, ?, ?, ?, ?, ?)"#,
+                shiftplan_id_vec,
+                included,
+                subfolder,
+                filename_prefix,
+                process,
+                version_vec,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,353 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    qualification::{
+        QualificationDao, QualificationEntity, SalesPersonQualificationEntity,
+        SlotQualificationRequirementEntity,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct QualificationDb {
+    id: Vec<u8>,
+    name: String,
+    description: String,
+    created: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&QualificationDb> for QualificationEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &QualificationDb) -> Result<Self, Self::Error> {
+        Ok(QualificationEntity {
+            id: Uuid::from_slice(&db.id)?,
+            name: db.name.as_str().into(),
+            description: db.description.as_str().into(),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            deleted: db
+                .deleted
+                .as_ref()
+                .map(|deleted| PrimitiveDateTime::parse(deleted, &Iso8601::DATE_TIME))
+                .transpose()?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SalesPersonQualificationDb {
+    sales_person_id: Vec<u8>,
+    qualification_id: Vec<u8>,
+}
+
+impl TryFrom<&SalesPersonQualificationDb> for SalesPersonQualificationEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SalesPersonQualificationDb) -> Result<Self, Self::Error> {
+        Ok(SalesPersonQualificationEntity {
+            sales_person_id: Uuid::from_slice(&db.sales_person_id)?,
+            qualification_id: Uuid::from_slice(&db.qualification_id)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SlotQualificationRequirementDb {
+    slot_id: Vec<u8>,
+    qualification_id: Vec<u8>,
+    min_count: i64,
+}
+
+impl TryFrom<&SlotQualificationRequirementDb> for SlotQualificationRequirementEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SlotQualificationRequirementDb) -> Result<Self, Self::Error> {
+        Ok(SlotQualificationRequirementEntity {
+            slot_id: Uuid::from_slice(&db.slot_id)?,
+            qualification_id: Uuid::from_slice(&db.qualification_id)?,
+            min_count: db.min_count as u8,
+        })
+    }
+}
+
+pub struct QualificationDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl QualificationDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QualificationDao for QualificationDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[QualificationEntity]>, DaoError> {
+        query_as!(
+            QualificationDb,
+            r#"SELECT id, name, description, created, deleted, update_version
+               FROM qualification
+               WHERE deleted IS NULL
+               ORDER BY name"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(QualificationEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<QualificationEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            QualificationDb,
+            r#"SELECT id, name, description, created, deleted, update_version
+               FROM qualification
+               WHERE id = ? AND deleted IS NULL"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(QualificationEntity::try_from)
+        .transpose()
+    }
+
+    async fn create(
+        &self,
+        entity: &QualificationEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let name = entity.name.as_ref();
+        let description = entity.description.as_ref();
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO qualification
+                 (id, name, description, created, deleted, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            name,
+            description,
+            created_str,
+            deleted_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &QualificationEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let name = entity.name.as_ref();
+        let description = entity.description.as_ref();
+        let deleted_str = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE qualification
+               SET name = ?, description = ?, deleted = ?, update_process = ?,
+                   update_version = ?
+               WHERE id = ?"#,
+            name,
+            description,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn all_sales_person_qualifications(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonQualificationEntity]>, DaoError> {
+        query_as!(
+            SalesPersonQualificationDb,
+            r#"SELECT spq.sales_person_id, spq.qualification_id
+               FROM sales_person_qualification spq
+               JOIN qualification q ON q.id = spq.qualification_id
+               WHERE q.deleted IS NULL"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonQualificationEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonQualificationEntity]>, DaoError> {
+        let sales_person_id_vec = sales_person_id.as_bytes().to_vec();
+        query_as!(
+            SalesPersonQualificationDb,
+            r#"SELECT spq.sales_person_id, spq.qualification_id
+               FROM sales_person_qualification spq
+               JOIN qualification q ON q.id = spq.qualification_id
+               WHERE spq.sales_person_id = ? AND q.deleted IS NULL
+               ORDER BY q.name"#,
+            sales_person_id_vec,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonQualificationEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn set_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        qualification_ids: &[Uuid],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let sales_person_id_vec = sales_person_id.as_bytes().to_vec();
+        query!(
+            "DELETE FROM sales_person_qualification WHERE sales_person_id = ?",
+            sales_person_id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        for qualification_id in qualification_ids {
+            let qualification_id_vec = qualification_id.as_bytes().to_vec();
+            query!(
+                r#"INSERT INTO sales_person_qualification
+                     (sales_person_id, qualification_id, update_process)
+                   VALUES (?, ?, ?)"#,
+                sales_person_id_vec,
+                qualification_id_vec,
+                process,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+        Ok(())
+    }
+
+    async fn all_slot_requirements(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotQualificationRequirementEntity]>, DaoError> {
+        query_as!(
+            SlotQualificationRequirementDb,
+            r#"SELECT sqr.slot_id, sqr.qualification_id, sqr.min_count
+               FROM slot_qualification_requirement sqr
+               JOIN qualification q ON q.id = sqr.qualification_id
+               WHERE q.deleted IS NULL"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SlotQualificationRequirementEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_requirements_by_slot(
+        &self,
+        slot_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SlotQualificationRequirementEntity]>, DaoError> {
+        let slot_id_vec = slot_id.as_bytes().to_vec();
+        query_as!(
+            SlotQualificationRequirementDb,
+            r#"SELECT sqr.slot_id, sqr.qualification_id, sqr.min_count
+               FROM slot_qualification_requirement sqr
+               JOIN qualification q ON q.id = sqr.qualification_id
+               WHERE sqr.slot_id = ? AND q.deleted IS NULL
+               ORDER BY q.name"#,
+            slot_id_vec,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SlotQualificationRequirementEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn set_requirements_for_slot(
+        &self,
+        slot_id: Uuid,
+        requirements: &[SlotQualificationRequirementEntity],
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let slot_id_vec = slot_id.as_bytes().to_vec();
+        query!(
+            "DELETE FROM slot_qualification_requirement WHERE slot_id = ?",
+            slot_id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+
+        for requirement in requirements {
+            let qualification_id_vec = requirement.qualification_id.as_bytes().to_vec();
+            let min_count = requirement.min_count as i64;
+            query!(
+                r#"INSERT INTO slot_qualification_requirement
+                     (slot_id, qualification_id, min_count, update_process)
+                   VALUES (?, ?, ?, ?)"#,
+                slot_id_vec,
+                qualification_id_vec,
+                min_count,
+                process,
+            )
+            .execute(tx.tx.lock().await.as_mut())
+            .await
+            .map_db_error()?;
+        }
+        Ok(())
+    }
+}
@@ -32,6 +32,9 @@ struct RebookingBatchDb {
     created: String,
     approved: Option<String>,
     approved_by: Option<String>,
+    rejected: Option<String>,
+    rejected_by: Option<String>,
+    reject_reason: Option<String>,
     deleted: Option<String>,
     update_version: Vec<u8>,
 }
@@ -54,6 +57,13 @@ impl TryFrom<&RebookingBatchDb> for RebookingBatchEntity {
                 .map(|s| PrimitiveDateTime::parse(s, &Iso8601::DATE_TIME))
                 .transpose()?,
             approved_by: db.approved_by.as_ref().map(|s| Arc::<str>::from(s.as_str())),
+            rejected: db
+                .rejected
+                .as_ref()
+                .map(|s| PrimitiveDateTime::parse(s, &Iso8601::DATE_TIME))
+                .transpose()?,
+            rejected_by: db.rejected_by.as_ref().map(|s| Arc::<str>::from(s.as_str())),
+            reject_reason: db.reject_reason.as_ref().map(|s| Arc::<str>::from(s.as_str())),
             deleted: db
                 .deleted
                 .as_ref()
@@ -182,7 +192,8 @@ impl RebookingBatchDao for RebookingBatchDaoImpl {
         Ok(query_as!(
             RebookingBatchDb,
             r#"SELECT id, sales_person_id, iso_year, iso_week, kind, state, created,
-                      approved, approved_by, deleted, update_version
+                      approved, approved_by, rejected, rejected_by, reject_reason,
+                      deleted, update_version
                FROM rebooking_batch
                WHERE id = ? AND deleted IS NULL"#,
             id_vec,
@@ -206,7 +217,8 @@ impl RebookingBatchDao for RebookingBatchDaoImpl {
         Ok(query_as!(
             RebookingBatchDb,
             r#"SELECT id, sales_person_id, iso_year, iso_week, kind, state, created,
-                      approved, approved_by, deleted, update_version
+                      approved, approved_by, rejected, rejected_by, reject_reason,
+                      deleted, update_version
                FROM rebooking_batch
                WHERE sales_person_id = ? AND iso_year = ? AND iso_week = ?
                  AND deleted IS NULL"#,
@@ -240,6 +252,13 @@ impl RebookingBatchDao for RebookingBatchDaoImpl {
             .transpose()
             .map_db_error()?;
         let approved_by_str = batch.approved_by.as_ref().map(|s| s.to_string());
+        let rejected_str = batch
+            .rejected
+            .map(|d| d.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let rejected_by_str = batch.rejected_by.as_deref();
+        let reject_reason_str = batch.reject_reason.as_deref();
         let deleted_str = batch
             .deleted
             .map(|d| d.format(&Iso8601::DATE_TIME))
@@ -250,8 +269,9 @@ impl RebookingBatchDao for RebookingBatchDaoImpl {
         query!(
             r#"INSERT INTO rebooking_batch
                    (id, sales_person_id, iso_year, iso_week, kind, state, created,
-                    approved, approved_by, deleted, update_process, update_version)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+                    approved, approved_by, rejected, rejected_by, reject_reason,
+                    deleted, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
             id_vec,
             sp_vec,
             batch.iso_year,
@@ -261,6 +281,9 @@ impl RebookingBatchDao for RebookingBatchDaoImpl {
             created_str,
             approved_str,
             approved_by_str,
+            rejected_str,
+            rejected_by_str,
+            reject_reason_str,
             deleted_str,
             process,
             version_vec,
@@ -339,6 +362,113 @@ impl RebookingBatchDao for RebookingBatchDaoImpl {
         .map(RebookingBatchEntryEntity::try_from)
         .collect::<Result<Arc<[_]>, _>>()?)
     }
+
+    async fn find_by_state(
+        &self,
+        state: RebookingBatchState,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[RebookingBatchEntity]>, DaoError> {
+        let state_str = state_to_str(&state);
+        Ok(query_as!(
+            RebookingBatchDb,
+            r#"SELECT id, sales_person_id, iso_year, iso_week, kind, state, created,
+                      approved, approved_by, rejected, rejected_by, reject_reason,
+                      deleted, update_version
+               FROM rebooking_batch
+               WHERE state = ? AND deleted IS NULL
+               ORDER BY created, iso_year, iso_week"#,
+            state_str,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(RebookingBatchEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()?)
+    }
+
+    async fn update_batch(
+        &self,
+        batch: &RebookingBatchEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = batch.id.as_bytes().to_vec();
+        let state_str = state_to_str(&batch.state);
+        let approved_str = batch
+            .approved
+            .map(|d| d.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let approved_by_str = batch.approved_by.as_deref();
+        let rejected_str = batch
+            .rejected
+            .map(|d| d.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let rejected_by_str = batch.rejected_by.as_deref();
+        let reject_reason_str = batch.reject_reason.as_deref();
+        let deleted_str = batch
+            .deleted
+            .map(|d| d.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = batch.version.as_bytes().to_vec();
+        query!(
+            r#"UPDATE rebooking_batch
+               SET state = ?, approved = ?, approved_by = ?, rejected = ?,
+                   rejected_by = ?, reject_reason = ?, deleted = ?,
+                   update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            state_str,
+            approved_str,
+            approved_by_str,
+            rejected_str,
+            rejected_by_str,
+            reject_reason_str,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_entry(
+        &self,
+        entry: &RebookingBatchEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entry.id.as_bytes().to_vec();
+        let out_vec = entry.extra_hours_out_id.map(|u| u.as_bytes().to_vec());
+        let in_vec = entry.extra_hours_in_id.map(|u| u.as_bytes().to_vec());
+        let deleted_str = entry
+            .deleted
+            .map(|d| d.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version_vec = entry.version.as_bytes().to_vec();
+        query!(
+            r#"UPDATE rebooking_batch_entry
+               SET extra_hours_out_id = ?, extra_hours_in_id = ?, deleted = ?,
+                   update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            out_vec,
+            in_vec,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
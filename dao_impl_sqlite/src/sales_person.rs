@@ -26,6 +26,7 @@ struct SalesPersonDb {
     background_color: String,
     is_paid: bool,
     inactive: bool,
+    personnel_number: Option<String>,
     deleted: Option<String>,
     update_version: Vec<u8>,
 }
@@ -38,6 +39,7 @@ impl TryFrom<&SalesPersonDb> for SalesPersonEntity {
             background_color: sales_person.background_color.as_str().into(),
             is_paid: sales_person.is_paid,
             inactive: sales_person.inactive,
+            personnel_number: sales_person.personnel_number.as_deref().map(Arc::from),
             deleted: sales_person
                 .deleted
                 .as_ref()
@@ -55,7 +57,7 @@ impl SalesPersonDao for SalesPersonDaoImpl {
     async fn all(&self, tx: Self::Transaction) -> Result<Arc<[SalesPersonEntity]>, DaoError> {
         Ok(query_as!(
             SalesPersonDb,
-            "SELECT id, name, background_color, is_paid, inactive, deleted, update_version FROM sales_person WHERE deleted IS NULL"
+            "SELECT id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version FROM sales_person WHERE deleted IS NULL"
         )
             .fetch_all(tx.tx.lock().await.as_mut())
             .await
@@ -69,7 +71,7 @@ impl SalesPersonDao for SalesPersonDaoImpl {
     async fn all_paid(&self, tx: Self::Transaction) -> Result<Arc<[SalesPersonEntity]>, DaoError> {
         Ok(query_as!(
             SalesPersonDb,
-            "SELECT id, name, background_color, is_paid, inactive, deleted, update_version FROM sales_person WHERE deleted IS NULL AND is_paid = 1"
+            "SELECT id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version FROM sales_person WHERE deleted IS NULL AND is_paid = 1"
         )
             .fetch_all(tx.tx.lock().await.as_mut())
             .await
@@ -88,7 +90,7 @@ impl SalesPersonDao for SalesPersonDaoImpl {
         let id_vec = id.as_bytes().to_vec();
         Ok(query_as!(
             SalesPersonDb,
-            "SELECT id, name, background_color, is_paid, inactive, deleted, update_version FROM sales_person WHERE id = ?",
+            "SELECT id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version FROM sales_person WHERE id = ?",
             id_vec
         )
         .fetch_optional(tx.tx.lock().await.as_mut())
@@ -106,7 +108,7 @@ impl SalesPersonDao for SalesPersonDaoImpl {
     ) -> Result<Option<SalesPersonEntity>, DaoError> {
         Ok(query_as!(
             SalesPersonDb,
-            "SELECT sp.id, sp.name, sp.background_color, sp.is_paid, sp.inactive, sp.deleted, sp.update_version FROM sales_person sp JOIN sales_person_user spu ON sp.id = spu.sales_person_id WHERE spu.user_id = ?",
+            "SELECT sp.id, sp.name, sp.background_color, sp.is_paid, sp.inactive, sp.personnel_number, sp.deleted, sp.update_version FROM sales_person sp JOIN sales_person_user spu ON sp.id = spu.sales_person_id WHERE spu.user_id = ?",
             user_id
         )
         .fetch_optional(tx.tx.lock().await.as_mut())
@@ -129,8 +131,9 @@ impl SalesPersonDao for SalesPersonDaoImpl {
         let background_color = entity.background_color.as_ref();
         let is_paid = entity.is_paid;
         let inactive = entity.inactive;
+        let personnel_number = entity.personnel_number.as_deref();
         let deleted = entity.deleted.as_ref().map(|deleted| deleted.to_string());
-        query!("INSERT INTO sales_person (id, name, background_color, is_paid, inactive, deleted, update_version, update_process) VALUES (?, ?, ?, ?, ?, ?, ?, ?)", id, name, background_color, is_paid, inactive, deleted, version, process)
+        query!("INSERT INTO sales_person (id, name, background_color, is_paid, inactive, personnel_number, deleted, update_version, update_process) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)", id, name, background_color, is_paid, inactive, personnel_number, deleted, version, process)
             .execute(tx.tx.lock().await.as_mut())
             .await
             .map_db_error()?;
@@ -148,8 +151,9 @@ impl SalesPersonDao for SalesPersonDaoImpl {
         let background_color = entity.background_color.as_ref();
         let is_paid = entity.is_paid;
         let inactive = entity.inactive;
+        let personnel_number = entity.personnel_number.as_deref();
         let deleted = entity.deleted.as_ref().map(|deleted| deleted.to_string());
-        query!("UPDATE sales_person SET name = ?, background_color = ?, is_paid = ?, inactive = ?, deleted = ?, update_version = ?, update_process = ? WHERE id = ?", name, background_color, is_paid, inactive, deleted, version, process, id)
+        query!("UPDATE sales_person SET name = ?, background_color = ?, is_paid = ?, inactive = ?, personnel_number = ?, deleted = ?, update_version = ?, update_process = ? WHERE id = ?", name, background_color, is_paid, inactive, personnel_number, deleted, version, process, id)
             .execute(tx.tx.lock().await.as_mut())
             .await
             .map_db_error()?;
@@ -230,7 +234,7 @@ impl SalesPersonDao for SalesPersonDaoImpl {
     ) -> Result<Option<SalesPersonEntity>, DaoError> {
         Ok(query_as!(
             SalesPersonDb,
-            "SELECT sp.id, sp.name, sp.background_color, sp.is_paid, sp.inactive, sp.deleted, sp.update_version FROM sales_person sp JOIN sales_person_user spu ON sp.id = spu.sales_person_id WHERE spu.user_id = ?",
+            "SELECT sp.id, sp.name, sp.background_color, sp.is_paid, sp.inactive, sp.personnel_number, sp.deleted, sp.update_version FROM sales_person sp JOIN sales_person_user spu ON sp.id = spu.sales_person_id WHERE spu.user_id = ?",
             user_id
         )
             .fetch_optional(tx.tx.lock().await.as_mut())
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    sales_person_availability::{
+        AvailabilityKind, SalesPersonAvailabilityDao, SalesPersonAvailabilityEntity,
+    },
+    DaoError,
+};
+use shifty_utils::DayOfWeek;
+use sqlx::{query, query_as};
+use time::{
+    format_description::well_known::Iso8601, macros::format_description, Date, PrimitiveDateTime,
+    Time,
+};
+use uuid::Uuid;
+
+struct SalesPersonAvailabilityDb {
+    id: Vec<u8>,
+    sales_person_id: Vec<u8>,
+    day_of_week: i64,
+    time_from: Option<String>,
+    time_to: Option<String>,
+    valid_from: String,
+    valid_to: Option<String>,
+    kind: String,
+    created: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&SalesPersonAvailabilityDb> for SalesPersonAvailabilityEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SalesPersonAvailabilityDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::from_slice(&db.id)?,
+            sales_person_id: Uuid::from_slice(&db.sales_person_id)?,
+            day_of_week: DayOfWeek::from_number(db.day_of_week as u8)
+                .ok_or(DaoError::InvalidDayOfWeek(db.day_of_week as u8))?,
+            time_from: db
+                .time_from
+                .as_ref()
+                .map(|time| Time::parse(time, &Iso8601::TIME))
+                .transpose()?,
+            time_to: db
+                .time_to
+                .as_ref()
+                .map(|time| Time::parse(time, &Iso8601::TIME))
+                .transpose()?,
+            valid_from: Date::parse(&db.valid_from, &Iso8601::DATE)?,
+            valid_to: db
+                .valid_to
+                .as_ref()
+                .map(|date| Date::parse(date, &Iso8601::DATE))
+                .transpose()?,
+            kind: str_to_kind(&db.kind)?,
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            deleted: db
+                .deleted
+                .as_ref()
+                .map(|deleted| PrimitiveDateTime::parse(deleted, &Iso8601::DATE_TIME))
+                .transpose()?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+fn kind_to_str(kind: AvailabilityKind) -> &'static str {
+    match kind {
+        AvailabilityKind::Preferred => "Preferred",
+        AvailabilityKind::Available => "Available",
+        AvailabilityKind::Unavailable => "Unavailable",
+    }
+}
+
+fn str_to_kind(value: &str) -> Result<AvailabilityKind, DaoError> {
+    match value {
+        "Preferred" => Ok(AvailabilityKind::Preferred),
+        "Available" => Ok(AvailabilityKind::Available),
+        "Unavailable" => Ok(AvailabilityKind::Unavailable),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+fn format_time(time: Option<Time>) -> Result<Option<String>, DaoError> {
+    let time_format = format_description!("[hour]:[minute]:[second].0");
+    Ok(time.map(|time| time.format(&time_format)).transpose()?)
+}
+
+pub struct SalesPersonAvailabilityDaoImpl {
+    pub _pool: Arc<sqlx::SqlitePool>,
+}
+impl SalesPersonAvailabilityDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl SalesPersonAvailabilityDao for SalesPersonAvailabilityDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<SalesPersonAvailabilityEntity>, DaoError> {
+        let id = id.as_bytes().to_vec();
+        query_as!(
+            SalesPersonAvailabilityDb,
+            "SELECT id, sales_person_id, day_of_week, time_from, time_to, valid_from, valid_to, kind, created, deleted, update_version FROM sales_person_availability WHERE id = ? AND deleted IS NULL",
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SalesPersonAvailabilityEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_all_by_sales_person_id(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[SalesPersonAvailabilityEntity]>, DaoError> {
+        let id = sales_person_id.as_bytes().to_vec();
+        query_as!(
+            SalesPersonAvailabilityDb,
+            "SELECT id, sales_person_id, day_of_week, time_from, time_to, valid_from, valid_to, kind, created, deleted, update_version FROM sales_person_availability WHERE sales_person_id = ? AND deleted IS NULL ORDER BY day_of_week, time_from",
+            id
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SalesPersonAvailabilityEntity::try_from)
+        .collect::<Result<Arc<[SalesPersonAvailabilityEntity]>, DaoError>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &SalesPersonAvailabilityEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id = entity.id.as_bytes().to_vec();
+        let version = entity.version.as_bytes().to_vec();
+        let sales_person_id = entity.sales_person_id.as_bytes().to_vec();
+        let day_of_week = entity.day_of_week.to_number() as i64;
+        let time_from = format_time(entity.time_from)?;
+        let time_to = format_time(entity.time_to)?;
+        let valid_from = entity.valid_from.to_string();
+        let valid_to = entity.valid_to.map(|valid_to| valid_to.to_string());
+        let kind = kind_to_str(entity.kind);
+        let created = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        query!(
+            r"INSERT INTO sales_person_availability (id, sales_person_id, day_of_week, time_from, time_to, valid_from, valid_to, kind, created, deleted, update_version, update_process) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            id,
+            sales_person_id,
+            day_of_week,
+            time_from,
+            time_to,
+            valid_from,
+            valid_to,
+            kind,
+            created,
+            deleted,
+            version,
+            process,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &SalesPersonAvailabilityEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id = entity.id.as_bytes().to_vec();
+        let version = entity.version.as_bytes().to_vec();
+        let day_of_week = entity.day_of_week.to_number() as i64;
+        let time_from = format_time(entity.time_from)?;
+        let time_to = format_time(entity.time_to)?;
+        let valid_from = entity.valid_from.to_string();
+        let valid_to = entity.valid_to.map(|valid_to| valid_to.to_string());
+        let kind = kind_to_str(entity.kind);
+        let deleted = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        query!(
+            r"UPDATE sales_person_availability SET day_of_week = ?, time_from = ?, time_to = ?, valid_from = ?, valid_to = ?, kind = ?, deleted = ?, update_version = ?, update_process = ? WHERE id = ?",
+            day_of_week,
+            time_from,
+            time_to,
+            valid_from,
+            valid_to,
+            kind,
+            deleted,
+            version,
+            process,
+            id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_roundtrip() {
+        for kind in [
+            AvailabilityKind::Preferred,
+            AvailabilityKind::Available,
+            AvailabilityKind::Unavailable,
+        ] {
+            assert_eq!(str_to_kind(kind_to_str(kind)).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn unknown_kind() {
+        match str_to_kind("Sometimes") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Sometimes"),
+            other => panic!("expected EnumValueNotFound(\"Sometimes\"), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn time_format_parses_back() {
+        let time = Time::from_hms(14, 30, 0).unwrap();
+        let formatted = format_time(Some(time)).unwrap().unwrap();
+        assert_eq!(Time::parse(&formatted, &Iso8601::TIME).unwrap(), time);
+        assert_eq!(format_time(None).unwrap(), None);
+    }
+}
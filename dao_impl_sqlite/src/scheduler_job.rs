@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    scheduler_job::{SchedulerJobDao, SchedulerJobEntity},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct SchedulerJobDb {
+    name: String,
+    cron_schedule: String,
+    enabled: i64,
+    last_run_at: Option<String>,
+    last_duration_ms: Option<i64>,
+    last_error_at: Option<String>,
+    last_error_message: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&SchedulerJobDb> for SchedulerJobEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &SchedulerJobDb) -> Result<Self, Self::Error> {
+        Ok(SchedulerJobEntity {
+            name: db.name.as_str().into(),
+            cron_schedule: db.cron_schedule.as_str().into(),
+            enabled: db.enabled != 0,
+            last_run_at: parse_optional_date_time(&db.last_run_at)?,
+            last_duration_ms: db.last_duration_ms.map(|ms| ms as u64),
+            last_error_at: parse_optional_date_time(&db.last_error_at)?,
+            last_error_message: db.last_error_message.as_deref().map(Arc::from),
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+fn parse_optional_date_time(value: &Option<String>) -> Result<Option<PrimitiveDateTime>, DaoError> {
+    Ok(value
+        .as_ref()
+        .map(|s| PrimitiveDateTime::parse(s, &Iso8601::DATE_TIME))
+        .transpose()?)
+}
+
+pub struct SchedulerJobDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl SchedulerJobDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SchedulerJobDao for SchedulerJobDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all(&self, tx: Self::Transaction) -> Result<Arc<[SchedulerJobEntity]>, DaoError> {
+        query_as!(
+            SchedulerJobDb,
+            r#"SELECT name, cron_schedule, enabled, last_run_at, last_duration_ms,
+                      last_error_at, last_error_message, update_version
+               FROM scheduler_job
+               ORDER BY name"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(SchedulerJobEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+        tx: Self::Transaction,
+    ) -> Result<Option<SchedulerJobEntity>, DaoError> {
+        query_as!(
+            SchedulerJobDb,
+            r#"SELECT name, cron_schedule, enabled, last_run_at, last_duration_ms,
+                      last_error_at, last_error_message, update_version
+               FROM scheduler_job
+               WHERE name = ?"#,
+            name,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(SchedulerJobEntity::try_from)
+        .transpose()
+    }
+
+    async fn update(
+        &self,
+        entity: &SchedulerJobEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let name = entity.name.as_ref();
+        let cron_schedule = entity.cron_schedule.as_ref();
+        let enabled = entity.enabled as i64;
+        let version_vec = entity.version.as_bytes().to_vec();
+        query!(
+            r#"UPDATE scheduler_job
+               SET cron_schedule = ?, enabled = ?, update_process = ?, update_version = ?
+               WHERE name = ?"#,
+            cron_schedule,
+            enabled,
+            process,
+            version_vec,
+            name,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn record_run(
+        &self,
+        name: &str,
+        started_at: PrimitiveDateTime,
+        duration_ms: u64,
+        error: Option<Arc<str>>,
+        process: &str,
+        version: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let started_at_str = started_at.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let error_at_str = error.as_ref().map(|_| started_at_str.clone());
+        let error = error.as_deref();
+        let duration_ms = duration_ms as i64;
+        let version_vec = version.as_bytes().to_vec();
+        query!(
+            r#"UPDATE scheduler_job
+               SET last_run_at = ?, last_duration_ms = ?, last_error_at = ?,
+                   last_error_message = ?, update_process = ?, update_version = ?
+               WHERE name = ?"#,
+            started_at_str,
+            duration_ms,
+            error_at_str,
+            error,
+            process,
+            version_vec,
+            name,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
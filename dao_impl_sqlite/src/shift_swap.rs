@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    shift_swap::{ShiftSwapRequestDao, ShiftSwapRequestEntity, ShiftSwapState},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct ShiftSwapRequestDb {
+    id: Vec<u8>,
+    booking_id: Vec<u8>,
+    offering_sales_person_id: Vec<u8>,
+    accepting_sales_person_id: Option<Vec<u8>>,
+    new_booking_id: Option<Vec<u8>>,
+    year: i64,
+    calendar_week: i64,
+    state: String,
+    message: String,
+    reject_reason: Option<String>,
+    created: String,
+    accepted: Option<String>,
+    decided: Option<String>,
+    decided_by: Option<String>,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+fn parse_optional_date_time(value: &Option<String>) -> Result<Option<PrimitiveDateTime>, DaoError> {
+    Ok(value
+        .as_ref()
+        .map(|s| PrimitiveDateTime::parse(s, &Iso8601::DATE_TIME))
+        .transpose()?)
+}
+
+fn format_optional_date_time(value: Option<PrimitiveDateTime>) -> Result<Option<String>, DaoError> {
+    value
+        .map(|d| d.format(&Iso8601::DATE_TIME))
+        .transpose()
+        .map_db_error()
+}
+
+impl TryFrom<&ShiftSwapRequestDb> for ShiftSwapRequestEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &ShiftSwapRequestDb) -> Result<Self, Self::Error> {
+        Ok(ShiftSwapRequestEntity {
+            id: Uuid::from_slice(&db.id)?,
+            booking_id: Uuid::from_slice(&db.booking_id)?,
+            offering_sales_person_id: Uuid::from_slice(&db.offering_sales_person_id)?,
+            accepting_sales_person_id: db
+                .accepting_sales_person_id
+                .as_ref()
+                .map(|id| Uuid::from_slice(id))
+                .transpose()?,
+            new_booking_id: db
+                .new_booking_id
+                .as_ref()
+                .map(|id| Uuid::from_slice(id))
+                .transpose()?,
+            year: db.year as u32,
+            calendar_week: db.calendar_week as u8,
+            state: str_to_state(&db.state)?,
+            message: db.message.as_str().into(),
+            reject_reason: db.reject_reason.as_deref().map(Arc::from),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            accepted: parse_optional_date_time(&db.accepted)?,
+            decided: parse_optional_date_time(&db.decided)?,
+            decided_by: db.decided_by.as_deref().map(Arc::from),
+            deleted: parse_optional_date_time(&db.deleted)?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+fn state_to_str(state: ShiftSwapState) -> &'static str {
+    match state {
+        ShiftSwapState::Open => "Open",
+        ShiftSwapState::Accepted => "Accepted",
+        ShiftSwapState::Approved => "Approved",
+        ShiftSwapState::Rejected => "Rejected",
+        ShiftSwapState::Cancelled => "Cancelled",
+    }
+}
+
+fn str_to_state(value: &str) -> Result<ShiftSwapState, DaoError> {
+    match value {
+        "Open" => Ok(ShiftSwapState::Open),
+        "Accepted" => Ok(ShiftSwapState::Accepted),
+        "Approved" => Ok(ShiftSwapState::Approved),
+        "Rejected" => Ok(ShiftSwapState::Rejected),
+        "Cancelled" => Ok(ShiftSwapState::Cancelled),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct ShiftSwapRequestDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl ShiftSwapRequestDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShiftSwapRequestDao for ShiftSwapRequestDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftSwapRequestEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE id = ? AND deleted IS NULL"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(ShiftSwapRequestEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_pending(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftSwapRequestEntity]>, DaoError> {
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE state IN ('Open', 'Accepted') AND deleted IS NULL
+               ORDER BY year, calendar_week, created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftSwapRequestEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_pending_by_booking_id(
+        &self,
+        booking_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<ShiftSwapRequestEntity>, DaoError> {
+        let booking_id_vec = booking_id.as_bytes().to_vec();
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE booking_id = ? AND state IN ('Open', 'Accepted') AND deleted IS NULL"#,
+            booking_id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(ShiftSwapRequestEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[ShiftSwapRequestEntity]>, DaoError> {
+        let sales_person_id_vec = sales_person_id.as_bytes().to_vec();
+        query_as!(
+            ShiftSwapRequestDb,
+            r#"SELECT id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                      new_booking_id, year, calendar_week, state, message, reject_reason,
+                      created, accepted, decided, decided_by, deleted, update_version
+               FROM shift_swap_request
+               WHERE (offering_sales_person_id = ? OR accepting_sales_person_id = ?)
+                 AND deleted IS NULL
+               ORDER BY year, calendar_week, created"#,
+            sales_person_id_vec,
+            sales_person_id_vec,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(ShiftSwapRequestEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &ShiftSwapRequestEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let booking_id_vec = entity.booking_id.as_bytes().to_vec();
+        let offering_vec = entity.offering_sales_person_id.as_bytes().to_vec();
+        let accepting_vec = entity
+            .accepting_sales_person_id
+            .map(|id| id.as_bytes().to_vec());
+        let new_booking_id_vec = entity.new_booking_id.map(|id| id.as_bytes().to_vec());
+        let state_str = state_to_str(entity.state);
+        let message = entity.message.as_ref();
+        let reject_reason = entity.reject_reason.as_deref();
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let accepted_str = format_optional_date_time(entity.accepted)?;
+        let decided_str = format_optional_date_time(entity.decided)?;
+        let decided_by = entity.decided_by.as_deref();
+        let deleted_str = format_optional_date_time(entity.deleted)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO shift_swap_request
+                 (id, booking_id, offering_sales_person_id, accepting_sales_person_id,
+                  new_booking_id, year, calendar_week, state, message, reject_reason,
+                  created, accepted, decided, decided_by, deleted, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            booking_id_vec,
+            offering_vec,
+            accepting_vec,
+            new_booking_id_vec,
+            entity.year,
+            entity.calendar_week,
+            state_str,
+            message,
+            reject_reason,
+            created_str,
+            accepted_str,
+            decided_str,
+            decided_by,
+            deleted_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &ShiftSwapRequestEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let accepting_vec = entity
+            .accepting_sales_person_id
+            .map(|id| id.as_bytes().to_vec());
+        let new_booking_id_vec = entity.new_booking_id.map(|id| id.as_bytes().to_vec());
+        let state_str = state_to_str(entity.state);
+        let reject_reason = entity.reject_reason.as_deref();
+        let accepted_str = format_optional_date_time(entity.accepted)?;
+        let decided_str = format_optional_date_time(entity.decided)?;
+        let decided_by = entity.decided_by.as_deref();
+        let deleted_str = format_optional_date_time(entity.deleted)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE shift_swap_request
+               SET accepting_sales_person_id = ?, new_booking_id = ?, state = ?,
+                   reject_reason = ?, accepted = ?, decided = ?, decided_by = ?,
+                   deleted = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            accepting_vec,
+            new_booking_id_vec,
+            state_str,
+            reject_reason,
+            accepted_str,
+            decided_str,
+            decided_by,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_roundtrip() {
+        for state in [
+            ShiftSwapState::Open,
+            ShiftSwapState::Accepted,
+            ShiftSwapState::Approved,
+            ShiftSwapState::Rejected,
+            ShiftSwapState::Cancelled,
+        ] {
+            assert_eq!(str_to_state(state_to_str(state)).unwrap(), state);
+        }
+    }
+
+    #[test]
+    fn unknown_state() {
+        match str_to_state("Bogus") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Bogus"),
+            other => panic!("expected EnumValueNotFound(\"Bogus\"), got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+use async_trait::async_trait;
+use dao::{
+    time_entry::{TimeEntryDao, TimeEntryEntity, TimeEntrySource},
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+struct TimeEntryDb {
+    id: Vec<u8>,
+    sales_person_id: Vec<u8>,
+    booking_id: Option<Vec<u8>>,
+    start_time: String,
+    end_time: Option<String>,
+    description: String,
+    source: String,
+    created: String,
+    created_by: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&TimeEntryDb> for TimeEntryEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &TimeEntryDb) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::from_slice(&db.id)?,
+            sales_person_id: Uuid::from_slice(&db.sales_person_id)?,
+            booking_id: db
+                .booking_id
+                .as_ref()
+                .map(|booking_id| Uuid::from_slice(booking_id))
+                .transpose()?,
+            start: PrimitiveDateTime::parse(&db.start_time, &Iso8601::DATE_TIME)?,
+            end: db
+                .end_time
+                .as_ref()
+                .map(|end| PrimitiveDateTime::parse(end, &Iso8601::DATE_TIME))
+                .transpose()?,
+            description: db.description.as_str().into(),
+            source: str_to_source(&db.source)?,
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            created_by: db.created_by.as_str().into(),
+            deleted: db
+                .deleted
+                .as_ref()
+                .map(|deleted| PrimitiveDateTime::parse(deleted, &Iso8601::DATE_TIME))
+                .transpose()?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+fn source_to_str(source: TimeEntrySource) -> &'static str {
+    match source {
+        TimeEntrySource::Clock => "Clock",
+        TimeEntrySource::Manual => "Manual",
+    }
+}
+
+fn str_to_source(value: &str) -> Result<TimeEntrySource, DaoError> {
+    match value {
+        "Clock" => Ok(TimeEntrySource::Clock),
+        "Manual" => Ok(TimeEntrySource::Manual),
+        other => Err(DaoError::EnumValueNotFound(other.into())),
+    }
+}
+
+pub struct TimeEntryDaoImpl {
+    pub _pool: Arc<sqlx::SqlitePool>,
+}
+impl TimeEntryDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { _pool: pool }
+    }
+}
+
+#[async_trait]
+impl TimeEntryDao for TimeEntryDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TimeEntryEntity>, DaoError> {
+        let id = id.as_bytes().to_vec();
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE id = ? AND deleted IS NULL"#,
+            id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(TimeEntryEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_open_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<TimeEntryEntity>, DaoError> {
+        let sales_person_id = sales_person_id.as_bytes().to_vec();
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE sales_person_id = ? AND end_time IS NULL AND deleted IS NULL
+               ORDER BY start_time DESC
+               LIMIT 1"#,
+            sales_person_id
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(TimeEntryEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_by_sales_person_and_range(
+        &self,
+        sales_person_id: Uuid,
+        from: PrimitiveDateTime,
+        to: PrimitiveDateTime,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TimeEntryEntity]>, DaoError> {
+        let sales_person_id = sales_person_id.as_bytes().to_vec();
+        let from = from.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let to = to.format(&Iso8601::DATE_TIME).map_db_error()?;
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE sales_person_id = ? AND start_time >= ? AND start_time < ?
+                 AND deleted IS NULL
+               ORDER BY start_time"#,
+            sales_person_id,
+            from,
+            to,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(TimeEntryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_by_range(
+        &self,
+        from: PrimitiveDateTime,
+        to: PrimitiveDateTime,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[TimeEntryEntity]>, DaoError> {
+        let from = from.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let to = to.format(&Iso8601::DATE_TIME).map_db_error()?;
+        query_as!(
+            TimeEntryDb,
+            r#"SELECT id, sales_person_id, booking_id, start_time, end_time, description, source,
+                      created, created_by, deleted, update_version
+               FROM time_entry
+               WHERE start_time >= ? AND start_time < ? AND deleted IS NULL
+               ORDER BY start_time"#,
+            from,
+            to,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(TimeEntryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create(
+        &self,
+        entity: &TimeEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id = entity.id.as_bytes().to_vec();
+        let sales_person_id = entity.sales_person_id.as_bytes().to_vec();
+        let booking_id = entity.booking_id.map(|id| id.as_bytes().to_vec());
+        let start_time = entity.start.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let end_time = entity
+            .end
+            .map(|end| end.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let description = entity.description.as_ref();
+        let source = source_to_str(entity.source);
+        let created = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let created_by = entity.created_by.as_ref();
+        let deleted = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version = entity.version.as_bytes().to_vec();
+        query!(
+            r#"INSERT INTO time_entry (id, sales_person_id, booking_id, start_time, end_time,
+                   description, source, created, created_by, deleted, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id,
+            sales_person_id,
+            booking_id,
+            start_time,
+            end_time,
+            description,
+            source,
+            created,
+            created_by,
+            deleted,
+            process,
+            version,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        entity: &TimeEntryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id = entity.id.as_bytes().to_vec();
+        let booking_id = entity.booking_id.map(|id| id.as_bytes().to_vec());
+        let start_time = entity.start.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let end_time = entity
+            .end
+            .map(|end| end.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let description = entity.description.as_ref();
+        let source = source_to_str(entity.source);
+        let deleted = entity
+            .deleted
+            .map(|deleted| deleted.format(&Iso8601::DATE_TIME))
+            .transpose()
+            .map_db_error()?;
+        let version = entity.version.as_bytes().to_vec();
+        query!(
+            r#"UPDATE time_entry SET booking_id = ?, start_time = ?, end_time = ?, description = ?,
+                   source = ?, deleted = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            booking_id,
+            start_time,
+            end_time,
+            description,
+            source,
+            deleted,
+            process,
+            version,
+            id,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_roundtrip() {
+        for source in [TimeEntrySource::Clock, TimeEntrySource::Manual] {
+            assert_eq!(str_to_source(source_to_str(source)).unwrap(), source);
+        }
+    }
+
+    #[test]
+    fn unknown_source() {
+        match str_to_source("Guess") {
+            Err(DaoError::EnumValueNotFound(value)) => assert_eq!(&*value, "Guess"),
+            other => panic!("expected EnumValueNotFound(\"Guess\"), got {other:?}"),
+        }
+    }
+}
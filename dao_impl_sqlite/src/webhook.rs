@@ -0,0 +1,390 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    webhook::{
+        join_event_types, split_event_types, WebhookDao, WebhookDeliveryEntity,
+        WebhookDeliveryState, WebhookEventType, WebhookSubscriptionEntity,
+    },
+    DaoError,
+};
+use sqlx::{query, query_as};
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::{ResultDbErrorExt, TransactionImpl};
+
+#[derive(Debug)]
+struct WebhookSubscriptionDb {
+    id: Vec<u8>,
+    url: String,
+    secret: String,
+    description: String,
+    event_types: String,
+    active: i64,
+    created: String,
+    deleted: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&WebhookSubscriptionDb> for WebhookSubscriptionEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &WebhookSubscriptionDb) -> Result<Self, Self::Error> {
+        Ok(WebhookSubscriptionEntity {
+            id: Uuid::from_slice(&db.id)?,
+            url: db.url.as_str().into(),
+            secret: db.secret.as_str().into(),
+            description: db.description.as_str().into(),
+            event_types: split_event_types(&db.event_types)?,
+            active: db.active != 0,
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            deleted: parse_optional_date_time(&db.deleted)?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct WebhookDeliveryDb {
+    id: Vec<u8>,
+    subscription_id: Vec<u8>,
+    event_type: String,
+    payload: String,
+    state: String,
+    attempts: i64,
+    next_attempt: String,
+    last_status: Option<i64>,
+    last_error: Option<String>,
+    created: String,
+    delivered: Option<String>,
+    update_version: Vec<u8>,
+}
+
+impl TryFrom<&WebhookDeliveryDb> for WebhookDeliveryEntity {
+    type Error = DaoError;
+
+    fn try_from(db: &WebhookDeliveryDb) -> Result<Self, Self::Error> {
+        Ok(WebhookDeliveryEntity {
+            id: Uuid::from_slice(&db.id)?,
+            subscription_id: Uuid::from_slice(&db.subscription_id)?,
+            event_type: WebhookEventType::try_from(db.event_type.as_str())?,
+            payload: db.payload.as_str().into(),
+            state: WebhookDeliveryState::try_from(db.state.as_str())?,
+            attempts: db.attempts as u32,
+            next_attempt: PrimitiveDateTime::parse(&db.next_attempt, &Iso8601::DATE_TIME)?,
+            last_status: db.last_status.map(|status| status as u16),
+            last_error: db.last_error.as_deref().map(Arc::from),
+            created: PrimitiveDateTime::parse(&db.created, &Iso8601::DATE_TIME)?,
+            delivered: parse_optional_date_time(&db.delivered)?,
+            version: Uuid::from_slice(&db.update_version)?,
+        })
+    }
+}
+
+fn parse_optional_date_time(value: &Option<String>) -> Result<Option<PrimitiveDateTime>, DaoError> {
+    Ok(value
+        .as_ref()
+        .map(|s| PrimitiveDateTime::parse(s, &Iso8601::DATE_TIME))
+        .transpose()?)
+}
+
+fn format_optional_date_time(value: Option<PrimitiveDateTime>) -> Result<Option<String>, DaoError> {
+    value
+        .map(|d| d.format(&Iso8601::DATE_TIME))
+        .transpose()
+        .map_db_error()
+}
+
+pub struct WebhookDaoImpl {
+    pub pool: Arc<sqlx::SqlitePool>,
+}
+
+impl WebhookDaoImpl {
+    pub fn new(pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDao for WebhookDaoImpl {
+    type Transaction = TransactionImpl;
+
+    async fn all_subscriptions(
+        &self,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookSubscriptionEntity]>, DaoError> {
+        query_as!(
+            WebhookSubscriptionDb,
+            r#"SELECT id, url, secret, description, event_types, active, created, deleted,
+                      update_version
+               FROM webhook_subscription
+               WHERE deleted IS NULL
+               ORDER BY created"#,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(WebhookSubscriptionEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_subscription_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WebhookSubscriptionEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            WebhookSubscriptionDb,
+            r#"SELECT id, url, secret, description, event_types, active, created, deleted,
+                      update_version
+               FROM webhook_subscription
+               WHERE id = ? AND deleted IS NULL"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WebhookSubscriptionEntity::try_from)
+        .transpose()
+    }
+
+    async fn create_subscription(
+        &self,
+        entity: &WebhookSubscriptionEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let url = entity.url.as_ref();
+        let secret = entity.secret.as_ref();
+        let description = entity.description.as_ref();
+        let event_types = join_event_types(&entity.event_types);
+        let active = entity.active as i64;
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let deleted_str = format_optional_date_time(entity.deleted)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO webhook_subscription
+                 (id, url, secret, description, event_types, active, created, deleted,
+                  update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            url,
+            secret,
+            description,
+            event_types,
+            active,
+            created_str,
+            deleted_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_subscription(
+        &self,
+        entity: &WebhookSubscriptionEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let url = entity.url.as_ref();
+        let secret = entity.secret.as_ref();
+        let description = entity.description.as_ref();
+        let event_types = join_event_types(&entity.event_types);
+        let active = entity.active as i64;
+        let deleted_str = format_optional_date_time(entity.deleted)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE webhook_subscription
+               SET url = ?, secret = ?, description = ?, event_types = ?, active = ?,
+                   deleted = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            url,
+            secret,
+            description,
+            event_types,
+            active,
+            deleted_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn find_delivery_by_id(
+        &self,
+        id: Uuid,
+        tx: Self::Transaction,
+    ) -> Result<Option<WebhookDeliveryEntity>, DaoError> {
+        let id_vec = id.as_bytes().to_vec();
+        query_as!(
+            WebhookDeliveryDb,
+            r#"SELECT id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                      last_status, last_error, created, delivered, update_version
+               FROM webhook_delivery
+               WHERE id = ?"#,
+            id_vec,
+        )
+        .fetch_optional(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .as_ref()
+        .map(WebhookDeliveryEntity::try_from)
+        .transpose()
+    }
+
+    async fn find_deliveries_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookDeliveryEntity]>, DaoError> {
+        let subscription_id_vec = subscription_id.as_bytes().to_vec();
+        let limit = limit as i64;
+        query_as!(
+            WebhookDeliveryDb,
+            r#"SELECT id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                      last_status, last_error, created, delivered, update_version
+               FROM webhook_delivery
+               WHERE subscription_id = ?
+               ORDER BY created DESC
+               LIMIT ?"#,
+            subscription_id_vec,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(WebhookDeliveryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn find_due_deliveries(
+        &self,
+        now: PrimitiveDateTime,
+        limit: u32,
+        tx: Self::Transaction,
+    ) -> Result<Arc<[WebhookDeliveryEntity]>, DaoError> {
+        let now_str = now.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let limit = limit as i64;
+        query_as!(
+            WebhookDeliveryDb,
+            r#"SELECT id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                      last_status, last_error, created, delivered, update_version
+               FROM webhook_delivery
+               WHERE state = 'Pending' AND next_attempt <= ?
+               ORDER BY next_attempt, created
+               LIMIT ?"#,
+            now_str,
+            limit,
+        )
+        .fetch_all(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?
+        .iter()
+        .map(WebhookDeliveryEntity::try_from)
+        .collect::<Result<Arc<[_]>, _>>()
+    }
+
+    async fn create_delivery(
+        &self,
+        entity: &WebhookDeliveryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let subscription_id_vec = entity.subscription_id.as_bytes().to_vec();
+        let event_type_str = entity.event_type.to_string();
+        let payload = entity.payload.as_ref();
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let next_attempt_str = entity
+            .next_attempt
+            .format(&Iso8601::DATE_TIME)
+            .map_db_error()?;
+        let last_status = entity.last_status.map(|status| status as i64);
+        let last_error = entity.last_error.as_deref();
+        let created_str = entity.created.format(&Iso8601::DATE_TIME).map_db_error()?;
+        let delivered_str = format_optional_date_time(entity.delivered)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"INSERT INTO webhook_delivery
+                 (id, subscription_id, event_type, payload, state, attempts, next_attempt,
+                  last_status, last_error, created, delivered, update_process, update_version)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            id_vec,
+            subscription_id_vec,
+            event_type_str,
+            payload,
+            state_str,
+            attempts,
+            next_attempt_str,
+            last_status,
+            last_error,
+            created_str,
+            delivered_str,
+            process,
+            version_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+
+    async fn update_delivery(
+        &self,
+        entity: &WebhookDeliveryEntity,
+        process: &str,
+        tx: Self::Transaction,
+    ) -> Result<(), DaoError> {
+        let id_vec = entity.id.as_bytes().to_vec();
+        let state_str = entity.state.to_string();
+        let attempts = entity.attempts as i64;
+        let next_attempt_str = entity
+            .next_attempt
+            .format(&Iso8601::DATE_TIME)
+            .map_db_error()?;
+        let last_status = entity.last_status.map(|status| status as i64);
+        let last_error = entity.last_error.as_deref();
+        let delivered_str = format_optional_date_time(entity.delivered)?;
+        let version_vec = entity.version.as_bytes().to_vec();
+
+        query!(
+            r#"UPDATE webhook_delivery
+               SET state = ?, attempts = ?, next_attempt = ?, last_status = ?, last_error = ?,
+                   delivered = ?, update_process = ?, update_version = ?
+               WHERE id = ?"#,
+            state_str,
+            attempts,
+            next_attempt_str,
+            last_status,
+            last_error,
+            delivered_str,
+            process,
+            version_vec,
+            id_vec,
+        )
+        .execute(tx.tx.lock().await.as_mut())
+        .await
+        .map_db_error()?;
+        Ok(())
+    }
+}
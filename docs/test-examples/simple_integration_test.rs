@@ -27,6 +27,7 @@ async fn test_simple_custom_report_generation() {
         name: "Test Employee".into(),
         background_color: "#000000".into(),
         inactive: false,
+        personnel_number: None,
         is_paid: Some(true),
         deleted: None,
     };
@@ -133,6 +134,7 @@ async fn test_german_hours_report() {
         name: "Natalie".into(),
         background_color: "#ff0000".into(),
         inactive: false,
+        personnel_number: None,
         is_paid: Some(true),
         deleted: None,
     };
@@ -143,6 +145,7 @@ async fn test_german_hours_report() {
         name: "Dany".into(),
         background_color: "#00ff00".into(),
         inactive: false,
+        personnel_number: None,
         is_paid: Some(true),
         deleted: None,
     };
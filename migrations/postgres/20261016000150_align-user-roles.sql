@@ -0,0 +1,84 @@
+-- Gleicht die Benutzer- und Rollentabellen aus 20240426150045 an
+-- migrations/sqlite (Stand 2024-06 bis Phase 4) an. Die ursprüngliche
+-- Migration bleibt unverändert, weil sie bereits eingespielt sein kann.
+--
+-- update_version gibt es in SQLite für diese Tabellen nicht; die DAOs setzen
+-- sie nicht. update_timestamp pflegt wie in SQLite ein Trigger. Die
+-- Beispielrolle `user` samt gleichnamigem Recht bleibt bestehen.
+
+ALTER TABLE "user" DROP COLUMN update_version;
+ALTER TABLE role DROP COLUMN update_version;
+ALTER TABLE privilege DROP COLUMN update_version;
+ALTER TABLE user_role DROP COLUMN update_version;
+ALTER TABLE role_privilege DROP COLUMN update_version;
+
+CREATE FUNCTION set_update_timestamp() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.update_timestamp := now() AT TIME ZONE 'UTC';
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER user_update_timestamp
+    BEFORE INSERT OR UPDATE ON "user"
+    FOR EACH ROW EXECUTE FUNCTION set_update_timestamp();
+CREATE TRIGGER role_update_timestamp
+    BEFORE INSERT OR UPDATE ON role
+    FOR EACH ROW EXECUTE FUNCTION set_update_timestamp();
+CREATE TRIGGER privilege_update_timestamp
+    BEFORE INSERT OR UPDATE ON privilege
+    FOR EACH ROW EXECUTE FUNCTION set_update_timestamp();
+CREATE TRIGGER user_role_update_timestamp
+    BEFORE INSERT OR UPDATE ON user_role
+    FOR EACH ROW EXECUTE FUNCTION set_update_timestamp();
+CREATE TRIGGER role_privilege_update_timestamp
+    BEFORE INSERT OR UPDATE ON role_privilege
+    FOR EACH ROW EXECUTE FUNCTION set_update_timestamp();
+
+INSERT INTO role (name, update_process) VALUES
+    ('admin', 'initial'),
+    ('sales', 'initial'),
+    ('hr', 'initial'),
+    ('shiftplanner', 'update-2024-06-14'),
+    ('shiftplan.edit', 'add-role-shiftplan-edit')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO privilege (name, update_process) VALUES
+    ('admin', 'initial'),
+    ('sales', 'initial'),
+    ('hr', 'initial'),
+    ('shiftplanner', 'update-2024-06-14'),
+    ('shiftplan.edit', 'add-role-shiftplan-edit'),
+    ('toggle_admin', 'initial'),
+    ('feature_flag_admin', 'initial'),
+    ('cutover_admin', 'phase-4-migration')
+ON CONFLICT DO NOTHING;
+
+INSERT INTO role_privilege (role_name, privilege_name, update_process) VALUES
+    ('sales', 'sales', 'initial'),
+    ('hr', 'hr', 'initial'),
+    ('shiftplanner', 'shiftplanner', 'update-2024-06-14'),
+    ('shiftplan.edit', 'shiftplan.edit', 'add-role-shiftplan-edit'),
+    ('admin', 'admin', 'initial'),
+    ('admin', 'sales', 'initial'),
+    ('admin', 'hr', 'initial'),
+    ('admin', 'shiftplanner', 'update-2024-06-14'),
+    ('admin', 'shiftplan.edit', 'add-role-shiftplan-edit'),
+    ('admin', 'toggle_admin', 'admin-auto-grant-backfill'),
+    ('admin', 'feature_flag_admin', 'admin-auto-grant-backfill'),
+    ('admin', 'cutover_admin', 'admin-auto-grant-backfill')
+ON CONFLICT DO NOTHING;
+
+-- Neue Privilegien erhält die admin-Rolle automatisch (wie in SQLite).
+CREATE FUNCTION privilege_auto_grant_admin() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO role_privilege (role_name, privilege_name, update_process)
+    VALUES ('admin', NEW.name, 'admin-auto-grant-trigger')
+    ON CONFLICT DO NOTHING;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER privilege_auto_grant_admin
+    AFTER INSERT ON privilege
+    FOR EACH ROW EXECUTE FUNCTION privilege_auto_grant_admin();
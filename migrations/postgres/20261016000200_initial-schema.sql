@@ -0,0 +1,513 @@
+-- Vollständiges Schema für dao_impl_postgres (Stand: migrations/sqlite bis
+-- 20261016000100_create-sales-person-availability).
+--
+-- Die SQLite-Historie wird hier konsolidiert; neue Schemaänderungen bekommen
+-- ab jetzt eine eigene Migration in beiden Verzeichnissen. Wie in
+-- 20240426150045 nutzen die Spalten die nativen Postgres-Typen (UUID, DATE,
+-- TIME, TIMESTAMP), die dao_impl_postgres direkt auf die Entity-Typen
+-- abbildet.
+
+CREATE TABLE shiftplan (
+    id UUID NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    is_planning BIGINT NOT NULL DEFAULT 0,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+INSERT INTO shiftplan (id, name, is_planning, update_process, update_version)
+VALUES (
+    '00000000-0000-4000-8000-000000000001',
+    'main',
+    0,
+    'migration',
+    '00000000-0000-4000-8000-000000000002'
+);
+
+CREATE TABLE slot (
+    id UUID NOT NULL PRIMARY KEY,
+    day_of_week BIGINT NOT NULL,
+    time_from TIME NOT NULL,
+    time_to TIME NOT NULL,
+    valid_from DATE NOT NULL,
+    valid_to DATE,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    min_resources BIGINT NOT NULL DEFAULT 2,
+    shiftplan_id UUID REFERENCES shiftplan(id),
+    max_paid_employees BIGINT
+);
+
+CREATE TABLE sales_person (
+    id UUID NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    inactive BOOLEAN NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    background_color TEXT NOT NULL DEFAULT '#FFF',
+    is_paid BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE sales_person_user (
+    sales_person_id UUID NOT NULL UNIQUE REFERENCES sales_person(id),
+    user_id TEXT NOT NULL UNIQUE REFERENCES "user"(name),
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    PRIMARY KEY (sales_person_id, user_id)
+);
+
+CREATE TABLE booking (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL,
+    slot_id UUID NOT NULL,
+    calendar_week BIGINT NOT NULL,
+    year BIGINT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    created_by TEXT,
+    deleted_by TEXT
+);
+
+CREATE TABLE employee_work_details (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    expected_hours DOUBLE PRECISION NOT NULL,
+    from_calendar_week BIGINT NOT NULL,
+    from_year BIGINT NOT NULL,
+    to_calendar_week BIGINT NOT NULL,
+    to_year BIGINT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    workdays_per_week BIGINT NOT NULL DEFAULT 5,
+    monday BIGINT NOT NULL DEFAULT 1,
+    tuesday BIGINT NOT NULL DEFAULT 1,
+    wednesday BIGINT NOT NULL DEFAULT 1,
+    thursday BIGINT NOT NULL DEFAULT 1,
+    friday BIGINT NOT NULL DEFAULT 1,
+    saturday BIGINT NOT NULL DEFAULT 1,
+    sunday BIGINT NOT NULL DEFAULT 0,
+    from_day_of_week BIGINT NOT NULL DEFAULT 1,
+    to_day_of_week BIGINT NOT NULL DEFAULT 7,
+    vacation_days BIGINT NOT NULL DEFAULT 0,
+    is_dynamic BIGINT NOT NULL DEFAULT 0,
+    cap_planned_hours_to_expected BIGINT NOT NULL DEFAULT 0,
+    committed_voluntary DOUBLE PRECISION NOT NULL DEFAULT 0
+);
+
+CREATE TABLE sales_person_unavailable (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    year BIGINT NOT NULL,
+    calendar_week BIGINT NOT NULL,
+    day_of_week BIGINT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE TABLE special_day (
+    id UUID NOT NULL PRIMARY KEY,
+    year BIGINT NOT NULL,
+    calendar_week BIGINT NOT NULL,
+    day_of_week BIGINT NOT NULL,
+    day_type TEXT NOT NULL,
+    time_of_day TIME,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE TABLE session (
+    id TEXT NOT NULL PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES "user"(name),
+    expires BIGINT NOT NULL,
+    created BIGINT NOT NULL,
+    impersonate_user_id TEXT
+);
+
+CREATE TABLE employee_yearly_carryover (
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    year BIGINT NOT NULL,
+    carryover_hours DOUBLE PRECISION NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    vacation BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (sales_person_id, year)
+);
+
+CREATE TABLE week_message (
+    id UUID NOT NULL PRIMARY KEY,
+    year BIGINT NOT NULL,
+    calendar_week BIGINT NOT NULL,
+    message TEXT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_version UUID NOT NULL,
+    update_process TEXT NOT NULL,
+    UNIQUE (year, calendar_week)
+);
+
+CREATE TABLE custom_extra_hours (
+    id UUID NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT,
+    modifies_balance BOOLEAN NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_version UUID NOT NULL,
+    update_process TEXT NOT NULL
+);
+
+CREATE TABLE custom_extra_hours_sales_person (
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    custom_extra_hours_id UUID NOT NULL REFERENCES custom_extra_hours(id),
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    PRIMARY KEY (sales_person_id, custom_extra_hours_id)
+);
+
+CREATE TABLE billing_period (
+    id UUID NOT NULL PRIMARY KEY,
+    from_date_time DATE NOT NULL,
+    to_date_time DATE NOT NULL,
+    created TIMESTAMP NOT NULL,
+    created_by TEXT NOT NULL,
+    deleted TIMESTAMP,
+    deleted_by TEXT,
+    update_version UUID NOT NULL,
+    update_process TEXT NOT NULL,
+    snapshot_schema_version BIGINT NOT NULL DEFAULT 1
+);
+
+CREATE TABLE billing_period_sales_person (
+    id UUID NOT NULL PRIMARY KEY,
+    billing_period_id UUID NOT NULL REFERENCES billing_period(id),
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    value_type TEXT NOT NULL,
+    value_delta DOUBLE PRECISION NOT NULL,
+    value_ytd_from DOUBLE PRECISION NOT NULL,
+    value_ytd_to DOUBLE PRECISION NOT NULL,
+    value_full_year DOUBLE PRECISION NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    created_by TEXT NOT NULL,
+    deleted_at TIMESTAMP,
+    deleted_by TEXT,
+    update_version UUID NOT NULL,
+    update_process TEXT NOT NULL,
+    UNIQUE (billing_period_id, sales_person_id, value_type)
+);
+
+CREATE TABLE text_template (
+    id UUID NOT NULL PRIMARY KEY,
+    template_type TEXT NOT NULL,
+    template_text TEXT NOT NULL,
+    created_at TIMESTAMP,
+    created_by TEXT,
+    deleted TIMESTAMP,
+    deleted_by TEXT,
+    update_version UUID NOT NULL,
+    update_process TEXT,
+    name TEXT,
+    template_engine TEXT NOT NULL DEFAULT 'tera'
+);
+CREATE INDEX idx_text_template_type ON text_template(template_type);
+CREATE INDEX idx_text_template_deleted ON text_template(deleted);
+CREATE INDEX idx_text_template_name ON text_template(name);
+
+CREATE TABLE user_invitation (
+    id UUID NOT NULL PRIMARY KEY,
+    username TEXT NOT NULL REFERENCES "user"(name) ON DELETE CASCADE,
+    token UUID NOT NULL UNIQUE,
+    expiration_date TIMESTAMPTZ NOT NULL,
+    created_date TIMESTAMPTZ NOT NULL DEFAULT now(),
+    update_process TEXT NOT NULL,
+    redeemed_at TIMESTAMPTZ,
+    session_id TEXT REFERENCES session(id) ON DELETE SET NULL,
+    session_revoked_at TIMESTAMPTZ
+);
+CREATE INDEX idx_user_invitation_token ON user_invitation(token);
+CREATE INDEX idx_user_invitation_expiration ON user_invitation(expiration_date);
+CREATE INDEX idx_user_invitation_session ON user_invitation(session_id);
+CREATE INDEX idx_user_invitation_redeemed ON user_invitation(redeemed_at);
+CREATE INDEX idx_user_invitation_session_revoked ON user_invitation(session_revoked_at);
+
+CREATE TABLE toggle (
+    name TEXT NOT NULL PRIMARY KEY,
+    enabled BIGINT NOT NULL DEFAULT 0,  -- 0 = disabled, 1 = enabled
+    description TEXT,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    value TEXT
+);
+
+CREATE TABLE toggle_group (
+    name TEXT NOT NULL PRIMARY KEY,
+    description TEXT,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL
+);
+
+CREATE TABLE toggle_group_toggle (
+    toggle_group_name TEXT NOT NULL REFERENCES toggle_group(name) ON DELETE CASCADE,
+    toggle_name TEXT NOT NULL REFERENCES toggle(name) ON DELETE CASCADE,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    UNIQUE (toggle_group_name, toggle_name)
+);
+
+INSERT INTO toggle (name, enabled, description, update_process) VALUES
+    ('paid_limit_hard_enforcement', 0, 'When ON, booking over a slot/week paid-employee limit is blocked for non-shiftplanners. Default OFF (soft, warning-only).', 'phase-24-migration'),
+    ('holiday_auto_credit', 0, 'When a cutoff date is set in `value` (ISO YYYY-MM-DD), holidays on or after that date are auto-credited in reports. Leave value NULL to disable.', 'phase-25-migration'),
+    ('shortday_slot_clipping_active_from', 0, 'When a cutoff date is set in `value` (ISO YYYY-MM-DD), slots at short-day dates >= that date are clipped at the ShortDay cutoff time in rendering and hours calculation. Leave value NULL to disable (legacy behavior).', 'phase-51-migration'),
+    ('voluntary_rebooking_auto_active_from', 0, 'When a cutoff date is set in `value` (ISO YYYY-MM-DD), the voluntary rebooking auto-cron (Phase 56 F4) runs only for ISO weeks >= that date. Leave value NULL to disable (default, no rebooking).', 'phase-54-migration');
+
+CREATE TABLE sales_person_shiftplan (
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    shiftplan_id UUID NOT NULL REFERENCES shiftplan(id),
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    permission_level TEXT NOT NULL DEFAULT 'available'
+        CHECK (permission_level IN ('available', 'planner_only')),
+    PRIMARY KEY (sales_person_id, shiftplan_id)
+);
+
+CREATE TABLE extra_hours (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    amount DOUBLE PRECISION NOT NULL,
+    category TEXT NOT NULL,
+    description TEXT,
+    date_time TIMESTAMP NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    custom_extra_hours_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    logical_id UUID NOT NULL,
+    source TEXT NOT NULL DEFAULT 'manual'
+);
+CREATE UNIQUE INDEX idx_extra_hours_logical_id_active
+    ON extra_hours(logical_id)
+    WHERE deleted IS NULL;
+
+CREATE TABLE feature_flag (
+    key TEXT NOT NULL PRIMARY KEY,
+    enabled BIGINT NOT NULL DEFAULT 0,  -- 0 = disabled, 1 = enabled
+    description TEXT,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL
+);
+
+CREATE TABLE absence_period (
+    id UUID NOT NULL PRIMARY KEY,
+    logical_id UUID NOT NULL,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    category TEXT NOT NULL,
+    from_date DATE NOT NULL,
+    to_date DATE NOT NULL,
+    description TEXT,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL,
+    day_fraction TEXT NOT NULL DEFAULT 'full'
+        CHECK (day_fraction IN ('full', 'half')),
+    CHECK (to_date >= from_date)
+);
+CREATE UNIQUE INDEX idx_absence_period_logical_id_active
+    ON absence_period(logical_id)
+    WHERE deleted IS NULL;
+CREATE INDEX idx_absence_period_sales_person_from
+    ON absence_period(sales_person_id, from_date)
+    WHERE deleted IS NULL;
+CREATE INDEX idx_absence_period_self_overlap
+    ON absence_period(sales_person_id, category, from_date)
+    WHERE deleted IS NULL;
+
+CREATE TABLE absence_period_migration_source (
+    extra_hours_id UUID NOT NULL PRIMARY KEY,
+    absence_period_id UUID NOT NULL,
+    migrated_at TIMESTAMP NOT NULL
+);
+
+CREATE TABLE vacation_entitlement_offset (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    year BIGINT NOT NULL,
+    offset_days BIGINT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+CREATE UNIQUE INDEX idx_vacation_entitlement_offset_active
+    ON vacation_entitlement_offset (sales_person_id, year)
+    WHERE deleted IS NULL;
+
+CREATE TABLE week_status (
+    id UUID NOT NULL PRIMARY KEY,
+    year BIGINT NOT NULL,
+    calendar_week BIGINT NOT NULL,
+    status TEXT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+CREATE UNIQUE INDEX idx_week_status_active
+    ON week_status (year, calendar_week)
+    WHERE deleted IS NULL;
+
+CREATE TABLE pdf_export_config (
+    id UUID NOT NULL PRIMARY KEY,
+    enabled BIGINT NOT NULL DEFAULT 0,
+    nextcloud_url TEXT,
+    webdav_user TEXT,
+    webdav_app_token TEXT,
+    target_folder TEXT,
+    weeks_horizon BIGINT NOT NULL DEFAULT 8,
+    cron_schedule TEXT NOT NULL DEFAULT '0 6 * * 1',
+    last_success_at TIMESTAMP,
+    last_error_at TIMESTAMP,
+    last_error_message TEXT,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+INSERT INTO pdf_export_config (id, enabled, weeks_horizon, cron_schedule, update_process, update_version)
+VALUES (
+    '00000000-0000-0000-0000-000000000048',
+    0,
+    8,
+    '0 0 6 * * 1',
+    'phase-48-migration',
+    '00000000-0000-0000-0000-000000000048'
+);
+
+CREATE TABLE rebooking_batch (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL,
+    iso_year BIGINT NOT NULL,
+    iso_week BIGINT NOT NULL,
+    kind TEXT NOT NULL,           -- 'manual' | 'hr_suggestion' | 'auto_cron' | 'auto_cron_backfill'
+    state TEXT NOT NULL,          -- 'pending' | 'approved' | 'rejected' | 'skipped_locked'
+    created TIMESTAMP NOT NULL,
+    approved TIMESTAMP,
+    approved_by TEXT,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+CREATE UNIQUE INDEX rebooking_batch_week_unique_idx
+    ON rebooking_batch (sales_person_id, iso_year, iso_week)
+    WHERE deleted IS NULL;
+CREATE INDEX rebooking_batch_state_idx
+    ON rebooking_batch (state)
+    WHERE deleted IS NULL;
+
+CREATE TABLE rebooking_batch_entry (
+    id UUID NOT NULL PRIMARY KEY,
+    batch_id UUID NOT NULL REFERENCES rebooking_batch(id),
+    sales_person_id UUID NOT NULL,
+    hours DOUBLE PRECISION NOT NULL,
+    balance_before DOUBLE PRECISION NOT NULL,
+    voluntary_actual DOUBLE PRECISION NOT NULL,
+    voluntary_committed DOUBLE PRECISION NOT NULL,
+    extra_hours_out_id UUID,
+    extra_hours_in_id UUID,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+CREATE INDEX rebooking_batch_entry_sp_idx
+    ON rebooking_batch_entry (sales_person_id)
+    WHERE deleted IS NULL;
+
+CREATE TABLE shift_swap_request (
+    id UUID NOT NULL PRIMARY KEY,
+    booking_id UUID NOT NULL REFERENCES booking(id),
+    offering_sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    accepting_sales_person_id UUID REFERENCES sales_person(id),
+    new_booking_id UUID REFERENCES booking(id),
+    year BIGINT NOT NULL,
+    calendar_week BIGINT NOT NULL,
+    state TEXT NOT NULL,          -- 'Open' | 'Accepted' | 'Approved' | 'Rejected' | 'Cancelled'
+    message TEXT NOT NULL,
+    reject_reason TEXT,
+    created TIMESTAMP NOT NULL,
+    accepted TIMESTAMP,
+    decided TIMESTAMP,
+    decided_by TEXT,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+CREATE UNIQUE INDEX shift_swap_request_pending_booking_idx
+    ON shift_swap_request (booking_id)
+    WHERE deleted IS NULL AND state IN ('Open', 'Accepted');
+CREATE INDEX shift_swap_request_state_idx
+    ON shift_swap_request (state)
+    WHERE deleted IS NULL;
+
+CREATE TABLE sales_person_availability (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    day_of_week BIGINT NOT NULL,
+    time_from TIME,
+    time_to TIME,
+    valid_from DATE NOT NULL,
+    valid_to DATE,
+    kind TEXT NOT NULL,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_timestamp TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+CREATE INDEX sales_person_availability_sales_person_idx
+    ON sales_person_availability (sales_person_id)
+    WHERE deleted IS NULL;
+
+-- Lesbare Buchungshistorie für booking_log; UUIDs im Bindestrich-Format.
+CREATE VIEW bookings_view AS
+SELECT
+    booking.id::text AS booking_hex,
+    sales_person.id::text AS sales_person_hex,
+    slot.id::text AS slot_hex,
+    sales_person.name,
+    booking.year,
+    booking.calendar_week,
+    slot.day_of_week,
+    slot.time_from,
+    slot.time_to,
+    booking.created,
+    booking.deleted,
+    booking.created_by,
+    booking.deleted_by,
+    shiftplan.name AS shiftplan_name
+FROM booking
+INNER JOIN sales_person ON sales_person.id = booking.sales_person_id
+INNER JOIN slot ON slot.id = booking.slot_id
+LEFT JOIN shiftplan ON slot.shiftplan_id = shiftplan.id;
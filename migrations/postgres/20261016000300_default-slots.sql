@@ -0,0 +1,66 @@
+-- Standard-Slots wie in migrations/sqlite/20240619085745_default-slots.sql,
+-- direkt dem Hauptschichtplan zugeordnet.
+
+INSERT INTO slot (id, day_of_week, time_from, time_to, valid_from, valid_to, deleted, update_timestamp, update_process, update_version, shiftplan_id) VALUES
+    ('08ab2588-1f9e-4d03-b70f-d6ecd9747e53',1,'09:00:00.0','10:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','5a4be30d-e0f7-4378-9a2d-a07d11b4f14d', '00000000-0000-4000-8000-000000000001'),
+    ('46fe075a-4e9b-49d2-8d13-8a3ce368ba3e',1,'10:00:00.0','11:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','52163187-6433-4451-9943-bac153a5d33f', '00000000-0000-4000-8000-000000000001'),
+    ('c34ad85a-7bfb-4a35-b98e-cd858eb84ea9',1,'11:00:00.0','12:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','9b3ee57e-71eb-46ee-a33e-b57ae6abd212', '00000000-0000-4000-8000-000000000001'),
+    ('4b6808fd-1955-436a-aee6-afaad87832f8',1,'12:00:00.0','13:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','3f719589-b047-4c5b-b575-af52290ac24f', '00000000-0000-4000-8000-000000000001'),
+    ('58ee28a0-28b4-4deb-ae3c-b909e14bbc82',1,'13:00:00.0','14:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','3e7351fd-fc90-4964-9b69-c43dfb51d860', '00000000-0000-4000-8000-000000000001'),
+    ('8ef857de-a2a7-429a-ad76-5a898e01948b',1,'14:00:00.0','15:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','b3dc7f13-2a35-4ad2-b719-366b507691ac', '00000000-0000-4000-8000-000000000001'),
+    ('0824b95a-e1a5-4ef9-8150-cc7c18172c9f',1,'15:00:00.0','16:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','023a5ad0-fe0b-4e55-bca6-1bb6634ac1db', '00000000-0000-4000-8000-000000000001'),
+    ('ae4fa09c-a6b9-4234-b2fa-d2daa993fee0',1,'16:00:00.0','17:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','2109af12-4c57-42e6-b107-6559a252e6fe', '00000000-0000-4000-8000-000000000001'),
+    ('70695390-f5ec-4f7b-83ca-1b4a68bb8a14',1,'17:00:00.0','18:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','1644962c-c6fe-455f-8e6d-f37126ce67c1', '00000000-0000-4000-8000-000000000001'),
+    ('8b538e96-1f21-4935-ac4c-4c045c989473',1,'18:00:00.0','19:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','726b7c1c-7412-46ef-a7e3-6114af3c8db5', '00000000-0000-4000-8000-000000000001'),
+    ('f0037047-d29e-4579-ae0d-1d743818bddc',1,'19:00:00.0','19:30:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','ea91542a-f18a-4a75-bc5d-1c04ab53e596', '00000000-0000-4000-8000-000000000001'),
+    ('96465be6-f166-4ba6-8c36-30858a7e4207',2,'09:00:00.0','10:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','6a6a7e10-7738-466a-acdb-7ca4e4f1ab8a', '00000000-0000-4000-8000-000000000001'),
+    ('6851abd6-b913-4ee7-b311-fbb9502a1d05',3,'09:00:00.0','10:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','0d379974-b71d-4202-9ad4-f174461d70c4', '00000000-0000-4000-8000-000000000001'),
+    ('54d84cb4-622b-424b-a1f6-cd3b09910db3',4,'09:00:00.0','10:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','64eb8bcb-d886-48dc-8881-32e3e5486967', '00000000-0000-4000-8000-000000000001'),
+    ('012f7b1e-b86a-4794-97ad-6d12bf502e1a',5,'09:00:00.0','10:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','72a49fcb-3ce9-496f-85a6-be39e067a5ab', '00000000-0000-4000-8000-000000000001'),
+    ('a847e67a-1a9a-4533-9710-987a022dac4b',6,'09:00:00.0','10:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','a3bf1b1f-ecd0-4294-991e-f3f432425991', '00000000-0000-4000-8000-000000000001'),
+    ('7cf59fc6-d6fe-4aba-83a6-a70044583eeb',6,'11:00:00.0','12:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','db82bcb2-29c7-4cec-828a-2f9c2fef761b', '00000000-0000-4000-8000-000000000001'),
+    ('d53ec027-2aef-4b41-823b-f500932d6283',6,'12:00:00.0','13:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','76dc52e4-8cd4-429c-8c8f-48ad9e3643bb', '00000000-0000-4000-8000-000000000001'),
+    ('70fb58cf-2f67-4f09-9ac3-dddb8ee5cbc2',6,'13:00:00.0','14:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','197021b8-f291-4972-82d9-c1f597b5ffeb', '00000000-0000-4000-8000-000000000001'),
+    ('c9ff96df-4246-4a18-beac-02ae7790fb37',6,'14:00:00.0','15:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','d5f2bc5d-014a-4387-afc7-9085a6bd3b01', '00000000-0000-4000-8000-000000000001'),
+    ('6a2537e6-18f9-4658-abea-65504da38325',6,'15:00:00.0','16:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','60b24f0a-3dc6-4634-bfaf-0e86912abe6b', '00000000-0000-4000-8000-000000000001'),
+    ('c4cfc6f8-f2c1-4b75-9639-2bd878c3fe2a',5,'10:00:00.0','11:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','a58e50f7-996a-47db-bb52-d345ce77b02a', '00000000-0000-4000-8000-000000000001'),
+    ('194f4a66-275f-4529-a65d-b8995f225afb',5,'11:00:00.0','12:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','321cb791-1dab-4d18-9d37-1317d853e5ab', '00000000-0000-4000-8000-000000000001'),
+    ('d59417fe-7224-4e18-9a21-f28c4e7d52cc',5,'12:00:00.0','13:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','b531a318-0b89-48f8-b2d1-34b2ceacf6ac', '00000000-0000-4000-8000-000000000001'),
+    ('08d74382-d32c-404c-a5f7-96c109f00787',5,'13:00:00.0','14:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','578a7054-92c0-4327-afbd-e317de6624a0', '00000000-0000-4000-8000-000000000001'),
+    ('bbea533b-eaab-463b-afe8-0e40a69c8393',5,'14:00:00.0','15:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','c38361f4-2db9-491c-a628-58ea0beddb50', '00000000-0000-4000-8000-000000000001'),
+    ('35f62954-2e39-42b0-81bd-0b1b214d5b59',5,'15:00:00.0','16:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','76edd2f0-214d-4ffe-b0e0-d32aa668f248', '00000000-0000-4000-8000-000000000001'),
+    ('8720cc55-e395-4470-be7b-977c1e31719f',5,'16:00:00.0','17:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','cc5f682b-46b8-4c83-9bd5-b62269422637', '00000000-0000-4000-8000-000000000001'),
+    ('345968ce-be6c-477f-9650-d81bd28e226a',5,'17:00:00.0','18:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','95feede9-d4ab-4d0c-a5c1-4a564b4ee4f4', '00000000-0000-4000-8000-000000000001'),
+    ('3afe8ab6-4c03-4c71-bfe8-b95ea1872d2b',5,'18:00:00.0','19:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','60bf3c70-4633-4ce7-b6cc-de20f2768c25', '00000000-0000-4000-8000-000000000001'),
+    ('68f62933-dead-423d-8e11-c19911a3bc57',5,'19:00:00.0','19:30:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','c9f4bf0f-0c07-4163-91f7-893da531009b', '00000000-0000-4000-8000-000000000001'),
+    ('e38a96be-82fc-4e88-9060-9bf1326ed377',4,'10:00:00.0','11:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','ed818211-19be-4dff-b126-6e29f5dcf5d6', '00000000-0000-4000-8000-000000000001'),
+    ('96d0aad4-dc33-4ceb-87c4-ac233e0c7c88',6,'10:00:00.0','11:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','7b5c1d9e-c707-472a-bf25-a9f224ee41d1', '00000000-0000-4000-8000-000000000001'),
+    ('b07a6062-1320-4cee-8910-9c3140f676b8',4,'11:00:00.0','12:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','bded6437-84b4-4411-8ea4-27821be72f0e', '00000000-0000-4000-8000-000000000001'),
+    ('de904c71-fc3f-4ec2-a5e8-104e99754fdc',4,'12:00:00.0','13:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','3a0375b7-f97c-4719-8db6-97de983de9fc', '00000000-0000-4000-8000-000000000001'),
+    ('87f3e28c-0da1-4435-9383-5f3aed078c1c',4,'13:00:00.0','14:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','bac09f2e-9e17-4c42-8458-5f72fd09f343', '00000000-0000-4000-8000-000000000001'),
+    ('81df50ac-841e-46f1-8fc7-b929c4a83d58',4,'14:00:00.0','15:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','53a09581-9114-46a5-b2a7-5a10db9e34e2', '00000000-0000-4000-8000-000000000001'),
+    ('c90300ba-0f34-4609-9eba-97244267cc2c',4,'15:00:00.0','16:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','0e554ef3-8f3a-4306-b231-8bd8237ef595', '00000000-0000-4000-8000-000000000001'),
+    ('96aad015-3a79-4bc4-a901-95b7ac8b67d5',4,'16:00:00.0','17:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','bb314bf0-d8b6-4960-b8a9-1f72717de972', '00000000-0000-4000-8000-000000000001'),
+    ('844d9ca4-24c0-4107-98a6-7518540b1bb7',4,'17:00:00.0','18:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','60402204-00a6-44ef-8fbe-867e92363a86', '00000000-0000-4000-8000-000000000001'),
+    ('62d00838-8f27-4da2-a6ba-0cbf9b2da9f1',4,'18:00:00.0','19:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','46bc35a8-fa45-423c-b8f5-dc44d639f95e', '00000000-0000-4000-8000-000000000001'),
+    ('06525073-a2e1-474f-af43-d013654f1219',4,'19:00:00.0','19:30:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','63affcfa-76f8-496b-a3e9-3441d085f607', '00000000-0000-4000-8000-000000000001'),
+    ('0141437e-cfed-44da-b8e9-21fd24b3f960',3,'10:00:00.0','11:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','c5cee643-e99e-4214-93ed-a930b33a9e12', '00000000-0000-4000-8000-000000000001'),
+    ('fd190d07-a228-449d-9677-8454c4b7d4e7',3,'11:00:00.0','12:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','60b766ff-2229-400d-95ee-bc8c101b509d', '00000000-0000-4000-8000-000000000001'),
+    ('c2548126-5173-4677-b7fe-dca933405920',3,'12:00:00.0','13:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','32a5c064-31a0-46b0-9fc3-a6b6f701dc7f', '00000000-0000-4000-8000-000000000001'),
+    ('68efe36f-9408-4de0-b409-655239a6488e',3,'13:00:00.0','14:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','2781e182-e09e-49bd-88fa-1531fbe5ff3a', '00000000-0000-4000-8000-000000000001'),
+    ('4ea95b27-fe1c-402a-a5a6-e45141fc4a9b',3,'14:00:00.0','15:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','ac7b30f3-379d-4d76-9673-1f55da78df11', '00000000-0000-4000-8000-000000000001'),
+    ('a001bb86-efab-4f2f-be81-7cbb065be1da',3,'15:00:00.0','16:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','b912efd3-b92c-4535-962b-99d95ab6fee2', '00000000-0000-4000-8000-000000000001'),
+    ('af02ed2b-3fee-4892-8db7-b58c11df0d75',3,'16:00:00.0','17:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','5d42c8a8-71c5-4cd7-9cc5-6172d7c5deb2', '00000000-0000-4000-8000-000000000001'),
+    ('69f19afd-18e0-44d0-b757-76f2f8704df1',3,'17:00:00.0','18:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','5bc60267-cc51-49ae-9cbd-a0f89cd43b51', '00000000-0000-4000-8000-000000000001'),
+    ('af973478-b5c5-4362-8633-85b39967c8e0',3,'18:00:00.0','19:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','5c4d1870-9a9b-4f80-97e1-6511f96f7415', '00000000-0000-4000-8000-000000000001'),
+    ('72b3086c-b7db-4a7c-be21-c0a2b87be249',3,'19:00:00.0','19:30:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','b8e08ba8-c6a0-47db-8954-c6e63ef33d35', '00000000-0000-4000-8000-000000000001'),
+    ('16b777be-c443-4e69-a255-665adcdd2977',2,'10:00:00.0','11:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','1d8947cf-33f2-4615-9fe6-1c2472ecf20a', '00000000-0000-4000-8000-000000000001'),
+    ('47fd87b9-4aab-4584-8ca8-fe3ace7e1469',2,'11:00:00.0','12:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','4c675d9d-0ea6-42f6-b75d-102a2dfccf2b', '00000000-0000-4000-8000-000000000001'),
+    ('81d4f730-bc69-4133-8788-a078d4f3fc33',2,'12:00:00.0','13:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','446fd4ec-fd35-4f49-8037-ad7a014245ca', '00000000-0000-4000-8000-000000000001'),
+    ('ea2ec8d6-2818-4d50-b820-de050f291efc',2,'13:00:00.0','14:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','55ce7330-b095-45f3-b640-a163552cd421', '00000000-0000-4000-8000-000000000001'),
+    ('1cabdbf2-c66a-4138-95fa-24c157e68cff',2,'14:00:00.0','15:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','f330de0d-cb2e-4fcc-b579-19aaa5b2e8af', '00000000-0000-4000-8000-000000000001'),
+    ('197f64b7-ff29-4b3e-a9fc-ea6aa3c2335d',2,'15:00:00.0','16:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','484f6a08-b700-42de-bab1-2ae44cca3bc5', '00000000-0000-4000-8000-000000000001'),
+    ('959a15a2-e77f-4f80-85f8-4016befdf588',2,'16:00:00.0','17:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','0e35c731-0b73-4d50-8cfe-e3774f71716e', '00000000-0000-4000-8000-000000000001'),
+    ('4a9b6cdc-cdd9-4e14-a221-af4f07149e03',2,'17:00:00.0','18:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','0f36127f-96e9-4dd0-918e-dc11d3aa3fe2', '00000000-0000-4000-8000-000000000001'),
+    ('a90a9707-39b9-4f43-9a0a-46f9134b3aa3',2,'18:00:00.0','19:00:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','bc9fe589-4e3b-4327-afd2-831e0842c9c7', '00000000-0000-4000-8000-000000000001'),
+    ('bf5245e0-3bef-43b5-80c1-fe245de4a8a9',2,'19:00:00.0','19:30:00.0','2020-01-01',NULL,NULL,NULL,'slot-service','b08db53d-3584-4c9e-a36f-7e63389dd727', '00000000-0000-4000-8000-000000000001');
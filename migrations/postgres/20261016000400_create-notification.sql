@@ -0,0 +1,41 @@
+-- E-Mail-Benachrichtigungen. notification_preference hält pro Benutzer die
+-- Zieladresse und die abonnierten Ereignisse (0/1); ohne Zeile wird nichts
+-- verschickt. user_id ist bewusst kein Fremdschlüssel, damit HR die Adresse
+-- schon vor dem Einlösen einer Einladung hinterlegen kann.
+-- notification_outbox nimmt gerenderte Nachrichten in derselben Transaktion
+-- wie die auslösende Änderung auf; der Versand-Job stellt alle
+-- 'Pending'-Einträge mit next_attempt <= jetzt zu.
+
+CREATE TABLE notification_preference (
+    user_id TEXT NOT NULL PRIMARY KEY,
+    email TEXT NOT NULL,
+    booking_deleted BIGINT NOT NULL DEFAULT 0,
+    week_planned BIGINT NOT NULL DEFAULT 0,
+    absence_created BIGINT NOT NULL DEFAULT 0,
+    invitation_created BIGINT NOT NULL DEFAULT 0,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE TABLE notification_outbox (
+    id UUID NOT NULL PRIMARY KEY,
+    recipient_user_id TEXT NOT NULL,
+    recipient_email TEXT NOT NULL,
+    kind TEXT NOT NULL,           -- 'booking_deleted' | 'week_planned' | 'absence_created' | 'invitation_created'
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    state TEXT NOT NULL,          -- 'Pending' | 'Sent' | 'Failed'
+    attempts BIGINT NOT NULL DEFAULT 0,
+    next_attempt TIMESTAMP NOT NULL,
+    last_error TEXT,
+    created TIMESTAMP NOT NULL,
+    sent TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE INDEX notification_outbox_due_idx
+    ON notification_outbox (next_attempt)
+    WHERE state = 'Pending';
@@ -0,0 +1,42 @@
+-- Ausgehende Webhooks. webhook_subscription hält Ziel-URL, HMAC-Schlüssel
+-- und die abonnierten Ereignisse (kommagetrennt, z. B.
+-- 'booking.created,week_status.changed'). webhook_delivery nimmt pro
+-- Ereignis und passendem Abonnement eine Zustellung in derselben
+-- Transaktion wie die fachliche Änderung auf; der Zustell-Job schickt alle
+-- 'Pending'-Einträge mit next_attempt <= jetzt.
+
+CREATE TABLE webhook_subscription (
+    id UUID NOT NULL PRIMARY KEY,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    event_types TEXT NOT NULL,
+    active BIGINT NOT NULL DEFAULT 1,
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE TABLE webhook_delivery (
+    id UUID NOT NULL PRIMARY KEY,
+    subscription_id UUID NOT NULL REFERENCES webhook_subscription(id),
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    state TEXT NOT NULL,          -- 'Pending' | 'Delivered' | 'Failed'
+    attempts BIGINT NOT NULL DEFAULT 0,
+    next_attempt TIMESTAMP NOT NULL,
+    last_status BIGINT,
+    last_error TEXT,
+    created TIMESTAMP NOT NULL,
+    delivered TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE INDEX webhook_delivery_due_idx
+    ON webhook_delivery (next_attempt)
+    WHERE state = 'Pending';
+
+CREATE INDEX webhook_delivery_subscription_idx
+    ON webhook_delivery (subscription_id, created);
@@ -0,0 +1,20 @@
+-- Lohnexport (DATEV LODAS). personnel_number ist die Personalnummer des
+-- Mitarbeiters im Lohnprogramm. payroll_wage_type ordnet einem Werttyp aus
+-- billing_period_sales_person.value_type (z. B. 'balance',
+-- 'custom_extra_hours:Fortbildung') und einer Sicht ('delta', 'ytd_from',
+-- 'ytd_to', 'full_year') eine Lohnart samt Bearbeitungsschlüssel zu.
+
+ALTER TABLE sales_person ADD COLUMN personnel_number TEXT;
+
+CREATE TABLE payroll_wage_type (
+    id UUID NOT NULL PRIMARY KEY,
+    value_type TEXT NOT NULL,
+    value_view TEXT NOT NULL,
+    wage_type TEXT NOT NULL,
+    processing_key BIGINT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
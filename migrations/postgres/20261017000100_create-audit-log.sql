@@ -0,0 +1,24 @@
+-- Änderungsprotokoll. Jeder schreibende Service-Aufruf hängt in derselben
+-- Transaktion wie die fachliche Änderung einen Eintrag an; Einträge werden
+-- nie geändert oder gelöscht. before_json/after_json sind JSON-Schnappschüsse
+-- der Service-Sicht (NULL bei Anlage bzw. Löschung). real_user_id ist nur
+-- gesetzt, wenn ein Admin als user_id handelt (Impersonation).
+
+CREATE TABLE audit_log (
+    id UUID NOT NULL PRIMARY KEY,
+    entity_type TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    action TEXT NOT NULL,         -- 'Create' | 'Update' | 'Delete'
+    user_id TEXT,
+    real_user_id TEXT,
+    process TEXT NOT NULL,
+    before_json TEXT,
+    after_json TEXT,
+    created TIMESTAMP NOT NULL
+);
+
+CREATE INDEX audit_log_entity_idx
+    ON audit_log (entity_type, entity_id, created);
+
+CREATE INDEX audit_log_created_idx
+    ON audit_log (created);
@@ -0,0 +1,5 @@
+-- Ablehnung eines Rebooking-Batches (F5): wer, wann und mit welcher
+-- Begruendung. approved/approved_by bleiben der Genehmigung vorbehalten.
+ALTER TABLE rebooking_batch ADD COLUMN rejected TIMESTAMP;
+ALTER TABLE rebooking_batch ADD COLUMN rejected_by TEXT;
+ALTER TABLE rebooking_batch ADD COLUMN reject_reason TEXT;
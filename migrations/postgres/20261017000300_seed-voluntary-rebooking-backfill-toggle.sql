@@ -0,0 +1,12 @@
+-- F4 Auto-Cron: Merker fuer den einmaligen Backfill. Der Cron traegt nach dem
+-- Backfill den Stichtag aus `voluntary_rebooking_auto_active_from` als
+-- `value` ein; solange beide Werte uebereinstimmen, laeuft kein weiterer
+-- Backfill. Wert leeren → naechster Lauf holt den Backfill nach.
+INSERT INTO toggle (name, enabled, description, update_process)
+VALUES (
+    'voluntary_rebooking_backfill_done_for',
+    0,
+    'Maintained by the voluntary rebooking auto-cron: the cutoff date (ISO YYYY-MM-DD) its one-time backfill already ran for. Clear `value` to run the backfill again.',
+    'f4-auto-cron-migration'
+)
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,28 @@
+-- Registry der periodischen Hintergrund-Jobs. Bisher waren die Cron-Ausdrücke
+-- in `SchedulerServiceImpl::start` fest verdrahtet; jetzt pflegt der Admin
+-- sie über `/scheduler-job`, und der Scheduler lädt sie ohne Neustart neu.
+-- Der Name ist der fachliche Schlüssel und wird im Code auf die auszuführende
+-- Arbeit abgebildet. Die Status-Felder schreibt der Scheduler nach jedem Lauf.
+--
+-- Cron-Ausdrücke im 6-Feld-Format (`sec min hour dom mon dow`), ausgewertet
+-- in lokaler Zeit. Der Carryover-Job lief bisher mit '0 * * * * *' und damit
+-- jede Minute statt wie gedacht stündlich; der Seed korrigiert das.
+CREATE TABLE scheduler_job (
+    name TEXT NOT NULL PRIMARY KEY,
+    cron_schedule TEXT NOT NULL,
+    enabled BIGINT NOT NULL DEFAULT 1,
+    last_run_at TIMESTAMP,
+    last_duration_ms BIGINT,
+    last_error_at TIMESTAMP,
+    last_error_message TEXT,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+INSERT INTO scheduler_job (name, cron_schedule, enabled, update_process, update_version)
+VALUES
+    ('carryover', '0 0 * * * *', 1, 'scheduler-job-migration', '00000000-0000-0000-0000-000000000141'),
+    ('notification_delivery', '30 * * * * *', 1, 'scheduler-job-migration', '00000000-0000-0000-0000-000000000142'),
+    ('webhook_delivery', '15 * * * * *', 1, 'scheduler-job-migration', '00000000-0000-0000-0000-000000000143'),
+    ('voluntary_rebooking', '0 0 3 * * Mon', 1, 'scheduler-job-migration', '00000000-0000-0000-0000-000000000144')
+ON CONFLICT DO NOTHING;
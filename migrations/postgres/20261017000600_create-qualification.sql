@@ -0,0 +1,31 @@
+-- Qualifikationen (z. B. Kasse, Stapler, Schlüsselträger). Der Katalog
+-- `qualification` wird soft-gelöscht; `sales_person_qualification` ordnet
+-- Mitarbeitern Qualifikationen zu und `slot_qualification_requirement`
+-- verlangt pro Slot, dass mindestens `min_count` der gebuchten Personen die
+-- Qualifikation haben. Zuordnungen und Anforderungen werden als Ganzes pro
+-- Mitarbeiter bzw. Slot ersetzt; gelöschte Qualifikationen blendet der
+-- Lesepfad aus.
+CREATE TABLE IF NOT EXISTS qualification (
+    id UUID NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    created TIMESTAMP NOT NULL,
+    deleted TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS sales_person_qualification (
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    qualification_id UUID NOT NULL REFERENCES qualification(id),
+    update_process TEXT NOT NULL,
+    PRIMARY KEY (sales_person_id, qualification_id)
+);
+
+CREATE TABLE IF NOT EXISTS slot_qualification_requirement (
+    slot_id UUID NOT NULL REFERENCES slot(id),
+    qualification_id UUID NOT NULL REFERENCES qualification(id),
+    min_count BIGINT NOT NULL,
+    update_process TEXT NOT NULL,
+    PRIMARY KEY (slot_id, qualification_id)
+);
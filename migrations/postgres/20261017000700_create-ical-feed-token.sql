@@ -0,0 +1,13 @@
+-- Geheime Feed-Tokens für den iCal-Kalender eines Mitarbeiters. Kalender-Apps
+-- abonnieren `/ical/{token}` ohne Session; ein Token gilt, bis `revoked`
+-- gesetzt ist. Ein Mitarbeiter kann mehrere Tokens haben (z. B. je Gerät).
+CREATE TABLE IF NOT EXISTS ical_feed_token (
+    id UUID NOT NULL PRIMARY KEY,
+    sales_person_id UUID NOT NULL REFERENCES sales_person(id),
+    token UUID NOT NULL UNIQUE,
+    label TEXT NOT NULL DEFAULT '',
+    created TIMESTAMP NOT NULL,
+    revoked TIMESTAMP,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
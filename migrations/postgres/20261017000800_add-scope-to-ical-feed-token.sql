@@ -0,0 +1,10 @@
+-- Feed-Tokens gelten nicht mehr nur für den Kalender eines Mitarbeiters,
+-- sondern auch für einen ganzen Schichtplan oder die Team-Abwesenheiten.
+-- `scope` bestimmt, welche der beiden ID-Spalten gesetzt ist:
+-- 'sales-person' → sales_person_id, 'shiftplan' → shiftplan_id,
+-- 'team-absences' → keine.
+ALTER TABLE ical_feed_token
+    ADD COLUMN scope TEXT NOT NULL DEFAULT 'sales-person'
+        CHECK (scope IN ('sales-person', 'shiftplan', 'team-absences'));
+ALTER TABLE ical_feed_token ALTER COLUMN sales_person_id DROP NOT NULL;
+ALTER TABLE ical_feed_token ADD COLUMN shiftplan_id UUID REFERENCES shiftplan(id);
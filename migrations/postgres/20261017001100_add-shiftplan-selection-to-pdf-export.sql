@@ -0,0 +1,20 @@
+-- Shiftplan-Auswahl für den Nextcloud-PDF-Export (pdf_export_config).
+--
+-- include_planning_plans: reine Planungs-Shiftplans (shiftplan.is_planning)
+-- werden per Default weiterhin exportiert; bestehende Installationen behalten
+-- damit ihr bisheriges Verhalten und können die Planungs-Plans abwählen.
+-- combined_pdf: statt einer Datei pro Shiftplan und Woche entsteht eine
+-- Datei pro Woche mit einer Seite je Shiftplan.
+ALTER TABLE pdf_export_config ADD COLUMN include_planning_plans BIGINT NOT NULL DEFAULT 1;
+ALTER TABLE pdf_export_config ADD COLUMN combined_pdf BIGINT NOT NULL DEFAULT 0;
+
+-- Einstellungen je Shiftplan. Ein Shiftplan ohne Zeile ist eingeschlossen
+-- und landet ohne Unterordner und eigenes Präfix direkt im Zielordner.
+CREATE TABLE pdf_export_shiftplan (
+    shiftplan_id UUID NOT NULL PRIMARY KEY,
+    included BIGINT NOT NULL DEFAULT 1,
+    subfolder TEXT,
+    filename_prefix TEXT,
+    update_process TEXT NOT NULL,
+    update_version UUID NOT NULL
+);
@@ -0,0 +1,35 @@
+-- Schichttausch-/Abgabe-Börse. Ein Mitarbeiter bietet ein eigenes Booking an
+-- (state 'Open'), ein anderer übernimmt es ('Accepted'), ein Shiftplanner
+-- genehmigt ('Approved') oder lehnt ab ('Rejected'); der Anbieter kann bis
+-- zur Entscheidung zurückziehen ('Cancelled').
+-- year/calendar_week sind aus dem Booking denormalisiert (Wochen-Sperre,
+-- Listen). Soft-Delete-Konvention (WHERE deleted IS NULL).
+
+CREATE TABLE IF NOT EXISTS shift_swap_request (
+    id BLOB NOT NULL PRIMARY KEY,
+    booking_id BLOB NOT NULL REFERENCES booking(id),
+    offering_sales_person_id BLOB NOT NULL REFERENCES sales_person(id),
+    accepting_sales_person_id BLOB REFERENCES sales_person(id),
+    new_booking_id BLOB REFERENCES booking(id),
+    year INTEGER NOT NULL,
+    calendar_week INTEGER NOT NULL,
+    state TEXT NOT NULL,          -- 'Open' | 'Accepted' | 'Approved' | 'Rejected' | 'Cancelled'
+    message TEXT NOT NULL,
+    reject_reason TEXT,
+    created TEXT NOT NULL,
+    accepted TEXT,
+    decided TEXT,
+    decided_by TEXT,
+    deleted TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+-- Höchstens eine laufende Anfrage pro Booking.
+CREATE UNIQUE INDEX IF NOT EXISTS shift_swap_request_pending_booking_idx
+    ON shift_swap_request (booking_id)
+    WHERE deleted IS NULL AND state IN ('Open', 'Accepted');
+
+CREATE INDEX IF NOT EXISTS shift_swap_request_state_idx
+    ON shift_swap_request (state)
+    WHERE deleted IS NULL;
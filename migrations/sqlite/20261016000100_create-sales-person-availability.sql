@@ -0,0 +1,26 @@
+-- Wiederkehrende Verfügbarkeit pro Mitarbeiter: Wochentag + optionales
+-- Zeitfenster, gültig von valid_from bis (optional) valid_to.
+-- kind: 'Preferred' | 'Available' | 'Unavailable'.
+-- time_from/time_to sind entweder beide NULL (ganzer Tag) oder beide gesetzt.
+-- Soft-Delete-Konvention (WHERE deleted IS NULL).
+
+CREATE TABLE IF NOT EXISTS sales_person_availability (
+    id BLOB NOT NULL PRIMARY KEY,
+    sales_person_id BLOB NOT NULL REFERENCES sales_person(id),
+    day_of_week INTEGER NOT NULL,
+    time_from TEXT,
+    time_to TEXT,
+    valid_from TEXT NOT NULL,
+    valid_to TEXT,
+    kind TEXT NOT NULL,
+    created TEXT NOT NULL,
+    deleted TEXT,
+
+    update_timestamp TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS sales_person_availability_sales_person_idx
+    ON sales_person_availability (sales_person_id)
+    WHERE deleted IS NULL;
@@ -0,0 +1,41 @@
+-- E-Mail-Benachrichtigungen. notification_preference hält pro Benutzer die
+-- Zieladresse und die abonnierten Ereignisse (0/1); ohne Zeile wird nichts
+-- verschickt. user_id ist bewusst kein Fremdschlüssel, damit HR die Adresse
+-- schon vor dem Einlösen einer Einladung hinterlegen kann.
+-- notification_outbox nimmt gerenderte Nachrichten in derselben Transaktion
+-- wie die auslösende Änderung auf; der Versand-Job stellt alle
+-- 'Pending'-Einträge mit next_attempt <= jetzt zu.
+
+CREATE TABLE IF NOT EXISTS notification_preference (
+    user_id TEXT NOT NULL PRIMARY KEY,
+    email TEXT NOT NULL,
+    booking_deleted INTEGER NOT NULL DEFAULT 0,
+    week_planned INTEGER NOT NULL DEFAULT 0,
+    absence_created INTEGER NOT NULL DEFAULT 0,
+    invitation_created INTEGER NOT NULL DEFAULT 0,
+    created TEXT NOT NULL,
+    deleted TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS notification_outbox (
+    id BLOB NOT NULL PRIMARY KEY,
+    recipient_user_id TEXT NOT NULL,
+    recipient_email TEXT NOT NULL,
+    kind TEXT NOT NULL,           -- 'booking_deleted' | 'week_planned' | 'absence_created' | 'invitation_created'
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    state TEXT NOT NULL,          -- 'Pending' | 'Sent' | 'Failed'
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt TEXT NOT NULL,
+    last_error TEXT,
+    created TEXT NOT NULL,
+    sent TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS notification_outbox_due_idx
+    ON notification_outbox (next_attempt)
+    WHERE state = 'Pending';
@@ -0,0 +1,34 @@
+-- Tatsächlich geleistete Arbeitszeit (Stempeluhr). Ein Eintrag beginnt mit
+-- start_time und ist offen, solange end_time NULL ist; pro Mitarbeiter darf
+-- höchstens ein Eintrag offen sein (im Service geprüft). booking_id
+-- verknüpft optional mit der geplanten Schicht. source: 'Clock'
+-- (ein-/ausgestempelt) | 'Manual' (von HR erfasst).
+-- Soft-Delete-Konvention (WHERE deleted IS NULL).
+--
+-- employee_work_details.use_actual_hours: Vertrag rechnet im Saldo mit den
+-- erfassten statt mit den geplanten Stunden.
+
+CREATE TABLE IF NOT EXISTS time_entry (
+    id BLOB NOT NULL PRIMARY KEY,
+    sales_person_id BLOB NOT NULL REFERENCES sales_person(id),
+    booking_id BLOB REFERENCES booking(id),
+    start_time TEXT NOT NULL,
+    end_time TEXT,
+    description TEXT NOT NULL DEFAULT '',
+    source TEXT NOT NULL,
+    created TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    deleted TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS time_entry_sales_person_start_idx
+    ON time_entry (sales_person_id, start_time)
+    WHERE deleted IS NULL;
+
+CREATE INDEX IF NOT EXISTS time_entry_start_idx
+    ON time_entry (start_time)
+    WHERE deleted IS NULL;
+
+ALTER TABLE employee_work_details ADD COLUMN use_actual_hours INTEGER NOT NULL DEFAULT 0;
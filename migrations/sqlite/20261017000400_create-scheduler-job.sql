@@ -0,0 +1,27 @@
+-- Registry der periodischen Hintergrund-Jobs. Bisher waren die Cron-Ausdrücke
+-- in `SchedulerServiceImpl::start` fest verdrahtet; jetzt pflegt der Admin
+-- sie über `/scheduler-job`, und der Scheduler lädt sie ohne Neustart neu.
+-- Der Name ist der fachliche Schlüssel und wird im Code auf die auszuführende
+-- Arbeit abgebildet. Die Status-Felder schreibt der Scheduler nach jedem Lauf.
+--
+-- Cron-Ausdrücke im 6-Feld-Format (`sec min hour dom mon dow`), ausgewertet
+-- in lokaler Zeit. Der Carryover-Job lief bisher mit '0 * * * * *' und damit
+-- jede Minute statt wie gedacht stündlich; der Seed korrigiert das.
+CREATE TABLE IF NOT EXISTS scheduler_job (
+    name TEXT NOT NULL PRIMARY KEY,
+    cron_schedule TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    last_run_at TEXT,
+    last_duration_ms INTEGER,
+    last_error_at TEXT,
+    last_error_message TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+INSERT OR IGNORE INTO scheduler_job (name, cron_schedule, enabled, update_process, update_version)
+VALUES
+    ('carryover', '0 0 * * * *', 1, 'scheduler-job-migration', X'00000000000000000000000000000141'),
+    ('notification_delivery', '30 * * * * *', 1, 'scheduler-job-migration', X'00000000000000000000000000000142'),
+    ('webhook_delivery', '15 * * * * *', 1, 'scheduler-job-migration', X'00000000000000000000000000000143'),
+    ('voluntary_rebooking', '0 0 3 * * Mon', 1, 'scheduler-job-migration', X'00000000000000000000000000000144');
@@ -0,0 +1,22 @@
+-- Merkliste der (Mitarbeiter, Jahr)-Paare, deren Carryover neu berechnet
+-- werden muss. Änderungen an Buchungen, Extra-Stunden, Abwesenheiten und
+-- Arbeitsverträgen tragen das betroffene ISO-Jahr ein; der Carryover-Job
+-- rechnet nur diese Paare (und die Folgejahre) neu und löscht die Einträge.
+-- `update_version` wechselt bei jedem erneuten Markieren, damit der Job nur
+-- die Einträge löscht, die er auch gelesen hat.
+CREATE TABLE IF NOT EXISTS carryover_dirty (
+    sales_person_id BLOB NOT NULL,
+    year INTEGER NOT NULL,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL,
+    PRIMARY KEY (sales_person_id, year)
+);
+
+-- Der Job ist ohne Markierungen ein einzelnes SELECT und darf deshalb öfter
+-- laufen als der bisherige stündliche Komplett-Neuaufbau. Ein vom Admin
+-- geänderter Ausdruck bleibt unangetastet.
+UPDATE scheduler_job
+SET cron_schedule = '0 */5 * * * *',
+    update_process = 'carryover-dirty-migration',
+    update_version = X'00000000000000000000000000000151'
+WHERE name = 'carryover' AND cron_schedule = '0 0 * * * *';
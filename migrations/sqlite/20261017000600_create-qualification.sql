@@ -0,0 +1,35 @@
+-- Qualifikationen (z. B. Kasse, Stapler, Schlüsselträger). Der Katalog
+-- `qualification` wird soft-gelöscht; `sales_person_qualification` ordnet
+-- Mitarbeitern Qualifikationen zu und `slot_qualification_requirement`
+-- verlangt pro Slot, dass mindestens `min_count` der gebuchten Personen die
+-- Qualifikation haben. Zuordnungen und Anforderungen werden als Ganzes pro
+-- Mitarbeiter bzw. Slot ersetzt; gelöschte Qualifikationen blendet der
+-- Lesepfad aus.
+CREATE TABLE IF NOT EXISTS qualification (
+    id BLOB NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    created TEXT NOT NULL,
+    deleted TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS sales_person_qualification (
+    sales_person_id BLOB NOT NULL,
+    qualification_id BLOB NOT NULL,
+    update_process TEXT NOT NULL,
+    PRIMARY KEY (sales_person_id, qualification_id),
+    FOREIGN KEY (sales_person_id) REFERENCES sales_person(id),
+    FOREIGN KEY (qualification_id) REFERENCES qualification(id)
+);
+
+CREATE TABLE IF NOT EXISTS slot_qualification_requirement (
+    slot_id BLOB NOT NULL,
+    qualification_id BLOB NOT NULL,
+    min_count INTEGER NOT NULL,
+    update_process TEXT NOT NULL,
+    PRIMARY KEY (slot_id, qualification_id),
+    FOREIGN KEY (slot_id) REFERENCES slot(id),
+    FOREIGN KEY (qualification_id) REFERENCES qualification(id)
+);
@@ -0,0 +1,14 @@
+-- Geheime Feed-Tokens für den iCal-Kalender eines Mitarbeiters. Kalender-Apps
+-- abonnieren `/ical/{token}` ohne Session; ein Token gilt, bis `revoked`
+-- gesetzt ist. Ein Mitarbeiter kann mehrere Tokens haben (z. B. je Gerät).
+CREATE TABLE IF NOT EXISTS ical_feed_token (
+    id BLOB NOT NULL PRIMARY KEY,
+    sales_person_id BLOB NOT NULL,
+    token BLOB NOT NULL UNIQUE,
+    label TEXT NOT NULL DEFAULT '',
+    created TEXT NOT NULL,
+    revoked TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL,
+    FOREIGN KEY (sales_person_id) REFERENCES sales_person(id)
+);
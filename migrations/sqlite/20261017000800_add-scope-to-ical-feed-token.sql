@@ -0,0 +1,32 @@
+-- Feed-Tokens gelten nicht mehr nur für den Kalender eines Mitarbeiters,
+-- sondern auch für einen ganzen Schichtplan oder die Team-Abwesenheiten.
+-- `scope` bestimmt, welche der beiden ID-Spalten gesetzt ist:
+-- 'sales-person' → sales_person_id, 'shiftplan' → shiftplan_id,
+-- 'team-absences' → keine.
+CREATE TABLE ical_feed_token_new (
+    id BLOB NOT NULL PRIMARY KEY,
+    scope TEXT NOT NULL DEFAULT 'sales-person'
+        CHECK (scope IN ('sales-person', 'shiftplan', 'team-absences')),
+    sales_person_id BLOB,
+    shiftplan_id BLOB,
+    token BLOB NOT NULL UNIQUE,
+    label TEXT NOT NULL DEFAULT '',
+    created TEXT NOT NULL,
+    revoked TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL,
+    FOREIGN KEY (sales_person_id) REFERENCES sales_person(id),
+    FOREIGN KEY (shiftplan_id) REFERENCES shiftplan(id)
+);
+
+INSERT INTO ical_feed_token_new (
+    id, scope, sales_person_id, shiftplan_id, token, label, created, revoked,
+    update_process, update_version
+)
+SELECT
+    id, 'sales-person', sales_person_id, NULL, token, label, created, revoked,
+    update_process, update_version
+FROM ical_feed_token;
+
+DROP TABLE ical_feed_token;
+ALTER TABLE ical_feed_token_new RENAME TO ical_feed_token;
@@ -0,0 +1,6 @@
+-- Urlaubsanträge: Mitarbeiter können Abwesenheiten für sich beantragen, HR
+-- bzw. Schichtplanung entscheidet. Nur 'approved' fließt in Stundenableitung
+-- und Reporting ein; Bestandsdaten sind per Default genehmigt (no-drift).
+ALTER TABLE absence_period
+ADD COLUMN status TEXT NOT NULL DEFAULT 'approved'
+    CHECK (status IN ('requested', 'approved', 'rejected', 'cancelled'));
@@ -0,0 +1,43 @@
+-- Benannte Export-Ziele neben der einzelnen Nextcloud-Konfiguration
+-- (pdf_export_config). Jedes Ziel hat eine Zielart ('webdav',
+-- 'local-directory', 'drop-directory'), einen eigenen Cron-Ausdruck im 6-Feld-Format,
+-- ein Artefakt ('shiftplan-week-pdf', 'billing-period-csv', 'ical-snapshot')
+-- und ein Dateinamen-Muster mit Platzhaltern wie {year} oder {week}.
+-- `secret` ist wie webdav_app_token in pdf_export_config Klartext.
+--
+-- export_target_run nimmt jeden Lauf eines Ziels auf (Cron oder manuell)
+-- und ersetzt für diese Ziele das Paar last_success_at/last_error_message.
+CREATE TABLE IF NOT EXISTS export_target (
+    id BLOB NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 0,
+    url TEXT,
+    username TEXT,
+    secret TEXT,
+    path TEXT NOT NULL,
+    cron_schedule TEXT NOT NULL,
+    artifact_type TEXT NOT NULL,
+    filename_pattern TEXT NOT NULL,
+    weeks_horizon INTEGER NOT NULL DEFAULT 4,
+    created TEXT NOT NULL,
+    deleted TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS export_target_run (
+    id BLOB NOT NULL PRIMARY KEY,
+    target_id BLOB NOT NULL REFERENCES export_target(id),
+    started TEXT NOT NULL,
+    duration_ms INTEGER NOT NULL,
+    state TEXT NOT NULL,          -- 'Success' | 'Partial' | 'Failed'
+    files_written INTEGER NOT NULL DEFAULT 0,
+    files_skipped INTEGER NOT NULL DEFAULT 0,
+    message TEXT,
+    update_process TEXT NOT NULL,
+    update_version BLOB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS export_target_run_target_idx
+    ON export_target_run (target_id, started);
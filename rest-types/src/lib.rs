@@ -206,6 +206,9 @@ pub struct SalesPersonTO {
     pub is_paid: Option<bool>,
     #[serde(default)]
     pub inactive: bool,
+    /// Personalnummer im Lohnprogramm; nur für HR befüllt.
+    #[serde(default)]
+    pub personnel_number: Option<Arc<str>>,
     #[serde(default)]
     pub deleted: Option<time::PrimitiveDateTime>,
     #[serde(rename = "$version")]
@@ -221,6 +224,7 @@ impl From<&SalesPerson> for SalesPersonTO {
             background_color: sales_person.background_color.clone(),
             is_paid: sales_person.is_paid,
             inactive: sales_person.inactive,
+            personnel_number: sales_person.personnel_number.clone(),
             deleted: sales_person.deleted,
             version: sales_person.version,
         }
@@ -235,6 +239,7 @@ impl From<&SalesPersonTO> for SalesPerson {
             background_color: sales_person.background_color.clone(),
             is_paid: sales_person.is_paid,
             inactive: sales_person.inactive,
+            personnel_number: sales_person.personnel_number.clone(),
             deleted: sales_person.deleted,
             version: sales_person.version,
         }
@@ -747,6 +752,9 @@ pub struct EmployeeWorkDetailsTO {
     pub cap_planned_hours_to_expected: bool,
     #[serde(default)]
     pub committed_voluntary: f32,
+    /// Saldo rechnet mit erfassten (Stempeluhr) statt geplanten Stunden.
+    #[serde(default)]
+    pub use_actual_hours: bool,
 
     pub monday: bool,
     pub tuesday: bool,
@@ -787,6 +795,7 @@ impl From<&service::employee_work_details::EmployeeWorkDetails> for EmployeeWork
             is_dynamic: working_hours.is_dynamic,
             cap_planned_hours_to_expected: working_hours.cap_planned_hours_to_expected,
             committed_voluntary: working_hours.committed_voluntary,
+            use_actual_hours: working_hours.use_actual_hours,
 
             monday: working_hours.monday,
             tuesday: working_hours.tuesday,
@@ -826,6 +835,7 @@ impl From<&EmployeeWorkDetailsTO> for service::employee_work_details::EmployeeWo
             is_dynamic: working_hours.is_dynamic,
             cap_planned_hours_to_expected: working_hours.cap_planned_hours_to_expected,
             committed_voluntary: working_hours.committed_voluntary,
+            use_actual_hours: working_hours.use_actual_hours,
 
             monday: working_hours.monday,
             tuesday: working_hours.tuesday,
@@ -989,6 +999,101 @@ impl From<&SalesPersonUnavailableTO> for service::sales_person_unavailable::Sale
     }
 }
 
+/// Art einer wiederkehrenden Verfügbarkeits-Regel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum AvailabilityKindTO {
+    Preferred,
+    Available,
+    Unavailable,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::sales_person_availability::AvailabilityKind> for AvailabilityKindTO {
+    fn from(kind: service::sales_person_availability::AvailabilityKind) -> Self {
+        match kind {
+            service::sales_person_availability::AvailabilityKind::Preferred => Self::Preferred,
+            service::sales_person_availability::AvailabilityKind::Available => Self::Available,
+            service::sales_person_availability::AvailabilityKind::Unavailable => Self::Unavailable,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<AvailabilityKindTO> for service::sales_person_availability::AvailabilityKind {
+    fn from(kind: AvailabilityKindTO) -> Self {
+        match kind {
+            AvailabilityKindTO::Preferred => Self::Preferred,
+            AvailabilityKindTO::Available => Self::Available,
+            AvailabilityKindTO::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+/// Wiederkehrende Verfügbarkeit: Wochentag + optionales Zeitfenster
+/// (beide Grenzen weglassen = ganzer Tag) + Gültigkeitszeitraum.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct SalesPersonAvailabilityTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub day_of_week: DayOfWeekTO,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "time")]
+    pub time_from: Option<time::Time>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "time")]
+    pub time_to: Option<time::Time>,
+    pub valid_from: time::Date,
+    #[serde(default)]
+    pub valid_to: Option<time::Date>,
+    pub kind: AvailabilityKindTO,
+    #[serde(default)]
+    pub created: Option<time::PrimitiveDateTime>,
+    #[serde(default)]
+    pub deleted: Option<time::PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::sales_person_availability::SalesPersonAvailability>
+    for SalesPersonAvailabilityTO
+{
+    fn from(availability: &service::sales_person_availability::SalesPersonAvailability) -> Self {
+        Self {
+            id: availability.id,
+            sales_person_id: availability.sales_person_id,
+            day_of_week: availability.day_of_week.into(),
+            time_from: availability.time_from,
+            time_to: availability.time_to,
+            valid_from: availability.valid_from,
+            valid_to: availability.valid_to,
+            kind: availability.kind.into(),
+            created: availability.created,
+            deleted: availability.deleted,
+            version: availability.version,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<&SalesPersonAvailabilityTO>
+    for service::sales_person_availability::SalesPersonAvailability
+{
+    fn from(availability: &SalesPersonAvailabilityTO) -> Self {
+        Self {
+            id: availability.id,
+            sales_person_id: availability.sales_person_id,
+            day_of_week: availability.day_of_week.into(),
+            time_from: availability.time_from,
+            time_to: availability.time_to,
+            valid_from: availability.valid_from,
+            valid_to: availability.valid_to,
+            kind: availability.kind.into(),
+            created: availability.created,
+            deleted: availability.deleted,
+            version: availability.version,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BookingConflictTO {
     pub booking: BookingTO,
@@ -1328,6 +1433,114 @@ impl From<&SpecialDayTO> for service::special_days::SpecialDay {
     }
 }
 
+/// Region des Feiertagskalenders, ISO-3166-2-Code (`DE` = nur bundesweite
+/// Feiertage).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum HolidayRegionTO {
+    #[default]
+    #[serde(rename = "DE")]
+    Germany,
+    #[serde(rename = "DE-BW")]
+    BadenWuerttemberg,
+    #[serde(rename = "DE-BY")]
+    Bavaria,
+    #[serde(rename = "DE-BE")]
+    Berlin,
+    #[serde(rename = "DE-BB")]
+    Brandenburg,
+    #[serde(rename = "DE-HB")]
+    Bremen,
+    #[serde(rename = "DE-HH")]
+    Hamburg,
+    #[serde(rename = "DE-HE")]
+    Hesse,
+    #[serde(rename = "DE-MV")]
+    MecklenburgWesternPomerania,
+    #[serde(rename = "DE-NI")]
+    LowerSaxony,
+    #[serde(rename = "DE-NW")]
+    NorthRhineWestphalia,
+    #[serde(rename = "DE-RP")]
+    RhinelandPalatinate,
+    #[serde(rename = "DE-SL")]
+    Saarland,
+    #[serde(rename = "DE-SN")]
+    Saxony,
+    #[serde(rename = "DE-ST")]
+    SaxonyAnhalt,
+    #[serde(rename = "DE-SH")]
+    SchleswigHolstein,
+    #[serde(rename = "DE-TH")]
+    Thuringia,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::holiday_calendar::HolidayRegion> for HolidayRegionTO {
+    fn from(region: service::holiday_calendar::HolidayRegion) -> Self {
+        match region {
+            service::holiday_calendar::HolidayRegion::Germany => Self::Germany,
+            service::holiday_calendar::HolidayRegion::BadenWuerttemberg => Self::BadenWuerttemberg,
+            service::holiday_calendar::HolidayRegion::Bavaria => Self::Bavaria,
+            service::holiday_calendar::HolidayRegion::Berlin => Self::Berlin,
+            service::holiday_calendar::HolidayRegion::Brandenburg => Self::Brandenburg,
+            service::holiday_calendar::HolidayRegion::Bremen => Self::Bremen,
+            service::holiday_calendar::HolidayRegion::Hamburg => Self::Hamburg,
+            service::holiday_calendar::HolidayRegion::Hesse => Self::Hesse,
+            service::holiday_calendar::HolidayRegion::MecklenburgWesternPomerania => Self::MecklenburgWesternPomerania,
+            service::holiday_calendar::HolidayRegion::LowerSaxony => Self::LowerSaxony,
+            service::holiday_calendar::HolidayRegion::NorthRhineWestphalia => Self::NorthRhineWestphalia,
+            service::holiday_calendar::HolidayRegion::RhinelandPalatinate => Self::RhinelandPalatinate,
+            service::holiday_calendar::HolidayRegion::Saarland => Self::Saarland,
+            service::holiday_calendar::HolidayRegion::Saxony => Self::Saxony,
+            service::holiday_calendar::HolidayRegion::SaxonyAnhalt => Self::SaxonyAnhalt,
+            service::holiday_calendar::HolidayRegion::SchleswigHolstein => Self::SchleswigHolstein,
+            service::holiday_calendar::HolidayRegion::Thuringia => Self::Thuringia,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<HolidayRegionTO> for service::holiday_calendar::HolidayRegion {
+    fn from(region: HolidayRegionTO) -> Self {
+        match region {
+            HolidayRegionTO::Germany => Self::Germany,
+            HolidayRegionTO::BadenWuerttemberg => Self::BadenWuerttemberg,
+            HolidayRegionTO::Bavaria => Self::Bavaria,
+            HolidayRegionTO::Berlin => Self::Berlin,
+            HolidayRegionTO::Brandenburg => Self::Brandenburg,
+            HolidayRegionTO::Bremen => Self::Bremen,
+            HolidayRegionTO::Hamburg => Self::Hamburg,
+            HolidayRegionTO::Hesse => Self::Hesse,
+            HolidayRegionTO::MecklenburgWesternPomerania => Self::MecklenburgWesternPomerania,
+            HolidayRegionTO::LowerSaxony => Self::LowerSaxony,
+            HolidayRegionTO::NorthRhineWestphalia => Self::NorthRhineWestphalia,
+            HolidayRegionTO::RhinelandPalatinate => Self::RhinelandPalatinate,
+            HolidayRegionTO::Saarland => Self::Saarland,
+            HolidayRegionTO::Saxony => Self::Saxony,
+            HolidayRegionTO::SaxonyAnhalt => Self::SaxonyAnhalt,
+            HolidayRegionTO::SchleswigHolstein => Self::SchleswigHolstein,
+            HolidayRegionTO::Thuringia => Self::Thuringia,
+        }
+    }
+}
+
+/// Berechneter Feiertag; `existing` ist der `SpecialDay`, der an diesem Datum
+/// schon existiert und beim Übernehmen unverändert bleibt.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct HolidayCandidateTO {
+    pub date: time::Date,
+    pub name: Arc<str>,
+    pub existing: Option<SpecialDayTO>,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::holiday_calendar::HolidayCandidate> for HolidayCandidateTO {
+    fn from(candidate: &service::holiday_calendar::HolidayCandidate) -> Self {
+        Self {
+            date: candidate.date,
+            name: candidate.name.clone(),
+            existing: candidate.existing.as_ref().map(SpecialDayTO::from),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct VacationPayloadTO {
     pub sales_person_id: Uuid,
@@ -1687,6 +1900,77 @@ pub struct UpdateTextTemplateRequestTO {
     pub template_engine: TemplateEngineTO,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct ValidateTextTemplateRequestTO {
+    pub template_text: Arc<str>,
+    #[serde(default)]
+    pub template_engine: TemplateEngineTO,
+}
+
+/// Syntaxfehler eines Templates; `line`/`column` sind 1-basiert.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, ToSchema)]
+pub struct TemplateSyntaxErrorTO {
+    pub message: Arc<str>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::text_template::TemplateSyntaxError> for TemplateSyntaxErrorTO {
+    fn from(error: &service::text_template::TemplateSyntaxError) -> Self {
+        Self {
+            message: error.message.clone(),
+            line: error.line,
+            column: error.column,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, ToSchema)]
+pub struct TextTemplateValidationTO {
+    pub valid: bool,
+    pub error: Option<TemplateSyntaxErrorTO>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TemplatePreviewSourceTO {
+    #[default]
+    Sample,
+    Live,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct PreviewTextTemplateRequestTO {
+    #[serde(default)]
+    pub name: Option<Arc<str>>,
+    pub template_type: Arc<str>,
+    pub template_text: Arc<str>,
+    #[serde(default)]
+    pub template_engine: TemplateEngineTO,
+    #[serde(default)]
+    pub source: TemplatePreviewSourceTO,
+    /// Nur für `live` bei `billing-period`-Typen.
+    #[serde(default)]
+    pub billing_period_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct TextTemplatePreviewTO {
+    pub output: Arc<str>,
+    pub variables: Arc<[Arc<str>]>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::text_template_preview::TemplatePreview> for TextTemplatePreviewTO {
+    fn from(preview: &service::text_template_preview::TemplatePreview) -> Self {
+        Self {
+            output: preview.output.clone(),
+            variables: preview.variables.clone(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct BlockTO {
     pub year: u32,
@@ -1861,6 +2145,42 @@ impl From<&DayFractionTO> for service::absence::DayFraction {
     }
 }
 
+/// Antrags-Status einer Absence-Periode. Read-only auf dem Wire: der
+/// Service setzt ihn (HR → `Approved`, sonst `Requested`) und ändert ihn nur
+/// über `/approve`, `/reject` und `/cancel`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema, Default)]
+pub enum AbsenceStatusTO {
+    Requested,
+    #[default]
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::absence::AbsenceStatus> for AbsenceStatusTO {
+    fn from(s: &service::absence::AbsenceStatus) -> Self {
+        match s {
+            service::absence::AbsenceStatus::Requested => Self::Requested,
+            service::absence::AbsenceStatus::Approved => Self::Approved,
+            service::absence::AbsenceStatus::Rejected => Self::Rejected,
+            service::absence::AbsenceStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&AbsenceStatusTO> for service::absence::AbsenceStatus {
+    fn from(s: &AbsenceStatusTO) -> Self {
+        match s {
+            AbsenceStatusTO::Requested => Self::Requested,
+            AbsenceStatusTO::Approved => Self::Approved,
+            AbsenceStatusTO::Rejected => Self::Rejected,
+            AbsenceStatusTO::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct AbsencePeriodTO {
     #[serde(default)]
@@ -1882,6 +2202,8 @@ pub struct AbsencePeriodTO {
     pub version: Uuid,
     #[serde(default)]
     pub day_fraction: DayFractionTO,
+    #[serde(default)]
+    pub status: AbsenceStatusTO,
     /// Read-only Anzeige-Feld: die abgeleiteten Urlaubs-/Abwesenheitstage dieser
     /// Periode = Anzahl der AKTIVEN Arbeitstage im Range (ohne Feiertage) ×
     /// Day-Fraction (0.5 bei `Half`). Vom List-Endpoint befüllt — Single Source
@@ -1905,6 +2227,7 @@ impl From<&service::absence::AbsencePeriod> for AbsencePeriodTO {
             deleted: a.deleted,
             version: a.version,
             day_fraction: (&a.day_fraction).into(),
+            status: (&a.status).into(),
             // Default; vom List-Endpoint via derive_hours_for_range überschrieben.
             derived_days: 0.0,
         }
@@ -1924,6 +2247,7 @@ impl From<&AbsencePeriodTO> for service::absence::AbsencePeriod {
             deleted: a.deleted,
             version: a.version,
             day_fraction: (&a.day_fraction).into(),
+            status: (&a.status).into(),
         }
     }
 }
@@ -2010,7 +2334,7 @@ pub struct AbsenceListWithProjectionTO {
 // 5 inline DTOs für die Phase-3 REST-Surface:
 // * `WarningTO`             — Tag-Enum (5 Varianten), JSON-Form
 //                             `{ "kind": ..., "data": { ... } }`.
-// * `UnavailabilityMarkerTO`— Tag-Enum (3 Varianten) für die per-sales-
+// * `UnavailabilityMarkerTO`— Tag-Enum (4 Varianten) für die per-sales-
 //                             person-Sicht (`ShiftplanDayTO.unavailable`).
 // * `BookingCreateResultTO` — Wrapper für `POST /shiftplan-edit/booking`.
 // * `CopyWeekResultTO`      — Wrapper für `POST /shiftplan-edit/copy-week`.
@@ -2075,6 +2399,18 @@ pub enum WarningTO {
         current_paid_count: u8,
         max_paid_employees: u8,
     },
+    /// Wire-Mirror von `service::warning::Warning::QualificationNotCovered`:
+    /// nach der Buchung haben weniger als `required_count` der in
+    /// (year, week, slot) gebuchten Personen die Qualifikation.
+    QualificationNotCovered {
+        slot_id: Uuid,
+        booking_id: Uuid,
+        year: u32,
+        week: u8,
+        qualification_id: Uuid,
+        required_count: u8,
+        qualified_count: u8,
+    },
 }
 
 #[cfg(feature = "service-impl")]
@@ -2134,6 +2470,23 @@ impl From<&service::warning::Warning> for WarningTO {
                 current_paid_count: *current_paid_count,
                 max_paid_employees: *max_paid_employees,
             },
+            service::warning::Warning::QualificationNotCovered {
+                slot_id,
+                booking_id,
+                year,
+                week,
+                qualification_id,
+                required_count,
+                qualified_count,
+            } => Self::QualificationNotCovered {
+                slot_id: *slot_id,
+                booking_id: *booking_id,
+                year: *year,
+                week: *week,
+                qualification_id: *qualification_id,
+                required_count: *required_count,
+                qualified_count: *qualified_count,
+            },
         }
     }
 }
@@ -2155,6 +2508,19 @@ pub enum UnavailabilityMarkerTO {
         absence_id: Uuid,
         category: AbsenceCategoryTO,
     },
+    /// Nur wiederkehrende `Unavailable`-Regeln mit Zeitfenster greifen —
+    /// außerhalb der Fenster ist der Tag verfügbar.
+    PartiallyUnavailable { windows: Vec<UnavailableWindowTO> },
+}
+
+/// Zeitfenster (halboffen `[from, to)`) einer wiederkehrenden
+/// `Unavailable`-Regel.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct UnavailableWindowTO {
+    #[schema(value_type = String, format = "time")]
+    pub from: time::Time,
+    #[schema(value_type = String, format = "time")]
+    pub to: time::Time,
 }
 
 #[cfg(feature = "service-impl")]
@@ -2176,6 +2542,17 @@ impl From<&service::shiftplan::UnavailabilityMarker> for UnavailabilityMarkerTO
                 absence_id: *absence_id,
                 category: category.into(),
             },
+            service::shiftplan::UnavailabilityMarker::PartiallyUnavailable { windows } => {
+                Self::PartiallyUnavailable {
+                    windows: windows
+                        .iter()
+                        .map(|window| UnavailableWindowTO {
+                            from: window.from,
+                            to: window.to,
+                        })
+                        .collect(),
+                }
+            }
         }
     }
 }
@@ -2214,6 +2591,75 @@ impl From<&service::shiftplan_edit::CopyWeekResult> for CopyWeekResultTO {
     }
 }
 
+/// Slot, den der Schichtplan-Generator nicht vollständig besetzen konnte.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct UnfilledSlotTO {
+    pub slot_id: Uuid,
+    pub min_resources: u8,
+    pub booked: u8,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::shiftplan_generator::UnfilledSlot> for UnfilledSlotTO {
+    fn from(s: &service::shiftplan_generator::UnfilledSlot) -> Self {
+        Self {
+            slot_id: s.slot_id,
+            min_resources: s.min_resources,
+            booked: s.booked,
+        }
+    }
+}
+
+/// Stunden-Bilanz pro Person im Generator-Entwurf.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProposedHoursTO {
+    pub sales_person_id: Uuid,
+    pub expected_hours: f32,
+    pub planned_hours_before: f32,
+    pub planned_hours_after: f32,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::shiftplan_generator::ProposedHours> for ProposedHoursTO {
+    fn from(h: &service::shiftplan_generator::ProposedHours) -> Self {
+        Self {
+            sales_person_id: h.sales_person_id,
+            expected_hours: h.expected_hours,
+            planned_hours_before: h.planned_hours_before,
+            planned_hours_after: h.planned_hours_after,
+        }
+    }
+}
+
+/// Entwurf von `POST /shiftplan-generator/{shiftplan_id}/{year}/{week}`.
+/// Die Booking-IDs stammen aus dem Trockenlauf und sind nur innerhalb des
+/// Entwurfs gültig (die `warnings` referenzieren sie).
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftplanProposalTO {
+    pub shiftplan_id: Uuid,
+    pub year: u32,
+    pub week: u8,
+    pub bookings: Vec<BookingTO>,
+    pub unfilled_slots: Vec<UnfilledSlotTO>,
+    pub hours: Vec<ProposedHoursTO>,
+    pub warnings: Vec<WarningTO>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::shiftplan_generator::ShiftplanProposal> for ShiftplanProposalTO {
+    fn from(p: &service::shiftplan_generator::ShiftplanProposal) -> Self {
+        Self {
+            shiftplan_id: p.shiftplan_id,
+            year: p.year,
+            week: p.week,
+            bookings: p.bookings.iter().map(BookingTO::from).collect(),
+            unfilled_slots: p.unfilled_slots.iter().map(UnfilledSlotTO::from).collect(),
+            hours: p.hours.iter().map(ProposedHoursTO::from).collect(),
+            warnings: p.warnings.iter().map(WarningTO::from).collect(),
+        }
+    }
+}
+
 /// Wrapper für `POST /absence-period` und `PATCH /absence-period/{id}`
 /// (BOOK-01 Forward-Warning).
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -2299,6 +2745,35 @@ impl From<&VacationBalanceTO> for service::vacation_balance::VacationBalance {
     }
 }
 
+/// Eintrag in `GET /absence-period/requests`: offener Antrag samt
+/// Auswirkung bei Genehmigung. `remaining_days_after` ist der Resturlaub im
+/// Jahr von `from_date` nach Abzug des Antrags (nur `Vacation`).
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct PendingAbsenceRequestTO {
+    pub absence: AbsencePeriodTO,
+    pub requested_days: f32,
+    #[serde(default)]
+    pub vacation_balance: Option<VacationBalanceTO>,
+    #[serde(default)]
+    pub remaining_days_after: Option<f32>,
+    pub warnings: Vec<WarningTO>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::absence_request::PendingAbsenceRequest> for PendingAbsenceRequestTO {
+    fn from(r: &service::absence_request::PendingAbsenceRequest) -> Self {
+        let mut absence = AbsencePeriodTO::from(&r.absence);
+        absence.derived_days = r.requested_days;
+        Self {
+            absence,
+            requested_days: r.requested_days,
+            vacation_balance: r.vacation_balance.as_ref().map(VacationBalanceTO::from),
+            remaining_days_after: r.remaining_days_after,
+            warnings: r.warnings.iter().map(WarningTO::from).collect(),
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Phase 28 — Vacation-Entitlement-Offset DTO (VAC-OFFSET-01, D-28-06b)
 //
@@ -2341,6 +2816,12 @@ pub struct PdfExportConfigTO {
     pub target_folder: Option<Arc<str>>,
     pub weeks_horizon: u32,
     pub cron_schedule: Arc<str>,
+    /// Reine Planungs-Shiftplans mit exportieren (Default `true`).
+    #[serde(default = "default_true")]
+    pub include_planning_plans: bool,
+    /// Eine Datei pro Woche mit einer Seite je Shiftplan (Default `false`).
+    #[serde(default)]
+    pub combined_pdf: bool,
     pub last_success_at: Option<PrimitiveDateTime>,
     pub last_error_at: Option<PrimitiveDateTime>,
     pub last_error_message: Option<Arc<str>>,
@@ -2360,6 +2841,8 @@ impl From<&service::pdf_export_config::PdfExportConfig> for PdfExportConfigTO {
             target_folder: cfg.target_folder.clone(),
             weeks_horizon: cfg.weeks_horizon,
             cron_schedule: cfg.cron_schedule.clone(),
+            include_planning_plans: cfg.include_planning_plans,
+            combined_pdf: cfg.combined_pdf,
             last_success_at: cfg.last_success_at,
             last_error_at: cfg.last_error_at,
             last_error_message: cfg.last_error_message.clone(),
@@ -2367,116 +2850,1693 @@ impl From<&service::pdf_export_config::PdfExportConfig> for PdfExportConfigTO {
     }
 }
 
-// =====================
-// User Invitation API (migrated from rest/src/user_invitation.rs in v1.2 Phase 6)
-// =====================
+/// Export-Einstellung eines Shiftplans für `GET`/`PUT
+/// /pdf-export-config/shiftplan`. Shiftplans ohne Eintrag werden exportiert.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+pub struct PdfExportShiftplanTO {
+    pub shiftplan_id: Uuid,
+    #[serde(default = "default_true")]
+    pub included: bool,
+    /// Unterordner relativ zum Zielordner, z. B. `laden`.
+    #[serde(default)]
+    pub subfolder: Option<Arc<str>>,
+    /// Präfix vor dem Wochen-Dateinamen, z. B. `laden` →
+    /// `laden-schichtplan-2026-KW27.pdf`.
+    #[serde(default)]
+    pub filename_prefix: Option<Arc<str>>,
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
-#[serde(rename_all = "lowercase")]
-pub enum InvitationStatus {
-    /// Invitation is valid and can be used
-    Valid,
-    /// Invitation has expired and cannot be used
-    Expired,
-    /// Invitation has already been redeemed
-    Redeemed,
-    /// Invitation session has been revoked
-    #[serde(rename = "sessionrevoked")]
-    SessionRevoked,
+#[cfg(feature = "service-impl")]
+impl From<&service::pdf_export_config::PdfExportShiftplanSetting> for PdfExportShiftplanTO {
+    fn from(setting: &service::pdf_export_config::PdfExportShiftplanSetting) -> Self {
+        Self {
+            shiftplan_id: setting.shiftplan_id,
+            included: setting.included,
+            subfolder: setting.subfolder.clone(),
+            filename_prefix: setting.filename_prefix.clone(),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct GenerateInvitationRequest {
-    /// Username of the user to invite
-    pub username: String,
-    /// Expiration time in hours (default: 168 hours = 7 days)
-    pub expiration_hours: Option<i64>,
+#[cfg(feature = "service-impl")]
+impl From<&PdfExportShiftplanTO> for service::pdf_export_config::PdfExportShiftplanSetting {
+    fn from(to: &PdfExportShiftplanTO) -> Self {
+        Self {
+            shiftplan_id: to.shiftplan_id,
+            included: to.included,
+            subfolder: to.subfolder.clone(),
+            filename_prefix: to.filename_prefix.clone(),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
-pub struct InvitationResponse {
-    /// Unique ID of the invitation
-    pub id: Uuid,
-    /// Username of the invited user
-    pub username: String,
-    /// Invitation token (UUID)
-    pub token: Uuid,
-    /// Complete invitation link URL
-    pub invitation_link: String,
-    /// When the invitation was redeemed (null if not yet redeemed; RFC3339 string for WASM-compat)
-    pub redeemed_at: Option<String>,
-    /// Current status of the invitation
-    pub status: InvitationStatus,
+// ─────────────────────────────────────────────────────────────────────────
+// Export-Ziele (`/export-target`).
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ExportTargetKindTO {
+    #[serde(rename = "webdav")]
+    WebDav,
+    #[serde(rename = "local-directory")]
+    LocalDirectory,
+    #[serde(rename = "drop-directory")]
+    DropDirectory,
 }
 
 #[cfg(feature = "service-impl")]
-impl From<service::user_invitation::InvitationStatus> for InvitationStatus {
-    fn from(status: service::user_invitation::InvitationStatus) -> Self {
-        match status {
-            service::user_invitation::InvitationStatus::Valid => Self::Valid,
-            service::user_invitation::InvitationStatus::Expired => Self::Expired,
-            service::user_invitation::InvitationStatus::Redeemed => Self::Redeemed,
-            service::user_invitation::InvitationStatus::SessionRevoked => Self::SessionRevoked,
+impl From<service::export_target::ExportTargetKind> for ExportTargetKindTO {
+    fn from(kind: service::export_target::ExportTargetKind) -> Self {
+        match kind {
+            service::export_target::ExportTargetKind::WebDav => Self::WebDav,
+            service::export_target::ExportTargetKind::LocalDirectory => Self::LocalDirectory,
+            service::export_target::ExportTargetKind::DropDirectory => Self::DropDirectory,
         }
     }
 }
 
-/// Body shape for the HTTP-403 returned when a deprecated ExtraHoursCategory
+#[cfg(feature = "service-impl")]
+impl From<ExportTargetKindTO> for service::export_target::ExportTargetKind {
+    fn from(kind: ExportTargetKindTO) -> Self {
+        match kind {
+            ExportTargetKindTO::WebDav => Self::WebDav,
+            ExportTargetKindTO::LocalDirectory => Self::LocalDirectory,
+            ExportTargetKindTO::DropDirectory => Self::DropDirectory,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ExportArtifactTypeTO {
+    #[serde(rename = "shiftplan-week-pdf")]
+    ShiftplanWeekPdf,
+    #[serde(rename = "billing-period-csv")]
+    BillingPeriodCsv,
+    #[serde(rename = "ical-snapshot")]
+    IcalSnapshot,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<service::export_target::ExportArtifactType> for ExportArtifactTypeTO {
+    fn from(artifact_type: service::export_target::ExportArtifactType) -> Self {
+        match artifact_type {
+            service::export_target::ExportArtifactType::ShiftplanWeekPdf => Self::ShiftplanWeekPdf,
+            service::export_target::ExportArtifactType::BillingPeriodCsv => Self::BillingPeriodCsv,
+            service::export_target::ExportArtifactType::IcalSnapshot => Self::IcalSnapshot,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<ExportArtifactTypeTO> for service::export_target::ExportArtifactType {
+    fn from(artifact_type: ExportArtifactTypeTO) -> Self {
+        match artifact_type {
+            ExportArtifactTypeTO::ShiftplanWeekPdf => Self::ShiftplanWeekPdf,
+            ExportArtifactTypeTO::BillingPeriodCsv => Self::BillingPeriodCsv,
+            ExportArtifactTypeTO::IcalSnapshot => Self::IcalSnapshot,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ExportRunStateTO {
+    Success,
+    Partial,
+    Failed,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<service::export_target::ExportRunState> for ExportRunStateTO {
+    fn from(state: service::export_target::ExportRunState) -> Self {
+        match state {
+            service::export_target::ExportRunState::Success => Self::Success,
+            service::export_target::ExportRunState::Partial => Self::Partial,
+            service::export_target::ExportRunState::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Benanntes Export-Ziel. `secret` (WebDAV-App-Token) ist in Antworten immer
+/// `None`; im PUT behält `None` den gespeicherten Token. Ein leeres
+/// `filename_pattern` nimmt das Standard-Muster des Artefakts.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExportTargetTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub name: Arc<str>,
+    pub kind: ExportTargetKindTO,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub url: Option<Arc<str>>,
+    #[serde(default)]
+    pub username: Option<Arc<str>>,
+    #[serde(default)]
+    pub secret: Option<Arc<str>>,
+    /// WebDAV: Ordner unterhalb der URL. Verzeichnis-Ziele: absoluter Pfad.
+    pub path: Arc<str>,
+    /// 6-Feld-Cron-Ausdruck (`sec min hour dom mon dow`), lokale Zeit.
+    pub cron_schedule: Arc<str>,
+    pub artifact_type: ExportArtifactTypeTO,
+    #[serde(default)]
+    pub filename_pattern: Arc<str>,
+    /// Nur beim Wochen-PDF: Anzahl Wochen ab der laufenden (1–26).
+    #[serde(default = "default_weeks_horizon")]
+    pub weeks_horizon: u32,
+    #[serde(default)]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub deleted: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+fn default_weeks_horizon() -> u32 {
+    4
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::export_target::ExportTarget> for ExportTargetTO {
+    fn from(target: &service::export_target::ExportTarget) -> Self {
+        Self {
+            id: target.id,
+            name: target.name.clone(),
+            kind: target.kind.into(),
+            enabled: target.enabled,
+            url: target.url.clone(),
+            username: target.username.clone(),
+            // Wie beim PDF-Export-Config: der Token verlässt den Server nie.
+            secret: None,
+            path: target.path.clone(),
+            cron_schedule: target.cron_schedule.clone(),
+            artifact_type: target.artifact_type.into(),
+            filename_pattern: target.filename_pattern.clone(),
+            weeks_horizon: target.weeks_horizon,
+            created: target.created,
+            deleted: target.deleted,
+            version: target.version,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&ExportTargetTO> for service::export_target::ExportTarget {
+    fn from(target: &ExportTargetTO) -> Self {
+        Self {
+            id: target.id,
+            name: target.name.clone(),
+            kind: target.kind.into(),
+            enabled: target.enabled,
+            url: target.url.clone(),
+            username: target.username.clone(),
+            secret: target.secret.clone(),
+            path: target.path.clone(),
+            cron_schedule: target.cron_schedule.clone(),
+            artifact_type: target.artifact_type.into(),
+            filename_pattern: target.filename_pattern.clone(),
+            weeks_horizon: target.weeks_horizon,
+            created: target.created,
+            deleted: target.deleted,
+            version: target.version,
+        }
+    }
+}
+
+/// Ein Lauf eines Export-Ziels.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExportTargetRunTO {
+    pub id: Uuid,
+    pub target_id: Uuid,
+    pub started: PrimitiveDateTime,
+    pub duration_ms: u64,
+    pub state: ExportRunStateTO,
+    pub files_written: u32,
+    pub files_skipped: u32,
+    /// Fehler und übersprungene Dateien, eine Zeile pro Eintrag.
+    pub message: Option<Arc<str>>,
+    #[serde(rename = "$version")]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::export_target::ExportTargetRun> for ExportTargetRunTO {
+    fn from(run: &service::export_target::ExportTargetRun) -> Self {
+        Self {
+            id: run.id,
+            target_id: run.target_id,
+            started: run.started,
+            duration_ms: run.duration_ms,
+            state: run.state.into(),
+            files_written: run.files_written,
+            files_skipped: run.files_skipped,
+            message: run.message.clone(),
+            version: run.version,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Scheduler-Job-Registry DTO
+//
+// `GET /scheduler-job` liefert alle Jobs inkl. Status des letzten Laufs.
+// Im `PUT /scheduler-job/{name}`-Body zählen nur `cron_schedule`, `enabled`
+// und `$version`; Name kommt aus dem Pfad, die Status-Felder pflegt der
+// Scheduler.
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+pub struct SchedulerJobTO {
+    #[serde(default)]
+    pub name: Arc<str>,
+    /// 6-Feld-Cron-Ausdruck (`sec min hour dom mon dow`), lokale Zeit.
+    pub cron_schedule: Arc<str>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub last_duration_ms: Option<u64>,
+    #[serde(default)]
+    pub last_error_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub last_error_message: Option<Arc<str>>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::scheduler_job::SchedulerJob> for SchedulerJobTO {
+    fn from(job: &service::scheduler_job::SchedulerJob) -> Self {
+        Self {
+            name: job.name.clone(),
+            cron_schedule: job.cron_schedule.clone(),
+            enabled: job.enabled,
+            last_run_at: job.last_run_at,
+            last_duration_ms: job.last_duration_ms,
+            last_error_at: job.last_error_at,
+            last_error_message: job.last_error_message.clone(),
+            version: job.version,
+        }
+    }
+}
+
+// =====================
+// User Invitation API (migrated from rest/src/user_invitation.rs in v1.2 Phase 6)
+// =====================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum InvitationStatus {
+    /// Invitation is valid and can be used
+    Valid,
+    /// Invitation has expired and cannot be used
+    Expired,
+    /// Invitation has already been redeemed
+    Redeemed,
+    /// Invitation session has been revoked
+    #[serde(rename = "sessionrevoked")]
+    SessionRevoked,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GenerateInvitationRequest {
+    /// Username of the user to invite
+    pub username: String,
+    /// E-mail address the invitation is sent to if the user has no
+    /// notification preferences yet
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Expiration time in hours (default: 168 hours = 7 days)
+    pub expiration_hours: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct InvitationResponse {
+    /// Unique ID of the invitation
+    pub id: Uuid,
+    /// Username of the invited user
+    pub username: String,
+    /// Invitation token (UUID)
+    pub token: Uuid,
+    /// Complete invitation link URL
+    pub invitation_link: String,
+    /// When the invitation was redeemed (null if not yet redeemed; RFC3339 string for WASM-compat)
+    pub redeemed_at: Option<String>,
+    /// Current status of the invitation
+    pub status: InvitationStatus,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<service::user_invitation::InvitationStatus> for InvitationStatus {
+    fn from(status: service::user_invitation::InvitationStatus) -> Self {
+        match status {
+            service::user_invitation::InvitationStatus::Valid => Self::Valid,
+            service::user_invitation::InvitationStatus::Expired => Self::Expired,
+            service::user_invitation::InvitationStatus::Redeemed => Self::Redeemed,
+            service::user_invitation::InvitationStatus::SessionRevoked => Self::SessionRevoked,
+        }
+    }
+}
+
+/// Body shape for the HTTP-403 returned when a deprecated ExtraHoursCategory
 /// is POST'd after the cutover flag is on (D-Phase4-09).
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
-pub struct ExtraHoursCategoryDeprecatedErrorTO {
-    /// Always `"extra_hours_category_deprecated"`.
-    pub error: String,
-    /// Lowercase variant name (e.g. `"vacation"`).
-    pub category: String,
-    /// User-facing migration hint.
-    pub message: String,
+pub struct ExtraHoursCategoryDeprecatedErrorTO {
+    /// Always `"extra_hours_category_deprecated"`.
+    pub error: String,
+    /// Lowercase variant name (e.g. `"vacation"`).
+    pub category: String,
+    /// User-facing migration hint.
+    pub message: String,
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Phase 8 Plan 08-07 Gap-Closure (Task 2) — Feature-Flag REST-DTO
+//
+// Read-only DTO für `GET /feature-flag/{key}` (auth-only). Liefert den
+// aktuellen Wert eines Feature-Flags zurück; unbekannte Keys liefern
+// `enabled: false` (fail-safe, kein 404). Das Frontend nutzt das, um den
+// Cutover-Zustand (`absence_range_source_active`) bei App-Start zu kennen.
+//
+// `From`-Impl ist hinter `feature = "service-impl"` gegated, damit der
+// WASM-/Frontend-Build den `service`-Crate nicht ziehen muss (analog
+// `VacationBalanceTO`).
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "service-impl", derive(PartialEq))]
+pub struct FeatureFlagTO {
+    /// Flag-Key (z.B. `"absence_range_source_active"`).
+    pub key: String,
+    /// Aktueller Wert des Flags. `false` bei unbekanntem Key (fail-safe).
+    pub enabled: bool,
+    /// Optionale Beschreibung aus der `feature_flag.description`-Spalte.
+    pub description: Option<String>,
+}
+
+#[cfg(feature = "service-impl")]
+impl FeatureFlagTO {
+    /// Konstruktor für den fail-safe-"unbekannter Key"-Pfad. Liefert
+    /// `enabled: false` und `description: None`.
+    pub fn unknown(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            enabled: false,
+            description: None,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::feature_flag::FeatureFlag> for FeatureFlagTO {
+    fn from(flag: &service::feature_flag::FeatureFlag) -> Self {
+        Self {
+            key: flag.key.as_ref().to_string(),
+            enabled: flag.enabled,
+            description: flag.description.as_deref().map(str::to_string),
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Schichttausch-/Abgabe-Börse (`/shift-swap`).
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ShiftSwapStateTO {
+    Open,
+    Accepted,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::shift_swap::ShiftSwapState> for ShiftSwapStateTO {
+    fn from(state: &service::shift_swap::ShiftSwapState) -> Self {
+        match state {
+            service::shift_swap::ShiftSwapState::Open => Self::Open,
+            service::shift_swap::ShiftSwapState::Accepted => Self::Accepted,
+            service::shift_swap::ShiftSwapState::Approved => Self::Approved,
+            service::shift_swap::ShiftSwapState::Rejected => Self::Rejected,
+            service::shift_swap::ShiftSwapState::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftSwapRequestTO {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub offering_sales_person_id: Uuid,
+    pub accepting_sales_person_id: Option<Uuid>,
+    pub new_booking_id: Option<Uuid>,
+    pub year: u32,
+    pub calendar_week: u8,
+    pub state: ShiftSwapStateTO,
+    pub message: Arc<str>,
+    pub reject_reason: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub accepted: Option<PrimitiveDateTime>,
+    pub decided: Option<PrimitiveDateTime>,
+    pub decided_by: Option<Arc<str>>,
+    #[serde(rename = "$version")]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::shift_swap::ShiftSwapRequest> for ShiftSwapRequestTO {
+    fn from(request: &service::shift_swap::ShiftSwapRequest) -> Self {
+        Self {
+            id: request.id,
+            booking_id: request.booking_id,
+            offering_sales_person_id: request.offering_sales_person_id,
+            accepting_sales_person_id: request.accepting_sales_person_id,
+            new_booking_id: request.new_booking_id,
+            year: request.year,
+            calendar_week: request.calendar_week,
+            state: (&request.state).into(),
+            message: request.message.clone(),
+            reject_reason: request.reject_reason.clone(),
+            created: request.created,
+            accepted: request.accepted,
+            decided: request.decided,
+            decided_by: request.decided_by.clone(),
+            version: request.version,
+        }
+    }
+}
+
+/// Body für `POST /shift-swap`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftSwapOfferTO {
+    pub booking_id: Uuid,
+    #[serde(default)]
+    pub message: Arc<str>,
+}
+
+/// Body für `POST /shift-swap/{id}/accept`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftSwapAcceptTO {
+    pub sales_person_id: Uuid,
+}
+
+/// Body für `POST /shift-swap/{id}/reject`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftSwapRejectTO {
+    #[serde(default)]
+    pub reason: Arc<str>,
+}
+
+/// Antwort von `POST /shift-swap/{id}/approve`: abgeschlossene Anfrage,
+/// neues Booking und Cross-Source-Warnings des Buchungspfads.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftSwapApproveResultTO {
+    pub request: ShiftSwapRequestTO,
+    pub booking: BookingTO,
+    pub warnings: Vec<WarningTO>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::shift_swap::ShiftSwapApproveResult> for ShiftSwapApproveResultTO {
+    fn from(result: &service::shift_swap::ShiftSwapApproveResult) -> Self {
+        Self {
+            request: ShiftSwapRequestTO::from(&result.request),
+            booking: BookingTO::from(&result.booking),
+            warnings: result.warnings.iter().map(WarningTO::from).collect(),
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// E-Mail-Benachrichtigungen (`/notification`).
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum NotificationKindTO {
+    BookingDeleted,
+    WeekPlanned,
+    AbsenceCreated,
+    InvitationCreated,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::notification::NotificationKind> for NotificationKindTO {
+    fn from(kind: &service::notification::NotificationKind) -> Self {
+        match kind {
+            service::notification::NotificationKind::BookingDeleted => Self::BookingDeleted,
+            service::notification::NotificationKind::WeekPlanned => Self::WeekPlanned,
+            service::notification::NotificationKind::AbsenceCreated => Self::AbsenceCreated,
+            service::notification::NotificationKind::InvitationCreated => Self::InvitationCreated,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum NotificationStateTO {
+    Pending,
+    Sent,
+    Failed,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::notification::NotificationState> for NotificationStateTO {
+    fn from(state: &service::notification::NotificationState) -> Self {
+        match state {
+            service::notification::NotificationState::Pending => Self::Pending,
+            service::notification::NotificationState::Sent => Self::Sent,
+            service::notification::NotificationState::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Benachrichtigungs-Einstellungen eines Benutzers. Bei
+/// `PUT /notification/preference` wird `user_id` ignoriert und durch den
+/// angemeldeten Benutzer ersetzt.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceTO {
+    #[serde(default)]
+    pub user_id: Arc<str>,
+    pub email: Arc<str>,
+    #[serde(default)]
+    pub booking_deleted: bool,
+    #[serde(default)]
+    pub week_planned: bool,
+    #[serde(default)]
+    pub absence_created: bool,
+    #[serde(default)]
+    pub invitation_created: bool,
+    #[serde(default)]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::notification::NotificationPreference> for NotificationPreferenceTO {
+    fn from(preference: &service::notification::NotificationPreference) -> Self {
+        Self {
+            user_id: preference.user_id.clone(),
+            email: preference.email.clone(),
+            booking_deleted: preference.booking_deleted,
+            week_planned: preference.week_planned,
+            absence_created: preference.absence_created,
+            invitation_created: preference.invitation_created,
+            created: preference.created,
+            version: preference.version,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&NotificationPreferenceTO> for service::notification::NotificationPreference {
+    fn from(preference: &NotificationPreferenceTO) -> Self {
+        Self {
+            user_id: preference.user_id.clone(),
+            email: preference.email.clone(),
+            booking_deleted: preference.booking_deleted,
+            week_planned: preference.week_planned,
+            absence_created: preference.absence_created,
+            invitation_created: preference.invitation_created,
+            created: preference.created,
+            version: preference.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationMessageTO {
+    pub id: Uuid,
+    pub recipient_user_id: Arc<str>,
+    pub recipient_email: Arc<str>,
+    pub kind: NotificationKindTO,
+    pub subject: Arc<str>,
+    pub body: Arc<str>,
+    pub state: NotificationStateTO,
+    pub attempts: u32,
+    pub next_attempt: PrimitiveDateTime,
+    pub last_error: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub sent: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::notification::NotificationMessage> for NotificationMessageTO {
+    fn from(message: &service::notification::NotificationMessage) -> Self {
+        Self {
+            id: message.id,
+            recipient_user_id: message.recipient_user_id.clone(),
+            recipient_email: message.recipient_email.clone(),
+            kind: (&message.kind).into(),
+            subject: message.subject.clone(),
+            body: message.body.clone(),
+            state: (&message.state).into(),
+            attempts: message.attempts,
+            next_attempt: message.next_attempt,
+            last_error: message.last_error.clone(),
+            created: message.created,
+            sent: message.sent,
+            version: message.version,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Ausgehende Webhooks (`/webhook`).
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Ereignistyp, identisch mit dem `event`-Feld im Payload und dem Header
+/// `X-Shifty-Event`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum WebhookEventTypeTO {
+    #[serde(rename = "booking.created")]
+    BookingCreated,
+    #[serde(rename = "booking.deleted")]
+    BookingDeleted,
+    #[serde(rename = "absence_period.created")]
+    AbsencePeriodCreated,
+    #[serde(rename = "absence_period.updated")]
+    AbsencePeriodUpdated,
+    #[serde(rename = "billing_period.created")]
+    BillingPeriodCreated,
+    #[serde(rename = "week_status.changed")]
+    WeekStatusChanged,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::webhook::WebhookEventType> for WebhookEventTypeTO {
+    fn from(event_type: &service::webhook::WebhookEventType) -> Self {
+        match event_type {
+            service::webhook::WebhookEventType::BookingCreated => Self::BookingCreated,
+            service::webhook::WebhookEventType::BookingDeleted => Self::BookingDeleted,
+            service::webhook::WebhookEventType::AbsencePeriodCreated => Self::AbsencePeriodCreated,
+            service::webhook::WebhookEventType::AbsencePeriodUpdated => Self::AbsencePeriodUpdated,
+            service::webhook::WebhookEventType::BillingPeriodCreated => Self::BillingPeriodCreated,
+            service::webhook::WebhookEventType::WeekStatusChanged => Self::WeekStatusChanged,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&WebhookEventTypeTO> for service::webhook::WebhookEventType {
+    fn from(event_type: &WebhookEventTypeTO) -> Self {
+        match event_type {
+            WebhookEventTypeTO::BookingCreated => Self::BookingCreated,
+            WebhookEventTypeTO::BookingDeleted => Self::BookingDeleted,
+            WebhookEventTypeTO::AbsencePeriodCreated => Self::AbsencePeriodCreated,
+            WebhookEventTypeTO::AbsencePeriodUpdated => Self::AbsencePeriodUpdated,
+            WebhookEventTypeTO::BillingPeriodCreated => Self::BillingPeriodCreated,
+            WebhookEventTypeTO::WeekStatusChanged => Self::WeekStatusChanged,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum WebhookDeliveryStateTO {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::webhook::WebhookDeliveryState> for WebhookDeliveryStateTO {
+    fn from(state: &service::webhook::WebhookDeliveryState) -> Self {
+        match state {
+            service::webhook::WebhookDeliveryState::Pending => Self::Pending,
+            service::webhook::WebhookDeliveryState::Delivered => Self::Delivered,
+            service::webhook::WebhookDeliveryState::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Webhook-Abonnement. Ohne `secret` erzeugt der Server beim Anlegen einen
+/// Schlüssel; beim Aktualisieren behält ein leeres `secret` den bisherigen.
+/// In Antworten ist `secret` nur beim Anlegen gesetzt, sonst immer `None`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub url: Arc<str>,
+    #[serde(default)]
+    pub secret: Option<Arc<str>>,
+    #[serde(default)]
+    pub description: Arc<str>,
+    pub event_types: Arc<[WebhookEventTypeTO]>,
+    #[serde(default = "default_true")]
+    pub active: bool,
+    #[serde(default)]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub deleted: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::webhook::WebhookSubscription> for WebhookSubscriptionTO {
+    fn from(subscription: &service::webhook::WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url.clone(),
+            // Der HMAC-Schlüssel geht nur in der Antwort auf das Anlegen raus.
+            secret: None,
+            description: subscription.description.clone(),
+            event_types: subscription
+                .event_types
+                .iter()
+                .map(WebhookEventTypeTO::from)
+                .collect(),
+            active: subscription.active,
+            created: subscription.created,
+            deleted: subscription.deleted,
+            version: subscription.version,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&WebhookSubscriptionTO> for service::webhook::WebhookSubscription {
+    fn from(subscription: &WebhookSubscriptionTO) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url.clone(),
+            secret: subscription.secret.clone().unwrap_or_default(),
+            description: subscription.description.clone(),
+            event_types: subscription
+                .event_types
+                .iter()
+                .map(service::webhook::WebhookEventType::from)
+                .collect(),
+            active: subscription.active,
+            created: subscription.created,
+            deleted: subscription.deleted,
+            version: subscription.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryTO {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_type: WebhookEventTypeTO,
+    /// Der signierte JSON-Body als Text.
+    pub payload: Arc<str>,
+    pub state: WebhookDeliveryStateTO,
+    pub attempts: u32,
+    pub next_attempt: PrimitiveDateTime,
+    pub last_status: Option<u16>,
+    pub last_error: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub delivered: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::webhook::WebhookDelivery> for WebhookDeliveryTO {
+    fn from(delivery: &service::webhook::WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            subscription_id: delivery.subscription_id,
+            event_type: (&delivery.event_type).into(),
+            payload: delivery.payload.clone(),
+            state: (&delivery.state).into(),
+            attempts: delivery.attempts,
+            next_attempt: delivery.next_attempt,
+            last_status: delivery.last_status,
+            last_error: delivery.last_error.clone(),
+            created: delivery.created,
+            delivered: delivery.delivered,
+            version: delivery.version,
+        }
+    }
+}
+
+/// Herkunft eines Zeiterfassungs-Eintrags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum TimeEntrySourceTO {
+    Clock,
+    Manual,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::time_entry::TimeEntrySource> for TimeEntrySourceTO {
+    fn from(source: service::time_entry::TimeEntrySource) -> Self {
+        match source {
+            service::time_entry::TimeEntrySource::Clock => Self::Clock,
+            service::time_entry::TimeEntrySource::Manual => Self::Manual,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<TimeEntrySourceTO> for service::time_entry::TimeEntrySource {
+    fn from(source: TimeEntrySourceTO) -> Self {
+        match source {
+            TimeEntrySourceTO::Clock => Self::Clock,
+            TimeEntrySourceTO::Manual => Self::Manual,
+        }
+    }
+}
+
+/// Tatsächlich geleistete Arbeitszeit. Ohne `end` ist der Mitarbeiter
+/// eingestempelt. Zeiten sind Ortszeit.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    #[serde(default)]
+    pub booking_id: Option<Uuid>,
+    pub start: PrimitiveDateTime,
+    #[serde(default)]
+    pub end: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub description: Arc<str>,
+    /// Wird vom Server gesetzt.
+    #[serde(default = "time_entry_source_manual")]
+    pub source: TimeEntrySourceTO,
+    /// Dauer in Stunden, 0 solange offen.
+    #[serde(default)]
+    pub hours: f32,
+    #[serde(default)]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub created_by: Option<Arc<str>>,
+    #[serde(default)]
+    pub deleted: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+fn time_entry_source_manual() -> TimeEntrySourceTO {
+    TimeEntrySourceTO::Manual
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::time_entry::TimeEntry> for TimeEntryTO {
+    fn from(entry: &service::time_entry::TimeEntry) -> Self {
+        Self {
+            id: entry.id,
+            sales_person_id: entry.sales_person_id,
+            booking_id: entry.booking_id,
+            start: entry.start,
+            end: entry.end,
+            description: entry.description.clone(),
+            source: entry.source.into(),
+            hours: entry.hours(),
+            created: entry.created,
+            created_by: entry.created_by.clone(),
+            deleted: entry.deleted,
+            version: entry.version,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<&TimeEntryTO> for service::time_entry::TimeEntry {
+    fn from(entry: &TimeEntryTO) -> Self {
+        Self {
+            id: entry.id,
+            sales_person_id: entry.sales_person_id,
+            booking_id: entry.booking_id,
+            start: entry.start,
+            end: entry.end,
+            description: entry.description.clone(),
+            source: entry.source.into(),
+            created: entry.created,
+            created_by: entry.created_by.clone(),
+            deleted: entry.deleted,
+            version: entry.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct ClockInTO {
+    /// Optional die geplante Buchung, zu der gestempelt wird.
+    #[serde(default)]
+    pub booking_id: Option<Uuid>,
+}
+
+/// Soll/Ist-Vergleich eines Tages.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimeReconciliationDayTO {
+    pub date: time::Date,
+    pub planned_hours: f32,
+    pub actual_hours: f32,
+    /// `actual_hours - planned_hours`.
+    pub difference: f32,
+    pub has_open_entry: bool,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::time_entry::ReconciliationDay> for TimeReconciliationDayTO {
+    fn from(day: &service::time_entry::ReconciliationDay) -> Self {
+        Self {
+            date: day.date,
+            planned_hours: day.planned_hours,
+            actual_hours: day.actual_hours,
+            difference: day.difference(),
+            has_open_entry: day.has_open_entry,
+        }
+    }
+}
+
+/// Dateiformat der Tabellen-Exporte (`?format=csv|xlsx`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormatTO {
+    #[default]
+    Csv,
+    Xlsx,
+}
+#[cfg(feature = "service-impl")]
+impl From<ExportFormatTO> for service::report_export::ExportFormat {
+    fn from(format: ExportFormatTO) -> Self {
+        match format {
+            ExportFormatTO::Csv => Self::Csv,
+            ExportFormatTO::Xlsx => Self::Xlsx,
+        }
+    }
+}
+
+/// Sprache der Spaltenüberschriften (`?lang=de|en|cs`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportLanguageTO {
+    #[default]
+    De,
+    En,
+    Cs,
+}
+#[cfg(feature = "service-impl")]
+impl From<ExportLanguageTO> for service::report_export::ExportLanguage {
+    fn from(language: ExportLanguageTO) -> Self {
+        match language {
+            ExportLanguageTO::De => Self::De,
+            ExportLanguageTO::En => Self::En,
+            ExportLanguageTO::Cs => Self::Cs,
+        }
+    }
+}
+
+/// Zeilen des Mitarbeiter-Exports (`?breakdown=week|month`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportBreakdownTO {
+    #[default]
+    Week,
+    Month,
+}
+#[cfg(feature = "service-impl")]
+impl From<ReportBreakdownTO> for service::report_export::ReportBreakdown {
+    fn from(breakdown: ReportBreakdownTO) -> Self {
+        match breakdown {
+            ReportBreakdownTO::Week => Self::ByWeek,
+            ReportBreakdownTO::Month => Self::ByMonth,
+        }
+    }
+}
+
+/// Sicht auf den Wert eines Abrechnungszeitraums, die als Lohnart exportiert
+/// wird.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayrollValueViewTO {
+    Delta,
+    YtdFrom,
+    YtdTo,
+    FullYear,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::payroll_wage_type::PayrollValueView> for PayrollValueViewTO {
+    fn from(value_view: service::payroll_wage_type::PayrollValueView) -> Self {
+        match value_view {
+            service::payroll_wage_type::PayrollValueView::Delta => Self::Delta,
+            service::payroll_wage_type::PayrollValueView::YtdFrom => Self::YtdFrom,
+            service::payroll_wage_type::PayrollValueView::YtdTo => Self::YtdTo,
+            service::payroll_wage_type::PayrollValueView::FullYear => Self::FullYear,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<PayrollValueViewTO> for service::payroll_wage_type::PayrollValueView {
+    fn from(value_view: PayrollValueViewTO) -> Self {
+        match value_view {
+            PayrollValueViewTO::Delta => Self::Delta,
+            PayrollValueViewTO::YtdFrom => Self::YtdFrom,
+            PayrollValueViewTO::YtdTo => Self::YtdTo,
+            PayrollValueViewTO::FullYear => Self::FullYear,
+        }
+    }
+}
+
+/// Lohnarten-Zuordnung für den DATEV-Export. `value_type` ist der Schlüssel
+/// aus `BillingPeriodSalesPersonTO::values`, z. B. `extra_work` oder
+/// `custom_extra_hours:<name>`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct PayrollWageTypeTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub value_type: Arc<str>,
+    pub value_view: PayrollValueViewTO,
+    pub wage_type: Arc<str>,
+    pub processing_key: u8,
+    #[serde(default)]
+    pub description: Arc<str>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub deleted: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::payroll_wage_type::PayrollWageType> for PayrollWageTypeTO {
+    fn from(wage_type: &service::payroll_wage_type::PayrollWageType) -> Self {
+        Self {
+            id: wage_type.id,
+            value_type: wage_type.value_type.as_str(),
+            value_view: wage_type.value_view.into(),
+            wage_type: wage_type.wage_type.clone(),
+            processing_key: wage_type.processing_key,
+            description: wage_type.description.clone(),
+            created: wage_type.created,
+            deleted: wage_type.deleted,
+            version: wage_type.version,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl TryFrom<&PayrollWageTypeTO> for service::payroll_wage_type::PayrollWageType {
+    type Error = service::billing_period::BillingPeriodValueTypeParseError;
+
+    fn try_from(wage_type: &PayrollWageTypeTO) -> Result<Self, Self::Error> {
+        use std::str::FromStr;
+        Ok(Self {
+            id: wage_type.id,
+            value_type: service::billing_period::BillingPeriodValueType::from_str(
+                &wage_type.value_type,
+            )?,
+            value_view: wage_type.value_view.into(),
+            wage_type: wage_type.wage_type.clone(),
+            processing_key: wage_type.processing_key,
+            description: wage_type.description.clone(),
+            created: wage_type.created,
+            deleted: wage_type.deleted,
+            version: wage_type.version,
+        })
+    }
+}
+
+/// Art einer protokollierten Änderung.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum AuditActionTO {
+    Create,
+    Update,
+    Delete,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::audit_log::AuditAction> for AuditActionTO {
+    fn from(action: service::audit_log::AuditAction) -> Self {
+        match action {
+            service::audit_log::AuditAction::Create => Self::Create,
+            service::audit_log::AuditAction::Update => Self::Update,
+            service::audit_log::AuditAction::Delete => Self::Delete,
+        }
+    }
+}
+
+/// Ein geändertes Feld; die Werte sind JSON-kodiert.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditFieldChangeTO {
+    pub field: Arc<str>,
+    pub before: Option<Arc<str>>,
+    pub after: Option<Arc<str>>,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::audit_log::AuditFieldChange> for AuditFieldChangeTO {
+    fn from(change: &service::audit_log::AuditFieldChange) -> Self {
+        Self {
+            field: change.field.clone(),
+            before: change.before.clone(),
+            after: change.after.clone(),
+        }
+    }
+}
+
+/// Eintrag im Änderungsprotokoll. `before`/`after` sind die vollständigen
+/// JSON-Schnappschüsse, `changes` die feldweise Differenz.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogEntryTO {
+    pub id: Uuid,
+    pub entity_type: Arc<str>,
+    pub entity_id: Arc<str>,
+    pub action: AuditActionTO,
+    /// `null` bei Änderungen ohne Benutzer (Scheduler, Systemaufrufe).
+    pub user_id: Option<Arc<str>>,
+    /// Admin, der per Impersonation als `user_id` gehandelt hat.
+    pub real_user_id: Option<Arc<str>>,
+    pub process: Arc<str>,
+    pub before: Option<Arc<str>>,
+    pub after: Option<Arc<str>>,
+    pub changes: Arc<[AuditFieldChangeTO]>,
+    #[schema(value_type = String, format = "date-time")]
+    pub created: PrimitiveDateTime,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::audit_log::AuditLogEntry> for AuditLogEntryTO {
+    fn from(entry: &service::audit_log::AuditLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            entity_type: entry.entity_type.clone(),
+            entity_id: entry.entity_id.clone(),
+            action: entry.action.into(),
+            user_id: entry.user_id.clone(),
+            real_user_id: entry.real_user_id.clone(),
+            process: entry.process.clone(),
+            before: entry.before.clone(),
+            after: entry.after.clone(),
+            changes: entry.changes.iter().map(AuditFieldChangeTO::from).collect(),
+            created: entry.created,
+        }
+    }
+}
+
+/// Herkunft eines Umbuchungs-Batches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum RebookingBatchKindTO {
+    Manual,
+    HrSuggestion,
+    AutoCron,
+    AutoCronBackfill,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::rebooking_reconciliation::RebookingBatchKind> for RebookingBatchKindTO {
+    fn from(kind: service::rebooking_reconciliation::RebookingBatchKind) -> Self {
+        use service::rebooking_reconciliation::RebookingBatchKind;
+        match kind {
+            RebookingBatchKind::Manual => Self::Manual,
+            RebookingBatchKind::HrSuggestion => Self::HrSuggestion,
+            RebookingBatchKind::AutoCron => Self::AutoCron,
+            RebookingBatchKind::AutoCronBackfill => Self::AutoCronBackfill,
+        }
+    }
+}
+
+/// Status eines Umbuchungs-Batches; nur `Pending` ist entscheidbar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum RebookingBatchStateTO {
+    Pending,
+    Approved,
+    Rejected,
+    SkippedLocked,
+}
+#[cfg(feature = "service-impl")]
+impl From<service::rebooking_reconciliation::RebookingBatchState> for RebookingBatchStateTO {
+    fn from(state: service::rebooking_reconciliation::RebookingBatchState) -> Self {
+        use service::rebooking_reconciliation::RebookingBatchState;
+        match state {
+            RebookingBatchState::Pending => Self::Pending,
+            RebookingBatchState::Approved => Self::Approved,
+            RebookingBatchState::Rejected => Self::Rejected,
+            RebookingBatchState::SkippedLocked => Self::SkippedLocked,
+        }
+    }
+}
+
+/// Eine Umbuchung innerhalb eines Batches mit den Werten zum Zeitpunkt des
+/// Vorschlags. Die `extra_hours`-Ids sind ab Genehmigung gesetzt.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct RebookingBatchEntryTO {
+    pub id: Uuid,
+    pub hours: f32,
+    pub balance_before: f32,
+    pub voluntary_actual: f32,
+    pub voluntary_committed: f32,
+    pub extra_hours_out_id: Option<Uuid>,
+    pub extra_hours_in_id: Option<Uuid>,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::rebooking_reconciliation::RebookingBatchEntry> for RebookingBatchEntryTO {
+    fn from(entry: &service::rebooking_reconciliation::RebookingBatchEntry) -> Self {
+        Self {
+            id: entry.id,
+            hours: entry.hours,
+            balance_before: entry.balance_before,
+            voluntary_actual: entry.voluntary_actual,
+            voluntary_committed: entry.voluntary_committed,
+            extra_hours_out_id: entry.extra_hours_out_id,
+            extra_hours_in_id: entry.extra_hours_in_id,
+        }
+    }
 }
 
-// ─────────────────────────────────────────────────────────────────────────
-// Phase 8 Plan 08-07 Gap-Closure (Task 2) — Feature-Flag REST-DTO
-//
-// Read-only DTO für `GET /feature-flag/{key}` (auth-only). Liefert den
-// aktuellen Wert eines Feature-Flags zurück; unbekannte Keys liefern
-// `enabled: false` (fail-safe, kein 404). Das Frontend nutzt das, um den
-// Cutover-Zustand (`absence_range_source_active`) bei App-Start zu kennen.
-//
-// `From`-Impl ist hinter `feature = "service-impl"` gegated, damit der
-// WASM-/Frontend-Build den `service`-Crate nicht ziehen muss (analog
-// `VacationBalanceTO`).
-// ─────────────────────────────────────────────────────────────────────────
+/// Umbuchungs-Batch eines Mitarbeiters für eine ISO-Woche.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct RebookingBatchTO {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub iso_year: u32,
+    pub iso_week: u8,
+    pub kind: RebookingBatchKindTO,
+    pub state: RebookingBatchStateTO,
+    /// Summe der Stunden aller Entries.
+    pub hours: f32,
+    #[schema(value_type = String, format = "date-time")]
+    pub created: PrimitiveDateTime,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub approved: Option<PrimitiveDateTime>,
+    pub approved_by: Option<Arc<str>>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub rejected: Option<PrimitiveDateTime>,
+    pub rejected_by: Option<Arc<str>>,
+    pub reject_reason: Option<Arc<str>>,
+    pub entries: Arc<[RebookingBatchEntryTO]>,
+    #[serde(rename = "$version")]
+    pub version: Uuid,
+}
+#[cfg(feature = "service-impl")]
+impl From<&service::rebooking_reconciliation::RebookingBatch> for RebookingBatchTO {
+    fn from(batch: &service::rebooking_reconciliation::RebookingBatch) -> Self {
+        Self {
+            id: batch.id,
+            sales_person_id: batch.sales_person_id,
+            iso_year: batch.iso_year,
+            iso_week: batch.iso_week,
+            kind: batch.kind.into(),
+            state: batch.state.into(),
+            hours: batch.hours(),
+            created: batch.created,
+            approved: batch.approved,
+            approved_by: batch.approved_by.clone(),
+            rejected: batch.rejected,
+            rejected_by: batch.rejected_by.clone(),
+            reject_reason: batch.reject_reason.clone(),
+            entries: batch.entries.iter().map(RebookingBatchEntryTO::from).collect(),
+            version: batch.version,
+        }
+    }
+}
 
+/// Vorschlag einer Umbuchung. Ohne `hours` berechnet der Server den Betrag
+/// aus freiwilligem Überschuss und Defizit im Stundenkonto.
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
-#[cfg_attr(feature = "service-impl", derive(PartialEq))]
-pub struct FeatureFlagTO {
-    /// Flag-Key (z.B. `"absence_range_source_active"`).
-    pub key: String,
-    /// Aktueller Wert des Flags. `false` bei unbekanntem Key (fail-safe).
-    pub enabled: bool,
-    /// Optionale Beschreibung aus der `feature_flag.description`-Spalte.
-    pub description: Option<String>,
+pub struct RebookingSuggestionTO {
+    pub sales_person_id: Uuid,
+    pub iso_year: u32,
+    pub iso_week: u8,
+    #[serde(default)]
+    pub hours: Option<f32>,
+}
+
+/// Ablehnung eines Umbuchungs-Batches; `reason` darf nicht leer sein.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct RebookingRejectTO {
+    pub reason: Arc<str>,
+}
+
+/// Eintrag im Qualifikations-Katalog, z. B. „Kasse“ oder „Stapler“.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct QualificationTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub name: Arc<str>,
+    #[serde(default)]
+    pub description: Arc<str>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub deleted: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
 }
 
 #[cfg(feature = "service-impl")]
-impl FeatureFlagTO {
-    /// Konstruktor für den fail-safe-"unbekannter Key"-Pfad. Liefert
-    /// `enabled: false` und `description: None`.
-    pub fn unknown(key: &str) -> Self {
+impl From<&service::qualification::Qualification> for QualificationTO {
+    fn from(qualification: &service::qualification::Qualification) -> Self {
         Self {
-            key: key.to_string(),
-            enabled: false,
-            description: None,
+            id: qualification.id,
+            name: qualification.name.clone(),
+            description: qualification.description.clone(),
+            created: qualification.created,
+            deleted: qualification.deleted,
+            version: qualification.version,
         }
     }
 }
 
 #[cfg(feature = "service-impl")]
-impl From<&service::feature_flag::FeatureFlag> for FeatureFlagTO {
-    fn from(flag: &service::feature_flag::FeatureFlag) -> Self {
+impl From<&QualificationTO> for service::qualification::Qualification {
+    fn from(qualification: &QualificationTO) -> Self {
         Self {
-            key: flag.key.as_ref().to_string(),
-            enabled: flag.enabled,
-            description: flag.description.as_deref().map(str::to_string),
+            id: qualification.id,
+            name: qualification.name.clone(),
+            description: qualification.description.clone(),
+            created: qualification.created,
+            deleted: qualification.deleted,
+            version: qualification.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct SalesPersonQualificationTO {
+    pub sales_person_id: Uuid,
+    pub qualification_id: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::qualification::SalesPersonQualification> for SalesPersonQualificationTO {
+    fn from(assignment: &service::qualification::SalesPersonQualification) -> Self {
+        Self {
+            sales_person_id: assignment.sales_person_id,
+            qualification_id: assignment.qualification_id,
+        }
+    }
+}
+
+/// Mindestens `min_count` der gebuchten Personen eines Slots müssen die
+/// Qualifikation haben. Beim Setzen über `PUT /qualification/slot/{id}`
+/// wird `slot_id` aus dem Pfad genommen.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct SlotQualificationRequirementTO {
+    #[serde(default)]
+    pub slot_id: Uuid,
+    pub qualification_id: Uuid,
+    pub min_count: u8,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::qualification::SlotQualificationRequirement>
+    for SlotQualificationRequirementTO
+{
+    fn from(requirement: &service::qualification::SlotQualificationRequirement) -> Self {
+        Self {
+            slot_id: requirement.slot_id,
+            qualification_id: requirement.qualification_id,
+            min_count: requirement.min_count,
+        }
+    }
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&SlotQualificationRequirementTO>
+    for service::qualification::SlotQualificationRequirement
+{
+    fn from(requirement: &SlotQualificationRequirementTO) -> Self {
+        Self {
+            slot_id: requirement.slot_id,
+            qualification_id: requirement.qualification_id,
+            min_count: requirement.min_count,
+        }
+    }
+}
+
+/// Slot einer Woche, dessen Buchungen eine Qualifikations-Anforderung nicht
+/// erfüllen.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct QualificationGapTO {
+    pub slot: Arc<SlotTO>,
+    pub qualification: Arc<QualificationTO>,
+    pub required_count: u8,
+    pub qualified_count: u8,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::booking_information::QualificationGap> for QualificationGapTO {
+    fn from(gap: &service::booking_information::QualificationGap) -> Self {
+        Self {
+            slot: Arc::new(SlotTO::from(&*gap.slot)),
+            qualification: Arc::new(QualificationTO::from(&*gap.qualification)),
+            required_count: gap.required_count,
+            qualified_count: gap.qualified_count,
+        }
+    }
+}
+
+/// Wessen Kalender ein Feed-Token freigibt; die passende ID steht in
+/// `sales_person_id` bzw. `shiftplan_id` des [`IcalFeedTokenTO`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum IcalFeedScopeTO {
+    SalesPerson,
+    Shiftplan,
+    TeamAbsences,
+}
+
+/// Geheimes Token für den iCal-Feed `/ical/{token}`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalFeedTokenTO {
+    pub id: Uuid,
+    pub scope: IcalFeedScopeTO,
+    pub sales_person_id: Option<Uuid>,
+    pub shiftplan_id: Option<Uuid>,
+    pub token: Uuid,
+    pub label: Arc<str>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub created: Option<PrimitiveDateTime>,
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub revoked: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    pub version: Uuid,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::ical_feed_token::IcalFeedToken> for IcalFeedTokenTO {
+    fn from(token: &service::ical_feed_token::IcalFeedToken) -> Self {
+        use service::ical_feed_token::IcalFeedScope;
+        let (scope, sales_person_id, shiftplan_id) = match token.scope {
+            IcalFeedScope::SalesPerson(id) => (IcalFeedScopeTO::SalesPerson, Some(id), None),
+            IcalFeedScope::Shiftplan(id) => (IcalFeedScopeTO::Shiftplan, None, Some(id)),
+            IcalFeedScope::TeamAbsences => (IcalFeedScopeTO::TeamAbsences, None, None),
+        };
+        Self {
+            id: token.id,
+            scope,
+            sales_person_id,
+            shiftplan_id,
+            token: token.token,
+            label: token.label.clone(),
+            created: token.created,
+            revoked: token.revoked,
+            version: token.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalFeedTokenCreateTO {
+    /// Frei wählbare Bezeichnung, z. B. „Handy“.
+    #[serde(default)]
+    pub label: Arc<str>,
+}
+
+/// Ziel eines iCal-Imports: Nicht-verfügbar-Tage oder Abwesenheiten einer
+/// Kategorie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum IcalImportTargetTO {
+    Unavailable,
+    Vacation,
+    SickLeave,
+    UnpaidLeave,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::ical_import::IcalImportTarget> for IcalImportTargetTO {
+    fn from(target: &service::ical_import::IcalImportTarget) -> Self {
+        use service::absence::AbsenceCategory;
+        use service::ical_import::IcalImportTarget;
+        match target {
+            IcalImportTarget::Unavailable => Self::Unavailable,
+            IcalImportTarget::Absence(AbsenceCategory::Vacation) => Self::Vacation,
+            IcalImportTarget::Absence(AbsenceCategory::SickLeave) => Self::SickLeave,
+            IcalImportTarget::Absence(AbsenceCategory::UnpaidLeave) => Self::UnpaidLeave,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<&IcalImportTargetTO> for service::ical_import::IcalImportTarget {
+    fn from(target: &IcalImportTargetTO) -> Self {
+        use service::absence::AbsenceCategory;
+        match target {
+            IcalImportTargetTO::Unavailable => Self::Unavailable,
+            IcalImportTargetTO::Vacation => Self::Absence(AbsenceCategory::Vacation),
+            IcalImportTargetTO::SickLeave => Self::Absence(AbsenceCategory::SickLeave),
+            IcalImportTargetTO::UnpaidLeave => Self::Absence(AbsenceCategory::UnpaidLeave),
+        }
+    }
+}
+
+/// Genau eines von `content` (Inhalt der `.ics`-Datei) oder `url`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalImportPreviewRequestTO {
+    pub target: IcalImportTargetTO,
+    #[serde(default)]
+    pub content: Option<Arc<str>>,
+    #[serde(default)]
+    pub url: Option<Arc<str>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum IcalImportStatusTO {
+    New,
+    AlreadyPresent,
+    /// Überlappt die Abwesenheit `conflicting_absence_id`.
+    Conflict,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalImportEntryTO {
+    pub uid: Arc<str>,
+    pub summary: Arc<str>,
+    #[schema(value_type = String, format = "date")]
+    pub from_date: time::Date,
+    #[schema(value_type = String, format = "date")]
+    pub to_date: time::Date,
+    #[serde(default = "ical_import_status_new")]
+    pub status: IcalImportStatusTO,
+    #[serde(default)]
+    pub conflicting_absence_id: Option<Uuid>,
+}
+
+fn ical_import_status_new() -> IcalImportStatusTO {
+    IcalImportStatusTO::New
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::ical_import::IcalImportEntry> for IcalImportEntryTO {
+    fn from(entry: &service::ical_import::IcalImportEntry) -> Self {
+        use service::ical_import::IcalImportStatus;
+        let (status, conflicting_absence_id) = match entry.status {
+            IcalImportStatus::New => (IcalImportStatusTO::New, None),
+            IcalImportStatus::AlreadyPresent => (IcalImportStatusTO::AlreadyPresent, None),
+            IcalImportStatus::Conflict(id) => (IcalImportStatusTO::Conflict, Some(id)),
+        };
+        Self {
+            uid: entry.uid.clone(),
+            summary: entry.summary.clone(),
+            from_date: entry.from_date,
+            to_date: entry.to_date,
+            status,
+            conflicting_absence_id,
+        }
+    }
+}
+#[cfg(feature = "service-impl")]
+impl From<&IcalImportEntryTO> for service::ical_import::IcalImportEntry {
+    fn from(entry: &IcalImportEntryTO) -> Self {
+        use service::ical_import::IcalImportStatus;
+        Self {
+            uid: entry.uid.clone(),
+            summary: entry.summary.clone(),
+            from_date: entry.from_date,
+            to_date: entry.to_date,
+            status: match (entry.status, entry.conflicting_absence_id) {
+                (IcalImportStatusTO::Conflict, Some(id)) => IcalImportStatus::Conflict(id),
+                (IcalImportStatusTO::AlreadyPresent, _) => IcalImportStatus::AlreadyPresent,
+                _ => IcalImportStatus::New,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum IcalImportSkipReasonTO {
+    Recurring,
+    Cancelled,
+    Free,
+    Past,
+    TooLong,
+    InvalidDates,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalImportSkippedTO {
+    pub uid: Arc<str>,
+    pub summary: Arc<str>,
+    pub reason: IcalImportSkipReasonTO,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::ical_import::IcalImportSkipped> for IcalImportSkippedTO {
+    fn from(skipped: &service::ical_import::IcalImportSkipped) -> Self {
+        use service::ical_import::IcalImportSkipReason;
+        Self {
+            uid: skipped.uid.clone(),
+            summary: skipped.summary.clone(),
+            reason: match skipped.reason {
+                IcalImportSkipReason::Recurring => IcalImportSkipReasonTO::Recurring,
+                IcalImportSkipReason::Cancelled => IcalImportSkipReasonTO::Cancelled,
+                IcalImportSkipReason::Free => IcalImportSkipReasonTO::Free,
+                IcalImportSkipReason::Past => IcalImportSkipReasonTO::Past,
+                IcalImportSkipReason::TooLong => IcalImportSkipReasonTO::TooLong,
+                IcalImportSkipReason::InvalidDates => IcalImportSkipReasonTO::InvalidDates,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalImportPreviewTO {
+    pub sales_person_id: Uuid,
+    pub target: IcalImportTargetTO,
+    pub entries: Vec<IcalImportEntryTO>,
+    pub skipped: Vec<IcalImportSkippedTO>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::ical_import::IcalImportPreview> for IcalImportPreviewTO {
+    fn from(preview: &service::ical_import::IcalImportPreview) -> Self {
+        Self {
+            sales_person_id: preview.sales_person_id,
+            target: (&preview.target).into(),
+            entries: preview.entries.iter().map(IcalImportEntryTO::from).collect(),
+            skipped: preview.skipped.iter().map(IcalImportSkippedTO::from).collect(),
+        }
+    }
+}
+
+/// Die aus der Vorschau übernommenen Einträge.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalImportApplyTO {
+    pub target: IcalImportTargetTO,
+    pub entries: Vec<IcalImportEntryTO>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct IcalImportResultTO {
+    pub unavailables: Vec<SalesPersonUnavailableTO>,
+    pub absences: Vec<AbsencePeriodTO>,
+    pub warnings: Vec<WarningTO>,
+}
+
+#[cfg(feature = "service-impl")]
+impl From<&service::ical_import::IcalImportResult> for IcalImportResultTO {
+    fn from(result: &service::ical_import::IcalImportResult) -> Self {
+        Self {
+            unavailables: result
+                .unavailables
+                .iter()
+                .map(SalesPersonUnavailableTO::from)
+                .collect(),
+            absences: result.absences.iter().map(AbsencePeriodTO::from).collect(),
+            warnings: result.warnings.iter().map(WarningTO::from).collect(),
         }
     }
 }
@@ -2538,6 +4598,7 @@ mod test_employee_work_details_round_trip {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
@@ -2745,3 +4806,44 @@ mod test_shiftplan_slot_to_effective_to {
         let _ = clipped;
     }
 }
+
+/// Der HMAC-Schlüssel eines Webhook-Abonnements verlässt den Server nur in der
+/// Antwort auf das Anlegen; Listen- und Einzelabfragen liefern `secret: null`.
+#[cfg(all(test, feature = "service-impl"))]
+mod test_webhook_subscription_secret {
+    use super::*;
+    use service::webhook::{WebhookEventType, WebhookSubscription};
+
+    fn subscription() -> WebhookSubscription {
+        WebhookSubscription {
+            id: Uuid::new_v4(),
+            url: "https://example.org/hook".into(),
+            secret: "top-secret".into(),
+            description: "".into(),
+            event_types: Arc::new([WebhookEventType::BookingCreated]),
+            active: true,
+            created: None,
+            deleted: None,
+            version: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn secret_is_not_part_of_the_to() {
+        let to = WebhookSubscriptionTO::from(&subscription());
+        assert_eq!(to.secret, None);
+        let json = serde_json::to_string(&to).unwrap();
+        assert!(!json.contains("top-secret"), "secret leaked: {json}");
+    }
+
+    #[test]
+    fn missing_secret_maps_to_empty_service_secret() {
+        let to = WebhookSubscriptionTO::from(&subscription());
+        assert_eq!(WebhookSubscription::from(&to).secret.as_ref(), "");
+        let to = WebhookSubscriptionTO {
+            secret: Some("new-secret".into()),
+            ..to
+        };
+        assert_eq!(WebhookSubscription::from(&to).secret.as_ref(), "new-secret");
+    }
+}
@@ -12,6 +12,10 @@
 //! `error_handler`. Alle Handler dispatchen ueber `rest_state.absence_service()`
 //! gemaess `RestStateDef`-Trait.
 //!
+//! Antrags-Workflow: GET `/requests` (offene Anträge mit Resturlaub und
+//! Warnings, über `AbsenceRequestService`), POST `/{id}/approve`,
+//! `/{id}/reject` und `/{id}/cancel`.
+//!
 //! Phase 8.5 (Plan 04): GET / + GET /by-sales-person/{id} geben jetzt
 //! `AbsenceListWithProjectionTO` zurueck — Ranges + lebende Stunden-Marker
 //! (Vacation/SickLeave/UnpaidLeave) ehrlich am when-Datum (D-07, kein Range-Raten).
@@ -25,9 +29,10 @@ use axum::{
 };
 use rest_types::{
     AbsenceCategoryTO, AbsencePeriodCreateResultTO, AbsencePeriodTO, AbsenceListWithProjectionTO,
-    ExtraHoursMarkerTO, WarningTO,
+    AbsenceStatusTO, ExtraHoursMarkerTO, PendingAbsenceRequestTO, WarningTO,
 };
-use service::absence::AbsenceService;
+use service::absence::{AbsenceService, AbsenceStatus};
+use service::absence_request::AbsenceRequestService;
 use service::extra_hours::{ExtraHoursCategory, ExtraHoursService};
 use service::sales_person::SalesPersonService;
 use shifty_utils::ShiftyDate;
@@ -144,7 +149,8 @@ async fn derived_days_for_entities<RestState: RestStateDef>(
             .or_insert((e.from_date, e.to_date));
     }
 
-    // Ein derive_hours_for_range-Aufruf pro Person.
+    // Ein derive_hours_for_range-Aufruf pro Person. Offene Anträge zählen
+    // dort nicht; ihre Tage kommen aus der Projektion (unten).
     let mut maps: HashMap<Uuid, BTreeMap<time::Date, service::absence::ResolvedAbsence>> =
         HashMap::with_capacity(windows.len());
     for (sp_id, (lo, hi)) in windows.into_iter() {
@@ -154,19 +160,33 @@ async fn derived_days_for_entities<RestState: RestStateDef>(
         maps.insert(sp_id, map);
     }
 
-    Ok(entities
-        .iter()
-        .map(|e| derived_days_from_map(maps.get(&e.sales_person_id), e.from_date, e.to_date))
-        .collect())
+    let mut result = Vec::with_capacity(entities.len());
+    for e in entities {
+        let days = if e.status == AbsenceStatus::Requested {
+            svc.project_request(e.id, context.clone().into(), None)
+                .await?
+                .days_by_year
+                .values()
+                .sum()
+        } else {
+            derived_days_from_map(maps.get(&e.sales_person_id), e.from_date, e.to_date)
+        };
+        result.push(days);
+    }
+    Ok(result)
 }
 
 pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
     Router::new()
         .route("/", post(create_absence_period::<RestState>))
         .route("/", get(get_all_absence_periods::<RestState>))
+        .route("/requests", get(get_pending_absence_requests::<RestState>))
         .route("/{id}", get(get_absence_period::<RestState>))
         .route("/{id}", put(update_absence_period::<RestState>))
         .route("/{id}", delete(delete_absence_period::<RestState>))
+        .route("/{id}/approve", post(approve_absence_period::<RestState>))
+        .route("/{id}/reject", post(reject_absence_period::<RestState>))
+        .route("/{id}/cancel", post(cancel_absence_period::<RestState>))
         .route(
             "/by-sales-person/{sales_person_id}",
             get(get_absence_periods_for_sales_person::<RestState>),
@@ -395,6 +415,7 @@ pub async fn update_absence_period<RestState: RestStateDef>(
         (status = 204, description = "Soft-deleted"),
         (status = 403, description = "Forbidden"),
         (status = 404, description = "Not found"),
+        (status = 422, description = "Approved period (only HR may delete it)"),
     ),
 )]
 pub async fn delete_absence_period<RestState: RestStateDef>(
@@ -520,6 +541,137 @@ pub async fn get_absence_periods_for_sales_person<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/requests",
+    tags = ["Absence"],
+    responses(
+        (status = 200, description = "Open absence requests with projected vacation balance and conflict warnings", body = [PendingAbsenceRequestTO]),
+        (status = 403, description = "Forbidden (HR or shiftplanner only)"),
+    ),
+)]
+pub async fn get_pending_absence_requests<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let requests = rest_state
+                .absence_request_service()
+                .find_pending(context.into(), None)
+                .await?;
+            let to: Vec<PendingAbsenceRequestTO> =
+                requests.iter().map(PendingAbsenceRequestTO::from).collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&to).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/approve",
+    tags = ["Absence"],
+    params(("id", description = "Absence period id")),
+    responses(
+        (status = 200, description = "Request approved (with warnings if any)", body = AbsencePeriodCreateResultTO),
+        (status = 403, description = "Forbidden (HR or shiftplanner only)"),
+        (status = 404, description = "Absence period not found"),
+        (status = 422, description = "Absence period is not an open request"),
+    ),
+)]
+pub async fn approve_absence_period<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let svc = rest_state.absence_service();
+            let result = svc.approve(id, context.into(), None).await?;
+            let to = AbsencePeriodCreateResultTO::from(&result);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&to).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/reject",
+    tags = ["Absence"],
+    params(("id", description = "Absence period id")),
+    responses(
+        (status = 200, description = "Request rejected", body = AbsencePeriodTO),
+        (status = 403, description = "Forbidden (HR or shiftplanner only)"),
+        (status = 404, description = "Absence period not found"),
+        (status = 422, description = "Absence period is not an open request"),
+    ),
+)]
+pub async fn reject_absence_period<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let svc = rest_state.absence_service();
+            let absence = svc.reject(id, context.into(), None).await?;
+            let to = AbsencePeriodTO::from(&absence);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&to).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/cancel",
+    tags = ["Absence"],
+    params(("id", description = "Absence period id")),
+    responses(
+        (status = 200, description = "Absence period cancelled", body = AbsencePeriodTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Absence period not found"),
+        (status = 422, description = "Already decided (employees may only cancel open requests)"),
+    ),
+)]
+pub async fn cancel_absence_period<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let svc = rest_state.absence_service();
+            let absence = svc.cancel(id, context.into(), None).await?;
+            let to = AbsencePeriodTO::from(&absence);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&to).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
 #[derive(OpenApi)]
 #[openapi(
     paths(
@@ -529,11 +681,17 @@ pub async fn get_absence_periods_for_sales_person<RestState: RestStateDef>(
         update_absence_period,
         delete_absence_period,
         get_absence_periods_for_sales_person,
+        get_pending_absence_requests,
+        approve_absence_period,
+        reject_absence_period,
+        cancel_absence_period,
     ),
     components(schemas(
         AbsencePeriodTO,
         AbsenceCategoryTO,
+        AbsenceStatusTO,
         AbsencePeriodCreateResultTO,
+        PendingAbsenceRequestTO,
         WarningTO,
         // Phase 8.5 (Plan 04): Read-Projektion Schemas.
         ExtraHoursMarkerTO,
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    response::Response,
+    routing::get,
+    Extension, Router,
+};
+use rest_types::{AuditActionTO, AuditFieldChangeTO, AuditLogEntryTO};
+use serde::Deserialize;
+use service::audit_log::{AuditLogFilter, AuditLogService};
+use tracing::instrument;
+use utoipa::{IntoParams, OpenApi};
+
+use crate::{error_handler, Context, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new().route("/", get(get_audit_log::<RestState>))
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditLogAttributes {
+    /// Entitätstyp, z. B. `slot`, `employee_work_details`, `absence_period`.
+    #[param(example = "slot")]
+    entity_type: Option<Arc<str>>,
+    /// Id der Entität, meist eine UUID.
+    entity_id: Option<Arc<str>>,
+    /// Handelnder oder impersonierender Benutzer.
+    user_id: Option<Arc<str>>,
+    /// Erster Tag (inklusive).
+    #[param(value_type = Option<String>, format = Date, example = "2026-10-01")]
+    from: Option<time::Date>,
+    /// Letzter Tag (inklusive).
+    #[param(value_type = Option<String>, format = Date, example = "2026-10-31")]
+    to: Option<time::Date>,
+    /// Maximale Anzahl Einträge (Default 200, höchstens 1000).
+    #[param(example = "200")]
+    limit: Option<u32>,
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["Audit Log"],
+    params(AuditLogAttributes),
+    responses(
+        (status = 200, description = "Matching audit log entries, newest first", body = [AuditLogEntryTO]),
+        (status = 403, description = "Forbidden (HR or admin only)"),
+        (status = 422, description = "from is after to"),
+    ),
+)]
+pub async fn get_audit_log<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    query: Query<AuditLogAttributes>,
+) -> Response {
+    error_handler(
+        (async {
+            let filter = AuditLogFilter {
+                entity_type: query.entity_type.clone(),
+                entity_id: query.entity_id.clone(),
+                user_id: query.user_id.clone(),
+                from: query.from,
+                to: query.to,
+                limit: query.limit.unwrap_or_default(),
+            };
+            let entries: Arc<[AuditLogEntryTO]> = rest_state
+                .audit_log_service()
+                .find(&filter, context.into(), None)
+                .await?
+                .iter()
+                .map(AuditLogEntryTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&entries).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(get_audit_log),
+    components(schemas(AuditLogEntryTO, AuditFieldChangeTO, AuditActionTO))
+)]
+pub struct AuditLogApiDoc;
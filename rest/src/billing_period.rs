@@ -1,20 +1,25 @@
 use std::sync::Arc;
 
 use axum::body::Body;
-use axum::extract::Path;
+use axum::extract::{Path, Query};
 use axum::routing::{delete, get, post};
 use axum::{extract::State, response::Response};
 use axum::{Extension, Json, Router};
 use rest_types::{
     BillingPeriodSalesPersonTO, BillingPeriodTO, BillingPeriodValueTO, CreateBillingPeriodRequestTO,
+    ExportFormatTO, ExportLanguageTO,
 };
+use serde::Deserialize;
 use tracing::instrument;
-use utoipa::OpenApi;
+use utoipa::{IntoParams, OpenApi};
 use uuid::Uuid;
 
+use crate::report::export_file_response;
 use crate::{error_handler, Context, RestStateDef};
 use service::billing_period::BillingPeriodService;
 use service::billing_period_report::BillingPeriodReportService;
+use service::payroll_export::PayrollExportService;
+use service::report_export::ReportExportService;
 use shifty_utils::ShiftyDate;
 
 pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
@@ -24,6 +29,20 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
         .route("/", post(create_billing_period::<RestState>))
         .route("/", delete(clear_all_billing_periods::<RestState>))
         .route("/{id}/custom-report/{template_id}", post(generate_custom_report::<RestState>))
+        .route("/{id}/custom-report/{template_id}/pdf", post(generate_custom_report_pdf::<RestState>))
+        .route("/{id}/export", get(export_billing_period::<RestState>))
+        .route("/{id}/payroll-export", get(export_payroll::<RestState>))
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BillingPeriodExportRequest {
+    /// File format, CSV if omitted
+    #[serde(default)]
+    format: ExportFormatTO,
+    /// Language of the column headers, German if omitted
+    #[serde(default)]
+    lang: ExportLanguageTO,
 }
 
 #[utoipa::path(
@@ -247,6 +266,121 @@ pub async fn generate_custom_report<RestState: RestStateDef>(
     )
 }
 
+#[utoipa::path(
+    post,
+    path = "/{id}/custom-report/{template_id}/pdf",
+    params(
+        ("id" = Uuid, Path, description = "Billing period ID"),
+        ("template_id" = Uuid, Path, description = "Text template ID (type `billing-period-pdf`)")
+    ),
+    responses(
+        (status = 200, description = "Custom report as PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - HR permission required"),
+        (status = 404, description = "Billing period or template not found"),
+        (status = 422, description = "Wrong template type or invalid PDF layout"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(rest_state))]
+pub async fn generate_custom_report_pdf<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path((billing_period_id, template_id)): Path<(Uuid, Uuid)>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .billing_period_report_service()
+                .generate_custom_report_pdf(template_id, billing_period_id, context.into(), None)
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/export",
+    params(
+        ("id" = Uuid, Path, description = "Billing period ID"),
+        BillingPeriodExportRequest
+    ),
+    responses(
+        (status = 200, description = "Billing period with all values as file", content(
+            (Vec<u8> = "text/csv"),
+            (Vec<u8> = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        )),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - HR permission required"),
+        (status = 404, description = "Billing period not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(rest_state))]
+pub async fn export_billing_period<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(billing_period_id): Path<Uuid>,
+    query: Query<BillingPeriodExportRequest>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .report_export_service()
+                .export_billing_period(
+                    billing_period_id,
+                    query.format.into(),
+                    query.lang.into(),
+                    context.into(),
+                    None,
+                )
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/payroll-export",
+    params(
+        ("id" = Uuid, Path, description = "Billing period ID"),
+    ),
+    responses(
+        (status = 200, description = "DATEV LODAS import file for the payroll month of the period end", content(
+            (Vec<u8> = "text/plain"),
+        )),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - HR permission required"),
+        (status = 404, description = "Billing period not found"),
+        (status = 422, description = "DATEV consultant/client number not configured or personnel numbers missing"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip(rest_state))]
+pub async fn export_payroll<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(billing_period_id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .payroll_export_service()
+                .export_billing_period(billing_period_id, context.into(), None)
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
 #[derive(OpenApi)]
 #[openapi(
     paths(
@@ -256,6 +390,9 @@ pub async fn generate_custom_report<RestState: RestStateDef>(
         clear_all_billing_periods,
         delete_billing_period,
         generate_custom_report,
+        generate_custom_report_pdf,
+        export_billing_period,
+        export_payroll,
     ),
     components(
         schemas(BillingPeriodTO, BillingPeriodSalesPersonTO, BillingPeriodValueTO, CreateBillingPeriodRequestTO)
@@ -5,7 +5,7 @@ use axum::extract::Path;
 use axum::routing::get;
 use axum::{extract::State, response::Response};
 use axum::{Extension, Router};
-use rest_types::{BookingConflictTO, WeeklySummaryTO};
+use rest_types::{BookingConflictTO, QualificationGapTO, WeeklySummaryTO};
 use service::booking_information::BookingInformationService;
 use tracing::instrument;
 
@@ -17,6 +17,10 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
             "/conflicts/for-week/{year}/{week}",
             get(get_booking_conflicts_for_week::<RestState>),
         )
+        .route(
+            "/conflicts/qualification/for-week/{year}/{week}",
+            get(get_qualification_gaps_for_week::<RestState>),
+        )
         .route(
             "/weekly-resource-report/{year}",
             get(get_weekly_summary::<RestState>),
@@ -39,6 +43,7 @@ pub async fn get_booking_conflicts_for_week<RestState: RestStateDef>(
                 .booking_information_service()
                 .get_booking_conflicts_for_week(year, week, context.into(), None)
                 .await?
+                .bookings
                 .iter()
                 .map(BookingConflictTO::from)
                 .collect();
@@ -54,6 +59,34 @@ pub async fn get_booking_conflicts_for_week<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+pub async fn get_qualification_gaps_for_week<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path((year, week)): Path<(u32, u8)>,
+) -> Response {
+    error_handler(
+        (async {
+            let qualification_gaps: Arc<[QualificationGapTO]> = rest_state
+                .booking_information_service()
+                .get_booking_conflicts_for_week(year, week, context.into(), None)
+                .await?
+                .qualification_gaps
+                .iter()
+                .map(QualificationGapTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&qualification_gaps).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
 #[instrument(skip(rest_state))]
 pub async fn get_weekly_summary<RestState: RestStateDef>(
     rest_state: State<RestState>,
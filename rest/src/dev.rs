@@ -151,6 +151,7 @@ async fn seed_sales_persons<RestState: RestStateDef>(
     let service = rest_state.sales_person_service();
 
     let create = |name: &str, color: &str, is_paid: bool, inactive: bool| SalesPerson {
+        personnel_number: None,
         id: Uuid::nil(),
         name: Arc::from(name),
         background_color: Arc::from(color),
@@ -220,6 +221,7 @@ async fn seed_work_details<RestState: RestStateDef>(
                 is_dynamic: false,
                 cap_planned_hours_to_expected: false,
                 committed_voluntary: 0.0,
+                use_actual_hours: false,
                 monday: mon,
                 tuesday: tue,
                 wednesday: wed,
@@ -0,0 +1,338 @@
+//! REST-Layer für die benannten Export-Ziele (`/export-target`).
+//!
+//! Admin-gated CRUD plus Lauf-Historie und Sofort-Lauf. Nach jedem Anlegen,
+//! Ändern und Löschen lädt der Handler den
+//! [`service::export_target_scheduler::ExportTargetScheduler`] neu, damit
+//! Cron-Ausdrücke ohne Server-Restart wirksam werden. Der App-Token eines
+//! WebDAV-Ziels wird in Antworten nie zurückgegeben.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    response::Response,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use rest_types::{
+    ExportArtifactTypeTO, ExportRunStateTO, ExportTargetKindTO, ExportTargetRunTO, ExportTargetTO,
+};
+use serde::Deserialize;
+use service::export_target::{ExportTarget, ExportTargetService};
+use service::export_target_scheduler::ExportTargetScheduler;
+use service::permission::Authentication;
+use tracing::instrument;
+use utoipa::{IntoParams, OpenApi};
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestError, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route(
+            "/",
+            get(get_targets::<RestState>).post(create_target::<RestState>),
+        )
+        .route(
+            "/{id}",
+            get(get_target::<RestState>)
+                .put(update_target::<RestState>)
+                .delete(delete_target::<RestState>),
+        )
+        .route("/{id}/run", get(get_runs::<RestState>))
+        .route("/{id}/trigger", post(trigger_target::<RestState>))
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RunAttributes {
+    /// Maximale Anzahl Einträge (Default 50, höchstens 500).
+    #[param(example = "50")]
+    limit: Option<u32>,
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["ExportTarget"],
+    responses(
+        (status = 200, description = "All export targets (secret masked)", body = [ExportTargetTO]),
+        (status = 403, description = "Forbidden (admin only)"),
+    ),
+)]
+pub async fn get_targets<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let targets: Arc<[ExportTargetTO]> = rest_state
+                .export_target_service()
+                .get_all(context.into(), None)
+                .await?
+                .iter()
+                .map(ExportTargetTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&targets).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tags = ["ExportTarget"],
+    params(("id", description = "Export target id")),
+    responses(
+        (status = 200, description = "Export target (secret masked)", body = ExportTargetTO),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Export target not found"),
+    ),
+)]
+pub async fn get_target<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let target = rest_state
+                .export_target_service()
+                .get(id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ExportTargetTO::from(&target)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state, body))]
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["ExportTarget"],
+    request_body = ExportTargetTO,
+    responses(
+        (status = 201, description = "Export target created (secret masked)", body = ExportTargetTO),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 422, description = "Invalid target fields, cron expression or filename pattern"),
+    ),
+)]
+pub async fn create_target<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<ExportTargetTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let target = rest_state
+                .export_target_service()
+                .create(&ExportTarget::from(&body), context.into(), None)
+                .await?;
+            rest_state
+                .export_target_scheduler()
+                .reload_from_db()
+                .await?;
+            Ok(Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ExportTargetTO::from(&target)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state, body))]
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tags = ["ExportTarget"],
+    params(("id", description = "Export target id")),
+    request_body = ExportTargetTO,
+    responses(
+        (status = 200, description = "Export target updated (secret masked)", body = ExportTargetTO),
+        (status = 400, description = "Inconsistent id"),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Export target not found"),
+        (status = 409, description = "Version conflict"),
+        (status = 422, description = "Invalid target fields, cron expression or filename pattern"),
+    ),
+)]
+pub async fn update_target<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<ExportTargetTO>,
+) -> Response {
+    error_handler(
+        (async {
+            if !body.id.is_nil() && body.id != id {
+                return Err(RestError::InconsistentId(id, body.id));
+            }
+            let target = ExportTarget {
+                id,
+                ..ExportTarget::from(&body)
+            };
+            let target = rest_state
+                .export_target_service()
+                .update(&target, context.into(), None)
+                .await?;
+            rest_state
+                .export_target_scheduler()
+                .reload_from_db()
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ExportTargetTO::from(&target)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tags = ["ExportTarget"],
+    params(("id", description = "Export target id")),
+    responses(
+        (status = 204, description = "Export target deleted, run history kept"),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Export target not found"),
+    ),
+)]
+pub async fn delete_target<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .export_target_service()
+                .delete(id, context.into(), None)
+                .await?;
+            rest_state
+                .export_target_scheduler()
+                .reload_from_db()
+                .await?;
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{id}/run",
+    tags = ["ExportTarget"],
+    params(("id", description = "Export target id"), RunAttributes),
+    responses(
+        (status = 200, description = "Most recent runs of the target, newest first", body = [ExportTargetRunTO]),
+        (status = 403, description = "Forbidden (admin only)"),
+    ),
+)]
+pub async fn get_runs<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    query: Query<RunAttributes>,
+) -> Response {
+    error_handler(
+        (async {
+            let runs: Arc<[ExportTargetRunTO]> = rest_state
+                .export_target_service()
+                .get_runs(id, query.limit.unwrap_or(50), context.into(), None)
+                .await?
+                .iter()
+                .map(ExportTargetRunTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&runs).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/trigger",
+    tags = ["ExportTarget"],
+    params(("id", description = "Export target id")),
+    responses(
+        (status = 204, description = "Run started asynchronously; the result appears in the run history"),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Export target not found"),
+    ),
+)]
+pub async fn trigger_target<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            // Admin-Gate über das admin-gated `get`; der Lauf selbst braucht
+            // `Authentication::Full`, um die Historie zu schreiben.
+            rest_state
+                .export_target_service()
+                .get(id, context.into(), None)
+                .await?;
+            let scheduler = rest_state.export_target_scheduler();
+            tokio::spawn(async move {
+                if let Err(e) = scheduler.run_target_now(id, Authentication::Full).await {
+                    tracing::error!("export target {id} trigger run failed: {e:?}");
+                }
+            });
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_targets,
+        get_target,
+        create_target,
+        update_target,
+        delete_target,
+        get_runs,
+        trigger_target,
+    ),
+    components(schemas(
+        ExportTargetTO,
+        ExportTargetRunTO,
+        ExportTargetKindTO,
+        ExportArtifactTypeTO,
+        ExportRunStateTO
+    ))
+)]
+pub struct ExportTargetApiDoc;
@@ -0,0 +1,279 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, Response, RestError, RestStateDef};
+use rest_types::{IcalFeedScopeTO, IcalFeedTokenCreateTO, IcalFeedTokenTO};
+use service::{
+    ical_feed_token::{IcalFeedScope, IcalFeedTokenService},
+    permission::Authentication,
+};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route(
+            "/sales-person/{id}",
+            get(get_tokens_for_sales_person::<RestState>),
+        )
+        .route(
+            "/sales-person/{id}",
+            post(create_token_for_sales_person::<RestState>),
+        )
+        .route(
+            "/shiftplan/{id}",
+            get(get_tokens_for_shiftplan::<RestState>),
+        )
+        .route(
+            "/shiftplan/{id}",
+            post(create_token_for_shiftplan::<RestState>),
+        )
+        .route(
+            "/team-absences",
+            get(get_tokens_for_team_absences::<RestState>),
+        )
+        .route(
+            "/team-absences",
+            post(create_token_for_team_absences::<RestState>),
+        )
+        .route("/{id}/revoke", post(revoke_token::<RestState>))
+}
+
+fn json_response(body: String) -> Response {
+    Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(Body::new(body))
+        .unwrap()
+}
+
+async fn list_tokens<RestState: RestStateDef>(
+    rest_state: &RestState,
+    scope: IcalFeedScope,
+    context: Context,
+) -> Result<Response, RestError> {
+    let tokens: Vec<IcalFeedTokenTO> = rest_state
+        .ical_feed_token_service()
+        .get_for_scope(scope, Authentication::Context(context), None)
+        .await?
+        .iter()
+        .map(IcalFeedTokenTO::from)
+        .collect();
+    Ok(json_response(serde_json::to_string(&tokens).unwrap()))
+}
+
+async fn create_token<RestState: RestStateDef>(
+    rest_state: &RestState,
+    scope: IcalFeedScope,
+    body: &IcalFeedTokenCreateTO,
+    context: Context,
+) -> Result<Response, RestError> {
+    let token = rest_state
+        .ical_feed_token_service()
+        .create(scope, &body.label, Authentication::Context(context), None)
+        .await?;
+    let to = IcalFeedTokenTO::from(&token);
+    Ok(json_response(serde_json::to_string(&to).unwrap()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/sales-person/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Sales person ID")
+    ),
+    responses(
+        (status = 200, description = "All feed tokens of the sales person, including revoked ones", body = [IcalFeedTokenTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn get_tokens_for_sales_person<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(list_tokens(&*rest_state, IcalFeedScope::SalesPerson(id), context).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/sales-person/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Sales person ID")
+    ),
+    request_body = IcalFeedTokenCreateTO,
+    responses(
+        (status = 200, description = "Feed token created; subscribe to /ical/{token}", body = IcalFeedTokenTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Sales person not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn create_token_for_sales_person<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<IcalFeedTokenCreateTO>,
+) -> Response {
+    error_handler(create_token(&*rest_state, IcalFeedScope::SalesPerson(id), &body, context).await)
+}
+
+#[utoipa::path(
+    get,
+    path = "/shiftplan/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Shiftplan ID")
+    ),
+    responses(
+        (status = 200, description = "All feed tokens of the shiftplan, including revoked ones", body = [IcalFeedTokenTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden (shiftplanner only)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn get_tokens_for_shiftplan<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(list_tokens(&*rest_state, IcalFeedScope::Shiftplan(id), context).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/shiftplan/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Shiftplan ID")
+    ),
+    request_body = IcalFeedTokenCreateTO,
+    responses(
+        (status = 200, description = "Feed token for all slots of the shiftplan created; subscribe to /ical/{token}", body = IcalFeedTokenTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden (shiftplanner only)"),
+        (status = 404, description = "Shiftplan not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn create_token_for_shiftplan<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<IcalFeedTokenCreateTO>,
+) -> Response {
+    error_handler(create_token(&*rest_state, IcalFeedScope::Shiftplan(id), &body, context).await)
+}
+
+#[utoipa::path(
+    get,
+    path = "/team-absences",
+    responses(
+        (status = 200, description = "All feed tokens for the team absence calendar, including revoked ones", body = [IcalFeedTokenTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn get_tokens_for_team_absences<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(list_tokens(&*rest_state, IcalFeedScope::TeamAbsences, context).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/team-absences",
+    request_body = IcalFeedTokenCreateTO,
+    responses(
+        (status = 200, description = "Feed token for absences and special days of the team created; subscribe to /ical/{token}", body = IcalFeedTokenTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn create_token_for_team_absences<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<IcalFeedTokenCreateTO>,
+) -> Response {
+    error_handler(create_token(&*rest_state, IcalFeedScope::TeamAbsences, &body, context).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/revoke",
+    params(
+        ("id" = Uuid, Path, description = "Feed token ID")
+    ),
+    responses(
+        (status = 200, description = "Feed token revoked", body = IcalFeedTokenTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Feed token not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-feed-token"
+)]
+async fn revoke_token<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let token = rest_state
+                .ical_feed_token_service()
+                .revoke(id, Authentication::Context(context), None)
+                .await?;
+            let to = IcalFeedTokenTO::from(&token);
+            Ok(json_response(serde_json::to_string(&to).unwrap()))
+        })
+        .await,
+    )
+}
+
+/// Öffentlicher Feed `/ical/{token}` für Kalender-Apps; läuft außerhalb der
+/// Session-Middleware, das Token ist die Berechtigung.
+pub async fn get_feed<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Path(token): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let ical = rest_state
+                .ical_feed_token_service()
+                .get_feed(token, None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "text/calendar; charset=utf-8")
+                .body(Body::new(ical.as_ref().to_string()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_tokens_for_sales_person,
+        create_token_for_sales_person,
+        get_tokens_for_shiftplan,
+        create_token_for_shiftplan,
+        get_tokens_for_team_absences,
+        create_token_for_team_absences,
+        revoke_token
+    ),
+    components(schemas(IcalFeedTokenTO, IcalFeedScopeTO, IcalFeedTokenCreateTO)),
+    tags(
+        (name = "ical-feed-token", description = "Secret, revocable tokens for the public iCal feed")
+    )
+)]
+pub struct IcalFeedTokenApiDoc;
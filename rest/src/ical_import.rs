@@ -0,0 +1,145 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    routing::post,
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, Response, RestError, RestStateDef};
+use rest_types::{
+    IcalImportApplyTO, IcalImportEntryTO, IcalImportPreviewRequestTO, IcalImportPreviewTO,
+    IcalImportResultTO, IcalImportSkipReasonTO, IcalImportSkippedTO, IcalImportStatusTO,
+    IcalImportTargetTO,
+};
+use service::{
+    ical_import::{IcalImportEntry, IcalImportService, IcalImportSource},
+    permission::Authentication,
+};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/{sales_person_id}/preview", post(preview::<RestState>))
+        .route("/{sales_person_id}/apply", post(apply::<RestState>))
+}
+
+fn json_response(body: String) -> Response {
+    Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(Body::new(body))
+        .unwrap()
+}
+
+#[utoipa::path(
+    post,
+    path = "/{sales_person_id}/preview",
+    params(
+        ("sales_person_id" = Uuid, Path, description = "Sales person ID")
+    ),
+    request_body = IcalImportPreviewRequestTO,
+    responses(
+        (status = 200, description = "Proposed entries compared with the existing ones; nothing is written", body = IcalImportPreviewTO, content_type = "application/json"),
+        (status = 400, description = "Neither or both of content and url"),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Unreadable calendar or unreachable url"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-import"
+)]
+async fn preview<RestState: RestStateDef>(
+    Path(sales_person_id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<IcalImportPreviewRequestTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let source = match (body.content, body.url) {
+                (Some(content), None) => IcalImportSource::Content(content),
+                (None, Some(url)) => IcalImportSource::Url(url),
+                _ => {
+                    return Err(RestError::BadRequest(
+                        "exactly one of content and url is required".into(),
+                    ))
+                }
+            };
+            let preview = rest_state
+                .ical_import_service()
+                .preview(
+                    sales_person_id,
+                    source,
+                    (&body.target).into(),
+                    Authentication::Context(context),
+                    None,
+                )
+                .await?;
+            let to = IcalImportPreviewTO::from(&preview);
+            Ok(json_response(serde_json::to_string(&to).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "/{sales_person_id}/apply",
+    params(
+        ("sales_person_id" = Uuid, Path, description = "Sales person ID")
+    ),
+    request_body = IcalImportApplyTO,
+    responses(
+        (status = 200, description = "Created entries and conflict warnings", body = IcalImportResultTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "An entry failed validation (e.g. overlapping absence); nothing was written"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "ical-import"
+)]
+async fn apply<RestState: RestStateDef>(
+    Path(sales_person_id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<IcalImportApplyTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let entries: Vec<IcalImportEntry> =
+                body.entries.iter().map(IcalImportEntry::from).collect();
+            let result = rest_state
+                .ical_import_service()
+                .apply(
+                    sales_person_id,
+                    (&body.target).into(),
+                    &entries,
+                    Authentication::Context(context),
+                    None,
+                )
+                .await?;
+            let to = IcalImportResultTO::from(&result);
+            Ok(json_response(serde_json::to_string(&to).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(preview, apply),
+    components(schemas(
+        IcalImportPreviewRequestTO,
+        IcalImportPreviewTO,
+        IcalImportEntryTO,
+        IcalImportStatusTO,
+        IcalImportSkippedTO,
+        IcalImportSkipReasonTO,
+        IcalImportTargetTO,
+        IcalImportApplyTO,
+        IcalImportResultTO
+    )),
+    tags(
+        (name = "ical-import", description = "Import of availability and absences from iCalendar files")
+    )
+)]
+pub struct IcalImportApiDoc;
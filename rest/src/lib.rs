@@ -1,6 +1,7 @@
 use std::{convert::Infallible, sync::Arc};
 
 mod absence;
+mod audit_log;
 mod billing_period;
 mod block_report;
 mod booking;
@@ -8,6 +9,7 @@ mod booking_information;
 mod booking_log;
 mod custom_extra_hours;
 mod employee_work_details;
+mod export_target;
 // Debug-Session convert-to-absence-404: pub-export so the shifty_bin integration
 // test can reach `extra_hours::generate_route` via tower::oneshot to probe
 // double-slash routing behavior — same pattern as `pub mod feature_flag;`.
@@ -16,29 +18,41 @@ pub mod extra_hours;
 // reach `feature_flag::generate_route` via tower::oneshot — same pattern as
 // `pub mod cutover;` which lets `integration_test/cutover.rs` use it.
 pub mod feature_flag;
+mod ical_feed_token;
+mod ical_import;
 pub mod impersonate;
 mod my_block;
+mod payroll_wage_type;
 mod pdf_export_config;
+mod pdf_personal;
 mod pdf_shiftplan;
 mod permission;
+mod qualification;
+mod rebooking_batch;
 // Phase 54 (Plan 04): pub-export so the shifty_bin integration test can reach
 // `report::generate_route` via tower::oneshot to exercise the
 // `/report/{id}/voluntary-stats` HR/Non-HR redaction end-to-end — same pattern
 // as `pub mod feature_flag;` (Plan 08-07 Gap-Closure).
 pub mod report;
+mod notification;
 mod sales_person;
 mod sales_person_shiftplan;
+mod scheduler_job;
 pub mod session;
 mod shiftplan;
 mod shiftplan_catalog;
 mod shiftplan_edit;
+mod shift_swap;
+mod shiftplan_generator;
 mod slot;
 mod special_day;
 mod text_template;
+mod time_entry;
 mod toggle;
 mod user_invitation;
 mod vacation_balance;
 mod vacation_entitlement_offset;
+mod webhook;
 mod week_message;
 mod week_status;
 
@@ -313,10 +327,18 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Send
         + Sync
         + 'static;
+    type HolidayCalendarService: service::holiday_calendar::HolidayCalendarService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     type SalesPersonUnavailableService: service::sales_person_unavailable::SalesPersonUnavailableService<Context = Context>
         + Send
         + Sync
         + 'static;
+    type SalesPersonAvailabilityService: service::sales_person_availability::SalesPersonAvailabilityService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     type BookingService: service::booking::BookingService<Context = Context> + Send + Sync + 'static;
     type CustomExtraHoursService: service::custom_extra_hours::CustomExtraHoursService<Context = Context>
         + Send
@@ -350,10 +372,23 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Send
         + Sync
         + 'static;
+    type AbsenceRequestService: service::absence_request::AbsenceRequestService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     type ShiftplanEditService: service::shiftplan_edit::ShiftplanEditService<Context = Context>
         + Send
         + Sync
         + 'static;
+    type ShiftplanGeneratorService: service::shiftplan_generator::ShiftplanGeneratorService<
+            Context = Context,
+        > + Send
+        + Sync
+        + 'static;
+    type ShiftSwapService: service::shift_swap::ShiftSwapService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     type BlockService: service::block::BlockService<Context = Context> + Send + Sync + 'static;
     type ShiftplanService: service::shiftplan_catalog::ShiftplanService<Context = Context>
         + Send
@@ -371,6 +406,38 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Send
         + Sync
         + 'static;
+    type NotificationService: service::notification::NotificationService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type WebhookService: service::webhook::WebhookService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type TimeEntryService: service::time_entry::TimeEntryService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type AuditLogService: service::audit_log::AuditLogService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type ReportExportService: service::report_export::ReportExportService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type PdfPersonalService: service::pdf_personal::PdfPersonalService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type PayrollWageTypeService: service::payroll_wage_type::PayrollWageTypeService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type PayrollExportService: service::payroll_export::PayrollExportService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     type BillingPeriodService: service::billing_period::BillingPeriodService<Context = Context>
         + Send
         + Sync
@@ -387,6 +454,11 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Send
         + Sync
         + 'static;
+    type TextTemplatePreviewService: service::text_template_preview::TextTemplatePreviewService<
+            Context = Context,
+        > + Send
+        + Sync
+        + 'static;
     type UserInvitationService: service::user_invitation::UserInvitationService<Context = Context>
         + Send
         + Sync
@@ -420,8 +492,8 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Sync
         + 'static;
     // Phase 54 (D-54-DM-01): Basic-Tier HR-gated RebookingBatchService.
-    // In Phase 54 noch nicht via REST exponiert — der Trait wird ab Phase 55
-    // vom Business-Logic RebookingReconciliationService konsumiert.
+    // Nicht direkt via REST exponiert — der Trait wird vom Business-Logic
+    // RebookingReconciliationService konsumiert.
     type RebookingBatchService: service::rebooking_batch::RebookingBatchService<Context = Context>
         + Send
         + Sync
@@ -433,6 +505,11 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Send
         + Sync
         + 'static;
+    // Phase 55 (F3/F5): BL-Tier Rebooking-Workflow hinter `/rebooking-batch`.
+    type RebookingReconciliationService: service::rebooking_reconciliation::RebookingReconciliationService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     // Phase 48 (EXP-02/EXP-03, D-48-ADMIN): Basic-Tier admin-gated Config-
     // Service für den `/pdf-export-config`-Endpoint (GET/PUT).
     type PdfExportConfigService: service::pdf_export_config::PdfExportConfigService<Context = Context>
@@ -455,6 +532,38 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
         + Send
         + Sync
         + 'static;
+    // Registry der Hintergrund-Jobs (`/scheduler-job`) und der Scheduler, der
+    // nach einem PUT neu lädt bzw. einen Job sofort ausführt.
+    type SchedulerJobService: service::scheduler_job::SchedulerJobService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type SchedulerService: service::scheduler::SchedulerService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type QualificationService: service::qualification::QualificationService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type IcalFeedTokenService: service::ical_feed_token::IcalFeedTokenService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type IcalImportService: service::ical_import::IcalImportService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    // Benannte Export-Ziele (`/export-target`) und ihr Scheduler, der nach
+    // jeder Änderung neu lädt bzw. ein Ziel sofort ausführt.
+    type ExportTargetService: service::export_target::ExportTargetService<Context = Context>
+        + Send
+        + Sync
+        + 'static;
+    type ExportTargetScheduler: service::export_target_scheduler::ExportTargetScheduler<Context = Context>
+        + Send
+        + Sync
+        + 'static;
     type BasicDao: dao::BasicDao + Send + Sync + 'static;
 
     fn backend_version(&self) -> Arc<str>;
@@ -465,10 +574,13 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
     fn slot_service(&self) -> Arc<Self::SlotService>;
     fn sales_person_service(&self) -> Arc<Self::SalesPersonService>;
     fn special_day_service(&self) -> Arc<Self::SpecialDayService>;
+    fn holiday_calendar_service(&self) -> Arc<Self::HolidayCalendarService>;
     fn sales_person_unavailable_service(&self) -> Arc<Self::SalesPersonUnavailableService>;
+    fn sales_person_availability_service(&self) -> Arc<Self::SalesPersonAvailabilityService>;
     fn booking_service(&self) -> Arc<Self::BookingService>;
     fn absence_service(&self) -> Arc<Self::AbsenceService>;
     fn vacation_balance_service(&self) -> Arc<Self::VacationBalanceService>;
+    fn absence_request_service(&self) -> Arc<Self::AbsenceRequestService>;
     fn custom_extra_hours_service(&self) -> Arc<Self::CustomExtraHoursService>;
     fn booking_information_service(&self) -> Arc<Self::BookingInformationService>;
     fn booking_log_service(&self) -> Arc<Self::BookingLogService>;
@@ -476,15 +588,26 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
     fn working_hours_service(&self) -> Arc<Self::WorkingHoursService>;
     fn extra_hours_service(&self) -> Arc<Self::ExtraHoursService>;
     fn shiftplan_edit_service(&self) -> Arc<Self::ShiftplanEditService>;
+    fn shiftplan_generator_service(&self) -> Arc<Self::ShiftplanGeneratorService>;
+    fn shift_swap_service(&self) -> Arc<Self::ShiftSwapService>;
     fn block_service(&self) -> Arc<Self::BlockService>;
     fn shiftplan_service(&self) -> Arc<Self::ShiftplanService>;
     fn shiftplan_view_service(&self) -> Arc<Self::ShiftplanViewService>;
     fn week_message_service(&self) -> Arc<Self::WeekMessageService>;
     fn week_status_service(&self) -> Arc<Self::WeekStatusService>;
+    fn notification_service(&self) -> Arc<Self::NotificationService>;
+    fn webhook_service(&self) -> Arc<Self::WebhookService>;
+    fn time_entry_service(&self) -> Arc<Self::TimeEntryService>;
+    fn audit_log_service(&self) -> Arc<Self::AuditLogService>;
+    fn report_export_service(&self) -> Arc<Self::ReportExportService>;
+    fn pdf_personal_service(&self) -> Arc<Self::PdfPersonalService>;
+    fn payroll_wage_type_service(&self) -> Arc<Self::PayrollWageTypeService>;
+    fn payroll_export_service(&self) -> Arc<Self::PayrollExportService>;
     fn billing_period_service(&self) -> Arc<Self::BillingPeriodService>;
     fn billing_period_report_service(&self) -> Arc<Self::BillingPeriodReportService>;
     fn block_report_service(&self) -> Arc<Self::BlockReportService>;
     fn text_template_service(&self) -> Arc<Self::TextTemplateService>;
+    fn text_template_preview_service(&self) -> Arc<Self::TextTemplatePreviewService>;
     fn user_invitation_service(&self) -> Arc<Self::UserInvitationService>;
     fn toggle_service(&self) -> Arc<Self::ToggleService>;
     fn feature_flag_service(&self) -> Arc<Self::FeatureFlagService>;
@@ -493,9 +616,17 @@ pub trait RestStateDef: Clone + Send + Sync + 'static {
     fn vacation_entitlement_offset_service(&self) -> Arc<Self::VacationEntitlementOffsetService>;
     fn rebooking_batch_service(&self) -> Arc<Self::RebookingBatchService>;
     fn voluntary_stats_service(&self) -> Arc<Self::VoluntaryStatsService>;
+    fn rebooking_reconciliation_service(&self) -> Arc<Self::RebookingReconciliationService>;
     fn pdf_export_config_service(&self) -> Arc<Self::PdfExportConfigService>;
     fn pdf_export_scheduler(&self) -> Arc<Self::PdfExportScheduler>;
     fn pdf_shiftplan_service(&self) -> Arc<Self::PdfShiftplanService>;
+    fn scheduler_job_service(&self) -> Arc<Self::SchedulerJobService>;
+    fn scheduler_service(&self) -> Arc<Self::SchedulerService>;
+    fn qualification_service(&self) -> Arc<Self::QualificationService>;
+    fn ical_feed_token_service(&self) -> Arc<Self::IcalFeedTokenService>;
+    fn ical_import_service(&self) -> Arc<Self::IcalImportService>;
+    fn export_target_service(&self) -> Arc<Self::ExportTargetService>;
+    fn export_target_scheduler(&self) -> Arc<Self::ExportTargetScheduler>;
     fn basic_dao(&self) -> Arc<Self::BasicDao>;
 }
 
@@ -597,10 +728,22 @@ pub async fn auth_info<RestState: RestStateDef>(
         (path = "/report", api = report::ReportApiDoc),
         (path = "/shiftplan-catalog", api = shiftplan_catalog::ShiftplanCatalogApiDoc),
         (path = "/shiftplan-edit", api = shiftplan_edit::ShiftplanEditApiDoc),
+        (path = "/shiftplan-generator", api = shiftplan_generator::ShiftplanGeneratorApiDoc),
         (path = "/shiftplan-info", api = shiftplan::ShiftplanApiDoc),
+        (path = "/shift-swap", api = shift_swap::ShiftSwapApiDoc),
         (path = "/week-message", api = week_message::WeekMessageApiDoc),
         (path = "/week-status", api = week_status::WeekStatusApiDoc),
+        (path = "/notification", api = notification::NotificationApiDoc),
+        (path = "/webhook", api = webhook::WebhookApiDoc),
+        (path = "/export-target", api = export_target::ExportTargetApiDoc),
+        (path = "/payroll-wage-type", api = payroll_wage_type::PayrollWageTypeApiDoc),
+        (path = "/time-entry", api = time_entry::TimeEntryApiDoc),
+        (path = "/audit-log", api = audit_log::AuditLogApiDoc),
+        (path = "/rebooking-batch", api = rebooking_batch::RebookingBatchApiDoc),
         (path = "/permission", api = permission::PermissionApiDoc),
+        (path = "/qualification", api = qualification::QualificationApiDoc),
+        (path = "/ical-feed-token", api = ical_feed_token::IcalFeedTokenApiDoc),
+        (path = "/ical-import", api = ical_import::IcalImportApiDoc),
         (path = "/special-days", api = special_day::SpecialDayApiDoc),
         (path = "/text-templates", api = TextTemplateApiDoc),
         (path = "/user-invitation", api = UserInvitationApiDoc),
@@ -610,7 +753,9 @@ pub async fn auth_info<RestState: RestStateDef>(
         (path = "/vacation-balance", api = vacation_balance::VacationBalanceApiDoc),
         (path = "/vacation-entitlement-offset", api = vacation_entitlement_offset::VacationEntitlementOffsetApiDoc),
         (path = "/pdf-export-config", api = pdf_export_config::PdfExportConfigApiDoc),
+        (path = "/scheduler-job", api = scheduler_job::SchedulerJobApiDoc),
         (path = "/shiftplan", api = pdf_shiftplan::PdfShiftplanApiDoc),
+        (path = "/personal-pdf", api = pdf_personal::PdfPersonalApiDoc),
         (path = "/feature-flag", api = feature_flag::FeatureFlagApiDoc),
         (path = "/admin/impersonate", api = impersonate::ImpersonateApiDoc),
     )
@@ -680,19 +825,33 @@ pub async fn start_server<RestState: RestStateDef>(rest_state: RestState) {
             vacation_entitlement_offset::generate_route(),
         )
         .nest("/pdf-export-config", pdf_export_config::generate_route())
+        .nest("/export-target", export_target::generate_route())
+        .nest("/scheduler-job", scheduler_job::generate_route())
         // Phase 49 (PDF-03/PDF-04/PDF-05): On-Demand-PDF-Download.
         // `/shiftplan` als eigenständiger Prefix (frei — belegt sind nur
         // `/shiftplan-catalog`, `/shiftplan-edit`, `/shiftplan-info`).
         .nest("/shiftplan", pdf_shiftplan::generate_route())
+        .nest("/personal-pdf", pdf_personal::generate_route())
         .nest("/extra-hours", extra_hours::generate_route())
         .nest("/blocks", my_block::generate_route())
         .nest("/special-days", special_day::generate_route())
         .nest("/shiftplan-catalog", shiftplan_catalog::generate_route())
         .nest("/shiftplan-edit", shiftplan_edit::generate_route())
+        .nest("/shiftplan-generator", shiftplan_generator::generate_route())
         .nest("/shiftplan-info", shiftplan::generate_route())
+        .nest("/shift-swap", shift_swap::generate_route())
         .nest("/text-templates", text_template::generate_route())
         .nest("/week-message", week_message::generate_route())
         .nest("/week-status", week_status::generate_route())
+        .nest("/notification", notification::generate_route())
+        .nest("/webhook", webhook::generate_route())
+        .nest("/payroll-wage-type", payroll_wage_type::generate_route())
+        .nest("/time-entry", time_entry::generate_route())
+        .nest("/audit-log", audit_log::generate_route())
+        .nest("/rebooking-batch", rebooking_batch::generate_route())
+        .nest("/qualification", qualification::generate_route())
+        .nest("/ical-feed-token", ical_feed_token::generate_route())
+        .nest("/ical-import", ical_import::generate_route())
         .nest("/user-invitation", user_invitation::generate_route())
         .nest("/toggle", toggle::generate_route())
         .nest("/toggle-group", toggle::generate_group_route())
@@ -775,6 +934,11 @@ pub async fn start_server<RestState: RestStateDef>(rest_state: RestState) {
             "/auth/invitation/{token}",
             get(user_invitation::authenticate_with_invitation::<RestState>),
         )
+        // Public iCal feed for calendar apps; the secret token authorizes.
+        .route(
+            "/ical/{token}",
+            get(ical_feed_token::get_feed::<RestState>),
+        )
         .with_state(rest_state.clone())
         .layer(CookieManagerLayer::new());
 
@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    response::Response,
+    routing::{get, post, put},
+    Extension, Json, Router,
+};
+use rest_types::{
+    NotificationKindTO, NotificationMessageTO, NotificationPreferenceTO, NotificationStateTO,
+};
+use serde::Deserialize;
+use service::{
+    notification::{NotificationPreference, NotificationService},
+    ServiceError,
+};
+use tracing::instrument;
+use utoipa::{IntoParams, OpenApi};
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestError, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/preference", get(get_own_preference::<RestState>))
+        .route("/preference", put(set_own_preference::<RestState>))
+        .route("/preference/{user_id}", get(get_preference::<RestState>))
+        .route("/preference/{user_id}", put(set_preference::<RestState>))
+        .route("/outbox", get(get_outbox::<RestState>))
+        .route("/outbox/{id}/retry", post(retry::<RestState>))
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OutboxAttributes {
+    /// Maximale Anzahl Einträge (Default 100, höchstens 500).
+    #[param(example = "100")]
+    limit: Option<u32>,
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/preference",
+    tags = ["Notification"],
+    responses(
+        (status = 200, description = "Notification preference of the current user, null if none is set", body = Option<NotificationPreferenceTO>),
+    ),
+)]
+pub async fn get_own_preference<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let preference = rest_state
+                .notification_service()
+                .get_own_preference(context.into(), None)
+                .await?
+                .as_ref()
+                .map(NotificationPreferenceTO::from);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&preference).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/preference",
+    tags = ["Notification"],
+    request_body = NotificationPreferenceTO,
+    responses(
+        (status = 200, description = "Notification preference of the current user stored", body = NotificationPreferenceTO),
+        (status = 422, description = "Invalid e-mail address"),
+    ),
+)]
+pub async fn set_own_preference<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<NotificationPreferenceTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let user_id = context.clone().ok_or(ServiceError::Unauthorized)?;
+            let preference = NotificationPreference {
+                user_id,
+                ..NotificationPreference::from(&body)
+            };
+            let preference = rest_state
+                .notification_service()
+                .set_preference(&preference, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&NotificationPreferenceTO::from(&preference)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/preference/{user_id}",
+    tags = ["Notification"],
+    params(("user_id", description = "User id")),
+    responses(
+        (status = 200, description = "Notification preference of the user, null if none is set", body = Option<NotificationPreferenceTO>),
+        (status = 403, description = "Forbidden (neither HR nor the user)"),
+    ),
+)]
+pub async fn get_preference<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(user_id): Path<String>,
+) -> Response {
+    error_handler(
+        (async {
+            let preference = rest_state
+                .notification_service()
+                .get_preference(&user_id, context.into(), None)
+                .await?
+                .as_ref()
+                .map(NotificationPreferenceTO::from);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&preference).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/preference/{user_id}",
+    tags = ["Notification"],
+    params(("user_id", description = "User id")),
+    request_body = NotificationPreferenceTO,
+    responses(
+        (status = 200, description = "Notification preference stored", body = NotificationPreferenceTO),
+        (status = 400, description = "user_id in body does not match the path"),
+        (status = 403, description = "Forbidden (neither HR nor the user)"),
+        (status = 422, description = "Invalid e-mail address"),
+    ),
+)]
+pub async fn set_preference<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(user_id): Path<String>,
+    Json(body): Json<NotificationPreferenceTO>,
+) -> Response {
+    error_handler(
+        (async {
+            if !body.user_id.is_empty() && *body.user_id != *user_id {
+                return Err(RestError::BadRequest(format!(
+                    "user_id {} in path but {} in body",
+                    user_id, body.user_id
+                )));
+            }
+            let preference = NotificationPreference {
+                user_id: Arc::from(user_id),
+                ..NotificationPreference::from(&body)
+            };
+            let preference = rest_state
+                .notification_service()
+                .set_preference(&preference, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&NotificationPreferenceTO::from(&preference)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/outbox",
+    tags = ["Notification"],
+    params(OutboxAttributes),
+    responses(
+        (status = 200, description = "Most recent outbox entries, newest first", body = [NotificationMessageTO]),
+        (status = 403, description = "Forbidden (admin only)"),
+    ),
+)]
+pub async fn get_outbox<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    query: Query<OutboxAttributes>,
+) -> Response {
+    error_handler(
+        (async {
+            let messages: Arc<[NotificationMessageTO]> = rest_state
+                .notification_service()
+                .get_outbox(query.limit.unwrap_or(100), context.into(), None)
+                .await?
+                .iter()
+                .map(NotificationMessageTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&messages).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/outbox/{id}/retry",
+    tags = ["Notification"],
+    params(("id", description = "Outbox entry id")),
+    responses(
+        (status = 200, description = "Failed entry queued again", body = NotificationMessageTO),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Outbox entry not found"),
+        (status = 422, description = "Entry is not in state Failed"),
+    ),
+)]
+pub async fn retry<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let message = rest_state
+                .notification_service()
+                .retry(id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&NotificationMessageTO::from(&message)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_own_preference,
+        set_own_preference,
+        get_preference,
+        set_preference,
+        get_outbox,
+        retry,
+    ),
+    components(schemas(
+        NotificationPreferenceTO,
+        NotificationMessageTO,
+        NotificationKindTO,
+        NotificationStateTO
+    ))
+)]
+pub struct NotificationApiDoc;
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    response::Response,
+    routing::{get, put},
+    Extension, Json, Router,
+};
+use rest_types::{PayrollValueViewTO, PayrollWageTypeTO};
+use service::payroll_wage_type::{PayrollWageType, PayrollWageTypeService};
+use tracing::instrument;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestError, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route(
+            "/",
+            get(get_all_wage_types::<RestState>).post(create_wage_type::<RestState>),
+        )
+        .route(
+            "/{id}",
+            put(update_wage_type::<RestState>).delete(delete_wage_type::<RestState>),
+        )
+}
+
+fn wage_type_from_to(body: &PayrollWageTypeTO) -> Result<PayrollWageType, RestError> {
+    PayrollWageType::try_from(body).map_err(|e| RestError::BadRequest(e.to_string()))
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["Payroll Wage Type"],
+    responses(
+        (status = 200, description = "All payroll wage type mappings", body = [PayrollWageTypeTO]),
+        (status = 403, description = "Forbidden (HR only)"),
+    ),
+)]
+pub async fn get_all_wage_types<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let wage_types: Arc<[PayrollWageTypeTO]> = rest_state
+                .payroll_wage_type_service()
+                .get_all(context.into(), None)
+                .await?
+                .iter()
+                .map(PayrollWageTypeTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&wage_types).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["Payroll Wage Type"],
+    request_body = PayrollWageTypeTO,
+    responses(
+        (status = 201, description = "Payroll wage type mapping created", body = PayrollWageTypeTO),
+        (status = 400, description = "Unknown value type"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 422, description = "Wage type is not a number of up to four digits"),
+    ),
+)]
+pub async fn create_wage_type<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<PayrollWageTypeTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let wage_type = rest_state
+                .payroll_wage_type_service()
+                .create(&wage_type_from_to(&body)?, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&PayrollWageTypeTO::from(&wage_type)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tags = ["Payroll Wage Type"],
+    params(("id", description = "Payroll wage type mapping id")),
+    request_body = PayrollWageTypeTO,
+    responses(
+        (status = 200, description = "Payroll wage type mapping updated", body = PayrollWageTypeTO),
+        (status = 400, description = "Inconsistent id or unknown value type"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Payroll wage type mapping not found"),
+        (status = 409, description = "Version conflict"),
+        (status = 422, description = "Wage type is not a number of up to four digits"),
+    ),
+)]
+pub async fn update_wage_type<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<PayrollWageTypeTO>,
+) -> Response {
+    error_handler(
+        (async {
+            if !body.id.is_nil() && body.id != id {
+                return Err(RestError::InconsistentId(id, body.id));
+            }
+            let wage_type = PayrollWageType {
+                id,
+                ..wage_type_from_to(&body)?
+            };
+            let wage_type = rest_state
+                .payroll_wage_type_service()
+                .update(&wage_type, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&PayrollWageTypeTO::from(&wage_type)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tags = ["Payroll Wage Type"],
+    params(("id", description = "Payroll wage type mapping id")),
+    responses(
+        (status = 204, description = "Payroll wage type mapping deleted"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Payroll wage type mapping not found"),
+    ),
+)]
+pub async fn delete_wage_type<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .payroll_wage_type_service()
+                .delete(id, context.into(), None)
+                .await?;
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_all_wage_types,
+        create_wage_type,
+        update_wage_type,
+        delete_wage_type,
+    ),
+    components(schemas(PayrollWageTypeTO, PayrollValueViewTO))
+)]
+pub struct PayrollWageTypeApiDoc;
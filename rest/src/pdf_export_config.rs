@@ -6,6 +6,10 @@
 //!   behält den bestehenden Wert (D-48-REST). Nach dem `update` wird
 //!   `pdf_export_scheduler.reload_from_db()` aufgerufen, damit ein neuer
 //!   Cron-Ausdruck ohne Server-Restart wirksam wird (CONTEXT Q4).
+//! - `GET /shiftplan` / `PUT /shiftplan` — Export-Einstellungen je Shiftplan
+//!   (eingeschlossen, Unterordner, Dateinamen-Präfix). `PUT` ersetzt die
+//!   komplette Liste und lädt den Scheduler nicht neu — der Cron-Lauf liest
+//!   die Auswahl bei jedem Lauf frisch.
 //! - `POST /trigger` — Phase 48 Plan 04: löst genau EINEN sofortigen
 //!   Export-Lauf asynchron (`tokio::spawn`) aus. Admin-gated; Response `202
 //!   Accepted`.
@@ -22,9 +26,13 @@ use axum::{
     routing::{get, post, put},
     Extension, Json, Router,
 };
-use rest_types::PdfExportConfigTO;
+use std::sync::Arc;
+
+use rest_types::{PdfExportConfigTO, PdfExportShiftplanTO};
 use service::pdf_export::PdfExportScheduler;
-use service::pdf_export_config::{PdfExportConfigService, PdfExportConfigUpdate};
+use service::pdf_export_config::{
+    PdfExportConfigService, PdfExportConfigUpdate, PdfExportShiftplanSetting,
+};
 use service::permission::Authentication;
 use tracing::instrument;
 use utoipa::OpenApi;
@@ -35,6 +43,10 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
     Router::new()
         .route("/", get(get_config::<RestState>))
         .route("/", put(update_config::<RestState>))
+        .route(
+            "/shiftplan",
+            get(get_shiftplan_settings::<RestState>).put(update_shiftplan_settings::<RestState>),
+        )
         .route("/trigger", post(trigger_export_now::<RestState>))
 }
 
@@ -98,6 +110,8 @@ pub async fn update_config<RestState: RestStateDef>(
                 target_folder: to.target_folder,
                 weeks_horizon: to.weeks_horizon,
                 cron_schedule: to.cron_schedule,
+                include_planning_plans: to.include_planning_plans,
+                combined_pdf: to.combined_pdf,
             };
             let result = rest_state
                 .pdf_export_config_service()
@@ -121,6 +135,79 @@ pub async fn update_config<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/shiftplan",
+    tags = ["PdfExportConfig"],
+    responses(
+        (status = 200, description = "Stored per-shiftplan export settings; shiftplans without an entry are exported", body = [PdfExportShiftplanTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden — admin privilege required"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn get_shiftplan_settings<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let settings: Arc<[PdfExportShiftplanTO]> = rest_state
+                .pdf_export_config_service()
+                .get_shiftplan_settings(context.into(), None)
+                .await?
+                .iter()
+                .map(PdfExportShiftplanTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&settings).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state, body))]
+#[utoipa::path(
+    put,
+    path = "/shiftplan",
+    tags = ["PdfExportConfig"],
+    request_body = [PdfExportShiftplanTO],
+    responses(
+        (status = 200, description = "Per-shiftplan export settings replaced", body = [PdfExportShiftplanTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden — admin privilege required"),
+        (status = 422, description = "Invalid subfolder, filename prefix or duplicate shiftplan"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn update_shiftplan_settings<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<Vec<PdfExportShiftplanTO>>,
+) -> Response {
+    error_handler(
+        (async {
+            let settings: Arc<[PdfExportShiftplanSetting]> =
+                body.iter().map(PdfExportShiftplanSetting::from).collect();
+            let settings: Arc<[PdfExportShiftplanTO]> = rest_state
+                .pdf_export_config_service()
+                .update_shiftplan_settings(settings, context.into(), None)
+                .await?
+                .iter()
+                .map(PdfExportShiftplanTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&settings).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
 #[instrument(skip(rest_state))]
 #[utoipa::path(
     post,
@@ -177,8 +264,10 @@ pub async fn trigger_export_now<RestState: RestStateDef>(
     paths(
         get_config,
         update_config,
+        get_shiftplan_settings,
+        update_shiftplan_settings,
         trigger_export_now,
     ),
-    components(schemas(PdfExportConfigTO)),
+    components(schemas(PdfExportConfigTO, PdfExportShiftplanTO)),
 )]
 pub struct PdfExportConfigApiDoc;
@@ -0,0 +1,138 @@
+//! REST-Layer für die persönlichen PDF-Dokumente eines Mitarbeiters.
+//!
+//! Routen:
+//! - `GET /personal-pdf/{sales_person_id}/schedule/{year}/{week}?weeks=N` —
+//!   Dienstplan ab der ISO-Woche für `N` Wochen (Standard 4).
+//! - `GET /personal-pdf/{sales_person_id}/timesheet/{year}/{month}` —
+//!   Stundenzettel eines Kalendermonats mit Unterschriftsfeldern.
+//!
+//! Beide liefern `application/pdf` als Attachment; Permission-Prüfung im
+//! Service (`PdfPersonalService`).
+
+use axum::{
+    extract::{Path, Query, State},
+    response::Response,
+    routing::get,
+    Extension, Router,
+};
+use serde::Deserialize;
+use service::pdf_personal::PdfPersonalService;
+use tracing::instrument;
+use utoipa::{IntoParams, OpenApi};
+use uuid::Uuid;
+
+use crate::report::export_file_response;
+use crate::{error_handler, Context, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route(
+            "/{sales_person_id}/schedule/{year}/{week}",
+            get(download_schedule_pdf::<RestState>),
+        )
+        .route(
+            "/{sales_person_id}/timesheet/{year}/{month}",
+            get(download_timesheet_pdf::<RestState>),
+        )
+}
+
+fn default_schedule_weeks() -> u8 {
+    4
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ScheduleRequest {
+    /// Number of weeks starting with the given week (1..=12), 4 if omitted
+    #[serde(default = "default_schedule_weeks")]
+    weeks: u8,
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{sales_person_id}/schedule/{year}/{week}",
+    tags = ["PdfPersonal"],
+    params(
+        ("sales_person_id" = Uuid, Path, description = "Sales person ID"),
+        ("year" = u32, Path, description = "ISO year", example = "2026"),
+        ("week" = u8, Path, description = "First ISO calendar week", example = "27"),
+        ScheduleRequest
+    ),
+    responses(
+        (status = 200, description = "Personal schedule as PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Invalid week or number of weeks"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn download_schedule_pdf<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path((sales_person_id, year, week)): Path<(Uuid, u32, u8)>,
+    query: Query<ScheduleRequest>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .pdf_personal_service()
+                .render_schedule_pdf(
+                    sales_person_id,
+                    year,
+                    week,
+                    query.weeks,
+                    context.into(),
+                    None,
+                )
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{sales_person_id}/timesheet/{year}/{month}",
+    tags = ["PdfPersonal"],
+    params(
+        ("sales_person_id" = Uuid, Path, description = "Sales person ID"),
+        ("year" = u32, Path, description = "Year", example = "2026"),
+        ("month" = u8, Path, description = "Month (1..=12)", example = "3"),
+    ),
+    responses(
+        (status = 200, description = "Monthly timesheet as PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Invalid month"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn download_timesheet_pdf<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path((sales_person_id, year, month)): Path<(Uuid, u32, u8)>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .pdf_personal_service()
+                .render_timesheet_pdf(sales_person_id, year, month, context.into(), None)
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    tags(
+        (
+            name = "PdfPersonal",
+            description = "Personal schedule and monthly timesheet PDFs per employee",
+        ),
+    ),
+    paths(download_schedule_pdf, download_timesheet_pdf),
+)]
+pub struct PdfPersonalApiDoc;
@@ -0,0 +1,449 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    routing::{delete, get, post, put},
+    Extension, Json, Router,
+};
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, Response, RestStateDef};
+use rest_types::{QualificationTO, SalesPersonQualificationTO, SlotQualificationRequirementTO};
+use service::{
+    permission::Authentication,
+    qualification::{Qualification, QualificationService, SlotQualificationRequirement},
+};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/", get(get_all_qualifications::<RestState>))
+        .route("/", post(create_qualification::<RestState>))
+        .route(
+            "/sales-person",
+            get(get_all_sales_person_qualifications::<RestState>),
+        )
+        .route(
+            "/sales-person/{id}",
+            get(get_sales_person_qualifications::<RestState>),
+        )
+        .route(
+            "/sales-person/{id}",
+            put(set_sales_person_qualifications::<RestState>),
+        )
+        .route("/slot", get(get_all_slot_requirements::<RestState>))
+        .route("/slot/{id}", get(get_slot_requirements::<RestState>))
+        .route("/slot/{id}", put(set_slot_requirements::<RestState>))
+        .route("/{id}", get(get_qualification::<RestState>))
+        .route("/{id}", put(update_qualification::<RestState>))
+        .route("/{id}", delete(delete_qualification::<RestState>))
+}
+
+fn json_response(body: String) -> Response {
+    Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(axum::body::Body::new(body))
+        .unwrap()
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 200, description = "List all qualifications", body = [QualificationTO]),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn get_all_qualifications<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let qualifications: Vec<QualificationTO> = rest_state
+                .qualification_service()
+                .get_all(Authentication::Context(context), None)
+                .await?
+                .iter()
+                .map(QualificationTO::from)
+                .collect();
+            Ok(json_response(
+                serde_json::to_string(&qualifications).unwrap(),
+            ))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Qualification ID")
+    ),
+    responses(
+        (status = 200, description = "Get qualification by ID", body = QualificationTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn get_qualification<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let qualification = rest_state
+                .qualification_service()
+                .get_by_id(id, Authentication::Context(context), None)
+                .await?;
+            let to = QualificationTO::from(&qualification);
+            Ok(json_response(serde_json::to_string(&to).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = QualificationTO,
+    responses(
+        (status = 200, description = "Qualification created", body = QualificationTO),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Invalid input"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn create_qualification<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<QualificationTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let qualification = Qualification::from(&body);
+            let created = rest_state
+                .qualification_service()
+                .create(&qualification, Authentication::Context(context), None)
+                .await?;
+            let to = QualificationTO::from(&created);
+            Ok(json_response(serde_json::to_string(&to).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Qualification ID")
+    ),
+    request_body = QualificationTO,
+    responses(
+        (status = 200, description = "Qualification updated", body = QualificationTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflict"),
+        (status = 422, description = "Invalid input"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn update_qualification<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<QualificationTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let mut qualification = Qualification::from(&body);
+            qualification.id = id;
+            let updated = rest_state
+                .qualification_service()
+                .update(&qualification, Authentication::Context(context), None)
+                .await?;
+            let to = QualificationTO::from(&updated);
+            Ok(json_response(serde_json::to_string(&to).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Qualification ID")
+    ),
+    responses(
+        (status = 204, description = "Qualification deleted"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn delete_qualification<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .qualification_service()
+                .delete(id, Authentication::Context(context), None)
+                .await?;
+            Ok(Response::builder()
+                .status(204)
+                .body(axum::body::Body::empty())
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/sales-person",
+    responses(
+        (status = 200, description = "All qualification assignments", body = [SalesPersonQualificationTO]),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn get_all_sales_person_qualifications<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let assignments: Vec<SalesPersonQualificationTO> = rest_state
+                .qualification_service()
+                .get_all_sales_person_qualifications(Authentication::Context(context), None)
+                .await?
+                .iter()
+                .map(SalesPersonQualificationTO::from)
+                .collect();
+            Ok(json_response(serde_json::to_string(&assignments).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/sales-person/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Sales person ID")
+    ),
+    responses(
+        (status = 200, description = "Qualifications of the sales person", body = [QualificationTO]),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn get_sales_person_qualifications<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let qualifications: Vec<QualificationTO> = rest_state
+                .qualification_service()
+                .get_for_sales_person(id, Authentication::Context(context), None)
+                .await?
+                .iter()
+                .map(QualificationTO::from)
+                .collect();
+            Ok(json_response(
+                serde_json::to_string(&qualifications).unwrap(),
+            ))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/sales-person/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Sales person ID")
+    ),
+    request_body = [Uuid],
+    responses(
+        (status = 204, description = "Qualifications replaced"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Sales person or qualification not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn set_sales_person_qualifications<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<Arc<[Uuid]>>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .qualification_service()
+                .set_for_sales_person(id, &body, Authentication::Context(context), None)
+                .await?;
+            Ok(Response::builder()
+                .status(204)
+                .body(axum::body::Body::empty())
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/slot",
+    responses(
+        (status = 200, description = "All slot qualification requirements", body = [SlotQualificationRequirementTO]),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn get_all_slot_requirements<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let requirements: Vec<SlotQualificationRequirementTO> = rest_state
+                .qualification_service()
+                .get_all_slot_requirements(Authentication::Context(context), None)
+                .await?
+                .iter()
+                .map(SlotQualificationRequirementTO::from)
+                .collect();
+            Ok(json_response(serde_json::to_string(&requirements).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/slot/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Slot ID")
+    ),
+    responses(
+        (status = 200, description = "Qualification requirements of the slot", body = [SlotQualificationRequirementTO]),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn get_slot_requirements<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let requirements: Vec<SlotQualificationRequirementTO> = rest_state
+                .qualification_service()
+                .get_slot_requirements(id, Authentication::Context(context), None)
+                .await?
+                .iter()
+                .map(SlotQualificationRequirementTO::from)
+                .collect();
+            Ok(json_response(serde_json::to_string(&requirements).unwrap()))
+        })
+        .await,
+    )
+}
+
+#[utoipa::path(
+    put,
+    path = "/slot/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Slot ID")
+    ),
+    request_body = [SlotQualificationRequirementTO],
+    responses(
+        (status = 204, description = "Requirements replaced"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Slot or qualification not found"),
+        (status = 409, description = "Qualification listed twice"),
+        (status = 422, description = "Invalid min_count"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "qualification"
+)]
+async fn set_slot_requirements<RestState: RestStateDef>(
+    Path(id): Path<Uuid>,
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<Vec<SlotQualificationRequirementTO>>,
+) -> Response {
+    error_handler(
+        (async {
+            let requirements: Vec<SlotQualificationRequirement> = body
+                .iter()
+                .map(|requirement| SlotQualificationRequirement {
+                    slot_id: id,
+                    ..SlotQualificationRequirement::from(requirement)
+                })
+                .collect();
+            rest_state
+                .qualification_service()
+                .set_slot_requirements(id, &requirements, Authentication::Context(context), None)
+                .await?;
+            Ok(Response::builder()
+                .status(204)
+                .body(axum::body::Body::empty())
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_all_qualifications,
+        get_qualification,
+        create_qualification,
+        update_qualification,
+        delete_qualification,
+        get_all_sales_person_qualifications,
+        get_sales_person_qualifications,
+        set_sales_person_qualifications,
+        get_all_slot_requirements,
+        get_slot_requirements,
+        set_slot_requirements,
+    ),
+    components(
+        schemas(
+            QualificationTO,
+            SalesPersonQualificationTO,
+            SlotQualificationRequirementTO,
+        )
+    ),
+    tags(
+        (name = "qualification", description = "Qualification catalog, assignments and slot requirements")
+    )
+)]
+pub struct QualificationApiDoc;
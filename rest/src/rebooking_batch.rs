@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    response::Response,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use rest_types::{
+    RebookingBatchEntryTO, RebookingBatchKindTO, RebookingBatchStateTO, RebookingBatchTO,
+    RebookingRejectTO, RebookingSuggestionTO,
+};
+use service::rebooking_reconciliation::RebookingReconciliationService;
+use tracing::instrument;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/pending", get(get_pending_batches::<RestState>))
+        .route("/suggest", post(suggest_batch::<RestState>))
+        .route("/{id}", get(get_batch::<RestState>))
+        .route("/{id}/approve", post(approve_batch::<RestState>))
+        .route("/{id}/reject", post(reject_batch::<RestState>))
+}
+
+fn batch_response(status: u16, batch: &RebookingBatchTO) -> Response {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Body::new(serde_json::to_string(batch).unwrap()))
+        .unwrap()
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/pending",
+    tags = ["Rebooking"],
+    responses(
+        (status = 200, description = "Pending rebooking batches, oldest first", body = [RebookingBatchTO]),
+        (status = 403, description = "Forbidden (HR only)"),
+    ),
+)]
+pub async fn get_pending_batches<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let batches: Arc<[RebookingBatchTO]> = rest_state
+                .rebooking_reconciliation_service()
+                .find_pending(context.into(), None)
+                .await?
+                .iter()
+                .map(RebookingBatchTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&batches).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/suggest",
+    tags = ["Rebooking"],
+    request_body = RebookingSuggestionTO,
+    responses(
+        (status = 201, description = "Pending rebooking batch created", body = RebookingBatchTO),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 409, description = "An active batch already exists for the week"),
+        (status = 422, description = "Nothing to rebook or hours not positive"),
+        (status = 423, description = "Week is locked"),
+    ),
+)]
+pub async fn suggest_batch<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(suggestion): Json<RebookingSuggestionTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let batch = rest_state
+                .rebooking_reconciliation_service()
+                .suggest(
+                    suggestion.sales_person_id,
+                    suggestion.iso_year,
+                    suggestion.iso_week,
+                    suggestion.hours,
+                    context.into(),
+                    None,
+                )
+                .await?;
+            Ok(batch_response(201, &RebookingBatchTO::from(&batch)))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tags = ["Rebooking"],
+    params(("id", description = "Rebooking batch id")),
+    responses(
+        (status = 200, description = "The rebooking batch", body = RebookingBatchTO),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Batch not found"),
+    ),
+)]
+pub async fn get_batch<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let batch = rest_state
+                .rebooking_reconciliation_service()
+                .find_by_id(id, context.into(), None)
+                .await?;
+            Ok(batch_response(200, &RebookingBatchTO::from(&batch)))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/approve",
+    tags = ["Rebooking"],
+    params(("id", description = "Rebooking batch id")),
+    responses(
+        (status = 200, description = "Batch approved, extra hours written", body = RebookingBatchTO),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Batch not found"),
+        (status = 409, description = "Batch was changed concurrently"),
+        (status = 422, description = "Batch is not pending"),
+        (status = 423, description = "Week is locked"),
+    ),
+)]
+pub async fn approve_batch<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let batch = rest_state
+                .rebooking_reconciliation_service()
+                .approve(id, context.into(), None)
+                .await?;
+            Ok(batch_response(200, &RebookingBatchTO::from(&batch)))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/reject",
+    tags = ["Rebooking"],
+    params(("id", description = "Rebooking batch id")),
+    request_body = RebookingRejectTO,
+    responses(
+        (status = 200, description = "Batch rejected", body = RebookingBatchTO),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Batch not found"),
+        (status = 409, description = "Batch was changed concurrently"),
+        (status = 422, description = "Batch is not pending or reason is empty"),
+    ),
+)]
+pub async fn reject_batch<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(reject): Json<RebookingRejectTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let batch = rest_state
+                .rebooking_reconciliation_service()
+                .reject(id, &reject.reason, context.into(), None)
+                .await?;
+            Ok(batch_response(200, &RebookingBatchTO::from(&batch)))
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_pending_batches,
+        suggest_batch,
+        get_batch,
+        approve_batch,
+        reject_batch
+    ),
+    components(schemas(
+        RebookingBatchTO,
+        RebookingBatchEntryTO,
+        RebookingBatchKindTO,
+        RebookingBatchStateTO,
+        RebookingSuggestionTO,
+        RebookingRejectTO
+    ))
+)]
+pub struct RebookingBatchApiDoc;
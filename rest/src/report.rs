@@ -8,14 +8,16 @@ use axum::{
     Extension, Router,
 };
 use rest_types::{
-    EmployeeAttendanceStatisticsTO, EmployeeReportTO, EmployeeWeeklyStatisticsTO,
-    ShortEmployeeReportTO, VoluntaryStatsTO, WeekdayAttendanceTO,
+    EmployeeAttendanceStatisticsTO, EmployeeReportTO, EmployeeWeeklyStatisticsTO, ExportFormatTO,
+    ExportLanguageTO, ReportBreakdownTO, ShortEmployeeReportTO, VoluntaryStatsTO,
+    WeekdayAttendanceTO,
 };
 use serde::Deserialize;
+use service::report_export::{ExportFile, ReportExportService};
 use service::reporting::ReportingService;
 use service::voluntary_stats::VoluntaryStatsService;
 use tracing::instrument;
-use utoipa::OpenApi;
+use utoipa::{IntoParams, OpenApi};
 use uuid::Uuid;
 
 use crate::{error_handler, Context, RestStateDef};
@@ -23,6 +25,7 @@ use crate::{error_handler, Context, RestStateDef};
 pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
     Router::new()
         .route("/", get(get_short_report_for_all::<RestState>))
+        .route("/export", get(export_short_report_for_all::<RestState>))
         .route(
             "/week/{year}/{calendar_week}",
             get(get_short_week_report::<RestState>),
@@ -36,6 +39,7 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
             "/{id}/voluntary-stats",
             get(get_voluntary_stats::<RestState>),
         )
+        .route("/{id}/export", get(export_report::<RestState>))
         .route("/{id}", get(get_report::<RestState>))
 }
 
@@ -45,6 +49,37 @@ pub struct ReportRequest {
     until_week: u8,
 }
 
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReportExportRequest {
+    /// The year for the report
+    year: u32,
+    /// The week to report until
+    until_week: u8,
+    /// File format, CSV if omitted
+    #[serde(default)]
+    format: ExportFormatTO,
+    /// Language of the column headers, German if omitted
+    #[serde(default)]
+    lang: ExportLanguageTO,
+    /// Rows per week or per month (employee report only), weekly if omitted
+    #[serde(default)]
+    breakdown: ReportBreakdownTO,
+}
+
+/// Antwort für Tabellen-Exporte: Datei als Attachment.
+pub(crate) fn export_file_response(file: ExportFile) -> Response {
+    Response::builder()
+        .status(200)
+        .header("Content-Type", file.content_type)
+        .header(
+            "Content-Disposition",
+            format!("attachment; filename=\"{}\"", file.file_name),
+        )
+        .body(Body::from(file.content.to_vec()))
+        .expect("static headers + ascii filename produce a valid response")
+}
+
 /// Phase 54 Gap-Closure G1 (VOL-STAT-01 / VOL-ACCT-01/02) — Query-Parameter
 /// fuer `GET /report/{id}/voluntary-stats`. Analog
 /// `ReportingService::get_report_for_employee_range` eine echte Date-Range:
@@ -142,6 +177,90 @@ pub async fn get_report<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/export",
+    tags = ["Report"],
+    params(ReportExportRequest),
+    responses(
+        (status = 200, description = "Short report for all employees as file", content(
+            (Vec<u8> = "text/csv"),
+            (Vec<u8> = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        )),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn export_short_report_for_all<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    query: Query<ReportExportRequest>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .report_export_service()
+                .export_employee_reports(
+                    query.year,
+                    query.until_week,
+                    query.format.into(),
+                    query.lang.into(),
+                    context.into(),
+                    None,
+                )
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{id}/export",
+    tags = ["Report"],
+    params(
+        ("id" = Uuid, Path, description = "Sales person ID"),
+        ReportExportRequest
+    ),
+    responses(
+        (status = 200, description = "Report for an employee as file", content(
+            (Vec<u8> = "text/csv"),
+            (Vec<u8> = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        )),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn export_report<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    query: Query<ReportExportRequest>,
+    Path(sales_person_id): Path<Uuid>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let file = rest_state
+                .report_export_service()
+                .export_employee_report(
+                    sales_person_id,
+                    query.year,
+                    query.until_week,
+                    query.breakdown.into(),
+                    query.format.into(),
+                    query.lang.into(),
+                    context.into(),
+                    None,
+                )
+                .await?;
+            Ok(export_file_response(file))
+        })
+        .await,
+    )
+}
+
 #[instrument(skip(rest_state))]
 #[utoipa::path(
     get,
@@ -348,6 +467,8 @@ pub async fn get_voluntary_stats<RestState: RestStateDef>(
     paths(
         get_short_report_for_all,
         get_report,
+        export_short_report_for_all,
+        export_report,
         get_short_week_report,
         get_weekly_statistics,
         get_attendance_statistics,
@@ -361,7 +482,10 @@ pub async fn get_voluntary_stats<RestState: RestStateDef>(
         EmployeeAttendanceStatisticsTO,
         WeekdayAttendanceTO,
         VoluntaryStatsTO,
-        VoluntaryStatsRequest
+        VoluntaryStatsRequest,
+        ExportFormatTO,
+        ExportLanguageTO,
+        ReportBreakdownTO
     ))
 )]
 pub struct ReportApiDoc;
@@ -5,10 +5,13 @@ use axum::extract::{Path, Query};
 use axum::routing::{delete, get, post, put};
 use axum::{extract::State, response::Response};
 use axum::{Extension, Json, Router};
-use rest_types::{SalesPersonTO, SalesPersonUnavailableTO};
+use rest_types::{
+    AvailabilityKindTO, SalesPersonAvailabilityTO, SalesPersonTO, SalesPersonUnavailableTO,
+};
 use serde::Deserialize;
 use service::block::BlockService;
 use service::sales_person::SalesPersonService;
+use service::sales_person_availability::SalesPersonAvailabilityService;
 use service::sales_person_unavailable::SalesPersonUnavailableService;
 use tracing::instrument;
 use utoipa::OpenApi;
@@ -43,6 +46,22 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
             "/unavailable/{id}",
             delete(delete_sales_person_unavailable::<RestState>),
         )
+        .route(
+            "/{id}/availability",
+            get(get_sales_person_availability::<RestState>),
+        )
+        .route(
+            "/availability",
+            post(create_sales_person_availability::<RestState>),
+        )
+        .route(
+            "/availability/{id}",
+            put(update_sales_person_availability::<RestState>),
+        )
+        .route(
+            "/availability/{id}",
+            delete(delete_sales_person_availability::<RestState>),
+        )
         .route("/current", get(get_sales_person_current_user::<RestState>))
 }
 
@@ -501,6 +520,174 @@ pub async fn delete_sales_person_unavailable<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{id}/availability",
+    tags = ["Sales persons"],
+    description = "Get recurring availability rules of a sales person. With year and calendar_week only rules valid in that week are returned.",
+    params(
+        ("id", description = "Sales person ID", example = "123e4567-e89b-12d3-a456-426614174000"),
+    ),
+    responses(
+        (status = 200, description = "Get sales person availability", body = [SalesPersonAvailabilityTO]),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn get_sales_person_availability<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+    query: Query<ReportRequest>,
+) -> Response {
+    error_handler(
+        (async {
+            let tos = if let (Some(year), Some(calendar_week)) = (query.year, query.calendar_week) {
+                rest_state
+                    .sales_person_availability_service()
+                    .get_by_week_for_sales_person(
+                        sales_person_id,
+                        year,
+                        calendar_week,
+                        context.into(),
+                        None,
+                    )
+                    .await?
+                    .iter()
+                    .map(SalesPersonAvailabilityTO::from)
+                    .collect::<Vec<_>>()
+            } else {
+                rest_state
+                    .sales_person_availability_service()
+                    .get_all_for_sales_person(sales_person_id, context.into(), None)
+                    .await?
+                    .iter()
+                    .map(SalesPersonAvailabilityTO::from)
+                    .collect::<Vec<_>>()
+            };
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&tos).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/availability",
+    tags = ["Sales persons"],
+    description = "Create a recurring availability rule",
+    request_body = SalesPersonAvailabilityTO,
+    responses(
+        (status = 200, description = "Create sales person availability", body = SalesPersonAvailabilityTO),
+        (status = 422, description = "Invalid time window or validity range"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn create_sales_person_availability<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(availability): Json<SalesPersonAvailabilityTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let availability = SalesPersonAvailabilityTO::from(
+                &rest_state
+                    .sales_person_availability_service()
+                    .create(&(&availability).into(), context.into(), None)
+                    .await?,
+            );
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&availability).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/availability/{id}",
+    tags = ["Sales persons"],
+    description = "Update a recurring availability rule",
+    params(
+        ("id", description = "Sales person availability ID", example = "123e4567-e89b-12d3-a456-426614174000"),
+    ),
+    request_body = SalesPersonAvailabilityTO,
+    responses(
+        (status = 200, description = "Update sales person availability", body = SalesPersonAvailabilityTO),
+        (status = 404, description = "Sales person availability not found"),
+        (status = 409, description = "Version conflict"),
+        (status = 422, description = "Invalid time window or validity range"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn update_sales_person_availability<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(availability_id): Path<Uuid>,
+    Json(availability): Json<SalesPersonAvailabilityTO>,
+) -> Response {
+    error_handler(
+        (async {
+            if availability_id != availability.id {
+                return Err(RestError::InconsistentId(availability_id, availability.id));
+            }
+            let availability = SalesPersonAvailabilityTO::from(
+                &rest_state
+                    .sales_person_availability_service()
+                    .update(&(&availability).into(), context.into(), None)
+                    .await?,
+            );
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&availability).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    delete,
+    path = "/availability/{id}",
+    tags = ["Sales persons"],
+    description = "Delete a recurring availability rule",
+    params(
+        ("id", description = "Sales person availability ID", example = "123e4567-e89b-12d3-a456-426614174000"),
+    ),
+    responses(
+        (status = 204, description = "Delete sales person availability"),
+        (status = 404, description = "Sales person availability not found"),
+        (status = 500, description = "Internal server error"),
+    ),
+)]
+pub async fn delete_sales_person_availability<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(availability_id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .sales_person_availability_service()
+                .delete(availability_id, context.into(), None)
+                .await?;
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
 #[instrument(skip(rest_state))]
 #[utoipa::path(
     get,
@@ -595,6 +782,10 @@ pub async fn get_sales_person_by_username<RestState: RestStateDef>(
         get_sales_person_unavailable,
         create_sales_person_unavailable,
         delete_sales_person_unavailable,
+        get_sales_person_availability,
+        create_sales_person_availability,
+        update_sales_person_availability,
+        delete_sales_person_availability,
         get_sales_person_current_user,
         ical_for_sales_person,
         get_sales_person_by_username,
@@ -603,6 +794,8 @@ pub async fn get_sales_person_by_username<RestState: RestStateDef>(
         schemas(
             SalesPersonTO,
             SalesPersonUnavailableTO,
+            SalesPersonAvailabilityTO,
+            AvailabilityKindTO,
         ),
     ),
 )]
@@ -0,0 +1,198 @@
+//! REST-Layer für die Registry der Hintergrund-Jobs.
+//!
+//! Admin-gated unter `/scheduler-job`:
+//! - `GET /` — alle Jobs inkl. Status des letzten Laufs.
+//! - `GET /{name}` — ein Job.
+//! - `PUT /{name}` — setzt `cron_schedule` und `enabled`. Danach wird
+//!   `scheduler_service.reload_from_db()` aufgerufen, damit die Änderung ohne
+//!   Server-Restart wirkt.
+//! - `POST /{name}/run` — löst genau einen Lauf asynchron (`tokio::spawn`)
+//!   aus, auch für deaktivierte Jobs. Response `204 No Content`; Dauer und
+//!   Ergebnis stehen danach am Job.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    response::Response,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use rest_types::SchedulerJobTO;
+use service::permission::Authentication;
+use service::scheduler::SchedulerService;
+use service::scheduler_job::{SchedulerJobService, SchedulerJobUpdate};
+use tracing::instrument;
+use utoipa::OpenApi;
+
+use crate::{error_handler, Context, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/", get(get_all_jobs::<RestState>))
+        .route(
+            "/{name}",
+            get(get_job::<RestState>).put(update_job::<RestState>),
+        )
+        .route("/{name}/run", post(run_job_now::<RestState>))
+}
+
+fn job_response(job: &SchedulerJobTO) -> Response {
+    Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(Body::new(serde_json::to_string(job).unwrap()))
+        .unwrap()
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["SchedulerJob"],
+    responses(
+        (status = 200, description = "All scheduler jobs", body = [SchedulerJobTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden — admin privilege required"),
+    ),
+)]
+pub async fn get_all_jobs<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let jobs: Arc<[SchedulerJobTO]> = rest_state
+                .scheduler_job_service()
+                .get_all(context.into(), None)
+                .await?
+                .iter()
+                .map(SchedulerJobTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&jobs).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/{name}",
+    tags = ["SchedulerJob"],
+    params(("name", description = "Job name, e.g. `carryover`")),
+    responses(
+        (status = 200, description = "The scheduler job", body = SchedulerJobTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden — admin privilege required"),
+        (status = 404, description = "Job not found"),
+    ),
+)]
+pub async fn get_job<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(name): Path<String>,
+) -> Response {
+    error_handler(
+        (async {
+            let job = rest_state
+                .scheduler_job_service()
+                .get(&name, context.into(), None)
+                .await?;
+            Ok(job_response(&SchedulerJobTO::from(&job)))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/{name}",
+    tags = ["SchedulerJob"],
+    params(("name", description = "Job name, e.g. `carryover`")),
+    request_body = SchedulerJobTO,
+    responses(
+        (status = 200, description = "Updated scheduler job, already re-registered", body = SchedulerJobTO, content_type = "application/json"),
+        (status = 403, description = "Forbidden — admin privilege required"),
+        (status = 404, description = "Job not found"),
+        (status = 409, description = "Version conflict — job was changed in the meantime"),
+        (status = 422, description = "Invalid cron expression"),
+    ),
+)]
+pub async fn update_job<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(name): Path<String>,
+    Json(to): Json<SchedulerJobTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let update = SchedulerJobUpdate {
+                cron_schedule: to.cron_schedule,
+                enabled: to.enabled,
+                version: to.version,
+            };
+            let job = rest_state
+                .scheduler_job_service()
+                .update(&name, update, context.into(), None)
+                .await?;
+            rest_state.scheduler_service().reload_from_db().await?;
+            Ok(job_response(&SchedulerJobTO::from(&job)))
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{name}/run",
+    tags = ["SchedulerJob"],
+    params(("name", description = "Job name, e.g. `carryover`")),
+    responses(
+        (status = 204, description = "Job run started asynchronously"),
+        (status = 403, description = "Forbidden — admin privilege required"),
+        (status = 404, description = "Job not found"),
+    ),
+)]
+pub async fn run_job_now<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(name): Path<String>,
+) -> Response {
+    error_handler(
+        (async {
+            // Admin-Gate und 404 über den admin-gated Registry-Service; der
+            // Lauf selbst braucht `Authentication::Full` für `record_run`.
+            rest_state
+                .scheduler_job_service()
+                .get(&name, context.into(), None)
+                .await?;
+            let scheduler = rest_state.scheduler_service();
+            tokio::spawn(async move {
+                if let Err(e) = scheduler.run_job_now(&name, Authentication::Full).await {
+                    tracing::error!("scheduler job '{name}' run failed: {e:?}");
+                }
+            });
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    tags(
+        (
+            name = "SchedulerJob",
+            description = "Admin-gated registry of periodic background jobs",
+        ),
+    ),
+    paths(get_all_jobs, get_job, update_job, run_job_now),
+    components(schemas(SchedulerJobTO)),
+)]
+pub struct SchedulerJobApiDoc;
@@ -59,14 +59,18 @@ pub fn should_audit_impersonated_write(method: &http::Method, real_user_present:
 /// in source order means it is the inner layer, so `context_extractor` (outer)
 /// runs first — populating `Context` + `RealUser` — and then this middleware
 /// sees them when it executes.
+///
+/// The request is additionally run inside
+/// [`service::audit_log::scope_real_user`] so the entity audit trail stores
+/// the real admin next to the effective user.
 pub async fn audit_impersonated_writes(request: Request, next: Next) -> Response {
     let method = request.method().clone();
     let path = request.uri().path().to_string();
+    let real_user = request.extensions().get::<RealUser>().cloned();
 
-    if should_audit_impersonated_write(&method, request.extensions().get::<RealUser>().is_some()) {
-        let real_user_id: Arc<str> = request
-            .extensions()
-            .get::<RealUser>()
+    if should_audit_impersonated_write(&method, real_user.is_some()) {
+        let real_user_id: Arc<str> = real_user
+            .as_ref()
             .map(|r| r.0.clone())
             .unwrap_or_else(|| Arc::from("<unknown>"));
         let acting_as: Arc<str> = request
@@ -83,7 +87,12 @@ pub async fn audit_impersonated_writes(request: Request, next: Next) -> Response
         );
     }
 
-    next.run(request).await
+    match real_user {
+        Some(RealUser(real_user_id)) => {
+            service::audit_log::scope_real_user(real_user_id, next.run(request)).await
+        }
+        None => next.run(request).await,
+    }
 }
 
 #[cfg(feature = "oidc")]
@@ -278,7 +287,6 @@ pub async fn forbid_unauthenticated<RestState: RestStateDef>(
     info!("Checking authentication");
     if request.extensions().get::<Context>().is_some()
         && request.extensions().get::<Context>().unwrap().is_some()
-        || request.uri().path().ends_with("/ical")
         || request.uri().path().ends_with("/authenticate")
     {
         info!("Authenticated: {:?}", request.extensions().get::<Context>());
@@ -0,0 +1,316 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    response::Response,
+    routing::{delete, get, post},
+    Extension, Json, Router,
+};
+use rest_types::{
+    BookingTO, ShiftSwapAcceptTO, ShiftSwapApproveResultTO, ShiftSwapOfferTO, ShiftSwapRejectTO,
+    ShiftSwapRequestTO, ShiftSwapStateTO, WarningTO,
+};
+use service::shift_swap::ShiftSwapService;
+use tracing::instrument;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/", get(get_pending::<RestState>))
+        .route("/", post(offer::<RestState>))
+        .route(
+            "/sales-person/{sales_person_id}",
+            get(get_for_sales_person::<RestState>),
+        )
+        .route("/{id}/accept", post(accept::<RestState>))
+        .route("/{id}/approve", post(approve::<RestState>))
+        .route("/{id}/reject", post(reject::<RestState>))
+        .route("/{id}", delete(cancel::<RestState>))
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["Shift Swap"],
+    responses(
+        (status = 200, description = "Open and accepted swap requests", body = [ShiftSwapRequestTO]),
+        (status = 403, description = "Forbidden"),
+    ),
+)]
+pub async fn get_pending<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let requests: Arc<[ShiftSwapRequestTO]> = rest_state
+                .shift_swap_service()
+                .get_pending(context.into(), None)
+                .await?
+                .iter()
+                .map(ShiftSwapRequestTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&requests).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/sales-person/{sales_person_id}",
+    tags = ["Shift Swap"],
+    params(("sales_person_id", description = "Sales person id")),
+    responses(
+        (status = 200, description = "Swap requests offered or accepted by the sales person", body = [ShiftSwapRequestTO]),
+        (status = 403, description = "Forbidden"),
+    ),
+)]
+pub async fn get_for_sales_person<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let requests: Arc<[ShiftSwapRequestTO]> = rest_state
+                .shift_swap_service()
+                .get_for_sales_person(sales_person_id, context.into(), None)
+                .await?
+                .iter()
+                .map(ShiftSwapRequestTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&requests).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["Shift Swap"],
+    request_body = ShiftSwapOfferTO,
+    responses(
+        (status = 201, description = "Booking offered", body = ShiftSwapRequestTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Booking not found"),
+        (status = 409, description = "A pending swap request for this booking already exists"),
+        (status = 423, description = "Week is locked — changes are not possible"),
+    ),
+)]
+pub async fn offer<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<ShiftSwapOfferTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let request = rest_state
+                .shift_swap_service()
+                .offer(body.booking_id, body.message, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ShiftSwapRequestTO::from(&request)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/accept",
+    tags = ["Shift Swap"],
+    params(("id", description = "Swap request id")),
+    request_body = ShiftSwapAcceptTO,
+    responses(
+        (status = 200, description = "Swap request accepted", body = ShiftSwapRequestTO),
+        (status = 403, description = "Forbidden or not eligible for the shiftplan"),
+        (status = 404, description = "Swap request not found"),
+        (status = 422, description = "Request is not open or acceptor is the offerer"),
+        (status = 423, description = "Week is locked — changes are not possible"),
+    ),
+)]
+pub async fn accept<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<ShiftSwapAcceptTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let request = rest_state
+                .shift_swap_service()
+                .accept(id, body.sales_person_id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ShiftSwapRequestTO::from(&request)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/approve",
+    tags = ["Shift Swap"],
+    params(("id", description = "Swap request id")),
+    responses(
+        (status = 200, description = "Booking moved (with cross-source warnings if any)", body = ShiftSwapApproveResultTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Swap request not found"),
+        (status = 409, description = "Paid employee limit exceeded — booking blocked"),
+        (status = 422, description = "Request has not been accepted"),
+        (status = 423, description = "Week is locked — changes are not possible"),
+    ),
+)]
+pub async fn approve<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let result = rest_state
+                .shift_swap_service()
+                .approve(id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ShiftSwapApproveResultTO::from(&result)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{id}/reject",
+    tags = ["Shift Swap"],
+    params(("id", description = "Swap request id")),
+    request_body = ShiftSwapRejectTO,
+    responses(
+        (status = 200, description = "Swap request rejected", body = ShiftSwapRequestTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Swap request not found"),
+        (status = 422, description = "Request is already decided"),
+    ),
+)]
+pub async fn reject<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<ShiftSwapRejectTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let request = rest_state
+                .shift_swap_service()
+                .reject(id, body.reason, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ShiftSwapRequestTO::from(&request)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tags = ["Shift Swap"],
+    params(("id", description = "Swap request id")),
+    responses(
+        (status = 200, description = "Swap request cancelled", body = ShiftSwapRequestTO),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Swap request not found"),
+        (status = 422, description = "Request is already decided"),
+    ),
+)]
+pub async fn cancel<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let request = rest_state
+                .shift_swap_service()
+                .cancel(id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&ShiftSwapRequestTO::from(&request)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_pending,
+        get_for_sales_person,
+        offer,
+        accept,
+        approve,
+        reject,
+        cancel,
+    ),
+    components(schemas(
+        ShiftSwapRequestTO,
+        ShiftSwapStateTO,
+        ShiftSwapOfferTO,
+        ShiftSwapAcceptTO,
+        ShiftSwapRejectTO,
+        ShiftSwapApproveResultTO,
+        BookingTO,
+        WarningTO,
+    )),
+    tags(
+        (name = "Shift Swap", description = "Shift swap and give-away marketplace"),
+    ),
+)]
+pub struct ShiftSwapApiDoc;
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    response::Response,
+    routing::post,
+    Extension, Json, Router,
+};
+use rest_types::{
+    BookingTO, CopyWeekResultTO, ProposedHoursTO, ShiftplanProposalTO, UnfilledSlotTO, WarningTO,
+};
+use service::shiftplan_generator::ShiftplanGeneratorService;
+use tracing::instrument;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route(
+            "/{shiftplan_id}/{year}/{week}",
+            post(propose_week::<RestState>),
+        )
+        .route(
+            "/{shiftplan_id}/{year}/{week}/accept",
+            post(accept_proposal::<RestState>),
+        )
+}
+
+/// Erzeugt einen Entwurf für die offenen Plätze der Woche. Es wird nichts
+/// persistiert; die Warnings stammen aus einem zurückgerollten Trockenlauf.
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{shiftplan_id}/{year}/{week}",
+    tags = ["ShiftplanGenerator"],
+    params(
+        ("shiftplan_id" = Uuid, Path, description = "Shiftplan id"),
+        ("year" = u32, Path, description = "ISO year"),
+        ("week" = u8, Path, description = "ISO week"),
+    ),
+    responses(
+        (status = 200, description = "Draft bookings (with cross-source warnings if any)", body = ShiftplanProposalTO),
+        (status = 403, description = "Forbidden"),
+        (status = 423, description = "Week is locked — changes are not possible"),
+    ),
+)]
+pub async fn propose_week<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path((shiftplan_id, year, week)): Path<(Uuid, u32, u8)>,
+) -> Response {
+    error_handler(
+        (async {
+            let proposal = rest_state
+                .shiftplan_generator_service()
+                .propose_week(shiftplan_id, year, week, context.into(), None)
+                .await?;
+            let to = ShiftplanProposalTO::from(&proposal);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&to).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+/// Bucht die (ggf. gekürzte) Booking-Liste eines Entwurfs in EINER
+/// Transaktion über den konflikt-aware Buchungspfad.
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/{shiftplan_id}/{year}/{week}/accept",
+    tags = ["ShiftplanGenerator"],
+    params(
+        ("shiftplan_id" = Uuid, Path, description = "Shiftplan id"),
+        ("year" = u32, Path, description = "ISO year"),
+        ("week" = u8, Path, description = "ISO week"),
+    ),
+    request_body = Vec<BookingTO>,
+    responses(
+        (status = 200, description = "Bookings persisted (with cross-source warnings if any)", body = CopyWeekResultTO),
+        (status = 403, description = "Forbidden"),
+        (status = 409, description = "Paid employee limit exceeded — booking blocked"),
+        (status = 422, description = "Booking does not belong to the shiftplan week"),
+        (status = 423, description = "Week is locked — changes are not possible"),
+    ),
+)]
+pub async fn accept_proposal<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path((shiftplan_id, year, week)): Path<(Uuid, u32, u8)>,
+    Json(bookings): Json<Vec<BookingTO>>,
+) -> Response {
+    error_handler(
+        (async {
+            let bookings: Arc<[service::booking::Booking]> = bookings
+                .iter()
+                .map(service::booking::Booking::from)
+                .collect();
+            let result = rest_state
+                .shiftplan_generator_service()
+                .accept_proposal(shiftplan_id, year, week, bookings, context.into(), None)
+                .await?;
+            let to = CopyWeekResultTO::from(&result);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&to).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(propose_week, accept_proposal),
+    components(schemas(
+        BookingTO,
+        CopyWeekResultTO,
+        ShiftplanProposalTO,
+        UnfilledSlotTO,
+        ProposedHoursTO,
+        WarningTO,
+    )),
+    tags(
+        (name = "ShiftplanGenerator", description = "Automatic shift-plan generator"),
+    ),
+)]
+pub struct ShiftplanGeneratorApiDoc;
@@ -1,17 +1,19 @@
 use axum::{
     body::Body,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::Response,
     routing::{delete, get, post},
     Extension, Json, Router,
 };
-use rest_types::SpecialDayTO;
+use rest_types::{HolidayCandidateTO, HolidayRegionTO, SpecialDayTO};
+use serde::Deserialize;
 use std::sync::Arc;
 use tracing::instrument;
-use utoipa::OpenApi;
+use utoipa::{IntoParams, OpenApi};
 use uuid::Uuid;
 
 use crate::{error_handler, Context, RestStateDef};
+use service::holiday_calendar::HolidayCalendarService;
 use service::special_days::SpecialDayService;
 
 pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
@@ -26,6 +28,19 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
         )
         .route("/", post(create_special_days::<RestState>))
         .route("/{id}", delete(delete_special_day::<RestState>))
+        .route(
+            "/holiday-calendar/{year}",
+            get(preview_holiday_calendar::<RestState>).post(generate_holiday_calendar::<RestState>),
+        )
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HolidayCalendarRequest {
+    /// ISO 3166-2 region (`DE`, `DE-BY`, ...), nationwide holidays only if omitted
+    #[serde(default)]
+    #[param(value_type = Option<HolidayRegionTO>)]
+    region: HolidayRegionTO,
 }
 
 #[instrument(skip(rest_state))]
@@ -164,6 +179,86 @@ pub async fn delete_special_day<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/holiday-calendar/{year}",
+    tags = ["Special Days"],
+    params(
+        ("year" = u32, Path, description = "The year"),
+        HolidayCalendarRequest
+    ),
+    responses(
+        (status = 200, description = "Public holidays of the year and the special days already existing on them", body = [HolidayCandidateTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden - shiftplanner permission required"),
+        (status = 422, description = "Year not supported"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn preview_holiday_calendar<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(year): Path<u32>,
+    query: Query<HolidayCalendarRequest>,
+) -> Response {
+    error_handler(
+        (async {
+            let candidates: Arc<[HolidayCandidateTO]> = rest_state
+                .holiday_calendar_service()
+                .preview(year, query.region.into(), context.into())
+                .await?
+                .iter()
+                .map(HolidayCandidateTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .body(Body::new(serde_json::to_string(&candidates).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/holiday-calendar/{year}",
+    tags = ["Special Days"],
+    params(
+        ("year" = u32, Path, description = "The year"),
+        HolidayCalendarRequest
+    ),
+    responses(
+        (status = 200, description = "Create the missing public holidays of the year, returns only the created special days", body = [SpecialDayTO], content_type = "application/json"),
+        (status = 403, description = "Forbidden - shiftplanner permission required"),
+        (status = 422, description = "Year not supported"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn generate_holiday_calendar<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(year): Path<u32>,
+    query: Query<HolidayCalendarRequest>,
+) -> Response {
+    error_handler(
+        (async {
+            let special_days: Arc<[SpecialDayTO]> = rest_state
+                .holiday_calendar_service()
+                .generate(year, query.region.into(), context.into())
+                .await?
+                .iter()
+                .map(SpecialDayTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .body(Body::new(serde_json::to_string(&special_days).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
 #[derive(OpenApi)]
 #[openapi(
     tags(
@@ -173,8 +268,10 @@ pub async fn delete_special_day<RestState: RestStateDef>(
         get_special_days_for_week,
         get_special_days_for_year,
         create_special_days,
-        delete_special_day
+        delete_special_day,
+        preview_holiday_calendar,
+        generate_holiday_calendar
     ),
-    components(schemas(SpecialDayTO))
+    components(schemas(SpecialDayTO, HolidayCandidateTO, HolidayRegionTO))
 )]
 pub struct SpecialDayApiDoc;
@@ -5,8 +5,13 @@ use axum::extract::Path;
 use axum::routing::{delete, get, post, put};
 use axum::{extract::State, response::Response};
 use axum::{Extension, Json, Router};
-use rest_types::{CreateTextTemplateRequestTO, TextTemplateTO, UpdateTextTemplateRequestTO};
+use rest_types::{
+    CreateTextTemplateRequestTO, PreviewTextTemplateRequestTO, TemplatePreviewSourceTO,
+    TemplateSyntaxErrorTO, TextTemplatePreviewTO, TextTemplateTO, TextTemplateValidationTO,
+    UpdateTextTemplateRequestTO, ValidateTextTemplateRequestTO,
+};
 use service::text_template::TextTemplateService;
+use service::text_template_preview::{TemplatePreviewSource, TextTemplatePreviewService};
 use tracing::instrument;
 use utoipa::OpenApi;
 use uuid::Uuid;
@@ -21,6 +26,8 @@ pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
         .route("/{id}", put(update_text_template::<RestState>))
         .route("/{id}", delete(delete_text_template::<RestState>))
         .route("/by-type/{template_type}", get(get_text_templates_by_type::<RestState>))
+        .route("/validate", post(validate_text_template::<RestState>))
+        .route("/preview", post(preview_text_template::<RestState>))
 }
 
 #[instrument(skip(rest_state))]
@@ -150,6 +157,7 @@ pub async fn get_text_templates_by_type<RestState: RestStateDef>(
         (status = 400, description = "Invalid request"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - HR permission required"),
+        (status = 422, description = "Template syntax error"),
         (status = 500, description = "Internal server error"),
     ),
     security(("bearer_auth" = []))
@@ -206,6 +214,7 @@ pub async fn create_text_template<RestState: RestStateDef>(
         (status = 400, description = "Inconsistent ID"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - HR permission required"),
+        (status = 422, description = "Template syntax error"),
         (status = 500, description = "Internal server error"),
     ),
     security(("bearer_auth" = []))
@@ -288,6 +297,108 @@ pub async fn delete_text_template<RestState: RestStateDef>(
     )
 }
 
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/validate",
+    tags = ["Text Templates"],
+    description = "Compile a template with its engine without saving it",
+    request_body = ValidateTextTemplateRequestTO,
+    responses(
+        (status = 200, description = "Validation result, the first syntax error with line and column", body = TextTemplateValidationTO),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - HR permission required"),
+        (status = 500, description = "Internal server error"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn validate_text_template<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(payload): Json<ValidateTextTemplateRequestTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let error = rest_state
+                .text_template_service()
+                .validate(
+                    &payload.template_text,
+                    &service::text_template::TemplateEngine::from(&payload.template_engine),
+                    context.into(),
+                )
+                .await?;
+            let validation = TextTemplateValidationTO {
+                valid: error.is_none(),
+                error: error.as_ref().map(TemplateSyntaxErrorTO::from),
+            };
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&validation).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/preview",
+    tags = ["Text Templates"],
+    description = "Render a template against sample or real data of its template type",
+    request_body = PreviewTextTemplateRequestTO,
+    responses(
+        (status = 200, description = "Rendered output and available variables", body = TextTemplatePreviewTO),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - HR permission required"),
+        (status = 404, description = "Billing period not found"),
+        (status = 422, description = "Syntax or render error, unknown template type or missing billing period"),
+        (status = 500, description = "Internal server error"),
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn preview_text_template<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(payload): Json<PreviewTextTemplateRequestTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let template = service::text_template::TextTemplate {
+                id: Uuid::nil(),
+                name: payload.name,
+                template_type: payload.template_type,
+                template_text: payload.template_text,
+                template_engine: service::text_template::TemplateEngine::from(&payload.template_engine),
+                created_at: None,
+                created_by: None,
+                deleted: None,
+                deleted_by: None,
+                version: Uuid::nil(),
+            };
+            let source = match payload.source {
+                TemplatePreviewSourceTO::Sample => TemplatePreviewSource::Sample,
+                TemplatePreviewSourceTO::Live => TemplatePreviewSource::Live {
+                    billing_period_id: payload.billing_period_id,
+                },
+            };
+            let preview = TextTemplatePreviewTO::from(
+                &rest_state
+                    .text_template_preview_service()
+                    .preview(&template, source, context.into(), None)
+                    .await?,
+            );
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&preview).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
 #[derive(OpenApi)]
 #[openapi(
     tags(
@@ -300,12 +411,20 @@ pub async fn delete_text_template<RestState: RestStateDef>(
         create_text_template,
         update_text_template,
         delete_text_template,
+        validate_text_template,
+        preview_text_template,
     ),
     components(
         schemas(
             TextTemplateTO,
             CreateTextTemplateRequestTO,
             UpdateTextTemplateRequestTO,
+            ValidateTextTemplateRequestTO,
+            TemplateSyntaxErrorTO,
+            TextTemplateValidationTO,
+            PreviewTextTemplateRequestTO,
+            TemplatePreviewSourceTO,
+            TextTemplatePreviewTO,
         ),
     ),
 )]
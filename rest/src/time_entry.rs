@@ -0,0 +1,367 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    response::Response,
+    routing::{get, post, put},
+    Extension, Json, Router,
+};
+use rest_types::{ClockInTO, TimeEntrySourceTO, TimeEntryTO, TimeReconciliationDayTO};
+use serde::Deserialize;
+use service::time_entry::{TimeEntry, TimeEntryService};
+use tracing::instrument;
+use utoipa::{IntoParams, OpenApi};
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestError, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route("/", post(create_time_entry::<RestState>))
+        .route(
+            "/{id}",
+            put(update_time_entry::<RestState>).delete(delete_time_entry::<RestState>),
+        )
+        .route(
+            "/sales-person/{sales_person_id}",
+            get(get_time_entries::<RestState>),
+        )
+        .route(
+            "/sales-person/{sales_person_id}/open",
+            get(get_open_time_entry::<RestState>),
+        )
+        .route(
+            "/sales-person/{sales_person_id}/clock-in",
+            post(clock_in::<RestState>),
+        )
+        .route(
+            "/sales-person/{sales_person_id}/clock-out",
+            post(clock_out::<RestState>),
+        )
+        .route(
+            "/sales-person/{sales_person_id}/reconciliation",
+            get(get_reconciliation::<RestState>),
+        )
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DateRangeAttributes {
+    /// Erster Tag (inklusive).
+    #[param(value_type = String, format = Date, example = "2026-10-12")]
+    from: time::Date,
+    /// Letzter Tag (inklusive).
+    #[param(value_type = String, format = Date, example = "2026-10-18")]
+    to: time::Date,
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/sales-person/{sales_person_id}",
+    tags = ["Time Entry"],
+    params(("sales_person_id", description = "Sales person id"), DateRangeAttributes),
+    responses(
+        (status = 200, description = "Time entries starting in the range", body = [TimeEntryTO]),
+        (status = 403, description = "Forbidden (HR or the sales person only)"),
+        (status = 422, description = "from is after to"),
+    ),
+)]
+pub async fn get_time_entries<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+    query: Query<DateRangeAttributes>,
+) -> Response {
+    error_handler(
+        (async {
+            let entries: Arc<[TimeEntryTO]> = rest_state
+                .time_entry_service()
+                .get_for_sales_person(sales_person_id, query.from, query.to, context.into(), None)
+                .await?
+                .iter()
+                .map(TimeEntryTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&entries).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/sales-person/{sales_person_id}/open",
+    tags = ["Time Entry"],
+    params(("sales_person_id", description = "Sales person id")),
+    responses(
+        (status = 200, description = "The open time entry, null if not clocked in", body = Option<TimeEntryTO>),
+        (status = 403, description = "Forbidden (HR or the sales person only)"),
+    ),
+)]
+pub async fn get_open_time_entry<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let entry = rest_state
+                .time_entry_service()
+                .get_open(sales_person_id, context.into(), None)
+                .await?
+                .as_ref()
+                .map(TimeEntryTO::from);
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&entry).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/sales-person/{sales_person_id}/clock-in",
+    tags = ["Time Entry"],
+    params(("sales_person_id", description = "Sales person id")),
+    request_body = ClockInTO,
+    responses(
+        (status = 201, description = "Clocked in, the new open time entry", body = TimeEntryTO),
+        (status = 403, description = "Forbidden (HR or the sales person only)"),
+        (status = 409, description = "Already clocked in"),
+        (status = 422, description = "Booking does not belong to the sales person"),
+    ),
+)]
+pub async fn clock_in<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+    body: Option<Json<ClockInTO>>,
+) -> Response {
+    error_handler(
+        (async {
+            let booking_id = body.and_then(|Json(body)| body.booking_id);
+            let entry = rest_state
+                .time_entry_service()
+                .clock_in(sales_person_id, booking_id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&TimeEntryTO::from(&entry)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/sales-person/{sales_person_id}/clock-out",
+    tags = ["Time Entry"],
+    params(("sales_person_id", description = "Sales person id")),
+    responses(
+        (status = 200, description = "Clocked out, the closed time entry", body = TimeEntryTO),
+        (status = 403, description = "Forbidden (HR or the sales person only)"),
+        (status = 404, description = "Not clocked in"),
+    ),
+)]
+pub async fn clock_out<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let entry = rest_state
+                .time_entry_service()
+                .clock_out(sales_person_id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&TimeEntryTO::from(&entry)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["Time Entry"],
+    request_body = TimeEntryTO,
+    responses(
+        (status = 201, description = "Time entry recorded", body = TimeEntryTO),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 409, description = "Overlaps another time entry"),
+        (status = 422, description = "End before start or unknown booking"),
+    ),
+)]
+pub async fn create_time_entry<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<TimeEntryTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let entry = rest_state
+                .time_entry_service()
+                .create(&TimeEntry::from(&body), context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&TimeEntryTO::from(&entry)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tags = ["Time Entry"],
+    params(("id", description = "Time entry id")),
+    request_body = TimeEntryTO,
+    responses(
+        (status = 200, description = "Time entry corrected", body = TimeEntryTO),
+        (status = 400, description = "Inconsistent id"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Time entry not found"),
+        (status = 409, description = "Version conflict or overlapping entry"),
+        (status = 422, description = "End before start or unknown booking"),
+    ),
+)]
+pub async fn update_time_entry<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<TimeEntryTO>,
+) -> Response {
+    error_handler(
+        (async {
+            if !body.id.is_nil() && body.id != id {
+                return Err(RestError::InconsistentId(id, body.id));
+            }
+            let entry = TimeEntry {
+                id,
+                ..TimeEntry::from(&body)
+            };
+            let entry = rest_state
+                .time_entry_service()
+                .update(&entry, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&TimeEntryTO::from(&entry)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tags = ["Time Entry"],
+    params(("id", description = "Time entry id")),
+    responses(
+        (status = 204, description = "Time entry deleted"),
+        (status = 403, description = "Forbidden (HR only)"),
+        (status = 404, description = "Time entry not found"),
+    ),
+)]
+pub async fn delete_time_entry<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .time_entry_service()
+                .delete(id, context.into(), None)
+                .await?;
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/sales-person/{sales_person_id}/reconciliation",
+    tags = ["Time Entry"],
+    params(("sales_person_id", description = "Sales person id"), DateRangeAttributes),
+    responses(
+        (status = 200, description = "Planned versus actual hours for every day of the range", body = [TimeReconciliationDayTO]),
+        (status = 403, description = "Forbidden (HR or the sales person only)"),
+        (status = 422, description = "from is after to or the range exceeds a year"),
+    ),
+)]
+pub async fn get_reconciliation<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(sales_person_id): Path<Uuid>,
+    query: Query<DateRangeAttributes>,
+) -> Response {
+    error_handler(
+        (async {
+            let days: Arc<[TimeReconciliationDayTO]> = rest_state
+                .time_entry_service()
+                .reconcile(sales_person_id, query.from, query.to, context.into(), None)
+                .await?
+                .iter()
+                .map(TimeReconciliationDayTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&days).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_time_entries,
+        get_open_time_entry,
+        clock_in,
+        clock_out,
+        create_time_entry,
+        update_time_entry,
+        delete_time_entry,
+        get_reconciliation,
+    ),
+    components(schemas(TimeEntryTO, TimeEntrySourceTO, ClockInTO, TimeReconciliationDayTO))
+)]
+pub struct TimeEntryApiDoc;
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use axum::extract::{Path, Request, State};
 use axum::response::{IntoResponse, Redirect, Response};
 use axum::routing::{get, post};
@@ -153,6 +155,7 @@ pub async fn generate_invitation<RestState: RestStateDef>(
                 .user_invitation_service()
                 .generate_invitation(
                     &request.username,
+                    request.email.as_deref().map(Arc::from),
                     expiration_hours,
                     None,
                     Authentication::Context(auth_context),
@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    response::Response,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use rest_types::{
+    WebhookDeliveryStateTO, WebhookDeliveryTO, WebhookEventTypeTO, WebhookSubscriptionTO,
+};
+use serde::Deserialize;
+use service::webhook::{WebhookService, WebhookSubscription};
+use tracing::instrument;
+use utoipa::{IntoParams, OpenApi};
+use uuid::Uuid;
+
+use crate::{error_handler, Context, RestError, RestStateDef};
+
+pub fn generate_route<RestState: RestStateDef>() -> Router<RestState> {
+    Router::new()
+        .route(
+            "/subscription",
+            get(get_subscriptions::<RestState>).post(create_subscription::<RestState>),
+        )
+        .route(
+            "/subscription/{id}",
+            get(get_subscription::<RestState>)
+                .put(update_subscription::<RestState>)
+                .delete(delete_subscription::<RestState>),
+        )
+        .route(
+            "/subscription/{id}/delivery",
+            get(get_deliveries::<RestState>),
+        )
+        .route("/delivery/{id}/retry", post(retry_delivery::<RestState>))
+}
+
+#[derive(Clone, Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeliveryAttributes {
+    /// Maximale Anzahl Einträge (Default 100, höchstens 500).
+    #[param(example = "100")]
+    limit: Option<u32>,
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/subscription",
+    tags = ["Webhook"],
+    responses(
+        (status = 200, description = "All webhook subscriptions", body = [WebhookSubscriptionTO]),
+        (status = 403, description = "Forbidden (admin only)"),
+    ),
+)]
+pub async fn get_subscriptions<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+) -> Response {
+    error_handler(
+        (async {
+            let subscriptions: Arc<[WebhookSubscriptionTO]> = rest_state
+                .webhook_service()
+                .get_subscriptions(context.into(), None)
+                .await?
+                .iter()
+                .map(WebhookSubscriptionTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&subscriptions).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/subscription/{id}",
+    tags = ["Webhook"],
+    params(("id", description = "Subscription id")),
+    responses(
+        (status = 200, description = "Webhook subscription", body = WebhookSubscriptionTO),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Subscription not found"),
+    ),
+)]
+pub async fn get_subscription<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let subscription = rest_state
+                .webhook_service()
+                .get_subscription(id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&WebhookSubscriptionTO::from(&subscription)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/subscription",
+    tags = ["Webhook"],
+    request_body = WebhookSubscriptionTO,
+    responses(
+        (status = 201, description = "Subscription created, including the generated secret", body = WebhookSubscriptionTO),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 422, description = "Invalid url or no event types"),
+    ),
+)]
+pub async fn create_subscription<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Json(body): Json<WebhookSubscriptionTO>,
+) -> Response {
+    error_handler(
+        (async {
+            let subscription = rest_state
+                .webhook_service()
+                .create_subscription(&WebhookSubscription::from(&body), context.into(), None)
+                .await?;
+            // Einzige Antwort mit dem Schlüssel; danach bleibt er serverseitig.
+            let subscription = WebhookSubscriptionTO {
+                secret: Some(subscription.secret.clone()),
+                ..WebhookSubscriptionTO::from(&subscription)
+            };
+            Ok(Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&subscription).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    put,
+    path = "/subscription/{id}",
+    tags = ["Webhook"],
+    params(("id", description = "Subscription id")),
+    request_body = WebhookSubscriptionTO,
+    responses(
+        (status = 200, description = "Subscription updated", body = WebhookSubscriptionTO),
+        (status = 400, description = "Inconsistent id"),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Subscription not found"),
+        (status = 409, description = "Version conflict"),
+        (status = 422, description = "Invalid url or no event types"),
+    ),
+)]
+pub async fn update_subscription<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<WebhookSubscriptionTO>,
+) -> Response {
+    error_handler(
+        (async {
+            if !body.id.is_nil() && body.id != id {
+                return Err(RestError::InconsistentId(id, body.id));
+            }
+            let subscription = WebhookSubscription {
+                id,
+                ..WebhookSubscription::from(&body)
+            };
+            let subscription = rest_state
+                .webhook_service()
+                .update_subscription(&subscription, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&WebhookSubscriptionTO::from(&subscription)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    delete,
+    path = "/subscription/{id}",
+    tags = ["Webhook"],
+    params(("id", description = "Subscription id")),
+    responses(
+        (status = 204, description = "Subscription deleted"),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Subscription not found"),
+    ),
+)]
+pub async fn delete_subscription<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            rest_state
+                .webhook_service()
+                .delete_subscription(id, context.into(), None)
+                .await?;
+            Ok(Response::builder().status(204).body(Body::empty()).unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    get,
+    path = "/subscription/{id}/delivery",
+    tags = ["Webhook"],
+    params(("id", description = "Subscription id"), DeliveryAttributes),
+    responses(
+        (status = 200, description = "Most recent deliveries of the subscription, newest first", body = [WebhookDeliveryTO]),
+        (status = 403, description = "Forbidden (admin only)"),
+    ),
+)]
+pub async fn get_deliveries<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+    query: Query<DeliveryAttributes>,
+) -> Response {
+    error_handler(
+        (async {
+            let deliveries: Arc<[WebhookDeliveryTO]> = rest_state
+                .webhook_service()
+                .get_deliveries(id, query.limit.unwrap_or(100), context.into(), None)
+                .await?
+                .iter()
+                .map(WebhookDeliveryTO::from)
+                .collect();
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(serde_json::to_string(&deliveries).unwrap()))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[instrument(skip(rest_state))]
+#[utoipa::path(
+    post,
+    path = "/delivery/{id}/retry",
+    tags = ["Webhook"],
+    params(("id", description = "Delivery id")),
+    responses(
+        (status = 200, description = "Failed delivery queued again", body = WebhookDeliveryTO),
+        (status = 403, description = "Forbidden (admin only)"),
+        (status = 404, description = "Delivery not found"),
+        (status = 422, description = "Delivery is not in state Failed"),
+    ),
+)]
+pub async fn retry_delivery<RestState: RestStateDef>(
+    rest_state: State<RestState>,
+    Extension(context): Extension<Context>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    error_handler(
+        (async {
+            let delivery = rest_state
+                .webhook_service()
+                .retry_delivery(id, context.into(), None)
+                .await?;
+            Ok(Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(Body::new(
+                    serde_json::to_string(&WebhookDeliveryTO::from(&delivery)).unwrap(),
+                ))
+                .unwrap())
+        })
+        .await,
+    )
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_subscriptions,
+        get_subscription,
+        create_subscription,
+        update_subscription,
+        delete_subscription,
+        get_deliveries,
+        retry_delivery,
+    ),
+    components(schemas(
+        WebhookSubscriptionTO,
+        WebhookDeliveryTO,
+        WebhookEventTypeTO,
+        WebhookDeliveryStateTO
+    ))
+)]
+pub struct WebhookApiDoc;
@@ -68,12 +68,26 @@ const ALLOWED_CONTENT_TYPES: &[&str] = &[
     //   - rest/src/toggle.rs             GET /toggle/{name}/enabled  (bool as text)
     //   - rest/src/toggle.rs             GET /toggle/{name}/value    (raw string value)
     //   - rest/src/sales_person.rs       GET /sales-person/{id}/user (username as text)
+    //   - rest/src/billing_period.rs     GET /billing-period/{id}/payroll-export (DATEV-LODAS-Datei)
     "text/plain",
     // Phase 49 (PDF-03): On-demand Wochen-PDF-Download.
     //   - rest/src/pdf_shiftplan.rs      GET /shiftplan/{shiftplan_id}/{year}/{week}/pdf
     // Handler setzt `Content-Type: application/pdf` + Content-Disposition
     // (Attachment mit `schichtplan-{JJJJ}-KW{NN:02}.pdf`).
+    // Persönliche PDFs eines Mitarbeiters:
+    //   - rest/src/pdf_personal.rs       GET /personal-pdf/{sales_person_id}/schedule/{year}/{week}
+    //   - rest/src/pdf_personal.rs       GET /personal-pdf/{sales_person_id}/timesheet/{year}/{month}
+    // Custom-Report als PDF (Template-Typ `billing-period-pdf`):
+    //   - rest/src/billing_period.rs     POST /billing-period/{id}/custom-report/{template_id}/pdf
     "application/pdf",
+    // Tabellen-Export (CSV/XLSX) von Reports und Abrechnungszeiträumen:
+    //   - rest/src/report.rs             GET /report/export
+    //   - rest/src/report.rs             GET /report/{id}/export
+    //   - rest/src/billing_period.rs     GET /billing-period/{id}/export
+    // Handler setzen den Content-Type je nach `?format=` + Content-Disposition
+    // (Attachment, ASCII-Dateiname aus `ReportExportService`).
+    "text/csv",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
 ];
 
 /// Endpoints known to declare a 2xx response (200 or 201) without a response
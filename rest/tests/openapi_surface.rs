@@ -55,6 +55,9 @@ const EXPECTED_PATHS: &[&str] = &[
     "/absence-period",
     "/absence-period/{id}",
     "/absence-period/by-sales-person/{sales_person_id}",
+    // Urlaubsantrag — Übersicht offener Anträge + Genehmigung.
+    "/absence-period/requests",
+    "/absence-period/{id}/approve",
     // Booking-log (read-only audit trail for bookings).
     "/booking-log/{year}/{week}",
     // Sales-person catalog.
@@ -72,9 +75,17 @@ const EXPECTED_PATHS: &[&str] = &[
     // Reporting (employee balance / week-report).
     "/report",
     "/report/week/{year}/{calendar_week}",
+    // Persönliche PDFs — Dienstplan + Monats-Stundenzettel.
+    "/personal-pdf/{sales_person_id}/schedule/{year}/{week}",
+    "/personal-pdf/{sales_person_id}/timesheet/{year}/{month}",
     // Billing periods (invoice / payroll snapshots).
     "/billing-period",
     "/billing-period/{id}",
+    "/billing-period/{id}/custom-report/{template_id}/pdf",
+    // Export-Ziele — CRUD + Lauf-Historie.
+    "/export-target",
+    "/export-target/{id}",
+    "/export-target/{id}/run",
     // Shiftplan info / week message (read aggregates).
     "/shiftplan-info/{shiftplan_id}/{year}/{week}",
     "/week-message",
@@ -125,6 +136,12 @@ const EXPECTED_SCHEMAS: &[&str] = &[
     // Phase 8.5 (Plan 04) — Read-Projektion: lebende Stunden-Marker + Wrapper-Shape.
     "ExtraHoursMarkerTO",
     "AbsenceListWithProjectionTO",
+    // Urlaubsantrag — Status + Antrags-Übersicht.
+    "AbsenceStatusTO",
+    "PendingAbsenceRequestTO",
+    // Export-Ziele.
+    "ExportTargetTO",
+    "ExportTargetRunTO",
 ];
 
 #[test]
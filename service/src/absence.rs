@@ -10,6 +10,13 @@
 //! Read-Sicht (D-10 Option A): HR-Privilege ∨ self (`verify_user_is_sales_person`).
 //! Schichtplan-Kollegen-Sicht ist auf Phase 3 verschoben. Schreib-Methoden enforcen
 //! das gleiche Pattern (D-09).
+//!
+//! Antrags-Workflow: jede Periode trägt einen [`AbsenceStatus`]. HR legt
+//! Perioden direkt als `Approved` an; Mitarbeiter ohne HR-Recht stellen mit
+//! `create` einen Antrag (`Requested`), den HR oder Schichtplanung per
+//! [`AbsenceService::approve`] / [`AbsenceService::reject`] entscheidet. Nur
+//! genehmigte Perioden ([`AbsencePeriod::is_effective`]) fließen in
+//! `derive_hours_for_range`, Reporting und Schichtplan ein.
 
 use std::collections::BTreeMap;
 use std::fmt::Debug;
@@ -17,6 +24,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::Serialize;
 use shifty_utils::DateRange;
 use time::Date;
 use uuid::Uuid;
@@ -24,7 +32,7 @@ use uuid::Uuid;
 use crate::{permission::Authentication, ServiceError};
 
 /// Domain-Kategorien einer Absence-Periode (Phase 1: 3 Werte).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
 pub enum AbsenceCategory {
     Vacation,
     SickLeave,
@@ -58,7 +66,7 @@ impl From<&AbsenceCategory> for dao::absence::AbsenceCategoryEntity {
 /// (Plan 08.3-04). Range-Verhalten: einheitlich für alle Tage einer Periode
 /// (CONTEXT.md D-04-default, klassischer Anwendungsfall = Range 1 Tag für
 /// Heiligabend/Silvester).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize)]
 pub enum DayFraction {
     #[default]
     Full,
@@ -83,12 +91,47 @@ impl From<&DayFraction> for dao::absence::DayFractionEntity {
     }
 }
 
+/// Antrags-Status einer Absence-Periode.
+///
+/// Übergänge: `Requested → Approved | Rejected | Cancelled`,
+/// `Approved → Cancelled` (nur HR). `Rejected` und `Cancelled` sind final.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize)]
+pub enum AbsenceStatus {
+    Requested,
+    #[default]
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+impl From<&dao::absence::AbsenceStatusEntity> for AbsenceStatus {
+    fn from(s: &dao::absence::AbsenceStatusEntity) -> Self {
+        match s {
+            dao::absence::AbsenceStatusEntity::Requested => Self::Requested,
+            dao::absence::AbsenceStatusEntity::Approved => Self::Approved,
+            dao::absence::AbsenceStatusEntity::Rejected => Self::Rejected,
+            dao::absence::AbsenceStatusEntity::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl From<&AbsenceStatus> for dao::absence::AbsenceStatusEntity {
+    fn from(s: &AbsenceStatus) -> Self {
+        match s {
+            AbsenceStatus::Requested => Self::Requested,
+            AbsenceStatus::Approved => Self::Approved,
+            AbsenceStatus::Rejected => Self::Rejected,
+            AbsenceStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
 /// Domain-Repräsentation einer Absence-Periode.
 ///
 /// `id` entspricht der DAO-`logical_id` (D-07). Der `update`-Pfad rotiert die
 /// physische Row, hält aber `id` (= logical_id) und damit externe Referenzen
 /// stabil. `from_date`/`to_date` sind beide inklusive (D-05).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct AbsencePeriod {
     /// Externally stable id == DAO `logical_id`. Equals the physical row id of the first version.
     pub id: Uuid,
@@ -101,6 +144,9 @@ pub struct AbsencePeriod {
     pub deleted: Option<time::PrimitiveDateTime>,
     pub version: Uuid,
     pub day_fraction: DayFraction,
+    /// Wird vom Service gesetzt (`create`: HR → `Approved`, sonst
+    /// `Requested`); der Wert aus dem Request wird ignoriert.
+    pub status: AbsenceStatus,
 }
 
 impl From<&dao::absence::AbsencePeriodEntity> for AbsencePeriod {
@@ -116,6 +162,7 @@ impl From<&dao::absence::AbsencePeriodEntity> for AbsencePeriod {
             deleted: e.deleted,
             version: e.version,
             day_fraction: (&e.day_fraction).into(),
+            status: (&e.status).into(),
         }
     }
 }
@@ -134,6 +181,7 @@ impl TryFrom<&AbsencePeriod> for dao::absence::AbsencePeriodEntity {
             deleted: a.deleted,
             version: a.version,
             day_fraction: (&a.day_fraction).into(),
+            status: (&a.status).into(),
         })
     }
 }
@@ -144,6 +192,12 @@ impl AbsencePeriod {
         DateRange::new(self.from_date, self.to_date)
             .map_err(|_| ServiceError::DateOrderWrong(self.from_date, self.to_date))
     }
+
+    /// Nicht gelöscht und genehmigt — nur solche Perioden zählen als
+    /// tatsächliche Abwesenheit (Stunden, Reporting, Schichtplan).
+    pub fn is_effective(&self) -> bool {
+        self.deleted.is_none() && self.status == AbsenceStatus::Approved
+    }
 }
 
 /// Output von [`AbsenceService::derive_hours_for_range`] — pro Tag bereits
@@ -176,6 +230,20 @@ pub struct AbsencePeriodCreateResult {
     pub warnings: Arc<[crate::warning::Warning]>,
 }
 
+/// Output von [`AbsenceService::project_request`]: was ein offener Antrag
+/// bei Genehmigung bewirken würde.
+///
+/// `days_by_year` sind die Tage, die die Periode nach Konflikt-Auflösung und
+/// Wochen-Deckelung (gleiche Logik wie [`AbsenceService::derive_hours_for_range`])
+/// in ihrer Kategorie belegen würde, pro Kalenderjahr. `warnings` sind die
+/// Forward-Warnings für den Zeitraum (Bookings, ManualUnavailables).
+#[derive(Clone, Debug)]
+pub struct AbsenceRequestProjection {
+    pub absence: AbsencePeriod,
+    pub days_by_year: BTreeMap<u32, f32>,
+    pub warnings: Arc<[crate::warning::Warning]>,
+}
+
 #[automock(type Context=(); type Transaction=dao::MockTransaction;)]
 #[async_trait]
 pub trait AbsenceService {
@@ -236,6 +304,8 @@ pub trait AbsenceService {
         tx: Option<Self::Transaction>,
     ) -> Result<Arc<[AbsencePeriod]>, ServiceError>;
 
+    /// Soft-Delete. HR ∨ self; genehmigte Perioden nur HR, sonst
+    /// `ValidationError([ModificationNotAllowed("status")])`.
     async fn delete(
         &self,
         id: Uuid,
@@ -243,8 +313,46 @@ pub trait AbsenceService {
         tx: Option<Self::Transaction>,
     ) -> Result<(), ServiceError>;
 
+    /// Genehmigt einen offenen Antrag (`Requested → Approved`). HR ∨
+    /// Shiftplanner. Liefert die Forward-Warnings für den Zeitraum wie
+    /// [`Self::create`].
+    async fn approve(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsencePeriodCreateResult, ServiceError>;
+
+    /// Lehnt einen offenen Antrag ab (`Requested → Rejected`). HR ∨
+    /// Shiftplanner.
+    async fn reject(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsencePeriod, ServiceError>;
+
+    /// Zieht eine Periode zurück (`→ Cancelled`). Der Mitarbeiter selbst nur
+    /// offene Anträge, HR auch genehmigte Perioden.
+    async fn cancel(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsencePeriod, ServiceError>;
+
+    /// Projektion eines offenen Antrags: belegte Tage pro Jahr, als wäre er
+    /// genehmigt, plus Forward-Warnings. HR ∨ Shiftplanner ∨ self.
+    async fn project_request(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsenceRequestProjection, ServiceError>;
+
     /// Conflict-resolved per-day hours map for a sales person in `[from, to]`.
     /// Prioritaet: `SickLeave > Vacation > UnpaidLeave` (D-Phase2-03, BUrlG §9).
+    /// Nur genehmigte Perioden zählen ([`AbsencePeriod::is_effective`]).
     /// Tage ohne Vertrag, ohne aktive Absence, oder mit
     /// `SpecialDayType::Holiday` liefern KEINEN Eintrag in der Map.
     /// Permission: HR ∨ self (gleiche Regel wie [`Self::find_by_sales_person`]).
@@ -323,6 +431,7 @@ mod tests {
             deleted: None,
             version: Uuid::nil(),
             day_fraction: dao::absence::DayFractionEntity::Full,
+            status: dao::absence::AbsenceStatusEntity::Approved,
         }
     }
 
@@ -335,6 +444,25 @@ mod tests {
         assert_eq!(domain, AbsenceCategory::SickLeave);
     }
 
+    #[test]
+    fn status_round_trips_and_only_approved_is_effective() {
+        let mut e = dao_entity();
+        e.status = dao::absence::AbsenceStatusEntity::Requested;
+        let domain = AbsencePeriod::from(&e);
+        assert_eq!(domain.status, AbsenceStatus::Requested);
+        assert!(!domain.is_effective());
+        let back = dao::absence::AbsencePeriodEntity::try_from(&domain).unwrap();
+        assert_eq!(back.status, dao::absence::AbsenceStatusEntity::Requested);
+
+        let approved = AbsencePeriod::from(&dao_entity());
+        assert!(approved.is_effective());
+        let deleted = AbsencePeriod {
+            deleted: Some(datetime!(2026 - 04 - 02 12:00:00)),
+            ..approved
+        };
+        assert!(!deleted.is_effective());
+    }
+
     #[test]
     fn domain_id_equals_logical_id() {
         let mut e = dao_entity();
@@ -359,6 +487,7 @@ mod tests {
             deleted: None,
             version: Uuid::nil(),
             day_fraction: DayFraction::Full,
+            status: AbsenceStatus::Approved,
         };
         let result = dao::absence::AbsencePeriodEntity::try_from(&domain);
         assert!(matches!(result, Err(ServiceError::InternalError)));
@@ -377,6 +506,7 @@ mod tests {
             deleted: None,
             version: Uuid::nil(),
             day_fraction: DayFraction::Full,
+            status: AbsenceStatus::Approved,
         };
         let r = p.date_range();
         assert!(matches!(r, Err(ServiceError::DateOrderWrong(_, _))));
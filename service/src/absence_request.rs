@@ -0,0 +1,52 @@
+//! Übersicht offener Urlaubs- und Abwesenheitsanträge für HR und
+//! Schichtplanung.
+//!
+//! Business-Logic-Tier: kombiniert [`crate::absence::AbsenceService`]
+//! (offene Anträge, Projektion) mit
+//! [`crate::vacation_balance::VacationBalanceService`] (Resturlaub), damit
+//! die Entscheidung mit Blick auf den Resturlaub und die Konflikte mit
+//! bestehenden Buchungen fällt. Die Entscheidung selbst läuft über
+//! `AbsenceService::approve` / `reject` — dieser Service schreibt nichts.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::absence::AbsencePeriod;
+use crate::permission::Authentication;
+use crate::vacation_balance::VacationBalance;
+use crate::warning::Warning;
+use crate::ServiceError;
+
+/// Ein offener Antrag samt Auswirkung bei Genehmigung.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingAbsenceRequest {
+    pub absence: AbsencePeriod,
+    /// Tage, die der Antrag bei Genehmigung belegt (alle Jahre).
+    pub requested_days: f32,
+    /// Aktueller Resturlaub im Jahr von `from_date` — nur für `Vacation`.
+    pub vacation_balance: Option<VacationBalance>,
+    /// `vacation_balance.remaining_days` abzüglich der Antragstage in diesem
+    /// Jahr; negativ heißt: der Antrag überzieht den Anspruch.
+    pub remaining_days_after: Option<f32>,
+    /// `AbsenceOverlapsBooking` / `AbsenceOverlapsManualUnavailable` für den
+    /// beantragten Zeitraum.
+    pub warnings: Arc<[Warning]>,
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait AbsenceRequestService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Alle offenen Anträge (`Requested`), nach `from_date` sortiert.
+    /// Permission: HR ∨ Shiftplanner.
+    async fn find_pending(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PendingAbsenceRequest]>, ServiceError>;
+}
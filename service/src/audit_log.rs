@@ -0,0 +1,264 @@
+//! Änderungsprotokoll (Business-Logic-Tier).
+//!
+//! Jeder schreibende Service-Aufruf meldet seine Änderung über
+//! [`AuditLogService::record`] in derselben Transaktion wie die fachliche
+//! Änderung. Gespeichert werden wer (samt impersonierendem Admin), wann,
+//! welche Entität und JSON-Schnappschüsse vor und nach der Änderung; die
+//! feldweise Differenz wird beim Lesen berechnet.
+//!
+//! Der impersonierende Admin ist nicht Teil des `Context`. Die REST-Schicht
+//! setzt ihn für die Dauer des Requests mit [`scope_real_user`], `record`
+//! liest ihn mit [`current_real_user`].
+
+use std::fmt::{Debug, Display};
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use serde::{Serialize, Serializer};
+use time::{Date, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+/// Werte für [`AuditChange::entity_type`], zugleich die Filterwerte der
+/// Abfrage-API.
+pub mod entity_type {
+    pub const ABSENCE_PERIOD: &str = "absence_period";
+    /// Ohne die eingefrorenen Werte, nur die enthaltenen Mitarbeiter.
+    pub const BILLING_PERIOD: &str = "billing_period";
+    pub const BOOKING: &str = "booking";
+    pub const CARRYOVER: &str = "carryover";
+    pub const CUSTOM_EXTRA_HOURS: &str = "custom_extra_hours";
+    pub const EMPLOYEE_WORK_DETAILS: &str = "employee_work_details";
+    /// Ohne Secret.
+    pub const EXPORT_TARGET: &str = "export_target";
+    pub const EXTRA_HOURS: &str = "extra_hours";
+    /// Id ist der Schlüssel.
+    pub const FEATURE_FLAG: &str = "feature_flag";
+    /// Secret selbst wird nicht protokolliert.
+    pub const ICAL_FEED_TOKEN: &str = "ical_feed_token";
+    /// Id ist die Benutzerkennung.
+    pub const NOTIFICATION_PREFERENCE: &str = "notification_preference";
+    pub const PAYROLL_WAGE_TYPE: &str = "payroll_wage_type";
+    /// Ohne App-Token.
+    pub const PDF_EXPORT_CONFIG: &str = "pdf_export_config";
+    /// Shiftplan-Auswahl des PDF-Exports als Ganzes, Id der Konfiguration.
+    pub const PDF_EXPORT_SHIFTPLAN: &str = "pdf_export_shiftplan";
+    pub const PRIVILEGE: &str = "privilege";
+    pub const QUALIFICATION: &str = "qualification";
+    /// Umbuchungs-Batch samt Entries.
+    pub const REBOOKING_BATCH: &str = "rebooking_batch";
+    pub const ROLE: &str = "role";
+    /// Privileg einer Rolle, Id `<rolle>/<privileg>`.
+    pub const ROLE_PRIVILEGE: &str = "role_privilege";
+    pub const SALES_PERSON: &str = "sales_person";
+    pub const SALES_PERSON_AVAILABILITY: &str = "sales_person_availability";
+    /// Qualifikationen eines Mitarbeiters als Ganzes.
+    pub const SALES_PERSON_QUALIFICATION: &str = "sales_person_qualification";
+    /// Schichtplan-Zuordnungen eines Mitarbeiters als Ganzes.
+    pub const SALES_PERSON_SHIFTPLAN: &str = "sales_person_shiftplan";
+    pub const SALES_PERSON_UNAVAILABLE: &str = "sales_person_unavailable";
+    /// Zuordnung eines Mitarbeiters zu einem Benutzerkonto.
+    pub const SALES_PERSON_USER: &str = "sales_person_user";
+    /// Job-Registry; die ID ist der Job-Name, Laufergebnisse werden nicht
+    /// protokolliert.
+    pub const SCHEDULER_JOB: &str = "scheduler_job";
+    pub const SHIFT_SWAP_REQUEST: &str = "shift_swap_request";
+    pub const SHIFTPLAN: &str = "shiftplan";
+    pub const SLOT: &str = "slot";
+    /// Qualifikations-Anforderungen eines Slots als Ganzes.
+    pub const SLOT_QUALIFICATION_REQUIREMENT: &str = "slot_qualification_requirement";
+    pub const SPECIAL_DAY: &str = "special_day";
+    pub const TEXT_TEMPLATE: &str = "text_template";
+    pub const TIME_ENTRY: &str = "time_entry";
+    pub const TOGGLE: &str = "toggle";
+    /// Gruppe samt Mitgliederliste; Zuordnungsänderungen sind Updates.
+    pub const TOGGLE_GROUP: &str = "toggle_group";
+    /// Benutzerkonto der Rechteverwaltung.
+    pub const USER: &str = "user";
+    /// Ohne Token.
+    pub const USER_INVITATION: &str = "user_invitation";
+    /// Rollen eines Benutzerkontos als Ganzes.
+    pub const USER_ROLE: &str = "user_role";
+    pub const VACATION_ENTITLEMENT_OFFSET: &str = "vacation_entitlement_offset";
+    /// Ohne HMAC-Schlüssel.
+    pub const WEBHOOK_SUBSCRIPTION: &str = "webhook_subscription";
+    pub const WEEK_MESSAGE: &str = "week_message";
+    pub const WEEK_STATUS: &str = "week_status";
+}
+
+tokio::task_local! {
+    static REAL_USER: Arc<str>;
+}
+
+/// Führt `future` mit `real_user` als impersonierendem Admin aus.
+pub async fn scope_real_user<F: Future>(real_user: Arc<str>, future: F) -> F::Output {
+    REAL_USER.scope(real_user, future).await
+}
+
+/// Der impersonierende Admin des laufenden Requests, sonst `None`.
+pub fn current_real_user() -> Option<Arc<str>> {
+    REAL_USER.try_with(Arc::clone).ok()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+impl From<dao::audit_log::AuditAction> for AuditAction {
+    fn from(action: dao::audit_log::AuditAction) -> Self {
+        match action {
+            dao::audit_log::AuditAction::Create => Self::Create,
+            dao::audit_log::AuditAction::Update => Self::Update,
+            dao::audit_log::AuditAction::Delete => Self::Delete,
+        }
+    }
+}
+impl From<AuditAction> for dao::audit_log::AuditAction {
+    fn from(action: AuditAction) -> Self {
+        match action {
+            AuditAction::Create => Self::Create,
+            AuditAction::Update => Self::Update,
+            AuditAction::Delete => Self::Delete,
+        }
+    }
+}
+
+/// Für `#[serde(serialize_with = ...)]` an Geheimnissen: im Protokoll steht
+/// nur, ob eines gesetzt ist.
+pub fn serialize_redacted<S: Serializer>(
+    secret: &Option<Arc<str>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    secret.as_ref().map(|_| "<redacted>").serialize(serializer)
+}
+
+fn snapshot<T: Serialize>(value: &T) -> Result<Arc<str>, ServiceError> {
+    serde_json::to_string(value)
+        .map(Arc::from)
+        .map_err(|_| ServiceError::InternalError)
+}
+
+/// Eine gemeldete Änderung. Die Schnappschüsse sind JSON der Service-Sicht.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditChange {
+    pub entity_type: &'static str,
+    pub entity_id: Arc<str>,
+    pub action: AuditAction,
+    pub before: Option<Arc<str>>,
+    pub after: Option<Arc<str>>,
+}
+impl AuditChange {
+    pub fn create<T: Serialize>(
+        entity_type: &'static str,
+        entity_id: impl Display,
+        after: &T,
+    ) -> Result<Self, ServiceError> {
+        Ok(Self {
+            entity_type,
+            entity_id: entity_id.to_string().into(),
+            action: AuditAction::Create,
+            before: None,
+            after: Some(snapshot(after)?),
+        })
+    }
+
+    pub fn update<T: Serialize>(
+        entity_type: &'static str,
+        entity_id: impl Display,
+        before: &T,
+        after: &T,
+    ) -> Result<Self, ServiceError> {
+        Ok(Self {
+            entity_type,
+            entity_id: entity_id.to_string().into(),
+            action: AuditAction::Update,
+            before: Some(snapshot(before)?),
+            after: Some(snapshot(after)?),
+        })
+    }
+
+    pub fn delete<T: Serialize>(
+        entity_type: &'static str,
+        entity_id: impl Display,
+        before: &T,
+    ) -> Result<Self, ServiceError> {
+        Ok(Self {
+            entity_type,
+            entity_id: entity_id.to_string().into(),
+            action: AuditAction::Delete,
+            before: Some(snapshot(before)?),
+            after: None,
+        })
+    }
+}
+
+/// Ein geändertes Feld; die Werte sind JSON, `None` wenn das Feld vorher
+/// bzw. nachher fehlt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditFieldChange {
+    pub field: Arc<str>,
+    pub before: Option<Arc<str>>,
+    pub after: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogEntry {
+    pub id: Uuid,
+    pub entity_type: Arc<str>,
+    pub entity_id: Arc<str>,
+    pub action: AuditAction,
+    /// `None` bei Änderungen ohne Benutzer (Scheduler, Systemaufrufe).
+    pub user_id: Option<Arc<str>>,
+    /// Admin, der als `user_id` gehandelt hat.
+    pub real_user_id: Option<Arc<str>>,
+    pub process: Arc<str>,
+    pub before: Option<Arc<str>>,
+    pub after: Option<Arc<str>>,
+    pub changes: Arc<[AuditFieldChange]>,
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditLogFilter {
+    pub entity_type: Option<Arc<str>>,
+    pub entity_id: Option<Arc<str>>,
+    /// Handelnder oder impersonierender Benutzer.
+    pub user_id: Option<Arc<str>>,
+    /// Inklusive.
+    pub from: Option<Date>,
+    /// Inklusive.
+    pub to: Option<Date>,
+    /// Höchstzahl der Einträge; 0 bedeutet den Standardwert.
+    pub limit: u32,
+}
+
+#[automock(type Context=(); type Transaction = dao::MockTransaction;)]
+#[async_trait]
+pub trait AuditLogService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Hängt `change` an. Keine eigene Berechtigungsprüfung, der aufrufende
+    /// Service hat sie schon gemacht; `context` bestimmt nur den Benutzer.
+    async fn record(
+        &self,
+        change: &AuditChange,
+        process: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Neueste Einträge zuerst. Permission: HR oder Admin.
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[AuditLogEntry]>, ServiceError>;
+}
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use dao::billing_period::BillingPeriodEntity;
 use dao::billing_period_sales_person::BillingPeriodSalesPersonEntity;
 use mockall::automock;
+use serde::Serialize;
 use shifty_utils::ShiftyDate;
 use std::{collections::BTreeMap, sync::Arc};
 use std::str::FromStr;
@@ -30,7 +31,7 @@ pub struct BillingPeriod {
     pub deleted_by: Option<Arc<str>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub enum BillingPeriodValueType {
     Balance,
     Overall,
@@ -1,3 +1,5 @@
+use crate::report_export::ExportFile;
+use crate::text_template::TextTemplate;
 use crate::ServiceError;
 use crate::{billing_period::BillingPeriod, permission::Authentication};
 use async_trait::async_trait;
@@ -5,6 +7,14 @@ use mockall::automock;
 use shifty_utils::ShiftyDate;
 use uuid::Uuid;
 
+/// `template_type` der Text-Templates für den Custom Report
+/// (siehe [`BillingPeriodReportService::generate_custom_report`]).
+pub const BILLING_PERIOD_TEMPLATE_TYPE: &str = "billing-period";
+
+/// `template_type` der Text-Templates, deren Ausgabe ein PDF-Layout ist
+/// (siehe [`BillingPeriodReportService::generate_custom_report_pdf`]).
+pub const BILLING_PERIOD_PDF_TEMPLATE_TYPE: &str = "billing-period-pdf";
+
 #[automock(type Context=(); type Transaction=dao::MockTransaction;)]
 #[async_trait]
 pub trait BillingPeriodReportService {
@@ -51,4 +61,35 @@ pub trait BillingPeriodReportService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<std::sync::Arc<str>, ServiceError>;
+
+    /// Generate a custom report as PDF
+    ///
+    /// Same template context as `generate_custom_report`, but the template
+    /// must have the type [`BILLING_PERIOD_PDF_TEMPLATE_TYPE`] and render a
+    /// PDF layout (`<document>` markup with page size, orientation, header,
+    /// logo and locale). Inserted values are HTML-escaped. Invalid markup is
+    /// reported as validation error with line and column.
+    ///
+    /// Only HR is allowed to generate custom reports.
+    async fn generate_custom_report_pdf(
+        &self,
+        template_id: Uuid,
+        billing_period_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+
+    /// Build the template context of the custom reports for `template`
+    ///
+    /// Same data the custom reports render against; used by the template
+    /// preview, so `template` does not have to be saved.
+    ///
+    /// Only HR is allowed to build the context.
+    async fn build_custom_report_context(
+        &self,
+        template: &TextTemplate,
+        billing_period_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<serde_json::Value, ServiceError>;
 }
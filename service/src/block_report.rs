@@ -7,6 +7,9 @@ use uuid::Uuid;
 use crate::permission::Authentication;
 use crate::ServiceError;
 
+/// `template_type` of the text templates rendered by the block report.
+pub const BLOCK_REPORT_TEMPLATE_TYPE: &str = "shiftplan-report";
+
 /// A service for generating block-based reports using text templates.
 /// This service provides template context with current week blocks, next two weeks blocks,
 /// current user blocks, and unsufficiently booked blocks.
@@ -31,4 +34,12 @@ pub trait BlockReportService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<Arc<str>, ServiceError>;
+
+    /// Builds the template context used by `generate_block_report` for the
+    /// current date, e.g. for a template preview.
+    async fn build_block_report_context(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<serde_json::Value, ServiceError>;
 }
\ No newline at end of file
@@ -3,13 +3,14 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::Serialize;
 use time::PrimitiveDateTime;
 use uuid::Uuid;
 
 use crate::permission::Authentication;
 use crate::ServiceError;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Booking {
     pub id: Uuid,
     pub sales_person_id: Uuid,
@@ -82,6 +83,15 @@ pub trait BookingService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<Arc<[Booking]>, ServiceError>;
+    /// Soft-gelöschte Buchungen der Woche. Wird für `STATUS:CANCELLED` im
+    /// iCal-Feed gebraucht.
+    async fn get_deleted_for_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[Booking]>, ServiceError>;
     async fn get_for_slot_id_since(
         &self,
         slot_id: Uuid,
@@ -9,6 +9,7 @@ use uuid::Uuid;
 
 use crate::booking::Booking;
 use crate::permission::Authentication;
+use crate::qualification::Qualification;
 use crate::reporting::CustomExtraHours;
 use crate::sales_person::SalesPerson;
 use crate::slot::Slot;
@@ -21,6 +22,27 @@ pub struct BookingInformation {
     pub sales_person: Arc<SalesPerson>,
 }
 
+/// Ein Slot der Woche, dessen Buchungen eine Qualifikations-Anforderung
+/// nicht erfüllen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QualificationGap {
+    pub slot: Arc<Slot>,
+    pub qualification: Arc<Qualification>,
+    pub required_count: u8,
+    pub qualified_count: u8,
+}
+
+/// Ergebnis von [`BookingInformationService::get_booking_conflicts_for_week`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookingConflicts {
+    /// Buchungen an Tagen, an denen die Person abwesend oder nicht
+    /// verfügbar ist.
+    pub bookings: Arc<[BookingInformation]>,
+    /// Nicht erfüllte Qualifikations-Anforderungen der Slots dieser Woche
+    /// (Feiertage ausgenommen).
+    pub qualification_gaps: Arc<[QualificationGap]>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct WorkingHoursPerSalesPerson {
     pub sales_person_id: Uuid,
@@ -112,7 +134,7 @@ pub trait BookingInformationService {
         week: u8,
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
-    ) -> Result<Arc<[BookingInformation]>, ServiceError>;
+    ) -> Result<BookingConflicts, ServiceError>;
 
     async fn get_weekly_summary(
         &self,
@@ -3,10 +3,12 @@ use crate::ServiceError;
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use std::fmt::Debug;
+use std::sync::Arc;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct Carryover {
     pub sales_person_id: Uuid,
     pub year: u32,
@@ -46,6 +48,43 @@ impl TryFrom<&Carryover> for dao::carryover::CarryoverEntity {
     }
 }
 
+/// Ein (Mitarbeiter, ISO-Jahr)-Paar, dessen Carryover neu berechnet werden
+/// muss. Siehe [`CarryoverService::mark_dirty`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarryoverDirty {
+    pub sales_person_id: Uuid,
+    pub year: u32,
+    pub version: Uuid,
+}
+
+impl From<&dao::carryover::CarryoverDirtyEntity> for CarryoverDirty {
+    fn from(entity: &dao::carryover::CarryoverDirtyEntity) -> Self {
+        Self {
+            sales_person_id: entity.sales_person_id,
+            year: entity.year,
+            version: entity.version,
+        }
+    }
+}
+
+impl From<&CarryoverDirty> for dao::carryover::CarryoverDirtyEntity {
+    fn from(dirty: &CarryoverDirty) -> Self {
+        Self {
+            sales_person_id: dirty.sales_person_id,
+            year: dirty.year,
+            version: dirty.version,
+        }
+    }
+}
+
+/// Erstes Carryover-Jahr, das eine Änderung am Tag `date` berührt. Der
+/// Jahres-Report arbeitet mit ISO-Wochen, Extra-Stunden werden aber teils nach
+/// Kalenderjahr zugeordnet; an der Jahresgrenze zählt deshalb das frühere der
+/// beiden Jahre — die Folgejahre rechnet der Carryover-Job ohnehin mit neu.
+pub fn first_dirty_year(date: time::Date) -> u32 {
+    date.to_iso_week_date().0.min(date.year()) as u32
+}
+
 #[automock(type Context=(); type Transaction=MockTransaction;)]
 #[async_trait]
 pub trait CarryoverService {
@@ -66,4 +105,33 @@ pub trait CarryoverService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<(), ServiceError>;
+
+    /// Markiert den Carryover von `sales_person_id` für das ISO-Jahr `year`
+    /// als veraltet. Aufgerufen von den Services, deren Daten in den
+    /// Jahres-Report eingehen, in derselben Transaktion wie die Änderung.
+    /// Keine eigene Berechtigungsprüfung, der aufrufende Service hat sie
+    /// schon gemacht.
+    async fn mark_dirty(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Alle offenen Markierungen, älteste Jahre zuerst.
+    async fn find_dirty(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[CarryoverDirty]>, ServiceError>;
+
+    /// Entfernt die Markierung, sofern sie seit `find_dirty` nicht erneut
+    /// gesetzt wurde.
+    async fn clear_dirty(
+        &self,
+        dirty: &CarryoverDirty,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
 }
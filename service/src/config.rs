@@ -7,6 +7,12 @@ use mockall::automock;
 pub struct Config {
     pub timezone: Arc<str>,
     pub ical_label: Arc<str>,
+    /// Öffentliche Basis-URL (`APP_URL`), z. B. für Links in E-Mails.
+    pub app_url: Arc<str>,
+    /// DATEV-Beraternummer (`DATEV_CONSULTANT_NUMBER`) für den Lohnexport.
+    pub datev_consultant_number: Option<Arc<str>>,
+    /// DATEV-Mandantennummer (`DATEV_CLIENT_NUMBER`) für den Lohnexport.
+    pub datev_client_number: Option<Arc<str>>,
 }
 
 #[automock]
@@ -4,11 +4,12 @@ use crate::{permission::Authentication, ServiceError};
 use async_trait::async_trait;
 use dao::custom_extra_hours::CustomExtraHoursEntity;
 use mockall::automock;
+use serde::Serialize;
 use std::fmt::Debug;
 use time::PrimitiveDateTime;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct CustomExtraHours {
     pub id: Uuid,
     pub name: Arc<str>,
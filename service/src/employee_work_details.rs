@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::Serialize;
 use shifty_utils::{DayOfWeek, ShiftyDate, ShiftyDateUtilsError};
 use time::{PrimitiveDateTime, Weekday};
 use uuid::Uuid;
@@ -10,7 +11,7 @@ use uuid::Uuid;
 use crate::permission::Authentication;
 use crate::ServiceError;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct EmployeeWorkDetails {
     pub id: Uuid,
     pub sales_person_id: Uuid,
@@ -25,6 +26,7 @@ pub struct EmployeeWorkDetails {
     pub is_dynamic: bool,
     pub cap_planned_hours_to_expected: bool,
     pub committed_voluntary: f32,
+    pub use_actual_hours: bool,
 
     pub monday: bool,
     pub tuesday: bool,
@@ -56,6 +58,7 @@ impl From<&dao::employee_work_details::EmployeeWorkDetailsEntity> for EmployeeWo
             is_dynamic: working_hours.is_dynamic,
             cap_planned_hours_to_expected: working_hours.cap_planned_hours_to_expected,
             committed_voluntary: working_hours.committed_voluntary,
+            use_actual_hours: working_hours.use_actual_hours,
 
             monday: working_hours.monday,
             tuesday: working_hours.tuesday,
@@ -211,6 +214,7 @@ impl TryFrom<&EmployeeWorkDetails> for dao::employee_work_details::EmployeeWorkD
             is_dynamic: working_hours.is_dynamic,
             cap_planned_hours_to_expected: working_hours.cap_planned_hours_to_expected,
             committed_voluntary: working_hours.committed_voluntary,
+            use_actual_hours: working_hours.use_actual_hours,
 
             monday: working_hours.monday,
             tuesday: working_hours.tuesday,
@@ -314,6 +318,7 @@ mod vacation_days_for_year_tests {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
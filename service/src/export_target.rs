@@ -0,0 +1,322 @@
+//! Benannte Export-Ziele (Basic-Tier).
+//!
+//! Ergänzt die einzelne Nextcloud-Konfiguration
+//! ([`crate::pdf_export_config::PdfExportConfigService`]) um beliebig viele
+//! Ziele mit eigener Zielart, eigenem Cron-Ausdruck, Artefakt und
+//! Dateinamen-Muster. Ausgeführt werden sie vom
+//! [`crate::export_target_scheduler::ExportTargetScheduler`], der jeden Lauf
+//! über [`ExportTargetService::record_run`] in die Historie schreibt.
+//!
+//! Dateinamen-Muster kennen die Platzhalter `{target}` (Name des Ziels) und
+//! `{date}` (Tag des Laufs) sowie je nach Artefakt:
+//! - Wochen-PDF: `{shiftplan}`, `{year}`, `{week}` (zweistellig)
+//! - Abrechnungszeitraum: `{start}`, `{end}`
+//! - iCal-Schnappschuss: `{shiftplan}`
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::export_target::{
+    ExportArtifactType as ExportArtifactTypeEntity, ExportRunState as ExportRunStateEntity,
+    ExportTargetEntity, ExportTargetKind as ExportTargetKindEntity, ExportTargetRunEntity,
+};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::audit_log::serialize_redacted;
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ExportTargetKind {
+    /// Upload per WebDAV (z. B. Nextcloud), wie der bisherige PDF-Export.
+    WebDav,
+    /// Ablage in einem Verzeichnis des Servers; fehlende Ordner werden angelegt.
+    LocalDirectory,
+    /// Abholordner auf dem Server, den ein externer Dienst (z. B. ein
+    /// SFTP-Server) ausliefert: das Verzeichnis muss existieren, Dateien
+    /// werden unter `.<name>.part` geschrieben und erst danach umbenannt,
+    /// damit Abholer keine halben Dateien sehen.
+    DropDirectory,
+}
+
+impl From<ExportTargetKindEntity> for ExportTargetKind {
+    fn from(kind: ExportTargetKindEntity) -> Self {
+        match kind {
+            ExportTargetKindEntity::WebDav => Self::WebDav,
+            ExportTargetKindEntity::LocalDirectory => Self::LocalDirectory,
+            ExportTargetKindEntity::DropDirectory => Self::DropDirectory,
+        }
+    }
+}
+
+impl From<ExportTargetKind> for ExportTargetKindEntity {
+    fn from(kind: ExportTargetKind) -> Self {
+        match kind {
+            ExportTargetKind::WebDav => Self::WebDav,
+            ExportTargetKind::LocalDirectory => Self::LocalDirectory,
+            ExportTargetKind::DropDirectory => Self::DropDirectory,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ExportArtifactType {
+    /// Wochen-PDF jedes aktiven Schichtplans für `weeks_horizon` Wochen; nur
+    /// Wochen mit Status `Planned` oder `Locked`.
+    ShiftplanWeekPdf,
+    /// Der jüngste Abrechnungszeitraum als CSV (deutsche Spalten).
+    BillingPeriodCsv,
+    /// iCal-Feed jedes aktiven Schichtplans als `.ics`-Datei.
+    IcalSnapshot,
+}
+
+impl ExportArtifactType {
+    /// Muster, das bei leerem `filename_pattern` gilt.
+    pub fn default_filename_pattern(&self) -> &'static str {
+        match self {
+            Self::ShiftplanWeekPdf => "schichtplan-{shiftplan}-{year}-KW{week}.pdf",
+            Self::BillingPeriodCsv => "abrechnung-{start}-{end}.csv",
+            Self::IcalSnapshot => "schichtplan-{shiftplan}.ics",
+        }
+    }
+
+    /// Platzhalter, die im Muster vorkommen dürfen.
+    pub fn placeholders(&self) -> &'static [&'static str] {
+        match self {
+            Self::ShiftplanWeekPdf => &["target", "date", "shiftplan", "year", "week"],
+            Self::BillingPeriodCsv => &["target", "date", "start", "end"],
+            Self::IcalSnapshot => &["target", "date", "shiftplan"],
+        }
+    }
+
+    /// Platzhalter, ohne die ein Lauf mehrere Dateien unter demselben Namen
+    /// ablegen würde.
+    pub fn required_placeholders(&self) -> &'static [&'static str] {
+        match self {
+            Self::ShiftplanWeekPdf => &["shiftplan", "year", "week"],
+            Self::BillingPeriodCsv => &[],
+            Self::IcalSnapshot => &["shiftplan"],
+        }
+    }
+}
+
+impl From<ExportArtifactTypeEntity> for ExportArtifactType {
+    fn from(artifact_type: ExportArtifactTypeEntity) -> Self {
+        match artifact_type {
+            ExportArtifactTypeEntity::ShiftplanWeekPdf => Self::ShiftplanWeekPdf,
+            ExportArtifactTypeEntity::BillingPeriodCsv => Self::BillingPeriodCsv,
+            ExportArtifactTypeEntity::IcalSnapshot => Self::IcalSnapshot,
+        }
+    }
+}
+
+impl From<ExportArtifactType> for ExportArtifactTypeEntity {
+    fn from(artifact_type: ExportArtifactType) -> Self {
+        match artifact_type {
+            ExportArtifactType::ShiftplanWeekPdf => Self::ShiftplanWeekPdf,
+            ExportArtifactType::BillingPeriodCsv => Self::BillingPeriodCsv,
+            ExportArtifactType::IcalSnapshot => Self::IcalSnapshot,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportRunState {
+    Success,
+    Partial,
+    Failed,
+}
+
+impl From<ExportRunStateEntity> for ExportRunState {
+    fn from(state: ExportRunStateEntity) -> Self {
+        match state {
+            ExportRunStateEntity::Success => Self::Success,
+            ExportRunStateEntity::Partial => Self::Partial,
+            ExportRunStateEntity::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<ExportRunState> for ExportRunStateEntity {
+    fn from(state: ExportRunState) -> Self {
+        match state {
+            ExportRunState::Success => Self::Success,
+            ExportRunState::Partial => Self::Partial,
+            ExportRunState::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ExportTarget {
+    pub id: Uuid,
+    pub name: Arc<str>,
+    pub kind: ExportTargetKind,
+    pub enabled: bool,
+    /// WebDAV-Basis-URL, nur bei [`ExportTargetKind::WebDav`].
+    pub url: Option<Arc<str>>,
+    pub username: Option<Arc<str>>,
+    /// WebDAV-App-Token. Das REST-DTO gibt ihn nie heraus; `None` beim
+    /// Aktualisieren behält den gespeicherten Wert.
+    #[serde(serialize_with = "serialize_redacted")]
+    pub secret: Option<Arc<str>>,
+    /// WebDAV: Ordner unterhalb der URL. Verzeichnis-Ziele: absoluter Pfad.
+    pub path: Arc<str>,
+    pub cron_schedule: Arc<str>,
+    pub artifact_type: ExportArtifactType,
+    /// Leer heißt [`ExportArtifactType::default_filename_pattern`].
+    pub filename_pattern: Arc<str>,
+    pub weeks_horizon: u32,
+    pub created: Option<PrimitiveDateTime>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl ExportTarget {
+    /// Das wirksame Dateinamen-Muster.
+    pub fn effective_filename_pattern(&self) -> &str {
+        if self.filename_pattern.trim().is_empty() {
+            self.artifact_type.default_filename_pattern()
+        } else {
+            self.filename_pattern.trim()
+        }
+    }
+}
+
+impl From<&ExportTargetEntity> for ExportTarget {
+    fn from(entity: &ExportTargetEntity) -> Self {
+        Self {
+            id: entity.id,
+            name: entity.name.clone(),
+            kind: entity.kind.into(),
+            enabled: entity.enabled,
+            url: entity.url.clone(),
+            username: entity.username.clone(),
+            secret: entity.secret.clone(),
+            path: entity.path.clone(),
+            cron_schedule: entity.cron_schedule.clone(),
+            artifact_type: entity.artifact_type.into(),
+            filename_pattern: entity.filename_pattern.clone(),
+            weeks_horizon: entity.weeks_horizon,
+            created: Some(entity.created),
+            deleted: entity.deleted,
+            version: entity.version,
+        }
+    }
+}
+
+/// Ein Lauf eines Export-Ziels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportTargetRun {
+    pub id: Uuid,
+    pub target_id: Uuid,
+    pub started: PrimitiveDateTime,
+    pub duration_ms: u64,
+    pub state: ExportRunState,
+    pub files_written: u32,
+    pub files_skipped: u32,
+    /// Fehler und übersprungene Dateien, eine Zeile pro Eintrag.
+    pub message: Option<Arc<str>>,
+    pub version: Uuid,
+}
+
+impl From<&ExportTargetRunEntity> for ExportTargetRun {
+    fn from(entity: &ExportTargetRunEntity) -> Self {
+        Self {
+            id: entity.id,
+            target_id: entity.target_id,
+            started: entity.started,
+            duration_ms: entity.duration_ms,
+            state: entity.state.into(),
+            files_written: entity.files_written,
+            files_skipped: entity.files_skipped,
+            message: entity.message.clone(),
+            version: entity.version,
+        }
+    }
+}
+
+impl From<&ExportTargetRun> for ExportTargetRunEntity {
+    fn from(run: &ExportTargetRun) -> Self {
+        Self {
+            id: run.id,
+            target_id: run.target_id,
+            started: run.started,
+            duration_ms: run.duration_ms,
+            state: run.state.into(),
+            files_written: run.files_written,
+            files_skipped: run.files_skipped,
+            message: run.message.clone(),
+            version: run.version,
+        }
+    }
+}
+
+#[automock(type Context=(); type Transaction=MockTransaction;)]
+#[async_trait]
+pub trait ExportTargetService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Alle Ziele. Permission: Admin.
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ExportTarget]>, ServiceError>;
+
+    /// Permission: Admin.
+    async fn get(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTarget, ServiceError>;
+
+    /// Permission: Admin. Prüft Zielart-Felder, Cron-Ausdruck und Muster.
+    async fn create(
+        &self,
+        target: &ExportTarget,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTarget, ServiceError>;
+
+    /// Permission: Admin. `version` muss zum gespeicherten Stand passen.
+    async fn update(
+        &self,
+        target: &ExportTarget,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTarget, ServiceError>;
+
+    /// Soft-Delete; die Historie bleibt erhalten. Permission: Admin.
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Die jüngsten Läufe eines Ziels, neueste zuerst. Permission: Admin.
+    async fn get_runs(
+        &self,
+        target_id: Uuid,
+        limit: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ExportTargetRun]>, ServiceError>;
+
+    /// Schreibt einen Lauf in die Historie; `id` und `version` vergibt der
+    /// Service. Nur `Authentication::Full` (Scheduler).
+    async fn record_run(
+        &self,
+        run: &ExportTargetRun,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTargetRun, ServiceError>;
+}
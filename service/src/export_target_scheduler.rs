@@ -0,0 +1,42 @@
+//! Ausführung der Export-Ziele (Business-Logic-Tier).
+//!
+//! Registriert für jedes aktivierte Ziel aus
+//! [`crate::export_target::ExportTargetService`] einen eigenen Cron-Job,
+//! erzeugt das Artefakt über die Fach-Services
+//! ([`crate::pdf_shiftplan::PdfShiftplanService`],
+//! [`crate::report_export::ReportExportService`],
+//! [`crate::team_ical::TeamIcalService`]) und legt die Dateien im Ziel ab.
+//! Jeder Lauf wird mit Dauer, Dateizahlen und Meldungen in der Historie
+//! festgehalten; Fehler gehen nicht nach oben, sondern dorthin.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::export_target::ExportTargetRun;
+use crate::{permission::Authentication, ServiceError};
+
+#[automock(type Context=();)]
+#[async_trait]
+pub trait ExportTargetScheduler {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+
+    /// Beim App-Boot: Scheduler anlegen und alle aktivierten Ziele
+    /// registrieren.
+    async fn start(&self) -> Result<(), ServiceError>;
+
+    /// Nach einer Änderung an den Zielen: alle Registrierungen entfernen und
+    /// neu anlegen.
+    async fn reload_from_db(&self) -> Result<(), ServiceError>;
+
+    /// Führt genau einen Lauf des Ziels aus, auch wenn es deaktiviert ist,
+    /// und liefert den gespeicherten Historien-Eintrag. Nur
+    /// `Authentication::Full` (Cron und REST-Handler nach dem Admin-Check).
+    async fn run_target_now(
+        &self,
+        target_id: Uuid,
+        context: Authentication<Self::Context>,
+    ) -> Result<ExportTargetRun, ServiceError>;
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::{Serialize, Serializer};
 use shifty_utils::LazyLoad;
 use shifty_utils::ShiftyDate;
 use uuid::Uuid;
@@ -37,7 +38,7 @@ pub enum Availability {
     None,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum ExtraHoursCategory {
     ExtraWork,
     Vacation,
@@ -46,7 +47,17 @@ pub enum ExtraHoursCategory {
     Unavailable,
     UnpaidLeave,
     VolunteerWork,
-    CustomExtraHours(LazyLoad<Uuid, CustomExtraHours>),
+    CustomExtraHours(
+        #[serde(serialize_with = "serialize_lazy_key")] LazyLoad<Uuid, CustomExtraHours>,
+    ),
+}
+
+/// Im Änderungsprotokoll zählt nur die Referenz, nicht die geladene Definition.
+fn serialize_lazy_key<S: Serializer>(
+    lazy: &LazyLoad<Uuid, CustomExtraHours>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    lazy.key().serialize(serializer)
 }
 
 /// Phase 54 (D-54-DM-02): Rebooking-Marker auf jeder `ExtraHours`-Row.
@@ -56,7 +67,7 @@ pub enum ExtraHoursCategory {
 ///
 /// Bestandsrows landen per SQL-DEFAULT auf `Manual`; Rebooking-Schreiber
 /// folgen ab Phase 55.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
 pub enum ExtraHoursSource {
     /// Row wurde manuell (UI-Add-Extra-Hours, HR-CRUD, Absence-Convert)
     /// erzeugt und zaehlt normal in Balance / F1-Ist / F2-Nutz.
@@ -166,7 +177,7 @@ impl From<&ExtraHoursCategory> for dao::extra_hours::ExtraHoursCategoryEntity {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ExtraHours {
     /// Externally-stable id. Maps to the `logical_id` column on the persistence layer
     /// (which equals the physical row id for the first version of the entry).
@@ -3,12 +3,13 @@ use crate::ServiceError;
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::sync::Arc;
 
 pub const FEATURE_FLAG_ADMIN_PRIVILEGE: &str = "feature_flag_admin";
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct FeatureFlag {
     pub key: Arc<str>,
     pub enabled: bool,
@@ -0,0 +1,144 @@
+//! Feiertagskalender für `SpecialDay`s (Business-Logic-Tier).
+//!
+//! Statt die gesetzlichen Feiertage jedes Jahr einzeln über
+//! [`crate::special_days::SpecialDayService::create`] anzulegen, berechnet der
+//! Service sie aus Regeln (feste Daten, Ostern-relative Daten, Buß- und
+//! Bettag) für ein Bundesland und legt die fehlenden als `Holiday` an.
+//!
+//! Bereits vorhandene `SpecialDay`s am selben Datum werden nie verändert,
+//! auch kein `ShortDay`. Ein erneuter Lauf für dasselbe Jahr legt daher
+//! nichts doppelt an. Die Gutschrift über `holiday_auto_credit` greift wie
+//! bei manuell angelegten Feiertagen.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use thiserror::Error;
+use time::Date;
+
+use crate::permission::Authentication;
+use crate::special_days::SpecialDay;
+use crate::ServiceError;
+
+#[derive(Debug, Error)]
+pub enum HolidayRegionParseError {
+    #[error("Invalid holiday region: {0}")]
+    InvalidRegion(String),
+}
+
+/// Bundesweit oder ein Bundesland, Codes nach ISO 3166-2 (`DE`, `DE-BY`, …).
+///
+/// Feiertage, die nur in Teilen eines Landes gelten (Fronleichnam in Teilen
+/// Sachsens und Thüringens, Mariä Himmelfahrt in Teilen Bayerns, Augsburger
+/// Friedensfest), sind nicht enthalten und bei Bedarf von Hand anzulegen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HolidayRegion {
+    Germany,
+    BadenWuerttemberg,
+    Bavaria,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hesse,
+    MecklenburgWesternPomerania,
+    LowerSaxony,
+    NorthRhineWestphalia,
+    RhinelandPalatinate,
+    Saarland,
+    Saxony,
+    SaxonyAnhalt,
+    SchleswigHolstein,
+    Thuringia,
+}
+
+impl HolidayRegion {
+    pub const ALL: [HolidayRegion; 17] = [
+        HolidayRegion::Germany,
+        HolidayRegion::BadenWuerttemberg,
+        HolidayRegion::Bavaria,
+        HolidayRegion::Berlin,
+        HolidayRegion::Brandenburg,
+        HolidayRegion::Bremen,
+        HolidayRegion::Hamburg,
+        HolidayRegion::Hesse,
+        HolidayRegion::MecklenburgWesternPomerania,
+        HolidayRegion::LowerSaxony,
+        HolidayRegion::NorthRhineWestphalia,
+        HolidayRegion::RhinelandPalatinate,
+        HolidayRegion::Saarland,
+        HolidayRegion::Saxony,
+        HolidayRegion::SaxonyAnhalt,
+        HolidayRegion::SchleswigHolstein,
+        HolidayRegion::Thuringia,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            HolidayRegion::Germany => "DE",
+            HolidayRegion::BadenWuerttemberg => "DE-BW",
+            HolidayRegion::Bavaria => "DE-BY",
+            HolidayRegion::Berlin => "DE-BE",
+            HolidayRegion::Brandenburg => "DE-BB",
+            HolidayRegion::Bremen => "DE-HB",
+            HolidayRegion::Hamburg => "DE-HH",
+            HolidayRegion::Hesse => "DE-HE",
+            HolidayRegion::MecklenburgWesternPomerania => "DE-MV",
+            HolidayRegion::LowerSaxony => "DE-NI",
+            HolidayRegion::NorthRhineWestphalia => "DE-NW",
+            HolidayRegion::RhinelandPalatinate => "DE-RP",
+            HolidayRegion::Saarland => "DE-SL",
+            HolidayRegion::Saxony => "DE-SN",
+            HolidayRegion::SaxonyAnhalt => "DE-ST",
+            HolidayRegion::SchleswigHolstein => "DE-SH",
+            HolidayRegion::Thuringia => "DE-TH",
+        }
+    }
+}
+
+impl FromStr for HolidayRegion {
+    type Err = HolidayRegionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HolidayRegion::ALL
+            .into_iter()
+            .find(|region| region.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| HolidayRegionParseError::InvalidRegion(s.into()))
+    }
+}
+
+/// Ein berechneter Feiertag und der `SpecialDay`, der an diesem Datum schon
+/// existiert (dann wird nichts angelegt).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HolidayCandidate {
+    pub date: Date,
+    pub name: Arc<str>,
+    pub existing: Option<SpecialDay>,
+}
+
+#[automock(type Context=();)]
+#[async_trait]
+pub trait HolidayCalendarService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+
+    /// Alle Feiertage des Kalenderjahres `year` in `region`, nach Datum
+    /// sortiert. Permission: Shiftplanner.
+    async fn preview(
+        &self,
+        year: u32,
+        region: HolidayRegion,
+        context: Authentication<Self::Context>,
+    ) -> Result<Arc<[HolidayCandidate]>, ServiceError>;
+
+    /// Legt die Feiertage aus [`Self::preview`] ohne `existing` als `Holiday`
+    /// an und gibt nur die neu angelegten zurück. Permission: Shiftplanner.
+    async fn generate(
+        &self,
+        year: u32,
+        region: HolidayRegion,
+        context: Authentication<Self::Context>,
+    ) -> Result<Arc<[SpecialDay]>, ServiceError>;
+}
@@ -1,17 +1,52 @@
 //! Service which provides iCalendar data.
+//!
+//! [`IcalService`] formatiert nur nach RFC 5545 (VTIMEZONE, Escaping,
+//! Zeilenfaltung, CRLF). Welche Termine im Feed landen und wie UID und
+//! SEQUENCE gebildet werden, entscheidet der Aufrufer, z. B.
+//! `BlockService::get_blocks_for_next_weeks_as_ical`.
 
 use crate::ServiceError;
 use mockall::automock;
 use std::sync::Arc;
+use time::PrimitiveDateTime;
 
-use crate::block::Block;
+/// Ein VEVENT.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcalEvent {
+    /// Stabile UID; Kalender-Apps erkennen daran Änderungen und Absagen.
+    pub uid: Arc<str>,
+    /// SEQUENCE; muss bei jeder Änderung des Termins steigen.
+    pub sequence: u32,
+    /// Ortszeit in der Zeitzone des Kalenders.
+    pub start: PrimitiveDateTime,
+    /// Ortszeit in der Zeitzone des Kalenders; bei `all_day` der erste Tag
+    /// nach dem Termin.
+    pub end: PrimitiveDateTime,
+    /// Ganztägig: nur das Datum von `start`/`end` zählt (`VALUE=DATE`).
+    pub all_day: bool,
+    pub summary: Arc<str>,
+    pub description: Option<Arc<str>>,
+    /// `STATUS:CANCELLED` statt `STATUS:CONFIRMED`.
+    pub cancelled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcalCalendar {
+    /// Anzeigename (`X-WR-CALNAME`).
+    pub name: Arc<str>,
+    /// IANA-Zeitzone, z. B. `Europe/Berlin`.
+    pub timezone: Arc<str>,
+    pub events: Arc<[IcalEvent]>,
+}
 
 #[automock]
 pub trait IcalService {
-    fn convert_blocks_to_ical_string(
+    /// Erzeugt den VCALENDAR-Text. `generated_at` ist UTC und wird als
+    /// DTSTAMP jedes Events geschrieben. Bei unbekannter Zeitzone entfällt
+    /// VTIMEZONE und die Zeiten werden als lokale Zeiten ohne TZID geschrieben.
+    fn render_calendar(
         &self,
-        blocks: Arc<[Block]>,
-        title: Arc<str>,
-        timezone: Arc<str>,
+        calendar: &IcalCalendar,
+        generated_at: PrimitiveDateTime,
     ) -> Result<Arc<str>, ServiceError>;
 }
@@ -0,0 +1,120 @@
+//! Geheime, widerrufbare Feed-Tokens für iCal-Kalender.
+//!
+//! Kalender-Apps können keine Session-Cookies mitschicken. Über
+//! `/ical/{token}` liefert [`IcalFeedTokenService::get_feed`] deshalb den
+//! Kalender zum [`IcalFeedScope`] des Tokens ohne Anmeldung. Wer Tokens
+//! eines Scopes verwalten darf, hängt am Scope:
+//!
+//! - [`IcalFeedScope::SalesPerson`] — der Mitarbeiter selbst oder ein Shiftplanner,
+//! - [`IcalFeedScope::Shiftplan`] — Shiftplanner,
+//! - [`IcalFeedScope::TeamAbsences`] — HR.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::ical_feed_token::{IcalFeedScopeEntity, IcalFeedTokenEntity};
+use mockall::automock;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+/// Wessen Kalender ein Token freigibt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum IcalFeedScope {
+    /// Die Blöcke eines Mitarbeiters.
+    SalesPerson(Uuid),
+    /// Alle Slots eines Schichtplans.
+    Shiftplan(Uuid),
+    /// Abwesenheiten aller Mitarbeiter und Sondertage.
+    TeamAbsences,
+}
+
+impl From<&IcalFeedScopeEntity> for IcalFeedScope {
+    fn from(entity: &IcalFeedScopeEntity) -> Self {
+        match entity {
+            IcalFeedScopeEntity::SalesPerson(id) => Self::SalesPerson(*id),
+            IcalFeedScopeEntity::Shiftplan(id) => Self::Shiftplan(*id),
+            IcalFeedScopeEntity::TeamAbsences => Self::TeamAbsences,
+        }
+    }
+}
+impl From<&IcalFeedScope> for IcalFeedScopeEntity {
+    fn from(scope: &IcalFeedScope) -> Self {
+        match scope {
+            IcalFeedScope::SalesPerson(id) => Self::SalesPerson(*id),
+            IcalFeedScope::Shiftplan(id) => Self::Shiftplan(*id),
+            IcalFeedScope::TeamAbsences => Self::TeamAbsences,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct IcalFeedToken {
+    pub id: Uuid,
+    pub scope: IcalFeedScope,
+    /// Das Geheimnis selbst; landet bewusst nicht im Audit-Log.
+    #[serde(skip_serializing)]
+    pub token: Uuid,
+    pub label: Arc<str>,
+    pub created: Option<PrimitiveDateTime>,
+    pub revoked: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl From<&IcalFeedTokenEntity> for IcalFeedToken {
+    fn from(entity: &IcalFeedTokenEntity) -> Self {
+        Self {
+            id: entity.id,
+            scope: (&entity.scope).into(),
+            token: entity.token,
+            label: entity.label.clone(),
+            created: Some(entity.created),
+            revoked: entity.revoked,
+            version: entity.version,
+        }
+    }
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait IcalFeedTokenService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Alle Tokens des Scopes inklusive widerrufener.
+    async fn get_for_scope(
+        &self,
+        scope: IcalFeedScope,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[IcalFeedToken]>, ServiceError>;
+
+    async fn create(
+        &self,
+        scope: IcalFeedScope,
+        label: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalFeedToken, ServiceError>;
+
+    /// Widerruft das Token; ein zweiter Aufruf ist ein No-op.
+    async fn revoke(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalFeedToken, ServiceError>;
+
+    /// Der iCal-Feed zum Token. Unbekannte und widerrufene Tokens liefern
+    /// `EntityNotFound`, damit ein Aufrufer nicht unterscheiden kann, ob es
+    /// das Token je gab.
+    async fn get_feed(
+        &self,
+        token: Uuid,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError>;
+}
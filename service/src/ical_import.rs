@@ -0,0 +1,144 @@
+//! Import von Verfügbarkeit und Abwesenheiten aus externen iCalendar-Dateien.
+//!
+//! Mitarbeiter pflegen Urlaub und andere Termine oft im eigenen Kalender.
+//! Der Import läuft in zwei Schritten:
+//!
+//! 1. [`IcalImportService::preview`] liest eine hochgeladene `.ics`-Datei
+//!    oder eine abonnierte URL, bildet die Termine auf Vorschläge für den
+//!    gewählten [`IcalImportTarget`] ab und vergleicht sie mit dem Bestand
+//!    (neu / schon vorhanden / Konflikt). Es wird nichts geschrieben.
+//! 2. [`IcalImportService::apply`] legt die akzeptierten Einträge über
+//!    `SalesPersonUnavailableService::create` bzw. `AbsenceService::create`
+//!    an — Berechtigungen, Validierung und die
+//!    [`crate::warning::Warning`]-Konfliktlogik laufen dort wie bei einer
+//!    manuellen Eingabe.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use serde::Serialize;
+use time::Date;
+use uuid::Uuid;
+
+use crate::absence::{AbsenceCategory, AbsencePeriod};
+use crate::permission::Authentication;
+use crate::sales_person_unavailable::SalesPersonUnavailable;
+use crate::warning::Warning;
+use crate::ServiceError;
+
+/// Herkunft des Kalenders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IcalImportSource {
+    /// Inhalt einer hochgeladenen `.ics`-Datei.
+    Content(Arc<str>),
+    /// `http(s)://`- oder `webcal://`-URL eines abonnierten Kalenders.
+    Url(Arc<str>),
+}
+
+/// Worauf die Termine abgebildet werden.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum IcalImportTarget {
+    /// Ein `SalesPersonUnavailable`-Eintrag pro Tag des Termins.
+    Unavailable,
+    /// Eine `AbsencePeriod` der Kategorie pro Termin.
+    Absence(AbsenceCategory),
+}
+
+/// Abgleich eines Vorschlags mit dem Bestand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum IcalImportStatus {
+    New,
+    /// Derselbe Tag bzw. dieselbe Periode existiert schon; `apply`
+    /// überspringt den Eintrag.
+    AlreadyPresent,
+    /// Überlappt die `AbsencePeriod` mit dieser Id (gleiche Kategorie);
+    /// `apply` würde mit `OverlappingPeriod` scheitern.
+    Conflict(Uuid),
+}
+
+/// Ein Vorschlag aus einem Termin. Bei [`IcalImportTarget::Unavailable`]
+/// ist `from_date == to_date` (ein Eintrag pro Tag), bei
+/// [`IcalImportTarget::Absence`] sind beide Grenzen inklusive.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct IcalImportEntry {
+    pub uid: Arc<str>,
+    pub summary: Arc<str>,
+    pub from_date: Date,
+    pub to_date: Date,
+    pub status: IcalImportStatus,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum IcalImportSkipReason {
+    /// `RRULE`/`RDATE` — Wiederholungen werden nicht expandiert.
+    Recurring,
+    /// `STATUS:CANCELLED`.
+    Cancelled,
+    /// `TRANSP:TRANSPARENT` — der Termin blockiert keine Zeit.
+    Free,
+    /// Endet vor heute.
+    Past,
+    /// Länger als ein Jahr.
+    TooLong,
+    /// Ende vor Beginn oder fehlendes `DTSTART`.
+    InvalidDates,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct IcalImportSkipped {
+    pub uid: Arc<str>,
+    pub summary: Arc<str>,
+    pub reason: IcalImportSkipReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct IcalImportPreview {
+    pub sales_person_id: Uuid,
+    pub target: IcalImportTarget,
+    pub entries: Arc<[IcalImportEntry]>,
+    pub skipped: Arc<[IcalImportSkipped]>,
+}
+
+/// Ergebnis von [`IcalImportService::apply`]: die angelegten Einträge und
+/// alle Warnings, die `AbsenceService::create` dabei geliefert hat.
+#[derive(Clone, Debug)]
+pub struct IcalImportResult {
+    pub unavailables: Arc<[SalesPersonUnavailable]>,
+    pub absences: Arc<[AbsencePeriod]>,
+    pub warnings: Arc<[Warning]>,
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait IcalImportService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Liest den Kalender und liefert die Vorschläge samt Abgleich.
+    ///
+    /// Permission: der Mitarbeiter selbst oder — je nach Ziel — Shiftplanner
+    /// (`Unavailable`) bzw. HR (`Absence`). Nicht lesbare Kalender und nicht
+    /// erreichbare URLs liefern einen `ValidationError`.
+    async fn preview(
+        &self,
+        sales_person_id: Uuid,
+        source: IcalImportSource,
+        target: IcalImportTarget,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalImportPreview, ServiceError>;
+
+    /// Legt die akzeptierten Einträge in einer Transaktion an. Einträge, die
+    /// inzwischen vorhanden sind, werden übersprungen; jeder andere Fehler
+    /// bricht den ganzen Import ab. `status` der Einträge wird ignoriert.
+    async fn apply(
+        &self,
+        sales_person_id: Uuid,
+        target: IcalImportTarget,
+        entries: &[IcalImportEntry],
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalImportResult, ServiceError>;
+}
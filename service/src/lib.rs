@@ -6,6 +6,8 @@ use uuid::Uuid;
 
 pub mod absence;
 pub mod absence_conversion;
+pub mod absence_request;
+pub mod audit_log;
 pub mod billing_period;
 pub mod billing_period_report;
 pub mod block;
@@ -19,27 +21,46 @@ pub mod config;
 pub mod custom_extra_hours;
 pub mod datetime_utils;
 pub mod employee_work_details;
+pub mod export_target;
+pub mod export_target_scheduler;
 pub mod extra_hours;
 pub mod feature_flag;
+pub mod holiday_calendar;
 pub mod ical;
+pub mod ical_feed_token;
+pub mod ical_import;
+pub mod notification;
+pub mod payroll_export;
+pub mod payroll_wage_type;
 pub mod pdf_export;
 pub mod pdf_export_config;
+pub mod pdf_personal;
 pub mod pdf_shiftplan;
 pub mod permission;
+pub mod qualification;
 pub mod rebooking_batch;
+pub mod rebooking_reconciliation;
+pub mod report_export;
 pub mod reporting;
 pub mod sales_person;
+pub mod sales_person_availability;
 pub mod sales_person_shiftplan;
 pub mod sales_person_unavailable;
 pub mod scheduler;
+pub mod scheduler_job;
 pub mod session;
+pub mod shift_swap;
 pub mod shiftplan;
 pub mod shiftplan_catalog;
 pub mod shiftplan_edit;
+pub mod shiftplan_generator;
 pub mod shiftplan_report;
 pub mod slot;
 pub mod special_days;
+pub mod team_ical;
 pub mod text_template;
+pub mod text_template_preview;
+pub mod time_entry;
 pub mod toggle;
 pub mod user_invitation;
 pub mod user_service;
@@ -48,6 +69,7 @@ pub mod vacation_balance;
 pub mod vacation_entitlement_offset;
 pub mod voluntary_stats;
 pub mod warning;
+pub mod webhook;
 pub mod week_message;
 pub mod week_status;
 
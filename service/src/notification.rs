@@ -0,0 +1,309 @@
+//! E-Mail-Benachrichtigungen (Basic-Tier).
+//!
+//! Fachliche Services melden Ereignisse über [`NotificationService::notify`].
+//! Der Service ermittelt die Empfänger aus den Benutzer-Einstellungen,
+//! rendert Betreff und Text aus dem TextTemplate des Ereignistyps
+//! (`notification_<kind>`, erste Zeile = Betreff) und legt die Nachrichten
+//! in derselben Transaktion in der Outbox ab. Zugestellt wird getrennt davon
+//! über [`NotificationService::deliver_pending`] (Scheduler-Job).
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::notification::{
+    NotificationKind as NotificationKindEntity, NotificationOutboxEntity,
+    NotificationPreferenceEntity, NotificationState as NotificationStateEntity,
+};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use shifty_utils::DayOfWeek;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+use crate::absence::AbsenceCategory;
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    BookingDeleted,
+    WeekPlanned,
+    AbsenceCreated,
+    InvitationCreated,
+}
+
+impl NotificationKind {
+    /// TextTemplate-Typ, aus dem Betreff und Text gerendert werden.
+    pub fn template_type(&self) -> &'static str {
+        match self {
+            Self::BookingDeleted => "notification_booking_deleted",
+            Self::WeekPlanned => "notification_week_planned",
+            Self::AbsenceCreated => "notification_absence_created",
+            Self::InvitationCreated => "notification_invitation_created",
+        }
+    }
+}
+
+impl From<NotificationKindEntity> for NotificationKind {
+    fn from(kind: NotificationKindEntity) -> Self {
+        match kind {
+            NotificationKindEntity::BookingDeleted => Self::BookingDeleted,
+            NotificationKindEntity::WeekPlanned => Self::WeekPlanned,
+            NotificationKindEntity::AbsenceCreated => Self::AbsenceCreated,
+            NotificationKindEntity::InvitationCreated => Self::InvitationCreated,
+        }
+    }
+}
+
+impl From<NotificationKind> for NotificationKindEntity {
+    fn from(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::BookingDeleted => Self::BookingDeleted,
+            NotificationKind::WeekPlanned => Self::WeekPlanned,
+            NotificationKind::AbsenceCreated => Self::AbsenceCreated,
+            NotificationKind::InvitationCreated => Self::InvitationCreated,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationState {
+    Pending,
+    Sent,
+    Failed,
+}
+
+impl From<NotificationStateEntity> for NotificationState {
+    fn from(state: NotificationStateEntity) -> Self {
+        match state {
+            NotificationStateEntity::Pending => Self::Pending,
+            NotificationStateEntity::Sent => Self::Sent,
+            NotificationStateEntity::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<NotificationState> for NotificationStateEntity {
+    fn from(state: NotificationState) -> Self {
+        match state {
+            NotificationState::Pending => Self::Pending,
+            NotificationState::Sent => Self::Sent,
+            NotificationState::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Fachliches Ereignis, das eine Benachrichtigung auslöst. Die Varianten
+/// tragen alles, was die Templates brauchen, damit der Service keine
+/// weiteren Fach-Services aufrufen muss.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotificationEvent {
+    /// Ein Booking wurde gelöscht; benachrichtigt die gebuchte Person.
+    BookingDeleted {
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+        day_of_week: DayOfWeek,
+        from: Time,
+        to: Time,
+    },
+    /// Eine Woche wurde auf `Planned` gesetzt; geht an alle Abonnenten.
+    WeekPlanned { year: u32, calendar_week: u8 },
+    /// Eine Abwesenheit wurde erfasst; benachrichtigt die betroffene Person.
+    AbsenceCreated {
+        sales_person_id: Uuid,
+        category: AbsenceCategory,
+        from_date: Date,
+        to_date: Date,
+    },
+    /// Eine Einladung wurde erzeugt; benachrichtigt den eingeladenen Benutzer.
+    /// Hat er noch keine Einstellungen, geht die Mail an `email`.
+    InvitationCreated {
+        username: Arc<str>,
+        email: Option<Arc<str>>,
+        token: Uuid,
+        expiration_date: OffsetDateTime,
+    },
+}
+
+impl NotificationEvent {
+    pub fn kind(&self) -> NotificationKind {
+        match self {
+            Self::BookingDeleted { .. } => NotificationKind::BookingDeleted,
+            Self::WeekPlanned { .. } => NotificationKind::WeekPlanned,
+            Self::AbsenceCreated { .. } => NotificationKind::AbsenceCreated,
+            Self::InvitationCreated { .. } => NotificationKind::InvitationCreated,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct NotificationPreference {
+    pub user_id: Arc<str>,
+    pub email: Arc<str>,
+    pub booking_deleted: bool,
+    pub week_planned: bool,
+    pub absence_created: bool,
+    pub invitation_created: bool,
+    pub created: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl NotificationPreference {
+    pub fn is_subscribed(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::BookingDeleted => self.booking_deleted,
+            NotificationKind::WeekPlanned => self.week_planned,
+            NotificationKind::AbsenceCreated => self.absence_created,
+            NotificationKind::InvitationCreated => self.invitation_created,
+        }
+    }
+}
+
+impl From<&NotificationPreferenceEntity> for NotificationPreference {
+    fn from(entity: &NotificationPreferenceEntity) -> Self {
+        Self {
+            user_id: entity.user_id.clone(),
+            email: entity.email.clone(),
+            booking_deleted: entity.booking_deleted,
+            week_planned: entity.week_planned,
+            absence_created: entity.absence_created,
+            invitation_created: entity.invitation_created,
+            created: Some(entity.created),
+            version: entity.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationMessage {
+    pub id: Uuid,
+    pub recipient_user_id: Arc<str>,
+    pub recipient_email: Arc<str>,
+    pub kind: NotificationKind,
+    pub subject: Arc<str>,
+    pub body: Arc<str>,
+    pub state: NotificationState,
+    pub attempts: u32,
+    pub next_attempt: PrimitiveDateTime,
+    pub last_error: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub sent: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl From<&NotificationOutboxEntity> for NotificationMessage {
+    fn from(entity: &NotificationOutboxEntity) -> Self {
+        Self {
+            id: entity.id,
+            recipient_user_id: entity.recipient_user_id.clone(),
+            recipient_email: entity.recipient_email.clone(),
+            kind: entity.kind.into(),
+            subject: entity.subject.clone(),
+            body: entity.body.clone(),
+            state: entity.state.into(),
+            attempts: entity.attempts,
+            next_attempt: entity.next_attempt,
+            last_error: entity.last_error.clone(),
+            created: entity.created,
+            sent: entity.sent,
+            version: entity.version,
+        }
+    }
+}
+
+impl From<&NotificationMessage> for NotificationOutboxEntity {
+    fn from(message: &NotificationMessage) -> Self {
+        Self {
+            id: message.id,
+            recipient_user_id: message.recipient_user_id.clone(),
+            recipient_email: message.recipient_email.clone(),
+            kind: message.kind.into(),
+            subject: message.subject.clone(),
+            body: message.body.clone(),
+            state: message.state.into(),
+            attempts: message.attempts,
+            next_attempt: message.next_attempt,
+            last_error: message.last_error.clone(),
+            created: message.created,
+            sent: message.sent,
+            version: message.version,
+        }
+    }
+}
+
+/// Ergebnis eines Versand-Laufs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NotificationDeliveryReport {
+    pub sent: u32,
+    /// Fehlgeschlagen, wird später erneut versucht.
+    pub retried: u32,
+    /// Fehlgeschlagen, keine weiteren Versuche.
+    pub failed: u32,
+}
+
+#[automock(type Context=(); type Transaction=MockTransaction;)]
+#[async_trait]
+pub trait NotificationService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction + Debug + Clone + Send + Sync + 'static;
+
+    /// Legt für alle abonnierten Empfänger des Ereignisses eine Nachricht in
+    /// der Outbox ab. Interner Aufruf der Fach-Services, daher kein
+    /// Permission-Check. Ohne Empfänger ist der Aufruf ein No-op.
+    async fn notify(
+        &self,
+        event: &NotificationEvent,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Einstellungen des angemeldeten Benutzers, `None` ohne Eintrag.
+    async fn get_own_preference(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Option<NotificationPreference>, ServiceError>;
+
+    /// Einstellungen eines Benutzers. Permission: HR ∨ der Benutzer selbst.
+    async fn get_preference(
+        &self,
+        user_id: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Option<NotificationPreference>, ServiceError>;
+
+    /// Legt die Einstellungen an oder überschreibt sie (Upsert). Permission:
+    /// HR ∨ der Benutzer selbst.
+    async fn set_preference(
+        &self,
+        preference: &NotificationPreference,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<NotificationPreference, ServiceError>;
+
+    /// Die jüngsten Outbox-Einträge, neueste zuerst. Permission: Admin.
+    async fn get_outbox(
+        &self,
+        limit: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[NotificationMessage]>, ServiceError>;
+
+    /// Setzt einen `Failed`-Eintrag zurück auf `Pending`. Permission: Admin.
+    async fn retry(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<NotificationMessage, ServiceError>;
+
+    /// Stellt alle fälligen `Pending`-Nachrichten zu. Jede Nachricht läuft in
+    /// einer eigenen Transaktion, damit ein Fehlschlag die anderen nicht
+    /// zurückrollt. Nur mit `Authentication::Full` (Scheduler).
+    async fn deliver_pending(
+        &self,
+        context: Authentication<Self::Context>,
+    ) -> Result<NotificationDeliveryReport, ServiceError>;
+}
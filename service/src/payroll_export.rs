@@ -0,0 +1,38 @@
+//! Lohnexport eines Abrechnungszeitraums im DATEV-LODAS-Format
+//! (Business-Logic-Tier).
+//!
+//! Das Lohnprogramm importiert Bewegungsdaten als ASCII-Datei: ein
+//! `[Allgemein]`-Kopf mit Berater- und Mandantennummer, eine
+//! Satzbeschreibung und pro Mitarbeiter und Lohnart eine Zeile
+//! `Abrechnungsmonat;Personalnummer;Lohnart;Bearbeitungsschlüssel;Wert`.
+//!
+//! Welche Werte exportiert werden, bestimmt die Lohnarten-Zuordnung
+//! ([`crate::payroll_wage_type::PayrollWageTypeService`]). Werte 0 entfallen.
+//! Mitarbeiter mit exportierten Werten brauchen eine Personalnummer
+//! (`SalesPerson::personnel_number`), sonst schlägt der Export fehl.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::report_export::ExportFile;
+use crate::ServiceError;
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait PayrollExportService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// DATEV-LODAS-Importdatei für den Abrechnungszeitraum. Abrechnungsmonat
+    /// ist der Monat des letzten Tags. Permission: HR.
+    async fn export_billing_period(
+        &self,
+        billing_period_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+}
@@ -0,0 +1,137 @@
+//! Lohnarten-Zuordnung für den Lohnexport (Basic-Service).
+//!
+//! HR legt fest, welcher Werttyp eines Abrechnungszeitraums in welcher Sicht
+//! unter welcher Lohnart an das Lohnprogramm geht, z. B. „Zusatzarbeit,
+//! Unterschied → Lohnart 200“. Der [`crate::payroll_export::PayrollExportService`]
+//! schreibt pro Mitarbeiter und Zuordnung eine Zeile.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::payroll_wage_type::PayrollWageTypeEntity;
+use mockall::automock;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::billing_period::{
+    BillingPeriodValue, BillingPeriodValueType, BillingPeriodValueTypeParseError,
+};
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+/// Welcher der vier Werte eines [`BillingPeriodValue`] exportiert wird.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum PayrollValueView {
+    Delta,
+    YtdFrom,
+    YtdTo,
+    FullYear,
+}
+
+impl PayrollValueView {
+    pub fn select(&self, value: &BillingPeriodValue) -> f32 {
+        match self {
+            Self::Delta => value.value_delta,
+            Self::YtdFrom => value.value_ytd_from,
+            Self::YtdTo => value.value_ytd_to,
+            Self::FullYear => value.value_full_year,
+        }
+    }
+}
+
+impl From<dao::payroll_wage_type::PayrollValueView> for PayrollValueView {
+    fn from(value_view: dao::payroll_wage_type::PayrollValueView) -> Self {
+        match value_view {
+            dao::payroll_wage_type::PayrollValueView::Delta => Self::Delta,
+            dao::payroll_wage_type::PayrollValueView::YtdFrom => Self::YtdFrom,
+            dao::payroll_wage_type::PayrollValueView::YtdTo => Self::YtdTo,
+            dao::payroll_wage_type::PayrollValueView::FullYear => Self::FullYear,
+        }
+    }
+}
+
+impl From<PayrollValueView> for dao::payroll_wage_type::PayrollValueView {
+    fn from(value_view: PayrollValueView) -> Self {
+        match value_view {
+            PayrollValueView::Delta => Self::Delta,
+            PayrollValueView::YtdFrom => Self::YtdFrom,
+            PayrollValueView::YtdTo => Self::YtdTo,
+            PayrollValueView::FullYear => Self::FullYear,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PayrollWageType {
+    pub id: Uuid,
+    pub value_type: BillingPeriodValueType,
+    pub value_view: PayrollValueView,
+    /// Lohnart-Nummer im Lohnprogramm, z. B. `200`.
+    pub wage_type: Arc<str>,
+    /// DATEV-Bearbeitungsschlüssel, wie in den Lohnarten-Stammdaten des
+    /// Steuerbüros hinterlegt.
+    pub processing_key: u8,
+    pub description: Arc<str>,
+    pub created: Option<PrimitiveDateTime>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl TryFrom<&PayrollWageTypeEntity> for PayrollWageType {
+    type Error = BillingPeriodValueTypeParseError;
+
+    fn try_from(entity: &PayrollWageTypeEntity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: entity.id,
+            value_type: BillingPeriodValueType::from_str(&entity.value_type)?,
+            value_view: entity.value_view.into(),
+            wage_type: entity.wage_type.clone(),
+            processing_key: entity.processing_key,
+            description: entity.description.clone(),
+            created: Some(entity.created),
+            deleted: entity.deleted,
+            version: entity.version,
+        })
+    }
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait PayrollWageTypeService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Alle Zuordnungen. Permission: HR.
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PayrollWageType]>, ServiceError>;
+
+    /// Permission: HR. Lohnart darf nicht leer sein.
+    async fn create(
+        &self,
+        wage_type: &PayrollWageType,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<PayrollWageType, ServiceError>;
+
+    /// Permission: HR. `version` muss zum gespeicherten Stand passen.
+    async fn update(
+        &self,
+        wage_type: &PayrollWageType,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<PayrollWageType, ServiceError>;
+
+    /// Permission: HR. Soft-Delete.
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+}
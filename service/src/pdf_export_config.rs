@@ -19,25 +19,32 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use dao::pdf_export_config::PdfExportConfigEntity;
+use dao::pdf_export_config::{PdfExportConfigEntity, PdfExportShiftplanEntity};
 use mockall::automock;
+use serde::Serialize;
 use uuid::Uuid;
 
-use crate::{permission::Authentication, ServiceError};
+use crate::{audit_log::serialize_redacted, permission::Authentication, ServiceError};
 
 /// Vollständige PDF-Export-Konfiguration (inkl. Status-Feldern). Interne
 /// Domain-Struktur — beim Übergang in das REST-DTO (`PdfExportConfigTO`) wird
 /// `webdav_app_token` maskiert (T-48-02).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct PdfExportConfig {
     pub id: Uuid,
     pub enabled: bool,
     pub nextcloud_url: Option<Arc<str>>,
     pub webdav_user: Option<Arc<str>>,
+    #[serde(serialize_with = "serialize_redacted")]
     pub webdav_app_token: Option<Arc<str>>,
     pub target_folder: Option<Arc<str>>,
     pub weeks_horizon: u32,
     pub cron_schedule: Arc<str>,
+    /// Reine Planungs-Shiftplans (`Shiftplan::is_planning`) mit exportieren.
+    pub include_planning_plans: bool,
+    /// Eine Datei pro Woche mit einer Seite je Shiftplan statt einer Datei
+    /// pro Shiftplan und Woche.
+    pub combined_pdf: bool,
     pub last_success_at: Option<time::PrimitiveDateTime>,
     pub last_error_at: Option<time::PrimitiveDateTime>,
     pub last_error_message: Option<Arc<str>>,
@@ -55,6 +62,8 @@ impl From<&PdfExportConfigEntity> for PdfExportConfig {
             target_folder: entity.target_folder.clone(),
             weeks_horizon: entity.weeks_horizon,
             cron_schedule: entity.cron_schedule.clone(),
+            include_planning_plans: entity.include_planning_plans,
+            combined_pdf: entity.combined_pdf,
             last_success_at: entity.last_success_at,
             last_error_at: entity.last_error_at,
             last_error_message: entity.last_error_message.clone(),
@@ -78,6 +87,46 @@ pub struct PdfExportConfigUpdate {
     pub target_folder: Option<Arc<str>>,
     pub weeks_horizon: u32,
     pub cron_schedule: Arc<str>,
+    pub include_planning_plans: bool,
+    pub combined_pdf: bool,
+}
+
+/// Export-Einstellung für einen Shiftplan. Shiftplans ohne Eintrag gelten
+/// als eingeschlossen, ohne Unterordner und ohne eigenes Präfix.
+///
+/// - `subfolder` — relativ zu `target_folder`, darf `/` enthalten, aber
+///   keine `..`-Segmente.
+/// - `filename_prefix` — wird mit `-` vor den Wochen-Dateinamen gesetzt.
+///
+/// Im Sammel-PDF-Modus (`combined_pdf`) zählt nur `included`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PdfExportShiftplanSetting {
+    pub shiftplan_id: Uuid,
+    pub included: bool,
+    pub subfolder: Option<Arc<str>>,
+    pub filename_prefix: Option<Arc<str>>,
+}
+
+impl From<&PdfExportShiftplanEntity> for PdfExportShiftplanSetting {
+    fn from(entity: &PdfExportShiftplanEntity) -> Self {
+        Self {
+            shiftplan_id: entity.shiftplan_id,
+            included: entity.included,
+            subfolder: entity.subfolder.clone(),
+            filename_prefix: entity.filename_prefix.clone(),
+        }
+    }
+}
+
+impl From<&PdfExportShiftplanSetting> for PdfExportShiftplanEntity {
+    fn from(setting: &PdfExportShiftplanSetting) -> Self {
+        Self {
+            shiftplan_id: setting.shiftplan_id,
+            included: setting.included,
+            subfolder: setting.subfolder.clone(),
+            filename_prefix: setting.filename_prefix.clone(),
+        }
+    }
 }
 
 #[automock(type Context=(); type Transaction=dao::MockTransaction;)]
@@ -119,4 +168,23 @@ pub trait PdfExportConfigService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<(), ServiceError>;
+
+    /// Admin-gated. Alle gespeicherten Shiftplan-Einstellungen; Shiftplans
+    /// ohne Eintrag fehlen in der Liste.
+    async fn get_shiftplan_settings(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PdfExportShiftplanSetting]>, ServiceError>;
+
+    /// Admin-gated. Ersetzt die komplette Shiftplan-Auswahl. Leere
+    /// `subfolder`/`filename_prefix` werden zu `None`; `..`-Segmente,
+    /// Pfadtrenner im Präfix und doppelte Shiftplan-IDs ergeben
+    /// [`ServiceError::ValidationError`].
+    async fn update_shiftplan_settings(
+        &self,
+        settings: Arc<[PdfExportShiftplanSetting]>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PdfExportShiftplanSetting]>, ServiceError>;
 }
@@ -0,0 +1,58 @@
+//! Persönliche PDF-Dokumente eines Mitarbeiters (Business-Logic-Tier).
+//!
+//! Neben dem Wochenplan des ganzen Schichtplans
+//! ([`crate::pdf_shiftplan::PdfShiftplanService`]) gibt es zwei Dokumente pro
+//! Mitarbeiter, gerendert mit derselben `printpdf`-Pipeline
+//! (`service_impl::pdf_personal_render`):
+//!
+//! - **Dienstplan**: die eigenen Blöcke für mehrere Wochen am Stück
+//!   ([`crate::block::BlockService::get_blocks_for_sales_person_week`]).
+//! - **Stundenzettel**: ein Kalendermonat aus `EmployeeReport::by_month` mit
+//!   Tagesliste, Abwesenheiten, Feiertagen und Saldo sowie Unterschriftsfeldern
+//!   für Mitarbeiter und Arbeitgeber.
+//!
+//! Beide liefern eine fertige [`ExportFile`] mit ASCII-Dateinamen.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::report_export::ExportFile;
+use crate::ServiceError;
+
+/// Höchstzahl Wochen in einem Dienstplan-PDF.
+pub const MAX_SCHEDULE_WEEKS: u8 = 12;
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait PdfPersonalService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Dienstplan ab der ISO-Woche `year`/`calendar_week` für `weeks` Wochen
+    /// (1..=[`MAX_SCHEDULE_WEEKS`]). Erlaubt für HR, Shiftplanner und den
+    /// Mitarbeiter selbst.
+    async fn render_schedule_pdf(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+        weeks: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+
+    /// Stundenzettel für den Kalendermonat `month` (1..=12). Erlaubt für HR
+    /// und den Mitarbeiter selbst (wie `ReportingService::get_report_for_employee_range`).
+    async fn render_timesheet_pdf(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        month: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+}
@@ -25,6 +25,8 @@
 
 use std::fmt::Debug;
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use mockall::automock;
 use uuid::Uuid;
@@ -57,6 +59,21 @@ pub trait PdfShiftplanService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<Vec<u8>, ServiceError>;
+
+    /// Rendert dieselbe ISO-Woche mehrerer Shiftplans in EIN PDF — eine
+    /// Seite pro Plan in der übergebenen Reihenfolge; `shiftplans` trägt
+    /// `(id, name)`, der Name landet im Seitenkopf.
+    ///
+    /// Gleiches WeekStatus-Gate wie [`Self::render_week_pdf`] (einmal pro
+    /// Woche, vor jedem View-Read); `context` wird ebenso weitergereicht.
+    async fn render_combined_week_pdf(
+        &self,
+        shiftplans: Arc<[(Uuid, Arc<str>)]>,
+        year: u32,
+        calendar_week: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Vec<u8>, ServiceError>;
 }
 
 /// Kanonischer Download-Filename für den Wochen-PDF-Export (Phase 49 PDF-03).
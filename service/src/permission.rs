@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::Serialize;
 
 use crate::ServiceError;
 
@@ -10,7 +11,7 @@ pub const SALES_PRIVILEGE: &str = "sales";
 pub const HR_PRIVILEGE: &str = "hr";
 pub const SHIFTPLANNER_PRIVILEGE: &str = "shiftplanner";
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct User {
     pub name: Arc<str>,
 }
@@ -22,7 +23,7 @@ impl From<&dao::UserEntity> for User {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct Role {
     pub name: Arc<str>,
 }
@@ -34,7 +35,7 @@ impl From<&dao::RoleEntity> for Role {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct Privilege {
     pub name: Arc<str>,
 }
@@ -0,0 +1,223 @@
+//! Qualifikationen (Basic-Service).
+//!
+//! Ein Katalog von Qualifikationen wie „Kasse“, „Stapler“ oder
+//! „Schlüsselträger“, ihre Zuordnung zu Mitarbeitern und pro Slot die
+//! Anforderung, dass mindestens `min_count` der gebuchten Personen eine
+//! Qualifikation haben. Die Prüfung selbst übernehmen
+//! `ShiftplanEditService` (Warning beim Buchen) und
+//! `BookingInformationService` (Konfliktliste der Woche) über
+//! [`unmet_requirements`].
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::qualification::{
+    QualificationEntity, SalesPersonQualificationEntity, SlotQualificationRequirementEntity,
+};
+use mockall::automock;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Qualification {
+    pub id: Uuid,
+    pub name: Arc<str>,
+    pub description: Arc<str>,
+    pub created: Option<PrimitiveDateTime>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl From<&QualificationEntity> for Qualification {
+    fn from(entity: &QualificationEntity) -> Self {
+        Self {
+            id: entity.id,
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+            created: Some(entity.created),
+            deleted: entity.deleted,
+            version: entity.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SalesPersonQualification {
+    pub sales_person_id: Uuid,
+    pub qualification_id: Uuid,
+}
+
+impl From<&SalesPersonQualificationEntity> for SalesPersonQualification {
+    fn from(entity: &SalesPersonQualificationEntity) -> Self {
+        Self {
+            sales_person_id: entity.sales_person_id,
+            qualification_id: entity.qualification_id,
+        }
+    }
+}
+
+/// Mindestens `min_count` der in einer Woche auf `slot_id` gebuchten
+/// Personen müssen `qualification_id` haben.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SlotQualificationRequirement {
+    pub slot_id: Uuid,
+    pub qualification_id: Uuid,
+    pub min_count: u8,
+}
+
+impl From<&SlotQualificationRequirementEntity> for SlotQualificationRequirement {
+    fn from(entity: &SlotQualificationRequirementEntity) -> Self {
+        Self {
+            slot_id: entity.slot_id,
+            qualification_id: entity.qualification_id,
+            min_count: entity.min_count,
+        }
+    }
+}
+
+impl From<&SlotQualificationRequirement> for SlotQualificationRequirementEntity {
+    fn from(requirement: &SlotQualificationRequirement) -> Self {
+        Self {
+            slot_id: requirement.slot_id,
+            qualification_id: requirement.qualification_id,
+            min_count: requirement.min_count,
+        }
+    }
+}
+
+/// Eine nicht erfüllte [`SlotQualificationRequirement`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnmetRequirement {
+    pub qualification_id: Uuid,
+    pub required_count: u8,
+    pub qualified_count: u8,
+}
+
+/// Prüft die Anforderungen eines Slots gegen die gebuchten Personen einer
+/// Woche. `requirements` enthält nur die Anforderungen dieses Slots; wer
+/// mehrfach in `booked_sales_person_ids` steht, zählt einmal.
+pub fn unmet_requirements(
+    requirements: &[SlotQualificationRequirement],
+    booked_sales_person_ids: &[Uuid],
+    sales_person_qualifications: &[SalesPersonQualification],
+) -> Vec<UnmetRequirement> {
+    let booked: HashSet<Uuid> = booked_sales_person_ids.iter().copied().collect();
+    requirements
+        .iter()
+        .filter_map(|requirement| {
+            let qualified_count = sales_person_qualifications
+                .iter()
+                .filter(|assignment| {
+                    assignment.qualification_id == requirement.qualification_id
+                        && booked.contains(&assignment.sales_person_id)
+                })
+                .map(|assignment| assignment.sales_person_id)
+                .collect::<HashSet<_>>()
+                .len()
+                .min(u8::MAX as usize) as u8;
+            (qualified_count < requirement.min_count).then_some(UnmetRequirement {
+                qualification_id: requirement.qualification_id,
+                required_count: requirement.min_count,
+                qualified_count,
+            })
+        })
+        .collect()
+}
+
+#[automock(type Context=(); type Transaction = dao::MockTransaction;)]
+#[async_trait]
+pub trait QualificationService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[Qualification]>, ServiceError>;
+
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Qualification, ServiceError>;
+
+    async fn create(
+        &self,
+        qualification: &Qualification,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Qualification, ServiceError>;
+
+    async fn update(
+        &self,
+        qualification: &Qualification,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Qualification, ServiceError>;
+
+    /// Soft-Delete. Zuordnungen und Slot-Anforderungen mit dieser
+    /// Qualifikation werden danach nicht mehr geliefert.
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Alle Zuordnungen aller Mitarbeiter (Shiftplanner).
+    async fn get_all_sales_person_qualifications(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SalesPersonQualification]>, ServiceError>;
+
+    /// Qualifikationen eines Mitarbeiters (Shiftplanner oder der
+    /// Mitarbeiter selbst).
+    async fn get_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[Qualification]>, ServiceError>;
+
+    /// Ersetzt die Qualifikationen eines Mitarbeiters (Shiftplanner).
+    async fn set_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        qualification_ids: &[Uuid],
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    async fn get_all_slot_requirements(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SlotQualificationRequirement]>, ServiceError>;
+
+    async fn get_slot_requirements(
+        &self,
+        slot_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SlotQualificationRequirement]>, ServiceError>;
+
+    /// Ersetzt die Anforderungen eines Slots (Shiftplanner). `min_count`
+    /// muss zwischen 1 und `min_resources` des Slots liegen, jede
+    /// Qualifikation darf nur einmal vorkommen.
+    async fn set_slot_requirements(
+        &self,
+        slot_id: Uuid,
+        requirements: &[SlotQualificationRequirement],
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+}
@@ -9,20 +9,24 @@
 //! `create` sind allesamt HR-gated. Ein nicht-HR-Aufrufer erhaelt
 //! `ServiceError::Forbidden`.
 //!
-//! Phase 55 wird zusaetzliche Methoden hinzufuegen (update_state
-//! approve/reject, list_pending). In Phase 54 halten wir das Trait minimal,
-//! damit die Basic-Tier-Konvention (Basic-Service ohne Domain-Service-Dep)
-//! klar sichtbar bleibt.
+//! Phase 55 (F3/F5): `find_by_state`, `list_entries`, `update` und
+//! `update_entry` fuer den Genehmigungs-Workflow. Die fachlichen Regeln
+//! (nur Pending ist entscheidbar, gesperrte Wochen, extra_hours-Paar) liegen
+//! im Business-Logic `RebookingReconciliationService`; hier gilt nur
+//! HR-Gate + optimistisches Locking ueber `version`.
 //!
 //! `automock` erzeugt `MockRebookingBatchService` fuer downstream Tests.
 
 use crate::permission::Authentication;
 use crate::ServiceError;
 use async_trait::async_trait;
-use dao::rebooking_batch::{RebookingBatchEntity, RebookingBatchEntryEntity};
+use dao::rebooking_batch::{
+    RebookingBatchEntity, RebookingBatchEntryEntity, RebookingBatchState,
+};
 use dao::MockTransaction;
 use mockall::automock;
 use std::fmt::Debug;
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[automock(type Context=(); type Transaction=MockTransaction;)]
@@ -71,4 +75,40 @@ pub trait RebookingBatchService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<RebookingBatchEntity, ServiceError>;
+
+    /// Alle aktiven Batches im State `state`, aelteste zuerst. HR-gated.
+    async fn find_by_state(
+        &self,
+        state: RebookingBatchState,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[RebookingBatchEntity]>, ServiceError>;
+
+    /// Aktive Entries eines Batches. HR-gated.
+    async fn list_entries(
+        &self,
+        batch_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[RebookingBatchEntryEntity]>, ServiceError>;
+
+    /// Uebernimmt State, Genehmigungs- und Ablehnungsfelder. `batch.version`
+    /// muss der gespeicherten Version entsprechen, sonst
+    /// `ServiceError::EntityConflicts`; die neue Version wird vergeben.
+    /// HR-gated.
+    async fn update(
+        &self,
+        batch: &RebookingBatchEntity,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatchEntity, ServiceError>;
+
+    /// Uebernimmt die extra_hours-Referenzen eines Entries, sonst wie
+    /// [`RebookingBatchService::update`]. HR-gated.
+    async fn update_entry(
+        &self,
+        entry: &RebookingBatchEntryEntity,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatchEntryEntity, ServiceError>;
 }
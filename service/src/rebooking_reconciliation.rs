@@ -0,0 +1,227 @@
+//! Phase 55 (F3/F5): Business-Logic-Tier Rebooking-Workflow.
+//!
+//! HR schlaegt pro Mitarbeiter und ISO-Woche einen Batch vor (F3), der als
+//! `Pending` den Wochen-Slot beansprucht (D-54-DM-01). Eine Genehmigung (F5)
+//! schreibt atomar das `extra_hours`-Paar `-N VolunteerWork` / `+N ExtraWork`,
+//! beide mit `ExtraHoursSource::Rebooking`, und traegt deren Ids in die
+//! Entries ein. Eine Ablehnung schreibt keine `extra_hours`, nur Begruendung,
+//! Zeitpunkt und Benutzer.
+//!
+//! Regeln:
+//! - Nur `Pending` ist entscheidbar; eine zweite Genehmigung oder Ablehnung
+//!   endet in `ValidationError(ModificationNotAllowed("state"))`.
+//! - Vorschlag und Genehmigung sind fuer gesperrte Wochen
+//!   (`WeekStatus::Locked`) nicht moeglich (`ServiceError::WeekLocked`).
+//! - Alle Methoden sind HR-gated, ausser dem Auto-Cron (F4), der nur mit
+//!   `Authentication::Full` laeuft.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::rebooking_batch::{RebookingBatchEntity, RebookingBatchEntryEntity};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum RebookingBatchKind {
+    Manual,
+    HrSuggestion,
+    AutoCron,
+    AutoCronBackfill,
+}
+impl From<dao::rebooking_batch::RebookingBatchKind> for RebookingBatchKind {
+    fn from(kind: dao::rebooking_batch::RebookingBatchKind) -> Self {
+        match kind {
+            dao::rebooking_batch::RebookingBatchKind::Manual => Self::Manual,
+            dao::rebooking_batch::RebookingBatchKind::HrSuggestion => Self::HrSuggestion,
+            dao::rebooking_batch::RebookingBatchKind::AutoCron => Self::AutoCron,
+            dao::rebooking_batch::RebookingBatchKind::AutoCronBackfill => Self::AutoCronBackfill,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum RebookingBatchState {
+    Pending,
+    Approved,
+    Rejected,
+    SkippedLocked,
+}
+impl From<dao::rebooking_batch::RebookingBatchState> for RebookingBatchState {
+    fn from(state: dao::rebooking_batch::RebookingBatchState) -> Self {
+        match state {
+            dao::rebooking_batch::RebookingBatchState::Pending => Self::Pending,
+            dao::rebooking_batch::RebookingBatchState::Approved => Self::Approved,
+            dao::rebooking_batch::RebookingBatchState::Rejected => Self::Rejected,
+            dao::rebooking_batch::RebookingBatchState::SkippedLocked => Self::SkippedLocked,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RebookingBatchEntry {
+    pub id: Uuid,
+    /// Umzubuchende Stunden (positiv).
+    pub hours: f32,
+    /// Stundenkonto zum Zeitpunkt des Vorschlags.
+    pub balance_before: f32,
+    /// F2-Ist zum Zeitpunkt des Vorschlags.
+    pub voluntary_actual: f32,
+    /// F2-Soll zum Zeitpunkt des Vorschlags.
+    pub voluntary_committed: f32,
+    /// `-N VolunteerWork`, gesetzt ab Genehmigung.
+    pub extra_hours_out_id: Option<Uuid>,
+    /// `+N ExtraWork`, gesetzt ab Genehmigung.
+    pub extra_hours_in_id: Option<Uuid>,
+}
+impl From<&RebookingBatchEntryEntity> for RebookingBatchEntry {
+    fn from(entry: &RebookingBatchEntryEntity) -> Self {
+        Self {
+            id: entry.id,
+            hours: entry.hours,
+            balance_before: entry.balance_before,
+            voluntary_actual: entry.voluntary_actual,
+            voluntary_committed: entry.voluntary_committed,
+            extra_hours_out_id: entry.extra_hours_out_id,
+            extra_hours_in_id: entry.extra_hours_in_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RebookingBatch {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub iso_year: u32,
+    pub iso_week: u8,
+    pub kind: RebookingBatchKind,
+    pub state: RebookingBatchState,
+    pub created: PrimitiveDateTime,
+    pub approved: Option<PrimitiveDateTime>,
+    pub approved_by: Option<Arc<str>>,
+    pub rejected: Option<PrimitiveDateTime>,
+    pub rejected_by: Option<Arc<str>>,
+    pub reject_reason: Option<Arc<str>>,
+    pub entries: Arc<[RebookingBatchEntry]>,
+    pub version: Uuid,
+}
+impl RebookingBatch {
+    pub fn from_entities(
+        batch: &RebookingBatchEntity,
+        entries: &[RebookingBatchEntryEntity],
+    ) -> Self {
+        Self {
+            id: batch.id,
+            sales_person_id: batch.sales_person_id,
+            iso_year: batch.iso_year,
+            iso_week: batch.iso_week,
+            kind: batch.kind.into(),
+            state: batch.state.into(),
+            created: batch.created,
+            approved: batch.approved,
+            approved_by: batch.approved_by.clone(),
+            rejected: batch.rejected,
+            rejected_by: batch.rejected_by.clone(),
+            reject_reason: batch.reject_reason.clone(),
+            entries: entries.iter().map(RebookingBatchEntry::from).collect(),
+            version: batch.version,
+        }
+    }
+
+    /// Summe der umzubuchenden Stunden.
+    pub fn hours(&self) -> f32 {
+        self.entries.iter().map(|entry| entry.hours).sum()
+    }
+}
+
+/// Ergebnis eines Auto-Cron-Laufs (F4).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AutoRebookingReport {
+    /// Neu angelegte `Pending`-Batches.
+    pub pending: u32,
+    /// Neu angelegte `SkippedLocked`-Batches fuer gesperrte Wochen.
+    pub skipped_locked: u32,
+    /// Anzahl der nachgeholten Wochen; 0, wenn kein Backfill lief.
+    pub backfill_weeks: u32,
+}
+
+#[automock(type Context=(); type Transaction=MockTransaction;)]
+#[async_trait]
+pub trait RebookingReconciliationService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// F3: Legt einen `Pending`-Batch (`HrSuggestion`) fuer die ISO-Woche an.
+    ///
+    /// Ohne `hours` wird der Vorschlag aus den Daten vom Beginn des ISO-Jahres
+    /// bis Ende der Woche berechnet: der freiwillige Ueberschuss ueber dem
+    /// Soll (`VoluntaryStats::delta`), hoechstens das Defizit im Stundenkonto.
+    /// Ergibt das nichts Positives, folgt
+    /// `ValidationError(InvalidValue("hours"))`. Ein bereits aktiver Batch
+    /// fuer die Woche fuehrt zu `EntityAlreadyExists`.
+    async fn suggest(
+        &self,
+        sales_person_id: Uuid,
+        iso_year: u32,
+        iso_week: u8,
+        hours: Option<f32>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError>;
+
+    /// Alle offenen Batches, aelteste zuerst.
+    async fn find_pending(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[RebookingBatch]>, ServiceError>;
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError>;
+
+    /// F5: Genehmigt einen `Pending`-Batch und schreibt das extra_hours-Paar
+    /// in derselben Transaktion.
+    async fn approve(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError>;
+
+    /// F5: Lehnt einen `Pending`-Batch ab; `reason` darf nicht leer sein.
+    async fn reject(
+        &self,
+        id: Uuid,
+        reason: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError>;
+
+    /// F4: Auto-Cron. Ohne Stichtag im Toggle
+    /// `voluntary_rebooking_auto_active_from` passiert nichts.
+    ///
+    /// Wertet die letzte abgeschlossene ISO-Woche vor `today` fuer jeden
+    /// gedeckelten Mitarbeiter mit `committed_voluntary > 0` aus und legt wie
+    /// [`Self::suggest`] einen `AutoCron`-Batch an — `Pending`, bzw.
+    /// `SkippedLocked` fuer gesperrte Wochen. Wochen mit bestehendem Batch
+    /// oder ohne umzubuchende Stunden werden uebersprungen. Beim ersten Lauf
+    /// fuer einen Stichtag werden zusaetzlich alle Wochen ab dem Stichtag als
+    /// `AutoCronBackfill` nachgeholt.
+    async fn run_auto_cron(
+        &self,
+        today: time::Date,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AutoRebookingReport, ServiceError>;
+}
@@ -0,0 +1,116 @@
+//! CSV-/XLSX-Export der Reports und Abrechnungszeiträume (Business-Logic-Tier).
+//!
+//! HR übernimmt die Zahlen aus dem Stundenkonto und den Abrechnungszeiträumen
+//! für den Steuerberater in Tabellenkalkulationen. Der Service liefert sie als
+//! fertige Datei: die Kurzübersicht aller Mitarbeiter
+//! (`ReportingService::get_reports_for_all_employees`), den Report eines
+//! Mitarbeiters nach Wochen oder Monaten und einen Abrechnungszeitraum mit
+//! allen Werten in allen vier Sichten (Unterschied, YTD von, YTD bis, ganzes
+//! Jahr).
+//!
+//! Spaltenüberschriften werden in der gewünschten Sprache geschrieben
+//! (Texte wie im Frontend). CSV richtet sich nach den Gewohnheiten der
+//! Tabellenkalkulation in dieser Sprache: Deutsch und Tschechisch mit `;` und
+//! Dezimalkomma, Englisch mit `,` und Dezimalpunkt.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportLanguage {
+    #[default]
+    De,
+    En,
+    Cs,
+}
+
+/// Zeilen des Mitarbeiter-Reports: `EmployeeReport::by_week` oder `by_month`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportBreakdown {
+    ByWeek,
+    ByMonth,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportFile {
+    /// Reiner ASCII-Dateiname inklusive Endung.
+    pub file_name: Arc<str>,
+    pub content_type: &'static str,
+    pub content: Arc<[u8]>,
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait ReportExportService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Kurzübersicht aller Mitarbeiter, eine Zeile pro Person.
+    /// Permission wie `ReportingService::get_reports_for_all_employees`.
+    async fn export_employee_reports(
+        &self,
+        year: u32,
+        until_week: u8,
+        format: ExportFormat,
+        language: ExportLanguage,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+
+    /// Report eines Mitarbeiters, eine Zeile pro Woche bzw. Monat.
+    /// Permission wie `ReportingService::get_report_for_employee`.
+    #[allow(clippy::too_many_arguments)]
+    async fn export_employee_report(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        until_week: u8,
+        breakdown: ReportBreakdown,
+        format: ExportFormat,
+        language: ExportLanguage,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+
+    /// Abrechnungszeitraum, eine Zeile pro Mitarbeiter und für jeden
+    /// `BillingPeriodValueType` je eine Spalte pro Sicht. Permission: HR.
+    async fn export_billing_period(
+        &self,
+        billing_period_id: Uuid,
+        format: ExportFormat,
+        language: ExportLanguage,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError>;
+}
@@ -4,18 +4,21 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::Serialize;
 use uuid::Uuid;
 
 use crate::permission::Authentication;
 use crate::ServiceError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SalesPerson {
     pub id: Uuid,
     pub name: Arc<str>,
     pub background_color: Arc<str>,
     pub is_paid: Option<bool>,
     pub inactive: bool,
+    /// Personalnummer im Lohnprogramm. Nur für HR sichtbar.
+    pub personnel_number: Option<Arc<str>>,
     pub deleted: Option<time::PrimitiveDateTime>,
     pub version: Uuid,
 }
@@ -27,6 +30,7 @@ impl From<&dao::sales_person::SalesPersonEntity> for SalesPerson {
             background_color: sales_person.background_color.clone(),
             is_paid: Some(sales_person.is_paid),
             inactive: sales_person.inactive,
+            personnel_number: sales_person.personnel_number.clone(),
             deleted: sales_person.deleted,
             version: sales_person.version,
         }
@@ -40,6 +44,7 @@ impl From<&SalesPerson> for dao::sales_person::SalesPersonEntity {
             background_color: sales_person.background_color.clone(),
             is_paid: sales_person.is_paid.unwrap_or(false),
             inactive: sales_person.inactive,
+            personnel_number: sales_person.personnel_number.clone(),
             deleted: sales_person.deleted,
             version: sales_person.version,
         }
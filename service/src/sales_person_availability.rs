@@ -0,0 +1,187 @@
+//! Wiederkehrende Verfügbarkeit pro Mitarbeiter (Basic-Tier).
+//!
+//! Statt „ich arbeite nie dienstags“ jede Woche als
+//! `sales_person_unavailable` neu einzutragen, hinterlegt der Mitarbeiter
+//! eine Regel: Wochentag + optionales Zeitfenster + Gültigkeitszeitraum +
+//! Art (`Preferred` / `Available` / `Unavailable`).
+//!
+//! `Unavailable`-Regeln werden in der per-sales-person-Schichtplansicht und
+//! im konflikt-aware Buchungspfad wie ein `ManualUnavailable` behandelt;
+//! Regeln mit Zeitfenster greifen nur, wenn sie den Slot überlappen.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::sales_person_availability::{
+    AvailabilityKind as AvailabilityKindEntity, SalesPersonAvailabilityEntity,
+};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use shifty_utils::DayOfWeek;
+use time::{Date, PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum AvailabilityKind {
+    Preferred,
+    Available,
+    Unavailable,
+}
+
+impl From<AvailabilityKindEntity> for AvailabilityKind {
+    fn from(kind: AvailabilityKindEntity) -> Self {
+        match kind {
+            AvailabilityKindEntity::Preferred => Self::Preferred,
+            AvailabilityKindEntity::Available => Self::Available,
+            AvailabilityKindEntity::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+impl From<AvailabilityKind> for AvailabilityKindEntity {
+    fn from(kind: AvailabilityKind) -> Self {
+        match kind {
+            AvailabilityKind::Preferred => Self::Preferred,
+            AvailabilityKind::Available => Self::Available,
+            AvailabilityKind::Unavailable => Self::Unavailable,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SalesPersonAvailability {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub day_of_week: DayOfWeek,
+    /// Beide `None` = ganzer Tag; sonst beide gesetzt mit `time_from < time_to`.
+    pub time_from: Option<Time>,
+    pub time_to: Option<Time>,
+    pub valid_from: Date,
+    pub valid_to: Option<Date>,
+    pub kind: AvailabilityKind,
+    pub created: Option<PrimitiveDateTime>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl SalesPersonAvailability {
+    /// Regel gilt am konkreten Datum (Wochentag + Gültigkeitszeitraum,
+    /// nicht gelöscht).
+    pub fn applies_on(&self, date: Date) -> bool {
+        self.deleted.is_none()
+            && DayOfWeek::from(date.weekday()) == self.day_of_week
+            && self.valid_from <= date
+            && self.valid_to.is_none_or(|valid_to| date <= valid_to)
+    }
+
+    pub fn is_full_day(&self) -> bool {
+        self.time_from.is_none() && self.time_to.is_none()
+    }
+
+    /// Überlappt die Regel das halboffene Intervall `[from, to)`? Ganztags-
+    /// Regeln überlappen immer.
+    pub fn overlaps(&self, from: Time, to: Time) -> bool {
+        match (self.time_from, self.time_to) {
+            (Some(own_from), Some(own_to)) => own_from < to && from < own_to,
+            _ => true,
+        }
+    }
+
+    /// `Unavailable`-Regel, die am Datum gilt und `[from, to)` überlappt.
+    pub fn blocks(&self, date: Date, from: Time, to: Time) -> bool {
+        self.kind == AvailabilityKind::Unavailable
+            && self.applies_on(date)
+            && self.overlaps(from, to)
+    }
+}
+
+impl From<&SalesPersonAvailabilityEntity> for SalesPersonAvailability {
+    fn from(entity: &SalesPersonAvailabilityEntity) -> Self {
+        Self {
+            id: entity.id,
+            sales_person_id: entity.sales_person_id,
+            day_of_week: entity.day_of_week,
+            time_from: entity.time_from,
+            time_to: entity.time_to,
+            valid_from: entity.valid_from,
+            valid_to: entity.valid_to,
+            kind: entity.kind.into(),
+            created: Some(entity.created),
+            deleted: entity.deleted,
+            version: entity.version,
+        }
+    }
+}
+
+impl TryFrom<&SalesPersonAvailability> for SalesPersonAvailabilityEntity {
+    type Error = ServiceError;
+    fn try_from(availability: &SalesPersonAvailability) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: availability.id,
+            sales_person_id: availability.sales_person_id,
+            day_of_week: availability.day_of_week,
+            time_from: availability.time_from,
+            time_to: availability.time_to,
+            valid_from: availability.valid_from,
+            valid_to: availability.valid_to,
+            kind: availability.kind.into(),
+            created: availability
+                .created
+                .ok_or_else(|| ServiceError::InternalError)?,
+            deleted: availability.deleted,
+            version: availability.version,
+        })
+    }
+}
+
+#[automock(type Context=(); type Transaction = MockTransaction;)]
+#[async_trait]
+pub trait SalesPersonAvailabilityService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Alle Regeln der Person. Permission: Shiftplanner ∨ self.
+    async fn get_all_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SalesPersonAvailability]>, ServiceError>;
+
+    /// Regeln, die an mindestens einem Tag der ISO-Woche gelten.
+    /// Permission: Shiftplanner ∨ self.
+    async fn get_by_week_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SalesPersonAvailability]>, ServiceError>;
+
+    async fn create(
+        &self,
+        availability: &SalesPersonAvailability,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SalesPersonAvailability, ServiceError>;
+
+    async fn update(
+        &self,
+        availability: &SalesPersonAvailability,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SalesPersonAvailability, ServiceError>;
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+}
@@ -5,13 +5,14 @@ use async_trait::async_trait;
 use dao::sales_person_unavailable::SalesPersonUnavailableEntity;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use uuid::Uuid;
 
 use crate::permission::Authentication;
 use crate::ServiceError;
 use shifty_utils::DayOfWeek;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SalesPersonUnavailable {
     pub id: Uuid,
     pub sales_person_id: Uuid,
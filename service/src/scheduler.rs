@@ -1,8 +1,10 @@
-use crate::ServiceError;
+use crate::{permission::Authentication, ServiceError};
 use async_trait::async_trait;
 use mockall::automock;
 use std::fmt::Debug;
 
+/// Führt die periodischen Hintergrund-Jobs aus der Registry
+/// [`crate::scheduler_job::SchedulerJobService`] aus.
 #[automock(type Context=();)]
 #[async_trait]
 pub trait SchedulerService {
@@ -10,11 +12,24 @@ pub trait SchedulerService {
     /// to other services when invoking them.
     type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
 
-    /// Start the scheduler in a background task.
-    /// After calling this, scheduled jobs (added via other methods) will run automatically.
+    /// Start the scheduler in a background task and register all enabled jobs
+    /// from the registry.
     async fn start(&self) -> Result<(), ServiceError>;
 
-    /// Schedules a periodic job that updates carryover for the previous year.
-    /// The `cron` parameter is a cron expression (e.g. `"0 * * * * *"` to run hourly).
-    async fn schedule_carryover_updates(&self, cron: &'static str) -> Result<(), ServiceError>;
+    /// Nach `PUT /scheduler-job/{name}`: alle registrierten Jobs entfernen und
+    /// die aktivierten Jobs mit ihren aktuellen Cron-Ausdrücken neu
+    /// registrieren. Ein ungültiger Ausdruck wird geloggt und übersprungen,
+    /// die übrigen Jobs laufen weiter.
+    async fn reload_from_db(&self) -> Result<(), ServiceError>;
+
+    /// Führt genau einen Lauf des Jobs `name` synchron aus und hält Dauer und
+    /// Ergebnis in der Registry fest — auch für deaktivierte Jobs. Läuft der
+    /// Job bereits, kehrt der Aufruf ohne Lauf und ohne Registry-Eintrag mit
+    /// `Ok(())` zurück. Nur mit `Authentication::Full`; der REST-Handler prüft
+    /// das admin-Privileg vorher.
+    async fn run_job_now(
+        &self,
+        name: &str,
+        context: Authentication<Self::Context>,
+    ) -> Result<(), ServiceError>;
 }
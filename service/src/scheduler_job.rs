@@ -0,0 +1,112 @@
+//! Registry der periodischen Hintergrund-Jobs.
+//!
+//! Tier-Klassifizierung: **Basic-Service (Entity-Manager)** — hängt nur von
+//! DAO + Permission + AuditLog + Uuid + Transaction ab. Die Ausführung der Jobs
+//! liegt im [`crate::scheduler::SchedulerService`], der die Registry beim
+//! Start und nach jeder Änderung neu lädt.
+//!
+//! `get_all`/`get`/`update` sind admin-gated; `record_run` läuft nur mit
+//! `Authentication::Full` (Scheduler).
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::scheduler_job::SchedulerJobEntity;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::{permission::Authentication, ServiceError};
+
+/// Jahres-Carryover für Vorjahr und laufendes Jahr neu berechnen.
+pub const JOB_CARRYOVER: &str = "carryover";
+/// Offene E-Mail-Benachrichtigungen aus der Outbox zustellen.
+pub const JOB_NOTIFICATION_DELIVERY: &str = "notification_delivery";
+/// Offene Webhook-Aufrufe aus der Outbox zustellen.
+pub const JOB_WEBHOOK_DELIVERY: &str = "webhook_delivery";
+/// Auto-Cron der freiwilligen Umbuchungen (F4).
+pub const JOB_VOLUNTARY_REBOOKING: &str = "voluntary_rebooking";
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct SchedulerJob {
+    pub name: Arc<str>,
+    /// 6-Feld-Cron-Ausdruck (`sec min hour dom mon dow`), lokale Zeit.
+    pub cron_schedule: Arc<str>,
+    pub enabled: bool,
+    /// Start des letzten Laufs, unabhängig vom Ergebnis.
+    pub last_run_at: Option<time::PrimitiveDateTime>,
+    pub last_duration_ms: Option<u64>,
+    /// Gesetzt, wenn der letzte Lauf fehlschlug.
+    pub last_error_at: Option<time::PrimitiveDateTime>,
+    pub last_error_message: Option<Arc<str>>,
+    pub version: Uuid,
+}
+
+impl From<&SchedulerJobEntity> for SchedulerJob {
+    fn from(entity: &SchedulerJobEntity) -> Self {
+        Self {
+            name: entity.name.clone(),
+            cron_schedule: entity.cron_schedule.clone(),
+            enabled: entity.enabled,
+            last_run_at: entity.last_run_at,
+            last_duration_ms: entity.last_duration_ms,
+            last_error_at: entity.last_error_at,
+            last_error_message: entity.last_error_message.clone(),
+            version: entity.version,
+        }
+    }
+}
+
+/// Admin-editierbare Felder eines Jobs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchedulerJobUpdate {
+    pub cron_schedule: Arc<str>,
+    pub enabled: bool,
+    /// Version, auf der die Änderung basiert.
+    pub version: Uuid,
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait SchedulerJobService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Admin-gated. Alle Jobs, sortiert nach Name.
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SchedulerJob]>, ServiceError>;
+
+    /// Admin-gated. Unbekannter Name → [`ServiceError::EntityNotFoundGeneric`].
+    async fn get(
+        &self,
+        name: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SchedulerJob, ServiceError>;
+
+    /// Admin-gated. Ein Cron-Ausdruck, den der Scheduler nicht parsen kann,
+    /// führt zu `ValidationError(InvalidValue("cron_schedule"))`, eine
+    /// veraltete Version zu [`ServiceError::EntityConflicts`] (Job-ID nil,
+    /// Jobs sind über den Namen adressiert).
+    async fn update(
+        &self,
+        name: &str,
+        update: SchedulerJobUpdate,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SchedulerJob, ServiceError>;
+
+    /// Nur Full-Auth (Scheduler): Ergebnis eines Laufs festhalten.
+    async fn record_run(
+        &self,
+        name: &str,
+        started_at: time::PrimitiveDateTime,
+        duration_ms: u64,
+        error: Option<Arc<str>>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+}
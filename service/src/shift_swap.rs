@@ -0,0 +1,213 @@
+//! Schichttausch-/Abgabe-Börse (Business-Logic-Tier).
+//!
+//! Ein Mitarbeiter bietet eines seiner Bookings an ([`ShiftSwapService::offer`]),
+//! eine andere, für den Schichtplan zulässige Person übernimmt es
+//! ([`ShiftSwapService::accept`]) und ein Shiftplanner genehmigt
+//! ([`ShiftSwapService::approve`]). Die Genehmigung löscht das alte Booking
+//! und legt das neue in EINER Transaktion über `ShiftplanEditService` an —
+//! damit greifen Wochen-Sperre, Warnings und die `booking_log`-Einträge
+//! (created_by/deleted_by) wie beim manuellen Umbuchen.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::shift_swap::{ShiftSwapRequestEntity, ShiftSwapState as ShiftSwapStateEntity};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use crate::booking::Booking;
+use crate::permission::Authentication;
+use crate::warning::Warning;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ShiftSwapState {
+    Open,
+    Accepted,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+impl ShiftSwapState {
+    /// `Open` und `Accepted` sind noch nicht entschieden.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ShiftSwapState::Open | ShiftSwapState::Accepted)
+    }
+}
+
+impl From<ShiftSwapStateEntity> for ShiftSwapState {
+    fn from(state: ShiftSwapStateEntity) -> Self {
+        match state {
+            ShiftSwapStateEntity::Open => Self::Open,
+            ShiftSwapStateEntity::Accepted => Self::Accepted,
+            ShiftSwapStateEntity::Approved => Self::Approved,
+            ShiftSwapStateEntity::Rejected => Self::Rejected,
+            ShiftSwapStateEntity::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl From<ShiftSwapState> for ShiftSwapStateEntity {
+    fn from(state: ShiftSwapState) -> Self {
+        match state {
+            ShiftSwapState::Open => Self::Open,
+            ShiftSwapState::Accepted => Self::Accepted,
+            ShiftSwapState::Approved => Self::Approved,
+            ShiftSwapState::Rejected => Self::Rejected,
+            ShiftSwapState::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ShiftSwapRequest {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub offering_sales_person_id: Uuid,
+    pub accepting_sales_person_id: Option<Uuid>,
+    pub new_booking_id: Option<Uuid>,
+    pub year: u32,
+    pub calendar_week: u8,
+    pub state: ShiftSwapState,
+    pub message: Arc<str>,
+    pub reject_reason: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub accepted: Option<PrimitiveDateTime>,
+    pub decided: Option<PrimitiveDateTime>,
+    pub decided_by: Option<Arc<str>>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl From<&ShiftSwapRequestEntity> for ShiftSwapRequest {
+    fn from(entity: &ShiftSwapRequestEntity) -> Self {
+        Self {
+            id: entity.id,
+            booking_id: entity.booking_id,
+            offering_sales_person_id: entity.offering_sales_person_id,
+            accepting_sales_person_id: entity.accepting_sales_person_id,
+            new_booking_id: entity.new_booking_id,
+            year: entity.year,
+            calendar_week: entity.calendar_week,
+            state: entity.state.into(),
+            message: entity.message.clone(),
+            reject_reason: entity.reject_reason.clone(),
+            created: entity.created,
+            accepted: entity.accepted,
+            decided: entity.decided,
+            decided_by: entity.decided_by.clone(),
+            deleted: entity.deleted,
+            version: entity.version,
+        }
+    }
+}
+
+impl From<&ShiftSwapRequest> for ShiftSwapRequestEntity {
+    fn from(request: &ShiftSwapRequest) -> Self {
+        Self {
+            id: request.id,
+            booking_id: request.booking_id,
+            offering_sales_person_id: request.offering_sales_person_id,
+            accepting_sales_person_id: request.accepting_sales_person_id,
+            new_booking_id: request.new_booking_id,
+            year: request.year,
+            calendar_week: request.calendar_week,
+            state: request.state.into(),
+            message: request.message.clone(),
+            reject_reason: request.reject_reason.clone(),
+            created: request.created,
+            accepted: request.accepted,
+            decided: request.decided,
+            decided_by: request.decided_by.clone(),
+            deleted: request.deleted,
+            version: request.version,
+        }
+    }
+}
+
+/// Ergebnis von [`ShiftSwapService::approve`]: die abgeschlossene Anfrage,
+/// das neu angelegte Booking und die Cross-Source-Warnings des
+/// Buchungspfads (D-Phase3-15: keine De-Dup).
+#[derive(Clone, Debug)]
+pub struct ShiftSwapApproveResult {
+    pub request: ShiftSwapRequest,
+    pub booking: Booking,
+    pub warnings: Arc<[Warning]>,
+}
+
+#[automock(type Context=(); type Transaction=MockTransaction;)]
+#[async_trait]
+pub trait ShiftSwapService {
+    type Context: Clone + std::fmt::Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction + std::fmt::Debug + Clone + Send + Sync + 'static;
+
+    /// Alle offenen und angenommenen Anfragen (die „Börse“).
+    /// Permission: Shiftplanner ∨ Sales.
+    async fn get_pending(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ShiftSwapRequest]>, ServiceError>;
+
+    /// Alle Anfragen, bei denen die Person anbietet oder übernimmt.
+    /// Permission: Shiftplanner ∨ self.
+    async fn get_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ShiftSwapRequest]>, ServiceError>;
+
+    /// Bietet ein Booking an. Permission: Shiftplanner ∨ Eigentümer des
+    /// Bookings. Pro Booking höchstens eine laufende Anfrage
+    /// (`EntityAlreadyExists`); gesperrte Wochen liefern `WeekLocked`.
+    async fn offer(
+        &self,
+        booking_id: Uuid,
+        message: Arc<str>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError>;
+
+    /// Übernimmt eine offene Anfrage. Permission: Shiftplanner ∨ die
+    /// übernehmende Person selbst. Die Person muss für den Schichtplan des
+    /// Slots zulässig sein und darf nicht der Anbieter sein.
+    async fn accept(
+        &self,
+        id: Uuid,
+        accepting_sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError>;
+
+    /// Genehmigt eine angenommene Anfrage: altes Booking löschen, neues
+    /// anlegen — alles in EINER Transaktion. Permission: Shiftplanner.
+    async fn approve(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapApproveResult, ServiceError>;
+
+    /// Lehnt eine laufende Anfrage ab. Permission: Shiftplanner.
+    async fn reject(
+        &self,
+        id: Uuid,
+        reason: Arc<str>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError>;
+
+    /// Zieht eine laufende Anfrage zurück. Permission: Shiftplanner ∨
+    /// Anbieter.
+    async fn cancel(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError>;
+}
@@ -31,6 +31,18 @@ pub enum UnavailabilityMarker {
         absence_id: uuid::Uuid,
         category: crate::absence::AbsenceCategory,
     },
+    /// Nur wiederkehrende `Unavailable`-Regeln mit Zeitfenster greifen an
+    /// diesem Tag — der Rest des Tages ist verfügbar. Ganztags-Regeln werden
+    /// wie `ManualUnavailable` behandelt und landen nie hier.
+    PartiallyUnavailable { windows: Vec<UnavailableWindow> },
+}
+
+/// Zeitfenster einer wiederkehrenden `Unavailable`-Regel (halboffen
+/// `[from, to)`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnavailableWindow {
+    pub from: time::Time,
+    pub to: time::Time,
 }
 
 #[derive(Debug, Clone)]
@@ -109,9 +121,9 @@ pub trait ShiftplanViewService {
     ///
     /// Liefert die Schichtplan-Woche, wobei jeder Tag das Feld `unavailable`
     /// gesetzt bekommt, falls für `sales_person_id` an diesem Tag eine
-    /// aktive AbsencePeriod und/oder ein aktiver `sales_person_unavailable`-
-    /// Eintrag existiert. Soft-deleted Einträge werden gefiltert
-    /// (Pitfall 1 / SC4).
+    /// aktive AbsencePeriod, ein aktiver `sales_person_unavailable`-Eintrag
+    /// und/oder eine wiederkehrende `Unavailable`-Regel existiert.
+    /// Soft-deleted Einträge werden gefiltert (Pitfall 1 / SC4).
     ///
     /// Permission: HR ∨ `verify_user_is_sales_person(sales_person_id)`
     /// (D-Phase3-12).
@@ -4,12 +4,13 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use uuid::Uuid;
 
 use crate::permission::Authentication;
 use crate::ServiceError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct Shiftplan {
     pub id: Uuid,
     pub name: Arc<str>,
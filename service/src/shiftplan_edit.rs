@@ -92,6 +92,22 @@ pub trait ShiftplanEditService {
         tx: Option<Self::Transaction>,
     ) -> Result<(), ServiceError>;
 
+    /// Rechnet nur die als veraltet markierten Carryover neu
+    /// ([`crate::carryover::CarryoverService::mark_dirty`]): pro Mitarbeiter
+    /// vom ältesten markierten Jahr an bis einschließlich `until_year` (bzw.
+    /// dem jüngsten markierten Jahr, falls das später liegt), weil jeder
+    /// Carryover in den Report des Folgejahres eingeht. Danach werden die
+    /// verarbeiteten Markierungen entfernt. Liefert die Zahl der neu
+    /// berechneten Carryover.
+    ///
+    /// Permission: `shiftplan.edit` (wie `update_carryover_all_employees`).
+    async fn update_dirty_carryovers(
+        &self,
+        until_year: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<u32, ServiceError>;
+
     async fn add_vacation(
         &self,
         sales_person_id: Uuid,
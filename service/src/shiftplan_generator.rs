@@ -0,0 +1,101 @@
+//! Automatischer Schichtplan-Generator (Business-Logic-Tier).
+//!
+//! Schlägt für einen Schichtplan und eine (year, week) einen vollständigen
+//! Satz Bookings vor, der `Slot::min_resources` auffüllt, `max_paid_employees`
+//! respektiert, `sales_person_unavailable`-Tage und ganztägige
+//! `AbsencePeriod`s überspringt, nur via
+//! `SalesPersonShiftplanService::is_eligible` zugelassene Personen einplant
+//! und die geplanten Stunden gegen `EmployeeWorkDetails::expected_hours`
+//! ausbalanciert.
+//!
+//! Der Vorschlag ist ein Entwurf: er wird per Trockenlauf über
+//! `ShiftplanEditService::book_slot_with_conflict_check` gebucht, die
+//! Warnings werden eingesammelt und die Transaktion anschließend
+//! zurückgerollt. Über [`ShiftplanGeneratorService::accept_proposal`] werden
+//! die (ggf. vom Planer gekürzten) Bookings in EINER Transaktion persistiert.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::MockTransaction;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::booking::Booking;
+use crate::permission::Authentication;
+use crate::shiftplan_edit::CopyWeekResult;
+use crate::warning::Warning;
+use crate::ServiceError;
+
+/// Slot, dessen `min_resources` der Generator nicht vollständig besetzen
+/// konnte (keine weitere zulässige Person verfügbar).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnfilledSlot {
+    pub slot_id: Uuid,
+    pub min_resources: u8,
+    pub booked: u8,
+}
+
+/// Stunden-Bilanz pro Person für die Ziel-Woche. `planned_hours_before`
+/// umfasst bereits vorhandene Bookings aller Schichtpläne,
+/// `planned_hours_after` zusätzlich die vorgeschlagenen Bookings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposedHours {
+    pub sales_person_id: Uuid,
+    pub expected_hours: f32,
+    pub planned_hours_before: f32,
+    pub planned_hours_after: f32,
+}
+
+/// Reviewbarer Entwurf des Generators.
+///
+/// Die IDs in `bookings` stammen aus dem zurückgerollten Trockenlauf und
+/// sind nur innerhalb des Entwurfs gültig — die `warnings` referenzieren
+/// genau diese IDs. Beim Annehmen werden neue IDs vergeben.
+#[derive(Clone, Debug)]
+pub struct ShiftplanProposal {
+    pub shiftplan_id: Uuid,
+    pub year: u32,
+    pub week: u8,
+    pub bookings: Arc<[Booking]>,
+    pub unfilled_slots: Arc<[UnfilledSlot]>,
+    pub hours: Arc<[ProposedHours]>,
+    pub warnings: Arc<[Warning]>,
+}
+
+#[automock(type Context=(); type Transaction=MockTransaction;)]
+#[async_trait]
+pub trait ShiftplanGeneratorService {
+    type Context: Clone + std::fmt::Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction + std::fmt::Debug + Clone + Send + Sync + 'static;
+
+    /// Erzeugt einen Entwurf für die offenen Plätze der Woche. Es wird nichts
+    /// persistiert: der Trockenlauf bucht immer in einer eigenen Transaktion,
+    /// die danach zurückgerollt wird. Ein übergebenes `tx` wird ignoriert.
+    ///
+    /// Permission: `shiftplan.edit` (Bulk-Operation, analog copy_week).
+    async fn propose_week(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftplanProposal, ServiceError>;
+
+    /// Bucht die übergebenen Bookings in EINER Transaktion über
+    /// `ShiftplanEditService::book_slot_with_conflict_check` und liefert die
+    /// persistierten Bookings samt Warnings. Jedes Booking muss zu einem Slot
+    /// des Schichtplans in (year, week) gehören, sonst `ValidationError`.
+    ///
+    /// Permission: `shiftplan.edit`.
+    async fn accept_proposal(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+        bookings: Arc<[Booking]>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<CopyWeekResult, ServiceError>;
+}
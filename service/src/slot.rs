@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use shifty_utils::DayOfWeek;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -9,7 +10,7 @@ use uuid::Uuid;
 use crate::permission::Authentication;
 use crate::ServiceError;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Slot {
     pub id: Uuid,
     pub day_of_week: DayOfWeek,
@@ -4,12 +4,13 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dao::special_day::{SpecialDayEntity, SpecialDayTypeEntity};
 use mockall::automock;
+use serde::Serialize;
 use shifty_utils::DayOfWeek;
 use uuid::Uuid;
 
 use crate::{permission::Authentication, ServiceError};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub enum SpecialDayType {
     Holiday,
     ShortDay,
@@ -31,7 +32,7 @@ impl From<&SpecialDayType> for SpecialDayTypeEntity {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SpecialDay {
     pub id: Uuid,
     pub year: u32,
@@ -0,0 +1,42 @@
+//! iCal-Feeds für Planer und Teamleitung.
+//!
+//! Anders als der persönliche Feed (`BlockService::get_blocks_for_next_weeks_as_ical`)
+//! zeigen diese Feeds nicht die Blöcke eines Mitarbeiters, sondern einen
+//! ganzen Schichtplan bzw. die Abwesenheiten und Sondertage des Teams.
+//! Kalender-Apps erreichen sie über Feed-Tokens
+//! ([`crate::ical_feed_token::IcalFeedScope`]).
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::ServiceError;
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait TeamIcalService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Alle Slots des Schichtplans der nächsten Wochen mit den gebuchten
+    /// Namen; Slots aus `BlockService::get_unsufficiently_booked_blocks`
+    /// sind als unterbesetzt markiert. Nur Shiftplanner.
+    async fn get_shiftplan_as_ical(
+        &self,
+        shiftplan_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError>;
+
+    /// Abwesenheiten aller Mitarbeiter und die Sondertage als ganztägige
+    /// Termine. Nur HR.
+    async fn get_team_absences_as_ical(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError>;
+}
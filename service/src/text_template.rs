@@ -3,12 +3,13 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use mockall::automock;
+use serde::Serialize;
 use uuid::Uuid;
 
 use crate::permission::Authentication;
 use crate::ServiceError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub enum TemplateEngine {
     Tera,
     MiniJinja,
@@ -32,7 +33,7 @@ impl From<&TemplateEngine> for dao::text_template::TemplateEngineEntity {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct TextTemplate {
     pub id: Uuid,
     pub name: Option<Arc<str>>,
@@ -80,6 +81,27 @@ impl From<&TextTemplate> for dao::text_template::TextTemplateEntity {
     }
 }
 
+/// Syntaxfehler beim Kompilieren eines Templates mit seiner Engine. Zeile und
+/// Spalte sind 1-basiert und fehlen, wenn die Engine keine Position liefert.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TemplateSyntaxError {
+    pub message: Arc<str>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl std::fmt::Display for TemplateSyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "Zeile {line}, Spalte {column}: {}", self.message)
+            }
+            (Some(line), None) => write!(f, "Zeile {line}: {}", self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
 #[automock(type Context=(); type Transaction = dao::MockTransaction;)]
 #[async_trait]
 pub trait TextTemplateService {
@@ -126,4 +148,16 @@ pub trait TextTemplateService {
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<(), ServiceError>;
+
+    /// Kompiliert `template_text` mit der Engine, ohne zu speichern.
+    ///
+    /// Gibt den ersten Syntaxfehler zurück, `None` wenn das Template gültig
+    /// ist. `create` und `update` lehnen dieselben Fehler als
+    /// `ValidationError` ab. Nur HR.
+    async fn validate(
+        &self,
+        template_text: &str,
+        template_engine: &TemplateEngine,
+        context: Authentication<Self::Context>,
+    ) -> Result<Option<TemplateSyntaxError>, ServiceError>;
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+//! Vorschau von Text-Templates (Business-Logic-Tier).
+//!
+//! Rendert ein (noch nicht gespeichertes) Template gegen den Kontext, den sein
+//! Konsument für den `template_type` aufbaut, und listet die darin
+//! verfügbaren Variablen. Bekannte Typen:
+//!
+//! - `billing-period` / `billing-period-pdf`: Kontext des Custom Reports
+//!   ([`crate::billing_period_report::BillingPeriodReportService::build_custom_report_context`]).
+//! - `shiftplan-report`: Kontext des Block-Reports
+//!   ([`crate::block_report::BlockReportService::build_block_report_context`]).
+//! - `notification_*`: Kontext der E-Mail-Benachrichtigungen, nur mit
+//!   Beispieldaten, weil echte Daten erst mit dem Ereignis entstehen.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::text_template::TextTemplate;
+use crate::ServiceError;
+
+/// Woher die Daten für die Vorschau kommen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplatePreviewSource {
+    /// Beispieldaten mit derselben Struktur wie der echte Kontext.
+    Sample,
+    /// Echte Daten des Konsumenten. `billing-period`-Typen brauchen die
+    /// Abrechnungsperiode, `shiftplan-report` nutzt die aktuellen Wochen.
+    Live { billing_period_id: Option<Uuid> },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplatePreview {
+    pub output: Arc<str>,
+    /// Pfade aller Kontext-Variablen, z.B. `billing_period.start_date` oder
+    /// `billing_period.sales_persons[].name` für Elemente einer Liste.
+    pub variables: Arc<[Arc<str>]>,
+}
+
+#[automock(type Context=(); type Transaction=dao::MockTransaction;)]
+#[async_trait]
+pub trait TextTemplatePreviewService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Rendert `template` gegen Beispiel- oder echte Daten seines
+    /// `template_type`. Syntax- und Renderfehler sowie unbekannte Typen
+    /// kommen als `ValidationError` zurück. Nur HR.
+    async fn preview(
+        &self,
+        template: &TextTemplate,
+        source: TemplatePreviewSource,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TemplatePreview, ServiceError>;
+}
@@ -0,0 +1,267 @@
+//! Zeiterfassung (Business-Logic-Tier).
+//!
+//! Das Reporting rechnet normalerweise mit den geplanten Stunden aus den
+//! Buchungen (`ExtraHoursReportCategory::Shiftplan`). Mit der Stempeluhr
+//! erfassen Mitarbeiter ihre tatsächliche Arbeitszeit (`clock_in` /
+//! `clock_out`), HR kann Einträge nachtragen und korrigieren. Ein Eintrag
+//! kann auf die geplante Buchung verweisen.
+//!
+//! Zeiten sind Ortszeit in der konfigurierten Zeitzone (`TIMEZONE`), wie die
+//! Slots. Ein Eintrag zählt vollständig zu dem Tag, an dem er beginnt.
+//!
+//! Verträge mit `use_actual_hours` rechnen im `EmployeeReport` mit den
+//! erfassten statt den geplanten Stunden, siehe
+//! [`TimeEntryService::extract_actual_hours_report`].
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::time_entry::{TimeEntryEntity, TimeEntrySource as TimeEntrySourceEntity};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use shifty_utils::ShiftyDate;
+use time::{Date, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::permission::Authentication;
+use crate::shiftplan_report::ShiftplanReportDay;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum TimeEntrySource {
+    Clock,
+    Manual,
+}
+
+impl From<TimeEntrySourceEntity> for TimeEntrySource {
+    fn from(source: TimeEntrySourceEntity) -> Self {
+        match source {
+            TimeEntrySourceEntity::Clock => Self::Clock,
+            TimeEntrySourceEntity::Manual => Self::Manual,
+        }
+    }
+}
+
+impl From<TimeEntrySource> for TimeEntrySourceEntity {
+    fn from(source: TimeEntrySource) -> Self {
+        match source {
+            TimeEntrySource::Clock => Self::Clock,
+            TimeEntrySource::Manual => Self::Manual,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub booking_id: Option<Uuid>,
+    pub start: PrimitiveDateTime,
+    /// `None`, solange der Mitarbeiter eingestempelt ist.
+    pub end: Option<PrimitiveDateTime>,
+    pub description: Arc<str>,
+    pub source: TimeEntrySource,
+    pub created: Option<PrimitiveDateTime>,
+    pub created_by: Option<Arc<str>>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl TimeEntry {
+    pub fn is_open(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// Dauer in Stunden; offene Einträge zählen noch nicht.
+    pub fn hours(&self) -> f32 {
+        match self.end {
+            Some(end) => (end - self.start).as_seconds_f32() / 3600.0,
+            None => 0.0,
+        }
+    }
+
+    pub fn date(&self) -> Date {
+        self.start.date()
+    }
+}
+
+impl From<&TimeEntryEntity> for TimeEntry {
+    fn from(entity: &TimeEntryEntity) -> Self {
+        Self {
+            id: entity.id,
+            sales_person_id: entity.sales_person_id,
+            booking_id: entity.booking_id,
+            start: entity.start,
+            end: entity.end,
+            description: entity.description.clone(),
+            source: entity.source.into(),
+            created: Some(entity.created),
+            created_by: Some(entity.created_by.clone()),
+            deleted: entity.deleted,
+            version: entity.version,
+        }
+    }
+}
+
+impl TryFrom<&TimeEntry> for TimeEntryEntity {
+    type Error = ServiceError;
+    fn try_from(entry: &TimeEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: entry.id,
+            sales_person_id: entry.sales_person_id,
+            booking_id: entry.booking_id,
+            start: entry.start,
+            end: entry.end,
+            description: entry.description.clone(),
+            source: entry.source.into(),
+            created: entry.created.ok_or(ServiceError::InternalError)?,
+            created_by: entry.created_by.clone().ok_or(ServiceError::InternalError)?,
+            deleted: entry.deleted,
+            version: entry.version,
+        })
+    }
+}
+
+/// Soll/Ist-Vergleich eines Tages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReconciliationDay {
+    pub date: Date,
+    /// Stunden laut Schichtplan (Buchungen).
+    pub planned_hours: f32,
+    /// Erfasste Stunden der abgeschlossenen Einträge.
+    pub actual_hours: f32,
+    /// An diesem Tag begonnener, noch offener Eintrag.
+    pub has_open_entry: bool,
+}
+
+impl ReconciliationDay {
+    /// Positiv: mehr gearbeitet als geplant.
+    pub fn difference(&self) -> f32 {
+        self.actual_hours - self.planned_hours
+    }
+}
+
+/// Summiert die Stunden der abgeschlossenen Einträge pro Person und Tag als
+/// [`ShiftplanReportDay`], damit das Reporting sie wie geplante Stunden
+/// verarbeiten kann.
+pub fn actual_hours_per_day(entries: &[TimeEntry]) -> Arc<[ShiftplanReportDay]> {
+    let mut per_day: BTreeMap<(Uuid, Date), f32> = BTreeMap::new();
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.deleted.is_none() && !entry.is_open())
+    {
+        *per_day
+            .entry((entry.sales_person_id, entry.date()))
+            .or_default() += entry.hours();
+    }
+    per_day
+        .into_iter()
+        .map(|((sales_person_id, date), hours)| {
+            let date = ShiftyDate::from_date(date);
+            ShiftplanReportDay {
+                sales_person_id,
+                hours,
+                year: date.year(),
+                calendar_week: date.week(),
+                day_of_week: date.day_of_week(),
+            }
+        })
+        .collect()
+}
+
+#[automock(type Context=(); type Transaction = MockTransaction;)]
+#[async_trait]
+pub trait TimeEntryService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction;
+
+    /// Einträge, die zwischen `from` und `to` (inklusive) beginnen.
+    /// Permission: HR ∨ self.
+    async fn get_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        from: Date,
+        to: Date,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[TimeEntry]>, ServiceError>;
+
+    /// Der offene Eintrag, falls der Mitarbeiter eingestempelt ist.
+    /// Permission: HR ∨ self.
+    async fn get_open(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Option<TimeEntry>, ServiceError>;
+
+    /// Stempelt jetzt ein. Schlägt fehl, wenn bereits ein Eintrag offen ist
+    /// oder `booking_id` nicht zu einer Buchung der Person gehört.
+    /// Permission: HR ∨ self.
+    async fn clock_in(
+        &self,
+        sales_person_id: Uuid,
+        booking_id: Option<Uuid>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError>;
+
+    /// Schließt den offenen Eintrag mit der aktuellen Zeit.
+    /// Permission: HR ∨ self.
+    async fn clock_out(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError>;
+
+    /// Erfasst einen Eintrag nachträglich. Permission: HR.
+    async fn create(
+        &self,
+        entry: &TimeEntry,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError>;
+
+    /// Korrigiert Zeiten, Buchung und Beschreibung. Permission: HR.
+    async fn update(
+        &self,
+        entry: &TimeEntry,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError>;
+
+    /// Soft-Delete. Permission: HR.
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Geplante und erfasste Stunden pro Tag von `from` bis `to`
+    /// (inklusive). Permission: HR ∨ self.
+    async fn reconcile(
+        &self,
+        sales_person_id: Uuid,
+        from: Date,
+        to: Date,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ReconciliationDay]>, ServiceError>;
+
+    /// Erfasste Stunden pro Tag im Format des Schichtplan-Reports, für eine
+    /// Person oder (`None`) alle. Interner Aufruf des Reportings,
+    /// Permission: HR.
+    async fn extract_actual_hours_report(
+        &self,
+        sales_person_id: Option<Uuid>,
+        from_date: ShiftyDate,
+        to_date: ShiftyDate,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ShiftplanReportDay]>, ServiceError>;
+}
@@ -3,12 +3,13 @@ use crate::ServiceError;
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::sync::Arc;
 
 pub const TOGGLE_ADMIN_PRIVILEGE: &str = "toggle_admin";
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct Toggle {
     pub name: Arc<str>,
     pub enabled: bool,
@@ -38,7 +39,7 @@ impl From<&Toggle> for dao::toggle::ToggleEntity {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ToggleGroup {
     pub name: Arc<str>,
     pub description: Option<Arc<str>>,
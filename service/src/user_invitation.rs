@@ -22,6 +22,8 @@ pub enum InvitationStatus {
 pub struct UserInvitation {
     pub id: Uuid,
     pub username: String,
+    /// Das Geheimnis selbst; landet bewusst nicht im Audit-Log.
+    #[serde(skip_serializing)]
     pub token: Uuid,
     #[serde(with = "time::serde::rfc3339")]
     pub expiration_date: OffsetDateTime,
@@ -41,6 +43,7 @@ pub trait UserInvitationService {
     async fn generate_invitation(
         &self,
         username: &str,
+        email: Option<Arc<str>>,
         expiration_hours: i64,
         tx: Option<Self::Transaction>,
         auth: Authentication<Self::Context>,
@@ -23,6 +23,7 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use dao::vacation_entitlement_offset::VacationEntitlementOffsetEntity;
 use mockall::automock;
+use serde::Serialize;
 use uuid::Uuid;
 
 use crate::{permission::Authentication, ServiceError};
@@ -30,7 +31,7 @@ use crate::{permission::Authentication, ServiceError};
 /// Vorzeichenbehafteter Urlaubsanspruch-Offset eines Mitarbeiters für ein
 /// konkretes Kalenderjahr. Genau eine aktive Zeile pro (sales_person_id,
 /// year) (Soft-Delete-Historie erlaubt).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct VacationEntitlementOffset {
     pub id: Uuid,
     pub sales_person_id: Uuid,
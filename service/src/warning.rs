@@ -30,7 +30,9 @@ pub enum Warning {
         category: AbsenceCategory,
     },
     /// Beim Anlegen eines Bookings auf einem Tag, der durch
-    /// `sales_person_unavailable` abgedeckt ist (Reverse-Warning, BOOK-02).
+    /// `sales_person_unavailable` oder eine wiederkehrende `Unavailable`-Regel
+    /// (mit Zeitfenster: nur bei Überlappung mit dem Slot) abgedeckt ist
+    /// (Reverse-Warning, BOOK-02).
     BookingOnUnavailableDay {
         booking_id: Uuid,
         year: u32,
@@ -46,7 +48,8 @@ pub enum Warning {
     },
     /// Beim Anlegen einer AbsencePeriod, die einen bestehenden manuellen
     /// `sales_person_unavailable`-Eintrag überdeckt (Forward-Warning,
-    /// BOOK-01, D-Phase3-16: KEIN Auto-Cleanup).
+    /// BOOK-01, D-Phase3-16: KEIN Auto-Cleanup). Für wiederkehrende
+    /// `Unavailable`-Regeln trägt `unavailable_id` die Regel-Id.
     AbsenceOverlapsManualUnavailable {
         absence_id: Uuid,
         unavailable_id: Uuid,
@@ -70,4 +73,18 @@ pub enum Warning {
         current_paid_count: u8,
         max_paid_employees: u8,
     },
+    /// Emittiert über `ShiftplanEditService::book_slot_with_conflict_check`
+    /// (und damit auch beim Woche-Kopieren), wenn nach der Buchung weniger
+    /// als `required_count` der in (year, week, slot) gebuchten Personen
+    /// die Qualifikation haben. Eine Warning pro nicht erfüllter
+    /// Anforderung; die Buchung bleibt bestehen.
+    QualificationNotCovered {
+        slot_id: Uuid,
+        booking_id: Uuid,
+        year: u32,
+        week: u8,
+        qualification_id: Uuid,
+        required_count: u8,
+        qualified_count: u8,
+    },
 }
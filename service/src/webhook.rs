@@ -0,0 +1,298 @@
+//! Ausgehende Webhooks (Basic-Tier).
+//!
+//! Fachliche Services melden Ereignisse über [`WebhookService::publish`].
+//! Der Service legt für jedes aktive Abonnement, das den Ereignistyp
+//! gefiltert hat, eine Zustellung mit fertig serialisiertem JSON-Payload in
+//! derselben Transaktion ab. Zugestellt wird getrennt davon über
+//! [`WebhookService::deliver_pending`] (Scheduler-Job): `POST` an die URL,
+//! signiert mit HMAC-SHA256 über `<timestamp>.<body>`.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::webhook::{
+    WebhookDeliveryEntity, WebhookDeliveryState as WebhookDeliveryStateEntity,
+    WebhookEventType as WebhookEventTypeEntity, WebhookSubscriptionEntity,
+};
+use dao::MockTransaction;
+use mockall::automock;
+use serde::Serialize;
+use time::{Date, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::absence::AbsencePeriod;
+use crate::booking::Booking;
+use crate::permission::Authentication;
+use crate::week_status::WeekStatus;
+use crate::ServiceError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub enum WebhookEventType {
+    BookingCreated,
+    BookingDeleted,
+    AbsencePeriodCreated,
+    AbsencePeriodUpdated,
+    BillingPeriodCreated,
+    WeekStatusChanged,
+}
+
+impl From<WebhookEventTypeEntity> for WebhookEventType {
+    fn from(event_type: WebhookEventTypeEntity) -> Self {
+        match event_type {
+            WebhookEventTypeEntity::BookingCreated => Self::BookingCreated,
+            WebhookEventTypeEntity::BookingDeleted => Self::BookingDeleted,
+            WebhookEventTypeEntity::AbsencePeriodCreated => Self::AbsencePeriodCreated,
+            WebhookEventTypeEntity::AbsencePeriodUpdated => Self::AbsencePeriodUpdated,
+            WebhookEventTypeEntity::BillingPeriodCreated => Self::BillingPeriodCreated,
+            WebhookEventTypeEntity::WeekStatusChanged => Self::WeekStatusChanged,
+        }
+    }
+}
+
+impl From<WebhookEventType> for WebhookEventTypeEntity {
+    fn from(event_type: WebhookEventType) -> Self {
+        match event_type {
+            WebhookEventType::BookingCreated => Self::BookingCreated,
+            WebhookEventType::BookingDeleted => Self::BookingDeleted,
+            WebhookEventType::AbsencePeriodCreated => Self::AbsencePeriodCreated,
+            WebhookEventType::AbsencePeriodUpdated => Self::AbsencePeriodUpdated,
+            WebhookEventType::BillingPeriodCreated => Self::BillingPeriodCreated,
+            WebhookEventType::WeekStatusChanged => Self::WeekStatusChanged,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", WebhookEventTypeEntity::from(*self))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookDeliveryState {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl From<WebhookDeliveryStateEntity> for WebhookDeliveryState {
+    fn from(state: WebhookDeliveryStateEntity) -> Self {
+        match state {
+            WebhookDeliveryStateEntity::Pending => Self::Pending,
+            WebhookDeliveryStateEntity::Delivered => Self::Delivered,
+            WebhookDeliveryStateEntity::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<WebhookDeliveryState> for WebhookDeliveryStateEntity {
+    fn from(state: WebhookDeliveryState) -> Self {
+        match state {
+            WebhookDeliveryState::Pending => Self::Pending,
+            WebhookDeliveryState::Delivered => Self::Delivered,
+            WebhookDeliveryState::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Fachliches Ereignis. Die Varianten tragen den Zustand nach der Änderung,
+/// der unverändert als `data` im Payload landet.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebhookEvent {
+    BookingCreated(Booking),
+    BookingDeleted(Booking),
+    AbsencePeriodCreated(AbsencePeriod),
+    AbsencePeriodUpdated(AbsencePeriod),
+    BillingPeriodCreated {
+        id: Uuid,
+        start_date: Date,
+        end_date: Date,
+    },
+    WeekStatusChanged {
+        year: u32,
+        calendar_week: u8,
+        status: WeekStatus,
+    },
+}
+
+impl WebhookEvent {
+    pub fn event_type(&self) -> WebhookEventType {
+        match self {
+            Self::BookingCreated(_) => WebhookEventType::BookingCreated,
+            Self::BookingDeleted(_) => WebhookEventType::BookingDeleted,
+            Self::AbsencePeriodCreated(_) => WebhookEventType::AbsencePeriodCreated,
+            Self::AbsencePeriodUpdated(_) => WebhookEventType::AbsencePeriodUpdated,
+            Self::BillingPeriodCreated { .. } => WebhookEventType::BillingPeriodCreated,
+            Self::WeekStatusChanged { .. } => WebhookEventType::WeekStatusChanged,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub url: Arc<str>,
+    /// HMAC-Schlüssel. Beim Anlegen ohne Wert erzeugt der Service einen.
+    #[serde(skip_serializing)]
+    pub secret: Arc<str>,
+    pub description: Arc<str>,
+    pub event_types: Arc<[WebhookEventType]>,
+    pub active: bool,
+    pub created: Option<PrimitiveDateTime>,
+    pub deleted: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl WebhookSubscription {
+    pub fn is_subscribed(&self, event_type: WebhookEventType) -> bool {
+        self.active && self.deleted.is_none() && self.event_types.contains(&event_type)
+    }
+}
+
+impl From<&WebhookSubscriptionEntity> for WebhookSubscription {
+    fn from(entity: &WebhookSubscriptionEntity) -> Self {
+        Self {
+            id: entity.id,
+            url: entity.url.clone(),
+            secret: entity.secret.clone(),
+            description: entity.description.clone(),
+            event_types: entity
+                .event_types
+                .iter()
+                .map(|event_type| (*event_type).into())
+                .collect(),
+            active: entity.active,
+            created: Some(entity.created),
+            deleted: entity.deleted,
+            version: entity.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub payload: Arc<str>,
+    pub state: WebhookDeliveryState,
+    pub attempts: u32,
+    pub next_attempt: PrimitiveDateTime,
+    /// HTTP-Status der letzten Antwort, `None` ohne Antwort.
+    pub last_status: Option<u16>,
+    pub last_error: Option<Arc<str>>,
+    pub created: PrimitiveDateTime,
+    pub delivered: Option<PrimitiveDateTime>,
+    pub version: Uuid,
+}
+
+impl From<&WebhookDeliveryEntity> for WebhookDelivery {
+    fn from(entity: &WebhookDeliveryEntity) -> Self {
+        Self {
+            id: entity.id,
+            subscription_id: entity.subscription_id,
+            event_type: entity.event_type.into(),
+            payload: entity.payload.clone(),
+            state: entity.state.into(),
+            attempts: entity.attempts,
+            next_attempt: entity.next_attempt,
+            last_status: entity.last_status,
+            last_error: entity.last_error.clone(),
+            created: entity.created,
+            delivered: entity.delivered,
+            version: entity.version,
+        }
+    }
+}
+
+/// Ergebnis eines Zustell-Laufs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WebhookDeliveryReport {
+    pub delivered: u32,
+    /// Fehlgeschlagen, wird später erneut versucht.
+    pub retried: u32,
+    /// Fehlgeschlagen, keine weiteren Versuche.
+    pub failed: u32,
+}
+
+#[automock(type Context=(); type Transaction=MockTransaction;)]
+#[async_trait]
+pub trait WebhookService {
+    type Context: Clone + Debug + PartialEq + Eq + Send + Sync + 'static;
+    type Transaction: dao::Transaction + Debug + Clone + Send + Sync + 'static;
+
+    /// Legt für alle aktiven Abonnements des Ereignistyps eine Zustellung
+    /// an. Interner Aufruf der Fach-Services, daher kein Permission-Check.
+    async fn publish(
+        &self,
+        event: &WebhookEvent,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Alle Abonnements. Permission: Admin.
+    async fn get_subscriptions(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[WebhookSubscription]>, ServiceError>;
+
+    /// Permission: Admin.
+    async fn get_subscription(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookSubscription, ServiceError>;
+
+    /// Permission: Admin. Ohne `secret` wird ein zufälliger Schlüssel erzeugt.
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookSubscription, ServiceError>;
+
+    /// Permission: Admin. `version` muss zum gespeicherten Stand passen; ein
+    /// leeres `secret` behält den bisherigen Schlüssel.
+    async fn update_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookSubscription, ServiceError>;
+
+    /// Soft-Delete. Offene Zustellungen werden nicht mehr verschickt.
+    /// Permission: Admin.
+    async fn delete_subscription(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError>;
+
+    /// Die jüngsten Zustellungen eines Abonnements, neueste zuerst.
+    /// Permission: Admin.
+    async fn get_deliveries(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[WebhookDelivery]>, ServiceError>;
+
+    /// Setzt eine `Failed`-Zustellung zurück auf `Pending`. Permission: Admin.
+    async fn retry_delivery(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookDelivery, ServiceError>;
+
+    /// Stellt alle fälligen Zustellungen zu, jede in einer eigenen
+    /// Transaktion. Nur mit `Authentication::Full` (Scheduler).
+    async fn deliver_pending(
+        &self,
+        context: Authentication<Self::Context>,
+    ) -> Result<WebhookDeliveryReport, ServiceError>;
+}
@@ -3,11 +3,12 @@ use crate::ServiceError;
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::sync::Arc;
 use uuid::Uuid;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct WeekMessage {
     pub id: Uuid,
     pub year: u32,
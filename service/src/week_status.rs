@@ -3,13 +3,14 @@ use crate::ServiceError;
 use async_trait::async_trait;
 use dao::MockTransaction;
 use mockall::automock;
+use serde::Serialize;
 use std::fmt::Debug;
 
 /// Domain-level KW status. Unlike the persisted `dao::week_status::WeekStatusKind`,
 /// this enum carries a fourth variant `Unset`, which lives only in the service /
 /// frontend layer: row absence == `Unset` (D-39-04). It is deliberately named
 /// `Unset`, never `None`, to avoid Option-shadowing (D-39-03).
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum WeekStatus {
     Unset,
     InPlanning,
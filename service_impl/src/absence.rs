@@ -11,6 +11,11 @@
 //! Insert, D-07) und exkludiert die alte Row beim Self-Overlap-Check
 //! (`Some(logical_id)`, D-15). `delete` ist Soft-Delete via
 //! `update(tombstone)`.
+//!
+//! Antrags-Workflow: `create` ohne HR-Recht legt `Requested` an, `update`
+//! ohne HR-Recht setzt eine Periode zurück auf `Requested`. `approve`,
+//! `reject` und `cancel` rotieren die Row wie `update` mit neuem Status.
+//! Nur `Approved` geht in `derive_hours_for_range` ein.
 
 use crate::gen_service_impl;
 use std::collections::{BTreeMap, BTreeSet};
@@ -23,18 +28,24 @@ use dao::{
 };
 use service::{
     absence::{
-        AbsenceCategory, AbsencePeriod, AbsencePeriodCreateResult, AbsenceService, ResolvedAbsence,
+        AbsenceCategory, AbsencePeriod, AbsencePeriodCreateResult, AbsenceRequestProjection,
+        AbsenceService, AbsenceStatus, ResolvedAbsence,
     },
+    audit_log::{entity_type, AuditChange, AuditLogService},
     booking::BookingService,
+    carryover::{first_dirty_year, CarryoverService},
     clock::ClockService,
     employee_work_details::EmployeeWorkDetailsService,
-    permission::{Authentication, HR_PRIVILEGE},
+    notification::{NotificationEvent, NotificationService},
+    permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
     sales_person::SalesPersonService,
+    sales_person_availability::{AvailabilityKind, SalesPersonAvailabilityService},
     sales_person_unavailable::SalesPersonUnavailableService,
     slot::SlotService,
     special_days::{SpecialDayService, SpecialDayType},
     uuid_service::UuidService,
     warning::Warning,
+    webhook::{WebhookEvent, WebhookService},
     PermissionService, ServiceError, ValidationFailureItem,
 };
 use shifty_utils::DateRange;
@@ -58,7 +69,15 @@ gen_service_impl! {
         // (Booking trägt nur slot_id + calendar_week + year).
         BookingService: BookingService<Context = Self::Context, Transaction = Self::Transaction> = booking_service,
         SalesPersonUnavailableService: SalesPersonUnavailableService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_unavailable_service,
+        // Wiederkehrende `Unavailable`-Regeln für dieselbe Forward-Warning.
+        SalesPersonAvailabilityService: SalesPersonAvailabilityService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_availability_service,
         SlotService: SlotService<Context = Self::Context, Transaction = Self::Transaction> = slot_service,
+        // E-Mail an die betroffene Person bei neuer Abwesenheit.
+        NotificationService: NotificationService<Context = Self::Context, Transaction = Self::Transaction> = notification_service,
+        WebhookService: WebhookService<Context = Self::Context, Transaction = Self::Transaction> = webhook_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        // Abwesenheiten gehen in den Jahres-Report und damit in den Carryover ein.
+        CarryoverService: CarryoverService<Context = Self::Context, Transaction = Self::Transaction> = carryover_service,
     }
 }
 
@@ -73,6 +92,12 @@ fn absence_category_priority(category: &AbsenceCategory) -> u8 {
     }
 }
 
+fn status_not_allowed() -> ServiceError {
+    ServiceError::ValidationError(Arc::from([ValidationFailureItem::ModificationNotAllowed(
+        "status".into(),
+    )]))
+}
+
 /// Helfer für `range.contains(date)` — `DateRange` selbst hat kein
 /// `contains` (Phase-1-Surface), wir nutzen die invariante
 /// `from <= date <= to`.
@@ -112,7 +137,7 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
@@ -144,7 +169,7 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 active.sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
@@ -166,10 +191,11 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 request.sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
+        let is_hr = hr.is_ok();
         hr.or(sp)?;
 
         let mut entity = request.to_owned();
@@ -211,6 +237,12 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
             .uuid_service
             .new_uuid("absence_service::create::version");
         entity.created = Some(self.clock_service.date_time_now());
+        // HR erfasst direkt, alle anderen stellen einen Antrag.
+        entity.status = if is_hr {
+            AbsenceStatus::Approved
+        } else {
+            AbsenceStatus::Requested
+        };
 
         let dao_entity = absence::AbsencePeriodEntity::try_from(&entity)?;
         self.absence_dao
@@ -229,6 +261,34 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
             )
             .await?;
 
+        if entity.is_effective() {
+            self.notify_absence_created(&entity, tx.clone()).await?;
+        }
+        self.webhook_service
+            .publish(
+                &WebhookEvent::AbsencePeriodCreated(entity.clone()),
+                Some(tx.clone()),
+            )
+            .await?;
+        if entity.is_effective() {
+            self.carryover_service
+                .mark_dirty(
+                    entity.sales_person_id,
+                    first_dirty_year(entity.from_date),
+                    Authentication::Full,
+                    Some(tx.clone()),
+                )
+                .await?;
+        }
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::ABSENCE_PERIOD, entity.id, &entity)?,
+                "absence_service::create",
+                context,
+                Some(tx.clone()),
+            )
+            .await?;
+
         self.transaction_dao.commit(tx).await?;
         Ok(AbsencePeriodCreateResult {
             absence: entity,
@@ -256,12 +316,22 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 active.sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
+        let is_hr = hr.is_ok();
         hr.or(sp)?;
 
+        // Abgelehnte und zurückgezogene Perioden sind final, eine genehmigte
+        // darf wie bei `delete` und `cancel` nur HR ändern.
+        let status = match AbsenceStatus::from(&active.status) {
+            AbsenceStatus::Rejected | AbsenceStatus::Cancelled => return Err(status_not_allowed()),
+            status if is_hr => status,
+            AbsenceStatus::Approved => return Err(status_not_allowed()),
+            _ => AbsenceStatus::Requested,
+        };
+
         if request.sales_person_id != active.sales_person_id {
             return Err(ServiceError::ValidationError(Arc::from([
                 ValidationFailureItem::ModificationNotAllowed("sales_person_id".into()),
@@ -325,6 +395,7 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
             // from `active` — otherwise the user could never change the
             // value via update. PATTERNS.md Pattern 6 covers this.
             day_fraction: (&request.day_fraction).into(),
+            status: (&status).into(),
         };
         self.absence_dao
             .create(&new_entity, "absence_service::update::insert", tx.clone())
@@ -344,11 +415,37 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
             )
             .await?;
 
+        let absence = AbsencePeriod::from(&new_entity);
+        self.webhook_service
+            .publish(
+                &WebhookEvent::AbsencePeriodUpdated(absence.clone()),
+                Some(tx.clone()),
+            )
+            .await?;
+        self.carryover_service
+            .mark_dirty(
+                active.sales_person_id,
+                first_dirty_year(active.from_date.min(absence.from_date)),
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::ABSENCE_PERIOD,
+                    logical_id,
+                    &AbsencePeriod::from(&active),
+                    &absence,
+                )?,
+                "absence_service::update",
+                context,
+                Some(tx.clone()),
+            )
+            .await?;
+
         self.transaction_dao.commit(tx).await?;
-        Ok(AbsencePeriodCreateResult {
-            absence: AbsencePeriod::from(&new_entity),
-            warnings,
-        })
+        Ok(AbsencePeriodCreateResult { absence, warnings })
     }
 
     async fn delete(
@@ -368,22 +465,241 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 active.sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
+        let is_hr = hr.is_ok();
         hr.or(sp)?;
+        // Wie bei `cancel`: eine genehmigte Periode darf nur HR entfernen.
+        if !is_hr && active.status == absence::AbsenceStatusEntity::Approved {
+            return Err(status_not_allowed());
+        }
 
+        let before = AbsencePeriod::from(&active);
         let mut tombstone = active;
         tombstone.deleted = Some(self.clock_service.date_time_now());
         self.absence_dao
             .update(&tombstone, "absence_service::delete", tx.clone())
             .await?;
+        self.carryover_service
+            .mark_dirty(
+                before.sales_person_id,
+                first_dirty_year(before.from_date),
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::ABSENCE_PERIOD, id, &before)?,
+                "absence_service::delete",
+                context,
+                Some(tx.clone()),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
 
+    async fn approve(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsencePeriodCreateResult, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (hr, planner) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+        );
+        hr.or(planner)?;
+        let active = self
+            .absence_dao
+            .find_by_logical_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        if active.status != absence::AbsenceStatusEntity::Requested {
+            return Err(status_not_allowed());
+        }
+
+        let absence = self
+            .change_status(
+                active,
+                AbsenceStatus::Approved,
+                "absence_service::approve",
+                context,
+                tx.clone(),
+            )
+            .await?;
+        let warnings = self
+            .compute_forward_warnings(
+                absence.id,
+                absence.sales_person_id,
+                absence.date_range()?,
+                tx.clone(),
+            )
+            .await?;
+        self.notify_absence_created(&absence, tx.clone()).await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(AbsencePeriodCreateResult { absence, warnings })
+    }
+
+    async fn reject(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsencePeriod, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (hr, planner) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+        );
+        hr.or(planner)?;
+        let active = self
+            .absence_dao
+            .find_by_logical_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        if active.status != absence::AbsenceStatusEntity::Requested {
+            return Err(status_not_allowed());
+        }
+
+        let absence = self
+            .change_status(
+                active,
+                AbsenceStatus::Rejected,
+                "absence_service::reject",
+                context,
+                tx.clone(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(absence)
+    }
+
+    async fn cancel(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsencePeriod, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let active = self
+            .absence_dao
+            .find_by_logical_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let (hr, sp) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                active.sales_person_id,
+                context.clone(),
+                tx.clone().into()
+            ),
+        );
+        let is_hr = hr.is_ok();
+        hr.or(sp)?;
+        // Genehmigte Perioden kann nur HR zurückziehen.
+        match active.status {
+            absence::AbsenceStatusEntity::Requested => {}
+            absence::AbsenceStatusEntity::Approved if is_hr => {}
+            _ => return Err(status_not_allowed()),
+        }
+
+        let absence = self
+            .change_status(
+                active,
+                AbsenceStatus::Cancelled,
+                "absence_service::cancel",
+                context,
+                tx.clone(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(absence)
+    }
+
+    async fn project_request(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AbsenceRequestProjection, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let active = self
+            .absence_dao
+            .find_by_logical_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let (hr, planner, sp) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                active.sales_person_id,
+                context.clone(),
+                tx.clone().into()
+            ),
+        );
+        hr.or(planner).or(sp)?;
+
+        let absence = AbsencePeriod::from(&active);
+        let range = absence.date_range()?;
+        // Bestand (genehmigt) plus der Antrag selbst, als wäre er genehmigt.
+        let mut absences: Vec<absence::AbsencePeriodEntity> = self
+            .absence_dao
+            .find_by_sales_person(active.sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .filter(|ap| {
+                ap.status == absence::AbsenceStatusEntity::Approved
+                    && ap.logical_id != active.logical_id
+            })
+            .cloned()
+            .collect();
+        absences.push(absence::AbsencePeriodEntity {
+            status: absence::AbsenceStatusEntity::Approved,
+            ..active.clone()
+        });
+        // Der Shiftplanner darf Vertragsdaten sonst nicht lesen; die
+        // Permission ist oben geprüft.
+        let resolved = self
+            .resolve_days(
+                range,
+                active.sales_person_id,
+                &absences,
+                Authentication::Full,
+                tx.clone(),
+            )
+            .await?;
+        let mut days_by_year: BTreeMap<u32, f32> = BTreeMap::new();
+        for (date, resolved_day) in resolved.iter() {
+            if resolved_day.category == absence.category {
+                *days_by_year.entry(date.year() as u32).or_default() += resolved_day.days;
+            }
+        }
+        let warnings = self
+            .compute_forward_warnings(absence.id, absence.sales_person_id, range, tx.clone())
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(AbsenceRequestProjection {
+            absence,
+            days_by_year,
+            warnings,
+        })
+    }
+
     async fn derive_hours_for_range(
         &self,
         from: Date,
@@ -411,146 +727,20 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
         hr.or(sp)?;
 
         // Batch-Fetch: Absences fuer den Mitarbeiter (D-Phase2-02 — keine
-        // per-Tag-DAO-Calls; einmal lesen, dann per Tag filtern).
-        let absences = self
+        // per-Tag-DAO-Calls; einmal lesen, dann per Tag filtern). Offene,
+        // abgelehnte und zurückgezogene Antraege zaehlen nicht.
+        let absences: Vec<absence::AbsencePeriodEntity> = self
             .absence_dao
             .find_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .filter(|ap| ap.status == absence::AbsenceStatusEntity::Approved)
+            .cloned()
+            .collect();
+        let result = self
+            .resolve_days(date_range, sales_person_id, &absences, context, tx.clone())
             .await?;
 
-        // Batch-Fetch: alle Vertraege; per Tag den am Tag aktiven via
-        // from_date()/to_date() filtern (analog reporting::find_working_hours_for_calendar_week).
-        let work_details = self
-            .employee_work_details_service
-            .find_by_sales_person_id(sales_person_id, context.clone(), Some(tx.clone()))
-            .await?;
-
-        // Kalenderwochen-Set aus dem Range bilden — pro Woche EIN
-        // SpecialDayService-Call (deduplizierter batch).
-        let mut weeks: BTreeSet<(u32, u8)> = BTreeSet::new();
-        for day in date_range.iter_days() {
-            let (iso_year, iso_week, _) = day.to_iso_week_date();
-            weeks.insert((iso_year as u32, iso_week));
-        }
-
-        // Holidays aus SpecialDayService ziehen und auf konkrete time::Date
-        // mappen (SpecialDay traegt year/calendar_week/day_of_week).
-        let mut holidays: BTreeSet<Date> = BTreeSet::new();
-        for (year, week) in weeks.iter() {
-            let special = self
-                .special_day_service
-                .get_by_week(*year, *week, context.clone())
-                .await?;
-            for sd in special.iter() {
-                if sd.deleted.is_some() {
-                    continue;
-                }
-                if sd.day_type != SpecialDayType::Holiday {
-                    continue;
-                }
-                if let Ok(holiday_date) = time::Date::from_iso_week_date(
-                    sd.year as i32,
-                    sd.calendar_week,
-                    sd.day_of_week.into(),
-                ) {
-                    holidays.insert(holiday_date);
-                }
-            }
-        }
-
-        // Per-Tag-Iteration mit WOCHEN-DECKELUNG.
-        //
-        // Domänenmodell (vom Nutzer bestätigt): Die angehakten Wochentag-Booleans
-        // (`has_day_of_week`) sagen nur, WANN die Person arbeiten KANN
-        // (Verfügbarkeit) — NICHT, wie viele Tage sie arbeitet. Maßgeblich ist
-        // `workdays_per_week`. Pro ISO-Woche werden daher höchstens
-        // `workdays_per_week` Urlaubstage gezählt; jeder gezählte Tag bringt
-        // `hours_per_day() = expected_hours / workdays_per_week`. Eine volle
-        // Urlaubswoche ergibt damit exakt `workdays_per_week` Tage /
-        // `expected_hours` Stunden, unabhängig davon, an wie vielen Wochentagen
-        // die Person verfügbar ist. (Bug: vacation-hours-overcounted — vorher
-        // wurde JEDER verfügbare Tag gezählt → Überzählung.)
-        let mut result: BTreeMap<Date, ResolvedAbsence> = BTreeMap::new();
-        // Akkumulator pro ISO-Woche (Schlüssel: Montag der Woche) → bereits
-        // gezählte Urlaubstage (fraktional wegen Halbtagen).
-        let mut week_counted: BTreeMap<Date, f32> = BTreeMap::new();
-        for day in date_range.iter_days() {
-            // Aktiven Vertrag fuer den Tag waehlen (from_date()/to_date()
-            // sind ShiftyDate-Wrapper — bei Konvertierungs-Fehlern wird der
-            // Vertrag uebersprungen).
-            let active_contract = work_details.iter().find(|wh| {
-                if wh.deleted.is_some() {
-                    return false;
-                }
-                let from_date = match wh.from_date() {
-                    Ok(d) => d.to_date(),
-                    Err(_) => return false,
-                };
-                let to_date = match wh.to_date() {
-                    Ok(d) => d.to_date(),
-                    Err(_) => return false,
-                };
-                from_date <= day && day <= to_date
-            });
-            let Some(contract) = active_contract else {
-                continue;
-            };
-            // Verfügbarkeit: nur an angehakten Wochentagen kann Urlaub anfallen.
-            if !contract.has_day_of_week(day.weekday()) {
-                continue;
-            }
-            if holidays.contains(&day) {
-                continue;
-            }
-
-            // Aktive Absences: alle nicht-getombstoneten Perioden, deren
-            // [from_date, to_date] den Tag enthaelt.
-            let dominant = absences
-                .iter()
-                .filter(|ap| {
-                    ap.deleted.is_none() && ap.from_date <= day && day <= ap.to_date
-                })
-                .max_by_key(|ap| absence_category_priority(&(&ap.category).into()));
-            let Some(dominant) = dominant else {
-                continue;
-            };
-
-            let workdays = contract.workdays_per_week as f32;
-            let hours_per_day = contract.hours_per_day();
-            if workdays <= 0.0 || hours_per_day <= 0.0 {
-                continue;
-            }
-
-            // Wochen-Deckelung: Montag der ISO-Woche als Schlüssel. Sobald das
-            // Wochenkontingent (`workdays_per_week`) erschöpft ist, zählen
-            // weitere verfügbare Tage derselben Woche nicht mehr (0 Stunden,
-            // kein Map-Eintrag).
-            let monday = day
-                - time::Duration::days(day.weekday().number_days_from_monday() as i64);
-            let already = *week_counted.get(&monday).unwrap_or(&0.0);
-            let remaining = workdays - already;
-            if remaining <= 0.0 {
-                continue;
-            }
-
-            // Phase 8.3 (D-08.3-04) — Halbtag zählt 0.5 Tage. An der
-            // Deckelungsgrenze wird der Tagesanteil zusätzlich auf `remaining`
-            // begrenzt.
-            let day_fraction_factor: f32 = match dominant.day_fraction {
-                dao::absence::DayFractionEntity::Half => 0.5,
-                dao::absence::DayFractionEntity::Full => 1.0,
-            };
-            let counted = day_fraction_factor.min(remaining);
-            week_counted.insert(monday, already + counted);
-            result.insert(
-                day,
-                ResolvedAbsence {
-                    category: (&dominant.category).into(),
-                    hours: counted * hours_per_day,
-                    days: counted,
-                },
-            );
-        }
-
         self.transaction_dao.commit(tx).await?;
         Ok(result)
     }
@@ -570,7 +760,7 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
@@ -803,6 +993,235 @@ impl<Deps: AbsenceServiceDeps> AbsenceService for AbsenceServiceImpl<Deps> {
     }
 }
 
+impl<Deps: AbsenceServiceDeps> AbsenceServiceImpl<Deps> {
+    /// Per-Tag-Aufloesung fuer [`AbsenceService::derive_hours_for_range`]
+    /// ueber die uebergebenen `absences` (Aufrufer filtert den Status).
+    async fn resolve_days(
+        &self,
+        date_range: DateRange,
+        sales_person_id: Uuid,
+        absences: &[absence::AbsencePeriodEntity],
+        context: Authentication<Deps::Context>,
+        tx: <Deps as AbsenceServiceDeps>::Transaction,
+    ) -> Result<BTreeMap<Date, ResolvedAbsence>, ServiceError> {
+        // Batch-Fetch: alle Vertraege; per Tag den am Tag aktiven via
+        // from_date()/to_date() filtern (analog reporting::find_working_hours_for_calendar_week).
+        let work_details = self
+            .employee_work_details_service
+            .find_by_sales_person_id(sales_person_id, context.clone(), Some(tx))
+            .await?;
+
+        // Kalenderwochen-Set aus dem Range bilden — pro Woche EIN
+        // SpecialDayService-Call (deduplizierter batch).
+        let mut weeks: BTreeSet<(u32, u8)> = BTreeSet::new();
+        for day in date_range.iter_days() {
+            let (iso_year, iso_week, _) = day.to_iso_week_date();
+            weeks.insert((iso_year as u32, iso_week));
+        }
+
+        // Holidays aus SpecialDayService ziehen und auf konkrete time::Date
+        // mappen (SpecialDay traegt year/calendar_week/day_of_week).
+        let mut holidays: BTreeSet<Date> = BTreeSet::new();
+        for (year, week) in weeks.iter() {
+            let special = self
+                .special_day_service
+                .get_by_week(*year, *week, context.clone())
+                .await?;
+            for sd in special.iter() {
+                if sd.deleted.is_some() {
+                    continue;
+                }
+                if sd.day_type != SpecialDayType::Holiday {
+                    continue;
+                }
+                if let Ok(holiday_date) = time::Date::from_iso_week_date(
+                    sd.year as i32,
+                    sd.calendar_week,
+                    sd.day_of_week.into(),
+                ) {
+                    holidays.insert(holiday_date);
+                }
+            }
+        }
+
+        // Per-Tag-Iteration mit WOCHEN-DECKELUNG.
+        //
+        // Domänenmodell (vom Nutzer bestätigt): Die angehakten Wochentag-Booleans
+        // (`has_day_of_week`) sagen nur, WANN die Person arbeiten KANN
+        // (Verfügbarkeit) — NICHT, wie viele Tage sie arbeitet. Maßgeblich ist
+        // `workdays_per_week`. Pro ISO-Woche werden daher höchstens
+        // `workdays_per_week` Urlaubstage gezählt; jeder gezählte Tag bringt
+        // `hours_per_day() = expected_hours / workdays_per_week`. Eine volle
+        // Urlaubswoche ergibt damit exakt `workdays_per_week` Tage /
+        // `expected_hours` Stunden, unabhängig davon, an wie vielen Wochentagen
+        // die Person verfügbar ist. (Bug: vacation-hours-overcounted — vorher
+        // wurde JEDER verfügbare Tag gezählt → Überzählung.)
+        let mut result: BTreeMap<Date, ResolvedAbsence> = BTreeMap::new();
+        // Akkumulator pro ISO-Woche (Schlüssel: Montag der Woche) → bereits
+        // gezählte Urlaubstage (fraktional wegen Halbtagen).
+        let mut week_counted: BTreeMap<Date, f32> = BTreeMap::new();
+        for day in date_range.iter_days() {
+            // Aktiven Vertrag fuer den Tag waehlen (from_date()/to_date()
+            // sind ShiftyDate-Wrapper — bei Konvertierungs-Fehlern wird der
+            // Vertrag uebersprungen).
+            let active_contract = work_details.iter().find(|wh| {
+                if wh.deleted.is_some() {
+                    return false;
+                }
+                let from_date = match wh.from_date() {
+                    Ok(d) => d.to_date(),
+                    Err(_) => return false,
+                };
+                let to_date = match wh.to_date() {
+                    Ok(d) => d.to_date(),
+                    Err(_) => return false,
+                };
+                from_date <= day && day <= to_date
+            });
+            let Some(contract) = active_contract else {
+                continue;
+            };
+            // Verfügbarkeit: nur an angehakten Wochentagen kann Urlaub anfallen.
+            if !contract.has_day_of_week(day.weekday()) {
+                continue;
+            }
+            if holidays.contains(&day) {
+                continue;
+            }
+
+            // Aktive Absences: alle nicht-getombstoneten Perioden, deren
+            // [from_date, to_date] den Tag enthaelt.
+            let dominant = absences
+                .iter()
+                .filter(|ap| {
+                    ap.deleted.is_none() && ap.from_date <= day && day <= ap.to_date
+                })
+                .max_by_key(|ap| absence_category_priority(&(&ap.category).into()));
+            let Some(dominant) = dominant else {
+                continue;
+            };
+
+            let workdays = contract.workdays_per_week as f32;
+            let hours_per_day = contract.hours_per_day();
+            if workdays <= 0.0 || hours_per_day <= 0.0 {
+                continue;
+            }
+
+            // Wochen-Deckelung: Montag der ISO-Woche als Schlüssel. Sobald das
+            // Wochenkontingent (`workdays_per_week`) erschöpft ist, zählen
+            // weitere verfügbare Tage derselben Woche nicht mehr (0 Stunden,
+            // kein Map-Eintrag).
+            let monday = day
+                - time::Duration::days(day.weekday().number_days_from_monday() as i64);
+            let already = *week_counted.get(&monday).unwrap_or(&0.0);
+            let remaining = workdays - already;
+            if remaining <= 0.0 {
+                continue;
+            }
+
+            // Phase 8.3 (D-08.3-04) — Halbtag zählt 0.5 Tage. An der
+            // Deckelungsgrenze wird der Tagesanteil zusätzlich auf `remaining`
+            // begrenzt.
+            let day_fraction_factor: f32 = match dominant.day_fraction {
+                dao::absence::DayFractionEntity::Half => 0.5,
+                dao::absence::DayFractionEntity::Full => 1.0,
+            };
+            let counted = day_fraction_factor.min(remaining);
+            week_counted.insert(monday, already + counted);
+            result.insert(
+                day,
+                ResolvedAbsence {
+                    category: (&dominant.category).into(),
+                    hours: counted * hours_per_day,
+                    days: counted,
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Status-Wechsel per Rotation (Tombstone + Insert, D-07) wie `update`;
+    /// Webhook, Carryover und Audit laufen wie dort.
+    async fn change_status(
+        &self,
+        active: absence::AbsencePeriodEntity,
+        status: AbsenceStatus,
+        process: &str,
+        context: Authentication<Deps::Context>,
+        tx: <Deps as AbsenceServiceDeps>::Transaction,
+    ) -> Result<AbsencePeriod, ServiceError> {
+        let before = AbsencePeriod::from(&active);
+        let now = self.clock_service.date_time_now();
+        let mut tombstone = active.clone();
+        tombstone.deleted = Some(now);
+        self.absence_dao
+            .update(&tombstone, process, tx.clone())
+            .await?;
+
+        let new_entity = absence::AbsencePeriodEntity {
+            id: self
+                .uuid_service
+                .new_uuid("absence_service::change_status::id"),
+            version: self
+                .uuid_service
+                .new_uuid("absence_service::change_status::version"),
+            created: now,
+            deleted: None,
+            status: (&status).into(),
+            ..active
+        };
+        self.absence_dao
+            .create(&new_entity, process, tx.clone())
+            .await?;
+
+        let absence = AbsencePeriod::from(&new_entity);
+        self.webhook_service
+            .publish(
+                &WebhookEvent::AbsencePeriodUpdated(absence.clone()),
+                Some(tx.clone()),
+            )
+            .await?;
+        if before.is_effective() || absence.is_effective() {
+            self.carryover_service
+                .mark_dirty(
+                    absence.sales_person_id,
+                    first_dirty_year(absence.from_date),
+                    Authentication::Full,
+                    Some(tx.clone()),
+                )
+                .await?;
+        }
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::ABSENCE_PERIOD, absence.id, &before, &absence)?,
+                process,
+                context,
+                Some(tx),
+            )
+            .await?;
+        Ok(absence)
+    }
+
+    async fn notify_absence_created(
+        &self,
+        absence: &AbsencePeriod,
+        tx: <Deps as AbsenceServiceDeps>::Transaction,
+    ) -> Result<(), ServiceError> {
+        self.notification_service
+            .notify(
+                &NotificationEvent::AbsenceCreated {
+                    sales_person_id: absence.sales_person_id,
+                    category: absence.category,
+                    from_date: absence.from_date,
+                    to_date: absence.to_date,
+                },
+                Some(tx),
+            )
+            .await
+    }
+}
+
 /// Reine Umrechnung eines Stundenpostens in Anzeige-Tage: `hours / hours_per_day`.
 /// `hours_per_day <= 0` (kein/fehlerhafter Vertrag) ⇒ 0.0 (keine Division durch
 /// Null, kein NaN/Inf in der UI). Single Source of Truth für die Marker-Tage.
@@ -824,13 +1243,15 @@ fn days_from_hours(hours: f32, hours_per_day: f32) -> f32 {
 // und VOR dem `commit`. Pro Booking-Tag in der neuen Range gibt es genau eine
 // `Warning::AbsenceOverlapsBooking` (D-Phase3-15: keine De-Dup); pro
 // überlappendem ManualUnavailable genau eine `Warning::AbsenceOverlapsManualUnavailable`
-// (D-Phase3-16: KEIN Auto-Cleanup).
+// (D-Phase3-16: KEIN Auto-Cleanup); ebenso pro wiederkehrender
+// `Unavailable`-Regel, die an mindestens einem Tag der Range gilt.
 //
 // Performance-Hinweis (C-Phase3-02): Der Loop iteriert pro Booking-betroffene
 // Kalenderwoche genau einmal `BookingService::get_for_week`. Pro Booking
 // genau einmal `SlotService::get_slot` für die day_of_week-Auflösung —
 // in einer Range mit N Wochen und M Bookings sind das O(N + M) Calls.
-// `SalesPersonUnavailableService::get_all_for_sales_person` läuft genau
+// `SalesPersonUnavailableService::get_all_for_sales_person` und
+// `SalesPersonAvailabilityService::get_all_for_sales_person` laufen genau
 // einmal — clientseitiger Filter pro Tag. Plan 06 verifiziert Performance
 // auf 60-Tage-Ranges.
 // =========================================================================
@@ -922,6 +1343,26 @@ impl<Deps: AbsenceServiceDeps> AbsenceServiceImpl<Deps> {
             });
         }
 
+        // 3) Wiederkehrende `Unavailable`-Regeln — ein Call, Regel gilt an
+        // mindestens einem Tag der Range. Teil-Regeln zählen mit, weil die
+        // Abwesenheit den ganzen Tag abdeckt.
+        let availabilities = self
+            .sales_person_availability_service
+            .get_all_for_sales_person(sales_person_id, Authentication::Full, tx.clone().into())
+            .await?;
+        for rule in availabilities.iter() {
+            if rule.kind != AvailabilityKind::Unavailable {
+                continue;
+            }
+            if !new_range.iter_days().any(|day| rule.applies_on(day)) {
+                continue;
+            }
+            warnings.push(Warning::AbsenceOverlapsManualUnavailable {
+                absence_id,
+                unavailable_id: rule.id,
+            });
+        }
+
         Ok(Arc::from(warnings))
     }
 }
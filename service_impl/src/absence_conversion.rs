@@ -20,7 +20,8 @@ use dao::{
 use service::{
     absence::{AbsencePeriod, DayFraction},
     absence_conversion::AbsenceConversionService,
-    extra_hours::ExtraHoursService,
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    extra_hours::{ExtraHours, ExtraHoursService},
     permission::{Authentication, HR_PRIVILEGE},
     PermissionService, ServiceError, ValidationFailureItem,
 };
@@ -36,6 +37,7 @@ gen_service_impl! {
         MigrationSourceDao: dao::migration_source::MigrationSourceDao<Transaction = Self::Transaction> = migration_source_dao,
         ExtraHoursService: service::extra_hours::ExtraHoursService<Context = Self::Context, Transaction = Self::Transaction> = extra_hours_service,
         PermissionService: service::PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -142,6 +144,8 @@ impl<Deps: AbsenceConversionServiceDeps> AbsenceConversionService
                 .as_ref()
                 .map(dao::absence::DayFractionEntity::from)
                 .unwrap_or(dao::absence::DayFractionEntity::Full),
+            // HR-Konvertierung von Bestandsdaten — kein Antrag.
+            status: dao::absence::AbsenceStatusEntity::Approved,
         };
         self.absence_dao
             .create(&absence_entity, "absence_conversion::convert", tx.clone())
@@ -178,7 +182,35 @@ impl<Deps: AbsenceConversionServiceDeps> AbsenceConversionService
             )
             .await?;
 
+        // Die Umwandlung erscheint im Änderungsprotokoll als Löschen der
+        // Stunden und Anlegen der Abwesenheit.
+        let absence_period = AbsencePeriod::from(&absence_entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(
+                    entity_type::EXTRA_HOURS,
+                    extra_hours_id,
+                    &ExtraHours::from(&entity),
+                )?,
+                "absence_conversion::convert",
+                context.clone(),
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(
+                    entity_type::ABSENCE_PERIOD,
+                    absence_period_id,
+                    &absence_period,
+                )?,
+                "absence_conversion::convert",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
         self.transaction_dao.commit(tx).await?;
-        Ok((&absence_entity).into())
+        Ok(absence_period)
     }
 }
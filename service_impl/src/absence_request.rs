@@ -0,0 +1,120 @@
+//! Service-Impl der Antrags-Übersicht (BL-Tier).
+//!
+//! Liest die offenen Anträge über `AbsenceService::find_all`, projiziert jeden
+//! per `AbsenceService::project_request` und ergänzt für Urlaub den
+//! Resturlaub aus dem `VacationBalanceService` (pro Mitarbeiter und Jahr
+//! einmal geladen). Die inneren Aufrufe laufen mit `Authentication::Full`,
+//! die Permission (HR ∨ Shiftplanner) ist vorher geprüft. Der HR-only
+//! Breakdown der Balance (`offset_days`, `computed_entitled_days`) wird für
+//! Nicht-HR-Aufrufer entfernt.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    absence::{AbsenceCategory, AbsenceService, AbsenceStatus},
+    absence_request::{AbsenceRequestService, PendingAbsenceRequest},
+    permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    vacation_balance::{VacationBalance, VacationBalanceService},
+    PermissionService, ServiceError,
+};
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+gen_service_impl! {
+    struct AbsenceRequestServiceImpl: AbsenceRequestService = AbsenceRequestServiceDeps {
+        AbsenceService: AbsenceService<Context = Self::Context, Transaction = Self::Transaction> = absence_service,
+        VacationBalanceService: VacationBalanceService<Context = Self::Context, Transaction = Self::Transaction> = vacation_balance_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+#[async_trait]
+impl<Deps: AbsenceRequestServiceDeps> AbsenceRequestService for AbsenceRequestServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn find_pending(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PendingAbsenceRequest]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (hr, planner) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+        );
+        let is_hr = hr.is_ok();
+        hr.or(planner)?;
+
+        let mut requested: Vec<_> = self
+            .absence_service
+            .find_all(Authentication::Full, tx.clone().into())
+            .await?
+            .iter()
+            .filter(|absence| absence.deleted.is_none())
+            .filter(|absence| absence.status == AbsenceStatus::Requested)
+            .cloned()
+            .collect();
+        requested.sort_by_key(|absence| (absence.from_date, absence.sales_person_id));
+
+        let mut balances: HashMap<(Uuid, u32), VacationBalance> = HashMap::new();
+        let mut result = Vec::with_capacity(requested.len());
+        for absence in requested {
+            let projection = self
+                .absence_service
+                .project_request(absence.id, Authentication::Full, tx.clone().into())
+                .await?;
+            let requested_days = projection.days_by_year.values().sum();
+
+            let (vacation_balance, remaining_days_after) =
+                if absence.category == AbsenceCategory::Vacation {
+                    let year = absence.from_date.year() as u32;
+                    let key = (absence.sales_person_id, year);
+                    let balance = match balances.get(&key) {
+                        Some(balance) => balance.clone(),
+                        None => {
+                            let mut balance = self
+                                .vacation_balance_service
+                                .get(
+                                    absence.sales_person_id,
+                                    year,
+                                    Authentication::Full,
+                                    tx.clone().into(),
+                                )
+                                .await?;
+                            if !is_hr {
+                                balance.offset_days = None;
+                                balance.computed_entitled_days = None;
+                            }
+                            balances.insert(key, balance.clone());
+                            balance
+                        }
+                    };
+                    let days_in_year = projection.days_by_year.get(&year).copied();
+                    let remaining = balance.remaining_days - days_in_year.unwrap_or(0.0);
+                    (Some(balance), Some(remaining))
+                } else {
+                    (None, None)
+                };
+
+            result.push(PendingAbsenceRequest {
+                absence,
+                requested_days,
+                vacation_balance,
+                remaining_days_after,
+                warnings: projection.warnings,
+            });
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(result.into())
+    }
+}
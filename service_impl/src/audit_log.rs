@@ -0,0 +1,257 @@
+//! Basic-Tier Implementation von [`service::audit_log::AuditLogService`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    audit_log::{AuditLogDao, AuditLogEntity, AuditLogQuery},
+    TransactionDao,
+};
+use serde_json::Value;
+use service::{
+    audit_log::{
+        current_real_user, AuditChange, AuditFieldChange, AuditLogEntry, AuditLogFilter,
+        AuditLogService,
+    },
+    clock::ClockService,
+    permission::{Authentication, HR_PRIVILEGE},
+    uuid_service::UuidService,
+    PermissionService, ServiceError,
+};
+use time::PrimitiveDateTime;
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+const AUDIT_LOG_SERVICE_PROCESS: &str = "audit-log-service";
+const ADMIN_PRIVILEGE: &str = "admin";
+const DEFAULT_LIMIT: u32 = 200;
+const MAX_LIMIT: u32 = 1000;
+
+/// Felder der obersten Ebene; kein Schnappschuss zählt als leeres Objekt,
+/// andere JSON-Werte als `None`.
+fn object_fields(value: Option<&Value>) -> Option<BTreeMap<&str, &Value>> {
+    match value {
+        None => Some(BTreeMap::new()),
+        Some(Value::Object(fields)) => Some(
+            fields
+                .iter()
+                .map(|(field, value)| (field.as_str(), value))
+                .collect(),
+        ),
+        Some(_) => None,
+    }
+}
+
+/// Feldweise Differenz zweier JSON-Schnappschüsse, nach Feldname sortiert.
+/// Sind beide Objekte, wird pro Feld der obersten Ebene verglichen; sonst
+/// gilt der ganze Wert als ein Feld `value`.
+pub fn diff_snapshots(before: Option<&str>, after: Option<&str>) -> Arc<[AuditFieldChange]> {
+    let parse =
+        |json: Option<&str>| json.and_then(|json| serde_json::from_str::<Value>(json).ok());
+    let (before, after) = (parse(before), parse(after));
+    let to_json = |value: Option<&Value>| value.map(|value| Arc::from(value.to_string()));
+    let (Some(before_fields), Some(after_fields)) =
+        (object_fields(before.as_ref()), object_fields(after.as_ref()))
+    else {
+        return if before == after {
+            Arc::new([])
+        } else {
+            Arc::new([AuditFieldChange {
+                field: "value".into(),
+                before: to_json(before.as_ref()),
+                after: to_json(after.as_ref()),
+            }])
+        };
+    };
+    before_fields
+        .keys()
+        .chain(after_fields.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|field| {
+            let old = before_fields.get(field).copied();
+            let new = after_fields.get(field).copied();
+            (old != new).then(|| AuditFieldChange {
+                field: (*field).into(),
+                before: to_json(old),
+                after: to_json(new),
+            })
+        })
+        .collect()
+}
+
+fn audit_log_entry(entity: &AuditLogEntity) -> AuditLogEntry {
+    AuditLogEntry {
+        id: entity.id,
+        entity_type: entity.entity_type.clone(),
+        entity_id: entity.entity_id.clone(),
+        action: entity.action.into(),
+        user_id: entity.user_id.clone(),
+        real_user_id: entity.real_user_id.clone(),
+        process: entity.process.clone(),
+        before: entity.before.clone(),
+        after: entity.after.clone(),
+        changes: diff_snapshots(entity.before.as_deref(), entity.after.as_deref()),
+        created: entity.created,
+    }
+}
+
+/// Protokolleintrag für `change` durch `user_id`. Auch vom
+/// `PermissionServiceImpl` genutzt, das den [`AuditLogService`] nicht als
+/// Dependency haben kann, weil dieser selbst vom Permission-Service abhängt.
+pub(crate) fn audit_log_entity(
+    change: &AuditChange,
+    process: &str,
+    user_id: Option<Arc<str>>,
+    id: Uuid,
+    created: PrimitiveDateTime,
+) -> AuditLogEntity {
+    let real_user_id = current_real_user().filter(|real_user| Some(real_user) != user_id.as_ref());
+    AuditLogEntity {
+        id,
+        entity_type: change.entity_type.into(),
+        entity_id: change.entity_id.clone(),
+        action: change.action.into(),
+        user_id,
+        real_user_id,
+        process: process.into(),
+        before: change.before.clone(),
+        after: change.after.clone(),
+        created,
+    }
+}
+
+gen_service_impl! {
+    struct AuditLogServiceImpl: AuditLogService = AuditLogServiceDeps {
+        AuditLogDao: AuditLogDao<Transaction = Self::Transaction> = audit_log_dao,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+#[async_trait]
+impl<Deps: AuditLogServiceDeps> AuditLogService for AuditLogServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn record(
+        &self,
+        change: &AuditChange,
+        process: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let user_id = self.permission_service.current_user_id(context).await?;
+        let entity = audit_log_entity(
+            change,
+            process,
+            user_id,
+            self.uuid_service
+                .new_uuid(&format!("{AUDIT_LOG_SERVICE_PROCESS}::record id")),
+            self.clock_service.date_time_now(),
+        );
+        self.audit_log_dao.create(&entity, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        filter: &AuditLogFilter,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[AuditLogEntry]>, ServiceError> {
+        let (hr, admin) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(ADMIN_PRIVILEGE, context),
+        );
+        hr.or(admin)?;
+        if let (Some(from), Some(to)) = (filter.from, filter.to) {
+            if from > to {
+                return Err(ServiceError::DateOrderWrong(from, to));
+            }
+        }
+        let query = AuditLogQuery {
+            entity_type: filter.entity_type.clone(),
+            entity_id: filter.entity_id.clone(),
+            user_id: filter.user_id.clone(),
+            from: filter.from.map(|from| from.midnight()),
+            to: filter
+                .to
+                .and_then(|to| to.next_day())
+                .map(|to| to.midnight()),
+            limit: match filter.limit {
+                0 => DEFAULT_LIMIT,
+                limit => limit.min(MAX_LIMIT),
+            },
+        };
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entries = self
+            .audit_log_dao
+            .find(&query, tx.clone())
+            .await?
+            .iter()
+            .map(audit_log_entry)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(field: &str, before: Option<&str>, after: Option<&str>) -> AuditFieldChange {
+        AuditFieldChange {
+            field: field.into(),
+            before: before.map(Arc::from),
+            after: after.map(Arc::from),
+        }
+    }
+
+    #[test]
+    fn diff_update_lists_changed_fields_only() {
+        let changes = diff_snapshots(
+            Some(r#"{"name":"Anna","min_resources":1,"note":null}"#),
+            Some(r#"{"name":"Anna","min_resources":2,"note":"x"}"#),
+        );
+        assert_eq!(
+            changes.as_ref(),
+            &[
+                change("min_resources", Some("1"), Some("2")),
+                change("note", Some("null"), Some("\"x\"")),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_create_and_delete_list_all_fields() {
+        assert_eq!(
+            diff_snapshots(None, Some(r#"{"b":true,"a":1}"#)).as_ref(),
+            &[change("a", None, Some("1")), change("b", None, Some("true"))]
+        );
+        assert_eq!(
+            diff_snapshots(Some(r#"{"a":1}"#), None).as_ref(),
+            &[change("a", Some("1"), None)]
+        );
+    }
+
+    #[test]
+    fn diff_non_object_values() {
+        assert_eq!(
+            diff_snapshots(Some(r#""Planned""#), Some(r#""Locked""#)).as_ref(),
+            &[change("value", Some("\"Planned\""), Some("\"Locked\""))]
+        );
+        assert!(diff_snapshots(Some("1"), Some("1")).is_empty());
+    }
+}
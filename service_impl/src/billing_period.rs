@@ -7,6 +7,8 @@ use dao::billing_period_sales_person::{
     BillingPeriodSalesPersonDao, BillingPeriodSalesPersonEntity,
 };
 use dao::TransactionDao;
+use serde::Serialize;
+use service::audit_log::{entity_type, AuditChange, AuditLogService};
 use service::billing_period::{
     BillingPeriod, BillingPeriodSalesPerson, BillingPeriodService, BillingPeriodValue,
     BillingPeriodValueType,
@@ -15,8 +17,10 @@ use service::clock::ClockService;
 use service::permission::{Authentication, HR_PRIVILEGE};
 use service::sales_person::SalesPersonService;
 use service::uuid_service::UuidService;
+use service::webhook::{WebhookEvent, WebhookService};
 use service::{PermissionService, ServiceError};
 use shifty_utils::ShiftyDate;
+use time::{Date, PrimitiveDateTime};
 use uuid::Uuid;
 
 use crate::gen_service_impl;
@@ -31,11 +35,66 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         UuidService: UuidService = uuid_service,
         ClockService: ClockService = clock_service,
+        WebhookService: WebhookService<Context = Self::Context, Transaction = Self::Transaction> = webhook_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
 
+/// Protokoll-Schnappschuss eines Abrechnungszeitraums. Die eingefrorenen
+/// Werte der Mitarbeiter sind nicht enthalten, nur wer dazugehört.
+#[derive(Serialize)]
+struct BillingPeriodAudit {
+    id: Uuid,
+    start_date: Date,
+    end_date: Date,
+    snapshot_schema_version: u32,
+    sales_person_ids: Vec<Uuid>,
+    created_at: PrimitiveDateTime,
+    created_by: Arc<str>,
+}
+
+impl BillingPeriodAudit {
+    fn new(entity: &BillingPeriodEntity, mut sales_person_ids: Vec<Uuid>) -> Self {
+        sales_person_ids.sort();
+        sales_person_ids.dedup();
+        Self {
+            id: entity.id,
+            start_date: entity.start_date,
+            end_date: entity.end_date,
+            snapshot_schema_version: entity.snapshot_schema_version,
+            sales_person_ids,
+            created_at: entity.created_at,
+            created_by: entity.created_by.clone(),
+        }
+    }
+}
+
 impl<Deps: BillingPeriodServiceDeps> BillingPeriodServiceImpl<Deps> {
+    /// `sales_persons`: alle nicht gelöschten Zeilen, vor dem Löschen gelesen.
+    async fn record_delete(
+        &self,
+        entity: &BillingPeriodEntity,
+        sales_persons: &[BillingPeriodSalesPersonEntity],
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let sales_person_ids = sales_persons
+            .iter()
+            .filter(|sales_person| sales_person.billing_period_id == entity.id)
+            .map(|sales_person| sales_person.sales_person_id)
+            .collect();
+        let before = BillingPeriodAudit::new(entity, sales_person_ids);
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::BILLING_PERIOD, entity.id, &before)?,
+                BILLING_PERIOD_REPORT_SERVICE,
+                context,
+                tx.into(),
+            )
+            .await
+    }
+
     pub async fn insert_billing_period_sales_person(
         &self,
         billing_period_id: Uuid,
@@ -189,6 +248,33 @@ impl<Deps: BillingPeriodServiceDeps> BillingPeriodService for BillingPeriodServi
             .await?;
         }
 
+        self.webhook_service
+            .publish(
+                &WebhookEvent::BillingPeriodCreated {
+                    id: created_entity.id,
+                    start_date: created_entity.start_date,
+                    end_date: created_entity.end_date,
+                },
+                Some(tx.clone()),
+            )
+            .await?;
+        let after = BillingPeriodAudit::new(
+            &created_entity,
+            entity
+                .sales_persons
+                .iter()
+                .map(|sales_person| sales_person.sales_person_id)
+                .collect(),
+        );
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::BILLING_PERIOD, created_entity.id, &after)?,
+                process,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
         self.transaction_dao.commit(tx).await?;
 
         Ok(BillingPeriod::from_billing_period_entity(
@@ -229,7 +315,8 @@ impl<Deps: BillingPeriodServiceDeps> BillingPeriodService for BillingPeriodServi
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
         // Check that the billing period exists
-        self.billing_period_dao
+        let entity = self
+            .billing_period_dao
             .find_by_id(id, tx.clone())
             .await?
             .ok_or(ServiceError::EntityNotFound(id))?;
@@ -247,9 +334,10 @@ impl<Deps: BillingPeriodServiceDeps> BillingPeriodService for BillingPeriodServi
 
         let user = self
             .permission_service
-            .current_user_id(context)
+            .current_user_id(context.clone())
             .await?
             .unwrap_or("Unauthenticated".into());
+        let sales_persons = self.billing_period_sales_person_dao.all(tx.clone()).await?;
 
         // Cascade: delete associated sales person entries first
         self.billing_period_sales_person_dao
@@ -260,6 +348,8 @@ impl<Deps: BillingPeriodServiceDeps> BillingPeriodService for BillingPeriodServi
         self.billing_period_dao
             .delete_by_id(id, &user, tx.clone())
             .await?;
+        self.record_delete(&entity, &sales_persons, context, tx.clone())
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
@@ -279,9 +369,11 @@ impl<Deps: BillingPeriodServiceDeps> BillingPeriodService for BillingPeriodServi
 
         let user = self
             .permission_service
-            .current_user_id(context)
+            .current_user_id(context.clone())
             .await?
             .unwrap_or("Unauthenticated".into());
+        let billing_periods = self.billing_period_dao.all(tx.clone()).await?;
+        let sales_persons = self.billing_period_sales_person_dao.all(tx.clone()).await?;
 
         // Clear all billing period sales person entries first
         self.billing_period_sales_person_dao
@@ -292,6 +384,10 @@ impl<Deps: BillingPeriodServiceDeps> BillingPeriodService for BillingPeriodServi
         self.billing_period_dao
             .clear_all(&user, tx.clone())
             .await?;
+        for billing_period in billing_periods.iter() {
+            self.record_delete(billing_period, &sales_persons, context.clone(), tx.clone())
+                .await?;
+        }
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
@@ -3,28 +3,33 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use serde_json::json;
-use service::text_template::TemplateEngine;
-use tera::{Context, Tera};
 use dao::TransactionDao;
 use service::billing_period::{
     BillingPeriod, BillingPeriodSalesPerson, BillingPeriodService, BillingPeriodValue,
     BillingPeriodValueType,
 };
-use service::billing_period_report::BillingPeriodReportService;
+use service::billing_period_report::{
+    BillingPeriodReportService, BILLING_PERIOD_PDF_TEMPLATE_TYPE,
+};
 use service::clock::ClockService;
 use service::employee_work_details::EmployeeWorkDetailsService;
 use service::permission::{Authentication, HR_PRIVILEGE};
+use service::report_export::ExportFile;
 use service::reporting::ReportingService;
 use service::sales_person::{SalesPerson, SalesPersonService};
-use service::text_template::TextTemplateService;
+use service::text_template::{TextTemplate, TextTemplateService};
 use service::uuid_service::UuidService;
 use service::PermissionService;
-use service::ServiceError;
+use service::{ServiceError, ValidationFailureItem};
 use shifty_utils::ShiftyDate;
 use time::macros::datetime;
 use uuid::Uuid;
 
 use crate::gen_service_impl;
+use crate::pdf_shiftplan::resolve_render_timestamp;
+use crate::pdf_template_render::{parse_layout, render_layout_pdf};
+use crate::report_export::file_name_slug;
+use crate::text_template::render_template_text;
 
 const BILLING_PERIOD_REPORT_SERVICE: &str = "BillingPeriodReportService";
 
@@ -329,6 +334,117 @@ impl<Deps: BillingPeriodReportServiceDeps> BillingPeriodReportServiceImpl<Deps>
             deleted_by: None,
         })
     }
+
+    /// Lädt Template und Abrechnungszeitraum und baut den Template-Kontext
+    /// für `generate_custom_report` und `generate_custom_report_pdf`.
+    async fn load_custom_report_input(
+        &self,
+        template_id: Uuid,
+        billing_period_id: Uuid,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(TextTemplate, BillingPeriod, serde_json::Value), ServiceError> {
+        // Check HR permission
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        // Load the text template
+        let text_template = self
+            .text_template_service
+            .get_by_id(template_id, context.clone(), tx.clone().into())
+            .await?;
+
+        let (billing_period, context_data) = self
+            .custom_report_context(&text_template, billing_period_id, context, tx)
+            .await?;
+
+        Ok((text_template, billing_period, context_data))
+    }
+
+    /// Baut den Template-Kontext der Custom Reports für `text_template`.
+    /// Die HR-Prüfung liegt beim Aufrufer.
+    async fn custom_report_context(
+        &self,
+        text_template: &TextTemplate,
+        billing_period_id: Uuid,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(BillingPeriod, serde_json::Value), ServiceError> {
+        // Load the billing period
+        let billing_period = self
+            .billing_period_service
+            .get_billing_period_by_id(billing_period_id, context.clone(), tx.clone().into())
+            .await?;
+
+        // Load all sales persons and employee work details for enrichment
+        let all_sales_persons = self
+            .sales_person_service
+            .get_all(context.clone(), tx.clone().into())
+            .await?;
+        let all_work_details = self
+            .employee_work_details_service
+            .all(context.clone(), tx.clone().into())
+            .await?;
+
+        // Build template context data as JSON
+        let context_data = json!({
+            "billing_period": {
+                "id": billing_period.id.to_string(),
+                "start_date": billing_period.start_date.to_date().to_string(),
+                "end_date": billing_period.end_date.to_date().to_string(),
+                "created_at": billing_period.created_at.to_string(),
+                "created_by": billing_period.created_by.as_ref(),
+                "sales_persons": billing_period.sales_persons.iter().map(|sp| {
+                    let sales_person = all_sales_persons.iter().find(|s| s.id == sp.sales_person_id);
+                    let name = sales_person.map(|s| s.name.as_ref()).unwrap_or("");
+                    let is_paid = sales_person.and_then(|s| s.is_paid).unwrap_or(false);
+                    let is_dynamic = all_work_details.iter()
+                        .filter(|wd| wd.sales_person_id == sp.sales_person_id)
+                        .any(|wd| wd.is_dynamic);
+                    let sanitize = |v: f32| -> f64 {
+                        let v = v as f64;
+                        if v.is_nan() || v.is_infinite() { 0.0 } else { v }
+                    };
+                    let values_map: serde_json::Map<String, serde_json::Value> = sp.values.iter().map(|(key, value)| {
+                        (key.as_str().to_string(), json!({
+                            "delta": sanitize(value.value_delta),
+                            "ytd_from": sanitize(value.value_ytd_from),
+                            "ytd_to": sanitize(value.value_ytd_to),
+                            "full_year": sanitize(value.value_full_year),
+                        }))
+                    }).collect();
+                    json!({
+                        "id": sp.id.to_string(),
+                        "sales_person_id": sp.sales_person_id.to_string(),
+                        "name": name,
+                        "is_paid": is_paid,
+                        "is_dynamic": is_dynamic,
+                        "values": sp.values.iter().map(|(key, value)| {
+                            json!({
+                                "type": key.as_str().as_ref(),
+                                "value_delta": sanitize(value.value_delta),
+                                "value_ytd_from": sanitize(value.value_ytd_from),
+                                "value_ytd_to": sanitize(value.value_ytd_to),
+                                "value_full_year": sanitize(value.value_full_year),
+                            })
+                        }).collect::<Vec<_>>(),
+                        "values_map": values_map,
+                        "created_at": sp.created_at.to_string(),
+                        "created_by": sp.created_by.as_ref(),
+                    })
+                }).collect::<Vec<_>>(),
+            },
+            "template": {
+                "id": text_template.id.to_string(),
+                "template_type": text_template.template_type.as_ref(),
+                "created_at": text_template.created_at.map(|dt| dt.to_string()),
+                "created_by": text_template.created_by.as_ref().map(|s| s.as_ref()),
+            }
+        });
+
+        Ok((billing_period, context_data))
+    }
 }
 
 #[async_trait]
@@ -433,119 +549,74 @@ impl<Deps: BillingPeriodReportServiceDeps> BillingPeriodReportService
     ) -> Result<Arc<str>, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
-        // Check HR permission
-        self.permission_service
-            .check_permission(HR_PRIVILEGE, context.clone())
+        let (text_template, _, context_data) = self
+            .load_custom_report_input(template_id, billing_period_id, context, tx.clone())
             .await?;
+        let rendered = render_template_text(&text_template, &context_data, false)?;
 
-        // Load the text template
-        let text_template = self
-            .text_template_service
-            .get_by_id(template_id, context.clone(), tx.clone().into())
-            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(rendered.into())
+    }
 
-        // Load the billing period
-        let billing_period = self
-            .billing_period_service
-            .get_billing_period_by_id(billing_period_id, context.clone(), tx.clone().into())
-            .await?;
+    async fn generate_custom_report_pdf(
+        &self,
+        template_id: Uuid,
+        billing_period_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
 
-        // Load all sales persons and employee work details for enrichment
-        let all_sales_persons = self
-            .sales_person_service
-            .get_all(context.clone(), tx.clone().into())
-            .await?;
-        let all_work_details = self
-            .employee_work_details_service
-            .all(context.clone(), tx.clone().into())
+        let (text_template, billing_period, context_data) = self
+            .load_custom_report_input(template_id, billing_period_id, context, tx.clone())
             .await?;
+        if text_template.template_type.as_ref() != BILLING_PERIOD_PDF_TEMPLATE_TYPE {
+            return Err(ServiceError::ValidationError(Arc::from([
+                ValidationFailureItem::InvalidValue(
+                    format!(
+                        "Template hat den Typ `{}`, für PDF wird `{BILLING_PERIOD_PDF_TEMPLATE_TYPE}` benötigt",
+                        text_template.template_type
+                    )
+                    .into(),
+                ),
+            ])));
+        }
+        let markup = render_template_text(&text_template, &context_data, true)?;
+        let layout = parse_layout(&markup)?;
+        let content = render_layout_pdf(&layout, resolve_render_timestamp())?;
 
-        // Build template context data as JSON
-        let context_data = json!({
-            "billing_period": {
-                "id": billing_period.id.to_string(),
-                "start_date": billing_period.start_date.to_date().to_string(),
-                "end_date": billing_period.end_date.to_date().to_string(),
-                "created_at": billing_period.created_at.to_string(),
-                "created_by": billing_period.created_by.as_ref(),
-                "sales_persons": billing_period.sales_persons.iter().map(|sp| {
-                    let sales_person = all_sales_persons.iter().find(|s| s.id == sp.sales_person_id);
-                    let name = sales_person.map(|s| s.name.as_ref()).unwrap_or("");
-                    let is_paid = sales_person.and_then(|s| s.is_paid).unwrap_or(false);
-                    let is_dynamic = all_work_details.iter()
-                        .filter(|wd| wd.sales_person_id == sp.sales_person_id)
-                        .any(|wd| wd.is_dynamic);
-                    let sanitize = |v: f32| -> f64 {
-                        let v = v as f64;
-                        if v.is_nan() || v.is_infinite() { 0.0 } else { v }
-                    };
-                    let values_map: serde_json::Map<String, serde_json::Value> = sp.values.iter().map(|(key, value)| {
-                        (key.as_str().to_string(), json!({
-                            "delta": sanitize(value.value_delta),
-                            "ytd_from": sanitize(value.value_ytd_from),
-                            "ytd_to": sanitize(value.value_ytd_to),
-                            "full_year": sanitize(value.value_full_year),
-                        }))
-                    }).collect();
-                    json!({
-                        "id": sp.id.to_string(),
-                        "sales_person_id": sp.sales_person_id.to_string(),
-                        "name": name,
-                        "is_paid": is_paid,
-                        "is_dynamic": is_dynamic,
-                        "values": sp.values.iter().map(|(key, value)| {
-                            json!({
-                                "type": key.as_str().as_ref(),
-                                "value_delta": sanitize(value.value_delta),
-                                "value_ytd_from": sanitize(value.value_ytd_from),
-                                "value_ytd_to": sanitize(value.value_ytd_to),
-                                "value_full_year": sanitize(value.value_full_year),
-                            })
-                        }).collect::<Vec<_>>(),
-                        "values_map": values_map,
-                        "created_at": sp.created_at.to_string(),
-                        "created_by": sp.created_by.as_ref(),
-                    })
-                }).collect::<Vec<_>>(),
-            },
-            "template": {
-                "id": text_template.id.to_string(),
-                "template_type": text_template.template_type.as_ref(),
-                "created_at": text_template.created_at.map(|dt| dt.to_string()),
-                "created_by": text_template.created_by.as_ref().map(|s| s.as_ref()),
-            }
-        });
+        let mut file_name = format!(
+            "billing-period-{}-{}",
+            billing_period.start_date.to_date(),
+            billing_period.end_date.to_date()
+        );
+        if let Some(name) = text_template.name.as_deref() {
+            file_name = format!("{file_name}-{}", file_name_slug(name));
+        }
 
-        // Render using the appropriate engine
-        let rendered = match text_template.template_engine {
-            TemplateEngine::Tera => {
-                let mut tera = Tera::default();
-                tera.add_raw_template("custom_report", &text_template.template_text)
-                    .map_err(|e| {
-                        tracing::error!("Failed to parse Tera template: {}", e);
-                        ServiceError::InternalError
-                    })?;
-                let template_context = Context::from_serialize(&context_data).map_err(|e| {
-                    tracing::error!("Failed to serialize template context: {}", e);
-                    ServiceError::InternalError
-                })?;
-                tera.render("custom_report", &template_context)
-                    .map_err(|e| {
-                        tracing::error!("Failed to render Tera template: {}", e);
-                        ServiceError::InternalError
-                    })?
-            }
-            TemplateEngine::MiniJinja => {
-                let env = minijinja::Environment::new();
-                env.render_str(&text_template.template_text, context_data)
-                    .map_err(|e| {
-                        tracing::error!("Failed to render MiniJinja template: {e:#}");
-                        ServiceError::InternalError
-                    })?
-            }
-        };
+        self.transaction_dao.commit(tx).await?;
+        Ok(ExportFile {
+            file_name: format!("{file_name}.pdf").into(),
+            content_type: "application/pdf",
+            content: content.into(),
+        })
+    }
 
+    async fn build_custom_report_context(
+        &self,
+        template: &TextTemplate,
+        billing_period_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<serde_json::Value, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        let (_, context_data) = self
+            .custom_report_context(template, billing_period_id, context, tx.clone())
+            .await?;
         self.transaction_dao.commit(tx).await?;
-        Ok(rendered.into())
+        Ok(context_data)
     }
 }
@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use service::{
@@ -6,24 +9,27 @@ use service::{
     booking::{Booking, BookingService},
     clock::ClockService,
     config::ConfigService,
-    ical::IcalService,
-    permission::Authentication,
-    sales_person::SalesPersonService,
+    ical::{IcalCalendar, IcalEvent, IcalService},
+    permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
+    sales_person::{SalesPerson, SalesPersonService},
     shiftplan::ShiftplanViewService,
     slot::{Slot, SlotService},
     special_days::SpecialDayService,
     toggle::ToggleService,
-    ServiceError,
+    week_message::WeekMessageService,
+    PermissionService, ServiceError,
 };
 use shifty_utils::{DayOfWeek, ShiftyWeek};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::gen_service_impl;
+use crate::ical::sequence_for;
 use crate::shortday_gate;
 use crate::shortday_gate::{clip_slot_for_week, ClipOutcome, ShortdayMode};
 use dao::TransactionDao; // import your transaction trait
-use time::Time;
+use time::{PrimitiveDateTime, Time};
+use tokio::join;
 
 // Automatically generate the `BlockServiceDeps` trait and the `BlockServiceImpl` struct.
 //
@@ -43,6 +49,9 @@ gen_service_impl! {
         // vor Merge braucht ShortDay-Lookup pro Woche + Toggle-Wert.
         SpecialDayService: SpecialDayService<Context = Self::Context> = special_day_service,
         ToggleService: ToggleService<Context = Self::Context, Transaction = Self::Transaction> = toggle_service,
+        // iCal-Feed: Wochennachricht in der DESCRIPTION, Rechteprüfung für den Feed.
+        WeekMessageService: WeekMessageService<Context = Self::Context, Transaction = Self::Transaction> = week_message_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -218,21 +227,49 @@ impl<Deps: BlockServiceDeps> BlockService for BlockServiceImpl<Deps> {
     async fn get_blocks_for_next_weeks_as_ical(
         &self,
         sales_person_id: Uuid,
-        _context: Authentication<Self::Context>,
+        context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<Arc<str>, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        // Eigener Feed oder Shiftplanner; den Feed der unterbesetzten Blöcke
+        // (nil-ID) nur Shiftplanner.
+        if sales_person_id != Uuid::nil() {
+            let (shiftplanner_permission, is_sales_person) = join!(
+                self.permission_service
+                    .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+                self.sales_person_service.verify_user_is_sales_person(
+                    sales_person_id,
+                    context,
+                    tx.clone().into()
+                ),
+            );
+            shiftplanner_permission.or(is_sales_person)?;
+        } else {
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+                .await?;
+        }
+
+        let config = self.config_service.get_config().await?;
+        let names: HashMap<Uuid, Arc<str>> = self
+            .sales_person_service
+            .get_all(Authentication::Full, tx.clone().into())
+            .await?
+            .iter()
+            .map(|sales_person: &SalesPerson| (sales_person.id, sales_person.name.clone()))
+            .collect();
         let mut now = self.clock_service.date_now();
         now -= time::Duration::weeks(2);
 
-        let mut blocks = vec![];
+        let mut events = vec![];
 
         for _ in 0..12 {
             let (year, week, _) = now.to_iso_week_date();
+            let (year, week) = (year as u32, week);
             let week_blocks = if sales_person_id != Uuid::nil() {
                 self.get_blocks_for_sales_person_week(
                     sales_person_id,
-                    year as u32,
+                    year,
                     week,
                     Authentication::Full,
                     tx.clone().into(),
@@ -240,20 +277,78 @@ impl<Deps: BlockServiceDeps> BlockService for BlockServiceImpl<Deps> {
                 .await?
             } else {
                 self.get_unsufficiently_booked_blocks(
-                    year as u32,
+                    year,
                     week,
                     Authentication::Full,
                     tx.clone().into(),
                 )
                 .await?
             };
-            blocks.extend_from_slice(&week_blocks);
+            let week_bookings = self
+                .booking_service
+                .get_for_week(week, year, Authentication::Full, tx.clone().into())
+                .await?;
+            let mut deleted_pairs = vec![];
+            for booking in self
+                .booking_service
+                .get_deleted_for_week(week, year, Authentication::Full, tx.clone().into())
+                .await?
+                .iter()
+                .filter(|booking| {
+                    sales_person_id == Uuid::nil() || booking.sales_person_id == sales_person_id
+                })
+            {
+                // Gelöschte Slots haben keinen Termin mehr, den man absagen kann.
+                if let Ok(slot) = self
+                    .slot_service
+                    .get_slot(&booking.slot_id, Authentication::Full, tx.clone().into())
+                    .await
+                {
+                    deleted_pairs.push((booking.clone(), slot));
+                }
+            }
+            let week_message = self
+                .week_message_service
+                .get_by_year_and_week(year, week, Authentication::Full, tx.clone().into())
+                .await?
+                .map(|week_message| week_message.message)
+                .filter(|message| !message.trim().is_empty());
+
+            for block in week_blocks.iter() {
+                events.push(IcalEvent {
+                    uid: block_uid(block),
+                    sequence: sequence_for(latest_change(block, &week_blocks, &deleted_pairs)),
+                    start: block.datetime_from()?,
+                    end: block.datetime_to()?,
+                    all_day: false,
+                    summary: config.ical_label.clone(),
+                    description: block_description(
+                        block,
+                        &week_bookings,
+                        &names,
+                        week_message.as_deref(),
+                    ),
+                    cancelled: false,
+                });
+            }
+            if sales_person_id != Uuid::nil() {
+                events.extend(cancelled_events(
+                    year,
+                    week,
+                    &deleted_pairs,
+                    &week_bookings,
+                    &config.ical_label,
+                )?);
+            }
             now += time::Duration::weeks(1);
         }
-        let ical = self.ical_service.convert_blocks_to_ical_string(
-            blocks.into(),
-            self.config_service.get_config().await?.ical_label.clone(),
-            self.config_service.get_config().await?.timezone.clone(),
+        let ical = self.ical_service.render_calendar(
+            &IcalCalendar {
+                name: config.ical_label.clone(),
+                timezone: config.timezone.clone(),
+                events: events.into(),
+            },
+            self.clock_service.date_time_now(),
         )?;
 
         self.transaction_dao.commit(tx).await?;
@@ -456,3 +551,154 @@ impl<Deps: BlockServiceDeps> BlockService for BlockServiceImpl<Deps> {
         Ok(Arc::from(all_blocks))
     }
 }
+
+/// Stabile UID eines Blocks: die erste Buchung des Blocks. Wird eine Buchung
+/// angehängt, bleibt die UID gleich; fällt die erste weg, trägt die Absage
+/// (siehe [`cancelled_events`]) ihre UID. Unterbesetzte Blöcke haben ggf.
+/// keine Buchung und nehmen den ersten Slot samt Woche.
+fn block_uid(block: &Block) -> Arc<str> {
+    match (block.sales_person.as_ref(), block.bookings.first()) {
+        (Some(_), Some(booking)) => format!("{}@shifty", booking.id).into(),
+        _ => format!(
+            "understaffed-{}-{}-{}@shifty",
+            block.year,
+            block.week,
+            block.slots.first().map(|slot| slot.id).unwrap_or_default()
+        )
+        .into(),
+    }
+}
+
+/// Letzte Änderung, die den Block betrifft: angelegte Buchungen desselben
+/// Tages und Löschungen an diesem Tag bzw. in den Slots des Blocks.
+fn latest_change(
+    block: &Block,
+    week_blocks: &[Block],
+    deleted_pairs: &[(Booking, Slot)],
+) -> Option<PrimitiveDateTime> {
+    let personal = block.sales_person.is_some();
+    let created = week_blocks
+        .iter()
+        .filter(|other| {
+            if personal {
+                other.day_of_week == block.day_of_week
+            } else {
+                other == &block
+            }
+        })
+        .flat_map(|other| other.bookings.iter())
+        .filter_map(|booking| booking.created);
+    let deleted = deleted_pairs
+        .iter()
+        .filter(|(_, slot)| {
+            if personal {
+                slot.day_of_week == block.day_of_week
+            } else {
+                block.slots.iter().any(|block_slot| block_slot.id == slot.id)
+            }
+        })
+        .filter_map(|(booking, _)| booking.deleted);
+    created.chain(deleted).max()
+}
+
+/// Kolleg:innen in denselben Slots und die Wochennachricht.
+fn block_description(
+    block: &Block,
+    week_bookings: &[Booking],
+    names: &HashMap<Uuid, Arc<str>>,
+    week_message: Option<&str>,
+) -> Option<Arc<str>> {
+    let own_id = block.sales_person.as_ref().map(|sales_person| sales_person.id);
+    let colleagues: BTreeSet<&str> = week_bookings
+        .iter()
+        .filter(|booking| Some(booking.sales_person_id) != own_id)
+        .filter(|booking| block.slots.iter().any(|slot| slot.id == booking.slot_id))
+        .filter_map(|booking| names.get(&booking.sales_person_id))
+        .map(|name| name.as_ref())
+        .collect();
+
+    let mut lines = vec![];
+    if !colleagues.is_empty() {
+        lines.push(format!(
+            "Kolleg:innen: {}",
+            colleagues.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+    }
+    if let Some(week_message) = week_message {
+        lines.push(format!("Wochennachricht: {week_message}"));
+    }
+    (!lines.is_empty()).then(|| lines.join("\n").into())
+}
+
+/// `STATUS:CANCELLED`-Events für gelöschte Buchungen, deren Slot in dieser
+/// Woche nicht erneut gebucht ist. Zusammenhängende Slots werden wie bei den
+/// Blöcken zusammengefasst, damit die UID der ersten Buchung mit der des
+/// ehemaligen Blocks übereinstimmt.
+fn cancelled_events(
+    year: u32,
+    week: u8,
+    deleted_pairs: &[(Booking, Slot)],
+    week_bookings: &[Booking],
+    summary: &Arc<str>,
+) -> Result<Vec<IcalEvent>, ServiceError> {
+    // Pro Slot nur die zuletzt gelöschte Buchung.
+    let mut per_slot: BTreeMap<Uuid, &(Booking, Slot)> = BTreeMap::new();
+    for pair in deleted_pairs {
+        let (booking, _) = pair;
+        let rebooked = week_bookings.iter().any(|active| {
+            active.sales_person_id == booking.sales_person_id && active.slot_id == booking.slot_id
+        });
+        if rebooked {
+            continue;
+        }
+        let entry = per_slot.entry(booking.slot_id).or_insert(pair);
+        if booking.deleted > entry.0.deleted {
+            *entry = pair;
+        }
+    }
+    let mut pairs: Vec<_> = per_slot.into_values().collect();
+    pairs.sort_by_key(|(_, slot)| (slot.day_of_week.to_number(), slot.from));
+
+    let mut events: Vec<IcalEvent> = vec![];
+    let mut current: Option<(DayOfWeek, Time, Time, Uuid, Option<PrimitiveDateTime>)> = None;
+    let flush = |current: Option<(DayOfWeek, Time, Time, Uuid, Option<PrimitiveDateTime>)>,
+                 events: &mut Vec<IcalEvent>|
+     -> Result<(), ServiceError> {
+        if let Some((day_of_week, from, to, booking_id, deleted)) = current {
+            let date = time::Date::from_iso_week_date(year as i32, week, day_of_week.into())?;
+            events.push(IcalEvent {
+                uid: format!("{booking_id}@shifty").into(),
+                sequence: sequence_for(deleted),
+                start: PrimitiveDateTime::new(date, from),
+                end: PrimitiveDateTime::new(date, to),
+                all_day: false,
+                summary: summary.clone(),
+                description: None,
+                cancelled: true,
+            });
+        }
+        Ok(())
+    };
+    for (booking, slot) in pairs {
+        match current.as_mut() {
+            Some((day_of_week, _, to, _, deleted))
+                if *day_of_week == slot.day_of_week && *to == slot.from =>
+            {
+                *to = slot.to;
+                *deleted = (*deleted).max(booking.deleted);
+            }
+            _ => {
+                flush(current.take(), &mut events)?;
+                current = Some((
+                    slot.day_of_week,
+                    slot.from,
+                    slot.to,
+                    booking.id,
+                    booking.deleted,
+                ));
+            }
+        }
+    }
+    flush(current, &mut events)?;
+    Ok(events)
+}
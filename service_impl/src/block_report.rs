@@ -59,30 +59,14 @@ gen_service_impl! {
     }
 }
 
-#[async_trait]
-impl<Deps: BlockReportServiceDeps> BlockReportService for BlockReportServiceImpl<Deps> {
-    type Context = Deps::Context;
-    type Transaction = Deps::Transaction;
-
-    async fn generate_block_report(
+impl<Deps: BlockReportServiceDeps> BlockReportServiceImpl<Deps> {
+    /// Template-Kontext mit den unterbesetzten Blöcken der aktuellen und der
+    /// zwei folgenden Wochen.
+    async fn block_report_context(
         &self,
-        template_id: Uuid,
-        context: Authentication<Self::Context>,
-        tx: Option<Self::Transaction>,
-    ) -> Result<Arc<str>, ServiceError> {
-        let tx = self.transaction_dao.use_transaction(tx).await?;
-
-        // Check HR permission
-        self.permission_service
-            .check_permission(HR_PRIVILEGE, context.clone())
-            .await?;
-
-        // Get the template
-        let template = self
-            .text_template_service
-            .get_by_id(template_id, context.clone(), Some(tx.clone()))
-            .await?;
-
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<serde_json::Value, ServiceError> {
         // Get current date and time for filtering
         let current_date = self.clock_service.date_now();
         let current_datetime = self.clock_service.date_time_now();
@@ -175,20 +159,54 @@ impl<Deps: BlockReportServiceDeps> BlockReportService for BlockReportServiceImpl
             .map(SimpleBlock::from)
             .collect();
 
+        Ok(serde_json::json!({
+            "current_week_blocks": current_week_blocks,
+            "next_week_blocks": next_week_blocks,
+            "week_after_next_blocks": week_after_next_blocks,
+            "unsufficiently_booked_blocks": all_simple_blocks,
+            "current_week": current_week,
+            "current_year": current_year,
+            "next_week": next_week,
+            "next_year": next_year,
+            "week_after_next_week": week_after_next_week,
+            "week_after_next_year": week_after_next_year,
+        }))
+    }
+}
+
+#[async_trait]
+impl<Deps: BlockReportServiceDeps> BlockReportService for BlockReportServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn generate_block_report(
+        &self,
+        template_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+
+        // Check HR permission
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        // Get the template
+        let template = self
+            .text_template_service
+            .get_by_id(template_id, context.clone(), Some(tx.clone()))
+            .await?;
+
+        let context_data = self.block_report_context(context, tx.clone()).await?;
+
         // Render using the appropriate engine
         let rendered = match template.template_engine {
             TemplateEngine::Tera => {
-                let mut tera_context = Context::new();
-                tera_context.insert("current_week_blocks", &current_week_blocks);
-                tera_context.insert("next_week_blocks", &next_week_blocks);
-                tera_context.insert("week_after_next_blocks", &week_after_next_blocks);
-                tera_context.insert("unsufficiently_booked_blocks", &all_simple_blocks);
-                tera_context.insert("current_week", &current_week);
-                tera_context.insert("current_year", &current_year);
-                tera_context.insert("next_week", &next_week);
-                tera_context.insert("next_year", &next_year);
-                tera_context.insert("week_after_next_week", &week_after_next_week);
-                tera_context.insert("week_after_next_year", &week_after_next_year);
+                let tera_context = Context::from_value(context_data).map_err(|e| {
+                    tracing::error!("Failed to build Tera context: {}", e);
+                    ServiceError::InternalError
+                })?;
 
                 let mut tera = Tera::default();
                 tera.render_str(&template.template_text, &tera_context)
@@ -202,18 +220,6 @@ impl<Deps: BlockReportServiceDeps> BlockReportService for BlockReportServiceImpl
                     })?
             }
             TemplateEngine::MiniJinja => {
-                let context_data = serde_json::json!({
-                    "current_week_blocks": current_week_blocks,
-                    "next_week_blocks": next_week_blocks,
-                    "week_after_next_blocks": week_after_next_blocks,
-                    "unsufficiently_booked_blocks": all_simple_blocks,
-                    "current_week": current_week,
-                    "current_year": current_year,
-                    "next_week": next_week,
-                    "next_year": next_year,
-                    "week_after_next_week": week_after_next_week,
-                    "week_after_next_year": week_after_next_year,
-                });
                 let env = minijinja::Environment::new();
                 env.render_str(&template.template_text, context_data)
                     .map_err(|e| {
@@ -230,4 +236,18 @@ impl<Deps: BlockReportServiceDeps> BlockReportService for BlockReportServiceImpl
         self.transaction_dao.commit(tx).await?;
         Ok(Arc::from(rendered))
     }
+
+    async fn build_block_report_context(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<serde_json::Value, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        let context_data = self.block_report_context(context, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(context_data)
+    }
 }
@@ -2,13 +2,16 @@ use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{booking::BookingDao, TransactionDao};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     booking::{Booking, BookingService},
+    carryover::CarryoverService,
     clock::ClockService,
     permission::{Authentication, PermissionService, SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
     sales_person::SalesPersonService,
     sales_person_shiftplan::SalesPersonShiftplanService,
     slot::SlotService,
     uuid_service::UuidService,
+    webhook::{WebhookEvent, WebhookService},
     ServiceError, ValidationFailureItem,
 };
 use std::sync::Arc;
@@ -26,6 +29,10 @@ gen_service_impl! {
         SalesPersonService: service::sales_person::SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
         SlotService: service::slot::SlotService<Context = Self::Context, Transaction = Self::Transaction> = slot_service,
         SalesPersonShiftplanService: service::sales_person_shiftplan::SalesPersonShiftplanService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_shiftplan_service,
+        WebhookService: service::webhook::WebhookService<Context = Self::Context, Transaction = Self::Transaction> = webhook_service,
+        AuditLogService: service::audit_log::AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        // Jede Buchung geht in den Jahres-Report und damit in den Carryover ein.
+        CarryoverService: service::carryover::CarryoverService<Context = Self::Context, Transaction = Self::Transaction> = carryover_service,
         TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -149,6 +156,34 @@ impl<Deps: BookingServiceDeps> BookingService for BookingServiceImpl<Deps> {
         Ok(booking)
     }
 
+    async fn get_deleted_for_week(
+        &self,
+        calendar_week: u8,
+        year: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[Booking]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (shiftplanner_permission, sales_permission) = join!(
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SALES_PRIVILEGE, context),
+        );
+        shiftplanner_permission.or(sales_permission)?;
+
+        let booking = self
+            .booking_dao
+            .find_deleted_by_week(calendar_week, year, tx.clone())
+            .await?
+            .iter()
+            .map(Booking::from)
+            .collect();
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(booking)
+    }
+
     async fn get_for_slot_id_since(
         &self,
         slot_id: Uuid,
@@ -306,6 +341,25 @@ impl<Deps: BookingServiceDeps> BookingService for BookingServiceImpl<Deps> {
                 tx.clone(),
             )
             .await?;
+        self.webhook_service
+            .publish(&WebhookEvent::BookingCreated(new_booking.clone()), tx.clone().into())
+            .await?;
+        self.carryover_service
+            .mark_dirty(
+                new_booking.sales_person_id,
+                new_booking.year,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::BOOKING, new_booking.id, &new_booking)?,
+                BOOKING_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(new_booking)
@@ -429,12 +483,35 @@ impl<Deps: BookingServiceDeps> BookingService for BookingServiceImpl<Deps> {
         // Same "system" fallback as create() — Authentication::Full delete
         // paths (modify_slot, remove_slot) would otherwise leave deleted_by
         // NULL in the audit trail.
+        let before = Booking::from(&booking_entity);
         booking_entity.deleted = Some(self.clock_service.date_time_now());
         booking_entity.deleted_by = current_user.or_else(|| Some("system".into()));
         booking_entity.version = self.uuid_service.new_uuid("booking-version");
         self.booking_dao
             .update(&booking_entity, BOOKING_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.webhook_service
+            .publish(
+                &WebhookEvent::BookingDeleted(Booking::from(&booking_entity)),
+                tx.clone().into(),
+            )
+            .await?;
+        self.carryover_service
+            .mark_dirty(
+                booking_entity.sales_person_id,
+                booking_entity.year,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::BOOKING, id, &before)?,
+                BOOKING_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
@@ -8,14 +8,16 @@ use service::{
     absence::AbsenceService,
     booking::BookingService,
     booking_information::{
-        build_booking_information, BookingInformation, BookingInformationService, WeeklySummary,
-        WorkingHoursPerSalesPerson,
+        build_booking_information, BookingConflicts, BookingInformationService, QualificationGap,
+        WeeklySummary, WorkingHoursPerSalesPerson,
     },
     clock::ClockService,
     employee_work_details::EmployeeWorkDetailsService,
     permission::{Authentication, SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    qualification::{unmet_requirements, QualificationService},
     reporting::ReportingService,
     sales_person::SalesPersonService,
+    sales_person_availability::{SalesPersonAvailability, SalesPersonAvailabilityService},
     sales_person_unavailable::SalesPersonUnavailableService,
     shiftplan_catalog::ShiftplanService,
     shiftplan_report::ShiftplanReportService,
@@ -123,6 +125,8 @@ gen_service_impl! {
         BookingService: BookingService<Transaction = Self::Transaction> = booking_service,
         SalesPersonService: SalesPersonService<Transaction = Self::Transaction> = sales_person_service,
         SalesPersonUnavailableService: SalesPersonUnavailableService<Transaction = Self::Transaction> = sales_person_unavailable_service,
+        // Wiederkehrende `Unavailable`-Regeln für die Konfliktliste.
+        SalesPersonAvailabilityService: SalesPersonAvailabilityService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_availability_service,
         ReportingService: ReportingService<Transaction = Self::Transaction> = reporting_service,
         SpecialDayService: SpecialDayService = special_day_service,
         // Phase 51 (D-51-06 Chain C + D-51-07): Stichtag-Toggle für pro-Slot-Clip
@@ -135,6 +139,8 @@ gen_service_impl! {
         // no DI cycle because AbsenceService does NOT consume BookingInformationService
         // (Service-Tier rule; D-Phase3-18 regression-lock preserved).
         AbsenceService: AbsenceService<Context = Self::Context, Transaction = Self::Transaction> = absence_service,
+        // Qualifikations-Anforderungen für die Konfliktliste (Basic-Tier-Dep).
+        QualificationService: QualificationService<Context = Self::Context, Transaction = Self::Transaction> = qualification_service,
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
@@ -142,6 +148,86 @@ gen_service_impl! {
     }
 }
 
+impl<Deps: BookingInformationServiceDeps> BookingInformationServiceImpl<Deps> {
+    /// Prüft die Qualifikations-Anforderungen aller Slots der Woche gegen
+    /// `bookings`. Slots an Feiertagen werden übersprungen.
+    async fn qualification_gaps_for_week(
+        &self,
+        year: u32,
+        week: u8,
+        bookings: &[service::booking::Booking],
+        tx: Deps::Transaction,
+    ) -> Result<Arc<[QualificationGap]>, ServiceError> {
+        use std::collections::HashMap;
+
+        let requirements = self
+            .qualification_service
+            .get_all_slot_requirements(Authentication::Full, tx.clone().into())
+            .await?;
+        if requirements.is_empty() {
+            return Ok(Arc::new([]));
+        }
+        let slots = self
+            .slot_service
+            .get_slots_for_week_all_plans(year, week, Authentication::Full, tx.clone().into())
+            .await?;
+        let holidays: Vec<DayOfWeek> = self
+            .special_day_service
+            .get_by_week(year, week, Authentication::Full)
+            .await?
+            .iter()
+            .filter(|day| day.day_type == service::special_days::SpecialDayType::Holiday)
+            .map(|day| day.day_of_week)
+            .collect();
+        let qualifications: HashMap<Uuid, Arc<service::qualification::Qualification>> = self
+            .qualification_service
+            .get_all(Authentication::Full, tx.clone().into())
+            .await?
+            .iter()
+            .map(|qualification| (qualification.id, Arc::new(qualification.clone())))
+            .collect();
+        let sales_person_qualifications = self
+            .qualification_service
+            .get_all_sales_person_qualifications(Authentication::Full, tx.into())
+            .await?;
+
+        let mut gaps = Vec::new();
+        for slot in slots.iter() {
+            if holidays.contains(&slot.day_of_week) {
+                continue;
+            }
+            let slot_requirements: Vec<_> = requirements
+                .iter()
+                .filter(|requirement| requirement.slot_id == slot.id)
+                .cloned()
+                .collect();
+            if slot_requirements.is_empty() {
+                continue;
+            }
+            let booked: Vec<Uuid> = bookings
+                .iter()
+                .filter(|booking| booking.slot_id == slot.id && booking.deleted.is_none())
+                .map(|booking| booking.sales_person_id)
+                .collect();
+            let slot = Arc::new(slot.clone());
+            for unmet in
+                unmet_requirements(&slot_requirements, &booked, &sales_person_qualifications)
+            {
+                let Some(qualification) = qualifications.get(&unmet.qualification_id) else {
+                    continue;
+                };
+                gaps.push(QualificationGap {
+                    slot: slot.clone(),
+                    qualification: qualification.clone(),
+                    required_count: unmet.required_count,
+                    qualified_count: unmet.qualified_count,
+                });
+            }
+        }
+        Ok(gaps.into())
+    }
+}
+
 #[async_trait]
 impl<Deps: BookingInformationServiceDeps> BookingInformationService
     for BookingInformationServiceImpl<Deps>
@@ -155,7 +241,7 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
         week: u8,
         context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
-    ) -> Result<Arc<[BookingInformation]>, ServiceError> {
+    ) -> Result<BookingConflicts, ServiceError> {
         use shifty_utils::{DateRange, ShiftyDate};
         use std::collections::HashMap;
 
@@ -179,7 +265,8 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
             .sales_person_unavailable_service
             .get_by_week(year, week, Authentication::Full, tx.clone().into())
             .await?;
-        let booking_informations = build_booking_information(slots, bookings, sales_persons);
+        let booking_informations =
+            build_booking_information(slots, bookings.clone(), sales_persons);
 
         // v2.2.1: additionally flag bookings that fall into any active absence
         // period for the same sales person. Fetches per-person absences that
@@ -194,6 +281,8 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
 
         let mut absences_by_person: HashMap<Uuid, Arc<[service::absence::AbsencePeriod]>> =
             HashMap::new();
+        let mut availabilities_by_person: HashMap<Uuid, Arc<[SalesPersonAvailability]>> =
+            HashMap::new();
         if let Some(range) = week_range {
             // Collect unique sales-person ids that actually have bookings.
             let mut seen = std::collections::HashSet::<Uuid>::new();
@@ -219,6 +308,17 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
                     )
                     .await?;
                 absences_by_person.insert(sp_id, overlapping);
+                let availabilities = self
+                    .sales_person_availability_service
+                    .get_by_week_for_sales_person(
+                        sp_id,
+                        year,
+                        week,
+                        Authentication::Full,
+                        tx.clone().into(),
+                    )
+                    .await?;
+                availabilities_by_person.insert(sp_id, availabilities);
             }
         }
 
@@ -250,18 +350,43 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
                     .map(|periods| periods.iter().map(|p| (p.from_date, p.to_date)).collect())
                     .unwrap_or_default();
 
-                is_booking_conflict(
-                    &unavailable_weekdays,
-                    booking_information.slot.day_of_week,
-                    booking_date,
-                    &absence_ranges,
-                )
+                // Wiederkehrende `Unavailable`-Regeln zählen wie in
+                // `book_slot_with_conflict_check`, sofern sie am Tag gelten und
+                // (bei Teil-Regeln) das Slot-Zeitfenster überlappen.
+                let recurring_match = booking_date.is_some_and(|date| {
+                    availabilities_by_person
+                        .get(&booking_information.sales_person.id)
+                        .is_some_and(|availabilities| {
+                            availabilities.iter().any(|availability| {
+                                availability.blocks(
+                                    date,
+                                    booking_information.slot.from,
+                                    booking_information.slot.to,
+                                )
+                            })
+                        })
+                });
+
+                recurring_match
+                    || is_booking_conflict(
+                        &unavailable_weekdays,
+                        booking_information.slot.day_of_week,
+                        booking_date,
+                        &absence_ranges,
+                    )
             })
             .cloned()
             .collect();
 
+        let qualification_gaps = self
+            .qualification_gaps_for_week(year, week, &bookings, tx.clone())
+            .await?;
+
         self.transaction_dao.commit(tx).await?;
-        Ok(conflicts)
+        Ok(BookingConflicts {
+            bookings: conflicts,
+            qualification_gaps,
+        })
     }
 
     async fn get_weekly_summary(
@@ -431,7 +556,8 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
                     all_absences
                         .iter()
                         .filter(|period| {
-                            volunteer_ids.contains(&period.sales_person_id)
+                            period.is_effective()
+                                && volunteer_ids.contains(&period.sales_person_id)
                                 && period_overlaps_week(
                                     period.from_date,
                                     period.to_date,
@@ -737,7 +863,8 @@ impl<Deps: BookingInformationServiceDeps> BookingInformationService
                 all_absences
                     .iter()
                     .filter(|period| {
-                        volunteer_ids.contains(&period.sales_person_id)
+                        period.is_effective()
+                            && volunteer_ids.contains(&period.sales_person_id)
                             && period_overlaps_week(
                                 period.from_date,
                                 period.to_date,
@@ -2,10 +2,13 @@ use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{carryover::CarryoverDao, TransactionDao};
 use service::{
-    carryover::{Carryover, CarryoverService},
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    carryover::{Carryover, CarryoverDirty, CarryoverService},
     permission::Authentication,
-    ServiceError,
+    uuid_service::UuidService,
+    PermissionService, ServiceError,
 };
+use std::sync::Arc;
 use uuid::Uuid;
 
 // If you need any particular process name constant, define here:
@@ -14,6 +17,9 @@ const CARRYOVER_SERVICE_PROCESS: &str = "carryover-service";
 gen_service_impl! {
     struct CarryoverServiceImpl: service::carryover::CarryoverService = CarryoverServiceDeps {
         CarryoverDao: dao::carryover::CarryoverDao<Transaction = Self::Transaction> = carryover_dao,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        UuidService: UuidService = uuid_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -43,14 +49,89 @@ impl<Deps: CarryoverServiceDeps> CarryoverService for CarryoverServiceImpl<Deps>
     async fn set_carryover(
         &self,
         carryover: &Carryover,
-        _context: Authentication<Self::Context>,
+        context: Authentication<Self::Context>,
         tx: Option<Self::Transaction>,
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self
+            .carryover_dao
+            .find_by_sales_person_id_and_year(
+                carryover.sales_person_id,
+                carryover.year,
+                tx.clone(),
+            )
+            .await?
+            .map(|entity| Carryover::from(&entity));
         let entity = carryover.try_into()?;
         self.carryover_dao
             .upsert(&entity, CARRYOVER_SERVICE_PROCESS, tx.clone())
             .await?;
+        // Der Übertrag hat keine eigene ID, Schlüssel ist Mitarbeiter und Jahr.
+        let entity_id = format!("{}/{}", carryover.sales_person_id, carryover.year);
+        let change = match &before {
+            Some(before) => {
+                AuditChange::update(entity_type::CARRYOVER, entity_id, before, carryover)?
+            }
+            None => AuditChange::create(entity_type::CARRYOVER, entity_id, carryover)?,
+        };
+        self.audit_log_service
+            .record(&change, CARRYOVER_SERVICE_PROCESS, context, tx.clone().into())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn mark_dirty(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        // Nur Service-intern: die Markierungen steuern den Neuberechnungs-Job.
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = dao::carryover::CarryoverDirtyEntity {
+            sales_person_id,
+            year,
+            version: self.uuid_service.new_uuid("carryover-dirty-version"),
+        };
+        self.carryover_dao
+            .mark_dirty(&entity, CARRYOVER_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn find_dirty(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[CarryoverDirty]>, ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let result = self.carryover_dao.all_dirty(tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(result.iter().map(CarryoverDirty::from).collect())
+    }
+
+    async fn clear_dirty(
+        &self,
+        dirty: &CarryoverDirty,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.carryover_dao
+            .clear_dirty(&dirty.into(), tx.clone())
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
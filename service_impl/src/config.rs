@@ -13,10 +13,16 @@ impl ConfigService for ConfigServiceImpl {
     async fn get_config(&self) -> Result<Config, ServiceError> {
         let timezone = env::var("TIMEZONE").unwrap_or("UTC".to_string());
         let ical_label = env::var("ICAL_LABEL").unwrap_or("Schicht".to_string());
+        let app_url = env::var("APP_URL").unwrap_or("http://localhost:3000".to_string());
+        let datev_consultant_number = env::var("DATEV_CONSULTANT_NUMBER").ok();
+        let datev_client_number = env::var("DATEV_CLIENT_NUMBER").ok();
 
         Ok(Config {
             timezone: Arc::from(timezone),
             ical_label: Arc::from(ical_label),
+            app_url: Arc::from(app_url),
+            datev_consultant_number: datev_consultant_number.map(Arc::from),
+            datev_client_number: datev_client_number.map(Arc::from),
         })
     }
 }
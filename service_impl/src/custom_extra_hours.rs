@@ -7,6 +7,7 @@ use dao::{
     TransactionDao,
 };
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
     custom_extra_hours::{CustomExtraHours, CustomExtraHoursService},
     permission::{Authentication, HR_PRIVILEGE},
@@ -26,6 +27,7 @@ gen_service_impl! {
         UuidService: UuidService = uuid_service,
         ClockService: ClockService = clock_service,
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -143,7 +145,16 @@ impl<Deps: CustomExtraHoursDeps> CustomExtraHoursService for CustomExtraHoursSer
         self.custom_extra_hours_dao
             .create(&entity, CARRYOVER_SERVICE_PROCESS, tx.clone())
             .await?;
-        let res = Ok((&entity).into());
+        let created = CustomExtraHours::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::CUSTOM_EXTRA_HOURS, created.id, &created)?,
+                CARRYOVER_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        let res = Ok(created);
 
         self.transaction_dao.commit(tx).await?;
         res
@@ -172,6 +183,7 @@ impl<Deps: CustomExtraHoursDeps> CustomExtraHoursService for CustomExtraHoursSer
                 entity.version,
             ));
         }
+        let before = CustomExtraHours::from(&entity);
         entity.version = self.uuid_service.new_uuid("update-version");
 
         self.custom_extra_hours_dao
@@ -181,6 +193,19 @@ impl<Deps: CustomExtraHoursDeps> CustomExtraHoursService for CustomExtraHoursSer
                 tx.clone(),
             )
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::CUSTOM_EXTRA_HOURS,
+                    custom_extra_hours.id,
+                    &before,
+                    custom_extra_hours,
+                )?,
+                CARRYOVER_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         let res = Ok(entity.into());
 
@@ -205,11 +230,20 @@ impl<Deps: CustomExtraHoursDeps> CustomExtraHoursService for CustomExtraHoursSer
             .await?
             .ok_or(ServiceError::EntityNotFound(id))?;
 
+        let before = CustomExtraHours::from(&entity);
         entity.deleted = Some(self.clock_service.date_time_now());
 
         self.custom_extra_hours_dao
             .update(entity, CARRYOVER_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::CUSTOM_EXTRA_HOURS, id, &before)?,
+                CARRYOVER_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
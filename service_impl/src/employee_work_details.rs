@@ -6,6 +6,8 @@ use dao::{
     TransactionDao,
 };
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    carryover::CarryoverService,
     clock::ClockService,
     employee_work_details::{EmployeeWorkDetails, EmployeeWorkDetailsService},
     permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
@@ -25,6 +27,9 @@ gen_service_impl! {
         PermissionService: service::PermissionService<Context = Self::Context> = permission_service,
         ClockService: service::clock::ClockService = clock_service,
         UuidService: service::uuid_service::UuidService = uuid_service,
+        AuditLogService: service::audit_log::AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        // Verträge bestimmen Soll-Stunden und Urlaubsanspruch im Carryover.
+        CarryoverService: service::carryover::CarryoverService<Context = Self::Context, Transaction = Self::Transaction> = carryover_service,
         TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -188,7 +193,7 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
         let tx = self.transaction_dao.use_transaction(tx).await?;
         let mut working_hours = working_hours.to_owned();
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
         working_hours.created = Some(self.clock_service.date_time_now());
@@ -209,9 +214,26 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
         self.employee_work_details_dao
             .create(&entity, "working-hours-service::create", tx.clone())
             .await?;
+        let created = EmployeeWorkDetails::from(&entity);
+        self.carryover_service
+            .mark_dirty(
+                entity.sales_person_id,
+                entity.from_year,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::EMPLOYEE_WORK_DETAILS, created.id, &created)?,
+                "working-hours-service::create",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
-        Ok(EmployeeWorkDetails::from(&entity))
+        Ok(created)
     }
 
     async fn update(
@@ -222,7 +244,7 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
     ) -> Result<EmployeeWorkDetails, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
         let mut entity = self
@@ -238,6 +260,7 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
             ));
         }
 
+        let before = EmployeeWorkDetails::from(&entity);
         entity.to_calendar_week = employee_work_details.to_calendar_week;
         entity.to_day_of_week = employee_work_details.to_day_of_week;
         entity.to_year = employee_work_details.to_year;
@@ -247,6 +270,7 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
         entity.is_dynamic = employee_work_details.is_dynamic;
         entity.cap_planned_hours_to_expected = employee_work_details.cap_planned_hours_to_expected;
         entity.committed_voluntary = employee_work_details.committed_voluntary;
+        entity.use_actual_hours = employee_work_details.use_actual_hours;
 
         entity.version = self
             .uuid_service
@@ -255,8 +279,30 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
         self.employee_work_details_dao
             .update(&entity, "working-hours-service::update", tx.clone())
             .await?;
+        let updated = EmployeeWorkDetails::from(&entity);
+        self.carryover_service
+            .mark_dirty(
+                entity.sales_person_id,
+                entity.from_year,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::EMPLOYEE_WORK_DETAILS,
+                    updated.id,
+                    &before,
+                    &updated,
+                )?,
+                "working-hours-service::update",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
-        Ok(EmployeeWorkDetails::from(&entity))
+        Ok(updated)
     }
 
     async fn delete(
@@ -267,17 +313,34 @@ impl<Deps: EmployeeWorkDetailsServiceDeps> EmployeeWorkDetailsService
     ) -> Result<EmployeeWorkDetails, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
         let entity = self
             .employee_work_details_dao
             .find_by_id(id, tx.clone())
             .await?;
         let ret = if let Some(mut entity) = entity {
+            let before = EmployeeWorkDetails::from(&entity);
             entity.deleted = Some(self.clock_service.date_time_now());
             self.employee_work_details_dao
                 .update(&entity, "working-hours-service::delete", tx.clone())
                 .await?;
+            self.carryover_service
+                .mark_dirty(
+                    entity.sales_person_id,
+                    entity.from_year,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?;
+            self.audit_log_service
+                .record(
+                    &AuditChange::delete(entity_type::EMPLOYEE_WORK_DETAILS, id, &before)?,
+                    "working-hours-service::delete",
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
             Ok(EmployeeWorkDetails::from(&entity))
         } else {
             return Err(ServiceError::EntityNotFound(id));
@@ -0,0 +1,214 @@
+//! Ablage der Export-Dateien in einem Export-Ziel.
+//!
+//! [`ExportDestination`] abstrahiert das Schreiben einer fertigen Datei, damit
+//! der [`crate::export_target_scheduler::ExportTargetSchedulerImpl`] ohne
+//! Netzwerk oder Dateisystem getestet werden kann. Die
+//! [`ProductionExportDestinationFactory`] baut pro Lauf die passende
+//! Implementierung aus dem Ziel:
+//!
+//! - WebDAV: der bestehende [`WebDavClient`] mit Retry und Backoff.
+//! - Lokales Verzeichnis: legt fehlende Ordner an und schreibt über eine
+//!   temporäre Datei mit anschließendem `rename`.
+//! - Abholordner: wie das lokale Verzeichnis, aber das Verzeichnis muss
+//!   bereits existieren (z. B. die Wurzel eines separat betriebenen
+//!   SFTP-Servers) und die temporäre Datei heißt `.<name>.part`. Abholer,
+//!   die `*.part` ignorieren, sehen nie eine halbe Datei. Shifty selbst
+//!   baut keine SFTP-Verbindung auf.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use service::export_target::{ExportTarget, ExportTargetKind};
+
+use crate::webdav_client::{WebDavClient, WebDavError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportDestinationError {
+    #[error("incomplete target configuration: {0}")]
+    Configuration(Arc<str>),
+
+    #[error(transparent)]
+    WebDav(#[from] WebDavError),
+
+    #[error("file system error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Schreibt eine Datei in das Ziel. `filename` ist bereits geprüft und
+/// enthält keine Pfadtrenner.
+#[automock]
+#[async_trait]
+pub trait ExportDestination: Send + Sync + 'static {
+    async fn store_file(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), ExportDestinationError>;
+}
+
+/// Baut die [`ExportDestination`] eines Ziels. In Tests wird eine Factory
+/// mit Mock-Ablage injiziert.
+pub trait ExportDestinationFactory: Send + Sync + 'static {
+    fn build(
+        &self,
+        target: &ExportTarget,
+    ) -> Result<Arc<dyn ExportDestination>, ExportDestinationError>;
+}
+
+pub struct ProductionExportDestinationFactory;
+
+impl ExportDestinationFactory for ProductionExportDestinationFactory {
+    fn build(
+        &self,
+        target: &ExportTarget,
+    ) -> Result<Arc<dyn ExportDestination>, ExportDestinationError> {
+        match target.kind {
+            ExportTargetKind::WebDav => {
+                let missing = |field: &str| {
+                    ExportDestinationError::Configuration(Arc::from(format!("{field} fehlt")))
+                };
+                let url = target.url.clone().ok_or_else(|| missing("url"))?;
+                let username = target.username.clone().ok_or_else(|| missing("username"))?;
+                let secret = target.secret.clone().ok_or_else(|| missing("secret"))?;
+                let client = WebDavClient::new(url, &username, &secret)?;
+                Ok(Arc::new(WebDavDestination {
+                    client,
+                    folder: target.path.clone(),
+                }))
+            }
+            ExportTargetKind::LocalDirectory => Ok(Arc::new(DirectoryDestination {
+                directory: PathBuf::from(target.path.as_ref()),
+                create_directory: true,
+                temp_suffix: ".tmp",
+            })),
+            ExportTargetKind::DropDirectory => Ok(Arc::new(DirectoryDestination {
+                directory: PathBuf::from(target.path.as_ref()),
+                create_directory: false,
+                temp_suffix: ".part",
+            })),
+        }
+    }
+}
+
+struct WebDavDestination {
+    client: WebDavClient,
+    folder: Arc<str>,
+}
+
+#[async_trait]
+impl ExportDestination for WebDavDestination {
+    async fn store_file(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), ExportDestinationError> {
+        Ok(self
+            .client
+            .upload_file(&self.folder, filename, bytes)
+            .await?)
+    }
+}
+
+/// Verzeichnis-Ablage für lokales Verzeichnis und Abholordner.
+pub struct DirectoryDestination {
+    pub directory: PathBuf,
+    /// Fehlende Ordner anlegen statt mit einem Fehler abzubrechen.
+    pub create_directory: bool,
+    /// Endung der temporären Datei `.<name><suffix>` vor dem `rename`.
+    pub temp_suffix: &'static str,
+}
+
+impl DirectoryDestination {
+    fn temp_path(&self, filename: &str) -> PathBuf {
+        self.directory
+            .join(format!(".{filename}{}", self.temp_suffix))
+    }
+}
+
+async fn write_and_rename(temp: &Path, target: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    tokio::fs::write(temp, bytes).await?;
+    if let Err(e) = tokio::fs::rename(temp, target).await {
+        let _ = tokio::fs::remove_file(temp).await;
+        return Err(e);
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ExportDestination for DirectoryDestination {
+    async fn store_file(
+        &self,
+        filename: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), ExportDestinationError> {
+        if self.create_directory {
+            tokio::fs::create_dir_all(&self.directory).await?;
+        } else if !tokio::fs::metadata(&self.directory).await?.is_dir() {
+            return Err(ExportDestinationError::Configuration(Arc::from(format!(
+                "{} ist kein Verzeichnis",
+                self.directory.display()
+            ))));
+        }
+        let temp = self.temp_path(filename);
+        write_and_rename(&temp, &self.directory.join(filename), &bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("shifty-export-{name}-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn local_directory_is_created_and_file_written() {
+        let directory = temp_dir("local").join("sub");
+        let destination = DirectoryDestination {
+            directory: directory.clone(),
+            create_directory: true,
+            temp_suffix: ".tmp",
+        };
+        destination
+            .store_file("plan.pdf", b"%PDF".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(directory.join("plan.pdf")).unwrap(), b"%PDF");
+        assert!(!directory.join(".plan.pdf.tmp").exists());
+        std::fs::remove_dir_all(directory.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn drop_directory_overwrites_without_leftover_part_file() {
+        let directory = temp_dir("drop");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("plan.ics"), b"old").unwrap();
+        let destination = DirectoryDestination {
+            directory: directory.clone(),
+            create_directory: false,
+            temp_suffix: ".part",
+        };
+        destination
+            .store_file("plan.ics", b"new".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(directory.join("plan.ics")).unwrap(), b"new");
+        assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 1);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn drop_directory_requires_existing_directory() {
+        let destination = DirectoryDestination {
+            directory: temp_dir("missing"),
+            create_directory: false,
+            temp_suffix: ".part",
+        };
+        let result = destination.store_file("plan.ics", b"x".to_vec()).await;
+        assert!(matches!(result, Err(ExportDestinationError::Io(_))));
+    }
+}
@@ -0,0 +1,437 @@
+//! Basic-Tier Implementation von [`service::export_target::ExportTargetService`].
+//!
+//! Admin-gated CRUD auf den Export-Zielen plus die vom Scheduler geschriebene
+//! Lauf-Historie. Die Prüfung von Zielart-Feldern, Cron-Ausdruck und
+//! Dateinamen-Muster passiert beim Speichern, damit ein Lauf nicht erst
+//! nachts an einer Tippfehler-Konfiguration scheitert.
+
+use std::path::{Component, Path};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    export_target::{ExportTargetDao, ExportTargetEntity, ExportTargetRunEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    clock::ClockService,
+    export_target::{
+        ExportArtifactType, ExportTarget, ExportTargetKind, ExportTargetRun, ExportTargetService,
+    },
+    permission::Authentication,
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::scheduler_job::is_valid_cron_schedule;
+
+const EXPORT_TARGET_SERVICE_PROCESS: &str = "export-target-service";
+const ADMIN_PRIVILEGE: &str = "admin";
+
+/// Obergrenze für `weeks_horizon` beim Wochen-PDF.
+pub const MAX_WEEKS_HORIZON: u32 = 26;
+const MAX_RUN_LIMIT: u32 = 500;
+
+/// Zerlegt ein Dateinamen-Muster in Text und Platzhalter. `None` bei einer
+/// nicht geschlossenen oder leeren Klammer.
+fn placeholders(pattern: &str) -> Option<Vec<&str>> {
+    let mut result = Vec::new();
+    let mut rest = pattern;
+    while let Some(open) = rest.find(['{', '}']) {
+        if rest[open..].starts_with('}') {
+            return None;
+        }
+        let close = rest[open..].find('}')? + open;
+        let name = &rest[open + 1..close];
+        if name.is_empty() || name.contains('{') {
+            return None;
+        }
+        result.push(name);
+        rest = &rest[close + 1..];
+    }
+    Some(result)
+}
+
+/// Ersetzt die Platzhalter `{name}` des Musters durch die Werte aus `values`.
+/// Unbekannte Platzhalter sind ein Fehler; das Muster ist beim Speichern
+/// geprüft, daher kommt das nur bei inkonsistenten Daten vor.
+pub fn expand_filename_pattern(
+    pattern: &str,
+    values: &[(&str, &str)],
+) -> Result<String, ServiceError> {
+    let invalid = || {
+        ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+            "filename_pattern".into(),
+        )]))
+    };
+    let mut result = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(open) = rest.find('{') {
+        let close = rest[open..].find('}').ok_or_else(invalid)? + open;
+        let name = &rest[open + 1..close];
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| *value)
+            .ok_or_else(invalid)?;
+        result.push_str(&rest[..open]);
+        result.push_str(value);
+        rest = &rest[close + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn is_valid_filename_pattern(pattern: &str, artifact_type: ExportArtifactType) -> bool {
+    let Some(names) = placeholders(pattern) else {
+        return false;
+    };
+    names
+        .iter()
+        .all(|name| artifact_type.placeholders().contains(name))
+        && artifact_type
+            .required_placeholders()
+            .iter()
+            .all(|required| names.contains(required))
+        && !pattern.contains(['/', '\\'])
+        && !pattern.starts_with('.')
+}
+
+fn is_blank(value: &Option<Arc<str>>) -> bool {
+    value.as_deref().map(str::trim).unwrap_or("").is_empty()
+}
+
+/// Prüft ein Ziel vor dem Speichern. `has_secret` sagt, ob nach dem Speichern
+/// ein App-Token vorliegt (neu übergeben oder schon gespeichert).
+fn validate_target(target: &ExportTarget, has_secret: bool) -> Result<(), ServiceError> {
+    let mut errors = Vec::new();
+    if target.name.trim().is_empty() {
+        errors.push(ValidationFailureItem::InvalidValue("name".into()));
+    }
+    match target.kind {
+        ExportTargetKind::WebDav => {
+            let url = target.url.as_deref().unwrap_or("").trim();
+            if !(url.starts_with("https://") || url.starts_with("http://"))
+                || url.contains(char::is_whitespace)
+            {
+                errors.push(ValidationFailureItem::InvalidValue("url".into()));
+            }
+            if is_blank(&target.username) {
+                errors.push(ValidationFailureItem::InvalidValue("username".into()));
+            }
+            if !has_secret {
+                errors.push(ValidationFailureItem::InvalidValue("secret".into()));
+            }
+            if target.path.trim().is_empty() {
+                errors.push(ValidationFailureItem::InvalidValue("path".into()));
+            }
+        }
+        ExportTargetKind::LocalDirectory | ExportTargetKind::DropDirectory => {
+            let path = Path::new(target.path.trim());
+            if !path.is_absolute() || path.components().any(|c| c == Component::ParentDir) {
+                errors.push(ValidationFailureItem::InvalidValue("path".into()));
+            }
+        }
+    }
+    if !is_valid_cron_schedule(target.cron_schedule.trim()) {
+        errors.push(ValidationFailureItem::InvalidValue("cron_schedule".into()));
+    }
+    if !is_valid_filename_pattern(target.effective_filename_pattern(), target.artifact_type) {
+        errors.push(ValidationFailureItem::InvalidValue(
+            "filename_pattern".into(),
+        ));
+    }
+    if target.artifact_type == ExportArtifactType::ShiftplanWeekPdf
+        && !(1..=MAX_WEEKS_HORIZON).contains(&target.weeks_horizon)
+    {
+        errors.push(ValidationFailureItem::InvalidValue("weeks_horizon".into()));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ServiceError::ValidationError(errors.into()))
+    }
+}
+
+/// Übernimmt die editierbaren Felder getrimmt; bei den Verzeichnis-Zielen
+/// werden die WebDAV-Felder geleert.
+fn editable_fields(target: &ExportTarget, entity: ExportTargetEntity) -> ExportTargetEntity {
+    let trimmed = |value: &Option<Arc<str>>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(Arc::from)
+    };
+    let is_webdav = target.kind == ExportTargetKind::WebDav;
+    ExportTargetEntity {
+        name: Arc::from(target.name.trim()),
+        kind: target.kind.into(),
+        enabled: target.enabled,
+        url: trimmed(&target.url).filter(|_| is_webdav),
+        username: trimmed(&target.username).filter(|_| is_webdav),
+        secret: trimmed(&target.secret)
+            .or(entity.secret.clone())
+            .filter(|_| is_webdav),
+        path: Arc::from(target.path.trim()),
+        cron_schedule: Arc::from(target.cron_schedule.trim()),
+        artifact_type: target.artifact_type.into(),
+        filename_pattern: Arc::from(target.filename_pattern.trim()),
+        weeks_horizon: target.weeks_horizon,
+        ..entity
+    }
+}
+
+gen_service_impl! {
+    struct ExportTargetServiceImpl: ExportTargetService = ExportTargetServiceDeps {
+        ExportTargetDao: ExportTargetDao<Transaction = Self::Transaction> = export_target_dao,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+#[async_trait]
+impl<Deps: ExportTargetServiceDeps> ExportTargetService for ExportTargetServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ExportTarget]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let targets = self
+            .export_target_dao
+            .all(tx.clone())
+            .await?
+            .iter()
+            .map(ExportTarget::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(targets)
+    }
+
+    async fn get(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTarget, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let target = self
+            .export_target_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .as_ref()
+            .map(ExportTarget::from)
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(target)
+    }
+
+    async fn create(
+        &self,
+        target: &ExportTarget,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTarget, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        if target.id != Uuid::nil() {
+            return Err(ServiceError::IdSetOnCreate);
+        }
+        if target.version != Uuid::nil() {
+            return Err(ServiceError::VersionSetOnCreate);
+        }
+        validate_target(target, !is_blank(&target.secret))?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = editable_fields(
+            target,
+            ExportTargetEntity {
+                id: self
+                    .uuid_service
+                    .new_uuid(&format!("{EXPORT_TARGET_SERVICE_PROCESS}::create id")),
+                name: target.name.clone(),
+                kind: target.kind.into(),
+                enabled: target.enabled,
+                url: None,
+                username: None,
+                secret: None,
+                path: target.path.clone(),
+                cron_schedule: target.cron_schedule.clone(),
+                artifact_type: target.artifact_type.into(),
+                filename_pattern: target.filename_pattern.clone(),
+                weeks_horizon: target.weeks_horizon,
+                created: self.clock_service.date_time_now(),
+                deleted: None,
+                version: self
+                    .uuid_service
+                    .new_uuid(&format!("{EXPORT_TARGET_SERVICE_PROCESS}::create version")),
+            },
+        );
+        self.export_target_dao
+            .create(&entity, EXPORT_TARGET_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = ExportTarget::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::EXPORT_TARGET, created.id, &created)?,
+                EXPORT_TARGET_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        target: &ExportTarget,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTarget, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .export_target_dao
+            .find_by_id(target.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(target.id))?;
+        if persisted.version != target.version {
+            return Err(ServiceError::EntityConflicts(
+                target.id,
+                persisted.version,
+                target.version,
+            ));
+        }
+        validate_target(
+            target,
+            !is_blank(&target.secret) || !is_blank(&persisted.secret),
+        )?;
+        let before = ExportTarget::from(&persisted);
+        let entity = ExportTargetEntity {
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{EXPORT_TARGET_SERVICE_PROCESS}::update version")),
+            ..editable_fields(target, persisted)
+        };
+        self.export_target_dao
+            .update(&entity, EXPORT_TARGET_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let updated = ExportTarget::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::EXPORT_TARGET, updated.id, &before, &updated)?,
+                EXPORT_TARGET_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .export_target_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let before = ExportTarget::from(&persisted);
+        let entity = ExportTargetEntity {
+            deleted: Some(self.clock_service.date_time_now()),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{EXPORT_TARGET_SERVICE_PROCESS}::delete version")),
+            ..persisted
+        };
+        self.export_target_dao
+            .update(&entity, EXPORT_TARGET_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::EXPORT_TARGET, id, &before)?,
+                EXPORT_TARGET_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn get_runs(
+        &self,
+        target_id: Uuid,
+        limit: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ExportTargetRun]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let runs = self
+            .export_target_dao
+            .find_runs_by_target(target_id, limit.clamp(1, MAX_RUN_LIMIT), tx.clone())
+            .await?
+            .iter()
+            .map(ExportTargetRun::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(runs)
+    }
+
+    async fn record_run(
+        &self,
+        run: &ExportTargetRun,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportTargetRun, ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = ExportTargetRunEntity {
+            id: self
+                .uuid_service
+                .new_uuid(&format!("{EXPORT_TARGET_SERVICE_PROCESS}::record_run id")),
+            version: self.uuid_service.new_uuid(&format!(
+                "{EXPORT_TARGET_SERVICE_PROCESS}::record_run version"
+            )),
+            ..ExportTargetRunEntity::from(run)
+        };
+        self.export_target_dao
+            .create_run(&entity, EXPORT_TARGET_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(ExportTargetRun::from(&entity))
+    }
+}
@@ -0,0 +1,511 @@
+//! Business-Logic-Tier Implementation von
+//! [`service::export_target_scheduler::ExportTargetScheduler`].
+//!
+//! Pro aktiviertem Export-Ziel wird ein eigener Cron-Job registriert (lokale
+//! Zeit, analog zu [`crate::scheduler::SchedulerServiceImpl`]). Ein Lauf baut
+//! über die [`ExportDestinationFactory`] die Ablage des Ziels, erzeugt die
+//! Dateien des Artefakts und schreibt das Ergebnis über
+//! [`ExportTargetService::record_run`] in die Historie:
+//!
+//! - `Success`: keine Datei ist fehlgeschlagen (übersprungene Wochen, die
+//!   noch nicht `Planned`/`Locked` sind, zählen nicht als Fehler).
+//! - `Partial`: mindestens eine Datei abgelegt, mindestens eine nicht.
+//! - `Failed`: nichts abgelegt und mindestens ein Fehler.
+//!
+//! Schlägt das Ablegen einer Datei fehl, bricht der Lauf ab: die übrigen
+//! Dateien würden am selben Ziel scheitern (bei WebDAV jeweils erst nach dem
+//! Retry-Backoff). Der nächste Cron-Slot versucht alles erneut.
+//!
+//! Shiftplan-Artefakte folgen der Auswahl des Nextcloud-Exports
+//! ([`PdfExportConfigService::get_shiftplan_settings`] und
+//! `include_planning_plans`); Unterordner und Präfix gelten nur dort, Ziele
+//! benennen ihre Dateien über das eigene Muster.
+//!
+//! Wie beim Nextcloud-Export wird das App-Token nie geloggt.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use chrono::Local;
+use service::{
+    billing_period::BillingPeriodService,
+    clock::ClockService,
+    export_target::{
+        ExportArtifactType, ExportRunState, ExportTarget, ExportTargetRun, ExportTargetService,
+    },
+    export_target_scheduler::ExportTargetScheduler,
+    pdf_export_config::PdfExportConfigService,
+    pdf_shiftplan::PdfShiftplanService,
+    permission::Authentication,
+    report_export::{ExportFormat, ExportLanguage, ReportExportService},
+    shiftplan_catalog::{Shiftplan, ShiftplanService},
+    team_ical::TeamIcalService,
+    PermissionService, ServiceError,
+};
+use shifty_utils::{ShiftyDate, ShiftyWeek};
+use tokio::sync::Mutex;
+use tokio_cron_scheduler::{Job, JobScheduler};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::export_destination::{ExportDestination, ExportDestinationFactory};
+use crate::export_target::expand_filename_pattern;
+use crate::gen_service_impl;
+use crate::pdf_export_scheduler::select_shiftplans;
+use crate::report_export::file_name_slug;
+
+/// Höchstzahl Meldungszeilen pro Lauf in der Historie.
+const MAX_MESSAGE_LINES: usize = 20;
+
+gen_service_impl! {
+    struct ExportTargetSchedulerImpl: service::export_target_scheduler::ExportTargetScheduler = ExportTargetSchedulerDeps {
+        ExportTargetService: ExportTargetService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = export_target_service,
+        PdfShiftplanService: PdfShiftplanService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = pdf_shiftplan_service,
+        ShiftplanService: ShiftplanService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = shiftplan_service,
+        PdfExportConfigService: PdfExportConfigService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = pdf_export_config_service,
+        BillingPeriodService: BillingPeriodService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = billing_period_service,
+        ReportExportService: ReportExportService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = report_export_service,
+        TeamIcalService: TeamIcalService<
+            Context = Self::Context,
+            Transaction = Self::Transaction,
+        > = team_ical_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+    }
+    ; custom_fields {
+        destination_factory: Arc<dyn ExportDestinationFactory> = destination_factory,
+        scheduler: Arc<Mutex<Option<JobScheduler>>> = scheduler,
+        registered_jobs: Arc<Mutex<Vec<Uuid>>> = registered_jobs,
+    }
+}
+
+/// Zähler und Meldungen eines Laufs.
+#[derive(Default)]
+struct RunOutcome {
+    written: u32,
+    skipped: u32,
+    failed: u32,
+    messages: Vec<String>,
+}
+
+impl RunOutcome {
+    fn skip(&mut self, message: String) {
+        self.skipped += 1;
+        self.messages.push(message);
+    }
+
+    fn fail(&mut self, message: String) {
+        self.failed += 1;
+        self.messages.push(message);
+    }
+
+    fn state(&self) -> ExportRunState {
+        match (self.failed, self.written) {
+            (0, _) => ExportRunState::Success,
+            (_, 0) => ExportRunState::Failed,
+            _ => ExportRunState::Partial,
+        }
+    }
+
+    fn message(&self) -> Option<Arc<str>> {
+        if self.messages.is_empty() {
+            return None;
+        }
+        let mut lines: Vec<&str> = self
+            .messages
+            .iter()
+            .take(MAX_MESSAGE_LINES)
+            .map(String::as_str)
+            .collect();
+        let more = self.messages.len().saturating_sub(MAX_MESSAGE_LINES);
+        let more_line = format!("… und {more} weitere");
+        if more > 0 {
+            lines.push(&more_line);
+        }
+        Some(Arc::from(lines.join("\n")))
+    }
+}
+
+impl<Deps: ExportTargetSchedulerDeps> ExportTargetSchedulerImpl<Deps> {
+    /// Der `JobScheduler` wird lazy in `start()` initialisiert, damit `new()`
+    /// synchron bleibt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        export_target_service: Arc<Deps::ExportTargetService>,
+        pdf_shiftplan_service: Arc<Deps::PdfShiftplanService>,
+        shiftplan_service: Arc<Deps::ShiftplanService>,
+        pdf_export_config_service: Arc<Deps::PdfExportConfigService>,
+        billing_period_service: Arc<Deps::BillingPeriodService>,
+        report_export_service: Arc<Deps::ReportExportService>,
+        team_ical_service: Arc<Deps::TeamIcalService>,
+        permission_service: Arc<Deps::PermissionService>,
+        clock_service: Arc<Deps::ClockService>,
+        destination_factory: Arc<dyn ExportDestinationFactory>,
+    ) -> Self {
+        Self {
+            export_target_service,
+            pdf_shiftplan_service,
+            shiftplan_service,
+            pdf_export_config_service,
+            billing_period_service,
+            report_export_service,
+            team_ical_service,
+            permission_service,
+            clock_service,
+            destination_factory,
+            scheduler: Arc::new(Mutex::new(None)),
+            registered_jobs: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn clone_for_job(&self) -> Arc<Self> {
+        Arc::new(Self {
+            export_target_service: self.export_target_service.clone(),
+            pdf_shiftplan_service: self.pdf_shiftplan_service.clone(),
+            shiftplan_service: self.shiftplan_service.clone(),
+            pdf_export_config_service: self.pdf_export_config_service.clone(),
+            billing_period_service: self.billing_period_service.clone(),
+            report_export_service: self.report_export_service.clone(),
+            team_ical_service: self.team_ical_service.clone(),
+            permission_service: self.permission_service.clone(),
+            clock_service: self.clock_service.clone(),
+            destination_factory: self.destination_factory.clone(),
+            scheduler: self.scheduler.clone(),
+            registered_jobs: self.registered_jobs.clone(),
+        })
+    }
+
+    /// Die im Nextcloud-Export ausgewählten Shiftplans in Katalog-Reihenfolge.
+    async fn active_shiftplans(&self) -> Result<Vec<Shiftplan>, ServiceError> {
+        let shiftplans = self
+            .shiftplan_service
+            .get_all(Authentication::Full, None)
+            .await?;
+        let include_planning_plans = self
+            .pdf_export_config_service
+            .get(Authentication::Full, None)
+            .await?
+            .include_planning_plans;
+        let settings = self
+            .pdf_export_config_service
+            .get_shiftplan_settings(Authentication::Full, None)
+            .await?;
+        let selected: Vec<Uuid> =
+            select_shiftplans(&shiftplans, &settings, include_planning_plans, "")
+                .iter()
+                .map(|shiftplan| shiftplan.id)
+                .collect();
+        Ok(shiftplans
+            .iter()
+            .filter(|shiftplan| selected.contains(&shiftplan.id))
+            .cloned()
+            .collect())
+    }
+
+    /// Legt eine Datei ab. `false` heißt: Lauf abbrechen.
+    async fn store(
+        &self,
+        destination: &dyn ExportDestination,
+        filename: &str,
+        bytes: Vec<u8>,
+        outcome: &mut RunOutcome,
+    ) -> bool {
+        match destination.store_file(filename, bytes).await {
+            Ok(()) => {
+                outcome.written += 1;
+                true
+            }
+            Err(e) => {
+                outcome.fail(format!("{filename}: Ablage fehlgeschlagen ({e})"));
+                false
+            }
+        }
+    }
+
+    async fn export_files(
+        &self,
+        target: &ExportTarget,
+        destination: &dyn ExportDestination,
+        outcome: &mut RunOutcome,
+    ) -> Result<(), ServiceError> {
+        let today = self.clock_service.date_now();
+        let date = today.to_string();
+        let target_slug = file_name_slug(&target.name);
+        let pattern = target.effective_filename_pattern();
+
+        match target.artifact_type {
+            ExportArtifactType::ShiftplanWeekPdf => {
+                let shiftplans = self.active_shiftplans().await?;
+                let start = ShiftyDate::from_date(today);
+                let mut week = ShiftyWeek::new(start.year(), start.week());
+                for _ in 0..target.weeks_horizon.max(1) {
+                    let year = week.year.to_string();
+                    let calendar_week = format!("{:02}", week.week);
+                    for shiftplan in shiftplans.iter() {
+                        let label = format!("{} KW{calendar_week}/{year}", shiftplan.name);
+                        // Das Status-Gate im PdfShiftplanService liefert für
+                        // Wochen außerhalb von Planned/Locked einen
+                        // ValidationError: übersprungen, kein Fehler.
+                        let bytes = match self
+                            .pdf_shiftplan_service
+                            .render_week_pdf(
+                                shiftplan.id,
+                                week.year,
+                                week.week,
+                                Authentication::Full,
+                                None,
+                            )
+                            .await
+                        {
+                            Ok(bytes) => bytes,
+                            Err(ServiceError::ValidationError(_)) => {
+                                outcome.skip(format!("{label}: Woche nicht freigegeben"));
+                                continue;
+                            }
+                            Err(e) => {
+                                outcome.fail(format!("{label}: {e}"));
+                                continue;
+                            }
+                        };
+                        let filename = expand_filename_pattern(
+                            pattern,
+                            &[
+                                ("target", &target_slug),
+                                ("date", &date),
+                                ("shiftplan", &file_name_slug(&shiftplan.name)),
+                                ("year", &year),
+                                ("week", &calendar_week),
+                            ],
+                        )?;
+                        if !self.store(destination, &filename, bytes, outcome).await {
+                            return Ok(());
+                        }
+                    }
+                    week = week.next();
+                }
+            }
+            ExportArtifactType::BillingPeriodCsv => {
+                let latest = self
+                    .billing_period_service
+                    .get_billing_period_overview(Authentication::Full, None)
+                    .await?
+                    .iter()
+                    .max_by_key(|billing_period| billing_period.end_date)
+                    .cloned();
+                let Some(billing_period) = latest else {
+                    outcome.skip("Kein Abrechnungszeitraum vorhanden".into());
+                    return Ok(());
+                };
+                let file = self
+                    .report_export_service
+                    .export_billing_period(
+                        billing_period.id,
+                        ExportFormat::Csv,
+                        ExportLanguage::De,
+                        Authentication::Full,
+                        None,
+                    )
+                    .await?;
+                let filename = expand_filename_pattern(
+                    pattern,
+                    &[
+                        ("target", &target_slug),
+                        ("date", &date),
+                        ("start", &billing_period.start_date.to_date().to_string()),
+                        ("end", &billing_period.end_date.to_date().to_string()),
+                    ],
+                )?;
+                self.store(destination, &filename, file.content.to_vec(), outcome)
+                    .await;
+            }
+            ExportArtifactType::IcalSnapshot => {
+                for shiftplan in self.active_shiftplans().await? {
+                    let ical = match self
+                        .team_ical_service
+                        .get_shiftplan_as_ical(shiftplan.id, Authentication::Full, None)
+                        .await
+                    {
+                        Ok(ical) => ical,
+                        Err(e) => {
+                            outcome.fail(format!("{}: {e}", shiftplan.name));
+                            continue;
+                        }
+                    };
+                    let filename = expand_filename_pattern(
+                        pattern,
+                        &[
+                            ("target", &target_slug),
+                            ("date", &date),
+                            ("shiftplan", &file_name_slug(&shiftplan.name)),
+                        ],
+                    )?;
+                    if !self
+                        .store(destination, &filename, ical.as_bytes().to_vec(), outcome)
+                        .await
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Deps: ExportTargetSchedulerDeps + 'static> ExportTargetScheduler
+    for ExportTargetSchedulerImpl<Deps>
+{
+    type Context = Deps::Context;
+
+    async fn start(&self) -> Result<(), ServiceError> {
+        let mut sched_guard = self.scheduler.lock().await;
+        if sched_guard.is_none() {
+            let scheduler = JobScheduler::new().await.map_err(|e| {
+                error!("export-target scheduler init failed: {e}");
+                ServiceError::InternalError
+            })?;
+            *sched_guard = Some(scheduler);
+        }
+        drop(sched_guard);
+        // Ein kaputtes Ziel darf den Backend-Start nicht verhindern; ein
+        // späteres PUT auf `/export-target/{id}` lädt neu.
+        if let Err(e) = self.reload_from_db().await {
+            warn!("export-target: initial reload at boot failed ({e:?}) — no targets registered");
+        }
+        let sched_guard = self.scheduler.lock().await;
+        if let Some(sched) = sched_guard.as_ref() {
+            sched.start().await.map_err(|e| {
+                error!("export-target scheduler start failed: {e}");
+                ServiceError::InternalError
+            })?;
+        }
+        Ok(())
+    }
+
+    async fn reload_from_db(&self) -> Result<(), ServiceError> {
+        let targets = self
+            .export_target_service
+            .get_all(Authentication::Full, None)
+            .await?;
+
+        let mut sched_guard = self.scheduler.lock().await;
+        let scheduler = match sched_guard.as_mut() {
+            Some(s) => s,
+            // Not initialised yet — `start()` will call reload_from_db.
+            None => return Ok(()),
+        };
+        let mut registered = self.registered_jobs.lock().await;
+        for job_id in registered.drain(..) {
+            if let Err(e) = scheduler.remove(&job_id).await {
+                warn!("export-target: could not remove previous cron job: {e}");
+            }
+        }
+
+        for target in targets.iter().filter(|target| target.enabled) {
+            let target_id = target.id;
+            let service = self.clone_for_job();
+            let cron_job =
+                Job::new_async_tz(target.cron_schedule.as_ref(), Local, move |_uuid, _lock| {
+                    let service = service.clone();
+                    Box::pin(async move {
+                        if let Err(e) = service
+                            .run_target_now(target_id, Authentication::Full)
+                            .await
+                        {
+                            error!("export target {target_id} run failed: {e:?}");
+                        }
+                    })
+                });
+            match cron_job {
+                Ok(cron_job) => match scheduler.add(cron_job).await {
+                    Ok(job_id) => {
+                        registered.push(job_id);
+                        info!(
+                            "Scheduled export target '{}' with cron expression: {}",
+                            target.name, target.cron_schedule
+                        );
+                    }
+                    Err(e) => error!(
+                        "export-target: could not add job for '{}': {e}",
+                        target.name
+                    ),
+                },
+                Err(e) => error!(
+                    "export-target: invalid cron expression '{}' for '{}': {e}",
+                    target.cron_schedule, target.name
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    async fn run_target_now(
+        &self,
+        target_id: Uuid,
+        context: Authentication<Self::Context>,
+    ) -> Result<ExportTargetRun, ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let target = self
+            .export_target_service
+            .get(target_id, Authentication::Full, None)
+            .await?;
+
+        let started = self.clock_service.date_time_now();
+        let timer = Instant::now();
+        let mut outcome = RunOutcome::default();
+        match self.destination_factory.build(&target) {
+            Ok(destination) => {
+                if let Err(e) = self
+                    .export_files(&target, destination.as_ref(), &mut outcome)
+                    .await
+                {
+                    outcome.fail(format!("Export abgebrochen: {e}"));
+                }
+            }
+            Err(e) => outcome.fail(format!("Ziel nicht erreichbar: {e}")),
+        }
+
+        let run = ExportTargetRun {
+            id: Uuid::nil(),
+            target_id,
+            started,
+            duration_ms: timer.elapsed().as_millis() as u64,
+            state: outcome.state(),
+            files_written: outcome.written,
+            files_skipped: outcome.skipped,
+            message: outcome.message(),
+            version: Uuid::nil(),
+        };
+        let run = self
+            .export_target_service
+            .record_run(&run, Authentication::Full, None)
+            .await?;
+        info!(
+            "export target '{}': {:?}, {} written, {} skipped, {} failed",
+            target.name, run.state, outcome.written, outcome.skipped, outcome.failed
+        );
+        Ok(run)
+    }
+}
@@ -7,6 +7,8 @@ use dao::{
     TransactionDao,
 };
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    carryover::{first_dirty_year, CarryoverService},
     clock::ClockService,
     custom_extra_hours::CustomExtraHoursService,
     extra_hours::{ExtraHours, ExtraHoursService},
@@ -31,6 +33,8 @@ gen_service_impl! {
         CustomExtraHoursService: CustomExtraHoursService<Context = Self::Context, Transaction = Self::Transaction> = custom_extra_hours_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        CarryoverService: CarryoverService<Context = Self::Context, Transaction = Self::Transaction> = carryover_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -252,6 +256,22 @@ impl<Deps: ExtraHoursServiceDeps> ExtraHoursService for ExtraHoursServiceImpl<De
                 tx.clone(),
             )
             .await?;
+        self.carryover_service
+            .mark_dirty(
+                extra_hours.sales_person_id,
+                first_dirty_year(extra_hours.date_time.date()),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::EXTRA_HOURS, extra_hours.id, &extra_hours)?,
+                "extra_hours_service::create",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(extra_hours)
@@ -277,7 +297,7 @@ impl<Deps: ExtraHoursServiceDeps> ExtraHoursService for ExtraHoursServiceImpl<De
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 active.sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
@@ -336,9 +356,31 @@ impl<Deps: ExtraHoursServiceDeps> ExtraHoursService for ExtraHoursServiceImpl<De
                 tx.clone(),
             )
             .await?;
+        let extra_hours = ExtraHours::from(&new_entity);
+        self.carryover_service
+            .mark_dirty(
+                active.sales_person_id,
+                first_dirty_year(active.date_time.date().min(request.date_time.date())),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::EXTRA_HOURS,
+                    logical_id,
+                    &ExtraHours::from(&active),
+                    &extra_hours,
+                )?,
+                "extra_hours_service::update",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
-        Ok(ExtraHours::from(&new_entity))
+        Ok(extra_hours)
     }
 
     async fn delete(
@@ -367,12 +409,13 @@ impl<Deps: ExtraHoursServiceDeps> ExtraHoursService for ExtraHoursServiceImpl<De
                 .check_permission(HR_PRIVILEGE, context.clone()),
             self.sales_person_service.verify_user_is_sales_person(
                 extra_hours_entity.sales_person_id,
-                context,
+                context.clone(),
                 tx.clone().into()
             ),
         );
         hr_permission.or(user_permission)?;
 
+        let before = ExtraHours::from(&extra_hours_entity);
         extra_hours_entity.deleted = Some(self.clock_service.date_time_now());
 
         self.extra_hours_dao
@@ -382,6 +425,22 @@ impl<Deps: ExtraHoursServiceDeps> ExtraHoursService for ExtraHoursServiceImpl<De
                 tx.clone(),
             )
             .await?;
+        self.carryover_service
+            .mark_dirty(
+                extra_hours_entity.sales_person_id,
+                first_dirty_year(extra_hours_entity.date_time.date()),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::EXTRA_HOURS, extra_hours_id, &before)?,
+                "extra_hours_service::delete",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
@@ -2,7 +2,8 @@ use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{feature_flag::FeatureFlagDao, TransactionDao};
 use service::{
-    feature_flag::{FeatureFlagService, FEATURE_FLAG_ADMIN_PRIVILEGE},
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    feature_flag::{FeatureFlag, FeatureFlagService, FEATURE_FLAG_ADMIN_PRIVILEGE},
     permission::Authentication,
     PermissionService, ServiceError,
 };
@@ -13,6 +14,7 @@ gen_service_impl! {
     struct FeatureFlagServiceImpl: FeatureFlagService = FeatureFlagServiceDeps {
         FeatureFlagDao: FeatureFlagDao<Transaction = Self::Transaction> = feature_flag_dao,
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -55,13 +57,30 @@ impl<Deps: FeatureFlagServiceDeps> FeatureFlagService for FeatureFlagServiceImpl
     ) -> Result<(), ServiceError> {
         // Admin-only via FEATURE_FLAG_ADMIN_PRIVILEGE
         self.permission_service
-            .check_permission(FEATURE_FLAG_ADMIN_PRIVILEGE, context)
+            .check_permission(FEATURE_FLAG_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.feature_flag_dao.get(key, tx.clone()).await?;
         self.feature_flag_dao
             .set(key, value, FEATURE_FLAG_SERVICE_PROCESS, tx.clone())
             .await?;
+        // UPDATE-only: ein unbekannter Schlüssel ändert nichts, also auch
+        // kein Protokolleintrag.
+        if let Some(before) = before.as_ref().map(FeatureFlag::from) {
+            let after = FeatureFlag {
+                enabled: value,
+                ..before.clone()
+            };
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(entity_type::FEATURE_FLAG, key, &before, &after)?,
+                    FEATURE_FLAG_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -0,0 +1,390 @@
+//! Business-Logic-Tier Implementation von
+//! [`service::holiday_calendar::HolidayCalendarService`].
+//!
+//! Die Regeln stehen in [`HOLIDAYS`] und gelten nach heutigem Stand der
+//! Feiertagsgesetze; Feiertage, die erst später eingeführt wurden, tragen das
+//! Jahr der Einführung. [`holidays`] ist pur und ohne Mocks testbar.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use service::{
+    holiday_calendar::{
+        HolidayCalendarService, HolidayCandidate, HolidayRegion,
+        HolidayRegion::{
+            BadenWuerttemberg, Bavaria, Berlin, Brandenburg, Bremen, Hamburg, Hesse,
+            LowerSaxony, MecklenburgWesternPomerania, NorthRhineWestphalia,
+            RhinelandPalatinate, Saarland, Saxony, SaxonyAnhalt, SchleswigHolstein, Thuringia,
+        },
+    },
+    permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
+    special_days::{SpecialDay, SpecialDayService, SpecialDayType},
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::ShiftyDate;
+use time::{Date, Duration, Month, Weekday};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+/// Erstes Jahr des gregorianischen Kalenders, ab dem die Osterformel gilt.
+const FIRST_GREGORIAN_YEAR: u32 = 1583;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HolidayRule {
+    Fixed { month: Month, day: u8 },
+    /// Tage relativ zum Ostersonntag.
+    Easter(i64),
+    /// Mittwoch vor dem 23. November.
+    BussUndBettag,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct HolidayDefinition {
+    pub name: &'static str,
+    pub rule: HolidayRule,
+    /// Leer: bundesweit.
+    pub regions: &'static [HolidayRegion],
+    pub since: Option<i32>,
+}
+
+const fn nationwide(name: &'static str, rule: HolidayRule) -> HolidayDefinition {
+    HolidayDefinition {
+        name,
+        rule,
+        regions: &[],
+        since: None,
+    }
+}
+
+const fn regional(
+    name: &'static str,
+    rule: HolidayRule,
+    regions: &'static [HolidayRegion],
+    since: Option<i32>,
+) -> HolidayDefinition {
+    HolidayDefinition {
+        name,
+        rule,
+        regions,
+        since,
+    }
+}
+
+const fn fixed(month: Month, day: u8) -> HolidayRule {
+    HolidayRule::Fixed { month, day }
+}
+
+pub const HOLIDAYS: &[HolidayDefinition] = &[
+    nationwide("Neujahr", fixed(Month::January, 1)),
+    regional(
+        "Heilige Drei Könige",
+        fixed(Month::January, 6),
+        &[BadenWuerttemberg, Bavaria, SaxonyAnhalt],
+        None,
+    ),
+    regional(
+        "Internationaler Frauentag",
+        fixed(Month::March, 8),
+        &[Berlin],
+        Some(2019),
+    ),
+    regional(
+        "Internationaler Frauentag",
+        fixed(Month::March, 8),
+        &[MecklenburgWesternPomerania],
+        Some(2023),
+    ),
+    nationwide("Karfreitag", HolidayRule::Easter(-2)),
+    regional("Ostersonntag", HolidayRule::Easter(0), &[Brandenburg], None),
+    nationwide("Ostermontag", HolidayRule::Easter(1)),
+    nationwide("Tag der Arbeit", fixed(Month::May, 1)),
+    nationwide("Christi Himmelfahrt", HolidayRule::Easter(39)),
+    regional("Pfingstsonntag", HolidayRule::Easter(49), &[Brandenburg], None),
+    nationwide("Pfingstmontag", HolidayRule::Easter(50)),
+    regional(
+        "Fronleichnam",
+        HolidayRule::Easter(60),
+        &[
+            BadenWuerttemberg,
+            Bavaria,
+            Hesse,
+            NorthRhineWestphalia,
+            RhinelandPalatinate,
+            Saarland,
+        ],
+        None,
+    ),
+    regional(
+        "Mariä Himmelfahrt",
+        fixed(Month::August, 15),
+        &[Saarland],
+        None,
+    ),
+    regional(
+        "Weltkindertag",
+        fixed(Month::September, 20),
+        &[Thuringia],
+        Some(2019),
+    ),
+    nationwide("Tag der Deutschen Einheit", fixed(Month::October, 3)),
+    regional(
+        "Reformationstag",
+        fixed(Month::October, 31),
+        &[
+            Brandenburg,
+            MecklenburgWesternPomerania,
+            Saxony,
+            SaxonyAnhalt,
+            Thuringia,
+        ],
+        None,
+    ),
+    regional(
+        "Reformationstag",
+        fixed(Month::October, 31),
+        &[Bremen, Hamburg, LowerSaxony, SchleswigHolstein],
+        Some(2018),
+    ),
+    regional(
+        "Allerheiligen",
+        fixed(Month::November, 1),
+        &[
+            BadenWuerttemberg,
+            Bavaria,
+            NorthRhineWestphalia,
+            RhinelandPalatinate,
+            Saarland,
+        ],
+        None,
+    ),
+    regional("Buß- und Bettag", HolidayRule::BussUndBettag, &[Saxony], None),
+    nationwide("1. Weihnachtstag", fixed(Month::December, 25)),
+    nationwide("2. Weihnachtstag", fixed(Month::December, 26)),
+];
+
+/// Ostersonntag nach der anonymen gregorianischen Osterformel
+/// (Meeus/Jones/Butcher).
+pub fn easter_sunday(year: i32) -> Result<Date, ServiceError> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Ok(Date::from_calendar_date(
+        year,
+        Month::try_from(month as u8)?,
+        day as u8,
+    )?)
+}
+
+impl HolidayRule {
+    pub fn date(&self, year: i32) -> Result<Date, ServiceError> {
+        Ok(match self {
+            HolidayRule::Fixed { month, day } => Date::from_calendar_date(year, *month, *day)?,
+            HolidayRule::Easter(offset) => easter_sunday(year)? + Duration::days(*offset),
+            HolidayRule::BussUndBettag => {
+                Date::from_calendar_date(year, Month::November, 23)?
+                    .prev_occurrence(Weekday::Wednesday)
+            }
+        })
+    }
+}
+
+impl HolidayDefinition {
+    pub fn applies_to(&self, region: HolidayRegion, year: i32) -> bool {
+        (self.regions.is_empty() || self.regions.contains(&region))
+            && self.since.is_none_or(|since| year >= since)
+    }
+}
+
+/// Alle Feiertage eines Kalenderjahres in `region`, nach Datum sortiert.
+pub fn holidays(region: HolidayRegion, year: i32) -> Result<Vec<(Date, &'static str)>, ServiceError> {
+    let mut holidays = HOLIDAYS
+        .iter()
+        .filter(|holiday| holiday.applies_to(region, year))
+        .map(|holiday| Ok((holiday.rule.date(year)?, holiday.name)))
+        .collect::<Result<Vec<_>, ServiceError>>()?;
+    holidays.sort();
+    Ok(holidays)
+}
+
+gen_service_impl! {
+    struct HolidayCalendarServiceImpl: HolidayCalendarService = HolidayCalendarServiceDeps {
+        SpecialDayService: SpecialDayService<Context = Self::Context> = special_day_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+    }
+}
+
+#[async_trait]
+impl<Deps: HolidayCalendarServiceDeps> HolidayCalendarService for HolidayCalendarServiceImpl<Deps> {
+    type Context = Deps::Context;
+
+    async fn preview(
+        &self,
+        year: u32,
+        region: HolidayRegion,
+        context: Authentication<Self::Context>,
+    ) -> Result<Arc<[HolidayCandidate]>, ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+        if year < FIRST_GREGORIAN_YEAR {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("year".into()),
+            ])));
+        }
+
+        let existing = self.special_day_service.get_by_year(year, context).await?;
+        holidays(region, year as i32)?
+            .into_iter()
+            .map(|(date, name)| {
+                let shifty_date = ShiftyDate::from_date(date);
+                Ok(HolidayCandidate {
+                    date,
+                    name: name.into(),
+                    existing: existing
+                        .iter()
+                        .find(|special_day| {
+                            special_day.year == shifty_date.year()
+                                && special_day.calendar_week == shifty_date.week()
+                                && special_day.day_of_week == shifty_date.day_of_week()
+                        })
+                        .cloned(),
+                })
+            })
+            .collect()
+    }
+
+    async fn generate(
+        &self,
+        year: u32,
+        region: HolidayRegion,
+        context: Authentication<Self::Context>,
+    ) -> Result<Arc<[SpecialDay]>, ServiceError> {
+        let candidates = self.preview(year, region, context.clone()).await?;
+        let mut created = Vec::new();
+        for candidate in candidates.iter().filter(|c| c.existing.is_none()) {
+            let shifty_date = ShiftyDate::from_date(candidate.date);
+            let special_day = SpecialDay {
+                id: Uuid::nil(),
+                year: shifty_date.year(),
+                calendar_week: shifty_date.week(),
+                day_of_week: shifty_date.day_of_week(),
+                day_type: SpecialDayType::Holiday,
+                time_of_day: None,
+                created: None,
+                deleted: None,
+                version: Uuid::nil(),
+            };
+            created.push(
+                self.special_day_service
+                    .create(&special_day, context.clone())
+                    .await?,
+            );
+        }
+        Ok(created.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn easter_sunday_known_years() {
+        assert_eq!(easter_sunday(2024).unwrap(), date!(2024 - 03 - 31));
+        assert_eq!(easter_sunday(2025).unwrap(), date!(2025 - 04 - 20));
+        assert_eq!(easter_sunday(2026).unwrap(), date!(2026 - 04 - 05));
+        assert_eq!(easter_sunday(2038).unwrap(), date!(2038 - 04 - 25));
+        assert_eq!(easter_sunday(2285).unwrap(), date!(2285 - 03 - 22));
+    }
+
+    #[test]
+    fn buss_und_bettag() {
+        assert_eq!(
+            HolidayRule::BussUndBettag.date(2026).unwrap(),
+            date!(2026 - 11 - 18)
+        );
+        // 23.11. ist selbst ein Mittwoch: eine Woche davor.
+        assert_eq!(
+            HolidayRule::BussUndBettag.date(2022).unwrap(),
+            date!(2022 - 11 - 16)
+        );
+    }
+
+    #[test]
+    fn nationwide_holidays() {
+        let days: Vec<Date> = holidays(HolidayRegion::Germany, 2026)
+            .unwrap()
+            .into_iter()
+            .map(|(date, _)| date)
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                date!(2026 - 01 - 01),
+                date!(2026 - 04 - 03),
+                date!(2026 - 04 - 06),
+                date!(2026 - 05 - 01),
+                date!(2026 - 05 - 14),
+                date!(2026 - 05 - 25),
+                date!(2026 - 10 - 03),
+                date!(2026 - 12 - 25),
+                date!(2026 - 12 - 26),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_region_has_the_nationwide_holidays() {
+        for region in HolidayRegion::ALL {
+            assert!(holidays(region, 2026).unwrap().len() >= 9, "{region:?}");
+        }
+    }
+
+    #[test]
+    fn regional_holidays() {
+        let names = |region, year| -> Vec<&'static str> {
+            holidays(region, year)
+                .unwrap()
+                .into_iter()
+                .map(|(_, name)| name)
+                .collect()
+        };
+        let bavaria = names(HolidayRegion::Bavaria, 2026);
+        assert!(bavaria.contains(&"Heilige Drei Könige"));
+        assert!(bavaria.contains(&"Fronleichnam"));
+        assert!(bavaria.contains(&"Allerheiligen"));
+        assert!(!bavaria.contains(&"Reformationstag"));
+        assert_eq!(bavaria.len(), 12);
+
+        assert!(names(HolidayRegion::Saxony, 2026).contains(&"Buß- und Bettag"));
+        assert!(names(HolidayRegion::Brandenburg, 2026).contains(&"Pfingstsonntag"));
+        assert!(names(HolidayRegion::LowerSaxony, 2018).contains(&"Reformationstag"));
+        assert!(!names(HolidayRegion::LowerSaxony, 2017).contains(&"Reformationstag"));
+        assert!(!names(HolidayRegion::MecklenburgWesternPomerania, 2022)
+            .contains(&"Internationaler Frauentag"));
+        assert!(names(HolidayRegion::MecklenburgWesternPomerania, 2023)
+            .contains(&"Internationaler Frauentag"));
+    }
+
+    #[test]
+    fn region_codes_round_trip() {
+        for region in HolidayRegion::ALL {
+            assert_eq!(region.code().parse::<HolidayRegion>().unwrap(), region);
+        }
+        assert_eq!("de-by".parse::<HolidayRegion>().unwrap(), HolidayRegion::Bavaria);
+        assert!("DE-XX".parse::<HolidayRegion>().is_err());
+    }
+}
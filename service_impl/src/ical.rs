@@ -1,41 +1,307 @@
+//! RFC 5545-Generator für [`service::ical::IcalService`].
+//!
+//! Die VTIMEZONE wird aus der tz-Datenbank (`time-tz`) abgeleitet: für jedes
+//! Jahr, das die Events berühren (plus das Vorjahr, damit auch Termine vor
+//! dem ersten Wechsel abgedeckt sind), wird jeder Offset-Wechsel als eigene
+//! STANDARD-/DAYLIGHT-Komponente ohne RRULE geschrieben.
+
 use std::sync::Arc;
 
-use service::{block::Block, ical::IcalService, ServiceError};
-use time::macros::format_description;
+use service::{
+    ical::{IcalCalendar, IcalEvent, IcalService},
+    ServiceError,
+};
+use time::{
+    format_description::BorrowedFormatItem,
+    macros::{datetime, format_description},
+    Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset,
+};
+use time_tz::{timezones, Offset, TimeZone, Tz};
+use tracing::warn;
+
+const PRODID: &str = "-//shifty//shifty//EN";
+/// Maximale Zeilenlänge in Oktetten ohne CRLF (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+const DATE_TIME_FORMAT: &[BorrowedFormatItem<'static>] =
+    format_description!("[year][month][day]T[hour][minute][second]");
+const DATE_FORMAT: &[BorrowedFormatItem<'static>] = format_description!("[year][month][day]");
+
+/// Bezugspunkt für SEQUENCE; Sekunden seit diesem Zeitpunkt passen noch bis
+/// 2088 in den 31-Bit-Wertebereich von RFC 5545.
+const SEQUENCE_EPOCH: PrimitiveDateTime = datetime!(2020-01-01 00:00:00);
+
+/// SEQUENCE aus dem Zeitpunkt der letzten Änderung; steigt damit bei jeder
+/// Änderung, ohne dass eine Versionsnummer gespeichert werden muss.
+pub(crate) fn sequence_for(latest_change: Option<PrimitiveDateTime>) -> u32 {
+    latest_change
+        .map(|latest_change| (latest_change - SEQUENCE_EPOCH).whole_seconds())
+        .unwrap_or(0)
+        .clamp(0, i32::MAX as i64) as u32
+}
 
 pub struct IcalServiceImpl;
 
 impl IcalService for IcalServiceImpl {
-    fn convert_blocks_to_ical_string(
+    fn render_calendar(
         &self,
-        blocks: Arc<[Block]>,
-        title: Arc<str>,
-        timezone: Arc<str>,
+        calendar: &IcalCalendar,
+        generated_at: PrimitiveDateTime,
     ) -> Result<Arc<str>, ServiceError> {
-        let datetime_format = format_description!("[year][month][day]T[hour][minute][second]");
-
-        let mut ical_string = String::new();
-        ical_string.push_str("BEGIN:VCALENDAR\n");
-        ical_string.push_str("VERSION:2.0\n");
-        ical_string.push_str("PRODID:-//shifty/handcal//NONSGML v1.0//EN\n");
-        for block in blocks.iter() {
-            ical_string.push_str("BEGIN:VEVENT\n");
-            ical_string.push_str(&format!("UID:{}\n", block.block_identifier()));
-            ical_string.push_str(&format!(
-                "DTSTART;TZID={}:{}\n",
-                timezone.as_ref(),
-                block.datetime_from()?.format(&datetime_format)?
-            ));
-            ical_string.push_str(&format!(
-                "DTEND;TZID={}:{}\n",
-                timezone.as_ref(),
-                block.datetime_to()?.format(&datetime_format)?
-            ));
-            ical_string.push_str(&format!("SUMMARY:{}\n", title.as_ref()));
-            ical_string.push_str("END:VEVENT\n");
+        let tz = timezones::get_by_name(&calendar.timezone);
+        if tz.is_none() {
+            warn!(
+                "Unknown timezone {}, writing floating times",
+                calendar.timezone
+            );
         }
-        ical_string.push_str("END:VCALENDAR\n");
 
-        Ok(ical_string.into())
+        let mut out = String::new();
+        push_line(&mut out, "BEGIN:VCALENDAR");
+        push_line(&mut out, "VERSION:2.0");
+        push_line(&mut out, &format!("PRODID:{PRODID}"));
+        push_line(&mut out, "CALSCALE:GREGORIAN");
+        push_line(&mut out, "METHOD:PUBLISH");
+        push_line(
+            &mut out,
+            &format!("X-WR-CALNAME:{}", escape_text(&calendar.name)),
+        );
+        if let Some(tz) = tz {
+            push_line(&mut out, &format!("X-WR-TIMEZONE:{}", calendar.timezone));
+            let (from_year, to_year) = year_range(&calendar.events, generated_at);
+            write_vtimezone(&mut out, &calendar.timezone, tz, from_year, to_year)?;
+        }
+        let dtstamp = generated_at.format(DATE_TIME_FORMAT)?;
+        let tzid_param = tz
+            .map(|_| format!(";TZID={}", calendar.timezone))
+            .unwrap_or_default();
+        for event in calendar.events.iter() {
+            write_event(&mut out, event, &dtstamp, &tzid_param)?;
+        }
+        push_line(&mut out, "END:VCALENDAR");
+
+        Ok(out.into())
+    }
+}
+
+fn write_event(
+    out: &mut String,
+    event: &IcalEvent,
+    dtstamp: &str,
+    tzid_param: &str,
+) -> Result<(), ServiceError> {
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", escape_text(&event.uid)));
+    push_line(out, &format!("DTSTAMP:{dtstamp}Z"));
+    push_line(out, &format!("SEQUENCE:{}", event.sequence));
+    if event.all_day {
+        push_line(
+            out,
+            &format!("DTSTART;VALUE=DATE:{}", event.start.format(DATE_FORMAT)?),
+        );
+        push_line(
+            out,
+            &format!("DTEND;VALUE=DATE:{}", event.end.format(DATE_FORMAT)?),
+        );
+    } else {
+        push_line(
+            out,
+            &format!(
+                "DTSTART{tzid_param}:{}",
+                event.start.format(DATE_TIME_FORMAT)?
+            ),
+        );
+        push_line(
+            out,
+            &format!("DTEND{tzid_param}:{}", event.end.format(DATE_TIME_FORMAT)?),
+        );
+    }
+    push_line(out, &format!("SUMMARY:{}", escape_text(&event.summary)));
+    if let Some(description) = &event.description {
+        push_line(out, &format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    push_line(
+        out,
+        if event.cancelled {
+            "STATUS:CANCELLED"
+        } else {
+            "STATUS:CONFIRMED"
+        },
+    );
+    push_line(out, "END:VEVENT");
+    Ok(())
+}
+
+/// Jahre, für die die VTIMEZONE Offset-Wechsel enthalten muss.
+fn year_range(events: &[IcalEvent], generated_at: PrimitiveDateTime) -> (i32, i32) {
+    let from_year = events
+        .iter()
+        .map(|event| event.start.year())
+        .min()
+        .unwrap_or(generated_at.year());
+    let to_year = events
+        .iter()
+        .map(|event| event.end.year())
+        .max()
+        .unwrap_or(generated_at.year());
+    (from_year - 1, to_year)
+}
+
+/// Ein Offset-Wechsel der Zeitzone.
+struct Transition {
+    at: OffsetDateTime,
+    from: UtcOffset,
+    to: UtcOffset,
+    name: String,
+    is_dst: bool,
+}
+
+fn offset_key(tz: &Tz, at: OffsetDateTime) -> (UtcOffset, bool) {
+    let offset = tz.get_offset_utc(&at);
+    (offset.to_utc(), offset.is_dst())
+}
+
+/// Sucht tageweise nach Offset-Wechseln und grenzt jeden per Bisektion auf
+/// die Sekunde ein.
+fn transitions(tz: &Tz, from_year: i32, to_year: i32) -> Result<Vec<Transition>, ServiceError> {
+    let start = time::Date::from_ordinal_date(from_year, 1)?
+        .midnight()
+        .assume_utc();
+    let end = time::Date::from_ordinal_date(to_year + 1, 1)?
+        .midnight()
+        .assume_utc();
+
+    let mut result = Vec::new();
+    let mut day = start;
+    while day < end {
+        let next_day = day + Duration::days(1);
+        if offset_key(tz, day) != offset_key(tz, next_day) {
+            let (mut low, mut high) = (day, next_day);
+            while high - low > Duration::seconds(1) {
+                let middle = low + (high - low) / 2;
+                if offset_key(tz, middle) == offset_key(tz, low) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            let to = tz.get_offset_utc(&high);
+            result.push(Transition {
+                at: high,
+                from: tz.get_offset_utc(&low).to_utc(),
+                to: to.to_utc(),
+                name: to.name().to_string(),
+                is_dst: to.is_dst(),
+            });
+        }
+        day = next_day;
+    }
+    Ok(result)
+}
+
+fn write_vtimezone(
+    out: &mut String,
+    tzid: &str,
+    tz: &Tz,
+    from_year: i32,
+    to_year: i32,
+) -> Result<(), ServiceError> {
+    push_line(out, "BEGIN:VTIMEZONE");
+    push_line(out, &format!("TZID:{tzid}"));
+    let transitions = transitions(tz, from_year, to_year)?;
+    if transitions.is_empty() {
+        // Zeitzone ohne Wechsel im Zeitraum: eine einzige STANDARD-Komponente.
+        let start = time::Date::from_ordinal_date(from_year, 1)?
+            .midnight()
+            .assume_utc();
+        let offset = tz.get_offset_utc(&start);
+        push_line(out, "BEGIN:STANDARD");
+        push_line(out, "DTSTART:19700101T000000");
+        push_line(
+            out,
+            &format!("TZOFFSETFROM:{}", format_offset(offset.to_utc())),
+        );
+        push_line(
+            out,
+            &format!("TZOFFSETTO:{}", format_offset(offset.to_utc())),
+        );
+        push_line(out, &format!("TZNAME:{}", escape_text(offset.name())));
+        push_line(out, "END:STANDARD");
+    }
+    for transition in transitions {
+        let component = if transition.is_dst {
+            "DAYLIGHT"
+        } else {
+            "STANDARD"
+        };
+        // DTSTART ist die Ortszeit vor dem Wechsel (RFC 5545 §3.6.5).
+        let local = transition.at.to_offset(transition.from);
+        push_line(out, &format!("BEGIN:{component}"));
+        push_line(
+            out,
+            &format!(
+                "DTSTART:{}",
+                PrimitiveDateTime::new(local.date(), local.time()).format(DATE_TIME_FORMAT)?
+            ),
+        );
+        push_line(
+            out,
+            &format!("TZOFFSETFROM:{}", format_offset(transition.from)),
+        );
+        push_line(out, &format!("TZOFFSETTO:{}", format_offset(transition.to)));
+        push_line(out, &format!("TZNAME:{}", escape_text(&transition.name)));
+        push_line(out, &format!("END:{component}"));
+    }
+    push_line(out, "END:VTIMEZONE");
+    Ok(())
+}
+
+/// `+HHMM` bzw. `+HHMMSS`, falls der Offset Sekunden hat.
+fn format_offset(offset: UtcOffset) -> String {
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    let (hours, minutes, seconds) = offset.as_hms();
+    let (hours, minutes, seconds) = (hours.abs(), minutes.abs(), seconds.abs());
+    if seconds == 0 {
+        format!("{sign}{hours:02}{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}{minutes:02}{seconds:02}")
+    }
+}
+
+/// Escaping für TEXT-Werte (RFC 5545 §3.3.11).
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Hängt eine Content-Line mit CRLF an und faltet sie nach 75 Oktetten,
+/// ohne UTF-8-Zeichen zu zerteilen (RFC 5545 §3.1).
+fn push_line(out: &mut String, line: &str) {
+    let mut remaining = line;
+    let mut limit = MAX_LINE_OCTETS;
+    loop {
+        if remaining.len() <= limit {
+            out.push_str(remaining);
+            out.push_str("\r\n");
+            return;
+        }
+        let mut split = limit;
+        while !remaining.is_char_boundary(split) {
+            split -= 1;
+        }
+        out.push_str(&remaining[..split]);
+        out.push_str("\r\n ");
+        remaining = &remaining[split..];
+        // Das führende Leerzeichen zählt zur Zeilenlänge.
+        limit = MAX_LINE_OCTETS - 1;
     }
 }
@@ -0,0 +1,395 @@
+//! HTTP-Client für den iCal-Import abonnierter Kalender.
+//!
+//! Wie [`crate::webhook_client`]: ein schlanker Trait ([`IcalFetcher`]),
+//! den der `IcalImportServiceImpl` als Dependency bekommt, plus die
+//! Produktions-Implementierung [`IcalClient`] auf Basis von `reqwest`
+//! (rustls, Timeout). `webcal://` wird wie bei Kalender-Apps als
+//! `https://` abgerufen; andere Schemata werden abgelehnt. Antworten über
+//! [`MAX_CALENDAR_BYTES`] werden verworfen.
+//!
+//! Die URL stammt vom Benutzer, der Server darf darüber nicht ins interne
+//! Netz greifen: Loopback-, Link-Local- und private Adressen werden nach der
+//! DNS-Auflösung abgelehnt, und zwar im Resolver des Clients selbst, damit
+//! eine zweite Auflösung beim Verbindungsaufbau nichts anderes liefern kann.
+//! Weiterleitungen folgt der Client nicht.
+
+use std::error::Error;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mockall::automock;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::Policy;
+use reqwest::Url;
+use tracing::warn;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Obergrenze für einen abonnierten Kalender.
+pub const MAX_CALENDAR_BYTES: usize = 5 * 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IcalFetchError {
+    #[error("unsupported url: {0}")]
+    UnsupportedUrl(Arc<str>),
+
+    #[error("address not allowed")]
+    ForbiddenAddress,
+
+    /// Details landen nur im Log, nicht beim Benutzer.
+    #[error("calendar could not be fetched")]
+    Network,
+
+    #[error("unexpected status {0}")]
+    Status(u16),
+
+    #[error("calendar exceeds {MAX_CALENDAR_BYTES} bytes")]
+    TooLarge,
+}
+
+#[automock]
+#[async_trait]
+pub trait IcalFetcher: Send + Sync + 'static {
+    /// Lädt den Kalender hinter `url` und liefert ihn als Text.
+    async fn fetch(&self, url: &str) -> Result<Arc<str>, IcalFetchError>;
+}
+
+/// `webcal://` → `https://`; nur `http(s)` ist erlaubt.
+fn normalize_url(url: &str) -> Result<String, IcalFetchError> {
+    let url = url.trim();
+    if let Some(rest) = url.strip_prefix("webcal://") {
+        return Ok(format!("https://{rest}"));
+    }
+    if url.starts_with("https://") || url.starts_with("http://") {
+        return Ok(url.to_string());
+    }
+    Err(IcalFetchError::UnsupportedUrl(url.into()))
+}
+
+/// Öffentlich erreichbare Adresse? Abgelehnt werden Loopback, Link-Local,
+/// private Netze (inkl. CGNAT und IPv6 Unique Local) sowie unspezifizierte,
+/// Broadcast- und Dokumentationsadressen.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || a == 0
+        || (a == 100 && (64..128).contains(&b)))
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// Erlaubte Zieladressen: öffentliche plus die explizit freigegebenen.
+#[derive(Clone, Debug, Default)]
+struct AddressPolicy {
+    allowed: Arc<[IpAddr]>,
+}
+
+impl AddressPolicy {
+    fn permits(&self, ip: IpAddr) -> bool {
+        is_public(ip) || self.allowed.contains(&ip)
+    }
+}
+
+/// Resolver, der nur erlaubte Adressen herausgibt. Der Client verbindet sich
+/// ausschließlich zu dem, was hier durchkommt.
+struct FilteringResolver {
+    policy: AddressPolicy,
+}
+
+impl Resolve for FilteringResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let policy = self.policy.clone();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| policy.permits(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(IcalFetchError::ForbiddenAddress.into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct IcalClient {
+    client: reqwest::Client,
+    policy: AddressPolicy,
+}
+
+impl fmt::Debug for IcalClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IcalClient").finish_non_exhaustive()
+    }
+}
+
+impl IcalClient {
+    pub fn new() -> Result<Self, reqwest::Error> {
+        Self::with_allowed_addresses([])
+    }
+
+    /// Wie [`IcalClient::new`], gibt aber zusätzlich die Adressen in
+    /// `allowed` frei, z. B. einen lokalen Testserver.
+    pub fn with_allowed_addresses(
+        allowed: impl Into<Arc<[IpAddr]>>,
+    ) -> Result<Self, reqwest::Error> {
+        let policy = AddressPolicy {
+            allowed: allowed.into(),
+        };
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("shifty-ical-import/", env!("CARGO_PKG_VERSION")))
+            .redirect(Policy::none())
+            .dns_resolver(Arc::new(FilteringResolver {
+                policy: policy.clone(),
+            }))
+            .build()?;
+        Ok(Self { client, policy })
+    }
+
+    /// IP-Literale in der URL laufen nicht durch den Resolver und werden
+    /// deshalb hier geprüft.
+    fn check_literal_host(&self, url: &Url) -> Result<(), IcalFetchError> {
+        let Some(host) = url.host_str() else {
+            return Err(IcalFetchError::UnsupportedUrl(url.as_str().into()));
+        };
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let Ok(ip) = host.parse::<IpAddr>() else {
+            return Ok(());
+        };
+        if self.policy.permits(ip) {
+            Ok(())
+        } else {
+            Err(IcalFetchError::ForbiddenAddress)
+        }
+    }
+}
+
+/// Bildet einen `reqwest`-Fehler auf den Fehler für den Benutzer ab; die
+/// Ursache wird nur geloggt.
+fn network_error(err: reqwest::Error) -> IcalFetchError {
+    let mut source = err.source();
+    while let Some(cause) = source {
+        if let Some(IcalFetchError::ForbiddenAddress) = cause.downcast_ref() {
+            return IcalFetchError::ForbiddenAddress;
+        }
+        source = cause.source();
+    }
+    warn!("iCal fetch failed: {err}");
+    IcalFetchError::Network
+}
+
+#[async_trait]
+impl IcalFetcher for IcalClient {
+    async fn fetch(&self, url: &str) -> Result<Arc<str>, IcalFetchError> {
+        let url = normalize_url(url)?;
+        let url = Url::parse(&url).map_err(|_| IcalFetchError::UnsupportedUrl(url.into()))?;
+        self.check_literal_host(&url)?;
+        let mut response = self.client.get(url).send().await.map_err(network_error)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(IcalFetchError::Status(status.as_u16()));
+        }
+        if response
+            .content_length()
+            .is_some_and(|length| length as usize > MAX_CALENDAR_BYTES)
+        {
+            return Err(IcalFetchError::TooLarge);
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(network_error)? {
+            body.extend_from_slice(&chunk);
+            if body.len() > MAX_CALENDAR_BYTES {
+                return Err(IcalFetchError::TooLarge);
+            }
+        }
+        Ok(String::from_utf8_lossy(&body).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nEND:VCALENDAR\r\n";
+
+    /// Der Mock-Server lauscht auf Loopback und muss freigegeben werden.
+    fn local_client() -> IcalClient {
+        IcalClient::with_allowed_addresses([IpAddr::V4(Ipv4Addr::LOCALHOST)]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetches_calendar_from_served_url() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/anna.ics"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "text/calendar")
+                    .set_body_string(CALENDAR),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let body = local_client()
+            .fetch(&format!("{}/anna.ics", server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(body.as_ref(), CALENDAR);
+    }
+
+    #[tokio::test]
+    async fn non_success_status_is_an_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let result = local_client()
+            .fetch(&format!("{}/missing.ics", server.uri()))
+            .await;
+
+        assert!(matches!(result, Err(IcalFetchError::Status(404))));
+    }
+
+    #[tokio::test]
+    async fn oversized_calendar_is_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("X".repeat(MAX_CALENDAR_BYTES + 1)),
+            )
+            .mount(&server)
+            .await;
+
+        let result = local_client()
+            .fetch(&format!("{}/huge.ics", server.uri()))
+            .await;
+
+        assert!(matches!(result, Err(IcalFetchError::TooLarge)));
+    }
+
+    #[tokio::test]
+    async fn unsupported_scheme_is_rejected_without_request() {
+        let result = IcalClient::new().unwrap().fetch("file:///etc/passwd").await;
+
+        assert!(matches!(result, Err(IcalFetchError::UnsupportedUrl(_))));
+    }
+
+    #[tokio::test]
+    async fn loopback_is_rejected_without_allow_list() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CALENDAR))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let client = IcalClient::new().unwrap();
+
+        let literal = client.fetch(&format!("{}/anna.ics", server.uri())).await;
+        let resolved = client
+            .fetch(&format!(
+                "http://localhost:{}/anna.ics",
+                server.address().port()
+            ))
+            .await;
+
+        assert!(matches!(literal, Err(IcalFetchError::ForbiddenAddress)));
+        assert!(matches!(resolved, Err(IcalFetchError::ForbiddenAddress)));
+    }
+
+    #[tokio::test]
+    async fn redirects_are_not_followed() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/anna.ics"))
+            .respond_with(
+                ResponseTemplate::new(302).insert_header("Location", "http://169.254.169.254/"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = local_client()
+            .fetch(&format!("{}/anna.ics", server.uri()))
+            .await;
+
+        assert!(matches!(result, Err(IcalFetchError::Status(302))));
+    }
+
+    #[tokio::test]
+    async fn network_error_does_not_leak_details() {
+        // Port 9 (discard) ist auf Loopback praktisch nie belegt.
+        let result = local_client().fetch("http://127.0.0.1:9/anna.ics").await;
+
+        let err = result.unwrap_err();
+        assert!(matches!(err, IcalFetchError::Network));
+        assert_eq!(err.to_string(), "calendar could not be fetched");
+    }
+
+    #[test]
+    fn internal_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "::",
+            "fc00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in ["93.184.216.34", "1.1.1.1", "2606:4700:4700::1111"] {
+            assert!(is_public(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn webcal_is_fetched_via_https() {
+        assert_eq!(
+            normalize_url("webcal://example.com/cal.ics").unwrap(),
+            "https://example.com/cal.ics"
+        );
+        assert_eq!(
+            normalize_url(" http://example.com/cal.ics ").unwrap(),
+            "http://example.com/cal.ics"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Implementation von [`service::ical_feed_token::IcalFeedTokenService`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    ical_feed_token::{IcalFeedTokenDao, IcalFeedTokenEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    block::BlockService,
+    clock::ClockService,
+    ical_feed_token::{IcalFeedScope, IcalFeedToken, IcalFeedTokenService},
+    permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::SalesPersonService,
+    shiftplan_catalog::ShiftplanService,
+    team_ical::TeamIcalService,
+    uuid_service::UuidService,
+    PermissionService, ServiceError,
+};
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+const ICAL_FEED_TOKEN_SERVICE_PROCESS: &str = "ical-feed-token-service";
+
+gen_service_impl! {
+    struct IcalFeedTokenServiceImpl: IcalFeedTokenService = IcalFeedTokenServiceDeps {
+        IcalFeedTokenDao: IcalFeedTokenDao<Transaction = Self::Transaction> = ical_feed_token_dao,
+        BlockService: BlockService<Context = Self::Context, Transaction = Self::Transaction> = block_service,
+        TeamIcalService: TeamIcalService<Context = Self::Context, Transaction = Self::Transaction> = team_ical_service,
+        ShiftplanService: ShiftplanService<Context = Self::Context, Transaction = Self::Transaction> = shiftplan_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: IcalFeedTokenServiceDeps> IcalFeedTokenServiceImpl<Deps> {
+    /// Eigene Tokens verwaltet der Mitarbeiter selbst oder ein Shiftplanner,
+    /// Schichtplan-Tokens ein Shiftplanner, Team-Abwesenheiten HR.
+    async fn check_scope_permission(
+        &self,
+        scope: &IcalFeedScope,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        match scope {
+            IcalFeedScope::SalesPerson(sales_person_id) => {
+                let (shiftplanner_permission, is_sales_person) = join!(
+                    self.permission_service
+                        .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+                    self.sales_person_service.verify_user_is_sales_person(
+                        *sales_person_id,
+                        context,
+                        tx.into()
+                    ),
+                );
+                shiftplanner_permission.or(is_sales_person)
+            }
+            IcalFeedScope::Shiftplan(_) => {
+                self.permission_service
+                    .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+                    .await
+            }
+            IcalFeedScope::TeamAbsences => {
+                self.permission_service
+                    .check_permission(HR_PRIVILEGE, context)
+                    .await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<Deps: IcalFeedTokenServiceDeps> IcalFeedTokenService for IcalFeedTokenServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_for_scope(
+        &self,
+        scope: IcalFeedScope,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[IcalFeedToken]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_scope_permission(&scope, context, tx.clone())
+            .await?;
+        let tokens = self
+            .ical_feed_token_dao
+            .find_by_scope((&scope).into(), tx.clone())
+            .await?
+            .iter()
+            .map(IcalFeedToken::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(tokens)
+    }
+
+    async fn create(
+        &self,
+        scope: IcalFeedScope,
+        label: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalFeedToken, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_scope_permission(&scope, context.clone(), tx.clone())
+            .await?;
+        // Existenzprüfung; liefert EntityNotFound für unbekannte IDs.
+        match scope {
+            IcalFeedScope::SalesPerson(sales_person_id) => {
+                self.sales_person_service
+                    .get(sales_person_id, Authentication::Full, tx.clone().into())
+                    .await?;
+            }
+            IcalFeedScope::Shiftplan(shiftplan_id) => {
+                self.shiftplan_service
+                    .get_by_id(shiftplan_id, Authentication::Full, tx.clone().into())
+                    .await?;
+            }
+            IcalFeedScope::TeamAbsences => {}
+        }
+
+        let entity = IcalFeedTokenEntity {
+            id: self
+                .uuid_service
+                .new_uuid(&format!("{ICAL_FEED_TOKEN_SERVICE_PROCESS}::create id")),
+            scope: (&scope).into(),
+            token: self
+                .uuid_service
+                .new_uuid(&format!("{ICAL_FEED_TOKEN_SERVICE_PROCESS}::create token")),
+            label: Arc::from(label.trim()),
+            created: self.clock_service.date_time_now(),
+            revoked: None,
+            version: self.uuid_service.new_uuid(&format!(
+                "{ICAL_FEED_TOKEN_SERVICE_PROCESS}::create version"
+            )),
+        };
+        self.ical_feed_token_dao
+            .create(&entity, ICAL_FEED_TOKEN_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = IcalFeedToken::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::ICAL_FEED_TOKEN, created.id, &created)?,
+                ICAL_FEED_TOKEN_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn revoke(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalFeedToken, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .ical_feed_token_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        self.check_scope_permission(
+            &IcalFeedScope::from(&persisted.scope),
+            context.clone(),
+            tx.clone(),
+        )
+        .await?;
+        if persisted.revoked.is_some() {
+            self.transaction_dao.commit(tx).await?;
+            return Ok(IcalFeedToken::from(&persisted));
+        }
+
+        let before = IcalFeedToken::from(&persisted);
+        let entity = IcalFeedTokenEntity {
+            revoked: Some(self.clock_service.date_time_now()),
+            version: self.uuid_service.new_uuid(&format!(
+                "{ICAL_FEED_TOKEN_SERVICE_PROCESS}::revoke version"
+            )),
+            ..persisted
+        };
+        self.ical_feed_token_dao
+            .update(&entity, ICAL_FEED_TOKEN_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let revoked = IcalFeedToken::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::ICAL_FEED_TOKEN, id, &before, &revoked)?,
+                ICAL_FEED_TOKEN_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(revoked)
+    }
+
+    async fn get_feed(
+        &self,
+        token: Uuid,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let feed_token = self
+            .ical_feed_token_dao
+            .find_by_token(token, tx.clone())
+            .await?
+            .filter(|feed_token| feed_token.revoked.is_none())
+            .ok_or(ServiceError::EntityNotFound(token))?;
+        // Das Token ersetzt die Anmeldung; es berechtigt genau zu dem Feed
+        // seines Scopes.
+        let ical = match IcalFeedScope::from(&feed_token.scope) {
+            IcalFeedScope::SalesPerson(sales_person_id) => {
+                self.block_service
+                    .get_blocks_for_next_weeks_as_ical(
+                        sales_person_id,
+                        Authentication::Full,
+                        tx.clone().into(),
+                    )
+                    .await?
+            }
+            IcalFeedScope::Shiftplan(shiftplan_id) => {
+                self.team_ical_service
+                    .get_shiftplan_as_ical(shiftplan_id, Authentication::Full, tx.clone().into())
+                    .await?
+            }
+            IcalFeedScope::TeamAbsences => {
+                self.team_ical_service
+                    .get_team_absences_as_ical(Authentication::Full, tx.clone().into())
+                    .await?
+            }
+        };
+        self.transaction_dao.commit(tx).await?;
+        Ok(ical)
+    }
+}
@@ -0,0 +1,532 @@
+//! Implementation von [`service::ical_import::IcalImportService`].
+//!
+//! Der Parser deckt den Teil von RFC 5545 ab, den Kalender-Exporte für
+//! einfache Termine schreiben: gefaltete Zeilen, `VEVENT` mit `UID`,
+//! `SUMMARY`, `DTSTART`/`DTEND` (Datum, Ortszeit mit oder ohne `TZID`,
+//! UTC), `STATUS`, `TRANSP` und `RRULE`/`RDATE`. Verschachtelte
+//! Komponenten wie `VALARM` werden übersprungen.
+
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    absence::{AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction},
+    clock::ClockService,
+    config::ConfigService,
+    ical_import::{
+        IcalImportEntry, IcalImportPreview, IcalImportResult, IcalImportService,
+        IcalImportSkipReason, IcalImportSkipped, IcalImportSource, IcalImportStatus,
+        IcalImportTarget,
+    },
+    permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::SalesPersonService,
+    sales_person_unavailable::{SalesPersonUnavailable, SalesPersonUnavailableService},
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use time::{macros::format_description, Date, PrimitiveDateTime, Time};
+use time_tz::{timezones, OffsetDateTimeExt, Tz};
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::ical_client::IcalFetcher;
+
+/// Längere Termine sind eher Serien-Platzhalter als Abwesenheiten.
+const MAX_EVENT_DAYS: i64 = 366;
+
+gen_service_impl! {
+    struct IcalImportServiceImpl: IcalImportService = IcalImportServiceDeps {
+        AbsenceService: AbsenceService<Context = Self::Context, Transaction = Self::Transaction> = absence_service,
+        SalesPersonUnavailableService: SalesPersonUnavailableService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_unavailable_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ConfigService: ConfigService = config_service,
+        ClockService: ClockService = clock_service,
+        IcalFetcher: IcalFetcher = ical_fetcher,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: IcalImportServiceDeps> IcalImportServiceImpl<Deps> {
+    /// Der Mitarbeiter selbst oder die Rolle, die das Ziel auch manuell
+    /// pflegen darf.
+    async fn check_permission(
+        &self,
+        sales_person_id: Uuid,
+        target: IcalImportTarget,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let privilege = match target {
+            IcalImportTarget::Unavailable => SHIFTPLANNER_PRIVILEGE,
+            IcalImportTarget::Absence(_) => HR_PRIVILEGE,
+        };
+        let (privileged, is_sales_person) = join!(
+            self.permission_service
+                .check_permission(privilege, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.into()
+            ),
+        );
+        privileged.or(is_sales_person)
+    }
+
+    async fn unavailable_days(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<HashSet<Date>, ServiceError> {
+        Ok(self
+            .sales_person_unavailable_service
+            .get_all_for_sales_person(sales_person_id, context, tx.into())
+            .await?
+            .iter()
+            .filter(|unavailable| unavailable.deleted.is_none())
+            .filter_map(|unavailable| {
+                Date::from_iso_week_date(
+                    unavailable.year as i32,
+                    unavailable.calendar_week,
+                    unavailable.day_of_week.into(),
+                )
+                .ok()
+            })
+            .collect())
+    }
+
+    async fn absences(
+        &self,
+        sales_person_id: Uuid,
+        target: IcalImportTarget,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<Vec<AbsencePeriod>, ServiceError> {
+        Ok(self
+            .absence_service
+            .find_by_sales_person(sales_person_id, context, tx.into())
+            .await?
+            .iter()
+            .filter(|absence| absence.deleted.is_none())
+            .filter(|absence| {
+                !matches!(
+                    absence.status,
+                    AbsenceStatus::Rejected | AbsenceStatus::Cancelled
+                )
+            })
+            .filter(|absence| target == IcalImportTarget::Absence(absence.category))
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<Deps: IcalImportServiceDeps> IcalImportService for IcalImportServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn preview(
+        &self,
+        sales_person_id: Uuid,
+        source: IcalImportSource,
+        target: IcalImportTarget,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalImportPreview, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_permission(sales_person_id, target, context.clone(), tx.clone())
+            .await?;
+
+        let content = match source {
+            IcalImportSource::Content(content) => content,
+            IcalImportSource::Url(url) => self.ical_fetcher.fetch(&url).await.map_err(|err| {
+                ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+                    format!("url: {err}").into(),
+                )]))
+            })?,
+        };
+        let events = parse_calendar(&content)?;
+        let config = self.config_service.get_config().await?;
+        let timezone = timezones::get_by_name(&config.timezone);
+        let today = self.clock_service.date_now();
+
+        let mut entries = vec![];
+        let mut skipped = vec![];
+        let mut ranges = vec![];
+        for event in events.iter() {
+            match event_range(event, timezone, today) {
+                Ok(range) => ranges.push((event, range)),
+                Err(reason) => skipped.push(IcalImportSkipped {
+                    uid: event.uid.clone(),
+                    summary: event.summary.clone(),
+                    reason,
+                }),
+            }
+        }
+
+        match target {
+            IcalImportTarget::Unavailable => {
+                let existing = self
+                    .unavailable_days(sales_person_id, context, tx.clone())
+                    .await?;
+                for (event, (from_date, to_date)) in ranges {
+                    for day in days(from_date.max(today), to_date) {
+                        entries.push(IcalImportEntry {
+                            uid: event.uid.clone(),
+                            summary: event.summary.clone(),
+                            from_date: day,
+                            to_date: day,
+                            status: if existing.contains(&day) {
+                                IcalImportStatus::AlreadyPresent
+                            } else {
+                                IcalImportStatus::New
+                            },
+                        });
+                    }
+                }
+            }
+            IcalImportTarget::Absence(_) => {
+                let existing = self
+                    .absences(sales_person_id, target, context, tx.clone())
+                    .await?;
+                for (event, (from_date, to_date)) in ranges {
+                    let status = if existing
+                        .iter()
+                        .any(|absence| absence.from_date == from_date && absence.to_date == to_date)
+                    {
+                        IcalImportStatus::AlreadyPresent
+                    } else if let Some(absence) = existing.iter().find(|absence| {
+                        absence.from_date <= to_date && absence.to_date >= from_date
+                    }) {
+                        IcalImportStatus::Conflict(absence.id)
+                    } else {
+                        IcalImportStatus::New
+                    };
+                    entries.push(IcalImportEntry {
+                        uid: event.uid.clone(),
+                        summary: event.summary.clone(),
+                        from_date,
+                        to_date,
+                        status,
+                    });
+                }
+            }
+        }
+        entries.sort_by(|a, b| (a.from_date, &a.uid).cmp(&(b.from_date, &b.uid)));
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(IcalImportPreview {
+            sales_person_id,
+            target,
+            entries: entries.into(),
+            skipped: skipped.into(),
+        })
+    }
+
+    async fn apply(
+        &self,
+        sales_person_id: Uuid,
+        target: IcalImportTarget,
+        entries: &[IcalImportEntry],
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<IcalImportResult, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_permission(sales_person_id, target, context.clone(), tx.clone())
+            .await?;
+
+        let mut unavailables = vec![];
+        let mut absences = vec![];
+        let mut warnings = vec![];
+        match target {
+            IcalImportTarget::Unavailable => {
+                let existing = self
+                    .unavailable_days(sales_person_id, context.clone(), tx.clone())
+                    .await?;
+                let new_days: BTreeSet<Date> = entries
+                    .iter()
+                    .flat_map(|entry| days(entry.from_date, entry.to_date))
+                    .filter(|day| !existing.contains(day))
+                    .collect();
+                for day in new_days {
+                    let (year, calendar_week, weekday) = day.to_iso_week_date();
+                    unavailables.push(
+                        self.sales_person_unavailable_service
+                            .create(
+                                &SalesPersonUnavailable {
+                                    id: Uuid::nil(),
+                                    sales_person_id,
+                                    year: year as u32,
+                                    calendar_week,
+                                    day_of_week: weekday.into(),
+                                    created: None,
+                                    deleted: None,
+                                    version: Uuid::nil(),
+                                },
+                                context.clone(),
+                                tx.clone().into(),
+                            )
+                            .await?,
+                    );
+                }
+            }
+            IcalImportTarget::Absence(category) => {
+                let existing = self
+                    .absences(sales_person_id, target, context.clone(), tx.clone())
+                    .await?;
+                for entry in entries.iter().filter(|entry| {
+                    !existing.iter().any(|absence| {
+                        absence.from_date == entry.from_date && absence.to_date == entry.to_date
+                    })
+                }) {
+                    let result = self
+                        .absence_service
+                        .create(
+                            &AbsencePeriod {
+                                id: Uuid::nil(),
+                                sales_person_id,
+                                category,
+                                from_date: entry.from_date,
+                                to_date: entry.to_date,
+                                description: entry.summary.clone(),
+                                created: None,
+                                deleted: None,
+                                version: Uuid::nil(),
+                                day_fraction: DayFraction::Full,
+                                // Setzt der AbsenceService (HR → genehmigt,
+                                // sonst Antrag).
+                                status: AbsenceStatus::Requested,
+                            },
+                            context.clone(),
+                            tx.clone().into(),
+                        )
+                        .await?;
+                    absences.push(result.absence);
+                    warnings.extend(result.warnings.iter().cloned());
+                }
+            }
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(IcalImportResult {
+            unavailables: unavailables.into(),
+            absences: absences.into(),
+            warnings: warnings.into(),
+        })
+    }
+}
+
+fn days(from_date: Date, to_date: Date) -> impl Iterator<Item = Date> {
+    std::iter::successors(Some(from_date), |day| day.next_day())
+        .take_while(move |day| *day <= to_date)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IcalValue {
+    Date(Date),
+    /// Ortszeit, mit `TZID` oder "floating".
+    Local(PrimitiveDateTime),
+    Utc(PrimitiveDateTime),
+}
+
+impl IcalValue {
+    /// Zeitpunkt in der konfigurierten Zeitzone; ganztägig = Mitternacht.
+    fn local(self, timezone: Option<&Tz>) -> PrimitiveDateTime {
+        match self {
+            IcalValue::Date(date) => PrimitiveDateTime::new(date, Time::MIDNIGHT),
+            IcalValue::Local(date_time) => date_time,
+            IcalValue::Utc(date_time) => match timezone {
+                Some(timezone) => {
+                    let local = date_time.assume_utc().to_timezone(timezone);
+                    PrimitiveDateTime::new(local.date(), local.time())
+                }
+                None => date_time,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct ParsedEvent {
+    uid: Arc<str>,
+    summary: Arc<str>,
+    start: Option<IcalValue>,
+    end: Option<IcalValue>,
+    recurring: bool,
+    cancelled: bool,
+    transparent: bool,
+}
+
+fn invalid_calendar(reason: &str) -> ServiceError {
+    ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+        format!("ics: {reason}").into(),
+    )]))
+}
+
+/// Zerlegt den Kalender in seine `VEVENT`s.
+fn parse_calendar(content: &str) -> Result<Vec<ParsedEvent>, ServiceError> {
+    let mut lines: Vec<String> = vec![];
+    for line in content.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(previous)) => previous.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(invalid_calendar("no VCALENDAR"));
+    }
+
+    let mut events = vec![];
+    let mut current: Option<ParsedEvent> = None;
+    let mut nested = 0usize;
+    for line in lines.iter().filter(|line| !line.is_empty()) {
+        let Some((name, params, value)) = split_property(line) else {
+            continue;
+        };
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(ParsedEvent {
+                    uid: format!("event-{}", events.len() + 1).into(),
+                    ..ParsedEvent::default()
+                });
+            }
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                events.extend(current.take());
+            }
+            (_, Some(_)) if nested > 0 => {}
+            ("UID", Some(event)) => event.uid = unescape(value).into(),
+            ("SUMMARY", Some(event)) => event.summary = unescape(value).into(),
+            ("DTSTART", Some(event)) => event.start = parse_value(&params, value),
+            ("DTEND", Some(event)) => event.end = parse_value(&params, value),
+            ("RRULE" | "RDATE", Some(event)) => event.recurring = true,
+            ("STATUS", Some(event)) => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            ("TRANSP", Some(event)) => {
+                event.transparent = value.eq_ignore_ascii_case("TRANSPARENT")
+            }
+            _ => {}
+        }
+    }
+    Ok(events)
+}
+
+/// Property-Parameter als (NAME, Wert), Namen in Großbuchstaben.
+type Params = Vec<(String, String)>;
+
+/// `NAME;PARAM=x;PARAM2="a:b":VALUE` → (NAME, Parameter, VALUE).
+fn split_property(line: &str) -> Option<(String, Params, &str)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(index, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(index),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| {
+            (
+                key.trim().to_ascii_uppercase(),
+                value.trim_matches('"').to_string(),
+            )
+        })
+        .collect();
+    Some((name, params, value))
+}
+
+fn parse_value(params: &[(String, String)], value: &str) -> Option<IcalValue> {
+    let value = value.trim();
+    let is_date = params
+        .iter()
+        .any(|(key, param)| key == "VALUE" && param.eq_ignore_ascii_case("DATE"))
+        || value.len() == 8;
+    if is_date {
+        return Date::parse(value, format_description!("[year][month][day]"))
+            .ok()
+            .map(IcalValue::Date);
+    }
+    let (value, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(value) => (value, true),
+        None => (value, false),
+    };
+    let date_time = PrimitiveDateTime::parse(
+        value,
+        format_description!("[year][month][day]T[hour][minute][second]"),
+    )
+    .ok()?;
+    Some(if utc {
+        IcalValue::Utc(date_time)
+    } else {
+        IcalValue::Local(date_time)
+    })
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result
+}
+
+/// Erster und letzter betroffener Tag (inklusive) oder der Grund, den
+/// Termin zu überspringen. `DTEND` ist exklusiv: ganztägig endet der Termin
+/// am Vortag, ein Termin bis Mitternacht ebenso.
+fn event_range(
+    event: &ParsedEvent,
+    timezone: Option<&Tz>,
+    today: Date,
+) -> Result<(Date, Date), IcalImportSkipReason> {
+    if event.recurring {
+        return Err(IcalImportSkipReason::Recurring);
+    }
+    if event.cancelled {
+        return Err(IcalImportSkipReason::Cancelled);
+    }
+    if event.transparent {
+        return Err(IcalImportSkipReason::Free);
+    }
+    let start = event
+        .start
+        .ok_or(IcalImportSkipReason::InvalidDates)?
+        .local(timezone);
+    let end = event.end.map(|end| end.local(timezone)).unwrap_or(start);
+    if end < start {
+        return Err(IcalImportSkipReason::InvalidDates);
+    }
+    let from_date = start.date();
+    let to_date = if end.time() == Time::MIDNIGHT && end > start {
+        end.date().previous_day().unwrap_or(from_date)
+    } else {
+        end.date()
+    };
+    if to_date < today {
+        return Err(IcalImportSkipReason::Past);
+    }
+    if (to_date - from_date).whole_days() >= MAX_EVENT_DAYS {
+        return Err(IcalImportSkipReason::TooLong);
+    }
+    Ok((from_date, to_date))
+}
@@ -0,0 +1,265 @@
+//! SMTP-Versand für die E-Mail-Benachrichtigungen.
+//!
+//! Analog zu [`crate::webdav_client`]: ein schlanker Trait
+//! ([`MailTransport`]), den der `NotificationServiceImpl` als Dependency
+//! bekommt, plus die Produktions-Implementierung [`SmtpMailTransport`] auf
+//! Basis von `lettre`. Tests injizieren `MockMailTransport`.
+//!
+//! Konfiguration über Umgebungsvariablen:
+//!
+//! | Variable        | Default     | Bedeutung                                   |
+//! |-----------------|-------------|---------------------------------------------|
+//! | `SMTP_HOST`     | —           | Ohne Wert ist der Versand deaktiviert.      |
+//! | `SMTP_PORT`     | je nach TLS | 25 (`none`), 587 (`starttls`), 465 (`tls`)  |
+//! | `SMTP_TLS`      | `starttls`  | `none` \| `starttls` \| `tls`               |
+//! | `SMTP_USERNAME` | —           | Optional, zusammen mit `SMTP_PASSWORD`.     |
+//! | `SMTP_PASSWORD` | —           |                                             |
+//! | `SMTP_FROM`     | —           | Pflicht, sobald `SMTP_HOST` gesetzt ist.    |
+//!
+//! Für lokale Tests genügt z. B. ein Mailpit/MailHog mit
+//! `SMTP_HOST=localhost SMTP_PORT=1025 SMTP_TLS=none`.
+
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use mockall::automock;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailError {
+    #[error("smtp is not configured")]
+    NotConfigured,
+
+    #[error("invalid smtp configuration: {0}")]
+    InvalidConfig(Arc<str>),
+
+    #[error("invalid mail: {0}")]
+    InvalidMessage(Arc<str>),
+
+    #[error("smtp error: {0}")]
+    Smtp(Arc<str>),
+}
+
+#[automock]
+#[async_trait]
+pub trait MailTransport: Send + Sync + 'static {
+    /// `false`, wenn kein SMTP-Server konfiguriert ist. Der Versand-Job lässt
+    /// die Outbox dann unangetastet.
+    fn is_configured(&self) -> bool;
+
+    /// Verschickt eine Text-Mail an `to`.
+    async fn send_mail(&self, to: &str, subject: &str, body: &str) -> Result<(), MailError>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SmtpTls {
+    None,
+    StartTls,
+    Tls,
+}
+
+impl SmtpTls {
+    fn default_port(&self) -> u16 {
+        match self {
+            SmtpTls::None => 25,
+            SmtpTls::StartTls => 587,
+            SmtpTls::Tls => 465,
+        }
+    }
+}
+
+impl TryFrom<&str> for SmtpTls {
+    type Error = MailError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "none" => Ok(SmtpTls::None),
+            "starttls" => Ok(SmtpTls::StartTls),
+            "tls" => Ok(SmtpTls::Tls),
+            other => Err(MailError::InvalidConfig(
+                format!("SMTP_TLS must be none, starttls or tls, got {other}").into(),
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SmtpConfig {
+    pub host: Arc<str>,
+    pub port: u16,
+    pub tls: SmtpTls,
+    pub username: Option<Arc<str>>,
+    pub password: Option<Arc<str>>,
+    pub from: Arc<str>,
+}
+
+impl fmt::Debug for SmtpConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmtpConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("tls", &self.tls)
+            .field("username", &self.username)
+            .field("from", &self.from)
+            // password intentionally omitted
+            .finish_non_exhaustive()
+    }
+}
+
+impl SmtpConfig {
+    /// Liest die `SMTP_*`-Variablen. `Ok(None)` ohne `SMTP_HOST`.
+    pub fn from_env() -> Result<Option<Self>, MailError> {
+        let Ok(host) = env::var("SMTP_HOST") else {
+            return Ok(None);
+        };
+        let tls = match env::var("SMTP_TLS") {
+            Ok(tls) => SmtpTls::try_from(tls.as_str())?,
+            Err(_) => SmtpTls::StartTls,
+        };
+        let port = match env::var("SMTP_PORT") {
+            Ok(port) => port.parse().map_err(|_| {
+                MailError::InvalidConfig(format!("SMTP_PORT is not a port: {port}").into())
+            })?,
+            Err(_) => tls.default_port(),
+        };
+        let from = env::var("SMTP_FROM")
+            .map_err(|_| MailError::InvalidConfig("SMTP_FROM is required".into()))?;
+        Ok(Some(Self {
+            host: host.into(),
+            port,
+            tls,
+            username: env::var("SMTP_USERNAME").ok().map(Arc::from),
+            password: env::var("SMTP_PASSWORD").ok().map(Arc::from),
+            from: from.into(),
+        }))
+    }
+}
+
+struct SmtpConnection {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+/// Produktions-Implementierung. Ohne Konfiguration liefert
+/// [`MailTransport::is_configured`] `false` und jeder Versand
+/// [`MailError::NotConfigured`].
+pub struct SmtpMailTransport {
+    connection: Option<SmtpConnection>,
+}
+
+impl fmt::Debug for SmtpMailTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmtpMailTransport")
+            .field("configured", &self.connection.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SmtpMailTransport {
+    pub fn new(config: Option<SmtpConfig>) -> Result<Self, MailError> {
+        let Some(config) = config else {
+            return Ok(Self { connection: None });
+        };
+        let from: Mailbox = config
+            .from
+            .parse()
+            .map_err(|e| MailError::InvalidConfig(format!("SMTP_FROM: {e}").into()))?;
+        let builder = match config.tls {
+            SmtpTls::None => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(config.host.as_ref())
+            }
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(|e| MailError::InvalidConfig(e.to_string().into()))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+                .map_err(|e| MailError::InvalidConfig(e.to_string().into()))?,
+        }
+        .port(config.port);
+        let builder = match (&config.username, &config.password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.to_string(), password.to_string()))
+            }
+            _ => builder,
+        };
+        Ok(Self {
+            connection: Some(SmtpConnection {
+                transport: builder.build(),
+                from,
+            }),
+        })
+    }
+
+    pub fn from_env() -> Result<Self, MailError> {
+        Self::new(SmtpConfig::from_env()?)
+    }
+}
+
+#[async_trait]
+impl MailTransport for SmtpMailTransport {
+    fn is_configured(&self) -> bool {
+        self.connection.is_some()
+    }
+
+    async fn send_mail(&self, to: &str, subject: &str, body: &str) -> Result<(), MailError> {
+        let connection = self.connection.as_ref().ok_or(MailError::NotConfigured)?;
+        let to: Mailbox = to
+            .parse()
+            .map_err(|e| MailError::InvalidMessage(format!("recipient {to}: {e}").into()))?;
+        let message = Message::builder()
+            .from(connection.from.clone())
+            .to(to)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|e| MailError::InvalidMessage(e.to_string().into()))?;
+        connection
+            .transport
+            .send(message)
+            .await
+            .map_err(|e| MailError::Smtp(e.to_string().into()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tls_parsing() {
+        assert_eq!(SmtpTls::try_from("none").unwrap(), SmtpTls::None);
+        assert_eq!(SmtpTls::try_from("STARTTLS").unwrap(), SmtpTls::StartTls);
+        assert_eq!(SmtpTls::try_from("tls").unwrap(), SmtpTls::Tls);
+        assert!(SmtpTls::try_from("ssl").is_err());
+    }
+
+    #[tokio::test]
+    async fn unconfigured_transport_refuses_to_send() {
+        let transport = SmtpMailTransport::new(None).unwrap();
+        assert!(!transport.is_configured());
+        assert!(matches!(
+            transport.send_mail("a@example.com", "s", "b").await,
+            Err(MailError::NotConfigured)
+        ));
+    }
+
+    #[test]
+    fn invalid_from_is_rejected() {
+        let config = SmtpConfig {
+            host: "localhost".into(),
+            port: 1025,
+            tls: SmtpTls::None,
+            username: None,
+            password: None,
+            from: "not an address".into(),
+        };
+        assert!(matches!(
+            SmtpMailTransport::new(Some(config)),
+            Err(MailError::InvalidConfig(_))
+        ));
+    }
+}
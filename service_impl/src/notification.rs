@@ -0,0 +1,647 @@
+//! Basic-Tier Implementation von [`service::notification::NotificationService`].
+//!
+//! Greift bewusst nur auf DAOs zu (Sales-Person, TextTemplate, Notification),
+//! damit Basic-Tier-Services wie `WeekStatusService` ihn als Dependency
+//! nutzen können. Rendering folgt dem Muster aus `block_report`: Tera oder
+//! MiniJinja je nach `template_engine`, fehlt ein Template oder ist es
+//! fehlerhaft, greift der eingebaute Default-Text.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    notification::{NotificationDao, NotificationOutboxEntity, NotificationPreferenceEntity},
+    sales_person::SalesPersonDao,
+    text_template::{TemplateEngineEntity, TextTemplateDao, TextTemplateEntity},
+    TransactionDao,
+};
+use service::{
+    absence::AbsenceCategory,
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    clock::ClockService,
+    config::ConfigService,
+    notification::{
+        NotificationDeliveryReport, NotificationEvent, NotificationKind, NotificationMessage,
+        NotificationPreference, NotificationService, NotificationState,
+    },
+    permission::{Authentication, HR_PRIVILEGE},
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::ShiftyDate;
+use time::{macros::format_description, Duration, PrimitiveDateTime};
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::mail_transport::MailTransport;
+
+const NOTIFICATION_SERVICE_PROCESS: &str = "notification-service";
+const ADMIN_PRIVILEGE: &str = "admin";
+
+/// Nach so vielen Fehlversuchen bleibt eine Nachricht `Failed`.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+/// Wartezeit vor dem zweiten Versuch; verdoppelt sich mit jedem weiteren.
+const RETRY_BASE_DELAY_MINUTES: i64 = 5;
+/// Maximale Anzahl Nachrichten pro Versand-Lauf.
+const DELIVERY_BATCH_SIZE: u32 = 50;
+const MAX_OUTBOX_LIMIT: u32 = 500;
+
+const DEFAULT_BOOKING_DELETED_TEMPLATE: &str = "\
+Deine Schicht am {{ date }} wurde entfernt
+Hallo {{ sales_person_name }},
+
+deine Schicht am {{ date }} von {{ from }} bis {{ to }} Uhr (KW {{ calendar_week }}/{{ year }}) wurde aus dem Schichtplan entfernt.
+
+{{ app_url }}
+";
+
+const DEFAULT_WEEK_PLANNED_TEMPLATE: &str = "\
+Schichtplan KW {{ calendar_week }}/{{ year }} ist fertig
+Hallo,
+
+der Schichtplan für KW {{ calendar_week }}/{{ year }} ist fertig geplant.
+
+{{ app_url }}
+";
+
+const DEFAULT_ABSENCE_CREATED_TEMPLATE: &str = "\
+Abwesenheit vom {{ from_date }} bis {{ to_date }} eingetragen
+Hallo {{ sales_person_name }},
+
+für dich wurde eine Abwesenheit ({{ category }}) vom {{ from_date }} bis {{ to_date }} eingetragen.
+
+{{ app_url }}
+";
+
+const DEFAULT_INVITATION_CREATED_TEMPLATE: &str = "\
+Einladung zu Shifty
+Hallo {{ username }},
+
+du wurdest zu Shifty eingeladen. Der Link ist bis {{ expiration_date }} gültig:
+
+{{ invitation_link }}
+";
+
+fn default_template(kind: NotificationKind) -> &'static str {
+    match kind {
+        NotificationKind::BookingDeleted => DEFAULT_BOOKING_DELETED_TEMPLATE,
+        NotificationKind::WeekPlanned => DEFAULT_WEEK_PLANNED_TEMPLATE,
+        NotificationKind::AbsenceCreated => DEFAULT_ABSENCE_CREATED_TEMPLATE,
+        NotificationKind::InvitationCreated => DEFAULT_INVITATION_CREATED_TEMPLATE,
+    }
+}
+
+fn category_label(category: AbsenceCategory) -> &'static str {
+    match category {
+        AbsenceCategory::Vacation => "Urlaub",
+        AbsenceCategory::SickLeave => "Krankheit",
+        AbsenceCategory::UnpaidLeave => "Unbezahlter Urlaub",
+    }
+}
+
+fn template_error(e: impl std::fmt::Display) -> ServiceError {
+    ServiceError::ValidationError(Arc::new([ValidationFailureItem::InvalidValue(Arc::from(
+        format!("Template rendering error: {}", e),
+    ))]))
+}
+
+fn render_template(
+    engine: &TemplateEngineEntity,
+    text: &str,
+    context: &serde_json::Value,
+) -> Result<String, ServiceError> {
+    match engine {
+        TemplateEngineEntity::Tera => {
+            let tera_context = tera::Context::from_serialize(context).map_err(template_error)?;
+            let mut tera = tera::Tera::default();
+            tera.render_str(text, &tera_context).map_err(template_error)
+        }
+        TemplateEngineEntity::MiniJinja => {
+            let env = minijinja::Environment::new();
+            env.render_str(text, context).map_err(template_error)
+        }
+    }
+}
+
+/// Erste Zeile = Betreff, der Rest (ohne führende Leerzeilen) = Text.
+fn split_subject(rendered: &str) -> (Arc<str>, Arc<str>) {
+    let (subject, body) = rendered.split_once('\n').unwrap_or((rendered, ""));
+    (
+        Arc::from(subject.trim()),
+        Arc::from(body.trim_start_matches(['\r', '\n'])),
+    )
+}
+
+/// Rendert Betreff und Text. Ein fehlerhaftes Template darf die auslösende
+/// Änderung nicht blockieren, deshalb wird dann der Default-Text verwendet.
+pub fn render_notification(
+    kind: NotificationKind,
+    template: Option<&TextTemplateEntity>,
+    context: &serde_json::Value,
+) -> Result<(Arc<str>, Arc<str>), ServiceError> {
+    if let Some(template) = template {
+        match render_template(&template.template_engine, &template.template_text, context) {
+            Ok(rendered) => return Ok(split_subject(&rendered)),
+            Err(e) => warn!(
+                "Notification template {} failed, falling back to default: {:?}",
+                template.id, e
+            ),
+        }
+    }
+    let rendered = render_template(
+        &TemplateEngineEntity::MiniJinja,
+        default_template(kind),
+        context,
+    )?;
+    Ok(split_subject(&rendered))
+}
+
+fn validate_preference(preference: &NotificationPreference) -> Result<(), ServiceError> {
+    let mut errors = Vec::new();
+    if preference.user_id.trim().is_empty() {
+        errors.push(ValidationFailureItem::InvalidValue("user_id".into()));
+    }
+    let email = preference.email.trim();
+    if email.is_empty() || !email.contains('@') || email.contains(char::is_whitespace) {
+        errors.push(ValidationFailureItem::InvalidValue("email".into()));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ServiceError::ValidationError(errors.into()))
+    }
+}
+
+gen_service_impl! {
+    struct NotificationServiceImpl: NotificationService = NotificationServiceDeps {
+        NotificationDao: NotificationDao<Transaction = Self::Transaction> = notification_dao,
+        TextTemplateDao: TextTemplateDao<Transaction = Self::Transaction> = text_template_dao,
+        SalesPersonDao: SalesPersonDao<Transaction = Self::Transaction> = sales_person_dao,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        ConfigService: ConfigService = config_service,
+        MailTransport: MailTransport = mail_transport,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: NotificationServiceDeps> NotificationServiceImpl<Deps> {
+    async fn check_hr_or_self(
+        &self,
+        user_id: &str,
+        context: Authentication<Deps::Context>,
+    ) -> Result<(), ServiceError> {
+        let hr = self
+            .permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await;
+        if hr.is_ok() {
+            return Ok(());
+        }
+        self.permission_service.check_user(user_id, context).await
+    }
+
+    /// Einstellung des Benutzers, der einer Sales-Person zugeordnet ist.
+    async fn preference_of_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<Option<NotificationPreferenceEntity>, ServiceError> {
+        let Some(user_id) = self
+            .sales_person_dao
+            .get_assigned_user(sales_person_id, tx.clone())
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(self
+            .notification_dao
+            .find_preference_by_user_id(&user_id, tx)
+            .await?)
+    }
+
+    async fn sales_person_name(
+        &self,
+        sales_person_id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<Arc<str>, ServiceError> {
+        Ok(self
+            .sales_person_dao
+            .find_by_id(sales_person_id, tx)
+            .await?
+            .map(|sales_person| sales_person.name)
+            .unwrap_or_else(|| Arc::from("")))
+    }
+
+    /// Empfänger und Template-Kontext eines Ereignisses.
+    async fn recipients_and_context(
+        &self,
+        event: &NotificationEvent,
+        app_url: &str,
+        tx: Deps::Transaction,
+    ) -> Result<(Vec<NotificationPreferenceEntity>, serde_json::Value), ServiceError> {
+        let date_format = format_description!("[day].[month].[year]");
+        let time_format = format_description!("[hour]:[minute]");
+        Ok(match event {
+            NotificationEvent::BookingDeleted {
+                sales_person_id,
+                year,
+                calendar_week,
+                day_of_week,
+                from,
+                to,
+            } => {
+                let recipients = self
+                    .preference_of_sales_person(*sales_person_id, tx.clone())
+                    .await?;
+                let date = ShiftyDate::new(*year, *calendar_week, *day_of_week)?.to_date();
+                let context = serde_json::json!({
+                    "sales_person_name": self.sales_person_name(*sales_person_id, tx).await?.as_ref(),
+                    "year": year,
+                    "calendar_week": calendar_week,
+                    "day_of_week": day_of_week.to_string(),
+                    "date": date.format(date_format)?,
+                    "from": from.format(time_format)?,
+                    "to": to.format(time_format)?,
+                    "app_url": app_url,
+                });
+                (recipients.into_iter().collect(), context)
+            }
+            NotificationEvent::WeekPlanned {
+                year,
+                calendar_week,
+            } => {
+                let recipients = self.notification_dao.all_preferences(tx).await?;
+                let context = serde_json::json!({
+                    "year": year,
+                    "calendar_week": calendar_week,
+                    "app_url": app_url,
+                });
+                (recipients.iter().cloned().collect(), context)
+            }
+            NotificationEvent::AbsenceCreated {
+                sales_person_id,
+                category,
+                from_date,
+                to_date,
+            } => {
+                let recipients = self
+                    .preference_of_sales_person(*sales_person_id, tx.clone())
+                    .await?;
+                let context = serde_json::json!({
+                    "sales_person_name": self.sales_person_name(*sales_person_id, tx).await?.as_ref(),
+                    "category": category_label(*category),
+                    "from_date": from_date.format(date_format)?,
+                    "to_date": to_date.format(date_format)?,
+                    "app_url": app_url,
+                });
+                (recipients.into_iter().collect(), context)
+            }
+            NotificationEvent::InvitationCreated {
+                username,
+                email,
+                token,
+                expiration_date,
+            } => {
+                let recipients = self
+                    .notification_dao
+                    .find_preference_by_user_id(username, tx)
+                    .await?
+                    .or_else(|| {
+                        email.as_ref().map(|email| {
+                            invitation_recipient(
+                                username,
+                                email,
+                                self.clock_service.date_time_now(),
+                            )
+                        })
+                    });
+                let context = serde_json::json!({
+                    "username": username.as_ref(),
+                    "invitation_link": format!("{}/auth/invitation/{}", app_url, token),
+                    "expiration_date": expiration_date.format(date_format)?,
+                    "app_url": app_url,
+                });
+                (recipients.into_iter().collect(), context)
+            }
+        })
+    }
+
+    async fn deliver_one(
+        &self,
+        message: NotificationOutboxEntity,
+    ) -> Result<NotificationState, ServiceError> {
+        let result = self
+            .mail_transport
+            .send_mail(&message.recipient_email, &message.subject, &message.body)
+            .await;
+        let now = self.clock_service.date_time_now();
+        let attempts = message.attempts + 1;
+        let entity = match result {
+            Ok(()) => NotificationOutboxEntity {
+                state: dao::notification::NotificationState::Sent,
+                attempts,
+                last_error: None,
+                sent: Some(now),
+                ..message
+            },
+            Err(e) => {
+                warn!("Notification {} could not be delivered: {}", message.id, e);
+                let state = if attempts >= MAX_DELIVERY_ATTEMPTS {
+                    dao::notification::NotificationState::Failed
+                } else {
+                    dao::notification::NotificationState::Pending
+                };
+                NotificationOutboxEntity {
+                    state,
+                    attempts,
+                    next_attempt: next_attempt(now, attempts),
+                    last_error: Some(Arc::from(e.to_string())),
+                    ..message
+                }
+            }
+        };
+        let entity = NotificationOutboxEntity {
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{NOTIFICATION_SERVICE_PROCESS}::deliver version")),
+            ..entity
+        };
+
+        let tx = self.transaction_dao.use_transaction(None).await?;
+        self.notification_dao
+            .update_outbox(&entity, NOTIFICATION_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(entity.state.into())
+    }
+}
+
+/// Empfänger einer Einladung ohne eigene Einstellungen: die beim Einladen
+/// angegebene Adresse, nur für `InvitationCreated` abonniert.
+fn invitation_recipient(
+    username: &Arc<str>,
+    email: &Arc<str>,
+    now: PrimitiveDateTime,
+) -> NotificationPreferenceEntity {
+    NotificationPreferenceEntity {
+        user_id: username.clone(),
+        email: email.clone(),
+        booking_deleted: false,
+        week_planned: false,
+        absence_created: false,
+        invitation_created: true,
+        created: now,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+/// Exponentielles Backoff: 5, 10, 20, … Minuten nach dem n-ten Fehlversuch.
+fn next_attempt(now: PrimitiveDateTime, attempts: u32) -> PrimitiveDateTime {
+    let exponent = attempts.saturating_sub(1).min(10);
+    now + Duration::minutes(RETRY_BASE_DELAY_MINUTES << exponent)
+}
+
+#[async_trait]
+impl<Deps: NotificationServiceDeps> NotificationService for NotificationServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn notify(
+        &self,
+        event: &NotificationEvent,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let kind = event.kind();
+        let config = self.config_service.get_config().await?;
+        let (recipients, context) = self
+            .recipients_and_context(event, &config.app_url, tx.clone())
+            .await?;
+        let recipients: Vec<_> = recipients
+            .into_iter()
+            .filter(|preference| NotificationPreference::from(preference).is_subscribed(kind))
+            .collect();
+        if recipients.is_empty() {
+            self.transaction_dao.commit(tx).await?;
+            return Ok(());
+        }
+
+        let templates = self
+            .text_template_dao
+            .find_by_template_type(kind.template_type(), tx.clone())
+            .await?;
+        let (subject, body) = render_notification(kind, templates.first(), &context)?;
+        let now = self.clock_service.date_time_now();
+
+        for recipient in recipients {
+            let entity = NotificationOutboxEntity {
+                id: self
+                    .uuid_service
+                    .new_uuid(&format!("{NOTIFICATION_SERVICE_PROCESS}::create id")),
+                recipient_user_id: recipient.user_id.clone(),
+                recipient_email: recipient.email.clone(),
+                kind: kind.into(),
+                subject: subject.clone(),
+                body: body.clone(),
+                state: dao::notification::NotificationState::Pending,
+                attempts: 0,
+                next_attempt: now,
+                last_error: None,
+                created: now,
+                sent: None,
+                version: self
+                    .uuid_service
+                    .new_uuid(&format!("{NOTIFICATION_SERVICE_PROCESS}::create version")),
+            };
+            self.notification_dao
+                .create_outbox(&entity, NOTIFICATION_SERVICE_PROCESS, tx.clone())
+                .await?;
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn get_own_preference(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Option<NotificationPreference>, ServiceError> {
+        let user_id = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?
+            .ok_or(ServiceError::Unauthorized)?;
+        self.get_preference(&user_id, context, tx).await
+    }
+
+    async fn get_preference(
+        &self,
+        user_id: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Option<NotificationPreference>, ServiceError> {
+        self.check_hr_or_self(user_id, context).await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let preference = self
+            .notification_dao
+            .find_preference_by_user_id(user_id, tx.clone())
+            .await?
+            .as_ref()
+            .map(NotificationPreference::from);
+        self.transaction_dao.commit(tx).await?;
+        Ok(preference)
+    }
+
+    async fn set_preference(
+        &self,
+        preference: &NotificationPreference,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<NotificationPreference, ServiceError> {
+        self.check_hr_or_self(&preference.user_id, context.clone()).await?;
+        validate_preference(preference)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let existing = self
+            .notification_dao
+            .find_preference_by_user_id(&preference.user_id, tx.clone())
+            .await?;
+        let entity = NotificationPreferenceEntity {
+            user_id: preference.user_id.clone(),
+            email: Arc::from(preference.email.trim()),
+            booking_deleted: preference.booking_deleted,
+            week_planned: preference.week_planned,
+            absence_created: preference.absence_created,
+            invitation_created: preference.invitation_created,
+            created: existing
+                .as_ref()
+                .map(|existing| existing.created)
+                .unwrap_or_else(|| self.clock_service.date_time_now()),
+            deleted: None,
+            version: self.uuid_service.new_uuid(&format!(
+                "{NOTIFICATION_SERVICE_PROCESS}::preference version"
+            )),
+        };
+        let after = NotificationPreference::from(&entity);
+        let change = if let Some(existing) = existing {
+            self.notification_dao
+                .update_preference(&entity, NOTIFICATION_SERVICE_PROCESS, tx.clone())
+                .await?;
+            AuditChange::update(
+                entity_type::NOTIFICATION_PREFERENCE,
+                &entity.user_id,
+                &NotificationPreference::from(&existing),
+                &after,
+            )?
+        } else {
+            self.notification_dao
+                .create_preference(&entity, NOTIFICATION_SERVICE_PROCESS, tx.clone())
+                .await?;
+            AuditChange::create(entity_type::NOTIFICATION_PREFERENCE, &entity.user_id, &after)?
+        };
+        self.audit_log_service
+            .record(&change, NOTIFICATION_SERVICE_PROCESS, context, tx.clone().into())
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(after)
+    }
+
+    async fn get_outbox(
+        &self,
+        limit: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[NotificationMessage]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let messages = self
+            .notification_dao
+            .recent_outbox(limit.min(MAX_OUTBOX_LIMIT), tx.clone())
+            .await?
+            .iter()
+            .map(NotificationMessage::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(messages)
+    }
+
+    async fn retry(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<NotificationMessage, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let existing = self
+            .notification_dao
+            .find_outbox_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        if existing.state != dao::notification::NotificationState::Failed {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::ModificationNotAllowed("state".into()),
+            ])));
+        }
+        let entity = NotificationOutboxEntity {
+            state: dao::notification::NotificationState::Pending,
+            attempts: 0,
+            next_attempt: self.clock_service.date_time_now(),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{NOTIFICATION_SERVICE_PROCESS}::retry version")),
+            ..existing
+        };
+        self.notification_dao
+            .update_outbox(&entity, NOTIFICATION_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(NotificationMessage::from(&entity))
+    }
+
+    async fn deliver_pending(
+        &self,
+        context: Authentication<Self::Context>,
+    ) -> Result<NotificationDeliveryReport, ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let mut report = NotificationDeliveryReport::default();
+        if !self.mail_transport.is_configured() {
+            return Ok(report);
+        }
+
+        let tx = self.transaction_dao.use_transaction(None).await?;
+        let due = self
+            .notification_dao
+            .find_due_outbox(
+                self.clock_service.date_time_now(),
+                DELIVERY_BATCH_SIZE,
+                tx.clone(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+
+        for message in due.iter().cloned() {
+            let id = message.id;
+            match self.deliver_one(message).await {
+                Ok(NotificationState::Sent) => report.sent += 1,
+                Ok(NotificationState::Pending) => report.retried += 1,
+                Ok(NotificationState::Failed) => report.failed += 1,
+                Err(e) => error!("Failed to update notification {}: {:?}", id, e),
+            }
+        }
+        Ok(report)
+    }
+}
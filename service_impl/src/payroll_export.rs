@@ -0,0 +1,249 @@
+//! Business-Logic-Tier Implementation von
+//! [`service::payroll_export::PayrollExportService`].
+//!
+//! Die Datei entsteht in zwei Schritten: [`payroll_lines`] wählt aus dem
+//! Abrechnungszeitraum und der Lohnarten-Zuordnung die Bewegungsdaten aus,
+//! [`write_lodas`] schreibt sie samt Kopf als ASCII-Text. Beide Schritte sind
+//! pur und ohne Mocks testbar.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    billing_period::{BillingPeriod, BillingPeriodService},
+    config::ConfigService,
+    payroll_export::PayrollExportService,
+    payroll_wage_type::{PayrollWageType, PayrollWageTypeService},
+    permission::{Authentication, HR_PRIVILEGE},
+    report_export::ExportFile,
+    sales_person::{SalesPerson, SalesPersonService},
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use time::Date;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+const LODAS_CONTENT_TYPE: &str = "text/plain; charset=us-ascii";
+/// Satzart der Bewegungsdaten, verweist auf die Zeile in `[Satzbeschreibung]`.
+const LODAS_RECORD_TYPE: &str = "10";
+
+gen_service_impl! {
+    struct PayrollExportServiceImpl: PayrollExportService = PayrollExportServiceDeps {
+        BillingPeriodService: BillingPeriodService<Context = Self::Context, Transaction = Self::Transaction> = billing_period_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        PayrollWageTypeService: PayrollWageTypeService<Context = Self::Context, Transaction = Self::Transaction> = payroll_wage_type_service,
+        ConfigService: ConfigService = config_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+/// Eine Zeile der Bewegungsdaten.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayrollLine {
+    pub personnel_number: Arc<str>,
+    pub wage_type: Arc<str>,
+    pub processing_key: u8,
+    pub value: f32,
+}
+
+/// Auf zwei Nachkommastellen gerundet, ohne `-0`.
+fn round_value(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0 + 0.0
+}
+
+/// Bewegungsdaten sortiert nach Personal- und Lohnart-Nummer. Werte, die auf
+/// 0 runden, entfallen. Fehlt einem Mitarbeiter mit exportierten Werten die
+/// Personalnummer (oder ist sie nicht numerisch), schlägt der Export mit
+/// einem Eintrag pro Mitarbeiter fehl.
+pub fn payroll_lines(
+    billing_period: &BillingPeriod,
+    sales_persons: &[SalesPerson],
+    wage_types: &[PayrollWageType],
+) -> Result<Vec<PayrollLine>, ServiceError> {
+    let mut lines = Vec::new();
+    let mut errors = Vec::new();
+    for billing_sales_person in billing_period
+        .sales_persons
+        .iter()
+        .filter(|sales_person| sales_person.deleted_at.is_none())
+    {
+        let values: Vec<(&PayrollWageType, f32)> = wage_types
+            .iter()
+            .filter_map(|wage_type| {
+                billing_sales_person
+                    .values
+                    .get(&wage_type.value_type)
+                    .map(|value| (wage_type, round_value(wage_type.value_view.select(value))))
+            })
+            .filter(|(_, value)| value.is_finite() && *value != 0.0)
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        let personnel_number = sales_persons
+            .iter()
+            .find(|sales_person| sales_person.id == billing_sales_person.sales_person_id)
+            .and_then(|sales_person| sales_person.personnel_number.clone())
+            .filter(|number| number.chars().all(|c| c.is_ascii_digit()));
+        let Some(personnel_number) = personnel_number else {
+            errors.push(ValidationFailureItem::InvalidValue(
+                format!(
+                    "personnel_number of sales person {}",
+                    billing_sales_person.sales_person_id
+                )
+                .into(),
+            ));
+            continue;
+        };
+        for (wage_type, value) in values {
+            lines.push(PayrollLine {
+                personnel_number: personnel_number.clone(),
+                wage_type: wage_type.wage_type.clone(),
+                processing_key: wage_type.processing_key,
+                value,
+            });
+        }
+    }
+    if !errors.is_empty() {
+        return Err(ServiceError::ValidationError(errors.into()));
+    }
+    lines.sort_by(|a, b| {
+        (a.personnel_number.len(), &a.personnel_number, a.wage_type.len(), &a.wage_type).cmp(&(
+            b.personnel_number.len(),
+            &b.personnel_number,
+            b.wage_type.len(),
+            &b.wage_type,
+        ))
+    });
+    Ok(lines)
+}
+
+fn format_lodas_date(date: Date) -> String {
+    format!(
+        "{:02}.{:02}.{:04}",
+        date.day(),
+        u8::from(date.month()),
+        date.year()
+    )
+}
+
+/// LODAS-Importdatei mit Windows-Zeilenenden. `payroll_month` ist ein
+/// beliebiger Tag im Abrechnungsmonat, geschrieben wird der Monatserste.
+pub fn write_lodas(
+    consultant_number: &str,
+    client_number: &str,
+    payroll_month: Date,
+    lines: &[PayrollLine],
+) -> Result<String, ServiceError> {
+    let month_start = payroll_month.replace_day(1)?;
+    let mut out = String::new();
+    out.push_str("[Allgemein]\r\n");
+    out.push_str("Ziel=LODAS\r\n");
+    out.push_str("Version_SST=1.0\r\n");
+    out.push_str(&format!("BeraterNr={consultant_number}\r\n"));
+    out.push_str(&format!("MandantenNr={client_number}\r\n"));
+    out.push_str("Datumsformat=TT.MM.JJJJ\r\n");
+    out.push_str("Feldtrennzeichen=;\r\n");
+    out.push_str("Zahlenkomma=,\r\n");
+    out.push_str("Stringbegrenzer=\"\r\n");
+    out.push_str("Kommentarzeichen=*\r\n");
+    out.push_str("\r\n[Satzbeschreibung]\r\n");
+    out.push_str(&format!(
+        "{LODAS_RECORD_TYPE};u_lod_bwd_buchung_standard;abrechnung_zeitraum#bwd;pnr#bwd;la_eigene#bwd;bs_nr#bwd;bs_wert_butab#bwd;\r\n"
+    ));
+    out.push_str("\r\n[Bewegungsdaten]\r\n");
+    let date = format_lodas_date(month_start);
+    for line in lines {
+        out.push_str(&format!(
+            "{LODAS_RECORD_TYPE};{date};{};{};{};{};\r\n",
+            line.personnel_number,
+            line.wage_type,
+            line.processing_key,
+            format!("{:.2}", line.value).replace('.', ",")
+        ));
+    }
+    Ok(out)
+}
+
+/// Berater- und Mandantennummer aus der Konfiguration, beide Pflicht.
+fn datev_numbers(
+    consultant_number: Option<Arc<str>>,
+    client_number: Option<Arc<str>>,
+) -> Result<(Arc<str>, Arc<str>), ServiceError> {
+    let consultant_number = consultant_number.filter(|number| !number.trim().is_empty());
+    let client_number = client_number.filter(|number| !number.trim().is_empty());
+    match (consultant_number, client_number) {
+        (Some(consultant_number), Some(client_number)) => Ok((consultant_number, client_number)),
+        (consultant_number, client_number) => {
+            let mut errors = Vec::new();
+            if consultant_number.is_none() {
+                errors.push(ValidationFailureItem::InvalidValue(
+                    "datev_consultant_number".into(),
+                ));
+            }
+            if client_number.is_none() {
+                errors.push(ValidationFailureItem::InvalidValue(
+                    "datev_client_number".into(),
+                ));
+            }
+            Err(ServiceError::ValidationError(errors.into()))
+        }
+    }
+}
+
+#[async_trait]
+impl<Deps: PayrollExportServiceDeps> PayrollExportService for PayrollExportServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn export_billing_period(
+        &self,
+        billing_period_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        let config = self.config_service.get_config().await?;
+        let (consultant_number, client_number) =
+            datev_numbers(config.datev_consultant_number, config.datev_client_number)?;
+
+        let billing_period = self
+            .billing_period_service
+            .get_billing_period_by_id(billing_period_id, context.clone(), tx.clone().into())
+            .await?;
+        let sales_persons = self
+            .sales_person_service
+            .get_all(context.clone(), tx.clone().into())
+            .await?;
+        let wage_types = self
+            .payroll_wage_type_service
+            .get_all(context, tx.clone().into())
+            .await?;
+
+        let lines = payroll_lines(&billing_period, &sales_persons, &wage_types)?;
+        let content = write_lodas(
+            &consultant_number,
+            &client_number,
+            billing_period.end_date.to_date(),
+            &lines,
+        )?;
+        let file = ExportFile {
+            file_name: format!(
+                "datev-lodas-{}-{}.txt",
+                billing_period.start_date.to_date(),
+                billing_period.end_date.to_date()
+            )
+            .into(),
+            content_type: LODAS_CONTENT_TYPE,
+            content: content.into_bytes().into(),
+        };
+        self.transaction_dao.commit(tx).await?;
+        Ok(file)
+    }
+}
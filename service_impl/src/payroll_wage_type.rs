@@ -0,0 +1,244 @@
+//! Basic-Tier Implementation von [`service::payroll_wage_type::PayrollWageTypeService`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    payroll_wage_type::{PayrollWageTypeDao, PayrollWageTypeEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    clock::ClockService,
+    payroll_wage_type::{PayrollWageType, PayrollWageTypeService},
+    permission::{Authentication, HR_PRIVILEGE},
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+const PAYROLL_WAGE_TYPE_SERVICE_PROCESS: &str = "payroll-wage-type-service";
+/// LODAS erlaubt höchstens vierstellige Lohnart-Nummern.
+const MAX_WAGE_TYPE_LEN: usize = 4;
+
+fn validate_wage_type(wage_type: &PayrollWageType) -> Result<(), ServiceError> {
+    let code = wage_type.wage_type.trim();
+    if code.is_empty()
+        || code.len() > MAX_WAGE_TYPE_LEN
+        || !code.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(ServiceError::ValidationError(Arc::new([
+            ValidationFailureItem::InvalidValue("wage_type".into()),
+        ])));
+    }
+    Ok(())
+}
+
+/// Schnappschuss des gespeicherten Stands für das Änderungsprotokoll.
+fn persisted_wage_type(entity: &PayrollWageTypeEntity) -> Result<PayrollWageType, ServiceError> {
+    PayrollWageType::try_from(entity).map_err(|e| {
+        warn!("Payroll wage type {} is unreadable: {}", entity.id, e);
+        ServiceError::InternalError
+    })
+}
+
+gen_service_impl! {
+    struct PayrollWageTypeServiceImpl: PayrollWageTypeService = PayrollWageTypeServiceDeps {
+        PayrollWageTypeDao: PayrollWageTypeDao<Transaction = Self::Transaction> = payroll_wage_type_dao,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+#[async_trait]
+impl<Deps: PayrollWageTypeServiceDeps> PayrollWageTypeService for PayrollWageTypeServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PayrollWageType]>, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let wage_types = self
+            .payroll_wage_type_dao
+            .all(tx.clone())
+            .await?
+            .iter()
+            .filter_map(|entity| match PayrollWageType::try_from(entity) {
+                Ok(wage_type) => Some(wage_type),
+                Err(e) => {
+                    warn!("Skipping payroll wage type {}: {}", entity.id, e);
+                    None
+                }
+            })
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(wage_types)
+    }
+
+    async fn create(
+        &self,
+        wage_type: &PayrollWageType,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<PayrollWageType, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        if wage_type.id != Uuid::nil() {
+            return Err(ServiceError::IdSetOnCreate);
+        }
+        if wage_type.version != Uuid::nil() {
+            return Err(ServiceError::VersionSetOnCreate);
+        }
+        validate_wage_type(wage_type)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = PayrollWageTypeEntity {
+            id: self
+                .uuid_service
+                .new_uuid(&format!("{PAYROLL_WAGE_TYPE_SERVICE_PROCESS}::create id")),
+            value_type: wage_type.value_type.as_str(),
+            value_view: wage_type.value_view.into(),
+            wage_type: Arc::from(wage_type.wage_type.trim()),
+            processing_key: wage_type.processing_key,
+            description: wage_type.description.clone(),
+            created: self.clock_service.date_time_now(),
+            deleted: None,
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{PAYROLL_WAGE_TYPE_SERVICE_PROCESS}::create version")),
+        };
+        self.payroll_wage_type_dao
+            .create(&entity, PAYROLL_WAGE_TYPE_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = PayrollWageType {
+            created: Some(entity.created),
+            id: entity.id,
+            version: entity.version,
+            wage_type: entity.wage_type,
+            ..wage_type.clone()
+        };
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::PAYROLL_WAGE_TYPE, created.id, &created)?,
+                PAYROLL_WAGE_TYPE_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        wage_type: &PayrollWageType,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<PayrollWageType, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        validate_wage_type(wage_type)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .payroll_wage_type_dao
+            .find_by_id(wage_type.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(wage_type.id))?;
+        if persisted.version != wage_type.version {
+            return Err(ServiceError::EntityConflicts(
+                wage_type.id,
+                persisted.version,
+                wage_type.version,
+            ));
+        }
+        let before = persisted_wage_type(&persisted)?;
+        let entity = PayrollWageTypeEntity {
+            value_type: wage_type.value_type.as_str(),
+            value_view: wage_type.value_view.into(),
+            wage_type: Arc::from(wage_type.wage_type.trim()),
+            processing_key: wage_type.processing_key,
+            description: wage_type.description.clone(),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{PAYROLL_WAGE_TYPE_SERVICE_PROCESS}::update version")),
+            ..persisted
+        };
+        self.payroll_wage_type_dao
+            .update(&entity, PAYROLL_WAGE_TYPE_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let updated = PayrollWageType {
+            created: Some(entity.created),
+            version: entity.version,
+            wage_type: entity.wage_type,
+            ..wage_type.clone()
+        };
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::PAYROLL_WAGE_TYPE,
+                    updated.id,
+                    &before,
+                    &updated,
+                )?,
+                PAYROLL_WAGE_TYPE_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .payroll_wage_type_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let before = persisted_wage_type(&persisted)?;
+        let entity = PayrollWageTypeEntity {
+            deleted: Some(self.clock_service.date_time_now()),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{PAYROLL_WAGE_TYPE_SERVICE_PROCESS}::delete version")),
+            ..persisted
+        };
+        self.payroll_wage_type_dao
+            .update(&entity, PAYROLL_WAGE_TYPE_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::PAYROLL_WAGE_TYPE, id, &before)?,
+                PAYROLL_WAGE_TYPE_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+}
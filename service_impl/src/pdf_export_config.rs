@@ -7,6 +7,9 @@
 //!   sich intern authentisiert.
 //! - Der `webdav_app_token`-Merge (leer = keep, gesetzt = replace) passiert
 //!   HIER, damit der REST-Handler den Merge nicht kennt.
+//! - Die Shiftplan-Auswahl wird als Ganzes ersetzt; Normalisierung (trimmen,
+//!   leer = `None`, Schrägstriche am Rand entfernen) und Validierung laufen
+//!   ebenfalls hier.
 
 use std::sync::Arc;
 
@@ -15,11 +18,15 @@ use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{pdf_export_config::PdfExportConfigDao, TransactionDao};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
-    pdf_export_config::{PdfExportConfig, PdfExportConfigService, PdfExportConfigUpdate},
+    pdf_export_config::{
+        PdfExportConfig, PdfExportConfigService, PdfExportConfigUpdate,
+        PdfExportShiftplanSetting,
+    },
     permission::Authentication,
     uuid_service::UuidService,
-    PermissionService, ServiceError,
+    PermissionService, ServiceError, ValidationFailureItem,
 };
 
 /// Admin-Privilege für die PDF-Export-Konfiguration (D-48-ADMIN). Nutzt die
@@ -30,6 +37,56 @@ const ADMIN_PRIVILEGE: &str = "admin";
 const PROCESS_UPDATE: &str = "pdf-export-config-service::update";
 const PROCESS_RECORD_SUCCESS: &str = "pdf-export-config-service::record_success";
 const PROCESS_RECORD_ERROR: &str = "pdf-export-config-service::record_error";
+const PROCESS_UPDATE_SHIFTPLANS: &str = "pdf-export-config-service::update_shiftplan_settings";
+
+/// Trimmt einen optionalen Text (plus `trim_chars` am Rand); leer wird `None`.
+fn normalize_optional(value: &Option<Arc<str>>, trim_chars: &[char]) -> Option<Arc<str>> {
+    value
+        .as_deref()
+        .map(|v| v.trim().trim_matches(trim_chars).trim())
+        .filter(|v| !v.is_empty())
+        .map(Arc::from)
+}
+
+fn normalize_setting(setting: &PdfExportShiftplanSetting) -> PdfExportShiftplanSetting {
+    PdfExportShiftplanSetting {
+        subfolder: normalize_optional(&setting.subfolder, &['/']),
+        filename_prefix: normalize_optional(&setting.filename_prefix, &[]),
+        ..setting.clone()
+    }
+}
+
+fn validate_settings(settings: &[PdfExportShiftplanSetting]) -> Result<(), ServiceError> {
+    let mut errors = Vec::new();
+    for (i, setting) in settings.iter().enumerate() {
+        if settings[..i]
+            .iter()
+            .any(|other| other.shiftplan_id == setting.shiftplan_id)
+        {
+            errors.push(ValidationFailureItem::Duplicate);
+        }
+        if setting.subfolder.as_deref().is_some_and(|folder| {
+            folder.contains('\\')
+                || folder
+                    .split('/')
+                    .any(|segment| segment.trim().is_empty() || segment == ".." || segment == ".")
+        }) {
+            errors.push(ValidationFailureItem::InvalidValue("subfolder".into()));
+        }
+        if setting
+            .filename_prefix
+            .as_deref()
+            .is_some_and(|prefix| prefix.contains(['/', '\\']))
+        {
+            errors.push(ValidationFailureItem::InvalidValue("filename_prefix".into()));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ServiceError::ValidationError(errors.into()))
+    }
+}
 
 gen_service_impl! {
     struct PdfExportConfigServiceImpl: PdfExportConfigService = PdfExportConfigServiceDeps {
@@ -37,6 +94,7 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -67,7 +125,7 @@ impl<Deps: PdfExportConfigServiceDeps> PdfExportConfigService for PdfExportConfi
         tx: Option<Self::Transaction>,
     ) -> Result<PdfExportConfig, ServiceError> {
         self.permission_service
-            .check_permission(ADMIN_PRIVILEGE, context)
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
             .await?;
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
@@ -91,10 +149,12 @@ impl<Deps: PdfExportConfigServiceDeps> PdfExportConfigService for PdfExportConfi
             target_folder: update.target_folder,
             weeks_horizon: update.weeks_horizon,
             cron_schedule: update.cron_schedule,
+            include_planning_plans: update.include_planning_plans,
+            combined_pdf: update.combined_pdf,
             // Status-Felder bleiben unverändert — die verwaltet der Scheduler.
             last_success_at: current.last_success_at,
             last_error_at: current.last_error_at,
-            last_error_message: current.last_error_message,
+            last_error_message: current.last_error_message.clone(),
             version: new_version,
         };
 
@@ -103,9 +163,22 @@ impl<Deps: PdfExportConfigServiceDeps> PdfExportConfigService for PdfExportConfi
             .await?;
 
         // Read-after-write: die persistierte Row als Antwort zurückliefern.
-        let after = self.pdf_export_config_dao.get(tx.clone()).await?;
+        let after = PdfExportConfig::from(&self.pdf_export_config_dao.get(tx.clone()).await?);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::PDF_EXPORT_CONFIG,
+                    after.id,
+                    &PdfExportConfig::from(&current),
+                    &after,
+                )?,
+                PROCESS_UPDATE,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
-        Ok((&after).into())
+        Ok(after)
     }
 
     async fn record_success(
@@ -149,4 +222,70 @@ impl<Deps: PdfExportConfigServiceDeps> PdfExportConfigService for PdfExportConfi
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
+
+    async fn get_shiftplan_settings(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PdfExportShiftplanSetting]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let settings = self
+            .pdf_export_config_dao
+            .get_shiftplan_settings(tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(settings.iter().map(PdfExportShiftplanSetting::from).collect())
+    }
+
+    async fn update_shiftplan_settings(
+        &self,
+        settings: Arc<[PdfExportShiftplanSetting]>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[PdfExportShiftplanSetting]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        let settings: Vec<PdfExportShiftplanSetting> =
+            settings.iter().map(normalize_setting).collect();
+        validate_settings(&settings)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let config_id = self.pdf_export_config_dao.get(tx.clone()).await?.id;
+        let before: Vec<PdfExportShiftplanSetting> = self
+            .pdf_export_config_dao
+            .get_shiftplan_settings(tx.clone())
+            .await?
+            .iter()
+            .map(PdfExportShiftplanSetting::from)
+            .collect();
+        let version = self
+            .uuid_service
+            .new_uuid("pdf-export-config-service::update_shiftplan_settings version");
+        let entities: Vec<dao::pdf_export_config::PdfExportShiftplanEntity> =
+            settings.iter().map(Into::into).collect();
+        self.pdf_export_config_dao
+            .replace_shiftplan_settings(&entities, PROCESS_UPDATE_SHIFTPLANS, version, tx.clone())
+            .await?;
+        let after: Vec<PdfExportShiftplanSetting> = self
+            .pdf_export_config_dao
+            .get_shiftplan_settings(tx.clone())
+            .await?
+            .iter()
+            .map(PdfExportShiftplanSetting::from)
+            .collect();
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::PDF_EXPORT_SHIFTPLAN, config_id, &before, &after)?,
+                PROCESS_UPDATE_SHIFTPLANS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(after.into())
+    }
 }
@@ -16,12 +16,15 @@
 //! [`crate::webdav_client::WebDavClient`] intern mit
 //! `header_value.set_sensitive(true)` markiert.
 //!
-//! ## v1-Vereinfachung: nur der erste aktive Shiftplan
+//! ## Shiftplan-Auswahl
 //!
-//! `run_once_now` rendert PRO WOCHE genau EINEN Shiftplan (den ersten
-//! non-deleted aus `shiftplan_service.get_all()`), weil das für den v1-Use-
-//! Case (ein „Planungs"-Shiftplan pro Woche) der Regelfall ist. Multi-
-//! Shiftplan-Zusammenführung im PDF ist ein Follow-up wenn benötigt.
+//! `run_once_now` exportiert alle aktiven Shiftplans aus
+//! `shiftplan_service.get_all()`, die [`select_shiftplans`] durchlässt:
+//! reine Planungs-Plans (`is_planning`) nur mit `include_planning_plans`,
+//! abgewählte Plans (`pdf_export_shiftplan.included = 0`) nie. Pro Plan und
+//! Woche entsteht eine Datei im Zielordner bzw. im Unterordner des Plans.
+//! Mit `combined_pdf` entsteht stattdessen eine Datei pro Woche mit einer
+//! Seite je Plan; Unterordner und Präfixe spielen dann keine Rolle.
 //!
 //! ## Phase 49 Refactor (D-49-08 + Q1)
 //!
@@ -30,7 +33,8 @@
 //! der Phase 49. Konsequenz: Der Service prüft den `WeekStatus` und liefert
 //! nur für `Planned`/`Locked`-Weeks Bytes; andere Weeks kommen als
 //! [`ServiceError::ValidationError`] zurück und werden per `record_error` +
-//! `return Ok(())` als per-Week-Skip behandelt (Q1 im Discussion-Log).
+//! `continue` als per-Week-Skip behandelt (Q1 im Discussion-Log) — im
+//! Einzeldatei-Modus für alle Plans der Woche.
 //! Der Scheduler exportiert nach diesem Refactor also NUR noch releasbare
 //! Wochen — kein leaky Export unfertiger Wochenplaene ins WebDAV-Storage.
 
@@ -42,10 +46,10 @@ use dao::TransactionDao;
 use service::{
     clock::ClockService,
     pdf_export::PdfExportScheduler,
-    pdf_export_config::PdfExportConfigService,
+    pdf_export_config::{PdfExportConfigService, PdfExportShiftplanSetting},
     pdf_shiftplan::PdfShiftplanService,
     permission::Authentication,
-    shiftplan_catalog::ShiftplanService,
+    shiftplan_catalog::{Shiftplan, ShiftplanService},
     PermissionService, ServiceError,
 };
 use shifty_utils::{ShiftyDate, ShiftyWeek};
@@ -55,9 +59,11 @@ use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::gen_service_impl;
+use crate::report_export::file_name_slug;
 use crate::webdav_client::{WebDavClient, WebDavError, WebDavUpload};
 
 const INCOMPLETE_CONFIG_MSG: &str = "Konfiguration unvollständig";
+const NO_SHIFTPLAN_SELECTED_MSG: &str = "Kein Shiftplan für den Export ausgewählt";
 
 gen_service_impl! {
     struct PdfExportSchedulerImpl: service::pdf_export::PdfExportScheduler = PdfExportSchedulerDeps {
@@ -338,25 +344,33 @@ impl<Deps: PdfExportSchedulerDeps + 'static> PdfExportScheduler for PdfExportSch
             }
         };
 
-        // Pull the shiftplans and sales-persons once for the whole horizon.
+        // Shiftplan-Auswahl einmal für den ganzen Horizont auflösen.
         let all_shiftplans = self
             .shiftplan_service
             .get_all(Authentication::Full, None)
             .await?;
-        let active_shiftplan = all_shiftplans
+        let settings = self
+            .pdf_export_config_service
+            .get_shiftplan_settings(Authentication::Full, None)
+            .await?;
+        let selected = select_shiftplans(
+            &all_shiftplans,
+            &settings,
+            cfg.include_planning_plans,
+            &run_cfg.target_folder,
+        );
+        if selected.is_empty() {
+            let at = self.clock_service.date_time_now();
+            let msg: Arc<str> = Arc::from(NO_SHIFTPLAN_SELECTED_MSG);
+            self.pdf_export_config_service
+                .record_error(at, msg, Authentication::Full, None)
+                .await?;
+            return Ok(());
+        }
+        let combined_plans: Arc<[(Uuid, Arc<str>)]> = selected
             .iter()
-            .find(|s| s.deleted.is_none());
-        let shiftplan_id = match active_shiftplan {
-            Some(s) => s.id,
-            None => {
-                let at = self.clock_service.date_time_now();
-                let msg: Arc<str> = Arc::from("Kein aktiver Shiftplan vorhanden");
-                self.pdf_export_config_service
-                    .record_error(at, msg, Authentication::Full, None)
-                    .await?;
-                return Ok(());
-            }
-        };
+            .map(|plan| (plan.id, plan.name.clone()))
+            .collect();
 
         // Determine the horizon of ISO weeks.
         let now_date = self.clock_service.date_now();
@@ -364,65 +378,77 @@ impl<Deps: PdfExportSchedulerDeps + 'static> PdfExportScheduler for PdfExportSch
         let start_week = ShiftyWeek::new(start_shifty.year(), start_shifty.week());
         let mut cursor = start_week;
         let horizon = run_cfg.weeks_horizon as usize;
+        let files_per_week = if cfg.combined_pdf { 1 } else { selected.len() };
         let mut succeeded_count: usize = 0;
         for _offset in 0..horizon {
             let (y, w) = (cursor.year, cursor.week);
             cursor = cursor.next();
-            // Phase 49 D-49-08: Delegate assemble (WeekStatus-Gate + View +
-            // active-SalesPersons + Render) an den PdfShiftplanService.
-            // D-49-07: Aufrufer-Kontext = `Authentication::Full` (Scheduler
-            // ist trusted; Cron-Callback ruft `run_once_now` mit Full).
-            // v2.3.1: ValidationError (Status != Planned/Locked) wird per
-            // `record_error` geloggt und die Schleife läuft mit `continue`
-            // weiter — vorher brach der komplette Horizon beim ersten
-            // Draft-Week ab und ließ spätere Planned-Wochen ungeexportiert.
-            let bytes = match self
-                .pdf_shiftplan_service
-                .render_week_pdf(shiftplan_id, y, w, Authentication::Full, None)
-                .await
-            {
-                Ok(b) => b,
-                Err(e) => {
-                    let at = self.clock_service.date_time_now();
-                    let msg: Arc<str> = Arc::from(format!(
-                        "PDF-Assemble/Render fuer KW{w:02}/{y} fehlgeschlagen: {e}"
-                    ));
-                    self.pdf_export_config_service
-                        .record_error(at, msg, Authentication::Full, None)
-                        .await?;
-                    continue;
-                }
-            };
 
-            let filename = crate::pdf_shiftplan::filename_for(y, w);
-            if let Err(e) = upload
-                .upload_file(&run_cfg.target_folder, &filename, bytes)
-                .await
-            {
-                let at = self.clock_service.date_time_now();
-                let msg: Arc<str> = match &e {
-                    WebDavError::Transient { attempts, .. } => Arc::from(format!(
-                        "WebDAV-Upload für KW{w:02}/{y} nach {attempts} Versuchen (transient) fehlgeschlagen"
-                    )),
-                    WebDavError::Permanent { status, .. } => Arc::from(format!(
-                        "WebDAV-Upload für KW{w:02}/{y} permanent fehlgeschlagen ({status})"
-                    )),
-                    WebDavError::Io(_) => Arc::from(format!(
-                        "WebDAV-Upload für KW{w:02}/{y} — Netzwerkfehler"
-                    )),
+            if cfg.combined_pdf {
+                // Sammel-PDF: eine Datei pro Woche direkt im Zielordner, eine
+                // Seite je ausgewähltem Shiftplan. Gleiches Status-Gate wie
+                // `render_week_pdf`.
+                let bytes = match self
+                    .pdf_shiftplan_service
+                    .render_combined_week_pdf(
+                        combined_plans.clone(),
+                        y,
+                        w,
+                        Authentication::Full,
+                        None,
+                    )
+                    .await
+                {
+                    Ok(b) => b,
+                    Err(e) => {
+                        self.record_render_error(y, w, None, &e).await?;
+                        continue;
+                    }
                 };
-                // Log a token-free diagnostic (WebDavError's Display never
-                // includes the Basic-Auth header — see 48-03 T-48-08).
-                error!("pdf-export upload failed for KW{w:02}/{y}: {e}");
-                self.pdf_export_config_service
-                    .record_error(at, msg, Authentication::Full, None)
-                    .await?;
-                // Do not attempt further weeks — surface first failure and
-                // let the next cron slot retry from scratch (per plan).
-                return Ok(());
+                let filename = crate::pdf_shiftplan::filename_for(y, w);
+                if !self
+                    .upload_or_record(upload.as_ref(), &run_cfg.target_folder, &filename, bytes, y, w)
+                    .await?
+                {
+                    return Ok(());
+                }
+                succeeded_count += 1;
+                continue;
             }
 
-            succeeded_count += 1;
+            for plan in selected.iter() {
+                // Phase 49 D-49-08: Delegate assemble (WeekStatus-Gate + View +
+                // active-SalesPersons + Render) an den PdfShiftplanService.
+                // D-49-07: Aufrufer-Kontext = `Authentication::Full` (Scheduler
+                // ist trusted; Cron-Callback ruft `run_once_now` mit Full).
+                // v2.3.1: ValidationError (Status != Planned/Locked) wird per
+                // `record_error` geloggt und die Schleife läuft mit der
+                // nächsten Woche weiter — der Status gilt für die Woche, nicht
+                // für den Plan, also bleiben die übrigen Plans dieser Woche aus.
+                let bytes = match self
+                    .pdf_shiftplan_service
+                    .render_week_pdf(plan.id, y, w, Authentication::Full, None)
+                    .await
+                {
+                    Ok(b) => b,
+                    Err(e) => {
+                        self.record_render_error(y, w, Some(&plan.name), &e).await?;
+                        if matches!(e, ServiceError::ValidationError(_)) {
+                            break;
+                        }
+                        continue;
+                    }
+                };
+
+                let filename = plan.filename_for(y, w);
+                if !self
+                    .upload_or_record(upload.as_ref(), &plan.folder, &filename, bytes, y, w)
+                    .await?
+                {
+                    return Ok(());
+                }
+                succeeded_count += 1;
+            }
         }
 
         // v2.3.1: nur `record_success` wenn tatsächlich mind. eine Woche
@@ -436,19 +462,151 @@ impl<Deps: PdfExportSchedulerDeps + 'static> PdfExportScheduler for PdfExportSch
                 .record_success(at, Authentication::Full, None)
                 .await?;
             info!(
-                "pdf-export success: {}/{} week(s) uploaded to Nextcloud",
-                succeeded_count, horizon
+                "pdf-export success: {}/{} file(s) uploaded to Nextcloud",
+                succeeded_count,
+                horizon * files_per_week
             );
         } else {
             info!(
-                "pdf-export run finished with 0/{} weeks uploaded — see last_error_message",
-                horizon
+                "pdf-export run finished with 0/{} files uploaded — see last_error_message",
+                horizon * files_per_week
             );
         }
         Ok(())
     }
 }
 
+/// Ein ausgewählter Shiftplan mit aufgelöstem Zielordner und Dateinamen-
+/// Präfix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SelectedShiftplan {
+    pub id: Uuid,
+    pub name: Arc<str>,
+    pub folder: Arc<str>,
+    pub filename_prefix: Option<Arc<str>>,
+}
+
+impl SelectedShiftplan {
+    /// `filename_for(year, week)`, mit `<präfix>-` davor falls gesetzt.
+    pub fn filename_for(&self, year: u32, calendar_week: u8) -> String {
+        let filename = crate::pdf_shiftplan::filename_for(year, calendar_week);
+        match &self.filename_prefix {
+            Some(prefix) => format!("{prefix}-{filename}"),
+            None => filename,
+        }
+    }
+}
+
+/// Wählt die zu exportierenden Shiftplans in Katalog-Reihenfolge aus:
+/// nicht gelöscht, Planungs-Plans nur mit `include_planning_plans`, und
+/// nicht per [`PdfExportShiftplanSetting::included`] abgewählt. Plans ohne
+/// Einstellung sind eingeschlossen.
+///
+/// Teilen sich mehrere Plans denselben Ordner und hat einer davon kein
+/// eigenes Präfix, bekommt er den Slug seines Namens — sonst würden sich
+/// die Wochen-Dateien gegenseitig überschreiben.
+pub(crate) fn select_shiftplans(
+    shiftplans: &[Shiftplan],
+    settings: &[PdfExportShiftplanSetting],
+    include_planning_plans: bool,
+    target_folder: &str,
+) -> Vec<SelectedShiftplan> {
+    let target_folder = target_folder.trim_end_matches('/');
+    let mut selected: Vec<SelectedShiftplan> = shiftplans
+        .iter()
+        .filter(|plan| plan.deleted.is_none())
+        .filter(|plan| include_planning_plans || !plan.is_planning)
+        .filter_map(|plan| {
+            let setting = settings.iter().find(|s| s.shiftplan_id == plan.id);
+            if setting.is_some_and(|s| !s.included) {
+                return None;
+            }
+            let folder: Arc<str> = match setting.and_then(|s| s.subfolder.as_deref()) {
+                Some(subfolder) => Arc::from(format!("{target_folder}/{subfolder}")),
+                None => Arc::from(target_folder),
+            };
+            Some(SelectedShiftplan {
+                id: plan.id,
+                name: plan.name.clone(),
+                folder,
+                filename_prefix: setting.and_then(|s| s.filename_prefix.clone()),
+            })
+        })
+        .collect();
+
+    let folders: Vec<Arc<str>> = selected.iter().map(|plan| plan.folder.clone()).collect();
+    for plan in selected.iter_mut() {
+        let shares_folder = folders.iter().filter(|f| **f == plan.folder).count() > 1;
+        if plan.filename_prefix.is_none() && shares_folder {
+            plan.filename_prefix = Some(Arc::from(file_name_slug(&plan.name)));
+        }
+    }
+    selected
+}
+
+impl<Deps: PdfExportSchedulerDeps> PdfExportSchedulerImpl<Deps> {
+    /// Persistiert einen Render-Fehler (inkl. abgelehntem Wochen-Status) als
+    /// `record_error`; `plan_name` ist `None` im Sammel-PDF-Modus.
+    async fn record_render_error(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        plan_name: Option<&str>,
+        error: &ServiceError,
+    ) -> Result<(), ServiceError> {
+        let at = self.clock_service.date_time_now();
+        let (y, w) = (year, calendar_week);
+        let msg: Arc<str> = match plan_name {
+            Some(name) => Arc::from(format!(
+                "PDF-Assemble/Render fuer {name} KW{w:02}/{y} fehlgeschlagen: {error}"
+            )),
+            None => Arc::from(format!(
+                "PDF-Assemble/Render fuer KW{w:02}/{y} fehlgeschlagen: {error}"
+            )),
+        };
+        self.pdf_export_config_service
+            .record_error(at, msg, Authentication::Full, None)
+            .await
+    }
+
+    /// Lädt eine Datei hoch. Bei einem Upload-Fehler wird `record_error`
+    /// geschrieben und `false` geliefert — der Aufrufer bricht den Lauf dann
+    /// ab und überlässt den Retry dem nächsten Cron-Slot.
+    async fn upload_or_record(
+        &self,
+        upload: &dyn WebDavUpload,
+        folder: &str,
+        filename: &str,
+        bytes: Vec<u8>,
+        year: u32,
+        calendar_week: u8,
+    ) -> Result<bool, ServiceError> {
+        let (y, w) = (year, calendar_week);
+        let Err(e) = upload.upload_file(folder, filename, bytes).await else {
+            return Ok(true);
+        };
+        let at = self.clock_service.date_time_now();
+        let msg: Arc<str> = match &e {
+            WebDavError::Transient { attempts, .. } => Arc::from(format!(
+                "WebDAV-Upload für KW{w:02}/{y} ({filename}) nach {attempts} Versuchen (transient) fehlgeschlagen"
+            )),
+            WebDavError::Permanent { status, .. } => Arc::from(format!(
+                "WebDAV-Upload für KW{w:02}/{y} ({filename}) permanent fehlgeschlagen ({status})"
+            )),
+            WebDavError::Io(_) => Arc::from(format!(
+                "WebDAV-Upload für KW{w:02}/{y} ({filename}) — Netzwerkfehler"
+            )),
+        };
+        // Log a token-free diagnostic (WebDavError's Display never
+        // includes the Basic-Auth header — see 48-03 T-48-08).
+        error!("pdf-export upload failed for KW{w:02}/{y} ({filename}): {e}");
+        self.pdf_export_config_service
+            .record_error(at, msg, Authentication::Full, None)
+            .await?;
+        Ok(false)
+    }
+}
+
 /// Clone-Handle für den Cron-Callback: wir dürfen `self` nicht direkt in die
 /// Closure moven (async trait method), also klonen wir alle `Arc`-Handles in
 /// einen leichten Handle-Struct.
@@ -0,0 +1,198 @@
+//! Business-Logic-Tier Implementation von
+//! [`service::pdf_personal::PdfPersonalService`].
+//!
+//! Sammelt die Daten für die persönlichen PDF-Dokumente und übergibt sie an
+//! die reinen Renderer in [`crate::pdf_personal_render`]:
+//!
+//! - Dienstplan: `BlockService::get_blocks_for_sales_person_week` pro Woche.
+//! - Stundenzettel: `ReportingService::get_report_for_employee_range` vom
+//!   Jahresanfang bis Monatsende (mit Übertrag), daraus der Monat aus
+//!   `by_month` und `balance_hours` als Saldo zum Monatsende.
+//!
+//! Die Permission wird vorab geprüft; die inneren Aufrufe laufen danach mit
+//! `Authentication::Full`, weil `BlockService` selbst keine Prüfung hat und
+//! die Sicht für alle berechtigten Aufrufer gleich ist.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    block::BlockService,
+    pdf_personal::{PdfPersonalService, MAX_SCHEDULE_WEEKS},
+    permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    report_export::ExportFile,
+    reporting::ReportingService,
+    sales_person::SalesPersonService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::{ShiftyDate, ShiftyWeek};
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::pdf_personal_render::{self, ScheduleWeek};
+use crate::pdf_shiftplan::resolve_render_timestamp;
+use crate::report_export::file_name_slug;
+
+const PDF_CONTENT_TYPE: &str = "application/pdf";
+
+gen_service_impl! {
+    struct PdfPersonalServiceImpl: PdfPersonalService = PdfPersonalServiceDeps {
+        BlockService: BlockService<Context = Self::Context, Transaction = Self::Transaction> = block_service,
+        ReportingService: ReportingService<Context = Self::Context, Transaction = Self::Transaction> = reporting_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+fn invalid_value(message: String) -> ServiceError {
+    ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+        message.into(),
+    )]))
+}
+
+#[async_trait]
+impl<Deps: PdfPersonalServiceDeps> PdfPersonalService for PdfPersonalServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn render_schedule_pdf(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+        weeks: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (hr, planner, is_self) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.clone().into()
+            ),
+        );
+        hr.or(planner).or(is_self)?;
+
+        if calendar_week == 0 || calendar_week > time::util::weeks_in_year(year as i32) {
+            return Err(invalid_value(format!(
+                "KW {calendar_week} gibt es {year} nicht"
+            )));
+        }
+        if weeks == 0 || weeks > MAX_SCHEDULE_WEEKS {
+            return Err(invalid_value(format!(
+                "Dienstplan umfasst 1 bis {MAX_SCHEDULE_WEEKS} Wochen, nicht {weeks}"
+            )));
+        }
+
+        let sales_person = self
+            .sales_person_service
+            .get(sales_person_id, Authentication::Full, tx.clone().into())
+            .await?;
+        let mut schedule = Vec::with_capacity(weeks as usize);
+        let mut week = ShiftyWeek::new(year, calendar_week);
+        for _ in 0..weeks {
+            let blocks = self
+                .block_service
+                .get_blocks_for_sales_person_week(
+                    sales_person_id,
+                    week.year,
+                    week.week,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?;
+            schedule.push(ScheduleWeek {
+                year: week.year,
+                week: week.week,
+                blocks,
+            });
+            week = week.next();
+        }
+        self.transaction_dao.commit(tx).await?;
+
+        let content = pdf_personal_render::render_personal_schedule_pdf(
+            &sales_person,
+            &schedule,
+            resolve_render_timestamp(),
+        )?;
+        Ok(ExportFile {
+            file_name: format!(
+                "dienstplan-{}-{year}-KW{calendar_week:02}.pdf",
+                file_name_slug(&sales_person.name)
+            )
+            .into(),
+            content_type: PDF_CONTENT_TYPE,
+            content: content.into(),
+        })
+    }
+
+    async fn render_timesheet_pdf(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        month: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (hr, is_self) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.clone().into()
+            ),
+        );
+        hr.or(is_self)?;
+
+        let month_start = time::Month::try_from(month)
+            .and_then(|calendar_month| {
+                time::Date::from_calendar_date(year as i32, calendar_month, 1)
+            })
+            .map_err(|_| invalid_value(format!("Monat {month}/{year} ist ungültig")))?;
+        let month_end = month_start.replace_day(month_start.month().length(month_start.year()))?;
+
+        let report = self
+            .reporting_service
+            .get_report_for_employee_range(
+                &sales_person_id,
+                ShiftyDate::first_day_in_year(year),
+                month_end.into(),
+                true,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+
+        let month_hours = report
+            .by_month
+            .iter()
+            .find(|group| group.from.to_date() == month_start)
+            .ok_or(ServiceError::InternalError)?;
+        let content = pdf_personal_render::render_monthly_timesheet_pdf(
+            &report,
+            month_hours,
+            report.balance_hours,
+            resolve_render_timestamp(),
+        )?;
+        Ok(ExportFile {
+            file_name: format!(
+                "stundenzettel-{}-{year}-{month:02}.pdf",
+                file_name_slug(&report.sales_person.name)
+            )
+            .into(),
+            content_type: PDF_CONTENT_TYPE,
+            content: content.into(),
+        })
+    }
+}
@@ -0,0 +1,682 @@
+//! Pure renderers for the per-employee PDF documents: the personal schedule
+//! (own blocks over several weeks) and the signable monthly timesheet.
+//!
+//! Same pipeline as [`crate::pdf_render`]: `printpdf` 0.7 with the builtin
+//! Helvetica fonts, fixed PDF metadata (`FIXED_METADATA_TIMESTAMP`,
+//! `PDF_PRODUCER`) and a visible `Erstellt am …` timestamp that the caller
+//! passes in already converted to local time.
+//!
+//! # Layout
+//!
+//! - Portrait A4 (210×297 mm), one text line per row. Both documents are
+//!   lists, so content that does not fit continues on a new page (unlike the
+//!   weekly grid, which has to fit on one landscape page).
+//! - Header on the first page: bold title left, timestamp right.
+//! - Hours are printed with two decimals and a decimal comma (`8,00 h`),
+//!   balances with an explicit sign (`+2,50 h`).
+//! - The timesheet ends with two signature lines (employee, employer). They
+//!   are kept together on one page.
+
+use service::block::Block;
+use service::reporting::{EmployeeReport, ExtraHoursReportCategory, GroupedReportHours};
+use service::sales_person::SalesPerson;
+use service::ServiceError;
+
+use printpdf::{
+    BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference,
+    Point,
+};
+
+use crate::pdf_render::{
+    day_label, format_render_timestamp, FIXED_METADATA_TIMESTAMP, PDF_PRODUCER,
+};
+
+/// Portrait A4 width in mm.
+const PAGE_WIDTH_MM: f32 = 210.0;
+/// Portrait A4 height in mm.
+const PAGE_HEIGHT_MM: f32 = 297.0;
+/// Left and right page margin in mm.
+const MARGIN_X_MM: f32 = 18.0;
+/// Top page margin in mm.
+const MARGIN_TOP_MM: f32 = 18.0;
+/// Bottom page margin in mm.
+const MARGIN_BOTTOM_MM: f32 = 18.0;
+
+/// Baseline-to-baseline distance of a table row.
+const ROW_HEIGHT_MM: f32 = 5.5;
+/// Extra space before a section heading.
+const SECTION_GAP_MM: f32 = 4.0;
+/// Space reserved for the two signature lines including their labels.
+const SIGNATURE_BLOCK_MM: f32 = 30.0;
+
+/// Font size of the bold document title.
+const TITLE_FONT_PT: f32 = 14.0;
+/// Font size of the `Erstellt am …` timestamp.
+const TIMESTAMP_FONT_PT: f32 = 9.0;
+/// Font size of section headings (one per week, table headers).
+const SECTION_FONT_PT: f32 = 11.0;
+/// Font size of table rows.
+const ROW_FONT_PT: f32 = 10.0;
+
+/// Column offsets (from the left margin) of the schedule table.
+const SCHEDULE_COLUMNS_MM: [f32; 3] = [0.0, 35.0, 140.0];
+/// Column offsets (from the left margin) of the timesheet day table.
+const TIMESHEET_COLUMNS_MM: [f32; 4] = [0.0, 25.0, 40.0, 140.0];
+
+/// One ISO week of a personal schedule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleWeek {
+    pub year: u32,
+    pub week: u8,
+    pub blocks: std::sync::Arc<[Block]>,
+}
+
+/// Render the personal schedule of `sales_person` for the given weeks.
+///
+/// Blocks are printed per week in date and start-time order, followed by the
+/// week total; weeks without blocks say so explicitly. The overall total of
+/// all weeks closes the document.
+///
+/// # Errors
+///
+/// Returns [`ServiceError::InternalError`] if the underlying `printpdf`
+/// serializer fails.
+pub fn render_personal_schedule_pdf(
+    sales_person: &SalesPerson,
+    weeks: &[ScheduleWeek],
+    render_timestamp: time::OffsetDateTime,
+) -> Result<Vec<u8>, ServiceError> {
+    let title = format!("Dienstplan {}", sales_person.name);
+    let mut page = PageWriter::new(&title, render_timestamp)?;
+
+    if let (Some(first), Some(last)) = (weeks.first(), weeks.last()) {
+        page.text_row(&[&format!(
+            "KW {:02}/{} bis KW {:02}/{}",
+            first.week, first.year, last.week, last.year
+        )]);
+    }
+
+    let mut total_hours = 0.0;
+    for week in weeks {
+        let mut blocks: Vec<&Block> = week.blocks.iter().collect();
+        blocks.sort_by_key(|block| (block.day_of_week, block.from, block.to));
+
+        page.section(&format_week_heading(week.year, week.week));
+        if blocks.is_empty() {
+            page.text_row(&["Keine Schichten"]);
+            continue;
+        }
+        let mut week_hours = 0.0;
+        for block in blocks {
+            let hours = block_hours(block);
+            week_hours += hours;
+            page.row(
+                &SCHEDULE_COLUMNS_MM,
+                &[
+                    &format_block_day(block),
+                    &format!(
+                        "{:02}:{:02} - {:02}:{:02}",
+                        block.from.hour(),
+                        block.from.minute(),
+                        block.to.hour(),
+                        block.to.minute()
+                    ),
+                    &format_hours(hours),
+                ],
+                false,
+            );
+        }
+        page.row(
+            &SCHEDULE_COLUMNS_MM,
+            &["Summe", "", &format_hours(week_hours)],
+            true,
+        );
+        total_hours += week_hours;
+    }
+
+    page.section("Gesamt");
+    page.row(
+        &SCHEDULE_COLUMNS_MM,
+        &["Geplante Stunden", "", &format_hours(total_hours)],
+        true,
+    );
+
+    page.finish()
+}
+
+/// Render the signable timesheet for one month of `report`.
+///
+/// `month` is the matching entry of `EmployeeReport::by_month`; its day list
+/// (shift plan, extra work, absences, holidays) becomes the day table. The
+/// summary lists target and actual hours per category, the month balance and
+/// `running_balance` — the balance of the year including carryover at the end
+/// of the month.
+///
+/// # Errors
+///
+/// Returns [`ServiceError::InternalError`] if the underlying `printpdf`
+/// serializer fails.
+pub fn render_monthly_timesheet_pdf(
+    report: &EmployeeReport,
+    month: &GroupedReportHours,
+    running_balance: f32,
+    render_timestamp: time::OffsetDateTime,
+) -> Result<Vec<u8>, ServiceError> {
+    let from = month.from.to_date();
+    let to = month.to.to_date();
+    let title = format!("Stundenzettel {} {}", month_name(from.month()), from.year());
+    let mut page = PageWriter::new(&title, render_timestamp)?;
+
+    page.text_row(&[&format!("Mitarbeiter: {}", report.sales_person.name)]);
+    if let Some(personnel_number) = &report.sales_person.personnel_number {
+        page.text_row(&[&format!("Personalnummer: {personnel_number}")]);
+    }
+    page.text_row(&[&format!(
+        "Zeitraum: {} - {}",
+        format_date(from),
+        format_date(to)
+    )]);
+
+    page.section("Tage");
+    page.row(
+        &TIMESHEET_COLUMNS_MM,
+        &["Datum", "Tag", "Art", "Stunden"],
+        true,
+    );
+    if month.days.is_empty() {
+        page.text_row(&["Keine Einträge"]);
+    }
+    for day in month.days.iter() {
+        page.row(
+            &TIMESHEET_COLUMNS_MM,
+            &[
+                &format_date(day.date),
+                day_label(day.date.weekday().into()),
+                &category_label(&day.category),
+                &format_hours(day.hours),
+            ],
+            false,
+        );
+    }
+
+    page.section("Zusammenfassung");
+    let summary: [(&str, f32); 9] = [
+        ("Sollstunden", month.expected_hours),
+        ("Geleistete Stunden", month.overall_hours),
+        ("davon Schichtplan", month.shiftplan_hours),
+        ("davon Mehrarbeit", month.extra_work_hours),
+        ("Urlaub", month.vacation_hours),
+        ("Krankheit", month.sick_leave_hours),
+        ("Feiertage", month.holiday_hours),
+        ("Unbezahlter Urlaub", month.unpaid_leave_hours),
+        ("Ehrenamt", month.volunteer_hours),
+    ];
+    for (label, hours) in summary {
+        page.row(
+            &SCHEDULE_COLUMNS_MM,
+            &[label, "", &format_hours(hours)],
+            false,
+        );
+    }
+    page.row(
+        &SCHEDULE_COLUMNS_MM,
+        &["Saldo Monat", "", &format_balance(month.balance)],
+        true,
+    );
+    page.row(
+        &SCHEDULE_COLUMNS_MM,
+        &[
+            &format!("Saldo gesamt zum {}", format_date(to)),
+            "",
+            &format_balance(running_balance),
+        ],
+        true,
+    );
+
+    page.signature_lines(&[
+        "Datum, Unterschrift Mitarbeiter",
+        "Datum, Unterschrift Arbeitgeber",
+    ]);
+
+    page.finish()
+}
+
+/// Cursor over the pages of a list document: writes rows top to bottom and
+/// starts a new page when the bottom margin is reached.
+struct PageWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    font: IndirectFontRef,
+    font_bold: IndirectFontRef,
+    /// Baseline of the last written row.
+    y: f32,
+}
+
+impl PageWriter {
+    fn new(title: &str, render_timestamp: time::OffsetDateTime) -> Result<Self, ServiceError> {
+        let (doc, page_index, layer_index) =
+            PdfDocument::new(title, Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), "Layer 1");
+        let doc = doc
+            .with_creation_date(FIXED_METADATA_TIMESTAMP)
+            .with_mod_date(FIXED_METADATA_TIMESTAMP)
+            .with_metadata_date(FIXED_METADATA_TIMESTAMP)
+            .with_producer(PDF_PRODUCER)
+            .with_creator(PDF_PRODUCER)
+            .with_author(PDF_PRODUCER);
+        let font = doc
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(|_| ServiceError::InternalError)?;
+        let font_bold = doc
+            .add_builtin_font(BuiltinFont::HelveticaBold)
+            .map_err(|_| ServiceError::InternalError)?;
+        let layer = doc.get_page(page_index).get_layer(layer_index);
+
+        let header_y = PAGE_HEIGHT_MM - MARGIN_TOP_MM;
+        layer.use_text(
+            title,
+            TITLE_FONT_PT,
+            Mm(MARGIN_X_MM),
+            Mm(header_y),
+            &font_bold,
+        );
+        layer.use_text(
+            format_render_timestamp(render_timestamp),
+            TIMESTAMP_FONT_PT,
+            Mm(PAGE_WIDTH_MM - MARGIN_X_MM - 55.0),
+            Mm(header_y),
+            &font,
+        );
+
+        Ok(Self {
+            doc,
+            layer,
+            font,
+            font_bold,
+            y: header_y - 3.0,
+        })
+    }
+
+    /// Move the cursor down by `height`, continuing on a new page if the row
+    /// would cross the bottom margin. Returns the new baseline.
+    fn advance(&mut self, height: f32) -> f32 {
+        if self.y - height < MARGIN_BOTTOM_MM {
+            self.new_page();
+        }
+        self.y -= height;
+        self.y
+    }
+
+    fn new_page(&mut self) {
+        let (page_index, layer_index) =
+            self.doc
+                .add_page(Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), "Layer 1");
+        self.layer = self.doc.get_page(page_index).get_layer(layer_index);
+        self.y = PAGE_HEIGHT_MM - MARGIN_TOP_MM + ROW_HEIGHT_MM;
+    }
+
+    fn section(&mut self, heading: &str) {
+        self.advance(SECTION_GAP_MM);
+        let y = self.advance(ROW_HEIGHT_MM + 1.0);
+        self.layer.use_text(
+            heading,
+            SECTION_FONT_PT,
+            Mm(MARGIN_X_MM),
+            Mm(y),
+            &self.font_bold,
+        );
+    }
+
+    fn text_row(&mut self, cells: &[&str]) {
+        self.row(&[0.0], cells, false);
+    }
+
+    fn row(&mut self, columns_mm: &[f32], cells: &[&str], bold: bool) {
+        let y = self.advance(ROW_HEIGHT_MM);
+        let font = if bold { &self.font_bold } else { &self.font };
+        for (offset, cell) in columns_mm.iter().zip(cells) {
+            if !cell.is_empty() {
+                self.layer
+                    .use_text(*cell, ROW_FONT_PT, Mm(MARGIN_X_MM + offset), Mm(y), font);
+            }
+        }
+    }
+
+    /// Signature lines side by side with their label below.
+    fn signature_lines(&mut self, labels: &[&str]) {
+        if self.y - SIGNATURE_BLOCK_MM < MARGIN_BOTTOM_MM {
+            self.new_page();
+        }
+        let line_y = self.advance(SIGNATURE_BLOCK_MM - ROW_HEIGHT_MM);
+        let label_y = self.advance(ROW_HEIGHT_MM);
+        let slot_width = (PAGE_WIDTH_MM - 2.0 * MARGIN_X_MM) / labels.len().max(1) as f32;
+        for (i, label) in labels.iter().enumerate() {
+            let x = MARGIN_X_MM + i as f32 * slot_width;
+            self.layer.add_line(Line {
+                points: vec![
+                    (Point::new(Mm(x), Mm(line_y)), false),
+                    (Point::new(Mm(x + slot_width - 10.0), Mm(line_y)), false),
+                ],
+                is_closed: false,
+            });
+            self.layer
+                .use_text(*label, TIMESTAMP_FONT_PT, Mm(x), Mm(label_y), &self.font);
+        }
+    }
+
+    fn finish(self) -> Result<Vec<u8>, ServiceError> {
+        self.doc
+            .save_to_bytes()
+            .map_err(|_| ServiceError::InternalError)
+    }
+}
+
+// -----------------------------------------------------------------------
+// Formatting helpers.
+// -----------------------------------------------------------------------
+
+/// Week heading `KW NN (DD.MM. - DD.MM.YYYY)`.
+fn format_week_heading(year: u32, week: u8) -> String {
+    let monday = time::Date::from_iso_week_date(year as i32, week, time::Weekday::Monday);
+    let sunday = time::Date::from_iso_week_date(year as i32, week, time::Weekday::Sunday);
+    match (monday, sunday) {
+        (Ok(monday), Ok(sunday)) => format!(
+            "KW {week:02} ({:02}.{:02}. - {})",
+            monday.day(),
+            monday.month() as u8,
+            format_date(sunday)
+        ),
+        _ => format!("KW {week:02} ({year})"),
+    }
+}
+
+/// `Mo 29.06.2026`; falls back to the day label alone for an invalid week.
+fn format_block_day(block: &Block) -> String {
+    match block.date() {
+        Ok(date) => format!("{} {}", day_label(block.day_of_week), format_date(date)),
+        Err(_) => day_label(block.day_of_week).to_string(),
+    }
+}
+
+fn block_hours(block: &Block) -> f32 {
+    (block.to - block.from).as_seconds_f32() / 3600.0
+}
+
+fn format_date(date: time::Date) -> String {
+    format!(
+        "{:02}.{:02}.{}",
+        date.day(),
+        date.month() as u8,
+        date.year()
+    )
+}
+
+fn format_hours(hours: f32) -> String {
+    format!("{hours:.2} h").replace('.', ",")
+}
+
+fn format_balance(hours: f32) -> String {
+    format!("{hours:+.2} h").replace('.', ",")
+}
+
+fn month_name(month: time::Month) -> &'static str {
+    match month {
+        time::Month::January => "Januar",
+        time::Month::February => "Februar",
+        time::Month::March => "März",
+        time::Month::April => "April",
+        time::Month::May => "Mai",
+        time::Month::June => "Juni",
+        time::Month::July => "Juli",
+        time::Month::August => "August",
+        time::Month::September => "September",
+        time::Month::October => "Oktober",
+        time::Month::November => "November",
+        time::Month::December => "Dezember",
+    }
+}
+
+/// Label of a day-list entry, wording as in the employee report view.
+fn category_label(category: &ExtraHoursReportCategory) -> String {
+    match category {
+        ExtraHoursReportCategory::Shiftplan => "Schichtplan".into(),
+        ExtraHoursReportCategory::ExtraWork => "Mehrarbeit".into(),
+        ExtraHoursReportCategory::Vacation => "Urlaub".into(),
+        ExtraHoursReportCategory::SickLeave => "Krankheit".into(),
+        ExtraHoursReportCategory::Holiday => "Feiertag".into(),
+        ExtraHoursReportCategory::Unavailable => "Nicht verfügbar".into(),
+        ExtraHoursReportCategory::UnpaidLeave => "Unbezahlter Urlaub".into(),
+        ExtraHoursReportCategory::VolunteerWork => "Ehrenamt".into(),
+        ExtraHoursReportCategory::Custom(custom) => custom
+            .get()
+            .map(|custom| custom.name.to_string())
+            .unwrap_or_else(|| "Sonstiges".into()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use service::reporting::WorkingHoursDay;
+    use shifty_utils::{DayOfWeek, ShiftyDate};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    const FIXED_RENDER_TIMESTAMP: time::OffsetDateTime =
+        time::macros::datetime!(2026-07-03 17:15 UTC);
+
+    fn sales_person() -> SalesPerson {
+        SalesPerson {
+            id: Uuid::from_u128(1),
+            name: Arc::from("Anna Schmidt"),
+            background_color: Arc::from("#ffffff"),
+            is_paid: Some(true),
+            inactive: false,
+            personnel_number: Some(Arc::from("4711")),
+            deleted: None,
+            version: Uuid::from_u128(2),
+        }
+    }
+
+    fn block(week: u8, day_of_week: DayOfWeek, from_h: u8, to_h: u8) -> Block {
+        Block {
+            year: 2026,
+            week,
+            sales_person: Some(Arc::new(sales_person())),
+            day_of_week,
+            from: time::Time::from_hms(from_h, 0, 0).unwrap(),
+            to: time::Time::from_hms(to_h, 0, 0).unwrap(),
+            bookings: Arc::new([]),
+            slots: Arc::new([]),
+        }
+    }
+
+    fn month_group(days: Vec<WorkingHoursDay>) -> GroupedReportHours {
+        GroupedReportHours {
+            from: ShiftyDate::from_ymd(2026, 3, 1).unwrap(),
+            to: ShiftyDate::from_ymd(2026, 3, 31).unwrap(),
+            year: 2026,
+            week: 9,
+            contract_weekly_hours: 20.0,
+            expected_hours: 80.0,
+            dynamic_hours: 0.0,
+            overall_hours: 82.5,
+            balance: 2.5,
+            days_per_week: 5,
+            workdays_per_week: 5.0,
+            shiftplan_hours: 74.5,
+            extra_work_hours: 8.0,
+            vacation_hours: 8.0,
+            sick_leave_hours: 0.0,
+            holiday_hours: 4.0,
+            unpaid_leave_hours: 0.0,
+            volunteer_hours: 0.0,
+            custom_extra_hours: Arc::new([]),
+            days: days.into(),
+        }
+    }
+
+    fn employee_report(month: GroupedReportHours) -> EmployeeReport {
+        EmployeeReport {
+            sales_person: Arc::new(sales_person()),
+            balance_hours: 10.0,
+            overall_hours: 82.5,
+            expected_hours: 80.0,
+            dynamic_hours: 0.0,
+            shiftplan_hours: 74.5,
+            extra_work_hours: 8.0,
+            vacation_hours: 8.0,
+            sick_leave_hours: 0.0,
+            holiday_hours: 4.0,
+            unpaid_leave_hours: 0.0,
+            volunteer_hours: 0.0,
+            vacation_carryover: 0,
+            vacation_days: 1.0,
+            vacation_entitlement: 25.0,
+            sick_leave_days: 0.0,
+            holiday_days: 1.0,
+            absence_days: 1.0,
+            carryover_hours: 7.5,
+            custom_extra_hours: Arc::new([]),
+            by_week: Arc::new([]),
+            by_month: Arc::new([month]),
+        }
+    }
+
+    /// printpdf writes builtin-font text as uppercase hex (WinAnsi, one byte
+    /// per character) into the content stream.
+    fn contains_text(bytes: &[u8], text: &str) -> bool {
+        let hex: String = text.bytes().map(|b| format!("{b:02X}")).collect();
+        bytes
+            .windows(hex.len())
+            .any(|window| window == hex.as_bytes())
+    }
+
+    #[test]
+    fn schedule_lists_blocks_and_totals_per_week() {
+        let weeks = [
+            ScheduleWeek {
+                year: 2026,
+                week: 27,
+                blocks: Arc::new([
+                    block(27, DayOfWeek::Wednesday, 14, 18),
+                    block(27, DayOfWeek::Monday, 8, 12),
+                ]),
+            },
+            ScheduleWeek {
+                year: 2026,
+                week: 28,
+                blocks: Arc::new([]),
+            },
+        ];
+        let bytes = render_personal_schedule_pdf(&sales_person(), &weeks, FIXED_RENDER_TIMESTAMP)
+            .expect("render succeeds");
+
+        assert_eq!(&bytes[..4], b"%PDF");
+        for text in [
+            "Dienstplan Anna Schmidt",
+            "Erstellt am 03.07.2026 17:15 Uhr",
+            "KW 27 (29.06. - 05.07.2026)",
+            "Mo 29.06.2026",
+            "08:00 - 12:00",
+            "Mi 01.07.2026",
+            "8,00 h",
+            "Keine Schichten",
+        ] {
+            assert!(contains_text(&bytes, text), "{text} not found in PDF");
+        }
+    }
+
+    #[test]
+    fn schedule_blocks_sorted_by_day_and_time() {
+        let weeks = [ScheduleWeek {
+            year: 2026,
+            week: 27,
+            blocks: Arc::new([
+                block(27, DayOfWeek::Wednesday, 14, 18),
+                block(27, DayOfWeek::Monday, 8, 12),
+            ]),
+        }];
+        let bytes = render_personal_schedule_pdf(&sales_person(), &weeks, FIXED_RENDER_TIMESTAMP)
+            .expect("render succeeds");
+        let position = |text: &str| {
+            let hex: String = text.bytes().map(|b| format!("{b:02X}")).collect();
+            bytes
+                .windows(hex.len())
+                .position(|window| window == hex.as_bytes())
+                .expect("text present")
+        };
+        assert!(position("Mo 29.06.2026") < position("Mi 01.07.2026"));
+    }
+
+    #[test]
+    fn timesheet_contains_days_summary_and_signatures() {
+        let month = month_group(vec![
+            WorkingHoursDay {
+                date: time::macros::date!(2026 - 03 - 02),
+                hours: 6.5,
+                category: ExtraHoursReportCategory::Shiftplan,
+            },
+            WorkingHoursDay {
+                date: time::macros::date!(2026 - 03 - 03),
+                hours: 8.0,
+                category: ExtraHoursReportCategory::Vacation,
+            },
+            WorkingHoursDay {
+                date: time::macros::date!(2026 - 03 - 04),
+                hours: 4.0,
+                category: ExtraHoursReportCategory::Holiday,
+            },
+        ]);
+        let report = employee_report(month.clone());
+        let bytes = render_monthly_timesheet_pdf(&report, &month, 10.0, FIXED_RENDER_TIMESTAMP)
+            .expect("render succeeds");
+
+        assert_eq!(&bytes[..4], b"%PDF");
+        for text in [
+            "Stundenzettel M",
+            "Mitarbeiter: Anna Schmidt",
+            "Personalnummer: 4711",
+            "Zeitraum: 01.03.2026 - 31.03.2026",
+            "02.03.2026",
+            "Schichtplan",
+            "6,50 h",
+            "Urlaub",
+            "Feiertag",
+            "Saldo Monat",
+            "+2,50 h",
+            "Saldo gesamt zum 31.03.2026",
+            "+10,00 h",
+            "Datum, Unterschrift Mitarbeiter",
+            "Datum, Unterschrift Arbeitgeber",
+        ] {
+            assert!(contains_text(&bytes, text), "{text} not found in PDF");
+        }
+    }
+
+    #[test]
+    fn long_timesheet_continues_on_next_page() {
+        let days = (0..80)
+            .map(|i| WorkingHoursDay {
+                date: time::macros::date!(2026 - 03 - 01) + time::Duration::days(i % 31),
+                hours: 1.0,
+                category: ExtraHoursReportCategory::Shiftplan,
+            })
+            .collect();
+        let month = month_group(days);
+        let report = employee_report(month.clone());
+        let bytes = render_monthly_timesheet_pdf(&report, &month, 0.0, FIXED_RENDER_TIMESTAMP)
+            .expect("render succeeds");
+        let pages = bytes
+            .windows(b"/Type/Page/".len())
+            .filter(|window| *window == b"/Type/Page/")
+            .count();
+        assert!(pages >= 2, "expected at least two pages, got {pages}");
+    }
+
+    #[test]
+    fn hours_use_decimal_comma_and_signed_balance() {
+        assert_eq!(format_hours(7.5), "7,50 h");
+        assert_eq!(format_balance(2.5), "+2,50 h");
+        assert_eq!(format_balance(-1.25), "-1,25 h");
+    }
+}
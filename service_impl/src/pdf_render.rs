@@ -70,10 +70,10 @@ use printpdf::{BuiltinFont, Mm, PdfDocument, Rect};
 /// Fixed metadata timestamp — 2000-01-01T00:00:00Z. Used for
 /// `creation_date`, `modification_date`, `metadata_date` so that repeated
 /// renders emit identical PDF-metadata bytes (D-50-13).
-const FIXED_METADATA_TIMESTAMP: time::OffsetDateTime = time::macros::datetime!(2000-01-01 0:00 UTC);
+pub(crate) const FIXED_METADATA_TIMESTAMP: time::OffsetDateTime = time::macros::datetime!(2000-01-01 0:00 UTC);
 
 /// Fixed producer/creator string embedded in the PDF metadata (D-50-13).
-const PDF_PRODUCER: &str = "shifty-pdf-export";
+pub(crate) const PDF_PRODUCER: &str = "shifty-pdf-export";
 
 // -----------------------------------------------------------------------
 // Layout constants — RESEARCH §Architecture Patterns Pattern 2.
@@ -182,16 +182,89 @@ pub fn render_shiftplan_week_pdf(
         .map_err(|_| ServiceError::InternalError)?;
 
     let layer = doc.get_page(page_index).get_layer(layer_index);
+    draw_week_page(&layer, week, sales_persons, &title, render_timestamp, &font, &font_bold);
 
+    doc.save_to_bytes().map_err(|_| ServiceError::InternalError)
+}
+
+/// Render several shift plans of the same ISO week into one PDF — one page
+/// per plan, in the given order. Each page carries the plan name in its
+/// header (`Schichtplan <Name> KW NN (YYYY)`); the grid layout is identical
+/// to [`render_shiftplan_week_pdf`].
+///
+/// The same `sales_persons` contract applies (caller pre-filters to active
+/// rows). An empty `plans` slice still yields a valid single-page PDF with
+/// the plain week header.
+///
+/// # Errors
+///
+/// Returns [`ServiceError::InternalError`] if the underlying `printpdf`
+/// serializer fails.
+pub fn render_combined_week_pdf(
+    plans: &[(&str, &ShiftplanWeek)],
+    sales_persons: &[SalesPerson],
+    header_year: u32,
+    header_week: u8,
+    render_timestamp: time::OffsetDateTime,
+) -> Result<Vec<u8>, ServiceError> {
+    let doc_title = build_page_header(header_year, header_week);
+
+    let (doc, page_index, layer_index) =
+        PdfDocument::new(&doc_title, Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), "Layer 1");
+
+    let doc = doc
+        .with_creation_date(FIXED_METADATA_TIMESTAMP)
+        .with_mod_date(FIXED_METADATA_TIMESTAMP)
+        .with_metadata_date(FIXED_METADATA_TIMESTAMP)
+        .with_producer(PDF_PRODUCER)
+        .with_creator(PDF_PRODUCER)
+        .with_author(PDF_PRODUCER);
+
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|_| ServiceError::InternalError)?;
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|_| ServiceError::InternalError)?;
+
+    if plans.is_empty() {
+        let layer = doc.get_page(page_index).get_layer(layer_index);
+        render_page_header(&layer, &doc_title, render_timestamp, &font, &font_bold);
+    }
+    for (i, (plan_name, week)) in plans.iter().enumerate() {
+        let layer = if i == 0 {
+            doc.get_page(page_index).get_layer(layer_index)
+        } else {
+            let (page, layer) = doc.add_page(Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), "Layer 1");
+            doc.get_page(page).get_layer(layer)
+        };
+        let title = build_plan_page_header(plan_name, header_year, header_week);
+        draw_week_page(&layer, week, sales_persons, &title, render_timestamp, &font, &font_bold);
+    }
+
+    doc.save_to_bytes().map_err(|_| ServiceError::InternalError)
+}
+
+/// Draw one week grid (header band, day-of-week row, slot boxes) onto
+/// `layer`. Shared by the single-plan and the combined multi-plan renderer.
+fn draw_week_page(
+    layer: &printpdf::PdfLayerReference,
+    week: &ShiftplanWeek,
+    sales_persons: &[SalesPerson],
+    title: &str,
+    render_timestamp: time::OffsetDateTime,
+    font: &printpdf::IndirectFontRef,
+    font_bold: &printpdf::IndirectFontRef,
+) {
     // 1) Page-Header (title left bold + timestamp right, D-50-09 / PDF-02).
-    render_page_header(&layer, header_year, header_week, render_timestamp, &font, &font_bold);
+    render_page_header(layer, title, render_timestamp, font, font_bold);
 
     // 2) Day-of-week header row + slot columns (D-50-08 dynamic Sunday).
     let visible_days = compute_visible_days(week);
     let n_days = visible_days.len();
     if n_days == 0 {
-        // Degenerate case: empty week — still emit a valid PDF.
-        return doc.save_to_bytes().map_err(|_| ServiceError::InternalError);
+        // Degenerate case: empty week — the page keeps only its header.
+        return;
     }
     let col_width_mm = compute_col_width_mm(n_days);
 
@@ -207,7 +280,7 @@ pub fn render_shiftplan_week_pdf(
             DAY_HEADER_FONT_PT,
             Mm(col_x + 1.5),
             Mm(day_header_y),
-            &font_bold,
+            font_bold,
         );
     }
 
@@ -248,15 +321,15 @@ pub fn render_shiftplan_week_pdf(
         let render_count = day.len().min(rows_fitting);
         for (row_i, ds) in day.iter().take(render_count).enumerate() {
             render_slot_box(
-                &layer,
+                layer,
                 ds.slot,
                 &ds.name_lines,
                 col_x,
                 row_y_bottoms[row_i],
                 col_width_mm,
                 row_heights[row_i],
-                &font,
-                &font_bold,
+                font,
+                font_bold,
             );
         }
         if day.len() > rows_fitting {
@@ -271,12 +344,11 @@ pub fn render_shiftplan_week_pdf(
                 NAME_FONT_PT,
                 Mm(col_x + SLOT_TEXT_H_PADDING_MM),
                 Mm(marker_y),
-                &font,
+                font,
             );
         }
     }
 
-    doc.save_to_bytes().map_err(|_| ServiceError::InternalError)
 }
 
 /// Per-day, per-slot pre-computed rendering data (used by the row-aligned
@@ -357,8 +429,14 @@ fn build_page_header(year: u32, week: u8) -> String {
     format!("Schichtplan KW {week:02} ({year})")
 }
 
+/// Page-header title for one plan inside a combined PDF:
+/// `Schichtplan <Name> KW NN (YYYY)`.
+fn build_plan_page_header(plan_name: &str, year: u32, week: u8) -> String {
+    format!("Schichtplan {plan_name} KW {week:02} ({year})")
+}
+
 /// Short two-letter day label for the column header row.
-fn day_label(dow: DayOfWeek) -> &'static str {
+pub(crate) fn day_label(dow: DayOfWeek) -> &'static str {
     match dow {
         DayOfWeek::Monday => "Mo",
         DayOfWeek::Tuesday => "Di",
@@ -404,7 +482,7 @@ fn compute_col_width_mm(n_days: usize) -> f32 {
 ///
 /// No implicit timezone conversion — the caller provided the value already
 /// in the desired local time (Wave 3 does `now_local()` with UTC fallback).
-fn format_render_timestamp(ts: time::OffsetDateTime) -> String {
+pub(crate) fn format_render_timestamp(ts: time::OffsetDateTime) -> String {
     format!(
         "Erstellt am {:02}.{:02}.{} {:02}:{:02} Uhr",
         ts.day(),
@@ -419,8 +497,7 @@ fn format_render_timestamp(ts: time::OffsetDateTime) -> String {
 /// right (D-50-09 / PDF-02). Both share one baseline near the top of the page.
 fn render_page_header(
     layer: &printpdf::PdfLayerReference,
-    year: u32,
-    week: u8,
+    title: &str,
     ts: time::OffsetDateTime,
     font: &printpdf::IndirectFontRef,
     font_bold: &printpdf::IndirectFontRef,
@@ -428,7 +505,7 @@ fn render_page_header(
     let header_y = PAGE_HEIGHT_MM - MARGIN_TOP_MM - 5.0;
     // Title left, bold.
     layer.use_text(
-        build_page_header(year, week),
+        title,
         HEADER_FONT_PT,
         Mm(MARGIN_LEFT_MM),
         Mm(header_y),
@@ -535,7 +612,7 @@ fn compute_slot_box_height_mm(duration_hours: f32, name_line_count: usize) -> f3
 /// point size. Uses average glyph advance ≈ 0.53 × font-size (empirical
 /// mean for Helvetica). Conservative enough for wrap decisions where a
 /// few millimetres slack is acceptable.
-fn approx_text_width_mm(text: &str, font_pt: f32) -> f32 {
+pub(crate) fn approx_text_width_mm(text: &str, font_pt: f32) -> f32 {
     const PT_TO_MM: f32 = 0.352_777_78;
     let avg_advance_mm = font_pt * 0.53 * PT_TO_MM;
     text.chars().count() as f32 * avg_advance_mm
@@ -723,6 +800,7 @@ mod test {
             background_color: Arc::from("#ffffff"),
             is_paid,
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::from_u128(0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff),
         }
@@ -860,6 +938,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn combined_pdf_has_one_header_per_plan() {
+        let laden = empty_week(2026, 27);
+        let cafe = empty_week(2026, 27);
+        let bytes = render_combined_week_pdf(
+            &[("Laden", &laden), ("Cafe", &cafe)],
+            &[],
+            2026,
+            27,
+            FIXED_RENDER_TIMESTAMP,
+        )
+        .expect("render succeeds");
+        assert_eq!(&bytes[..4], b"%PDF");
+        for title in ["Schichtplan Laden KW 27 (2026)", "Schichtplan Cafe KW 27 (2026)"] {
+            let hex = encode_ascii_to_pdf_hex(title);
+            assert!(
+                find_subsequence(&bytes, hex.as_bytes()).is_some(),
+                "page header '{title}' not found in PDF bytes",
+            );
+        }
+    }
+
+    #[test]
+    fn combined_pdf_without_plans_keeps_week_header() {
+        let bytes = render_combined_week_pdf(&[], &[], 2026, 27, FIXED_RENDER_TIMESTAMP)
+            .expect("render succeeds");
+        let hex = encode_ascii_to_pdf_hex("Schichtplan KW 27 (2026)");
+        assert!(find_subsequence(&bytes, hex.as_bytes()).is_some());
+    }
+
     // ---------------------------------------------------------------
     // Test C — all active sales-persons appear (Slot-Box layout).
     // ---------------------------------------------------------------
@@ -921,6 +1029,10 @@ mod test {
     #[test]
     fn build_page_header_produces_expected_text() {
         assert_eq!(build_page_header(2026, 27), "Schichtplan KW 27 (2026)");
+        assert_eq!(
+            build_plan_page_header("Laden", 2026, 3),
+            "Schichtplan Laden KW 03 (2026)"
+        );
         assert_eq!(build_page_header(2026, 1), "Schichtplan KW 01 (2026)");
         assert_eq!(build_page_header(1999, 52), "Schichtplan KW 52 (1999)");
     }
@@ -29,7 +29,7 @@ use service::{
     pdf_shiftplan::PdfShiftplanService,
     permission::Authentication,
     sales_person::{SalesPerson, SalesPersonService},
-    shiftplan::ShiftplanViewService,
+    shiftplan::{ShiftplanViewService, ShiftplanWeek},
     week_status::{WeekStatus, WeekStatusService},
     PermissionService, ServiceError, ValidationFailureItem,
 };
@@ -133,17 +133,8 @@ impl<Deps: PdfShiftplanServiceDeps + 'static> PdfShiftplanService for PdfShiftpl
     ) -> Result<Vec<u8>, ServiceError> {
         // 1) WeekStatus-Gate — Defense-in-Depth. Nur Planned/Locked freigegeben
         //    (D-49-06). Kein View/get_all-Call bei Ablehnung → zero side effects.
-        let status = self
-            .week_status_service
-            .get_week_status(year, calendar_week, context.clone(), tx.clone())
+        self.check_week_released(year, calendar_week, context.clone(), tx.clone())
             .await?;
-        if !matches!(status, WeekStatus::Planned | WeekStatus::Locked) {
-            return Err(ServiceError::ValidationError(Arc::from([
-                ValidationFailureItem::InvalidValue(Arc::from(format!(
-                    "Woche KW{calendar_week:02}/{year} ist im Status {status:?} — kein Download"
-                ))),
-            ])));
-        }
 
         // 2) View-Read (D-49-07: caller-context weitergereicht).
         let week_view = self
@@ -168,6 +159,75 @@ impl<Deps: PdfShiftplanServiceDeps + 'static> PdfShiftplanService for PdfShiftpl
             render_timestamp,
         )
     }
+
+    async fn render_combined_week_pdf(
+        &self,
+        shiftplans: Arc<[(Uuid, Arc<str>)]>,
+        year: u32,
+        calendar_week: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Vec<u8>, ServiceError> {
+        // Das Gate hängt nur an der Woche, nicht am Plan — einmal reicht.
+        self.check_week_released(year, calendar_week, context.clone(), tx.clone())
+            .await?;
+
+        let mut week_views = Vec::with_capacity(shiftplans.len());
+        for (shiftplan_id, _) in shiftplans.iter() {
+            week_views.push(
+                self.shiftplan_view_service
+                    .get_shiftplan_week(
+                        *shiftplan_id,
+                        year,
+                        calendar_week,
+                        context.clone(),
+                        tx.clone(),
+                    )
+                    .await?,
+            );
+        }
+
+        let all_sales_persons = self.sales_person_service.get_all(context, tx).await?;
+        let active_sales_persons = filter_active(&all_sales_persons);
+
+        let pages: Vec<(&str, &ShiftplanWeek)> = shiftplans
+            .iter()
+            .zip(week_views.iter())
+            .map(|((_, name), view)| (name.as_ref(), view))
+            .collect();
+        pdf_render::render_combined_week_pdf(
+            &pages,
+            &active_sales_persons,
+            year,
+            calendar_week,
+            resolve_render_timestamp(),
+        )
+    }
+}
+
+impl<Deps: PdfShiftplanServiceDeps> PdfShiftplanServiceImpl<Deps> {
+    /// WeekStatus-Gate (D-49-06): nur `Planned`/`Locked` sind freigegeben,
+    /// alles andere endet in [`ServiceError::ValidationError`].
+    async fn check_week_released(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        context: Authentication<Deps::Context>,
+        tx: Option<Deps::Transaction>,
+    ) -> Result<(), ServiceError> {
+        let status = self
+            .week_status_service
+            .get_week_status(year, calendar_week, context, tx)
+            .await?;
+        if !matches!(status, WeekStatus::Planned | WeekStatus::Locked) {
+            return Err(ServiceError::ValidationError(Arc::from([
+                ValidationFailureItem::InvalidValue(Arc::from(format!(
+                    "Woche KW{calendar_week:02}/{year} ist im Status {status:?} — kein Download"
+                ))),
+            ])));
+        }
+        Ok(())
+    }
 }
 
 /// Re-export von `service::pdf_shiftplan::filename_for` — hält Test- und
@@ -0,0 +1,1355 @@
+//! Pure renderer for template-driven PDFs: a `TextTemplate` is rendered with
+//! Tera/MiniJinja into a small HTML-like layout markup, which this module
+//! parses and lays out with the same `printpdf` 0.7 pipeline as
+//! [`crate::pdf_render`] (builtin Helvetica fonts, fixed PDF metadata).
+//!
+//! # Markup
+//!
+//! ```text
+//! <document size="A4" orientation="portrait" locale="de" title="Abrechnung">
+//!   <header>Musterladen GmbH</header>
+//!   <logo src="data:image/jpeg;base64,..." width="35" />
+//!   <footer>Vertraulich</footer>
+//!   <h1>Abrechnung 01.01. - 31.03.2026</h1>
+//!   <p>Freitext, wird umbrochen.<br/>Neue Zeile.</p>
+//!   <table widths="3,1,1">
+//!     <tr><th>Name</th><th align="right">Saldo</th></tr>
+//!     <tr><td>Anna</td><td align="right">+2,50</td></tr>
+//!   </table>
+//!   <hr/>
+//!   <pagebreak/>
+//! </document>
+//! ```
+//!
+//! - `<document>` is the mandatory root. `size` is `A4` (default) or `A3`,
+//!   `orientation` `portrait` (default) or `landscape`, `locale` `de`
+//!   (default) or `en` — the locale only affects the built-in labels (creation
+//!   timestamp, page numbers).
+//! - `<header>`, `<footer>` and `<logo>` are repeated on every page. The logo
+//!   is a base64 JPEG data URI (embedded as-is via `DCTDecode`, no image
+//!   decoding); `width` is in mm, the height follows the aspect ratio.
+//! - Blocks: `<h1>`, `<h2>`, `<p>`, `<table>` (`<tr>` with `<th>`/`<td>`),
+//!   `<hr/>`, `<pagebreak/>`. Text is whitespace-collapsed and wrapped;
+//!   `<br/>` forces a line break. Leading rows consisting only of `<th>` cells
+//!   are repeated when a table continues on the next page.
+//! - The usual entities (`&amp;`, `&lt;`, `&#39;`, `&#x2F;`, …) are decoded,
+//!   so the HTML auto-escaping of both engines produces valid markup. Unknown
+//!   elements or attributes are rejected with line and column, so template
+//!   mistakes surface when the PDF is requested instead of as broken output.
+
+use std::fmt;
+
+use base64::Engine;
+use printpdf::{
+    BuiltinFont, ColorBits, ColorSpace, Image, ImageFilter, ImageTransform, ImageXObject,
+    IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Point, Px,
+};
+use service::ServiceError;
+
+use crate::pdf_render::{
+    approx_text_width_mm, format_render_timestamp, FIXED_METADATA_TIMESTAMP, PDF_PRODUCER,
+};
+
+/// Page margin on all sides in mm.
+const MARGIN_MM: f32 = 15.0;
+/// Gap between the header band and the first content line.
+const HEADER_GAP_MM: f32 = 4.0;
+/// Space reserved above the bottom margin for footer and page number.
+const FOOTER_BAND_MM: f32 = 8.0;
+/// Default logo width in mm.
+const DEFAULT_LOGO_WIDTH_MM: f32 = 30.0;
+/// Inner horizontal padding of a table cell.
+const CELL_PADDING_MM: f32 = 1.5;
+/// Vertical gap after a paragraph, heading or table.
+const BLOCK_GAP_MM: f32 = 2.5;
+
+const H1_FONT_PT: f32 = 16.0;
+const H2_FONT_PT: f32 = 13.0;
+const TEXT_FONT_PT: f32 = 10.0;
+const TABLE_FONT_PT: f32 = 9.0;
+const HEADER_FONT_PT: f32 = 11.0;
+const SMALL_FONT_PT: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageSize {
+    A4,
+    A3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    De,
+    En,
+}
+
+/// Parsed layout markup, ready to be rendered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PdfLayout {
+    pub title: String,
+    pub size: PageSize,
+    pub orientation: Orientation,
+    pub locale: Locale,
+    pub header: Vec<String>,
+    pub footer: Vec<String>,
+    pub logo: Option<Logo>,
+    pub blocks: Vec<LayoutBlock>,
+}
+
+/// JPEG logo with the dimensions read from its SOF marker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Logo {
+    pub jpeg: Vec<u8>,
+    pub width_px: usize,
+    pub height_px: usize,
+    pub components: u8,
+    pub width_mm: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutBlock {
+    Heading { level: u8, lines: Vec<String> },
+    Paragraph(Vec<String>),
+    Table(Table),
+    Rule,
+    PageBreak,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    /// Relative column weights; equal columns if `None`.
+    pub widths: Option<Vec<f32>>,
+    pub rows: Vec<Vec<TableCell>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableCell {
+    pub lines: Vec<String>,
+    pub header: bool,
+    pub align_right: bool,
+}
+
+/// Syntax or structure error in the layout markup, with the 1-based position
+/// of the offending token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayoutError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PDF-Layout Zeile {}, Spalte {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl From<LayoutError> for ServiceError {
+    fn from(error: LayoutError) -> Self {
+        ServiceError::ValidationError(std::sync::Arc::from([
+            service::ValidationFailureItem::InvalidValue(error.to_string().into()),
+        ]))
+    }
+}
+
+// -----------------------------------------------------------------------
+// Tokenizer.
+// -----------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    fn error(self, message: impl Into<String>) -> LayoutError {
+        LayoutError {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Text(String, Position),
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+        self_closing: bool,
+        position: Position,
+    },
+    End(String, Position),
+}
+
+struct Tokenizer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    position: Position,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(markup: &'a str) -> Self {
+        Self {
+            chars: markup.chars().peekable(),
+            position: Position { line: 1, column: 1 },
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn read_name(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                name.push(c.to_ascii_lowercase());
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        name
+    }
+
+    /// Skips until (and including) `terminator`.
+    fn skip_until(&mut self, terminator: &str, start: Position) -> Result<(), LayoutError> {
+        let mut buffer = String::new();
+        loop {
+            let c = self
+                .bump()
+                .ok_or_else(|| start.error(format!("`{terminator}` fehlt")))?;
+            buffer.push(c);
+            if buffer.ends_with(terminator) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn tokens(mut self) -> Result<Vec<Token>, LayoutError> {
+        let mut tokens = Vec::new();
+        let mut text = String::new();
+        let mut text_start = self.position;
+        while let Some(&c) = self.chars.peek() {
+            if c != '<' {
+                if text.is_empty() {
+                    text_start = self.position;
+                }
+                text.push(c);
+                self.bump();
+                continue;
+            }
+            if !text.is_empty() {
+                tokens.push(Token::Text(decode_entities(&text), text_start));
+                text.clear();
+            }
+            let start = self.position;
+            self.bump();
+            match self.chars.peek() {
+                Some('!') => {
+                    self.bump();
+                    if self.chars.peek() == Some(&'-') {
+                        self.skip_until("-->", start)?;
+                    } else {
+                        self.skip_until(">", start)?;
+                    }
+                }
+                Some('?') => self.skip_until("?>", start)?,
+                Some('/') => {
+                    self.bump();
+                    let name = self.read_name();
+                    self.skip_whitespace();
+                    if self.bump() != Some('>') {
+                        return Err(start.error(format!("`>` nach </{name} erwartet")));
+                    }
+                    tokens.push(Token::End(name, start));
+                }
+                _ => tokens.push(self.start_tag(start)?),
+            }
+        }
+        if !text.is_empty() {
+            tokens.push(Token::Text(decode_entities(&text), text_start));
+        }
+        Ok(tokens)
+    }
+
+    fn start_tag(&mut self, start: Position) -> Result<Token, LayoutError> {
+        let name = self.read_name();
+        if name.is_empty() {
+            return Err(start.error("Elementname nach `<` erwartet"));
+        }
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some('>') => {
+                    self.bump();
+                    return Ok(Token::Start {
+                        name,
+                        attributes,
+                        self_closing: false,
+                        position: start,
+                    });
+                }
+                Some('/') => {
+                    self.bump();
+                    if self.bump() != Some('>') {
+                        return Err(start.error(format!("`/>` in <{name}> erwartet")));
+                    }
+                    return Ok(Token::Start {
+                        name,
+                        attributes,
+                        self_closing: true,
+                        position: start,
+                    });
+                }
+                Some(_) => {
+                    let attribute_start = self.position;
+                    let attribute = self.read_name();
+                    if attribute.is_empty() {
+                        return Err(
+                            attribute_start.error(format!("Ungültiges Zeichen in <{name}>"))
+                        );
+                    }
+                    self.skip_whitespace();
+                    if self.bump() != Some('=') {
+                        return Err(attribute_start
+                            .error(format!("Attribut `{attribute}` braucht einen Wert")));
+                    }
+                    self.skip_whitespace();
+                    let quote = match self.bump() {
+                        Some(q @ ('"' | '\'')) => q,
+                        _ => {
+                            return Err(attribute_start.error(format!(
+                                "Wert von `{attribute}` muss in Anführungszeichen stehen"
+                            )))
+                        }
+                    };
+                    let mut value = String::new();
+                    loop {
+                        match self.bump() {
+                            Some(c) if c == quote => break,
+                            Some(c) => value.push(c),
+                            None => {
+                                return Err(attribute_start
+                                    .error(format!("Wert von `{attribute}` nicht geschlossen")))
+                            }
+                        }
+                    }
+                    attributes.push((attribute, decode_entities(&value)));
+                }
+                None => return Err(start.error(format!("<{name}> nicht geschlossen"))),
+            }
+        }
+    }
+}
+
+/// Decodes the named entities produced by HTML escaping plus numeric ones.
+/// Unknown sequences stay as they are, so a stray `&` survives unescaped
+/// template output.
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse::<u32>))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+// -----------------------------------------------------------------------
+// Parser.
+// -----------------------------------------------------------------------
+
+/// Parses layout markup as produced by a rendered PDF template.
+pub fn parse_layout(markup: &str) -> Result<PdfLayout, LayoutError> {
+    let tokens = Tokenizer::new(markup).tokens()?;
+    let mut parser = Parser {
+        tokens: tokens.into_iter().peekable(),
+    };
+    parser.skip_blank_text()?;
+    let (attributes, self_closing, position) = match parser.tokens.next() {
+        Some(Token::Start {
+            name,
+            attributes,
+            self_closing,
+            position,
+        }) if name == "document" => (attributes, self_closing, position),
+        Some(token) => return Err(token_position(&token).error("<document> als Wurzel erwartet")),
+        None => return Err(Position { line: 1, column: 1 }.error("Layout ist leer")),
+    };
+
+    let mut layout = PdfLayout {
+        title: String::new(),
+        size: PageSize::A4,
+        orientation: Orientation::Portrait,
+        locale: Locale::De,
+        header: Vec::new(),
+        footer: Vec::new(),
+        logo: None,
+        blocks: Vec::new(),
+    };
+    for (attribute, value) in attributes {
+        match attribute.as_str() {
+            "title" => layout.title = value,
+            "size" => {
+                layout.size = match value.to_ascii_uppercase().as_str() {
+                    "A4" => PageSize::A4,
+                    "A3" => PageSize::A3,
+                    _ => return Err(position.error(format!("Unbekanntes Format `{value}`"))),
+                }
+            }
+            "orientation" => {
+                layout.orientation = match value.to_ascii_lowercase().as_str() {
+                    "portrait" => Orientation::Portrait,
+                    "landscape" => Orientation::Landscape,
+                    _ => return Err(position.error(format!("Unbekannte Ausrichtung `{value}`"))),
+                }
+            }
+            "locale" => {
+                layout.locale = match value.to_ascii_lowercase().as_str() {
+                    "de" => Locale::De,
+                    "en" => Locale::En,
+                    _ => return Err(position.error(format!("Unbekannte Sprache `{value}`"))),
+                }
+            }
+            _ => return Err(unknown_attribute(position, "document", &attribute)),
+        }
+    }
+    if self_closing {
+        return parser.finish(layout);
+    }
+
+    loop {
+        parser.skip_blank_text()?;
+        match parser.tokens.next() {
+            Some(Token::End(name, _)) if name == "document" => break,
+            Some(Token::Start {
+                name,
+                attributes,
+                self_closing,
+                position,
+            }) => match name.as_str() {
+                "header" | "footer" | "h1" | "h2" | "p" => {
+                    no_attributes(position, &name, &attributes)?;
+                    let lines = if self_closing {
+                        Vec::new()
+                    } else {
+                        parser.inline_content(&name)?
+                    };
+                    match name.as_str() {
+                        "header" => layout.header = lines,
+                        "footer" => layout.footer = lines,
+                        "h1" => layout.blocks.push(LayoutBlock::Heading { level: 1, lines }),
+                        "h2" => layout.blocks.push(LayoutBlock::Heading { level: 2, lines }),
+                        _ => layout.blocks.push(LayoutBlock::Paragraph(lines)),
+                    }
+                }
+                "logo" => {
+                    layout.logo = Some(parse_logo(position, &attributes)?);
+                    parser.close_empty(&name, self_closing)?;
+                }
+                "hr" | "pagebreak" => {
+                    no_attributes(position, &name, &attributes)?;
+                    parser.close_empty(&name, self_closing)?;
+                    layout.blocks.push(if name == "hr" {
+                        LayoutBlock::Rule
+                    } else {
+                        LayoutBlock::PageBreak
+                    });
+                }
+                "table" => {
+                    let table = parser.table(position, &attributes, self_closing)?;
+                    layout.blocks.push(LayoutBlock::Table(table));
+                }
+                _ => return Err(position.error(format!("Unbekanntes Element <{name}>"))),
+            },
+            Some(Token::End(name, position)) => {
+                return Err(position.error(format!("Unerwartetes </{name}>")))
+            }
+            Some(Token::Text(_, position)) => {
+                return Err(position.error("Text außerhalb eines Elements"))
+            }
+            None => return Err(position.error("</document> fehlt")),
+        }
+    }
+    parser.finish(layout)
+}
+
+struct Parser {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn skip_blank_text(&mut self) -> Result<(), LayoutError> {
+        while let Some(Token::Text(text, _)) = self.tokens.peek() {
+            if !text.trim().is_empty() {
+                return Ok(());
+            }
+            self.tokens.next();
+        }
+        Ok(())
+    }
+
+    fn finish(mut self, layout: PdfLayout) -> Result<PdfLayout, LayoutError> {
+        self.skip_blank_text()?;
+        match self.tokens.next() {
+            None => Ok(layout),
+            Some(token) => Err(token_position(&token).error("Inhalt nach </document>")),
+        }
+    }
+
+    /// Consumes the end tag of an element without content.
+    fn close_empty(&mut self, name: &str, self_closing: bool) -> Result<(), LayoutError> {
+        if self_closing {
+            return Ok(());
+        }
+        self.skip_blank_text()?;
+        match self.tokens.next() {
+            Some(Token::End(end, _)) if end == name => Ok(()),
+            Some(token) => {
+                Err(token_position(&token).error(format!("<{name}> darf keinen Inhalt haben")))
+            }
+            None => Err(Position { line: 1, column: 1 }.error(format!("</{name}> fehlt"))),
+        }
+    }
+
+    /// Text with `<br/>` up to `</name>`, returned as whitespace-collapsed lines.
+    fn inline_content(&mut self, name: &str) -> Result<Vec<String>, LayoutError> {
+        let mut lines = vec![String::new()];
+        loop {
+            match self.tokens.next() {
+                Some(Token::Text(text, _)) => {
+                    let current = lines.last_mut().expect("lines is never empty");
+                    for word in text.split_whitespace() {
+                        if !current.is_empty() {
+                            current.push(' ');
+                        }
+                        current.push_str(word);
+                    }
+                }
+                Some(Token::Start {
+                    name: child,
+                    self_closing,
+                    position,
+                    ..
+                }) if child == "br" => {
+                    self.close_empty("br", self_closing)
+                        .map_err(|_| position.error("<br> darf keinen Inhalt haben"))?;
+                    lines.push(String::new());
+                }
+                Some(Token::Start {
+                    name: child,
+                    position,
+                    ..
+                }) => {
+                    return Err(
+                        position.error(format!("Element <{child}> ist in <{name}> nicht erlaubt"))
+                    )
+                }
+                Some(Token::End(end, _)) if end == name => break,
+                Some(Token::End(end, position)) => {
+                    return Err(position.error(format!("</{name}> erwartet, nicht </{end}>")))
+                }
+                None => {
+                    return Err(Position { line: 1, column: 1 }.error(format!("</{name}> fehlt")))
+                }
+            }
+        }
+        Ok(lines)
+    }
+
+    fn table(
+        &mut self,
+        position: Position,
+        attributes: &[(String, String)],
+        self_closing: bool,
+    ) -> Result<Table, LayoutError> {
+        let mut table = Table {
+            widths: None,
+            rows: Vec::new(),
+        };
+        for (attribute, value) in attributes {
+            match attribute.as_str() {
+                "widths" => {
+                    let widths = value
+                        .split(',')
+                        .map(|w| w.trim().parse::<f32>().ok().filter(|w| *w > 0.0))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| {
+                            position
+                                .error(format!("`widths` muss positive Zahlen enthalten: {value}"))
+                        })?;
+                    table.widths = Some(widths);
+                }
+                _ => return Err(unknown_attribute(position, "table", attribute)),
+            }
+        }
+        if self_closing {
+            return Ok(table);
+        }
+        loop {
+            self.skip_blank_text()?;
+            match self.tokens.next() {
+                Some(Token::Start {
+                    name,
+                    attributes,
+                    self_closing,
+                    position,
+                }) if name == "tr" => {
+                    no_attributes(position, "tr", &attributes)?;
+                    table.rows.push(if self_closing {
+                        Vec::new()
+                    } else {
+                        self.table_row()?
+                    });
+                }
+                Some(Token::End(name, _)) if name == "table" => break,
+                Some(token) => {
+                    return Err(token_position(&token).error("In <table> ist nur <tr> erlaubt"))
+                }
+                None => return Err(position.error("</table> fehlt")),
+            }
+        }
+        if let Some(widths) = &table.widths {
+            let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+            if widths.len() != columns {
+                return Err(position.error(format!(
+                    "`widths` hat {} Einträge, die Tabelle {columns} Spalten",
+                    widths.len()
+                )));
+            }
+        }
+        Ok(table)
+    }
+
+    fn table_row(&mut self) -> Result<Vec<TableCell>, LayoutError> {
+        let mut cells = Vec::new();
+        loop {
+            self.skip_blank_text()?;
+            match self.tokens.next() {
+                Some(Token::Start {
+                    name,
+                    attributes,
+                    self_closing,
+                    position,
+                }) if name == "td" || name == "th" => {
+                    let mut align_right = false;
+                    for (attribute, value) in &attributes {
+                        match (attribute.as_str(), value.as_str()) {
+                            ("align", "right") => align_right = true,
+                            ("align", "left") => align_right = false,
+                            ("align", _) => {
+                                return Err(position.error(format!(
+                                    "`align` ist `left` oder `right`, nicht `{value}`"
+                                )))
+                            }
+                            _ => return Err(unknown_attribute(position, &name, attribute)),
+                        }
+                    }
+                    let lines = if self_closing {
+                        Vec::new()
+                    } else {
+                        self.inline_content(&name)?
+                    };
+                    cells.push(TableCell {
+                        lines,
+                        header: name == "th",
+                        align_right,
+                    });
+                }
+                Some(Token::End(name, _)) if name == "tr" => break,
+                Some(token) => {
+                    return Err(
+                        token_position(&token).error("In <tr> sind nur <th> und <td> erlaubt")
+                    )
+                }
+                None => return Err(Position { line: 1, column: 1 }.error("</tr> fehlt")),
+            }
+        }
+        Ok(cells)
+    }
+}
+
+fn token_position(token: &Token) -> Position {
+    match token {
+        Token::Text(_, position) | Token::End(_, position) | Token::Start { position, .. } => {
+            *position
+        }
+    }
+}
+
+fn unknown_attribute(position: Position, element: &str, attribute: &str) -> LayoutError {
+    position.error(format!("Unbekanntes Attribut `{attribute}` an <{element}>"))
+}
+
+fn no_attributes(
+    position: Position,
+    element: &str,
+    attributes: &[(String, String)],
+) -> Result<(), LayoutError> {
+    match attributes.first() {
+        Some((attribute, _)) => Err(unknown_attribute(position, element, attribute)),
+        None => Ok(()),
+    }
+}
+
+fn parse_logo(position: Position, attributes: &[(String, String)]) -> Result<Logo, LayoutError> {
+    let mut src = None;
+    let mut width_mm = DEFAULT_LOGO_WIDTH_MM;
+    for (attribute, value) in attributes {
+        match attribute.as_str() {
+            "src" => src = Some(value.as_str()),
+            "width" => {
+                width_mm = value
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|w| *w > 0.0)
+                    .ok_or_else(|| position.error(format!("Ungültige Logo-Breite `{value}`")))?;
+            }
+            _ => return Err(unknown_attribute(position, "logo", attribute)),
+        }
+    }
+    let data = src
+        .ok_or_else(|| position.error("<logo> braucht `src`"))?
+        .strip_prefix("data:image/jpeg;base64,")
+        .ok_or_else(|| position.error("Logo muss eine data:image/jpeg;base64-URI sein"))?;
+    let jpeg = base64::engine::general_purpose::STANDARD
+        .decode(data.trim())
+        .map_err(|_| position.error("Logo ist kein gültiges Base64"))?;
+    let (width_px, height_px, components) =
+        jpeg_dimensions(&jpeg).ok_or_else(|| position.error("Logo ist kein gültiges JPEG"))?;
+    Ok(Logo {
+        jpeg,
+        width_px,
+        height_px,
+        components,
+        width_mm,
+    })
+}
+
+/// Width, height and component count from the first SOF marker of a JPEG.
+fn jpeg_dimensions(jpeg: &[u8]) -> Option<(usize, usize, u8)> {
+    if jpeg.get(..2)? != [0xFF, 0xD8] {
+        return None;
+    }
+    let mut index = 2;
+    loop {
+        if *jpeg.get(index)? != 0xFF {
+            return None;
+        }
+        let marker = *jpeg.get(index + 1)?;
+        match marker {
+            0xFF => index += 1,
+            0x01 | 0xD0..=0xD7 => index += 2,
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                let segment = jpeg.get(index + 4..index + 10)?;
+                let height = u16::from_be_bytes([segment[1], segment[2]]) as usize;
+                let width = u16::from_be_bytes([segment[3], segment[4]]) as usize;
+                return (width > 0 && height > 0).then_some((width, height, segment[5]));
+            }
+            _ => {
+                let length = u16::from_be_bytes([*jpeg.get(index + 2)?, *jpeg.get(index + 3)?]);
+                index += 2 + length as usize;
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// Renderer.
+// -----------------------------------------------------------------------
+
+/// Renders a parsed layout into PDF bytes. `render_timestamp` is printed as
+/// is (caller converts to local time, like [`crate::pdf_render`]).
+///
+/// # Errors
+///
+/// [`ServiceError::InternalError`] if `printpdf` fails to serialize.
+pub fn render_layout_pdf(
+    layout: &PdfLayout,
+    render_timestamp: time::OffsetDateTime,
+) -> Result<Vec<u8>, ServiceError> {
+    let mut writer = LayoutWriter::new(layout)?;
+    for block in &layout.blocks {
+        match block {
+            LayoutBlock::Heading { level, lines } => {
+                let font_pt = if *level == 1 { H1_FONT_PT } else { H2_FONT_PT };
+                writer.lines(lines, font_pt, true);
+                writer.gap(BLOCK_GAP_MM);
+            }
+            LayoutBlock::Paragraph(lines) => {
+                writer.lines(lines, TEXT_FONT_PT, false);
+                writer.gap(BLOCK_GAP_MM);
+            }
+            LayoutBlock::Table(table) => {
+                writer.table(table);
+                writer.gap(BLOCK_GAP_MM);
+            }
+            LayoutBlock::Rule => {
+                writer.gap(BLOCK_GAP_MM);
+                let y = writer.y;
+                writer.horizontal_line(y, 0.5);
+                writer.gap(BLOCK_GAP_MM);
+            }
+            LayoutBlock::PageBreak => {
+                if !writer.at_page_top() {
+                    writer.new_page();
+                }
+            }
+        }
+    }
+    writer.finish(render_timestamp)
+}
+
+fn page_dimensions(layout: &PdfLayout) -> (f32, f32) {
+    let (short, long) = match layout.size {
+        PageSize::A4 => (210.0, 297.0),
+        PageSize::A3 => (297.0, 420.0),
+    };
+    match layout.orientation {
+        Orientation::Portrait => (short, long),
+        Orientation::Landscape => (long, short),
+    }
+}
+
+fn line_height_mm(font_pt: f32) -> f32 {
+    font_pt * 0.352_777_78 * 1.35
+}
+
+/// Greedy word wrap; words wider than `max_width_mm` get a line of their own.
+fn wrap_text(text: &str, font_pt: f32, max_width_mm: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{current} {word}")
+        };
+        if approx_text_width_mm(&candidate, font_pt) > max_width_mm && !current.is_empty() {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        } else {
+            current = candidate;
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn wrap_lines(lines: &[String], font_pt: f32, max_width_mm: f32) -> Vec<String> {
+    lines
+        .iter()
+        .flat_map(|line| wrap_text(line, font_pt, max_width_mm))
+        .collect()
+}
+
+/// Cursor over the pages of a layout document. Header, logo and footer are
+/// drawn per page; footer and page numbers in [`LayoutWriter::finish`], once
+/// the page count is known.
+struct LayoutWriter<'a> {
+    layout: &'a PdfLayout,
+    doc: PdfDocumentReference,
+    pages: Vec<PdfLayerReference>,
+    font: IndirectFontRef,
+    font_bold: IndirectFontRef,
+    width: f32,
+    height: f32,
+    content_top: f32,
+    content_bottom: f32,
+    /// Top edge of the free area on the current page.
+    y: f32,
+}
+
+impl<'a> LayoutWriter<'a> {
+    fn new(layout: &'a PdfLayout) -> Result<Self, ServiceError> {
+        let (width, height) = page_dimensions(layout);
+        let title = if layout.title.is_empty() {
+            "Shifty"
+        } else {
+            layout.title.as_str()
+        };
+        let (doc, page_index, layer_index) =
+            PdfDocument::new(title, Mm(width), Mm(height), "Layer 1");
+        let doc = doc
+            .with_creation_date(FIXED_METADATA_TIMESTAMP)
+            .with_mod_date(FIXED_METADATA_TIMESTAMP)
+            .with_metadata_date(FIXED_METADATA_TIMESTAMP)
+            .with_producer(PDF_PRODUCER)
+            .with_creator(PDF_PRODUCER)
+            .with_author(PDF_PRODUCER);
+        let font = doc
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(|_| ServiceError::InternalError)?;
+        let font_bold = doc
+            .add_builtin_font(BuiltinFont::HelveticaBold)
+            .map_err(|_| ServiceError::InternalError)?;
+        let layer = doc.get_page(page_index).get_layer(layer_index);
+
+        let header_text_height = (layout.header.len() as f32) * line_height_mm(HEADER_FONT_PT)
+            + line_height_mm(SMALL_FONT_PT);
+        let logo_height = layout
+            .logo
+            .as_ref()
+            .map(|logo| logo.width_mm * logo.height_px as f32 / logo.width_px as f32)
+            .unwrap_or(0.0);
+        let content_top = height - MARGIN_MM - header_text_height.max(logo_height) - HEADER_GAP_MM;
+
+        let mut writer = Self {
+            layout,
+            doc,
+            pages: Vec::new(),
+            font,
+            font_bold,
+            width,
+            height,
+            content_top,
+            content_bottom: MARGIN_MM + FOOTER_BAND_MM,
+            y: content_top,
+        };
+        writer.start_page(layer);
+        Ok(writer)
+    }
+
+    fn content_width(&self) -> f32 {
+        self.width - 2.0 * MARGIN_MM
+    }
+
+    fn at_page_top(&self) -> bool {
+        self.y >= self.content_top
+    }
+
+    fn start_page(&mut self, layer: PdfLayerReference) {
+        let mut y = self.height - MARGIN_MM;
+        for line in &self.layout.header {
+            y -= line_height_mm(HEADER_FONT_PT);
+            layer.use_text(
+                line.as_str(),
+                HEADER_FONT_PT,
+                Mm(MARGIN_MM),
+                Mm(y),
+                &self.font_bold,
+            );
+        }
+        if let Some(logo) = &self.layout.logo {
+            let height_mm = logo.width_mm * logo.height_px as f32 / logo.width_px as f32;
+            let color_space = match logo.components {
+                1 => ColorSpace::Greyscale,
+                4 => ColorSpace::Cmyk,
+                _ => ColorSpace::Rgb,
+            };
+            let image = Image::from(ImageXObject {
+                width: Px(logo.width_px),
+                height: Px(logo.height_px),
+                color_space,
+                bits_per_component: ColorBits::Bit8,
+                interpolate: true,
+                image_data: logo.jpeg.clone(),
+                image_filter: Some(ImageFilter::DCT),
+                smask: None,
+                clipping_bbox: None,
+            });
+            image.add_to_layer(
+                layer.clone(),
+                ImageTransform {
+                    translate_x: Some(Mm(self.width - MARGIN_MM - logo.width_mm)),
+                    translate_y: Some(Mm(self.height - MARGIN_MM - height_mm)),
+                    // At this dpi the image is exactly `width_mm` wide.
+                    dpi: Some(logo.width_px as f32 * 25.4 / logo.width_mm),
+                    ..ImageTransform::default()
+                },
+            );
+        }
+        self.pages.push(layer);
+        self.y = self.content_top;
+    }
+
+    fn new_page(&mut self) {
+        let (page_index, layer_index) =
+            self.doc
+                .add_page(Mm(self.width), Mm(self.height), "Layer 1");
+        let layer = self.doc.get_page(page_index).get_layer(layer_index);
+        self.start_page(layer);
+    }
+
+    fn layer(&self) -> &PdfLayerReference {
+        self.pages.last().expect("at least one page")
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y = (self.y - height).max(self.content_bottom);
+    }
+
+    /// Reserves `height` on the current page or starts a new one.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < self.content_bottom && !self.at_page_top() {
+            self.new_page();
+        }
+    }
+
+    fn lines(&mut self, lines: &[String], font_pt: f32, bold: bool) {
+        let line_height = line_height_mm(font_pt);
+        for line in wrap_lines(lines, font_pt, self.content_width()) {
+            self.reserve(line_height);
+            self.y -= line_height;
+            let font = if bold { &self.font_bold } else { &self.font };
+            self.layer()
+                .use_text(line, font_pt, Mm(MARGIN_MM), Mm(self.y + 1.0), font);
+        }
+    }
+
+    fn horizontal_line(&self, y: f32, thickness: f32) {
+        let layer = self.layer();
+        layer.set_outline_thickness(thickness);
+        layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN_MM), Mm(y)), false),
+                (Point::new(Mm(self.width - MARGIN_MM), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    fn table(&mut self, table: &Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        let weights = table.widths.clone().unwrap_or_else(|| vec![1.0; columns]);
+        let total: f32 = weights.iter().sum();
+        let column_widths: Vec<f32> = weights
+            .iter()
+            .map(|w| w / total * self.content_width())
+            .collect();
+        let header_rows = table
+            .rows
+            .iter()
+            .take_while(|row| !row.is_empty() && row.iter().all(|cell| cell.header))
+            .count();
+
+        for (index, row) in table.rows.iter().enumerate() {
+            let height = self.row_height(row, &column_widths);
+            if self.y - height < self.content_bottom && !self.at_page_top() {
+                self.new_page();
+                if index >= header_rows {
+                    for header in &table.rows[..header_rows] {
+                        self.table_row(header, &column_widths);
+                    }
+                }
+            }
+            self.table_row(row, &column_widths);
+        }
+    }
+
+    fn row_height(&self, row: &[TableCell], column_widths: &[f32]) -> f32 {
+        let lines = row
+            .iter()
+            .zip(column_widths)
+            .map(|(cell, width)| {
+                wrap_lines(&cell.lines, TABLE_FONT_PT, width - 2.0 * CELL_PADDING_MM).len()
+            })
+            .max()
+            .unwrap_or(1);
+        lines as f32 * line_height_mm(TABLE_FONT_PT) + 1.5
+    }
+
+    fn table_row(&mut self, row: &[TableCell], column_widths: &[f32]) {
+        let height = self.row_height(row, column_widths);
+        let top = self.y;
+        let line_height = line_height_mm(TABLE_FONT_PT);
+        let mut x = MARGIN_MM;
+        for (cell, width) in row.iter().zip(column_widths) {
+            let inner_width = width - 2.0 * CELL_PADDING_MM;
+            let font = if cell.header {
+                &self.font_bold
+            } else {
+                &self.font
+            };
+            for (i, line) in wrap_lines(&cell.lines, TABLE_FONT_PT, inner_width)
+                .into_iter()
+                .enumerate()
+            {
+                let text_x = if cell.align_right {
+                    x + width
+                        - CELL_PADDING_MM
+                        - approx_text_width_mm(&line, TABLE_FONT_PT).min(inner_width)
+                } else {
+                    x + CELL_PADDING_MM
+                };
+                let baseline = top - (i as f32 + 1.0) * line_height + 0.5;
+                self.layer()
+                    .use_text(line, TABLE_FONT_PT, Mm(text_x), Mm(baseline), font);
+            }
+            x += width;
+        }
+        self.y -= height;
+        let thickness = if row.iter().all(|cell| cell.header) {
+            0.6
+        } else {
+            0.2
+        };
+        self.horizontal_line(self.y, thickness);
+    }
+
+    fn finish(self, render_timestamp: time::OffsetDateTime) -> Result<Vec<u8>, ServiceError> {
+        let created = match self.layout.locale {
+            Locale::De => format_render_timestamp(render_timestamp),
+            Locale::En => format!(
+                "Created {:04}-{:02}-{:02} {:02}:{:02}",
+                render_timestamp.year(),
+                render_timestamp.month() as u8,
+                render_timestamp.day(),
+                render_timestamp.hour(),
+                render_timestamp.minute(),
+            ),
+        };
+        let created_y = self.height
+            - MARGIN_MM
+            - (self.layout.header.len() as f32) * line_height_mm(HEADER_FONT_PT)
+            - line_height_mm(SMALL_FONT_PT);
+        let page_count = self.pages.len();
+        for (index, layer) in self.pages.iter().enumerate() {
+            layer.use_text(
+                created.as_str(),
+                SMALL_FONT_PT,
+                Mm(MARGIN_MM),
+                Mm(created_y),
+                &self.font,
+            );
+            let mut footer_y = MARGIN_MM + FOOTER_BAND_MM - line_height_mm(SMALL_FONT_PT);
+            for line in &self.layout.footer {
+                layer.use_text(
+                    line.as_str(),
+                    SMALL_FONT_PT,
+                    Mm(MARGIN_MM),
+                    Mm(footer_y),
+                    &self.font,
+                );
+                footer_y -= line_height_mm(SMALL_FONT_PT);
+            }
+            let page_label = match self.layout.locale {
+                Locale::De => format!("Seite {} von {page_count}", index + 1),
+                Locale::En => format!("Page {} of {page_count}", index + 1),
+            };
+            let page_x = self.width - MARGIN_MM - approx_text_width_mm(&page_label, SMALL_FONT_PT);
+            layer.use_text(
+                page_label,
+                SMALL_FONT_PT,
+                Mm(page_x),
+                Mm(MARGIN_MM + FOOTER_BAND_MM - line_height_mm(SMALL_FONT_PT)),
+                &self.font,
+            );
+        }
+        self.doc
+            .save_to_bytes()
+            .map_err(|_| ServiceError::InternalError)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 1×1 pixel baseline JPEG header (SOI, APP0, SOF0) — enough for the
+    /// dimension probe and for `printpdf`, which embeds the bytes verbatim.
+    const TINY_JPEG: [u8; 31] = [
+        0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, b'J', b'F', b'I', b'F', 0x00, 0x01, 0x01, 0x00, 0x00,
+        0x01, 0x00, 0x01, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00, 0x02, 0x00, 0x04, 0x01,
+        0x01,
+    ];
+
+    fn timestamp() -> time::OffsetDateTime {
+        time::macros::datetime!(2026-10-17 14:05 UTC)
+    }
+
+    fn hex(text: &str) -> String {
+        text.bytes().map(|b| format!("{b:02X}")).collect()
+    }
+
+    fn contains(bytes: &[u8], needle: &str) -> bool {
+        let haystack = String::from_utf8_lossy(bytes);
+        haystack.contains(&hex(needle)) || haystack.contains(needle)
+    }
+
+    #[test]
+    fn parses_document_attributes_and_blocks() {
+        let layout = parse_layout(
+            r#"<!-- Abrechnung -->
+            <document size="a3" orientation="landscape" locale="en" title="Report">
+              <header>Shop &amp; Co</header>
+              <h1>Title</h1>
+              <p>Line   one
+                 continues<br/>line two</p>
+              <table widths="2,1">
+                <tr><th>Name</th><th align="right">Hours</th></tr>
+                <tr><td>Anna</td><td align="right">8,00</td></tr>
+              </table>
+              <hr/>
+              <pagebreak></pagebreak>
+            </document>"#,
+        )
+        .unwrap();
+
+        assert_eq!(layout.size, PageSize::A3);
+        assert_eq!(layout.orientation, Orientation::Landscape);
+        assert_eq!(layout.locale, Locale::En);
+        assert_eq!(layout.title, "Report");
+        assert_eq!(layout.header, vec!["Shop & Co".to_string()]);
+        assert_eq!(
+            layout.blocks[1],
+            LayoutBlock::Paragraph(vec!["Line one continues".into(), "line two".into()])
+        );
+        let LayoutBlock::Table(table) = &layout.blocks[2] else {
+            panic!("expected table");
+        };
+        assert_eq!(table.widths, Some(vec![2.0, 1.0]));
+        assert!(table.rows[0][1].header && table.rows[0][1].align_right);
+        assert!(!table.rows[1][0].header);
+        assert_eq!(layout.blocks[3], LayoutBlock::Rule);
+        assert_eq!(layout.blocks[4], LayoutBlock::PageBreak);
+    }
+
+    #[test]
+    fn decodes_html_escaped_template_output() {
+        assert_eq!(
+            decode_entities("M&#xFC;ller &amp; S&#246;hne &#x2F; &lt;b&gt; &quot;x&quot; & y"),
+            "Müller & Söhne / <b> \"x\" & y"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_element_with_position() {
+        let error =
+            parse_layout("<document>\n  <p>ok</p>\n  <div>x</div>\n</document>").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert!(error.message.contains("<div>"));
+    }
+
+    #[test]
+    fn rejects_invalid_structure() {
+        for markup in [
+            "",
+            "<p>kein Root</p>",
+            "<document><p>offen</document>",
+            "<document>lose</document>",
+            "<document size=\"A5\"></document>",
+            "<document><table widths=\"1,1\"><tr><td>a</td></tr></table></document>",
+            "<document><p><b>fett</b></p></document>",
+            "<document></document><p>danach</p>",
+            "<document><logo src=\"https://example.org/logo.png\"/></document>",
+        ] {
+            assert!(parse_layout(markup).is_err(), "accepted: {markup:?}");
+        }
+    }
+
+    #[test]
+    fn reads_jpeg_dimensions_from_sof_marker() {
+        assert_eq!(jpeg_dimensions(&TINY_JPEG), Some((4, 2, 1)));
+        assert_eq!(jpeg_dimensions(b"GIF89a"), None);
+    }
+
+    #[test]
+    fn renders_pages_header_footer_and_logo() {
+        let logo = base64::engine::general_purpose::STANDARD.encode(TINY_JPEG);
+        let rows: String = (0..150)
+            .map(|i| format!("<tr><td>Person {i}</td><td align=\"right\">{i},00</td></tr>"))
+            .collect();
+        let markup = format!(
+            r#"<document locale="de" title="Abrechnung">
+                 <header>Musterladen</header>
+                 <logo src="data:image/jpeg;base64,{logo}" width="20"/>
+                 <footer>Vertraulich</footer>
+                 <h1>Abrechnung Q1</h1>
+                 <table><tr><th>Name</th><th>Saldo</th></tr>{rows}</table>
+               </document>"#
+        );
+        let layout = parse_layout(&markup).unwrap();
+        let bytes = render_layout_pdf(&layout, timestamp()).unwrap();
+
+        assert!(bytes.starts_with(b"%PDF-"));
+        assert!(contains(&bytes, "Musterladen"));
+        assert!(contains(&bytes, "Vertraulich"));
+        assert!(contains(&bytes, "Person 149"));
+        assert!(contains(&bytes, "Seite 1 von "));
+        assert!(contains(&bytes, "Seite 2 von "));
+        assert!(contains(&bytes, "Erstellt am 17.10.2026 14:05 Uhr"));
+        assert!(String::from_utf8_lossy(&bytes).contains("DCTDecode"));
+    }
+
+    #[test]
+    fn english_locale_changes_builtin_labels() {
+        let layout =
+            parse_layout(r#"<document locale="en" size="A3"><p>Hello</p></document>"#).unwrap();
+        let bytes = render_layout_pdf(&layout, timestamp()).unwrap();
+        assert!(contains(&bytes, "Page 1 of 1"));
+        assert!(contains(&bytes, "Created 2026-10-17 14:05"));
+        assert!(!contains(&bytes, "Seite"));
+    }
+
+    #[test]
+    fn wraps_long_text_at_content_width() {
+        let text = "wort ".repeat(100);
+        let lines = wrap_text(&text, TEXT_FONT_PT, 100.0);
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|line| approx_text_width_mm(line, TEXT_FONT_PT) <= 100.0));
+    }
+}
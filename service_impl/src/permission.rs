@@ -1,21 +1,72 @@
+use crate::audit_log::audit_log_entity;
 use crate::gen_service_impl;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use dao::PermissionDao;
+use dao::{audit_log::AuditLogDao, PermissionDao, TransactionDao};
+use serde::Serialize;
+use service::audit_log::{entity_type, AuditChange};
+use service::clock::ClockService;
 use service::permission::Authentication;
 use service::user_service::UserService;
-use service::{Privilege, ServiceError};
+use service::uuid_service::UuidService;
+use service::{PermissionService, Privilege, ServiceError};
 
 gen_service_impl! {
     struct PermissionServiceImpl: service::PermissionService = PermissionServiceDeps {
         PermissionDao: dao::PermissionDao = permission_dao,
-        UserService: service::user_service::UserService<Context = Self::Context> = user_service
+        UserService: service::user_service::UserService<Context = Self::Context> = user_service,
+        AuditLogDao: AuditLogDao<Transaction = Self::Transaction> = audit_log_dao,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
 
 const PERMISSION_SERVICE_PROCESS: &str = "permission-service";
 
+#[derive(Serialize)]
+struct RolePrivilege<'a> {
+    role: &'a str,
+    privilege: &'a str,
+}
+
+impl<Deps: PermissionServiceDeps> PermissionServiceImpl<Deps> {
+    /// Schreibt den Protokolleintrag direkt über den DAO, siehe
+    /// [`audit_log_entity`]. Der Permission-DAO arbeitet ohne Transaktion,
+    /// daher bekommt das Protokoll eine eigene.
+    async fn record(
+        &self,
+        change: AuditChange,
+        context: Authentication<Deps::Context>,
+    ) -> Result<(), ServiceError> {
+        let user_id = self.current_user_id(context).await?;
+        let entity = audit_log_entity(
+            &change,
+            PERMISSION_SERVICE_PROCESS,
+            user_id,
+            self.uuid_service
+                .new_uuid(&format!("{PERMISSION_SERVICE_PROCESS}::record id")),
+            self.clock_service.date_time_now(),
+        );
+        let tx = self.transaction_dao.use_transaction(None).await?;
+        self.audit_log_dao.create(&entity, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    /// Rollennamen eines Benutzers als Schnappschuss für Zuordnungsänderungen.
+    async fn role_names(&self, user: &str) -> Result<Vec<Arc<str>>, ServiceError> {
+        Ok(self
+            .permission_dao
+            .roles_for_user(user)
+            .await?
+            .iter()
+            .map(|role| role.name.clone())
+            .collect())
+    }
+}
+
 #[async_trait]
 impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServiceImpl<Deps> {
     type Context = Deps::Context;
@@ -123,23 +174,24 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         user: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
+        let entity = dao::UserEntity { name: user.into() };
         self.permission_dao
-            .create_user(
-                &dao::UserEntity { name: user.into() },
-                PERMISSION_SERVICE_PROCESS,
-            )
+            .create_user(&entity, PERMISSION_SERVICE_PROCESS)
             .await?;
-        Ok(())
+        let change = AuditChange::create(entity_type::USER, user, &service::User::from(&entity))?;
+        self.record(change, context).await
     }
     async fn delete_user(
         &self,
         user: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
         self.permission_dao.delete_user(user).await?;
-        Ok(())
+        let before = service::User { name: user.into() };
+        let change = AuditChange::delete(entity_type::USER, user, &before)?;
+        self.record(change, context).await
     }
 
     async fn user_exists(
@@ -174,23 +226,24 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         role: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
+        let entity = dao::RoleEntity { name: role.into() };
         self.permission_dao
-            .create_role(
-                &dao::RoleEntity { name: role.into() },
-                PERMISSION_SERVICE_PROCESS,
-            )
+            .create_role(&entity, PERMISSION_SERVICE_PROCESS)
             .await?;
-        Ok(())
+        let change = AuditChange::create(entity_type::ROLE, role, &service::Role::from(&entity))?;
+        self.record(change, context).await
     }
     async fn delete_role(
         &self,
         role: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
         self.permission_dao.delete_role(role).await?;
-        Ok(())
+        let before = service::Role { name: role.into() };
+        let change = AuditChange::delete(entity_type::ROLE, role, &before)?;
+        self.record(change, context).await
     }
     async fn get_all_roles(
         &self,
@@ -211,16 +264,16 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         privilege: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
+        let entity = dao::PrivilegeEntity {
+            name: privilege.into(),
+        };
         self.permission_dao
-            .create_privilege(
-                &dao::PrivilegeEntity {
-                    name: privilege.into(),
-                },
-                PERMISSION_SERVICE_PROCESS,
-            )
+            .create_privilege(&entity, PERMISSION_SERVICE_PROCESS)
             .await?;
-        Ok(())
+        let change =
+            AuditChange::create(entity_type::PRIVILEGE, privilege, &Privilege::from(&entity))?;
+        self.record(change, context).await
     }
 
     async fn delete_privilege(
@@ -228,9 +281,13 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         privilege: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
         self.permission_dao.delete_privilege(privilege).await?;
-        Ok(())
+        let before = Privilege {
+            name: privilege.into(),
+        };
+        let change = AuditChange::delete(entity_type::PRIVILEGE, privilege, &before)?;
+        self.record(change, context).await
     }
     async fn get_all_privileges(
         &self,
@@ -252,11 +309,14 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         role: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
+        let before = self.role_names(user).await?;
         self.permission_dao
             .add_user_role(user, role, PERMISSION_SERVICE_PROCESS)
             .await?;
-        Ok(())
+        let after = self.role_names(user).await?;
+        let change = AuditChange::update(entity_type::USER_ROLE, user, &before, &after)?;
+        self.record(change, context).await
     }
     async fn add_role_privilege(
         &self,
@@ -264,11 +324,16 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         privilege: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
         self.permission_dao
             .add_role_privilege(role, privilege, PERMISSION_SERVICE_PROCESS)
             .await?;
-        Ok(())
+        let change = AuditChange::create(
+            entity_type::ROLE_PRIVILEGE,
+            format!("{role}/{privilege}"),
+            &RolePrivilege { role, privilege },
+        )?;
+        self.record(change, context).await
     }
     async fn delete_role_privilege(
         &self,
@@ -276,11 +341,16 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         privilege: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
         self.permission_dao
             .delete_role_privilege(role, privilege)
             .await?;
-        Ok(())
+        let change = AuditChange::delete(
+            entity_type::ROLE_PRIVILEGE,
+            format!("{role}/{privilege}"),
+            &RolePrivilege { role, privilege },
+        )?;
+        self.record(change, context).await
     }
     async fn delete_user_role(
         &self,
@@ -288,8 +358,11 @@ impl<Deps: PermissionServiceDeps> service::PermissionService for PermissionServi
         role: &str,
         context: Authentication<Self::Context>,
     ) -> Result<(), service::ServiceError> {
-        self.check_permission("admin", context).await?;
+        self.check_permission("admin", context.clone()).await?;
+        let before = self.role_names(user).await?;
         self.permission_dao.delete_user_role(user, role).await?;
-        Ok(())
+        let after = self.role_names(user).await?;
+        let change = AuditChange::update(entity_type::USER_ROLE, user, &before, &after)?;
+        self.record(change, context).await
     }
 }
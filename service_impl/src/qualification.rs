@@ -0,0 +1,509 @@
+//! Basic-Tier Implementation von [`service::qualification::QualificationService`].
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    qualification::{QualificationDao, QualificationEntity, SlotQualificationRequirementEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    clock::ClockService,
+    permission::{Authentication, SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    qualification::{
+        Qualification, QualificationService, SalesPersonQualification,
+        SlotQualificationRequirement,
+    },
+    sales_person::SalesPersonService,
+    slot::SlotService,
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+const QUALIFICATION_SERVICE_PROCESS: &str = "qualification-service";
+
+fn validate_qualification(qualification: &Qualification) -> Result<(), ServiceError> {
+    if qualification.name.trim().is_empty() {
+        return Err(ServiceError::ValidationError(Arc::new([
+            ValidationFailureItem::InvalidValue("name".into()),
+        ])));
+    }
+    Ok(())
+}
+
+gen_service_impl! {
+    struct QualificationServiceImpl: QualificationService = QualificationServiceDeps {
+        QualificationDao: QualificationDao<Transaction = Self::Transaction> = qualification_dao,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        SlotService: SlotService<Context = Self::Context, Transaction = Self::Transaction> = slot_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: QualificationServiceDeps> QualificationServiceImpl<Deps> {
+    /// Lesen wie bei Slots: Shiftplanner oder Sales.
+    async fn check_shiftplanner_or_sales(
+        &self,
+        context: Authentication<Deps::Context>,
+    ) -> Result<(), ServiceError> {
+        let (shiftplanner_permission, sales_permission) = join!(
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SALES_PRIVILEGE, context),
+        );
+        shiftplanner_permission.or(sales_permission)
+    }
+
+    /// Meldet jede unbekannte oder gelöschte Qualifikation aus `ids`.
+    async fn unknown_qualifications(
+        &self,
+        ids: impl Iterator<Item = Uuid>,
+        tx: Deps::Transaction,
+    ) -> Result<Vec<ValidationFailureItem>, ServiceError> {
+        let known: HashSet<Uuid> = self
+            .qualification_dao
+            .all(tx)
+            .await?
+            .iter()
+            .map(|entity| entity.id)
+            .collect();
+        Ok(ids
+            .filter(|id| !known.contains(id))
+            .map(|id| ValidationFailureItem::IdDoesNotExist("qualification_id".into(), id))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<Deps: QualificationServiceDeps> QualificationService for QualificationServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[Qualification]>, ServiceError> {
+        self.check_shiftplanner_or_sales(context).await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let qualifications = self
+            .qualification_dao
+            .all(tx.clone())
+            .await?
+            .iter()
+            .map(Qualification::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(qualifications)
+    }
+
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Qualification, ServiceError> {
+        self.check_shiftplanner_or_sales(context).await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let qualification = self
+            .qualification_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .as_ref()
+            .map(Qualification::from)
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(qualification)
+    }
+
+    async fn create(
+        &self,
+        qualification: &Qualification,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Qualification, ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+        if qualification.id != Uuid::nil() {
+            return Err(ServiceError::IdSetOnCreate);
+        }
+        if qualification.version != Uuid::nil() {
+            return Err(ServiceError::VersionSetOnCreate);
+        }
+        validate_qualification(qualification)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = QualificationEntity {
+            id: self
+                .uuid_service
+                .new_uuid(&format!("{QUALIFICATION_SERVICE_PROCESS}::create id")),
+            name: Arc::from(qualification.name.trim()),
+            description: qualification.description.clone(),
+            created: self.clock_service.date_time_now(),
+            deleted: None,
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{QUALIFICATION_SERVICE_PROCESS}::create version")),
+        };
+        self.qualification_dao
+            .create(&entity, QUALIFICATION_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = Qualification::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::QUALIFICATION, created.id, &created)?,
+                QUALIFICATION_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        qualification: &Qualification,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Qualification, ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+        validate_qualification(qualification)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .qualification_dao
+            .find_by_id(qualification.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(qualification.id))?;
+        if persisted.version != qualification.version {
+            return Err(ServiceError::EntityConflicts(
+                qualification.id,
+                persisted.version,
+                qualification.version,
+            ));
+        }
+        let before = Qualification::from(&persisted);
+        let entity = QualificationEntity {
+            name: Arc::from(qualification.name.trim()),
+            description: qualification.description.clone(),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{QUALIFICATION_SERVICE_PROCESS}::update version")),
+            ..persisted
+        };
+        self.qualification_dao
+            .update(&entity, QUALIFICATION_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let updated = Qualification::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::QUALIFICATION, updated.id, &before, &updated)?,
+                QUALIFICATION_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .qualification_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let before = Qualification::from(&persisted);
+        let entity = QualificationEntity {
+            deleted: Some(self.clock_service.date_time_now()),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{QUALIFICATION_SERVICE_PROCESS}::delete version")),
+            ..persisted
+        };
+        self.qualification_dao
+            .update(&entity, QUALIFICATION_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::QUALIFICATION, id, &before)?,
+                QUALIFICATION_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn get_all_sales_person_qualifications(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SalesPersonQualification]>, ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let assignments = self
+            .qualification_dao
+            .all_sales_person_qualifications(tx.clone())
+            .await?
+            .iter()
+            .map(SalesPersonQualification::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(assignments)
+    }
+
+    async fn get_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[Qualification]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (shiftplanner_permission, is_sales_person) = join!(
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.clone().into()
+            ),
+        );
+        shiftplanner_permission.or(is_sales_person)?;
+
+        let assigned: HashSet<Uuid> = self
+            .qualification_dao
+            .find_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .map(|assignment| assignment.qualification_id)
+            .collect();
+        let qualifications = self
+            .qualification_dao
+            .all(tx.clone())
+            .await?
+            .iter()
+            .filter(|entity| assigned.contains(&entity.id))
+            .map(Qualification::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(qualifications)
+    }
+
+    async fn set_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        qualification_ids: &[Uuid],
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        if !self
+            .sales_person_service
+            .exists(sales_person_id, Authentication::Full, tx.clone().into())
+            .await?
+        {
+            return Err(ServiceError::EntityNotFound(sales_person_id));
+        }
+
+        let mut seen = HashSet::new();
+        let qualification_ids: Vec<Uuid> = qualification_ids
+            .iter()
+            .copied()
+            .filter(|id| seen.insert(*id))
+            .collect();
+        let unknown = self
+            .unknown_qualifications(qualification_ids.iter().copied(), tx.clone())
+            .await?;
+        if !unknown.is_empty() {
+            return Err(ServiceError::ValidationError(unknown.into()));
+        }
+
+        let mut before: Vec<Uuid> = self
+            .qualification_dao
+            .find_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .map(|assignment| assignment.qualification_id)
+            .collect();
+        before.sort();
+        let mut after = qualification_ids.clone();
+        after.sort();
+        self.qualification_dao
+            .set_for_sales_person(
+                sales_person_id,
+                &qualification_ids,
+                QUALIFICATION_SERVICE_PROCESS,
+                tx.clone(),
+            )
+            .await?;
+        if before != after {
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(
+                        entity_type::SALES_PERSON_QUALIFICATION,
+                        sales_person_id,
+                        &before,
+                        &after,
+                    )?,
+                    QUALIFICATION_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn get_all_slot_requirements(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SlotQualificationRequirement]>, ServiceError> {
+        self.check_shiftplanner_or_sales(context).await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let requirements = self
+            .qualification_dao
+            .all_slot_requirements(tx.clone())
+            .await?
+            .iter()
+            .map(SlotQualificationRequirement::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(requirements)
+    }
+
+    async fn get_slot_requirements(
+        &self,
+        slot_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SlotQualificationRequirement]>, ServiceError> {
+        self.check_shiftplanner_or_sales(context).await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let requirements = self
+            .qualification_dao
+            .find_requirements_by_slot(slot_id, tx.clone())
+            .await?
+            .iter()
+            .map(SlotQualificationRequirement::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(requirements)
+    }
+
+    async fn set_slot_requirements(
+        &self,
+        slot_id: Uuid,
+        requirements: &[SlotQualificationRequirement],
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let slot = self
+            .slot_service
+            .get_slot(&slot_id, Authentication::Full, tx.clone().into())
+            .await?;
+
+        let mut validation = Vec::new();
+        let mut seen = HashSet::new();
+        for requirement in requirements {
+            if !seen.insert(requirement.qualification_id) {
+                validation.push(ValidationFailureItem::Duplicate);
+            }
+            if requirement.min_count == 0 || requirement.min_count > slot.min_resources {
+                validation.push(ValidationFailureItem::InvalidValue("min_count".into()));
+            }
+        }
+        validation.extend(
+            self.unknown_qualifications(seen.iter().copied(), tx.clone())
+                .await?,
+        );
+        if !validation.is_empty() {
+            return Err(ServiceError::ValidationError(validation.into()));
+        }
+
+        let entities: Vec<SlotQualificationRequirementEntity> = requirements
+            .iter()
+            .map(|requirement| SlotQualificationRequirementEntity {
+                slot_id,
+                ..requirement.into()
+            })
+            .collect();
+        let before: Vec<SlotQualificationRequirement> = self
+            .qualification_dao
+            .find_requirements_by_slot(slot_id, tx.clone())
+            .await?
+            .iter()
+            .map(SlotQualificationRequirement::from)
+            .collect();
+        let after: Vec<SlotQualificationRequirement> = entities
+            .iter()
+            .map(SlotQualificationRequirement::from)
+            .collect();
+        self.qualification_dao
+            .set_requirements_for_slot(
+                slot_id,
+                &entities,
+                QUALIFICATION_SERVICE_PROCESS,
+                tx.clone(),
+            )
+            .await?;
+        let sorted = |requirements: &[SlotQualificationRequirement]| {
+            let mut requirements = requirements.to_vec();
+            requirements.sort_by_key(|requirement| requirement.qualification_id);
+            requirements
+        };
+        if sorted(&before) != sorted(&after) {
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(
+                        entity_type::SLOT_QUALIFICATION_REQUIREMENT,
+                        slot_id,
+                        &before,
+                        &after,
+                    )?,
+                    QUALIFICATION_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+}
@@ -5,10 +5,14 @@
 //! Ab Phase 55 wird dieser Service vom Business-Logic
 //! `RebookingReconciliationService` konsumiert.
 
+use std::sync::Arc;
+
 use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{
-    rebooking_batch::{RebookingBatchDao, RebookingBatchEntity, RebookingBatchEntryEntity},
+    rebooking_batch::{
+        RebookingBatchDao, RebookingBatchEntity, RebookingBatchEntryEntity, RebookingBatchState,
+    },
     TransactionDao,
 };
 use service::{
@@ -138,6 +142,9 @@ impl<Deps: RebookingBatchServiceDeps> RebookingBatchService for RebookingBatchSe
             },
             approved: batch.approved,
             approved_by: batch.approved_by.clone(),
+            rejected: batch.rejected,
+            rejected_by: batch.rejected_by.clone(),
+            reject_reason: batch.reject_reason.clone(),
             deleted: batch.deleted,
             version: if batch.version == Uuid::nil() {
                 self.uuid_service.new_uuid(&format!(
@@ -198,4 +205,130 @@ impl<Deps: RebookingBatchServiceDeps> RebookingBatchService for RebookingBatchSe
         self.transaction_dao.commit(tx).await?;
         Ok(new_batch)
     }
+
+    async fn find_by_state(
+        &self,
+        state: RebookingBatchState,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[RebookingBatchEntity]>, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let result = self
+            .rebooking_batch_dao
+            .find_by_state(state, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(result)
+    }
+
+    async fn list_entries(
+        &self,
+        batch_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[RebookingBatchEntryEntity]>, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let result = self
+            .rebooking_batch_dao
+            .list_entries_for_batch(batch_id, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(result)
+    }
+
+    async fn update(
+        &self,
+        batch: &RebookingBatchEntity,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatchEntity, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let active = self
+            .rebooking_batch_dao
+            .find_by_id(batch.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(batch.id))?;
+        if batch.version != active.version {
+            return Err(ServiceError::EntityConflicts(
+                batch.id,
+                batch.version,
+                active.version,
+            ));
+        }
+
+        // Identitaet und Wochen-Slot bleiben unveraenderlich.
+        let updated = RebookingBatchEntity {
+            state: batch.state,
+            approved: batch.approved,
+            approved_by: batch.approved_by.clone(),
+            rejected: batch.rejected,
+            rejected_by: batch.rejected_by.clone(),
+            reject_reason: batch.reject_reason.clone(),
+            version: self.uuid_service.new_uuid(&format!(
+                "{REBOOKING_BATCH_SERVICE_PROCESS}::update batch version"
+            )),
+            ..active
+        };
+        self.rebooking_batch_dao
+            .update_batch(&updated, REBOOKING_BATCH_SERVICE_PROCESS, tx.clone())
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn update_entry(
+        &self,
+        entry: &RebookingBatchEntryEntity,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatchEntryEntity, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let active = self
+            .rebooking_batch_dao
+            .list_entries_for_batch(entry.batch_id, tx.clone())
+            .await?
+            .iter()
+            .find(|active| active.id == entry.id)
+            .cloned()
+            .ok_or(ServiceError::EntityNotFound(entry.id))?;
+        if entry.version != active.version {
+            return Err(ServiceError::EntityConflicts(
+                entry.id,
+                entry.version,
+                active.version,
+            ));
+        }
+
+        let updated = RebookingBatchEntryEntity {
+            extra_hours_out_id: entry.extra_hours_out_id,
+            extra_hours_in_id: entry.extra_hours_in_id,
+            version: self.uuid_service.new_uuid(&format!(
+                "{REBOOKING_BATCH_SERVICE_PROCESS}::update entry version"
+            )),
+            ..active
+        };
+        self.rebooking_batch_dao
+            .update_entry(&updated, REBOOKING_BATCH_SERVICE_PROCESS, tx.clone())
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
 }
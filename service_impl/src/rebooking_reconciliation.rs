@@ -0,0 +1,710 @@
+//! Phase 55 (F3/F5): Business-Logic-Tier `RebookingReconciliationService`.
+//!
+//! Konsumiert den Basic-Tier `RebookingBatchService` (Persistenz + optimistisches
+//! Locking), `VoluntaryStatsService` + `ReportingService` fuer den Vorschlag,
+//! `ExtraHoursService` fuer das Paar bei Genehmigung und `WeekStatusService`
+//! fuer die Wochensperre. Nach dem HR-Gate am Einstieg laufen interne Loads mit
+//! `Authentication::Full`; die `extra_hours`-Paare werden mit dem Context des
+//! Aufrufers angelegt, damit das Aenderungsprotokoll den HR-Benutzer zeigt.
+//!
+//! Der Auto-Cron (F4) liest Stichtag und Backfill-Merker ueber den
+//! `ToggleService` und die Kandidaten ueber den `EmployeeWorkDetailsService`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    rebooking_batch::{
+        RebookingBatchEntity, RebookingBatchEntryEntity, RebookingBatchKind, RebookingBatchState,
+    },
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    clock::ClockService,
+    employee_work_details::EmployeeWorkDetailsService,
+    extra_hours::{ExtraHours, ExtraHoursCategory, ExtraHoursService, ExtraHoursSource},
+    permission::{Authentication, HR_PRIVILEGE},
+    rebooking_batch::RebookingBatchService,
+    rebooking_reconciliation::{
+        AutoRebookingReport, RebookingBatch, RebookingReconciliationService,
+    },
+    reporting::ReportingService,
+    toggle::ToggleService,
+    voluntary_stats::VoluntaryStatsService,
+    week_status::{WeekStatus, WeekStatusService},
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::{DayOfWeek, ShiftyDate, ShiftyWeek};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::shortday_gate::parse_active_from;
+
+const REBOOKING_RECONCILIATION_PROCESS: &str = "rebooking-reconciliation-service";
+
+/// Stichtag (ISO-Datum im `value`) fuer den Auto-Cron; ohne Wert ist F4 aus.
+pub const AUTO_ACTIVE_FROM_TOGGLE: &str = "voluntary_rebooking_auto_active_from";
+/// Stichtag, fuer den der einmalige Backfill bereits gelaufen ist.
+pub const BACKFILL_DONE_TOGGLE: &str = "voluntary_rebooking_backfill_done_for";
+
+fn state_not_allowed() -> ServiceError {
+    ServiceError::ValidationError(Arc::new([ValidationFailureItem::ModificationNotAllowed(
+        "state".into(),
+    )]))
+}
+
+fn invalid_value(field: &str) -> ServiceError {
+    ServiceError::ValidationError(Arc::new([ValidationFailureItem::InvalidValue(
+        field.into(),
+    )]))
+}
+
+/// Vorschlag in Stunden: freiwilliger Ueberschuss ueber dem Soll, hoechstens
+/// das Defizit im Stundenkonto, auf zwei Nachkommastellen gerundet.
+pub fn suggested_hours(voluntary_delta: f32, balance: f32) -> f32 {
+    let hours = voluntary_delta.max(0.0).min((-balance).max(0.0));
+    (hours * 100.0).round() / 100.0
+}
+
+gen_service_impl! {
+    struct RebookingReconciliationServiceImpl: RebookingReconciliationService = RebookingReconciliationServiceDeps {
+        RebookingBatchService: RebookingBatchService<Context = Self::Context, Transaction = Self::Transaction> = rebooking_batch_service,
+        VoluntaryStatsService: VoluntaryStatsService<Context = Self::Context, Transaction = Self::Transaction> = voluntary_stats_service,
+        ReportingService: ReportingService<Context = Self::Context, Transaction = Self::Transaction> = reporting_service,
+        ExtraHoursService: ExtraHoursService<Context = Self::Context, Transaction = Self::Transaction> = extra_hours_service,
+        WeekStatusService: WeekStatusService<Context = Self::Context, Transaction = Self::Transaction> = week_status_service,
+        ToggleService: ToggleService<Context = Self::Context, Transaction = Self::Transaction> = toggle_service,
+        EmployeeWorkDetailsService: EmployeeWorkDetailsService<Context = Self::Context, Transaction = Self::Transaction> = employee_work_details_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: RebookingReconciliationServiceDeps> RebookingReconciliationServiceImpl<Deps> {
+    async fn is_week_locked(
+        &self,
+        iso_year: u32,
+        iso_week: u8,
+        tx: Deps::Transaction,
+    ) -> Result<bool, ServiceError> {
+        let status = self
+            .week_status_service
+            .get_week_status(iso_year, iso_week, Authentication::Full, Some(tx))
+            .await?;
+        Ok(status == WeekStatus::Locked)
+    }
+
+    async fn assert_week_not_locked(
+        &self,
+        iso_year: u32,
+        iso_week: u8,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        if self.is_week_locked(iso_year, iso_week, tx).await? {
+            return Err(ServiceError::WeekLocked {
+                year: iso_year,
+                week: iso_week,
+            });
+        }
+        Ok(())
+    }
+
+    async fn load(
+        &self,
+        id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<(RebookingBatchEntity, Arc<[RebookingBatchEntryEntity]>), ServiceError> {
+        let batch = self
+            .rebooking_batch_service
+            .find_by_id(id, Authentication::Full, Some(tx.clone()))
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let entries = self
+            .rebooking_batch_service
+            .list_entries(id, Authentication::Full, Some(tx))
+            .await?;
+        Ok((batch, entries))
+    }
+
+    async fn load_pending(
+        &self,
+        id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<(RebookingBatchEntity, Arc<[RebookingBatchEntryEntity]>), ServiceError> {
+        let (batch, entries) = self.load(id, tx).await?;
+        if batch.state != RebookingBatchState::Pending {
+            return Err(state_not_allowed());
+        }
+        Ok((batch, entries))
+    }
+
+    /// Stunden aus genehmigten und offenen Batches des Mitarbeiters im
+    /// ISO-Jahr als `(claimed, outside_balance)`: `claimed` ist bereits vom
+    /// freiwilligen Ueberschuss beansprucht, `outside_balance` fehlt dem
+    /// Stundenkonto bis Ende von `iso_week` noch (offene Batches und
+    /// genehmigte Batches spaeterer Wochen).
+    async fn rebooked_hours(
+        &self,
+        sales_person_id: Uuid,
+        iso_year: u32,
+        iso_week: u8,
+        tx: Deps::Transaction,
+    ) -> Result<(f32, f32), ServiceError> {
+        let mut claimed = 0.0;
+        let mut outside_balance = 0.0;
+        for state in [RebookingBatchState::Approved, RebookingBatchState::Pending] {
+            let batches = self
+                .rebooking_batch_service
+                .find_by_state(state, Authentication::Full, Some(tx.clone()))
+                .await?;
+            for batch in batches.iter().filter(|batch| {
+                batch.sales_person_id == sales_person_id && batch.iso_year == iso_year
+            }) {
+                let hours: f32 = self
+                    .rebooking_batch_service
+                    .list_entries(batch.id, Authentication::Full, Some(tx.clone()))
+                    .await?
+                    .iter()
+                    .map(|entry| entry.hours)
+                    .sum();
+                claimed += hours;
+                if state == RebookingBatchState::Pending || batch.iso_week > iso_week {
+                    outside_balance += hours;
+                }
+            }
+        }
+        Ok((claimed, outside_balance))
+    }
+
+    /// Entry fuer die Woche mit F2-Ist/Soll und Stundenkonto von ISO-Woche 1
+    /// bis Ende der Woche. Ohne `hours` gilt [`suggested_hours`], abzueglich
+    /// der Stunden, die genehmigte und offene Batches im Jahr schon umbuchen.
+    async fn compute_entry(
+        &self,
+        sales_person_id: Uuid,
+        iso_year: u32,
+        iso_week: u8,
+        hours: Option<f32>,
+        tx: Deps::Transaction,
+    ) -> Result<RebookingBatchEntryEntity, ServiceError> {
+        let from_date = ShiftyDate::new(iso_year, 1, DayOfWeek::Monday)?;
+        let to_date = ShiftyDate::new(iso_year, iso_week, DayOfWeek::Sunday)?;
+        let stats = self
+            .voluntary_stats_service
+            .get_voluntary_stats(
+                sales_person_id,
+                from_date,
+                to_date,
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let report = self
+            .reporting_service
+            .get_report_for_employee_range(
+                &sales_person_id,
+                from_date,
+                to_date,
+                true,
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let hours = match hours {
+            Some(hours) => hours,
+            None => {
+                let (claimed, outside_balance) = self
+                    .rebooked_hours(sales_person_id, iso_year, iso_week, tx)
+                    .await?;
+                suggested_hours(
+                    stats.delta.unwrap_or(0.0) - claimed,
+                    report.balance_hours + outside_balance,
+                )
+            }
+        };
+
+        // id/version/created vergibt der Basic-Service.
+        Ok(RebookingBatchEntryEntity {
+            id: Uuid::nil(),
+            batch_id: Uuid::nil(),
+            sales_person_id,
+            hours,
+            balance_before: report.balance_hours,
+            voluntary_actual: stats.ist_total.unwrap_or(0.0),
+            voluntary_committed: stats.soll_total.unwrap_or(0.0),
+            extra_hours_out_id: None,
+            extra_hours_in_id: None,
+            created: time::PrimitiveDateTime::MIN,
+            deleted: None,
+            version: Uuid::nil(),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_batch(
+        &self,
+        entry: &RebookingBatchEntryEntity,
+        iso_year: u32,
+        iso_week: u8,
+        kind: RebookingBatchKind,
+        state: RebookingBatchState,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<RebookingBatch, ServiceError> {
+        let batch = RebookingBatchEntity {
+            id: Uuid::nil(),
+            sales_person_id: entry.sales_person_id,
+            iso_year,
+            iso_week,
+            kind,
+            state,
+            created: time::PrimitiveDateTime::MIN,
+            approved: None,
+            approved_by: None,
+            rejected: None,
+            rejected_by: None,
+            reject_reason: None,
+            deleted: None,
+            version: Uuid::nil(),
+        };
+        let batch = self
+            .rebooking_batch_service
+            .create(
+                &batch,
+                std::slice::from_ref(entry),
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let entries = self
+            .rebooking_batch_service
+            .list_entries(batch.id, Authentication::Full, Some(tx.clone()))
+            .await?;
+        let batch = RebookingBatch::from_entities(&batch, &entries);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::REBOOKING_BATCH, batch.id, &batch)?,
+                REBOOKING_RECONCILIATION_PROCESS,
+                context,
+                tx.into(),
+            )
+            .await?;
+        Ok(batch)
+    }
+
+    /// Zustandswechsel eines Batches fuer das Aenderungsprotokoll.
+    async fn record_update(
+        &self,
+        before: &RebookingBatch,
+        after: &RebookingBatch,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::REBOOKING_BATCH, after.id, before, after)?,
+                REBOOKING_RECONCILIATION_PROCESS,
+                context,
+                tx.into(),
+            )
+            .await
+    }
+
+    /// Gedeckelte Mitarbeiter mit `committed_voluntary > 0` und Vertrag in
+    /// der Woche.
+    async fn auto_cron_candidates(
+        &self,
+        week: ShiftyWeek,
+        tx: Deps::Transaction,
+    ) -> Result<Vec<Uuid>, ServiceError> {
+        let mut sales_person_ids: Vec<Uuid> = self
+            .employee_work_details_service
+            .all_for_week(week.week, week.year, Authentication::Full, Some(tx))
+            .await?
+            .iter()
+            .filter(|details| {
+                details.cap_planned_hours_to_expected && details.committed_voluntary > 0.0
+            })
+            .map(|details| details.sales_person_id)
+            .collect();
+        sales_person_ids.sort();
+        sales_person_ids.dedup();
+        Ok(sales_person_ids)
+    }
+
+    /// Legt fuer jeden Kandidaten ohne Batch in der Woche einen Batch der
+    /// Art `kind` an, sofern es etwas umzubuchen gibt.
+    async fn auto_cron_week(
+        &self,
+        week: ShiftyWeek,
+        kind: RebookingBatchKind,
+        report: &mut AutoRebookingReport,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let state = if self
+            .is_week_locked(week.year, week.week, tx.clone())
+            .await?
+        {
+            RebookingBatchState::SkippedLocked
+        } else {
+            RebookingBatchState::Pending
+        };
+        for sales_person_id in self.auto_cron_candidates(week, tx.clone()).await? {
+            let existing = self
+                .rebooking_batch_service
+                .find_by_sales_person_year_week(
+                    sales_person_id,
+                    week.year,
+                    week.week,
+                    Authentication::Full,
+                    Some(tx.clone()),
+                )
+                .await?;
+            if existing.is_some() {
+                continue;
+            }
+            let entry = self
+                .compute_entry(sales_person_id, week.year, week.week, None, tx.clone())
+                .await?;
+            if entry.hours <= 0.0 {
+                continue;
+            }
+            self.create_batch(
+                &entry,
+                week.year,
+                week.week,
+                kind,
+                state,
+                Authentication::Full,
+                tx.clone(),
+            )
+            .await?;
+            match state {
+                RebookingBatchState::SkippedLocked => report.skipped_locked += 1,
+                _ => report.pending += 1,
+            }
+        }
+        Ok(())
+    }
+
+    /// Legt eine `Rebooking`-Row am Montag der Batch-Woche an.
+    async fn create_extra_hours(
+        &self,
+        batch: &RebookingBatchEntity,
+        amount: f32,
+        category: ExtraHoursCategory,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<ExtraHours, ServiceError> {
+        let monday = ShiftyDate::new(batch.iso_year, batch.iso_week, DayOfWeek::Monday)?;
+        let extra_hours = ExtraHours {
+            id: Uuid::nil(),
+            sales_person_id: batch.sales_person_id,
+            amount,
+            category,
+            description: format!("Umbuchung KW {}/{}", batch.iso_week, batch.iso_year).into(),
+            date_time: monday.to_date().midnight(),
+            created: None,
+            deleted: None,
+            version: Uuid::nil(),
+            source: ExtraHoursSource::Rebooking,
+        };
+        self.extra_hours_service
+            .create(&extra_hours, context, Some(tx))
+            .await
+    }
+}
+
+#[async_trait]
+impl<Deps: RebookingReconciliationServiceDeps> RebookingReconciliationService
+    for RebookingReconciliationServiceImpl<Deps>
+{
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn suggest(
+        &self,
+        sales_person_id: Uuid,
+        iso_year: u32,
+        iso_week: u8,
+        hours: Option<f32>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        if hours.is_some_and(|hours| hours <= 0.0) {
+            return Err(invalid_value("hours"));
+        }
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.assert_week_not_locked(iso_year, iso_week, tx.clone())
+            .await?;
+
+        let entry = self
+            .compute_entry(sales_person_id, iso_year, iso_week, hours, tx.clone())
+            .await?;
+        if entry.hours <= 0.0 {
+            return Err(invalid_value("hours"));
+        }
+        let batch = self
+            .create_batch(
+                &entry,
+                iso_year,
+                iso_week,
+                RebookingBatchKind::HrSuggestion,
+                RebookingBatchState::Pending,
+                context,
+                tx.clone(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(batch)
+    }
+
+    async fn find_pending(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[RebookingBatch]>, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let batches = self
+            .rebooking_batch_service
+            .find_by_state(
+                RebookingBatchState::Pending,
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let mut result = Vec::with_capacity(batches.len());
+        for batch in batches.iter() {
+            let entries = self
+                .rebooking_batch_service
+                .list_entries(batch.id, Authentication::Full, Some(tx.clone()))
+                .await?;
+            result.push(RebookingBatch::from_entities(batch, &entries));
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(result.into())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (batch, entries) = self.load(id, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(RebookingBatch::from_entities(&batch, &entries))
+    }
+
+    async fn approve(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (batch, entries) = self.load_pending(id, tx.clone()).await?;
+        self.assert_week_not_locked(batch.iso_year, batch.iso_week, tx.clone())
+            .await?;
+
+        let mut approved_entries = Vec::with_capacity(entries.len());
+        for entry in entries.iter() {
+            let out = self
+                .create_extra_hours(
+                    &batch,
+                    -entry.hours,
+                    ExtraHoursCategory::VolunteerWork,
+                    context.clone(),
+                    tx.clone(),
+                )
+                .await?;
+            let into = self
+                .create_extra_hours(
+                    &batch,
+                    entry.hours,
+                    ExtraHoursCategory::ExtraWork,
+                    context.clone(),
+                    tx.clone(),
+                )
+                .await?;
+            let entry = self
+                .rebooking_batch_service
+                .update_entry(
+                    &RebookingBatchEntryEntity {
+                        extra_hours_out_id: Some(out.id),
+                        extra_hours_in_id: Some(into.id),
+                        ..entry.clone()
+                    },
+                    Authentication::Full,
+                    Some(tx.clone()),
+                )
+                .await?;
+            approved_entries.push(entry);
+        }
+
+        let before = RebookingBatch::from_entities(&batch, &entries);
+        let approved_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?;
+        let batch = self
+            .rebooking_batch_service
+            .update(
+                &RebookingBatchEntity {
+                    state: RebookingBatchState::Approved,
+                    approved: Some(self.clock_service.date_time_now()),
+                    approved_by,
+                    ..batch
+                },
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let after = RebookingBatch::from_entities(&batch, &approved_entries);
+        self.record_update(&before, &after, context, tx.clone()).await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(after)
+    }
+
+    async fn reject(
+        &self,
+        id: Uuid,
+        reason: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<RebookingBatch, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(invalid_value("reason"));
+        }
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (batch, entries) = self.load_pending(id, tx.clone()).await?;
+        let before = RebookingBatch::from_entities(&batch, &entries);
+        let rejected_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?;
+        let batch = self
+            .rebooking_batch_service
+            .update(
+                &RebookingBatchEntity {
+                    state: RebookingBatchState::Rejected,
+                    rejected: Some(self.clock_service.date_time_now()),
+                    rejected_by,
+                    reject_reason: Some(reason.into()),
+                    ..batch
+                },
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let after = RebookingBatch::from_entities(&batch, &entries);
+        self.record_update(&before, &after, context, tx.clone()).await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(after)
+    }
+
+    async fn run_auto_cron(
+        &self,
+        today: time::Date,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<AutoRebookingReport, ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let mut report = AutoRebookingReport::default();
+        let raw_cutoff = self
+            .toggle_service
+            .get_toggle_value(
+                AUTO_ACTIVE_FROM_TOGGLE,
+                Authentication::Full,
+                Some(tx.clone()),
+            )
+            .await?;
+        let Some(cutoff) = parse_active_from(raw_cutoff.as_deref()) else {
+            self.transaction_dao.commit(tx).await?;
+            return Ok(report);
+        };
+
+        // Nur Wochen, die am oder nach dem Stichtag beginnen.
+        let cutoff_date = ShiftyDate::from_date(cutoff);
+        let first_week = if cutoff_date.day_of_week() == DayOfWeek::Monday {
+            cutoff_date.as_shifty_week()
+        } else {
+            cutoff_date.as_shifty_week().next()
+        };
+        let last_week = ShiftyDate::from_date(today - time::Duration::weeks(1)).as_shifty_week();
+        if first_week > last_week {
+            self.transaction_dao.commit(tx).await?;
+            return Ok(report);
+        }
+
+        let backfill_done_for = self
+            .toggle_service
+            .get_toggle_value(BACKFILL_DONE_TOGGLE, Authentication::Full, Some(tx.clone()))
+            .await?;
+        if parse_active_from(backfill_done_for.as_deref()) != Some(cutoff) {
+            for week in first_week
+                .iter_until(&last_week)
+                .take_while(|week| *week < last_week)
+            {
+                self.auto_cron_week(
+                    week,
+                    RebookingBatchKind::AutoCronBackfill,
+                    &mut report,
+                    tx.clone(),
+                )
+                .await?;
+                report.backfill_weeks += 1;
+            }
+            self.toggle_service
+                .set_toggle_value(
+                    BACKFILL_DONE_TOGGLE,
+                    Some(cutoff.to_string()),
+                    Authentication::Full,
+                    Some(tx.clone()),
+                )
+                .await?;
+        }
+        self.auto_cron_week(
+            last_week,
+            RebookingBatchKind::AutoCron,
+            &mut report,
+            tx.clone(),
+        )
+        .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,544 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    billing_period::{BillingPeriod, BillingPeriodService, BillingPeriodValueType},
+    permission::{Authentication, HR_PRIVILEGE},
+    report_export::{
+        ExportFile, ExportFormat, ExportLanguage, ReportBreakdown, ReportExportService,
+    },
+    reporting::{CustomExtraHours, EmployeeReport, ReportingService, ShortEmployeeReport},
+    sales_person::{SalesPerson, SalesPersonService},
+    PermissionService, ServiceError,
+};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::table_export::{write_table, ExportCell, ExportTable};
+
+gen_service_impl! {
+    struct ReportExportServiceImpl: ReportExportService = ReportExportServiceDeps {
+        ReportingService: ReportingService<Context = Self::Context, Transaction = Self::Transaction> = reporting_service,
+        BillingPeriodService: BillingPeriodService<Context = Self::Context, Transaction = Self::Transaction> = billing_period_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+/// Spaltenüberschriften. Texte wie im Frontend (`shifty-dioxus/src/i18n`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Label {
+    Name,
+    Balance,
+    ExpectedHours,
+    Overall,
+    DynamicHours,
+    ContractHours,
+    Shiftplan,
+    ExtraWork,
+    Vacation,
+    VacationHours,
+    VacationDays,
+    VacationEntitlement,
+    SickLeave,
+    Holiday,
+    Unavailable,
+    UnpaidLeave,
+    Volunteer,
+    CalendarWeek,
+    Month,
+    From,
+    To,
+    Delta,
+    YtdFrom,
+    YtdTo,
+    FullYear,
+    EmployeeReportsSheet,
+    BillingPeriodSheet,
+}
+
+impl Label {
+    pub fn text(self, language: ExportLanguage) -> &'static str {
+        use ExportLanguage::*;
+        match (self, language) {
+            (Label::Name, De) => "Name",
+            (Label::Name, En) => "Name",
+            (Label::Name, Cs) => "Jméno",
+            (Label::Balance, De) => "Stundenkonto",
+            (Label::Balance, En) => "Balance",
+            (Label::Balance, Cs) => "Zůstatek",
+            (Label::ExpectedHours, De) => "Sollstunden",
+            (Label::ExpectedHours, En) => "Expected Hours",
+            (Label::ExpectedHours, Cs) => "Očekávané hodiny",
+            (Label::Overall, De) => "Gesamt",
+            (Label::Overall, En) => "Actual",
+            (Label::Overall, Cs) => "Skutečné",
+            (Label::DynamicHours, De) => "Dynamische Stunden",
+            (Label::DynamicHours, En) => "Dynamic hours",
+            (Label::DynamicHours, Cs) => "Dynamické hodiny",
+            (Label::ContractHours, De) => "Vertragsstunden",
+            (Label::ContractHours, En) => "Contract hours",
+            (Label::ContractHours, Cs) => "Smluvní hodiny",
+            (Label::Shiftplan, De) => "Schichtplan",
+            (Label::Shiftplan, En) => "Shiftplan",
+            (Label::Shiftplan, Cs) => "Plán směn",
+            (Label::ExtraWork, De) => "Zusatzarbeit",
+            (Label::ExtraWork, En) => "Extra work",
+            (Label::ExtraWork, Cs) => "Přesčasy",
+            (Label::Vacation, De) => "Urlaub",
+            (Label::Vacation, En) => "Vacation",
+            (Label::Vacation, Cs) => "Dovolená",
+            (Label::VacationHours, De) => "Urlaub (Stunden)",
+            (Label::VacationHours, En) => "Vacation (hours)",
+            (Label::VacationHours, Cs) => "Dovolená (hodiny)",
+            (Label::VacationDays, De) => "Urlaubstage",
+            (Label::VacationDays, En) => "Vacation days",
+            (Label::VacationDays, Cs) => "Dny dovolené",
+            (Label::VacationEntitlement, De) => "Urlaubsanspruch",
+            (Label::VacationEntitlement, En) => "Vacation entitlement",
+            (Label::VacationEntitlement, Cs) => "Nárok na dovolenou",
+            (Label::SickLeave, De) => "Krank",
+            (Label::SickLeave, En) => "Sick leave",
+            (Label::SickLeave, Cs) => "Nemocenská",
+            (Label::Holiday, De) => "Feiertage",
+            (Label::Holiday, En) => "Holiday",
+            (Label::Holiday, Cs) => "Svátky",
+            (Label::Unavailable, De) => "Nicht verfügbar",
+            (Label::Unavailable, En) => "Unavailable",
+            (Label::Unavailable, Cs) => "Nedostupný",
+            (Label::UnpaidLeave, De) => "Unbezahlter Urlaub",
+            (Label::UnpaidLeave, En) => "Unpaid Leave",
+            (Label::UnpaidLeave, Cs) => "Neplacené volno",
+            (Label::Volunteer, De) => "Ehrenamt",
+            (Label::Volunteer, En) => "Voluntary work",
+            (Label::Volunteer, Cs) => "Dobrovolnictví",
+            (Label::CalendarWeek, De) => "KW",
+            (Label::CalendarWeek, En) => "Week",
+            (Label::CalendarWeek, Cs) => "Týden",
+            (Label::Month, De) => "Monat",
+            (Label::Month, En) => "Month",
+            (Label::Month, Cs) => "Měsíc",
+            (Label::From, De) => "Von",
+            (Label::From, En) => "From",
+            (Label::From, Cs) => "Od",
+            (Label::To, De) => "Bis",
+            (Label::To, En) => "To",
+            (Label::To, Cs) => "Do",
+            (Label::Delta, De) => "Unterschied",
+            (Label::Delta, En) => "Delta",
+            (Label::Delta, Cs) => "Delta",
+            (Label::YtdFrom, De) => "YTD Von",
+            (Label::YtdFrom, En) => "YTD From",
+            (Label::YtdFrom, Cs) => "YTD Od",
+            (Label::YtdTo, De) => "YTD Bis",
+            (Label::YtdTo, En) => "YTD To",
+            (Label::YtdTo, Cs) => "YTD Do",
+            (Label::FullYear, De) => "Ganzes Jahr",
+            (Label::FullYear, En) => "Full Year",
+            (Label::FullYear, Cs) => "Celý rok",
+            (Label::EmployeeReportsSheet, De) => "Mitarbeiter",
+            (Label::EmployeeReportsSheet, En) => "Employees",
+            (Label::EmployeeReportsSheet, Cs) => "Zaměstnanci",
+            (Label::BillingPeriodSheet, De) => "Abrechnungszeitraum",
+            (Label::BillingPeriodSheet, En) => "Billing period",
+            (Label::BillingPeriodSheet, Cs) => "Zúčtovací období",
+        }
+    }
+
+    fn header(self, language: ExportLanguage) -> Arc<str> {
+        self.text(language).into()
+    }
+}
+
+/// ASCII-Teil für Dateinamen (`Müller Anna` → `mueller-anna`).
+pub(crate) fn file_name_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.to_lowercase().chars() {
+        match c {
+            'ä' => slug.push_str("ae"),
+            'ö' => slug.push_str("oe"),
+            'ü' => slug.push_str("ue"),
+            'ß' => slug.push_str("ss"),
+            c if c.is_ascii_alphanumeric() => slug.push(c),
+            _ if !slug.ends_with('-') => slug.push('-'),
+            _ => {}
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "export".into()
+    } else {
+        slug.into()
+    }
+}
+
+/// Namen der zusätzlichen Stunden über alle Zeilen, alphabetisch. Jeder Name
+/// wird eine eigene Spalte.
+fn custom_extra_hours_names<'a>(
+    rows: impl Iterator<Item = &'a Arc<[CustomExtraHours]>>,
+) -> Vec<Arc<str>> {
+    rows.flat_map(|custom| custom.iter().map(|custom| custom.name.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn custom_extra_hours_cells(
+    names: &[Arc<str>],
+    custom_extra_hours: &[CustomExtraHours],
+) -> impl Iterator<Item = ExportCell> {
+    names
+        .iter()
+        .map(|name| {
+            custom_extra_hours
+                .iter()
+                .filter(|custom| &custom.name == name)
+                .fold(0.0, |sum, custom| sum + custom.hours)
+                .into()
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+pub fn employee_reports_table(
+    reports: &[ShortEmployeeReport],
+    language: ExportLanguage,
+) -> ExportTable {
+    let custom_names = custom_extra_hours_names(reports.iter().map(|r| &r.custom_absence_hours));
+    let headers = [
+        Label::Name,
+        Label::Balance,
+        Label::ExpectedHours,
+        Label::Overall,
+        Label::DynamicHours,
+        Label::Vacation,
+        Label::SickLeave,
+        Label::Holiday,
+        Label::Unavailable,
+        Label::UnpaidLeave,
+        Label::Volunteer,
+    ]
+    .into_iter()
+    .map(|label| label.header(language))
+    .chain(custom_names.iter().cloned())
+    .collect();
+
+    let mut reports: Vec<&ShortEmployeeReport> = reports.iter().collect();
+    reports.sort_by(|a, b| a.sales_person.name.cmp(&b.sales_person.name));
+    let rows = reports
+        .into_iter()
+        .map(|report| {
+            [
+                report.sales_person.name.clone().into(),
+                report.balance_hours.into(),
+                report.expected_hours.into(),
+                report.overall_hours.into(),
+                report.dynamic_hours.into(),
+                report.vacation_hours.into(),
+                report.sick_leave_hours.into(),
+                report.holiday_hours.into(),
+                report.unavailable_hours.into(),
+                report.unpaid_leave_hours.into(),
+                report.volunteer_hours.into(),
+            ]
+            .into_iter()
+            .chain(custom_extra_hours_cells(
+                &custom_names,
+                &report.custom_absence_hours,
+            ))
+            .collect()
+        })
+        .collect();
+
+    ExportTable {
+        sheet_name: Label::EmployeeReportsSheet.header(language),
+        headers,
+        rows,
+    }
+}
+
+pub fn employee_report_table(
+    report: &EmployeeReport,
+    breakdown: ReportBreakdown,
+    language: ExportLanguage,
+) -> ExportTable {
+    let groups = match breakdown {
+        ReportBreakdown::ByWeek => &report.by_week,
+        ReportBreakdown::ByMonth => &report.by_month,
+    };
+    let custom_names = custom_extra_hours_names(groups.iter().map(|g| &g.custom_extra_hours));
+    let period_label = match breakdown {
+        ReportBreakdown::ByWeek => Label::CalendarWeek,
+        ReportBreakdown::ByMonth => Label::Month,
+    };
+    let headers = [
+        period_label,
+        Label::From,
+        Label::To,
+        Label::ContractHours,
+        Label::ExpectedHours,
+        Label::Overall,
+        Label::Balance,
+        Label::Shiftplan,
+        Label::ExtraWork,
+        Label::Vacation,
+        Label::SickLeave,
+        Label::Holiday,
+        Label::UnpaidLeave,
+        Label::Volunteer,
+        Label::DynamicHours,
+    ]
+    .into_iter()
+    .map(|label| label.header(language))
+    .chain(custom_names.iter().cloned())
+    .collect();
+
+    let rows = groups
+        .iter()
+        .map(|group| {
+            let from = group.from.to_date();
+            let period: Arc<str> = match breakdown {
+                ReportBreakdown::ByWeek => format!("{}-{:02}", group.year, group.week).into(),
+                ReportBreakdown::ByMonth => {
+                    format!("{}-{:02}", from.year(), u8::from(from.month())).into()
+                }
+            };
+            [
+                period.into(),
+                Arc::<str>::from(from.to_string()).into(),
+                Arc::<str>::from(group.to.to_date().to_string()).into(),
+                group.contract_weekly_hours.into(),
+                group.expected_hours.into(),
+                group.overall_hours.into(),
+                group.balance.into(),
+                group.shiftplan_hours.into(),
+                group.extra_work_hours.into(),
+                group.vacation_hours.into(),
+                group.sick_leave_hours.into(),
+                group.holiday_hours.into(),
+                group.unpaid_leave_hours.into(),
+                group.volunteer_hours.into(),
+                group.dynamic_hours.into(),
+            ]
+            .into_iter()
+            .chain(custom_extra_hours_cells(
+                &custom_names,
+                &group.custom_extra_hours,
+            ))
+            .collect()
+        })
+        .collect();
+
+    ExportTable {
+        sheet_name: report.sales_person.name.clone(),
+        headers,
+        rows,
+    }
+}
+
+fn value_type_label(value_type: &BillingPeriodValueType, language: ExportLanguage) -> Arc<str> {
+    let label = match value_type {
+        BillingPeriodValueType::Balance => Label::Balance,
+        BillingPeriodValueType::Overall => Label::Overall,
+        BillingPeriodValueType::ExpectedHours => Label::ExpectedHours,
+        BillingPeriodValueType::ExtraWork => Label::ExtraWork,
+        BillingPeriodValueType::VacationHours => Label::VacationHours,
+        BillingPeriodValueType::SickLeave => Label::SickLeave,
+        BillingPeriodValueType::UnpaidLeave => Label::UnpaidLeave,
+        BillingPeriodValueType::Holiday => Label::Holiday,
+        BillingPeriodValueType::Volunteer => Label::Volunteer,
+        BillingPeriodValueType::VacationDays => Label::VacationDays,
+        BillingPeriodValueType::VacationEntitlement => Label::VacationEntitlement,
+        BillingPeriodValueType::CustomExtraHours(name) => return name.clone(),
+    };
+    label.header(language)
+}
+
+/// Eine Zeile pro Mitarbeiter; je Werttyp vier Spalten (Unterschied, YTD von,
+/// YTD bis, ganzes Jahr). Fehlt ein Wert (ältere Snapshots), bleibt die
+/// Zelle leer.
+pub fn billing_period_table(
+    billing_period: &BillingPeriod,
+    sales_persons: &[SalesPerson],
+    language: ExportLanguage,
+) -> ExportTable {
+    let value_types: BTreeSet<&BillingPeriodValueType> = billing_period
+        .sales_persons
+        .iter()
+        .flat_map(|sales_person| sales_person.values.keys())
+        .collect();
+    let views = [Label::Delta, Label::YtdFrom, Label::YtdTo, Label::FullYear];
+
+    let headers = std::iter::once(Label::Name.header(language))
+        .chain(value_types.iter().flat_map(|value_type| {
+            let value_label = value_type_label(value_type, language);
+            views.iter().map(move |view| -> Arc<str> {
+                format!("{} ({})", value_label, view.text(language)).into()
+            })
+        }))
+        .collect();
+
+    let name_of = |sales_person_id: Uuid| -> Arc<str> {
+        sales_persons
+            .iter()
+            .find(|sales_person| sales_person.id == sales_person_id)
+            .map(|sales_person| sales_person.name.clone())
+            .unwrap_or_else(|| sales_person_id.to_string().into())
+    };
+    let mut rows: Vec<(Arc<str>, Vec<ExportCell>)> = billing_period
+        .sales_persons
+        .iter()
+        .map(|sales_person| {
+            let name = name_of(sales_person.sales_person_id);
+            let cells = std::iter::once(name.clone().into())
+                .chain(value_types.iter().flat_map(|value_type| {
+                    match sales_person.values.get(value_type) {
+                        Some(value) => [
+                            value.value_delta.into(),
+                            value.value_ytd_from.into(),
+                            value.value_ytd_to.into(),
+                            value.value_full_year.into(),
+                        ],
+                        None => [
+                            ExportCell::Empty,
+                            ExportCell::Empty,
+                            ExportCell::Empty,
+                            ExportCell::Empty,
+                        ],
+                    }
+                }))
+                .collect();
+            (name, cells)
+        })
+        .collect();
+    rows.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    ExportTable {
+        sheet_name: Label::BillingPeriodSheet.header(language),
+        headers,
+        rows: rows.into_iter().map(|(_, cells)| cells).collect(),
+    }
+}
+
+fn export_file(
+    table: &ExportTable,
+    base_name: String,
+    format: ExportFormat,
+    language: ExportLanguage,
+) -> Result<ExportFile, ServiceError> {
+    Ok(ExportFile {
+        file_name: format!("{}.{}", base_name, format.extension()).into(),
+        content_type: format.content_type(),
+        content: write_table(table, format, language)?.into(),
+    })
+}
+
+#[async_trait]
+impl<Deps: ReportExportServiceDeps> ReportExportService for ReportExportServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn export_employee_reports(
+        &self,
+        year: u32,
+        until_week: u8,
+        format: ExportFormat,
+        language: ExportLanguage,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let reports = self
+            .reporting_service
+            .get_reports_for_all_employees(year, until_week, context, tx.clone().into())
+            .await?;
+
+        let file = export_file(
+            &employee_reports_table(&reports, language),
+            format!("employee-reports-{year}-w{until_week:02}"),
+            format,
+            language,
+        )?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(file)
+    }
+
+    async fn export_employee_report(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        until_week: u8,
+        breakdown: ReportBreakdown,
+        format: ExportFormat,
+        language: ExportLanguage,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let report = self
+            .reporting_service
+            .get_report_for_employee(
+                &sales_person_id,
+                year,
+                until_week,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        let breakdown_name = match breakdown {
+            ReportBreakdown::ByWeek => "by-week",
+            ReportBreakdown::ByMonth => "by-month",
+        };
+        let file = export_file(
+            &employee_report_table(&report, breakdown, language),
+            format!(
+                "employee-report-{}-{year}-{breakdown_name}",
+                file_name_slug(&report.sales_person.name)
+            ),
+            format,
+            language,
+        )?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(file)
+    }
+
+    async fn export_billing_period(
+        &self,
+        billing_period_id: Uuid,
+        format: ExportFormat,
+        language: ExportLanguage,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ExportFile, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        let billing_period = self
+            .billing_period_service
+            .get_billing_period_by_id(billing_period_id, context.clone(), tx.clone().into())
+            .await?;
+        let sales_persons = self
+            .sales_person_service
+            .get_all(context, tx.clone().into())
+            .await?;
+
+        let file = export_file(
+            &billing_period_table(&billing_period, &sales_persons, language),
+            format!(
+                "billing-period-{}-{}",
+                billing_period.start_date.to_date(),
+                billing_period.end_date.to_date()
+            ),
+            format,
+            language,
+        )?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(file)
+    }
+}
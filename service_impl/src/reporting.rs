@@ -13,7 +13,8 @@ use service::{
     clock::ClockService,
     employee_work_details::{EmployeeWorkDetails, EmployeeWorkDetailsService},
     extra_hours::{
-        Availability, ExtraHours, ExtraHoursCategory, ExtraHoursService, ReportType,
+        Availability, ExtraHours, ExtraHoursCategory, ExtraHoursService, ExtraHoursSource,
+        ReportType,
     },
     permission::{Authentication, HR_PRIVILEGE},
     reporting::{
@@ -23,6 +24,7 @@ use service::{
     sales_person::{SalesPerson, SalesPersonService},
     shiftplan_report::{ShiftplanReportDay, ShiftplanReportService},
     special_days::{SpecialDay, SpecialDayService, SpecialDayType},
+    time_entry::TimeEntryService,
     toggle::ToggleService,
     uuid_service::UuidService,
     PermissionService, ServiceError,
@@ -84,6 +86,9 @@ gen_service_impl! {
         // is Business-Logic tier and may consume both — no cycle.
         SpecialDayService: SpecialDayService<Context = Self::Context> = special_day_service,
         ToggleService: ToggleService<Context = Self::Context, Transaction = Self::Transaction> = toggle_service,
+        // Zeiterfassung: Verträge mit `use_actual_hours` rechnen mit den
+        // erfassten statt den geplanten Stunden.
+        TimeEntryService: TimeEntryService<Context = Self::Context, Transaction = Self::Transaction> = time_entry_service,
     }
 }
 
@@ -181,9 +186,26 @@ pub fn committed_voluntary_prorata_for_week(
 // `ReportingService::get_report_for_employee_range`) — das deckt alle drei
 // Erfassungsquellen (manuelle VolunteerWork-ExtraHours + Shiftplan-Cap-
 // Überlauf + no_contract-Shiftplan-Stunden) konsistent ab, statt nur die
-// erste zu zählen. Der source-Marker-Filter für Rebooking-Neutralität wird
-// ab Phase 55 zentral im ReportingService greifen und trifft dann
-// automatisch auch diese Kette.
+// erste zu zählen. Der source-Marker-Filter für Rebooking-Neutralität
+// greift zentral über [`reportable_extra_hours`] und trifft damit automatisch
+// auch diese Kette.
+
+/// F14 Reader-Regel (D-54-DM-02, VOL-ACCT-03): von einem genehmigten
+/// Rebooking-Batch bleibt nur die `-h VolunteerWork`-Row aus den
+/// Reporting-Aggregaten draußen, damit `volunteer_hours` (F1/F2) stabil
+/// bleibt. Die `+h ExtraWork`-Row zählt regulär in `balance_hours` — genau
+/// das ist der Zweck der Umbuchung. Audit-Abfragen lesen beide Rows weiterhin
+/// direkt über den `ExtraHoursService`.
+fn reportable_extra_hours(extra_hours: Arc<[ExtraHours]>) -> Arc<[ExtraHours]> {
+    extra_hours
+        .iter()
+        .filter(|eh| {
+            !(eh.source == ExtraHoursSource::Rebooking
+                && eh.category == ExtraHoursCategory::VolunteerWork)
+        })
+        .cloned()
+        .collect()
+}
 
 /// Phase 54 Gap-Closure G1 (VOL-STAT-01-Nenner, D-F1-01) +
 /// Phase 54.5 (D-54.5-02): Anzahl ISO-Wochen im Range
@@ -252,7 +274,7 @@ pub fn contract_weeks_count_in_range(
         // (kategorie-agnostisch, deleted-Filter, `period_overlaps_week`
         // ist die Single Source of Truth in booking_information.rs).
         let has_absence = absences.iter().any(|p| {
-            p.deleted.is_none() && period_overlaps_week(p.from_date, p.to_date, monday, sunday)
+            p.is_effective() && period_overlaps_week(p.from_date, p.to_date, monday, sunday)
         });
         if has_contract && !has_absence {
             count += 1;
@@ -351,6 +373,27 @@ pub fn committed_voluntary_target_in_range(
     total
 }
 
+/// Ersetzt die geplanten Tage einer Person in den Wochen, in denen einer
+/// ihrer aktiven Verträge `use_actual_hours` setzt, durch die erfassten
+/// Stunden (`actual_hours`). Alle anderen Wochen behalten die geplanten
+/// Stunden; erfasste Stunden dort werden ignoriert.
+pub fn apply_actual_hours(
+    shiftplan_report: &[ShiftplanReportDay],
+    actual_hours: &[ShiftplanReportDay],
+    working_hours: &[EmployeeWorkDetails],
+) -> Arc<[ShiftplanReportDay]> {
+    let uses_actual_hours = |day: &ShiftplanReportDay| {
+        find_working_hours_for_calendar_week(working_hours, day.year, day.calendar_week)
+            .any(|wh| wh.sales_person_id == day.sales_person_id && wh.use_actual_hours)
+    };
+    shiftplan_report
+        .iter()
+        .filter(|day| !uses_actual_hours(day))
+        .chain(actual_hours.iter().filter(|day| uses_actual_hours(day)))
+        .cloned()
+        .collect()
+}
+
 /// Caps shiftplan hours at expected hours when at least one of the active
 /// `EmployeeWorkDetails` records for the week sets `cap_planned_hours_to_expected`.
 /// Returns `(capped_shiftplan_hours, auto_volunteer_hours)`. When the cap is
@@ -460,7 +503,7 @@ fn derive_hours_for_week_pure(
         // ties broken by `max_by_key` = last-encountered wins — preserved).
         let dominant = absences_for_person
             .iter()
-            .filter(|ap| ap.deleted.is_none() && ap.from_date <= day && day <= ap.to_date)
+            .filter(|ap| ap.is_effective() && ap.from_date <= day && day <= ap.to_date)
             .max_by_key(|ap| absence_category_priority(&ap.category));
         let Some(dominant) = dominant else {
             continue;
@@ -555,6 +598,37 @@ fn build_derived_holiday_map_for_week_pure(
 }
 
 impl<Deps: ReportingServiceDeps> ReportingServiceImpl<Deps> {
+    /// Wendet [`apply_actual_hours`] an. Die Zeiterfassung wird nur gelesen,
+    /// wenn einer der Verträge `use_actual_hours` setzt.
+    async fn with_actual_hours(
+        &self,
+        shiftplan_report: Arc<[ShiftplanReportDay]>,
+        sales_person_id: Option<Uuid>,
+        from_date: ShiftyDate,
+        to_date: ShiftyDate,
+        working_hours: &[EmployeeWorkDetails],
+        tx: Option<Deps::Transaction>,
+    ) -> Result<Arc<[ShiftplanReportDay]>, ServiceError> {
+        if !working_hours.iter().any(|wh| wh.use_actual_hours) {
+            return Ok(shiftplan_report);
+        }
+        let actual_hours = self
+            .time_entry_service
+            .extract_actual_hours_report(
+                sales_person_id,
+                from_date,
+                to_date,
+                Authentication::Full,
+                tx,
+            )
+            .await?;
+        Ok(apply_actual_hours(
+            &shiftplan_report,
+            &actual_hours,
+            working_hours,
+        ))
+    }
+
     /// Phase 25 (HOL-01/02, HCFG-01/03): Build a per-employee derived-holiday map
     /// for a date range. The map is keyed by the concrete holiday date and
     /// contains the credited hours (= `EmployeeWorkDetails::holiday_hours()`).
@@ -754,7 +828,8 @@ impl<Deps: ReportingServiceDeps> ReportingServiceImpl<Deps> {
         //     so per-person entries stay in from_date order).
         let mut absences_by_sp: HashMap<Uuid, Vec<&AbsencePeriod>> = HashMap::new();
         for ap in all_absences.iter() {
-            if ap.deleted.is_some() {
+            // Offene, abgelehnte und zurückgezogene Anträge zählen nicht.
+            if !ap.is_effective() {
                 continue;
             }
             absences_by_sp.entry(ap.sales_person_id).or_default().push(ap);
@@ -1128,6 +1203,16 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
                 .filter(|wh| wh.sales_person_id == paid_employee.id)
                 .cloned()
                 .collect();
+            let detailed_shiftplan_report = self
+                .with_actual_hours(
+                    detailed_shiftplan_report,
+                    Some(paid_employee.id),
+                    ShiftyDate::first_day_in_year(year),
+                    ShiftyWeek::new(year, until_week).as_date(DayOfWeek::Sunday),
+                    &working_hours,
+                    tx.clone(),
+                )
+                .await?;
             let extra_hours_array = self
                 .extra_hours_service
                 .find_by_sales_person_id_and_year(
@@ -1138,6 +1223,7 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
                     tx.clone(),
                 )
                 .await?;
+            let extra_hours_array = reportable_extra_hours(extra_hours_array);
             let previous_year_carryover = self
                 .carryover_service
                 .get_carryover(
@@ -1553,6 +1639,16 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
                 tx.clone(),
             )
             .await?;
+        let shiftplan_report = self
+            .with_actual_hours(
+                shiftplan_report,
+                Some(*sales_person_id),
+                from_date,
+                to_date,
+                &working_hours,
+                tx.clone(),
+            )
+            .await?;
         let extra_hours = self
             .extra_hours_service
             .find_by_sales_person_id_and_year_range(
@@ -1563,6 +1659,7 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
                 tx.clone(),
             )
             .await?;
+        let extra_hours = reportable_extra_hours(extra_hours);
 
         // Additiver Merge (Phase 8.4 / D-01): immer beide Quellen.
         // Die lebenden `extra_hours` fliessen ungefiltert (deleted IS NULL-
@@ -1618,6 +1715,15 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
             from_date,
             to_date,
         )?;
+        let by_month = hours_per_month(
+            &shiftplan_report,
+            &extra_hours,
+            &working_hours,
+            &derived,
+            &derived_holiday,
+            from_date,
+            to_date,
+        )?;
         let shiftplan_hours_by_week = by_week.iter().map(|week| week.shiftplan_hours).sum::<f32>();
         tracing::info!("Shiftplan hours: {}", shiftplan_hours_by_week);
         let (vacation_days, sick_leave_days, holiday_days, absence_days) = by_week.iter().fold(
@@ -1710,7 +1816,7 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
             unpaid_leave_hours: by_week.iter().map(|w| w.unpaid_leave_hours).sum::<f32>(),
             carryover_hours: previous_year_carryover,
             by_week,
-            by_month: Arc::new([]),
+            by_month,
             custom_extra_hours: aggregated_custom_extra_hours,
         };
 
@@ -1745,10 +1851,21 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
             .shiftplan_report_service
             .extract_shiftplan_report_for_week(year, week, Authentication::Full, tx.clone())
             .await?;
+        let shiftplan_report = self
+            .with_actual_hours(
+                shiftplan_report,
+                None,
+                ShiftyWeek::new(year, week).as_date(DayOfWeek::Monday),
+                ShiftyWeek::new(year, week).as_date(DayOfWeek::Sunday),
+                &work_details,
+                tx.clone(),
+            )
+            .await?;
         let extra_hours = self
             .extra_hours_service
             .find_by_week(year, week, Authentication::Full, tx.clone())
             .await?;
+        let extra_hours = reportable_extra_hours(extra_hours);
         info!("Extra hours: {:?}", &extra_hours);
 
         // Phase 52 Follow-Up (WOP-04): pre-build the two in-memory indexes
@@ -1827,10 +1944,22 @@ impl<Deps: ReportingServiceDeps> service::reporting::ReportingService
             .shiftplan_report_service
             .extract_shiftplan_report_for_iso_year(year, Authentication::Full, tx.clone())
             .await?;
+        let shiftplan_reports = self
+            .with_actual_hours(
+                shiftplan_reports,
+                None,
+                ShiftyWeek::new(year, 1).as_date(DayOfWeek::Monday),
+                ShiftyWeek::new(year, time::util::weeks_in_year(year as i32))
+                    .as_date(DayOfWeek::Sunday),
+                &work_details,
+                tx.clone(),
+            )
+            .await?;
         let extra_hours = self
             .extra_hours_service
             .find_by_iso_year(year, Authentication::Full, tx.clone())
             .await?;
+        let extra_hours = reportable_extra_hours(extra_hours);
         info!("Extra hours (year batch): {:?}", &extra_hours);
 
         // Phase 52 Follow-Up (WOP-04): pre-build the two in-memory indexes
@@ -2274,6 +2403,129 @@ fn hours_per_week(
     Ok(weeks.into())
 }
 
+/// Monatsgruppen fuer `EmployeeReport::by_month`.
+///
+/// Jeder Kalendermonat im Bereich wird wie ein eigener Report-Bereich durch
+/// [`hours_per_week`] gerechnet (Rand-Wochen damit tagesgenau auf den Monat
+/// geschnitten) und die Wochen danach zu einer Gruppe addiert.
+/// `contract_weekly_hours`, `days_per_week` und `workdays_per_week` kommen aus
+/// der letzten Woche des Monats, `week` ist die erste KW des Monats.
+///
+/// Die Tagesliste enthaelt zusaetzlich die aus Abwesenheitszeitraeumen und der
+/// Feiertags-Automatik abgeleiteten Tage, damit der Monats-Stundenzettel
+/// Abwesenheiten und Feiertage tagesgenau ausweisen kann.
+fn hours_per_month(
+    shiftplan_hours_list: &Arc<[ShiftplanReportDay]>,
+    extra_hours_list: &Arc<[ExtraHours]>,
+    working_hours: &[EmployeeWorkDetails],
+    derived_absence: &BTreeMap<time::Date, ResolvedAbsence>,
+    derived_holiday: &HashMap<time::Date, f32>,
+    from_date: ShiftyDate,
+    to_date: ShiftyDate,
+) -> Result<Arc<[GroupedReportHours]>, ServiceError> {
+    let mut months: Vec<GroupedReportHours> = Vec::new();
+    let mut month_start = from_date.to_date();
+    while month_start <= to_date.to_date() {
+        let days_in_month = month_start.month().length(month_start.year());
+        let month_end = month_start
+            .replace_day(days_in_month)?
+            .min(to_date.to_date());
+        let in_month = |date: time::Date| date >= month_start && date <= month_end;
+
+        let shiftplan_hours: Arc<[ShiftplanReportDay]> = shiftplan_hours_list
+            .iter()
+            .filter(|day| day.to_date().is_ok_and(|date| in_month(date.to_date())))
+            .cloned()
+            .collect();
+        let extra_hours: Arc<[ExtraHours]> = extra_hours_list
+            .iter()
+            .filter(|eh| in_month(eh.date_time.date()))
+            .cloned()
+            .collect();
+        let absence: BTreeMap<time::Date, ResolvedAbsence> = derived_absence
+            .range(month_start..=month_end)
+            .map(|(date, absence)| (*date, absence.clone()))
+            .collect();
+        let holiday: HashMap<time::Date, f32> = derived_holiday
+            .iter()
+            .filter(|(date, _)| in_month(**date))
+            .map(|(date, hours)| (*date, *hours))
+            .collect();
+        let weeks = hours_per_week(
+            &shiftplan_hours,
+            &extra_hours,
+            working_hours,
+            &absence,
+            &holiday,
+            month_start.into(),
+            month_end.into(),
+        )?;
+
+        let mut days: Vec<WorkingHoursDay> = weeks
+            .iter()
+            .flat_map(|week| week.days.iter().cloned())
+            .collect();
+        days.extend(absence.iter().map(|(date, absence)| WorkingHoursDay {
+            date: *date,
+            hours: absence.hours,
+            category: match absence.category {
+                AbsenceCategory::Vacation => ExtraHoursReportCategory::Vacation,
+                AbsenceCategory::SickLeave => ExtraHoursReportCategory::SickLeave,
+                AbsenceCategory::UnpaidLeave => ExtraHoursReportCategory::UnpaidLeave,
+            },
+        }));
+        days.extend(holiday.iter().map(|(date, hours)| WorkingHoursDay {
+            date: *date,
+            hours: *hours,
+            category: ExtraHoursReportCategory::Holiday,
+        }));
+        days.sort_by_key(|day| day.date);
+
+        let mut custom_extra_hours: Vec<CustomExtraHours> = Vec::new();
+        for entry in weeks.iter().flat_map(|week| week.custom_extra_hours.iter()) {
+            match custom_extra_hours
+                .iter_mut()
+                .find(|custom| custom.id == entry.id)
+            {
+                Some(custom) => custom.hours += entry.hours,
+                None => custom_extra_hours.push(entry.clone()),
+            }
+        }
+
+        let sum = |field: fn(&GroupedReportHours) -> f32| weeks.iter().map(field).sum::<f32>();
+        let last_week = weeks.last();
+        months.push(GroupedReportHours {
+            from: month_start.into(),
+            to: month_end.into(),
+            year: month_start.year() as u32,
+            week: weeks.first().map(|week| week.week).unwrap_or_default(),
+            contract_weekly_hours: last_week
+                .map(|week| week.contract_weekly_hours)
+                .unwrap_or_default(),
+            expected_hours: sum(|week| week.expected_hours),
+            dynamic_hours: sum(|week| week.dynamic_hours),
+            overall_hours: sum(|week| week.overall_hours),
+            balance: sum(|week| week.balance),
+            days_per_week: last_week.map(|week| week.days_per_week).unwrap_or_default(),
+            workdays_per_week: last_week
+                .map(|week| week.workdays_per_week)
+                .unwrap_or_default(),
+            shiftplan_hours: sum(|week| week.shiftplan_hours),
+            extra_work_hours: sum(|week| week.extra_work_hours),
+            vacation_hours: sum(|week| week.vacation_hours),
+            sick_leave_hours: sum(|week| week.sick_leave_hours),
+            holiday_hours: sum(|week| week.holiday_hours),
+            unpaid_leave_hours: sum(|week| week.unpaid_leave_hours),
+            volunteer_hours: sum(|week| week.volunteer_hours),
+            custom_extra_hours: custom_extra_hours.into(),
+            days: days.into(),
+        });
+
+        month_start = month_end.next_day().ok_or(ServiceError::InternalError)?;
+    }
+    Ok(months.into())
+}
+
 #[cfg(test)]
 mod test_dynamic_vacation_days {
     use super::*;
@@ -2296,6 +2548,7 @@ mod test_dynamic_vacation_days {
             is_dynamic,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
@@ -2605,6 +2858,7 @@ mod test_weekly_planned_hours_cap {
             is_dynamic: false,
             cap_planned_hours_to_expected: cap,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
@@ -2857,6 +3111,7 @@ mod test_committed_voluntary_for_calendar_week {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
@@ -2930,3 +3185,132 @@ mod test_committed_voluntary_for_calendar_week {
         );
     }
 }
+
+#[cfg(test)]
+mod test_hours_per_month {
+    use super::*;
+    use shifty_utils::DayOfWeek;
+    use time::macros::{date, datetime};
+    use uuid::Uuid;
+
+    fn work_details() -> EmployeeWorkDetails {
+        EmployeeWorkDetails {
+            id: Uuid::new_v4(),
+            sales_person_id: Uuid::new_v4(),
+            expected_hours: 40.0,
+            from_day_of_week: DayOfWeek::Monday,
+            from_calendar_week: 1,
+            from_year: 2024,
+            to_day_of_week: DayOfWeek::Sunday,
+            to_calendar_week: 52,
+            to_year: 2024,
+            workdays_per_week: 5,
+            is_dynamic: false,
+            cap_planned_hours_to_expected: false,
+            committed_voluntary: 0.0,
+            use_actual_hours: false,
+            monday: true,
+            tuesday: true,
+            wednesday: true,
+            thursday: true,
+            friday: true,
+            saturday: false,
+            sunday: false,
+            vacation_days: 30,
+            created: Some(datetime!(2024-01-01 10:00:00)),
+            deleted: None,
+            version: Uuid::new_v4(),
+        }
+    }
+
+    fn shiftplan_day(week: u8, day: DayOfWeek, hours: f32) -> ShiftplanReportDay {
+        ShiftplanReportDay {
+            sales_person_id: Uuid::new_v4(),
+            hours,
+            year: 2024,
+            calendar_week: week,
+            day_of_week: day,
+        }
+    }
+
+    fn vacation(date_time: time::PrimitiveDateTime, amount: f32) -> ExtraHours {
+        ExtraHours {
+            id: Uuid::new_v4(),
+            sales_person_id: Uuid::new_v4(),
+            amount,
+            category: ExtraHoursCategory::Vacation,
+            description: "Vacation".into(),
+            date_time,
+            created: Some(datetime!(2024-01-01 10:00:00)),
+            deleted: None,
+            version: Uuid::new_v4(),
+            source: service::extra_hours::ExtraHoursSource::Manual,
+        }
+    }
+
+    /// KW 5/2024 (29.01.–04.02.) liegt in zwei Monaten: jeder Eintrag zählt
+    /// nur in seinem Monat, das Soll wird tagesgenau aufgeteilt.
+    #[test]
+    fn splits_week_spanning_two_months() {
+        let shiftplan: Arc<[ShiftplanReportDay]> = Arc::new([
+            shiftplan_day(5, DayOfWeek::Tuesday, 4.0),
+            shiftplan_day(5, DayOfWeek::Thursday, 8.0),
+        ]);
+        let extra_hours: Arc<[ExtraHours]> =
+            Arc::new([vacation(datetime!(2024-02-02 00:00:00), 8.0)]);
+        let working_hours = [work_details()];
+        let derived_absence = BTreeMap::new();
+        let derived_holiday = HashMap::from([(date!(2024 - 01 - 31), 8.0)]);
+        let from = ShiftyDate::from_ymd(2024, 1, 22).unwrap();
+        let to = ShiftyDate::from_ymd(2024, 2, 11).unwrap();
+
+        let months = hours_per_month(
+            &shiftplan,
+            &extra_hours,
+            &working_hours,
+            &derived_absence,
+            &derived_holiday,
+            from,
+            to,
+        )
+        .unwrap();
+        let weeks = hours_per_week(
+            &shiftplan,
+            &extra_hours,
+            &working_hours,
+            &derived_absence,
+            &derived_holiday,
+            from,
+            to,
+        )
+        .unwrap();
+
+        assert_eq!(months.len(), 2);
+        let (january, february) = (&months[0], &months[1]);
+        assert_eq!(january.from, from);
+        assert_eq!(january.to, ShiftyDate::from_ymd(2024, 1, 31).unwrap());
+        assert_eq!(february.from, ShiftyDate::from_ymd(2024, 2, 1).unwrap());
+        assert_eq!(february.to, to);
+
+        assert_eq!(january.shiftplan_hours, 4.0);
+        assert_eq!(february.shiftplan_hours, 8.0);
+        assert_eq!(january.vacation_hours, 0.0);
+        assert_eq!(february.vacation_hours, 8.0);
+        assert_eq!(january.holiday_hours, 8.0);
+        assert_eq!(february.holiday_hours, 0.0);
+
+        let expected_by_week: f32 = weeks.iter().map(|week| week.expected_hours).sum();
+        let expected_by_month: f32 = months.iter().map(|month| month.expected_hours).sum();
+        assert!((expected_by_week - expected_by_month).abs() < 0.01);
+
+        assert!(january
+            .days
+            .iter()
+            .any(|day| day.date == date!(2024 - 01 - 31)
+                && day.category == ExtraHoursReportCategory::Holiday));
+        assert!(february
+            .days
+            .iter()
+            .all(|day| day.date >= date!(2024 - 02 - 01)));
+    }
+}
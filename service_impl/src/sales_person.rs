@@ -7,6 +7,7 @@ use dao::{
     TransactionDao,
 };
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
     permission::{Authentication, HR_PRIVILEGE, SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
     sales_person::{SalesPerson, SalesPersonService},
@@ -24,12 +25,46 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
 
 const SALES_PERSON_SERVICE_PROCESS: &str = "sales-person-service";
 
+/// Leere Personalnummern werden nicht gespeichert.
+fn normalize_personnel_number(personnel_number: &Option<Arc<str>>) -> Option<Arc<str>> {
+    personnel_number
+        .as_deref()
+        .map(str::trim)
+        .filter(|personnel_number| !personnel_number.is_empty())
+        .map(Arc::from)
+}
+
+impl<Deps: SalesPersonServiceDeps> SalesPersonServiceImpl<Deps> {
+    /// Eine Personalnummer darf nur einem aktiven Mitarbeiter gehören, sonst
+    /// wäre der Lohnexport nicht eindeutig.
+    async fn check_personnel_number_unique(
+        &self,
+        sales_person: &SalesPerson,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let Some(personnel_number) = &sales_person.personnel_number else {
+            return Ok(());
+        };
+        let duplicate = self.sales_person_dao.all(tx).await?.iter().any(|other| {
+            other.id != sales_person.id
+                && other.personnel_number.as_ref() == Some(personnel_number)
+        });
+        if duplicate {
+            return Err(ServiceError::ValidationError(
+                [ValidationFailureItem::Duplicate].into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl<Deps> {
     type Context = Deps::Context;
@@ -67,6 +102,7 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
         {
             sales_persons.iter_mut().for_each(|sales_person| {
                 sales_person.is_paid = None;
+                sales_person.personnel_number = None;
             });
         }
         self.transaction_dao.commit(tx).await?;
@@ -140,6 +176,7 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
 
         if remove_sensitive_data {
             sales_person.is_paid = None;
+            sales_person.personnel_number = None;
         }
 
         self.transaction_dao.commit(tx).await?;
@@ -171,7 +208,7 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
     ) -> Result<SalesPerson, service::ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
         if sales_person.id != Uuid::nil() {
@@ -184,8 +221,11 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
         let sales_person = SalesPerson {
             id: self.uuid_service.new_uuid("sales-person-id"),
             version: self.uuid_service.new_uuid("sales-person-version"),
+            personnel_number: normalize_personnel_number(&sales_person.personnel_number),
             ..sales_person.clone()
         };
+        self.check_personnel_number_unique(&sales_person, tx.clone())
+            .await?;
         self.sales_person_dao
             .create(
                 &SalesPersonEntity::from(&sales_person),
@@ -193,6 +233,14 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
                 tx.clone(),
             )
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SALES_PERSON, sales_person.id, &sales_person)?,
+                SALES_PERSON_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(sales_person)
@@ -206,7 +254,7 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
     ) -> Result<SalesPerson, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
         let sales_person_entity = self
@@ -236,8 +284,11 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
 
         let sales_person = SalesPerson {
             version: self.uuid_service.new_uuid("sales-person-version"),
+            personnel_number: normalize_personnel_number(&sales_person.personnel_number),
             ..sales_person.clone()
         };
+        self.check_personnel_number_unique(&sales_person, tx.clone())
+            .await?;
 
         self.sales_person_dao
             .update(
@@ -246,6 +297,19 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
                 tx.clone(),
             )
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::SALES_PERSON,
+                    sales_person.id,
+                    &sales_person_entity,
+                    &sales_person,
+                )?,
+                SALES_PERSON_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(sales_person)
     }
@@ -258,13 +322,14 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
         let mut sales_person_entity = self
             .sales_person_dao
             .find_by_id(id, tx.clone())
             .await?
             .ok_or(ServiceError::EntityNotFound(id))?;
+        let before = SalesPerson::from(&sales_person_entity);
         sales_person_entity.deleted = Some(self.clock_service.date_time_now());
         sales_person_entity.version = self.uuid_service.new_uuid("sales-person-version");
         self.sales_person_dao
@@ -274,6 +339,14 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
                 tx.clone(),
             )
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::SALES_PERSON, id, &before)?,
+                SALES_PERSON_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -322,12 +395,16 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        let before = self
+            .sales_person_dao
+            .get_assigned_user(sales_person_id, tx.clone())
             .await?;
         self.sales_person_dao
             .discard_assigned_user(sales_person_id, tx.clone())
             .await?;
-        if let Some(user) = user_id {
+        if let Some(user) = &user_id {
             self.sales_person_dao
                 .assign_to_user(
                     sales_person_id,
@@ -337,6 +414,21 @@ impl<Deps: SalesPersonServiceDeps> SalesPersonService for SalesPersonServiceImpl
                 )
                 .await?;
         }
+        if before != user_id {
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(
+                        entity_type::SALES_PERSON_USER,
+                        sales_person_id,
+                        &before,
+                        &user_id,
+                    )?,
+                    SALES_PERSON_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
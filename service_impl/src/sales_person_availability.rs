@@ -0,0 +1,310 @@
+use crate::gen_service_impl;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    sales_person_availability::{SalesPersonAvailabilityDao, SalesPersonAvailabilityEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    clock::ClockService,
+    permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
+    sales_person::SalesPersonService,
+    sales_person_availability::{SalesPersonAvailability, SalesPersonAvailabilityService},
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use tokio::join;
+use uuid::Uuid;
+
+gen_service_impl! {
+    struct SalesPersonAvailabilityServiceImpl: SalesPersonAvailabilityService = SalesPersonAvailabilityServiceDeps {
+        SalesPersonAvailabilityDao: SalesPersonAvailabilityDao<Transaction = Self::Transaction> = sales_person_availability_dao,
+        SalesPersonService: SalesPersonService<Transaction = Self::Transaction, Context = Self::Context> = sales_person_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: SalesPersonAvailabilityServiceDeps> SalesPersonAvailabilityServiceImpl<Deps> {
+    async fn check_shiftplanner_or_self(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let (shiftplanner_permission, is_sales_person) = join!(
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.into()
+            ),
+        );
+        shiftplanner_permission.or(is_sales_person)
+    }
+}
+
+/// Zeitfenster: beide Grenzen oder keine (ganzer Tag), `time_from < time_to`;
+/// Gültigkeit: `valid_from <= valid_to`.
+fn validate(availability: &SalesPersonAvailability) -> Result<(), ServiceError> {
+    match (availability.time_from, availability.time_to) {
+        (Some(from), Some(to)) if from >= to => {
+            return Err(ServiceError::TimeOrderWrong(from, to));
+        }
+        (Some(_), None) => {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("time_to".into()),
+            ])));
+        }
+        (None, Some(_)) => {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("time_from".into()),
+            ])));
+        }
+        _ => {}
+    }
+    if let Some(valid_to) = availability.valid_to {
+        if valid_to < availability.valid_from {
+            return Err(ServiceError::DateOrderWrong(
+                availability.valid_from,
+                valid_to,
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl<Deps: SalesPersonAvailabilityServiceDeps> SalesPersonAvailabilityService
+    for SalesPersonAvailabilityServiceImpl<Deps>
+{
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_all_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SalesPersonAvailability]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_shiftplanner_or_self(sales_person_id, context, tx.clone())
+            .await?;
+
+        let ret = self
+            .sales_person_availability_dao
+            .find_all_by_sales_person_id(sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .map(SalesPersonAvailability::from)
+            .collect();
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(ret)
+    }
+
+    async fn get_by_week_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SalesPersonAvailability]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_shiftplanner_or_self(sales_person_id, context, tx.clone())
+            .await?;
+
+        let monday =
+            time::Date::from_iso_week_date(year as i32, calendar_week, time::Weekday::Monday)?;
+        let sunday =
+            time::Date::from_iso_week_date(year as i32, calendar_week, time::Weekday::Sunday)?;
+        let ret = self
+            .sales_person_availability_dao
+            .find_all_by_sales_person_id(sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .map(SalesPersonAvailability::from)
+            .filter(|availability| {
+                availability.valid_from <= sunday
+                    && availability
+                        .valid_to
+                        .is_none_or(|valid_to| monday <= valid_to)
+            })
+            .collect();
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(ret)
+    }
+
+    async fn create(
+        &self,
+        availability: &SalesPersonAvailability,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SalesPersonAvailability, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_shiftplanner_or_self(availability.sales_person_id, context.clone(), tx.clone())
+            .await?;
+
+        if availability.id != Uuid::nil() {
+            return Err(ServiceError::IdSetOnCreate);
+        }
+        if availability.version != Uuid::nil() {
+            return Err(ServiceError::VersionSetOnCreate);
+        }
+        if availability.deleted.is_some() {
+            return Err(ServiceError::DeletedSetOnCreate);
+        }
+        if availability.created.is_some() {
+            return Err(ServiceError::CreatedSetOnCreate);
+        }
+        validate(availability)?;
+
+        let entity = SalesPersonAvailabilityEntity::try_from(&SalesPersonAvailability {
+            id: self
+                .uuid_service
+                .new_uuid("SalesPersonAvailabilityService::create id"),
+            version: self
+                .uuid_service
+                .new_uuid("SalesPersonAvailabilityService::create version"),
+            created: Some(self.clock_service.date_time_now()),
+            ..availability.clone()
+        })?;
+        self.sales_person_availability_dao
+            .create(
+                &entity,
+                "SalesPersonAvailabilityService::create",
+                tx.clone(),
+            )
+            .await?;
+        let created = SalesPersonAvailability::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SALES_PERSON_AVAILABILITY, created.id, &created)?,
+                "SalesPersonAvailabilityService::create",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        availability: &SalesPersonAvailability,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SalesPersonAvailability, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .sales_person_availability_dao
+            .find_by_id(availability.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(availability.id))?;
+        self.check_shiftplanner_or_self(persisted.sales_person_id, context.clone(), tx.clone())
+            .await?;
+
+        if persisted.version != availability.version {
+            return Err(ServiceError::EntityConflicts(
+                availability.id,
+                persisted.version,
+                availability.version,
+            ));
+        }
+        if persisted.sales_person_id != availability.sales_person_id {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::ModificationNotAllowed("sales_person_id".into()),
+            ])));
+        }
+        validate(availability)?;
+
+        let before = SalesPersonAvailability::from(&persisted);
+        let entity = SalesPersonAvailabilityEntity {
+            day_of_week: availability.day_of_week,
+            time_from: availability.time_from,
+            time_to: availability.time_to,
+            valid_from: availability.valid_from,
+            valid_to: availability.valid_to,
+            kind: availability.kind.into(),
+            version: self
+                .uuid_service
+                .new_uuid("SalesPersonAvailabilityService::update version"),
+            ..persisted
+        };
+        self.sales_person_availability_dao
+            .update(
+                &entity,
+                "SalesPersonAvailabilityService::update",
+                tx.clone(),
+            )
+            .await?;
+        let updated = SalesPersonAvailability::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::SALES_PERSON_AVAILABILITY,
+                    updated.id,
+                    &before,
+                    &updated,
+                )?,
+                "SalesPersonAvailabilityService::update",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = self
+            .sales_person_availability_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        self.check_shiftplanner_or_self(entity.sales_person_id, context.clone(), tx.clone())
+            .await?;
+
+        let before = SalesPersonAvailability::from(&entity);
+        self.sales_person_availability_dao
+            .update(
+                &SalesPersonAvailabilityEntity {
+                    deleted: Some(self.clock_service.date_time_now()),
+                    version: self
+                        .uuid_service
+                        .new_uuid("SalesPersonAvailabilityService::delete version"),
+                    ..entity
+                },
+                "SalesPersonAvailabilityService::delete",
+                tx.clone(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::SALES_PERSON_AVAILABILITY, id, &before)?,
+                "SalesPersonAvailabilityService::delete",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ use crate::gen_service_impl;
 use dao::sales_person_shiftplan::SalesPersonShiftplanDao;
 use dao::TransactionDao;
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     permission::{Authentication, PermissionService, SHIFTPLANNER_PRIVILEGE},
     sales_person::{SalesPerson, SalesPersonService},
     sales_person_shiftplan::SalesPersonShiftplanService,
@@ -20,6 +21,7 @@ gen_service_impl! {
         SalesPersonShiftplanDao: dao::sales_person_shiftplan::SalesPersonShiftplanDao<Transaction = Self::Transaction> = sales_person_shiftplan_dao,
         SalesPersonService: service::sales_person::SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
         PermissionService: service::permission::PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -58,7 +60,7 @@ impl<Deps: SalesPersonShiftplanServiceDeps> SalesPersonShiftplanService
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
         if !self
             .sales_person_service
@@ -67,9 +69,28 @@ impl<Deps: SalesPersonShiftplanServiceDeps> SalesPersonShiftplanService
         {
             return Err(ServiceError::EntityNotFound(sales_person_id));
         }
+        let before = self
+            .sales_person_shiftplan_dao
+            .get_by_sales_person(sales_person_id, tx.clone())
+            .await?;
         self.sales_person_shiftplan_dao
             .set_for_sales_person(sales_person_id, assignments, PROCESS, tx.clone())
             .await?;
+        if before.as_slice() != assignments {
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(
+                        entity_type::SALES_PERSON_SHIFTPLAN,
+                        sales_person_id,
+                        &before.as_slice(),
+                        &assignments,
+                    )?,
+                    PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dao::{sales_person_unavailable::SalesPersonUnavailableDao, TransactionDao};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
     permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
     sales_person::SalesPersonService,
@@ -21,6 +22,7 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -182,9 +184,18 @@ impl<Deps: SalesPersonUnavailableServiceDeps> SalesPersonUnavailableService
         self.sales_person_unavailable_dao
             .create(&entity, "SalesPersonUnavailableService::create", tx.clone())
             .await?;
+        let created = SalesPersonUnavailable::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SALES_PERSON_UNAVAILABLE, created.id, &created)?,
+                "SalesPersonUnavailableService::create",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
-        Ok((&entity).into())
+        Ok(created)
     }
 
     async fn delete(
@@ -211,6 +222,7 @@ impl<Deps: SalesPersonUnavailableServiceDeps> SalesPersonUnavailableService
         );
         shiftplanner_permission.or(is_sales_person)?;
 
+        let before = SalesPersonUnavailable::from(&entity);
         self.sales_person_unavailable_dao
             .update(
                 &dao::sales_person_unavailable::SalesPersonUnavailableEntity {
@@ -224,6 +236,14 @@ impl<Deps: SalesPersonUnavailableServiceDeps> SalesPersonUnavailableService
                 tx.clone(),
             )
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::SALES_PERSON_UNAVAILABLE, id, &before)?,
+                "SalesPersonUnavailableService::delete",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
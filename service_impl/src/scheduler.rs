@@ -1,81 +1,330 @@
+//! Business-Logic-Tier Implementation von [`SchedulerService`].
+//!
+//! Welche Jobs wann laufen, steht in der Registry `scheduler_job`
+//! ([`SchedulerJobService`]). `start` registriert beim Boot alle aktivierten
+//! Jobs; `reload_from_db` entfernt nach einer Änderung über
+//! `PUT /scheduler-job/{name}` alle Registrierungen und legt sie neu an —
+//! analog zu [`crate::pdf_export_scheduler::PdfExportSchedulerImpl`]. Der Name
+//! eines Jobs wird in [`SchedulerServiceImpl::execute`] auf die Arbeit
+//! abgebildet; Dauer und Ergebnis jedes Laufs landen über
+//! [`SchedulerJobService::record_run`] wieder in der Registry. Läuft ein Job
+//! noch, wird ein weiterer Start (Cron-Tick oder `POST .../run`)
+//! übersprungen, damit sich Läufe desselben Jobs nie überlappen.
+
 use crate::gen_service_impl;
 use async_trait::async_trait;
 use chrono::Local;
 use service::{
-    permission::Authentication, scheduler::SchedulerService, shiftplan_edit::ShiftplanEditService,
-    ServiceError,
+    clock::ClockService,
+    notification::NotificationService,
+    permission::Authentication,
+    rebooking_reconciliation::RebookingReconciliationService,
+    scheduler::SchedulerService,
+    scheduler_job::{
+        SchedulerJobService, JOB_CARRYOVER, JOB_NOTIFICATION_DELIVERY, JOB_VOLUNTARY_REBOOKING,
+        JOB_WEBHOOK_DELIVERY,
+    },
+    shiftplan_edit::ShiftplanEditService,
+    webhook::WebhookService,
+    PermissionService, ServiceError,
 };
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::Mutex;
-use tokio_cron::{Job, Scheduler};
-use tracing::{error, info};
+use tokio_cron_scheduler::{Job, JobScheduler};
+use tracing::{error, info, warn};
+use uuid::Uuid;
 
 gen_service_impl! {
     struct SchedulerServiceImpl: service::scheduler::SchedulerService = SchedulerServiceDeps {
         ShiftplanEditService: service::shiftplan_edit::ShiftplanEditService = shiftplan_edit_service,
+        NotificationService: service::notification::NotificationService = notification_service,
+        WebhookService: service::webhook::WebhookService = webhook_service,
+        RebookingReconciliationService: service::rebooking_reconciliation::RebookingReconciliationService = rebooking_reconciliation_service,
+        SchedulerJobService: SchedulerJobService<Context = Self::Context> = scheduler_job_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
     }
     ; custom_fields {
-        scheduler: Arc<Mutex<Scheduler<Local>>> = scheduler
+        scheduler: Arc<Mutex<Option<JobScheduler>>> = scheduler,
+        registered_jobs: Arc<Mutex<Vec<Uuid>>> = registered_jobs,
+        running_jobs: RunningJobs = running_jobs,
     }
 }
 
-impl<Deps: SchedulerServiceDeps> SchedulerServiceImpl<Deps> {
-    pub fn new(shiftplan_edit_service: Arc<Deps::ShiftplanEditService>) -> Self {
-        let scheduler = Arc::new(Mutex::new(Scheduler::local()));
+/// Namen der Jobs, deren Lauf gerade ausgeführt wird.
+type RunningJobs = Arc<std::sync::Mutex<HashSet<Arc<str>>>>;
+
+/// Markiert einen Job als laufend, solange der Guard lebt; der Eintrag
+/// verschwindet auch dann, wenn der Lauf fehlschlägt.
+struct RunningJobGuard {
+    running_jobs: RunningJobs,
+    name: Arc<str>,
+}
+
+impl RunningJobGuard {
+    /// `None`, wenn der Job bereits läuft.
+    fn acquire(running_jobs: &RunningJobs, name: &str) -> Option<Self> {
+        let name: Arc<str> = name.into();
+        let inserted = running_jobs
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(name.clone());
+        inserted.then(|| Self {
+            running_jobs: running_jobs.clone(),
+            name,
+        })
+    }
+}
+
+impl Drop for RunningJobGuard {
+    fn drop(&mut self) {
+        self.running_jobs
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&self.name);
+    }
+}
+
+/// Alle Jobs, die [`SchedulerServiceImpl::execute`] kennt.
+const KNOWN_JOBS: [&str; 4] = [
+    JOB_CARRYOVER,
+    JOB_NOTIFICATION_DELIVERY,
+    JOB_WEBHOOK_DELIVERY,
+    JOB_VOLUNTARY_REBOOKING,
+];
 
+impl<Deps: SchedulerServiceDeps> SchedulerServiceImpl<Deps> {
+    /// Der `JobScheduler` wird lazy in `start()` initialisiert, damit `new()`
+    /// synchron bleibt.
+    pub fn new(
+        shiftplan_edit_service: Arc<Deps::ShiftplanEditService>,
+        notification_service: Arc<Deps::NotificationService>,
+        webhook_service: Arc<Deps::WebhookService>,
+        rebooking_reconciliation_service: Arc<Deps::RebookingReconciliationService>,
+        scheduler_job_service: Arc<Deps::SchedulerJobService>,
+        permission_service: Arc<Deps::PermissionService>,
+        clock_service: Arc<Deps::ClockService>,
+    ) -> Self {
         Self {
-            scheduler,
             shiftplan_edit_service,
+            notification_service,
+            webhook_service,
+            rebooking_reconciliation_service,
+            scheduler_job_service,
+            permission_service,
+            clock_service,
+            scheduler: Arc::new(Mutex::new(None)),
+            registered_jobs: Arc::new(Mutex::new(Vec::new())),
+            running_jobs: Arc::new(std::sync::Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Bildet den Job-Namen auf die eigentliche Arbeit ab.
+    async fn execute(&self, name: &str) -> Result<(), ServiceError> {
+        match name {
+            JOB_CARRYOVER => self.update_carryover().await,
+            JOB_NOTIFICATION_DELIVERY => self.deliver_notifications().await,
+            JOB_WEBHOOK_DELIVERY => self.deliver_webhooks().await,
+            JOB_VOLUNTARY_REBOOKING => self.run_voluntary_rebooking().await,
+            _ => Err(ServiceError::EntityNotFoundGeneric(name.into())),
+        }
+    }
+
+    /// Rechnet die als veraltet markierten Carryover bis einschließlich des
+    /// laufenden Jahres neu; ohne Markierungen ist das ein einzelnes SELECT.
+    async fn update_carryover(&self) -> Result<(), ServiceError> {
+        let year = self.clock_service.date_now().year() as u32;
+        let updated = self
+            .shiftplan_edit_service
+            .update_dirty_carryovers(year, Authentication::Full, None)
+            .await?;
+        if updated > 0 {
+            info!("Updated carryover (cron job): {updated} employee years");
+        }
+        Ok(())
+    }
+
+    async fn deliver_notifications(&self) -> Result<(), ServiceError> {
+        let report = self
+            .notification_service
+            .deliver_pending(Authentication::Full)
+            .await?;
+        if report.sent + report.retried + report.failed > 0 {
+            info!(
+                "Delivered notifications (cron job): {} sent, {} retried, {} failed",
+                report.sent, report.retried, report.failed
+            );
+        }
+        Ok(())
+    }
+
+    async fn deliver_webhooks(&self) -> Result<(), ServiceError> {
+        let report = self
+            .webhook_service
+            .deliver_pending(Authentication::Full)
+            .await?;
+        if report.delivered + report.retried + report.failed > 0 {
+            info!(
+                "Delivered webhooks (cron job): {} delivered, {} retried, {} failed",
+                report.delivered, report.retried, report.failed
+            );
+        }
+        Ok(())
+    }
+
+    async fn run_voluntary_rebooking(&self) -> Result<(), ServiceError> {
+        let today = self.clock_service.date_now();
+        let report = self
+            .rebooking_reconciliation_service
+            .run_auto_cron(today, Authentication::Full, None)
+            .await?;
+        if report.pending + report.skipped_locked > 0 {
+            info!(
+                "Voluntary rebooking (cron job): {} pending, {} skipped locked, {} backfill weeks",
+                report.pending, report.skipped_locked, report.backfill_weeks
+            );
         }
+        Ok(())
+    }
+
+    fn clone_for_job(&self) -> Arc<Self> {
+        Arc::new(Self {
+            shiftplan_edit_service: self.shiftplan_edit_service.clone(),
+            notification_service: self.notification_service.clone(),
+            webhook_service: self.webhook_service.clone(),
+            rebooking_reconciliation_service: self.rebooking_reconciliation_service.clone(),
+            scheduler_job_service: self.scheduler_job_service.clone(),
+            permission_service: self.permission_service.clone(),
+            clock_service: self.clock_service.clone(),
+            scheduler: self.scheduler.clone(),
+            registered_jobs: self.registered_jobs.clone(),
+            running_jobs: self.running_jobs.clone(),
+        })
     }
 }
 
 #[async_trait]
-impl<Deps: SchedulerServiceDeps> SchedulerService for SchedulerServiceImpl<Deps> {
+impl<Deps: SchedulerServiceDeps + 'static> SchedulerService for SchedulerServiceImpl<Deps> {
     type Context = Deps::Context;
 
-    // Start the scheduler in a background task
     async fn start(&self) -> Result<(), ServiceError> {
-        /*let scheduler = self.scheduler.clone();
-        tokio::spawn(async move {
-            info!("Starting the SchedulerService background loop");
-            let mut s = scheduler.lock().await;
-            s.start().await;
-        });*/
-        self.schedule_carryover_updates("0 * * * * *").await?;
+        let mut sched_guard = self.scheduler.lock().await;
+        if sched_guard.is_none() {
+            let scheduler = JobScheduler::new().await.map_err(|e| {
+                error!("scheduler init failed: {e}");
+                ServiceError::InternalError
+            })?;
+            *sched_guard = Some(scheduler);
+        }
+        drop(sched_guard);
+        // Ein kaputter Registry-Zustand darf den Backend-Start nicht
+        // verhindern; ein späteres PUT auf `/scheduler-job/{name}` lädt neu.
+        if let Err(e) = self.reload_from_db().await {
+            warn!("scheduler: initial reload at boot failed ({e:?}) — no jobs registered");
+        }
+        let sched_guard = self.scheduler.lock().await;
+        if let Some(sched) = sched_guard.as_ref() {
+            sched.start().await.map_err(|e| {
+                error!("scheduler start failed: {e}");
+                ServiceError::InternalError
+            })?;
+        }
         Ok(())
     }
 
-    async fn schedule_carryover_updates(&self, cron: &'static str) -> Result<(), ServiceError> {
-        let mut sched = self.scheduler.lock().await;
-
-        let shiftplan_edit_service = self.shiftplan_edit_service.clone();
-
-        sched.add(Job::new(cron, move || {
-            let shiftplan_edit_service = shiftplan_edit_service.clone();
-            async move {
-                let date = time::OffsetDateTime::now_utc();
-                let year = date.year() as u32;
-                if let Err(e) = shiftplan_edit_service
-                    .update_carryover_all_employees(year - 1, Authentication::Full, None)
-                    .await
-                {
-                    error!("Failed to update carryover for previous year: {:?}", e);
-                } else {
-                    info!("Successfully updated carryover for previous year (cron job)");
-                }
-                if let Err(e) = shiftplan_edit_service
-                    .update_carryover_all_employees(year, Authentication::Full, None)
-                    .await
-                {
-                    error!("Failed to update carryover for current year: {:?}", e);
-                } else {
-                    info!("Successfully updated carryover for current year (cron job)");
-                }
+    async fn reload_from_db(&self) -> Result<(), ServiceError> {
+        let jobs = self
+            .scheduler_job_service
+            .get_all(Authentication::Full, None)
+            .await?;
+
+        let mut sched_guard = self.scheduler.lock().await;
+        let scheduler = match sched_guard.as_mut() {
+            Some(s) => s,
+            // Not initialised yet — `start()` will call reload_from_db.
+            None => return Ok(()),
+        };
+        let mut registered = self.registered_jobs.lock().await;
+        for job_id in registered.drain(..) {
+            if let Err(e) = scheduler.remove(&job_id).await {
+                warn!("scheduler: could not remove previous cron job: {e}");
             }
-        }));
+        }
 
-        info!("Scheduled carryover updates with cron expression: {}", cron);
+        for job in jobs.iter().filter(|job| job.enabled) {
+            if !KNOWN_JOBS.contains(&job.name.as_ref()) {
+                warn!("scheduler: unknown job '{}' in registry, skipped", job.name);
+                continue;
+            }
+            let name = job.name.clone();
+            let service = self.clone_for_job();
+            let cron_job =
+                Job::new_async_tz(job.cron_schedule.as_ref(), Local, move |_uuid, _lock| {
+                    let name = name.clone();
+                    let service = service.clone();
+                    Box::pin(async move {
+                        if let Err(e) = service.run_job_now(&name, Authentication::Full).await {
+                            error!("scheduler job '{name}' failed: {e:?}");
+                        }
+                    })
+                });
+            match cron_job {
+                Ok(cron_job) => match scheduler.add(cron_job).await {
+                    Ok(job_id) => {
+                        registered.push(job_id);
+                        info!(
+                            "Scheduled job '{}' with cron expression: {}",
+                            job.name, job.cron_schedule
+                        );
+                    }
+                    Err(e) => error!("scheduler: could not add job '{}': {e}", job.name),
+                },
+                Err(e) => error!(
+                    "scheduler: invalid cron expression '{}' for job '{}': {e}",
+                    job.cron_schedule, job.name
+                ),
+            }
+        }
         Ok(())
     }
+
+    async fn run_job_now(
+        &self,
+        name: &str,
+        context: Authentication<Self::Context>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        if !KNOWN_JOBS.contains(&name) {
+            return Err(ServiceError::EntityNotFoundGeneric(name.into()));
+        }
+        let Some(_running) = RunningJobGuard::acquire(&self.running_jobs, name) else {
+            info!("scheduler job '{name}' is still running, this run is skipped");
+            return Ok(());
+        };
+
+        let started_at = self.clock_service.date_time_now();
+        let started = Instant::now();
+        let result = self.execute(name).await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+
+        let error = result
+            .as_ref()
+            .err()
+            .map(|e| Arc::<str>::from(format!("{e:?}")));
+        self.scheduler_job_service
+            .record_run(
+                name,
+                started_at,
+                duration_ms,
+                error,
+                Authentication::Full,
+                None,
+            )
+            .await?;
+        result
+    }
 }
@@ -0,0 +1,183 @@
+//! Basic-Tier Implementation von [`SchedulerJobService`].
+//!
+//! - `get_all` / `get` / `update` sind admin-gated (`ADMIN_PRIVILEGE`);
+//!   `update` prüft die Version und landet im Audit-Log.
+//! - `record_run` ist Scheduler-only (`Authentication::Full`).
+//! - Cron-Ausdrücke werden HIER mit demselben Parser geprüft, den der
+//!   Scheduler beim Registrieren nutzt, damit ein ungültiger Ausdruck als
+//!   422 beim Admin landet und nicht erst beim nächsten Reload auffällt.
+
+use std::sync::Arc;
+
+use crate::gen_service_impl;
+
+use async_trait::async_trait;
+use dao::{
+    scheduler_job::{SchedulerJobDao, SchedulerJobEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    permission::Authentication,
+    scheduler_job::{SchedulerJob, SchedulerJobService, SchedulerJobUpdate},
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use uuid::Uuid;
+
+const ADMIN_PRIVILEGE: &str = "admin";
+
+const PROCESS_UPDATE: &str = "scheduler-job-service::update";
+const PROCESS_RECORD_RUN: &str = "scheduler-job-service::record_run";
+
+gen_service_impl! {
+    struct SchedulerJobServiceImpl: SchedulerJobService = SchedulerJobServiceDeps {
+        SchedulerJobDao: SchedulerJobDao<Transaction = Self::Transaction> = scheduler_job_dao,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        UuidService: UuidService = uuid_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+/// Prüft einen Cron-Ausdruck mit dem Parser von `tokio-cron-scheduler`
+/// (6 Felder, Sekunden verpflichtend).
+pub fn is_valid_cron_schedule(cron_schedule: &str) -> bool {
+    tokio_cron_scheduler::Job::new_async(cron_schedule, |_uuid, _lock| Box::pin(async {})).is_ok()
+}
+
+impl<Deps: SchedulerJobServiceDeps> SchedulerJobServiceImpl<Deps> {
+    async fn find_entity(
+        &self,
+        name: &str,
+        tx: Deps::Transaction,
+    ) -> Result<SchedulerJobEntity, ServiceError> {
+        self.scheduler_job_dao
+            .find_by_name(name, tx)
+            .await?
+            .ok_or_else(|| ServiceError::EntityNotFoundGeneric(name.into()))
+    }
+}
+
+#[async_trait]
+impl<Deps: SchedulerJobServiceDeps> SchedulerJobService for SchedulerJobServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_all(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[SchedulerJob]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let jobs = self
+            .scheduler_job_dao
+            .all(tx.clone())
+            .await?
+            .iter()
+            .map(SchedulerJob::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(jobs)
+    }
+
+    async fn get(
+        &self,
+        name: &str,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SchedulerJob, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let entity = self.find_entity(name, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok((&entity).into())
+    }
+
+    async fn update(
+        &self,
+        name: &str,
+        update: SchedulerJobUpdate,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<SchedulerJob, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        if !is_valid_cron_schedule(update.cron_schedule.trim()) {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("cron_schedule".into()),
+            ])));
+        }
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let current = self.find_entity(name, tx.clone()).await?;
+        if current.version != update.version {
+            return Err(ServiceError::EntityConflicts(
+                Uuid::nil(),
+                update.version,
+                current.version,
+            ));
+        }
+        let before = SchedulerJob::from(&current);
+
+        let entity = SchedulerJobEntity {
+            cron_schedule: update.cron_schedule.trim().into(),
+            enabled: update.enabled,
+            version: self
+                .uuid_service
+                .new_uuid("scheduler-job-service::update version"),
+            ..current
+        };
+        self.scheduler_job_dao
+            .update(&entity, PROCESS_UPDATE, tx.clone())
+            .await?;
+
+        let after = SchedulerJob::from(&self.find_entity(name, tx.clone()).await?);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::SCHEDULER_JOB, name, &before, &after)?,
+                PROCESS_UPDATE,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(after)
+    }
+
+    async fn record_run(
+        &self,
+        name: &str,
+        started_at: time::PrimitiveDateTime,
+        duration_ms: u64,
+        error: Option<Arc<str>>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let version = self
+            .uuid_service
+            .new_uuid("scheduler-job-service::record_run version");
+        self.scheduler_job_dao
+            .record_run(
+                name,
+                started_at,
+                duration_ms,
+                error,
+                PROCESS_RECORD_RUN,
+                version,
+                tx.clone(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+}
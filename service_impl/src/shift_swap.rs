@@ -0,0 +1,538 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    shift_swap::{ShiftSwapRequestDao, ShiftSwapRequestEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    booking::{Booking, BookingService},
+    clock::ClockService,
+    permission::{Authentication, SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::SalesPersonService,
+    sales_person_shiftplan::SalesPersonShiftplanService,
+    shift_swap::{ShiftSwapApproveResult, ShiftSwapRequest, ShiftSwapService, ShiftSwapState},
+    shiftplan_edit::ShiftplanEditService,
+    slot::SlotService,
+    uuid_service::UuidService,
+    warning::Warning,
+    week_status::{WeekStatus, WeekStatusService},
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use tokio::join;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+const SHIFT_SWAP_SERVICE_PROCESS: &str = "shift-swap-service";
+
+gen_service_impl! {
+    struct ShiftSwapServiceImpl: ShiftSwapService = ShiftSwapServiceDeps {
+        ShiftSwapRequestDao: dao::shift_swap::ShiftSwapRequestDao<Transaction = Self::Transaction> = shift_swap_request_dao,
+        PermissionService: service::PermissionService<Context = Self::Context> = permission_service,
+        BookingService: service::booking::BookingService<Context = Self::Context, Transaction = Self::Transaction> = booking_service,
+        SlotService: service::slot::SlotService<Transaction = Self::Transaction> = slot_service,
+        SalesPersonService: service::sales_person::SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        SalesPersonShiftplanService: service::sales_person_shiftplan::SalesPersonShiftplanService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_shiftplan_service,
+        WeekStatusService: service::week_status::WeekStatusService<Context = Self::Context, Transaction = Self::Transaction> = week_status_service,
+        // Business-Logic-Dep: Genehmigung bucht über den konflikt-aware Pfad
+        // (Wochen-Sperre, Warnings, created_by/deleted_by für booking_log).
+        ShiftplanEditService: service::shiftplan_edit::ShiftplanEditService<Context = Self::Context, Transaction = Self::Transaction> = shiftplan_edit_service,
+        ClockService: service::clock::ClockService = clock_service,
+        UuidService: service::uuid_service::UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
+    }
+}
+
+impl<Deps: ShiftSwapServiceDeps> ShiftSwapServiceImpl<Deps> {
+    /// Shiftplanner ∨ die angegebene Person selbst.
+    async fn check_shiftplanner_or_self(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let (shiftplanner, is_self) = join!(
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.into()
+            ),
+        );
+        shiftplanner.or(is_self)
+    }
+
+    /// Wochen-Sperre für Anbieten/Übernehmen — gleiche Semantik wie
+    /// `ShiftplanEditServiceImpl::assert_week_not_locked` (shiftplan.edit
+    /// umgeht die Sperre, D-40-02). Die Genehmigung prüft die Sperre über
+    /// den ShiftplanEditService selbst.
+    async fn assert_week_not_locked(
+        &self,
+        year: u32,
+        calendar_week: u8,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        if self
+            .permission_service
+            .check_permission("shiftplan.edit", context)
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+        let status = self
+            .week_status_service
+            .get_week_status(year, calendar_week, Authentication::Full, Some(tx))
+            .await?;
+        if status == WeekStatus::Locked {
+            return Err(ServiceError::WeekLocked {
+                year,
+                week: calendar_week,
+            });
+        }
+        Ok(())
+    }
+
+    async fn load_pending(
+        &self,
+        id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<ShiftSwapRequest, ServiceError> {
+        let request = self
+            .shift_swap_request_dao
+            .find_by_id(id, tx)
+            .await?
+            .as_ref()
+            .map(ShiftSwapRequest::from)
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        if !request.state.is_pending() {
+            return Err(state_not_allowed());
+        }
+        Ok(request)
+    }
+
+    /// Prüft bei der Genehmigung erneut, ob die übernehmende Person die
+    /// Schicht noch leisten kann: Seit dem Übernehmen kann sie deaktiviert,
+    /// aus dem Schichtplan genommen oder zur selben Zeit gebucht worden sein.
+    /// Abwesenheiten meldet erst die Buchung selbst (siehe `approve`).
+    async fn assert_acceptor_available(
+        &self,
+        accepting_sales_person_id: Uuid,
+        booking: &Booking,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let sales_person = self
+            .sales_person_service
+            .get(
+                accepting_sales_person_id,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        if sales_person.inactive || sales_person.deleted.is_some() {
+            return Err(invalid_value("accepting_sales_person_id"));
+        }
+
+        let slot = self
+            .slot_service
+            .get_slot(&booking.slot_id, Authentication::Full, tx.clone().into())
+            .await?;
+        if let Some(shiftplan_id) = slot.shiftplan_id {
+            if !self
+                .sales_person_shiftplan_service
+                .is_eligible(
+                    accepting_sales_person_id,
+                    shiftplan_id,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?
+            {
+                return Err(invalid_value("accepting_sales_person_id"));
+            }
+        }
+
+        let week_bookings = self
+            .booking_service
+            .get_for_week(
+                booking.calendar_week as u8,
+                booking.year,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        for other in week_bookings.iter().filter(|other| {
+            other.sales_person_id == accepting_sales_person_id && other.deleted.is_none()
+        }) {
+            let other_slot = self
+                .slot_service
+                .get_slot(&other.slot_id, Authentication::Full, tx.clone().into())
+                .await?;
+            if other_slot.day_of_week == slot.day_of_week
+                && other_slot.from < slot.to
+                && slot.from < other_slot.to
+            {
+                return Err(ServiceError::OverlappingTimeRange);
+            }
+        }
+        Ok(())
+    }
+
+    /// Speichert den Zustandswechsel von `before` nach `request`.
+    async fn store(
+        &self,
+        before: &ShiftSwapRequest,
+        mut request: ShiftSwapRequest,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<ShiftSwapRequest, ServiceError> {
+        request.version = self.uuid_service.new_uuid("shift-swap-request-version");
+        self.shift_swap_request_dao
+            .update(
+                &ShiftSwapRequestEntity::from(&request),
+                SHIFT_SWAP_SERVICE_PROCESS,
+                tx.clone(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::SHIFT_SWAP_REQUEST,
+                    request.id,
+                    before,
+                    &request,
+                )?,
+                SHIFT_SWAP_SERVICE_PROCESS,
+                context,
+                tx.into(),
+            )
+            .await?;
+        Ok(request)
+    }
+}
+
+fn invalid_value(field: &str) -> ServiceError {
+    ServiceError::ValidationError(Arc::new([ValidationFailureItem::InvalidValue(
+        field.into(),
+    )]))
+}
+
+fn state_not_allowed() -> ServiceError {
+    ServiceError::ValidationError(Arc::new([ValidationFailureItem::ModificationNotAllowed(
+        "state".into(),
+    )]))
+}
+
+#[async_trait]
+impl<Deps: ShiftSwapServiceDeps> ShiftSwapService for ShiftSwapServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_pending(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ShiftSwapRequest]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let (shiftplanner, sales) = join!(
+            self.permission_service
+                .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone()),
+            self.permission_service
+                .check_permission(SALES_PRIVILEGE, context),
+        );
+        shiftplanner.or(sales)?;
+
+        let requests = self
+            .shift_swap_request_dao
+            .find_pending(tx.clone())
+            .await?
+            .iter()
+            .map(ShiftSwapRequest::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(requests)
+    }
+
+    async fn get_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ShiftSwapRequest]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_shiftplanner_or_self(sales_person_id, context, tx.clone())
+            .await?;
+
+        let requests = self
+            .shift_swap_request_dao
+            .find_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .iter()
+            .map(ShiftSwapRequest::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(requests)
+    }
+
+    async fn offer(
+        &self,
+        booking_id: Uuid,
+        message: Arc<str>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let booking = self
+            .booking_service
+            .get(booking_id, Authentication::Full, tx.clone().into())
+            .await?;
+        if booking.deleted.is_some() {
+            return Err(ServiceError::EntityNotFound(booking_id));
+        }
+        self.check_shiftplanner_or_self(booking.sales_person_id, context.clone(), tx.clone())
+            .await?;
+        let calendar_week = booking.calendar_week as u8;
+        self.assert_week_not_locked(booking.year, calendar_week, context.clone(), tx.clone())
+            .await?;
+
+        if let Some(existing) = self
+            .shift_swap_request_dao
+            .find_pending_by_booking_id(booking_id, tx.clone())
+            .await?
+        {
+            return Err(ServiceError::EntityAlreadyExists(existing.id));
+        }
+
+        let request = ShiftSwapRequest {
+            id: self.uuid_service.new_uuid("shift-swap-request-id"),
+            booking_id,
+            offering_sales_person_id: booking.sales_person_id,
+            accepting_sales_person_id: None,
+            new_booking_id: None,
+            year: booking.year,
+            calendar_week,
+            state: ShiftSwapState::Open,
+            message,
+            reject_reason: None,
+            created: self.clock_service.date_time_now(),
+            accepted: None,
+            decided: None,
+            decided_by: None,
+            deleted: None,
+            version: self.uuid_service.new_uuid("shift-swap-request-version"),
+        };
+        self.shift_swap_request_dao
+            .create(
+                &ShiftSwapRequestEntity::from(&request),
+                SHIFT_SWAP_SERVICE_PROCESS,
+                tx.clone(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SHIFT_SWAP_REQUEST, request.id, &request)?,
+                SHIFT_SWAP_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(request)
+    }
+
+    async fn accept(
+        &self,
+        id: Uuid,
+        accepting_sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_shiftplanner_or_self(accepting_sales_person_id, context.clone(), tx.clone())
+            .await?;
+
+        let before = self.load_pending(id, tx.clone()).await?;
+        let mut request = before.clone();
+        if request.state != ShiftSwapState::Open {
+            return Err(state_not_allowed());
+        }
+        if request.offering_sales_person_id == accepting_sales_person_id {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("accepting_sales_person_id".into()),
+            ])));
+        }
+        self.assert_week_not_locked(
+            request.year,
+            request.calendar_week,
+            context.clone(),
+            tx.clone(),
+        )
+        .await?;
+
+        let booking = self
+            .booking_service
+            .get(request.booking_id, Authentication::Full, tx.clone().into())
+            .await?;
+        let slot = self
+            .slot_service
+            .get_slot(&booking.slot_id, Authentication::Full, tx.clone().into())
+            .await?;
+        if let Some(shiftplan_id) = slot.shiftplan_id {
+            if !self
+                .sales_person_shiftplan_service
+                .is_eligible(
+                    accepting_sales_person_id,
+                    shiftplan_id,
+                    context.clone(),
+                    tx.clone().into(),
+                )
+                .await?
+            {
+                return Err(ServiceError::Forbidden);
+            }
+        }
+
+        request.accepting_sales_person_id = Some(accepting_sales_person_id);
+        request.state = ShiftSwapState::Accepted;
+        request.accepted = Some(self.clock_service.date_time_now());
+        let request = self.store(&before, request, context, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(request)
+    }
+
+    async fn approve(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapApproveResult, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+
+        let before = self.load_pending(id, tx.clone()).await?;
+        let mut request = before.clone();
+        let Some(accepting_sales_person_id) = request.accepting_sales_person_id else {
+            return Err(state_not_allowed());
+        };
+        let old_booking = self
+            .booking_service
+            .get(request.booking_id, Authentication::Full, tx.clone().into())
+            .await?;
+        // Die Schicht wurde seit dem Anbieten anderweitig gelöscht — eine
+        // Genehmigung würde sie wieder aufleben lassen.
+        if old_booking.deleted.is_some() {
+            return Err(invalid_value("booking_id"));
+        }
+        self.assert_acceptor_available(accepting_sales_person_id, &old_booking, tx.clone())
+            .await?;
+
+        // Löschen + Neuanlage in derselben Transaktion; beide Pfade prüfen
+        // die Wochen-Sperre und setzen deleted_by/created_by (booking_log).
+        self.shiftplan_edit_service
+            .delete_booking(old_booking.id, context.clone(), Some(tx.clone()))
+            .await?;
+        let created = self
+            .shiftplan_edit_service
+            .book_slot_with_conflict_check(
+                &Booking {
+                    id: Uuid::nil(),
+                    sales_person_id: accepting_sales_person_id,
+                    slot_id: old_booking.slot_id,
+                    calendar_week: old_booking.calendar_week,
+                    year: old_booking.year,
+                    created: None,
+                    deleted: None,
+                    created_by: None,
+                    deleted_by: None,
+                    version: Uuid::nil(),
+                },
+                context.clone(),
+                Some(tx.clone()),
+            )
+            .await?;
+        // Abwesenheit am Schicht-Tag: beim normalen Buchen nur eine Warning,
+        // bei der Genehmigung ein Fehler. Ohne Commit bleibt nichts bestehen.
+        if created
+            .warnings
+            .iter()
+            .any(|warning| matches!(warning, Warning::BookingOnAbsenceDay { .. }))
+        {
+            return Err(invalid_value("accepting_sales_person_id"));
+        }
+
+        request.state = ShiftSwapState::Approved;
+        request.new_booking_id = Some(created.booking.id);
+        request.decided = Some(self.clock_service.date_time_now());
+        request.decided_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?;
+        let request = self.store(&before, request, context, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(ShiftSwapApproveResult {
+            request,
+            booking: created.booking,
+            warnings: created.warnings,
+        })
+    }
+
+    async fn reject(
+        &self,
+        id: Uuid,
+        reason: Arc<str>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
+            .await?;
+
+        let before = self.load_pending(id, tx.clone()).await?;
+        let mut request = before.clone();
+        request.state = ShiftSwapState::Rejected;
+        request.reject_reason = Some(reason);
+        request.decided = Some(self.clock_service.date_time_now());
+        request.decided_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?;
+        let request = self.store(&before, request, context, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(request)
+    }
+
+    async fn cancel(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<ShiftSwapRequest, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.load_pending(id, tx.clone()).await?;
+        let mut request = before.clone();
+        self.check_shiftplanner_or_self(
+            request.offering_sales_person_id,
+            context.clone(),
+            tx.clone(),
+        )
+        .await?;
+
+        request.state = ShiftSwapState::Cancelled;
+        request.decided = Some(self.clock_service.date_time_now());
+        request.decided_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?;
+        let request = self.store(&before, request, context, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(request)
+    }
+}
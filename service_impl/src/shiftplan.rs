@@ -8,10 +8,13 @@ use service::{
     booking::{Booking, BookingService},
     permission::{Authentication, PermissionService, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
     sales_person::{SalesPerson, SalesPersonService},
+    sales_person_availability::{
+        AvailabilityKind, SalesPersonAvailability, SalesPersonAvailabilityService,
+    },
     sales_person_unavailable::{SalesPersonUnavailable, SalesPersonUnavailableService},
     shiftplan::{
         PlanDayView, ShiftplanBooking, ShiftplanDay, ShiftplanDayAggregate, ShiftplanSlot,
-        ShiftplanViewService, ShiftplanWeek, UnavailabilityMarker,
+        ShiftplanViewService, ShiftplanWeek, UnavailabilityMarker, UnavailableWindow,
     },
     shiftplan_catalog::ShiftplanService,
     slot::{Slot, SlotService},
@@ -155,6 +158,10 @@ pub(crate) fn build_shiftplan_day(
 /// werden client-side ignoriert; der DAO-Layer filtert das ohnehin schon,
 /// dies ist eine doppelte Defensive für den Fall, dass Test-Mocks Soft-deleted-
 /// Daten injizieren.
+///
+/// Wiederkehrende `Unavailable`-Regeln: Ganztags-Regeln zählen wie ein
+/// ManualUnavailable; Regeln mit Zeitfenster liefern nur dann
+/// `PartiallyUnavailable`, wenn keine andere Quelle den Tag blockiert.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn build_shiftplan_day_for_sales_person(
     day_of_week: DayOfWeek,
@@ -168,6 +175,7 @@ pub(crate) fn build_shiftplan_day_for_sales_person(
     sales_person_id: Uuid,
     absence_periods: &[AbsencePeriod],
     manual_unavailables: &[SalesPersonUnavailable],
+    availabilities: &[SalesPersonAvailability],
     year: u32,
     week: u8,
     active_from: Option<time::Date>,
@@ -186,16 +194,24 @@ pub(crate) fn build_shiftplan_day_for_sales_person(
     )?;
 
     let absence_match = absence_periods.iter().find(|ap| {
-        ap.deleted.is_none()
+        ap.is_effective()
             && ap.sales_person_id == sales_person_id
             && ap.from_date <= day_date
             && day_date <= ap.to_date
     });
+    let recurring_rules: Vec<&SalesPersonAvailability> = availabilities
+        .iter()
+        .filter(|availability| {
+            availability.sales_person_id == sales_person_id
+                && availability.kind == AvailabilityKind::Unavailable
+                && availability.applies_on(day_date)
+        })
+        .collect();
     let manual_match = manual_unavailables.iter().any(|mu| {
         mu.deleted.is_none()
             && mu.sales_person_id == sales_person_id
             && mu.day_of_week == day_of_week
-    });
+    }) || recurring_rules.iter().any(|rule| rule.is_full_day());
 
     day.unavailable = match (absence_match, manual_match) {
         (Some(ap), false) => Some(UnavailabilityMarker::AbsencePeriod {
@@ -207,7 +223,22 @@ pub(crate) fn build_shiftplan_day_for_sales_person(
             absence_id: ap.id,
             category: ap.category,
         }),
-        (None, false) => None,
+        (None, false) => {
+            let windows: Vec<UnavailableWindow> = recurring_rules
+                .iter()
+                .filter_map(|rule| {
+                    Some(UnavailableWindow {
+                        from: rule.time_from?,
+                        to: rule.time_to?,
+                    })
+                })
+                .collect();
+            if windows.is_empty() {
+                None
+            } else {
+                Some(UnavailabilityMarker::PartiallyUnavailable { windows })
+            }
+        }
     };
 
     Ok(day)
@@ -225,6 +256,8 @@ gen_service_impl! {
         // NEU für Phase 3 (D-Phase3-09):
         AbsenceService: service::absence::AbsenceService<Context = Self::Context, Transaction = Self::Transaction> = absence_service,
         SalesPersonUnavailableService: service::sales_person_unavailable::SalesPersonUnavailableService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_unavailable_service,
+        // Wiederkehrende Verfügbarkeit (Regeln statt Einzel-Tage).
+        SalesPersonAvailabilityService: service::sales_person_availability::SalesPersonAvailabilityService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_availability_service,
         // NEU für Phase 51 (D-51-07): Stichtag-Gate für ShortDay-Slot-Kürzung.
         ToggleService: service::toggle::ToggleService<Context = Self::Context, Transaction = Self::Transaction> = toggle_service
     }
@@ -478,6 +511,16 @@ impl<Deps: ShiftplanViewServiceDeps> ShiftplanViewService for ShiftplanViewServi
                 tx.clone().into(),
             )
             .await?;
+        let availabilities = self
+            .sales_person_availability_service
+            .get_by_week_for_sales_person(
+                sales_person_id,
+                year,
+                week,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
 
         // Standard-Sicht-Daten (Slots + Bookings + Sales-Persons + Special-Days).
         let special_days = self
@@ -554,6 +597,7 @@ impl<Deps: ShiftplanViewServiceDeps> ShiftplanViewService for ShiftplanViewServi
                 sales_person_id,
                 &absence_periods,
                 &manual_unavailables,
+                &availabilities,
                 year,
                 week,
                 active_from,
@@ -612,6 +656,16 @@ impl<Deps: ShiftplanViewServiceDeps> ShiftplanViewService for ShiftplanViewServi
                 tx.clone().into(),
             )
             .await?;
+        let availabilities = self
+            .sales_person_availability_service
+            .get_by_week_for_sales_person(
+                sales_person_id,
+                year,
+                week,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
 
         let special_days = self
             .special_day_service
@@ -682,6 +736,7 @@ impl<Deps: ShiftplanViewServiceDeps> ShiftplanViewService for ShiftplanViewServi
                 sales_person_id,
                 &absence_periods,
                 &manual_unavailables,
+                &availabilities,
                 year,
                 week,
                 active_from,
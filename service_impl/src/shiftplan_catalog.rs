@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use dao::{shiftplan::ShiftplanDao, TransactionDao};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
     permission::{Authentication, PermissionService, SHIFTPLANNER_PRIVILEGE},
     shiftplan_catalog::{Shiftplan, ShiftplanService},
@@ -18,6 +19,7 @@ gen_service_impl! {
         PermissionService: service::permission::PermissionService<Context = Self::Context> = permission_service,
         ClockService: service::clock::ClockService = clock_service,
         UuidService: service::uuid_service::UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -64,7 +66,7 @@ impl<Deps: ShiftplanServiceDeps> ShiftplanService for ShiftplanServiceImpl<Deps>
     ) -> Result<Shiftplan, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         if shiftplan.id != Uuid::nil() {
@@ -83,6 +85,14 @@ impl<Deps: ShiftplanServiceDeps> ShiftplanService for ShiftplanServiceImpl<Deps>
         self.shiftplan_dao
             .create(&entity, "shiftplan-service", tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SHIFTPLAN, new_shiftplan.id, &new_shiftplan)?,
+                "shiftplan-service",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(new_shiftplan)
     }
@@ -95,7 +105,7 @@ impl<Deps: ShiftplanServiceDeps> ShiftplanService for ShiftplanServiceImpl<Deps>
     ) -> Result<Shiftplan, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         let persisted = self
@@ -120,6 +130,19 @@ impl<Deps: ShiftplanServiceDeps> ShiftplanService for ShiftplanServiceImpl<Deps>
         self.shiftplan_dao
             .update(&entity, "shiftplan-service", tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::SHIFTPLAN,
+                    updated.id,
+                    &Shiftplan::from(&persisted),
+                    &updated,
+                )?,
+                "shiftplan-service",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(updated)
     }
@@ -132,7 +155,7 @@ impl<Deps: ShiftplanServiceDeps> ShiftplanService for ShiftplanServiceImpl<Deps>
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         let mut entity = self
@@ -141,10 +164,19 @@ impl<Deps: ShiftplanServiceDeps> ShiftplanService for ShiftplanServiceImpl<Deps>
             .await?
             .ok_or(ServiceError::EntityNotFound(id))?;
 
+        let before = Shiftplan::from(&entity);
         entity.deleted = Some(self.clock_service.date_time_now());
         self.shiftplan_dao
             .update(&entity, "shiftplan-service", tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::SHIFTPLAN, id, &before)?,
+                "shiftplan-service",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
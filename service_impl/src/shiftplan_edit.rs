@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -5,12 +6,15 @@ use dao::TransactionDao;
 use service::{
     absence::AbsenceService,
     booking::{Booking, BookingService},
-    carryover::{Carryover, CarryoverService},
+    carryover::{Carryover, CarryoverDirty, CarryoverService},
     employee_work_details::EmployeeWorkDetailsService,
     extra_hours::{ExtraHours, ExtraHoursCategory, ExtraHoursService},
+    notification::{NotificationEvent, NotificationService},
     permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
+    qualification::{unmet_requirements, QualificationService, SlotQualificationRequirement},
     reporting::ReportingService,
     sales_person::SalesPersonService,
+    sales_person_availability::SalesPersonAvailabilityService,
     sales_person_unavailable::{SalesPersonUnavailable, SalesPersonUnavailableService},
     shiftplan_edit::{BookingCreateResult, CopyWeekResult, ShiftplanEditService},
     slot::{Slot, SlotService},
@@ -44,7 +48,15 @@ gen_service_impl! {
         // NEU für Phase 40 (D-40-01): WeekStatusService liefert den Lock-Status
         // für das Wochen-Sperre-Gate. Basic-Tier-Dep in Business-Logic-Service
         // (CLAUDE.md § Service-Tier-Konventionen).
-        WeekStatusService: service::week_status::WeekStatusService<Context = Self::Context, Transaction = Self::Transaction> = week_status_service
+        WeekStatusService: service::week_status::WeekStatusService<Context = Self::Context, Transaction = Self::Transaction> = week_status_service,
+        // Wiederkehrende `Unavailable`-Regeln lösen dieselbe Reverse-Warning
+        // aus wie ein ManualUnavailable (Basic-Tier-Dep).
+        SalesPersonAvailabilityService: SalesPersonAvailabilityService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_availability_service,
+        // E-Mail bei gelöschtem Booking (Basic-Tier-Dep).
+        NotificationService: NotificationService<Context = Self::Context, Transaction = Self::Transaction> = notification_service,
+        // Qualifikations-Anforderungen: Warning beim Buchen, Übernahme beim
+        // Slot-Split (Basic-Tier-Dep).
+        QualificationService: QualificationService<Context = Self::Context, Transaction = Self::Transaction> = qualification_service
     }
 }
 
@@ -123,6 +135,8 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
             .slot_service
             .create_slot(&new_slot, Authentication::Full, tx.clone().into())
             .await?;
+        self.copy_slot_requirements(slot.id, &new_slot, tx.clone())
+            .await?;
 
         for booking in bookings.iter() {
             self.booking_service
@@ -297,6 +311,8 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
             .slot_service
             .create_slot(&seg2, Authentication::Full, tx.clone().into())
             .await?;
+        self.copy_slot_requirements(slot.id, &seg2_slot, tx.clone())
+            .await?;
 
         // Segment 3: Wiederherstellung ab Montag KW+1 mit Original-Werten (NEU)
         // WR-01: Nur anlegen wenn noch eine nicht-leere Restspanne existiert.
@@ -315,6 +331,8 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
                     .slot_service
                     .create_slot(&seg3, Authentication::Full, tx.clone().into())
                     .await?;
+                self.copy_slot_requirements(slot.id, &seg3_slot, tx.clone())
+                    .await?;
                 Some(seg3_slot.id)
             } else {
                 // Ausnahme-KW ist die letzte Woche des Slots → keine Restspanne
@@ -440,6 +458,69 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
         Ok(())
     }
 
+    async fn update_dirty_carryovers(
+        &self,
+        until_year: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<u32, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission("shiftplan.edit", context.clone())
+            .await?;
+
+        let dirty = self
+            .carryover_service
+            .find_dirty(Authentication::Full, tx.clone().into())
+            .await?;
+        if dirty.is_empty() {
+            self.transaction_dao.commit(tx).await?;
+            return Ok(0);
+        }
+
+        let mut dirty_by_sales_person: BTreeMap<Uuid, Vec<&CarryoverDirty>> = BTreeMap::new();
+        for entry in dirty.iter() {
+            dirty_by_sales_person
+                .entry(entry.sales_person_id)
+                .or_default()
+                .push(entry);
+        }
+        // Markierungen gelöschter Mitarbeiter werden nur entfernt.
+        let sales_persons: HashSet<Uuid> = self
+            .sales_person_service
+            .get_all(context.clone(), tx.clone().into())
+            .await?
+            .iter()
+            .map(|sp| sp.id)
+            .collect();
+
+        let mut updated = 0;
+        for (sales_person_id, entries) in dirty_by_sales_person {
+            if sales_persons.contains(&sales_person_id) {
+                let first_year = entries.iter().map(|e| e.year).min().unwrap_or(until_year);
+                let last_year = entries
+                    .iter()
+                    .map(|e| e.year)
+                    .max()
+                    .unwrap_or(until_year)
+                    .max(until_year);
+                for year in first_year..=last_year {
+                    self.update_carryover(sales_person_id, year, context.clone(), Some(tx.clone()))
+                        .await?;
+                    updated += 1;
+                }
+            }
+            for entry in entries {
+                self.carryover_service
+                    .clear_dirty(entry, Authentication::Full, tx.clone().into())
+                    .await?;
+            }
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
     async fn add_vacation(
         &self,
         sales_person_id: Uuid,
@@ -690,6 +771,17 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
                 tx.clone().into(),
             )
             .await?;
+        // Wiederkehrende Verfügbarkeits-Regeln der Woche.
+        let availabilities = self
+            .sales_person_availability_service
+            .get_by_week_for_sales_person(
+                booking.sales_person_id,
+                booking.year,
+                booking.calendar_week as u8,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
 
         // Persist via Basic-Service — BookingService::create UNVERÄNDERT
         // (D-Phase3-18 Regression-Lock). Wir reichen den ursprünglichen User
@@ -730,19 +822,24 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
                 category: ap.category,
             });
         }
-        for mu in manual_unavailables.iter() {
-            // Soft-Delete-Filter (Pitfall 1 / SC4) + Day-of-Week-Match.
-            if mu.deleted.is_none() && mu.day_of_week == slot.day_of_week {
-                warnings.push(Warning::BookingOnUnavailableDay {
-                    booking_id: persisted_booking.id,
-                    year: booking.year,
-                    week: booking.calendar_week as u8,
-                    day_of_week: slot.day_of_week,
-                });
-                // Eine Warning pro day_of_week-Match reicht — die DAO liefert
-                // typischerweise nur einen Eintrag pro (sp, year, week, dow).
-                break;
-            }
+        // Soft-Delete-Filter (Pitfall 1 / SC4) + Day-of-Week-Match. Eine
+        // Warning pro day_of_week-Match reicht — die DAO liefert typischerweise
+        // nur einen Eintrag pro (sp, year, week, dow). Wiederkehrende
+        // `Unavailable`-Regeln zählen genauso, sofern sie am Tag gelten und
+        // (bei Teil-Regeln) das Slot-Zeitfenster überlappen.
+        let manual_match = manual_unavailables
+            .iter()
+            .any(|mu| mu.deleted.is_none() && mu.day_of_week == slot.day_of_week);
+        let recurring_match = availabilities
+            .iter()
+            .any(|availability| availability.blocks(booking_date, slot.from, slot.to));
+        if manual_match || recurring_match {
+            warnings.push(Warning::BookingOnUnavailableDay {
+                booking_id: persisted_booking.id,
+                year: booking.year,
+                week: booking.calendar_week as u8,
+                day_of_week: slot.day_of_week,
+            });
         }
 
         // Phase 5 (D-04, D-06, D-07, D-08, D-15, D-16): Paid-Employee-Limit-
@@ -785,6 +882,19 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
             }
         }
 
+        // Qualifikations-Anforderungen des Slots nach der Buchung
+        // (informativ wie das Bezahlt-Limit).
+        warnings.extend(
+            self.qualification_warnings(
+                booking.slot_id,
+                booking.year,
+                booking.calendar_week as u8,
+                persisted_booking.id,
+                tx.clone(),
+            )
+            .await?,
+        );
+
         self.transaction_dao.commit(tx).await?;
         Ok(BookingCreateResult {
             booking: persisted_booking,
@@ -827,6 +937,9 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
 
         let mut copied_bookings: Vec<Booking> = Vec::new();
         let mut all_warnings: Vec<Warning> = Vec::new();
+        // Slot → letzte kopierte Buchung. Qualifikationen werden erst nach
+        // der ganzen Woche geprüft, sonst warnt jede Zwischenstufe.
+        let mut last_booking_per_slot: BTreeMap<Uuid, Uuid> = BTreeMap::new();
 
         for source in source_bookings.iter() {
             // Konstruiere Ziel-Booking — id/version werden vom BookingService
@@ -846,8 +959,27 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
             let result = self
                 .book_slot_with_conflict_check(&target, context.clone(), Some(tx.clone()))
                 .await?;
+            last_booking_per_slot.insert(result.booking.slot_id, result.booking.id);
             copied_bookings.push(result.booking);
-            all_warnings.extend(result.warnings.iter().cloned());
+            all_warnings.extend(
+                result
+                    .warnings
+                    .iter()
+                    .filter(|warning| !matches!(warning, Warning::QualificationNotCovered { .. }))
+                    .cloned(),
+            );
+        }
+        for (slot_id, booking_id) in last_booking_per_slot {
+            all_warnings.extend(
+                self.qualification_warnings(
+                    slot_id,
+                    to_year,
+                    to_calendar_week,
+                    booking_id,
+                    tx.clone(),
+                )
+                .await?,
+            );
         }
 
         self.transaction_dao.commit(tx).await?;
@@ -888,6 +1020,26 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditService for ShiftplanEditServi
             .delete(booking_id, context, Some(tx.clone()))
             .await?;
 
+        // Benachrichtigung landet in derselben Transaktion in der Outbox —
+        // rollt der Delete zurück, wird auch keine Mail verschickt.
+        let slot = self
+            .slot_service
+            .get_slot(&booking.slot_id, Authentication::Full, Some(tx.clone()))
+            .await?;
+        self.notification_service
+            .notify(
+                &NotificationEvent::BookingDeleted {
+                    sales_person_id: booking.sales_person_id,
+                    year: booking.year,
+                    calendar_week: booking.calendar_week as u8,
+                    day_of_week: slot.day_of_week,
+                    from: slot.from,
+                    to: slot.to,
+                },
+                Some(tx.clone()),
+            )
+            .await?;
+
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -944,6 +1096,77 @@ impl<Deps: ShiftplanEditServiceDeps> ShiftplanEditServiceImpl<Deps> {
         Ok(())
     }
 
+    /// Eine [`Warning::QualificationNotCovered`] pro Anforderung des Slots,
+    /// die von den Buchungen in (year, week) nicht erfüllt wird.
+    async fn qualification_warnings(
+        &self,
+        slot_id: Uuid,
+        year: u32,
+        week: u8,
+        booking_id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<Vec<Warning>, ServiceError> {
+        let requirements = self
+            .qualification_service
+            .get_slot_requirements(slot_id, Authentication::Full, Some(tx.clone()))
+            .await?;
+        if requirements.is_empty() {
+            return Ok(Vec::new());
+        }
+        let booked: Vec<Uuid> = self
+            .booking_service
+            .get_for_week(week, year, Authentication::Full, Some(tx.clone()))
+            .await?
+            .iter()
+            .filter(|b| b.slot_id == slot_id && b.deleted.is_none())
+            .map(|b| b.sales_person_id)
+            .collect();
+        let qualifications = self
+            .qualification_service
+            .get_all_sales_person_qualifications(Authentication::Full, Some(tx))
+            .await?;
+        Ok(unmet_requirements(&requirements, &booked, &qualifications)
+            .into_iter()
+            .map(|unmet| Warning::QualificationNotCovered {
+                slot_id,
+                booking_id,
+                year,
+                week,
+                qualification_id: unmet.qualification_id,
+                required_count: unmet.required_count,
+                qualified_count: unmet.qualified_count,
+            })
+            .collect())
+    }
+
+    /// Übernimmt die Qualifikations-Anforderungen auf einen beim Split neu
+    /// angelegten Slot. `min_count` wird auf dessen `min_resources` gekappt.
+    async fn copy_slot_requirements(
+        &self,
+        from_slot_id: Uuid,
+        to_slot: &Slot,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let requirements: Vec<SlotQualificationRequirement> = self
+            .qualification_service
+            .get_slot_requirements(from_slot_id, Authentication::Full, Some(tx.clone()))
+            .await?
+            .iter()
+            .map(|requirement| SlotQualificationRequirement {
+                slot_id: to_slot.id,
+                min_count: requirement.min_count.min(to_slot.min_resources),
+                ..requirement.clone()
+            })
+            .filter(|requirement| requirement.min_count > 0)
+            .collect();
+        if requirements.is_empty() {
+            return Ok(());
+        }
+        self.qualification_service
+            .set_slot_requirements(to_slot.id, &requirements, Authentication::Full, Some(tx))
+            .await
+    }
+
     /// Phase 5 (D-04, D-05): zählt aktive Bookings im (slot_id, year, week)
     /// deren Sales Person aktuell `is_paid = true` hat.
     ///
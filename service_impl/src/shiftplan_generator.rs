@@ -0,0 +1,528 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    absence::{AbsenceService, DayFraction},
+    booking::{Booking, BookingService},
+    employee_work_details::EmployeeWorkDetailsService,
+    permission::Authentication,
+    sales_person::SalesPersonService,
+    sales_person_availability::SalesPersonAvailabilityService,
+    sales_person_shiftplan::SalesPersonShiftplanService,
+    sales_person_unavailable::SalesPersonUnavailableService,
+    shiftplan_edit::{CopyWeekResult, ShiftplanEditService},
+    shiftplan_generator::{
+        ProposedHours, ShiftplanGeneratorService, ShiftplanProposal, UnfilledSlot,
+    },
+    slot::{Slot, SlotService},
+    warning::Warning,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::DayOfWeek;
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+
+gen_service_impl! {
+    struct ShiftplanGeneratorServiceImpl: ShiftplanGeneratorService = ShiftplanGeneratorServiceDeps {
+        PermissionService: service::PermissionService<Context = Self::Context> = permission_service,
+        SlotService: service::slot::SlotService<Transaction = Self::Transaction> = slot_service,
+        BookingService: service::booking::BookingService<Context = Self::Context, Transaction = Self::Transaction> = booking_service,
+        SalesPersonService: service::sales_person::SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        SalesPersonShiftplanService: service::sales_person_shiftplan::SalesPersonShiftplanService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_shiftplan_service,
+        SalesPersonUnavailableService: service::sales_person_unavailable::SalesPersonUnavailableService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_unavailable_service,
+        SalesPersonAvailabilityService: service::sales_person_availability::SalesPersonAvailabilityService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_availability_service,
+        AbsenceService: service::absence::AbsenceService<Context = Self::Context, Transaction = Self::Transaction> = absence_service,
+        EmployeeWorkDetailsService: service::employee_work_details::EmployeeWorkDetailsService<Context = Self::Context, Transaction = Self::Transaction> = employee_work_details_service,
+        // Business-Logic-Dep: Trockenlauf und Annehmen laufen über den
+        // konflikt-aware Buchungspfad (Warnings, Wochen-Sperre, Paid-Limit).
+        ShiftplanEditService: service::shiftplan_edit::ShiftplanEditService<Context = Self::Context, Transaction = Self::Transaction> = shiftplan_edit_service,
+        TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
+    }
+}
+
+/// Eingeplante Person aus Sicht des Solvers.
+#[derive(Clone, Debug)]
+pub(crate) struct Candidate {
+    pub sales_person_id: Uuid,
+    pub name: Arc<str>,
+    pub is_paid: bool,
+    pub expected_hours: f32,
+    /// `None` = kein Arbeitsvertrag in der Woche (z. B. Ehrenamt), alle Tage
+    /// zulässig. `Some(days)` = nur die Vertragstage.
+    pub workdays: Option<BTreeSet<DayOfWeek>>,
+    /// Tage mit `sales_person_unavailable` oder ganztägiger AbsencePeriod.
+    pub blocked_days: BTreeSet<DayOfWeek>,
+    /// Slots, die eine wiederkehrende `Unavailable`-Regel blockiert (ganzer
+    /// Tag oder überlappendes Zeitfenster, wie im Buchungspfad).
+    pub blocked_slots: HashSet<Uuid>,
+}
+
+/// Ergebnis des reinen Solver-Laufs (ohne Persistenz).
+#[derive(Clone, Debug)]
+pub(crate) struct PlannedWeek {
+    /// (sales_person_id, slot_id) in Vergabereihenfolge.
+    pub assignments: Vec<(Uuid, Uuid)>,
+    pub unfilled_slots: Vec<UnfilledSlot>,
+    pub hours: Vec<ProposedHours>,
+}
+
+fn slot_hours(slot: &Slot) -> f32 {
+    (slot.to - slot.from).as_seconds_f32() / 3600.0
+}
+
+/// Greedy-Solver: Slots chronologisch (Tag, Beginn), pro fehlendem Platz die
+/// zulässige Person mit der größten Reststunden-Differenz
+/// (`expected_hours - planned`). Gleichstand wird deterministisch über
+/// bisher geplante Stunden, Name und ID aufgelöst.
+///
+/// `all_slots` enthält die Slots aller Schichtpläne der Woche, damit bereits
+/// vorhandene Bookings in die Stunden-Bilanz und die Überschneidungsprüfung
+/// eingehen. `paid_sales_persons` umfasst alle bezahlten Personen (auch
+/// nicht-kandidierende), damit `max_paid_employees` korrekt gezählt wird.
+pub(crate) fn plan_week(
+    slots: &[Slot],
+    all_slots: &HashMap<Uuid, Slot>,
+    existing_bookings: &[Booking],
+    candidates: &[Candidate],
+    paid_sales_persons: &HashSet<Uuid>,
+) -> PlannedWeek {
+    let mut planned: HashMap<Uuid, f32> = HashMap::new();
+    let mut busy: HashMap<(Uuid, u8), Vec<(time::Time, time::Time)>> = HashMap::new();
+    let mut booked_in_slot: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+    for booking in existing_bookings.iter().filter(|b| b.deleted.is_none()) {
+        let Some(slot) = all_slots.get(&booking.slot_id) else {
+            continue;
+        };
+        *planned.entry(booking.sales_person_id).or_default() += slot_hours(slot);
+        busy.entry((booking.sales_person_id, slot.day_of_week.to_number()))
+            .or_default()
+            .push((slot.from, slot.to));
+        booked_in_slot
+            .entry(booking.slot_id)
+            .or_default()
+            .insert(booking.sales_person_id);
+    }
+    let planned_before = planned.clone();
+
+    let mut ordered_slots: Vec<&Slot> = slots.iter().filter(|s| s.deleted.is_none()).collect();
+    ordered_slots.sort_by_key(|s| (s.day_of_week.to_number(), s.from, s.id));
+
+    let mut assignments = Vec::new();
+    let mut unfilled_slots = Vec::new();
+    for slot in ordered_slots {
+        let day = slot.day_of_week.to_number();
+        let hours = slot_hours(slot);
+        let in_slot = booked_in_slot.entry(slot.id).or_default();
+        let mut paid_count = in_slot
+            .iter()
+            .filter(|id| paid_sales_persons.contains(id))
+            .count();
+
+        while in_slot.len() < slot.min_resources as usize {
+            let best = candidates
+                .iter()
+                .filter(|c| !in_slot.contains(&c.sales_person_id))
+                .filter(|c| !c.blocked_days.contains(&slot.day_of_week))
+                .filter(|c| !c.blocked_slots.contains(&slot.id))
+                .filter(|c| {
+                    c.workdays
+                        .as_ref()
+                        .is_none_or(|days| days.contains(&slot.day_of_week))
+                })
+                .filter(|c| {
+                    !c.is_paid
+                        || slot
+                            .max_paid_employees
+                            .is_none_or(|max| paid_count < max as usize)
+                })
+                .filter(|c| {
+                    busy.get(&(c.sales_person_id, day)).is_none_or(|ranges| {
+                        ranges
+                            .iter()
+                            .all(|(from, to)| *to <= slot.from || *from >= slot.to)
+                    })
+                })
+                .min_by(|a, b| {
+                    let planned_a = planned.get(&a.sales_person_id).copied().unwrap_or(0.0);
+                    let planned_b = planned.get(&b.sales_person_id).copied().unwrap_or(0.0);
+                    let remaining_a = a.expected_hours - planned_a;
+                    let remaining_b = b.expected_hours - planned_b;
+                    remaining_b
+                        .total_cmp(&remaining_a)
+                        .then(planned_a.total_cmp(&planned_b))
+                        .then_with(|| a.name.cmp(&b.name))
+                        .then(a.sales_person_id.cmp(&b.sales_person_id))
+                });
+            let Some(best) = best else {
+                break;
+            };
+            in_slot.insert(best.sales_person_id);
+            if best.is_paid {
+                paid_count += 1;
+            }
+            *planned.entry(best.sales_person_id).or_default() += hours;
+            busy.entry((best.sales_person_id, day))
+                .or_default()
+                .push((slot.from, slot.to));
+            assignments.push((best.sales_person_id, slot.id));
+        }
+
+        if in_slot.len() < slot.min_resources as usize {
+            unfilled_slots.push(UnfilledSlot {
+                slot_id: slot.id,
+                min_resources: slot.min_resources,
+                booked: in_slot.len() as u8,
+            });
+        }
+    }
+
+    let hours = candidates
+        .iter()
+        .map(|c| ProposedHours {
+            sales_person_id: c.sales_person_id,
+            expected_hours: c.expected_hours,
+            planned_hours_before: planned_before
+                .get(&c.sales_person_id)
+                .copied()
+                .unwrap_or(0.0),
+            planned_hours_after: planned.get(&c.sales_person_id).copied().unwrap_or(0.0),
+        })
+        .collect();
+
+    PlannedWeek {
+        assignments,
+        unfilled_slots,
+        hours,
+    }
+}
+
+impl<Deps: ShiftplanGeneratorServiceDeps> ShiftplanGeneratorServiceImpl<Deps> {
+    /// Lädt alle zulässigen Kandidaten: aktiv, nicht gelöscht,
+    /// `is_eligible` für den Schichtplan; inkl. Vertragsstunden, blockierten
+    /// Tagen der Woche und der von wiederkehrenden `Unavailable`-Regeln
+    /// blockierten `slots`. `is_eligible` läuft mit dem Kontext des
+    /// Aufrufers, damit `planner_only`-Zuordnungen wie beim manuellen
+    /// Buchen behandelt werden.
+    async fn load_candidates(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+        slots: &[Slot],
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(Vec<Candidate>, HashSet<Uuid>), ServiceError> {
+        let sales_persons = self
+            .sales_person_service
+            .get_all(Authentication::Full, tx.clone().into())
+            .await?;
+        let work_details = self
+            .employee_work_details_service
+            .all_for_week(week, year, Authentication::Full, tx.clone().into())
+            .await?;
+        let unavailables = self
+            .sales_person_unavailable_service
+            .get_by_week(year, week, Authentication::Full, tx.clone().into())
+            .await?;
+        let monday = time::Date::from_iso_week_date(year as i32, week, time::Weekday::Monday)?;
+        let sunday = monday + time::Duration::days(6);
+        let week_range = shifty_utils::DateRange::new(monday, sunday)
+            .map_err(|_| ServiceError::DateOrderWrong(monday, sunday))?;
+
+        let paid_sales_persons: HashSet<Uuid> = sales_persons
+            .iter()
+            .filter(|sp| sp.is_paid.unwrap_or(false))
+            .map(|sp| sp.id)
+            .collect();
+
+        let mut candidates = Vec::new();
+        for sales_person in sales_persons
+            .iter()
+            .filter(|sp| sp.deleted.is_none() && !sp.inactive)
+        {
+            if !self
+                .sales_person_shiftplan_service
+                .is_eligible(
+                    sales_person.id,
+                    shiftplan_id,
+                    context.clone(),
+                    tx.clone().into(),
+                )
+                .await?
+            {
+                continue;
+            }
+
+            let contracts: Vec<_> = work_details
+                .iter()
+                .filter(|wd| wd.sales_person_id == sales_person.id && wd.deleted.is_none())
+                .collect();
+            let expected_hours = contracts.iter().map(|wd| wd.expected_hours).sum();
+            let workdays = if contracts.is_empty() {
+                None
+            } else {
+                Some(
+                    (1..=7)
+                        .filter_map(DayOfWeek::from_number)
+                        .filter(|day| contracts.iter().any(|wd| wd.has_day_of_week((*day).into())))
+                        .collect(),
+                )
+            };
+
+            let mut blocked_days: BTreeSet<DayOfWeek> = unavailables
+                .iter()
+                .filter(|u| u.sales_person_id == sales_person.id && u.deleted.is_none())
+                .map(|u| u.day_of_week)
+                .collect();
+            // Halbtags-Absences werden wie im Buchungspfad toleriert
+            // (D-08.3-05) und blockieren den Tag nicht.
+            let absences = self
+                .absence_service
+                .find_overlapping_for_booking(
+                    sales_person.id,
+                    week_range,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?;
+            for absence in absences
+                .iter()
+                .filter(|a| a.day_fraction == DayFraction::Full && a.deleted.is_none())
+            {
+                for date in week_range.iter_days() {
+                    if date >= absence.from_date && date <= absence.to_date {
+                        blocked_days.insert(date.weekday().into());
+                    }
+                }
+            }
+
+            let availabilities = self
+                .sales_person_availability_service
+                .get_by_week_for_sales_person(
+                    sales_person.id,
+                    year,
+                    week,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?;
+            let mut blocked_slots = HashSet::new();
+            for slot in slots {
+                let slot_date =
+                    time::Date::from_iso_week_date(year as i32, week, slot.day_of_week.into())?;
+                if availabilities
+                    .iter()
+                    .any(|availability| availability.blocks(slot_date, slot.from, slot.to))
+                {
+                    blocked_slots.insert(slot.id);
+                }
+            }
+
+            candidates.push(Candidate {
+                sales_person_id: sales_person.id,
+                name: sales_person.name.clone(),
+                is_paid: sales_person.is_paid.unwrap_or(false),
+                expected_hours,
+                workdays,
+                blocked_days,
+                blocked_slots,
+            });
+        }
+        Ok((candidates, paid_sales_persons))
+    }
+
+    /// Bucht die Bookings innerhalb von `tx` über den konflikt-aware Pfad und
+    /// sammelt die Warnings (D-Phase3-15: keine De-Dup).
+    async fn book_all(
+        &self,
+        bookings: &[Booking],
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<CopyWeekResult, ServiceError> {
+        let mut persisted = Vec::with_capacity(bookings.len());
+        let mut warnings: Vec<Warning> = Vec::new();
+        for booking in bookings {
+            let target = Booking {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                created: None,
+                deleted: None,
+                created_by: None,
+                deleted_by: None,
+                ..booking.clone()
+            };
+            let result = self
+                .shiftplan_edit_service
+                .book_slot_with_conflict_check(&target, context.clone(), Some(tx.clone()))
+                .await?;
+            persisted.push(result.booking);
+            warnings.extend(result.warnings.iter().cloned());
+        }
+        Ok(CopyWeekResult {
+            copied_bookings: Arc::from(persisted),
+            warnings: Arc::from(warnings),
+        })
+    }
+
+    async fn propose_week_dry_run(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<ShiftplanProposal, ServiceError> {
+        self.permission_service
+            .check_permission("shiftplan.edit", context.clone())
+            .await?;
+
+        let slots = self
+            .slot_service
+            .get_slots_for_week(
+                year,
+                week,
+                shiftplan_id,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        let all_slots: HashMap<Uuid, Slot> = self
+            .slot_service
+            .get_slots_for_week_all_plans(year, week, Authentication::Full, tx.clone().into())
+            .await?
+            .iter()
+            .map(|slot| (slot.id, slot.clone()))
+            .collect();
+        let existing_bookings = self
+            .booking_service
+            .get_for_week(week, year, Authentication::Full, tx.clone().into())
+            .await?;
+        let (candidates, paid_sales_persons) = self
+            .load_candidates(
+                shiftplan_id,
+                year,
+                week,
+                &slots,
+                context.clone(),
+                tx.clone(),
+            )
+            .await?;
+
+        let planned = plan_week(
+            &slots,
+            &all_slots,
+            &existing_bookings,
+            &candidates,
+            &paid_sales_persons,
+        );
+
+        let proposed: Vec<Booking> = planned
+            .assignments
+            .iter()
+            .map(|(sales_person_id, slot_id)| Booking {
+                id: Uuid::nil(),
+                sales_person_id: *sales_person_id,
+                slot_id: *slot_id,
+                calendar_week: week as i32,
+                year,
+                created: None,
+                deleted: None,
+                created_by: None,
+                deleted_by: None,
+                version: Uuid::nil(),
+            })
+            .collect();
+
+        // Trockenlauf: gleicher Pfad wie beim Annehmen, damit die Warnings
+        // exakt dem späteren Ergebnis entsprechen. Der Aufrufer rollt zurück.
+        let dry_run = self.book_all(&proposed, context, tx).await?;
+
+        Ok(ShiftplanProposal {
+            shiftplan_id,
+            year,
+            week,
+            bookings: dry_run.copied_bookings,
+            unfilled_slots: planned.unfilled_slots.into(),
+            hours: planned.hours.into(),
+            warnings: dry_run.warnings,
+        })
+    }
+}
+
+#[async_trait]
+impl<Deps: ShiftplanGeneratorServiceDeps> ShiftplanGeneratorService
+    for ShiftplanGeneratorServiceImpl<Deps>
+{
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn propose_week(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+        context: Authentication<Self::Context>,
+        _tx: Option<Self::Transaction>,
+    ) -> Result<ShiftplanProposal, ServiceError> {
+        // Der Trockenlauf bucht tatsächlich. Er läuft deshalb immer in einer
+        // eigenen Transaktion, die unabhängig vom Ergebnis zurückgerollt wird —
+        // ein Rollback auf einer Transaktion des Aufrufers wäre wirkungslos.
+        let tx = self.transaction_dao.new_transaction().await?;
+        let result = self
+            .propose_week_dry_run(shiftplan_id, year, week, context, tx.clone())
+            .await;
+        self.transaction_dao.rollback(tx).await?;
+        result
+    }
+
+    async fn accept_proposal(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+        bookings: Arc<[Booking]>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<CopyWeekResult, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission("shiftplan.edit", context.clone())
+            .await?;
+
+        let slot_ids: HashSet<Uuid> = self
+            .slot_service
+            .get_slots_for_week(
+                year,
+                week,
+                shiftplan_id,
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?
+            .iter()
+            .map(|slot| slot.id)
+            .collect();
+        let mut validation = Vec::new();
+        for booking in bookings.iter() {
+            if booking.year != year || booking.calendar_week != week as i32 {
+                validation.push(ValidationFailureItem::InvalidValue("calendar_week".into()));
+            }
+            if !slot_ids.contains(&booking.slot_id) {
+                validation.push(ValidationFailureItem::IdDoesNotExist(
+                    "slot_id".into(),
+                    booking.slot_id,
+                ));
+            }
+        }
+        if !validation.is_empty() {
+            return Err(ServiceError::ValidationError(validation.into()));
+        }
+
+        let result = self.book_all(&bookings, context, tx.clone()).await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(result)
+    }
+}
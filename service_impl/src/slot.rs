@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use service::{
+    audit_log::{entity_type, AuditChange},
     permission::{Authentication, SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
     slot::Slot,
     ServiceError, ValidationFailureItem,
@@ -12,27 +13,30 @@ use uuid::Uuid;
 
 const SLOT_SERVICE_PROCESS: &str = "slot-service";
 
-pub struct SlotServiceImpl<SlotDao, PermissionService, ClockService, UuidService, TransactionDao>
+pub struct SlotServiceImpl<SlotDao, PermissionService, ClockService, UuidService, AuditLogService, TransactionDao>
 where
     SlotDao: dao::slot::SlotDao + Send + Sync,
     PermissionService: service::permission::PermissionService + Send + Sync,
     ClockService: service::clock::ClockService + Send + Sync,
     UuidService: service::uuid_service::UuidService + Send + Sync,
+    AuditLogService: service::audit_log::AuditLogService + Send + Sync,
     TransactionDao: dao::TransactionDao + Send + Sync,
 {
     pub slot_dao: Arc<SlotDao>,
     pub permission_service: Arc<PermissionService>,
     pub clock_service: Arc<ClockService>,
     pub uuid_service: Arc<UuidService>,
+    pub audit_log_service: Arc<AuditLogService>,
     pub transaction_dao: Arc<TransactionDao>,
 }
-impl<SlotDao, PermissionService, ClockService, UuidService, TransactionDao>
-    SlotServiceImpl<SlotDao, PermissionService, ClockService, UuidService, TransactionDao>
+impl<SlotDao, PermissionService, ClockService, UuidService, AuditLogService, TransactionDao>
+    SlotServiceImpl<SlotDao, PermissionService, ClockService, UuidService, AuditLogService, TransactionDao>
 where
     SlotDao: dao::slot::SlotDao + Send + Sync,
     PermissionService: service::permission::PermissionService + Send + Sync,
     ClockService: service::clock::ClockService + Send + Sync,
     UuidService: service::uuid_service::UuidService + Send + Sync,
+    AuditLogService: service::audit_log::AuditLogService + Send + Sync,
     TransactionDao: dao::TransactionDao + Send + Sync,
 {
     pub fn new(
@@ -40,6 +44,7 @@ where
         permission_service: Arc<PermissionService>,
         clock_service: Arc<ClockService>,
         uuid_service: Arc<UuidService>,
+        audit_log_service: Arc<AuditLogService>,
         transaction_dao: Arc<TransactionDao>,
     ) -> Self {
         Self {
@@ -47,6 +52,7 @@ where
             permission_service,
             clock_service,
             uuid_service,
+            audit_log_service,
             transaction_dao,
         }
     }
@@ -60,14 +66,19 @@ pub fn test_overlapping_slots(slot_1: &Slot, slot_2: &Slot) -> bool {
 }
 
 #[async_trait]
-impl<SlotDao, PermissionService, ClockService, UuidService, TransactionDao>
+impl<SlotDao, PermissionService, ClockService, UuidService, AuditLogService, TransactionDao>
     service::slot::SlotService
-    for SlotServiceImpl<SlotDao, PermissionService, ClockService, UuidService, TransactionDao>
+    for SlotServiceImpl<SlotDao, PermissionService, ClockService, UuidService, AuditLogService, TransactionDao>
 where
     SlotDao: dao::slot::SlotDao<Transaction = TransactionDao::Transaction> + Send + Sync,
     PermissionService: service::permission::PermissionService + Send + Sync,
     ClockService: service::clock::ClockService + Send + Sync,
     UuidService: service::uuid_service::UuidService + Send + Sync,
+    AuditLogService: service::audit_log::AuditLogService<
+            Context = PermissionService::Context,
+            Transaction = TransactionDao::Transaction,
+        > + Send
+        + Sync,
     TransactionDao: dao::TransactionDao + Send + Sync,
 {
     type Context = PermissionService::Context;
@@ -232,7 +243,7 @@ where
         }
 
         if self
-            .get_slots(context, tx.clone().into())
+            .get_slots(context.clone(), tx.clone().into())
             .await?
             .iter()
             .filter(|s| s.shiftplan_id == slot.shiftplan_id)
@@ -253,6 +264,14 @@ where
         self.slot_dao
             .create_slot(&(&slot).into(), SLOT_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SLOT, slot.id, &slot)?,
+                SLOT_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(slot)
@@ -266,17 +285,26 @@ where
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
         let mut slot = self
             .slot_dao
             .get_slot(id, tx.clone())
             .await?
             .ok_or(ServiceError::EntityNotFound(*id))?;
+        let before = Slot::from(&slot);
         slot.deleted = Some(self.clock_service.date_time_now());
         self.slot_dao
             .update_slot(&slot, SLOT_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::SLOT, slot.id, &before)?,
+                SLOT_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
@@ -290,7 +318,7 @@ where
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
         let persisted_slot = self
             .slot_dao
@@ -344,6 +372,19 @@ where
         self.slot_dao
             .update_slot(&(&slot).into(), SLOT_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::SLOT,
+                    slot.id,
+                    &Slot::from(&persisted_slot),
+                    &slot,
+                )?,
+                SLOT_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
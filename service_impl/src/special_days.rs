@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dao::special_day::SpecialDayEntity;
 use service::{
+    audit_log::{entity_type, AuditChange},
     permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
     special_days::{SpecialDay, SpecialDayType},
     ServiceError, ValidationFailureItem,
@@ -14,33 +15,82 @@ pub struct SpecialDayServiceImpl<
     PermissionService: service::PermissionService,
     ClockService: service::clock::ClockService,
     UuidService: service::uuid_service::UuidService,
+    AuditLogService: service::audit_log::AuditLogService,
+    SalesPersonService: service::sales_person::SalesPersonService,
+    CarryoverService: service::carryover::CarryoverService,
 > {
     special_day_dao: Arc<SpecialDayDao>,
     permission_service: Arc<PermissionService>,
     clock_service: Arc<ClockService>,
     uuid_service: Arc<UuidService>,
+    /// Der Special-Day-DAO arbeitet ohne Transaktion, daher schreibt das
+    /// Protokoll in einer eigenen.
+    audit_log_service: Arc<AuditLogService>,
+    sales_person_service: Arc<SalesPersonService>,
+    /// Feiertage und kurze Tage ändern die Soll-Stunden aller Mitarbeiter.
+    carryover_service: Arc<CarryoverService>,
 }
 
-impl<SpecialDayDao, PermissionService, ClockService, UuidService>
-    SpecialDayServiceImpl<SpecialDayDao, PermissionService, ClockService, UuidService>
+impl<
+        SpecialDayDao,
+        PermissionService,
+        ClockService,
+        UuidService,
+        AuditLogService,
+        SalesPersonService,
+        CarryoverService,
+    >
+    SpecialDayServiceImpl<
+        SpecialDayDao,
+        PermissionService,
+        ClockService,
+        UuidService,
+        AuditLogService,
+        SalesPersonService,
+        CarryoverService,
+    >
 where
     SpecialDayDao: dao::special_day::SpecialDayDao + Sync + Send,
     PermissionService: service::PermissionService + Sync + Send,
     ClockService: service::clock::ClockService + Sync + Send,
     UuidService: service::uuid_service::UuidService + Sync + Send,
+    AuditLogService: service::audit_log::AuditLogService + Sync + Send,
+    SalesPersonService: service::sales_person::SalesPersonService + Sync + Send,
+    CarryoverService: service::carryover::CarryoverService + Sync + Send,
 {
     pub fn new(
         special_day_dao: Arc<SpecialDayDao>,
         permission_service: Arc<PermissionService>,
         clock_service: Arc<ClockService>,
         uuid_service: Arc<UuidService>,
+        audit_log_service: Arc<AuditLogService>,
+        sales_person_service: Arc<SalesPersonService>,
+        carryover_service: Arc<CarryoverService>,
     ) -> Self {
         Self {
             special_day_dao,
             permission_service,
             clock_service,
             uuid_service,
+            audit_log_service,
+            sales_person_service,
+            carryover_service,
+        }
+    }
+
+    /// Markiert den Carryover aller Mitarbeiter für das ISO-Jahr `year` als
+    /// veraltet.
+    async fn mark_year_dirty(&self, year: u32) -> Result<(), ServiceError> {
+        let sales_persons = self
+            .sales_person_service
+            .get_all(Authentication::Full, None)
+            .await?;
+        for sales_person in sales_persons.iter() {
+            self.carryover_service
+                .mark_dirty(sales_person.id, year, Authentication::Full, None)
+                .await?;
         }
+        Ok(())
     }
 }
 
@@ -50,8 +100,21 @@ impl<
         PermissionService: service::PermissionService + Sync + Send,
         ClockService: service::clock::ClockService + Sync + Send,
         UuidService: service::uuid_service::UuidService + Sync + Send,
+        AuditLogService: service::audit_log::AuditLogService<Context = PermissionService::Context>
+            + Sync
+            + Send,
+        SalesPersonService: service::sales_person::SalesPersonService + Sync + Send,
+        CarryoverService: service::carryover::CarryoverService + Sync + Send,
     > service::special_days::SpecialDayService
-    for SpecialDayServiceImpl<SpecialDayDao, PermissionService, ClockService, UuidService>
+    for SpecialDayServiceImpl<
+        SpecialDayDao,
+        PermissionService,
+        ClockService,
+        UuidService,
+        AuditLogService,
+        SalesPersonService,
+        CarryoverService,
+    >
 {
     type Context = PermissionService::Context;
 
@@ -143,7 +206,7 @@ impl<
         context: Authentication<Self::Context>,
     ) -> Result<SpecialDay, ServiceError> {
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         // Server-side input validation (D-33-06 / D-33-07). The backend is the
@@ -214,7 +277,22 @@ impl<
             self.special_day_dao
                 .update(&updated, "special-days-service::replace")
                 .await?;
-            return Ok(SpecialDay::from(&updated));
+            self.mark_year_dirty(updated.year).await?;
+            let replaced = SpecialDay::from(&updated);
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(
+                        entity_type::SPECIAL_DAY,
+                        replaced.id,
+                        &SpecialDay::from(existing_entry),
+                        &replaced,
+                    )?,
+                    "special-days-service::replace",
+                    context,
+                    None,
+                )
+                .await?;
+            return Ok(replaced);
         }
 
         // Create path: stamp `created` from the clock only when actually inserting
@@ -229,7 +307,17 @@ impl<
         self.special_day_dao
             .create(&entity, "special-days-service::create")
             .await?;
-        Ok(SpecialDay::from(&entity))
+        self.mark_year_dirty(entity.year).await?;
+        let created = SpecialDay::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::SPECIAL_DAY, created.id, &created)?,
+                "special-days-service::create",
+                context,
+                None,
+            )
+            .await?;
+        Ok(created)
     }
     async fn delete(
         &self,
@@ -237,7 +325,7 @@ impl<
         context: Authentication<Self::Context>,
     ) -> Result<SpecialDay, ServiceError> {
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         let mut entity = self
@@ -250,12 +338,22 @@ impl<
             return Err(ServiceError::EntityNotFound(special_day_id));
         }
 
+        let before = SpecialDay::from(&entity);
         entity.deleted = Some(self.clock_service.date_time_now());
         entity.version = self.uuid_service.new_uuid("special-day-service::delete version");
 
         self.special_day_dao
             .update(&entity, "special-days-service::delete")
             .await?;
+        self.mark_year_dirty(entity.year).await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::SPECIAL_DAY, special_day_id, &before)?,
+                "special-days-service::delete",
+                context,
+                None,
+            )
+            .await?;
 
         Ok(SpecialDay::from(&entity))
     }
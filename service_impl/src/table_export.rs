@@ -0,0 +1,234 @@
+//! Pure writer for tabular exports (CSV and XLSX).
+//!
+//! Pure module: takes an [`ExportTable`], returns bytes. No I/O, no DAO.
+//! [`crate::report_export`] builds the tables from the reports; this module
+//! only knows about cells and formats.
+//!
+//! # CSV
+//!
+//! - UTF-8 with byte order mark, so spreadsheet applications detect the
+//!   encoding of umlauts in names and headers.
+//! - Delimiter and decimal separator follow the [`ExportLanguage`]: German
+//!   and Czech spreadsheets expect `;` and a decimal comma, English ones `,`
+//!   and a decimal point.
+//! - Numbers are written with two decimals.
+//!
+//! # XLSX
+//!
+//! - One worksheet, bold header row, frozen below the header.
+//! - Numbers stay numeric cells (format `0.00`), so sums and formulas work
+//!   without conversion.
+
+use std::sync::Arc;
+
+use rust_xlsxwriter::{Format, Workbook};
+use service::report_export::{ExportFormat, ExportLanguage};
+use service::ServiceError;
+use tracing::error;
+
+/// Excel limits worksheet names to 31 characters.
+const MAX_SHEET_NAME_LEN: usize = 31;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportCell {
+    Text(Arc<str>),
+    Number(f32),
+    Empty,
+}
+
+impl From<&str> for ExportCell {
+    fn from(value: &str) -> Self {
+        ExportCell::Text(value.into())
+    }
+}
+
+impl From<Arc<str>> for ExportCell {
+    fn from(value: Arc<str>) -> Self {
+        ExportCell::Text(value)
+    }
+}
+
+impl From<f32> for ExportCell {
+    fn from(value: f32) -> Self {
+        ExportCell::Number(value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportTable {
+    pub sheet_name: Arc<str>,
+    pub headers: Vec<Arc<str>>,
+    pub rows: Vec<Vec<ExportCell>>,
+}
+
+pub fn write_table(
+    table: &ExportTable,
+    format: ExportFormat,
+    language: ExportLanguage,
+) -> Result<Vec<u8>, ServiceError> {
+    match format {
+        ExportFormat::Csv => write_csv(table, language),
+        ExportFormat::Xlsx => write_xlsx(table),
+    }
+}
+
+fn csv_delimiter(language: ExportLanguage) -> u8 {
+    match language {
+        ExportLanguage::De | ExportLanguage::Cs => b';',
+        ExportLanguage::En => b',',
+    }
+}
+
+fn format_number(value: f32, language: ExportLanguage) -> String {
+    // NaN/inf from divisions by zero never end up in the file, and values
+    // rounding to zero are written without a minus sign.
+    let value = if value.is_finite() { value } else { 0.0 };
+    let value = (value * 100.0).round() / 100.0 + 0.0;
+    let formatted = format!("{value:.2}");
+    match language {
+        ExportLanguage::De | ExportLanguage::Cs => formatted.replace('.', ","),
+        ExportLanguage::En => formatted,
+    }
+}
+
+pub fn write_csv(table: &ExportTable, language: ExportLanguage) -> Result<Vec<u8>, ServiceError> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(csv_delimiter(language))
+        .from_writer(b"\xEF\xBB\xBF".to_vec());
+    let csv_error = |err: csv::Error| {
+        error!("Failed to write CSV export: {err}");
+        ServiceError::InternalError
+    };
+    writer
+        .write_record(table.headers.iter().map(|header| header.as_bytes()))
+        .map_err(csv_error)?;
+    for row in table.rows.iter() {
+        writer
+            .write_record(row.iter().map(|cell| match cell {
+                ExportCell::Text(text) => text.to_string(),
+                ExportCell::Number(number) => format_number(*number, language),
+                ExportCell::Empty => String::new(),
+            }))
+            .map_err(csv_error)?;
+    }
+    writer.into_inner().map_err(|err| {
+        error!("Failed to flush CSV export: {err}");
+        ServiceError::InternalError
+    })
+}
+
+/// Worksheet names must not contain `[]:*?/\` or exceed 31 characters.
+fn sheet_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            '[' | ']' | ':' | '*' | '?' | '/' | '\\' => '-',
+            c => c,
+        })
+        .take(MAX_SHEET_NAME_LEN)
+        .collect();
+    if name.trim().is_empty() {
+        "Export".into()
+    } else {
+        name
+    }
+}
+
+pub fn write_xlsx(table: &ExportTable) -> Result<Vec<u8>, ServiceError> {
+    let xlsx_error = |err: rust_xlsxwriter::XlsxError| {
+        error!("Failed to write XLSX export: {err}");
+        ServiceError::InternalError
+    };
+    let header_format = Format::new().set_bold();
+    let number_format = Format::new().set_num_format("0.00");
+
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet
+        .set_name(sheet_name(&table.sheet_name))
+        .map_err(xlsx_error)?;
+    for (col, header) in table.headers.iter().enumerate() {
+        worksheet
+            .write_string_with_format(0, col as u16, header.as_ref(), &header_format)
+            .map_err(xlsx_error)?;
+    }
+    for (row_index, row) in table.rows.iter().enumerate() {
+        let row_index = row_index as u32 + 1;
+        for (col, cell) in row.iter().enumerate() {
+            let col = col as u16;
+            match cell {
+                ExportCell::Text(text) => {
+                    worksheet
+                        .write_string(row_index, col, text.as_ref())
+                        .map_err(xlsx_error)?;
+                }
+                ExportCell::Number(number) if number.is_finite() => {
+                    worksheet
+                        .write_number_with_format(row_index, col, *number as f64, &number_format)
+                        .map_err(xlsx_error)?;
+                }
+                ExportCell::Number(_) | ExportCell::Empty => {}
+            }
+        }
+    }
+    worksheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+    worksheet.autofit();
+    workbook.save_to_buffer().map_err(xlsx_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> ExportTable {
+        ExportTable {
+            sheet_name: "Stundenkonto".into(),
+            headers: vec!["Name".into(), "Stundenkonto".into()],
+            rows: vec![
+                vec!["Müller; Anna".into(), 12.5.into()],
+                vec!["Bob".into(), ExportCell::Empty],
+            ],
+        }
+    }
+
+    #[test]
+    fn csv_german_uses_semicolon_and_decimal_comma() {
+        let bytes = write_csv(&table(), ExportLanguage::De).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        assert_eq!(
+            text,
+            "\u{feff}Name;Stundenkonto\n\"Müller; Anna\";12,50\nBob;\n"
+        );
+    }
+
+    #[test]
+    fn csv_english_uses_comma_and_decimal_point() {
+        let bytes = write_csv(&table(), ExportLanguage::En).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        assert_eq!(
+            text,
+            "\u{feff}Name,Stundenkonto\nMüller; Anna,12.50\nBob,\n"
+        );
+    }
+
+    #[test]
+    fn csv_replaces_non_finite_numbers() {
+        assert_eq!(format_number(f32::NAN, ExportLanguage::En), "0.00");
+        assert_eq!(format_number(-1.5, ExportLanguage::Cs), "-1,50");
+        assert_eq!(format_number(-0.0, ExportLanguage::De), "0,00");
+        assert_eq!(format_number(-0.001, ExportLanguage::En), "0.00");
+    }
+
+    #[test]
+    fn xlsx_is_a_zip_container() {
+        let bytes = write_xlsx(&table()).unwrap();
+        assert_eq!(&bytes[..2], b"PK");
+    }
+
+    #[test]
+    fn sheet_name_is_sanitized() {
+        assert_eq!(sheet_name("2026/01: Abrechnung"), "2026-01- Abrechnung");
+        assert_eq!(sheet_name(&"x".repeat(40)).len(), MAX_SHEET_NAME_LEN);
+        assert_eq!(sheet_name(" "), "Export");
+    }
+}
@@ -0,0 +1,295 @@
+//! Implementation von [`service::team_ical::TeamIcalService`].
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use service::{
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction},
+    block::BlockService,
+    booking::BookingService,
+    clock::ClockService,
+    config::ConfigService,
+    ical::{IcalCalendar, IcalEvent, IcalService},
+    permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::SalesPersonService,
+    shiftplan::{ShiftplanSlot, ShiftplanViewService},
+    shiftplan_catalog::ShiftplanService,
+    special_days::{SpecialDay, SpecialDayService, SpecialDayType},
+    team_ical::TeamIcalService,
+    PermissionService, ServiceError,
+};
+use time::{macros::format_description, Date, Duration, PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::ical::sequence_for;
+
+/// Wie der persönliche Feed: zwei Wochen zurück, zwölf Wochen insgesamt.
+const SHIFTPLAN_FEED_PAST_WEEKS: i64 = 2;
+const SHIFTPLAN_FEED_WEEKS: usize = 12;
+/// Urlaube werden weit im Voraus geplant; der Abwesenheits-Feed reicht
+/// deshalb ein Jahr in die Zukunft.
+const ABSENCE_FEED_PAST_DAYS: i64 = 14;
+const ABSENCE_FEED_FUTURE_DAYS: i64 = 365;
+const ABSENCE_FEED_NAME: &str = "Abwesenheiten";
+
+gen_service_impl! {
+    struct TeamIcalServiceImpl: TeamIcalService = TeamIcalServiceDeps {
+        ShiftplanViewService: ShiftplanViewService<Context = Self::Context, Transaction = Self::Transaction> = shiftplan_view_service,
+        ShiftplanService: ShiftplanService<Context = Self::Context, Transaction = Self::Transaction> = shiftplan_service,
+        BlockService: BlockService<Context = Self::Context, Transaction = Self::Transaction> = block_service,
+        BookingService: BookingService<Context = Self::Context, Transaction = Self::Transaction> = booking_service,
+        AbsenceService: AbsenceService<Context = Self::Context, Transaction = Self::Transaction> = absence_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        SpecialDayService: SpecialDayService<Context = Self::Context> = special_day_service,
+        ConfigService: ConfigService = config_service,
+        ClockService: ClockService = clock_service,
+        IcalService: IcalService = ical_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+#[async_trait]
+impl<Deps: TeamIcalServiceDeps> TeamIcalService for TeamIcalServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_shiftplan_as_ical(
+        &self,
+        shiftplan_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .await?;
+        let shiftplan = self
+            .shiftplan_service
+            .get_by_id(shiftplan_id, Authentication::Full, tx.clone().into())
+            .await?;
+        let config = self.config_service.get_config().await?;
+
+        let mut day = self.clock_service.date_now() - Duration::weeks(SHIFTPLAN_FEED_PAST_WEEKS);
+        let mut events = vec![];
+        for _ in 0..SHIFTPLAN_FEED_WEEKS {
+            let (year, week, _) = day.to_iso_week_date();
+            let year = year as u32;
+            let shiftplan_week = self
+                .shiftplan_view_service
+                .get_shiftplan_week(
+                    shiftplan_id,
+                    year,
+                    week,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?;
+            let understaffed_slot_ids: HashSet<Uuid> = self
+                .block_service
+                .get_unsufficiently_booked_blocks(
+                    year,
+                    week,
+                    Authentication::Full,
+                    tx.clone().into(),
+                )
+                .await?
+                .iter()
+                .flat_map(|block| block.slots.iter().map(|slot| slot.id))
+                .collect();
+            let deleted_bookings = self
+                .booking_service
+                .get_deleted_for_week(week, year, Authentication::Full, tx.clone().into())
+                .await?;
+
+            for shiftplan_day in shiftplan_week.days.iter() {
+                let date =
+                    Date::from_iso_week_date(year as i32, week, shiftplan_day.day_of_week.into())?;
+                for shiftplan_slot in shiftplan_day.slots.iter() {
+                    let slot = &shiftplan_slot.slot;
+                    let latest_change = shiftplan_slot
+                        .bookings
+                        .iter()
+                        .filter_map(|booking| booking.booking.created)
+                        .chain(
+                            deleted_bookings
+                                .iter()
+                                .filter(|booking| booking.slot_id == slot.id)
+                                .filter_map(|booking| booking.deleted),
+                        )
+                        .max();
+                    let understaffed = understaffed_slot_ids.contains(&slot.id);
+                    events.push(IcalEvent {
+                        uid: format!("slot-{}-{year}-{week}@shifty", slot.id).into(),
+                        sequence: sequence_for(latest_change),
+                        start: PrimitiveDateTime::new(date, slot.from),
+                        end: PrimitiveDateTime::new(date, shiftplan_slot.effective_to),
+                        all_day: false,
+                        summary: slot_summary(shiftplan_slot, understaffed),
+                        description: understaffed.then(|| {
+                            format!(
+                                "Mindestbesetzung {}, gebucht {}",
+                                slot.min_resources,
+                                shiftplan_slot.bookings.len()
+                            )
+                            .into()
+                        }),
+                        cancelled: false,
+                    });
+                }
+            }
+            day += Duration::weeks(1);
+        }
+
+        let ical = self.ical_service.render_calendar(
+            &IcalCalendar {
+                name: shiftplan.name.clone(),
+                timezone: config.timezone.clone(),
+                events: events.into(),
+            },
+            self.clock_service.date_time_now(),
+        )?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(ical)
+    }
+
+    async fn get_team_absences_as_ical(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<str>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+        let config = self.config_service.get_config().await?;
+        let today = self.clock_service.date_now();
+        let window_start = today - Duration::days(ABSENCE_FEED_PAST_DAYS);
+        let window_end = today + Duration::days(ABSENCE_FEED_FUTURE_DAYS);
+
+        let names: HashMap<Uuid, Arc<str>> = self
+            .sales_person_service
+            .get_all(Authentication::Full, tx.clone().into())
+            .await?
+            .iter()
+            .map(|sales_person| (sales_person.id, sales_person.name.clone()))
+            .collect();
+        let mut events = vec![];
+        for absence in self
+            .absence_service
+            .find_all(Authentication::Full, tx.clone().into())
+            .await?
+            .iter()
+            .filter(|absence| absence.is_effective())
+            .filter(|absence| absence.to_date >= window_start && absence.from_date <= window_end)
+        {
+            let name = names
+                .get(&absence.sales_person_id)
+                .cloned()
+                .unwrap_or_else(|| "?".into());
+            events.push(absence_event(absence, &name));
+        }
+
+        let (first_iso_year, _, _) = window_start.to_iso_week_date();
+        let (last_iso_year, _, _) = window_end.to_iso_week_date();
+        for iso_year in first_iso_year..=last_iso_year {
+            for special_day in self
+                .special_day_service
+                .get_by_iso_year(iso_year as u32, Authentication::Full)
+                .await?
+                .iter()
+                .filter(|special_day| special_day.deleted.is_none())
+            {
+                let date = Date::from_iso_week_date(
+                    special_day.year as i32,
+                    special_day.calendar_week,
+                    special_day.day_of_week.into(),
+                )?;
+                if date >= window_start && date <= window_end {
+                    events.push(special_day_event(special_day, date)?);
+                }
+            }
+        }
+
+        let ical = self.ical_service.render_calendar(
+            &IcalCalendar {
+                name: ABSENCE_FEED_NAME.into(),
+                timezone: config.timezone.clone(),
+                events: events.into(),
+            },
+            self.clock_service.date_time_now(),
+        )?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(ical)
+    }
+}
+
+/// Gebuchte Namen; unterbesetzte Slots tragen vorne die Besetzung.
+fn slot_summary(shiftplan_slot: &ShiftplanSlot, understaffed: bool) -> Arc<str> {
+    let names = shiftplan_slot
+        .bookings
+        .iter()
+        .map(|booking| booking.sales_person.name.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ");
+    match (understaffed, names.is_empty()) {
+        (true, true) => format!("Unterbesetzt 0/{}", shiftplan_slot.slot.min_resources),
+        (true, false) => format!(
+            "Unterbesetzt {}/{}: {names}",
+            shiftplan_slot.bookings.len(),
+            shiftplan_slot.slot.min_resources
+        ),
+        (false, true) => "Offen".to_string(),
+        (false, false) => names,
+    }
+    .into()
+}
+
+fn absence_event(absence: &AbsencePeriod, name: &str) -> IcalEvent {
+    let category = match absence.category {
+        AbsenceCategory::Vacation => "Urlaub",
+        AbsenceCategory::SickLeave => "Krank",
+        AbsenceCategory::UnpaidLeave => "Unbezahlter Urlaub",
+    };
+    let fraction = match absence.day_fraction {
+        DayFraction::Full => "",
+        DayFraction::Half => " (halber Tag)",
+    };
+    IcalEvent {
+        uid: format!("absence-{}@shifty", absence.id).into(),
+        sequence: sequence_for(absence.created),
+        start: PrimitiveDateTime::new(absence.from_date, Time::MIDNIGHT),
+        end: PrimitiveDateTime::new(absence.to_date + Duration::days(1), Time::MIDNIGHT),
+        all_day: true,
+        summary: format!("{name}: {category}{fraction}").into(),
+        description: Some(absence.description.clone())
+            .filter(|description| !description.trim().is_empty()),
+        cancelled: false,
+    }
+}
+
+fn special_day_event(special_day: &SpecialDay, date: Date) -> Result<IcalEvent, ServiceError> {
+    let summary = match (&special_day.day_type, special_day.time_of_day) {
+        (SpecialDayType::Holiday, _) => "Feiertag".to_string(),
+        (SpecialDayType::ShortDay, Some(time_of_day)) => format!(
+            "Kurzer Tag bis {}",
+            time_of_day.format(format_description!("[hour]:[minute]"))?
+        ),
+        (SpecialDayType::ShortDay, None) => "Kurzer Tag".to_string(),
+    };
+    Ok(IcalEvent {
+        uid: format!("special-day-{}@shifty", special_day.id).into(),
+        sequence: sequence_for(special_day.created),
+        start: PrimitiveDateTime::new(date, Time::MIDNIGHT),
+        end: PrimitiveDateTime::new(date + Duration::days(1), Time::MIDNIGHT),
+        all_day: true,
+        summary: summary.into(),
+        description: None,
+        cancelled: false,
+    })
+}
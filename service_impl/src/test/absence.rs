@@ -9,23 +9,37 @@
 //! - Range-Validation auf `from > to` mappt nach `DateOrderWrong` (D-14).
 //! - Optimistic-Lock: stale `version` → `EntityConflicts`.
 //! - `sales_person_id`-Immutability beim Update → `ModificationNotAllowed`.
+//! - Antrags-Workflow: Nicht-HR legt `Requested` an; approve / reject / cancel
+//!   nur aus erlaubten Status, Benachrichtigung erst bei Genehmigung.
 
 use std::sync::Arc;
 
-use dao::absence::{AbsenceCategoryEntity, AbsencePeriodEntity, DayFractionEntity, MockAbsenceDao};
+use dao::absence::{
+    AbsenceCategoryEntity, AbsencePeriodEntity, AbsenceStatusEntity, DayFractionEntity,
+    MockAbsenceDao,
+};
 use dao::MockTransaction;
 use dao::MockTransactionDao;
 use mockall::predicate::{always, eq};
-use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction};
+use service::absence::{
+    AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction,
+};
+use service::audit_log::MockAuditLogService;
+use service::carryover::MockCarryoverService;
 use service::booking::MockBookingService;
 use service::clock::MockClockService;
 use service::employee_work_details::MockEmployeeWorkDetailsService;
-use service::permission::{Authentication, HR_PRIVILEGE};
+use service::notification::MockNotificationService;
+use service::permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE};
 use service::sales_person::MockSalesPersonService;
+use service::sales_person_availability::{
+    AvailabilityKind, MockSalesPersonAvailabilityService, SalesPersonAvailability,
+};
 use service::sales_person_unavailable::MockSalesPersonUnavailableService;
 use service::slot::{MockSlotService, Slot};
 use service::special_days::MockSpecialDayService;
 use service::uuid_service::MockUuidService;
+use service::webhook::MockWebhookService;
 use service::{MockPermissionService, ServiceError, ValidationFailureItem};
 use shifty_utils::DayOfWeek;
 use time::macros::{date, datetime};
@@ -78,6 +92,7 @@ fn default_active_entity() -> AbsencePeriodEntity {
         deleted: None,
         version: default_version(),
         day_fraction: DayFractionEntity::Full,
+        status: AbsenceStatusEntity::Approved,
     }
 }
 
@@ -94,6 +109,7 @@ fn other_logical_active_entity() -> AbsencePeriodEntity {
         deleted: None,
         version: uuid!("CC000000-0000-0000-0000-000000000099"),
         day_fraction: DayFractionEntity::Full,
+        status: AbsenceStatusEntity::Approved,
     }
 }
 
@@ -109,6 +125,7 @@ fn default_create_request() -> AbsencePeriod {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -124,6 +141,7 @@ fn default_update_request() -> AbsencePeriod {
         deleted: None,
         version: default_version(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -161,7 +179,12 @@ pub(crate) struct AbsenceDependencies {
     // Phase-3 Forward-Warning-Loop-Deps (D-Phase3-08):
     pub booking_service: MockBookingService,
     pub sales_person_unavailable_service: MockSalesPersonUnavailableService,
+    pub sales_person_availability_service: MockSalesPersonAvailabilityService,
     pub slot_service: MockSlotService,
+    pub notification_service: MockNotificationService,
+    pub webhook_service: MockWebhookService,
+    pub audit_log_service: MockAuditLogService,
+    pub carryover_service: MockCarryoverService,
 }
 
 impl AbsenceServiceDeps for AbsenceDependencies {
@@ -177,7 +200,12 @@ impl AbsenceServiceDeps for AbsenceDependencies {
     type TransactionDao = MockTransactionDao;
     type BookingService = MockBookingService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = MockSalesPersonAvailabilityService;
     type SlotService = MockSlotService;
+    type NotificationService = MockNotificationService;
+    type WebhookService = MockWebhookService;
+    type AuditLogService = MockAuditLogService;
+    type CarryoverService = MockCarryoverService;
 }
 
 impl AbsenceDependencies {
@@ -193,7 +221,12 @@ impl AbsenceDependencies {
             transaction_dao: self.transaction_dao.into(),
             booking_service: self.booking_service.into(),
             sales_person_unavailable_service: self.sales_person_unavailable_service.into(),
+            sales_person_availability_service: self.sales_person_availability_service.into(),
             slot_service: self.slot_service.into(),
+            notification_service: self.notification_service.into(),
+            webhook_service: self.webhook_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            carryover_service: self.carryover_service.into(),
         }
     }
 }
@@ -233,10 +266,29 @@ pub(crate) fn build_dependencies() -> AbsenceDependencies {
     sales_person_unavailable_service
         .expect_get_all_for_sales_person()
         .returning(|_, _, _| Ok(Arc::from([])));
+    let mut sales_person_availability_service = MockSalesPersonAvailabilityService::new();
+    sales_person_availability_service
+        .expect_get_all_for_sales_person()
+        .returning(|_, _, _| Ok(Arc::from([])));
     let mut slot_service = MockSlotService::new();
     slot_service
         .expect_get_slot()
         .returning(|_, _, _| Ok(default_slot_monday()));
+    let mut notification_service = MockNotificationService::new();
+    notification_service
+        .expect_notify()
+        .returning(|_, _| Ok(()));
+    let mut webhook_service = MockWebhookService::new();
+    webhook_service.expect_publish().returning(|_, _| Ok(()));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .returning(|_, _, _, _| Ok(()));
 
     AbsenceDependencies {
         absence_dao,
@@ -249,7 +301,12 @@ pub(crate) fn build_dependencies() -> AbsenceDependencies {
         transaction_dao,
         booking_service,
         sales_person_unavailable_service,
+        sales_person_availability_service,
         slot_service,
+        notification_service,
+        webhook_service,
+        audit_log_service,
+        carryover_service,
     }
 }
 
@@ -956,6 +1013,114 @@ async fn test_create_warning_for_manual_unavailable_in_range() {
     }
 }
 
+/// Wiederkehrende Regel der Art `kind` am Wochentag `day_of_week`, gültig ab
+/// `valid_from`; `window` ist das optionale Zeitfenster.
+fn fixture_recurring_rule(
+    day_of_week: DayOfWeek,
+    kind: AvailabilityKind,
+    window: Option<(Time, Time)>,
+    valid_from: time::Date,
+) -> SalesPersonAvailability {
+    SalesPersonAvailability {
+        id: Uuid::new_v4(),
+        sales_person_id: default_sales_person_id(),
+        day_of_week,
+        time_from: window.map(|(from, _)| from),
+        time_to: window.map(|(_, to)| to),
+        valid_from,
+        valid_to: None,
+        kind,
+        created: Some(datetime!(2026 - 01 - 01 12:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn deps_for_create_with_rules(rules: Vec<SalesPersonAvailability>) -> AbsenceDependencies {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_overlapping()
+        .returning(|_, _, _, _, _| Ok(Arc::from([])));
+    deps.absence_dao.expect_create().returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| alternate_physical_id());
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_all_for_sales_person()
+        .with(eq(default_sales_person_id()), always(), always())
+        .returning(move |_, _, _| Ok(Arc::from(rules.clone())));
+    deps
+}
+
+#[tokio::test]
+async fn test_create_warning_for_recurring_unavailable_rule_in_range() {
+    // Range So 12.04. – Mi 15.04.2026: die Montags-Teil-Regel (14–18 Uhr)
+    // gilt am 13.04. und wird von der ganztägigen Abwesenheit überdeckt.
+    let rule = fixture_recurring_rule(
+        DayOfWeek::Monday,
+        AvailabilityKind::Unavailable,
+        Some((
+            Time::from_hms(14, 0, 0).unwrap(),
+            Time::from_hms(18, 0, 0).unwrap(),
+        )),
+        date!(2026 - 01 - 01),
+    );
+    let rule_id = rule.id;
+    let service = deps_for_create_with_rules(vec![rule]).build_service();
+
+    let result = service
+        .create(&default_create_request(), Authentication::Full, None)
+        .await
+        .expect("create should succeed");
+
+    assert_eq!(
+        result.warnings.as_ref(),
+        &[Warning::AbsenceOverlapsManualUnavailable {
+            absence_id: alternate_physical_id(),
+            unavailable_id: rule_id,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_create_no_warning_for_recurring_rules_outside_range() {
+    // Freitags-Regel, Montags-Regel erst ab 20.04. und eine Available-Regel
+    // greifen in der Range So 12.04. – Mi 15.04.2026 nicht.
+    let service = deps_for_create_with_rules(vec![
+        fixture_recurring_rule(
+            DayOfWeek::Friday,
+            AvailabilityKind::Unavailable,
+            None,
+            date!(2026 - 01 - 01),
+        ),
+        fixture_recurring_rule(
+            DayOfWeek::Monday,
+            AvailabilityKind::Unavailable,
+            None,
+            date!(2026 - 04 - 20),
+        ),
+        fixture_recurring_rule(
+            DayOfWeek::Tuesday,
+            AvailabilityKind::Available,
+            None,
+            date!(2026 - 01 - 01),
+        ),
+    ])
+    .build_service();
+
+    let result = service
+        .create(&default_create_request(), Authentication::Full, None)
+        .await
+        .expect("create should succeed");
+
+    assert!(
+        result.warnings.is_empty(),
+        "expected no warnings, got {:?}",
+        result.warnings
+    );
+}
+
 #[tokio::test]
 async fn test_update_returns_warnings_for_full_new_range() {
     // D-Phase3-04: update-Warnings für ALLE Tage der NEUEN Range, kein
@@ -1055,6 +1220,373 @@ async fn test_find_overlapping_for_booking_forbidden() {
     test_forbidden(&result);
 }
 
+// =========================================================================
+// Antrags-Workflow (Requested → Approved / Rejected / Cancelled)
+// =========================================================================
+
+fn requested_entity() -> AbsencePeriodEntity {
+    AbsencePeriodEntity {
+        status: AbsenceStatusEntity::Requested,
+        ..default_active_entity()
+    }
+}
+
+/// Nur HR hat die Privilege; der Aufrufer ist der Mitarbeiter selbst.
+fn deny_hr_and_planner(deps: &mut AbsenceDependencies) {
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Err(ServiceError::Forbidden));
+}
+
+fn expect_status_change(deps: &mut AbsenceDependencies, status: AbsenceStatusEntity) {
+    deps.absence_dao
+        .expect_update()
+        .withf(|entity: &AbsencePeriodEntity, _process: &str, _tx| {
+            entity.id == default_physical_id() && entity.deleted.is_some()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    deps.absence_dao
+        .expect_create()
+        .withf(move |entity: &AbsencePeriodEntity, _process: &str, _tx| {
+            entity.id == alternate_physical_id()
+                && entity.logical_id == default_logical_id()
+                && entity.version == alternate_version()
+                && entity.status == status
+                && entity.deleted.is_none()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("absence_service::change_status::id"))
+        .returning(|_| alternate_physical_id());
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("absence_service::change_status::version"))
+        .returning(|_| alternate_version());
+}
+
+#[tokio::test]
+async fn test_create_without_hr_files_request_without_notification() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.absence_dao
+        .expect_find_overlapping()
+        .returning(|_, _, _, _, _| Ok(Arc::from([])));
+    deps.absence_dao
+        .expect_create()
+        .withf(|entity: &AbsencePeriodEntity, _process: &str, _tx| {
+            entity.status == AbsenceStatusEntity::Requested
+        })
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| alternate_physical_id());
+    deps.notification_service.checkpoint();
+    deps.notification_service.expect_notify().never();
+    deps.carryover_service.checkpoint();
+    deps.carryover_service.expect_mark_dirty().never();
+    let service = deps.build_service();
+
+    let result = service
+        .create(&default_create_request(), Authentication::Full, None)
+        .await
+        .expect("employee may file a request");
+    assert_eq!(result.absence.status, AbsenceStatus::Requested);
+}
+
+#[tokio::test]
+async fn test_create_by_hr_is_approved_directly() {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_overlapping()
+        .returning(|_, _, _, _, _| Ok(Arc::from([])));
+    deps.absence_dao
+        .expect_create()
+        .withf(|entity: &AbsencePeriodEntity, _process: &str, _tx| {
+            entity.status == AbsenceStatusEntity::Approved
+        })
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| alternate_physical_id());
+    let service = deps.build_service();
+
+    let result = service
+        .create(&default_create_request(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(result.absence.status, AbsenceStatus::Approved);
+}
+
+#[tokio::test]
+async fn test_approve_requested_period() {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .with(eq(default_logical_id()), always())
+        .returning(|_, _| Ok(Some(requested_entity())));
+    expect_status_change(&mut deps, AbsenceStatusEntity::Approved);
+    deps.notification_service.checkpoint();
+    deps.notification_service
+        .expect_notify()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    deps.carryover_service.checkpoint();
+    deps.carryover_service
+        .expect_mark_dirty()
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .approve(default_logical_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(result.absence.id, default_logical_id());
+    assert_eq!(result.absence.version, alternate_version());
+    assert_eq!(result.absence.status, AbsenceStatus::Approved);
+}
+
+#[tokio::test]
+async fn test_approve_as_shiftplanner_is_allowed() {
+    let mut deps = build_dependencies();
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(SHIFTPLANNER_PRIVILEGE), always())
+        .returning(|_, _| Ok(()));
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(requested_entity())));
+    expect_status_change(&mut deps, AbsenceStatusEntity::Approved);
+    let service = deps.build_service();
+
+    let result = service
+        .approve(default_logical_id(), Authentication::Full, None)
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_approve_without_hr_or_planner_is_forbidden() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    let service = deps.build_service();
+
+    let result = service
+        .approve(default_logical_id(), Authentication::Full, None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_approve_already_approved_is_not_allowed() {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(default_active_entity())));
+    let service = deps.build_service();
+
+    let result = service
+        .approve(default_logical_id(), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("status".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_reject_requested_period() {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(requested_entity())));
+    expect_status_change(&mut deps, AbsenceStatusEntity::Rejected);
+    deps.notification_service.checkpoint();
+    deps.notification_service.expect_notify().never();
+    // Weder vorher noch nachher wirksam → kein Carryover-Recompute.
+    deps.carryover_service.checkpoint();
+    deps.carryover_service.expect_mark_dirty().never();
+    let service = deps.build_service();
+
+    let result = service
+        .reject(default_logical_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(result.status, AbsenceStatus::Rejected);
+}
+
+#[tokio::test]
+async fn test_cancel_own_request() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(requested_entity())));
+    expect_status_change(&mut deps, AbsenceStatusEntity::Cancelled);
+    let service = deps.build_service();
+
+    let result = service
+        .cancel(default_logical_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(result.status, AbsenceStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_cancel_approved_without_hr_is_not_allowed() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(default_active_entity())));
+    let service = deps.build_service();
+
+    let result = service
+        .cancel(default_logical_id(), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("status".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_delete_approved_without_hr_is_not_allowed() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(default_active_entity())));
+    deps.absence_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service
+        .delete(default_logical_id(), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("status".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_delete_own_request_without_hr() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(requested_entity())));
+    deps.absence_dao
+        .expect_update()
+        .withf(|entity: &AbsencePeriodEntity, _process: &str, _tx| {
+            entity.id == default_physical_id() && entity.deleted.is_some()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .delete(default_logical_id(), Authentication::Full, None)
+        .await;
+    assert!(result.is_ok(), "delete should succeed");
+}
+
+#[tokio::test]
+async fn test_cancel_approved_by_hr_marks_carryover_dirty() {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(default_active_entity())));
+    expect_status_change(&mut deps, AbsenceStatusEntity::Cancelled);
+    deps.carryover_service.checkpoint();
+    deps.carryover_service
+        .expect_mark_dirty()
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .cancel(default_logical_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(result.status, AbsenceStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_cancel_other_sales_person_request_is_forbidden() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.sales_person_service.checkpoint();
+    deps.sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(|_, _, _| Err(ServiceError::Forbidden));
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(requested_entity())));
+    let service = deps.build_service();
+
+    let result = service
+        .cancel(default_logical_id(), Authentication::Full, None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_update_rejected_period_is_not_allowed() {
+    let mut deps = build_dependencies();
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| {
+            Ok(Some(AbsencePeriodEntity {
+                status: AbsenceStatusEntity::Rejected,
+                ..default_active_entity()
+            }))
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .update(&default_update_request(), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("status".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_update_approved_without_hr_is_not_allowed() {
+    let mut deps = build_dependencies();
+    deny_hr_and_planner(&mut deps);
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(default_active_entity())));
+    deps.absence_dao.expect_update().never();
+    deps.absence_dao.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .update(&default_update_request(), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("status".into()),
+        1,
+    );
+}
+
 // =========================================================================
 // suggest_convert_ranges_for_markers (Phase 19, Plan 01)
 // =========================================================================
@@ -1078,6 +1610,7 @@ fn suggest_test_work_details() -> service::employee_work_details::EmployeeWorkDe
         is_dynamic: false,
         cap_planned_hours_to_expected: false,
         committed_voluntary: 0.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
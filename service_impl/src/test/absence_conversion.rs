@@ -12,11 +12,15 @@
 
 use std::sync::Arc;
 
-use dao::absence::{AbsenceCategoryEntity, AbsencePeriodEntity, DayFractionEntity, MockAbsenceDao};
+use dao::absence::{
+    AbsenceCategoryEntity, AbsencePeriodEntity, AbsenceStatusEntity, DayFractionEntity,
+    MockAbsenceDao,
+};
 use dao::extra_hours::{ExtraHoursCategoryEntity, ExtraHoursEntity, MockExtraHoursDao};
 use dao::migration_source::MockMigrationSourceDao;
 use dao::{MockTransaction, MockTransactionDao};
 use service::absence_conversion::AbsenceConversionService;
+use service::audit_log::MockAuditLogService;
 use service::extra_hours::MockExtraHoursService;
 use service::permission::Authentication;
 use service::{MockPermissionService, ServiceError, ValidationFailureItem};
@@ -70,6 +74,7 @@ fn make_absence_period_entity(logical_id: Uuid) -> AbsencePeriodEntity {
         deleted: None,
         version: Uuid::new_v4(),
         day_fraction: DayFractionEntity::Full,
+        status: AbsenceStatusEntity::Approved,
     }
 }
 
@@ -83,6 +88,7 @@ pub(crate) struct AbsenceConversionDependencies {
     pub migration_source_dao: MockMigrationSourceDao,
     pub extra_hours_service: MockExtraHoursService,
     pub permission_service: MockPermissionService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -94,6 +100,7 @@ impl AbsenceConversionServiceDeps for AbsenceConversionDependencies {
     type MigrationSourceDao = MockMigrationSourceDao;
     type ExtraHoursService = MockExtraHoursService;
     type PermissionService = MockPermissionService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -107,6 +114,7 @@ impl AbsenceConversionDependencies {
             migration_source_dao: self.migration_source_dao.into(),
             extra_hours_service: self.extra_hours_service.into(),
             permission_service: self.permission_service.into(),
+            audit_log_service: self.audit_log_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -131,12 +139,18 @@ pub(crate) fn build_dependencies() -> AbsenceConversionDependencies {
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     AbsenceConversionDependencies {
         extra_hours_dao,
         absence_dao,
         migration_source_dao,
         extra_hours_service,
         permission_service,
+        audit_log_service,
         transaction_dao,
     }
 }
@@ -415,6 +429,7 @@ mod integration {
 
     use async_trait::async_trait;
     use dao_impl_sqlite::absence::AbsenceDaoImpl;
+    use dao_impl_sqlite::audit_log::AuditLogDaoImpl;
     use dao_impl_sqlite::extra_hours::ExtraHoursDaoImpl;
     use dao_impl_sqlite::migration_source::MigrationSourceDaoImpl;
     use dao_impl_sqlite::{TransactionDaoImpl, TransactionImpl};
@@ -425,6 +440,9 @@ mod integration {
     use uuid::Uuid;
 
     use crate::absence_conversion::{AbsenceConversionServiceDeps, AbsenceConversionServiceImpl};
+    use crate::audit_log::{AuditLogServiceDeps, AuditLogServiceImpl};
+    use crate::clock::ClockServiceImpl;
+    use crate::uuid_service::UuidServiceImpl;
 
     async fn setup_pool() -> Arc<sqlx::SqlitePool> {
         let pool = Arc::new(
@@ -530,6 +548,19 @@ mod integration {
         }
     }
 
+    // Echtes Änderungsprotokoll auf derselben Datenbank.
+    struct AuditLogDeps;
+
+    impl AuditLogServiceDeps for AuditLogDeps {
+        type Context = ();
+        type Transaction = TransactionImpl;
+        type AuditLogDao = AuditLogDaoImpl;
+        type PermissionService = MockPermissionService;
+        type ClockService = ClockServiceImpl;
+        type UuidService = UuidServiceImpl;
+        type TransactionDao = TransactionDaoImpl;
+    }
+
     // Integration-Deps: echte DAO-Impls + Stubs fuer Services
     struct IntegrationDeps;
 
@@ -541,6 +572,7 @@ mod integration {
         type MigrationSourceDao = MigrationSourceDaoImpl;
         type ExtraHoursService = StubExtraHoursService;
         type PermissionService = MockPermissionService;
+        type AuditLogService = AuditLogServiceImpl<AuditLogDeps>;
         type TransactionDao = TransactionDaoImpl;
     }
 
@@ -607,12 +639,25 @@ mod integration {
             .expect_check_permission()
             .returning(|_, _| Ok(()));
 
+        let mut audit_permission_service = MockPermissionService::new();
+        audit_permission_service
+            .expect_current_user_id()
+            .returning(|_| Ok(Some("hr".into())));
+        let audit_log_service = AuditLogServiceImpl::<AuditLogDeps> {
+            audit_log_dao: AuditLogDaoImpl::new(pool.clone()).into(),
+            permission_service: audit_permission_service.into(),
+            clock_service: ClockServiceImpl.into(),
+            uuid_service: UuidServiceImpl.into(),
+            transaction_dao: TransactionDaoImpl::new(pool.clone()).into(),
+        };
+
         let svc = AbsenceConversionServiceImpl::<IntegrationDeps> {
             extra_hours_dao: extra_hours_dao.into(),
             absence_dao: absence_dao.into(),
             migration_source_dao: migration_source_dao_instance.into(),
             extra_hours_service: extra_hours_service.into(),
             permission_service: permission_service.into(),
+            audit_log_service: audit_log_service.into(),
             transaction_dao: tx_dao.into(),
         };
 
@@ -655,5 +700,20 @@ mod integration {
         let found_absence_period_id = Uuid::from_slice(&row.1).expect("parse absence_period_id");
         assert_eq!(found_extra_hours_id, extra_hours_logical_id);
         assert_eq!(found_absence_period_id, period.id);
+
+        // (d) Änderungsprotokoll: Stunden gelöscht, Abwesenheit angelegt.
+        let audit_rows = sqlx::query_as::<_, (String, String, String)>(
+            "SELECT entity_type, action, user_id FROM audit_log ORDER BY entity_type",
+        )
+        .fetch_all(pool.as_ref())
+        .await
+        .expect("query audit log");
+        assert_eq!(
+            audit_rows,
+            vec![
+                ("absence_period".into(), "Create".into(), "hr".into()),
+                ("extra_hours".into(), "Delete".into(), "hr".into()),
+            ]
+        );
     }
 }
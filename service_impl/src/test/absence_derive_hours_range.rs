@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use dao::absence::{AbsenceCategoryEntity, AbsencePeriodEntity, DayFractionEntity};
-use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction, ResolvedAbsence};
+use service::absence::{
+    AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction, ResolvedAbsence,
+};
 use service::permission::Authentication;
 use service::special_days::{SpecialDay, SpecialDayType};
 use shifty_utils::DayOfWeek;
@@ -53,6 +55,7 @@ fn period_to_entity(period: &AbsencePeriod) -> AbsencePeriodEntity {
             DayFraction::Full => DayFractionEntity::Full,
             DayFraction::Half => DayFractionEntity::Half,
         },
+        status: (&period.status).into(),
     }
 }
 
@@ -250,6 +253,7 @@ async fn test_derive_hours_contract_change() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let v2 = AbsencePeriod {
         id: Uuid::from_u128(0x0000_0000_0000_0000_0000_0000_AAAA_0002),
@@ -262,6 +266,7 @@ async fn test_derive_hours_contract_change() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let entities: Arc<[AbsencePeriodEntity]> =
         Arc::from(vec![period_to_entity(&v1), period_to_entity(&v2)]);
@@ -577,6 +582,7 @@ async fn test_lump_sum_vacation_period_with_holiday_emits_short_derived_sum() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let absence_entities: Arc<[AbsencePeriodEntity]> =
         Arc::from(vec![period_to_entity(&vacation)]);
@@ -714,6 +720,7 @@ async fn test_repro_weekly_contract_overcounts_when_workdays_per_week_diverges_f
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let absence_entities: Arc<[AbsencePeriodEntity]> = Arc::from(vec![period_to_entity(&vacation)]);
     deps.absence_dao
@@ -843,6 +850,7 @@ async fn run_vacation_range(
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let absence_entities: Arc<[AbsencePeriodEntity]> =
         Arc::from(vec![period_to_entity(&vacation)]);
@@ -990,6 +998,7 @@ async fn test_multi_week_vacation_counts_only_active_days_per_week() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let absence_entities: Arc<[AbsencePeriodEntity]> =
         Arc::from(vec![period_to_entity(&vacation)]);
@@ -1099,6 +1108,7 @@ async fn test_multi_week_vacation_partial_trailing_week_counts_only_its_active_d
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let absence_entities: Arc<[AbsencePeriodEntity]> =
         Arc::from(vec![period_to_entity(&vacation)]);
@@ -1176,3 +1186,83 @@ async fn test_multi_week_vacation_partial_trailing_week_counts_only_its_active_d
          Beobachtet: {total}h"
     );
 }
+
+#[tokio::test]
+async fn test_derive_hours_ignores_pending_and_rejected_periods() {
+    // Offener Antrag (Vacation) und abgelehnte Krankmeldung fließen nicht in
+    // Stunden ein — nur genehmigte Perioden zählen.
+    let mut deps = build_dependencies();
+
+    let requested = period_to_entity(&AbsencePeriod {
+        status: AbsenceStatus::Requested,
+        ..fixture_vacation_period()
+    });
+    let rejected = period_to_entity(&AbsencePeriod {
+        status: AbsenceStatus::Rejected,
+        ..fixture_sick_period()
+    });
+    let absence_entities: Arc<[AbsencePeriodEntity]> = Arc::from(vec![requested, rejected]);
+    deps.absence_dao
+        .expect_find_by_sales_person()
+        .returning(move |_, _| Ok(absence_entities.clone()));
+    let work_details_arc: Arc<[_]> = Arc::from(vec![fixture_work_details_8h_mon_fri()]);
+    deps.employee_work_details_service
+        .expect_find_by_sales_person_id()
+        .returning(move |_, _, _| Ok(work_details_arc.clone()));
+    deps.special_day_service
+        .expect_get_by_week()
+        .returning(|_, _, _| Ok(Arc::from(Vec::<SpecialDay>::new())));
+    let service = deps.build_service();
+
+    let result = service
+        .derive_hours_for_range(
+            date!(2024 - 06 - 03),
+            date!(2024 - 06 - 09),
+            fixture_sales_person_id(),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .expect("derive_hours_for_range should succeed");
+
+    assert!(result.is_empty(), "only approved periods resolve to hours");
+}
+
+#[tokio::test]
+async fn test_project_request_counts_days_as_if_approved() {
+    // Antrag Mo-Mi, genehmigte Krankmeldung am Di: bei Genehmigung belegt
+    // der Antrag nur Mo + Mi (BUrlG §9), also 2 Urlaubstage.
+    let mut deps = build_dependencies();
+
+    let requested = period_to_entity(&AbsencePeriod {
+        status: AbsenceStatus::Requested,
+        ..fixture_vacation_period()
+    });
+    let sick = period_to_entity(&fixture_sick_period());
+    let by_logical_id = requested.clone();
+    deps.absence_dao
+        .expect_find_by_logical_id()
+        .returning(move |_, _| Ok(Some(by_logical_id.clone())));
+    let absence_entities: Arc<[AbsencePeriodEntity]> = Arc::from(vec![requested, sick]);
+    deps.absence_dao
+        .expect_find_by_sales_person()
+        .returning(move |_, _| Ok(absence_entities.clone()));
+    let work_details_arc: Arc<[_]> = Arc::from(vec![fixture_work_details_8h_mon_fri()]);
+    deps.employee_work_details_service
+        .expect_find_by_sales_person_id()
+        .returning(move |_, _, _| Ok(work_details_arc.clone()));
+    deps.special_day_service
+        .expect_get_by_week()
+        .returning(|_, _, _| Ok(Arc::from(Vec::<SpecialDay>::new())));
+    let service = deps.build_service();
+
+    let projection = service
+        .project_request(fixture_vacation_period().id, Authentication::Full, None)
+        .await
+        .expect("project_request should succeed");
+
+    assert_eq!(projection.absence.status, AbsenceStatus::Requested);
+    assert_eq!(projection.days_by_year.get(&2024), Some(&2.0));
+    assert_eq!(projection.days_by_year.len(), 1);
+    assert!(projection.warnings.is_empty());
+}
@@ -0,0 +1,248 @@
+//! Mock-basierte Tests für `AbsenceRequestServiceImpl`.
+//!
+//! Coverage:
+//! - Permission: HR ∨ Shiftplanner, sonst `Forbidden`.
+//! - Nur `Requested` erscheint; sortiert nach `from_date`.
+//! - Resturlaub nach Genehmigung = `remaining_days` − Antragstage im Jahr.
+//! - HR-only Breakdown der Balance wird für den Shiftplanner entfernt.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::absence::{
+    AbsenceCategory, AbsencePeriod, AbsenceRequestProjection, AbsenceStatus, DayFraction,
+    MockAbsenceService,
+};
+use service::absence_request::AbsenceRequestService;
+use service::permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE};
+use service::vacation_balance::{MockVacationBalanceService, VacationBalance};
+use service::{MockPermissionService, ServiceError};
+use time::macros::{date, datetime};
+use time::Date;
+use uuid::{uuid, Uuid};
+
+use crate::absence_request::{AbsenceRequestServiceDeps, AbsenceRequestServiceImpl};
+use crate::test::error_test::test_forbidden;
+
+fn sales_person_id() -> Uuid {
+    uuid!("BB000000-0000-0000-0000-000000000001")
+}
+
+fn absence(
+    id: Uuid,
+    category: AbsenceCategory,
+    from_date: Date,
+    to_date: Date,
+    status: AbsenceStatus,
+) -> AbsencePeriod {
+    AbsencePeriod {
+        id,
+        sales_person_id: sales_person_id(),
+        category,
+        from_date,
+        to_date,
+        description: "".into(),
+        created: Some(datetime!(2026 - 04 - 01 12:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+        day_fraction: DayFraction::Full,
+        status,
+    }
+}
+
+fn balance() -> VacationBalance {
+    VacationBalance {
+        sales_person_id: sales_person_id(),
+        year: 2026,
+        entitled_days: 25.0,
+        carryover_days: 2,
+        used_days: 5.0,
+        planned_days: 3.0,
+        remaining_days: 19.0,
+        offset_days: Some(1),
+        computed_entitled_days: Some(24.0),
+    }
+}
+
+struct AbsenceRequestDependencies {
+    absence_service: MockAbsenceService,
+    vacation_balance_service: MockVacationBalanceService,
+    permission_service: MockPermissionService,
+}
+
+impl AbsenceRequestServiceDeps for AbsenceRequestDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type AbsenceService = MockAbsenceService;
+    type VacationBalanceService = MockVacationBalanceService;
+    type PermissionService = MockPermissionService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl AbsenceRequestDependencies {
+    fn build_service(self) -> AbsenceRequestServiceImpl<AbsenceRequestDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+        AbsenceRequestServiceImpl {
+            absence_service: self.absence_service.into(),
+            vacation_balance_service: self.vacation_balance_service.into(),
+            permission_service: self.permission_service.into(),
+            transaction_dao: transaction_dao.into(),
+        }
+    }
+}
+
+/// Zwei offene Anträge (Urlaub im Mai, Krankmeldung im April) plus je ein
+/// genehmigter und ein abgelehnter Eintrag, die nicht erscheinen dürfen.
+fn build_dependencies() -> AbsenceRequestDependencies {
+    let vacation_id = uuid!("AB000000-0000-0000-0000-000000000001");
+    let sick_id = uuid!("AB000000-0000-0000-0000-000000000002");
+    let all: Arc<[AbsencePeriod]> = Arc::from(vec![
+        absence(
+            vacation_id,
+            AbsenceCategory::Vacation,
+            date!(2026 - 05 - 04),
+            date!(2026 - 05 - 08),
+            AbsenceStatus::Requested,
+        ),
+        absence(
+            uuid!("AB000000-0000-0000-0000-000000000003"),
+            AbsenceCategory::Vacation,
+            date!(2026 - 03 - 02),
+            date!(2026 - 03 - 03),
+            AbsenceStatus::Approved,
+        ),
+        absence(
+            uuid!("AB000000-0000-0000-0000-000000000004"),
+            AbsenceCategory::Vacation,
+            date!(2026 - 03 - 09),
+            date!(2026 - 03 - 10),
+            AbsenceStatus::Rejected,
+        ),
+        absence(
+            sick_id,
+            AbsenceCategory::SickLeave,
+            date!(2026 - 04 - 20),
+            date!(2026 - 04 - 21),
+            AbsenceStatus::Requested,
+        ),
+    ]);
+
+    let mut absence_service = MockAbsenceService::new();
+    absence_service
+        .expect_find_all()
+        .returning(move |_, _| Ok(all.clone()));
+    absence_service
+        .expect_project_request()
+        .returning(move |id, _, _| {
+            let (absence, days) = if id == vacation_id {
+                (
+                    absence(
+                        id,
+                        AbsenceCategory::Vacation,
+                        date!(2026 - 05 - 04),
+                        date!(2026 - 05 - 08),
+                        AbsenceStatus::Requested,
+                    ),
+                    5.0,
+                )
+            } else {
+                (
+                    absence(
+                        id,
+                        AbsenceCategory::SickLeave,
+                        date!(2026 - 04 - 20),
+                        date!(2026 - 04 - 21),
+                        AbsenceStatus::Requested,
+                    ),
+                    2.0,
+                )
+            };
+            Ok(AbsenceRequestProjection {
+                absence,
+                days_by_year: BTreeMap::from([(2026, days)]),
+                warnings: Arc::from([]),
+            })
+        });
+
+    let mut vacation_balance_service = MockVacationBalanceService::new();
+    vacation_balance_service
+        .expect_get()
+        .with(eq(sales_person_id()), eq(2026), always(), always())
+        .returning(|_, _, _, _| Ok(balance()));
+
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+
+    AbsenceRequestDependencies {
+        absence_service,
+        vacation_balance_service,
+        permission_service,
+    }
+}
+
+#[tokio::test]
+async fn test_find_pending_lists_requests_with_balance_impact() {
+    let service = build_dependencies().build_service();
+
+    let pending = service
+        .find_pending(Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].absence.category, AbsenceCategory::SickLeave);
+    assert_eq!(pending[0].requested_days, 2.0);
+    assert_eq!(pending[0].vacation_balance, None);
+    assert_eq!(pending[0].remaining_days_after, None);
+
+    assert_eq!(pending[1].absence.category, AbsenceCategory::Vacation);
+    assert_eq!(pending[1].requested_days, 5.0);
+    assert_eq!(pending[1].vacation_balance, Some(balance()));
+    assert_eq!(pending[1].remaining_days_after, Some(14.0));
+}
+
+#[tokio::test]
+async fn test_find_pending_hides_hr_breakdown_from_shiftplanner() {
+    let mut deps = build_dependencies();
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(SHIFTPLANNER_PRIVILEGE), always())
+        .returning(|_, _| Ok(()));
+    let service = deps.build_service();
+
+    let pending = service
+        .find_pending(Authentication::Full, None)
+        .await
+        .unwrap();
+
+    let balance = pending[1].vacation_balance.clone().unwrap();
+    assert_eq!(balance.remaining_days, 19.0);
+    assert_eq!(balance.offset_days, None);
+    assert_eq!(balance.computed_entitled_days, None);
+}
+
+#[tokio::test]
+async fn test_find_pending_without_hr_or_planner_is_forbidden() {
+    let mut deps = build_dependencies();
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    let service = deps.build_service();
+
+    let result = service.find_pending(Authentication::Full, None).await;
+    test_forbidden(&result);
+}
@@ -0,0 +1,309 @@
+use std::sync::Arc;
+
+use dao::{
+    audit_log::{AuditAction as AuditActionEntity, AuditLogEntity, AuditLogQuery, MockAuditLogDao},
+    MockTransaction, MockTransactionDao,
+};
+use mockall::predicate::{always, eq};
+use service::{
+    audit_log::{
+        entity_type, scope_real_user, AuditAction, AuditChange, AuditFieldChange, AuditLogFilter,
+        AuditLogService,
+    },
+    clock::MockClockService,
+    permission::{Authentication, HR_PRIVILEGE},
+    uuid_service::MockUuidService,
+    MockPermissionService, ServiceError,
+};
+use time::macros::{date, datetime};
+use uuid::{uuid, Uuid};
+
+use crate::audit_log::{AuditLogServiceDeps, AuditLogServiceImpl};
+use crate::test::error_test::{test_date_order_wrong, test_forbidden, NoneTypeExt};
+
+fn default_id() -> Uuid {
+    uuid!("5B0C1E9A-3F7D-4C21-9E55-0A6B7C8D9E01")
+}
+
+fn default_entity() -> AuditLogEntity {
+    AuditLogEntity {
+        id: default_id(),
+        entity_type: entity_type::SLOT.into(),
+        entity_id: "slot-1".into(),
+        action: AuditActionEntity::Update,
+        user_id: Some("planner".into()),
+        real_user_id: None,
+        process: "slot-service".into(),
+        before: Some(r#"{"min_resources":1,"pause":false}"#.into()),
+        after: Some(r#"{"min_resources":2,"pause":false}"#.into()),
+        created: datetime!(2026-10-17 09:30:00),
+    }
+}
+
+fn slot_change() -> AuditChange {
+    AuditChange {
+        entity_type: entity_type::SLOT,
+        entity_id: "slot-1".into(),
+        action: AuditAction::Update,
+        before: Some(r#"{"min_resources":1}"#.into()),
+        after: Some(r#"{"min_resources":2}"#.into()),
+    }
+}
+
+pub struct AuditLogServiceDependencies {
+    pub audit_log_dao: MockAuditLogDao,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+}
+
+impl AuditLogServiceDeps for AuditLogServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type AuditLogDao = MockAuditLogDao;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl AuditLogServiceDependencies {
+    pub fn build_service(self) -> AuditLogServiceImpl<AuditLogServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        AuditLogServiceImpl {
+            audit_log_dao: self.audit_log_dao.into(),
+            permission_service: self.permission_service.into(),
+            clock_service: self.clock_service.into(),
+            uuid_service: self.uuid_service.into(),
+            transaction_dao: transaction_dao.into(),
+        }
+    }
+}
+
+/// `user` ist der Benutzer des Contexts, `privileges` die gewährten Rechte.
+fn build_dependencies(
+    user: Option<&'static str>,
+    privileges: &'static [&'static str],
+) -> AuditLogServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_current_user_id()
+        .returning(move |_| Ok(user.map(Arc::from)));
+    permission_service
+        .expect_check_permission()
+        .returning(move |privilege, _| {
+            if privileges.contains(&privilege) {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_time_now()
+        .returning(|| datetime!(2026-10-17 09:30:00));
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| default_id());
+
+    AuditLogServiceDependencies {
+        audit_log_dao: MockAuditLogDao::new(),
+        permission_service,
+        clock_service,
+        uuid_service,
+    }
+}
+
+#[tokio::test]
+async fn test_record_stores_user_and_snapshots() {
+    let mut deps = build_dependencies(Some("planner"), &[]);
+    deps.audit_log_dao
+        .expect_create()
+        .with(
+            eq(AuditLogEntity {
+                before: Some(r#"{"min_resources":1}"#.into()),
+                after: Some(r#"{"min_resources":2}"#.into()),
+                ..default_entity()
+            }),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .record(&slot_change(), "slot-service", ().auth(), None)
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_record_impersonation_stores_real_user() {
+    let mut deps = build_dependencies(Some("planner"), &[]);
+    deps.audit_log_dao
+        .expect_create()
+        .withf(|entity, _| {
+            entity.user_id.as_deref() == Some("planner")
+                && entity.real_user_id.as_deref() == Some("admin")
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = scope_real_user(
+        "admin".into(),
+        service.record(&slot_change(), "slot-service", ().auth(), None),
+    )
+    .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_record_without_impersonation_has_no_real_user() {
+    let mut deps = build_dependencies(Some("planner"), &[]);
+    deps.audit_log_dao
+        .expect_create()
+        .withf(|entity, _| entity.real_user_id.is_none())
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = scope_real_user(
+        "planner".into(),
+        service.record(&slot_change(), "slot-service", ().auth(), None),
+    )
+    .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_record_system_change_has_no_user() {
+    let mut deps = build_dependencies(None, &[]);
+    deps.audit_log_dao
+        .expect_create()
+        .withf(|entity, _| entity.user_id.is_none() && entity.real_user_id.is_none())
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .record(&slot_change(), "slot-service", Authentication::Full, None)
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_find_forbidden() {
+    let service = build_dependencies(Some("sales"), &["sales"]).build_service();
+
+    let result = service
+        .find(&AuditLogFilter::default(), ().auth(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_find_as_admin_computes_changes() {
+    let mut deps = build_dependencies(Some("admin"), &["admin"]);
+    deps.audit_log_dao
+        .expect_find()
+        .returning(|_, _| Ok(Arc::new([default_entity()])));
+    let service = deps.build_service();
+
+    let result = service
+        .find(&AuditLogFilter::default(), ().auth(), None)
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].action, AuditAction::Update);
+    assert_eq!(
+        result[0].changes.as_ref(),
+        &[AuditFieldChange {
+            field: "min_resources".into(),
+            before: Some("1".into()),
+            after: Some("2".into()),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_find_maps_filter_to_query() {
+    let mut deps = build_dependencies(Some("hr"), &[HR_PRIVILEGE]);
+    deps.audit_log_dao
+        .expect_find()
+        .with(
+            eq(AuditLogQuery {
+                entity_type: Some(entity_type::SLOT.into()),
+                entity_id: Some("slot-1".into()),
+                user_id: Some("planner".into()),
+                from: Some(datetime!(2026-10-01 00:00:00)),
+                to: Some(datetime!(2026-10-18 00:00:00)),
+                limit: 200,
+            }),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _| Ok(Arc::new([])));
+    let service = deps.build_service();
+
+    let result = service
+        .find(
+            &AuditLogFilter {
+                entity_type: Some(entity_type::SLOT.into()),
+                entity_id: Some("slot-1".into()),
+                user_id: Some("planner".into()),
+                from: Some(date!(2026 - 10 - 01)),
+                to: Some(date!(2026 - 10 - 17)),
+                limit: 0,
+            },
+            ().auth(),
+            None,
+        )
+        .await;
+    assert!(result.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_find_caps_limit() {
+    let mut deps = build_dependencies(Some("hr"), &[HR_PRIVILEGE]);
+    deps.audit_log_dao
+        .expect_find()
+        .withf(|query, _| query.limit == 1000)
+        .times(1)
+        .returning(|_, _| Ok(Arc::new([])));
+    let service = deps.build_service();
+
+    let result = service
+        .find(
+            &AuditLogFilter {
+                limit: 50_000,
+                ..Default::default()
+            },
+            ().auth(),
+            None,
+        )
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_find_date_order_wrong() {
+    let service = build_dependencies(Some("hr"), &[HR_PRIVILEGE]).build_service();
+
+    let result = service
+        .find(
+            &AuditLogFilter {
+                from: Some(date!(2026 - 10 - 17)),
+                to: Some(date!(2026 - 10 - 01)),
+                ..Default::default()
+            },
+            ().auth(),
+            None,
+        )
+        .await;
+    test_date_order_wrong(&result);
+}
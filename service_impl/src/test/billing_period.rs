@@ -17,6 +17,8 @@ struct MockDeps {
     permission_service: service::MockPermissionService,
     uuid_service: service::uuid_service::MockUuidService,
     clock_service: service::clock::MockClockService,
+    webhook_service: service::webhook::MockWebhookService,
+    audit_log_service: service::audit_log::MockAuditLogService,
     transaction_dao: dao::MockTransactionDao,
 }
 
@@ -30,11 +32,19 @@ impl BillingPeriodServiceDeps for MockDeps {
     type PermissionService = service::MockPermissionService;
     type UuidService = service::uuid_service::MockUuidService;
     type ClockService = service::clock::MockClockService;
+    type WebhookService = service::webhook::MockWebhookService;
+    type AuditLogService = service::audit_log::MockAuditLogService;
     type TransactionDao = dao::MockTransactionDao;
 }
 
 impl MockDeps {
     fn new() -> Self {
+        let mut webhook_service = service::webhook::MockWebhookService::new();
+        webhook_service.expect_publish().returning(|_, _| Ok(()));
+        let mut audit_log_service = service::audit_log::MockAuditLogService::new();
+        audit_log_service
+            .expect_record()
+            .returning(|_, _, _, _| Ok(()));
         Self {
             billing_period_dao: dao::billing_period::MockBillingPeriodDao::new(),
             billing_period_sales_person_dao:
@@ -43,6 +53,8 @@ impl MockDeps {
             permission_service: service::MockPermissionService::new(),
             uuid_service: service::uuid_service::MockUuidService::new(),
             clock_service: service::clock::MockClockService::new(),
+            webhook_service,
+            audit_log_service,
             transaction_dao: dao::MockTransactionDao::new(),
         }
     }
@@ -55,6 +67,8 @@ impl MockDeps {
             permission_service: self.permission_service.into(),
             uuid_service: self.uuid_service.into(),
             clock_service: self.clock_service.into(),
+            webhook_service: self.webhook_service.into(),
+            audit_log_service: self.audit_log_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -135,6 +149,20 @@ async fn test_delete_billing_period_success() {
         .times(1)
         .returning(|_| Ok(Some("test_user".into())));
 
+    deps.billing_period_sales_person_dao
+        .expect_all()
+        .returning(|_| Ok(Arc::new([])));
+    deps.audit_log_service.checkpoint();
+    deps.audit_log_service
+        .expect_record()
+        .withf(move |change, _, _, _| {
+            change.entity_type == service::audit_log::entity_type::BILLING_PERIOD
+                && change.entity_id.as_ref() == latest_id.to_string()
+                && change.action == service::audit_log::AuditAction::Delete
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
     deps.billing_period_sales_person_dao
         .expect_delete_by_billing_period_id()
         .with(eq(latest_id), eq("test_user"), always())
@@ -319,6 +347,10 @@ async fn test_delete_billing_period_cascades_sales_person_entries() {
         .times(1)
         .returning(|_| Ok(Some("test_user".into())));
 
+    deps.billing_period_sales_person_dao
+        .expect_all()
+        .returning(|_| Ok(Arc::new([])));
+
     // Verify cascade: delete_by_billing_period_id is called BEFORE delete_by_id
     let mut seq = mockall::Sequence::new();
 
@@ -350,6 +382,68 @@ async fn test_delete_billing_period_cascades_sales_person_entries() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_clear_all_billing_periods_records_each_period() {
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+    let context = Authentication::Full;
+
+    let mut deps = MockDeps::new();
+    setup_common_mocks(&mut deps);
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+    deps.permission_service
+        .expect_current_user_id()
+        .returning(|_| Ok(Some("test_user".into())));
+    let all_entities: Arc<[dao::billing_period::BillingPeriodEntity]> = vec![
+        make_billing_period_entity(
+            first_id,
+            time::macros::date!(2025 - 01 - 01),
+            time::macros::date!(2025 - 03 - 31),
+        ),
+        make_billing_period_entity(
+            second_id,
+            time::macros::date!(2025 - 04 - 01),
+            time::macros::date!(2025 - 06 - 30),
+        ),
+    ]
+    .into();
+    deps.billing_period_dao
+        .expect_all()
+        .returning(move |_| Ok(all_entities.clone()));
+    deps.billing_period_sales_person_dao
+        .expect_all()
+        .returning(|_| Ok(Arc::new([])));
+    deps.billing_period_sales_person_dao
+        .expect_clear_all()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    deps.billing_period_dao
+        .expect_clear_all()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    deps.transaction_dao
+        .expect_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+    deps.audit_log_service.checkpoint();
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == service::audit_log::entity_type::BILLING_PERIOD
+                && change.action == service::audit_log::AuditAction::Delete
+        })
+        .times(2)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = deps.build_service();
+    service
+        .clear_all_billing_periods(context, None)
+        .await
+        .unwrap();
+}
+
 /// Phase-2 SC-5 (D-Phase2-05): v2-Snapshots ohne `unpaid_leave`-Zeile bleiben
 /// nach dem Schema-Bump 2->3 lesbar. `BillingPeriodSalesPerson::from_entities`
 /// ignoriert fehlende `value_type`s — der Caller bekommt
@@ -3,12 +3,12 @@ use std::sync::Arc;
 
 use mockall::predicate::{self, *};
 use service::billing_period::{BillingPeriod, BillingPeriodSalesPerson, BillingPeriodValue, BillingPeriodValueType};
-use service::billing_period_report::BillingPeriodReportService;
+use service::billing_period_report::{BillingPeriodReportService, BILLING_PERIOD_PDF_TEMPLATE_TYPE};
 use service::employee_work_details::EmployeeWorkDetails;
 use service::permission::Authentication;
 use service::sales_person::SalesPerson;
 use service::text_template::TextTemplate;
-use service::ServiceError;
+use service::{ServiceError, ValidationFailureItem};
 use time::macros::datetime;
 use uuid::Uuid;
 
@@ -94,6 +94,7 @@ fn create_test_sales_persons() -> Arc<[SalesPerson]> {
             background_color: "#ff0000".into(),
             is_paid: Some(true),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::new_v4(),
         },
@@ -103,6 +104,7 @@ fn create_test_sales_persons() -> Arc<[SalesPerson]> {
             background_color: "#00ff00".into(),
             is_paid: Some(false),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::new_v4(),
         },
@@ -124,6 +126,7 @@ fn create_test_work_details(sales_person_id: Uuid, is_dynamic: bool) -> Employee
         is_dynamic,
         cap_planned_hours_to_expected: false,
         committed_voluntary: 0.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -362,6 +365,23 @@ fn setup_generate_report_mocks_with_work_details(
     billing_period: BillingPeriod,
     work_details: Arc<[EmployeeWorkDetails]>,
 ) -> BillingPeriodReportServiceImpl<MockDeps> {
+    let mut deps = setup_generate_report_deps(template, billing_period, work_details);
+
+    deps.transaction_dao
+        .expect_commit()
+        .with(always())
+        .times(1)
+        .returning(|_| Ok(()));
+
+    deps.build_service()
+}
+
+/// Mocks for loading template and billing period, without the final commit.
+fn setup_generate_report_deps(
+    template: TextTemplate,
+    billing_period: BillingPeriod,
+    work_details: Arc<[EmployeeWorkDetails]>,
+) -> MockDeps {
     let template_id = template.id;
     let billing_period_id = billing_period.id;
     let context = Authentication::Full;
@@ -404,13 +424,7 @@ fn setup_generate_report_mocks_with_work_details(
 
     setup_enrichment_mocks(&mut deps, work_details);
 
-    deps.transaction_dao
-        .expect_commit()
-        .with(always())
-        .times(1)
-        .returning(|_| Ok(()));
-
-    deps.build_service()
+    deps
 }
 
 #[tokio::test]
@@ -730,6 +744,115 @@ async fn test_minijinja_dict_literal() {
     assert!(report.contains("Bob: 140"));
 }
 
+fn create_pdf_template(template_text: &str, engine: service::text_template::TemplateEngine) -> TextTemplate {
+    TextTemplate {
+        name: Some("Quartal & Lohn".into()),
+        template_type: BILLING_PERIOD_PDF_TEMPLATE_TYPE.into(),
+        ..create_test_text_template_with_engine(template_text, engine)
+    }
+}
+
+fn pdf_contains(content: &[u8], text: &str) -> bool {
+    let hex: String = text.bytes().map(|b| format!("{b:02X}")).collect();
+    String::from_utf8_lossy(content).contains(&hex)
+}
+
+#[tokio::test]
+async fn test_generate_custom_report_pdf_success() {
+    let template = create_pdf_template(
+        r#"<document size="A4" orientation="landscape" title="Abrechnung">
+  <header>Abrechnung {{ billing_period.start_date }}</header>
+  <table>{% for person in billing_period.sales_persons %}
+    <tr><td>{{ person.name }}</td><td align="right">{{ person.values_map.overall.delta }}</td></tr>{% endfor %}
+  </table>
+</document>"#,
+        service::text_template::TemplateEngine::Tera,
+    );
+    let billing_period = create_enriched_billing_period();
+    let template_id = template.id;
+    let billing_period_id = billing_period.id;
+
+    let service = setup_generate_report_mocks(template, billing_period);
+
+    let file = service
+        .generate_custom_report_pdf(template_id, billing_period_id, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert_eq!(file.content_type, "application/pdf");
+    assert_eq!(
+        file.file_name.as_ref(),
+        "billing-period-2024-07-15-2024-08-14-quartal-lohn.pdf"
+    );
+    assert!(file.content.starts_with(b"%PDF-"));
+    assert!(pdf_contains(&file.content, "Abrechnung 2024-07-15"));
+    assert!(pdf_contains(&file.content, "Natalie"));
+    assert!(pdf_contains(&file.content, "160"));
+}
+
+#[tokio::test]
+async fn test_generate_custom_report_pdf_escapes_inserted_values() {
+    let template = create_pdf_template(
+        r#"<document><p>{{ "<b>Müller & Söhne</b>" }}</p></document>"#,
+        service::text_template::TemplateEngine::MiniJinja,
+    );
+    let billing_period = create_test_billing_period();
+    let template_id = template.id;
+    let billing_period_id = billing_period.id;
+
+    let service = setup_generate_report_mocks(template, billing_period);
+
+    let file = service
+        .generate_custom_report_pdf(template_id, billing_period_id, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert!(pdf_contains(&file.content, "<b>M"));
+}
+
+#[tokio::test]
+async fn test_generate_custom_report_pdf_rejects_text_template_type() {
+    let template = create_test_text_template("<document></document>");
+    let billing_period = create_test_billing_period();
+    let template_id = template.id;
+    let billing_period_id = billing_period.id;
+
+    let service = setup_generate_report_deps(template, billing_period, default_work_details())
+        .build_service();
+
+    let result = service
+        .generate_custom_report_pdf(template_id, billing_period_id, Authentication::Full, None)
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_generate_custom_report_pdf_reports_layout_position() {
+    let template = create_pdf_template(
+        "<document>\n  <div>{{ billing_period.id }}</div>\n</document>",
+        service::text_template::TemplateEngine::Tera,
+    );
+    let billing_period = create_test_billing_period();
+    let template_id = template.id;
+    let billing_period_id = billing_period.id;
+
+    let service = setup_generate_report_deps(template, billing_period, default_work_details())
+        .build_service();
+
+    let result = service
+        .generate_custom_report_pdf(template_id, billing_period_id, Authentication::Full, None)
+        .await;
+
+    let Err(ServiceError::ValidationError(items)) = result else {
+        panic!("expected validation error, got {result:?}");
+    };
+    assert!(matches!(
+        &items[0],
+        ValidationFailureItem::InvalidValue(message) if message.contains("Zeile 2, Spalte 3")
+    ));
+}
+
 #[tokio::test]
 async fn test_same_context_both_engines() {
     // Tera version
@@ -1210,6 +1333,7 @@ async fn test_snapshot_v3_pinned_values() {
         background_color: "#000000".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     };
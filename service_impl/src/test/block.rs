@@ -7,16 +7,17 @@ use service::block::BlockService;
 use service::booking::Booking;
 use service::clock::MockClockService;
 use service::config::MockConfigService;
-use service::ical::MockIcalService;
+use service::ical::{IcalCalendar, MockIcalService};
 use service::sales_person::MockSalesPersonService;
 use service::shiftplan::MockShiftplanViewService;
 use service::slot::{MockSlotService, Slot};
 use service::special_days::{MockSpecialDayService, SpecialDay, SpecialDayType};
 use service::toggle::MockToggleService;
-use service::ServiceError;
+use service::week_message::{MockWeekMessageService, WeekMessage};
+use service::{MockPermissionService, ServiceError};
 use service::{booking::MockBookingService, sales_person::SalesPerson};
 use shifty_utils::DayOfWeek;
-use time::macros::date;
+use time::macros::{date, datetime};
 use time::{Date, Month, PrimitiveDateTime, Time};
 use uuid::{uuid, Uuid};
 
@@ -36,6 +37,8 @@ pub struct BlockServiceDependencies {
     // für pro-Slot-Clip vor Block-Merge.
     pub special_day_service: MockSpecialDayService,
     pub toggle_service: MockToggleService,
+    pub week_message_service: MockWeekMessageService,
+    pub permission_service: MockPermissionService,
 }
 
 impl crate::block::BlockServiceDeps for BlockServiceDependencies {
@@ -51,8 +54,8 @@ impl crate::block::BlockServiceDeps for BlockServiceDependencies {
     type ShiftplanViewService = MockShiftplanViewService;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
-    // If you also want to enforce permission checks here, you can add:
-    // type PermissionService = MockPermissionService;
+    type WeekMessageService = MockWeekMessageService;
+    type PermissionService = MockPermissionService;
 }
 
 impl BlockServiceDependencies {
@@ -69,6 +72,8 @@ impl BlockServiceDependencies {
             shiftplan_service: self.shiftplan_service.into(),
             special_day_service: self.special_day_service.into(),
             toggle_service: self.toggle_service.into(),
+            week_message_service: self.week_message_service.into(),
+            permission_service: self.permission_service.into(),
         }
     }
 }
@@ -98,6 +103,7 @@ pub fn default_sales_person() -> SalesPerson {
         background_color: "#FFFFFF".into(),
         is_paid: None,
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -211,6 +217,9 @@ pub fn build_dependencies() -> BlockServiceDependencies {
         Ok(service::config::Config {
             timezone: Arc::from("UTC"),
             ical_label: Arc::from("Shift"),
+            app_url: Arc::from("http://localhost:3000"),
+            datev_consultant_number: None,
+            datev_client_number: None,
         })
     });
 
@@ -226,6 +235,11 @@ pub fn build_dependencies() -> BlockServiceDependencies {
         .expect_get_toggle_value()
         .returning(|_, _, _| Ok(None));
 
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+
     BlockServiceDependencies {
         booking_service,
         slot_service,
@@ -237,6 +251,8 @@ pub fn build_dependencies() -> BlockServiceDependencies {
         config_service,
         special_day_service,
         toggle_service,
+        week_message_service: MockWeekMessageService::new(),
+        permission_service,
     }
 }
 
@@ -1043,3 +1059,215 @@ async fn test_get_blocks_tolerates_toggle_unauthorized() {
         "Slot.to unverändert (roh, ungeclippt)"
     );
 }
+
+// iCal-Feed: Events mit Kolleg:innen, Wochennachricht und Absagen.
+
+fn colleague_id() -> Uuid {
+    uuid!("0f6f1c1e-6f3a-4f55-9d0e-2b4b3c7d2e11")
+}
+
+fn colleague_booking() -> Booking {
+    Booking {
+        id: uuid!("5b0e7f3c-6d7e-4b8a-9c1d-2e3f4a5b6c7d"),
+        sales_person_id: colleague_id(),
+        ..default_booking()
+    }
+}
+
+fn deleted_second_booking() -> Booking {
+    Booking {
+        deleted: Some(PrimitiveDateTime::new(
+            Date::from_calendar_date(2025, Month::January, 2).unwrap(),
+            Time::from_hms(8, 0, 0).unwrap(),
+        )),
+        ..second_booking()
+    }
+}
+
+/// Abhängigkeiten für `get_blocks_for_next_weeks_as_ical`; Buchungen gibt es
+/// nur in KW 3/2025. Das an `render_calendar` übergebene Kalendermodell
+/// landet in `captured`.
+fn build_ical_dependencies(
+    active: Vec<Booking>,
+    deleted: Vec<Booking>,
+    captured: Arc<std::sync::Mutex<Option<IcalCalendar>>>,
+) -> BlockServiceDependencies {
+    let mut deps = build_dependencies();
+    deps.booking_service
+        .expect_get_for_week()
+        .returning(move |week, year, _, _| {
+            if (week, year) == (3, 2025) {
+                Ok(active.clone().into())
+            } else {
+                Ok(Arc::new([]))
+            }
+        });
+    deps.booking_service
+        .expect_get_deleted_for_week()
+        .returning(move |week, year, _, _| {
+            if (week, year) == (3, 2025) {
+                Ok(deleted.clone().into())
+            } else {
+                Ok(Arc::new([]))
+            }
+        });
+    deps.slot_service.expect_get_slot().returning(|slot_id, _, _| {
+        if *slot_id == default_slot_id() {
+            Ok(default_slot())
+        } else {
+            Ok(second_slot())
+        }
+    });
+    deps.sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(|_, _, _| Ok(()));
+    deps.sales_person_service.expect_get_all().returning(|_, _| {
+        Ok(Arc::new([
+            default_sales_person(),
+            SalesPerson {
+                id: colleague_id(),
+                name: "Bea".into(),
+                ..default_sales_person()
+            },
+        ]))
+    });
+    deps.week_message_service
+        .expect_get_by_year_and_week()
+        .returning(|year, week, _, _| {
+            Ok(((year, week) == (2025, 3)).then(|| WeekMessage {
+                id: Uuid::nil(),
+                year,
+                calendar_week: week,
+                message: "Inventur am Freitag".into(),
+                created: None,
+                deleted: None,
+                version: Uuid::nil(),
+            }))
+        });
+    deps.clock_service
+        .expect_date_time_now()
+        .returning(|| datetime!(2025-01-01 12:00:00));
+    deps.ical_service
+        .expect_render_calendar()
+        .returning(move |calendar, _| {
+            *captured.lock().unwrap() = Some(calendar.clone());
+            Ok("BEGIN:VCALENDAR".into())
+        });
+    deps
+}
+
+#[tokio::test]
+async fn test_ical_feed_event_with_colleagues_and_week_message() {
+    let captured = Arc::new(std::sync::Mutex::new(None));
+    let deps = build_ical_dependencies(
+        vec![default_booking(), colleague_booking()],
+        vec![],
+        captured.clone(),
+    );
+    let service = deps.build_service();
+
+    service
+        .get_blocks_for_next_weeks_as_ical(default_sales_person_id(), ().auth(), None)
+        .await
+        .unwrap();
+
+    let calendar = captured.lock().unwrap().clone().unwrap();
+    assert_eq!(calendar.timezone.as_ref(), "UTC");
+    assert_eq!(calendar.events.len(), 1);
+    let event = &calendar.events[0];
+    assert_eq!(
+        event.uid.as_ref(),
+        format!("{}@shifty", default_booking_id())
+    );
+    assert_eq!(event.start, datetime!(2025-01-13 09:00:00));
+    assert_eq!(event.end, datetime!(2025-01-13 10:00:00));
+    assert_eq!(event.summary.as_ref(), "Shift");
+    assert_eq!(
+        event.description.as_deref(),
+        Some("Kolleg:innen: Bea\nWochennachricht: Inventur am Freitag")
+    );
+    assert!(!event.cancelled);
+    // Sekunden zwischen 2020-01-01 und dem Anlegen der Buchung.
+    assert_eq!(event.sequence, 157_852_800);
+}
+
+#[tokio::test]
+async fn test_ical_feed_cancels_deleted_booking() {
+    let captured = Arc::new(std::sync::Mutex::new(None));
+    let deps = build_ical_dependencies(
+        vec![default_booking()],
+        vec![deleted_second_booking()],
+        captured.clone(),
+    );
+    let service = deps.build_service();
+
+    service
+        .get_blocks_for_next_weeks_as_ical(default_sales_person_id(), ().auth(), None)
+        .await
+        .unwrap();
+
+    let calendar = captured.lock().unwrap().clone().unwrap();
+    assert_eq!(calendar.events.len(), 2);
+    let cancelled = calendar
+        .events
+        .iter()
+        .find(|event| event.cancelled)
+        .expect("cancelled event");
+    assert_eq!(
+        cancelled.uid.as_ref(),
+        format!("{}@shifty", second_booking_id())
+    );
+    assert_eq!(cancelled.start, datetime!(2025-01-13 10:00:00));
+    assert_eq!(cancelled.end, datetime!(2025-01-13 11:00:00));
+    // Die Löschung am selben Tag erhöht auch die SEQUENCE des Blocks.
+    let block = calendar
+        .events
+        .iter()
+        .find(|event| !event.cancelled)
+        .unwrap();
+    assert_eq!(block.sequence, cancelled.sequence);
+}
+
+#[tokio::test]
+async fn test_ical_feed_no_cancellation_when_rebooked() {
+    let captured = Arc::new(std::sync::Mutex::new(None));
+    let deleted_default = Booking {
+        id: uuid!("9d3b3f9e-1c55-4d7f-8f36-0a1b2c3d4e5f"),
+        deleted: deleted_second_booking().deleted,
+        ..default_booking()
+    };
+    let deps = build_ical_dependencies(
+        vec![default_booking()],
+        vec![deleted_default],
+        captured.clone(),
+    );
+    let service = deps.build_service();
+
+    service
+        .get_blocks_for_next_weeks_as_ical(default_sales_person_id(), ().auth(), None)
+        .await
+        .unwrap();
+
+    let calendar = captured.lock().unwrap().clone().unwrap();
+    assert_eq!(calendar.events.len(), 1);
+    assert!(!calendar.events[0].cancelled);
+}
+
+#[tokio::test]
+async fn test_ical_feed_forbidden_for_other_sales_person() {
+    let mut deps = build_dependencies();
+    deps.permission_service = MockPermissionService::new();
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    deps.sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(|_, _, _| Err(ServiceError::Forbidden));
+    let service = deps.build_service();
+
+    let result = service
+        .get_blocks_for_next_weeks_as_ical(default_sales_person_id(), ().auth(), None)
+        .await;
+
+    test_forbidden(&result);
+}
@@ -257,4 +257,58 @@ async fn test_generate_block_report_template_not_found() {
     // Assert
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), ServiceError::EntityNotFoundGeneric(_)));
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_build_block_report_context_matches_preview_sample() {
+    let context = Authentication::Full;
+    let test_blocks = create_test_blocks();
+
+    let mut deps = MockDeps {
+        block_service: service::block::MockBlockService::new(),
+        text_template_service: service::text_template::MockTextTemplateService::new(),
+        permission_service: service::MockPermissionService::new(),
+        clock_service: service::clock::MockClockService::new(),
+        transaction_dao: dao::MockTransactionDao::new(),
+    };
+    deps.transaction_dao
+        .expect_use_transaction()
+        .times(1)
+        .returning(|_| Ok(dao::MockTransaction));
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(service::permission::HR_PRIVILEGE), eq(context.clone()))
+        .times(1)
+        .returning(|_, _| Ok(()));
+    deps.clock_service
+        .expect_date_now()
+        .returning(|| time::Date::from_calendar_date(2024, time::Month::August, 12).unwrap());
+    deps.clock_service
+        .expect_date_time_now()
+        .returning(|| time::Date::from_calendar_date(2024, time::Month::August, 12).unwrap().with_time(time::Time::from_hms(9, 0, 0).unwrap()));
+    deps.block_service
+        .expect_get_unsufficiently_booked_blocks()
+        .returning(move |_, week, _, _| {
+            Ok(test_blocks.iter().filter(|b| b.week == week || week == 35).cloned().collect())
+        });
+    deps.transaction_dao
+        .expect_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+    let service = deps.build_service();
+
+    let context_data = service
+        .build_block_report_context(context, None)
+        .await
+        .unwrap();
+
+    // Die Vorschau-Beispieldaten müssen alle Variablen des echten Kontexts enthalten.
+    let sample = crate::text_template_preview::sample_context(&TextTemplate {
+        template_type: service::block_report::BLOCK_REPORT_TEMPLATE_TYPE.into(),
+        ..create_test_text_template("")
+    })
+    .unwrap();
+    let sample_variables = crate::text_template_preview::context_variables(&sample);
+    for variable in crate::text_template_preview::context_variables(&context_data) {
+        assert!(sample_variables.contains(&variable), "{variable} missing in sample");
+    }
+}
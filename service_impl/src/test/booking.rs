@@ -5,11 +5,15 @@ use dao::{
 };
 use mockall::predicate::{always, eq};
 use service::{
-    booking::Booking, clock::MockClockService, permission::Authentication,
+    audit_log::MockAuditLogService,
+    booking::Booking, carryover::MockCarryoverService, clock::MockClockService,
+    permission::Authentication,
     sales_person::MockSalesPersonService,
     sales_person_shiftplan::MockSalesPersonShiftplanService, slot::MockSlotService,
     slot::Slot,
-    uuid_service::MockUuidService, MockPermissionService, ValidationFailureItem,
+    uuid_service::MockUuidService,
+    webhook::{MockWebhookService, WebhookEvent},
+    MockPermissionService, ValidationFailureItem,
 };
 use time::{Date, Month, PrimitiveDateTime, Time};
 use uuid::{uuid, Uuid};
@@ -81,6 +85,9 @@ pub struct BookingServiceDependencies {
     pub sales_person_service: MockSalesPersonService,
     pub slot_service: MockSlotService,
     pub sales_person_shiftplan_service: MockSalesPersonShiftplanService,
+    pub webhook_service: MockWebhookService,
+    pub audit_log_service: MockAuditLogService,
+    pub carryover_service: MockCarryoverService,
     pub transaction_dao: MockTransactionDao,
 }
 impl BookingServiceDeps for BookingServiceDependencies {
@@ -93,6 +100,9 @@ impl BookingServiceDeps for BookingServiceDependencies {
     type SalesPersonService = MockSalesPersonService;
     type SlotService = MockSlotService;
     type SalesPersonShiftplanService = MockSalesPersonShiftplanService;
+    type WebhookService = MockWebhookService;
+    type AuditLogService = MockAuditLogService;
+    type CarryoverService = MockCarryoverService;
     type TransactionDao = MockTransactionDao;
 }
 impl BookingServiceDependencies {
@@ -105,6 +115,9 @@ impl BookingServiceDependencies {
             sales_person_service: self.sales_person_service.into(),
             slot_service: self.slot_service.into(),
             sales_person_shiftplan_service: self.sales_person_shiftplan_service.into(),
+            webhook_service: self.webhook_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            carryover_service: self.carryover_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -180,6 +193,19 @@ pub fn build_dependencies(permission: bool, role: &'static str) -> BookingServic
         .expect_is_eligible()
         .returning(|_, _, _, _| Ok(true));
 
+    let mut webhook_service = MockWebhookService::new();
+    webhook_service.expect_publish().returning(|_, _| Ok(()));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .returning(|_, _, _, _| Ok(()));
+
     BookingServiceDependencies {
         booking_dao,
         permission_service,
@@ -188,6 +214,9 @@ pub fn build_dependencies(permission: bool, role: &'static str) -> BookingServic
         sales_person_service,
         slot_service,
         sales_person_shiftplan_service,
+        webhook_service,
+        audit_log_service,
+        carryover_service,
         transaction_dao,
     }
 }
@@ -705,6 +734,94 @@ async fn test_delete() {
     assert_eq!(result.unwrap(), ());
 }
 
+#[tokio::test]
+async fn test_create_and_delete_mark_carryover_dirty() {
+    let mut deps = build_dependencies(true, "shiftplanner");
+    deps.booking_dao
+        .expect_create()
+        .returning(|_, _, _| Ok(()));
+    deps.booking_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(default_booking_entity())));
+    deps.booking_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| default_version());
+    deps.carryover_service = MockCarryoverService::new();
+    deps.carryover_service
+        .expect_mark_dirty()
+        .with(eq(default_sales_person_id()), eq(2024), always(), always())
+        .times(2)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .create(
+            &Booking {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                created: None,
+                ..default_booking()
+            },
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+    service.delete(default_id(), ().auth(), None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_create_and_delete_publish_webhooks() {
+    let mut deps = build_dependencies(true, "shiftplanner");
+    deps.booking_dao
+        .expect_create()
+        .returning(|_, _, _| Ok(()));
+    deps.booking_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(default_booking_entity())));
+    deps.booking_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| default_version());
+    deps.webhook_service.checkpoint();
+    deps.webhook_service
+        .expect_publish()
+        .withf(|event, _| {
+            matches!(event, WebhookEvent::BookingCreated(booking) if booking.slot_id == default_slot_id())
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    deps.webhook_service
+        .expect_publish()
+        .withf(|event, _| {
+            matches!(event, WebhookEvent::BookingDeleted(booking)
+                if booking.id == default_id() && booking.deleted.is_some())
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .create(
+            &Booking {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                created: None,
+                ..default_booking()
+            },
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+    service.delete(default_id(), ().auth(), None).await.unwrap();
+}
+
 #[tokio::test]
 async fn test_delete_sales_user() {
     let mut deps = build_dependencies(true, "sales");
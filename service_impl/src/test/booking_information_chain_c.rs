@@ -27,6 +27,7 @@ use service::booking_information::BookingInformationService;
 use service::clock::MockClockService;
 use service::employee_work_details::MockEmployeeWorkDetailsService;
 use service::permission::Authentication;
+use service::qualification::MockQualificationService;
 use service::reporting::MockReportingService;
 use service::sales_person::MockSalesPersonService;
 use service::sales_person_unavailable::MockSalesPersonUnavailableService;
@@ -56,11 +57,14 @@ impl BookingInformationServiceDeps for TestDeps {
     type BookingService = MockBookingService;
     type SalesPersonService = MockSalesPersonService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService =
+        service::sales_person_availability::MockSalesPersonAvailabilityService;
     type ReportingService = MockReportingService;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
     type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
     type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
@@ -273,11 +277,15 @@ fn build_service(
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
@@ -676,11 +684,15 @@ async fn test_get_weekly_summary_tolerates_toggle_unauthorized() {
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
@@ -0,0 +1,278 @@
+//! Qualifikationslücken in `get_booking_conflicts_for_week`.
+//!
+//! Ein Slot verlangt eine Kasse-qualifizierte Person. Gebucht ist nur eine
+//! Person ohne die Qualifikation → eine `QualificationGap`. Liegt der Slot
+//! auf einem Feiertag, wird er nicht geprüft.
+
+use std::sync::Arc;
+
+use time::macros::datetime;
+use uuid::{uuid, Uuid};
+
+use service::absence::{AbsencePeriod, MockAbsenceService};
+use service::booking::{Booking, MockBookingService};
+use service::booking_information::BookingInformationService;
+use service::clock::MockClockService;
+use service::employee_work_details::MockEmployeeWorkDetailsService;
+use service::permission::Authentication;
+use service::qualification::{
+    MockQualificationService, Qualification, SalesPersonQualification, SlotQualificationRequirement,
+};
+use service::reporting::MockReportingService;
+use service::sales_person::{MockSalesPersonService, SalesPerson};
+use service::sales_person_unavailable::MockSalesPersonUnavailableService;
+use service::shiftplan_report::MockShiftplanReportService;
+use service::slot::{MockSlotService, Slot};
+use service::special_days::{MockSpecialDayService, SpecialDay, SpecialDayType};
+use service::toggle::MockToggleService;
+use service::uuid_service::MockUuidService;
+use service::MockPermissionService;
+use shifty_utils::DayOfWeek;
+
+use crate::booking_information::{BookingInformationServiceDeps, BookingInformationServiceImpl};
+
+struct TestDeps;
+
+impl BookingInformationServiceDeps for TestDeps {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type ShiftplanReportService = MockShiftplanReportService;
+    type SlotService = MockSlotService;
+    type ShiftplanService = service::shiftplan_catalog::MockShiftplanService;
+    type BookingService = MockBookingService;
+    type SalesPersonService = MockSalesPersonService;
+    type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService =
+        service::sales_person_availability::MockSalesPersonAvailabilityService;
+    type ReportingService = MockReportingService;
+    type SpecialDayService = MockSpecialDayService;
+    type ToggleService = MockToggleService;
+    type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
+    type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+const YEAR: u32 = 2026;
+const WEEK: u8 = 31;
+
+fn slot_id() -> Uuid {
+    uuid!("7A7FF57A-782B-4C2E-A68B-4E2D81D79380")
+}
+
+fn sales_person_id() -> Uuid {
+    uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F0")
+}
+
+fn cashier_id() -> Uuid {
+    uuid!("D0000000-0000-0000-0000-000000000001")
+}
+
+fn slot() -> Slot {
+    Slot {
+        id: slot_id(),
+        day_of_week: DayOfWeek::Monday,
+        from: time::Time::from_hms(9, 0, 0).unwrap(),
+        to: time::Time::from_hms(17, 0, 0).unwrap(),
+        min_resources: 1,
+        max_paid_employees: None,
+        valid_from: time::Date::from_calendar_date(2026, time::Month::January, 1).unwrap(),
+        valid_to: None,
+        deleted: None,
+        version: Uuid::nil(),
+        shiftplan_id: None,
+    }
+}
+
+fn booking() -> Booking {
+    Booking {
+        id: uuid!("CEA260A0-112B-4970-936C-F7E529955BD0"),
+        sales_person_id: sales_person_id(),
+        slot_id: slot_id(),
+        calendar_week: WEEK as i32,
+        year: YEAR,
+        created: Some(datetime!(2026 - 07 - 01 10:00:00)),
+        deleted: None,
+        created_by: None,
+        deleted_by: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn sales_person() -> SalesPerson {
+    SalesPerson {
+        id: sales_person_id(),
+        name: "Anna".into(),
+        background_color: "#fff".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn cashier() -> Qualification {
+    Qualification {
+        id: cashier_id(),
+        name: "Kasse".into(),
+        description: "".into(),
+        created: Some(datetime!(2026 - 01 - 01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn build_service(
+    special_days: Vec<SpecialDay>,
+    assignments: Vec<SalesPersonQualification>,
+) -> BookingInformationServiceImpl<TestDeps> {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+
+    let mut booking_service = MockBookingService::new();
+    booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::from([booking()])));
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_get_all()
+        .returning(|_, _| Ok(Arc::from([sales_person()])));
+
+    let mut slot_service = MockSlotService::new();
+    slot_service
+        .expect_get_slots()
+        .returning(|_, _| Ok(Arc::from([slot()])));
+    slot_service
+        .expect_get_slots_for_week_all_plans()
+        .returning(|_, _, _, _| Ok(Arc::from([slot()])));
+
+    let mut sales_person_unavailable_service = MockSalesPersonUnavailableService::new();
+    sales_person_unavailable_service
+        .expect_get_by_week()
+        .returning(|_, _, _, _| Ok(Arc::from([])));
+
+    let mut sales_person_availability_service =
+        service::sales_person_availability::MockSalesPersonAvailabilityService::new();
+    sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from([])));
+
+    let mut absence_service = MockAbsenceService::new();
+    absence_service
+        .expect_find_overlapping_for_booking()
+        .returning(|_, _, _, _| Ok(Arc::from(Vec::<AbsencePeriod>::new())));
+
+    let mut special_day_service = MockSpecialDayService::new();
+    special_day_service
+        .expect_get_by_week()
+        .returning(move |_, _, _| Ok(Arc::from(special_days.clone())));
+
+    let mut qualification_service = MockQualificationService::new();
+    qualification_service
+        .expect_get_all_slot_requirements()
+        .returning(|_, _| {
+            Ok(Arc::from([SlotQualificationRequirement {
+                slot_id: slot_id(),
+                qualification_id: cashier_id(),
+                min_count: 1,
+            }]))
+        });
+    qualification_service
+        .expect_get_all()
+        .returning(|_, _| Ok(Arc::from([cashier()])));
+    qualification_service
+        .expect_get_all_sales_person_qualifications()
+        .returning(move |_, _| Ok(Arc::from(assignments.clone())));
+
+    let mut transaction_dao = dao::MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(dao::MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    BookingInformationServiceImpl::<TestDeps> {
+        shiftplan_report_service: Arc::new(MockShiftplanReportService::new()),
+        slot_service: Arc::new(slot_service),
+        shiftplan_service: Arc::new(service::shiftplan_catalog::MockShiftplanService::new()),
+        booking_service: Arc::new(booking_service),
+        sales_person_service: Arc::new(sales_person_service),
+        sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(sales_person_availability_service),
+        reporting_service: Arc::new(MockReportingService::new()),
+        special_day_service: Arc::new(special_day_service),
+        toggle_service: Arc::new(MockToggleService::new()),
+        employee_work_details_service: Arc::new(MockEmployeeWorkDetailsService::new()),
+        absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(qualification_service),
+        permission_service: Arc::new(permission_service),
+        clock_service: Arc::new(MockClockService::new()),
+        uuid_service: Arc::new(MockUuidService::new()),
+        transaction_dao: Arc::new(transaction_dao),
+    }
+}
+
+#[tokio::test]
+async fn test_conflicts_report_qualification_gap() {
+    let service = build_service(vec![], vec![]);
+
+    let conflicts = service
+        .get_booking_conflicts_for_week(YEAR, WEEK, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert!(conflicts.bookings.is_empty());
+    assert_eq!(conflicts.qualification_gaps.len(), 1);
+    let gap = &conflicts.qualification_gaps[0];
+    assert_eq!(gap.slot.id, slot_id());
+    assert_eq!(gap.qualification.id, cashier_id());
+    assert_eq!(gap.required_count, 1);
+    assert_eq!(gap.qualified_count, 0);
+}
+
+#[tokio::test]
+async fn test_conflicts_no_gap_when_qualified_person_booked() {
+    let service = build_service(
+        vec![],
+        vec![SalesPersonQualification {
+            sales_person_id: sales_person_id(),
+            qualification_id: cashier_id(),
+        }],
+    );
+
+    let conflicts = service
+        .get_booking_conflicts_for_week(YEAR, WEEK, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert!(conflicts.qualification_gaps.is_empty());
+}
+
+#[tokio::test]
+async fn test_conflicts_skip_slots_on_holiday() {
+    let holiday = SpecialDay {
+        id: Uuid::nil(),
+        year: YEAR,
+        calendar_week: WEEK,
+        day_of_week: DayOfWeek::Monday,
+        day_type: SpecialDayType::Holiday,
+        time_of_day: None,
+        created: Some(datetime!(2026 - 01 - 01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    };
+    let service = build_service(vec![holiday], vec![]);
+
+    let conflicts = service
+        .get_booking_conflicts_for_week(YEAR, WEEK, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert!(conflicts.qualification_gaps.is_empty());
+}
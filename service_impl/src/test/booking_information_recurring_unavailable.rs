@@ -0,0 +1,256 @@
+//! Wiederkehrende `Unavailable`-Regeln in `get_booking_conflicts_for_week`.
+//!
+//! Anna ist Montag 09:00–17:00 gebucht (KW 31/2026, Montag 27.07.). Eine
+//! Ganztags-Regel für Montag macht die Buchung zum Konflikt; eine Teil-Regel
+//! nur, wenn ihr Zeitfenster den Slot überlappt. Regeln außerhalb ihres
+//! Gültigkeitszeitraums zählen nicht.
+
+use std::sync::Arc;
+
+use time::macros::{date, datetime, time};
+use uuid::{uuid, Uuid};
+
+use service::absence::{AbsencePeriod, MockAbsenceService};
+use service::booking::{Booking, MockBookingService};
+use service::booking_information::BookingInformationService;
+use service::clock::MockClockService;
+use service::employee_work_details::MockEmployeeWorkDetailsService;
+use service::permission::Authentication;
+use service::qualification::MockQualificationService;
+use service::reporting::MockReportingService;
+use service::sales_person::{MockSalesPersonService, SalesPerson};
+use service::sales_person_availability::{
+    AvailabilityKind, MockSalesPersonAvailabilityService, SalesPersonAvailability,
+};
+use service::sales_person_unavailable::MockSalesPersonUnavailableService;
+use service::shiftplan_report::MockShiftplanReportService;
+use service::slot::{MockSlotService, Slot};
+use service::special_days::MockSpecialDayService;
+use service::toggle::MockToggleService;
+use service::uuid_service::MockUuidService;
+use service::MockPermissionService;
+use shifty_utils::DayOfWeek;
+
+use crate::booking_information::{BookingInformationServiceDeps, BookingInformationServiceImpl};
+
+struct TestDeps;
+
+impl BookingInformationServiceDeps for TestDeps {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type ShiftplanReportService = MockShiftplanReportService;
+    type SlotService = MockSlotService;
+    type ShiftplanService = service::shiftplan_catalog::MockShiftplanService;
+    type BookingService = MockBookingService;
+    type SalesPersonService = MockSalesPersonService;
+    type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = MockSalesPersonAvailabilityService;
+    type ReportingService = MockReportingService;
+    type SpecialDayService = MockSpecialDayService;
+    type ToggleService = MockToggleService;
+    type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
+    type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+const YEAR: u32 = 2026;
+const WEEK: u8 = 31;
+
+fn slot_id() -> Uuid {
+    uuid!("5B1C2D3E-4F50-4A61-8B72-9C83D4E5F601")
+}
+
+fn sales_person_id() -> Uuid {
+    uuid!("5B1C2D3E-4F50-4A61-8B72-9C83D4E5F602")
+}
+
+fn slot() -> Slot {
+    Slot {
+        id: slot_id(),
+        day_of_week: DayOfWeek::Monday,
+        from: time!(09:00),
+        to: time!(17:00),
+        min_resources: 1,
+        max_paid_employees: None,
+        valid_from: date!(2026 - 01 - 01),
+        valid_to: None,
+        deleted: None,
+        version: Uuid::nil(),
+        shiftplan_id: None,
+    }
+}
+
+fn booking() -> Booking {
+    Booking {
+        id: uuid!("5B1C2D3E-4F50-4A61-8B72-9C83D4E5F603"),
+        sales_person_id: sales_person_id(),
+        slot_id: slot_id(),
+        calendar_week: WEEK as i32,
+        year: YEAR,
+        created: Some(datetime!(2026 - 07 - 01 10:00:00)),
+        deleted: None,
+        created_by: None,
+        deleted_by: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn sales_person() -> SalesPerson {
+    SalesPerson {
+        id: sales_person_id(),
+        name: "Anna".into(),
+        background_color: "#fff".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+/// Montags-Regel der Art `kind`, gültig ab 01.01.2026.
+fn rule(
+    kind: AvailabilityKind,
+    window: Option<(time::Time, time::Time)>,
+) -> SalesPersonAvailability {
+    SalesPersonAvailability {
+        id: Uuid::new_v4(),
+        sales_person_id: sales_person_id(),
+        day_of_week: DayOfWeek::Monday,
+        time_from: window.map(|(from, _)| from),
+        time_to: window.map(|(_, to)| to),
+        valid_from: date!(2026 - 01 - 01),
+        valid_to: None,
+        kind,
+        created: Some(datetime!(2026 - 01 - 01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn build_service(rules: Vec<SalesPersonAvailability>) -> BookingInformationServiceImpl<TestDeps> {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+
+    let mut booking_service = MockBookingService::new();
+    booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::from([booking()])));
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_get_all()
+        .returning(|_, _| Ok(Arc::from([sales_person()])));
+
+    let mut slot_service = MockSlotService::new();
+    slot_service
+        .expect_get_slots()
+        .returning(|_, _| Ok(Arc::from([slot()])));
+
+    let mut sales_person_unavailable_service = MockSalesPersonUnavailableService::new();
+    sales_person_unavailable_service
+        .expect_get_by_week()
+        .returning(|_, _, _, _| Ok(Arc::from([])));
+
+    let mut sales_person_availability_service = MockSalesPersonAvailabilityService::new();
+    sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .withf(|sales_person, year, week, _, _| {
+            *sales_person == sales_person_id() && *year == YEAR && *week == WEEK
+        })
+        .returning(move |_, _, _, _, _| Ok(Arc::from(rules.clone())));
+
+    let mut absence_service = MockAbsenceService::new();
+    absence_service
+        .expect_find_overlapping_for_booking()
+        .returning(|_, _, _, _| Ok(Arc::from(Vec::<AbsencePeriod>::new())));
+
+    let mut qualification_service = MockQualificationService::new();
+    qualification_service
+        .expect_get_all_slot_requirements()
+        .returning(|_, _| Ok(Arc::from([])));
+
+    let mut transaction_dao = dao::MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(dao::MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    BookingInformationServiceImpl::<TestDeps> {
+        shiftplan_report_service: Arc::new(MockShiftplanReportService::new()),
+        slot_service: Arc::new(slot_service),
+        shiftplan_service: Arc::new(service::shiftplan_catalog::MockShiftplanService::new()),
+        booking_service: Arc::new(booking_service),
+        sales_person_service: Arc::new(sales_person_service),
+        sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(sales_person_availability_service),
+        reporting_service: Arc::new(MockReportingService::new()),
+        special_day_service: Arc::new(MockSpecialDayService::new()),
+        toggle_service: Arc::new(MockToggleService::new()),
+        employee_work_details_service: Arc::new(MockEmployeeWorkDetailsService::new()),
+        absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(qualification_service),
+        permission_service: Arc::new(permission_service),
+        clock_service: Arc::new(MockClockService::new()),
+        uuid_service: Arc::new(MockUuidService::new()),
+        transaction_dao: Arc::new(transaction_dao),
+    }
+}
+
+async fn conflicting_booking_ids(rules: Vec<SalesPersonAvailability>) -> Vec<Uuid> {
+    build_service(rules)
+        .get_booking_conflicts_for_week(YEAR, WEEK, Authentication::Full, None)
+        .await
+        .unwrap()
+        .bookings
+        .iter()
+        .map(|booking_information| booking_information.booking.id)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_full_day_recurring_rule_is_conflict() {
+    let conflicts = conflicting_booking_ids(vec![rule(AvailabilityKind::Unavailable, None)]).await;
+    assert_eq!(conflicts, vec![booking().id]);
+}
+
+#[tokio::test]
+async fn test_partial_recurring_rule_overlapping_slot_is_conflict() {
+    let conflicts = conflicting_booking_ids(vec![rule(
+        AvailabilityKind::Unavailable,
+        Some((time!(12:00), time!(14:00))),
+    )])
+    .await;
+    assert_eq!(conflicts, vec![booking().id]);
+}
+
+#[tokio::test]
+async fn test_partial_recurring_rule_outside_slot_is_no_conflict() {
+    let conflicts = conflicting_booking_ids(vec![rule(
+        AvailabilityKind::Unavailable,
+        Some((time!(17:00), time!(20:00))),
+    )])
+    .await;
+    assert!(conflicts.is_empty());
+}
+
+#[tokio::test]
+async fn test_expired_or_non_blocking_rules_are_no_conflict() {
+    let expired = SalesPersonAvailability {
+        valid_to: Some(date!(2026 - 07 - 26)),
+        ..rule(AvailabilityKind::Unavailable, None)
+    };
+    let conflicts = conflicting_booking_ids(vec![
+        expired,
+        rule(AvailabilityKind::Available, None),
+        rule(AvailabilityKind::Preferred, None),
+    ])
+    .await;
+    assert!(conflicts.is_empty());
+}
@@ -19,12 +19,15 @@ use std::sync::Arc;
 use time::macros::datetime;
 use uuid::Uuid;
 
-use service::absence::{AbsenceCategory, AbsencePeriod, DayFraction, MockAbsenceService};
+use service::absence::{
+    AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction, MockAbsenceService,
+};
 use service::booking::MockBookingService;
 use service::booking_information::BookingInformationService;
 use service::clock::MockClockService;
 use service::employee_work_details::{EmployeeWorkDetails, MockEmployeeWorkDetailsService};
 use service::permission::Authentication;
+use service::qualification::MockQualificationService;
 use service::reporting::{MockReportingService, ShortEmployeeReport};
 use service::sales_person::{MockSalesPersonService, SalesPerson};
 use service::sales_person_unavailable::MockSalesPersonUnavailableService;
@@ -51,11 +54,14 @@ impl BookingInformationServiceDeps for TestDeps {
     type BookingService = MockBookingService;
     type SalesPersonService = MockSalesPersonService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService =
+        service::sales_person_availability::MockSalesPersonAvailabilityService;
     type ReportingService = MockReportingService;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
     type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
     type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
@@ -93,6 +99,7 @@ fn sales_person(id: Uuid, name: &'static str, is_paid: bool) -> SalesPerson {
         background_color: Arc::from("#123456"),
         is_paid: Some(is_paid),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -113,6 +120,7 @@ fn work_details(sales_person_id: Uuid) -> EmployeeWorkDetails {
         is_dynamic: false,
         cap_planned_hours_to_expected: true,
         committed_voluntary: 5.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -141,6 +149,7 @@ fn absence_period(sales_person_id: Uuid, seq: u128) -> AbsencePeriod {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -279,11 +288,15 @@ fn build_service(
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(MockSalesPersonUnavailableService::new()),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
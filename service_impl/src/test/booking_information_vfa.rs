@@ -21,12 +21,15 @@ use std::sync::Arc;
 use time::macros::datetime;
 use uuid::Uuid;
 
-use service::absence::{AbsenceCategory, AbsencePeriod, DayFraction, MockAbsenceService};
+use service::absence::{
+    AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction, MockAbsenceService,
+};
 use service::booking::MockBookingService;
 use service::booking_information::BookingInformationService;
 use service::clock::MockClockService;
 use service::employee_work_details::{EmployeeWorkDetails, MockEmployeeWorkDetailsService};
 use service::permission::Authentication;
+use service::qualification::MockQualificationService;
 use service::reporting::MockReportingService;
 use service::sales_person::{MockSalesPersonService, SalesPerson};
 use service::sales_person_unavailable::MockSalesPersonUnavailableService;
@@ -54,11 +57,14 @@ impl BookingInformationServiceDeps for TestDeps {
     type BookingService = MockBookingService;
     type SalesPersonService = MockSalesPersonService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService =
+        service::sales_person_availability::MockSalesPersonAvailabilityService;
     type ReportingService = MockReportingService;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
     type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
     type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
@@ -93,6 +99,7 @@ fn volunteer_sales_person() -> SalesPerson {
         background_color: Arc::from("#00FF00"),
         is_paid: Some(false),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -118,6 +125,7 @@ fn volunteer_work_details() -> EmployeeWorkDetails {
         is_dynamic: false,
         cap_planned_hours_to_expected: true,
         committed_voluntary: 5.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -149,6 +157,7 @@ fn volunteer_absence_period() -> AbsencePeriod {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -308,11 +317,15 @@ async fn vfa02_holiday_vs_absence_asymmetry() {
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(MockSalesPersonUnavailableService::new()),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
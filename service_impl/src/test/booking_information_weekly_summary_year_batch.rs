@@ -27,7 +27,9 @@ use std::sync::Arc;
 use time::macros::datetime;
 use uuid::Uuid;
 
-use service::absence::{AbsenceCategory, AbsencePeriod, DayFraction, MockAbsenceService};
+use service::absence::{
+    AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction, MockAbsenceService,
+};
 use service::booking::MockBookingService;
 use service::booking_information::{
     BookingInformationService, WeeklySummary, WorkingHoursPerSalesPerson,
@@ -35,6 +37,7 @@ use service::booking_information::{
 use service::clock::MockClockService;
 use service::employee_work_details::{EmployeeWorkDetails, MockEmployeeWorkDetailsService};
 use service::permission::Authentication;
+use service::qualification::MockQualificationService;
 use service::reporting::{MockReportingService, ShortEmployeeReport};
 use service::sales_person::{MockSalesPersonService, SalesPerson};
 use service::sales_person_unavailable::MockSalesPersonUnavailableService;
@@ -63,11 +66,14 @@ impl BookingInformationServiceDeps for TestDeps {
     type BookingService = MockBookingService;
     type SalesPersonService = MockSalesPersonService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService =
+        service::sales_person_availability::MockSalesPersonAvailabilityService;
     type ReportingService = MockReportingService;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
     type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
     type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
@@ -527,11 +533,15 @@ fn build_service_with(config: FixtureConfig) -> BookingInformationServiceImpl<Te
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
@@ -550,6 +560,7 @@ fn sales_person(id: Uuid, name: &'static str, is_paid: bool) -> SalesPerson {
         background_color: Arc::from("#ffffff"),
         is_paid: Some(is_paid),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -575,6 +586,7 @@ fn work_details(
         is_dynamic: false,
         cap_planned_hours_to_expected,
         committed_voluntary,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -650,6 +662,7 @@ fn absence_period(
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -32,6 +32,7 @@ use service::booking_information::BookingInformationService;
 use service::clock::MockClockService;
 use service::employee_work_details::MockEmployeeWorkDetailsService;
 use service::permission::Authentication;
+use service::qualification::MockQualificationService;
 use service::reporting::{MockReportingService, ShortEmployeeReport};
 use service::sales_person::{MockSalesPersonService, SalesPerson};
 use service::sales_person_unavailable::MockSalesPersonUnavailableService;
@@ -58,11 +59,14 @@ impl BookingInformationServiceDeps for TestDeps {
     type BookingService = MockBookingService;
     type SalesPersonService = MockSalesPersonService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService =
+        service::sales_person_availability::MockSalesPersonAvailabilityService;
     type ReportingService = MockReportingService;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
     type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
     type AbsenceService = MockAbsenceService;
+    type QualificationService = MockQualificationService;
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
@@ -80,6 +84,7 @@ fn paid_person() -> SalesPerson {
         background_color: Arc::from("#ffffff"),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -259,11 +264,15 @@ fn build_service(paid_report_by_week: Vec<((u32, u8), f32)>) -> BookingInformati
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
@@ -461,11 +470,15 @@ async fn slot_with_calendar_year_end_valid_to_bulk_matches_legacy() {
         booking_service: Arc::new(MockBookingService::new()),
         sales_person_service: Arc::new(sales_person_service),
         sales_person_unavailable_service: Arc::new(sales_person_unavailable_service),
+        sales_person_availability_service: Arc::new(
+            service::sales_person_availability::MockSalesPersonAvailabilityService::new(),
+        ),
         reporting_service: Arc::new(reporting_service),
         special_day_service: Arc::new(special_day_service),
         toggle_service: Arc::new(toggle_service),
         employee_work_details_service: Arc::new(employee_work_details_service),
         absence_service: Arc::new(absence_service),
+        qualification_service: Arc::new(MockQualificationService::new()),
         permission_service: Arc::new(permission_service),
         clock_service: Arc::new(MockClockService::new()),
         uuid_service: Arc::new(MockUuidService::new()),
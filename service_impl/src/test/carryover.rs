@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
 use crate::carryover::{CarryoverServiceDeps, CarryoverServiceImpl};
-use dao::carryover::CarryoverEntity;
+use dao::carryover::{CarryoverDirtyEntity, CarryoverEntity};
 use dao::{MockTransaction, MockTransactionDao};
 use mockall::predicate::{always, eq};
-use service::carryover::{Carryover, CarryoverService};
+use service::audit_log::{AuditAction, MockAuditLogService};
+use service::carryover::{first_dirty_year, Carryover, CarryoverDirty, CarryoverService};
 use service::permission::Authentication;
-use service::ServiceError;
+use service::uuid_service::MockUuidService;
+use service::{MockPermissionService, ServiceError};
 use time::{Date, Month, PrimitiveDateTime, Time};
 use uuid::{uuid, Uuid};
 
@@ -56,6 +58,9 @@ fn generate_default_datetime() -> PrimitiveDateTime {
 // Dependencies for the Carryover service
 pub struct CarryoverServiceDependencies {
     pub carryover_dao: MockCarryoverDao,
+    pub audit_log_service: MockAuditLogService,
+    pub permission_service: MockPermissionService,
+    pub uuid_service: MockUuidService,
 }
 
 impl CarryoverServiceDeps for CarryoverServiceDependencies {
@@ -63,6 +68,9 @@ impl CarryoverServiceDeps for CarryoverServiceDependencies {
     type Transaction = MockTransaction;
 
     type CarryoverDao = MockCarryoverDao;
+    type AuditLogService = MockAuditLogService;
+    type PermissionService = MockPermissionService;
+    type UuidService = MockUuidService;
 
     type TransactionDao = MockTransactionDao;
 }
@@ -77,20 +85,43 @@ impl CarryoverServiceDependencies {
 
         CarryoverServiceImpl {
             carryover_dao: self.carryover_dao.into(),
+            audit_log_service: self.audit_log_service.into(),
+            permission_service: self.permission_service.into(),
+            uuid_service: self.uuid_service.into(),
             transaction_dao: Arc::new(transaction_dao),
         }
     }
 }
 
 fn build_dependencies() -> CarryoverServiceDependencies {
-    // By default no expectations, tests will set them up
+    // By default no DAO expectations, tests will set them up
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
     CarryoverServiceDependencies {
         carryover_dao: MockCarryoverDao::new(),
+        audit_log_service,
+        permission_service: full_authentication_only(),
+        uuid_service: MockUuidService::new(),
     }
 }
 
-// Since CarryoverService doesn't do permission checks, we pass ()
-// and convert it to Authentication::Context(())
+/// Wie der echte PermissionService: nur `Authentication::Full` kommt durch.
+fn full_authentication_only() -> MockPermissionService {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| match context {
+            Authentication::Full => Ok(()),
+            Authentication::Context(_) => Err(ServiceError::Forbidden),
+        });
+    permission_service
+}
+
+// Reading and saving carryovers does no permission checks, we pass ()
+// and convert it to Authentication::Context(()). The dirty marks only
+// accept Authentication::Full.
 trait NoneTypeExt {
     fn auth(&self) -> Authentication<()>;
 }
@@ -158,6 +189,9 @@ async fn test_set_carryover_success() {
     let carryover = default_carryover();
     let entity = default_carryover_entity();
 
+    deps.carryover_dao
+        .expect_find_by_sales_person_id_and_year()
+        .returning(|_, _, _| Ok(None));
     deps.carryover_dao
         .expect_upsert()
         .with(eq(entity.clone()), eq("carryover-service"), always())
@@ -173,6 +207,9 @@ async fn test_set_carryover_dao_error() {
     let mut deps = build_dependencies();
     let carryover = default_carryover();
 
+    deps.carryover_dao
+        .expect_find_by_sales_person_id_and_year()
+        .returning(|_, _, _| Ok(None));
     deps.carryover_dao
         .expect_upsert()
         .with(always(), eq("carryover-service"), always())
@@ -185,3 +222,249 @@ async fn test_set_carryover_dao_error() {
         _ => panic!("Expected a data access error"),
     }
 }
+
+#[tokio::test]
+async fn test_set_carryover_records_update_of_existing_carryover() {
+    let mut deps = build_dependencies();
+    let carryover = Carryover {
+        carryover_hours: 12.5,
+        ..default_carryover()
+    };
+
+    deps.carryover_dao
+        .expect_find_by_sales_person_id_and_year()
+        .returning(|_, _, _| Ok(Some(default_carryover_entity())));
+    deps.carryover_dao
+        .expect_upsert()
+        .returning(|_, _, _| Ok(()));
+    deps.audit_log_service = MockAuditLogService::new();
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, process, _, _| {
+            change.action == AuditAction::Update
+                && change.entity_id.as_ref()
+                    == format!("{}/2025", default_sales_person_id()).as_str()
+                && change.before.as_deref().is_some_and(|json| json.contains("10.0"))
+                && change.after.as_deref().is_some_and(|json| json.contains("12.5"))
+                && process == "carryover-service"
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = deps.build_service();
+    let result = service.set_carryover(&carryover, ().auth(), None).await;
+    assert!(result.is_ok(), "Expected Ok result");
+}
+
+fn default_dirty_entity() -> CarryoverDirtyEntity {
+    CarryoverDirtyEntity {
+        sales_person_id: default_sales_person_id(),
+        year: 2025,
+        version: default_version(),
+    }
+}
+
+#[tokio::test]
+async fn test_mark_dirty_uses_new_version() {
+    let mut deps = build_dependencies();
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("carryover-dirty-version"))
+        .returning(|_| default_version());
+    deps.carryover_dao
+        .expect_mark_dirty()
+        .with(
+            eq(default_dirty_entity()),
+            eq("carryover-service"),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let service = deps.build_service();
+    let result = service
+        .mark_dirty(default_sales_person_id(), 2025, Authentication::Full, None)
+        .await;
+    assert!(result.is_ok(), "Expected Ok result");
+}
+
+#[tokio::test]
+async fn test_mark_dirty_rejects_user_context() {
+    let mut deps = build_dependencies();
+    deps.carryover_dao.expect_mark_dirty().never();
+
+    let service = deps.build_service();
+    let result = service
+        .mark_dirty(default_sales_person_id(), 2025, ().auth(), None)
+        .await;
+    assert!(
+        matches!(result, Err(ServiceError::Forbidden)),
+        "Expected Forbidden, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_find_and_clear_dirty() {
+    let mut deps = build_dependencies();
+    deps.carryover_dao
+        .expect_all_dirty()
+        .returning(|_| Ok(Arc::from([default_dirty_entity()])));
+    deps.carryover_dao
+        .expect_clear_dirty()
+        .with(eq(default_dirty_entity()), always())
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let service = deps.build_service();
+    let dirty = service.find_dirty(Authentication::Full, None).await.unwrap();
+    assert_eq!(
+        dirty.as_ref(),
+        &[CarryoverDirty {
+            sales_person_id: default_sales_person_id(),
+            year: 2025,
+            version: default_version(),
+        }]
+    );
+    service
+        .clear_dirty(&dirty[0], Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn test_first_dirty_year_at_year_boundary() {
+    // Mitten im Jahr stimmen ISO- und Kalenderjahr überein.
+    assert_eq!(
+        first_dirty_year(Date::from_calendar_date(2026, Month::May, 4).unwrap()),
+        2026
+    );
+    // 2027-01-01 gehört zur ISO-KW 53/2026.
+    assert_eq!(
+        first_dirty_year(Date::from_calendar_date(2027, Month::January, 1).unwrap()),
+        2026
+    );
+    // 2025-12-29 gehört schon zur ISO-KW 1/2026.
+    assert_eq!(
+        first_dirty_year(Date::from_calendar_date(2025, Month::December, 29).unwrap()),
+        2025
+    );
+}
+
+mod integration {
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use std::sync::Arc;
+
+    use dao_impl_sqlite::audit_log::AuditLogDaoImpl;
+    use dao_impl_sqlite::carryover::CarryoverDaoImpl;
+    use dao_impl_sqlite::{TransactionDaoImpl, TransactionImpl};
+    use service::carryover::CarryoverService;
+    use service::permission::Authentication;
+    use service::uuid_service::MockUuidService;
+    use service::MockPermissionService;
+    use uuid::{uuid, Uuid};
+
+    use crate::audit_log::{AuditLogServiceDeps, AuditLogServiceImpl};
+    use crate::carryover::{CarryoverServiceDeps, CarryoverServiceImpl};
+    use crate::clock::ClockServiceImpl;
+    use crate::uuid_service::UuidServiceImpl;
+
+    struct AuditLogDeps;
+    impl AuditLogServiceDeps for AuditLogDeps {
+        type Context = ();
+        type Transaction = TransactionImpl;
+        type AuditLogDao = AuditLogDaoImpl;
+        type PermissionService = MockPermissionService;
+        type ClockService = ClockServiceImpl;
+        type UuidService = UuidServiceImpl;
+        type TransactionDao = TransactionDaoImpl;
+    }
+
+    struct IntegrationDeps;
+    impl CarryoverServiceDeps for IntegrationDeps {
+        type Context = ();
+        type Transaction = TransactionImpl;
+        type CarryoverDao = CarryoverDaoImpl;
+        type AuditLogService = AuditLogServiceImpl<AuditLogDeps>;
+        type PermissionService = MockPermissionService;
+        type UuidService = MockUuidService;
+        type TransactionDao = TransactionDaoImpl;
+    }
+
+    async fn build_service() -> CarryoverServiceImpl<IntegrationDeps> {
+        let pool = Arc::new(
+            sqlx::SqlitePool::connect("sqlite::memory:")
+                .await
+                .expect("Could not connect to in-memory SQLite"),
+        );
+        sqlx::migrate!("./../migrations/sqlite")
+            .run(pool.as_ref())
+            .await
+            .expect("Could not run migrations");
+
+        let counter = AtomicU8::new(0);
+        let mut uuid_service = MockUuidService::new();
+        uuid_service
+            .expect_new_uuid()
+            .returning(move |_| Uuid::from_bytes([counter.fetch_add(1, Ordering::SeqCst) + 1; 16]));
+
+        CarryoverServiceImpl {
+            carryover_dao: Arc::new(CarryoverDaoImpl::new(pool.clone())),
+            audit_log_service: Arc::new(AuditLogServiceImpl {
+                audit_log_dao: AuditLogDaoImpl::new(pool.clone()).into(),
+                permission_service: MockPermissionService::new().into(),
+                clock_service: ClockServiceImpl.into(),
+                uuid_service: UuidServiceImpl.into(),
+                transaction_dao: TransactionDaoImpl::new(pool.clone()).into(),
+            }),
+            permission_service: Arc::new(super::full_authentication_only()),
+            uuid_service: Arc::new(uuid_service),
+            transaction_dao: Arc::new(TransactionDaoImpl::new(pool)),
+        }
+    }
+
+    fn sales_person_id() -> Uuid {
+        uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F0")
+    }
+
+    #[tokio::test]
+    async fn clear_keeps_marks_renewed_after_find() {
+        let service = build_service().await;
+        service
+            .mark_dirty(sales_person_id(), 2025, Authentication::Full, None)
+            .await
+            .unwrap();
+        service
+            .mark_dirty(sales_person_id(), 2024, Authentication::Full, None)
+            .await
+            .unwrap();
+
+        let dirty = service
+            .find_dirty(Authentication::Full, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            dirty.iter().map(|d| d.year).collect::<Vec<_>>(),
+            vec![2024, 2025]
+        );
+
+        // 2025 wird erneut markiert, während der Job noch rechnet.
+        service
+            .mark_dirty(sales_person_id(), 2025, Authentication::Full, None)
+            .await
+            .unwrap();
+        for entry in dirty.iter() {
+            service
+                .clear_dirty(entry, Authentication::Full, None)
+                .await
+                .unwrap();
+        }
+
+        let remaining = service
+            .find_dirty(Authentication::Full, None)
+            .await
+            .unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].year, 2025);
+        assert_ne!(remaining[0].version, dirty[1].version);
+    }
+}
@@ -6,6 +6,7 @@ use dao::MockTransaction;
 use dao::MockTransactionDao;
 use mockall::predicate::always;
 use mockall::predicate::eq;
+use service::audit_log::MockAuditLogService;
 use service::clock::MockClockService;
 use service::custom_extra_hours::CustomExtraHours;
 use service::custom_extra_hours::CustomExtraHoursService;
@@ -99,6 +100,7 @@ struct CustomExtraHoursDependencies {
     clock_service: MockClockService,
     permission_service: MockPermissionService,
     transaction_dao: MockTransactionDao,
+    pub audit_log_service: MockAuditLogService,
 }
 
 impl CustomExtraHoursDeps for CustomExtraHoursDependencies {
@@ -109,6 +111,7 @@ impl CustomExtraHoursDeps for CustomExtraHoursDependencies {
     type UuidService = MockUuidService;
     type ClockService = MockClockService;
     type PermissionService = MockPermissionService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 impl CustomExtraHoursDependencies {
@@ -119,6 +122,7 @@ impl CustomExtraHoursDependencies {
             uuid_service: self.uuid_service.into(),
             clock_service: self.clock_service.into(),
             permission_service: self.permission_service.into(),
+            audit_log_service: self.audit_log_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -152,12 +156,18 @@ fn build_dependencies() -> CustomExtraHoursDependencies {
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     CustomExtraHoursDependencies {
         custom_extra_hours_dao,
         sales_person_service,
         uuid_service,
         clock_service,
         permission_service,
+        audit_log_service,
         transaction_dao,
     }
 }
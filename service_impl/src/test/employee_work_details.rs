@@ -8,6 +8,8 @@ use dao::{
 };
 use mockall::predicate::{always, function};
 use service::{
+    audit_log::MockAuditLogService,
+    carryover::MockCarryoverService,
     clock::MockClockService,
     employee_work_details::{EmployeeWorkDetails, EmployeeWorkDetailsService},
     sales_person::MockSalesPersonService,
@@ -24,6 +26,8 @@ struct Deps {
     permission_service: MockPermissionService,
     clock_service: MockClockService,
     uuid_service: MockUuidService,
+    audit_log_service: MockAuditLogService,
+    carryover_service: MockCarryoverService,
     transaction_dao: MockTransactionDao,
 }
 
@@ -35,6 +39,8 @@ impl EmployeeWorkDetailsServiceDeps for Deps {
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type CarryoverService = MockCarryoverService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -46,11 +52,29 @@ impl Deps {
             permission_service: self.permission_service.into(),
             clock_service: self.clock_service.into(),
             uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            carryover_service: self.carryover_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
 }
 
+fn audit_log_service() -> MockAuditLogService {
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+    audit_log_service
+}
+
+fn carryover_service() -> MockCarryoverService {
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .returning(|_, _, _, _| Ok(()));
+    carryover_service
+}
+
 fn entity_with_cap(id: Uuid, version: Uuid, cap: bool) -> EmployeeWorkDetailsEntity {
     entity_with_cap_and_committed(id, version, cap, 0.0)
 }
@@ -75,6 +99,7 @@ fn entity_with_cap_and_committed(
         is_dynamic: false,
         cap_planned_hours_to_expected: cap,
         committed_voluntary,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -136,6 +161,8 @@ async fn update_propagates_committed_voluntary_to_dao() {
         permission_service,
         clock_service: MockClockService::new(),
         uuid_service,
+        audit_log_service: audit_log_service(),
+        carryover_service: carryover_service(),
         transaction_dao,
     };
     let service = deps.build();
@@ -200,6 +227,8 @@ async fn update_propagates_cap_planned_hours_flag_to_dao() {
         permission_service,
         clock_service: MockClockService::new(),
         uuid_service,
+        audit_log_service: audit_log_service(),
+        carryover_service: carryover_service(),
         transaction_dao,
     };
     let service = deps.build();
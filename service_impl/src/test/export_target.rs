@@ -0,0 +1,495 @@
+use std::sync::Arc;
+
+use dao::export_target::{
+    ExportArtifactType as ExportArtifactTypeEntity, ExportRunState as ExportRunStateEntity,
+    ExportTargetEntity, ExportTargetKind as ExportTargetKindEntity, MockExportTargetDao,
+};
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::audit_log::{entity_type, AuditAction, MockAuditLogService};
+use service::clock::MockClockService;
+use service::export_target::{
+    ExportArtifactType, ExportRunState, ExportTarget, ExportTargetKind, ExportTargetRun,
+    ExportTargetService,
+};
+use service::permission::Authentication;
+use service::uuid_service::MockUuidService;
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use time::macros::datetime;
+use time::PrimitiveDateTime;
+use uuid::{uuid, Uuid};
+
+use crate::export_target::{
+    expand_filename_pattern, ExportTargetServiceDeps, ExportTargetServiceImpl,
+};
+use crate::test::error_test::{test_conflicts, test_forbidden, test_validation_error};
+
+fn now() -> PrimitiveDateTime {
+    datetime!(2026 - 03 - 15 10:00:00)
+}
+
+fn target_id() -> Uuid {
+    uuid!("0E0E0000-0000-0000-0000-000000000001")
+}
+
+fn version() -> Uuid {
+    uuid!("0E0E0000-0000-0000-0000-0000000000AA")
+}
+
+fn new_version() -> Uuid {
+    uuid!("0E0E0000-0000-0000-0000-0000000000FF")
+}
+
+fn webdav_entity() -> ExportTargetEntity {
+    ExportTargetEntity {
+        id: target_id(),
+        name: "Nextcloud Team".into(),
+        kind: ExportTargetKindEntity::WebDav,
+        enabled: true,
+        url: Some("https://cloud.example.com/remote.php/dav/files/tester".into()),
+        username: Some("tester".into()),
+        secret: Some("stored-token".into()),
+        path: "Schichtplaene".into(),
+        cron_schedule: "0 0 6 * * Mon".into(),
+        artifact_type: ExportArtifactTypeEntity::ShiftplanWeekPdf,
+        filename_pattern: "".into(),
+        weeks_horizon: 2,
+        created: datetime!(2026 - 01 - 01 12:00:00),
+        deleted: None,
+        version: version(),
+    }
+}
+
+fn directory_target() -> ExportTarget {
+    ExportTarget {
+        id: Uuid::nil(),
+        name: " Lohnbuero ".into(),
+        kind: ExportTargetKind::LocalDirectory,
+        enabled: true,
+        url: Some("https://ignored.example.com".into()),
+        username: Some("ignored".into()),
+        secret: Some("ignored".into()),
+        path: "/srv/export/lohn".into(),
+        cron_schedule: "0 0 5 1 * *".into(),
+        artifact_type: ExportArtifactType::BillingPeriodCsv,
+        filename_pattern: "{target}-{start}-{end}.csv".into(),
+        weeks_horizon: 0,
+        created: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+pub struct ExportTargetServiceDependencies {
+    pub export_target_dao: MockExportTargetDao,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+}
+
+impl ExportTargetServiceDeps for ExportTargetServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type ExportTargetDao = MockExportTargetDao;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl ExportTargetServiceDependencies {
+    pub fn build_service(self) -> ExportTargetServiceImpl<ExportTargetServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        ExportTargetServiceImpl {
+            export_target_dao: Arc::new(self.export_target_dao),
+            permission_service: Arc::new(self.permission_service),
+            clock_service: Arc::new(self.clock_service),
+            uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
+            transaction_dao: Arc::new(transaction_dao),
+        }
+    }
+}
+
+/// Alle Permission-Checks schlagen fehl, außer bei `Authentication::Full`.
+fn build_dependencies() -> ExportTargetServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+
+    let mut clock_service = MockClockService::new();
+    clock_service.expect_date_time_now().returning(now);
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| new_version());
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    ExportTargetServiceDependencies {
+        export_target_dao: MockExportTargetDao::new(),
+        permission_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+    }
+}
+
+// --- create ---------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_create_directory_target_trims_and_drops_webdav_fields() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .export_target_dao
+        .expect_create()
+        .withf(|entity, process, _| {
+            entity.id == new_version()
+                && entity.name.as_ref() == "Lohnbuero"
+                && entity.kind == ExportTargetKindEntity::LocalDirectory
+                && entity.url.is_none()
+                && entity.username.is_none()
+                && entity.secret.is_none()
+                && entity.artifact_type == ExportArtifactTypeEntity::BillingPeriodCsv
+                && entity.created == now()
+                && process == "export-target-service"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let created = service
+        .create(&directory_target(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(created.id, new_version());
+    assert_eq!(created.version, new_version());
+    assert_eq!(created.created, Some(now()));
+}
+
+#[tokio::test]
+async fn test_create_webdav_target_requires_connection_fields() {
+    let mut dependencies = build_dependencies();
+    dependencies.export_target_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let target = ExportTarget {
+        kind: ExportTargetKind::WebDav,
+        url: Some("ftp://cloud.example.com".into()),
+        username: None,
+        secret: Some("  ".into()),
+        ..directory_target()
+    };
+    let result = service.create(&target, Authentication::Full, None).await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("url".into()),
+        3,
+    );
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("secret".into()),
+        3,
+    );
+}
+
+#[tokio::test]
+async fn test_create_rejects_relative_path_and_bad_cron() {
+    let mut dependencies = build_dependencies();
+    dependencies.export_target_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let target = ExportTarget {
+        path: "/srv/export/../etc".into(),
+        cron_schedule: "jeden Montag".into(),
+        ..directory_target()
+    };
+    let result = service.create(&target, Authentication::Full, None).await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("path".into()),
+        2,
+    );
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("cron_schedule".into()),
+        2,
+    );
+}
+
+#[tokio::test]
+async fn test_create_rejects_pattern_without_required_placeholders() {
+    let service = build_dependencies().build_service();
+
+    for pattern in [
+        "schichtplan-{year}-KW{week}.pdf",
+        "{shiftplan}/{year}-{week}.pdf",
+        "{shiftplan}-{year}-{week}-{start}.pdf",
+        "{shiftplan-{year}-{week}.pdf",
+    ] {
+        let target = ExportTarget {
+            artifact_type: ExportArtifactType::ShiftplanWeekPdf,
+            filename_pattern: pattern.into(),
+            weeks_horizon: 4,
+            ..directory_target()
+        };
+        let result = service.create(&target, Authentication::Full, None).await;
+        test_validation_error(
+            &result,
+            &ValidationFailureItem::InvalidValue("filename_pattern".into()),
+            1,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_create_week_pdf_checks_weeks_horizon() {
+    let service = build_dependencies().build_service();
+
+    let target = ExportTarget {
+        artifact_type: ExportArtifactType::ShiftplanWeekPdf,
+        filename_pattern: "".into(),
+        weeks_horizon: 27,
+        ..directory_target()
+    };
+    let result = service.create(&target, Authentication::Full, None).await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("weeks_horizon".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_create_with_id_fails() {
+    let service = build_dependencies().build_service();
+    let target = ExportTarget {
+        id: target_id(),
+        ..directory_target()
+    };
+    let result = service.create(&target, Authentication::Full, None).await;
+    assert!(matches!(result, Err(ServiceError::IdSetOnCreate)));
+}
+
+#[tokio::test]
+async fn test_create_forbidden() {
+    let service = build_dependencies().build_service();
+    let result = service
+        .create(&directory_target(), Authentication::Context(()), None)
+        .await;
+    test_forbidden(&result);
+}
+
+// --- update ---------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_update_without_secret_keeps_stored_secret() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .export_target_dao
+        .expect_find_by_id()
+        .with(eq(target_id()), always())
+        .returning(|_, _| Ok(Some(webdav_entity())));
+    dependencies
+        .export_target_dao
+        .expect_update()
+        .withf(|entity, _, _| {
+            entity.secret.as_deref() == Some("stored-token")
+                && entity.weeks_horizon == 4
+                && entity.created == datetime!(2026 - 01 - 01 12:00:00)
+                && entity.version == new_version()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            // Das Secret darf nicht im Audit-Log landen.
+            change.entity_type == entity_type::EXPORT_TARGET
+                && change.action == AuditAction::Update
+                && !change.before.as_deref().unwrap_or_default().contains("stored-token")
+                && !change.after.as_deref().unwrap_or_default().contains("stored-token")
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let target = ExportTarget {
+        secret: None,
+        weeks_horizon: 4,
+        ..ExportTarget::from(&webdav_entity())
+    };
+    let updated = service
+        .update(&target, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(updated.version, new_version());
+}
+
+#[tokio::test]
+async fn test_update_version_conflict() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .export_target_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(webdav_entity())));
+    dependencies.export_target_dao.expect_update().never();
+    let service = dependencies.build_service();
+
+    let target = ExportTarget {
+        version: new_version(),
+        ..ExportTarget::from(&webdav_entity())
+    };
+    let result = service.update(&target, Authentication::Full, None).await;
+    test_conflicts(&result, &target_id(), &version(), &new_version());
+}
+
+// --- delete / runs ----------------------------------------------------------------
+
+#[tokio::test]
+async fn test_delete_is_soft_delete() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .export_target_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(webdav_entity())));
+    dependencies
+        .export_target_dao
+        .expect_update()
+        .withf(|entity, _, _| entity.deleted == Some(now()) && entity.version == new_version())
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == entity_type::EXPORT_TARGET
+                && change.entity_id.as_ref() == target_id().to_string()
+                && change.action == AuditAction::Delete
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .delete(target_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_get_runs_clamps_limit() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .export_target_dao
+        .expect_find_runs_by_target()
+        .with(eq(target_id()), eq(500), always())
+        .times(1)
+        .returning(|_, _, _| Ok(Arc::from([])));
+    let service = dependencies.build_service();
+
+    let runs = service
+        .get_runs(target_id(), 10_000, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert!(runs.is_empty());
+}
+
+#[tokio::test]
+async fn test_record_run_assigns_id_and_version() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .export_target_dao
+        .expect_create_run()
+        .withf(|entity, _, _| {
+            entity.id == new_version()
+                && entity.target_id == target_id()
+                && entity.state == ExportRunStateEntity::Partial
+                && entity.files_written == 3
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let run = ExportTargetRun {
+        id: Uuid::nil(),
+        target_id: target_id(),
+        started: now(),
+        duration_ms: 120,
+        state: ExportRunState::Partial,
+        files_written: 3,
+        files_skipped: 1,
+        message: Some("KW12: Ablage fehlgeschlagen".into()),
+        version: Uuid::nil(),
+    };
+    let recorded = service
+        .record_run(&run, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(recorded.id, new_version());
+    assert_eq!(recorded.version, new_version());
+}
+
+#[tokio::test]
+async fn test_record_run_requires_full_authentication() {
+    let mut dependencies = build_dependencies();
+    dependencies.export_target_dao.expect_create_run().never();
+    let service = dependencies.build_service();
+
+    let run = ExportTargetRun {
+        id: Uuid::nil(),
+        target_id: target_id(),
+        started: now(),
+        duration_ms: 0,
+        state: ExportRunState::Success,
+        files_written: 0,
+        files_skipped: 0,
+        message: None,
+        version: Uuid::nil(),
+    };
+    let result = service
+        .record_run(&run, Authentication::Context(()), None)
+        .await;
+    test_forbidden(&result);
+}
+
+// --- filename pattern -------------------------------------------------------------
+
+#[test]
+fn test_expand_filename_pattern() {
+    let filename = expand_filename_pattern(
+        "schichtplan-{shiftplan}-{year}-KW{week}.pdf",
+        &[("shiftplan", "laden"), ("year", "2026"), ("week", "07")],
+    )
+    .unwrap();
+    assert_eq!(filename, "schichtplan-laden-2026-KW07.pdf");
+
+    let result = expand_filename_pattern("{unknown}.pdf", &[("year", "2026")]);
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("filename_pattern".into()),
+        1,
+    );
+}
@@ -0,0 +1,500 @@
+//! Tests für [`ExportTargetSchedulerImpl`].
+//!
+//! Die Ablage wird über eine feste [`ExportDestinationFactory`] mit
+//! [`MockExportDestination`] ersetzt; geprüft werden Dateinamen, Zähler und
+//! der Zustand des Historien-Eintrags.
+
+use std::sync::{Arc, Mutex};
+
+use mockall::predicate::{always, eq};
+use service::{
+    billing_period::{BillingPeriod, MockBillingPeriodService},
+    clock::MockClockService,
+    export_target::{
+        ExportArtifactType, ExportRunState, ExportTarget, ExportTargetKind, ExportTargetRun,
+        MockExportTargetService,
+    },
+    export_target_scheduler::ExportTargetScheduler,
+    pdf_export_config::{MockPdfExportConfigService, PdfExportConfig, PdfExportShiftplanSetting},
+    pdf_shiftplan::MockPdfShiftplanService,
+    permission::Authentication,
+    report_export::{ExportFile, ExportFormat, MockReportExportService},
+    shiftplan_catalog::{MockShiftplanService, Shiftplan},
+    team_ical::MockTeamIcalService,
+    MockPermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::ShiftyDate;
+use time::macros::{date, datetime};
+use uuid::{uuid, Uuid};
+
+use crate::export_destination::{
+    ExportDestination, ExportDestinationError, ExportDestinationFactory, MockExportDestination,
+};
+use crate::export_target_scheduler::{ExportTargetSchedulerDeps, ExportTargetSchedulerImpl};
+use crate::test::error_test::test_forbidden;
+
+pub struct TestDeps;
+
+impl ExportTargetSchedulerDeps for TestDeps {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type ExportTargetService = MockExportTargetService;
+    type PdfShiftplanService = MockPdfShiftplanService;
+    type ShiftplanService = MockShiftplanService;
+    type PdfExportConfigService = MockPdfExportConfigService;
+    type BillingPeriodService = MockBillingPeriodService;
+    type ReportExportService = MockReportExportService;
+    type TeamIcalService = MockTeamIcalService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+}
+
+/// Liefert immer dieselbe Ablage oder, ohne Ablage, einen
+/// Konfigurationsfehler.
+struct FixedFactory {
+    destination: Option<Arc<dyn ExportDestination>>,
+}
+
+impl ExportDestinationFactory for FixedFactory {
+    fn build(
+        &self,
+        _target: &ExportTarget,
+    ) -> Result<Arc<dyn ExportDestination>, ExportDestinationError> {
+        self.destination
+            .clone()
+            .ok_or_else(|| ExportDestinationError::Configuration("secret fehlt".into()))
+    }
+}
+
+fn target_id() -> Uuid {
+    uuid!("0F0F0000-0000-0000-0000-000000000001")
+}
+
+fn shiftplan_id() -> Uuid {
+    uuid!("0F0F0000-0000-0000-0000-000000000002")
+}
+
+fn target(artifact_type: ExportArtifactType, filename_pattern: &str) -> ExportTarget {
+    ExportTarget {
+        id: target_id(),
+        name: "Aushang Laden".into(),
+        kind: ExportTargetKind::LocalDirectory,
+        enabled: true,
+        url: None,
+        username: None,
+        secret: None,
+        path: "/srv/export".into(),
+        cron_schedule: "0 0 6 * * Mon".into(),
+        artifact_type,
+        filename_pattern: filename_pattern.into(),
+        weeks_horizon: 2,
+        created: Some(datetime!(2026 - 01 - 01 12:00:00)),
+        deleted: None,
+        version: uuid!("0F0F0000-0000-0000-0000-0000000000AA"),
+    }
+}
+
+fn shiftplan(id: Uuid, name: &str, deleted: bool) -> Shiftplan {
+    Shiftplan {
+        id,
+        name: name.into(),
+        is_planning: false,
+        deleted: deleted.then_some(datetime!(2026 - 02 - 01 12:00:00)),
+        version: Uuid::nil(),
+    }
+}
+
+fn planning_shiftplan(id: Uuid, name: &str) -> Shiftplan {
+    Shiftplan {
+        is_planning: true,
+        ..shiftplan(id, name, false)
+    }
+}
+
+fn pdf_export_config(include_planning_plans: bool) -> PdfExportConfig {
+    PdfExportConfig {
+        id: Uuid::nil(),
+        enabled: false,
+        nextcloud_url: None,
+        webdav_user: None,
+        webdav_app_token: None,
+        target_folder: None,
+        weeks_horizon: 1,
+        cron_schedule: "0 0 6 * * Mon".into(),
+        include_planning_plans,
+        combined_pdf: false,
+        last_success_at: None,
+        last_error_at: None,
+        last_error_message: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn billing_period(id: Uuid, start: time::Date, end: time::Date) -> BillingPeriod {
+    BillingPeriod {
+        id,
+        start_date: ShiftyDate::from_date(start),
+        end_date: ShiftyDate::from_date(end),
+        snapshot_schema_version: 1,
+        sales_persons: Arc::from([]),
+        created_at: datetime!(2026 - 03 - 01 12:00:00),
+        created_by: "admin".into(),
+        deleted_at: None,
+        deleted_by: None,
+    }
+}
+
+/// Mocks, die jeder Lauf braucht: Ziel laden, Uhr, Historie (der
+/// gespeicherte Lauf landet in `recorded`) und die Shiftplan-Auswahl des
+/// Nextcloud-Exports (ohne Einstellungen, ohne Planungs-Plans).
+struct Setup {
+    export_target_service: MockExportTargetService,
+    pdf_shiftplan_service: MockPdfShiftplanService,
+    shiftplan_service: MockShiftplanService,
+    include_planning_plans: bool,
+    shiftplan_settings: Vec<PdfExportShiftplanSetting>,
+    billing_period_service: MockBillingPeriodService,
+    report_export_service: MockReportExportService,
+    team_ical_service: MockTeamIcalService,
+    recorded: Arc<Mutex<Option<ExportTargetRun>>>,
+}
+
+impl Setup {
+    fn new(target: ExportTarget) -> Self {
+        let mut export_target_service = MockExportTargetService::new();
+        export_target_service
+            .expect_get()
+            .with(eq(target_id()), eq(Authentication::Full), always())
+            .returning(move |_, _, _| Ok(target.clone()));
+        let recorded = Arc::new(Mutex::new(None));
+        let recorded_clone = recorded.clone();
+        export_target_service
+            .expect_record_run()
+            .times(1)
+            .returning(move |run, _, _| {
+                *recorded_clone.lock().unwrap() = Some(run.clone());
+                Ok(run.clone())
+            });
+        Self {
+            export_target_service,
+            pdf_shiftplan_service: MockPdfShiftplanService::new(),
+            shiftplan_service: MockShiftplanService::new(),
+            include_planning_plans: false,
+            shiftplan_settings: Vec::new(),
+            billing_period_service: MockBillingPeriodService::new(),
+            report_export_service: MockReportExportService::new(),
+            team_ical_service: MockTeamIcalService::new(),
+            recorded,
+        }
+    }
+
+    fn with_shiftplans(mut self, shiftplans: Vec<Shiftplan>) -> Self {
+        self.shiftplan_service
+            .expect_get_all()
+            .returning(move |_, _| Ok(shiftplans.clone().into()));
+        self
+    }
+
+    async fn run(self, destination: Option<MockExportDestination>) -> ExportTargetRun {
+        let mut permission_service = MockPermissionService::new();
+        permission_service
+            .expect_check_only_full_authentication()
+            .returning(|context| match context {
+                Authentication::Full => Ok(()),
+                _ => Err(ServiceError::Forbidden),
+            });
+        let mut clock_service = MockClockService::new();
+        // 2026-07-01 ist ein Mittwoch in KW 27.
+        clock_service
+            .expect_date_now()
+            .returning(|| date!(2026 - 07 - 01));
+        clock_service
+            .expect_date_time_now()
+            .returning(|| datetime!(2026 - 07 - 01 06:00:00));
+        let mut pdf_export_config_service = MockPdfExportConfigService::new();
+        let include_planning_plans = self.include_planning_plans;
+        pdf_export_config_service
+            .expect_get()
+            .returning(move |_, _| Ok(pdf_export_config(include_planning_plans)));
+        let shiftplan_settings = self.shiftplan_settings;
+        pdf_export_config_service
+            .expect_get_shiftplan_settings()
+            .returning(move |_, _| Ok(shiftplan_settings.clone().into()));
+        let destination = destination.map(|d| Arc::new(d) as Arc<dyn ExportDestination>);
+        let scheduler = ExportTargetSchedulerImpl::<TestDeps>::new(
+            Arc::new(self.export_target_service),
+            Arc::new(self.pdf_shiftplan_service),
+            Arc::new(self.shiftplan_service),
+            Arc::new(pdf_export_config_service),
+            Arc::new(self.billing_period_service),
+            Arc::new(self.report_export_service),
+            Arc::new(self.team_ical_service),
+            Arc::new(permission_service),
+            Arc::new(clock_service),
+            Arc::new(FixedFactory { destination }),
+        );
+        let run = scheduler
+            .run_target_now(target_id(), Authentication::Full)
+            .await
+            .unwrap();
+        assert_eq!(self.recorded.lock().unwrap().as_ref(), Some(&run));
+        run
+    }
+}
+
+#[tokio::test]
+async fn week_pdf_skips_unreleased_weeks_and_ignores_deleted_plans() {
+    let deleted_id = uuid!("0F0F0000-0000-0000-0000-000000000003");
+    let mut setup = Setup::new(target(
+        ExportArtifactType::ShiftplanWeekPdf,
+        "{target}-{shiftplan}-{year}-KW{week}.pdf",
+    ))
+    .with_shiftplans(vec![
+        shiftplan(shiftplan_id(), "Laden Süd", false),
+        shiftplan(deleted_id, "Alt", true),
+    ]);
+    setup
+        .pdf_shiftplan_service
+        .expect_render_week_pdf()
+        .with(
+            eq(shiftplan_id()),
+            eq(2026),
+            eq(27),
+            eq(Authentication::Full),
+            always(),
+        )
+        .returning(|_, _, _, _, _| Ok(b"%PDF".to_vec()));
+    setup
+        .pdf_shiftplan_service
+        .expect_render_week_pdf()
+        .with(
+            eq(shiftplan_id()),
+            eq(2026),
+            eq(28),
+            eq(Authentication::Full),
+            always(),
+        )
+        .returning(|_, _, _, _, _| {
+            Err(ServiceError::ValidationError(Arc::from([
+                ValidationFailureItem::InvalidValue("week_status".into()),
+            ])))
+        });
+
+    let mut destination = MockExportDestination::new();
+    destination
+        .expect_store_file()
+        .withf(|filename, bytes| {
+            filename == "aushang-laden-laden-sued-2026-KW27.pdf" && bytes == b"%PDF"
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let run = setup.run(Some(destination)).await;
+    assert_eq!(run.state, ExportRunState::Success);
+    assert_eq!(run.files_written, 1);
+    assert_eq!(run.files_skipped, 1);
+    assert_eq!(run.started, datetime!(2026 - 07 - 01 06:00:00));
+    assert!(run.message.unwrap().contains("KW28/2026"));
+}
+
+/// Ziele folgen der Auswahl des Nextcloud-Exports: abgewählte Plans und
+/// Planungs-Plans ohne `include_planning_plans` entfallen.
+#[tokio::test]
+async fn week_pdf_follows_shiftplan_selection() {
+    let excluded_id = uuid!("0F0F0000-0000-0000-0000-000000000005");
+    let planning_id = uuid!("0F0F0000-0000-0000-0000-000000000006");
+    let mut setup = Setup::new(target(ExportArtifactType::ShiftplanWeekPdf, ""))
+        .with_shiftplans(vec![
+            shiftplan(shiftplan_id(), "Laden", false),
+            shiftplan(excluded_id, "Lager", false),
+            planning_shiftplan(planning_id, "Entwurf"),
+        ]);
+    setup.shiftplan_settings = vec![PdfExportShiftplanSetting {
+        shiftplan_id: excluded_id,
+        included: false,
+        subfolder: None,
+        filename_prefix: None,
+    }];
+    setup
+        .pdf_shiftplan_service
+        .expect_render_week_pdf()
+        .with(eq(shiftplan_id()), always(), always(), always(), always())
+        .times(2)
+        .returning(|_, _, _, _, _| Ok(b"%PDF".to_vec()));
+
+    let mut destination = MockExportDestination::new();
+    destination
+        .expect_store_file()
+        .withf(|filename, _| filename.starts_with("schichtplan-laden-"))
+        .times(2)
+        .returning(|_, _| Ok(()));
+
+    let run = setup.run(Some(destination)).await;
+    assert_eq!(run.state, ExportRunState::Success);
+    assert_eq!(run.files_written, 2);
+}
+
+#[tokio::test]
+async fn ical_snapshot_includes_planning_plans_when_enabled() {
+    let planning_id = uuid!("0F0F0000-0000-0000-0000-000000000006");
+    let mut setup = Setup::new(target(ExportArtifactType::IcalSnapshot, ""))
+        .with_shiftplans(vec![planning_shiftplan(planning_id, "Entwurf")]);
+    setup.include_planning_plans = true;
+    setup
+        .team_ical_service
+        .expect_get_shiftplan_as_ical()
+        .with(eq(planning_id), eq(Authentication::Full), always())
+        .times(1)
+        .returning(|_, _, _| Ok("BEGIN:VCALENDAR".into()));
+
+    let mut destination = MockExportDestination::new();
+    destination
+        .expect_store_file()
+        .withf(|filename, _| filename == "schichtplan-entwurf.ics")
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let run = setup.run(Some(destination)).await;
+    assert_eq!(run.state, ExportRunState::Success);
+    assert_eq!(run.files_written, 1);
+}
+
+#[tokio::test]
+async fn week_pdf_render_error_makes_run_partial() {
+    let mut setup = Setup::new(target(ExportArtifactType::ShiftplanWeekPdf, ""))
+        .with_shiftplans(vec![shiftplan(shiftplan_id(), "Laden", false)]);
+    setup
+        .pdf_shiftplan_service
+        .expect_render_week_pdf()
+        .with(always(), always(), eq(27), always(), always())
+        .returning(|_, _, _, _, _| Ok(b"%PDF".to_vec()));
+    setup
+        .pdf_shiftplan_service
+        .expect_render_week_pdf()
+        .with(always(), always(), eq(28), always(), always())
+        .returning(|_, _, _, _, _| Err(ServiceError::InternalError));
+
+    let mut destination = MockExportDestination::new();
+    destination
+        .expect_store_file()
+        .withf(|filename, _| filename == "schichtplan-laden-2026-KW27.pdf")
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let run = setup.run(Some(destination)).await;
+    assert_eq!(run.state, ExportRunState::Partial);
+    assert_eq!(run.files_written, 1);
+    assert_eq!(run.files_skipped, 0);
+}
+
+#[tokio::test]
+async fn billing_period_csv_uses_latest_period() {
+    let older = uuid!("0F0F0000-0000-0000-0000-000000000010");
+    let latest = uuid!("0F0F0000-0000-0000-0000-000000000011");
+    let mut setup = Setup::new(target(ExportArtifactType::BillingPeriodCsv, ""));
+    setup
+        .billing_period_service
+        .expect_get_billing_period_overview()
+        .returning(move |_, _| {
+            Ok(Arc::from([
+                billing_period(latest, date!(2026 - 06 - 01), date!(2026 - 06 - 30)),
+                billing_period(older, date!(2026 - 05 - 01), date!(2026 - 05 - 31)),
+            ]))
+        });
+    setup
+        .report_export_service
+        .expect_export_billing_period()
+        .with(
+            eq(latest),
+            eq(ExportFormat::Csv),
+            always(),
+            eq(Authentication::Full),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| {
+            Ok(ExportFile {
+                file_name: "ignored.csv".into(),
+                content_type: "text/csv",
+                content: Arc::from(&b"name;stunden"[..]),
+            })
+        });
+
+    let mut destination = MockExportDestination::new();
+    destination
+        .expect_store_file()
+        .withf(|filename, bytes| {
+            filename == "abrechnung-2026-06-01-2026-06-30.csv" && bytes == b"name;stunden"
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let run = setup.run(Some(destination)).await;
+    assert_eq!(run.state, ExportRunState::Success);
+    assert_eq!(run.files_written, 1);
+    assert_eq!(run.message, None);
+}
+
+#[tokio::test]
+async fn ical_snapshot_stops_after_first_store_error() {
+    let second = uuid!("0F0F0000-0000-0000-0000-000000000004");
+    let mut setup = Setup::new(target(ExportArtifactType::IcalSnapshot, "")).with_shiftplans(vec![
+        shiftplan(shiftplan_id(), "Laden", false),
+        shiftplan(second, "Lager", false),
+    ]);
+    setup
+        .team_ical_service
+        .expect_get_shiftplan_as_ical()
+        .with(eq(shiftplan_id()), eq(Authentication::Full), always())
+        .times(1)
+        .returning(|_, _, _| Ok("BEGIN:VCALENDAR".into()));
+
+    let mut destination = MockExportDestination::new();
+    destination
+        .expect_store_file()
+        .withf(|filename, _| filename == "schichtplan-laden.ics")
+        .times(1)
+        .returning(|_, _| {
+            Err(ExportDestinationError::Io(std::io::Error::other(
+                "disk full",
+            )))
+        });
+
+    let run = setup.run(Some(destination)).await;
+    assert_eq!(run.state, ExportRunState::Failed);
+    assert_eq!(run.files_written, 0);
+    assert!(run.message.unwrap().contains("schichtplan-laden.ics"));
+}
+
+#[tokio::test]
+async fn destination_error_records_failed_run() {
+    let setup = Setup::new(target(ExportArtifactType::IcalSnapshot, ""));
+
+    let run = setup.run(None).await;
+    assert_eq!(run.state, ExportRunState::Failed);
+    assert!(run.message.unwrap().contains("secret fehlt"));
+}
+
+#[tokio::test]
+async fn run_target_now_requires_full_authentication() {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|_| Err(ServiceError::Forbidden));
+    let scheduler = ExportTargetSchedulerImpl::<TestDeps>::new(
+        Arc::new(MockExportTargetService::new()),
+        Arc::new(MockPdfShiftplanService::new()),
+        Arc::new(MockShiftplanService::new()),
+        Arc::new(MockPdfExportConfigService::new()),
+        Arc::new(MockBillingPeriodService::new()),
+        Arc::new(MockReportExportService::new()),
+        Arc::new(MockTeamIcalService::new()),
+        Arc::new(permission_service),
+        Arc::new(MockClockService::new()),
+        Arc::new(FixedFactory { destination: None }),
+    );
+    let result = scheduler
+        .run_target_now(target_id(), Authentication::Context(()))
+        .await;
+    test_forbidden(&result);
+}
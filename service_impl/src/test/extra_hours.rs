@@ -26,6 +26,8 @@ use dao::MockTransaction;
 use dao::MockTransactionDao;
 use mockall::predicate::always;
 use mockall::predicate::eq;
+use service::audit_log::MockAuditLogService;
+use service::carryover::MockCarryoverService;
 use service::clock::MockClockService;
 use service::custom_extra_hours::MockCustomExtraHoursService;
 use service::extra_hours::ExtraHours;
@@ -125,6 +127,8 @@ pub struct ExtraHoursDependencies {
     pub custom_extra_hours_service: MockCustomExtraHoursService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub carryover_service: MockCarryoverService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -137,6 +141,8 @@ impl ExtraHoursServiceDeps for ExtraHoursDependencies {
     type CustomExtraHoursService = MockCustomExtraHoursService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type CarryoverService = MockCarryoverService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -149,6 +155,8 @@ impl ExtraHoursDependencies {
             custom_extra_hours_service: self.custom_extra_hours_service.into(),
             clock_service: self.clock_service.into(),
             uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            carryover_service: self.carryover_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -163,6 +171,22 @@ fn build_default_transaction_dao() -> MockTransactionDao {
     transaction_dao
 }
 
+fn build_default_audit_log_service() -> MockAuditLogService {
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+    audit_log_service
+}
+
+fn build_default_carryover_service() -> MockCarryoverService {
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .returning(|_, _, _, _| Ok(()));
+    carryover_service
+}
+
 // ----------------------------------------------------------------------------
 // Helper for the logical_id update tests (Side #2 — pre-Phase-4)
 // ----------------------------------------------------------------------------
@@ -197,6 +221,8 @@ fn build_dependencies() -> ExtraHoursDependencies {
         custom_extra_hours_service,
         clock_service,
         uuid_service,
+        audit_log_service: build_default_audit_log_service(),
+        carryover_service: build_default_carryover_service(),
         transaction_dao,
     }
 }
@@ -281,6 +307,8 @@ fn build_dependencies_for_create() -> ExtraHoursDependencies {
         custom_extra_hours_service: MockCustomExtraHoursService::new(),
         clock_service,
         uuid_service,
+        audit_log_service: build_default_audit_log_service(),
+        carryover_service: build_default_carryover_service(),
         transaction_dao: build_default_transaction_dao(),
     }
 }
@@ -609,6 +637,61 @@ async fn test_update_persists_editable_fields_to_new_row() {
     assert!(new_row.deleted.is_none());
 }
 
+#[tokio::test]
+async fn test_create_marks_earlier_year_at_iso_year_boundary() {
+    // 2027-01-01 liegt in ISO-KW 53/2026 — der Carryover 2026 ist betroffen.
+    let mut deps = build_dependencies_for_create();
+    deps.extra_hours_dao
+        .expect_create()
+        .returning(|_, _, _| Ok(()));
+    deps.carryover_service = MockCarryoverService::new();
+    deps.carryover_service
+        .expect_mark_dirty()
+        .with(eq(fixture_sales_person_id()), eq(2026), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let entity = ExtraHours {
+        date_time: datetime!(2027-01-01 0:00:00),
+        ..fixture_extra_hours(ExtraHoursCategory::ExtraWork)
+    };
+    service.create(&entity, ().auth(), None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_update_moving_entry_back_marks_earlier_year() {
+    let mut deps = build_dependencies();
+    deps.extra_hours_dao
+        .expect_find_by_logical_id()
+        .returning(|_, _| Ok(Some(default_active_entity())));
+    deps.extra_hours_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    deps.extra_hours_dao
+        .expect_create()
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| alternate_version());
+    deps.carryover_service = MockCarryoverService::new();
+    deps.carryover_service
+        .expect_mark_dirty()
+        .with(always(), eq(2025), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let request = ExtraHours {
+        date_time: datetime!(2025-11-03 10:00:00),
+        ..default_update_request()
+    };
+    service
+        .update(&request, Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
 // ============================================================================
 // Tests — Phase 4 / Plan 04-04 (flag-gate + soft_delete_bulk)
 // ============================================================================
@@ -692,6 +775,8 @@ async fn soft_delete_bulk_calls_dao_with_provided_ids_and_update_process() {
         custom_extra_hours_service: MockCustomExtraHoursService::new(),
         clock_service,
         uuid_service,
+        audit_log_service: build_default_audit_log_service(),
+        carryover_service: build_default_carryover_service(),
         transaction_dao: build_default_transaction_dao(),
     };
 
@@ -736,6 +821,8 @@ async fn soft_delete_bulk_forbidden_for_unprivileged_user() {
         custom_extra_hours_service: MockCustomExtraHoursService::new(),
         clock_service: MockClockService::new(),
         uuid_service: MockUuidService::new(),
+        audit_log_service: build_default_audit_log_service(),
+        carryover_service: build_default_carryover_service(),
         transaction_dao,
     };
 
@@ -785,6 +872,8 @@ fn build_deps_for_find_by_year(
         custom_extra_hours_service: MockCustomExtraHoursService::new(),
         clock_service: MockClockService::new(),
         uuid_service: MockUuidService::new(),
+        audit_log_service: build_default_audit_log_service(),
+        carryover_service: build_default_carryover_service(),
         transaction_dao: build_default_transaction_dao(),
     }
 }
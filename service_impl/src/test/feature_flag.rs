@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use crate::feature_flag::{FeatureFlagServiceDeps, FeatureFlagServiceImpl};
-use dao::feature_flag::MockFeatureFlagDao;
+use dao::feature_flag::{FeatureFlagEntity, MockFeatureFlagDao};
 use dao::{MockTransaction, MockTransactionDao};
 use mockall::predicate::{always, eq};
+use service::audit_log::{entity_type, AuditAction, MockAuditLogService};
 use service::feature_flag::{FeatureFlagService, FEATURE_FLAG_ADMIN_PRIVILEGE};
 use service::permission::{Authentication, MockPermissionService};
 use service::ServiceError;
@@ -11,6 +12,7 @@ use service::ServiceError;
 pub struct FeatureFlagServiceDependencies {
     pub feature_flag_dao: MockFeatureFlagDao,
     pub permission_service: MockPermissionService,
+    pub audit_log_service: MockAuditLogService,
 }
 
 impl FeatureFlagServiceDeps for FeatureFlagServiceDependencies {
@@ -18,6 +20,7 @@ impl FeatureFlagServiceDeps for FeatureFlagServiceDependencies {
     type Transaction = MockTransaction;
     type FeatureFlagDao = MockFeatureFlagDao;
     type PermissionService = MockPermissionService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -32,6 +35,7 @@ impl FeatureFlagServiceDependencies {
         FeatureFlagServiceImpl {
             feature_flag_dao: self.feature_flag_dao.into(),
             permission_service: Arc::new(self.permission_service),
+            audit_log_service: Arc::new(self.audit_log_service),
             transaction_dao: Arc::new(transaction_dao),
         }
     }
@@ -41,6 +45,7 @@ fn build_dependencies() -> FeatureFlagServiceDependencies {
     FeatureFlagServiceDependencies {
         feature_flag_dao: MockFeatureFlagDao::new(),
         permission_service: MockPermissionService::new(),
+        audit_log_service: MockAuditLogService::new(),
     }
 }
 
@@ -161,10 +166,22 @@ async fn test_is_enabled_authentication_full_bypasses_user_check() {
 
 // set tests ---------------------------------------------------------------
 
+fn test_flag_entity() -> FeatureFlagEntity {
+    FeatureFlagEntity {
+        key: "test_flag".into(),
+        enabled: false,
+        description: Some("Test".into()),
+    }
+}
+
 #[tokio::test]
 async fn test_set_requires_admin_permission() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_feature_flag_admin_permission_service();
+    deps.feature_flag_dao
+        .expect_get()
+        .with(eq("test_flag"), always())
+        .returning(|_, _| Ok(Some(test_flag_entity())));
     deps.feature_flag_dao
         .expect_set()
         .withf(|key, value, process, _| {
@@ -173,6 +190,25 @@ async fn test_set_requires_admin_permission() {
                 && process == "feature-flag-service"
         })
         .returning(|_, _, _, _| Ok(()));
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, process, _, tx| {
+            change.entity_type == entity_type::FEATURE_FLAG
+                && change.entity_id.as_ref() == "test_flag"
+                && change.action == AuditAction::Update
+                && change
+                    .before
+                    .as_deref()
+                    .is_some_and(|before| before.contains(r#""enabled":false"#))
+                && change
+                    .after
+                    .as_deref()
+                    .is_some_and(|after| after.contains(r#""enabled":true"#))
+                && process == "feature-flag-service"
+                && tx.is_some()
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
 
     let service = deps.build_service();
     let result = service
@@ -187,6 +223,7 @@ async fn test_set_forbidden_for_non_admin() {
     deps.permission_service = mock_no_feature_flag_admin_permission_service();
     // DAO must NOT be called when permission check fails.
     deps.feature_flag_dao.expect_set().times(0);
+    deps.audit_log_service.expect_record().times(0);
 
     let service = deps.build_service();
     let result = service
@@ -194,3 +231,22 @@ async fn test_set_forbidden_for_non_admin() {
         .await;
     assert!(matches!(result, Err(ServiceError::Forbidden)));
 }
+
+#[tokio::test]
+async fn test_set_unknown_key_is_not_audited() {
+    let mut deps = build_dependencies();
+    deps.permission_service = mock_feature_flag_admin_permission_service();
+    deps.feature_flag_dao
+        .expect_get()
+        .returning(|_, _| Ok(None));
+    deps.feature_flag_dao
+        .expect_set()
+        .returning(|_, _, _, _| Ok(()));
+    deps.audit_log_service.expect_record().times(0);
+
+    let service = deps.build_service();
+    let result = service
+        .set("nonexistent", true, ().auth(), None)
+        .await;
+    assert!(result.is_ok());
+}
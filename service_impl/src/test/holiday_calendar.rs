@@ -0,0 +1,190 @@
+use std::sync::{Arc, Mutex};
+
+use mockall::predicate::{always, eq};
+use service::holiday_calendar::{HolidayCalendarService, HolidayRegion};
+use service::permission::{Authentication, SHIFTPLANNER_PRIVILEGE};
+use service::special_days::{MockSpecialDayService, SpecialDay, SpecialDayType};
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use shifty_utils::{DayOfWeek, ShiftyDate};
+use time::macros::{date, datetime, time};
+use uuid::Uuid;
+
+use crate::holiday_calendar::{holidays, HolidayCalendarServiceDeps, HolidayCalendarServiceImpl};
+use crate::test::error_test::{test_forbidden, test_validation_error};
+
+struct HolidayCalendarDependencies {
+    special_day_service: MockSpecialDayService,
+    permission_service: MockPermissionService,
+}
+
+impl HolidayCalendarServiceDeps for HolidayCalendarDependencies {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type SpecialDayService = MockSpecialDayService;
+    type PermissionService = MockPermissionService;
+}
+
+impl HolidayCalendarDependencies {
+    fn new(permission: bool) -> Self {
+        let mut permission_service = MockPermissionService::new();
+        permission_service
+            .expect_check_permission()
+            .with(eq(SHIFTPLANNER_PRIVILEGE), always())
+            .returning(move |_, _| {
+                if permission {
+                    Ok(())
+                } else {
+                    Err(ServiceError::Forbidden)
+                }
+            });
+        Self {
+            special_day_service: MockSpecialDayService::new(),
+            permission_service,
+        }
+    }
+
+    fn build_service(self) -> HolidayCalendarServiceImpl<HolidayCalendarDependencies> {
+        HolidayCalendarServiceImpl {
+            special_day_service: self.special_day_service.into(),
+            permission_service: self.permission_service.into(),
+        }
+    }
+}
+
+fn special_day(date: time::Date, day_type: SpecialDayType) -> SpecialDay {
+    let shifty_date = ShiftyDate::from_date(date);
+    SpecialDay {
+        id: Uuid::from_u128(date.to_julian_day() as u128),
+        year: shifty_date.year(),
+        calendar_week: shifty_date.week(),
+        day_of_week: shifty_date.day_of_week(),
+        time_of_day: (day_type == SpecialDayType::ShortDay).then_some(time!(14:00)),
+        day_type,
+        created: Some(datetime!(2025-12-01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+/// Neujahr ist schon als Feiertag angelegt, der 2. Weihnachtstag als ShortDay.
+fn existing_2026() -> Arc<[SpecialDay]> {
+    Arc::new([
+        special_day(date!(2026 - 01 - 01), SpecialDayType::Holiday),
+        special_day(date!(2026 - 12 - 26), SpecialDayType::ShortDay),
+    ])
+}
+
+#[tokio::test]
+async fn test_preview_marks_existing_special_days() {
+    let mut deps = HolidayCalendarDependencies::new(true);
+    deps.special_day_service
+        .expect_get_by_year()
+        .with(eq(2026), always())
+        .returning(|_, _| Ok(existing_2026()));
+    let service = deps.build_service();
+
+    let candidates = service
+        .preview(2026, HolidayRegion::Germany, Authentication::Context(()))
+        .await
+        .unwrap();
+
+    assert_eq!(candidates.len(), 9);
+    assert_eq!(candidates[0].date, date!(2026 - 01 - 01));
+    assert_eq!(candidates[0].name.as_ref(), "Neujahr");
+    assert_eq!(candidates[0].existing, Some(existing_2026()[0].clone()));
+    assert_eq!(candidates[1].name.as_ref(), "Karfreitag");
+    assert_eq!(candidates[1].existing, None);
+    assert_eq!(candidates[8].existing, Some(existing_2026()[1].clone()));
+}
+
+#[tokio::test]
+async fn test_generate_creates_only_missing_holidays() {
+    let mut deps = HolidayCalendarDependencies::new(true);
+    deps.special_day_service
+        .expect_get_by_year()
+        .returning(|_, _| Ok(existing_2026()));
+    let created: Arc<Mutex<Vec<SpecialDay>>> = Arc::new(Mutex::new(Vec::new()));
+    let created_in_mock = created.clone();
+    deps.special_day_service
+        .expect_create()
+        .times(7)
+        .returning(move |special_day, _| {
+            created_in_mock.lock().unwrap().push(special_day.clone());
+            Ok(SpecialDay {
+                id: Uuid::new_v4(),
+                version: Uuid::new_v4(),
+                ..special_day.clone()
+            })
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .generate(2026, HolidayRegion::Germany, Authentication::Context(()))
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 7);
+    let created = created.lock().unwrap();
+    assert!(created
+        .iter()
+        .all(|day| day.day_type == SpecialDayType::Holiday
+            && day.time_of_day.is_none()
+            && day.id.is_nil()
+            && day.version.is_nil()));
+    // Karfreitag 2026: Freitag der KW 14.
+    assert_eq!(
+        (created[0].year, created[0].calendar_week, created[0].day_of_week),
+        (2026, 14, DayOfWeek::Friday)
+    );
+}
+
+#[tokio::test]
+async fn test_generate_is_idempotent() {
+    let mut deps = HolidayCalendarDependencies::new(true);
+    deps.special_day_service.expect_get_by_year().returning(|_, _| {
+        Ok(holidays(HolidayRegion::Saxony, 2026)
+            .unwrap()
+            .into_iter()
+            .map(|(date, _)| special_day(date, SpecialDayType::Holiday))
+            .collect())
+    });
+    deps.special_day_service.expect_create().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .generate(2026, HolidayRegion::Saxony, Authentication::Context(()))
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn test_generate_requires_shiftplanner() {
+    let mut deps = HolidayCalendarDependencies::new(false);
+    deps.special_day_service.expect_get_by_year().times(0);
+    deps.special_day_service.expect_create().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .generate(2026, HolidayRegion::Bavaria, Authentication::Context(()))
+        .await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_preview_rejects_pre_gregorian_year() {
+    let deps = HolidayCalendarDependencies::new(true);
+    let service = deps.build_service();
+
+    let result = service
+        .preview(1500, HolidayRegion::Germany, Authentication::Context(()))
+        .await;
+
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("year".into()),
+        1,
+    );
+}
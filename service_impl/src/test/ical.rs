@@ -0,0 +1,140 @@
+//! RFC 5545-Ausgabe von `IcalServiceImpl`.
+
+use std::sync::Arc;
+
+use service::ical::{IcalCalendar, IcalEvent, IcalService};
+use time::macros::datetime;
+
+use crate::ical::IcalServiceImpl;
+
+fn event() -> IcalEvent {
+    IcalEvent {
+        uid: "522c46c6-1062-4ce2-8fdf-c9530dcc7fc2@shifty".into(),
+        sequence: 7,
+        start: datetime!(2026-07-27 09:00:00),
+        end: datetime!(2026-07-27 13:00:00),
+        all_day: false,
+        summary: "Schicht".into(),
+        description: None,
+        cancelled: false,
+    }
+}
+
+fn render(timezone: &str, events: Vec<IcalEvent>) -> String {
+    IcalServiceImpl
+        .render_calendar(
+            &IcalCalendar {
+                name: "Schicht".into(),
+                timezone: timezone.into(),
+                events: Arc::from(events),
+            },
+            datetime!(2026-07-20 06:30:00),
+        )
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_render_event_with_timezone() {
+    let ical = render("Europe/Berlin", vec![event()]);
+
+    assert!(ical.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ical.ends_with("END:VCALENDAR\r\n"));
+    assert!(
+        !ical.replace("\r\n", "").contains('\n'),
+        "only CRLF line ends"
+    );
+    assert!(ical.contains("\r\nUID:522c46c6-1062-4ce2-8fdf-c9530dcc7fc2@shifty\r\n"));
+    assert!(ical.contains("\r\nDTSTAMP:20260720T063000Z\r\n"));
+    assert!(ical.contains("\r\nSEQUENCE:7\r\n"));
+    assert!(ical.contains("\r\nDTSTART;TZID=Europe/Berlin:20260727T090000\r\n"));
+    assert!(ical.contains("\r\nDTEND;TZID=Europe/Berlin:20260727T130000\r\n"));
+    assert!(ical.contains("\r\nSTATUS:CONFIRMED\r\n"));
+}
+
+#[test]
+fn test_render_vtimezone_transitions() {
+    let ical = render("Europe/Berlin", vec![event()]);
+
+    assert!(ical.contains("\r\nBEGIN:VTIMEZONE\r\nTZID:Europe/Berlin\r\n"));
+    // Sommerzeit 2026 beginnt am 29.03. um 02:00 Ortszeit.
+    assert!(ical.contains(
+        "BEGIN:DAYLIGHT\r\nDTSTART:20260329T020000\r\nTZOFFSETFROM:+0100\r\nTZOFFSETTO:+0200\r\nTZNAME:CEST\r\nEND:DAYLIGHT\r\n"
+    ));
+    // Das Vorjahr ist enthalten, damit Termine vor dem ersten Wechsel passen.
+    assert!(ical.contains(
+        "BEGIN:STANDARD\r\nDTSTART:20251026T030000\r\nTZOFFSETFROM:+0200\r\nTZOFFSETTO:+0100\r\nTZNAME:CET\r\nEND:STANDARD\r\n"
+    ));
+}
+
+#[test]
+fn test_render_escapes_and_folds_description() {
+    let description = format!(
+        "Kolleg:innen: Anna, Bea; Carl\\Dora\nWochennachricht: {}",
+        "Ä".repeat(60)
+    );
+    let ical = render(
+        "Europe/Berlin",
+        vec![IcalEvent {
+            description: Some(description.into()),
+            ..event()
+        }],
+    );
+
+    for line in ical.split("\r\n") {
+        assert!(line.len() <= 75, "line too long: {line}");
+    }
+    let unfolded = ical.replace("\r\n ", "");
+    assert!(unfolded.contains(&format!(
+        "\r\nDESCRIPTION:Kolleg:innen: Anna\\, Bea\\; Carl\\\\Dora\\nWochennachricht: {}\r\n",
+        "Ä".repeat(60)
+    )));
+}
+
+#[test]
+fn test_render_cancelled_event() {
+    let ical = render(
+        "Europe/Berlin",
+        vec![IcalEvent {
+            cancelled: true,
+            ..event()
+        }],
+    );
+
+    assert!(ical.contains("\r\nSTATUS:CANCELLED\r\n"));
+    assert!(!ical.contains("STATUS:CONFIRMED"));
+}
+
+#[test]
+fn test_render_all_day_event() {
+    let ical = render(
+        "Europe/Berlin",
+        vec![IcalEvent {
+            start: datetime!(2026-07-27 00:00:00),
+            end: datetime!(2026-07-30 00:00:00),
+            all_day: true,
+            ..event()
+        }],
+    );
+
+    assert!(ical.contains("\r\nDTSTART;VALUE=DATE:20260727\r\n"));
+    assert!(ical.contains("\r\nDTEND;VALUE=DATE:20260730\r\n"));
+    assert!(!ical.contains("DTSTART;TZID"));
+}
+
+#[test]
+fn test_render_unknown_timezone_writes_floating_times() {
+    let ical = render("Mars/Olympus", vec![event()]);
+
+    assert!(!ical.contains("VTIMEZONE"));
+    assert!(ical.contains("\r\nDTSTART:20260727T090000\r\n"));
+}
+
+#[test]
+fn test_render_timezone_without_transitions() {
+    let ical = render("UTC", vec![event()]);
+
+    assert!(ical.contains(
+        "BEGIN:STANDARD\r\nDTSTART:19700101T000000\r\nTZOFFSETFROM:+0000\r\nTZOFFSETTO:+0000\r\n"
+    ));
+}
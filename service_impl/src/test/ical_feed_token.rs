@@ -0,0 +1,515 @@
+use crate::test::error_test::*;
+use dao::{
+    ical_feed_token::{IcalFeedScopeEntity, IcalFeedTokenEntity, MockIcalFeedTokenDao},
+    MockTransaction, MockTransactionDao,
+};
+use mockall::predicate::{always, eq};
+use service::{
+    audit_log::MockAuditLogService,
+    block::MockBlockService,
+    clock::MockClockService,
+    ical_feed_token::{IcalFeedScope, IcalFeedTokenService},
+    permission::{Authentication, MockPermissionService, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::{MockSalesPersonService, SalesPerson},
+    shiftplan_catalog::{MockShiftplanService, Shiftplan},
+    team_ical::MockTeamIcalService,
+    uuid_service::MockUuidService,
+    ServiceError,
+};
+use uuid::{uuid, Uuid};
+
+use crate::ical_feed_token::{IcalFeedTokenServiceDeps, IcalFeedTokenServiceImpl};
+
+fn default_id() -> Uuid {
+    uuid!("3C0F1A52-0B2D-4C11-9E1B-7A1D2C3B4A50")
+}
+
+fn default_sales_person_id() -> Uuid {
+    uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F0")
+}
+
+fn default_shiftplan_id() -> Uuid {
+    uuid!("B1C2D3E4-F5A6-4B7C-8D9E-0F1A2B3C4D5E")
+}
+
+fn default_token() -> Uuid {
+    uuid!("8E3B6F0A-4D2C-4B7E-9A1F-5C6D7E8F9A0B")
+}
+
+fn default_entity() -> IcalFeedTokenEntity {
+    IcalFeedTokenEntity {
+        id: default_id(),
+        scope: IcalFeedScopeEntity::SalesPerson(default_sales_person_id()),
+        token: default_token(),
+        label: "Handy".into(),
+        created: generate_default_datetime(),
+        revoked: None,
+        version: uuid!("AAAAAAAA-BBBB-4CCC-8DDD-EEEEEEEEEEEE"),
+    }
+}
+
+pub struct IcalFeedTokenServiceDependencies {
+    pub ical_feed_token_dao: MockIcalFeedTokenDao,
+    pub block_service: MockBlockService,
+    pub team_ical_service: MockTeamIcalService,
+    pub shiftplan_service: MockShiftplanService,
+    pub sales_person_service: MockSalesPersonService,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl IcalFeedTokenServiceDeps for IcalFeedTokenServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type IcalFeedTokenDao = MockIcalFeedTokenDao;
+    type BlockService = MockBlockService;
+    type TeamIcalService = MockTeamIcalService;
+    type ShiftplanService = MockShiftplanService;
+    type SalesPersonService = MockSalesPersonService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl IcalFeedTokenServiceDependencies {
+    pub fn build_service(self) -> IcalFeedTokenServiceImpl<IcalFeedTokenServiceDependencies> {
+        IcalFeedTokenServiceImpl {
+            ical_feed_token_dao: self.ical_feed_token_dao.into(),
+            block_service: self.block_service.into(),
+            team_ical_service: self.team_ical_service.into(),
+            shiftplan_service: self.shiftplan_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            permission_service: self.permission_service.into(),
+            clock_service: self.clock_service.into(),
+            uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// `is_owner`: der angemeldete Benutzer ist der Mitarbeiter selbst.
+/// Weitere Rechte gibt es nur über [`build_dependencies_with_privilege`].
+fn build_dependencies(is_owner: bool) -> IcalFeedTokenServiceDependencies {
+    build_dependencies_with_privilege(is_owner, None)
+}
+
+fn build_dependencies_with_privilege(
+    is_owner: bool,
+    privilege: Option<&'static str>,
+) -> IcalFeedTokenServiceDependencies {
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(move |requested, _| {
+            if Some(requested) == privilege {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(move |_, _, _| {
+            if is_owner {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    sales_person_service.expect_get().returning(|id, _, _| {
+        Ok(SalesPerson {
+            id,
+            name: "Anna".into(),
+            background_color: "#fff".into(),
+            is_paid: Some(true),
+            inactive: false,
+            personnel_number: None,
+            deleted: None,
+            version: Uuid::nil(),
+        })
+    });
+
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_time_now()
+        .returning(generate_default_datetime);
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| Uuid::new_v4());
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut shiftplan_service = MockShiftplanService::new();
+    shiftplan_service.expect_get_by_id().returning(|id, _, _| {
+        Ok(Shiftplan {
+            id,
+            name: "Laden".into(),
+            is_planning: false,
+            deleted: None,
+            version: Uuid::nil(),
+        })
+    });
+
+    IcalFeedTokenServiceDependencies {
+        ical_feed_token_dao: MockIcalFeedTokenDao::new(),
+        block_service: MockBlockService::new(),
+        team_ical_service: MockTeamIcalService::new(),
+        shiftplan_service,
+        sales_person_service,
+        permission_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        transaction_dao,
+    }
+}
+
+#[tokio::test]
+async fn test_create_token() {
+    let mut deps = build_dependencies(true);
+    deps.ical_feed_token_dao
+        .expect_create()
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    deps.audit_log_service = MockAuditLogService::new();
+    deps.audit_log_service
+        .expect_record()
+        .times(1)
+        .withf(|change, _, _, _| {
+            // Das Secret darf nicht im Audit-Log landen.
+            !change
+                .after
+                .as_deref()
+                .unwrap_or_default()
+                .contains("token")
+        })
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let token = service
+        .create(
+            IcalFeedScope::SalesPerson(default_sales_person_id()),
+            "  Handy ",
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token.scope,
+        IcalFeedScope::SalesPerson(default_sales_person_id())
+    );
+    assert_eq!(token.label.as_ref(), "Handy");
+    assert_ne!(token.token, Uuid::nil());
+    assert_ne!(token.token, token.id);
+    assert_eq!(token.created, Some(generate_default_datetime()));
+    assert_eq!(token.revoked, None);
+}
+
+#[tokio::test]
+async fn test_create_token_for_other_sales_person_forbidden() {
+    let deps = build_dependencies(false);
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            IcalFeedScope::SalesPerson(default_sales_person_id()),
+            "Handy",
+            ().auth(),
+            None,
+        )
+        .await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_get_for_sales_person() {
+    let mut deps = build_dependencies(true);
+    deps.ical_feed_token_dao
+        .expect_find_by_scope()
+        .with(
+            eq(IcalFeedScopeEntity::SalesPerson(default_sales_person_id())),
+            always(),
+        )
+        .returning(|_, _| Ok([default_entity()].into()));
+    let service = deps.build_service();
+
+    let tokens = service
+        .get_for_scope(
+            IcalFeedScope::SalesPerson(default_sales_person_id()),
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token, default_token());
+}
+
+#[tokio::test]
+async fn test_revoke_token() {
+    let mut deps = build_dependencies(true);
+    deps.ical_feed_token_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(default_entity())));
+    deps.ical_feed_token_dao
+        .expect_update()
+        .times(1)
+        .withf(|entity, _, _| entity.revoked == Some(generate_default_datetime()))
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let token = service.revoke(default_id(), ().auth(), None).await.unwrap();
+
+    assert_eq!(token.revoked, Some(generate_default_datetime()));
+}
+
+#[tokio::test]
+async fn test_revoke_already_revoked_is_noop() {
+    let mut deps = build_dependencies(true);
+    deps.ical_feed_token_dao
+        .expect_find_by_id()
+        .returning(|_, _| {
+            Ok(Some(IcalFeedTokenEntity {
+                revoked: Some(generate_default_datetime()),
+                ..default_entity()
+            }))
+        });
+    deps.ical_feed_token_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let token = service.revoke(default_id(), ().auth(), None).await.unwrap();
+
+    assert_eq!(token.revoked, Some(generate_default_datetime()));
+}
+
+#[tokio::test]
+async fn test_revoke_foreign_token_forbidden() {
+    let mut deps = build_dependencies(false);
+    deps.ical_feed_token_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(default_entity())));
+    deps.ical_feed_token_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.revoke(default_id(), ().auth(), None).await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_get_feed_for_valid_token() {
+    let mut deps = build_dependencies(false);
+    deps.ical_feed_token_dao
+        .expect_find_by_token()
+        .with(eq(default_token()), always())
+        .returning(|_, _| Ok(Some(default_entity())));
+    deps.block_service
+        .expect_get_blocks_for_next_weeks_as_ical()
+        .with(
+            eq(default_sales_person_id()),
+            eq(Authentication::Full),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok("BEGIN:VCALENDAR".into()));
+    let service = deps.build_service();
+
+    let feed = service.get_feed(default_token(), None).await.unwrap();
+
+    assert_eq!(feed.as_ref(), "BEGIN:VCALENDAR");
+}
+
+#[tokio::test]
+async fn test_get_feed_for_unknown_token() {
+    let mut deps = build_dependencies(false);
+    deps.ical_feed_token_dao
+        .expect_find_by_token()
+        .returning(|_, _| Ok(None));
+    deps.block_service
+        .expect_get_blocks_for_next_weeks_as_ical()
+        .never();
+    let service = deps.build_service();
+
+    let result = service.get_feed(default_token(), None).await;
+
+    test_not_found(&result, &default_token());
+}
+
+#[tokio::test]
+async fn test_get_feed_for_revoked_token() {
+    let mut deps = build_dependencies(false);
+    deps.ical_feed_token_dao
+        .expect_find_by_token()
+        .returning(|_, _| {
+            Ok(Some(IcalFeedTokenEntity {
+                revoked: Some(generate_default_datetime()),
+                ..default_entity()
+            }))
+        });
+    deps.block_service
+        .expect_get_blocks_for_next_weeks_as_ical()
+        .never();
+    let service = deps.build_service();
+
+    let result = service.get_feed(default_token(), None).await;
+
+    test_not_found(&result, &default_token());
+}
+
+#[tokio::test]
+async fn test_create_shiftplan_token() {
+    let mut deps = build_dependencies_with_privilege(false, Some(SHIFTPLANNER_PRIVILEGE));
+    deps.ical_feed_token_dao
+        .expect_create()
+        .withf(|entity, _, _| {
+            entity.scope == IcalFeedScopeEntity::Shiftplan(default_shiftplan_id())
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let token = service
+        .create(
+            IcalFeedScope::Shiftplan(default_shiftplan_id()),
+            "Büro",
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token.scope,
+        IcalFeedScope::Shiftplan(default_shiftplan_id())
+    );
+}
+
+#[tokio::test]
+async fn test_create_shiftplan_token_requires_shiftplanner() {
+    // HR allein reicht für den Schichtplan-Feed nicht.
+    let mut deps = build_dependencies_with_privilege(true, Some(HR_PRIVILEGE));
+    deps.ical_feed_token_dao.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            IcalFeedScope::Shiftplan(default_shiftplan_id()),
+            "Büro",
+            ().auth(),
+            None,
+        )
+        .await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_create_team_absences_token_requires_hr() {
+    let mut deps = build_dependencies_with_privilege(false, Some(SHIFTPLANNER_PRIVILEGE));
+    deps.ical_feed_token_dao.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .create(IcalFeedScope::TeamAbsences, "Team", ().auth(), None)
+        .await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_get_team_absences_tokens_as_hr() {
+    let mut deps = build_dependencies_with_privilege(false, Some(HR_PRIVILEGE));
+    deps.ical_feed_token_dao
+        .expect_find_by_scope()
+        .with(eq(IcalFeedScopeEntity::TeamAbsences), always())
+        .returning(|_, _| {
+            Ok([IcalFeedTokenEntity {
+                scope: IcalFeedScopeEntity::TeamAbsences,
+                ..default_entity()
+            }]
+            .into())
+        });
+    let service = deps.build_service();
+
+    let tokens = service
+        .get_for_scope(IcalFeedScope::TeamAbsences, ().auth(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].scope, IcalFeedScope::TeamAbsences);
+}
+
+#[tokio::test]
+async fn test_get_feed_for_shiftplan_token() {
+    let mut deps = build_dependencies(false);
+    deps.ical_feed_token_dao
+        .expect_find_by_token()
+        .returning(|_, _| {
+            Ok(Some(IcalFeedTokenEntity {
+                scope: IcalFeedScopeEntity::Shiftplan(default_shiftplan_id()),
+                ..default_entity()
+            }))
+        });
+    deps.block_service
+        .expect_get_blocks_for_next_weeks_as_ical()
+        .never();
+    deps.team_ical_service
+        .expect_get_shiftplan_as_ical()
+        .with(
+            eq(default_shiftplan_id()),
+            eq(Authentication::Full),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok("BEGIN:VCALENDAR".into()));
+    let service = deps.build_service();
+
+    let feed = service.get_feed(default_token(), None).await.unwrap();
+
+    assert_eq!(feed.as_ref(), "BEGIN:VCALENDAR");
+}
+
+#[tokio::test]
+async fn test_get_feed_for_team_absences_token() {
+    let mut deps = build_dependencies(false);
+    deps.ical_feed_token_dao
+        .expect_find_by_token()
+        .returning(|_, _| {
+            Ok(Some(IcalFeedTokenEntity {
+                scope: IcalFeedScopeEntity::TeamAbsences,
+                ..default_entity()
+            }))
+        });
+    deps.team_ical_service
+        .expect_get_team_absences_as_ical()
+        .with(eq(Authentication::Full), always())
+        .times(1)
+        .returning(|_, _| Ok("BEGIN:VCALENDAR".into()));
+    let service = deps.build_service();
+
+    let feed = service.get_feed(default_token(), None).await.unwrap();
+
+    assert_eq!(feed.as_ref(), "BEGIN:VCALENDAR");
+}
@@ -0,0 +1,612 @@
+use std::sync::{Arc, Mutex};
+
+use crate::ical_client::{IcalFetchError, MockIcalFetcher};
+use crate::ical_import::{IcalImportServiceDeps, IcalImportServiceImpl};
+use crate::test::error_test::*;
+use dao::{MockTransaction, MockTransactionDao};
+use service::{
+    absence::{
+        AbsenceCategory, AbsencePeriod, AbsencePeriodCreateResult, AbsenceStatus, DayFraction,
+        MockAbsenceService,
+    },
+    clock::MockClockService,
+    config::MockConfigService,
+    ical_import::{
+        IcalImportEntry, IcalImportService, IcalImportSkipReason, IcalImportSource,
+        IcalImportStatus, IcalImportTarget,
+    },
+    permission::{HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::MockSalesPersonService,
+    sales_person_unavailable::{MockSalesPersonUnavailableService, SalesPersonUnavailable},
+    warning::Warning,
+    MockPermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime};
+use time::Date;
+use uuid::{uuid, Uuid};
+
+fn default_sales_person_id() -> Uuid {
+    uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F0")
+}
+
+/// Heute: Mittwoch, 22.07.2026.
+const CALENDAR: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//Test//Kalender//DE\r
+BEGIN:VEVENT\r
+UID:trip@example.com\r
+SUMMARY:Kurztrip\\, Ostsee\r
+DTSTART;VALUE=DATE:20260803\r
+DTEND;VALUE=DATE:20260805\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Erinnerung\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:concert@example.com\r
+SUMMARY:Konzert mit einem sehr langen Titel\\, der über mehrere Zeilen gefa\r
+ ltet wurde\r
+DTSTART:20260810T223000Z\r
+DTEND:20260810T233000Z\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:choir@example.com\r
+SUMMARY:Chorprobe\r
+DTSTART;TZID=Europe/Berlin:20260805T190000\r
+DTEND;TZID=Europe/Berlin:20260805T210000\r
+RRULE:FREQ=WEEKLY\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:cancelled@example.com\r
+SUMMARY:Abgesagt\r
+DTSTART;VALUE=DATE:20260901\r
+STATUS:CANCELLED\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:free@example.com\r
+SUMMARY:Homeoffice\r
+DTSTART;VALUE=DATE:20260902\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:past@example.com\r
+SUMMARY:Winterurlaub\r
+DTSTART;VALUE=DATE:20260105\r
+DTEND;VALUE=DATE:20260110\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+pub struct IcalImportServiceDependencies {
+    pub absence_service: MockAbsenceService,
+    pub sales_person_unavailable_service: MockSalesPersonUnavailableService,
+    pub sales_person_service: MockSalesPersonService,
+    pub permission_service: MockPermissionService,
+    pub config_service: MockConfigService,
+    pub clock_service: MockClockService,
+    pub ical_fetcher: MockIcalFetcher,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl IcalImportServiceDeps for IcalImportServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type AbsenceService = MockAbsenceService;
+    type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonService = MockSalesPersonService;
+    type PermissionService = MockPermissionService;
+    type ConfigService = MockConfigService;
+    type ClockService = MockClockService;
+    type IcalFetcher = MockIcalFetcher;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl IcalImportServiceDependencies {
+    pub fn build_service(self) -> IcalImportServiceImpl<IcalImportServiceDependencies> {
+        IcalImportServiceImpl {
+            absence_service: self.absence_service.into(),
+            sales_person_unavailable_service: self.sales_person_unavailable_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            permission_service: self.permission_service.into(),
+            config_service: self.config_service.into(),
+            clock_service: self.clock_service.into(),
+            ical_fetcher: self.ical_fetcher.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// `is_owner`: der Benutzer ist der Mitarbeiter selbst; `privilege`: die
+/// einzige Rolle, die er zusätzlich hat.
+fn build_dependencies(
+    is_owner: bool,
+    privilege: Option<&'static str>,
+) -> IcalImportServiceDependencies {
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(move |requested, _| {
+            if Some(requested) == privilege {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(move |_, _, _| {
+            if is_owner {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut config_service = MockConfigService::new();
+    config_service.expect_get_config().returning(|| {
+        Ok(service::config::Config {
+            timezone: Arc::from("Europe/Berlin"),
+            ical_label: Arc::from("Schicht"),
+            app_url: Arc::from("http://localhost:3000"),
+            datev_consultant_number: None,
+            datev_client_number: None,
+        })
+    });
+
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_now()
+        .returning(|| date!(2026 - 07 - 22));
+
+    IcalImportServiceDependencies {
+        absence_service: MockAbsenceService::new(),
+        sales_person_unavailable_service: MockSalesPersonUnavailableService::new(),
+        sales_person_service,
+        permission_service,
+        config_service,
+        clock_service,
+        ical_fetcher: MockIcalFetcher::new(),
+        transaction_dao,
+    }
+}
+
+fn unavailable(date: Date) -> SalesPersonUnavailable {
+    let (year, calendar_week, weekday) = date.to_iso_week_date();
+    SalesPersonUnavailable {
+        id: Uuid::new_v4(),
+        sales_person_id: default_sales_person_id(),
+        year: year as u32,
+        calendar_week,
+        day_of_week: weekday.into(),
+        created: Some(datetime!(2026-07-01 10:00:00)),
+        deleted: None,
+        version: Uuid::new_v4(),
+    }
+}
+
+/// 04.08.2026 ist schon als nicht verfügbar eingetragen.
+fn with_existing_unavailable(deps: &mut IcalImportServiceDependencies) {
+    deps.sales_person_unavailable_service
+        .expect_get_all_for_sales_person()
+        .returning(|_, _, _| Ok([unavailable(date!(2026 - 08 - 04))].into()));
+}
+
+fn absence(id: Uuid, category: AbsenceCategory, from_date: Date, to_date: Date) -> AbsencePeriod {
+    AbsencePeriod {
+        id,
+        sales_person_id: default_sales_person_id(),
+        category,
+        from_date,
+        to_date,
+        description: "".into(),
+        created: Some(datetime!(2026-06-01 12:00:00)),
+        deleted: None,
+        version: Uuid::new_v4(),
+        day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
+    }
+}
+
+fn conflicting_absence_id() -> Uuid {
+    uuid!("11111111-2222-4333-8444-555555555555")
+}
+
+/// Urlaub 03.–04.08. existiert genau so, Urlaub 09.08.–15.08. überlappt
+/// das Konzert am 11.08.; Krankheit zählt für Urlaub nicht.
+fn with_existing_absences(deps: &mut IcalImportServiceDependencies) {
+    deps.absence_service
+        .expect_find_by_sales_person()
+        .returning(|_, _, _| {
+            Ok([
+                absence(
+                    Uuid::new_v4(),
+                    AbsenceCategory::Vacation,
+                    date!(2026 - 08 - 03),
+                    date!(2026 - 08 - 04),
+                ),
+                absence(
+                    conflicting_absence_id(),
+                    AbsenceCategory::Vacation,
+                    date!(2026 - 08 - 09),
+                    date!(2026 - 08 - 15),
+                ),
+                absence(
+                    Uuid::new_v4(),
+                    AbsenceCategory::SickLeave,
+                    date!(2026 - 08 - 01),
+                    date!(2026 - 08 - 31),
+                ),
+            ]
+            .into())
+        });
+}
+
+#[tokio::test]
+async fn test_preview_unavailable_from_content() {
+    let mut deps = build_dependencies(true, None);
+    with_existing_unavailable(&mut deps);
+    let service = deps.build_service();
+
+    let preview = service
+        .preview(
+            default_sales_person_id(),
+            IcalImportSource::Content(CALENDAR.into()),
+            IcalImportTarget::Unavailable,
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let entries: Vec<_> = preview
+        .entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.uid.as_ref(),
+                entry.from_date,
+                entry.to_date,
+                entry.status,
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (
+                "trip@example.com",
+                date!(2026 - 08 - 03),
+                date!(2026 - 08 - 03),
+                IcalImportStatus::New
+            ),
+            (
+                "trip@example.com",
+                date!(2026 - 08 - 04),
+                date!(2026 - 08 - 04),
+                IcalImportStatus::AlreadyPresent
+            ),
+            // 22:30 UTC ist 00:30 Ortszeit am Folgetag.
+            (
+                "concert@example.com",
+                date!(2026 - 08 - 11),
+                date!(2026 - 08 - 11),
+                IcalImportStatus::New
+            ),
+        ]
+    );
+    assert_eq!(preview.entries[0].summary.as_ref(), "Kurztrip, Ostsee");
+    assert_eq!(
+        preview.entries[2].summary.as_ref(),
+        "Konzert mit einem sehr langen Titel, der über mehrere Zeilen gefaltet wurde"
+    );
+
+    let skipped: Vec<_> = preview
+        .skipped
+        .iter()
+        .map(|skipped| (skipped.uid.as_ref(), skipped.reason))
+        .collect();
+    assert_eq!(
+        skipped,
+        vec![
+            ("choir@example.com", IcalImportSkipReason::Recurring),
+            ("cancelled@example.com", IcalImportSkipReason::Cancelled),
+            ("free@example.com", IcalImportSkipReason::Free),
+            ("past@example.com", IcalImportSkipReason::Past),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_preview_absence_marks_present_and_conflicting_periods() {
+    let mut deps = build_dependencies(false, Some(HR_PRIVILEGE));
+    with_existing_absences(&mut deps);
+    let service = deps.build_service();
+
+    let preview = service
+        .preview(
+            default_sales_person_id(),
+            IcalImportSource::Content(CALENDAR.into()),
+            IcalImportTarget::Absence(AbsenceCategory::Vacation),
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let entries: Vec<_> = preview
+        .entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.uid.as_ref(),
+                entry.from_date,
+                entry.to_date,
+                entry.status,
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (
+                "trip@example.com",
+                date!(2026 - 08 - 03),
+                date!(2026 - 08 - 04),
+                IcalImportStatus::AlreadyPresent
+            ),
+            (
+                "concert@example.com",
+                date!(2026 - 08 - 11),
+                date!(2026 - 08 - 11),
+                IcalImportStatus::Conflict(conflicting_absence_id())
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_preview_fetches_subscribed_url() {
+    let mut deps = build_dependencies(true, None);
+    with_existing_unavailable(&mut deps);
+    deps.ical_fetcher
+        .expect_fetch()
+        .withf(|url| url == "webcal://calendar.example.com/anna.ics")
+        .times(1)
+        .returning(|_| Ok(CALENDAR.into()));
+    let service = deps.build_service();
+
+    let preview = service
+        .preview(
+            default_sales_person_id(),
+            IcalImportSource::Url("webcal://calendar.example.com/anna.ics".into()),
+            IcalImportTarget::Unavailable,
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(preview.entries.len(), 3);
+}
+
+#[tokio::test]
+async fn test_preview_unreachable_url_is_validation_error() {
+    let mut deps = build_dependencies(true, None);
+    deps.ical_fetcher
+        .expect_fetch()
+        .returning(|_| Err(IcalFetchError::Status(404)));
+    let service = deps.build_service();
+
+    let result = service
+        .preview(
+            default_sales_person_id(),
+            IcalImportSource::Url("https://calendar.example.com/missing.ics".into()),
+            IcalImportTarget::Unavailable,
+            ().auth(),
+            None,
+        )
+        .await;
+
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("url: unexpected status 404".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_preview_rejects_content_without_calendar() {
+    let deps = build_dependencies(true, None);
+    let service = deps.build_service();
+
+    let result = service
+        .preview(
+            default_sales_person_id(),
+            IcalImportSource::Content("Urlaub vom 3. bis 7. August".into()),
+            IcalImportTarget::Unavailable,
+            ().auth(),
+            None,
+        )
+        .await;
+
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("ics: no VCALENDAR".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_preview_forbidden_for_other_employee() {
+    // Shiftplanner darf Nicht-verfügbar-Tage pflegen, aber keine Abwesenheiten.
+    let mut deps = build_dependencies(false, Some(SHIFTPLANNER_PRIVILEGE));
+    deps.ical_fetcher.expect_fetch().never();
+    let service = deps.build_service();
+
+    let result = service
+        .preview(
+            default_sales_person_id(),
+            IcalImportSource::Url("https://calendar.example.com/anna.ics".into()),
+            IcalImportTarget::Absence(AbsenceCategory::Vacation),
+            ().auth(),
+            None,
+        )
+        .await;
+
+    test_forbidden(&result);
+}
+
+fn entry(uid: &str, from_date: Date, to_date: Date) -> IcalImportEntry {
+    IcalImportEntry {
+        uid: uid.into(),
+        summary: "Kurztrip".into(),
+        from_date,
+        to_date,
+        status: IcalImportStatus::New,
+    }
+}
+
+#[tokio::test]
+async fn test_apply_unavailable_creates_missing_days_only() {
+    let mut deps = build_dependencies(false, Some(SHIFTPLANNER_PRIVILEGE));
+    with_existing_unavailable(&mut deps);
+    let created = Arc::new(Mutex::new(vec![]));
+    let captured = created.clone();
+    deps.sales_person_unavailable_service
+        .expect_create()
+        .returning(move |unavailable, _, _| {
+            captured.lock().unwrap().push(unavailable.clone());
+            Ok(SalesPersonUnavailable {
+                id: Uuid::new_v4(),
+                ..unavailable.clone()
+            })
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .apply(
+            default_sales_person_id(),
+            IcalImportTarget::Unavailable,
+            &[
+                entry("trip", date!(2026 - 08 - 03), date!(2026 - 08 - 03)),
+                entry("trip", date!(2026 - 08 - 04), date!(2026 - 08 - 04)),
+                // Doppelt übergebene Tage werden nur einmal angelegt.
+                entry("other", date!(2026 - 08 - 03), date!(2026 - 08 - 03)),
+            ],
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.unavailables.len(), 1);
+    assert!(result.absences.is_empty());
+    let created = created.lock().unwrap();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].sales_person_id, default_sales_person_id());
+    assert_eq!(created[0].year, 2026);
+    assert_eq!(created[0].calendar_week, 32);
+    assert_eq!(created[0].day_of_week, DayOfWeek::Monday);
+    assert_eq!(created[0].id, Uuid::nil());
+    assert_eq!(created[0].created, None);
+}
+
+#[tokio::test]
+async fn test_apply_absence_returns_warnings_of_absence_service() {
+    let mut deps = build_dependencies(true, None);
+    with_existing_absences(&mut deps);
+    deps.absence_service
+        .expect_create()
+        .times(1)
+        .returning(|absence, _, _| {
+            assert_eq!(absence.category, AbsenceCategory::Vacation);
+            assert_eq!(absence.from_date, date!(2026 - 09 - 07));
+            assert_eq!(absence.to_date, date!(2026 - 09 - 11));
+            assert_eq!(absence.description.as_ref(), "Kurztrip");
+            assert_eq!(absence.id, Uuid::nil());
+            let created = AbsencePeriod {
+                id: Uuid::new_v4(),
+                ..absence.clone()
+            };
+            Ok(AbsencePeriodCreateResult {
+                warnings: [Warning::AbsenceOverlapsBooking {
+                    absence_id: created.id,
+                    booking_id: Uuid::new_v4(),
+                    date: date!(2026 - 09 - 08),
+                }]
+                .into(),
+                absence: created,
+            })
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .apply(
+            default_sales_person_id(),
+            IcalImportTarget::Absence(AbsenceCategory::Vacation),
+            &[
+                // Existiert schon genau so → übersprungen.
+                entry("trip", date!(2026 - 08 - 03), date!(2026 - 08 - 04)),
+                entry("september", date!(2026 - 09 - 07), date!(2026 - 09 - 11)),
+            ],
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.absences.len(), 1);
+    assert_eq!(result.warnings.len(), 1);
+    assert!(matches!(
+        result.warnings[0],
+        Warning::AbsenceOverlapsBooking { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_apply_propagates_overlap_error() {
+    let mut deps = build_dependencies(true, None);
+    with_existing_absences(&mut deps);
+    deps.absence_service.expect_create().returning(|_, _, _| {
+        Err(ServiceError::ValidationError(Arc::from([
+            ValidationFailureItem::OverlappingPeriod(conflicting_absence_id()),
+        ])))
+    });
+    deps.transaction_dao.checkpoint();
+    deps.transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    deps.transaction_dao.expect_commit().never();
+    let service = deps.build_service();
+
+    let result = service
+        .apply(
+            default_sales_person_id(),
+            IcalImportTarget::Absence(AbsenceCategory::Vacation),
+            &[entry(
+                "concert",
+                date!(2026 - 08 - 11),
+                date!(2026 - 08 - 11),
+            )],
+            ().auth(),
+            None,
+        )
+        .await;
+
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::OverlappingPeriod(conflicting_absence_id()),
+        1,
+    );
+}
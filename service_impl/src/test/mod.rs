@@ -9,6 +9,10 @@ pub mod booking_information_vaa;
 #[cfg(test)]
 pub mod booking_information_chain_c;
 #[cfg(test)]
+pub mod booking_information_qualification;
+#[cfg(test)]
+pub mod booking_information_recurring_unavailable;
+#[cfg(test)]
 pub mod booking_information_weekly_summary_year_batch;
 #[cfg(test)]
 pub mod booking_information_weekly_summary_year_boundary;
@@ -17,8 +21,12 @@ pub mod booking_information_weekly_summary_year_boundary_2026;
 #[cfg(test)]
 pub mod absence_conversion;
 #[cfg(test)]
+pub mod audit_log;
+#[cfg(test)]
 pub mod absence_derive_hours_range;
 #[cfg(test)]
+pub mod absence_request;
+#[cfg(test)]
 pub mod block;
 #[cfg(test)]
 pub mod booking;
@@ -31,14 +39,26 @@ pub mod custom_extra_hours;
 #[cfg(test)]
 pub mod error_test;
 #[cfg(test)]
+pub mod export_target;
+#[cfg(test)]
+pub mod export_target_scheduler;
+#[cfg(test)]
 pub mod feature_flag;
 #[cfg(test)]
+pub mod ical;
+#[cfg(test)]
+pub mod ical_feed_token;
+#[cfg(test)]
 pub mod pdf_export_config;
 #[cfg(test)]
 pub mod rebooking_batch;
 #[cfg(test)]
+pub mod rebooking_reconciliation;
+#[cfg(test)]
 pub mod pdf_export_scheduler;
 #[cfg(test)]
+pub mod pdf_personal;
+#[cfg(test)]
 pub mod pdf_shiftplan;
 #[cfg(test)]
 mod permission_test;
@@ -59,16 +79,26 @@ pub mod reporting_year_boundary;
 #[cfg(test)]
 pub mod sales_person;
 #[cfg(test)]
+pub mod sales_person_availability;
+#[cfg(test)]
 pub mod sales_person_shiftplan;
 #[cfg(test)]
 pub mod sales_person_unavailable;
 #[cfg(test)]
+pub mod scheduler;
+#[cfg(test)]
+pub mod scheduler_job;
+#[cfg(test)]
 pub mod shiftplan;
 #[cfg(test)]
 pub mod shiftplan_edit;
 #[cfg(test)]
 pub mod shiftplan_edit_lock;
 #[cfg(test)]
+pub mod shiftplan_generator;
+#[cfg(test)]
+pub mod shift_swap;
+#[cfg(test)]
 pub mod shiftplan_catalog;
 #[cfg(test)]
 pub mod slot;
@@ -85,6 +115,8 @@ pub mod session;
 #[cfg(test)]
 pub mod toggle;
 #[cfg(test)]
+pub mod user_invitation;
+#[cfg(test)]
 pub mod employee_work_details;
 #[cfg(test)]
 pub mod extra_hours;
@@ -106,3 +138,27 @@ pub mod special_days;
 pub mod week_status;
 #[cfg(test)]
 pub mod shiftplan_report;
+#[cfg(test)]
+pub mod notification;
+#[cfg(test)]
+pub mod webhook;
+#[cfg(test)]
+pub mod time_entry;
+#[cfg(test)]
+pub mod report_export;
+#[cfg(test)]
+pub mod payroll_export;
+#[cfg(test)]
+pub mod payroll_wage_type;
+#[cfg(test)]
+pub mod holiday_calendar;
+#[cfg(test)]
+pub mod qualification;
+#[cfg(test)]
+pub mod team_ical;
+#[cfg(test)]
+pub mod ical_import;
+#[cfg(test)]
+pub mod text_template;
+#[cfg(test)]
+pub mod text_template_preview;
@@ -0,0 +1,695 @@
+use std::sync::Arc;
+
+use dao::notification::{
+    MockNotificationDao, NotificationKind as NotificationKindEntity, NotificationOutboxEntity,
+    NotificationPreferenceEntity, NotificationState as NotificationStateEntity,
+};
+use dao::sales_person::MockSalesPersonDao;
+use dao::text_template::{MockTextTemplateDao, TemplateEngineEntity, TextTemplateEntity};
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::audit_log::{AuditAction, MockAuditLogService};
+use service::clock::MockClockService;
+use service::config::{Config, MockConfigService};
+use service::notification::{NotificationEvent, NotificationPreference, NotificationService};
+use service::permission::{Authentication, HR_PRIVILEGE};
+use service::uuid_service::MockUuidService;
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use time::macros::datetime;
+use time::PrimitiveDateTime;
+use uuid::{uuid, Uuid};
+
+use crate::mail_transport::{MailError, MockMailTransport};
+use crate::notification::{
+    render_notification, NotificationServiceDeps, NotificationServiceImpl, MAX_DELIVERY_ATTEMPTS,
+};
+use crate::test::error_test::{test_forbidden, test_not_found, test_validation_error};
+
+const YEAR: u32 = 2026;
+const WEEK: u8 = 11;
+
+fn now() -> PrimitiveDateTime {
+    datetime!(2026 - 03 - 15 10:00:00)
+}
+
+fn outbox_id() -> Uuid {
+    uuid!("0B0B0000-0000-0000-0000-000000000001")
+}
+
+fn new_version() -> Uuid {
+    uuid!("0B0B0000-0000-0000-0000-0000000000FF")
+}
+
+fn preference(user_id: &str, week_planned: bool) -> NotificationPreferenceEntity {
+    NotificationPreferenceEntity {
+        user_id: user_id.into(),
+        email: format!("{user_id}@example.com").into(),
+        booking_deleted: true,
+        week_planned,
+        absence_created: true,
+        invitation_created: true,
+        created: datetime!(2026 - 01 - 01 12:00:00),
+        deleted: None,
+        version: uuid!("0B0B0000-0000-0000-0000-0000000000AA"),
+    }
+}
+
+fn pending_message(attempts: u32) -> NotificationOutboxEntity {
+    NotificationOutboxEntity {
+        id: outbox_id(),
+        recipient_user_id: "anna".into(),
+        recipient_email: "anna@example.com".into(),
+        kind: NotificationKindEntity::WeekPlanned,
+        subject: "Schichtplan KW 11/2026 ist fertig".into(),
+        body: "Hallo".into(),
+        state: NotificationStateEntity::Pending,
+        attempts,
+        next_attempt: now(),
+        last_error: None,
+        created: now(),
+        sent: None,
+        version: uuid!("0B0B0000-0000-0000-0000-0000000000BB"),
+    }
+}
+
+fn template(text: &str) -> TextTemplateEntity {
+    TextTemplateEntity {
+        id: uuid!("0B0B0000-0000-0000-0000-0000000000CC"),
+        name: None,
+        template_type: "notification_week_planned".into(),
+        template_text: text.into(),
+        template_engine: TemplateEngineEntity::MiniJinja,
+        created_at: None,
+        created_by: None,
+        deleted: None,
+        deleted_by: None,
+        version: Uuid::nil(),
+    }
+}
+
+pub struct NotificationServiceDependencies {
+    pub notification_dao: MockNotificationDao,
+    pub text_template_dao: MockTextTemplateDao,
+    pub sales_person_dao: MockSalesPersonDao,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub config_service: MockConfigService,
+    pub mail_transport: MockMailTransport,
+}
+
+impl NotificationServiceDeps for NotificationServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type NotificationDao = MockNotificationDao;
+    type TextTemplateDao = MockTextTemplateDao;
+    type SalesPersonDao = MockSalesPersonDao;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type ConfigService = MockConfigService;
+    type MailTransport = MockMailTransport;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl NotificationServiceDependencies {
+    pub fn build_service(self) -> NotificationServiceImpl<NotificationServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        NotificationServiceImpl {
+            notification_dao: Arc::new(self.notification_dao),
+            text_template_dao: Arc::new(self.text_template_dao),
+            sales_person_dao: Arc::new(self.sales_person_dao),
+            permission_service: Arc::new(self.permission_service),
+            clock_service: Arc::new(self.clock_service),
+            uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
+            config_service: Arc::new(self.config_service),
+            mail_transport: Arc::new(self.mail_transport),
+            transaction_dao: Arc::new(transaction_dao),
+        }
+    }
+}
+
+/// Alle Permission-Checks schlagen fehl, außer bei `Authentication::Full`.
+fn build_dependencies() -> NotificationServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+    permission_service
+        .expect_check_user()
+        .returning(|_, _| Err(ServiceError::Forbidden));
+
+    let mut clock_service = MockClockService::new();
+    clock_service.expect_date_time_now().returning(now);
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| new_version());
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut config_service = MockConfigService::new();
+    config_service.expect_get_config().returning(|| {
+        Ok(Config {
+            timezone: "Europe/Berlin".into(),
+            ical_label: "Shifty".into(),
+            app_url: "https://shifty.example.com".into(),
+            datev_consultant_number: None,
+            datev_client_number: None,
+        })
+    });
+
+    let mut mail_transport = MockMailTransport::new();
+    mail_transport.expect_is_configured().returning(|| true);
+
+    NotificationServiceDependencies {
+        notification_dao: MockNotificationDao::new(),
+        text_template_dao: MockTextTemplateDao::new(),
+        sales_person_dao: MockSalesPersonDao::new(),
+        permission_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        config_service,
+        mail_transport,
+    }
+}
+
+fn week_planned() -> NotificationEvent {
+    NotificationEvent::WeekPlanned {
+        year: YEAR,
+        calendar_week: WEEK,
+    }
+}
+
+// --- notify -------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_notify_week_planned_only_subscribers() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_all_preferences()
+        .returning(|_| {
+            Ok(Arc::from([
+                preference("anna", true),
+                preference("bernd", false),
+            ]))
+        });
+    dependencies
+        .text_template_dao
+        .expect_find_by_template_type()
+        .returning(|_, _| Ok(Arc::from([])));
+    dependencies
+        .notification_dao
+        .expect_create_outbox()
+        .withf(|entity, _, _| {
+            entity.recipient_user_id.as_ref() == "anna"
+                && entity.recipient_email.as_ref() == "anna@example.com"
+                && entity.state == NotificationStateEntity::Pending
+                && entity.attempts == 0
+                && entity.next_attempt == now()
+                && entity.subject.as_ref() == "Schichtplan KW 11/2026 ist fertig"
+                && entity.body.contains("https://shifty.example.com")
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service.notify(&week_planned(), None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_notify_without_assigned_user_is_noop() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .sales_person_dao
+        .expect_get_assigned_user()
+        .returning(|_, _| Ok(None));
+    dependencies
+        .sales_person_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(None));
+    dependencies
+        .text_template_dao
+        .expect_find_by_template_type()
+        .never();
+    dependencies.notification_dao.expect_create_outbox().never();
+    let service = dependencies.build_service();
+
+    service
+        .notify(
+            &NotificationEvent::AbsenceCreated {
+                sales_person_id: uuid!("0B0B0000-0000-0000-0000-000000000010"),
+                category: service::absence::AbsenceCategory::Vacation,
+                from_date: time::macros::date!(2026 - 04 - 01),
+                to_date: time::macros::date!(2026 - 04 - 03),
+            },
+            None,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_notify_uses_custom_template() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_all_preferences()
+        .returning(|_| Ok(Arc::from([preference("anna", true)])));
+    dependencies
+        .text_template_dao
+        .expect_find_by_template_type()
+        .with(eq("notification_week_planned"), always())
+        .returning(|_, _| {
+            Ok(Arc::from([template(
+                "KW {{ calendar_week }} steht\n\nFertig.",
+            )]))
+        });
+    dependencies
+        .notification_dao
+        .expect_create_outbox()
+        .withf(|entity, _, _| {
+            entity.subject.as_ref() == "KW 11 steht" && entity.body.as_ref() == "Fertig."
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service.notify(&week_planned(), None).await.unwrap();
+}
+
+fn invitation_created(email: Option<&str>) -> NotificationEvent {
+    NotificationEvent::InvitationCreated {
+        username: "carla".into(),
+        email: email.map(Arc::from),
+        token: uuid!("0B0B0000-0000-0000-0000-000000000020"),
+        expiration_date: time::macros::datetime!(2026 - 03 - 22 10:00:00 UTC),
+    }
+}
+
+#[tokio::test]
+async fn test_notify_invitation_without_preference_uses_invitation_email() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_preference_by_user_id()
+        .with(eq("carla"), always())
+        .returning(|_, _| Ok(None));
+    dependencies
+        .text_template_dao
+        .expect_find_by_template_type()
+        .returning(|_, _| Ok(Arc::from([])));
+    dependencies
+        .notification_dao
+        .expect_create_outbox()
+        .withf(|entity, _, _| {
+            entity.recipient_user_id.as_ref() == "carla"
+                && entity.recipient_email.as_ref() == "carla@invited.example.com"
+                && entity.kind == NotificationKindEntity::InvitationCreated
+                && entity.body.contains(
+                    "https://shifty.example.com/auth/invitation/0b0b0000-0000-0000-0000-000000000020",
+                )
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .notify(&invitation_created(Some("carla@invited.example.com")), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_notify_invitation_prefers_stored_preference() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_preference_by_user_id()
+        .returning(|_, _| Ok(Some(preference("carla", false))));
+    dependencies
+        .text_template_dao
+        .expect_find_by_template_type()
+        .returning(|_, _| Ok(Arc::from([])));
+    dependencies
+        .notification_dao
+        .expect_create_outbox()
+        .withf(|entity, _, _| entity.recipient_email.as_ref() == "carla@example.com")
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .notify(&invitation_created(Some("carla@invited.example.com")), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_notify_invitation_without_preference_or_email_is_noop() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_preference_by_user_id()
+        .returning(|_, _| Ok(None));
+    dependencies.notification_dao.expect_create_outbox().never();
+    let service = dependencies.build_service();
+
+    service
+        .notify(&invitation_created(None), None)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn test_render_falls_back_on_broken_template() {
+    let context = serde_json::json!({
+        "year": YEAR,
+        "calendar_week": WEEK,
+        "app_url": "https://shifty.example.com",
+    });
+    let (subject, _) = render_notification(
+        service::notification::NotificationKind::WeekPlanned,
+        Some(&template("{{ unclosed")),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(subject.as_ref(), "Schichtplan KW 11/2026 ist fertig");
+}
+
+// --- deliver_pending ------------------------------------------------------------
+
+fn deliver_dependencies(
+    attempts: u32,
+    send_result: fn() -> Result<(), MailError>,
+) -> NotificationServiceDependencies {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_due_outbox()
+        .with(eq(now()), always(), always())
+        .returning(move |_, _, _| Ok(Arc::from([pending_message(attempts)])));
+    dependencies
+        .mail_transport
+        .expect_send_mail()
+        .returning(move |_, _, _| send_result());
+    dependencies
+}
+
+#[tokio::test]
+async fn test_deliver_pending_marks_sent() {
+    let mut dependencies = deliver_dependencies(0, || Ok(()));
+    dependencies
+        .notification_dao
+        .expect_update_outbox()
+        .with(
+            eq(NotificationOutboxEntity {
+                state: NotificationStateEntity::Sent,
+                attempts: 1,
+                sent: Some(now()),
+                version: new_version(),
+                ..pending_message(0)
+            }),
+            always(),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.sent, 1);
+    assert_eq!(report.retried, 0);
+    assert_eq!(report.failed, 0);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_schedules_retry_with_backoff() {
+    let mut dependencies = deliver_dependencies(1, || Err(MailError::Smtp("timeout".into())));
+    dependencies
+        .notification_dao
+        .expect_update_outbox()
+        .with(
+            eq(NotificationOutboxEntity {
+                state: NotificationStateEntity::Pending,
+                attempts: 2,
+                next_attempt: now() + time::Duration::minutes(10),
+                last_error: Some("smtp error: timeout".into()),
+                version: new_version(),
+                ..pending_message(1)
+            }),
+            always(),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.retried, 1);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_fails_after_max_attempts() {
+    let mut dependencies = deliver_dependencies(MAX_DELIVERY_ATTEMPTS - 1, || {
+        Err(MailError::Smtp("rejected".into()))
+    });
+    dependencies
+        .notification_dao
+        .expect_update_outbox()
+        .withf(|entity, _, _| {
+            entity.state == NotificationStateEntity::Failed
+                && entity.attempts == MAX_DELIVERY_ATTEMPTS
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.failed, 1);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_without_smtp_leaves_outbox() {
+    let mut dependencies = build_dependencies();
+    dependencies.mail_transport.checkpoint();
+    dependencies
+        .mail_transport
+        .expect_is_configured()
+        .returning(|| false);
+    dependencies
+        .notification_dao
+        .expect_find_due_outbox()
+        .never();
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report, Default::default());
+}
+
+#[tokio::test]
+async fn test_deliver_pending_requires_full_authentication() {
+    let dependencies = build_dependencies();
+    let service = dependencies.build_service();
+
+    let result = service.deliver_pending(Authentication::Context(())).await;
+    test_forbidden(&result);
+}
+
+// --- Einstellungen --------------------------------------------------------------
+
+fn own_preference(email: &str) -> NotificationPreference {
+    NotificationPreference {
+        user_id: "anna".into(),
+        email: email.into(),
+        booking_deleted: true,
+        week_planned: true,
+        absence_created: false,
+        invitation_created: false,
+        created: None,
+        version: Uuid::nil(),
+    }
+}
+
+#[tokio::test]
+async fn test_set_preference_creates_for_self() {
+    let mut dependencies = build_dependencies();
+    dependencies.permission_service.checkpoint();
+    dependencies
+        .permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    dependencies
+        .permission_service
+        .expect_check_user()
+        .returning(|_, _| Ok(()));
+    dependencies
+        .notification_dao
+        .expect_find_preference_by_user_id()
+        .returning(|_, _| Ok(None));
+    dependencies
+        .notification_dao
+        .expect_create_preference()
+        .withf(|entity, _, _| {
+            entity.user_id.as_ref() == "anna"
+                && entity.email.as_ref() == "anna@example.com"
+                && entity.created == now()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == service::audit_log::entity_type::NOTIFICATION_PREFERENCE
+                && change.entity_id.as_ref() == "anna"
+                && change.action == AuditAction::Create
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let result = service
+        .set_preference(
+            &own_preference(" anna@example.com "),
+            Authentication::Context(()),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.email.as_ref(), "anna@example.com");
+    assert_eq!(result.version, new_version());
+}
+
+#[tokio::test]
+async fn test_set_preference_other_user_forbidden() {
+    let dependencies = build_dependencies();
+    let service = dependencies.build_service();
+
+    let result = service
+        .set_preference(
+            &own_preference("anna@example.com"),
+            Authentication::Context(()),
+            None,
+        )
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_set_preference_invalid_email() {
+    let dependencies = build_dependencies();
+    let service = dependencies.build_service();
+
+    let result = service
+        .set_preference(&own_preference("anna"), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("email".into()),
+        1,
+    );
+}
+
+// --- Outbox-Verwaltung ------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_outbox_forbidden() {
+    let dependencies = build_dependencies();
+    let service = dependencies.build_service();
+
+    let result = service
+        .get_outbox(100, Authentication::Context(()), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_retry_resets_failed_entry() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_outbox_by_id()
+        .with(eq(outbox_id()), always())
+        .returning(|_, _| {
+            Ok(Some(NotificationOutboxEntity {
+                state: NotificationStateEntity::Failed,
+                last_error: Some("rejected".into()),
+                ..pending_message(MAX_DELIVERY_ATTEMPTS)
+            }))
+        });
+    dependencies
+        .notification_dao
+        .expect_update_outbox()
+        .withf(|entity, _, _| {
+            entity.state == NotificationStateEntity::Pending
+                && entity.attempts == 0
+                && entity.next_attempt == now()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let result = service
+        .retry(outbox_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        result.state,
+        service::notification::NotificationState::Pending
+    );
+}
+
+#[tokio::test]
+async fn test_retry_rejects_pending_entry() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_outbox_by_id()
+        .returning(|_, _| Ok(Some(pending_message(1))));
+    dependencies.notification_dao.expect_update_outbox().never();
+    let service = dependencies.build_service();
+
+    let result = service.retry(outbox_id(), Authentication::Full, None).await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("state".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_retry_not_found() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .notification_dao
+        .expect_find_outbox_by_id()
+        .returning(|_, _| Ok(None));
+    let service = dependencies.build_service();
+
+    let result = service.retry(outbox_id(), Authentication::Full, None).await;
+    test_not_found(&result, &outbox_id());
+}
@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use mockall::predicate::{always, eq};
+use service::billing_period::{
+    BillingPeriod, BillingPeriodSalesPerson, BillingPeriodValue, BillingPeriodValueType,
+    MockBillingPeriodService,
+};
+use service::config::{Config, MockConfigService};
+use service::payroll_export::PayrollExportService;
+use service::payroll_wage_type::{MockPayrollWageTypeService, PayrollValueView, PayrollWageType};
+use service::permission::{Authentication, HR_PRIVILEGE};
+use service::sales_person::{MockSalesPersonService, SalesPerson};
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use shifty_utils::ShiftyDate;
+use time::macros::datetime;
+use uuid::{uuid, Uuid};
+
+use crate::payroll_export::{PayrollExportServiceDeps, PayrollExportServiceImpl};
+
+const ANNA_ID: Uuid = uuid!("5b1d3f0e-9c3a-4f52-8d1e-000000000001");
+const BOB_ID: Uuid = uuid!("5b1d3f0e-9c3a-4f52-8d1e-000000000002");
+const CARL_ID: Uuid = uuid!("5b1d3f0e-9c3a-4f52-8d1e-000000000003");
+
+struct PayrollExportDependencies {
+    billing_period_service: MockBillingPeriodService,
+    sales_person_service: MockSalesPersonService,
+    payroll_wage_type_service: MockPayrollWageTypeService,
+    config_service: MockConfigService,
+    permission_service: MockPermissionService,
+    transaction_dao: dao::MockTransactionDao,
+}
+
+impl PayrollExportServiceDeps for PayrollExportDependencies {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type BillingPeriodService = MockBillingPeriodService;
+    type SalesPersonService = MockSalesPersonService;
+    type PayrollWageTypeService = MockPayrollWageTypeService;
+    type ConfigService = MockConfigService;
+    type PermissionService = MockPermissionService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+impl PayrollExportDependencies {
+    fn new() -> Self {
+        let mut transaction_dao = dao::MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(dao::MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+        let mut permission_service = MockPermissionService::new();
+        permission_service
+            .expect_check_permission()
+            .with(eq(HR_PRIVILEGE), always())
+            .returning(|_, _| Ok(()));
+        Self {
+            billing_period_service: MockBillingPeriodService::new(),
+            sales_person_service: MockSalesPersonService::new(),
+            payroll_wage_type_service: MockPayrollWageTypeService::new(),
+            config_service: MockConfigService::new(),
+            permission_service,
+            transaction_dao,
+        }
+    }
+
+    fn with_config(mut self, consultant_number: Option<&str>, client_number: Option<&str>) -> Self {
+        let consultant_number: Option<Arc<str>> = consultant_number.map(Arc::from);
+        let client_number: Option<Arc<str>> = client_number.map(Arc::from);
+        self.config_service.expect_get_config().returning(move || {
+            Ok(Config {
+                timezone: "Europe/Berlin".into(),
+                ical_label: "Shift".into(),
+                app_url: "http://localhost:3000".into(),
+                datev_consultant_number: consultant_number.clone(),
+                datev_client_number: client_number.clone(),
+            })
+        });
+        self
+    }
+
+    fn with_data(mut self, sales_persons: Vec<SalesPerson>) -> Self {
+        self.billing_period_service
+            .expect_get_billing_period_by_id()
+            .returning(|id, _, _| Ok(billing_period(id)));
+        let sales_persons: Arc<[SalesPerson]> = sales_persons.into();
+        self.sales_person_service
+            .expect_get_all()
+            .returning(move |_, _| Ok(sales_persons.clone()));
+        self.payroll_wage_type_service
+            .expect_get_all()
+            .returning(|_, _| Ok(wage_types()));
+        self
+    }
+
+    fn build_service(self) -> PayrollExportServiceImpl<PayrollExportDependencies> {
+        PayrollExportServiceImpl {
+            billing_period_service: self.billing_period_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            payroll_wage_type_service: self.payroll_wage_type_service.into(),
+            config_service: self.config_service.into(),
+            permission_service: self.permission_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+fn sales_person(id: Uuid, name: &str, personnel_number: Option<&str>) -> SalesPerson {
+    SalesPerson {
+        id,
+        name: name.into(),
+        background_color: "#ffffff".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: personnel_number.map(Arc::from),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn wage_type(
+    value_type: BillingPeriodValueType,
+    value_view: PayrollValueView,
+    wage_type: &str,
+    processing_key: u8,
+) -> PayrollWageType {
+    PayrollWageType {
+        id: Uuid::new_v4(),
+        value_type,
+        value_view,
+        wage_type: wage_type.into(),
+        processing_key,
+        description: "".into(),
+        created: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn wage_types() -> Arc<[PayrollWageType]> {
+    Arc::new([
+        wage_type(BillingPeriodValueType::SickLeave, PayrollValueView::Delta, "300", 1),
+        wage_type(BillingPeriodValueType::ExtraWork, PayrollValueView::Delta, "200", 1),
+        wage_type(
+            BillingPeriodValueType::CustomExtraHours("Fortbildung".into()),
+            PayrollValueView::Delta,
+            "210",
+            1,
+        ),
+        wage_type(BillingPeriodValueType::Balance, PayrollValueView::YtdTo, "9000", 1),
+    ])
+}
+
+fn billing_period(id: Uuid) -> BillingPeriod {
+    let value = |value_delta: f32, value_ytd_to: f32| BillingPeriodValue {
+        value_delta,
+        value_ytd_from: 0.0,
+        value_ytd_to,
+        value_full_year: 0.0,
+    };
+    let entry = |sales_person_id: Uuid,
+                 values: BTreeMap<BillingPeriodValueType, BillingPeriodValue>,
+                 deleted: bool| BillingPeriodSalesPerson {
+        id: Uuid::new_v4(),
+        sales_person_id,
+        values,
+        created_at: datetime!(2026-04-01 10:00:00),
+        created_by: "hr".into(),
+        deleted_at: deleted.then_some(datetime!(2026-04-02 10:00:00)),
+        deleted_by: None,
+    };
+    BillingPeriod {
+        id,
+        start_date: ShiftyDate::from_ymd(2026, 3, 1).unwrap(),
+        end_date: ShiftyDate::from_ymd(2026, 3, 31).unwrap(),
+        snapshot_schema_version: 3,
+        sales_persons: Arc::new([
+            entry(
+                BOB_ID,
+                BTreeMap::from([
+                    (BillingPeriodValueType::ExtraWork, value(2.0, 2.0)),
+                    (BillingPeriodValueType::Balance, value(-1.25, -3.5)),
+                ]),
+                false,
+            ),
+            entry(
+                ANNA_ID,
+                BTreeMap::from([
+                    (BillingPeriodValueType::ExtraWork, value(12.5, 20.0)),
+                    (BillingPeriodValueType::SickLeave, value(0.0, 8.0)),
+                    (
+                        BillingPeriodValueType::CustomExtraHours("Fortbildung".into()),
+                        value(4.0, 4.0),
+                    ),
+                    (BillingPeriodValueType::VacationDays, value(3.0, 3.0)),
+                ]),
+                false,
+            ),
+            // Gelöschter Eintrag ohne Personalnummer darf nicht stören.
+            entry(
+                CARL_ID,
+                BTreeMap::from([(BillingPeriodValueType::ExtraWork, value(5.0, 5.0))]),
+                true,
+            ),
+        ]),
+        created_at: datetime!(2026-04-01 10:00:00),
+        created_by: "hr".into(),
+        deleted_at: None,
+        deleted_by: None,
+    }
+}
+
+#[tokio::test]
+async fn test_export_billing_period_lodas() {
+    let deps = PayrollExportDependencies::new()
+        .with_config(Some("1234567"), Some("54321"))
+        .with_data(vec![
+            sales_person(ANNA_ID, "Anna Müller", Some("1002")),
+            sales_person(BOB_ID, "Bob", Some("999")),
+            sales_person(CARL_ID, "Carl", None),
+        ]);
+    let service = deps.build_service();
+
+    let file = service
+        .export_billing_period(Uuid::new_v4(), Authentication::Context(()), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        file.file_name.as_ref(),
+        "datev-lodas-2026-03-01-2026-03-31.txt"
+    );
+    assert_eq!(file.content_type, "text/plain; charset=us-ascii");
+    assert!(file.content.is_ascii());
+    let text = String::from_utf8(file.content.to_vec()).unwrap();
+    assert!(text.starts_with("[Allgemein]\r\nZiel=LODAS\r\n"));
+    assert!(text.contains("BeraterNr=1234567\r\nMandantenNr=54321\r\n"));
+    let (_, movements) = text.split_once("[Bewegungsdaten]\r\n").unwrap();
+    assert_eq!(
+        movements,
+        "10;01.03.2026;999;200;1;2,00;\r\n\
+         10;01.03.2026;999;9000;1;-3,50;\r\n\
+         10;01.03.2026;1002;200;1;12,50;\r\n\
+         10;01.03.2026;1002;210;1;4,00;\r\n"
+    );
+}
+
+#[tokio::test]
+async fn test_export_billing_period_missing_personnel_number() {
+    let deps = PayrollExportDependencies::new()
+        .with_config(Some("1234567"), Some("54321"))
+        .with_data(vec![
+            sales_person(ANNA_ID, "Anna Müller", None),
+            sales_person(BOB_ID, "Bob", Some("P-999")),
+        ]);
+    let service = deps.build_service();
+
+    let result = service
+        .export_billing_period(Uuid::new_v4(), Authentication::Context(()), None)
+        .await;
+
+    let Err(ServiceError::ValidationError(items)) = result else {
+        panic!("Expected validation error, got {result:?}");
+    };
+    assert_eq!(
+        items.as_ref(),
+        &[
+            ValidationFailureItem::InvalidValue(
+                format!("personnel_number of sales person {BOB_ID}").into()
+            ),
+            ValidationFailureItem::InvalidValue(
+                format!("personnel_number of sales person {ANNA_ID}").into()
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_export_billing_period_requires_datev_numbers() {
+    let mut deps = PayrollExportDependencies::new().with_config(Some(" "), None);
+    deps.billing_period_service
+        .expect_get_billing_period_by_id()
+        .times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .export_billing_period(Uuid::new_v4(), Authentication::Context(()), None)
+        .await;
+
+    let Err(ServiceError::ValidationError(items)) = result else {
+        panic!("Expected validation error, got {result:?}");
+    };
+    assert_eq!(
+        items.as_ref(),
+        &[
+            ValidationFailureItem::InvalidValue("datev_consultant_number".into()),
+            ValidationFailureItem::InvalidValue("datev_client_number".into()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_export_billing_period_requires_hr() {
+    let mut deps = PayrollExportDependencies::new();
+    deps.permission_service = MockPermissionService::new();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    deps.config_service.expect_get_config().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .export_billing_period(Uuid::new_v4(), Authentication::Context(()), None)
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::Forbidden)));
+}
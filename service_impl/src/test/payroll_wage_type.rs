@@ -0,0 +1,277 @@
+use std::sync::Arc;
+
+use dao::payroll_wage_type::{
+    MockPayrollWageTypeDao, PayrollValueView as PayrollValueViewEntity, PayrollWageTypeEntity,
+};
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::audit_log::MockAuditLogService;
+use service::billing_period::BillingPeriodValueType;
+use service::clock::MockClockService;
+use service::payroll_wage_type::{PayrollValueView, PayrollWageType, PayrollWageTypeService};
+use service::permission::{Authentication, HR_PRIVILEGE};
+use service::uuid_service::MockUuidService;
+use service::{MockPermissionService, ValidationFailureItem};
+use time::macros::datetime;
+use time::PrimitiveDateTime;
+use uuid::{uuid, Uuid};
+
+use crate::payroll_wage_type::{PayrollWageTypeServiceDeps, PayrollWageTypeServiceImpl};
+use crate::test::error_test::{test_conflicts, test_forbidden, test_validation_error};
+
+fn now() -> PrimitiveDateTime {
+    datetime!(2026 - 10 - 17 09:00:00)
+}
+
+fn wage_type_id() -> Uuid {
+    uuid!("7A9E0000-0000-0000-0000-000000000001")
+}
+
+fn version() -> Uuid {
+    uuid!("7A9E0000-0000-0000-0000-0000000000AA")
+}
+
+fn new_version() -> Uuid {
+    uuid!("7A9E0000-0000-0000-0000-0000000000FF")
+}
+
+fn entity() -> PayrollWageTypeEntity {
+    PayrollWageTypeEntity {
+        id: wage_type_id(),
+        value_type: "custom_extra_hours:Fortbildung".into(),
+        value_view: PayrollValueViewEntity::Delta,
+        wage_type: "210".into(),
+        processing_key: 1,
+        description: "Fortbildung".into(),
+        created: now(),
+        deleted: None,
+        version: version(),
+    }
+}
+
+fn wage_type() -> PayrollWageType {
+    PayrollWageType {
+        id: wage_type_id(),
+        value_type: BillingPeriodValueType::CustomExtraHours("Fortbildung".into()),
+        value_view: PayrollValueView::Delta,
+        wage_type: "210".into(),
+        processing_key: 1,
+        description: "Fortbildung".into(),
+        created: Some(now()),
+        deleted: None,
+        version: version(),
+    }
+}
+
+pub struct PayrollWageTypeServiceDependencies {
+    pub payroll_wage_type_dao: MockPayrollWageTypeDao,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+}
+
+impl PayrollWageTypeServiceDeps for PayrollWageTypeServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type PayrollWageTypeDao = MockPayrollWageTypeDao;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl PayrollWageTypeServiceDependencies {
+    pub fn build_service(self) -> PayrollWageTypeServiceImpl<PayrollWageTypeServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        PayrollWageTypeServiceImpl {
+            payroll_wage_type_dao: Arc::new(self.payroll_wage_type_dao),
+            permission_service: Arc::new(self.permission_service),
+            clock_service: Arc::new(self.clock_service),
+            uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
+            transaction_dao: Arc::new(transaction_dao),
+        }
+    }
+}
+
+fn build_dependencies(hr: bool) -> PayrollWageTypeServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(move |_, _| {
+            if hr {
+                Ok(())
+            } else {
+                Err(service::ServiceError::Forbidden)
+            }
+        });
+    let mut clock_service = MockClockService::new();
+    clock_service.expect_date_time_now().returning(now);
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+    PayrollWageTypeServiceDependencies {
+        payroll_wage_type_dao: MockPayrollWageTypeDao::new(),
+        permission_service,
+        clock_service,
+        uuid_service: MockUuidService::new(),
+        audit_log_service,
+    }
+}
+
+#[tokio::test]
+async fn test_get_all_skips_unknown_value_types() {
+    let mut deps = build_dependencies(true);
+    deps.payroll_wage_type_dao.expect_all().returning(|_| {
+        Ok([
+            entity(),
+            PayrollWageTypeEntity {
+                id: Uuid::new_v4(),
+                value_type: "removed_value_type".into(),
+                ..entity()
+            },
+        ]
+        .into())
+    });
+    let service = deps.build_service();
+
+    let result = service.get_all(Authentication::Context(()), None).await.unwrap();
+
+    assert_eq!(result.as_ref(), &[wage_type()]);
+}
+
+#[tokio::test]
+async fn test_get_all_requires_hr() {
+    let service = build_dependencies(false).build_service();
+    let result = service.get_all(Authentication::Context(()), None).await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_create() {
+    let mut deps = build_dependencies(true);
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("payroll-wage-type-service::create id"))
+        .returning(|_| wage_type_id());
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("payroll-wage-type-service::create version"))
+        .returning(|_| version());
+    deps.payroll_wage_type_dao
+        .expect_create()
+        .with(eq(entity()), eq("payroll-wage-type-service"), always())
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            &PayrollWageType {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                created: None,
+                wage_type: " 210 ".into(),
+                ..wage_type()
+            },
+            Authentication::Context(()),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result, wage_type());
+}
+
+#[tokio::test]
+async fn test_create_rejects_invalid_wage_type() {
+    for code in ["", "12a", "12345"] {
+        let mut deps = build_dependencies(true);
+        deps.payroll_wage_type_dao.expect_create().times(0);
+        let service = deps.build_service();
+
+        let result = service
+            .create(
+                &PayrollWageType {
+                    id: Uuid::nil(),
+                    version: Uuid::nil(),
+                    wage_type: code.into(),
+                    ..wage_type()
+                },
+                Authentication::Context(()),
+                None,
+            )
+            .await;
+
+        test_validation_error(
+            &result,
+            &ValidationFailureItem::InvalidValue("wage_type".into()),
+            1,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_update_version_conflict() {
+    let mut deps = build_dependencies(true);
+    deps.payroll_wage_type_dao
+        .expect_find_by_id()
+        .with(eq(wage_type_id()), always())
+        .returning(|_, _| Ok(Some(entity())));
+    deps.payroll_wage_type_dao.expect_update().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .update(
+            &PayrollWageType {
+                version: new_version(),
+                ..wage_type()
+            },
+            Authentication::Context(()),
+            None,
+        )
+        .await;
+
+    test_conflicts(&result, &wage_type_id(), &version(), &new_version());
+}
+
+#[tokio::test]
+async fn test_delete_sets_deleted() {
+    let mut deps = build_dependencies(true);
+    deps.payroll_wage_type_dao
+        .expect_find_by_id()
+        .with(eq(wage_type_id()), always())
+        .returning(|_, _| Ok(Some(entity())));
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("payroll-wage-type-service::delete version"))
+        .returning(|_| new_version());
+    deps.payroll_wage_type_dao
+        .expect_update()
+        .with(
+            eq(PayrollWageTypeEntity {
+                deleted: Some(now()),
+                version: new_version(),
+                ..entity()
+            }),
+            eq("payroll-wage-type-service"),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .delete(wage_type_id(), Authentication::Context(()), None)
+        .await
+        .unwrap();
+}
@@ -10,19 +10,26 @@
 
 use std::sync::Arc;
 
-use dao::{pdf_export_config::MockPdfExportConfigDao, MockTransaction, MockTransactionDao};
+use dao::{
+    pdf_export_config::{MockPdfExportConfigDao, PdfExportShiftplanEntity},
+    MockTransaction, MockTransactionDao,
+};
 use mockall::predicate::{always, eq};
 use service::{
+    audit_log::{AuditAction, MockAuditLogService},
     clock::MockClockService,
-    pdf_export_config::{PdfExportConfig, PdfExportConfigService, PdfExportConfigUpdate},
+    pdf_export_config::{
+        PdfExportConfig, PdfExportConfigService, PdfExportConfigUpdate,
+        PdfExportShiftplanSetting,
+    },
     permission::Authentication,
     uuid_service::MockUuidService,
-    MockPermissionService,
+    MockPermissionService, ValidationFailureItem,
 };
 use uuid::{uuid, Uuid};
 
 use crate::pdf_export_config::{PdfExportConfigServiceDeps, PdfExportConfigServiceImpl};
-use crate::test::error_test::test_forbidden;
+use crate::test::error_test::{test_forbidden, test_validation_error};
 
 // ─── Mock-Setup (Admin-Gate + Update-Merge-Semantik) ───────────────────────
 
@@ -31,6 +38,7 @@ pub struct PdfExportConfigServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -41,6 +49,7 @@ impl PdfExportConfigServiceDeps for PdfExportConfigServiceDependencies {
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -51,6 +60,7 @@ impl PdfExportConfigServiceDependencies {
             permission_service: Arc::new(self.permission_service),
             clock_service: Arc::new(self.clock_service),
             uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
             transaction_dao: Arc::new(self.transaction_dao),
         }
     }
@@ -86,6 +96,11 @@ pub fn build_dependencies(admin: bool) -> PdfExportConfigServiceDependencies {
 
     let uuid_service = MockUuidService::new();
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     let mut transaction_dao = MockTransactionDao::new();
     transaction_dao
         .expect_use_transaction()
@@ -97,6 +112,7 @@ pub fn build_dependencies(admin: bool) -> PdfExportConfigServiceDependencies {
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
     }
 }
@@ -126,6 +142,8 @@ fn seed_entity() -> dao::pdf_export_config::PdfExportConfigEntity {
         target_folder: None,
         weeks_horizon: 8,
         cron_schedule: Arc::from("0 6 * * 1"),
+        include_planning_plans: false,
+        combined_pdf: false,
         last_success_at: None,
         last_error_at: None,
         last_error_message: None,
@@ -173,6 +191,8 @@ async fn update_non_admin_forbidden() {
         target_folder: Some(Arc::from("Schichtplaene/")),
         weeks_horizon: 4,
         cron_schedule: Arc::from("0 7 * * 1"),
+        include_planning_plans: false,
+        combined_pdf: false,
     };
     let result = service.update(update, ().into(), None).await;
     test_forbidden(&result);
@@ -202,6 +222,8 @@ async fn update_with_empty_token_keeps_existing() {
         target_folder: Some(Arc::from("Schichtplaene/")),
         weeks_horizon: 4,
         cron_schedule: Arc::from("0 7 * * 1"),
+        include_planning_plans: false,
+        combined_pdf: false,
         version: v2(),
         ..existing_with_token.clone()
     };
@@ -245,6 +267,8 @@ async fn update_with_empty_token_keeps_existing() {
         target_folder: Some(Arc::from("Schichtplaene/")),
         weeks_horizon: 4,
         cron_schedule: Arc::from("0 7 * * 1"),
+        include_planning_plans: false,
+        combined_pdf: false,
     };
     let result: PdfExportConfig = service.update(update, ().into(), None).await.unwrap();
 
@@ -306,6 +330,19 @@ async fn update_with_set_token_replaces_existing() {
         .with(eq(after_update.clone()), always(), always())
         .times(1)
         .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .times(1)
+        .withf(|change, _, _, _| {
+            // Der Token darf nicht im Audit-Log landen.
+            change.entity_type == service::audit_log::entity_type::PDF_EXPORT_CONFIG
+                && change.action == AuditAction::Update
+                && !change.before.as_deref().unwrap_or_default().contains("alt-token")
+                && !change.after.as_deref().unwrap_or_default().contains("neu-token")
+        })
+        .returning(|_, _, _, _| Ok(()));
 
     let service = dependencies.build_service();
     let update = PdfExportConfigUpdate {
@@ -316,11 +353,189 @@ async fn update_with_set_token_replaces_existing() {
         target_folder: None,
         weeks_horizon: 8,
         cron_schedule: Arc::from("0 6 * * 1"),
+        include_planning_plans: false,
+        combined_pdf: false,
     };
     let result = service.update(update, ().into(), None).await.unwrap();
     assert_eq!(result.webdav_app_token.as_deref(), Some("neu-token"));
 }
 
+// ─── Shiftplan-Auswahl ─────────────────────────────────────────────────────
+
+fn shiftplan_a() -> Uuid {
+    uuid!("aaaa0000-0000-0000-0000-000000000048")
+}
+fn shiftplan_b() -> Uuid {
+    uuid!("bbbb0000-0000-0000-0000-000000000048")
+}
+
+fn setting(
+    shiftplan_id: Uuid,
+    subfolder: Option<&str>,
+    filename_prefix: Option<&str>,
+) -> PdfExportShiftplanSetting {
+    PdfExportShiftplanSetting {
+        shiftplan_id,
+        included: true,
+        subfolder: subfolder.map(Arc::from),
+        filename_prefix: filename_prefix.map(Arc::from),
+    }
+}
+
+#[tokio::test]
+async fn update_shiftplan_settings_non_admin_forbidden() {
+    let mut dependencies = build_dependencies(false);
+    dependencies
+        .pdf_export_config_dao
+        .expect_replace_shiftplan_settings()
+        .times(0)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = dependencies.build_service();
+    let result = service
+        .update_shiftplan_settings(Arc::from([setting(shiftplan_a(), None, None)]), ().into(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+/// Leere Werte werden zu `None`, Schrägstriche am Rand des Unterordners
+/// verschwinden; die Liste wird als Ganzes ersetzt.
+#[tokio::test]
+async fn update_shiftplan_settings_normalizes_and_replaces() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| v2());
+    dependencies
+        .pdf_export_config_dao
+        .expect_replace_shiftplan_settings()
+        .times(1)
+        .withf(|settings, _process, version, _tx| {
+            *version == v2()
+                && settings
+                    == [
+                        PdfExportShiftplanEntity {
+                            shiftplan_id: shiftplan_a(),
+                            included: true,
+                            subfolder: Some(Arc::from("laden/2026")),
+                            filename_prefix: Some(Arc::from("laden")),
+                        },
+                        PdfExportShiftplanEntity {
+                            shiftplan_id: shiftplan_b(),
+                            included: false,
+                            subfolder: None,
+                            filename_prefix: None,
+                        },
+                    ]
+        })
+        .returning(|_, _, _, _| Ok(()));
+    dependencies
+        .pdf_export_config_dao
+        .expect_get()
+        .returning(|_| Ok(seed_entity()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .times(1)
+        .withf(|change, _, _, _| {
+            change.entity_type == service::audit_log::entity_type::PDF_EXPORT_SHIFTPLAN
+                && change.entity_id.as_ref() == seed_id().to_string()
+                && change.action == AuditAction::Update
+        })
+        .returning(|_, _, _, _| Ok(()));
+    dependencies
+        .pdf_export_config_dao
+        .expect_get_shiftplan_settings()
+        .returning(|_| {
+            Ok(Arc::from([PdfExportShiftplanEntity {
+                shiftplan_id: shiftplan_a(),
+                included: true,
+                subfolder: Some(Arc::from("laden/2026")),
+                filename_prefix: Some(Arc::from("laden")),
+            }]))
+        });
+
+    let service = dependencies.build_service();
+    let excluded = PdfExportShiftplanSetting {
+        included: false,
+        ..setting(shiftplan_b(), Some("  "), Some(""))
+    };
+    let result = service
+        .update_shiftplan_settings(
+            Arc::from([
+                setting(shiftplan_a(), Some(" /laden/2026/ "), Some(" laden ")),
+                excluded,
+            ]),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].subfolder.as_deref(), Some("laden/2026"));
+}
+
+#[tokio::test]
+async fn update_shiftplan_settings_rejects_parent_segment() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .pdf_export_config_dao
+        .expect_replace_shiftplan_settings()
+        .times(0)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = dependencies.build_service();
+    let result = service
+        .update_shiftplan_settings(
+            Arc::from([setting(shiftplan_a(), Some("laden/../privat"), None)]),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("subfolder".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn update_shiftplan_settings_rejects_path_in_prefix() {
+    let dependencies = build_dependencies(true);
+    let service = dependencies.build_service();
+    let result = service
+        .update_shiftplan_settings(
+            Arc::from([setting(shiftplan_a(), None, Some("laden/kw"))]),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("filename_prefix".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn update_shiftplan_settings_rejects_duplicate_shiftplan() {
+    let dependencies = build_dependencies(true);
+    let service = dependencies.build_service();
+    let result = service
+        .update_shiftplan_settings(
+            Arc::from([
+                setting(shiftplan_a(), Some("laden"), None),
+                setting(shiftplan_a(), Some("cafe"), None),
+            ]),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(&result, &ValidationFailureItem::Duplicate, 1);
+}
+
 // ─── Integration-Tests gegen echte SQLite-in-memory DB ────────────────────
 //
 // Diese Tests fahren die echte Migration `20260703000000_create-pdf-export-
@@ -333,16 +548,20 @@ async fn update_with_set_token_replaces_existing() {
 mod integration {
     use std::sync::Arc;
 
+    use dao_impl_sqlite::audit_log::AuditLogDaoImpl;
     use dao_impl_sqlite::pdf_export_config::PdfExportConfigDaoImpl;
     use dao_impl_sqlite::{TransactionDaoImpl, TransactionImpl};
     use mockall::predicate::always;
-    use service::pdf_export_config::PdfExportConfigService;
+    use service::pdf_export_config::{PdfExportConfigService, PdfExportShiftplanSetting};
     use service::permission::Authentication;
     use service::uuid_service::MockUuidService;
     use service::MockPermissionService;
     use uuid::{uuid, Uuid};
 
+    use crate::audit_log::{AuditLogServiceDeps, AuditLogServiceImpl};
+    use crate::clock::ClockServiceImpl;
     use crate::pdf_export_config::{PdfExportConfigServiceDeps, PdfExportConfigServiceImpl};
+    use crate::uuid_service::UuidServiceImpl;
     use service::clock::MockClockService;
 
     async fn setup_pool() -> Arc<sqlx::SqlitePool> {
@@ -379,9 +598,35 @@ mod integration {
         type PermissionService = MockPermissionService;
         type ClockService = MockClockService;
         type UuidService = MockUuidService;
+        type AuditLogService = AuditLogServiceImpl<AuditLogDeps>;
+        type TransactionDao = TransactionDaoImpl;
+    }
+
+    struct AuditLogDeps;
+    impl AuditLogServiceDeps for AuditLogDeps {
+        type Context = ();
+        type Transaction = TransactionImpl;
+        type AuditLogDao = AuditLogDaoImpl;
+        type PermissionService = MockPermissionService;
+        type ClockService = ClockServiceImpl;
+        type UuidService = UuidServiceImpl;
         type TransactionDao = TransactionDaoImpl;
     }
 
+    fn build_audit_log(pool: Arc<sqlx::SqlitePool>) -> AuditLogServiceImpl<AuditLogDeps> {
+        let mut permission_service = MockPermissionService::new();
+        permission_service
+            .expect_current_user_id()
+            .returning(|_| Ok(None));
+        AuditLogServiceImpl {
+            audit_log_dao: AuditLogDaoImpl::new(pool.clone()).into(),
+            permission_service: permission_service.into(),
+            clock_service: ClockServiceImpl.into(),
+            uuid_service: UuidServiceImpl.into(),
+            transaction_dao: TransactionDaoImpl::new(pool).into(),
+        }
+    }
+
     fn build_perm_full() -> MockPermissionService {
         let mut permission_service = MockPermissionService::new();
         permission_service
@@ -420,6 +665,7 @@ mod integration {
             permission_service: Arc::new(build_perm_full()),
             clock_service: Arc::new(MockClockService::new()),
             uuid_service: Arc::new(MockUuidService::new()),
+            audit_log_service: Arc::new(build_audit_log(pool.clone())),
             transaction_dao: Arc::new(tx_dao),
         };
 
@@ -460,6 +706,7 @@ mod integration {
             permission_service: Arc::new(build_perm_full()),
             clock_service: Arc::new(MockClockService::new()),
             uuid_service: Arc::new(uuid_service),
+            audit_log_service: Arc::new(build_audit_log(pool.clone())),
             transaction_dao: Arc::new(tx_dao),
         };
 
@@ -471,6 +718,8 @@ mod integration {
             target_folder: Some(Arc::from("Schichtplaene/")),
             weeks_horizon: 12,
             cron_schedule: Arc::from("0 7 * * 1"),
+            include_planning_plans: false,
+            combined_pdf: false,
         };
         let after = service
             .update(update, Authentication::Full, None)
@@ -515,6 +764,7 @@ mod integration {
             permission_service: Arc::new(build_perm_full()),
             clock_service: Arc::new(MockClockService::new()),
             uuid_service: Arc::new(uuid_service),
+            audit_log_service: Arc::new(build_audit_log(pool.clone())),
             transaction_dao: Arc::new(tx_dao),
         };
 
@@ -547,6 +797,80 @@ mod integration {
         assert_eq!(after_error.last_error_at, Some(now_b()));
         assert_eq!(after_error.last_error_message.as_deref(), Some("boom"));
     }
+
+    /// Flags und Shiftplan-Auswahl überleben den Roundtrip durch die echte
+    /// Migration; ein zweites Ersetzen löscht die alte Auswahl.
+    #[tokio::test]
+    async fn shiftplan_selection_and_flags_persist() {
+        let pool = setup_pool().await;
+        let dao = PdfExportConfigDaoImpl::new(pool.clone());
+        let tx_dao = TransactionDaoImpl::new(pool.clone());
+
+        let mut uuid_service = MockUuidService::new();
+        uuid_service
+            .expect_new_uuid()
+            .returning(|_| Uuid::new_v4());
+
+        let service = PdfExportConfigServiceImpl::<IntegrationDeps> {
+            pdf_export_config_dao: Arc::new(dao),
+            permission_service: Arc::new(build_perm_full()),
+            clock_service: Arc::new(MockClockService::new()),
+            uuid_service: Arc::new(uuid_service),
+            audit_log_service: Arc::new(build_audit_log(pool.clone())),
+            transaction_dao: Arc::new(tx_dao),
+        };
+
+        let fresh = service.get(Authentication::Full, None).await.unwrap();
+        assert!(fresh.include_planning_plans);
+        assert!(!fresh.combined_pdf);
+        assert!(service
+            .get_shiftplan_settings(Authentication::Full, None)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let update = service::pdf_export_config::PdfExportConfigUpdate {
+            enabled: false,
+            nextcloud_url: None,
+            webdav_user: None,
+            webdav_app_token: None,
+            target_folder: None,
+            weeks_horizon: 8,
+            cron_schedule: Arc::from("0 0 6 * * 1"),
+            include_planning_plans: false,
+            combined_pdf: true,
+        };
+        let after = service
+            .update(update, Authentication::Full, None)
+            .await
+            .unwrap();
+        assert!(!after.include_planning_plans);
+        assert!(after.combined_pdf);
+
+        let laden = uuid!("aaaa0000-0000-0000-0000-000000000048");
+        let cafe = uuid!("bbbb0000-0000-0000-0000-000000000048");
+        let first = PdfExportShiftplanSetting {
+            shiftplan_id: laden,
+            included: true,
+            subfolder: Some(Arc::from("laden")),
+            filename_prefix: Some(Arc::from("laden")),
+        };
+        service
+            .update_shiftplan_settings(Arc::from([first]), Authentication::Full, None)
+            .await
+            .unwrap();
+        let second = PdfExportShiftplanSetting {
+            shiftplan_id: cafe,
+            included: false,
+            subfolder: None,
+            filename_prefix: None,
+        };
+        let stored = service
+            .update_shiftplan_settings(Arc::from([second.clone()]), Authentication::Full, None)
+            .await
+            .unwrap();
+        assert_eq!(stored.as_ref(), &[second]);
+    }
 }
 
 // ─── Snapshot-Version-Gate ──────────────────────────────────────────────────
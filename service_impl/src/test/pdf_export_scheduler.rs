@@ -23,7 +23,7 @@ use mockall::predicate::always;
 use service::{
     clock::MockClockService,
     pdf_export::PdfExportScheduler,
-    pdf_export_config::{MockPdfExportConfigService, PdfExportConfig},
+    pdf_export_config::{MockPdfExportConfigService, PdfExportConfig, PdfExportShiftplanSetting},
     pdf_shiftplan::MockPdfShiftplanService,
     permission::Authentication,
     shiftplan_catalog::{MockShiftplanService, Shiftplan},
@@ -77,6 +77,8 @@ fn base_config(enabled: bool) -> PdfExportConfig {
         target_folder: Some(Arc::from("Schichtplaene")),
         weeks_horizon: 2,
         cron_schedule: Arc::from("0 6 * * 1"),
+        include_planning_plans: true,
+        combined_pdf: false,
         last_success_at: None,
         last_error_at: None,
         last_error_message: None,
@@ -94,6 +96,15 @@ fn active_shiftplan(id: Uuid) -> Shiftplan {
     }
 }
 
+/// Config-Mock ohne gespeicherte Shiftplan-Einstellungen — jeder aktive
+/// Shiftplan wird exportiert.
+fn config_service_without_settings() -> MockPdfExportConfigService {
+    let mut cfg = MockPdfExportConfigService::new();
+    cfg.expect_get_shiftplan_settings()
+        .returning(|_ctx, _tx| Ok(Arc::from([])));
+    cfg
+}
+
 /// Baue einen sinnvollen minimalen PDF-Body (>400 Bytes) fuer Byte-Length-
 /// Assertions in den Upload-Erwartungen — der Scheduler leitet die Bytes
 /// 1:1 an den WebDAV-Upload weiter.
@@ -141,7 +152,7 @@ fn full_auth_permission() -> MockPermissionService {
 
 #[tokio::test]
 async fn disabled_config_skips_run() {
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get()
         .returning(|_ctx, _tx| Ok(base_config(false)));
     // record_success and record_error MUST NOT be called
@@ -168,7 +179,7 @@ async fn disabled_config_skips_run() {
 
 #[tokio::test]
 async fn incomplete_config_records_error() {
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(|_ctx, _tx| {
         let mut c = base_config(true);
         c.webdav_app_token = None;
@@ -218,7 +229,7 @@ async fn incomplete_config_records_error() {
 async fn happy_path_renders_horizon_and_uploads() {
     let shiftplan_id = uuid!("aaaa1111-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 2;
@@ -296,7 +307,7 @@ async fn happy_path_renders_horizon_and_uploads() {
 async fn webdav_transient_fail_after_3_retries_records_error() {
     let shiftplan_id = uuid!("aaaa2222-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 2;
@@ -366,7 +377,7 @@ async fn webdav_transient_fail_after_3_retries_records_error() {
 async fn permanent_401_records_error_immediately() {
     let shiftplan_id = uuid!("aaaa3333-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 1;
@@ -434,7 +445,7 @@ async fn permanent_401_records_error_immediately() {
 async fn year_week_wraps_correctly() {
     let shiftplan_id = uuid!("aaaa4444-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 2;
@@ -508,7 +519,7 @@ async fn year_week_wraps_correctly() {
 async fn scheduler_calls_pdf_shiftplan_service_with_full_auth() {
     let shiftplan_id = uuid!("aaaa5555-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 1;
@@ -571,7 +582,7 @@ async fn scheduler_calls_pdf_shiftplan_service_with_full_auth() {
 async fn scheduler_skips_week_on_validation_error() {
     let shiftplan_id = uuid!("aaaa6666-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 1;
@@ -653,7 +664,7 @@ async fn scheduler_skips_week_on_validation_error() {
 async fn scheduler_continues_past_validation_error_for_later_weeks() {
     let shiftplan_id = uuid!("aaaa8888-0000-0000-0000-000000000048");
 
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 2;
@@ -769,7 +780,7 @@ async fn boot_trigger_reload_flow() {
 
     // Config-Mock: liefert enabled=true mit vollständiger Konfig; zählt
     // record_success.
-    let mut cfg = MockPdfExportConfigService::new();
+    let mut cfg = config_service_without_settings();
     cfg.expect_get().returning(move |_ctx, _tx| {
         let mut c = base_config(true);
         c.weeks_horizon = 1;
@@ -844,3 +855,287 @@ async fn boot_trigger_reload_flow() {
     // (header + xref + trailer + metadata).
     assert!(*body_len > 400, "PDF too short: {body_len}");
 }
+
+// ─── Shiftplan-Auswahl, Unterordner/Präfix, Sammel-PDF ───────────────────
+
+fn shiftplan(id: Uuid, name: &str, is_planning: bool) -> Shiftplan {
+    Shiftplan {
+        id,
+        name: Arc::from(name),
+        is_planning,
+        ..active_shiftplan(id)
+    }
+}
+
+fn main_plan() -> Shiftplan {
+    shiftplan(uuid!("aaaa0001-0000-0000-0000-000000000048"), "main", false)
+}
+
+fn bakery_plan() -> Shiftplan {
+    shiftplan(uuid!("aaaa0002-0000-0000-0000-000000000048"), "Bäckerei", false)
+}
+
+fn draft_plan() -> Shiftplan {
+    shiftplan(uuid!("aaaa0003-0000-0000-0000-000000000048"), "Entwurf", true)
+}
+
+fn not_released(week: u8) -> ServiceError {
+    ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+        Arc::from(format!("Woche KW{week:02}/2026 ist im Status Unset — kein Download")),
+    )]))
+}
+
+#[derive(Default)]
+struct SelectionRun {
+    uploads: Vec<String>,
+    rendered: Vec<(Uuid, u8)>,
+    combined: Vec<(Vec<Uuid>, u8)>,
+    errors: Vec<String>,
+    successes: usize,
+}
+
+/// Führt einen Lauf ab 2026-07-01 (KW27) aus. Wochen aus `unreleased`
+/// liefern beim Rendern den `ValidationError` des Status-Gates.
+async fn run_selection(
+    config: PdfExportConfig,
+    shiftplans: Vec<Shiftplan>,
+    settings: Vec<PdfExportShiftplanSetting>,
+    unreleased: &'static [u8],
+) -> SelectionRun {
+    let result = Arc::new(std::sync::Mutex::new(SelectionRun::default()));
+
+    let mut cfg = MockPdfExportConfigService::new();
+    cfg.expect_get()
+        .returning(move |_ctx, _tx| Ok(config.clone()));
+    let settings: Arc<[PdfExportShiftplanSetting]> = settings.into();
+    cfg.expect_get_shiftplan_settings()
+        .returning(move |_ctx, _tx| Ok(settings.clone()));
+    let r = result.clone();
+    cfg.expect_record_error().returning(move |_at, msg, _ctx, _tx| {
+        r.lock().unwrap().errors.push(msg.to_string());
+        Ok(())
+    });
+    let r = result.clone();
+    cfg.expect_record_success().returning(move |_at, _ctx, _tx| {
+        r.lock().unwrap().successes += 1;
+        Ok(())
+    });
+
+    let mut pdf_svc = MockPdfShiftplanService::new();
+    let r = result.clone();
+    pdf_svc
+        .expect_render_week_pdf()
+        .returning(move |id, _y, w, ctx, _tx| {
+            assert_eq!(ctx, Authentication::Full);
+            r.lock().unwrap().rendered.push((id, w));
+            if unreleased.contains(&w) {
+                Err(not_released(w))
+            } else {
+                Ok(fake_pdf_bytes())
+            }
+        });
+    let r = result.clone();
+    pdf_svc
+        .expect_render_combined_week_pdf()
+        .returning(move |plans, _y, w, ctx, _tx| {
+            assert_eq!(ctx, Authentication::Full);
+            let ids = plans.iter().map(|(id, _)| *id).collect();
+            r.lock().unwrap().combined.push((ids, w));
+            if unreleased.contains(&w) {
+                Err(not_released(w))
+            } else {
+                Ok(fake_pdf_bytes())
+            }
+        });
+
+    let mut sh = MockShiftplanService::new();
+    let shiftplans: Arc<[Shiftplan]> = shiftplans.into();
+    sh.expect_get_all()
+        .returning(move |_ctx, _tx| Ok(shiftplans.clone()));
+
+    let mut clock = MockClockService::new();
+    clock.expect_date_now().returning(|| {
+        time::Date::from_calendar_date(2026, time::Month::July, 1).unwrap()
+    });
+    clock.expect_date_time_now().returning(|| {
+        time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2026, time::Month::July, 1).unwrap(),
+            time::Time::from_hms(6, 0, 0).unwrap(),
+        )
+    });
+
+    let mut upload = MockWebDavUpload::new();
+    let r = result.clone();
+    upload
+        .expect_upload_file()
+        .returning(move |folder, filename, _bytes| {
+            r.lock().unwrap().uploads.push(format!("{folder}/{filename}"));
+            Ok(())
+        });
+    let factory = Arc::new(FixedFactory {
+        upload: Arc::new(upload),
+    });
+
+    let scheduler = build_scheduler(cfg, pdf_svc, sh, full_auth_permission(), clock, factory);
+    scheduler
+        .run_once_now(Authentication::Full)
+        .await
+        .expect("run must be Ok");
+    drop(scheduler);
+    Arc::try_unwrap(result)
+        .ok()
+        .expect("all mock handles dropped")
+        .into_inner()
+        .unwrap()
+}
+
+fn one_week_config() -> PdfExportConfig {
+    PdfExportConfig {
+        weeks_horizon: 1,
+        ..base_config(true)
+    }
+}
+
+/// Zwei Plans ohne Unterordner und Präfix im selben Ordner bekommen den
+/// Slug ihres Namens vorangestellt, damit sich die Dateien nicht
+/// überschreiben.
+#[tokio::test]
+async fn planning_plans_are_exported_by_default() {
+    let run = run_selection(
+        one_week_config(),
+        vec![main_plan(), draft_plan()],
+        vec![],
+        &[],
+    )
+    .await;
+    assert_eq!(
+        run.uploads,
+        vec![
+            "Schichtplaene/main-schichtplan-2026-KW27.pdf",
+            "Schichtplaene/entwurf-schichtplan-2026-KW27.pdf",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn planning_plans_are_skipped_when_opted_out() {
+    let config = PdfExportConfig {
+        include_planning_plans: false,
+        ..one_week_config()
+    };
+    let run = run_selection(config, vec![main_plan(), draft_plan()], vec![], &[]).await;
+    assert_eq!(run.rendered, vec![(main_plan().id, 27)]);
+    assert_eq!(run.uploads, vec!["Schichtplaene/schichtplan-2026-KW27.pdf"]);
+    assert_eq!(run.successes, 1);
+}
+
+#[tokio::test]
+async fn shiftplan_settings_control_folder_prefix_and_exclusion() {
+    let lager = shiftplan(uuid!("aaaa0004-0000-0000-0000-000000000048"), "Lager", false);
+    let settings = vec![
+        PdfExportShiftplanSetting {
+            shiftplan_id: main_plan().id,
+            included: true,
+            subfolder: Some(Arc::from("laden")),
+            filename_prefix: None,
+        },
+        PdfExportShiftplanSetting {
+            shiftplan_id: bakery_plan().id,
+            included: true,
+            subfolder: None,
+            filename_prefix: Some(Arc::from("baeckerei")),
+        },
+        PdfExportShiftplanSetting {
+            shiftplan_id: lager.id,
+            included: false,
+            subfolder: None,
+            filename_prefix: None,
+        },
+    ];
+    let run = run_selection(
+        one_week_config(),
+        vec![main_plan(), bakery_plan(), lager],
+        settings,
+        &[],
+    )
+    .await;
+    assert_eq!(
+        run.uploads,
+        vec![
+            "Schichtplaene/laden/schichtplan-2026-KW27.pdf",
+            "Schichtplaene/baeckerei-schichtplan-2026-KW27.pdf",
+        ]
+    );
+    assert!(run.errors.is_empty());
+}
+
+/// Der Status gilt für die Woche: lehnt das Gate sie für den ersten Plan
+/// ab, werden die übrigen Plans dieser Woche gar nicht erst gerendert.
+#[tokio::test]
+async fn unreleased_week_skips_all_plans_of_that_week() {
+    let config = PdfExportConfig {
+        weeks_horizon: 2,
+        ..base_config(true)
+    };
+    let run = run_selection(config, vec![main_plan(), bakery_plan()], vec![], &[27]).await;
+    assert_eq!(
+        run.rendered,
+        vec![
+            (main_plan().id, 27),
+            (main_plan().id, 28),
+            (bakery_plan().id, 28),
+        ]
+    );
+    assert_eq!(run.errors.len(), 1);
+    assert!(run.errors[0].contains("KW27"), "got: {}", run.errors[0]);
+    assert_eq!(
+        run.uploads,
+        vec![
+            "Schichtplaene/main-schichtplan-2026-KW28.pdf",
+            "Schichtplaene/baeckerei-schichtplan-2026-KW28.pdf",
+        ]
+    );
+    assert_eq!(run.successes, 1);
+}
+
+#[tokio::test]
+async fn combined_pdf_uploads_one_file_per_released_week() {
+    let config = PdfExportConfig {
+        weeks_horizon: 2,
+        include_planning_plans: false,
+        combined_pdf: true,
+        ..base_config(true)
+    };
+    let settings = vec![PdfExportShiftplanSetting {
+        shiftplan_id: bakery_plan().id,
+        included: true,
+        subfolder: Some(Arc::from("baeckerei")),
+        filename_prefix: Some(Arc::from("baeckerei")),
+    }];
+    let run = run_selection(
+        config,
+        vec![main_plan(), bakery_plan(), draft_plan()],
+        settings,
+        &[27],
+    )
+    .await;
+    let ids = vec![main_plan().id, bakery_plan().id];
+    assert_eq!(run.combined, vec![(ids.clone(), 27), (ids, 28)]);
+    assert!(run.rendered.is_empty());
+    assert_eq!(run.errors.len(), 1);
+    assert_eq!(run.uploads, vec!["Schichtplaene/schichtplan-2026-KW28.pdf"]);
+    assert_eq!(run.successes, 1);
+}
+
+#[tokio::test]
+async fn no_selected_shiftplan_records_error() {
+    let config = PdfExportConfig {
+        include_planning_plans: false,
+        ..one_week_config()
+    };
+    let run = run_selection(config, vec![draft_plan()], vec![], &[]).await;
+    assert!(run.rendered.is_empty());
+    assert!(run.uploads.is_empty());
+    assert_eq!(run.errors, vec!["Kein Shiftplan für den Export ausgewählt"]);
+    assert_eq!(run.successes, 0);
+}
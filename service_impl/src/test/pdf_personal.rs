@@ -0,0 +1,364 @@
+use std::sync::Arc;
+
+use mockall::predicate::{always, eq};
+use service::block::{Block, MockBlockService};
+use service::pdf_personal::PdfPersonalService;
+use service::permission::{Authentication, HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE};
+use service::reporting::{EmployeeReport, GroupedReportHours, MockReportingService};
+use service::sales_person::{MockSalesPersonService, SalesPerson};
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use shifty_utils::{DayOfWeek, ShiftyDate};
+use time::macros::time;
+use uuid::{uuid, Uuid};
+
+use crate::pdf_personal::{PdfPersonalServiceDeps, PdfPersonalServiceImpl};
+
+const ANNA_ID: Uuid = uuid!("6d1f2a3b-4c5d-4e6f-8a9b-000000000001");
+
+struct PdfPersonalDependencies {
+    block_service: MockBlockService,
+    reporting_service: MockReportingService,
+    sales_person_service: MockSalesPersonService,
+    permission_service: MockPermissionService,
+    transaction_dao: dao::MockTransactionDao,
+}
+
+impl PdfPersonalServiceDeps for PdfPersonalDependencies {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type BlockService = MockBlockService;
+    type ReportingService = MockReportingService;
+    type SalesPersonService = MockSalesPersonService;
+    type PermissionService = MockPermissionService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+impl PdfPersonalDependencies {
+    fn new() -> Self {
+        let mut transaction_dao = dao::MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(dao::MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+        let mut sales_person_service = MockSalesPersonService::new();
+        sales_person_service
+            .expect_get()
+            .returning(|id, _, _| Ok(sales_person(id)));
+        Self {
+            block_service: MockBlockService::new(),
+            reporting_service: MockReportingService::new(),
+            sales_person_service,
+            permission_service: MockPermissionService::new(),
+            transaction_dao,
+        }
+    }
+
+    /// Aufrufer ist weder HR noch Shiftplanner; `is_self` entscheidet, ob er
+    /// der angefragte Mitarbeiter ist.
+    fn with_employee(mut self, is_self: bool) -> Self {
+        self.permission_service
+            .expect_check_permission()
+            .returning(|_, _| Err(ServiceError::Forbidden));
+        self.sales_person_service
+            .expect_verify_user_is_sales_person()
+            .returning(move |_, _, _| {
+                if is_self {
+                    Ok(())
+                } else {
+                    Err(ServiceError::Forbidden)
+                }
+            });
+        self
+    }
+
+    fn with_shiftplanner(mut self) -> Self {
+        self.permission_service
+            .expect_check_permission()
+            .with(eq(SHIFTPLANNER_PRIVILEGE), always())
+            .returning(|_, _| Ok(()));
+        self.permission_service
+            .expect_check_permission()
+            .returning(|_, _| Err(ServiceError::Forbidden));
+        self.sales_person_service
+            .expect_verify_user_is_sales_person()
+            .returning(|_, _, _| Err(ServiceError::Forbidden));
+        self
+    }
+
+    fn with_hr(mut self) -> Self {
+        self.permission_service
+            .expect_check_permission()
+            .with(eq(HR_PRIVILEGE), always())
+            .returning(|_, _| Ok(()));
+        self.permission_service
+            .expect_check_permission()
+            .returning(|_, _| Err(ServiceError::Forbidden));
+        self.sales_person_service
+            .expect_verify_user_is_sales_person()
+            .returning(|_, _, _| Err(ServiceError::Forbidden));
+        self
+    }
+
+    fn build_service(self) -> PdfPersonalServiceImpl<PdfPersonalDependencies> {
+        PdfPersonalServiceImpl {
+            block_service: self.block_service.into(),
+            reporting_service: self.reporting_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            permission_service: self.permission_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+fn sales_person(id: Uuid) -> SalesPerson {
+    SalesPerson {
+        id,
+        name: "Anna Müller".into(),
+        background_color: "#ffffff".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn block(year: u32, week: u8) -> Block {
+    Block {
+        year,
+        week,
+        sales_person: Some(Arc::new(sales_person(ANNA_ID))),
+        day_of_week: DayOfWeek::Monday,
+        from: time!(08:00),
+        to: time!(12:00),
+        bookings: Arc::new([]),
+        slots: Arc::new([]),
+    }
+}
+
+fn month(month: u8) -> GroupedReportHours {
+    let from = ShiftyDate::from_ymd(2026, month, 1).unwrap();
+    GroupedReportHours {
+        from,
+        to: from,
+        year: 2026,
+        week: 1,
+        contract_weekly_hours: 20.0,
+        expected_hours: 80.0,
+        dynamic_hours: 0.0,
+        overall_hours: 78.0,
+        balance: -2.0,
+        days_per_week: 5,
+        workdays_per_week: 5.0,
+        shiftplan_hours: 78.0,
+        extra_work_hours: 0.0,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        custom_extra_hours: Arc::new([]),
+        days: Arc::new([]),
+    }
+}
+
+fn employee_report() -> EmployeeReport {
+    EmployeeReport {
+        sales_person: sales_person(ANNA_ID).into(),
+        balance_hours: 5.5,
+        overall_hours: 234.0,
+        expected_hours: 240.0,
+        dynamic_hours: 0.0,
+        shiftplan_hours: 234.0,
+        extra_work_hours: 0.0,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        vacation_carryover: 0,
+        vacation_days: 0.0,
+        vacation_entitlement: 0.0,
+        sick_leave_days: 0.0,
+        holiday_days: 0.0,
+        absence_days: 0.0,
+        carryover_hours: 11.5,
+        custom_extra_hours: Arc::new([]),
+        by_week: Arc::new([]),
+        by_month: Arc::new([month(1), month(2), month(3)]),
+    }
+}
+
+fn is_invalid_value(result: &Result<service::report_export::ExportFile, ServiceError>) -> bool {
+    matches!(
+        result,
+        Err(ServiceError::ValidationError(items))
+            if matches!(items.as_ref(), [ValidationFailureItem::InvalidValue(_)])
+    )
+}
+
+// ── Dienstplan ──────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn schedule_loads_each_week_across_year_end() {
+    let mut deps = PdfPersonalDependencies::new().with_employee(true);
+    for (year, week) in [(2026, 52), (2026, 53), (2027, 1)] {
+        deps.block_service
+            .expect_get_blocks_for_sales_person_week()
+            .with(
+                eq(ANNA_ID),
+                eq(year),
+                eq(week),
+                eq(Authentication::Full),
+                always(),
+            )
+            .times(1)
+            .returning(move |_, _, _, _, _| Ok(Arc::new([block(year, week)])));
+    }
+    let service = deps.build_service();
+
+    let file = service
+        .render_schedule_pdf(ANNA_ID, 2026, 52, 3, Authentication::Context(()), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        file.file_name.as_ref(),
+        "dienstplan-anna-mueller-2026-KW52.pdf"
+    );
+    assert_eq!(file.content_type, "application/pdf");
+    assert_eq!(&file.content[..4], b"%PDF");
+}
+
+#[tokio::test]
+async fn schedule_allowed_for_shiftplanner() {
+    let mut deps = PdfPersonalDependencies::new().with_shiftplanner();
+    deps.block_service
+        .expect_get_blocks_for_sales_person_week()
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(Arc::new([])));
+    let service = deps.build_service();
+
+    let result = service
+        .render_schedule_pdf(ANNA_ID, 2026, 10, 1, Authentication::Context(()), None)
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn schedule_forbidden_for_other_employee() {
+    let mut deps = PdfPersonalDependencies::new().with_employee(false);
+    deps.block_service
+        .expect_get_blocks_for_sales_person_week()
+        .never();
+    let service = deps.build_service();
+
+    let result = service
+        .render_schedule_pdf(ANNA_ID, 2026, 10, 4, Authentication::Context(()), None)
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::Forbidden)));
+}
+
+#[tokio::test]
+async fn schedule_rejects_invalid_week_and_week_count() {
+    let mut deps = PdfPersonalDependencies::new().with_hr();
+    deps.block_service
+        .expect_get_blocks_for_sales_person_week()
+        .never();
+    let service = deps.build_service();
+
+    for (week, weeks) in [(0, 1), (54, 1), (10, 0), (10, 13)] {
+        let result = service
+            .render_schedule_pdf(
+                ANNA_ID,
+                2026,
+                week,
+                weeks,
+                Authentication::Context(()),
+                None,
+            )
+            .await;
+        assert!(is_invalid_value(&result), "KW {week}, {weeks} Wochen");
+    }
+}
+
+// ── Stundenzettel ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn timesheet_reports_from_year_start_to_month_end() {
+    let mut deps = PdfPersonalDependencies::new().with_hr();
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .with(
+            eq(ANNA_ID),
+            eq(ShiftyDate::from_ymd(2026, 1, 1).unwrap()),
+            eq(ShiftyDate::from_ymd(2026, 2, 28).unwrap()),
+            eq(true),
+            eq(Authentication::Full),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _, _| Ok(employee_report()));
+    let service = deps.build_service();
+
+    let file = service
+        .render_timesheet_pdf(ANNA_ID, 2026, 2, Authentication::Context(()), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        file.file_name.as_ref(),
+        "stundenzettel-anna-mueller-2026-02.pdf"
+    );
+    assert_eq!(file.content_type, "application/pdf");
+    assert_eq!(&file.content[..4], b"%PDF");
+}
+
+#[tokio::test]
+async fn timesheet_allowed_for_employee_self() {
+    let mut deps = PdfPersonalDependencies::new().with_employee(true);
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .times(1)
+        .returning(|_, _, _, _, _, _| Ok(employee_report()));
+    let service = deps.build_service();
+
+    let result = service
+        .render_timesheet_pdf(ANNA_ID, 2026, 3, Authentication::Context(()), None)
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn timesheet_forbidden_for_shiftplanner() {
+    let mut deps = PdfPersonalDependencies::new().with_shiftplanner();
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .never();
+    let service = deps.build_service();
+
+    let result = service
+        .render_timesheet_pdf(ANNA_ID, 2026, 3, Authentication::Context(()), None)
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::Forbidden)));
+}
+
+#[tokio::test]
+async fn timesheet_rejects_invalid_month() {
+    let mut deps = PdfPersonalDependencies::new().with_hr();
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .never();
+    let service = deps.build_service();
+
+    for month in [0, 13] {
+        let result = service
+            .render_timesheet_pdf(ANNA_ID, 2026, month, Authentication::Context(()), None)
+            .await;
+        assert!(is_invalid_value(&result), "Monat {month}");
+    }
+}
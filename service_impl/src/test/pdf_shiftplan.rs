@@ -63,6 +63,7 @@ fn sales_person(id: Uuid, name: &str, deleted: bool) -> SalesPerson {
         background_color: Arc::from("#000000"),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: if deleted {
             Some(time::PrimitiveDateTime::new(
                 time::Date::from_calendar_date(2026, time::Month::January, 1).unwrap(),
@@ -378,6 +379,64 @@ async fn week_status_error_bubbles_up() {
     assert!(matches!(err, ServiceError::InternalError), "got {err:?}");
 }
 
+// ─── Sammel-PDF mehrerer Shiftplans ───────────────────────────────────────
+
+const SECOND_SHIFTPLAN_ID: Uuid = uuid!("aaaa0001-0000-0000-0000-000000000049");
+
+fn combined_plans() -> Arc<[(Uuid, Arc<str>)]> {
+    Arc::from([
+        (SHIFTPLAN_ID, Arc::from("Laden")),
+        (SECOND_SHIFTPLAN_ID, Arc::from("Lager")),
+    ])
+}
+
+/// Das Status-Gate läuft einmal pro Woche, die View einmal pro Plan.
+#[tokio::test]
+async fn combined_pdf_reads_each_plan_once_behind_one_gate() {
+    let ws = ok_week_status(WeekStatus::Locked, 1);
+
+    let mut view = MockShiftplanViewService::new();
+    view.expect_get_shiftplan_week()
+        .with(eq(SHIFTPLAN_ID), eq(YEAR), eq(WEEK), always(), always())
+        .times(1)
+        .returning(|_id, y, w, _ctx, _tx| Ok(empty_week(y, w)));
+    view.expect_get_shiftplan_week()
+        .with(eq(SECOND_SHIFTPLAN_ID), eq(YEAR), eq(WEEK), always(), always())
+        .times(1)
+        .returning(|_id, y, w, _ctx, _tx| Ok(empty_week(y, w)));
+
+    let mut sp = MockSalesPersonService::new();
+    sp.expect_get_all()
+        .times(1)
+        .returning(|_ctx, _tx| Ok(Arc::from(vec![])));
+
+    let service = build_service(view, sp, ws, MockPermissionService::new());
+    let bytes = service
+        .render_combined_week_pdf(combined_plans(), YEAR, WEEK, Authentication::Full, None)
+        .await
+        .expect("combined render must succeed");
+    assert!(bytes.starts_with(b"%PDF-"), "missing %PDF- header");
+}
+
+#[tokio::test]
+async fn combined_pdf_rejects_unreleased_week_without_reads() {
+    let ws = ok_week_status(WeekStatus::InPlanning, 1);
+
+    let mut view = MockShiftplanViewService::new();
+    view.expect_get_shiftplan_week().times(0);
+    let mut sp = MockSalesPersonService::new();
+    sp.expect_get_all().times(0);
+
+    let service = build_service(view, sp, ws, MockPermissionService::new());
+    let result = service
+        .render_combined_week_pdf(combined_plans(), YEAR, WEEK, Authentication::Full, None)
+        .await;
+    assert!(
+        matches!(result, Err(ServiceError::ValidationError(_))),
+        "expected ValidationError, got {result:?}"
+    );
+}
+
 // ─── PDF-03 (Task 1 RED): Filename-Helper Format-Regel ────────────────────
 //
 // Filename-Format `schichtplan-{JJJJ}-KW{NN:02}.pdf` (D-49-01 + PDF-03).
@@ -1,23 +1,49 @@
+use std::sync::Arc;
+
 use crate::test::error_test::*;
 use crate::*;
+use dao::audit_log::{AuditAction, AuditLogEntity, MockAuditLogDao};
 use mockall::predicate::eq;
+use service::clock::MockClockService;
+use service::uuid_service::MockUuidService;
 use service::PermissionService;
+use time::macros::datetime;
 
 pub struct PermissionServiceDependencies {
     pub permission_dao: dao::MockPermissionDao,
     pub user_service: service::user_service::MockUserService,
+    pub audit_log_dao: MockAuditLogDao,
 }
 impl crate::permission::PermissionServiceDeps for PermissionServiceDependencies {
     type Context = ();
     type Transaction = dao::MockTransaction;
     type PermissionDao = dao::MockPermissionDao;
     type UserService = service::user_service::MockUserService;
+    type AuditLogDao = MockAuditLogDao;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type TransactionDao = dao::MockTransactionDao;
 }
 impl PermissionServiceDependencies {
     pub fn build_service(self) -> PermissionServiceImpl<PermissionServiceDependencies> {
+        let mut clock_service = MockClockService::new();
+        clock_service
+            .expect_date_time_now()
+            .returning(|| datetime!(2026 - 10 - 17 12:00:00));
+        let mut uuid_service = MockUuidService::new();
+        uuid_service.expect_new_uuid().returning(|_| uuid::Uuid::nil());
+        let mut transaction_dao = dao::MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(dao::MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
         PermissionServiceImpl {
             permission_dao: self.permission_dao.into(),
             user_service: self.user_service.into(),
+            audit_log_dao: self.audit_log_dao.into(),
+            clock_service: clock_service.into(),
+            uuid_service: uuid_service.into(),
+            transaction_dao: transaction_dao.into(),
         }
     }
 }
@@ -36,12 +62,30 @@ fn generate_dependencies_mocks_permission(
     user_service
         .expect_current_user()
         .returning(|_| Ok("DEVUSER".into()));
+
+    let mut audit_log_dao = MockAuditLogDao::new();
+    audit_log_dao.expect_create().returning(|_, _| Ok(()));
     PermissionServiceDependencies {
         permission_dao,
         user_service,
+        audit_log_dao,
     }
 }
 
+/// Erwartet genau einen Protokolleintrag, auf den `check` zutrifft.
+fn expect_audit(
+    dependencies: &mut PermissionServiceDependencies,
+    check: impl Fn(&AuditLogEntity) -> bool + Send + 'static,
+) {
+    dependencies.audit_log_dao.checkpoint();
+    dependencies
+        .audit_log_dao
+        .expect_create()
+        .withf(move |entity, _| check(entity))
+        .times(1)
+        .returning(|_, _| Ok(()));
+}
+
 #[tokio::test]
 async fn test_check_permission() {
     let permission_service = generate_dependencies_mocks_permission(true, "hello").build_service();
@@ -152,6 +196,13 @@ async fn test_create_role() {
         )
         .times(1)
         .returning(|_, _| Ok(()));
+    expect_audit(&mut dependencies, |entity| {
+        entity.entity_type.as_ref() == "role"
+            && entity.entity_id.as_ref() == "testrole"
+            && entity.action == AuditAction::Create
+            && entity.user_id.as_deref() == Some("DEVUSER")
+            && entity.process.as_ref() == "permission-service"
+    });
 
     let permission_service = dependencies.build_service();
     permission_service
@@ -175,6 +226,11 @@ async fn test_delete_role() {
         .with(eq("testrole"))
         .times(1)
         .returning(|_| Ok(()));
+    expect_audit(&mut dependencies, |entity| {
+        entity.entity_type.as_ref() == "role"
+            && entity.action == AuditAction::Delete
+            && entity.before.as_deref() == Some(r#"{"name":"testrole"}"#)
+    });
 
     let permission_service = dependencies.build_service();
 
@@ -259,6 +315,30 @@ async fn test_add_user_role() {
         .with(eq("testuser"), eq("testrole"), eq("permission-service"))
         .times(1)
         .returning(|_, _, _| Ok(()));
+    let mut assigned = false;
+    dependencies
+        .permission_dao
+        .expect_roles_for_user()
+        .with(eq("testuser"))
+        .times(2)
+        .returning(move |_| {
+            let roles: Arc<[dao::RoleEntity]> = if assigned {
+                Arc::new([dao::RoleEntity {
+                    name: "testrole".into(),
+                }])
+            } else {
+                Arc::new([])
+            };
+            assigned = true;
+            Ok(roles)
+        });
+    expect_audit(&mut dependencies, |entity| {
+        entity.entity_type.as_ref() == "user_role"
+            && entity.entity_id.as_ref() == "testuser"
+            && entity.action == AuditAction::Update
+            && entity.before.as_deref() == Some("[]")
+            && entity.after.as_deref() == Some(r#"["testrole"]"#)
+    });
 
     let permission_service = dependencies.build_service();
 
@@ -347,6 +427,28 @@ async fn test_delete_user_role() {
         .with(eq("testuser"), eq("testrole"))
         .times(1)
         .returning(|_, _| Ok(()));
+    let mut removed = false;
+    dependencies
+        .permission_dao
+        .expect_roles_for_user()
+        .with(eq("testuser"))
+        .times(2)
+        .returning(move |_| {
+            let roles: Arc<[dao::RoleEntity]> = if removed {
+                Arc::new([])
+            } else {
+                Arc::new([dao::RoleEntity {
+                    name: "testrole".into(),
+                }])
+            };
+            removed = true;
+            Ok(roles)
+        });
+    expect_audit(&mut dependencies, |entity| {
+        entity.entity_type.as_ref() == "user_role"
+            && entity.before.as_deref() == Some(r#"["testrole"]"#)
+            && entity.after.as_deref() == Some("[]")
+    });
 
     let permission_service = dependencies.build_service();
     permission_service
@@ -0,0 +1,447 @@
+use crate::test::error_test::*;
+use dao::{
+    qualification::{MockQualificationDao, QualificationEntity},
+    MockTransaction, MockTransactionDao,
+};
+use service::{
+    audit_log::MockAuditLogService,
+    clock::MockClockService,
+    permission::MockPermissionService,
+    qualification::{
+        unmet_requirements, Qualification, QualificationService, SalesPersonQualification,
+        SlotQualificationRequirement, UnmetRequirement,
+    },
+    sales_person::MockSalesPersonService,
+    slot::{MockSlotService, Slot},
+    uuid_service::MockUuidService,
+    ServiceError, ValidationFailureItem,
+};
+use shifty_utils::DayOfWeek;
+use std::sync::Arc;
+use time::{Month, Time};
+use uuid::{uuid, Uuid};
+
+use crate::qualification::{QualificationServiceDeps, QualificationServiceImpl};
+
+fn default_qualification_id() -> Uuid {
+    uuid!("D0000000-0000-0000-0000-000000000001")
+}
+
+fn default_sales_person_id() -> Uuid {
+    uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F0")
+}
+
+fn default_slot_id() -> Uuid {
+    uuid!("7A7FF57A-782B-4C2E-A68B-4E2D81D79380")
+}
+
+fn default_version() -> Uuid {
+    uuid!("AAAAAAAA-BBBB-4CCC-8DDD-EEEEEEEEEEEE")
+}
+
+fn default_qualification_entity() -> QualificationEntity {
+    QualificationEntity {
+        id: default_qualification_id(),
+        name: "Kasse".into(),
+        description: "".into(),
+        created: generate_default_datetime(),
+        deleted: None,
+        version: default_version(),
+    }
+}
+
+fn default_slot() -> Slot {
+    Slot {
+        id: default_slot_id(),
+        day_of_week: DayOfWeek::Monday,
+        from: Time::from_hms(9, 0, 0).unwrap(),
+        to: Time::from_hms(17, 0, 0).unwrap(),
+        min_resources: 2,
+        max_paid_employees: None,
+        valid_from: time::Date::from_calendar_date(2024, Month::January, 1).unwrap(),
+        valid_to: None,
+        deleted: None,
+        version: default_version(),
+        shiftplan_id: None,
+    }
+}
+
+fn requirement(min_count: u8) -> SlotQualificationRequirement {
+    SlotQualificationRequirement {
+        slot_id: default_slot_id(),
+        qualification_id: default_qualification_id(),
+        min_count,
+    }
+}
+
+pub struct QualificationServiceDependencies {
+    pub qualification_dao: MockQualificationDao,
+    pub sales_person_service: MockSalesPersonService,
+    pub slot_service: MockSlotService,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl QualificationServiceDeps for QualificationServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type QualificationDao = MockQualificationDao;
+    type SalesPersonService = MockSalesPersonService;
+    type SlotService = MockSlotService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl QualificationServiceDependencies {
+    pub fn build_service(self) -> QualificationServiceImpl<QualificationServiceDependencies> {
+        QualificationServiceImpl {
+            qualification_dao: self.qualification_dao.into(),
+            sales_person_service: self.sales_person_service.into(),
+            slot_service: self.slot_service.into(),
+            permission_service: self.permission_service.into(),
+            clock_service: self.clock_service.into(),
+            uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+fn build_dependencies(permission_granted: bool) -> QualificationServiceDependencies {
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(move |_, _| {
+            if permission_granted {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_time_now()
+        .returning(generate_default_datetime);
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| Uuid::new_v4());
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut qualification_dao = MockQualificationDao::new();
+    qualification_dao
+        .expect_all()
+        .returning(|_| Ok(Arc::new([default_qualification_entity()])));
+
+    QualificationServiceDependencies {
+        qualification_dao,
+        sales_person_service: MockSalesPersonService::new(),
+        slot_service: MockSlotService::new(),
+        permission_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        transaction_dao,
+    }
+}
+
+#[tokio::test]
+async fn test_create() {
+    let mut deps = build_dependencies(true);
+    deps.qualification_dao
+        .expect_create()
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let service = deps.build_service();
+    let qualification = Qualification {
+        id: Uuid::nil(),
+        name: "  Stapler ".into(),
+        description: "".into(),
+        created: None,
+        deleted: None,
+        version: Uuid::nil(),
+    };
+    let created = service
+        .create(&qualification, ().auth(), None)
+        .await
+        .unwrap();
+    assert_ne!(created.id, Uuid::nil());
+    assert_ne!(created.version, Uuid::nil());
+    assert_eq!(created.name.as_ref(), "Stapler");
+    assert_eq!(created.created, Some(generate_default_datetime()));
+}
+
+#[tokio::test]
+async fn test_create_empty_name_fails() {
+    let deps = build_dependencies(true);
+    let service = deps.build_service();
+    let qualification = Qualification {
+        id: Uuid::nil(),
+        name: " ".into(),
+        description: "".into(),
+        created: None,
+        deleted: None,
+        version: Uuid::nil(),
+    };
+    let result = service.create(&qualification, ().auth(), None).await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("name".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_create_forbidden() {
+    let deps = build_dependencies(false);
+    let service = deps.build_service();
+    let qualification = Qualification::from(&default_qualification_entity());
+    let result = service.create(&qualification, ().auth(), None).await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_update_version_conflict() {
+    let mut deps = build_dependencies(true);
+    deps.qualification_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(default_qualification_entity())));
+
+    let service = deps.build_service();
+    let wrong_version = Uuid::new_v4();
+    let qualification = Qualification {
+        version: wrong_version,
+        ..Qualification::from(&default_qualification_entity())
+    };
+    let result = service.update(&qualification, ().auth(), None).await;
+    test_conflicts(
+        &result,
+        &default_qualification_id(),
+        &default_version(),
+        &wrong_version,
+    );
+}
+
+#[tokio::test]
+async fn test_delete_sets_deleted() {
+    let mut deps = build_dependencies(true);
+    deps.qualification_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(Some(default_qualification_entity())));
+    deps.qualification_dao
+        .expect_update()
+        .withf(|entity, _, _| entity.deleted == Some(generate_default_datetime()))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let service = deps.build_service();
+    service
+        .delete(default_qualification_id(), ().auth(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_for_sales_person_deduplicates() {
+    let mut deps = build_dependencies(true);
+    deps.sales_person_service
+        .expect_exists()
+        .returning(|_, _, _| Ok(true));
+    deps.qualification_dao
+        .expect_find_by_sales_person()
+        .returning(|_, _| Ok(Arc::new([])));
+    deps.qualification_dao
+        .expect_set_for_sales_person()
+        .withf(|sales_person_id, qualification_ids, _, _| {
+            *sales_person_id == default_sales_person_id()
+                && qualification_ids == [default_qualification_id()]
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = deps.build_service();
+    service
+        .set_for_sales_person(
+            default_sales_person_id(),
+            &[default_qualification_id(), default_qualification_id()],
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_for_sales_person_unknown_qualification() {
+    let mut deps = build_dependencies(true);
+    deps.sales_person_service
+        .expect_exists()
+        .returning(|_, _, _| Ok(true));
+    deps.qualification_dao
+        .expect_set_for_sales_person()
+        .times(0);
+
+    let service = deps.build_service();
+    let unknown_id = Uuid::new_v4();
+    let result = service
+        .set_for_sales_person(default_sales_person_id(), &[unknown_id], ().auth(), None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::IdDoesNotExist("qualification_id".into(), unknown_id),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_set_for_unknown_sales_person() {
+    let mut deps = build_dependencies(true);
+    deps.sales_person_service
+        .expect_exists()
+        .returning(|_, _, _| Ok(false));
+
+    let service = deps.build_service();
+    let result = service
+        .set_for_sales_person(default_sales_person_id(), &[], ().auth(), None)
+        .await;
+    test_not_found(&result, &default_sales_person_id());
+}
+
+#[tokio::test]
+async fn test_set_slot_requirements() {
+    let mut deps = build_dependencies(true);
+    deps.slot_service
+        .expect_get_slot()
+        .returning(|_, _, _| Ok(default_slot()));
+    deps.qualification_dao
+        .expect_find_requirements_by_slot()
+        .returning(|_, _| Ok(Arc::new([])));
+    deps.qualification_dao
+        .expect_set_requirements_for_slot()
+        .withf(|slot_id, requirements, _, _| {
+            *slot_id == default_slot_id()
+                && requirements.len() == 1
+                && requirements[0].min_count == 2
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = deps.build_service();
+    service
+        .set_slot_requirements(default_slot_id(), &[requirement(2)], ().auth(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_slot_requirements_min_count_out_of_range() {
+    let mut deps = build_dependencies(true);
+    deps.slot_service
+        .expect_get_slot()
+        .returning(|_, _, _| Ok(default_slot()));
+    deps.qualification_dao
+        .expect_set_requirements_for_slot()
+        .times(0);
+
+    let service = deps.build_service();
+    for min_count in [0, 3] {
+        let result = service
+            .set_slot_requirements(
+                default_slot_id(),
+                &[requirement(min_count)],
+                ().auth(),
+                None,
+            )
+            .await;
+        test_validation_error(
+            &result,
+            &ValidationFailureItem::InvalidValue("min_count".into()),
+            1,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_set_slot_requirements_duplicate() {
+    let mut deps = build_dependencies(true);
+    deps.slot_service
+        .expect_get_slot()
+        .returning(|_, _, _| Ok(default_slot()));
+
+    let service = deps.build_service();
+    let result = service
+        .set_slot_requirements(
+            default_slot_id(),
+            &[requirement(1), requirement(2)],
+            ().auth(),
+            None,
+        )
+        .await;
+    test_validation_error(&result, &ValidationFailureItem::Duplicate, 1);
+}
+
+#[tokio::test]
+async fn test_set_slot_requirements_forbidden() {
+    let deps = build_dependencies(false);
+    let service = deps.build_service();
+    let result = service
+        .set_slot_requirements(default_slot_id(), &[requirement(1)], ().auth(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[test]
+fn test_unmet_requirements() {
+    let other_sales_person_id = uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F1");
+    let assignments = [
+        SalesPersonQualification {
+            sales_person_id: default_sales_person_id(),
+            qualification_id: default_qualification_id(),
+        },
+        SalesPersonQualification {
+            sales_person_id: other_sales_person_id,
+            qualification_id: default_qualification_id(),
+        },
+    ];
+
+    // Zwei gebuchte Qualifizierte erfüllen min_count 2.
+    assert!(unmet_requirements(
+        &[requirement(2)],
+        &[default_sales_person_id(), other_sales_person_id],
+        &assignments,
+    )
+    .is_empty());
+
+    // Doppelt gebucht zählt einmal; nicht gebuchte Qualifizierte zählen nicht.
+    assert_eq!(
+        unmet_requirements(
+            &[requirement(2)],
+            &[default_sales_person_id(), default_sales_person_id()],
+            &assignments,
+        ),
+        vec![UnmetRequirement {
+            qualification_id: default_qualification_id(),
+            required_count: 2,
+            qualified_count: 1,
+        }]
+    );
+
+    assert!(unmet_requirements(&[], &[], &assignments).is_empty());
+}
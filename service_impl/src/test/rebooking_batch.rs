@@ -121,6 +121,9 @@ fn existing_batch_entity() -> RebookingBatchEntity {
         created: fixed_datetime(),
         approved: None,
         approved_by: None,
+        rejected: None,
+        rejected_by: None,
+        reject_reason: None,
         deleted: None,
         version: default_version(),
     }
@@ -216,6 +219,9 @@ async fn create_success() {
         created: time::PrimitiveDateTime::MIN,
         approved: None,
         approved_by: None,
+        rejected: None,
+        rejected_by: None,
+        reject_reason: None,
         deleted: None,
         version: Uuid::nil(),
     };
@@ -275,6 +281,9 @@ async fn create_unique_conflict_maps_to_already_exists() {
         created: time::PrimitiveDateTime::MIN,
         approved: None,
         approved_by: None,
+        rejected: None,
+        rejected_by: None,
+        reject_reason: None,
         deleted: None,
         version: Uuid::nil(),
     };
@@ -305,3 +314,71 @@ async fn find_by_id_non_hr_forbidden() {
 
     test_forbidden(&result);
 }
+
+/// (6) update: Version passt → State uebernommen, neue Version vergeben.
+#[tokio::test]
+async fn update_takes_state_and_assigns_version() {
+    let mut deps = build_dependencies(true, "hr");
+    deps.rebooking_batch_dao
+        .expect_find_by_id()
+        .returning(|_id, _tx| Ok(Some(existing_batch_entity())));
+    deps.uuid_service
+        .expect_new_uuid()
+        .returning(|_| fresh_version());
+    deps.rebooking_batch_dao
+        .expect_update_batch()
+        .withf(|batch, _process, _tx| {
+            batch.state == RebookingBatchState::Rejected
+                && batch.reject_reason.as_deref() == Some("zu frueh")
+                && batch.version == fresh_version()
+        })
+        .times(1)
+        .returning(|_batch, _process, _tx| Ok(()));
+
+    let service = deps.build_service();
+    let result = service
+        .update(
+            &RebookingBatchEntity {
+                state: RebookingBatchState::Rejected,
+                reject_reason: Some("zu frueh".into()),
+                ..existing_batch_entity()
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .expect("update should succeed");
+    assert_eq!(result.version, fresh_version());
+    assert_eq!(result.iso_week, 14);
+}
+
+/// (7) update mit veralteter Version → EntityConflicts; DAO-UPDATE wird nie
+/// erreicht (doppelte Entscheidung parallel).
+#[tokio::test]
+async fn update_stale_version_conflicts() {
+    let mut deps = build_dependencies(true, "hr");
+    deps.rebooking_batch_dao
+        .expect_find_by_id()
+        .returning(|_id, _tx| Ok(Some(existing_batch_entity())));
+
+    let service = deps.build_service();
+    let result = service
+        .update(
+            &RebookingBatchEntity {
+                version: fresh_version(),
+                ..existing_batch_entity()
+            },
+            Authentication::Full,
+            None,
+        )
+        .await;
+
+    match result {
+        Err(ServiceError::EntityConflicts(id, given, current)) => {
+            assert_eq!(id, default_batch_id());
+            assert_eq!(given, fresh_version());
+            assert_eq!(current, default_version());
+        }
+        other => panic!("Expected EntityConflicts, got {other:?}"),
+    }
+}
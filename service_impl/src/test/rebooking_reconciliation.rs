@@ -0,0 +1,992 @@
+//! Phase 55 (F3/F5) — Unit-Tests fuer RebookingReconciliationServiceImpl:
+//! Vorschlagsberechnung, HR-Gate, Wochensperre, extra_hours-Paar bei
+//! Genehmigung und Schutz gegen doppelte Entscheidung; dazu der Auto-Cron
+//! (F4) mit Stichtag, Backfill und `SkippedLocked`.
+
+use std::sync::{Arc, Mutex};
+
+use dao::{
+    rebooking_batch::{
+        RebookingBatchEntity, RebookingBatchEntryEntity, RebookingBatchKind, RebookingBatchState,
+    },
+    MockTransaction, MockTransactionDao,
+};
+use mockall::predicate::{always, eq};
+use service::{
+    audit_log::{entity_type, AuditAction, AuditChange, MockAuditLogService},
+    clock::MockClockService,
+    employee_work_details::{EmployeeWorkDetails, MockEmployeeWorkDetailsService},
+    extra_hours::{ExtraHours, ExtraHoursCategory, ExtraHoursSource, MockExtraHoursService},
+    permission::{Authentication, HR_PRIVILEGE},
+    rebooking_batch::MockRebookingBatchService,
+    rebooking_reconciliation::{
+        AutoRebookingReport, RebookingBatchState as BatchState, RebookingReconciliationService,
+    },
+    reporting::{EmployeeReport, MockReportingService},
+    sales_person::SalesPerson,
+    toggle::MockToggleService,
+    voluntary_stats::{MockVoluntaryStatsService, VoluntaryStats},
+    week_status::{MockWeekStatusService, WeekStatus},
+    MockPermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime};
+use uuid::{uuid, Uuid};
+
+use crate::rebooking_reconciliation::{
+    suggested_hours, RebookingReconciliationServiceDeps, RebookingReconciliationServiceImpl,
+    AUTO_ACTIVE_FROM_TOGGLE, BACKFILL_DONE_TOGGLE,
+};
+use crate::test::error_test::{test_forbidden, NoneTypeExt};
+
+fn batch_id() -> Uuid {
+    uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E01")
+}
+fn entry_id() -> Uuid {
+    uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E02")
+}
+fn sales_person_id() -> Uuid {
+    uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E03")
+}
+fn out_id() -> Uuid {
+    uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E04")
+}
+fn in_id() -> Uuid {
+    uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E05")
+}
+
+fn pending_batch() -> RebookingBatchEntity {
+    RebookingBatchEntity {
+        id: batch_id(),
+        sales_person_id: sales_person_id(),
+        iso_year: 2026,
+        iso_week: 41,
+        kind: RebookingBatchKind::HrSuggestion,
+        state: RebookingBatchState::Pending,
+        created: datetime!(2026-10-12 08:00:00),
+        approved: None,
+        approved_by: None,
+        rejected: None,
+        rejected_by: None,
+        reject_reason: None,
+        deleted: None,
+        version: uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E06"),
+    }
+}
+
+fn pending_entry() -> RebookingBatchEntryEntity {
+    RebookingBatchEntryEntity {
+        id: entry_id(),
+        batch_id: batch_id(),
+        sales_person_id: sales_person_id(),
+        hours: 4.0,
+        balance_before: -6.0,
+        voluntary_actual: 20.0,
+        voluntary_committed: 16.0,
+        extra_hours_out_id: None,
+        extra_hours_in_id: None,
+        created: datetime!(2026-10-12 08:00:00),
+        deleted: None,
+        version: uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E07"),
+    }
+}
+
+fn report(balance_hours: f32) -> EmployeeReport {
+    EmployeeReport {
+        sales_person: Arc::new(SalesPerson {
+            id: sales_person_id(),
+            name: "Anna".into(),
+            background_color: "#123456".into(),
+            is_paid: Some(true),
+            inactive: false,
+            personnel_number: None,
+            deleted: None,
+            version: Uuid::nil(),
+        }),
+        balance_hours,
+        overall_hours: 0.0,
+        expected_hours: 0.0,
+        dynamic_hours: 0.0,
+        shiftplan_hours: 0.0,
+        extra_work_hours: 0.0,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        vacation_carryover: 0,
+        vacation_days: 0.0,
+        vacation_entitlement: 0.0,
+        sick_leave_days: 0.0,
+        holiday_days: 0.0,
+        absence_days: 0.0,
+        carryover_hours: 0.0,
+        custom_extra_hours: Arc::new([]),
+        by_week: Arc::new([]),
+        by_month: Arc::new([]),
+    }
+}
+
+fn stats(ist_total: f32, soll_total: f32) -> VoluntaryStats {
+    VoluntaryStats {
+        ist_per_contract_week: None,
+        ist_total: Some(ist_total),
+        soll_total: Some(soll_total),
+        delta: Some(ist_total - soll_total),
+        contract_weeks: None,
+        ist_per_soll_pct: None,
+    }
+}
+
+pub struct RebookingReconciliationServiceDependencies {
+    pub rebooking_batch_service: MockRebookingBatchService,
+    pub voluntary_stats_service: MockVoluntaryStatsService,
+    pub reporting_service: MockReportingService,
+    pub extra_hours_service: MockExtraHoursService,
+    pub week_status_service: MockWeekStatusService,
+    pub toggle_service: MockToggleService,
+    pub employee_work_details_service: MockEmployeeWorkDetailsService,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub audit_log_service: MockAuditLogService,
+}
+
+impl RebookingReconciliationServiceDeps for RebookingReconciliationServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type RebookingBatchService = MockRebookingBatchService;
+    type VoluntaryStatsService = MockVoluntaryStatsService;
+    type ReportingService = MockReportingService;
+    type ExtraHoursService = MockExtraHoursService;
+    type WeekStatusService = MockWeekStatusService;
+    type ToggleService = MockToggleService;
+    type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl RebookingReconciliationServiceDependencies {
+    pub fn build_service(
+        self,
+    ) -> RebookingReconciliationServiceImpl<RebookingReconciliationServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        RebookingReconciliationServiceImpl {
+            rebooking_batch_service: self.rebooking_batch_service.into(),
+            voluntary_stats_service: self.voluntary_stats_service.into(),
+            reporting_service: self.reporting_service.into(),
+            extra_hours_service: self.extra_hours_service.into(),
+            week_status_service: self.week_status_service.into(),
+            toggle_service: self.toggle_service.into(),
+            employee_work_details_service: self.employee_work_details_service.into(),
+            permission_service: self.permission_service.into(),
+            clock_service: self.clock_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            transaction_dao: transaction_dao.into(),
+        }
+    }
+}
+
+/// HR-Benutzer "hr" mit unverriegelter Woche; mit `hr == false` fehlt das Recht.
+fn build_dependencies(hr: bool) -> RebookingReconciliationServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(move |_, _| {
+            if hr {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    permission_service
+        .expect_current_user_id()
+        .returning(|_| Ok(Some("hr".into())));
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| match context {
+            Authentication::Full => Ok(()),
+            Authentication::Context(_) => Err(ServiceError::Forbidden),
+        });
+    let mut week_status_service = MockWeekStatusService::new();
+    week_status_service
+        .expect_get_week_status()
+        .returning(|_, _, _, _| Ok(WeekStatus::Planned));
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_time_now()
+        .returning(|| datetime!(2026-10-17 10:00:00));
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    RebookingReconciliationServiceDependencies {
+        rebooking_batch_service: MockRebookingBatchService::new(),
+        voluntary_stats_service: MockVoluntaryStatsService::new(),
+        reporting_service: MockReportingService::new(),
+        extra_hours_service: MockExtraHoursService::new(),
+        week_status_service,
+        toggle_service: MockToggleService::new(),
+        employee_work_details_service: MockEmployeeWorkDetailsService::new(),
+        permission_service,
+        clock_service,
+        audit_log_service,
+    }
+}
+
+/// Genau ein Protokolleintrag `action` fuer den Batch, dessen Zustand nach
+/// der Aenderung `state` ist.
+fn expect_audit(
+    deps: &mut RebookingReconciliationServiceDependencies,
+    action: AuditAction,
+    state: &'static str,
+) {
+    deps.audit_log_service.checkpoint();
+    deps.audit_log_service
+        .expect_record()
+        .withf(move |change: &AuditChange, _, _, _| {
+            change.entity_type == entity_type::REBOOKING_BATCH
+                && change.entity_id.as_ref() == batch_id().to_string()
+                && change.action == action
+                && change
+                    .after
+                    .as_deref()
+                    .unwrap_or_default()
+                    .contains(&format!(r#""state":"{state}""#))
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+}
+
+fn lock_week(deps: &mut RebookingReconciliationServiceDependencies) {
+    deps.week_status_service.checkpoint();
+    deps.week_status_service
+        .expect_get_week_status()
+        .returning(|_, _, _, _| Ok(WeekStatus::Locked));
+}
+
+fn expect_load(deps: &mut RebookingReconciliationServiceDependencies, batch: RebookingBatchEntity) {
+    deps.rebooking_batch_service
+        .expect_find_by_id()
+        .with(eq(batch_id()), always(), always())
+        .returning(move |_, _, _| Ok(Some(batch.clone())));
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .returning(|_, _, _| Ok(Arc::new([pending_entry()])));
+}
+
+/// Keine genehmigten oder offenen Batches, die den Vorschlag mindern.
+fn expect_no_batches(deps: &mut RebookingReconciliationServiceDependencies) {
+    deps.rebooking_batch_service
+        .expect_find_by_state()
+        .returning(|_, _, _| Ok(Arc::new([])));
+}
+
+fn assert_state_not_allowed<T: std::fmt::Debug>(result: &Result<T, ServiceError>) {
+    match result {
+        Err(ServiceError::ValidationError(items)) => assert_eq!(
+            items.as_ref(),
+            &[ValidationFailureItem::ModificationNotAllowed(
+                "state".into()
+            )]
+        ),
+        other => panic!("expected ModificationNotAllowed(state), got {other:?}"),
+    }
+}
+
+#[test]
+fn test_suggested_hours() {
+    // Ueberschuss 4h, Defizit 6h → 4h.
+    assert_eq!(suggested_hours(4.0, -6.0), 4.0);
+    // Defizit kleiner als Ueberschuss → Defizit.
+    assert_eq!(suggested_hours(10.0, -2.5), 2.5);
+    // Kein Defizit oder kein Ueberschuss → nichts.
+    assert_eq!(suggested_hours(4.0, 3.0), 0.0);
+    assert_eq!(suggested_hours(-1.0, -6.0), 0.0);
+    assert_eq!(suggested_hours(10.0 / 3.0, -6.0), 3.33);
+}
+
+#[tokio::test]
+async fn test_suggest_creates_pending_batch() {
+    let mut deps = build_dependencies(true);
+    deps.voluntary_stats_service
+        .expect_get_voluntary_stats()
+        .returning(|_, from, to, _, _| {
+            assert_eq!(from.to_date(), date!(2025 - 12 - 29));
+            assert_eq!(to.to_date(), date!(2026 - 10 - 11));
+            Ok(stats(20.0, 16.0))
+        });
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .with(
+            eq(sales_person_id()),
+            always(),
+            always(),
+            eq(true),
+            always(),
+            always(),
+        )
+        .returning(|_, _, _, _, _, _| Ok(report(-6.0)));
+    expect_no_batches(&mut deps);
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|batch, entries, _, _| {
+            batch.kind == RebookingBatchKind::HrSuggestion
+                && batch.state == RebookingBatchState::Pending
+                && batch.iso_week == 41
+                && entries.len() == 1
+                && entries[0].hours == 4.0
+                && entries[0].balance_before == -6.0
+                && entries[0].voluntary_actual == 20.0
+                && entries[0].voluntary_committed == 16.0
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .returning(|_, _, _| Ok(Arc::new([pending_entry()])));
+    expect_audit(&mut deps, AuditAction::Create, "Pending");
+    let service = deps.build_service();
+
+    let batch = service
+        .suggest(sales_person_id(), 2026, 41, None, ().auth(), None)
+        .await
+        .unwrap();
+    assert_eq!(batch.state, BatchState::Pending);
+    assert_eq!(batch.hours(), 4.0);
+}
+
+#[tokio::test]
+async fn test_suggest_explicit_hours() {
+    let mut deps = build_dependencies(true);
+    deps.voluntary_stats_service
+        .expect_get_voluntary_stats()
+        .returning(|_, _, _, _, _| Ok(stats(20.0, 16.0)));
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .returning(|_, _, _, _, _, _| Ok(report(-6.0)));
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|_, entries, _, _| entries[0].hours == 1.5)
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .returning(|_, _, _| Ok(Arc::new([pending_entry()])));
+    let service = deps.build_service();
+
+    let result = service
+        .suggest(sales_person_id(), 2026, 41, Some(1.5), ().auth(), None)
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_suggest_nothing_to_rebook() {
+    let mut deps = build_dependencies(true);
+    deps.voluntary_stats_service
+        .expect_get_voluntary_stats()
+        .returning(|_, _, _, _, _| Ok(stats(20.0, 16.0)));
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .returning(|_, _, _, _, _, _| Ok(report(2.0)));
+    expect_no_batches(&mut deps);
+    deps.rebooking_batch_service.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .suggest(sales_person_id(), 2026, 41, None, ().auth(), None)
+        .await;
+    match result {
+        Err(ServiceError::ValidationError(items)) => assert_eq!(
+            items.as_ref(),
+            &[ValidationFailureItem::InvalidValue("hours".into())]
+        ),
+        other => panic!("expected InvalidValue(hours), got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_suggest_subtracts_rebooked_hours() {
+    // Ueberschuss 10h, Defizit 6h; KW 30 ist mit 3h genehmigt (schon im
+    // Stundenkonto), KW 35 mit 2h offen → min(10 - 5, 6 - 2) = 4h.
+    let mut deps = build_dependencies(true);
+    deps.voluntary_stats_service
+        .expect_get_voluntary_stats()
+        .returning(|_, _, _, _, _| Ok(stats(26.0, 16.0)));
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .returning(|_, _, _, _, _, _| Ok(report(-6.0)));
+    let approved_id = Uuid::new_v4();
+    let open_id = Uuid::new_v4();
+    let other_year_id = Uuid::new_v4();
+    deps.rebooking_batch_service
+        .expect_find_by_state()
+        .with(eq(RebookingBatchState::Approved), always(), always())
+        .returning(move |_, _, _| {
+            Ok(Arc::new([
+                RebookingBatchEntity {
+                    id: approved_id,
+                    iso_week: 30,
+                    state: RebookingBatchState::Approved,
+                    ..pending_batch()
+                },
+                RebookingBatchEntity {
+                    id: other_year_id,
+                    iso_year: 2025,
+                    state: RebookingBatchState::Approved,
+                    ..pending_batch()
+                },
+            ]))
+        });
+    deps.rebooking_batch_service
+        .expect_find_by_state()
+        .with(eq(RebookingBatchState::Pending), always(), always())
+        .returning(move |_, _, _| {
+            Ok(Arc::new([RebookingBatchEntity {
+                id: open_id,
+                iso_week: 35,
+                ..pending_batch()
+            }]))
+        });
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .returning(move |id, _, _| {
+            let hours = if id == approved_id { 3.0 } else { 2.0 };
+            Ok(Arc::new([RebookingBatchEntryEntity {
+                hours,
+                ..pending_entry()
+            }]))
+        });
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|_, entries, _, _| entries[0].hours == 4.0)
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    let service = deps.build_service();
+
+    let result = service
+        .suggest(sales_person_id(), 2026, 41, None, ().auth(), None)
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_suggest_locked_week() {
+    let mut deps = build_dependencies(true);
+    lock_week(&mut deps);
+    deps.rebooking_batch_service.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .suggest(sales_person_id(), 2026, 41, Some(2.0), ().auth(), None)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::WeekLocked {
+            year: 2026,
+            week: 41
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_suggest_forbidden() {
+    let service = build_dependencies(false).build_service();
+
+    let result = service
+        .suggest(sales_person_id(), 2026, 41, None, ().auth(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_find_pending() {
+    let mut deps = build_dependencies(true);
+    deps.rebooking_batch_service
+        .expect_find_by_state()
+        .with(eq(RebookingBatchState::Pending), always(), always())
+        .returning(|_, _, _| Ok(Arc::new([pending_batch()])));
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .with(eq(batch_id()), always(), always())
+        .returning(|_, _, _| Ok(Arc::new([pending_entry()])));
+    let service = deps.build_service();
+
+    let pending = service.find_pending(().auth(), None).await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].entries.len(), 1);
+}
+
+#[tokio::test]
+async fn test_approve_writes_rebooking_pair() {
+    let mut deps = build_dependencies(true);
+    expect_load(&mut deps, pending_batch());
+    deps.extra_hours_service
+        .expect_create()
+        .withf(|extra_hours: &ExtraHours, _, _| {
+            extra_hours.amount == -4.0
+                && extra_hours.category == ExtraHoursCategory::VolunteerWork
+                && extra_hours.source == ExtraHoursSource::Rebooking
+                && extra_hours.date_time == datetime!(2026-10-05 00:00:00)
+        })
+        .times(1)
+        .returning(|extra_hours, _, _| {
+            Ok(ExtraHours {
+                id: out_id(),
+                ..extra_hours.clone()
+            })
+        });
+    deps.extra_hours_service
+        .expect_create()
+        .withf(|extra_hours: &ExtraHours, _, _| {
+            extra_hours.amount == 4.0
+                && extra_hours.category == ExtraHoursCategory::ExtraWork
+                && extra_hours.source == ExtraHoursSource::Rebooking
+        })
+        .times(1)
+        .returning(|extra_hours, _, _| {
+            Ok(ExtraHours {
+                id: in_id(),
+                ..extra_hours.clone()
+            })
+        });
+    deps.rebooking_batch_service
+        .expect_update_entry()
+        .withf(|entry, _, _| {
+            entry.extra_hours_out_id == Some(out_id()) && entry.extra_hours_in_id == Some(in_id())
+        })
+        .times(1)
+        .returning(|entry, _, _| Ok(entry.clone()));
+    deps.rebooking_batch_service
+        .expect_update()
+        .withf(|batch, _, _| {
+            batch.state == RebookingBatchState::Approved
+                && batch.approved == Some(datetime!(2026-10-17 10:00:00))
+                && batch.approved_by.as_deref() == Some("hr")
+        })
+        .times(1)
+        .returning(|batch, _, _| Ok(batch.clone()));
+    expect_audit(&mut deps, AuditAction::Update, "Approved");
+    let service = deps.build_service();
+
+    let batch = service.approve(batch_id(), ().auth(), None).await.unwrap();
+    assert_eq!(batch.state, BatchState::Approved);
+    assert_eq!(batch.entries[0].extra_hours_out_id, Some(out_id()));
+    assert_eq!(batch.entries[0].extra_hours_in_id, Some(in_id()));
+}
+
+#[tokio::test]
+async fn test_approve_twice_is_rejected() {
+    let mut deps = build_dependencies(true);
+    expect_load(
+        &mut deps,
+        RebookingBatchEntity {
+            state: RebookingBatchState::Approved,
+            ..pending_batch()
+        },
+    );
+    deps.extra_hours_service.expect_create().never();
+    deps.rebooking_batch_service.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.approve(batch_id(), ().auth(), None).await;
+    assert_state_not_allowed(&result);
+}
+
+#[tokio::test]
+async fn test_approve_locked_week() {
+    let mut deps = build_dependencies(true);
+    lock_week(&mut deps);
+    expect_load(&mut deps, pending_batch());
+    deps.extra_hours_service.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service.approve(batch_id(), ().auth(), None).await;
+    assert!(matches!(result, Err(ServiceError::WeekLocked { .. })));
+}
+
+#[tokio::test]
+async fn test_approve_not_found() {
+    let mut deps = build_dependencies(true);
+    deps.rebooking_batch_service
+        .expect_find_by_id()
+        .returning(|_, _, _| Ok(None));
+    let service = deps.build_service();
+
+    let result = service.approve(batch_id(), ().auth(), None).await;
+    assert!(matches!(result, Err(ServiceError::EntityNotFound(id)) if id == batch_id()));
+}
+
+#[tokio::test]
+async fn test_reject_stores_reason() {
+    let mut deps = build_dependencies(true);
+    expect_load(&mut deps, pending_batch());
+    deps.extra_hours_service.expect_create().never();
+    deps.rebooking_batch_service
+        .expect_update()
+        .withf(|batch, _, _| {
+            batch.state == RebookingBatchState::Rejected
+                && batch.rejected_by.as_deref() == Some("hr")
+                && batch.reject_reason.as_deref() == Some("Stunden schon ausgezahlt")
+                && batch.approved.is_none()
+        })
+        .times(1)
+        .returning(|batch, _, _| Ok(batch.clone()));
+    expect_audit(&mut deps, AuditAction::Update, "Rejected");
+    let service = deps.build_service();
+
+    let batch = service
+        .reject(batch_id(), "  Stunden schon ausgezahlt ", ().auth(), None)
+        .await
+        .unwrap();
+    assert_eq!(batch.state, BatchState::Rejected);
+}
+
+#[tokio::test]
+async fn test_reject_after_rejection() {
+    let mut deps = build_dependencies(true);
+    expect_load(
+        &mut deps,
+        RebookingBatchEntity {
+            state: RebookingBatchState::Rejected,
+            ..pending_batch()
+        },
+    );
+    deps.rebooking_batch_service.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.reject(batch_id(), "doppelt", ().auth(), None).await;
+    assert_state_not_allowed(&result);
+}
+
+#[tokio::test]
+async fn test_reject_requires_reason() {
+    let service = build_dependencies(true).build_service();
+
+    let result = service.reject(batch_id(), "   ", ().auth(), None).await;
+    match result {
+        Err(ServiceError::ValidationError(items)) => assert_eq!(
+            items.as_ref(),
+            &[ValidationFailureItem::InvalidValue("reason".into())]
+        ),
+        other => panic!("expected InvalidValue(reason), got {other:?}"),
+    }
+}
+
+fn other_sales_person_id() -> Uuid {
+    uuid!("0E4B6D52-2C1A-4B7E-9F30-5A1D2C3B4E08")
+}
+
+fn work_details(
+    sales_person_id: Uuid,
+    capped: bool,
+    committed_voluntary: f32,
+) -> EmployeeWorkDetails {
+    EmployeeWorkDetails {
+        id: Uuid::new_v4(),
+        sales_person_id,
+        expected_hours: 20.0,
+        from_day_of_week: DayOfWeek::Monday,
+        from_calendar_week: 1,
+        from_year: 2026,
+        to_day_of_week: DayOfWeek::Sunday,
+        to_calendar_week: 52,
+        to_year: 2026,
+        workdays_per_week: 5,
+        is_dynamic: false,
+        cap_planned_hours_to_expected: capped,
+        committed_voluntary,
+        use_actual_hours: false,
+        monday: true,
+        tuesday: true,
+        wednesday: true,
+        thursday: true,
+        friday: true,
+        saturday: false,
+        sunday: false,
+        vacation_days: 30,
+        created: Some(datetime!(2026-01-01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+/// Stichtag `cutoff`, Backfill bereits gelaufen fuer `backfill_done_for`.
+/// Kandidat ist nur `sales_person_id()`; der zweite Vertrag ist ungedeckelt.
+fn build_auto_cron_dependencies(
+    cutoff: Option<&'static str>,
+    backfill_done_for: Option<&'static str>,
+) -> RebookingReconciliationServiceDependencies {
+    let mut deps = build_dependencies(true);
+    deps.toggle_service
+        .expect_get_toggle_value()
+        .with(eq(AUTO_ACTIVE_FROM_TOGGLE), always(), always())
+        .returning(move |_, _, _| Ok(cutoff.map(Arc::from)));
+    deps.toggle_service
+        .expect_get_toggle_value()
+        .with(eq(BACKFILL_DONE_TOGGLE), always(), always())
+        .returning(move |_, _, _| Ok(backfill_done_for.map(Arc::from)));
+    deps.employee_work_details_service
+        .expect_all_for_week()
+        .returning(|_, _, _, _| {
+            Ok(Arc::new([
+                work_details(sales_person_id(), true, 5.0),
+                work_details(other_sales_person_id(), false, 5.0),
+            ]))
+        });
+    deps.rebooking_batch_service
+        .expect_find_by_sales_person_year_week()
+        .returning(|_, _, _, _, _| Ok(None));
+    deps.voluntary_stats_service
+        .expect_get_voluntary_stats()
+        .returning(|_, _, _, _, _| Ok(stats(20.0, 16.0)));
+    deps.reporting_service
+        .expect_get_report_for_employee_range()
+        .returning(|_, _, _, _, _, _| Ok(report(-6.0)));
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .returning(|_, _, _| Ok(Arc::new([pending_entry()])));
+    expect_no_batches(&mut deps);
+    deps
+}
+
+#[tokio::test]
+async fn test_auto_cron_without_cutoff_does_nothing() {
+    let mut deps = build_dependencies(true);
+    deps.toggle_service
+        .expect_get_toggle_value()
+        .with(eq(AUTO_ACTIVE_FROM_TOGGLE), always(), always())
+        .returning(|_, _, _| Ok(None));
+    deps.employee_work_details_service
+        .expect_all_for_week()
+        .never();
+    deps.rebooking_batch_service.expect_create().never();
+    let service = deps.build_service();
+
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(report, AutoRebookingReport::default());
+}
+
+#[tokio::test]
+async fn test_auto_cron_creates_pending_batch_for_last_week() {
+    let mut deps = build_auto_cron_dependencies(Some("2026-01-05"), Some("2026-01-05"));
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|batch, entries, _, _| {
+            batch.kind == RebookingBatchKind::AutoCron
+                && batch.state == RebookingBatchState::Pending
+                && batch.sales_person_id == sales_person_id()
+                && (batch.iso_year, batch.iso_week) == (2026, 42)
+                && entries[0].hours == 4.0
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    deps.toggle_service.expect_set_toggle_value().never();
+    let service = deps.build_service();
+
+    // Montag KW 43 → ausgewertet wird KW 42.
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        report,
+        AutoRebookingReport {
+            pending: 1,
+            skipped_locked: 0,
+            backfill_weeks: 0,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_auto_cron_skips_claimed_week() {
+    let mut deps = build_auto_cron_dependencies(Some("2026-01-05"), Some("2026-01-05"));
+    deps.rebooking_batch_service.checkpoint();
+    deps.rebooking_batch_service
+        .expect_find_by_sales_person_year_week()
+        .returning(|_, _, _, _, _| Ok(Some(pending_batch())));
+    deps.rebooking_batch_service.expect_create().never();
+    let service = deps.build_service();
+
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(report, AutoRebookingReport::default());
+}
+
+#[tokio::test]
+async fn test_auto_cron_locked_week_is_skipped_locked() {
+    let mut deps = build_auto_cron_dependencies(Some("2026-01-05"), Some("2026-01-05"));
+    lock_week(&mut deps);
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|batch, _, _, _| {
+            batch.kind == RebookingBatchKind::AutoCron
+                && batch.state == RebookingBatchState::SkippedLocked
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    let service = deps.build_service();
+
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(report.skipped_locked, 1);
+    assert_eq!(report.pending, 0);
+}
+
+#[tokio::test]
+async fn test_auto_cron_backfills_once_per_cutoff() {
+    // Stichtag Mittwoch KW 40 → erste volle Woche KW 41, Backfill nur KW 41.
+    let mut deps = build_auto_cron_dependencies(Some("2026-09-30"), None);
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|batch, _, _, _| {
+            batch.kind == RebookingBatchKind::AutoCronBackfill && batch.iso_week == 41
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    deps.rebooking_batch_service
+        .expect_create()
+        .withf(|batch, _, _, _| batch.kind == RebookingBatchKind::AutoCron && batch.iso_week == 42)
+        .times(1)
+        .returning(|_, _, _, _| Ok(pending_batch()));
+    deps.toggle_service
+        .expect_set_toggle_value()
+        .with(
+            eq(BACKFILL_DONE_TOGGLE),
+            eq(Some("2026-09-30".to_string())),
+            always(),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        report,
+        AutoRebookingReport {
+            pending: 2,
+            skipped_locked: 0,
+            backfill_weeks: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_auto_cron_backfill_does_not_rebook_twice() {
+    // Stichtag Montag KW 40 → Backfill KW 40 + 41, regulaer KW 42. Ueberschuss
+    // 4h und Defizit 6h gelten fuer alle drei Wochen; insgesamt darf der Lauf
+    // nur 4h vorschlagen.
+    let mut deps = build_auto_cron_dependencies(Some("2026-09-28"), None);
+    deps.rebooking_batch_service.checkpoint();
+    let created: Arc<Mutex<Vec<(RebookingBatchEntity, RebookingBatchEntryEntity)>>> =
+        Arc::new(Mutex::new(Vec::new()));
+    deps.rebooking_batch_service
+        .expect_find_by_sales_person_year_week()
+        .returning(|_, _, _, _, _| Ok(None));
+    let store = created.clone();
+    deps.rebooking_batch_service
+        .expect_create()
+        .returning(move |batch, entries, _, _| {
+            let batch = RebookingBatchEntity {
+                id: Uuid::new_v4(),
+                ..batch.clone()
+            };
+            let entry = RebookingBatchEntryEntity {
+                batch_id: batch.id,
+                ..entries[0].clone()
+            };
+            store.lock().unwrap().push((batch.clone(), entry));
+            Ok(batch)
+        });
+    let store = created.clone();
+    deps.rebooking_batch_service
+        .expect_find_by_state()
+        .returning(move |state, _, _| {
+            Ok(store
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(batch, _)| batch.state == state)
+                .map(|(batch, _)| batch.clone())
+                .collect())
+        });
+    let store = created.clone();
+    deps.rebooking_batch_service
+        .expect_list_entries()
+        .returning(move |batch_id, _, _| {
+            Ok(store
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(_, entry)| entry.batch_id == batch_id)
+                .map(|(_, entry)| entry.clone())
+                .collect())
+        });
+    deps.toggle_service
+        .expect_set_toggle_value()
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    let created = created.lock().unwrap();
+    let proposed: f32 = created.iter().map(|(_, entry)| entry.hours).sum();
+    assert_eq!(proposed, 4.0);
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].0.iso_week, 40);
+    assert_eq!(
+        report,
+        AutoRebookingReport {
+            pending: 1,
+            skipped_locked: 0,
+            backfill_weeks: 2,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_auto_cron_cutoff_after_last_week() {
+    let mut deps = build_auto_cron_dependencies(Some("2026-10-19"), None);
+    deps.rebooking_batch_service.expect_create().never();
+    deps.toggle_service.expect_set_toggle_value().never();
+    let service = deps.build_service();
+
+    let report = service
+        .run_auto_cron(date!(2026 - 10 - 19), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(report, AutoRebookingReport::default());
+}
+
+#[tokio::test]
+async fn test_auto_cron_requires_full_authentication() {
+    let service = build_dependencies(true).build_service();
+
+    let result = service
+        .run_auto_cron(date!(2026 - 10 - 19), ().auth(), None)
+        .await;
+    test_forbidden(&result);
+}
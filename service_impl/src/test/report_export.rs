@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use mockall::predicate::{always, eq};
+use service::billing_period::{
+    BillingPeriod, BillingPeriodSalesPerson, BillingPeriodValue, BillingPeriodValueType,
+    MockBillingPeriodService,
+};
+use service::permission::{Authentication, HR_PRIVILEGE};
+use service::report_export::{ExportFormat, ExportLanguage, ReportBreakdown, ReportExportService};
+use service::reporting::{
+    CustomExtraHours, EmployeeReport, GroupedReportHours, MockReportingService,
+    ShortEmployeeReport,
+};
+use service::sales_person::{MockSalesPersonService, SalesPerson};
+use service::{MockPermissionService, ServiceError};
+use shifty_utils::ShiftyDate;
+use time::macros::datetime;
+use uuid::{uuid, Uuid};
+
+use crate::report_export::{ReportExportServiceDeps, ReportExportServiceImpl};
+
+const ANNA_ID: Uuid = uuid!("1c0a7c2e-4a7e-4d0c-9a51-000000000001");
+const BOB_ID: Uuid = uuid!("1c0a7c2e-4a7e-4d0c-9a51-000000000002");
+
+struct ReportExportDependencies {
+    reporting_service: MockReportingService,
+    billing_period_service: MockBillingPeriodService,
+    sales_person_service: MockSalesPersonService,
+    permission_service: MockPermissionService,
+    transaction_dao: dao::MockTransactionDao,
+}
+
+impl ReportExportServiceDeps for ReportExportDependencies {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type ReportingService = MockReportingService;
+    type BillingPeriodService = MockBillingPeriodService;
+    type SalesPersonService = MockSalesPersonService;
+    type PermissionService = MockPermissionService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+impl ReportExportDependencies {
+    fn new() -> Self {
+        let mut transaction_dao = dao::MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(dao::MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+        Self {
+            reporting_service: MockReportingService::new(),
+            billing_period_service: MockBillingPeriodService::new(),
+            sales_person_service: MockSalesPersonService::new(),
+            permission_service: MockPermissionService::new(),
+            transaction_dao,
+        }
+    }
+
+    fn build_service(self) -> ReportExportServiceImpl<ReportExportDependencies> {
+        ReportExportServiceImpl {
+            reporting_service: self.reporting_service.into(),
+            billing_period_service: self.billing_period_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            permission_service: self.permission_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+fn sales_person(id: Uuid, name: &str) -> SalesPerson {
+    SalesPerson {
+        id,
+        name: name.into(),
+        background_color: "#ffffff".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn short_report(
+    sales_person: SalesPerson,
+    balance_hours: f32,
+    custom_absence_hours: Arc<[CustomExtraHours]>,
+) -> ShortEmployeeReport {
+    ShortEmployeeReport {
+        sales_person: sales_person.into(),
+        balance_hours,
+        dynamic_hours: 0.0,
+        expected_hours: 20.0,
+        overall_hours: 20.0 + balance_hours,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unavailable_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        custom_absence_hours,
+    }
+}
+
+fn grouped_hours(from: ShiftyDate, to: ShiftyDate, week: u8) -> GroupedReportHours {
+    GroupedReportHours {
+        from,
+        to,
+        year: 2026,
+        week,
+        contract_weekly_hours: 20.0,
+        expected_hours: 20.0,
+        dynamic_hours: 0.0,
+        overall_hours: 18.5,
+        balance: -1.5,
+        days_per_week: 5,
+        workdays_per_week: 5.0,
+        shiftplan_hours: 18.5,
+        extra_work_hours: 0.0,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        custom_extra_hours: Arc::new([]),
+        days: Arc::new([]),
+    }
+}
+
+fn employee_report() -> EmployeeReport {
+    let week = grouped_hours(
+        ShiftyDate::from_ymd(2026, 3, 2).unwrap(),
+        ShiftyDate::from_ymd(2026, 3, 8).unwrap(),
+        10,
+    );
+    let month = grouped_hours(
+        ShiftyDate::from_ymd(2026, 3, 1).unwrap(),
+        ShiftyDate::from_ymd(2026, 3, 31).unwrap(),
+        10,
+    );
+    EmployeeReport {
+        sales_person: sales_person(ANNA_ID, "Anna Müller").into(),
+        balance_hours: -1.5,
+        overall_hours: 18.5,
+        expected_hours: 20.0,
+        dynamic_hours: 0.0,
+        shiftplan_hours: 18.5,
+        extra_work_hours: 0.0,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        vacation_carryover: 0,
+        vacation_days: 0.0,
+        vacation_entitlement: 0.0,
+        sick_leave_days: 0.0,
+        holiday_days: 0.0,
+        absence_days: 0.0,
+        carryover_hours: 0.0,
+        custom_extra_hours: Arc::new([]),
+        by_week: Arc::new([week]),
+        by_month: Arc::new([month]),
+    }
+}
+
+fn billing_period(id: Uuid) -> BillingPeriod {
+    let value = |value_delta: f32| BillingPeriodValue {
+        value_delta,
+        value_ytd_from: 10.0,
+        value_ytd_to: 10.0 + value_delta,
+        value_full_year: 40.0,
+    };
+    let entry = |sales_person_id: Uuid, values: BTreeMap<BillingPeriodValueType, BillingPeriodValue>| {
+        BillingPeriodSalesPerson {
+            id: Uuid::new_v4(),
+            sales_person_id,
+            values,
+            created_at: datetime!(2026-04-01 10:00:00),
+            created_by: "hr".into(),
+            deleted_at: None,
+            deleted_by: None,
+        }
+    };
+    BillingPeriod {
+        id,
+        start_date: ShiftyDate::from_ymd(2026, 3, 1).unwrap(),
+        end_date: ShiftyDate::from_ymd(2026, 3, 31).unwrap(),
+        snapshot_schema_version: 1,
+        sales_persons: Arc::new([
+            entry(
+                BOB_ID,
+                BTreeMap::from([(BillingPeriodValueType::Balance, value(2.0))]),
+            ),
+            entry(
+                ANNA_ID,
+                BTreeMap::from([
+                    (BillingPeriodValueType::Balance, value(-1.5)),
+                    (BillingPeriodValueType::VacationDays, value(3.0)),
+                ]),
+            ),
+        ]),
+        created_at: datetime!(2026-04-01 10:00:00),
+        created_by: "hr".into(),
+        deleted_at: None,
+        deleted_by: None,
+    }
+}
+
+fn csv_text(content: &[u8]) -> String {
+    String::from_utf8(content.to_vec())
+        .unwrap()
+        .trim_start_matches('\u{feff}')
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_export_employee_reports_csv_german() {
+    let mut deps = ReportExportDependencies::new();
+    let overtime = CustomExtraHours {
+        id: Uuid::new_v4(),
+        name: "Fortbildung".into(),
+        hours: 4.0,
+    };
+    deps.reporting_service
+        .expect_get_reports_for_all_employees()
+        .with(eq(2026), eq(12), eq(Authentication::Full), always())
+        .times(1)
+        .returning(move |_, _, _, _| {
+            Ok(Arc::new([
+                short_report(sales_person(BOB_ID, "Bob"), 2.0, Arc::new([])),
+                short_report(
+                    sales_person(ANNA_ID, "Anna Müller"),
+                    -1.5,
+                    Arc::new([overtime.clone()]),
+                ),
+            ]))
+        });
+    let service = deps.build_service();
+
+    let file = service
+        .export_employee_reports(
+            2026,
+            12,
+            ExportFormat::Csv,
+            ExportLanguage::De,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(file.file_name.as_ref(), "employee-reports-2026-w12.csv");
+    assert_eq!(file.content_type, "text/csv; charset=utf-8");
+    let text = csv_text(&file.content);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines[0],
+        "Name;Stundenkonto;Sollstunden;Gesamt;Dynamische Stunden;Urlaub;Krank;Feiertage;\
+         Nicht verfügbar;Unbezahlter Urlaub;Ehrenamt;Fortbildung"
+    );
+    assert_eq!(
+        lines[1],
+        "Anna Müller;-1,50;20,00;18,50;0,00;0,00;0,00;0,00;0,00;0,00;0,00;4,00"
+    );
+    assert_eq!(
+        lines[2],
+        "Bob;2,00;20,00;22,00;0,00;0,00;0,00;0,00;0,00;0,00;0,00;0,00"
+    );
+    assert_eq!(lines.len(), 3);
+}
+
+#[tokio::test]
+async fn test_export_employee_report_by_week_english() {
+    let mut deps = ReportExportDependencies::new();
+    deps.reporting_service
+        .expect_get_report_for_employee()
+        .with(eq(ANNA_ID), eq(2026), eq(12), always(), always())
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(employee_report()));
+    let service = deps.build_service();
+
+    let file = service
+        .export_employee_report(
+            ANNA_ID,
+            2026,
+            12,
+            ReportBreakdown::ByWeek,
+            ExportFormat::Csv,
+            ExportLanguage::En,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        file.file_name.as_ref(),
+        "employee-report-anna-mueller-2026-by-week.csv"
+    );
+    let text = csv_text(&file.content);
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines[0].starts_with("Week,From,To,Contract hours,Expected Hours,Actual,Balance"));
+    assert!(lines[1].starts_with("2026-10,2026-03-02,2026-03-08,20.00,20.00,18.50,-1.50"));
+}
+
+#[tokio::test]
+async fn test_export_employee_report_by_month_xlsx() {
+    let mut deps = ReportExportDependencies::new();
+    deps.reporting_service
+        .expect_get_report_for_employee()
+        .returning(|_, _, _, _, _| Ok(employee_report()));
+    let service = deps.build_service();
+
+    let file = service
+        .export_employee_report(
+            ANNA_ID,
+            2026,
+            12,
+            ReportBreakdown::ByMonth,
+            ExportFormat::Xlsx,
+            ExportLanguage::Cs,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        file.file_name.as_ref(),
+        "employee-report-anna-mueller-2026-by-month.xlsx"
+    );
+    assert_eq!(
+        file.content_type,
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    );
+    assert_eq!(&file.content[..2], b"PK");
+}
+
+#[tokio::test]
+async fn test_export_employee_reports_propagates_forbidden() {
+    let mut deps = ReportExportDependencies::new();
+    deps.reporting_service
+        .expect_get_reports_for_all_employees()
+        .returning(|_, _, _, _| Err(ServiceError::Forbidden));
+    let service = deps.build_service();
+
+    let result = service
+        .export_employee_reports(
+            2026,
+            12,
+            ExportFormat::Csv,
+            ExportLanguage::De,
+            Authentication::Context(()),
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_export_billing_period_csv_english() {
+    let billing_period_id = Uuid::new_v4();
+    let mut deps = ReportExportDependencies::new();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .times(1)
+        .returning(|_, _| Ok(()));
+    deps.billing_period_service
+        .expect_get_billing_period_by_id()
+        .with(eq(billing_period_id), always(), always())
+        .times(1)
+        .returning(|id, _, _| Ok(billing_period(id)));
+    deps.sales_person_service
+        .expect_get_all()
+        .times(1)
+        .returning(|_, _| {
+            Ok(Arc::new([
+                sales_person(ANNA_ID, "Anna Müller"),
+                sales_person(BOB_ID, "Bob"),
+            ]))
+        });
+    let service = deps.build_service();
+
+    let file = service
+        .export_billing_period(
+            billing_period_id,
+            ExportFormat::Csv,
+            ExportLanguage::En,
+            Authentication::Context(()),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        file.file_name.as_ref(),
+        "billing-period-2026-03-01-2026-03-31.csv"
+    );
+    let text = csv_text(&file.content);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines[0],
+        "Name,Balance (Delta),Balance (YTD From),Balance (YTD To),Balance (Full Year),\
+         Vacation days (Delta),Vacation days (YTD From),Vacation days (YTD To),\
+         Vacation days (Full Year)"
+    );
+    assert_eq!(
+        lines[1],
+        "Anna Müller,-1.50,10.00,8.50,40.00,3.00,10.00,13.00,40.00"
+    );
+    assert_eq!(lines[2], "Bob,2.00,10.00,12.00,40.00,,,,");
+}
+
+#[tokio::test]
+async fn test_export_billing_period_requires_hr() {
+    let mut deps = ReportExportDependencies::new();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    deps.billing_period_service
+        .expect_get_billing_period_by_id()
+        .times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .export_billing_period(
+            Uuid::new_v4(),
+            ExportFormat::Csv,
+            ExportLanguage::De,
+            Authentication::Context(()),
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::Forbidden)));
+}
@@ -33,6 +33,7 @@ use service::reporting::ReportingService;
 use service::sales_person::MockSalesPersonService;
 use service::shiftplan_report::MockShiftplanReportService;
 use service::special_days::MockSpecialDayService;
+use service::time_entry::MockTimeEntryService;
 use service::toggle::MockToggleService;
 use service::uuid_service::MockUuidService;
 use service::MockPermissionService;
@@ -60,6 +61,7 @@ fn unpaid_volunteer_sales_person() -> service::sales_person::SalesPerson {
         background_color: Arc::from("#FF0000"),
         is_paid: Some(false),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -82,6 +84,7 @@ fn unpaid_volunteer_work_details() -> service::employee_work_details::EmployeeWo
         is_dynamic: false,
         cap_planned_hours_to_expected: false,
         committed_voluntary: 5.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -129,6 +132,7 @@ impl ReportingServiceDeps for TestDeps {
     // Phase 25: holiday derive-on-read deps.
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
+    type TimeEntryService = MockTimeEntryService;
 }
 
 impl ReportingMocks {
@@ -168,6 +172,7 @@ impl ReportingMocks {
             transaction_dao: Arc::new(self.transaction_dao),
             special_day_service: Arc::new(self.special_day_service),
             toggle_service: Arc::new(self.toggle_service),
+            time_entry_service: Arc::new(MockTimeEntryService::new()),
         }
     }
 }
@@ -578,6 +583,7 @@ async fn test_get_week_additive_merge() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: service::absence::DayFraction::Full,
+        status: service::absence::AbsenceStatus::Approved,
     };
     let periods: Arc<[service::absence::AbsencePeriod]> = Arc::from(vec![sick_period]);
     mocks
@@ -1193,6 +1199,7 @@ async fn test_balance_parity_dynamic_get_week() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: service::absence::DayFraction::Full,
+        status: service::absence::AbsenceStatus::Approved,
     };
     let periods_b: Arc<[service::absence::AbsencePeriod]> = Arc::from(vec![vac_period_b]);
     mocks_b
@@ -25,6 +25,7 @@ use service::reporting::ReportingService;
 use service::sales_person::MockSalesPersonService;
 use service::shiftplan_report::{MockShiftplanReportService, ShiftplanReportDay};
 use service::special_days::MockSpecialDayService;
+use service::time_entry::MockTimeEntryService;
 use service::toggle::MockToggleService;
 use service::uuid_service::MockUuidService;
 use service::permission::Authentication;
@@ -71,6 +72,7 @@ impl ReportingServiceDeps for TestDeps {
     type TransactionDao = dao::MockTransactionDao;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
+    type TimeEntryService = MockTimeEntryService;
 }
 
 impl ReportingMocks {
@@ -105,6 +107,7 @@ impl ReportingMocks {
             transaction_dao: Arc::new(self.transaction_dao),
             special_day_service: Arc::new(self.special_day_service),
             toggle_service: Arc::new(self.toggle_service),
+            time_entry_service: Arc::new(MockTimeEntryService::new()),
         }
     }
 }
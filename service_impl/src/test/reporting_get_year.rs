@@ -30,6 +30,7 @@ use service::reporting::{ReportingService, ShortEmployeeReport};
 use service::sales_person::MockSalesPersonService;
 use service::shiftplan_report::MockShiftplanReportService;
 use service::special_days::MockSpecialDayService;
+use service::time_entry::MockTimeEntryService;
 use service::toggle::MockToggleService;
 use service::uuid_service::MockUuidService;
 use service::MockPermissionService;
@@ -55,6 +56,7 @@ impl ReportingServiceDeps for TestDeps {
     type TransactionDao = dao::MockTransactionDao;
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
+    type TimeEntryService = MockTimeEntryService;
 }
 
 /// Baut ein minimales Mock-Setup fuer `get_year` / `get_week`.
@@ -143,6 +145,7 @@ fn build_service() -> ReportingServiceImpl<TestDeps> {
         transaction_dao: Arc::new(transaction_dao),
         special_day_service: Arc::new(MockSpecialDayService::new()),
         toggle_service: Arc::new(toggle_service),
+        time_entry_service: Arc::new(MockTimeEntryService::new()),
     }
 }
 
@@ -261,6 +264,7 @@ async fn test_get_year_empty_when_no_work_details() {
         transaction_dao: Arc::new(transaction_dao),
         special_day_service: Arc::new(MockSpecialDayService::new()),
         toggle_service: Arc::new(toggle_service),
+        time_entry_service: Arc::new(MockTimeEntryService::new()),
     };
 
     let result = service
@@ -30,6 +30,7 @@ use service::reporting::ReportingService;
 use service::sales_person::MockSalesPersonService;
 use service::shiftplan_report::{MockShiftplanReportService, ShiftplanReportDay};
 use service::special_days::{MockSpecialDayService, SpecialDay, SpecialDayType};
+use service::time_entry::MockTimeEntryService;
 use service::toggle::MockToggleService;
 use service::uuid_service::MockUuidService;
 use service::MockPermissionService;
@@ -75,6 +76,7 @@ impl ReportingServiceDeps for TestDeps {
     // Phase 25: holiday derive-on-read deps.
     type SpecialDayService = MockSpecialDayService;
     type ToggleService = MockToggleService;
+    type TimeEntryService = MockTimeEntryService;
 }
 
 impl ReportingMocks {
@@ -114,6 +116,7 @@ impl ReportingMocks {
             transaction_dao: Arc::new(self.transaction_dao),
             special_day_service: Arc::new(self.special_day_service),
             toggle_service: Arc::new(self.toggle_service),
+            time_entry_service: Arc::new(MockTimeEntryService::new()),
         }
     }
 }
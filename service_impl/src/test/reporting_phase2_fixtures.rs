@@ -16,7 +16,7 @@ use shifty_utils::DayOfWeek;
 use time::macros::{date, datetime};
 use uuid::Uuid;
 
-use service::absence::{AbsenceCategory, AbsencePeriod, DayFraction};
+use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction};
 use service::employee_work_details::EmployeeWorkDetails;
 use service::extra_hours::{ExtraHours, ExtraHoursCategory};
 use service::sales_person::SalesPerson;
@@ -36,6 +36,7 @@ pub fn fixture_sales_person() -> SalesPerson {
         background_color: Arc::from("#000000"),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::nil(),
     }
@@ -58,6 +59,7 @@ pub fn fixture_work_details_8h_mon_fri() -> EmployeeWorkDetails {
         is_dynamic: false,
         cap_planned_hours_to_expected: false,
         committed_voluntary: 0.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -98,6 +100,7 @@ pub fn fixture_vacation_period() -> AbsencePeriod {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -115,6 +118,7 @@ pub fn fixture_sick_period() -> AbsencePeriod {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -5,6 +5,7 @@ use dao::{
 };
 use mockall::predicate::{always, eq};
 use service::{
+    audit_log::MockAuditLogService,
     clock::MockClockService,
     permission::Authentication,
     sales_person::{SalesPerson, SalesPersonService},
@@ -22,6 +23,7 @@ pub struct SalesPersonServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 impl SalesPersonServiceDeps for SalesPersonServiceDependencies {
@@ -31,6 +33,7 @@ impl SalesPersonServiceDeps for SalesPersonServiceDependencies {
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -41,6 +44,7 @@ impl SalesPersonServiceDependencies {
             permission_service: self.permission_service.into(),
             clock_service: self.clock_service.into(),
             uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -85,11 +89,17 @@ pub fn build_dependencies(permission: bool, role: &'static str) -> SalesPersonSe
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     SalesPersonServiceDependencies {
         sales_person_dao,
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
     }
 }
@@ -116,6 +126,7 @@ pub fn default_sales_person_entity() -> dao::sales_person::SalesPersonEntity {
         is_paid: false,
         deleted: None,
         inactive: false,
+        personnel_number: None,
         version: default_version(),
     }
 }
@@ -127,6 +138,7 @@ pub fn default_sales_person() -> service::sales_person::SalesPerson {
         background_color: "#FFF".into(),
         is_paid: Some(false),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: default_version(),
     }
@@ -834,3 +846,96 @@ async fn test_exists() {
         .unwrap();
     assert!(result);
 }
+
+#[tokio::test]
+async fn test_get_all_hides_personnel_number_for_non_hr() {
+    let mut dependencies = build_dependencies(true, "shiftplanner");
+    dependencies.sales_person_dao.expect_all().returning(|_| {
+        Ok([SalesPersonEntity {
+            personnel_number: Some("1001".into()),
+            ..default_sales_person_entity()
+        }]
+        .into())
+    });
+    let sales_person_service = dependencies.build_service();
+    let result = sales_person_service.get_all(().auth(), None).await.unwrap();
+    assert_eq!(result[0].personnel_number, None);
+}
+
+#[tokio::test]
+async fn test_create_duplicate_personnel_number() {
+    let mut dependencies = build_dependencies(true, "hr");
+    dependencies.sales_person_dao.expect_all().returning(|_| {
+        Ok([SalesPersonEntity {
+            id: alternate_id(),
+            personnel_number: Some("1001".into()),
+            ..default_sales_person_entity()
+        }]
+        .into())
+    });
+    dependencies.sales_person_dao.expect_create().times(0);
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| default_id());
+    let sales_person_service = dependencies.build_service();
+    let result = sales_person_service
+        .create(
+            &SalesPerson {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                personnel_number: Some(" 1001 ".into()),
+                ..default_sales_person()
+            },
+            ().auth(),
+            None,
+        )
+        .await;
+    test_validation_error(&result, &service::ValidationFailureItem::Duplicate, 1);
+}
+
+#[tokio::test]
+async fn test_update_personnel_number_is_trimmed() {
+    let mut dependencies = build_dependencies(true, "hr");
+    dependencies
+        .sales_person_dao
+        .expect_find_by_id()
+        .with(eq(default_id()), always())
+        .returning(|_, _| Ok(Some(default_sales_person_entity())));
+    dependencies
+        .sales_person_dao
+        .expect_all()
+        .returning(|_| Ok([default_sales_person_entity()].into()));
+    dependencies
+        .sales_person_dao
+        .expect_update()
+        .with(
+            eq(SalesPersonEntity {
+                personnel_number: Some("1001".into()),
+                version: alternate_version(),
+                ..default_sales_person_entity()
+            }),
+            eq("sales-person-service"),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .with(eq("sales-person-version"))
+        .returning(|_| alternate_version());
+    let sales_person_service = dependencies.build_service();
+    let result = sales_person_service
+        .update(
+            &SalesPerson {
+                personnel_number: Some(" 1001 ".into()),
+                ..default_sales_person()
+            },
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.personnel_number, Some("1001".into()));
+}
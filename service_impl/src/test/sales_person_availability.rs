@@ -0,0 +1,508 @@
+//! Mock-basierte Tests für `SalesPersonAvailabilityServiceImpl`.
+//!
+//! Abgedeckt:
+//!  - Anlegen: Validierung von Zeitfenster und Gültigkeitszeitraum,
+//!    Shiftplanner ∨ self.
+//!  - Wochenabfrage filtert nach Gültigkeitszeitraum.
+//!  - Ändern: Versionskonflikt, Personenwechsel nicht erlaubt.
+//!  - Löschen als Soft-Delete.
+
+use std::sync::Arc;
+
+use dao::{
+    sales_person_availability::{
+        AvailabilityKind as AvailabilityKindEntity, MockSalesPersonAvailabilityDao,
+        SalesPersonAvailabilityEntity,
+    },
+    MockTransaction, MockTransactionDao,
+};
+use mockall::predicate::{always, eq};
+use service::{
+    audit_log::MockAuditLogService,
+    clock::MockClockService,
+    permission::SHIFTPLANNER_PRIVILEGE,
+    sales_person::MockSalesPersonService,
+    sales_person_availability::{
+        AvailabilityKind, SalesPersonAvailability, SalesPersonAvailabilityService,
+    },
+    uuid_service::MockUuidService,
+    MockPermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime};
+use time::Time;
+use uuid::{uuid, Uuid};
+
+use crate::sales_person_availability::{
+    SalesPersonAvailabilityServiceDeps, SalesPersonAvailabilityServiceImpl,
+};
+use crate::test::error_test::{
+    test_conflicts, test_date_order_wrong, test_forbidden, test_not_found, test_time_order_wrong,
+    test_validation_error, test_zero_id_error,
+};
+
+// ---------- Fixtures ----------
+
+fn availability_id() -> Uuid {
+    uuid!("5C000000-0000-0000-0000-000000000001")
+}
+
+fn anna_id() -> Uuid {
+    uuid!("5C000000-0000-0000-0000-0000000000A1")
+}
+
+fn bert_id() -> Uuid {
+    uuid!("5C000000-0000-0000-0000-0000000000A2")
+}
+
+fn persisted_version() -> Uuid {
+    uuid!("5C000000-0000-0000-0000-0000000000F1")
+}
+
+fn new_version() -> Uuid {
+    uuid!("5C000000-0000-0000-0000-0000000000FF")
+}
+
+fn availability_entity() -> SalesPersonAvailabilityEntity {
+    SalesPersonAvailabilityEntity {
+        id: availability_id(),
+        sales_person_id: anna_id(),
+        day_of_week: DayOfWeek::Tuesday,
+        time_from: None,
+        time_to: None,
+        valid_from: date!(2026 - 01 - 01),
+        valid_to: None,
+        kind: AvailabilityKindEntity::Unavailable,
+        created: datetime!(2026 - 01 - 01 12:00:00),
+        deleted: None,
+        version: persisted_version(),
+    }
+}
+
+fn availability() -> SalesPersonAvailability {
+    (&availability_entity()).into()
+}
+
+fn new_availability() -> SalesPersonAvailability {
+    SalesPersonAvailability {
+        id: Uuid::nil(),
+        created: None,
+        version: Uuid::nil(),
+        ..availability()
+    }
+}
+
+pub struct SalesPersonAvailabilityDependencies {
+    pub sales_person_availability_dao: MockSalesPersonAvailabilityDao,
+    pub sales_person_service: MockSalesPersonService,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl SalesPersonAvailabilityServiceDeps for SalesPersonAvailabilityDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type SalesPersonAvailabilityDao = MockSalesPersonAvailabilityDao;
+    type SalesPersonService = MockSalesPersonService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl SalesPersonAvailabilityDependencies {
+    fn build_service(
+        self,
+    ) -> SalesPersonAvailabilityServiceImpl<SalesPersonAvailabilityDependencies> {
+        SalesPersonAvailabilityServiceImpl {
+            sales_person_availability_dao: self.sales_person_availability_dao.into(),
+            sales_person_service: self.sales_person_service.into(),
+            permission_service: self.permission_service.into(),
+            clock_service: self.clock_service.into(),
+            uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// Basis-Setup: der Aufrufer hat genau `privileges` und ist — falls gesetzt —
+/// die Person `self_id`. Die DAO kennt eine ganztägige Dienstags-Regel von
+/// Anna.
+fn build_dependencies(
+    privileges: &'static [&'static str],
+    self_id: Option<Uuid>,
+) -> SalesPersonAvailabilityDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(move |privilege, _| {
+            if privileges.contains(&privilege) {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(move |sales_person_id, _, _| {
+            if Some(sales_person_id) == self_id {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut sales_person_availability_dao = MockSalesPersonAvailabilityDao::new();
+    sales_person_availability_dao
+        .expect_find_by_id()
+        .with(eq(availability_id()), always())
+        .returning(|_, _| Ok(Some(availability_entity())));
+    sales_person_availability_dao
+        .expect_find_by_id()
+        .returning(|_, _| Ok(None));
+
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_time_now()
+        .returning(|| datetime!(2026 - 04 - 04 12:00:00));
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| new_version());
+
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    SalesPersonAvailabilityDependencies {
+        sales_person_availability_dao,
+        sales_person_service,
+        permission_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        transaction_dao,
+    }
+}
+
+// ---------- Anlegen ----------
+
+#[tokio::test]
+async fn test_create_as_self() {
+    let mut deps = build_dependencies(&[], Some(anna_id()));
+    deps.sales_person_availability_dao
+        .expect_create()
+        .withf(|entity, process, _| {
+            entity.id == new_version()
+                && entity.sales_person_id == anna_id()
+                && entity.kind == AvailabilityKindEntity::Unavailable
+                && process == "SalesPersonAvailabilityService::create"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let created = service
+        .create(&new_availability(), ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, new_version());
+    assert_eq!(created.created, Some(datetime!(2026 - 04 - 04 12:00:00)));
+    assert_eq!(created.kind, AvailabilityKind::Unavailable);
+}
+
+#[tokio::test]
+async fn test_create_for_other_person_forbidden() {
+    let mut deps = build_dependencies(&[], Some(bert_id()));
+    deps.sales_person_availability_dao.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service.create(&new_availability(), ().into(), None).await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_create_with_id_set() {
+    let deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            &SalesPersonAvailability {
+                id: availability_id(),
+                ..new_availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_zero_id_error(&result);
+}
+
+#[tokio::test]
+async fn test_create_time_window_order_wrong() {
+    let deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            &SalesPersonAvailability {
+                time_from: Some(Time::from_hms(18, 0, 0).unwrap()),
+                time_to: Some(Time::from_hms(14, 0, 0).unwrap()),
+                ..new_availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_time_order_wrong(&result);
+}
+
+#[tokio::test]
+async fn test_create_one_sided_time_window() {
+    let deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            &SalesPersonAvailability {
+                time_from: Some(Time::from_hms(14, 0, 0).unwrap()),
+                ..new_availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("time_to".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_create_validity_order_wrong() {
+    let deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            &SalesPersonAvailability {
+                valid_to: Some(date!(2025 - 12 - 31)),
+                ..new_availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_date_order_wrong(&result);
+}
+
+// ---------- Abfragen ----------
+
+/// Nur Regeln, deren Gültigkeit 2026-W17 (20.–26.04.) berührt.
+#[tokio::test]
+async fn test_get_by_week_filters_validity() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    deps.sales_person_availability_dao
+        .expect_find_all_by_sales_person_id()
+        .with(eq(anna_id()), always())
+        .returning(|_, _| {
+            Ok(Arc::from(vec![
+                availability_entity(),
+                SalesPersonAvailabilityEntity {
+                    id: uuid!("5C000000-0000-0000-0000-000000000002"),
+                    valid_to: Some(date!(2026 - 04 - 19)),
+                    ..availability_entity()
+                },
+                SalesPersonAvailabilityEntity {
+                    id: uuid!("5C000000-0000-0000-0000-000000000003"),
+                    valid_from: date!(2026 - 04 - 27),
+                    ..availability_entity()
+                },
+                SalesPersonAvailabilityEntity {
+                    id: uuid!("5C000000-0000-0000-0000-000000000004"),
+                    valid_from: date!(2026 - 04 - 26),
+                    ..availability_entity()
+                },
+            ]))
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .get_by_week_for_sales_person(anna_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+
+    let ids: Vec<Uuid> = result.iter().map(|availability| availability.id).collect();
+    assert_eq!(
+        ids,
+        vec![
+            availability_id(),
+            uuid!("5C000000-0000-0000-0000-000000000004")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_all_for_other_person_forbidden() {
+    let deps = build_dependencies(&[], Some(bert_id()));
+    let service = deps.build_service();
+
+    let result = service
+        .get_all_for_sales_person(anna_id(), ().into(), None)
+        .await;
+
+    test_forbidden(&result);
+}
+
+// ---------- Ändern / Löschen ----------
+
+#[tokio::test]
+async fn test_update_sets_new_version() {
+    let mut deps = build_dependencies(&[], Some(anna_id()));
+    deps.sales_person_availability_dao
+        .expect_update()
+        .withf(|entity, process, _| {
+            entity.version == new_version()
+                && entity.time_from == Some(Time::from_hms(14, 0, 0).unwrap())
+                && entity.created == datetime!(2026 - 01 - 01 12:00:00)
+                && process == "SalesPersonAvailabilityService::update"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let updated = service
+        .update(
+            &SalesPersonAvailability {
+                time_from: Some(Time::from_hms(14, 0, 0).unwrap()),
+                time_to: Some(Time::from_hms(18, 0, 0).unwrap()),
+                ..availability()
+            },
+            ().into(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(updated.version, new_version());
+}
+
+#[tokio::test]
+async fn test_update_version_conflict() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    deps.sales_person_availability_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let stale_version = uuid!("5C000000-0000-0000-0000-0000000000E1");
+    let result = service
+        .update(
+            &SalesPersonAvailability {
+                version: stale_version,
+                ..availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_conflicts(
+        &result,
+        &availability_id(),
+        &persisted_version(),
+        &stale_version,
+    );
+}
+
+#[tokio::test]
+async fn test_update_sales_person_change_not_allowed() {
+    let deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    let service = deps.build_service();
+
+    let result = service
+        .update(
+            &SalesPersonAvailability {
+                sales_person_id: bert_id(),
+                ..availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("sales_person_id".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_update_not_found() {
+    let deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    let service = deps.build_service();
+    let unknown_id = uuid!("5C000000-0000-0000-0000-0000000000DD");
+
+    let result = service
+        .update(
+            &SalesPersonAvailability {
+                id: unknown_id,
+                ..availability()
+            },
+            ().into(),
+            None,
+        )
+        .await;
+
+    test_not_found(&result, &unknown_id);
+}
+
+#[tokio::test]
+async fn test_delete_is_soft_delete() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None);
+    deps.sales_person_availability_dao
+        .expect_update()
+        .withf(|entity, process, _| {
+            entity.deleted == Some(datetime!(2026 - 04 - 04 12:00:00))
+                && process == "SalesPersonAvailabilityService::delete"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .delete(availability_id(), ().into(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_for_other_person_forbidden() {
+    let mut deps = build_dependencies(&[], Some(bert_id()));
+    deps.sales_person_availability_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.delete(availability_id(), ().into(), None).await;
+
+    test_forbidden(&result);
+}
@@ -3,6 +3,7 @@ use dao::{
     sales_person_shiftplan::MockSalesPersonShiftplanDao, MockTransaction, MockTransactionDao,
 };
 use service::{
+    audit_log::{entity_type, AuditAction, MockAuditLogService},
     permission::MockPermissionService,
     sales_person::{MockSalesPersonService, SalesPerson},
     sales_person_shiftplan::SalesPersonShiftplanService,
@@ -41,6 +42,7 @@ fn default_sales_person() -> SalesPerson {
         background_color: "#FF0000".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     }
@@ -53,6 +55,7 @@ fn alternate_sales_person() -> SalesPerson {
         background_color: "#00FF00".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     }
@@ -65,6 +68,7 @@ fn third_sales_person() -> SalesPerson {
         background_color: "#0000FF".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     }
@@ -74,6 +78,7 @@ pub struct TestDependencies {
     pub sales_person_shiftplan_dao: MockSalesPersonShiftplanDao,
     pub sales_person_service: MockSalesPersonService,
     pub permission_service: MockPermissionService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -83,6 +88,7 @@ impl SalesPersonShiftplanServiceDeps for TestDependencies {
     type SalesPersonShiftplanDao = MockSalesPersonShiftplanDao;
     type SalesPersonService = MockSalesPersonService;
     type PermissionService = MockPermissionService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -92,6 +98,7 @@ impl TestDependencies {
             sales_person_shiftplan_dao: self.sales_person_shiftplan_dao.into(),
             sales_person_service: self.sales_person_service.into(),
             permission_service: self.permission_service.into(),
+            audit_log_service: self.audit_log_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -114,10 +121,16 @@ fn build_dependencies() -> TestDependencies {
         .expect_exists()
         .returning(|_, _, _| Ok(true));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     TestDependencies {
         sales_person_shiftplan_dao: MockSalesPersonShiftplanDao::new(),
         sales_person_service,
         permission_service,
+        audit_log_service,
         transaction_dao,
     }
 }
@@ -165,9 +178,22 @@ async fn test_get_shiftplans_for_sales_person_empty() {
 #[tokio::test]
 async fn test_set_shiftplans_for_sales_person() {
     let mut deps = build_dependencies();
+    deps.sales_person_shiftplan_dao
+        .expect_get_by_sales_person()
+        .returning(|_, _| Ok(vec![]));
     deps.sales_person_shiftplan_dao
         .expect_set_for_sales_person()
         .returning(|_, _, _, _| Ok(()));
+    deps.audit_log_service = MockAuditLogService::new();
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == entity_type::SALES_PERSON_SHIFTPLAN
+                && change.action == AuditAction::Update
+                && change.before.as_deref() == Some("[]")
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
 
     let service = deps.build_service();
     let assignments = vec![
@@ -183,6 +209,9 @@ async fn test_set_shiftplans_for_sales_person() {
 #[tokio::test]
 async fn test_clear_shiftplans_for_sales_person() {
     let mut deps = build_dependencies();
+    deps.sales_person_shiftplan_dao
+        .expect_get_by_sales_person()
+        .returning(|_, _| Ok(vec![]));
     deps.sales_person_shiftplan_dao
         .expect_set_for_sales_person()
         .returning(|_, _, _, _| Ok(()));
@@ -444,6 +473,7 @@ fn build_dependencies_non_shiftplanner() -> TestDependencies {
         sales_person_shiftplan_dao: MockSalesPersonShiftplanDao::new(),
         sales_person_service,
         permission_service,
+        audit_log_service: MockAuditLogService::new(),
         transaction_dao,
     }
 }
@@ -452,6 +482,9 @@ fn build_dependencies_non_shiftplanner() -> TestDependencies {
 #[tokio::test]
 async fn test_assignment_defaults_to_available() {
     let mut deps = build_dependencies();
+    deps.sales_person_shiftplan_dao
+        .expect_get_by_sales_person()
+        .returning(|_, _| Ok(vec![]));
     deps.sales_person_shiftplan_dao
         .expect_set_for_sales_person()
         .withf(|_, assignments, _, _| {
@@ -475,6 +508,9 @@ async fn test_assignment_defaults_to_available() {
 #[tokio::test]
 async fn test_assignment_stores_planner_only() {
     let mut deps = build_dependencies();
+    deps.sales_person_shiftplan_dao
+        .expect_get_by_sales_person()
+        .returning(|_, _| Ok(vec![]));
     deps.sales_person_shiftplan_dao
         .expect_set_for_sales_person()
         .withf(|_, assignments, _, _| {
@@ -6,6 +6,7 @@ use dao::{
 };
 use mockall::predicate::{always, eq};
 use service::{
+    audit_log::MockAuditLogService,
     clock::MockClockService,
     permission::Authentication,
     sales_person::MockSalesPersonService,
@@ -30,6 +31,7 @@ pub struct SalesPersonUnavailableServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -41,6 +43,7 @@ impl SalesPersonUnavailableServiceDeps for SalesPersonUnavailableServiceDependen
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -54,6 +57,7 @@ impl SalesPersonUnavailableServiceDependencies {
             permission_service: Arc::new(self.permission_service),
             clock_service: Arc::new(self.clock_service),
             uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
             transaction_dao: Arc::new(self.transaction_dao),
         }
     }
@@ -104,12 +108,18 @@ pub fn build_dependencies(
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     SalesPersonUnavailableServiceDependencies {
         sales_person_unavailable_dao,
         sales_person_service,
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
     }
 }
@@ -0,0 +1,307 @@
+//! Tests für [`SchedulerServiceImpl`]: Job-Ausführung über `run_job_now`
+//! inkl. Status-Meldung an die Registry sowie die Registrierung der Jobs
+//! beim Start und Reload.
+
+use std::sync::Arc;
+
+use mockall::predicate::{always, eq};
+use service::{
+    clock::MockClockService,
+    notification::{MockNotificationService, NotificationDeliveryReport},
+    permission::Authentication,
+    rebooking_reconciliation::{AutoRebookingReport, MockRebookingReconciliationService},
+    scheduler::SchedulerService,
+    scheduler_job::{MockSchedulerJobService, SchedulerJob},
+    shiftplan_edit::MockShiftplanEditService,
+    webhook::MockWebhookService,
+    MockPermissionService, ServiceError,
+};
+use uuid::Uuid;
+
+use crate::scheduler::{SchedulerServiceDeps, SchedulerServiceImpl};
+use crate::test::error_test::test_forbidden;
+
+pub struct SchedulerServiceDependencies {
+    pub shiftplan_edit_service: MockShiftplanEditService,
+    pub notification_service: MockNotificationService,
+    pub webhook_service: MockWebhookService,
+    pub rebooking_reconciliation_service: MockRebookingReconciliationService,
+    pub scheduler_job_service: MockSchedulerJobService,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+}
+
+impl SchedulerServiceDeps for SchedulerServiceDependencies {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type ShiftplanEditService = MockShiftplanEditService;
+    type NotificationService = MockNotificationService;
+    type WebhookService = MockWebhookService;
+    type RebookingReconciliationService = MockRebookingReconciliationService;
+    type SchedulerJobService = MockSchedulerJobService;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+}
+
+impl SchedulerServiceDependencies {
+    pub fn build_service(self) -> SchedulerServiceImpl<SchedulerServiceDependencies> {
+        SchedulerServiceImpl::new(
+            Arc::new(self.shiftplan_edit_service),
+            Arc::new(self.notification_service),
+            Arc::new(self.webhook_service),
+            Arc::new(self.rebooking_reconciliation_service),
+            Arc::new(self.scheduler_job_service),
+            Arc::new(self.permission_service),
+            Arc::new(self.clock_service),
+        )
+    }
+}
+
+fn today() -> time::Date {
+    time::Date::from_calendar_date(2026, time::Month::October, 17).unwrap()
+}
+
+fn now() -> time::PrimitiveDateTime {
+    time::PrimitiveDateTime::new(today(), time::Time::from_hms(3, 0, 0).unwrap())
+}
+
+pub fn build_dependencies() -> SchedulerServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| {
+            if context == Authentication::Full {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    let mut clock_service = MockClockService::new();
+    clock_service.expect_date_now().returning(today);
+    clock_service.expect_date_time_now().returning(now);
+
+    SchedulerServiceDependencies {
+        shiftplan_edit_service: MockShiftplanEditService::new(),
+        notification_service: MockNotificationService::new(),
+        webhook_service: MockWebhookService::new(),
+        rebooking_reconciliation_service: MockRebookingReconciliationService::new(),
+        scheduler_job_service: MockSchedulerJobService::new(),
+        permission_service,
+        clock_service,
+    }
+}
+
+fn job(name: &str, cron_schedule: &str, enabled: bool) -> SchedulerJob {
+    SchedulerJob {
+        name: name.into(),
+        cron_schedule: cron_schedule.into(),
+        enabled,
+        last_run_at: None,
+        last_duration_ms: None,
+        last_error_at: None,
+        last_error_message: None,
+        version: Uuid::nil(),
+    }
+}
+
+#[tokio::test]
+async fn run_job_now_requires_full_authentication() {
+    let service = build_dependencies().build_service();
+    let result = service
+        .run_job_now("carryover", Authentication::Context(()))
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn run_job_now_unknown_job_is_not_found() {
+    let mut deps = build_dependencies();
+    deps.scheduler_job_service.expect_record_run().never();
+    let service = deps.build_service();
+    let result = service.run_job_now("nope", Authentication::Full).await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::EntityNotFoundGeneric(ref name)) if name.as_ref() == "nope"
+    ));
+}
+
+#[tokio::test]
+async fn run_carryover_updates_dirty_years_and_records_success() {
+    let mut deps = build_dependencies();
+    deps.shiftplan_edit_service
+        .expect_update_dirty_carryovers()
+        .with(eq(2026), eq(Authentication::Full), always())
+        .times(1)
+        .returning(|_, _, _| Ok(3));
+    deps.shiftplan_edit_service
+        .expect_update_carryover_all_employees()
+        .times(0);
+    deps.scheduler_job_service
+        .expect_record_run()
+        .withf(|name, started_at, _, error, context, _| {
+            name == "carryover"
+                && *started_at == now()
+                && error.is_none()
+                && *context == Authentication::Full
+        })
+        .times(1)
+        .returning(|_, _, _, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .run_job_now("carryover", Authentication::Full)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn run_carryover_failure_records_error() {
+    let mut deps = build_dependencies();
+    deps.shiftplan_edit_service
+        .expect_update_dirty_carryovers()
+        .times(1)
+        .returning(|_, _, _| Err(ServiceError::InternalError));
+    deps.scheduler_job_service
+        .expect_record_run()
+        .withf(|name, _, _, error, _, _| {
+            name == "carryover" && error.as_deref() == Some("InternalError")
+        })
+        .times(1)
+        .returning(|_, _, _, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let result = service.run_job_now("carryover", Authentication::Full).await;
+    assert!(matches!(result, Err(ServiceError::InternalError)));
+}
+
+#[tokio::test]
+async fn run_notification_delivery() {
+    let mut deps = build_dependencies();
+    deps.notification_service
+        .expect_deliver_pending()
+        .with(eq(Authentication::Full))
+        .times(1)
+        .returning(|_| {
+            Ok(NotificationDeliveryReport {
+                sent: 2,
+                retried: 0,
+                failed: 0,
+            })
+        });
+    deps.scheduler_job_service
+        .expect_record_run()
+        .withf(|name, _, _, error, _, _| name == "notification_delivery" && error.is_none())
+        .times(1)
+        .returning(|_, _, _, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .run_job_now("notification_delivery", Authentication::Full)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn run_job_now_skips_job_still_running() {
+    let mut deps = build_dependencies();
+    deps.shiftplan_edit_service
+        .expect_update_dirty_carryovers()
+        .never();
+    deps.scheduler_job_service.expect_record_run().never();
+    let service = deps.build_service();
+    service
+        .running_jobs
+        .lock()
+        .unwrap()
+        .insert("carryover".into());
+
+    service
+        .run_job_now("carryover", Authentication::Full)
+        .await
+        .unwrap();
+    assert!(service.running_jobs.lock().unwrap().contains("carryover"));
+}
+
+#[tokio::test]
+async fn run_job_now_releases_job_after_failure() {
+    let mut deps = build_dependencies();
+    deps.shiftplan_edit_service
+        .expect_update_dirty_carryovers()
+        .times(2)
+        .returning(|_, _, _| Err(ServiceError::InternalError));
+    deps.scheduler_job_service
+        .expect_record_run()
+        .times(2)
+        .returning(|_, _, _, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    for _ in 0..2 {
+        let result = service.run_job_now("carryover", Authentication::Full).await;
+        assert!(matches!(result, Err(ServiceError::InternalError)));
+    }
+    assert!(service.running_jobs.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn run_voluntary_rebooking_uses_clock_date() {
+    let mut deps = build_dependencies();
+    deps.rebooking_reconciliation_service
+        .expect_run_auto_cron()
+        .with(eq(today()), eq(Authentication::Full), always())
+        .times(1)
+        .returning(|_, _, _| Ok(AutoRebookingReport::default()));
+    deps.scheduler_job_service
+        .expect_record_run()
+        .withf(|name, _, _, error, _, _| name == "voluntary_rebooking" && error.is_none())
+        .times(1)
+        .returning(|_, _, _, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    service
+        .run_job_now("voluntary_rebooking", Authentication::Full)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn reload_before_start_registers_nothing() {
+    let mut deps = build_dependencies();
+    deps.scheduler_job_service
+        .expect_get_all()
+        .times(1)
+        .returning(|_, _| Ok(Arc::new([job("carryover", "0 0 * * * *", true)])));
+    let service = deps.build_service();
+
+    service.reload_from_db().await.unwrap();
+    assert!(service.registered_jobs.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn start_and_reload_register_enabled_valid_jobs_only() {
+    let mut deps = build_dependencies();
+    deps.scheduler_job_service
+        .expect_get_all()
+        .with(eq(Authentication::Full), always())
+        .times(2)
+        .returning(|_, _| {
+            Ok(Arc::new([
+                job("carryover", "0 0 * * * *", true),
+                job("notification_delivery", "30 * * * * *", false),
+                job("voluntary_rebooking", "not a cron", true),
+                job("unknown", "0 0 * * * *", true),
+                job("webhook_delivery", "15 * * * * *", true),
+            ]))
+        });
+    let service = deps.build_service();
+
+    service.start().await.unwrap();
+    assert_eq!(service.registered_jobs.lock().await.len(), 2);
+
+    service.reload_from_db().await.unwrap();
+    assert_eq!(service.registered_jobs.lock().await.len(), 2);
+
+    let mut scheduler = service.scheduler.lock().await;
+    if let Some(scheduler) = scheduler.as_mut() {
+        scheduler.shutdown().await.unwrap();
+    }
+}
@@ -0,0 +1,485 @@
+//! Tests für [`SchedulerJobService`]: Admin-Gate, Cron-Validierung,
+//! Versionsprüfung und Audit über Mocks, Seed und Status-Persistenz über eine
+//! SQLite-in-memory DB.
+
+use std::sync::Arc;
+
+use dao::{
+    scheduler_job::{MockSchedulerJobDao, SchedulerJobEntity},
+    MockTransaction, MockTransactionDao,
+};
+use mockall::predicate::{always, eq};
+use service::{
+    audit_log::{entity_type, AuditAction, MockAuditLogService},
+    permission::Authentication,
+    scheduler_job::{SchedulerJobService, SchedulerJobUpdate},
+    uuid_service::MockUuidService,
+    MockPermissionService, ServiceError, ValidationFailureItem,
+};
+use uuid::{uuid, Uuid};
+
+use crate::scheduler_job::{
+    is_valid_cron_schedule, SchedulerJobServiceDeps, SchedulerJobServiceImpl,
+};
+use crate::test::error_test::{test_forbidden, test_validation_error};
+
+pub struct SchedulerJobServiceDependencies {
+    pub scheduler_job_dao: MockSchedulerJobDao,
+    pub permission_service: MockPermissionService,
+    pub audit_log_service: MockAuditLogService,
+    pub uuid_service: MockUuidService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl SchedulerJobServiceDeps for SchedulerJobServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type SchedulerJobDao = MockSchedulerJobDao;
+    type PermissionService = MockPermissionService;
+    type AuditLogService = MockAuditLogService;
+    type UuidService = MockUuidService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl SchedulerJobServiceDependencies {
+    pub fn build_service(self) -> SchedulerJobServiceImpl<SchedulerJobServiceDependencies> {
+        SchedulerJobServiceImpl {
+            scheduler_job_dao: Arc::new(self.scheduler_job_dao),
+            permission_service: Arc::new(self.permission_service),
+            audit_log_service: Arc::new(self.audit_log_service),
+            uuid_service: Arc::new(self.uuid_service),
+            transaction_dao: Arc::new(self.transaction_dao),
+        }
+    }
+}
+
+pub fn build_dependencies(admin: bool) -> SchedulerJobServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .with(always(), always())
+        .returning(move |privilege, context| {
+            if context == Authentication::Full || (admin && privilege == "admin") {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| {
+            if context == Authentication::Full {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    SchedulerJobServiceDependencies {
+        scheduler_job_dao: MockSchedulerJobDao::new(),
+        permission_service,
+        audit_log_service,
+        uuid_service: MockUuidService::new(),
+        transaction_dao,
+    }
+}
+
+fn v1() -> Uuid {
+    uuid!("11111111-1111-1111-1111-111111111111")
+}
+fn v2() -> Uuid {
+    uuid!("22222222-2222-2222-2222-222222222222")
+}
+fn last_run() -> time::PrimitiveDateTime {
+    time::PrimitiveDateTime::new(
+        time::Date::from_calendar_date(2026, time::Month::October, 17).unwrap(),
+        time::Time::from_hms(3, 0, 0).unwrap(),
+    )
+}
+fn carryover_entity() -> SchedulerJobEntity {
+    SchedulerJobEntity {
+        name: "carryover".into(),
+        cron_schedule: "0 0 * * * *".into(),
+        enabled: true,
+        last_run_at: Some(last_run()),
+        last_duration_ms: Some(1200),
+        last_error_at: None,
+        last_error_message: None,
+        version: v1(),
+    }
+}
+
+#[test]
+fn cron_validation_uses_six_fields() {
+    assert!(is_valid_cron_schedule("0 0 * * * *"));
+    assert!(is_valid_cron_schedule("0 0 3 * * Mon"));
+    assert!(!is_valid_cron_schedule("0 * * * *"));
+    assert!(!is_valid_cron_schedule("every hour"));
+    assert!(!is_valid_cron_schedule(""));
+}
+
+#[tokio::test]
+async fn get_all_non_admin_forbidden() {
+    let service = build_dependencies(false).build_service();
+    let result = service.get_all(Authentication::Context(()), None).await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn update_non_admin_forbidden() {
+    let service = build_dependencies(false).build_service();
+    let update = SchedulerJobUpdate {
+        cron_schedule: "0 0 4 * * *".into(),
+        enabled: true,
+        version: v1(),
+    };
+    let result = service
+        .update("carryover", update, Authentication::Context(()), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn get_unknown_job_is_not_found() {
+    let mut deps = build_dependencies(true);
+    deps.scheduler_job_dao
+        .expect_find_by_name()
+        .with(eq("nope"), always())
+        .returning(|_, _| Ok(None));
+    let service = deps.build_service();
+    let result = service.get("nope", Authentication::Context(()), None).await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::EntityNotFoundGeneric(ref name)) if name.as_ref() == "nope"
+    ));
+}
+
+#[tokio::test]
+async fn update_rejects_invalid_cron() {
+    let service = build_dependencies(true).build_service();
+    let update = SchedulerJobUpdate {
+        cron_schedule: "0 * * * *".into(),
+        enabled: true,
+        version: v1(),
+    };
+    let result = service
+        .update("carryover", update, Authentication::Context(()), None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("cron_schedule".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn update_keeps_status_fields() {
+    let mut deps = build_dependencies(true);
+    let mut calls = 0;
+    deps.scheduler_job_dao
+        .expect_find_by_name()
+        .with(eq("carryover"), always())
+        .returning(move |_, _| {
+            calls += 1;
+            if calls == 1 {
+                Ok(Some(carryover_entity()))
+            } else {
+                Ok(Some(SchedulerJobEntity {
+                    cron_schedule: "0 30 2 * * *".into(),
+                    enabled: false,
+                    version: v2(),
+                    ..carryover_entity()
+                }))
+            }
+        });
+    deps.scheduler_job_dao
+        .expect_update()
+        .withf(|entity, process, _| {
+            entity.cron_schedule.as_ref() == "0 30 2 * * *"
+                && !entity.enabled
+                && entity.last_run_at == Some(last_run())
+                && entity.last_duration_ms == Some(1200)
+                && entity.version == v2()
+                && process == "scheduler-job-service::update"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    deps.uuid_service.expect_new_uuid().returning(|_| v2());
+    deps.audit_log_service.checkpoint();
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == entity_type::SCHEDULER_JOB
+                && change.entity_id.as_ref() == "carryover"
+                && change.action == AuditAction::Update
+                && change
+                    .before
+                    .as_deref()
+                    .unwrap_or_default()
+                    .contains("0 0 * * * *")
+                && change
+                    .after
+                    .as_deref()
+                    .unwrap_or_default()
+                    .contains("0 30 2 * * *")
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let update = SchedulerJobUpdate {
+        cron_schedule: " 0 30 2 * * * ".into(),
+        enabled: false,
+        version: v1(),
+    };
+    let job = service
+        .update("carryover", update, Authentication::Context(()), None)
+        .await
+        .unwrap();
+    assert_eq!(job.cron_schedule.as_ref(), "0 30 2 * * *");
+    assert!(!job.enabled);
+    assert_eq!(job.version, v2());
+}
+
+#[tokio::test]
+async fn update_with_stale_version_conflicts() {
+    let mut deps = build_dependencies(true);
+    deps.scheduler_job_dao
+        .expect_find_by_name()
+        .with(eq("carryover"), always())
+        .returning(|_, _| Ok(Some(carryover_entity())));
+    deps.scheduler_job_dao.expect_update().never();
+    deps.audit_log_service.checkpoint();
+    deps.audit_log_service.expect_record().never();
+    let service = deps.build_service();
+
+    let update = SchedulerJobUpdate {
+        cron_schedule: "0 30 2 * * *".into(),
+        enabled: true,
+        version: v2(),
+    };
+    let result = service
+        .update("carryover", update, Authentication::Context(()), None)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::EntityConflicts(id, requested, current))
+            if id == Uuid::nil() && requested == v2() && current == v1()
+    ));
+}
+
+#[tokio::test]
+async fn record_run_requires_full_authentication() {
+    let service = build_dependencies(true).build_service();
+    let result = service
+        .record_run(
+            "carryover",
+            last_run(),
+            10,
+            None,
+            Authentication::Context(()),
+            None,
+        )
+        .await;
+    test_forbidden(&result);
+}
+
+#[cfg(test)]
+mod integration {
+    use std::sync::Arc;
+
+    use dao_impl_sqlite::audit_log::AuditLogDaoImpl;
+    use dao_impl_sqlite::scheduler_job::SchedulerJobDaoImpl;
+    use dao_impl_sqlite::{TransactionDaoImpl, TransactionImpl};
+    use service::permission::Authentication;
+    use service::scheduler_job::{SchedulerJobService, SchedulerJobUpdate};
+    use service::uuid_service::MockUuidService;
+    use service::MockPermissionService;
+    use uuid::uuid;
+
+    use crate::audit_log::{AuditLogServiceDeps, AuditLogServiceImpl};
+    use crate::clock::ClockServiceImpl;
+    use crate::scheduler_job::{SchedulerJobServiceDeps, SchedulerJobServiceImpl};
+    use crate::uuid_service::UuidServiceImpl;
+
+    struct IntegrationDeps;
+    impl SchedulerJobServiceDeps for IntegrationDeps {
+        type Context = ();
+        type Transaction = TransactionImpl;
+        type SchedulerJobDao = SchedulerJobDaoImpl;
+        type PermissionService = MockPermissionService;
+        type AuditLogService = AuditLogServiceImpl<AuditLogDeps>;
+        type UuidService = MockUuidService;
+        type TransactionDao = TransactionDaoImpl;
+    }
+
+    struct AuditLogDeps;
+    impl AuditLogServiceDeps for AuditLogDeps {
+        type Context = ();
+        type Transaction = TransactionImpl;
+        type AuditLogDao = AuditLogDaoImpl;
+        type PermissionService = MockPermissionService;
+        type ClockService = ClockServiceImpl;
+        type UuidService = UuidServiceImpl;
+        type TransactionDao = TransactionDaoImpl;
+    }
+
+    fn build_audit_log(pool: Arc<sqlx::SqlitePool>) -> AuditLogServiceImpl<AuditLogDeps> {
+        let mut permission_service = MockPermissionService::new();
+        permission_service
+            .expect_current_user_id()
+            .returning(|_| Ok(None));
+        AuditLogServiceImpl {
+            audit_log_dao: AuditLogDaoImpl::new(pool.clone()).into(),
+            permission_service: permission_service.into(),
+            clock_service: ClockServiceImpl.into(),
+            uuid_service: UuidServiceImpl.into(),
+            transaction_dao: TransactionDaoImpl::new(pool).into(),
+        }
+    }
+
+    async fn build_service() -> SchedulerJobServiceImpl<IntegrationDeps> {
+        let pool = Arc::new(
+            sqlx::SqlitePool::connect("sqlite::memory:")
+                .await
+                .expect("Could not connect to in-memory SQLite"),
+        );
+        sqlx::migrate!("./../migrations/sqlite")
+            .run(pool.as_ref())
+            .await
+            .expect("Could not run migrations");
+
+        let mut permission_service = MockPermissionService::new();
+        permission_service
+            .expect_check_permission()
+            .returning(|_, _| Ok(()));
+        permission_service
+            .expect_check_only_full_authentication()
+            .returning(|_| Ok(()));
+        let mut uuid_service = MockUuidService::new();
+        uuid_service
+            .expect_new_uuid()
+            .returning(|_| uuid!("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa"));
+
+        SchedulerJobServiceImpl {
+            scheduler_job_dao: Arc::new(SchedulerJobDaoImpl::new(pool.clone())),
+            permission_service: Arc::new(permission_service),
+            audit_log_service: Arc::new(build_audit_log(pool.clone())),
+            uuid_service: Arc::new(uuid_service),
+            transaction_dao: Arc::new(TransactionDaoImpl::new(pool)),
+        }
+    }
+
+    fn at(hour: u8) -> time::PrimitiveDateTime {
+        time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2026, time::Month::October, 17).unwrap(),
+            time::Time::from_hms(hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn fresh_db_seeds_existing_jobs() {
+        let service = build_service().await;
+        let jobs = service.get_all(Authentication::Full, None).await.unwrap();
+        let summary: Vec<(&str, &str, bool)> = jobs
+            .iter()
+            .map(|job| (job.name.as_ref(), job.cron_schedule.as_ref(), job.enabled))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("carryover", "0 */5 * * * *", true),
+                ("notification_delivery", "30 * * * * *", true),
+                ("voluntary_rebooking", "0 0 3 * * Mon", true),
+                ("webhook_delivery", "15 * * * * *", true),
+            ]
+        );
+        assert!(jobs.iter().all(|job| job.last_run_at.is_none()));
+        assert!(jobs
+            .iter()
+            .all(|job| crate::scheduler_job::is_valid_cron_schedule(&job.cron_schedule)));
+    }
+
+    #[tokio::test]
+    async fn update_persists_cron_and_enabled() {
+        let service = build_service().await;
+        let current = service
+            .get("carryover", Authentication::Full, None)
+            .await
+            .unwrap();
+        let update = SchedulerJobUpdate {
+            cron_schedule: "0 15 1 * * *".into(),
+            enabled: false,
+            version: current.version,
+        };
+        service
+            .update("carryover", update, Authentication::Full, None)
+            .await
+            .unwrap();
+        let job = service
+            .get("carryover", Authentication::Full, None)
+            .await
+            .unwrap();
+        assert_eq!(job.cron_schedule.as_ref(), "0 15 1 * * *");
+        assert!(!job.enabled);
+        assert_eq!(job.version, uuid!("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa"));
+    }
+
+    #[tokio::test]
+    async fn record_run_sets_and_clears_error() {
+        let service = build_service().await;
+        service
+            .record_run(
+                "webhook_delivery",
+                at(1),
+                250,
+                Some("DatabaseQueryError".into()),
+                Authentication::Full,
+                None,
+            )
+            .await
+            .unwrap();
+        let job = service
+            .get("webhook_delivery", Authentication::Full, None)
+            .await
+            .unwrap();
+        assert_eq!(job.last_run_at, Some(at(1)));
+        assert_eq!(job.last_duration_ms, Some(250));
+        assert_eq!(job.last_error_at, Some(at(1)));
+        assert_eq!(
+            job.last_error_message.as_deref(),
+            Some("DatabaseQueryError")
+        );
+
+        service
+            .record_run(
+                "webhook_delivery",
+                at(2),
+                40,
+                None,
+                Authentication::Full,
+                None,
+            )
+            .await
+            .unwrap();
+        let job = service
+            .get("webhook_delivery", Authentication::Full, None)
+            .await
+            .unwrap();
+        assert_eq!(job.last_run_at, Some(at(2)));
+        assert_eq!(job.last_duration_ms, Some(40));
+        assert_eq!(job.last_error_at, None);
+        assert_eq!(job.last_error_message, None);
+    }
+}
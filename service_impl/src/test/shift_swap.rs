@@ -0,0 +1,740 @@
+//! Mock-basierte Tests für `ShiftSwapServiceImpl`.
+//!
+//! Abgedeckt:
+//!  - Anbieten: Eigentümer-Check, Wochen-Sperre, doppelte Anfrage.
+//!  - Übernehmen: Zustand, Selbst-Übernahme, Eligibility.
+//!  - Genehmigen: Löschen + Neuanlage über den ShiftplanEditService in einer
+//!    Transaktion, nur für angenommene Anfragen, nur für Shiftplanner; keine
+//!    Genehmigung für gelöschte Buchungen oder eine nicht (mehr) verfügbare
+//!    übernehmende Person (inaktiv, abwesend, doppelt gebucht).
+//!  - Ablehnen / Zurückziehen inkl. bereits entschiedener Anfragen.
+
+use std::sync::{Arc, Mutex};
+
+use dao::{
+    shift_swap::{MockShiftSwapRequestDao, ShiftSwapRequestEntity, ShiftSwapState as StateEntity},
+    MockTransaction, MockTransactionDao,
+};
+use mockall::predicate::{always, eq};
+use service::{
+    absence::AbsenceCategory,
+    audit_log::MockAuditLogService,
+    booking::{Booking, MockBookingService},
+    clock::MockClockService,
+    permission::{SALES_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::{MockSalesPersonService, SalesPerson},
+    sales_person_shiftplan::MockSalesPersonShiftplanService,
+    shift_swap::{ShiftSwapService, ShiftSwapState},
+    shiftplan_edit::{BookingCreateResult, MockShiftplanEditService},
+    slot::{MockSlotService, Slot},
+    uuid_service::MockUuidService,
+    warning::Warning,
+    week_status::{MockWeekStatusService, WeekStatus},
+    MockPermissionService, ServiceError,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime};
+use time::Time;
+use uuid::{uuid, Uuid};
+
+use crate::shift_swap::{ShiftSwapServiceDeps, ShiftSwapServiceImpl};
+use crate::test::error_test::test_forbidden;
+
+// ---------- Fixtures ----------
+
+fn request_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-000000000001")
+}
+
+fn booking_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-0000000000B1")
+}
+
+fn new_booking_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-0000000000B2")
+}
+
+fn slot_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-000000000511")
+}
+
+fn shiftplan_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-000000000021")
+}
+
+fn anna_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-0000000000A1")
+}
+
+fn bert_id() -> Uuid {
+    uuid!("5B000000-0000-0000-0000-0000000000A2")
+}
+
+fn booking() -> Booking {
+    Booking {
+        id: booking_id(),
+        sales_person_id: anna_id(),
+        slot_id: slot_id(),
+        calendar_week: 17,
+        year: 2026,
+        created: Some(datetime!(2026 - 04 - 01 10:00:00)),
+        deleted: None,
+        created_by: Some("anna".into()),
+        deleted_by: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn bert() -> SalesPerson {
+    SalesPerson {
+        id: bert_id(),
+        name: "Bert".into(),
+        background_color: "#00ff00".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn slot() -> Slot {
+    Slot {
+        id: slot_id(),
+        day_of_week: DayOfWeek::Monday,
+        from: Time::from_hms(9, 0, 0).unwrap(),
+        to: Time::from_hms(13, 0, 0).unwrap(),
+        min_resources: 1,
+        max_paid_employees: None,
+        valid_from: date!(2024 - 01 - 01),
+        valid_to: None,
+        deleted: None,
+        version: Uuid::nil(),
+        shiftplan_id: Some(shiftplan_id()),
+    }
+}
+
+fn request_entity(state: StateEntity) -> ShiftSwapRequestEntity {
+    let accepted = matches!(state, StateEntity::Accepted);
+    ShiftSwapRequestEntity {
+        id: request_id(),
+        booking_id: booking_id(),
+        offering_sales_person_id: anna_id(),
+        accepting_sales_person_id: accepted.then(bert_id),
+        new_booking_id: None,
+        year: 2026,
+        calendar_week: 17,
+        state,
+        message: "Kann Montag nicht".into(),
+        reject_reason: None,
+        created: datetime!(2026 - 04 - 02 10:00:00),
+        accepted: accepted.then_some(datetime!(2026 - 04 - 03 10:00:00)),
+        decided: None,
+        decided_by: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+pub struct ShiftSwapDependencies {
+    pub shift_swap_request_dao: MockShiftSwapRequestDao,
+    pub permission_service: MockPermissionService,
+    pub booking_service: MockBookingService,
+    pub slot_service: MockSlotService,
+    pub sales_person_service: MockSalesPersonService,
+    pub sales_person_shiftplan_service: MockSalesPersonShiftplanService,
+    pub week_status_service: MockWeekStatusService,
+    pub shiftplan_edit_service: MockShiftplanEditService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl ShiftSwapServiceDeps for ShiftSwapDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type ShiftSwapRequestDao = MockShiftSwapRequestDao;
+    type PermissionService = MockPermissionService;
+    type BookingService = MockBookingService;
+    type SlotService = MockSlotService;
+    type SalesPersonService = MockSalesPersonService;
+    type SalesPersonShiftplanService = MockSalesPersonShiftplanService;
+    type WeekStatusService = MockWeekStatusService;
+    type ShiftplanEditService = MockShiftplanEditService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl ShiftSwapDependencies {
+    fn build_service(self) -> ShiftSwapServiceImpl<ShiftSwapDependencies> {
+        ShiftSwapServiceImpl {
+            shift_swap_request_dao: self.shift_swap_request_dao.into(),
+            permission_service: self.permission_service.into(),
+            booking_service: self.booking_service.into(),
+            slot_service: self.slot_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            sales_person_shiftplan_service: self.sales_person_shiftplan_service.into(),
+            week_status_service: self.week_status_service.into(),
+            shiftplan_edit_service: self.shiftplan_edit_service.into(),
+            clock_service: self.clock_service.into(),
+            uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// Basis-Setup: der Aufrufer hat genau `privileges` und ist — falls gesetzt —
+/// die Person `self_id`. Die Woche ist offen, Bert ist aktiv, eligible und in
+/// der Woche sonst nicht gebucht, die DAO kennt eine Anfrage im Zustand `state`.
+fn build_dependencies(
+    privileges: &'static [&'static str],
+    self_id: Option<Uuid>,
+    state: StateEntity,
+) -> ShiftSwapDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(move |privilege, _| {
+            if privileges.contains(&privilege) {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    permission_service
+        .expect_current_user_id()
+        .returning(|_| Ok(Some("planner".into())));
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(move |sales_person_id, _, _| {
+            if Some(sales_person_id) == self_id {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+    sales_person_service
+        .expect_get()
+        .with(eq(bert_id()), always(), always())
+        .returning(|_, _, _| Ok(bert()));
+
+    let mut booking_service = MockBookingService::new();
+    booking_service
+        .expect_get()
+        .with(eq(booking_id()), always(), always())
+        .returning(|_, _, _| Ok(booking()));
+    booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::from([booking()])));
+
+    let mut slot_service = MockSlotService::new();
+    slot_service
+        .expect_get_slot()
+        .returning(|_, _, _| Ok(slot()));
+
+    let mut sales_person_shiftplan_service = MockSalesPersonShiftplanService::new();
+    sales_person_shiftplan_service
+        .expect_is_eligible()
+        .returning(|_, _, _, _| Ok(true));
+
+    let mut week_status_service = MockWeekStatusService::new();
+    week_status_service
+        .expect_get_week_status()
+        .returning(|_, _, _, _| Ok(WeekStatus::Planned));
+
+    let mut shift_swap_request_dao = MockShiftSwapRequestDao::new();
+    shift_swap_request_dao
+        .expect_find_by_id()
+        .with(eq(request_id()), always())
+        .returning(move |_, _| Ok(Some(request_entity(state))));
+    shift_swap_request_dao
+        .expect_find_pending_by_booking_id()
+        .returning(|_, _| Ok(None));
+
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_time_now()
+        .returning(|| datetime!(2026 - 04 - 04 12:00:00));
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service
+        .expect_new_uuid()
+        .returning(|_| uuid!("5B000000-0000-0000-0000-0000000000FF"));
+
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    ShiftSwapDependencies {
+        shift_swap_request_dao,
+        permission_service,
+        booking_service,
+        slot_service,
+        sales_person_service,
+        sales_person_shiftplan_service,
+        week_status_service,
+        shiftplan_edit_service: MockShiftplanEditService::new(),
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        transaction_dao,
+    }
+}
+
+fn expect_update_state(deps: &mut ShiftSwapDependencies, state: StateEntity) {
+    deps.shift_swap_request_dao
+        .expect_update()
+        .withf(move |entity, process, _| entity.state == state && process == "shift-swap-service")
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+}
+
+fn assert_validation_error<T: std::fmt::Debug>(result: &Result<T, ServiceError>) {
+    assert!(
+        matches!(result, Err(ServiceError::ValidationError(_))),
+        "expected ValidationError, got {result:?}"
+    );
+}
+
+// ---------- Anbieten ----------
+
+#[tokio::test]
+async fn test_offer_own_booking() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(anna_id()), StateEntity::Open);
+    deps.shift_swap_request_dao
+        .expect_create()
+        .withf(|entity, _, _| {
+            entity.booking_id == booking_id()
+                && entity.offering_sales_person_id == anna_id()
+                && entity.state == StateEntity::Open
+                && entity.year == 2026
+                && entity.calendar_week == 17
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let request = service
+        .offer(booking_id(), "Kann Montag nicht".into(), ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(request.state, ShiftSwapState::Open);
+    assert_eq!(request.accepting_sales_person_id, None);
+}
+
+#[tokio::test]
+async fn test_offer_foreign_booking_forbidden() {
+    let deps = build_dependencies(&[SALES_PRIVILEGE], Some(bert_id()), StateEntity::Open);
+    let service = deps.build_service();
+
+    let result = service
+        .offer(booking_id(), "".into(), ().into(), None)
+        .await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_offer_locked_week() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(anna_id()), StateEntity::Open);
+    deps.week_status_service.checkpoint();
+    deps.week_status_service
+        .expect_get_week_status()
+        .returning(|_, _, _, _| Ok(WeekStatus::Locked));
+    deps.shift_swap_request_dao.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .offer(booking_id(), "".into(), ().into(), None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ServiceError::WeekLocked {
+            year: 2026,
+            week: 17
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_offer_duplicate_pending_request() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(anna_id()), StateEntity::Open);
+    deps.shift_swap_request_dao.checkpoint();
+    deps.shift_swap_request_dao
+        .expect_find_pending_by_booking_id()
+        .returning(|_, _| Ok(Some(request_entity(StateEntity::Open))));
+    deps.shift_swap_request_dao.expect_create().never();
+    let service = deps.build_service();
+
+    let result = service
+        .offer(booking_id(), "".into(), ().into(), None)
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::EntityAlreadyExists(id)) if id == request_id()));
+}
+
+// ---------- Übernehmen ----------
+
+#[tokio::test]
+async fn test_accept_open_request() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(bert_id()), StateEntity::Open);
+    expect_update_state(&mut deps, StateEntity::Accepted);
+    let service = deps.build_service();
+
+    let request = service
+        .accept(request_id(), bert_id(), ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(request.state, ShiftSwapState::Accepted);
+    assert_eq!(request.accepting_sales_person_id, Some(bert_id()));
+    assert_eq!(request.accepted, Some(datetime!(2026 - 04 - 04 12:00:00)));
+}
+
+#[tokio::test]
+async fn test_accept_own_offer_rejected() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(anna_id()), StateEntity::Open);
+    deps.shift_swap_request_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service
+        .accept(request_id(), anna_id(), ().into(), None)
+        .await;
+
+    assert_validation_error(&result);
+}
+
+#[tokio::test]
+async fn test_accept_not_eligible_forbidden() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(bert_id()), StateEntity::Open);
+    deps.sales_person_shiftplan_service.checkpoint();
+    deps.sales_person_shiftplan_service
+        .expect_is_eligible()
+        .with(eq(bert_id()), eq(shiftplan_id()), always(), always())
+        .returning(|_, _, _, _| Ok(false));
+    deps.shift_swap_request_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service
+        .accept(request_id(), bert_id(), ().into(), None)
+        .await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_accept_already_accepted_request() {
+    let deps = build_dependencies(&[SALES_PRIVILEGE], Some(bert_id()), StateEntity::Accepted);
+    let service = deps.build_service();
+
+    let result = service
+        .accept(request_id(), bert_id(), ().into(), None)
+        .await;
+
+    assert_validation_error(&result);
+}
+
+// ---------- Genehmigen ----------
+
+#[tokio::test]
+async fn test_approve_moves_booking_in_one_transaction() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let delete_order = order.clone();
+    deps.shiftplan_edit_service
+        .expect_delete_booking()
+        .with(eq(booking_id()), always(), always())
+        .times(1)
+        .returning(move |_, _, tx| {
+            assert!(tx.is_some(), "delete must run in the caller transaction");
+            delete_order.lock().unwrap().push("delete");
+            Ok(())
+        });
+    let book_order = order.clone();
+    deps.shiftplan_edit_service
+        .expect_book_slot_with_conflict_check()
+        .withf(|booking, _, tx| {
+            booking.sales_person_id == bert_id()
+                && booking.slot_id == slot_id()
+                && booking.year == 2026
+                && booking.calendar_week == 17
+                && tx.is_some()
+        })
+        .times(1)
+        .returning(move |booking, _, _| {
+            book_order.lock().unwrap().push("book");
+            Ok(BookingCreateResult {
+                booking: Booking {
+                    id: new_booking_id(),
+                    ..booking.clone()
+                },
+                warnings: Arc::from([]),
+            })
+        });
+    expect_update_state(&mut deps, StateEntity::Approved);
+    deps.transaction_dao.checkpoint();
+    deps.transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    deps.transaction_dao
+        .expect_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+    let service = deps.build_service();
+
+    let result = service
+        .approve(request_id(), ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(*order.lock().unwrap(), vec!["delete", "book"]);
+    assert_eq!(result.request.state, ShiftSwapState::Approved);
+    assert_eq!(result.request.new_booking_id, Some(new_booking_id()));
+    assert_eq!(result.request.decided_by.as_deref(), Some("planner"));
+    assert_eq!(result.booking.sales_person_id, bert_id());
+}
+
+#[tokio::test]
+async fn test_approve_open_request_not_allowed() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Open);
+    deps.shiftplan_edit_service.expect_delete_booking().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert_validation_error(&result);
+}
+
+#[tokio::test]
+async fn test_approve_forbidden_for_sales() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(bert_id()), StateEntity::Accepted);
+    deps.shiftplan_edit_service.expect_delete_booking().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_approve_propagates_week_lock_without_commit() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    deps.shiftplan_edit_service
+        .expect_delete_booking()
+        .returning(|_, _, _| {
+            Err(ServiceError::WeekLocked {
+                year: 2026,
+                week: 17,
+            })
+        });
+    deps.shift_swap_request_dao.expect_update().never();
+    deps.transaction_dao.checkpoint();
+    deps.transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    deps.transaction_dao.expect_commit().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert!(matches!(result, Err(ServiceError::WeekLocked { .. })));
+}
+
+#[tokio::test]
+async fn test_approve_deleted_booking_not_allowed() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    deps.booking_service.checkpoint();
+    deps.booking_service.expect_get().returning(|_, _, _| {
+        Ok(Booking {
+            deleted: Some(datetime!(2026 - 04 - 03 18:00:00)),
+            ..booking()
+        })
+    });
+    deps.shiftplan_edit_service.expect_delete_booking().never();
+    deps.shift_swap_request_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert_validation_error(&result);
+}
+
+#[tokio::test]
+async fn test_approve_inactive_acceptor_not_allowed() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    deps.sales_person_service.checkpoint();
+    deps.sales_person_service.expect_get().returning(|_, _, _| {
+        Ok(SalesPerson {
+            inactive: true,
+            ..bert()
+        })
+    });
+    deps.shiftplan_edit_service.expect_delete_booking().never();
+    deps.shift_swap_request_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert_validation_error(&result);
+}
+
+#[tokio::test]
+async fn test_approve_no_longer_eligible_acceptor_not_allowed() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    deps.sales_person_shiftplan_service.checkpoint();
+    deps.sales_person_shiftplan_service
+        .expect_is_eligible()
+        .returning(|_, _, _, _| Ok(false));
+    deps.shiftplan_edit_service.expect_delete_booking().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert_validation_error(&result);
+}
+
+#[tokio::test]
+async fn test_approve_double_booked_acceptor_conflict() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    let other_slot_id = uuid!("5B000000-0000-0000-0000-000000000512");
+    deps.booking_service.checkpoint();
+    deps.booking_service
+        .expect_get()
+        .returning(|_, _, _| Ok(booking()));
+    deps.booking_service
+        .expect_get_for_week()
+        .returning(move |_, _, _, _| {
+            Ok(Arc::from([
+                booking(),
+                Booking {
+                    id: uuid!("5B000000-0000-0000-0000-0000000000B3"),
+                    sales_person_id: bert_id(),
+                    slot_id: other_slot_id,
+                    ..booking()
+                },
+            ]))
+        });
+    deps.slot_service.checkpoint();
+    deps.slot_service
+        .expect_get_slot()
+        .returning(move |id, _, _| {
+            if *id == other_slot_id {
+                Ok(Slot {
+                    id: other_slot_id,
+                    from: Time::from_hms(12, 0, 0).unwrap(),
+                    to: Time::from_hms(16, 0, 0).unwrap(),
+                    ..slot()
+                })
+            } else {
+                Ok(slot())
+            }
+        });
+    deps.shiftplan_edit_service.expect_delete_booking().never();
+    deps.shift_swap_request_dao.expect_update().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert!(
+        matches!(result, Err(ServiceError::OverlappingTimeRange)),
+        "expected OverlappingTimeRange, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_approve_absent_acceptor_without_commit() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    deps.shiftplan_edit_service
+        .expect_delete_booking()
+        .returning(|_, _, _| Ok(()));
+    deps.shiftplan_edit_service
+        .expect_book_slot_with_conflict_check()
+        .returning(|booking, _, _| {
+            Ok(BookingCreateResult {
+                booking: Booking {
+                    id: new_booking_id(),
+                    ..booking.clone()
+                },
+                warnings: Arc::from([Warning::BookingOnAbsenceDay {
+                    booking_id: new_booking_id(),
+                    date: date!(2026 - 04 - 20),
+                    absence_id: uuid!("5B000000-0000-0000-0000-0000000000AB"),
+                    category: AbsenceCategory::Vacation,
+                }]),
+            })
+        });
+    deps.shift_swap_request_dao.expect_update().never();
+    deps.transaction_dao.checkpoint();
+    deps.transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    deps.transaction_dao.expect_commit().never();
+    let service = deps.build_service();
+
+    let result = service.approve(request_id(), ().into(), None).await;
+
+    assert_validation_error(&result);
+}
+
+// ---------- Ablehnen / Zurückziehen ----------
+
+#[tokio::test]
+async fn test_reject_accepted_request() {
+    let mut deps = build_dependencies(&[SHIFTPLANNER_PRIVILEGE], None, StateEntity::Accepted);
+    expect_update_state(&mut deps, StateEntity::Rejected);
+    let service = deps.build_service();
+
+    let request = service
+        .reject(request_id(), "Zu kurzfristig".into(), ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(request.state, ShiftSwapState::Rejected);
+    assert_eq!(request.reject_reason.as_deref(), Some("Zu kurzfristig"));
+}
+
+#[tokio::test]
+async fn test_cancel_by_offering_person() {
+    let mut deps = build_dependencies(&[SALES_PRIVILEGE], Some(anna_id()), StateEntity::Open);
+    expect_update_state(&mut deps, StateEntity::Cancelled);
+    let service = deps.build_service();
+
+    let request = service.cancel(request_id(), ().into(), None).await.unwrap();
+
+    assert_eq!(request.state, ShiftSwapState::Cancelled);
+}
+
+#[tokio::test]
+async fn test_cancel_by_other_person_forbidden() {
+    let deps = build_dependencies(&[SALES_PRIVILEGE], Some(bert_id()), StateEntity::Open);
+    let service = deps.build_service();
+
+    let result = service.cancel(request_id(), ().into(), None).await;
+
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_cancel_decided_request_not_allowed() {
+    let deps = build_dependencies(&[SALES_PRIVILEGE], Some(anna_id()), StateEntity::Approved);
+    let service = deps.build_service();
+
+    let result = service.cancel(request_id(), ().into(), None).await;
+
+    assert_validation_error(&result);
+}
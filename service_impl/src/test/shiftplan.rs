@@ -5,6 +5,7 @@ use service::{
     booking::{Booking, MockBookingService},
     permission::MockPermissionService,
     sales_person::{MockSalesPersonService, SalesPerson},
+    sales_person_availability::{MockSalesPersonAvailabilityService, SalesPersonAvailability},
     sales_person_unavailable::{MockSalesPersonUnavailableService, SalesPersonUnavailable},
     shiftplan::ShiftplanViewService,
     shiftplan_catalog::{MockShiftplanService, Shiftplan},
@@ -58,6 +59,7 @@ pub fn default_sales_person() -> SalesPerson {
         background_color: "#FF0000".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     }
@@ -76,6 +78,7 @@ pub struct ShiftplanViewServiceDependencies {
     pub sales_person_unavailable_service: MockSalesPersonUnavailableService,
     // Phase 51 (D-51-07): Stichtag-Gate für ShortDay-Slot-Kürzung.
     pub toggle_service: MockToggleService,
+    pub sales_person_availability_service: MockSalesPersonAvailabilityService,
 }
 impl ShiftplanViewServiceDeps for ShiftplanViewServiceDependencies {
     type Context = ();
@@ -90,6 +93,7 @@ impl ShiftplanViewServiceDeps for ShiftplanViewServiceDependencies {
     type AbsenceService = MockAbsenceService;
     type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
     type ToggleService = MockToggleService;
+    type SalesPersonAvailabilityService = MockSalesPersonAvailabilityService;
 }
 
 impl ShiftplanViewServiceDependencies {
@@ -105,6 +109,7 @@ impl ShiftplanViewServiceDependencies {
             absence_service: self.absence_service.into(),
             sales_person_unavailable_service: self.sales_person_unavailable_service.into(),
             toggle_service: self.toggle_service.into(),
+            sales_person_availability_service: self.sales_person_availability_service.into(),
         }
     }
 }
@@ -184,6 +189,11 @@ pub fn build_dependencies() -> ShiftplanViewServiceDependencies {
         .expect_get_toggle_value()
         .returning(|_, _, _| Ok(None));
 
+    let mut sales_person_availability_service = MockSalesPersonAvailabilityService::new();
+    sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from(Vec::<SalesPersonAvailability>::new())));
+
     ShiftplanViewServiceDependencies {
         slot_service,
         booking_service,
@@ -195,6 +205,7 @@ pub fn build_dependencies() -> ShiftplanViewServiceDependencies {
         absence_service,
         sales_person_unavailable_service,
         toggle_service,
+        sales_person_availability_service,
     }
 }
 
@@ -616,6 +627,7 @@ fn unpaid_sales_person(id: Uuid, name: &str) -> SalesPerson {
         background_color: "#00FF00".into(),
         is_paid: Some(false),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     }
@@ -628,6 +640,7 @@ fn paid_sales_person(id: Uuid, name: &str) -> SalesPerson {
         background_color: "#0000FF".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     }
@@ -759,6 +772,7 @@ fn test_shiftplan_week_paid_in_absence_still_counts() {
         deleted: None,
         version: uuid!("AB000000-0000-0000-0000-000000000098"),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
 
     let paid_ids: HashSet<Uuid> = [paid.id].into_iter().collect();
@@ -871,8 +885,9 @@ async fn test_get_shiftplan_day_invalid_week() {
 
 // ---- Phase-3 per-sales-person-Tests (Plan 03-04 Wave 3) ----
 
-use service::absence::{AbsenceCategory, DayFraction};
-use service::shiftplan::UnavailabilityMarker;
+use service::absence::{AbsenceCategory, AbsenceStatus, DayFraction};
+use service::sales_person_availability::AvailabilityKind;
+use service::shiftplan::{UnavailabilityMarker, UnavailableWindow};
 use time::macros::{date, datetime};
 
 /// 2024-W3 Monday — `time::Date::from_iso_week_date(2024, 3, Monday)` =
@@ -889,6 +904,7 @@ fn absence_period_w3_monday() -> AbsencePeriod {
         deleted: None,
         version: uuid!("CC000000-0000-0000-0000-000000000099"),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -1056,6 +1072,123 @@ async fn test_get_shiftplan_week_for_sales_person_marker_both() {
     }
 }
 
+fn recurring_monday_unavailable() -> SalesPersonAvailability {
+    SalesPersonAvailability {
+        id: uuid!("CC000000-0000-0000-0000-000000000200"),
+        sales_person_id: default_sales_person_id(),
+        day_of_week: DayOfWeek::Monday,
+        time_from: None,
+        time_to: None,
+        valid_from: date!(2024 - 01 - 01),
+        valid_to: None,
+        kind: AvailabilityKind::Unavailable,
+        created: Some(datetime!(2024 - 01 - 01 12:00:00)),
+        deleted: None,
+        version: uuid!("CC000000-0000-0000-0000-000000000201"),
+    }
+}
+
+/// Ganztägige wiederkehrende Unavailable-Regel → ManualUnavailable-Marker
+/// wie ein Einzeleintrag.
+#[tokio::test]
+async fn test_get_shiftplan_week_for_sales_person_marker_recurring_full_day() {
+    let mut deps = build_dependencies();
+    deps.booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::new([])));
+
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from(vec![recurring_monday_unavailable()])));
+
+    let service = deps.build_service();
+    let result = service
+        .get_shiftplan_week_for_sales_person(
+            Uuid::nil(),
+            2024,
+            3,
+            default_sales_person_id(),
+            ().auth(),
+            None,
+        )
+        .await
+        .expect("get_shiftplan_week_for_sales_person should succeed");
+
+    match &result.days[0].unavailable {
+        Some(UnavailabilityMarker::ManualUnavailable) => {}
+        other => panic!("expected ManualUnavailable marker, got {other:?}"),
+    }
+    for d in result.days.iter().skip(1) {
+        assert!(d.unavailable.is_none(), "unexpected marker on {:?}", d.day_of_week);
+    }
+}
+
+/// Regel mit Zeitfenster → PartiallyUnavailable mit genau diesem Fenster;
+/// `Preferred`-Regeln erzeugen keinen Marker.
+#[tokio::test]
+async fn test_get_shiftplan_week_for_sales_person_marker_partially_unavailable() {
+    let mut deps = build_dependencies();
+    deps.booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::new([])));
+
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| {
+            Ok(Arc::from(vec![
+                SalesPersonAvailability {
+                    time_from: Some(Time::from_hms(14, 0, 0).unwrap()),
+                    time_to: Some(Time::from_hms(18, 0, 0).unwrap()),
+                    ..recurring_monday_unavailable()
+                },
+                SalesPersonAvailability {
+                    day_of_week: DayOfWeek::Tuesday,
+                    kind: AvailabilityKind::Preferred,
+                    ..recurring_monday_unavailable()
+                },
+            ]))
+        });
+
+    let service = deps.build_service();
+    let result = service
+        .get_shiftplan_week_for_sales_person(
+            Uuid::nil(),
+            2024,
+            3,
+            default_sales_person_id(),
+            ().auth(),
+            None,
+        )
+        .await
+        .expect("get_shiftplan_week_for_sales_person should succeed");
+
+    match &result.days[0].unavailable {
+        Some(UnavailabilityMarker::PartiallyUnavailable { windows }) => {
+            assert_eq!(
+                windows,
+                &vec![UnavailableWindow {
+                    from: Time::from_hms(14, 0, 0).unwrap(),
+                    to: Time::from_hms(18, 0, 0).unwrap(),
+                }]
+            );
+        }
+        other => panic!("expected PartiallyUnavailable marker, got {other:?}"),
+    }
+    assert!(result.days[1].unavailable.is_none());
+}
+
 /// Test 4: Soft-deleted AbsencePeriod (`deleted.is_some()`) wird NICHT als
 /// Marker gesetzt (Pitfall-1 / SC4).
 #[tokio::test]
@@ -1171,6 +1304,7 @@ async fn test_current_paid_count_correct_for_non_hr_caller() {
         background_color: "#0000FF".into(),
         is_paid: None, // scrubbed — simulates non-HR response
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     };
@@ -1181,6 +1315,7 @@ async fn test_current_paid_count_correct_for_non_hr_caller() {
         background_color: "#00FF00".into(),
         is_paid: None, // scrubbed
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: Uuid::new_v4(),
     };
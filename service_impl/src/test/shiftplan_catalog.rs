@@ -4,6 +4,7 @@ use dao::{
     MockTransaction, MockTransactionDao,
 };
 use service::{
+    audit_log::MockAuditLogService,
     clock::MockClockService,
     permission::MockPermissionService,
     shiftplan_catalog::{Shiftplan, ShiftplanService},
@@ -37,6 +38,7 @@ pub struct ShiftplanCatalogServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -47,6 +49,7 @@ impl ShiftplanServiceDeps for ShiftplanCatalogServiceDependencies {
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -59,6 +62,7 @@ impl ShiftplanCatalogServiceDependencies {
             permission_service: self.permission_service.into(),
             clock_service: self.clock_service.into(),
             uuid_service: self.uuid_service.into(),
+            audit_log_service: self.audit_log_service.into(),
             transaction_dao: self.transaction_dao.into(),
         }
     }
@@ -83,11 +87,17 @@ fn build_dependencies() -> ShiftplanCatalogServiceDependencies {
         .expect_new_uuid()
         .returning(|_| Uuid::new_v4());
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     ShiftplanCatalogServiceDependencies {
         shiftplan_dao: MockShiftplanDao::new(),
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
     }
 }
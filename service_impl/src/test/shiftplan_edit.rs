@@ -5,6 +5,7 @@
 //!  - `test_book_slot_warning_on_absence_day`        (BOOK-02 / D-Phase3-14 BookingOnAbsenceDay)
 //!  - `test_book_slot_warning_on_manual_unavailable` (BOOK-02 / D-Phase3-14 BookingOnUnavailableDay)
 //!  - `test_book_slot_no_warning_when_softdeleted_absence` (SC4 / Pitfall-1)
+//!  - `test_book_slot_warning_on_recurring_unavailable` (+ Zeitfenster-/Preferred-Varianten)
 //!  - `test_copy_week_aggregates_warnings`           (BOOK-02 / D-Phase3-02, D-Phase3-15: KEINE De-Dup)
 //!  - `test_book_slot_with_conflict_check_forbidden` (D-24-04 Shiftplanner ∨ self)
 //!  - `test_copy_week_with_conflict_check_forbidden` (D-Phase3-12 — bulk-Op fordert shiftplan.edit)
@@ -23,13 +24,18 @@ use std::sync::{Arc, Mutex};
 use dao::{MockTransaction, MockTransactionDao};
 use mockall::predicate::{always, eq};
 use service::{
-    absence::{AbsenceCategory, AbsencePeriod, DayFraction, MockAbsenceService},
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction, MockAbsenceService},
     booking::{Booking, MockBookingService},
-    carryover::MockCarryoverService,
+    carryover::{CarryoverDirty, MockCarryoverService},
     employee_work_details::MockEmployeeWorkDetailsService,
     extra_hours::MockExtraHoursService,
-    reporting::MockReportingService,
+    notification::MockNotificationService,
+    qualification::{MockQualificationService, SalesPersonQualification, SlotQualificationRequirement},
+    reporting::{EmployeeReport, MockReportingService},
     sales_person::{MockSalesPersonService, SalesPerson},
+    sales_person_availability::{
+        AvailabilityKind, MockSalesPersonAvailabilityService, SalesPersonAvailability,
+    },
     sales_person_unavailable::{MockSalesPersonUnavailableService, SalesPersonUnavailable},
     shiftplan_edit::ShiftplanEditService,
     slot::{MockSlotService, Slot},
@@ -130,6 +136,7 @@ fn default_absence_period() -> AbsencePeriod {
         deleted: None,
         version: uuid!("CC000000-0000-0000-0000-000000000099"),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -163,6 +170,9 @@ pub(crate) struct ShiftplanEditDependencies {
     pub absence_service: MockAbsenceService,
     pub toggle_service: MockToggleService,
     pub week_status_service: MockWeekStatusService,
+    pub sales_person_availability_service: MockSalesPersonAvailabilityService,
+    pub notification_service: MockNotificationService,
+    pub qualification_service: MockQualificationService,
 }
 
 impl ShiftplanEditServiceDeps for ShiftplanEditDependencies {
@@ -182,6 +192,9 @@ impl ShiftplanEditServiceDeps for ShiftplanEditDependencies {
     type AbsenceService = MockAbsenceService;
     type ToggleService = MockToggleService;
     type WeekStatusService = MockWeekStatusService;
+    type SalesPersonAvailabilityService = MockSalesPersonAvailabilityService;
+    type NotificationService = MockNotificationService;
+    type QualificationService = MockQualificationService;
 }
 
 impl ShiftplanEditDependencies {
@@ -201,6 +214,9 @@ impl ShiftplanEditDependencies {
             absence_service: self.absence_service.into(),
             toggle_service: self.toggle_service.into(),
             week_status_service: self.week_status_service.into(),
+            sales_person_availability_service: self.sales_person_availability_service.into(),
+            notification_service: self.notification_service.into(),
+            qualification_service: self.qualification_service.into(),
         }
     }
 }
@@ -289,6 +305,23 @@ pub(crate) fn build_dependencies(
         .expect_get_week_status()
         .returning(|_, _, _, _| Ok(WeekStatus::Unset));
 
+    let mut sales_person_availability_service = MockSalesPersonAvailabilityService::new();
+    sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from([])));
+
+    let mut notification_service = MockNotificationService::new();
+    notification_service
+        .expect_notify()
+        .returning(|_, _| Ok(()));
+
+    // Default: keine Qualifikations-Anforderungen → keine
+    // QualificationNotCovered-Warnings und nichts zu kopieren beim Split.
+    let mut qualification_service = MockQualificationService::new();
+    qualification_service
+        .expect_get_slot_requirements()
+        .returning(|_, _, _| Ok(Arc::from([])));
+
     ShiftplanEditDependencies {
         permission_service,
         slot_service,
@@ -304,6 +337,9 @@ pub(crate) fn build_dependencies(
         absence_service,
         toggle_service,
         week_status_service,
+        sales_person_availability_service,
+        notification_service,
+        qualification_service,
     }
 }
 
@@ -408,6 +444,132 @@ async fn test_book_slot_no_warning_when_softdeleted_absence() {
     );
 }
 
+/// Wiederkehrende Unavailable-Regel „montags“ ohne Zeitfenster.
+fn recurring_monday_unavailable() -> SalesPersonAvailability {
+    SalesPersonAvailability {
+        id: uuid!("CC000000-0000-0000-0000-000000000200"),
+        sales_person_id: default_sales_person_id(),
+        day_of_week: DayOfWeek::Monday,
+        time_from: None,
+        time_to: None,
+        valid_from: date!(2026 - 01 - 01),
+        valid_to: None,
+        kind: AvailabilityKind::Unavailable,
+        created: Some(datetime!(2026 - 01 - 01 12:00:00)),
+        deleted: None,
+        version: uuid!("CC000000-0000-0000-0000-000000000201"),
+    }
+}
+
+#[tokio::test]
+async fn test_book_slot_warning_on_recurring_unavailable() {
+    let mut deps = build_dependencies(true, false);
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from(vec![recurring_monday_unavailable()])));
+    let service = deps.build_service();
+
+    let result = service
+        .book_slot_with_conflict_check(&default_booking(), ().auth(), None)
+        .await
+        .expect("book_slot_with_conflict_check should succeed");
+
+    assert_eq!(result.warnings.len(), 1);
+    assert!(matches!(
+        result.warnings[0],
+        Warning::BookingOnUnavailableDay {
+            day_of_week: DayOfWeek::Monday,
+            ..
+        }
+    ));
+}
+
+/// Manueller Eintrag und wiederkehrende Regel am selben Tag ergeben genau
+/// eine Warning.
+#[tokio::test]
+async fn test_book_slot_manual_and_recurring_unavailable_single_warning() {
+    let mut deps = build_dependencies(true, false);
+    deps.sales_person_unavailable_service.checkpoint();
+    deps.sales_person_unavailable_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from(vec![default_manual_unavailable()])));
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from(vec![recurring_monday_unavailable()])));
+    let service = deps.build_service();
+
+    let result = service
+        .book_slot_with_conflict_check(&default_booking(), ().auth(), None)
+        .await
+        .expect("book_slot_with_conflict_check should succeed");
+
+    assert_eq!(result.warnings.len(), 1);
+}
+
+/// Zeitfenster 17:00–20:00 berührt den Slot 09:00–17:00 nur an der Grenze
+/// (halboffen) → keine Warning; 16:00–20:00 überlappt → Warning.
+#[tokio::test]
+async fn test_book_slot_recurring_time_window_overlap() {
+    for (from, expected_warnings) in [(17, 0), (16, 1)] {
+        let mut deps = build_dependencies(true, false);
+        deps.sales_person_availability_service.checkpoint();
+        deps.sales_person_availability_service
+            .expect_get_by_week_for_sales_person()
+            .returning(move |_, _, _, _, _| {
+                Ok(Arc::from(vec![SalesPersonAvailability {
+                    time_from: Some(Time::from_hms(from, 0, 0).unwrap()),
+                    time_to: Some(Time::from_hms(20, 0, 0).unwrap()),
+                    ..recurring_monday_unavailable()
+                }]))
+            });
+        let service = deps.build_service();
+
+        let result = service
+            .book_slot_with_conflict_check(&default_booking(), ().auth(), None)
+            .await
+            .expect("book_slot_with_conflict_check should succeed");
+
+        assert_eq!(
+            result.warnings.len(),
+            expected_warnings,
+            "window from {from}:00, got {:?}",
+            result.warnings
+        );
+    }
+}
+
+/// `Preferred`-Regeln und Regeln außerhalb des Gültigkeitszeitraums warnen
+/// nicht.
+#[tokio::test]
+async fn test_book_slot_no_warning_for_preferred_or_expired_rule() {
+    let mut deps = build_dependencies(true, false);
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| {
+            Ok(Arc::from(vec![
+                SalesPersonAvailability {
+                    kind: AvailabilityKind::Preferred,
+                    ..recurring_monday_unavailable()
+                },
+                SalesPersonAvailability {
+                    valid_to: Some(date!(2026 - 04 - 19)),
+                    ..recurring_monday_unavailable()
+                },
+            ]))
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .book_slot_with_conflict_check(&default_booking(), ().auth(), None)
+        .await
+        .expect("book_slot_with_conflict_check should succeed");
+
+    assert!(result.warnings.is_empty(), "got {:?}", result.warnings);
+}
+
 /// D-Phase3-02 + D-Phase3-15: copy_week aggregiert Warnings über alle
 /// inneren `book_slot_with_conflict_check`-Calls; KEINE De-Dup.
 ///
@@ -595,6 +757,7 @@ fn paid_sales_person(id: Uuid) -> SalesPerson {
         background_color: "#fff".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: uuid!("AAAA0000-0000-0000-0000-000000000001"),
     }
@@ -1995,3 +2158,305 @@ async fn test_msw_last_week_of_bounded_slot_no_date_order_error() {
         "Rückgabe muss Segment-2-Slot sein"
     );
 }
+
+// ---------- update_dirty_carryovers ----------
+
+fn dirty(sales_person_id: Uuid, year: u32) -> CarryoverDirty {
+    CarryoverDirty {
+        sales_person_id,
+        year,
+        version: default_version(),
+    }
+}
+
+fn carryover_report(sales_person_id: Uuid) -> EmployeeReport {
+    EmployeeReport {
+        sales_person: Arc::new(paid_sales_person(sales_person_id)),
+        balance_hours: 4.0,
+        overall_hours: 0.0,
+        expected_hours: 0.0,
+        dynamic_hours: 0.0,
+        shiftplan_hours: 0.0,
+        extra_work_hours: 0.0,
+        vacation_hours: 0.0,
+        sick_leave_hours: 0.0,
+        holiday_hours: 0.0,
+        unpaid_leave_hours: 0.0,
+        volunteer_hours: 0.0,
+        vacation_carryover: 0,
+        vacation_days: 0.0,
+        vacation_entitlement: 0.0,
+        sick_leave_days: 0.0,
+        holiday_days: 0.0,
+        absence_days: 0.0,
+        carryover_hours: 0.0,
+        custom_extra_hours: Arc::new([]),
+        by_week: Arc::new([]),
+        by_month: Arc::new([]),
+    }
+}
+
+#[tokio::test]
+async fn test_update_dirty_carryovers_nothing_dirty() {
+    let mut deps = build_dependencies(true, true);
+    deps.carryover_service
+        .expect_find_dirty()
+        .returning(|_, _| Ok(Arc::from([])));
+    deps.reporting_service
+        .expect_get_report_for_employee()
+        .times(0);
+    let service = deps.build_service();
+
+    let updated = service
+        .update_dirty_carryovers(2026, ().auth(), None)
+        .await
+        .unwrap();
+    assert_eq!(updated, 0);
+}
+
+#[tokio::test]
+async fn test_update_dirty_carryovers_cascades_from_oldest_year() {
+    let mut deps = build_dependencies(true, true);
+    let sp = paid_sp_a_id();
+    deps.carryover_service
+        .expect_find_dirty()
+        .returning(move |_, _| Ok(Arc::from([dirty(sp, 2024), dirty(sp, 2025)])));
+    deps.sales_person_service
+        .expect_get_all()
+        .returning(move |_, _| Ok(Arc::from([paid_sales_person(sp)])));
+    let years = Arc::new(Mutex::new(Vec::new()));
+    let years_clone = years.clone();
+    deps.reporting_service
+        .expect_get_report_for_employee()
+        .returning(move |sales_person_id, year, _, _, _| {
+            years_clone.lock().unwrap().push(year);
+            Ok(carryover_report(*sales_person_id))
+        });
+    deps.carryover_service
+        .expect_set_carryover()
+        .withf(move |carryover, _, _| {
+            carryover.sales_person_id == sp && carryover.carryover_hours == 4.0
+        })
+        .times(3)
+        .returning(|_, _, _| Ok(()));
+    deps.carryover_service
+        .expect_clear_dirty()
+        .times(2)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let updated = service
+        .update_dirty_carryovers(2026, ().auth(), None)
+        .await
+        .unwrap();
+    assert_eq!(updated, 3);
+    assert_eq!(*years.lock().unwrap(), vec![2024, 2025, 2026]);
+}
+
+#[tokio::test]
+async fn test_update_dirty_carryovers_clears_unknown_sales_person() {
+    let mut deps = build_dependencies(true, true);
+    let sp = paid_sp_a_id();
+    deps.carryover_service
+        .expect_find_dirty()
+        .returning(move |_, _| Ok(Arc::from([dirty(sp, 2025)])));
+    deps.sales_person_service
+        .expect_get_all()
+        .returning(|_, _| Ok(Arc::from([])));
+    deps.reporting_service
+        .expect_get_report_for_employee()
+        .times(0);
+    deps.carryover_service
+        .expect_clear_dirty()
+        .with(eq(dirty(sp, 2025)), always(), always())
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = deps.build_service();
+
+    let updated = service
+        .update_dirty_carryovers(2026, ().auth(), None)
+        .await
+        .unwrap();
+    assert_eq!(updated, 0);
+}
+
+#[tokio::test]
+async fn test_update_dirty_carryovers_forbidden() {
+    let mut deps = build_dependencies(false, true);
+    deps.carryover_service.expect_find_dirty().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .update_dirty_carryovers(2026, ().auth(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+// ---------- Qualifikationen ----------
+
+fn cashier_qualification_id() -> Uuid {
+    uuid!("D0000000-0000-0000-0000-000000000001")
+}
+
+fn cashier_requirement() -> SlotQualificationRequirement {
+    SlotQualificationRequirement {
+        slot_id: default_slot_id(),
+        qualification_id: cashier_qualification_id(),
+        min_count: 1,
+    }
+}
+
+/// Der Slot verlangt eine Kasse-qualifizierte Person; die gebuchte Person
+/// hat die Qualifikation nicht → QualificationNotCovered (0 von 1).
+#[tokio::test]
+async fn test_book_slot_warns_when_qualification_not_covered() {
+    let mut deps = build_dependencies(true, false);
+    deps.qualification_service.checkpoint();
+    deps.qualification_service
+        .expect_get_slot_requirements()
+        .with(eq(default_slot_id()), always(), always())
+        .returning(|_, _, _| Ok(Arc::from([cashier_requirement()])));
+    deps.qualification_service
+        .expect_get_all_sales_person_qualifications()
+        .returning(|_, _| Ok(Arc::from([])));
+    deps.booking_service.checkpoint();
+    deps.booking_service
+        .expect_create()
+        .returning(|_, _, _| Ok(persisted_booking()));
+    deps.booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::from([persisted_booking()])));
+    let service = deps.build_service();
+
+    let result = service
+        .book_slot_with_conflict_check(&default_booking(), ().auth(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.warnings.as_ref(),
+        &[Warning::QualificationNotCovered {
+            slot_id: default_slot_id(),
+            booking_id: default_booking_id(),
+            year: 2026,
+            week: 17,
+            qualification_id: cashier_qualification_id(),
+            required_count: 1,
+            qualified_count: 0,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_book_slot_no_warning_when_qualification_covered() {
+    let mut deps = build_dependencies(true, false);
+    deps.qualification_service.checkpoint();
+    deps.qualification_service
+        .expect_get_slot_requirements()
+        .returning(|_, _, _| Ok(Arc::from([cashier_requirement()])));
+    deps.qualification_service
+        .expect_get_all_sales_person_qualifications()
+        .returning(|_, _| {
+            Ok(Arc::from([SalesPersonQualification {
+                sales_person_id: default_sales_person_id(),
+                qualification_id: cashier_qualification_id(),
+            }]))
+        });
+    deps.booking_service.checkpoint();
+    deps.booking_service
+        .expect_create()
+        .returning(|_, _, _| Ok(persisted_booking()));
+    deps.booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::from([persisted_booking()])));
+    let service = deps.build_service();
+
+    let result = service
+        .book_slot_with_conflict_check(&default_booking(), ().auth(), None)
+        .await
+        .unwrap();
+
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+}
+
+/// Beim Kopieren einer Woche wird die Anforderung erst nach allen Buchungen
+/// geprüft: eine Warning pro Slot, nicht eine pro kopierter Buchung.
+#[tokio::test]
+async fn test_copy_week_reports_qualification_once_per_slot() {
+    let mut deps = build_dependencies(true, false);
+    deps.qualification_service.checkpoint();
+    deps.qualification_service
+        .expect_get_slot_requirements()
+        .returning(|_, _, _| {
+            Ok(Arc::from([SlotQualificationRequirement {
+                min_count: 2,
+                ..cashier_requirement()
+            }]))
+        });
+    deps.qualification_service
+        .expect_get_all_sales_person_qualifications()
+        .returning(|_, _| {
+            Ok(Arc::from([SalesPersonQualification {
+                sales_person_id: default_sales_person_id(),
+                qualification_id: cashier_qualification_id(),
+            }]))
+        });
+
+    let other_sales_person_id = uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F1");
+    let source_bookings: Arc<[Booking]> = Arc::from([
+        Booking {
+            id: uuid!("BB000000-0000-0000-0000-000000000001"),
+            ..default_booking()
+        },
+        Booking {
+            id: uuid!("BB000000-0000-0000-0000-000000000002"),
+            sales_person_id: other_sales_person_id,
+            ..default_booking()
+        },
+    ]);
+    let copied: Arc<Mutex<Vec<Booking>>> = Arc::new(Mutex::new(Vec::new()));
+    deps.booking_service.checkpoint();
+    let source = source_bookings.clone();
+    deps.booking_service
+        .expect_get_for_week()
+        .with(eq(16u8), eq(2026u32), always(), always())
+        .returning(move |_, _, _, _| Ok(source.clone()));
+    let copied_for_read = copied.clone();
+    deps.booking_service
+        .expect_get_for_week()
+        .with(eq(17u8), eq(2026u32), always(), always())
+        .returning(move |_, _, _, _| Ok(Arc::from(copied_for_read.lock().unwrap().clone())));
+    let copied_for_create = copied.clone();
+    deps.booking_service
+        .expect_create()
+        .returning(move |booking, _, _| {
+            let mut copied = copied_for_create.lock().unwrap();
+            let persisted = Booking {
+                id: Uuid::from_u128(0xCC00 + copied.len() as u128),
+                version: default_version(),
+                ..booking.clone()
+            };
+            copied.push(persisted.clone());
+            Ok(persisted)
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .copy_week_with_conflict_check(16, 2026, 17, 2026, ().auth(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.copied_bookings.len(), 2);
+    assert_eq!(
+        result.warnings.as_ref(),
+        &[Warning::QualificationNotCovered {
+            slot_id: default_slot_id(),
+            booking_id: result.copied_bookings[1].id,
+            year: 2026,
+            week: 17,
+            qualification_id: cashier_qualification_id(),
+            required_count: 2,
+            qualified_count: 1,
+        }]
+    );
+}
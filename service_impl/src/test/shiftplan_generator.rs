@@ -0,0 +1,765 @@
+//! Mock-basierte Tests für `ShiftplanGeneratorServiceImpl` und den reinen
+//! Solver `plan_week`.
+//!
+//! Abgedeckt:
+//!  - Solver: min_resources, max_paid_employees, blockierte Tage,
+//!    Vertragstage, Zeitüberschneidung, Stunden-Balance, unfilled slots.
+//!  - Service: Eligibility-Filter, Absence/Unavailable-Filter, wiederkehrende
+//!    `Unavailable`-Regeln (ganzer Tag und Zeitfenster), Trockenlauf
+//!    mit Rollback (kein Commit), Accept in einer Transaktion,
+//!    Slot-Validierung, Forbidden für beide Methoden.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::{
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction, MockAbsenceService},
+    booking::{Booking, MockBookingService},
+    employee_work_details::{EmployeeWorkDetails, MockEmployeeWorkDetailsService},
+    sales_person::{MockSalesPersonService, SalesPerson},
+    sales_person_availability::{
+        AvailabilityKind, MockSalesPersonAvailabilityService, SalesPersonAvailability,
+    },
+    sales_person_shiftplan::MockSalesPersonShiftplanService,
+    sales_person_unavailable::{MockSalesPersonUnavailableService, SalesPersonUnavailable},
+    shiftplan_edit::{BookingCreateResult, MockShiftplanEditService},
+    shiftplan_generator::{ShiftplanGeneratorService, UnfilledSlot},
+    slot::{MockSlotService, Slot},
+    warning::Warning,
+    MockPermissionService, ServiceError,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime};
+use time::Time;
+use uuid::{uuid, Uuid};
+
+use crate::shiftplan_generator::{
+    plan_week, Candidate, ShiftplanGeneratorServiceDeps, ShiftplanGeneratorServiceImpl,
+};
+use crate::test::error_test::test_forbidden;
+
+// ---------- Fixtures ----------
+
+fn shiftplan_id() -> Uuid {
+    uuid!("5A000000-0000-0000-0000-000000000001")
+}
+
+fn anna_id() -> Uuid {
+    uuid!("5A000000-0000-0000-0000-0000000000A1")
+}
+
+fn bert_id() -> Uuid {
+    uuid!("5A000000-0000-0000-0000-0000000000B2")
+}
+
+fn monday_slot_id() -> Uuid {
+    uuid!("5A000000-0000-0000-0000-000000000511")
+}
+
+fn tuesday_slot_id() -> Uuid {
+    uuid!("5A000000-0000-0000-0000-000000000512")
+}
+
+fn slot(id: Uuid, day_of_week: DayOfWeek, min_resources: u8) -> Slot {
+    Slot {
+        id,
+        day_of_week,
+        from: Time::from_hms(9, 0, 0).unwrap(),
+        to: Time::from_hms(13, 0, 0).unwrap(),
+        min_resources,
+        max_paid_employees: None,
+        valid_from: date!(2024 - 01 - 01),
+        valid_to: None,
+        deleted: None,
+        version: Uuid::nil(),
+        shiftplan_id: Some(shiftplan_id()),
+    }
+}
+
+fn candidate(id: Uuid, name: &str, expected_hours: f32) -> Candidate {
+    Candidate {
+        sales_person_id: id,
+        name: name.into(),
+        is_paid: false,
+        expected_hours,
+        workdays: None,
+        blocked_days: BTreeSet::new(),
+        blocked_slots: HashSet::new(),
+    }
+}
+
+fn sales_person(id: Uuid, name: &str) -> SalesPerson {
+    SalesPerson {
+        id,
+        name: name.into(),
+        background_color: "#FFFFFF".into(),
+        is_paid: Some(false),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn work_details(sales_person_id: Uuid, expected_hours: f32) -> EmployeeWorkDetails {
+    EmployeeWorkDetails {
+        id: Uuid::new_v4(),
+        sales_person_id,
+        expected_hours,
+        from_day_of_week: DayOfWeek::Monday,
+        from_calendar_week: 1,
+        from_year: 2026,
+        to_day_of_week: DayOfWeek::Sunday,
+        to_calendar_week: 52,
+        to_year: 2026,
+        workdays_per_week: 5,
+        is_dynamic: false,
+        cap_planned_hours_to_expected: false,
+        committed_voluntary: 0.0,
+        use_actual_hours: false,
+        monday: true,
+        tuesday: true,
+        wednesday: true,
+        thursday: true,
+        friday: true,
+        saturday: false,
+        sunday: false,
+        vacation_days: 30,
+        created: Some(datetime!(2026 - 01 - 01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn booking(sales_person_id: Uuid, slot_id: Uuid) -> Booking {
+    Booking {
+        id: Uuid::nil(),
+        sales_person_id,
+        slot_id,
+        calendar_week: 17,
+        year: 2026,
+        created: None,
+        deleted: None,
+        created_by: None,
+        deleted_by: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn slot_map(slots: &[Slot]) -> HashMap<Uuid, Slot> {
+    slots.iter().map(|s| (s.id, s.clone())).collect()
+}
+
+// ---------- Solver ----------
+
+#[test]
+fn test_plan_week_prefers_most_remaining_hours() {
+    let slots = [slot(monday_slot_id(), DayOfWeek::Monday, 1)];
+    let candidates = [
+        candidate(anna_id(), "Anna", 4.0),
+        candidate(bert_id(), "Bert", 20.0),
+    ];
+
+    let planned = plan_week(&slots, &slot_map(&slots), &[], &candidates, &HashSet::new());
+
+    assert_eq!(planned.assignments, vec![(bert_id(), monday_slot_id())]);
+    assert!(planned.unfilled_slots.is_empty());
+}
+
+#[test]
+fn test_plan_week_balances_hours_across_slots() {
+    let slots = [
+        slot(monday_slot_id(), DayOfWeek::Monday, 1),
+        slot(tuesday_slot_id(), DayOfWeek::Tuesday, 1),
+    ];
+    let candidates = [
+        candidate(anna_id(), "Anna", 8.0),
+        candidate(bert_id(), "Bert", 8.0),
+    ];
+
+    let planned = plan_week(&slots, &slot_map(&slots), &[], &candidates, &HashSet::new());
+
+    assert_eq!(
+        planned.assignments,
+        vec![
+            (anna_id(), monday_slot_id()),
+            (bert_id(), tuesday_slot_id())
+        ]
+    );
+    let anna = planned
+        .hours
+        .iter()
+        .find(|h| h.sales_person_id == anna_id())
+        .unwrap();
+    assert_eq!(anna.planned_hours_before, 0.0);
+    assert_eq!(anna.planned_hours_after, 4.0);
+}
+
+#[test]
+fn test_plan_week_counts_existing_bookings() {
+    let slots = [slot(monday_slot_id(), DayOfWeek::Monday, 2)];
+    let existing = [booking(anna_id(), monday_slot_id())];
+    let candidates = [
+        candidate(anna_id(), "Anna", 20.0),
+        candidate(bert_id(), "Bert", 4.0),
+    ];
+
+    let planned = plan_week(
+        &slots,
+        &slot_map(&slots),
+        &existing,
+        &candidates,
+        &HashSet::new(),
+    );
+
+    // Anna sitzt bereits im Slot — nur der zweite Platz wird vergeben.
+    assert_eq!(planned.assignments, vec![(bert_id(), monday_slot_id())]);
+}
+
+#[test]
+fn test_plan_week_skips_blocked_days_and_contract_days() {
+    let slots = [slot(monday_slot_id(), DayOfWeek::Monday, 1)];
+    let mut anna = candidate(anna_id(), "Anna", 20.0);
+    anna.blocked_days.insert(DayOfWeek::Monday);
+    let mut bert = candidate(bert_id(), "Bert", 20.0);
+    bert.workdays = Some([DayOfWeek::Tuesday].into_iter().collect());
+
+    let planned = plan_week(
+        &slots,
+        &slot_map(&slots),
+        &[],
+        &[anna, bert],
+        &HashSet::new(),
+    );
+
+    assert!(planned.assignments.is_empty());
+    assert_eq!(
+        planned.unfilled_slots,
+        vec![UnfilledSlot {
+            slot_id: monday_slot_id(),
+            min_resources: 1,
+            booked: 0,
+        }]
+    );
+}
+
+#[test]
+fn test_plan_week_skips_blocked_slots() {
+    let slots = [
+        slot(monday_slot_id(), DayOfWeek::Monday, 1),
+        slot(tuesday_slot_id(), DayOfWeek::Tuesday, 1),
+    ];
+    let mut anna = candidate(anna_id(), "Anna", 20.0);
+    anna.blocked_slots.insert(tuesday_slot_id());
+    let bert = candidate(bert_id(), "Bert", 4.0);
+
+    let planned = plan_week(
+        &slots,
+        &slot_map(&slots),
+        &[],
+        &[anna, bert],
+        &HashSet::new(),
+    );
+
+    assert_eq!(
+        planned.assignments,
+        vec![
+            (anna_id(), monday_slot_id()),
+            (bert_id(), tuesday_slot_id())
+        ]
+    );
+}
+
+#[test]
+fn test_plan_week_respects_max_paid_employees() {
+    let mut limited = slot(monday_slot_id(), DayOfWeek::Monday, 2);
+    limited.max_paid_employees = Some(1);
+    let slots = [limited];
+    let mut anna = candidate(anna_id(), "Anna", 20.0);
+    anna.is_paid = true;
+    let mut bert = candidate(bert_id(), "Bert", 20.0);
+    bert.is_paid = true;
+    let paid: HashSet<Uuid> = [anna_id(), bert_id()].into_iter().collect();
+
+    let planned = plan_week(&slots, &slot_map(&slots), &[], &[anna, bert], &paid);
+
+    assert_eq!(planned.assignments, vec![(anna_id(), monday_slot_id())]);
+    assert_eq!(planned.unfilled_slots.len(), 1);
+    assert_eq!(planned.unfilled_slots[0].booked, 1);
+}
+
+#[test]
+fn test_plan_week_skips_overlapping_booking_in_other_plan() {
+    let slots = [slot(monday_slot_id(), DayOfWeek::Monday, 1)];
+    let other_plan_slot = Slot {
+        id: tuesday_slot_id(),
+        from: Time::from_hms(12, 0, 0).unwrap(),
+        to: Time::from_hms(14, 0, 0).unwrap(),
+        shiftplan_id: None,
+        ..slot(tuesday_slot_id(), DayOfWeek::Monday, 1)
+    };
+    let all_slots = slot_map(&[slots[0].clone(), other_plan_slot]);
+    let existing = [booking(anna_id(), tuesday_slot_id())];
+    let candidates = [
+        candidate(anna_id(), "Anna", 40.0),
+        candidate(bert_id(), "Bert", 4.0),
+    ];
+
+    let planned = plan_week(&slots, &all_slots, &existing, &candidates, &HashSet::new());
+
+    assert_eq!(planned.assignments, vec![(bert_id(), monday_slot_id())]);
+}
+
+// ---------- DI-Setup ----------
+
+pub(crate) struct ShiftplanGeneratorDependencies {
+    pub permission_service: MockPermissionService,
+    pub slot_service: MockSlotService,
+    pub booking_service: MockBookingService,
+    pub sales_person_service: MockSalesPersonService,
+    pub sales_person_shiftplan_service: MockSalesPersonShiftplanService,
+    pub sales_person_unavailable_service: MockSalesPersonUnavailableService,
+    pub sales_person_availability_service: MockSalesPersonAvailabilityService,
+    pub absence_service: MockAbsenceService,
+    pub employee_work_details_service: MockEmployeeWorkDetailsService,
+    pub shiftplan_edit_service: MockShiftplanEditService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl ShiftplanGeneratorServiceDeps for ShiftplanGeneratorDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type PermissionService = MockPermissionService;
+    type SlotService = MockSlotService;
+    type BookingService = MockBookingService;
+    type SalesPersonService = MockSalesPersonService;
+    type SalesPersonShiftplanService = MockSalesPersonShiftplanService;
+    type SalesPersonUnavailableService = MockSalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = MockSalesPersonAvailabilityService;
+    type AbsenceService = MockAbsenceService;
+    type EmployeeWorkDetailsService = MockEmployeeWorkDetailsService;
+    type ShiftplanEditService = MockShiftplanEditService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl ShiftplanGeneratorDependencies {
+    fn build_service(self) -> ShiftplanGeneratorServiceImpl<ShiftplanGeneratorDependencies> {
+        ShiftplanGeneratorServiceImpl {
+            permission_service: self.permission_service.into(),
+            slot_service: self.slot_service.into(),
+            booking_service: self.booking_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            sales_person_shiftplan_service: self.sales_person_shiftplan_service.into(),
+            sales_person_unavailable_service: self.sales_person_unavailable_service.into(),
+            sales_person_availability_service: self.sales_person_availability_service.into(),
+            absence_service: self.absence_service.into(),
+            employee_work_details_service: self.employee_work_details_service.into(),
+            shiftplan_edit_service: self.shiftplan_edit_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// Woche 2026-W17 mit einem Montags-Slot (min 1), Anna (20h) und Bert (4h),
+/// beide eligible, keine Konflikte. Der Trockenlauf-Buchungspfad vergibt
+/// eine feste ID und liefert keine Warnings.
+fn build_dependencies(grant_permission: bool) -> ShiftplanGeneratorDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .with(eq("shiftplan.edit"), always())
+        .returning(move |_, _| {
+            if grant_permission {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut slot_service = MockSlotService::new();
+    slot_service
+        .expect_get_slots_for_week()
+        .returning(|_, _, _, _, _| Ok(Arc::from([slot(monday_slot_id(), DayOfWeek::Monday, 1)])));
+    slot_service
+        .expect_get_slots_for_week_all_plans()
+        .returning(|_, _, _, _| Ok(Arc::from([slot(monday_slot_id(), DayOfWeek::Monday, 1)])));
+
+    let mut booking_service = MockBookingService::new();
+    booking_service
+        .expect_get_for_week()
+        .returning(|_, _, _, _| Ok(Arc::from(Vec::<Booking>::new())));
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service.expect_get_all().returning(|_, _| {
+        Ok(Arc::from([
+            sales_person(anna_id(), "Anna"),
+            sales_person(bert_id(), "Bert"),
+        ]))
+    });
+
+    let mut sales_person_shiftplan_service = MockSalesPersonShiftplanService::new();
+    sales_person_shiftplan_service
+        .expect_is_eligible()
+        .returning(|_, _, _, _| Ok(true));
+
+    let mut sales_person_unavailable_service = MockSalesPersonUnavailableService::new();
+    sales_person_unavailable_service
+        .expect_get_by_week()
+        .returning(|_, _, _, _| Ok(Arc::from(Vec::<SalesPersonUnavailable>::new())));
+
+    let mut sales_person_availability_service = MockSalesPersonAvailabilityService::new();
+    sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(|_, _, _, _, _| Ok(Arc::from(Vec::<SalesPersonAvailability>::new())));
+
+    let mut absence_service = MockAbsenceService::new();
+    absence_service
+        .expect_find_overlapping_for_booking()
+        .returning(|_, _, _, _| Ok(Arc::from(Vec::<AbsencePeriod>::new())));
+
+    let mut employee_work_details_service = MockEmployeeWorkDetailsService::new();
+    employee_work_details_service
+        .expect_all_for_week()
+        .returning(|_, _, _, _| {
+            Ok(Arc::from([
+                work_details(anna_id(), 20.0),
+                work_details(bert_id(), 4.0),
+            ]))
+        });
+
+    let mut shiftplan_edit_service = MockShiftplanEditService::new();
+    shiftplan_edit_service
+        .expect_book_slot_with_conflict_check()
+        .returning(|booking, _, _| {
+            Ok(BookingCreateResult {
+                booking: Booking {
+                    id: uuid!("5A000000-0000-0000-0000-0000000B0001"),
+                    ..booking.clone()
+                },
+                warnings: Arc::from([]),
+            })
+        });
+
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_new_transaction()
+        .returning(|| Ok(MockTransaction));
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+
+    ShiftplanGeneratorDependencies {
+        permission_service,
+        slot_service,
+        booking_service,
+        sales_person_service,
+        sales_person_shiftplan_service,
+        sales_person_unavailable_service,
+        sales_person_availability_service,
+        absence_service,
+        employee_work_details_service,
+        shiftplan_edit_service,
+        transaction_dao,
+    }
+}
+
+// ---------- Service ----------
+
+#[tokio::test]
+async fn test_propose_week_dry_run_rolls_back() {
+    let mut deps = build_dependencies(true);
+    deps.transaction_dao
+        .expect_rollback()
+        .times(1)
+        .returning(|_| Ok(()));
+    deps.transaction_dao.expect_commit().never();
+    let service = deps.build_service();
+
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(proposal.bookings.len(), 1);
+    assert_eq!(proposal.bookings[0].sales_person_id, anna_id());
+    assert_eq!(proposal.bookings[0].slot_id, monday_slot_id());
+    assert!(proposal.unfilled_slots.is_empty());
+    assert!(proposal.warnings.is_empty());
+}
+
+#[tokio::test]
+async fn test_propose_week_ignores_caller_transaction() {
+    let mut deps = build_dependencies(true);
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_new_transaction()
+        .times(1)
+        .returning(|| Ok(MockTransaction));
+    transaction_dao.expect_use_transaction().never();
+    transaction_dao
+        .expect_rollback()
+        .times(1)
+        .returning(|_| Ok(()));
+    transaction_dao.expect_commit().never();
+    deps.transaction_dao = transaction_dao;
+    let service = deps.build_service();
+
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), Some(MockTransaction))
+        .await
+        .unwrap();
+
+    assert_eq!(proposal.bookings.len(), 1);
+}
+
+#[tokio::test]
+async fn test_propose_week_skips_ineligible_absent_and_unavailable() {
+    let mut deps = build_dependencies(true);
+    deps.transaction_dao.expect_rollback().returning(|_| Ok(()));
+    // Anna ist nicht eligible; Bert hat am Montag Urlaub.
+    deps.sales_person_shiftplan_service.checkpoint();
+    deps.sales_person_shiftplan_service
+        .expect_is_eligible()
+        .returning(|sales_person_id, _, _, _| Ok(sales_person_id != anna_id()));
+    deps.absence_service.checkpoint();
+    deps.absence_service
+        .expect_find_overlapping_for_booking()
+        .returning(|sales_person_id, _, _, _| {
+            Ok(Arc::from([AbsencePeriod {
+                id: Uuid::new_v4(),
+                sales_person_id,
+                category: AbsenceCategory::Vacation,
+                from_date: date!(2026 - 04 - 20),
+                to_date: date!(2026 - 04 - 20),
+                description: "".into(),
+                created: None,
+                deleted: None,
+                version: Uuid::nil(),
+                day_fraction: DayFraction::Full,
+                status: AbsenceStatus::Approved,
+            }]))
+        });
+    let service = deps.build_service();
+
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+
+    assert!(proposal.bookings.is_empty());
+    assert_eq!(proposal.unfilled_slots.len(), 1);
+}
+
+#[tokio::test]
+async fn test_propose_week_skips_manual_unavailable() {
+    let mut deps = build_dependencies(true);
+    deps.transaction_dao.expect_rollback().returning(|_| Ok(()));
+    deps.sales_person_unavailable_service.checkpoint();
+    deps.sales_person_unavailable_service
+        .expect_get_by_week()
+        .returning(|_, _, _, _| {
+            Ok(Arc::from([SalesPersonUnavailable {
+                id: Uuid::new_v4(),
+                sales_person_id: anna_id(),
+                year: 2026,
+                calendar_week: 17,
+                day_of_week: DayOfWeek::Monday,
+                created: None,
+                deleted: None,
+                version: Uuid::nil(),
+            }]))
+        });
+    let service = deps.build_service();
+
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(proposal.bookings.len(), 1);
+    assert_eq!(proposal.bookings[0].sales_person_id, bert_id());
+}
+
+/// Wiederkehrende Regel: Anna ist dienstags nicht verfügbar, optional nur
+/// im Zeitfenster `window`.
+fn anna_tuesday_unavailable(window: Option<(Time, Time)>) -> SalesPersonAvailability {
+    SalesPersonAvailability {
+        id: uuid!("5A000000-0000-0000-0000-000000000A01"),
+        sales_person_id: anna_id(),
+        day_of_week: DayOfWeek::Tuesday,
+        time_from: window.map(|(from, _)| from),
+        time_to: window.map(|(_, to)| to),
+        valid_from: date!(2026 - 01 - 01),
+        valid_to: None,
+        kind: AvailabilityKind::Unavailable,
+        created: Some(datetime!(2026 - 01 - 01 10:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+/// Nur ein Dienstags-Slot (09:00–13:00) im Plan; Anna hat die Regel `rule`.
+fn build_tuesday_dependencies(rule: SalesPersonAvailability) -> ShiftplanGeneratorDependencies {
+    let mut deps = build_dependencies(true);
+    deps.transaction_dao.expect_rollback().returning(|_| Ok(()));
+    deps.slot_service.checkpoint();
+    deps.slot_service
+        .expect_get_slots_for_week()
+        .returning(|_, _, _, _, _| Ok(Arc::from([slot(tuesday_slot_id(), DayOfWeek::Tuesday, 1)])));
+    deps.slot_service
+        .expect_get_slots_for_week_all_plans()
+        .returning(|_, _, _, _| Ok(Arc::from([slot(tuesday_slot_id(), DayOfWeek::Tuesday, 1)])));
+    deps.sales_person_availability_service.checkpoint();
+    deps.sales_person_availability_service
+        .expect_get_by_week_for_sales_person()
+        .returning(move |sales_person_id, year, week, _, _| {
+            assert_eq!((year, week), (2026, 17));
+            if sales_person_id == anna_id() {
+                Ok(Arc::from([rule.clone()]))
+            } else {
+                Ok(Arc::from([]))
+            }
+        });
+    deps
+}
+
+#[tokio::test]
+async fn test_propose_week_skips_recurring_tuesday_unavailable() {
+    let service = build_tuesday_dependencies(anna_tuesday_unavailable(None)).build_service();
+
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+
+    // Anna hätte die meisten Reststunden, ist dienstags aber nie verfügbar.
+    assert_eq!(proposal.bookings.len(), 1);
+    assert_eq!(proposal.bookings[0].sales_person_id, bert_id());
+    assert_eq!(proposal.bookings[0].slot_id, tuesday_slot_id());
+}
+
+#[tokio::test]
+async fn test_propose_week_recurring_time_window_blocks_only_overlapping_slots() {
+    // 12:00–18:00 überlappt den Slot 09:00–13:00 → Bert.
+    let overlapping = anna_tuesday_unavailable(Some((
+        Time::from_hms(12, 0, 0).unwrap(),
+        Time::from_hms(18, 0, 0).unwrap(),
+    )));
+    let service = build_tuesday_dependencies(overlapping).build_service();
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+    assert_eq!(proposal.bookings[0].sales_person_id, bert_id());
+
+    // 13:00–18:00 beginnt erst mit Slot-Ende → Anna bleibt zulässig.
+    let adjacent = anna_tuesday_unavailable(Some((
+        Time::from_hms(13, 0, 0).unwrap(),
+        Time::from_hms(18, 0, 0).unwrap(),
+    )));
+    let service = build_tuesday_dependencies(adjacent).build_service();
+    let proposal = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await
+        .unwrap();
+    assert_eq!(proposal.bookings[0].sales_person_id, anna_id());
+}
+
+#[tokio::test]
+async fn test_propose_week_forbidden() {
+    let mut deps = build_dependencies(false);
+    deps.transaction_dao
+        .expect_rollback()
+        .times(1)
+        .returning(|_| Ok(()));
+    let service = deps.build_service();
+    let result = service
+        .propose_week(shiftplan_id(), 2026, 17, ().into(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_accept_proposal_books_and_commits_with_warnings() {
+    let mut deps = build_dependencies(true);
+    deps.transaction_dao
+        .expect_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+    deps.shiftplan_edit_service.checkpoint();
+    deps.shiftplan_edit_service
+        .expect_book_slot_with_conflict_check()
+        .times(1)
+        .returning(|booking, _, _| {
+            let persisted = Booking {
+                id: uuid!("5A000000-0000-0000-0000-0000000B0002"),
+                ..booking.clone()
+            };
+            Ok(BookingCreateResult {
+                warnings: Arc::from([Warning::BookingOnUnavailableDay {
+                    booking_id: persisted.id,
+                    year: 2026,
+                    week: 17,
+                    day_of_week: DayOfWeek::Monday,
+                }]),
+                booking: persisted,
+            })
+        });
+    let service = deps.build_service();
+
+    let result = service
+        .accept_proposal(
+            shiftplan_id(),
+            2026,
+            17,
+            Arc::from([booking(anna_id(), monday_slot_id())]),
+            ().into(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.copied_bookings.len(), 1);
+    assert_eq!(result.warnings.len(), 1);
+}
+
+#[tokio::test]
+async fn test_accept_proposal_rejects_foreign_slot() {
+    let mut deps = build_dependencies(true);
+    deps.shiftplan_edit_service.checkpoint();
+    deps.shiftplan_edit_service
+        .expect_book_slot_with_conflict_check()
+        .never();
+    let service = deps.build_service();
+
+    let result = service
+        .accept_proposal(
+            shiftplan_id(),
+            2026,
+            17,
+            Arc::from([booking(anna_id(), tuesday_slot_id())]),
+            ().into(),
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_accept_proposal_forbidden() {
+    let service = build_dependencies(false).build_service();
+    let result = service
+        .accept_proposal(
+            shiftplan_id(),
+            2026,
+            17,
+            Arc::from([booking(anna_id(), monday_slot_id())]),
+            ().into(),
+            None,
+        )
+        .await;
+    test_forbidden(&result);
+}
@@ -8,7 +8,7 @@ use dao::{
 };
 use mockall::predicate::{always, eq};
 use service::{
-    clock::MockClockService, slot::*, uuid_service::MockUuidService, MockPermissionService,
+    audit_log::MockAuditLogService, clock::MockClockService, slot::*, uuid_service::MockUuidService, MockPermissionService,
     ValidationFailureItem,
 };
 use shifty_utils::DayOfWeek;
@@ -85,6 +85,7 @@ pub struct SlotServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 impl SlotServiceDependencies {
@@ -95,6 +96,7 @@ impl SlotServiceDependencies {
         MockPermissionService,
         MockClockService,
         MockUuidService,
+        MockAuditLogService,
         MockTransactionDao,
     > {
         SlotServiceImpl::new(
@@ -102,6 +104,7 @@ impl SlotServiceDependencies {
             self.permission_service.into(),
             self.clock_service.into(),
             self.uuid_service.into(),
+            self.audit_log_service.into(),
             self.transaction_dao.into(),
         )
     }
@@ -144,11 +147,17 @@ pub fn build_dependencies(permission: bool, role: &'static str) -> SlotServiceDe
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     SlotServiceDependencies {
         slot_dao,
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
     }
 }
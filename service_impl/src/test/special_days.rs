@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::special_days::SpecialDayServiceImpl;
+use crate::test::sales_person::default_sales_person;
 use crate::test::error_test::{
     test_forbidden, test_not_found, test_validation_error, test_zero_id_error,
     test_zero_version_error,
@@ -8,8 +9,11 @@ use crate::test::error_test::{
 use dao::special_day::{MockSpecialDayDao, SpecialDayEntity, SpecialDayTypeEntity};
 use mockall::predicate::{always, eq};
 use service::{
+    audit_log::MockAuditLogService,
+    carryover::MockCarryoverService,
     clock::MockClockService,
     permission::SHIFTPLANNER_PRIVILEGE,
+    sales_person::MockSalesPersonService,
     special_days::{SpecialDay, SpecialDayService, SpecialDayType},
     uuid_service::MockUuidService,
     MockPermissionService, ServiceError, ValidationFailureItem,
@@ -27,15 +31,56 @@ fn make_service(
     MockPermissionService,
     MockClockService,
     MockUuidService,
+    MockAuditLogService,
+    MockSalesPersonService,
+    MockCarryoverService,
 > {
     SpecialDayServiceImpl::new(
         Arc::new(dao),
         Arc::new(permission),
         Arc::new(MockClockService::new()),
         Arc::new(MockUuidService::new()),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service(),
     )
 }
 
+fn audit_log_service() -> Arc<MockAuditLogService> {
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+    Arc::new(audit_log_service)
+}
+
+fn sales_person_service() -> Arc<MockSalesPersonService> {
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_get_all()
+        .returning(|_, _| Ok(Arc::new([default_sales_person()])));
+    Arc::new(sales_person_service)
+}
+
+fn carryover_service() -> Arc<MockCarryoverService> {
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .returning(|_, _, _, _| Ok(()));
+    Arc::new(carryover_service)
+}
+
+/// Erwartet genau eine Markierung für `default_sales_person()` in `year`.
+fn carryover_service_expecting(year: u32) -> Arc<MockCarryoverService> {
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .with(eq(default_sales_person().id), eq(year), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    Arc::new(carryover_service)
+}
+
 fn default_id() -> Uuid {
     uuid!("682DA62E-20CB-49D9-A2A7-3F53C6842405")
 }
@@ -257,6 +302,9 @@ async fn test_create_rejects_nonnil_id() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(MockUuidService::new()),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service(),
     );
     let day_with_id = SpecialDay {
         id: default_id(),
@@ -299,6 +347,36 @@ async fn test_delete_not_found() {
     test_not_found(&result, &default_id());
 }
 
+#[tokio::test]
+async fn test_delete_marks_carryover_dirty() {
+    let mut permission = MockPermissionService::new();
+    permission
+        .expect_check_permission()
+        .returning(|_, _| Ok(()));
+    let mut clock = MockClockService::new();
+    clock.expect_date_time_now().returning(fixed_created);
+    let mut uuid = MockUuidService::new();
+    uuid.expect_new_uuid().returning(|_| default_version());
+    let mut dao = MockSpecialDayDao::new();
+    dao.expect_find_by_id().returning(|_| {
+        Ok(Some(SpecialDayEntity {
+            id: default_id(),
+            ..make_entity()
+        }))
+    });
+    dao.expect_update().times(1).returning(|_, _| Ok(()));
+    let svc = SpecialDayServiceImpl::new(
+        Arc::new(dao),
+        Arc::new(permission),
+        Arc::new(clock),
+        Arc::new(uuid),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service_expecting(2026),
+    );
+    svc.delete(default_id(), ().into()).await.unwrap();
+}
+
 /// SPD-01, WR-05: create happy-path assigns a fresh id/version, stamps `created`
 /// from the clock, and invokes `dao.create` once with the expected entity.
 #[tokio::test]
@@ -347,6 +425,9 @@ async fn test_create_success() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(uuid),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service_expecting(2026),
     );
     let result = svc.create(&minimal_special_day(), ().into()).await;
     assert!(result.is_ok(), "create must succeed: {:?}", result);
@@ -374,6 +455,9 @@ async fn test_create_rejects_nonnil_version() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(MockUuidService::new()),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service(),
     );
     let day_with_version = SpecialDay {
         version: default_version(),
@@ -432,6 +516,9 @@ async fn test_create_replaces_same_date_entry() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(uuid),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service_expecting(2026),
     );
     let result = svc.create(&minimal_special_day(), ().into()).await;
     assert!(
@@ -558,6 +645,9 @@ async fn test_create_switches_holiday_to_shortday() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(uuid),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service(),
     );
     let input = SpecialDay {
         day_type: SpecialDayType::ShortDay,
@@ -629,6 +719,9 @@ async fn test_create_switches_shortday_to_holiday() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(uuid),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service(),
     );
     let input = SpecialDay {
         day_type: SpecialDayType::Holiday,
@@ -755,6 +848,9 @@ async fn test_holiday_shortday_roundtrip_atomic() {
         Arc::new(permission),
         Arc::new(clock),
         Arc::new(uuid),
+        audit_log_service(),
+        sales_person_service(),
+        carryover_service(),
     );
 
     // --- Step 1: initial Holiday (empty week → insert path) ---
@@ -0,0 +1,534 @@
+use std::sync::{Arc, Mutex};
+
+use crate::team_ical::{TeamIcalServiceDeps, TeamIcalServiceImpl};
+use crate::test::error_test::*;
+use dao::{MockTransaction, MockTransactionDao};
+use service::{
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction, MockAbsenceService},
+    block::{Block, MockBlockService},
+    booking::{Booking, MockBookingService},
+    clock::MockClockService,
+    config::MockConfigService,
+    ical::{IcalCalendar, IcalEvent, MockIcalService},
+    permission::{HR_PRIVILEGE, SHIFTPLANNER_PRIVILEGE},
+    sales_person::{MockSalesPersonService, SalesPerson},
+    shiftplan::{
+        MockShiftplanViewService, ShiftplanBooking, ShiftplanDay, ShiftplanSlot, ShiftplanWeek,
+    },
+    shiftplan_catalog::{MockShiftplanService, Shiftplan},
+    slot::Slot,
+    special_days::{MockSpecialDayService, SpecialDay, SpecialDayType},
+    team_ical::TeamIcalService,
+    MockPermissionService, ServiceError,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime, time};
+use uuid::{uuid, Uuid};
+
+fn default_shiftplan_id() -> Uuid {
+    uuid!("B1C2D3E4-F5A6-4B7C-8D9E-0F1A2B3C4D5E")
+}
+
+fn anna() -> SalesPerson {
+    SalesPerson {
+        id: uuid!("04215DFE-13C4-413C-8C66-77AC741BB5F0"),
+        name: "Anna".into(),
+        background_color: "#fff".into(),
+        is_paid: Some(true),
+        inactive: false,
+        personnel_number: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn bea() -> SalesPerson {
+    SalesPerson {
+        id: uuid!("5F0A2D4B-6C8E-4A1B-9D3F-7E5C2B1A0D9F"),
+        name: "Bea".into(),
+        ..anna()
+    }
+}
+
+fn slot(id: Uuid, from: time::Time, to: time::Time, min_resources: u8) -> Slot {
+    Slot {
+        id,
+        day_of_week: DayOfWeek::Monday,
+        from,
+        to,
+        min_resources,
+        max_paid_employees: None,
+        valid_from: date!(2026 - 01 - 01),
+        valid_to: None,
+        deleted: None,
+        version: Uuid::nil(),
+        shiftplan_id: Some(default_shiftplan_id()),
+    }
+}
+
+fn morning_slot() -> Slot {
+    slot(
+        uuid!("7A3E5C1D-2B4F-4E6A-8C0D-1F2E3D4C5B6A"),
+        time!(9:00),
+        time!(13:00),
+        2,
+    )
+}
+
+fn afternoon_slot() -> Slot {
+    slot(
+        uuid!("8B4F6D2E-3C5A-4F7B-9D1E-2A3F4E5D6C7B"),
+        time!(13:00),
+        time!(17:00),
+        1,
+    )
+}
+
+fn booking(slot: &Slot, sales_person: &SalesPerson) -> ShiftplanBooking {
+    ShiftplanBooking {
+        booking: Booking {
+            id: Uuid::new_v4(),
+            sales_person_id: sales_person.id,
+            slot_id: slot.id,
+            calendar_week: 30,
+            year: 2026,
+            created: Some(datetime!(2026-07-01 10:00:00)),
+            deleted: None,
+            created_by: None,
+            deleted_by: None,
+            version: Uuid::nil(),
+        },
+        sales_person: sales_person.clone(),
+        self_added: None,
+    }
+}
+
+pub struct TeamIcalServiceDependencies {
+    pub shiftplan_view_service: MockShiftplanViewService,
+    pub shiftplan_service: MockShiftplanService,
+    pub block_service: MockBlockService,
+    pub booking_service: MockBookingService,
+    pub absence_service: MockAbsenceService,
+    pub sales_person_service: MockSalesPersonService,
+    pub special_day_service: MockSpecialDayService,
+    pub config_service: MockConfigService,
+    pub clock_service: MockClockService,
+    pub ical_service: MockIcalService,
+    pub permission_service: MockPermissionService,
+    pub transaction_dao: MockTransactionDao,
+}
+
+impl TeamIcalServiceDeps for TeamIcalServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type ShiftplanViewService = MockShiftplanViewService;
+    type ShiftplanService = MockShiftplanService;
+    type BlockService = MockBlockService;
+    type BookingService = MockBookingService;
+    type AbsenceService = MockAbsenceService;
+    type SalesPersonService = MockSalesPersonService;
+    type SpecialDayService = MockSpecialDayService;
+    type ConfigService = MockConfigService;
+    type ClockService = MockClockService;
+    type IcalService = MockIcalService;
+    type PermissionService = MockPermissionService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl TeamIcalServiceDependencies {
+    pub fn build_service(self) -> TeamIcalServiceImpl<TeamIcalServiceDependencies> {
+        TeamIcalServiceImpl {
+            shiftplan_view_service: self.shiftplan_view_service.into(),
+            shiftplan_service: self.shiftplan_service.into(),
+            block_service: self.block_service.into(),
+            booking_service: self.booking_service.into(),
+            absence_service: self.absence_service.into(),
+            sales_person_service: self.sales_person_service.into(),
+            special_day_service: self.special_day_service.into(),
+            config_service: self.config_service.into(),
+            clock_service: self.clock_service.into(),
+            ical_service: self.ical_service.into(),
+            permission_service: self.permission_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// Heute ist Mittwoch, 22.07.2026 (KW 30). Der angemeldete Benutzer hat
+/// genau `privilege`; das gerenderte Kalender-Modell landet in `captured`.
+fn build_dependencies(
+    privilege: &'static str,
+    captured: Arc<Mutex<Option<IcalCalendar>>>,
+) -> TeamIcalServiceDependencies {
+    let mut transaction_dao = MockTransactionDao::new();
+    transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(MockTransaction));
+    transaction_dao.expect_commit().returning(|_| Ok(()));
+
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(move |requested, _| {
+            if requested == privilege {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut config_service = MockConfigService::new();
+    config_service.expect_get_config().returning(|| {
+        Ok(service::config::Config {
+            timezone: Arc::from("Europe/Berlin"),
+            ical_label: Arc::from("Schicht"),
+            app_url: Arc::from("http://localhost:3000"),
+            datev_consultant_number: None,
+            datev_client_number: None,
+        })
+    });
+
+    let mut clock_service = MockClockService::new();
+    clock_service
+        .expect_date_now()
+        .returning(|| date!(2026 - 07 - 22));
+    clock_service
+        .expect_date_time_now()
+        .returning(generate_default_datetime);
+
+    let mut shiftplan_service = MockShiftplanService::new();
+    shiftplan_service.expect_get_by_id().returning(|id, _, _| {
+        Ok(Shiftplan {
+            id,
+            name: "Laden".into(),
+            is_planning: false,
+            deleted: None,
+            version: Uuid::nil(),
+        })
+    });
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_get_all()
+        .returning(|_, _| Ok([anna(), bea()].into()));
+
+    let mut ical_service = MockIcalService::new();
+    ical_service
+        .expect_render_calendar()
+        .returning(move |calendar, _| {
+            *captured.lock().unwrap() = Some(calendar.clone());
+            Ok("BEGIN:VCALENDAR".into())
+        });
+
+    TeamIcalServiceDependencies {
+        shiftplan_view_service: MockShiftplanViewService::new(),
+        shiftplan_service,
+        block_service: MockBlockService::new(),
+        booking_service: MockBookingService::new(),
+        absence_service: MockAbsenceService::new(),
+        sales_person_service,
+        special_day_service: MockSpecialDayService::new(),
+        config_service,
+        clock_service,
+        ical_service,
+        permission_service,
+        transaction_dao,
+    }
+}
+
+/// KW 30: Montag vormittags Anna (Mindestbesetzung 2, unterbesetzt),
+/// nachmittags Bea. Alle anderen Wochen sind leer.
+fn with_shiftplan_week(deps: &mut TeamIcalServiceDependencies) {
+    deps.shiftplan_view_service
+        .expect_get_shiftplan_week()
+        .returning(|_, year, week, _, _| {
+            let days = if (year, week) == (2026, 30) {
+                vec![ShiftplanDay {
+                    day_of_week: DayOfWeek::Monday,
+                    slots: vec![
+                        ShiftplanSlot {
+                            slot: morning_slot(),
+                            bookings: vec![booking(&morning_slot(), &anna())],
+                            current_paid_count: 1,
+                            effective_to: time!(13:00),
+                        },
+                        ShiftplanSlot {
+                            slot: afternoon_slot(),
+                            bookings: vec![booking(&afternoon_slot(), &bea())],
+                            current_paid_count: 1,
+                            // ShortDay: der Slot endet früher.
+                            effective_to: time!(15:00),
+                        },
+                    ],
+                    unavailable: None,
+                }]
+            } else {
+                vec![]
+            };
+            Ok(ShiftplanWeek {
+                year,
+                calendar_week: week,
+                days,
+            })
+        });
+    deps.block_service
+        .expect_get_unsufficiently_booked_blocks()
+        .returning(|year, week, _, _| {
+            if (year, week) == (2026, 30) {
+                Ok([Block {
+                    year,
+                    week,
+                    sales_person: None,
+                    day_of_week: DayOfWeek::Monday,
+                    from: time!(9:00),
+                    to: time!(13:00),
+                    bookings: [].into(),
+                    slots: [morning_slot()].into(),
+                }]
+                .into())
+            } else {
+                Ok([].into())
+            }
+        });
+    deps.booking_service
+        .expect_get_deleted_for_week()
+        .returning(|week, year, _, _| {
+            if (year, week) == (2026, 30) {
+                Ok([Booking {
+                    id: Uuid::new_v4(),
+                    sales_person_id: bea().id,
+                    slot_id: morning_slot().id,
+                    calendar_week: 30,
+                    year: 2026,
+                    created: Some(datetime!(2026-07-01 10:00:00)),
+                    deleted: Some(datetime!(2026-07-15 08:00:00)),
+                    created_by: None,
+                    deleted_by: None,
+                    version: Uuid::nil(),
+                }]
+                .into())
+            } else {
+                Ok([].into())
+            }
+        });
+}
+
+fn event_with_uid<'a>(calendar: &'a IcalCalendar, uid: &str) -> &'a IcalEvent {
+    calendar
+        .events
+        .iter()
+        .find(|event| event.uid.as_ref() == uid)
+        .unwrap_or_else(|| panic!("no event with uid {uid}"))
+}
+
+#[tokio::test]
+async fn test_shiftplan_feed_lists_slots_with_names_and_understaffing() {
+    let captured = Arc::new(Mutex::new(None));
+    let mut deps = build_dependencies(SHIFTPLANNER_PRIVILEGE, captured.clone());
+    with_shiftplan_week(&mut deps);
+    let service = deps.build_service();
+
+    service
+        .get_shiftplan_as_ical(default_shiftplan_id(), ().auth(), None)
+        .await
+        .unwrap();
+
+    let calendar = captured.lock().unwrap().clone().unwrap();
+    assert_eq!(calendar.name.as_ref(), "Laden");
+    assert_eq!(calendar.timezone.as_ref(), "Europe/Berlin");
+    assert_eq!(calendar.events.len(), 2);
+
+    let morning = event_with_uid(
+        &calendar,
+        &format!("slot-{}-2026-30@shifty", morning_slot().id),
+    );
+    assert_eq!(morning.summary.as_ref(), "Unterbesetzt 1/2: Anna");
+    assert_eq!(
+        morning.description.as_deref(),
+        Some("Mindestbesetzung 2, gebucht 1")
+    );
+    assert_eq!(morning.start, datetime!(2026-07-20 09:00:00));
+    assert_eq!(morning.end, datetime!(2026-07-20 13:00:00));
+    assert!(!morning.all_day);
+    // Die gelöschte Buchung ist die jüngste Änderung des Slots.
+    assert_eq!(
+        morning.sequence,
+        crate::ical::sequence_for(Some(datetime!(2026-07-15 08:00:00)))
+    );
+
+    let afternoon = event_with_uid(
+        &calendar,
+        &format!("slot-{}-2026-30@shifty", afternoon_slot().id),
+    );
+    assert_eq!(afternoon.summary.as_ref(), "Bea");
+    assert_eq!(afternoon.description, None);
+    assert_eq!(afternoon.end, datetime!(2026-07-20 15:00:00));
+}
+
+#[tokio::test]
+async fn test_shiftplan_feed_requires_shiftplanner() {
+    let captured = Arc::new(Mutex::new(None));
+    let mut deps = build_dependencies(HR_PRIVILEGE, captured);
+    deps.shiftplan_view_service
+        .expect_get_shiftplan_week()
+        .never();
+    let service = deps.build_service();
+
+    let result = service
+        .get_shiftplan_as_ical(default_shiftplan_id(), ().auth(), None)
+        .await;
+
+    test_forbidden(&result);
+}
+
+fn absence(
+    id: Uuid,
+    sales_person: &SalesPerson,
+    category: AbsenceCategory,
+    from_date: time::Date,
+    to_date: time::Date,
+) -> AbsencePeriod {
+    AbsencePeriod {
+        id,
+        sales_person_id: sales_person.id,
+        category,
+        from_date,
+        to_date,
+        description: "".into(),
+        created: Some(datetime!(2026-06-01 12:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+        day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
+    }
+}
+
+fn special_day(id: Uuid, week: u8, day_type: SpecialDayType) -> SpecialDay {
+    SpecialDay {
+        id,
+        year: 2026,
+        calendar_week: week,
+        day_of_week: DayOfWeek::Friday,
+        day_type,
+        time_of_day: None,
+        created: Some(datetime!(2026-01-05 09:00:00)),
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+#[tokio::test]
+async fn test_team_absences_feed() {
+    let captured = Arc::new(Mutex::new(None));
+    let mut deps = build_dependencies(HR_PRIVILEGE, captured.clone());
+    let vacation_id = uuid!("11111111-2222-4333-8444-555555555555");
+    let sick_id = uuid!("22222222-3333-4444-8555-666666666666");
+    let holiday_id = uuid!("33333333-4444-4555-8666-777777777777");
+    let short_day_id = uuid!("44444444-5555-4666-8777-888888888888");
+    deps.absence_service
+        .expect_find_all()
+        .returning(move |_, _| {
+            Ok([
+                AbsencePeriod {
+                    description: "Sommerurlaub".into(),
+                    ..absence(
+                        vacation_id,
+                        &anna(),
+                        AbsenceCategory::Vacation,
+                        date!(2026 - 08 - 03),
+                        date!(2026 - 08 - 07),
+                    )
+                },
+                AbsencePeriod {
+                    day_fraction: DayFraction::Half,
+                    ..absence(
+                        sick_id,
+                        &bea(),
+                        AbsenceCategory::SickLeave,
+                        date!(2026 - 07 - 21),
+                        date!(2026 - 07 - 21),
+                    )
+                },
+                // Gelöscht: taucht nicht auf.
+                AbsencePeriod {
+                    deleted: Some(datetime!(2026-06-02 12:00:00)),
+                    ..absence(
+                        Uuid::new_v4(),
+                        &anna(),
+                        AbsenceCategory::Vacation,
+                        date!(2026 - 09 - 01),
+                        date!(2026 - 09 - 02),
+                    )
+                },
+                // Vor dem Zeitfenster: taucht nicht auf.
+                absence(
+                    Uuid::new_v4(),
+                    &anna(),
+                    AbsenceCategory::UnpaidLeave,
+                    date!(2025 - 03 - 01),
+                    date!(2025 - 03 - 02),
+                ),
+            ]
+            .into())
+        });
+    deps.special_day_service
+        .expect_get_by_iso_year()
+        .returning(move |year, _| {
+            if year == 2026 {
+                Ok([
+                    special_day(holiday_id, 53, SpecialDayType::Holiday),
+                    SpecialDay {
+                        time_of_day: Some(time!(14:00)),
+                        ..special_day(short_day_id, 52, SpecialDayType::ShortDay)
+                    },
+                ]
+                .into())
+            } else {
+                Ok([].into())
+            }
+        });
+    let service = deps.build_service();
+
+    service
+        .get_team_absences_as_ical(().auth(), None)
+        .await
+        .unwrap();
+
+    let calendar = captured.lock().unwrap().clone().unwrap();
+    assert_eq!(calendar.name.as_ref(), "Abwesenheiten");
+    assert_eq!(calendar.events.len(), 4);
+
+    let vacation = event_with_uid(&calendar, &format!("absence-{vacation_id}@shifty"));
+    assert_eq!(vacation.summary.as_ref(), "Anna: Urlaub");
+    assert_eq!(vacation.description.as_deref(), Some("Sommerurlaub"));
+    assert!(vacation.all_day);
+    assert_eq!(vacation.start, datetime!(2026-08-03 00:00:00));
+    // DTEND ist bei ganztägigen Terminen exklusiv.
+    assert_eq!(vacation.end, datetime!(2026-08-08 00:00:00));
+
+    let sick = event_with_uid(&calendar, &format!("absence-{sick_id}@shifty"));
+    assert_eq!(sick.summary.as_ref(), "Bea: Krank (halber Tag)");
+    assert_eq!(sick.description, None);
+
+    // KW 53/2026, Freitag = 01.01.2027.
+    let holiday = event_with_uid(&calendar, &format!("special-day-{holiday_id}@shifty"));
+    assert_eq!(holiday.summary.as_ref(), "Feiertag");
+    assert_eq!(holiday.start, datetime!(2027-01-01 00:00:00));
+    assert_eq!(holiday.end, datetime!(2027-01-02 00:00:00));
+
+    let short_day = event_with_uid(&calendar, &format!("special-day-{short_day_id}@shifty"));
+    assert_eq!(short_day.summary.as_ref(), "Kurzer Tag bis 14:00");
+    assert_eq!(short_day.start, datetime!(2026-12-25 00:00:00));
+}
+
+#[tokio::test]
+async fn test_team_absences_feed_requires_hr() {
+    let captured = Arc::new(Mutex::new(None));
+    let mut deps = build_dependencies(SHIFTPLANNER_PRIVILEGE, captured);
+    deps.absence_service.expect_find_all().never();
+    let service = deps.build_service();
+
+    let result = service.get_team_absences_as_ical(().auth(), None).await;
+
+    test_forbidden(&result);
+}
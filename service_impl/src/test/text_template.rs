@@ -0,0 +1,180 @@
+use mockall::predicate::{always, eq};
+use service::permission::{Authentication, HR_PRIVILEGE};
+use service::text_template::{TemplateEngine, TextTemplate, TextTemplateService};
+use service::{ServiceError, ValidationFailureItem};
+use uuid::Uuid;
+
+use crate::test::error_test::test_forbidden;
+use crate::text_template::{TextTemplateServiceDeps, TextTemplateServiceImpl};
+
+struct MockDeps {
+    text_template_dao: dao::text_template::MockTextTemplateDao,
+    permission_service: service::MockPermissionService,
+    audit_log_service: service::audit_log::MockAuditLogService,
+    transaction_dao: dao::MockTransactionDao,
+}
+
+impl TextTemplateServiceDeps for MockDeps {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type TextTemplateDao = dao::text_template::MockTextTemplateDao;
+    type PermissionService = service::MockPermissionService;
+    type AuditLogService = service::audit_log::MockAuditLogService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+impl MockDeps {
+    fn new() -> Self {
+        Self {
+            text_template_dao: dao::text_template::MockTextTemplateDao::new(),
+            permission_service: service::MockPermissionService::new(),
+            audit_log_service: service::audit_log::MockAuditLogService::new(),
+            transaction_dao: dao::MockTransactionDao::new(),
+        }
+    }
+
+    fn build_service(self) -> TextTemplateServiceImpl<MockDeps> {
+        TextTemplateServiceImpl {
+            text_template_dao: self.text_template_dao.into(),
+            permission_service: self.permission_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+fn hr_deps() -> MockDeps {
+    let mut deps = MockDeps::new();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Ok(()));
+    deps
+}
+
+fn template(template_text: &str, template_engine: TemplateEngine) -> TextTemplate {
+    TextTemplate {
+        id: Uuid::new_v4(),
+        name: Some("Monatsbericht".into()),
+        template_type: "billing-period".into(),
+        template_text: template_text.into(),
+        template_engine,
+        created_at: None,
+        created_by: None,
+        deleted: None,
+        deleted_by: None,
+        version: Uuid::new_v4(),
+    }
+}
+
+#[tokio::test]
+async fn validate_accepts_valid_templates() {
+    let service = hr_deps().build_service();
+    for engine in [TemplateEngine::Tera, TemplateEngine::MiniJinja] {
+        let result = service
+            .validate(
+                "{% for sp in billing_period.sales_persons %}{{ sp.name }}{% endfor %}",
+                &engine,
+                Authentication::Full,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result, None, "{engine:?}");
+    }
+}
+
+#[tokio::test]
+async fn validate_reports_tera_syntax_error_with_position() {
+    let service = hr_deps().build_service();
+    let error = service
+        .validate(
+            "Hallo\n{{ billing_period.start_date }\n",
+            &TemplateEngine::Tera,
+            Authentication::Full,
+        )
+        .await
+        .unwrap()
+        .expect("syntax error expected");
+    assert_eq!(error.line, Some(2));
+    assert_eq!(error.column, Some(30));
+    assert!(error.message.starts_with("expected"), "{}", error.message);
+}
+
+#[tokio::test]
+async fn validate_reports_minijinja_syntax_error_with_position() {
+    let service = hr_deps().build_service();
+    let error = service
+        .validate(
+            "Hallo\n  {% if billing_period %}offen",
+            &TemplateEngine::MiniJinja,
+            Authentication::Full,
+        )
+        .await
+        .unwrap()
+        .expect("syntax error expected");
+    assert_eq!(error.line, Some(2));
+    assert!(error.column.is_some());
+    assert!(error.message.starts_with("syntax error"), "{}", error.message);
+}
+
+#[tokio::test]
+async fn validate_requires_hr() {
+    let mut deps = MockDeps::new();
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    let service = deps.build_service();
+    let result = service
+        .validate("{{ x }}", &TemplateEngine::Tera, Authentication::Full)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn create_rejects_syntax_error_without_writing() {
+    let mut deps = hr_deps();
+    deps.text_template_dao.expect_create().times(0);
+    deps.transaction_dao.expect_use_transaction().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .create(
+            &template("{% if x %}offen", TemplateEngine::MiniJinja),
+            Authentication::Full,
+            None,
+        )
+        .await;
+
+    match result {
+        Err(ServiceError::ValidationError(items)) => match items.as_ref() {
+            [ValidationFailureItem::InvalidValue(message)] => {
+                assert!(message.starts_with("template_text: Zeile 1"), "{message}")
+            }
+            other => panic!("unexpected validation items {other:?}"),
+        },
+        other => panic!("expected validation error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn update_rejects_syntax_error_without_writing() {
+    let mut deps = hr_deps();
+    deps.text_template_dao.expect_update().times(0);
+    deps.text_template_dao.expect_find_by_id().times(0);
+    let service = deps.build_service();
+
+    let result = service
+        .update(
+            &template("{{ billing_period.start_date", TemplateEngine::Tera),
+            Authentication::Full,
+            None,
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(ServiceError::ValidationError(_))),
+        "expected validation error, got {result:?}"
+    );
+}
+
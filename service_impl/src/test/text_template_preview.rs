@@ -0,0 +1,385 @@
+use std::sync::Arc;
+
+use mockall::predicate::{always, eq};
+use serde_json::json;
+use service::billing_period_report::{BILLING_PERIOD_PDF_TEMPLATE_TYPE, BILLING_PERIOD_TEMPLATE_TYPE};
+use service::block_report::BLOCK_REPORT_TEMPLATE_TYPE;
+use service::permission::{Authentication, HR_PRIVILEGE};
+use service::text_template::{TemplateEngine, TextTemplate};
+use service::text_template_preview::{TemplatePreviewSource, TextTemplatePreviewService};
+use service::{ServiceError, ValidationFailureItem};
+use uuid::{uuid, Uuid};
+
+use crate::test::error_test::{test_forbidden, test_validation_error};
+use crate::text_template_preview::{TextTemplatePreviewServiceDeps, TextTemplatePreviewServiceImpl};
+
+const BILLING_PERIOD_ID: Uuid = uuid!("aaaa0001-0000-0000-0000-000000000025");
+
+struct MockDeps {
+    billing_period_report_service: service::billing_period_report::MockBillingPeriodReportService,
+    block_report_service: service::block_report::MockBlockReportService,
+    permission_service: service::MockPermissionService,
+    transaction_dao: dao::MockTransactionDao,
+}
+
+impl TextTemplatePreviewServiceDeps for MockDeps {
+    type Context = ();
+    type Transaction = dao::MockTransaction;
+    type BillingPeriodReportService = service::billing_period_report::MockBillingPeriodReportService;
+    type BlockReportService = service::block_report::MockBlockReportService;
+    type PermissionService = service::MockPermissionService;
+    type TransactionDao = dao::MockTransactionDao;
+}
+
+impl MockDeps {
+    fn build_service(self) -> TextTemplatePreviewServiceImpl<MockDeps> {
+        TextTemplatePreviewServiceImpl {
+            billing_period_report_service: self.billing_period_report_service.into(),
+            block_report_service: self.block_report_service.into(),
+            permission_service: self.permission_service.into(),
+            transaction_dao: self.transaction_dao.into(),
+        }
+    }
+}
+
+/// HR-Berechtigung; `commits` = erwartete Anzahl erfolgreicher Vorschauen.
+fn hr_deps(commits: usize) -> MockDeps {
+    let mut deps = MockDeps {
+        billing_period_report_service:
+            service::billing_period_report::MockBillingPeriodReportService::new(),
+        block_report_service: service::block_report::MockBlockReportService::new(),
+        permission_service: service::MockPermissionService::new(),
+        transaction_dao: dao::MockTransactionDao::new(),
+    };
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Ok(()));
+    deps.transaction_dao
+        .expect_use_transaction()
+        .returning(|_| Ok(dao::MockTransaction));
+    deps.transaction_dao
+        .expect_commit()
+        .times(commits)
+        .returning(|_| Ok(()));
+    deps
+}
+
+fn template(template_type: &str, template_text: &str, template_engine: TemplateEngine) -> TextTemplate {
+    TextTemplate {
+        id: Uuid::nil(),
+        name: None,
+        template_type: template_type.into(),
+        template_text: template_text.into(),
+        template_engine,
+        created_at: None,
+        created_by: None,
+        deleted: None,
+        deleted_by: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn has_variable(variables: &[Arc<str>], variable: &str) -> bool {
+    variables.iter().any(|v| v.as_ref() == variable)
+}
+
+#[tokio::test]
+async fn sample_billing_period_preview_renders_with_both_engines() {
+    let text = "{% for sp in billing_period.sales_persons %}{{ sp.name }}={{ sp.values_map.balance.delta }};{% endfor %}";
+    for engine in [TemplateEngine::Tera, TemplateEngine::MiniJinja] {
+        let service = hr_deps(1).build_service();
+        let preview = service
+            .preview(
+                &template(BILLING_PERIOD_TEMPLATE_TYPE, text, engine.clone()),
+                TemplatePreviewSource::Sample,
+                Authentication::Full,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            preview.output.as_ref(),
+            "Anna Beispiel=3.5;Ben Muster=-1.5;",
+            "{engine:?}"
+        );
+        for variable in [
+            "billing_period.start_date",
+            "billing_period.sales_persons[].name",
+            "billing_period.sales_persons[].values[].value_delta",
+            "billing_period.sales_persons[].values_map.balance.ytd_to",
+            "template.template_type",
+        ] {
+            assert!(has_variable(&preview.variables, variable), "{variable} missing");
+        }
+    }
+}
+
+#[tokio::test]
+async fn sample_block_report_and_notification_previews() {
+    let service = hr_deps(2).build_service();
+    let preview = service
+        .preview(
+            &template(
+                BLOCK_REPORT_TEMPLATE_TYPE,
+                "KW {{ current_week }}: {% for b in current_week_blocks %}{{ b.day_of_week }} {{ b.from }}{% endfor %}",
+                TemplateEngine::Tera,
+            ),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(preview.output.as_ref(), "KW 43: Friday 14:00:00.0");
+    assert!(has_variable(&preview.variables, "next_week_blocks[].sales_person_name"));
+
+    let preview = service
+        .preview(
+            &template(
+                "notification_week_planned",
+                "KW {{ calendar_week }}/{{ year }} ist geplant",
+                TemplateEngine::MiniJinja,
+            ),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(preview.output.as_ref(), "KW 44/2026 ist geplant");
+    assert_eq!(
+        preview.variables.iter().map(|v| v.as_ref()).collect::<Vec<_>>(),
+        vec!["app_url", "calendar_week", "year"]
+    );
+}
+
+#[tokio::test]
+async fn live_billing_period_uses_report_context_and_adds_its_variables() {
+    let mut deps = hr_deps(1);
+    deps.billing_period_report_service
+        .expect_build_custom_report_context()
+        .withf(|template, id, _, _| {
+            template.template_type.as_ref() == BILLING_PERIOD_TEMPLATE_TYPE
+                && *id == BILLING_PERIOD_ID
+        })
+        .times(1)
+        .returning(|_, _, _, _| {
+            Ok(json!({
+                "billing_period": {
+                    "start_date": "2026-01-01",
+                    "sales_persons": [{
+                        "name": "Natalie",
+                        "values_map": {"custom_extra_hours:Messe": {"delta": 4.0}},
+                    }],
+                }
+            }))
+        });
+    let service = deps.build_service();
+
+    let preview = service
+        .preview(
+            &template(
+                BILLING_PERIOD_TEMPLATE_TYPE,
+                "{{ billing_period.start_date }} {{ billing_period.sales_persons[0].name }}",
+                TemplateEngine::MiniJinja,
+            ),
+            TemplatePreviewSource::Live {
+                billing_period_id: Some(BILLING_PERIOD_ID),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(preview.output.as_ref(), "2026-01-01 Natalie");
+    assert!(has_variable(&preview.variables, "billing_period.end_date"));
+    assert!(has_variable(
+        &preview.variables,
+        "billing_period.sales_persons[].values_map.custom_extra_hours:Messe.delta"
+    ));
+}
+
+#[tokio::test]
+async fn live_block_report_uses_current_context() {
+    let mut deps = hr_deps(1);
+    deps.block_report_service
+        .expect_build_block_report_context()
+        .times(1)
+        .returning(|_, _| Ok(json!({"current_week": 7, "current_week_blocks": []})));
+    let service = deps.build_service();
+
+    let preview = service
+        .preview(
+            &template(BLOCK_REPORT_TEMPLATE_TYPE, "KW {{ current_week }}", TemplateEngine::Tera),
+            TemplatePreviewSource::Live {
+                billing_period_id: None,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(preview.output.as_ref(), "KW 7");
+}
+
+#[tokio::test]
+async fn live_billing_period_requires_billing_period_id() {
+    let service = hr_deps(0).build_service();
+    let result = service
+        .preview(
+            &template(BILLING_PERIOD_TEMPLATE_TYPE, "{{ billing_period.id }}", TemplateEngine::Tera),
+            TemplatePreviewSource::Live {
+                billing_period_id: None,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("billing_period_id".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn live_notification_preview_is_rejected() {
+    let service = hr_deps(0).build_service();
+    let result = service
+        .preview(
+            &template("notification_booking_deleted", "{{ date }}", TemplateEngine::MiniJinja),
+            TemplatePreviewSource::Live {
+                billing_period_id: None,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue(
+            "Für den Template-Typ `notification_booking_deleted` gibt es nur Beispieldaten".into(),
+        ),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn unknown_template_type_is_rejected() {
+    let service = hr_deps(0).build_service();
+    let result = service
+        .preview(
+            &template("email", "Hallo", TemplateEngine::Tera),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue(
+            "Für den Template-Typ `email` gibt es keinen Vorschau-Kontext".into(),
+        ),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn syntax_and_render_errors_are_validation_errors() {
+    let service = hr_deps(0).build_service();
+    let result = service
+        .preview(
+            &template(BILLING_PERIOD_TEMPLATE_TYPE, "{% for x in y %}", TemplateEngine::Tera),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await;
+    assert!(
+        matches!(&result, Err(ServiceError::ValidationError(items))
+            if matches!(items.as_ref(), [ValidationFailureItem::InvalidValue(m)] if m.starts_with("template_text: Zeile 1"))),
+        "{result:?}"
+    );
+
+    let result = service
+        .preview(
+            &template(BILLING_PERIOD_TEMPLATE_TYPE, "{{ unknown_variable }}", TemplateEngine::Tera),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await;
+    assert!(
+        matches!(&result, Err(ServiceError::ValidationError(items))
+            if matches!(items.as_ref(), [ValidationFailureItem::InvalidValue(m)] if m.starts_with("Renderfehler:") && m.contains("unknown_variable"))),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn pdf_template_preview_is_escaped_and_layout_checked() {
+    let service = hr_deps(1).build_service();
+    let preview = service
+        .preview(
+            &template(
+                BILLING_PERIOD_PDF_TEMPLATE_TYPE,
+                "<document><p>{{ billing_period.start_date }} {{ '<&>' }}</p></document>",
+                TemplateEngine::MiniJinja,
+            ),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        preview.output.as_ref(),
+        "<document><p>2026-09-01 &lt;&amp;&gt;</p></document>"
+    );
+
+    let service = hr_deps(0).build_service();
+    let result = service
+        .preview(
+            &template(
+                BILLING_PERIOD_PDF_TEMPLATE_TYPE,
+                "<document>\n<blink>{{ billing_period.id }}</blink></document>",
+                TemplateEngine::MiniJinja,
+            ),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await;
+    assert!(
+        matches!(&result, Err(ServiceError::ValidationError(items))
+            if matches!(items.as_ref(), [ValidationFailureItem::InvalidValue(m)] if m.starts_with("PDF-Layout Zeile 2"))),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn preview_requires_hr() {
+    let mut deps = MockDeps {
+        billing_period_report_service:
+            service::billing_period_report::MockBillingPeriodReportService::new(),
+        block_report_service: service::block_report::MockBlockReportService::new(),
+        permission_service: service::MockPermissionService::new(),
+        transaction_dao: dao::MockTransactionDao::new(),
+    };
+    deps.permission_service
+        .expect_check_permission()
+        .with(eq(HR_PRIVILEGE), always())
+        .returning(|_, _| Err(ServiceError::Forbidden));
+    let service = deps.build_service();
+    let result = service
+        .preview(
+            &template(BILLING_PERIOD_TEMPLATE_TYPE, "Hallo", TemplateEngine::Tera),
+            TemplatePreviewSource::Sample,
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_forbidden(&result);
+}
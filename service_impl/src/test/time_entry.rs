@@ -0,0 +1,793 @@
+use std::sync::Arc;
+
+use dao::time_entry::{
+    MockTimeEntryDao, TimeEntryEntity, TimeEntrySource as TimeEntrySourceEntity,
+};
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::audit_log::MockAuditLogService;
+use service::booking::{Booking, MockBookingService};
+use service::carryover::MockCarryoverService;
+use service::clock::MockClockService;
+use service::config::{Config, MockConfigService};
+use service::employee_work_details::EmployeeWorkDetails;
+use service::permission::Authentication;
+use service::sales_person::MockSalesPersonService;
+use service::shiftplan_report::{MockShiftplanReportService, ShiftplanReportDay};
+use service::time_entry::{actual_hours_per_day, TimeEntry, TimeEntryService, TimeEntrySource};
+use service::uuid_service::MockUuidService;
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use shifty_utils::DayOfWeek;
+use time::macros::{date, datetime};
+use time::PrimitiveDateTime;
+use uuid::{uuid, Uuid};
+
+use crate::reporting::apply_actual_hours;
+use crate::test::error_test::{
+    test_conflicts, test_date_order_wrong, test_forbidden, test_overlapping_time_range_error,
+    test_validation_error,
+};
+use crate::test::reporting_phase2_fixtures::fixture_work_details_8h_mon_fri;
+use crate::time_entry::{TimeEntryServiceDeps, TimeEntryServiceImpl};
+
+/// 10:00 UTC, in Europe/Berlin (Winterzeit) 11:00 Ortszeit.
+fn now() -> PrimitiveDateTime {
+    datetime!(2026 - 03 - 02 10:00:00)
+}
+
+fn local_now() -> PrimitiveDateTime {
+    datetime!(2026 - 03 - 02 11:00:00)
+}
+
+fn sales_person_id() -> Uuid {
+    uuid!("0D0D0000-0000-0000-0000-000000000001")
+}
+
+fn other_sales_person_id() -> Uuid {
+    uuid!("0D0D0000-0000-0000-0000-000000000002")
+}
+
+fn entry_id() -> Uuid {
+    uuid!("0D0D0000-0000-0000-0000-000000000010")
+}
+
+fn booking_id() -> Uuid {
+    uuid!("0D0D0000-0000-0000-0000-000000000020")
+}
+
+fn version() -> Uuid {
+    uuid!("0D0D0000-0000-0000-0000-0000000000AA")
+}
+
+fn new_version() -> Uuid {
+    uuid!("0D0D0000-0000-0000-0000-0000000000FF")
+}
+
+fn entity(start: PrimitiveDateTime, end: Option<PrimitiveDateTime>) -> TimeEntryEntity {
+    TimeEntryEntity {
+        id: entry_id(),
+        sales_person_id: sales_person_id(),
+        booking_id: None,
+        start,
+        end,
+        description: "".into(),
+        source: TimeEntrySourceEntity::Clock,
+        created: start,
+        created_by: "DEVUSER".into(),
+        deleted: None,
+        version: version(),
+    }
+}
+
+fn new_entry(start: PrimitiveDateTime, end: Option<PrimitiveDateTime>) -> TimeEntry {
+    TimeEntry {
+        id: Uuid::nil(),
+        sales_person_id: sales_person_id(),
+        booking_id: None,
+        start,
+        end,
+        description: "Inventur".into(),
+        source: TimeEntrySource::Manual,
+        created: None,
+        created_by: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+fn booking(sales_person_id: Uuid) -> Booking {
+    Booking {
+        id: booking_id(),
+        sales_person_id,
+        slot_id: Uuid::nil(),
+        calendar_week: 10,
+        year: 2026,
+        created: Some(datetime!(2026 - 02 - 20 08:00:00)),
+        deleted: None,
+        created_by: Some("DEVUSER".into()),
+        deleted_by: None,
+        version: version(),
+    }
+}
+
+pub struct TimeEntryServiceDependencies {
+    pub time_entry_dao: MockTimeEntryDao,
+    pub booking_service: MockBookingService,
+    pub sales_person_service: MockSalesPersonService,
+    pub shiftplan_report_service: MockShiftplanReportService,
+    pub permission_service: MockPermissionService,
+    pub config_service: MockConfigService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub carryover_service: MockCarryoverService,
+}
+
+impl TimeEntryServiceDeps for TimeEntryServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type TimeEntryDao = MockTimeEntryDao;
+    type BookingService = MockBookingService;
+    type SalesPersonService = MockSalesPersonService;
+    type ShiftplanReportService = MockShiftplanReportService;
+    type PermissionService = MockPermissionService;
+    type ConfigService = MockConfigService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type CarryoverService = MockCarryoverService;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl TimeEntryServiceDependencies {
+    pub fn build_service(self) -> TimeEntryServiceImpl<TimeEntryServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        TimeEntryServiceImpl {
+            time_entry_dao: Arc::new(self.time_entry_dao),
+            booking_service: Arc::new(self.booking_service),
+            sales_person_service: Arc::new(self.sales_person_service),
+            shiftplan_report_service: Arc::new(self.shiftplan_report_service),
+            permission_service: Arc::new(self.permission_service),
+            config_service: Arc::new(self.config_service),
+            clock_service: Arc::new(self.clock_service),
+            uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
+            carryover_service: Arc::new(self.carryover_service),
+            transaction_dao: Arc::new(transaction_dao),
+        }
+    }
+}
+
+/// HR-Checks gelingen nur mit `Authentication::Full`; der Kontext `()` ist
+/// der Mitarbeiter `sales_person_id()` selbst.
+fn build_dependencies() -> TimeEntryServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+    permission_service
+        .expect_current_user_id()
+        .returning(|_| Ok(Some("DEVUSER".into())));
+
+    let mut sales_person_service = MockSalesPersonService::new();
+    sales_person_service
+        .expect_verify_user_is_sales_person()
+        .returning(|id, _, _| {
+            if id == sales_person_id() {
+                Ok(())
+            } else {
+                Err(ServiceError::Forbidden)
+            }
+        });
+
+    let mut config_service = MockConfigService::new();
+    config_service.expect_get_config().returning(|| {
+        Ok(Config {
+            timezone: "Europe/Berlin".into(),
+            ical_label: "Shifty".into(),
+            app_url: "".into(),
+            datev_consultant_number: None,
+            datev_client_number: None,
+        })
+    });
+
+    let mut clock_service = MockClockService::new();
+    clock_service.expect_date_time_now().returning(now);
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| new_version());
+
+    let mut time_entry_dao = MockTimeEntryDao::new();
+    time_entry_dao
+        .expect_find_open_by_sales_person()
+        .returning(|_, _| Ok(None));
+    time_entry_dao
+        .expect_find_by_sales_person_and_range()
+        .returning(|_, _, _, _| Ok(Arc::new([])));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut carryover_service = MockCarryoverService::new();
+    carryover_service
+        .expect_mark_dirty()
+        .returning(|_, _, _, _| Ok(()));
+
+    TimeEntryServiceDependencies {
+        time_entry_dao,
+        booking_service: MockBookingService::new(),
+        sales_person_service,
+        shiftplan_report_service: MockShiftplanReportService::new(),
+        permission_service,
+        config_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        carryover_service,
+    }
+}
+
+// --- Stempeluhr -----------------------------------------------------------------
+
+#[tokio::test]
+async fn test_clock_in_uses_local_time() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .time_entry_dao
+        .expect_create()
+        .withf(|entity, process, _| {
+            entity.start == local_now()
+                && entity.end.is_none()
+                && entity.source == TimeEntrySourceEntity::Clock
+                && entity.created == now()
+                && process == "time-entry-service"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let entry = service
+        .clock_in(sales_person_id(), None, ().into(), None)
+        .await
+        .unwrap();
+    assert!(entry.is_open());
+    assert_eq!(entry.start, local_now());
+    assert_eq!(entry.created_by.as_deref(), Some("DEVUSER"));
+}
+
+#[tokio::test]
+async fn test_clock_in_forbidden_for_other_sales_person() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .clock_in(other_sales_person_id(), None, ().into(), None)
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_clock_in_twice() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.checkpoint();
+    dependencies
+        .time_entry_dao
+        .expect_find_open_by_sales_person()
+        .with(eq(sales_person_id()), always())
+        .returning(|_, _| Ok(Some(entity(datetime!(2026 - 03 - 02 08:00:00), None))));
+    dependencies.time_entry_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .clock_in(sales_person_id(), None, ().into(), None)
+        .await;
+    test_overlapping_time_range_error(&result);
+}
+
+#[tokio::test]
+async fn test_clock_in_with_foreign_booking() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .booking_service
+        .expect_get()
+        .with(eq(booking_id()), eq(Authentication::Full), always())
+        .returning(|_, _, _| Ok(booking(other_sales_person_id())));
+    dependencies.time_entry_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .clock_in(sales_person_id(), Some(booking_id()), ().into(), None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::IdDoesNotExist("booking_id".into(), booking_id()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_clock_out_closes_open_entry() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.checkpoint();
+    dependencies
+        .time_entry_dao
+        .expect_find_open_by_sales_person()
+        .returning(|_, _| Ok(Some(entity(datetime!(2026 - 03 - 02 07:30:00), None))));
+    dependencies
+        .time_entry_dao
+        .expect_update()
+        .withf(|entity, _, _| entity.end == Some(local_now()) && entity.version == new_version())
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let entry = service
+        .clock_out(sales_person_id(), ().into(), None)
+        .await
+        .unwrap();
+    assert_eq!(entry.hours(), 3.5);
+}
+
+#[tokio::test]
+async fn test_clock_out_without_open_entry() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.expect_update().never();
+    let service = dependencies.build_service();
+
+    let result = service.clock_out(sales_person_id(), ().into(), None).await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::EntityNotFoundGeneric(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_clock_out_marks_carryover_dirty() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.checkpoint();
+    dependencies
+        .time_entry_dao
+        .expect_find_open_by_sales_person()
+        .returning(|_, _| Ok(Some(entity(datetime!(2026 - 03 - 02 07:30:00), None))));
+    dependencies
+        .time_entry_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.carryover_service.checkpoint();
+    dependencies
+        .carryover_service
+        .expect_mark_dirty()
+        .with(eq(sales_person_id()), eq(2026), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .clock_out(sales_person_id(), ().into(), None)
+        .await
+        .unwrap();
+}
+
+// --- Nachtragen und Korrigieren ---------------------------------------------------
+
+#[tokio::test]
+async fn test_create_forbidden_for_sales_person() {
+    let service = build_dependencies().build_service();
+    let result = service
+        .create(
+            &new_entry(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            ),
+            ().into(),
+            None,
+        )
+        .await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_create_end_before_start() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .create(
+            &new_entry(
+                datetime!(2026 - 03 - 02 12:00:00),
+                Some(datetime!(2026 - 03 - 02 08:00:00)),
+            ),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("end".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_create_overlapping() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.checkpoint();
+    dependencies
+        .time_entry_dao
+        .expect_find_open_by_sales_person()
+        .returning(|_, _| Ok(None));
+    dependencies
+        .time_entry_dao
+        .expect_find_by_sales_person_and_range()
+        .returning(|_, _, _, _| {
+            Ok(Arc::new([entity(
+                datetime!(2026 - 03 - 02 11:00:00),
+                Some(datetime!(2026 - 03 - 02 15:00:00)),
+            )]))
+        });
+    dependencies.time_entry_dao.expect_create().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .create(
+            &new_entry(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            ),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_overlapping_time_range_error(&result);
+}
+
+#[tokio::test]
+async fn test_create_adjacent_entries_do_not_overlap() {
+    let mut dependencies = build_dependencies();
+    dependencies.time_entry_dao.checkpoint();
+    dependencies
+        .time_entry_dao
+        .expect_find_open_by_sales_person()
+        .returning(|_, _| Ok(None));
+    dependencies
+        .time_entry_dao
+        .expect_find_by_sales_person_and_range()
+        .returning(|_, _, _, _| {
+            Ok(Arc::new([entity(
+                datetime!(2026 - 03 - 02 12:00:00),
+                Some(datetime!(2026 - 03 - 02 15:00:00)),
+            )]))
+        });
+    dependencies
+        .time_entry_dao
+        .expect_create()
+        .withf(|entity, _, _| {
+            entity.id == new_version()
+                && entity.source == TimeEntrySourceEntity::Manual
+                && entity.created_by.as_ref() == "DEVUSER"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let created = service
+        .create(
+            &new_entry(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            ),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.hours(), 4.0);
+}
+
+#[tokio::test]
+async fn test_update_version_conflict() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .time_entry_dao
+        .expect_find_by_id()
+        .returning(|_, _| {
+            Ok(Some(entity(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            )))
+        });
+    dependencies.time_entry_dao.expect_update().never();
+    let service = dependencies.build_service();
+
+    let stale = TimeEntry {
+        id: entry_id(),
+        version: new_version(),
+        ..new_entry(
+            datetime!(2026 - 03 - 02 08:00:00),
+            Some(datetime!(2026 - 03 - 02 11:00:00)),
+        )
+    };
+    let result = service.update(&stale, Authentication::Full, None).await;
+    test_conflicts(&result, &entry_id(), &version(), &new_version());
+}
+
+#[tokio::test]
+async fn test_update_marks_entry_manual() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .time_entry_dao
+        .expect_find_by_id()
+        .returning(|_, _| {
+            Ok(Some(entity(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            )))
+        });
+    dependencies
+        .time_entry_dao
+        .expect_update()
+        .withf(|entity, _, _| {
+            entity.end == Some(datetime!(2026 - 03 - 02 11:00:00))
+                && entity.source == TimeEntrySourceEntity::Manual
+                && entity.created_by.as_ref() == "DEVUSER"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let corrected = TimeEntry {
+        id: entry_id(),
+        version: version(),
+        ..new_entry(
+            datetime!(2026 - 03 - 02 08:00:00),
+            Some(datetime!(2026 - 03 - 02 11:00:00)),
+        )
+    };
+    let updated = service
+        .update(&corrected, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(updated.version, new_version());
+    assert_eq!(updated.source, TimeEntrySource::Manual);
+}
+
+#[tokio::test]
+async fn test_create_marks_carryover_dirty() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .time_entry_dao
+        .expect_create()
+        .returning(|_, _, _| Ok(()));
+    dependencies.carryover_service.checkpoint();
+    dependencies
+        .carryover_service
+        .expect_mark_dirty()
+        .with(eq(sales_person_id()), eq(2026), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .create(
+            &new_entry(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            ),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_update_marks_earlier_year_dirty() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .time_entry_dao
+        .expect_find_by_id()
+        .returning(|_, _| {
+            Ok(Some(entity(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            )))
+        });
+    dependencies
+        .time_entry_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.carryover_service.checkpoint();
+    dependencies
+        .carryover_service
+        .expect_mark_dirty()
+        .with(eq(sales_person_id()), eq(2025), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let moved = TimeEntry {
+        id: entry_id(),
+        version: version(),
+        ..new_entry(
+            datetime!(2025 - 12 - 15 08:00:00),
+            Some(datetime!(2025 - 12 - 15 12:00:00)),
+        )
+    };
+    service
+        .update(&moved, Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_marks_carryover_dirty() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .time_entry_dao
+        .expect_find_by_id()
+        .with(eq(entry_id()), always())
+        .returning(|_, _| {
+            Ok(Some(entity(
+                datetime!(2026 - 03 - 02 08:00:00),
+                Some(datetime!(2026 - 03 - 02 12:00:00)),
+            )))
+        });
+    dependencies
+        .time_entry_dao
+        .expect_update()
+        .withf(|entity, _, _| entity.deleted == Some(now()))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies.carryover_service.checkpoint();
+    dependencies
+        .carryover_service
+        .expect_mark_dirty()
+        .with(eq(sales_person_id()), eq(2026), always(), always())
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .delete(entry_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+// --- Soll/Ist-Vergleich -----------------------------------------------------------
+
+#[tokio::test]
+async fn test_reconcile_per_day() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .shiftplan_report_service
+        .expect_extract_shiftplan_report()
+        .returning(|sales_person_id, _, _, _, _| {
+            Ok(Arc::new([ShiftplanReportDay {
+                sales_person_id,
+                hours: 4.0,
+                year: 2026,
+                calendar_week: 10,
+                day_of_week: DayOfWeek::Monday,
+            }]))
+        });
+    dependencies.time_entry_dao.checkpoint();
+    dependencies
+        .time_entry_dao
+        .expect_find_by_sales_person_and_range()
+        .with(
+            eq(sales_person_id()),
+            eq(datetime!(2026 - 03 - 02 00:00:00)),
+            eq(datetime!(2026 - 03 - 05 00:00:00)),
+            always(),
+        )
+        .returning(|_, _, _, _| {
+            Ok(Arc::new([
+                entity(
+                    datetime!(2026 - 03 - 02 08:00:00),
+                    Some(datetime!(2026 - 03 - 02 12:30:00)),
+                ),
+                entity(datetime!(2026 - 03 - 04 08:00:00), None),
+            ]))
+        });
+    let service = dependencies.build_service();
+
+    let days = service
+        .reconcile(
+            sales_person_id(),
+            date!(2026 - 03 - 02),
+            date!(2026 - 03 - 04),
+            ().into(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[0].date, date!(2026 - 03 - 02));
+    assert_eq!(days[0].planned_hours, 4.0);
+    assert_eq!(days[0].actual_hours, 4.5);
+    assert_eq!(days[0].difference(), 0.5);
+    assert_eq!(days[1].planned_hours, 0.0);
+    assert_eq!(days[1].actual_hours, 0.0);
+    assert!(!days[1].has_open_entry);
+    assert!(days[2].has_open_entry);
+    assert_eq!(days[2].actual_hours, 0.0);
+}
+
+#[tokio::test]
+async fn test_reconcile_date_order_wrong() {
+    let service = build_dependencies().build_service();
+    let result = service
+        .reconcile(
+            sales_person_id(),
+            date!(2026 - 03 - 04),
+            date!(2026 - 03 - 02),
+            ().into(),
+            None,
+        )
+        .await;
+    test_date_order_wrong(&result);
+}
+
+// --- Reporting --------------------------------------------------------------------
+
+#[test]
+fn test_actual_hours_per_day_sums_closed_entries() {
+    let entries = [
+        TimeEntry::from(&entity(
+            datetime!(2024 - 06 - 03 08:00:00),
+            Some(datetime!(2024 - 06 - 03 12:00:00)),
+        )),
+        TimeEntry::from(&entity(
+            datetime!(2024 - 06 - 03 13:00:00),
+            Some(datetime!(2024 - 06 - 03 14:30:00)),
+        )),
+        TimeEntry::from(&entity(datetime!(2024 - 06 - 04 08:00:00), None)),
+    ];
+    let days = actual_hours_per_day(&entries);
+    assert_eq!(days.len(), 1);
+    assert_eq!(days[0].hours, 5.5);
+    assert_eq!(days[0].year, 2024);
+    assert_eq!(days[0].calendar_week, 23);
+    assert_eq!(days[0].day_of_week, DayOfWeek::Monday);
+}
+
+#[test]
+fn test_apply_actual_hours_only_for_flagged_contracts() {
+    let report_day = |calendar_week: u8, hours: f32| ShiftplanReportDay {
+        sales_person_id: fixture_work_details_8h_mon_fri().sales_person_id,
+        hours,
+        year: 2024,
+        calendar_week,
+        day_of_week: DayOfWeek::Monday,
+    };
+    let planned = [report_day(23, 8.0), report_day(26, 8.0)];
+    let actual = [report_day(23, 7.5), report_day(26, 6.0)];
+    let working_hours: Arc<[EmployeeWorkDetails]> = Arc::new([EmployeeWorkDetails {
+        use_actual_hours: true,
+        ..fixture_work_details_8h_mon_fri()
+    }]);
+
+    let merged = apply_actual_hours(&planned, &actual, &working_hours);
+    // KW 23 liegt im Vertrag mit `use_actual_hours`, KW 26 nicht mehr.
+    let mut hours: Vec<(u8, f32)> = merged
+        .iter()
+        .map(|day| (day.calendar_week, day.hours))
+        .collect();
+    hours.sort_by_key(|(calendar_week, _)| *calendar_week);
+    assert_eq!(hours, vec![(23, 7.5), (26, 8.0)]);
+
+    let unflagged = apply_actual_hours(&planned, &actual, &[fixture_work_details_8h_mon_fri()]);
+    assert_eq!(unflagged.as_ref(), planned.as_slice());
+}
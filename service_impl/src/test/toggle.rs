@@ -4,6 +4,7 @@ use crate::toggle::{ToggleServiceDeps, ToggleServiceImpl};
 use dao::toggle::{MockToggleDao, ToggleEntity};
 use dao::{MockTransaction, MockTransactionDao};
 use mockall::predicate::{always, eq};
+use service::audit_log::{entity_type, AuditAction, MockAuditLogService};
 use service::permission::{Authentication, MockPermissionService};
 use service::toggle::{Toggle, ToggleGroup, ToggleService, TOGGLE_ADMIN_PRIVILEGE};
 use service::ServiceError;
@@ -12,6 +13,7 @@ use service::ServiceError;
 pub struct ToggleServiceDependencies {
     pub toggle_dao: MockToggleDao,
     pub permission_service: MockPermissionService,
+    pub audit_log_service: MockAuditLogService,
 }
 
 impl ToggleServiceDeps for ToggleServiceDependencies {
@@ -19,6 +21,7 @@ impl ToggleServiceDeps for ToggleServiceDependencies {
     type Transaction = MockTransaction;
     type ToggleDao = MockToggleDao;
     type PermissionService = MockPermissionService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -33,15 +36,22 @@ impl ToggleServiceDependencies {
         ToggleServiceImpl {
             toggle_dao: self.toggle_dao.into(),
             permission_service: Arc::new(self.permission_service),
+            audit_log_service: Arc::new(self.audit_log_service),
             transaction_dao: Arc::new(transaction_dao),
         }
     }
 }
 
 fn build_dependencies() -> ToggleServiceDependencies {
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     ToggleServiceDependencies {
         toggle_dao: MockToggleDao::new(),
         permission_service: MockPermissionService::new(),
+        audit_log_service,
     }
 }
 
@@ -272,6 +282,10 @@ async fn test_disable_toggle_success() {
 async fn test_delete_toggle_success() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_toggle_admin_permission_service();
+    deps.toggle_dao
+        .expect_get_toggle()
+        .with(eq("test_toggle"), always())
+        .returning(|_, _| Ok(Some(default_toggle_entity())));
     deps.toggle_dao
         .expect_delete_toggle()
         .with(eq("test_toggle"), eq("toggle-service"), always())
@@ -303,6 +317,10 @@ async fn test_create_toggle_group_success() {
 async fn test_delete_toggle_group_success() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_toggle_admin_permission_service();
+    deps.toggle_dao
+        .expect_get_toggle_group()
+        .with(eq("test_group"), always())
+        .returning(|_, _| Ok(Some((&default_toggle_group()).into())));
     deps.toggle_dao
         .expect_delete_toggle_group()
         .with(eq("test_group"), eq("toggle-service"), always())
@@ -319,6 +337,10 @@ async fn test_delete_toggle_group_success() {
 async fn test_add_toggle_to_group_success() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_toggle_admin_permission_service();
+    deps.toggle_dao
+        .expect_get_toggles_in_group()
+        .with(eq("test_group"), always())
+        .returning(|_, _| Ok(Arc::new([default_toggle_entity()])));
     deps.toggle_dao
         .expect_add_toggle_to_group()
         .with(
@@ -340,6 +362,10 @@ async fn test_add_toggle_to_group_success() {
 async fn test_remove_toggle_from_group_success() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_toggle_admin_permission_service();
+    deps.toggle_dao
+        .expect_get_toggles_in_group()
+        .with(eq("test_group"), always())
+        .returning(|_, _| Ok(Arc::new([default_toggle_entity()])));
     deps.toggle_dao
         .expect_remove_toggle_from_group()
         .with(
@@ -361,6 +387,25 @@ async fn test_remove_toggle_from_group_success() {
 async fn test_enable_group_enables_all_toggles_in_group() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_toggle_admin_permission_service();
+    deps.toggle_dao
+        .expect_get_toggles_in_group()
+        .with(eq("test_group"), always())
+        .returning(|_, _| {
+            Ok(Arc::new([ToggleEntity {
+                enabled: false,
+                ..default_toggle_entity()
+            }]))
+        });
+    deps.audit_log_service = MockAuditLogService::new();
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == entity_type::TOGGLE
+                && &*change.entity_id == "test_toggle"
+                && change.action == AuditAction::Update
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
     deps.toggle_dao
         .expect_enable_group()
         .with(eq("test_group"), eq("toggle-service"), always())
@@ -375,6 +420,10 @@ async fn test_enable_group_enables_all_toggles_in_group() {
 async fn test_disable_group_disables_all_toggles_in_group() {
     let mut deps = build_dependencies();
     deps.permission_service = mock_toggle_admin_permission_service();
+    deps.toggle_dao
+        .expect_get_toggles_in_group()
+        .with(eq("test_group"), always())
+        .returning(|_, _| Ok(Arc::new([default_toggle_entity()])));
     deps.toggle_dao
         .expect_disable_group()
         .with(eq("test_group"), eq("toggle-service"), always())
@@ -404,6 +453,10 @@ async fn test_toggle_value_roundtrip() {
     {
         let mut deps = build_dependencies();
         deps.permission_service = mock_toggle_admin_permission_service();
+        deps.toggle_dao
+            .expect_get_toggle()
+            .with(eq("holiday_auto_credit"), always())
+            .returning(|_, _| Ok(None));
         deps.toggle_dao
             .expect_set_toggle_value()
             .with(
@@ -451,6 +504,10 @@ async fn test_toggle_value_roundtrip() {
     {
         let mut deps = build_dependencies();
         deps.permission_service = mock_toggle_admin_permission_service();
+        deps.toggle_dao
+            .expect_get_toggle()
+            .with(eq("holiday_auto_credit"), always())
+            .returning(|_, _| Ok(None));
         deps.toggle_dao
             .expect_set_toggle_value()
             .with(
@@ -509,6 +566,58 @@ async fn test_toggle_value_roundtrip() {
     }
 }
 
+/// Der DAO schaltet mit dem Wert auch `enabled`; das Audit-Protokoll zeigt
+/// den tatsächlich gespeicherten Zustand.
+#[tokio::test]
+async fn test_set_toggle_value_audits_persisted_toggle() {
+    let mut deps = build_dependencies();
+    deps.permission_service = mock_toggle_admin_permission_service();
+    let mut call = 0;
+    deps.toggle_dao
+        .expect_get_toggle()
+        .with(eq("test_toggle"), always())
+        .times(2)
+        .returning(move |_, _| {
+            call += 1;
+            Ok(Some(if call == 1 {
+                ToggleEntity {
+                    enabled: false,
+                    ..default_toggle_entity()
+                }
+            } else {
+                ToggleEntity {
+                    value: Some("2026-01-01".to_string()),
+                    ..default_toggle_entity()
+                }
+            }))
+        });
+    deps.toggle_dao
+        .expect_set_toggle_value()
+        .returning(|_, _, _, _| Ok(()));
+    deps.audit_log_service = MockAuditLogService::new();
+    deps.audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            let after = change.after.as_deref().unwrap_or_default();
+            change.action == AuditAction::Update
+                && after.contains(r#""enabled":true"#)
+                && after.contains("2026-01-01")
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = deps.build_service();
+    service
+        .set_toggle_value(
+            "test_toggle",
+            Some("2026-01-01".to_string()),
+            ().auth(),
+            None,
+        )
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn test_set_toggle_value_requires_toggle_admin_privilege() {
     let mut deps = build_dependencies();
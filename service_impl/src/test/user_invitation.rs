@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use dao::user_invitation::{MockUserInvitationDao, UserInvitationEntity};
+use dao::{MockPermissionDao, MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::audit_log::{entity_type, AuditAction, MockAuditLogService};
+use service::notification::MockNotificationService;
+use service::session::MockSessionService;
+use service::user_invitation::UserInvitationService;
+use service::uuid_service::MockUuidService;
+use service::MockPermissionService;
+use time::{Duration, OffsetDateTime};
+use uuid::{uuid, Uuid};
+
+use crate::user_invitation::{UserInvitationServiceDeps, UserInvitationServiceImpl};
+
+pub struct UserInvitationServiceDependencies {
+    pub user_invitation_dao: MockUserInvitationDao,
+    pub permission_dao: MockPermissionDao,
+    pub permission_service: MockPermissionService,
+    pub uuid_service: MockUuidService,
+    pub notification_service: MockNotificationService,
+    pub audit_log_service: MockAuditLogService,
+}
+
+impl UserInvitationServiceDeps for UserInvitationServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type UserInvitationDao = MockUserInvitationDao;
+    type PermissionDao = MockPermissionDao;
+    type PermissionService = MockPermissionService;
+    type SessionService = MockSessionService;
+    type UuidService = MockUuidService;
+    type TransactionDao = MockTransactionDao;
+    type NotificationService = MockNotificationService;
+    type AuditLogService = MockAuditLogService;
+}
+
+impl UserInvitationServiceDependencies {
+    fn build_service(self) -> UserInvitationServiceImpl<UserInvitationServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        UserInvitationServiceImpl {
+            user_invitation_dao: self.user_invitation_dao.into(),
+            permission_dao: self.permission_dao.into(),
+            permission_service: self.permission_service.into(),
+            session_service: MockSessionService::new().into(),
+            uuid_service: self.uuid_service.into(),
+            transaction_dao: transaction_dao.into(),
+            notification_service: self.notification_service.into(),
+            audit_log_service: self.audit_log_service.into(),
+        }
+    }
+}
+
+fn invitation_id() -> Uuid {
+    uuid!("1A000000-0000-0000-0000-000000000001")
+}
+
+fn token() -> Uuid {
+    uuid!("1A000000-0000-0000-0000-0000000000F1")
+}
+
+fn invitation_entity() -> UserInvitationEntity {
+    let now = OffsetDateTime::now_utc();
+    UserInvitationEntity {
+        id: invitation_id(),
+        username: "anna".into(),
+        token: token(),
+        expiration_date: now + Duration::hours(24),
+        created_date: now,
+        update_process: "user-invitation-service".into(),
+        redeemed_at: None,
+        session_id: None,
+        session_revoked_at: None,
+    }
+}
+
+fn build_dependencies() -> UserInvitationServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .with(eq("admin"), always())
+        .returning(|_, _| Ok(()));
+
+    UserInvitationServiceDependencies {
+        user_invitation_dao: MockUserInvitationDao::new(),
+        permission_dao: MockPermissionDao::new(),
+        permission_service,
+        uuid_service: MockUuidService::new(),
+        notification_service: MockNotificationService::new(),
+        audit_log_service: MockAuditLogService::new(),
+    }
+}
+
+/// Erwartet genau einen Audit-Eintrag; Snapshots dürfen das Token nicht
+/// enthalten.
+fn expect_audit(
+    deps: &mut UserInvitationServiceDependencies,
+    entity_type: &'static str,
+    action: AuditAction,
+) {
+    let secret = token().to_string();
+    deps.audit_log_service
+        .expect_record()
+        .withf(move |change, process, _, tx| {
+            change.entity_type == entity_type
+                && change.action == action
+                && [&change.before, &change.after]
+                    .iter()
+                    .all(|snapshot| !snapshot.as_deref().unwrap_or("").contains(&secret))
+                && process == "user-invitation-service"
+                && tx.is_some()
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+}
+
+#[tokio::test]
+async fn test_generate_invitation_is_audited() {
+    let mut deps = build_dependencies();
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("user-invitation-id"))
+        .returning(|_| invitation_id());
+    deps.uuid_service
+        .expect_new_uuid()
+        .with(eq("user-invitation-token"))
+        .returning(|_| token());
+    deps.user_invitation_dao
+        .expect_create_invitation()
+        .times(1)
+        .returning(|_| Ok(()));
+    deps.notification_service
+        .expect_notify()
+        .returning(|_, _| Ok(()));
+    expect_audit(&mut deps, entity_type::USER_INVITATION, AuditAction::Create);
+    let service = deps.build_service();
+
+    let invitation = service
+        .generate_invitation("anna", None, 24, None, ().into())
+        .await
+        .unwrap();
+
+    assert_eq!(invitation.id, invitation_id());
+    assert_eq!(invitation.token, token());
+}
+
+#[tokio::test]
+async fn test_revoke_invitation_is_audited() {
+    let mut deps = build_dependencies();
+    deps.user_invitation_dao
+        .expect_find_by_id()
+        .with(eq(invitation_id()))
+        .returning(|_| Ok(Some(invitation_entity())));
+    deps.user_invitation_dao
+        .expect_delete_by_id()
+        .with(eq(invitation_id()))
+        .times(1)
+        .returning(|_| Ok(()));
+    expect_audit(&mut deps, entity_type::USER_INVITATION, AuditAction::Delete);
+    let service = deps.build_service();
+
+    service
+        .revoke_invitation(&invitation_id(), None, ().into())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_mark_token_redeemed_is_audited() {
+    let mut deps = build_dependencies();
+    let mut redeemed = false;
+    deps.user_invitation_dao
+        .expect_find_by_token()
+        .with(eq(token()))
+        .times(2)
+        .returning(move |_| {
+            let entity = if redeemed {
+                UserInvitationEntity {
+                    redeemed_at: Some(OffsetDateTime::now_utc()),
+                    session_id: Some("session-1".into()),
+                    ..invitation_entity()
+                }
+            } else {
+                invitation_entity()
+            };
+            redeemed = true;
+            Ok(Some(entity))
+        });
+    deps.user_invitation_dao
+        .expect_mark_as_redeemed()
+        .with(eq(token()), eq("session-1"))
+        .times(1)
+        .returning(|_, _| Ok(()));
+    expect_audit(&mut deps, entity_type::USER_INVITATION, AuditAction::Update);
+    let service = deps.build_service();
+
+    service
+        .mark_token_redeemed(&token(), "session-1", None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_validate_token_audits_created_user() {
+    let mut deps = build_dependencies();
+    deps.user_invitation_dao
+        .expect_find_by_token()
+        .returning(|_| Ok(Some(invitation_entity())));
+    deps.permission_dao
+        .expect_find_user()
+        .returning(|_| Ok(None));
+    deps.permission_dao
+        .expect_create_user()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    expect_audit(&mut deps, entity_type::USER, AuditAction::Create);
+    let service = deps.build_service();
+
+    let username = service
+        .validate_and_consume_token(&token(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(username, Arc::from("anna"));
+}
+
+#[tokio::test]
+async fn test_revoke_invitation_forbidden_is_not_audited() {
+    let mut deps = build_dependencies();
+    deps.permission_service.checkpoint();
+    deps.permission_service
+        .expect_check_permission()
+        .returning(|_, _| Err(service::ServiceError::Forbidden));
+    deps.user_invitation_dao.expect_delete_by_id().never();
+    deps.audit_log_service.expect_record().never();
+    let service = deps.build_service();
+
+    let result = service
+        .revoke_invitation(&invitation_id(), None, ().into())
+        .await;
+
+    assert!(matches!(result, Err(service::ServiceError::Forbidden)));
+}
@@ -105,6 +105,7 @@ fn contract(
         is_dynamic: false,
         cap_planned_hours_to_expected: false,
         committed_voluntary: 0.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -126,6 +127,7 @@ fn paid_sales_person(id: Uuid, name: &str) -> SalesPerson {
         background_color: "#888888".into(),
         is_paid: Some(true),
         inactive: false,
+        personnel_number: None,
         deleted: None,
         version: uuid!("EE000000-0000-0000-0000-000000000001"),
     }
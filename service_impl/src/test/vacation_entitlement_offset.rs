@@ -8,6 +8,7 @@ use dao::{
 };
 use mockall::predicate::{always, eq};
 use service::{
+    audit_log::MockAuditLogService,
     clock::MockClockService,
     permission::Authentication,
     uuid_service::MockUuidService,
@@ -26,6 +27,7 @@ pub struct VacationEntitlementOffsetServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
 }
 
@@ -36,6 +38,7 @@ impl VacationEntitlementOffsetServiceDeps for VacationEntitlementOffsetServiceDe
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
 }
 
@@ -48,6 +51,7 @@ impl VacationEntitlementOffsetServiceDependencies {
             permission_service: Arc::new(self.permission_service),
             clock_service: Arc::new(self.clock_service),
             uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
             transaction_dao: Arc::new(self.transaction_dao),
         }
     }
@@ -87,11 +91,17 @@ pub fn build_dependencies(
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     VacationEntitlementOffsetServiceDependencies {
         vacation_entitlement_offset_dao,
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
     }
 }
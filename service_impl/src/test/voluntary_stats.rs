@@ -25,7 +25,7 @@ use std::sync::Arc;
 use time::macros::{date, datetime};
 use uuid::Uuid;
 
-use service::absence::{AbsenceCategory, AbsencePeriod, DayFraction};
+use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction};
 use service::employee_work_details::EmployeeWorkDetails;
 use shifty_utils::{DayOfWeek, ShiftyDate};
 
@@ -53,6 +53,7 @@ fn make_absence(
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     }
 }
 
@@ -79,6 +80,7 @@ fn make_working_hours(
         is_dynamic: false,
         cap_planned_hours_to_expected: false,
         committed_voluntary,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
@@ -127,6 +129,7 @@ fn f2_soll_prorata_midweek_change_d_f2_01() {
         to_calendar_week: 10,
         to_year: 2026,
         committed_voluntary: 1.0,
+        use_actual_hours: false,
         ..make_working_hours(sp_id, (2026, 10), (2026, 10), 40.0, 1.0)
     };
     // Vertrag B: KW 10 Mi..=So, committed=2.0
@@ -138,6 +141,7 @@ fn f2_soll_prorata_midweek_change_d_f2_01() {
         to_calendar_week: 10,
         to_year: 2026,
         committed_voluntary: 2.0,
+        use_actual_hours: false,
         ..make_working_hours(sp_id, (2026, 10), (2026, 10), 40.0, 2.0)
     };
     let wh = vec![wh_a, wh_b];
@@ -525,6 +529,7 @@ mod service_tests {
             background_color: Arc::from("#123456"),
             is_paid: Some(false),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }
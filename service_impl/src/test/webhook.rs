@@ -0,0 +1,676 @@
+use std::sync::Arc;
+
+use dao::webhook::{
+    MockWebhookDao, WebhookDeliveryEntity, WebhookDeliveryState as WebhookDeliveryStateEntity,
+    WebhookEventType as WebhookEventTypeEntity, WebhookSubscriptionEntity,
+};
+use dao::{MockTransaction, MockTransactionDao};
+use mockall::predicate::{always, eq};
+use service::audit_log::{AuditAction, MockAuditLogService};
+use service::clock::MockClockService;
+use service::permission::Authentication;
+use service::uuid_service::MockUuidService;
+use service::webhook::{WebhookEvent, WebhookEventType, WebhookService, WebhookSubscription};
+use service::week_status::WeekStatus;
+use service::{MockPermissionService, ServiceError, ValidationFailureItem};
+use time::macros::{date, datetime};
+use time::PrimitiveDateTime;
+use uuid::{uuid, Uuid};
+
+use crate::test::error_test::{test_conflicts, test_forbidden, test_validation_error};
+use crate::webhook::{
+    event_payload, WebhookServiceDeps, WebhookServiceImpl, MAX_DELIVERY_ATTEMPTS,
+};
+use crate::webhook_client::{sign, MockWebhookSender, WebhookError};
+
+fn now() -> PrimitiveDateTime {
+    datetime!(2026 - 03 - 15 10:00:00)
+}
+
+fn subscription_id() -> Uuid {
+    uuid!("0C0C0000-0000-0000-0000-000000000001")
+}
+
+fn delivery_id() -> Uuid {
+    uuid!("0C0C0000-0000-0000-0000-000000000002")
+}
+
+fn version() -> Uuid {
+    uuid!("0C0C0000-0000-0000-0000-0000000000AA")
+}
+
+fn new_version() -> Uuid {
+    uuid!("0C0C0000-0000-0000-0000-0000000000FF")
+}
+
+fn subscription(
+    id: Uuid,
+    active: bool,
+    event_types: &[WebhookEventTypeEntity],
+) -> WebhookSubscriptionEntity {
+    WebhookSubscriptionEntity {
+        id,
+        url: "https://hooks.example.com/shifty".into(),
+        secret: "s3cret".into(),
+        description: "Lohnbuchhaltung".into(),
+        event_types: event_types.into(),
+        active,
+        created: datetime!(2026 - 01 - 01 12:00:00),
+        deleted: None,
+        version: version(),
+    }
+}
+
+fn pending_delivery(attempts: u32) -> WebhookDeliveryEntity {
+    WebhookDeliveryEntity {
+        id: delivery_id(),
+        subscription_id: subscription_id(),
+        event_type: WebhookEventTypeEntity::WeekStatusChanged,
+        payload: r#"{"event":"week_status.changed"}"#.into(),
+        state: WebhookDeliveryStateEntity::Pending,
+        attempts,
+        next_attempt: now(),
+        last_status: None,
+        last_error: None,
+        created: now(),
+        delivered: None,
+        version: version(),
+    }
+}
+
+fn week_status_event() -> WebhookEvent {
+    WebhookEvent::WeekStatusChanged {
+        year: 2026,
+        calendar_week: 11,
+        status: WeekStatus::Planned,
+    }
+}
+
+pub struct WebhookServiceDependencies {
+    pub webhook_dao: MockWebhookDao,
+    pub permission_service: MockPermissionService,
+    pub clock_service: MockClockService,
+    pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
+    pub webhook_sender: MockWebhookSender,
+}
+
+impl WebhookServiceDeps for WebhookServiceDependencies {
+    type Context = ();
+    type Transaction = MockTransaction;
+    type WebhookDao = MockWebhookDao;
+    type PermissionService = MockPermissionService;
+    type ClockService = MockClockService;
+    type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
+    type WebhookSender = MockWebhookSender;
+    type TransactionDao = MockTransactionDao;
+}
+
+impl WebhookServiceDependencies {
+    pub fn build_service(self) -> WebhookServiceImpl<WebhookServiceDependencies> {
+        let mut transaction_dao = MockTransactionDao::new();
+        transaction_dao
+            .expect_use_transaction()
+            .returning(|_| Ok(MockTransaction));
+        transaction_dao.expect_commit().returning(|_| Ok(()));
+
+        WebhookServiceImpl {
+            webhook_dao: Arc::new(self.webhook_dao),
+            permission_service: Arc::new(self.permission_service),
+            clock_service: Arc::new(self.clock_service),
+            uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
+            webhook_sender: Arc::new(self.webhook_sender),
+            transaction_dao: Arc::new(transaction_dao),
+        }
+    }
+}
+
+/// Alle Permission-Checks schlagen fehl, außer bei `Authentication::Full`.
+fn build_dependencies() -> WebhookServiceDependencies {
+    let mut permission_service = MockPermissionService::new();
+    permission_service
+        .expect_check_permission()
+        .returning(|_, context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+    permission_service
+        .expect_check_only_full_authentication()
+        .returning(|context| match context {
+            Authentication::Full => Ok(()),
+            _ => Err(ServiceError::Forbidden),
+        });
+
+    let mut clock_service = MockClockService::new();
+    clock_service.expect_date_time_now().returning(now);
+
+    let mut uuid_service = MockUuidService::new();
+    uuid_service.expect_new_uuid().returning(|_| new_version());
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
+    WebhookServiceDependencies {
+        webhook_dao: MockWebhookDao::new(),
+        permission_service,
+        clock_service,
+        uuid_service,
+        audit_log_service,
+        webhook_sender: MockWebhookSender::new(),
+    }
+}
+
+// --- publish --------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_publish_creates_delivery_for_matching_active_subscriptions() {
+    let other = uuid!("0C0C0000-0000-0000-0000-000000000011");
+    let inactive = uuid!("0C0C0000-0000-0000-0000-000000000012");
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_all_subscriptions()
+        .returning(move |_| {
+            Ok(Arc::from([
+                subscription(
+                    subscription_id(),
+                    true,
+                    &[
+                        WebhookEventTypeEntity::BookingCreated,
+                        WebhookEventTypeEntity::WeekStatusChanged,
+                    ],
+                ),
+                subscription(other, true, &[WebhookEventTypeEntity::BookingCreated]),
+                subscription(
+                    inactive,
+                    false,
+                    &[WebhookEventTypeEntity::WeekStatusChanged],
+                ),
+            ]))
+        });
+    dependencies
+        .webhook_dao
+        .expect_create_delivery()
+        .withf(|delivery, process, _| {
+            delivery.subscription_id == subscription_id()
+                && delivery.event_type == WebhookEventTypeEntity::WeekStatusChanged
+                && delivery.state == WebhookDeliveryStateEntity::Pending
+                && delivery.attempts == 0
+                && delivery.next_attempt == now()
+                && delivery
+                    .payload
+                    .contains(r#""event":"week_status.changed""#)
+                && process == "webhook-service"
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .publish(&week_status_event(), Some(MockTransaction))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_publish_without_subscribers_is_noop() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_all_subscriptions()
+        .returning(|_| Ok(Arc::from([])));
+    dependencies.webhook_dao.expect_create_delivery().never();
+    let service = dependencies.build_service();
+
+    service.publish(&week_status_event(), None).await.unwrap();
+}
+
+#[test]
+fn test_event_payload() {
+    let payload = event_payload(
+        &WebhookEvent::BillingPeriodCreated {
+            id: subscription_id(),
+            start_date: date!(2026 - 01 - 01),
+            end_date: date!(2026 - 01 - 31),
+        },
+        now(),
+    )
+    .unwrap();
+    assert_eq!(
+        payload,
+        serde_json::json!({
+            "event": "billing_period.created",
+            "occurred_at": "2026-03-15T10:00:00",
+            "data": {
+                "id": subscription_id(),
+                "start_date": "2026-01-01",
+                "end_date": "2026-01-31",
+            },
+        })
+    );
+
+    let payload = event_payload(&week_status_event(), now()).unwrap();
+    assert_eq!(payload["event"], "week_status.changed");
+    assert_eq!(payload["data"]["status"], "Planned");
+    assert_eq!(payload["data"]["calendar_week"], 11);
+}
+
+// --- Verwaltung -----------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_subscriptions_forbidden() {
+    let service = build_dependencies().build_service();
+    let result = service.get_subscriptions(().into(), None).await;
+    test_forbidden(&result);
+}
+
+fn new_subscription(url: &str, event_types: &[WebhookEventType]) -> WebhookSubscription {
+    WebhookSubscription {
+        id: Uuid::nil(),
+        url: url.into(),
+        secret: "".into(),
+        description: "".into(),
+        event_types: event_types.into(),
+        active: true,
+        created: None,
+        deleted: None,
+        version: Uuid::nil(),
+    }
+}
+
+#[tokio::test]
+async fn test_create_subscription_generates_secret() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_create_subscription()
+        .withf(|entity, _, _| entity.secret.len() == 64 && entity.deleted.is_none())
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            // Der HMAC-Schlüssel darf nicht im Audit-Log landen.
+            change.entity_type == service::audit_log::entity_type::WEBHOOK_SUBSCRIPTION
+                && change.action == AuditAction::Create
+                && !change
+                    .after
+                    .as_deref()
+                    .unwrap_or_default()
+                    .contains("secret")
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let created = service
+        .create_subscription(
+            &new_subscription(
+                " https://hooks.example.com/shifty ",
+                &[WebhookEventType::BookingCreated],
+            ),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.id, new_version());
+    assert_eq!(created.url.as_ref(), "https://hooks.example.com/shifty");
+    assert_eq!(created.secret.len(), 64);
+}
+
+#[tokio::test]
+async fn test_create_subscription_validation() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_create_subscription()
+        .never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .create_subscription(
+            &new_subscription("ftp://hooks.example.com", &[]),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("url".into()),
+        2,
+    );
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::InvalidValue("event_types".into()),
+        2,
+    );
+}
+
+#[tokio::test]
+async fn test_update_subscription_version_conflict() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_find_subscription_by_id()
+        .returning(|id, _| {
+            Ok(Some(subscription(
+                id,
+                true,
+                &[WebhookEventTypeEntity::BookingCreated],
+            )))
+        });
+    dependencies
+        .webhook_dao
+        .expect_update_subscription()
+        .never();
+    let service = dependencies.build_service();
+
+    let stale = WebhookSubscription {
+        id: subscription_id(),
+        version: new_version(),
+        ..new_subscription("https://example.com", &[WebhookEventType::BookingCreated])
+    };
+    let result = service
+        .update_subscription(&stale, Authentication::Full, None)
+        .await;
+    test_conflicts(&result, &subscription_id(), &version(), &new_version());
+}
+
+#[tokio::test]
+async fn test_update_subscription_keeps_secret() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_find_subscription_by_id()
+        .returning(|id, _| {
+            Ok(Some(subscription(
+                id,
+                true,
+                &[WebhookEventTypeEntity::BookingCreated],
+            )))
+        });
+    dependencies
+        .webhook_dao
+        .expect_update_subscription()
+        .withf(|entity, _, _| {
+            entity.secret.as_ref() == "s3cret"
+                && !entity.active
+                && entity.event_types.as_ref() == [WebhookEventTypeEntity::BookingDeleted]
+                && entity.version == new_version()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let update = WebhookSubscription {
+        id: subscription_id(),
+        version: version(),
+        active: false,
+        ..new_subscription("https://example.com", &[WebhookEventType::BookingDeleted])
+    };
+    service
+        .update_subscription(&update, Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_subscription_is_soft_delete() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_find_subscription_by_id()
+        .returning(|id, _| {
+            Ok(Some(subscription(
+                id,
+                true,
+                &[WebhookEventTypeEntity::BookingCreated],
+            )))
+        });
+    dependencies
+        .webhook_dao
+        .expect_update_subscription()
+        .withf(|entity, _, _| entity.deleted == Some(now()))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .withf(|change, _, _, _| {
+            change.entity_type == service::audit_log::entity_type::WEBHOOK_SUBSCRIPTION
+                && change.entity_id.as_ref() == subscription_id().to_string()
+                && change.action == AuditAction::Delete
+        })
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .delete_subscription(subscription_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_retry_delivery_requires_failed_state() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_find_delivery_by_id()
+        .returning(|_, _| Ok(Some(pending_delivery(2))));
+    dependencies.webhook_dao.expect_update_delivery().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .retry_delivery(delivery_id(), Authentication::Full, None)
+        .await;
+    test_validation_error(
+        &result,
+        &ValidationFailureItem::ModificationNotAllowed("state".into()),
+        1,
+    );
+}
+
+#[tokio::test]
+async fn test_retry_delivery_resets_failed() {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_find_delivery_by_id()
+        .returning(|_, _| {
+            Ok(Some(WebhookDeliveryEntity {
+                state: WebhookDeliveryStateEntity::Failed,
+                ..pending_delivery(MAX_DELIVERY_ATTEMPTS)
+            }))
+        });
+    dependencies
+        .webhook_dao
+        .expect_update_delivery()
+        .withf(|entity, _, _| {
+            entity.state == WebhookDeliveryStateEntity::Pending
+                && entity.attempts == 0
+                && entity.next_attempt == now()
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .retry_delivery(delivery_id(), Authentication::Full, None)
+        .await
+        .unwrap();
+}
+
+// --- deliver_pending ------------------------------------------------------------
+
+fn deliver_dependencies(
+    attempts: u32,
+    active: bool,
+    send_result: fn() -> Result<u16, WebhookError>,
+) -> WebhookServiceDependencies {
+    let mut dependencies = build_dependencies();
+    dependencies
+        .webhook_dao
+        .expect_find_due_deliveries()
+        .with(eq(now()), always(), always())
+        .returning(move |_, _, _| Ok(Arc::from([pending_delivery(attempts)])));
+    dependencies
+        .webhook_dao
+        .expect_find_subscription_by_id()
+        .with(eq(subscription_id()), always())
+        .returning(move |id, _| {
+            Ok(Some(subscription(
+                id,
+                active,
+                &[WebhookEventTypeEntity::WeekStatusChanged],
+            )))
+        });
+    dependencies
+        .webhook_sender
+        .expect_send()
+        .returning(move |_| send_result());
+    dependencies
+}
+
+#[tokio::test]
+async fn test_deliver_pending_forbidden() {
+    let service = build_dependencies().build_service();
+    let result = service.deliver_pending(().into()).await;
+    test_forbidden(&result);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_sends_signed_request() {
+    let mut dependencies = deliver_dependencies(0, true, || Ok(200));
+    dependencies.webhook_sender.checkpoint();
+    dependencies
+        .webhook_sender
+        .expect_send()
+        .withf(|request| {
+            let timestamp = now().assume_utc().unix_timestamp();
+            request.url.as_ref() == "https://hooks.example.com/shifty"
+                && request.event_type.as_ref() == "week_status.changed"
+                && request.delivery_id == delivery_id()
+                && request.timestamp == timestamp
+                && request.signature.as_ref()
+                    == sign("s3cret", timestamp, r#"{"event":"week_status.changed"}"#)
+        })
+        .times(1)
+        .returning(|_| Ok(200));
+    dependencies
+        .webhook_dao
+        .expect_update_delivery()
+        .withf(|entity, _, _| {
+            entity.state == WebhookDeliveryStateEntity::Delivered
+                && entity.attempts == 1
+                && entity.last_status == Some(200)
+                && entity.delivered == Some(now())
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.delivered, 1);
+    assert_eq!(report.retried, 0);
+    assert_eq!(report.failed, 0);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_schedules_retry_with_backoff() {
+    let mut dependencies = deliver_dependencies(1, true, || {
+        Err(WebhookError::Transient {
+            status: Some(503),
+            reason: "503 busy".into(),
+        })
+    });
+    dependencies
+        .webhook_dao
+        .expect_update_delivery()
+        .withf(|entity, _, _| {
+            entity.state == WebhookDeliveryStateEntity::Pending
+                && entity.attempts == 2
+                && entity.next_attempt == now() + time::Duration::minutes(2)
+                && entity.last_status == Some(503)
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.retried, 1);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_fails_after_max_attempts() {
+    let mut dependencies = deliver_dependencies(MAX_DELIVERY_ATTEMPTS - 1, true, || {
+        Err(WebhookError::Transient {
+            status: None,
+            reason: "network error".into(),
+        })
+    });
+    dependencies
+        .webhook_dao
+        .expect_update_delivery()
+        .withf(|entity, _, _| {
+            entity.state == WebhookDeliveryStateEntity::Failed
+                && entity.attempts == MAX_DELIVERY_ATTEMPTS
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.failed, 1);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_client_error_fails_immediately() {
+    let mut dependencies = deliver_dependencies(0, true, || {
+        Err(WebhookError::Permanent {
+            status: 410,
+            body: "gone".into(),
+        })
+    });
+    dependencies
+        .webhook_dao
+        .expect_update_delivery()
+        .withf(|entity, _, _| {
+            entity.state == WebhookDeliveryStateEntity::Failed
+                && entity.attempts == 1
+                && entity.last_status == Some(410)
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.failed, 1);
+}
+
+#[tokio::test]
+async fn test_deliver_pending_skips_inactive_subscription() {
+    let mut dependencies = deliver_dependencies(0, false, || Ok(200));
+    dependencies.webhook_sender.checkpoint();
+    dependencies.webhook_sender.expect_send().never();
+    dependencies
+        .webhook_dao
+        .expect_update_delivery()
+        .withf(|entity, _, _| {
+            entity.state == WebhookDeliveryStateEntity::Failed && entity.attempts == 0
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let report = service.deliver_pending(Authentication::Full).await.unwrap();
+    assert_eq!(report.failed, 1);
+}
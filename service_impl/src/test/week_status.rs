@@ -6,9 +6,12 @@ use dao::{
 };
 use mockall::predicate::{always, eq};
 use service::{
+    audit_log::{entity_type, AuditChange, MockAuditLogService},
     clock::MockClockService,
+    notification::{MockNotificationService, NotificationEvent},
     permission::Authentication,
     uuid_service::MockUuidService,
+    webhook::{MockWebhookService, WebhookEvent},
     week_status::{WeekStatus, WeekStatusService},
     MockPermissionService,
 };
@@ -58,7 +61,10 @@ pub struct WeekStatusServiceDependencies {
     pub permission_service: MockPermissionService,
     pub clock_service: MockClockService,
     pub uuid_service: MockUuidService,
+    pub audit_log_service: MockAuditLogService,
     pub transaction_dao: MockTransactionDao,
+    pub notification_service: MockNotificationService,
+    pub webhook_service: MockWebhookService,
 }
 
 impl WeekStatusServiceDeps for WeekStatusServiceDependencies {
@@ -68,7 +74,10 @@ impl WeekStatusServiceDeps for WeekStatusServiceDependencies {
     type PermissionService = MockPermissionService;
     type ClockService = MockClockService;
     type UuidService = MockUuidService;
+    type AuditLogService = MockAuditLogService;
     type TransactionDao = MockTransactionDao;
+    type NotificationService = MockNotificationService;
+    type WebhookService = MockWebhookService;
 }
 
 impl WeekStatusServiceDependencies {
@@ -78,7 +87,10 @@ impl WeekStatusServiceDependencies {
             permission_service: Arc::new(self.permission_service),
             clock_service: Arc::new(self.clock_service),
             uuid_service: Arc::new(self.uuid_service),
+            audit_log_service: Arc::new(self.audit_log_service),
             transaction_dao: Arc::new(self.transaction_dao),
+            notification_service: Arc::new(self.notification_service),
+            webhook_service: Arc::new(self.webhook_service),
         }
     }
 }
@@ -113,12 +125,28 @@ fn build_dependencies(permission: bool) -> WeekStatusServiceDependencies {
         .returning(|_| Ok(MockTransaction));
     transaction_dao.expect_commit().returning(|_| Ok(()));
 
+    let mut notification_service = MockNotificationService::new();
+    notification_service
+        .expect_notify()
+        .returning(|_, _| Ok(()));
+
+    let mut webhook_service = MockWebhookService::new();
+    webhook_service.expect_publish().returning(|_, _| Ok(()));
+
+    let mut audit_log_service = MockAuditLogService::new();
+    audit_log_service
+        .expect_record()
+        .returning(|_, _, _, _| Ok(()));
+
     WeekStatusServiceDependencies {
         week_status_dao,
         permission_service,
         clock_service,
         uuid_service,
+        audit_log_service,
         transaction_dao,
+        notification_service,
+        webhook_service,
     }
 }
 
@@ -301,6 +329,125 @@ async fn test_set_updates_when_present() {
     assert_eq!(result, WeekStatus::Planned);
 }
 
+/// Übergang nach Planned erzeugt genau eine WeekPlanned-Benachrichtigung.
+#[tokio::test]
+async fn test_set_planned_notifies_once() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .week_status_dao
+        .expect_find_by_year_and_week()
+        .returning(|_, _, _| Ok(Some(existing_entity(WeekStatusKind::InPlanning))));
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| new_version());
+    dependencies
+        .week_status_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.notification_service.checkpoint();
+    dependencies
+        .notification_service
+        .expect_notify()
+        .with(
+            eq(NotificationEvent::WeekPlanned {
+                year: YEAR,
+                calendar_week: WEEK,
+            }),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .set_week_status(YEAR, WEEK, WeekStatus::Planned, ().into(), None)
+        .await
+        .unwrap();
+}
+
+/// Erneutes Setzen von Planned verschickt nichts.
+#[tokio::test]
+async fn test_set_planned_again_does_not_notify() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .week_status_dao
+        .expect_find_by_year_and_week()
+        .returning(|_, _, _| Ok(Some(existing_entity(WeekStatusKind::Planned))));
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| new_version());
+    dependencies
+        .week_status_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.notification_service.checkpoint();
+    dependencies.notification_service.expect_notify().never();
+    let service = dependencies.build_service();
+
+    service
+        .set_week_status(YEAR, WEEK, WeekStatus::Planned, ().into(), None)
+        .await
+        .unwrap();
+}
+
+/// Jeder Statuswechsel wird als Webhook-Ereignis veröffentlicht.
+#[tokio::test]
+async fn test_status_change_publishes_webhook() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .week_status_dao
+        .expect_find_by_year_and_week()
+        .returning(|_, _, _| Ok(Some(existing_entity(WeekStatusKind::Planned))));
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| new_version());
+    dependencies
+        .week_status_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.webhook_service.checkpoint();
+    dependencies
+        .webhook_service
+        .expect_publish()
+        .with(
+            eq(WebhookEvent::WeekStatusChanged {
+                year: YEAR,
+                calendar_week: WEEK,
+                status: WeekStatus::Locked,
+            }),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = dependencies.build_service();
+
+    service
+        .set_week_status(YEAR, WEEK, WeekStatus::Locked, ().into(), None)
+        .await
+        .unwrap();
+}
+
+/// Unveränderter Status erzeugt kein Webhook-Ereignis.
+#[tokio::test]
+async fn test_same_status_does_not_publish_webhook() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .week_status_dao
+        .expect_find_by_year_and_week()
+        .returning(|_, _, _| Ok(None));
+    dependencies.webhook_service.checkpoint();
+    dependencies.webhook_service.expect_publish().never();
+    let service = dependencies.build_service();
+
+    service
+        .set_week_status(YEAR, WEEK, WeekStatus::Unset, ().into(), None)
+        .await
+        .unwrap();
+}
+
 // --- Free transitions (D-39-02) -----------------------------------------------
 
 /// Every transition is allowed, including Locked -> InPlanning and Locked -> Unset
@@ -444,3 +591,71 @@ async fn test_get_maps_kind() {
         .unwrap();
     assert_eq!(result, WeekStatus::Locked);
 }
+
+// --- Änderungsprotokoll ----------------------------------------------------------
+
+#[tokio::test]
+async fn test_set_records_status_change() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .week_status_dao
+        .expect_find_by_year_and_week()
+        .returning(|_, _, _| Ok(Some(existing_entity(WeekStatusKind::Planned))));
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| new_version());
+    dependencies
+        .week_status_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies
+        .audit_log_service
+        .expect_record()
+        .with(
+            eq(AuditChange::update(
+                entity_type::WEEK_STATUS,
+                format!("{YEAR}/{WEEK}"),
+                &WeekStatus::Planned,
+                &WeekStatus::Locked,
+            )
+            .unwrap()),
+            eq(PROCESS),
+            always(),
+            always(),
+        )
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = dependencies.build_service();
+
+    let result = service
+        .set_week_status(YEAR, WEEK, WeekStatus::Locked, ().into(), None)
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_set_same_status_records_nothing() {
+    let mut dependencies = build_dependencies(true);
+    dependencies
+        .week_status_dao
+        .expect_find_by_year_and_week()
+        .returning(|_, _, _| Ok(Some(existing_entity(WeekStatusKind::Locked))));
+    dependencies
+        .uuid_service
+        .expect_new_uuid()
+        .returning(|_| new_version());
+    dependencies
+        .week_status_dao
+        .expect_update()
+        .returning(|_, _, _| Ok(()));
+    dependencies.audit_log_service.checkpoint();
+    dependencies.audit_log_service.expect_record().never();
+    let service = dependencies.build_service();
+
+    let result = service
+        .set_week_status(YEAR, WEEK, WeekStatus::Locked, ().into(), None)
+        .await;
+    assert!(result.is_ok());
+}
@@ -3,9 +3,12 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dao::text_template::TextTemplateDao;
 use dao::TransactionDao;
+use service::audit_log::{entity_type, AuditChange, AuditLogService};
 use service::permission::{Authentication, HR_PRIVILEGE};
-use service::text_template::{TextTemplate, TextTemplateService};
-use service::{PermissionService, ServiceError};
+use service::text_template::{
+    TemplateEngine, TemplateSyntaxError, TextTemplate, TextTemplateService,
+};
+use service::{PermissionService, ServiceError, ValidationFailureItem};
 use uuid::Uuid;
 
 use crate::gen_service_impl;
@@ -14,6 +17,7 @@ gen_service_impl! {
     struct TextTemplateServiceImpl: service::text_template::TextTemplateService = TextTemplateServiceDeps {
         TextTemplateDao: dao::text_template::TextTemplateDao<Transaction = Self::Transaction> = text_template_dao,
         PermissionService: service::PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
     }
 }
@@ -78,11 +82,13 @@ impl<Deps: TextTemplateServiceDeps> TextTemplateService for TextTemplateServiceI
             .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
+        check_template_syntax(item)?;
+
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
         let user = self
             .permission_service
-            .current_user_id(context)
+            .current_user_id(context.clone())
             .await?
             .unwrap_or("Unauthenticated".into());
 
@@ -98,6 +104,14 @@ impl<Deps: TextTemplateServiceDeps> TextTemplateService for TextTemplateServiceI
             .await?;
 
         let text_template = TextTemplate::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::TEXT_TEMPLATE, entity.id, &text_template)?,
+                "text-template-service::create",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(text_template)
     }
@@ -112,16 +126,18 @@ impl<Deps: TextTemplateServiceDeps> TextTemplateService for TextTemplateServiceI
             .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
+        check_template_syntax(item)?;
+
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
         let user = self
             .permission_service
-            .current_user_id(context)
+            .current_user_id(context.clone())
             .await?
             .unwrap_or("Unauthenticated".into());
 
         // Verify the entity exists
-        let _existing = self
+        let existing = self
             .text_template_dao
             .find_by_id(item.id, tx.clone())
             .await?
@@ -135,6 +151,19 @@ impl<Deps: TextTemplateServiceDeps> TextTemplateService for TextTemplateServiceI
             .await?;
 
         let text_template = TextTemplate::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::TEXT_TEMPLATE,
+                    entity.id,
+                    &TextTemplate::from(&existing),
+                    &text_template,
+                )?,
+                "text-template-service::update",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(text_template)
     }
@@ -153,12 +182,12 @@ impl<Deps: TextTemplateServiceDeps> TextTemplateService for TextTemplateServiceI
 
         let user = self
             .permission_service
-            .current_user_id(context)
+            .current_user_id(context.clone())
             .await?
             .unwrap_or("Unauthenticated".into());
 
         // Verify the entity exists
-        let _existing = self
+        let existing = self
             .text_template_dao
             .find_by_id(id, tx.clone())
             .await?
@@ -167,8 +196,157 @@ impl<Deps: TextTemplateServiceDeps> TextTemplateService for TextTemplateServiceI
         self.text_template_dao
             .delete(id, &user, tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(
+                    entity_type::TEXT_TEMPLATE,
+                    id,
+                    &TextTemplate::from(&existing),
+                )?,
+                "text-template-service::delete",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn validate(
+        &self,
+        template_text: &str,
+        template_engine: &TemplateEngine,
+        context: Authentication<Self::Context>,
+    ) -> Result<Option<TemplateSyntaxError>, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+        Ok(compile_template(template_text, template_engine).err())
+    }
+}
+
+/// Lehnt Templates ab, die ihre Engine nicht kompilieren kann.
+pub(crate) fn check_template_syntax(item: &TextTemplate) -> Result<(), ServiceError> {
+    compile_template(&item.template_text, &item.template_engine).map_err(|error| {
+        ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+            format!("template_text: {error}").into(),
+        )]))
+    })
+}
+
+/// Kompiliert `template_text` mit der Engine, ohne zu rendern.
+pub(crate) fn compile_template(
+    template_text: &str,
+    template_engine: &TemplateEngine,
+) -> Result<(), TemplateSyntaxError> {
+    match template_engine {
+        TemplateEngine::Tera => {
+            let mut tera = tera::Tera::default();
+            tera.add_raw_template("template", template_text)
+                .map(|_| ())
+                .map_err(|e| tera_syntax_error(&e))
+        }
+        TemplateEngine::MiniJinja => {
+            let env = minijinja::Environment::new();
+            env.template_from_str(template_text)
+                .map(|_| ())
+                .map_err(|e| minijinja_syntax_error(&e, template_text))
+        }
+    }
+}
+
+/// Tera liefert Parserfehler als pest-Meldung (` --> 3:7` … `= expected …`)
+/// in der Fehlerkette; daraus werden Position und Kurztext gelesen.
+fn tera_syntax_error(error: &tera::Error) -> TemplateSyntaxError {
+    let chain = error_chain(error);
+    let position = chain.lines().find_map(|line| {
+        let (line_no, column) = line.split_once("--> ")?.1.split_once(':')?;
+        Some((line_no.trim().parse().ok()?, column.trim().parse().ok()?))
+    });
+    let message = chain
+        .lines()
+        .find_map(|line| line.trim_start().strip_prefix("= "))
+        .map(str::to_string)
+        .unwrap_or_else(|| chain.replace('\n', " "));
+    TemplateSyntaxError {
+        message: message.into(),
+        line: position.map(|(line, _)| line),
+        column: position.map(|(_, column)| column),
+    }
+}
+
+fn minijinja_syntax_error(error: &minijinja::Error, template_text: &str) -> TemplateSyntaxError {
+    let message = match error.detail() {
+        Some(detail) => format!("{}: {detail}", error.kind()),
+        None => error.kind().to_string(),
+    };
+    let column = error.range().map(|range| {
+        let start = range.start.min(template_text.len());
+        let line_start = template_text[..start].rfind('\n').map_or(0, |i| i + 1);
+        template_text[line_start..start].chars().count() + 1
+    });
+    TemplateSyntaxError {
+        message: message.into(),
+        line: error.line(),
+        column,
+    }
+}
+
+/// Fehler samt aller `source()`-Ursachen, getrennt durch `: `.
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut text = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        text.push_str(": ");
+        text.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    text
+}
+/// Rendert `template` mit seiner Engine gegen `context_data`.
+///
+/// Mit `escape_html` werden eingesetzte Werte HTML-escaped — für Templates,
+/// deren Ausgabe als PDF-Layout (`pdf_template_render`) geparst wird, damit
+/// Namen wie `Müller & Söhne` das Markup nicht zerbrechen.
+pub(crate) fn render_template_text(
+    template: &TextTemplate,
+    context_data: &serde_json::Value,
+    escape_html: bool,
+) -> Result<String, ServiceError> {
+    try_render_template_text(template, context_data, escape_html).map_err(|e| {
+        tracing::error!("Failed to render {:?} template: {}", template.template_engine, e);
+        ServiceError::InternalError
+    })
+}
+
+/// Wie [`render_template_text`], gibt den Fehler aber als Text zurück — für
+/// die Vorschau, die ihn dem Benutzer zeigt.
+pub(crate) fn try_render_template_text(
+    template: &TextTemplate,
+    context_data: &serde_json::Value,
+    escape_html: bool,
+) -> Result<String, String> {
+    match template.template_engine {
+        TemplateEngine::Tera => {
+            let mut tera = tera::Tera::default();
+            tera.add_raw_template("custom_report", &template.template_text)
+                .map_err(|e| error_chain(&e))?;
+            if escape_html {
+                tera.autoescape_on(vec!["custom_report"]);
+            }
+            let template_context =
+                tera::Context::from_serialize(context_data).map_err(|e| error_chain(&e))?;
+            tera.render("custom_report", &template_context)
+                .map_err(|e| error_chain(&e))
+        }
+        TemplateEngine::MiniJinja => {
+            let mut env = minijinja::Environment::new();
+            if escape_html {
+                env.set_auto_escape_callback(|_| minijinja::AutoEscape::Html);
+            }
+            env.render_str(&template.template_text, context_data)
+                .map_err(|e| e.to_string())
+        }
+    }
+}
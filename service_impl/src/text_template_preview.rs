@@ -0,0 +1,348 @@
+//! Business-Logic-Tier Implementation von
+//! [`service::text_template_preview::TextTemplatePreviewService`].
+//!
+//! Die Beispieldaten haben dieselbe Struktur wie die Kontexte der
+//! Konsumenten (`billing_period_report`, `block_report`, `notification`) und
+//! müssen bei deren Änderungen nachgezogen werden. Die Variablenliste kommt
+//! aus den Beispieldaten, bei echten Daten ergänzt um zusätzliche Schlüssel
+//! (z.B. Custom-Extra-Hours in `values_map`).
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::TransactionDao;
+use serde_json::{json, Value};
+use uuid::Uuid;
+use service::{
+    billing_period_report::{
+        BillingPeriodReportService, BILLING_PERIOD_PDF_TEMPLATE_TYPE, BILLING_PERIOD_TEMPLATE_TYPE,
+    },
+    block_report::{BlockReportService, BLOCK_REPORT_TEMPLATE_TYPE},
+    notification::NotificationKind,
+    permission::{Authentication, HR_PRIVILEGE},
+    text_template::TextTemplate,
+    text_template_preview::{TemplatePreview, TemplatePreviewSource, TextTemplatePreviewService},
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+
+use crate::gen_service_impl;
+use crate::pdf_template_render::parse_layout;
+use crate::text_template::{check_template_syntax, try_render_template_text};
+
+const NOTIFICATION_KINDS: [NotificationKind; 4] = [
+    NotificationKind::BookingDeleted,
+    NotificationKind::WeekPlanned,
+    NotificationKind::AbsenceCreated,
+    NotificationKind::InvitationCreated,
+];
+
+const SAMPLE_APP_URL: &str = "https://shifty.example.org";
+
+gen_service_impl! {
+    struct TextTemplatePreviewServiceImpl: TextTemplatePreviewService = TextTemplatePreviewServiceDeps {
+        BillingPeriodReportService: BillingPeriodReportService<Context = Self::Context, Transaction = Self::Transaction> = billing_period_report_service,
+        BlockReportService: BlockReportService<Context = Self::Context, Transaction = Self::Transaction> = block_report_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+/// Quelle der echten Daten für eine Live-Vorschau.
+enum LiveContext {
+    BillingPeriod(Uuid),
+    BlockReport,
+}
+
+fn invalid_value(message: String) -> ServiceError {
+    ServiceError::ValidationError(Arc::from([ValidationFailureItem::InvalidValue(
+        message.into(),
+    )]))
+}
+
+#[async_trait]
+impl<Deps: TextTemplatePreviewServiceDeps> TextTemplatePreviewService
+    for TextTemplatePreviewServiceImpl<Deps>
+{
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn preview(
+        &self,
+        template: &TextTemplate,
+        source: TemplatePreviewSource,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TemplatePreview, ServiceError> {
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+        check_template_syntax(template)?;
+
+        let template_type = template.template_type.as_ref();
+        let sample = sample_context(template).ok_or_else(|| {
+            invalid_value(format!(
+                "Für den Template-Typ `{template_type}` gibt es keinen Vorschau-Kontext"
+            ))
+        })?;
+
+        let live_source = match source {
+            TemplatePreviewSource::Sample => None,
+            TemplatePreviewSource::Live { billing_period_id } => Some(match template_type {
+                BILLING_PERIOD_TEMPLATE_TYPE | BILLING_PERIOD_PDF_TEMPLATE_TYPE => {
+                    LiveContext::BillingPeriod(billing_period_id.ok_or_else(|| {
+                        invalid_value("billing_period_id".to_string())
+                    })?)
+                }
+                BLOCK_REPORT_TEMPLATE_TYPE => LiveContext::BlockReport,
+                _ => {
+                    return Err(invalid_value(format!(
+                        "Für den Template-Typ `{template_type}` gibt es nur Beispieldaten"
+                    )))
+                }
+            }),
+        };
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let live = match live_source {
+            None => None,
+            Some(LiveContext::BillingPeriod(billing_period_id)) => Some(
+                self.billing_period_report_service
+                    .build_custom_report_context(
+                        template,
+                        billing_period_id,
+                        context,
+                        tx.clone().into(),
+                    )
+                    .await?,
+            ),
+            Some(LiveContext::BlockReport) => Some(
+                self.block_report_service
+                    .build_block_report_context(context, tx.clone().into())
+                    .await?,
+            ),
+        };
+
+        let is_pdf_layout = template_type == BILLING_PERIOD_PDF_TEMPLATE_TYPE;
+        let context_data = live.as_ref().unwrap_or(&sample);
+        let output = try_render_template_text(template, context_data, is_pdf_layout)
+            .map_err(|e| invalid_value(format!("Renderfehler: {e}")))?;
+        if is_pdf_layout {
+            parse_layout(&output)?;
+        }
+
+        let mut variables = context_variables(&sample);
+        if let Some(live) = &live {
+            for variable in context_variables(live) {
+                if !variables.contains(&variable) {
+                    variables.push(variable);
+                }
+            }
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(TemplatePreview {
+            output: output.into(),
+            variables: variables.into(),
+        })
+    }
+}
+
+/// Pfade aller Schlüssel in `value`; Listen werden über ihr erstes Element
+/// als `name[]` beschrieben.
+pub(crate) fn context_variables(value: &Value) -> Vec<Arc<str>> {
+    let mut variables = Vec::new();
+    collect_variables("", value, &mut variables);
+    variables
+}
+
+fn collect_variables(prefix: &str, value: &Value, variables: &mut Vec<Arc<str>>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                variables.push(path.as_str().into());
+                collect_variables(&path, child, variables);
+            }
+        }
+        Value::Array(items) => {
+            if let Some(first) = items.first() {
+                collect_variables(&format!("{prefix}[]"), first, variables);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Beispielkontext für den `template_type` von `template`, `None` für
+/// unbekannte Typen.
+pub(crate) fn sample_context(template: &TextTemplate) -> Option<Value> {
+    match template.template_type.as_ref() {
+        BILLING_PERIOD_TEMPLATE_TYPE | BILLING_PERIOD_PDF_TEMPLATE_TYPE => {
+            Some(sample_billing_period_context(template))
+        }
+        BLOCK_REPORT_TEMPLATE_TYPE => Some(sample_block_report_context()),
+        template_type => NOTIFICATION_KINDS
+            .into_iter()
+            .find(|kind| kind.template_type() == template_type)
+            .map(sample_notification_context),
+    }
+}
+
+fn sample_billing_period_context(template: &TextTemplate) -> Value {
+    let sample_value = |delta: f64, ytd_from: f64, ytd_to: f64, full_year: f64| {
+        json!({
+            "delta": delta,
+            "ytd_from": ytd_from,
+            "ytd_to": ytd_to,
+            "full_year": full_year,
+        })
+    };
+    let sales_person = |index: u8, name: &str, is_paid: bool, balance: f64, overall: f64| {
+        let values_map = json!({
+            "balance": sample_value(balance, 2.5, 2.5 + balance, 2.5 + balance),
+            "overall": sample_value(overall, 410.0, 410.0 + overall, 410.0 + overall),
+            "expected_hours": sample_value(overall - balance, 407.5, 407.5 + overall - balance, 1040.0),
+            "vacation_days": sample_value(2.0, 10.0, 12.0, 12.0),
+        });
+        let values: Vec<Value> = values_map
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(value_type, value)| {
+                json!({
+                    "type": value_type,
+                    "value_delta": value["delta"],
+                    "value_ytd_from": value["ytd_from"],
+                    "value_ytd_to": value["ytd_to"],
+                    "value_full_year": value["full_year"],
+                })
+            })
+            .collect();
+        json!({
+            "id": format!("00000000-0000-0000-0000-00000000010{index}"),
+            "sales_person_id": format!("00000000-0000-0000-0000-00000000020{index}"),
+            "name": name,
+            "is_paid": is_paid,
+            "is_dynamic": false,
+            "values": values,
+            "values_map": values_map,
+            "created_at": "2026-10-01 9:30:00.0",
+            "created_by": "hr",
+        })
+    };
+    json!({
+        "billing_period": {
+            "id": "00000000-0000-0000-0000-000000000100",
+            "start_date": "2026-09-01",
+            "end_date": "2026-09-30",
+            "created_at": "2026-10-01 9:30:00.0",
+            "created_by": "hr",
+            "sales_persons": [
+                sales_person(1, "Anna Beispiel", true, 3.5, 83.5),
+                sales_person(2, "Ben Muster", false, -1.5, 38.5),
+            ],
+        },
+        "template": {
+            "id": template.id.to_string(),
+            "template_type": template.template_type.as_ref(),
+            "created_at": template.created_at.map(|dt| dt.to_string()),
+            "created_by": template.created_by.as_ref().map(|s| s.as_ref()),
+        }
+    })
+}
+
+fn sample_block_report_context() -> Value {
+    let block =
+        |week: u8, name: Option<&str>, day_of_week: &str, from: &str, to: &str, date: &str| {
+            json!({
+                "year": 2026,
+                "week": week,
+                "sales_person_name": name,
+                "day_of_week": day_of_week,
+                "from": from,
+                "to": to,
+                "date": date,
+            })
+        };
+    let current_week_blocks = vec![block(
+        43,
+        None,
+        "Friday",
+        "14:00:00.0",
+        "18:00:00.0",
+        "2026-10-23",
+    )];
+    let next_week_blocks = vec![block(
+        44,
+        Some("Anna Beispiel"),
+        "Monday",
+        "8:00:00.0",
+        "12:00:00.0",
+        "2026-10-26",
+    )];
+    let week_after_next_blocks = vec![block(
+        45,
+        None,
+        "Saturday",
+        "9:00:00.0",
+        "13:00:00.0",
+        "2026-11-07",
+    )];
+    let all_blocks: Vec<Value> = current_week_blocks
+        .iter()
+        .chain(&next_week_blocks)
+        .chain(&week_after_next_blocks)
+        .cloned()
+        .collect();
+    json!({
+        "current_week_blocks": current_week_blocks,
+        "next_week_blocks": next_week_blocks,
+        "week_after_next_blocks": week_after_next_blocks,
+        "unsufficiently_booked_blocks": all_blocks,
+        "current_week": 43,
+        "current_year": 2026,
+        "next_week": 44,
+        "next_year": 2026,
+        "week_after_next_week": 45,
+        "week_after_next_year": 2026,
+    })
+}
+
+fn sample_notification_context(kind: NotificationKind) -> Value {
+    match kind {
+        NotificationKind::BookingDeleted => json!({
+            "sales_person_name": "Anna Beispiel",
+            "year": 2026,
+            "calendar_week": 44,
+            "day_of_week": "Monday",
+            "date": "26.10.2026",
+            "from": "08:00",
+            "to": "12:00",
+            "app_url": SAMPLE_APP_URL,
+        }),
+        NotificationKind::WeekPlanned => json!({
+            "year": 2026,
+            "calendar_week": 44,
+            "app_url": SAMPLE_APP_URL,
+        }),
+        NotificationKind::AbsenceCreated => json!({
+            "sales_person_name": "Anna Beispiel",
+            "category": "Urlaub",
+            "from_date": "26.10.2026",
+            "to_date": "30.10.2026",
+            "app_url": SAMPLE_APP_URL,
+        }),
+        NotificationKind::InvitationCreated => json!({
+            "username": "anna",
+            "invitation_link": format!(
+                "{SAMPLE_APP_URL}/auth/invitation/00000000-0000-0000-0000-000000000300"
+            ),
+            "expiration_date": "26.10.2026",
+            "app_url": SAMPLE_APP_URL,
+        }),
+    }
+}
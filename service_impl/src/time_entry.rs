@@ -0,0 +1,622 @@
+use crate::gen_service_impl;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    time_entry::{TimeEntryDao, TimeEntryEntity},
+    TransactionDao,
+};
+use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    booking::BookingService,
+    carryover::{first_dirty_year, CarryoverService},
+    clock::ClockService,
+    config::ConfigService,
+    permission::{Authentication, HR_PRIVILEGE},
+    sales_person::SalesPersonService,
+    shiftplan_report::{ShiftplanReportDay, ShiftplanReportService},
+    time_entry::{
+        actual_hours_per_day, ReconciliationDay, TimeEntry, TimeEntryService, TimeEntrySource,
+    },
+    uuid_service::UuidService,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use shifty_utils::ShiftyDate;
+use time::{Date, Duration, PrimitiveDateTime};
+use time_tz::{timezones, OffsetDateTimeExt};
+use tokio::join;
+use tracing::warn;
+use uuid::Uuid;
+
+const TIME_ENTRY_SERVICE_PROCESS: &str = "time-entry-service";
+
+/// Längster Zeitraum für die Soll/Ist-Ansicht.
+const MAX_RECONCILIATION_DAYS: i64 = 366;
+
+gen_service_impl! {
+    struct TimeEntryServiceImpl: TimeEntryService = TimeEntryServiceDeps {
+        TimeEntryDao: TimeEntryDao<Transaction = Self::Transaction> = time_entry_dao,
+        BookingService: BookingService<Context = Self::Context, Transaction = Self::Transaction> = booking_service,
+        SalesPersonService: SalesPersonService<Context = Self::Context, Transaction = Self::Transaction> = sales_person_service,
+        ShiftplanReportService: ShiftplanReportService<Context = Self::Context, Transaction = Self::Transaction> = shiftplan_report_service,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ConfigService: ConfigService = config_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        CarryoverService: CarryoverService<Context = Self::Context, Transaction = Self::Transaction> = carryover_service,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+fn day_start(date: Date) -> PrimitiveDateTime {
+    date.midnight()
+}
+
+/// Ende muss nach dem Beginn liegen.
+fn validate(entry: &TimeEntry) -> Result<(), ServiceError> {
+    if let Some(end) = entry.end {
+        if end <= entry.start {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("end".into()),
+            ])));
+        }
+    }
+    Ok(())
+}
+
+/// Überlappen sich zwei Einträge? Offene Einträge reichen bis unendlich.
+fn overlaps(a: &TimeEntry, b: &TimeEntry) -> bool {
+    let a_before_b_ends = b.end.is_none_or(|b_end| a.start < b_end);
+    let b_before_a_ends = a.end.is_none_or(|a_end| b.start < a_end);
+    a_before_b_ends && b_before_a_ends
+}
+
+impl<Deps: TimeEntryServiceDeps> TimeEntryServiceImpl<Deps> {
+    async fn check_hr_or_self(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let (hr_permission, is_sales_person) = join!(
+            self.permission_service
+                .check_permission(HR_PRIVILEGE, context.clone()),
+            self.sales_person_service.verify_user_is_sales_person(
+                sales_person_id,
+                context,
+                tx.into()
+            ),
+        );
+        hr_permission.or(is_sales_person)
+    }
+
+    /// Aktuelle Ortszeit in der konfigurierten Zeitzone. Unbekannte Zeitzonen
+    /// fallen auf UTC zurück.
+    async fn local_now(&self) -> Result<PrimitiveDateTime, ServiceError> {
+        let now = self.clock_service.date_time_now();
+        let config = self.config_service.get_config().await?;
+        match timezones::get_by_name(&config.timezone) {
+            Some(tz) => {
+                let local = now.assume_utc().to_timezone(tz);
+                Ok(PrimitiveDateTime::new(local.date(), local.time()))
+            }
+            None => {
+                warn!("Unknown timezone {}, using UTC", config.timezone);
+                Ok(now)
+            }
+        }
+    }
+
+    /// Buchung muss existieren und der Person gehören.
+    async fn check_booking(
+        &self,
+        booking_id: Option<Uuid>,
+        sales_person_id: Uuid,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        let Some(booking_id) = booking_id else {
+            return Ok(());
+        };
+        let unknown_booking = || {
+            ServiceError::ValidationError(Arc::new([ValidationFailureItem::IdDoesNotExist(
+                "booking_id".into(),
+                booking_id,
+            )]))
+        };
+        let booking = self
+            .booking_service
+            .get(booking_id, Authentication::Full, tx.into())
+            .await
+            .map_err(|err| match err {
+                ServiceError::EntityNotFound(_) => unknown_booking(),
+                err => err,
+            })?;
+        if booking.sales_person_id != sales_person_id || booking.deleted.is_some() {
+            return Err(unknown_booking());
+        }
+        Ok(())
+    }
+
+    /// Kein anderer Eintrag der Person darf sich mit `entry` überschneiden.
+    async fn check_overlap(
+        &self,
+        entry: &TimeEntry,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        if let Some(open) = self
+            .time_entry_dao
+            .find_open_by_sales_person(entry.sales_person_id, tx.clone())
+            .await?
+        {
+            if open.id != entry.id && overlaps(entry, &TimeEntry::from(&open)) {
+                return Err(ServiceError::OverlappingTimeRange);
+            }
+        }
+        // Einträge über Mitternacht beginnen am Vortag.
+        let from = day_start(entry.start.date() - Duration::days(1));
+        let to = entry.end.unwrap_or(day_start(Date::MAX));
+        let conflict = self
+            .time_entry_dao
+            .find_by_sales_person_and_range(entry.sales_person_id, from, to, tx)
+            .await?
+            .iter()
+            .map(TimeEntry::from)
+            .any(|other| other.id != entry.id && overlaps(entry, &other));
+        if conflict {
+            return Err(ServiceError::OverlappingTimeRange);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Deps: TimeEntryServiceDeps> TimeEntryService for TimeEntryServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn get_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        from: Date,
+        to: Date,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[TimeEntry]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_hr_or_self(sales_person_id, context, tx.clone())
+            .await?;
+        if to < from {
+            return Err(ServiceError::DateOrderWrong(from, to));
+        }
+
+        let ret = self
+            .time_entry_dao
+            .find_by_sales_person_and_range(
+                sales_person_id,
+                day_start(from),
+                day_start(to.next_day().ok_or(ServiceError::InternalError)?),
+                tx.clone(),
+            )
+            .await?
+            .iter()
+            .map(TimeEntry::from)
+            .collect();
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(ret)
+    }
+
+    async fn get_open(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Option<TimeEntry>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_hr_or_self(sales_person_id, context, tx.clone())
+            .await?;
+
+        let ret = self
+            .time_entry_dao
+            .find_open_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .as_ref()
+            .map(TimeEntry::from);
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(ret)
+    }
+
+    async fn clock_in(
+        &self,
+        sales_person_id: Uuid,
+        booking_id: Option<Uuid>,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_hr_or_self(sales_person_id, context.clone(), tx.clone())
+            .await?;
+
+        if self
+            .time_entry_dao
+            .find_open_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .is_some()
+        {
+            return Err(ServiceError::OverlappingTimeRange);
+        }
+        self.check_booking(booking_id, sales_person_id, tx.clone())
+            .await?;
+
+        let created_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?
+            .unwrap_or_else(|| "system".into());
+        let entity = TimeEntryEntity {
+            id: self.uuid_service.new_uuid("TimeEntryService::clock_in id"),
+            sales_person_id,
+            booking_id,
+            start: self.local_now().await?,
+            end: None,
+            description: "".into(),
+            source: TimeEntrySource::Clock.into(),
+            created: self.clock_service.date_time_now(),
+            created_by,
+            deleted: None,
+            version: self
+                .uuid_service
+                .new_uuid("TimeEntryService::clock_in version"),
+        };
+        self.time_entry_dao
+            .create(&entity, TIME_ENTRY_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = TimeEntry::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::TIME_ENTRY, created.id, &created)?,
+                TIME_ENTRY_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn clock_out(
+        &self,
+        sales_person_id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_hr_or_self(sales_person_id, context.clone(), tx.clone())
+            .await?;
+
+        let mut entity = self
+            .time_entry_dao
+            .find_open_by_sales_person(sales_person_id, tx.clone())
+            .await?
+            .ok_or_else(|| ServiceError::EntityNotFoundGeneric("open time entry".into()))?;
+        let before = TimeEntry::from(&entity);
+        let now = self.local_now().await?;
+        // Uhrumstellung oder Ein-/Ausstempeln in derselben Sekunde: das Ende
+        // darf nie vor dem Beginn liegen.
+        entity.end = Some(now.max(entity.start));
+        entity.version = self
+            .uuid_service
+            .new_uuid("TimeEntryService::clock_out version");
+        self.time_entry_dao
+            .update(&entity, TIME_ENTRY_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let updated = TimeEntry::from(&entity);
+        self.carryover_service
+            .mark_dirty(
+                updated.sales_person_id,
+                first_dirty_year(updated.date()),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::TIME_ENTRY, updated.id, &before, &updated)?,
+                TIME_ENTRY_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn create(
+        &self,
+        entry: &TimeEntry,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        if entry.id != Uuid::nil() {
+            return Err(ServiceError::IdSetOnCreate);
+        }
+        if entry.version != Uuid::nil() {
+            return Err(ServiceError::VersionSetOnCreate);
+        }
+        if entry.deleted.is_some() {
+            return Err(ServiceError::DeletedSetOnCreate);
+        }
+        if entry.created.is_some() {
+            return Err(ServiceError::CreatedSetOnCreate);
+        }
+        validate(entry)?;
+        self.check_booking(entry.booking_id, entry.sales_person_id, tx.clone())
+            .await?;
+        self.check_overlap(entry, tx.clone()).await?;
+
+        let created_by = self
+            .permission_service
+            .current_user_id(context.clone())
+            .await?
+            .unwrap_or_else(|| "system".into());
+        let entity = TimeEntryEntity::try_from(&TimeEntry {
+            id: self.uuid_service.new_uuid("TimeEntryService::create id"),
+            version: self
+                .uuid_service
+                .new_uuid("TimeEntryService::create version"),
+            source: TimeEntrySource::Manual,
+            created: Some(self.clock_service.date_time_now()),
+            created_by: Some(created_by),
+            ..entry.clone()
+        })?;
+        self.time_entry_dao
+            .create(&entity, TIME_ENTRY_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = TimeEntry::from(&entity);
+        self.carryover_service
+            .mark_dirty(
+                created.sales_person_id,
+                first_dirty_year(created.date()),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::TIME_ENTRY, created.id, &created)?,
+                TIME_ENTRY_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        entry: &TimeEntry,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<TimeEntry, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        let mut entity = self
+            .time_entry_dao
+            .find_by_id(entry.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(entry.id))?;
+        if entity.version != entry.version {
+            return Err(ServiceError::EntityConflicts(
+                entity.id,
+                entity.version,
+                entry.version,
+            ));
+        }
+        if entity.sales_person_id != entry.sales_person_id {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::ModificationNotAllowed("sales_person_id".into()),
+            ])));
+        }
+        validate(entry)?;
+        self.check_booking(entry.booking_id, entry.sales_person_id, tx.clone())
+            .await?;
+        self.check_overlap(entry, tx.clone()).await?;
+
+        let before = TimeEntry::from(&entity);
+        entity.booking_id = entry.booking_id;
+        entity.start = entry.start;
+        entity.end = entry.end;
+        entity.description = entry.description.clone();
+        entity.source = TimeEntrySource::Manual.into();
+        entity.version = self
+            .uuid_service
+            .new_uuid("TimeEntryService::update version");
+        self.time_entry_dao
+            .update(&entity, TIME_ENTRY_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let updated = TimeEntry::from(&entity);
+        self.carryover_service
+            .mark_dirty(
+                updated.sales_person_id,
+                first_dirty_year(before.date().min(updated.date())),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::update(entity_type::TIME_ENTRY, updated.id, &before, &updated)?,
+                TIME_ENTRY_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context.clone())
+            .await?;
+
+        let mut entity = self
+            .time_entry_dao
+            .find_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let before = TimeEntry::from(&entity);
+        entity.deleted = Some(self.clock_service.date_time_now());
+        entity.version = self
+            .uuid_service
+            .new_uuid("TimeEntryService::delete version");
+        self.time_entry_dao
+            .update(&entity, TIME_ENTRY_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.carryover_service
+            .mark_dirty(
+                before.sales_person_id,
+                first_dirty_year(before.date()),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::TIME_ENTRY, id, &before)?,
+                TIME_ENTRY_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn reconcile(
+        &self,
+        sales_person_id: Uuid,
+        from: Date,
+        to: Date,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ReconciliationDay]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.check_hr_or_self(sales_person_id, context, tx.clone())
+            .await?;
+        if to < from {
+            return Err(ServiceError::DateOrderWrong(from, to));
+        }
+        if (to - from).whole_days() >= MAX_RECONCILIATION_DAYS {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::InvalidValue("to".into()),
+            ])));
+        }
+
+        let planned = self
+            .shiftplan_report_service
+            .extract_shiftplan_report(
+                sales_person_id,
+                ShiftyDate::from_date(from),
+                ShiftyDate::from_date(to),
+                Authentication::Full,
+                tx.clone().into(),
+            )
+            .await?;
+        let entries: Arc<[TimeEntry]> = self
+            .time_entry_dao
+            .find_by_sales_person_and_range(
+                sales_person_id,
+                day_start(from),
+                day_start(to.next_day().ok_or(ServiceError::InternalError)?),
+                tx.clone(),
+            )
+            .await?
+            .iter()
+            .map(TimeEntry::from)
+            .collect();
+
+        let mut days: BTreeMap<Date, ReconciliationDay> = BTreeMap::new();
+        let mut date = from;
+        while date <= to {
+            days.insert(
+                date,
+                ReconciliationDay {
+                    date,
+                    planned_hours: 0.0,
+                    actual_hours: 0.0,
+                    has_open_entry: false,
+                },
+            );
+            date = date.next_day().ok_or(ServiceError::InternalError)?;
+        }
+        for planned_day in planned.iter() {
+            if let Some(day) = days.get_mut(&planned_day.to_date()?.to_date()) {
+                day.planned_hours += planned_day.hours;
+            }
+        }
+        for entry in entries.iter() {
+            if let Some(day) = days.get_mut(&entry.date()) {
+                day.actual_hours += entry.hours();
+                day.has_open_entry |= entry.is_open();
+            }
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(days.into_values().collect())
+    }
+
+    async fn extract_actual_hours_report(
+        &self,
+        sales_person_id: Option<Uuid>,
+        from_date: ShiftyDate,
+        to_date: ShiftyDate,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[ShiftplanReportDay]>, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        self.permission_service
+            .check_permission(HR_PRIVILEGE, context)
+            .await?;
+
+        let from = day_start(from_date.to_date());
+        let to = day_start(from_date.max(to_date).next_day().to_date());
+        let entries: Arc<[TimeEntry]> = match sales_person_id {
+            Some(sales_person_id) => {
+                self.time_entry_dao
+                    .find_by_sales_person_and_range(sales_person_id, from, to, tx.clone())
+                    .await?
+            }
+            None => self.time_entry_dao.find_by_range(from, to, tx.clone()).await?,
+        }
+        .iter()
+        .map(TimeEntry::from)
+        .collect();
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(actual_hours_per_day(&entries))
+    }
+}
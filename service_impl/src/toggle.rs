@@ -1,7 +1,11 @@
 use crate::gen_service_impl;
 use async_trait::async_trait;
-use dao::{toggle::ToggleDao, TransactionDao};
+use dao::{
+    toggle::{ToggleDao, ToggleEntity},
+    TransactionDao,
+};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     permission::Authentication,
     toggle::{Toggle, ToggleGroup, ToggleService, TOGGLE_ADMIN_PRIVILEGE},
     PermissionService, ServiceError,
@@ -14,10 +18,59 @@ gen_service_impl! {
     struct ToggleServiceImpl: ToggleService = ToggleServiceDeps {
         ToggleDao: ToggleDao<Transaction = Self::Transaction> = toggle_dao,
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
 
+impl<Deps: ToggleServiceDeps> ToggleServiceImpl<Deps> {
+    async fn record(
+        &self,
+        change: AuditChange,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        self.audit_log_service
+            .record(&change, TOGGLE_SERVICE_PROCESS, context, tx.into())
+            .await
+    }
+
+    /// Mitglieder einer Gruppe als Schnappschuss für Zuordnungsänderungen.
+    async fn group_members(
+        &self,
+        group: &str,
+        tx: Deps::Transaction,
+    ) -> Result<Vec<Arc<str>>, ServiceError> {
+        Ok(self
+            .toggle_dao
+            .get_toggles_in_group(group, tx)
+            .await?
+            .iter()
+            .map(|toggle| Arc::from(toggle.name.as_str()))
+            .collect())
+    }
+
+    /// Ein Eintrag pro Toggle der Gruppe, dessen Zustand sich ändert.
+    async fn record_group_switch(
+        &self,
+        toggles: &[ToggleEntity],
+        enabled: bool,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        for entity in toggles.iter().filter(|entity| entity.enabled != enabled) {
+            let before = Toggle::from(entity);
+            let after = Toggle {
+                enabled,
+                ..before.clone()
+            };
+            let change = AuditChange::update(entity_type::TOGGLE, &before.name, &before, &after)?;
+            self.record(change, context.clone(), tx.clone()).await?;
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     type Context = Deps::Context;
@@ -105,7 +158,7 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let entity = dao::toggle::ToggleEntity::from(toggle);
@@ -113,6 +166,8 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
         self.toggle_dao
             .create_toggle(&entity, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let change = AuditChange::create(entity_type::TOGGLE, &toggle.name, toggle)?;
+        self.record(change, context, tx.clone()).await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -125,7 +180,7 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
@@ -137,10 +192,14 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
             return Err(ServiceError::EntityNotFoundGeneric(name.into()));
         };
 
+        let before = Toggle::from(&entity);
         entity.enabled = true;
         self.toggle_dao
             .update_toggle(&entity, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let change =
+            AuditChange::update(entity_type::TOGGLE, name, &before, &Toggle::from(&entity))?;
+        self.record(change, context, tx.clone()).await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -153,7 +212,7 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
@@ -165,10 +224,14 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
             return Err(ServiceError::EntityNotFoundGeneric(name.into()));
         };
 
+        let before = Toggle::from(&entity);
         entity.enabled = false;
         self.toggle_dao
             .update_toggle(&entity, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let change =
+            AuditChange::update(entity_type::TOGGLE, name, &before, &Toggle::from(&entity))?;
+        self.record(change, context, tx.clone()).await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -210,13 +273,25 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege (same gate as enable_toggle/disable_toggle)
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.toggle_dao.get_toggle(name, tx.clone()).await?;
         self.toggle_dao
             .set_toggle_value(name, value, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        // Neu lesen: der DAO setzt mit dem Wert auch `enabled`.
+        let after = self.toggle_dao.get_toggle(name, tx.clone()).await?;
+        if let (Some(before), Some(after)) = (before.as_ref(), after.as_ref()) {
+            let change = AuditChange::update(
+                entity_type::TOGGLE,
+                name,
+                &Toggle::from(before),
+                &Toggle::from(after),
+            )?;
+            self.record(change, context, tx.clone()).await?;
+        }
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -229,13 +304,18 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.toggle_dao.get_toggle(name, tx.clone()).await?;
         self.toggle_dao
             .delete_toggle(name, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        if let Some(before) = before.as_ref().map(Toggle::from) {
+            let change = AuditChange::delete(entity_type::TOGGLE, name, &before)?;
+            self.record(change, context, tx.clone()).await?;
+        }
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -248,7 +328,7 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let entity = dao::toggle::ToggleGroupEntity::from(group);
@@ -256,6 +336,8 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
         self.toggle_dao
             .create_toggle_group(&entity, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let change = AuditChange::create(entity_type::TOGGLE_GROUP, &group.name, group)?;
+        self.record(change, context, tx.clone()).await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -268,13 +350,18 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.toggle_dao.get_toggle_group(name, tx.clone()).await?;
         self.toggle_dao
             .delete_toggle_group(name, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        if let Some(before) = before.as_ref().map(ToggleGroup::from) {
+            let change = AuditChange::delete(entity_type::TOGGLE_GROUP, name, &before)?;
+            self.record(change, context, tx.clone()).await?;
+        }
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -321,13 +408,17 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.group_members(group, tx.clone()).await?;
         self.toggle_dao
             .add_toggle_to_group(group, toggle, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let after = self.group_members(group, tx.clone()).await?;
+        let change = AuditChange::update(entity_type::TOGGLE_GROUP, group, &before, &after)?;
+        self.record(change, context, tx.clone()).await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -341,13 +432,17 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.group_members(group, tx.clone()).await?;
         self.toggle_dao
             .remove_toggle_from_group(group, toggle, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let after = self.group_members(group, tx.clone()).await?;
+        let change = AuditChange::update(entity_type::TOGGLE_GROUP, group, &before, &after)?;
+        self.record(change, context, tx.clone()).await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -377,13 +472,19 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self
+            .toggle_dao
+            .get_toggles_in_group(group, tx.clone())
+            .await?;
         self.toggle_dao
             .enable_group(group, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.record_group_switch(&before, true, context, tx.clone())
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
@@ -396,13 +497,19 @@ impl<Deps: ToggleServiceDeps> ToggleService for ToggleServiceImpl<Deps> {
     ) -> Result<(), ServiceError> {
         // Requires toggle_admin privilege
         self.permission_service
-            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context)
+            .check_permission(TOGGLE_ADMIN_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self
+            .toggle_dao
+            .get_toggles_in_group(group, tx.clone())
+            .await?;
         self.toggle_dao
             .disable_group(group, TOGGLE_SERVICE_PROCESS, tx.clone())
             .await?;
+        self.record_group_switch(&before, false, context, tx.clone())
+            .await?;
         self.transaction_dao.commit(tx).await?;
         Ok(())
     }
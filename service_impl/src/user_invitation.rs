@@ -4,6 +4,8 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dao::user_invitation::{UserInvitationDao, UserInvitationEntity};
 use dao::{PermissionDao, TransactionDao, UserEntity};
+use service::audit_log::{entity_type, AuditChange, AuditLogService};
+use service::notification::{NotificationEvent, NotificationService};
 use service::permission::Authentication;
 use service::session::SessionService;
 use service::user_invitation::{InvitationStatus, UserInvitation, UserInvitationService};
@@ -19,7 +21,9 @@ gen_service_impl! {
         PermissionService: service::PermissionService<Context = Self::Context> = permission_service,
         SessionService: service::session::SessionService<Context = Self::Context> = session_service,
         UuidService: service::uuid_service::UuidService = uuid_service,
-        TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao
+        TransactionDao: dao::TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+        NotificationService: service::notification::NotificationService<Context = Self::Context, Transaction = Self::Transaction> = notification_service,
+        AuditLogService: service::audit_log::AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service
     }
 }
 
@@ -37,6 +41,31 @@ fn compute_invitation_status(entity: &UserInvitationEntity) -> InvitationStatus
     }
 }
 
+fn invitation_from_entity(entity: &UserInvitationEntity) -> UserInvitation {
+    UserInvitation {
+        id: entity.id,
+        username: entity.username.to_string(),
+        token: entity.token,
+        expiration_date: entity.expiration_date,
+        created_date: entity.created_date,
+        redeemed_at: entity.redeemed_at,
+        status: compute_invitation_status(entity),
+    }
+}
+
+impl<Deps: UserInvitationServiceDeps> UserInvitationServiceImpl<Deps> {
+    async fn record(
+        &self,
+        change: AuditChange,
+        context: Authentication<Deps::Context>,
+        tx: Deps::Transaction,
+    ) -> Result<(), ServiceError> {
+        self.audit_log_service
+            .record(&change, USER_INVITATION_SERVICE_PROCESS, context, tx.into())
+            .await
+    }
+}
+
 #[async_trait]
 impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationServiceImpl<Deps> {
     type Context = Deps::Context;
@@ -45,12 +74,13 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
     async fn generate_invitation(
         &self,
         username: &str,
+        email: Option<Arc<str>>,
         expiration_hours: i64,
         tx: Option<Self::Transaction>,
         auth: Authentication<Self::Context>,
     ) -> Result<UserInvitation, ServiceError> {
         self.permission_service
-            .check_permission("admin", auth)
+            .check_permission("admin", auth.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
@@ -74,17 +104,29 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
 
         self.user_invitation_dao.create_invitation(&entity).await?;
 
+        self.notification_service
+            .notify(
+                &NotificationEvent::InvitationCreated {
+                    username: entity.username.clone(),
+                    email,
+                    token: entity.token,
+                    expiration_date: entity.expiration_date,
+                },
+                Some(tx.clone()),
+            )
+            .await?;
+
+        let invitation = invitation_from_entity(&entity);
+        self.record(
+            AuditChange::create(entity_type::USER_INVITATION, invitation.id, &invitation)?,
+            auth,
+            tx.clone(),
+        )
+        .await?;
+
         self.transaction_dao.commit(tx).await?;
 
-        Ok(UserInvitation {
-            id: entity.id,
-            username: entity.username.to_string(),
-            token: entity.token,
-            expiration_date: entity.expiration_date,
-            created_date: entity.created_date,
-            redeemed_at: entity.redeemed_at,
-            status: compute_invitation_status(&entity),
-        })
+        Ok(invitation)
     }
 
     async fn validate_and_consume_token(
@@ -130,6 +172,17 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
             self.permission_dao
                 .create_user(&user_entity, USER_INVITATION_SERVICE_PROCESS)
                 .await?;
+            // Einlösen läuft vor dem Login, daher ohne Benutzer im Eintrag.
+            self.record(
+                AuditChange::create(
+                    entity_type::USER,
+                    &user_entity.name,
+                    &service::User::from(&user_entity),
+                )?,
+                Authentication::Full,
+                tx.clone(),
+            )
+            .await?;
         }
 
         // Note: We no longer delete the token here - it will be marked as redeemed
@@ -177,12 +230,25 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
         auth: Authentication<Self::Context>,
     ) -> Result<(), ServiceError> {
         self.permission_service
-            .check_permission("admin", auth)
+            .check_permission("admin", auth.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
+        let before = self.user_invitation_dao.find_by_id(id).await?;
         self.user_invitation_dao.delete_by_id(id).await?;
+        if let Some(before) = before {
+            self.record(
+                AuditChange::delete(
+                    entity_type::USER_INVITATION,
+                    id,
+                    &invitation_from_entity(&before),
+                )?,
+                auth,
+                tx.clone(),
+            )
+            .await?;
+        }
 
         self.transaction_dao.commit(tx).await?;
 
@@ -197,7 +263,23 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
 
+        let before = self.user_invitation_dao.find_by_token(token).await?;
         self.user_invitation_dao.mark_as_redeemed(token, session_id).await?;
+        let after = self.user_invitation_dao.find_by_token(token).await?;
+        if let (Some(before), Some(after)) = (before, after) {
+            // Wie beim Einlösen: noch kein Login, daher ohne Benutzer.
+            self.record(
+                AuditChange::update(
+                    entity_type::USER_INVITATION,
+                    after.id,
+                    &invitation_from_entity(&before),
+                    &invitation_from_entity(&after),
+                )?,
+                Authentication::Full,
+                tx.clone(),
+            )
+            .await?;
+        }
 
         self.transaction_dao.commit(tx).await?;
 
@@ -251,7 +333,7 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
     ) -> Result<(), ServiceError> {
         // Check admin permission
         self.permission_service
-            .check_permission("admin", auth)
+            .check_permission("admin", auth.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
@@ -269,6 +351,21 @@ impl<Deps: UserInvitationServiceDeps> UserInvitationService for UserInvitationSe
             
             // Mark the invitation as session revoked
             self.user_invitation_dao.mark_session_revoked(invitation_id).await?;
+            let after = UserInvitationEntity {
+                session_revoked_at: Some(OffsetDateTime::now_utc()),
+                ..invitation.clone()
+            };
+            self.record(
+                AuditChange::update(
+                    entity_type::USER_INVITATION,
+                    invitation_id,
+                    &invitation_from_entity(&invitation),
+                    &invitation_from_entity(&after),
+                )?,
+                auth,
+                tx.clone(),
+            )
+            .await?;
             
             self.transaction_dao.commit(tx).await?;
             Ok(())
@@ -3,6 +3,7 @@ use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{vacation_entitlement_offset::VacationEntitlementOffsetDao, TransactionDao};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
     permission::{Authentication, HR_PRIVILEGE},
     uuid_service::UuidService,
@@ -17,6 +18,7 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -61,7 +63,7 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
     ) -> Result<VacationEntitlementOffset, ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
         let existing = self
@@ -69,7 +71,8 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
             .find_by_sales_person_id_and_year(sales_person_id, year, tx.clone())
             .await?;
 
-        let entity = if let Some(existing) = existing {
+        let (entity, change) = if let Some(existing) = existing {
+            let before = VacationEntitlementOffset::from(&existing);
             let entity = dao::vacation_entitlement_offset::VacationEntitlementOffsetEntity {
                 offset_days,
                 version: self
@@ -80,7 +83,13 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
             self.vacation_entitlement_offset_dao
                 .update(&entity, "vacation-entitlement-offset-service::update", tx.clone())
                 .await?;
-            entity
+            let change = AuditChange::update(
+                entity_type::VACATION_ENTITLEMENT_OFFSET,
+                entity.id,
+                &before,
+                &VacationEntitlementOffset::from(&entity),
+            )?;
+            (entity, change)
         } else {
             let entity = dao::vacation_entitlement_offset::VacationEntitlementOffsetEntity {
                 id: self
@@ -98,8 +107,21 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
             self.vacation_entitlement_offset_dao
                 .create(&entity, "vacation-entitlement-offset-service::create", tx.clone())
                 .await?;
-            entity
+            let change = AuditChange::create(
+                entity_type::VACATION_ENTITLEMENT_OFFSET,
+                entity.id,
+                &VacationEntitlementOffset::from(&entity),
+            )?;
+            (entity, change)
         };
+        self.audit_log_service
+            .record(
+                &change,
+                "vacation-entitlement-offset-service::set",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok((&entity).into())
@@ -114,7 +136,7 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
     ) -> Result<(), ServiceError> {
         let tx = self.transaction_dao.use_transaction(tx).await?;
         self.permission_service
-            .check_permission(HR_PRIVILEGE, context)
+            .check_permission(HR_PRIVILEGE, context.clone())
             .await?;
 
         let existing = self
@@ -127,6 +149,7 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
                 )
             })?;
 
+        let before = VacationEntitlementOffset::from(&existing);
         let entity = dao::vacation_entitlement_offset::VacationEntitlementOffsetEntity {
             deleted: Some(self.clock_service.date_time_now()),
             version: self
@@ -137,6 +160,14 @@ impl<Deps: VacationEntitlementOffsetServiceDeps> VacationEntitlementOffsetServic
         self.vacation_entitlement_offset_dao
             .update(&entity, "vacation-entitlement-offset-service::delete", tx.clone())
             .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(entity_type::VACATION_ENTITLEMENT_OFFSET, entity.id, &before)?,
+                "vacation-entitlement-offset-service::delete",
+                context,
+                tx.clone().into(),
+            )
+            .await?;
 
         self.transaction_dao.commit(tx).await?;
         Ok(())
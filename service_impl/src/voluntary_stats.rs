@@ -132,7 +132,11 @@ impl<Deps: VoluntaryStatsServiceDeps> VoluntaryStatsService for VoluntaryStatsSe
             .absence_service
             .find_by_sales_person(sales_person_id, Authentication::Full, Some(tx.clone()))
             .await?;
-        let absences_vec: Vec<_> = absences.iter().cloned().collect();
+        let absences_vec: Vec<_> = absences
+            .iter()
+            .filter(|absence| absence.is_effective())
+            .cloned()
+            .collect();
 
         self.transaction_dao.commit(tx).await?;
 
@@ -182,7 +182,8 @@ impl WebDavClient {
     /// Upload a file to `folder/filename` under the WebDAV base URL.
     ///
     /// Sequence per attempt:
-    /// 1. MKCOL on `folder` (405 = "already exists", treated as success).
+    /// 1. MKCOL on every segment of `folder`, outermost first (405 =
+    ///    "already exists", treated as success).
     /// 2. PUT `folder/filename` with the given bytes as body (overwrite).
     ///
     /// Retries the whole MKCOL+PUT sequence on transient errors according to
@@ -221,13 +222,8 @@ impl WebDavClient {
         })
     }
 
-    async fn one_upload_pass(
-        &self,
-        folder: &str,
-        filename: &str,
-        bytes: &[u8],
-    ) -> Result<(), PassOutcome> {
-        // 1. MKCOL — idempotent folder create.
+    /// MKCOL on a single folder path; 405 ("already exists") counts as success.
+    async fn mkcol(&self, folder: &str) -> Result<(), PassOutcome> {
         let mkcol_url = format!("{}/{}", self.base_url, folder);
         let mkcol_method = Method::from_bytes(b"MKCOL").expect("MKCOL is a valid HTTP method name");
         let mkcol_resp = match self.client.request(mkcol_method, &mkcol_url).send().await {
@@ -240,24 +236,41 @@ impl WebDavClient {
         };
 
         match classify(mkcol_resp.status(), true) {
-            Classification::Success | Classification::MkcolExisting => {
-                // proceed to PUT
-            }
+            Classification::Success | Classification::MkcolExisting => Ok(()),
             Classification::Transient => {
                 let status = mkcol_resp.status().as_u16();
                 let body = mkcol_resp.text().await.unwrap_or_default();
-                return Err(PassOutcome::Transient {
+                Err(PassOutcome::Transient {
                     reason: Arc::from(format!("MKCOL {status}: {body}")),
-                });
+                })
             }
             Classification::Permanent => {
                 let status = mkcol_resp.status().as_u16();
                 let body = mkcol_resp.text().await.unwrap_or_default();
-                return Err(PassOutcome::Permanent {
+                Err(PassOutcome::Permanent {
                     status,
                     body: Arc::from(body),
-                });
+                })
+            }
+        }
+    }
+
+    async fn one_upload_pass(
+        &self,
+        folder: &str,
+        filename: &str,
+        bytes: &[u8],
+    ) -> Result<(), PassOutcome> {
+        // 1. MKCOL — idempotent folder create, segment by segment so that a
+        //    per-shiftplan subfolder below a fresh target folder works too
+        //    (MKCOL on `a/b` answers 409 while `a` is missing).
+        let mut prefix = String::new();
+        for segment in folder.split('/').filter(|s| !s.is_empty()) {
+            if !prefix.is_empty() {
+                prefix.push('/');
             }
+            prefix.push_str(segment);
+            self.mkcol(&prefix).await?;
         }
 
         // 2. PUT — upload bytes (overwrite).
@@ -0,0 +1,620 @@
+//! Basic-Tier Implementation von [`service::webhook::WebhookService`].
+//!
+//! Greift nur auf den eigenen DAO zu, damit Basic-Tier-Services wie
+//! `BookingService` oder `WeekStatusService` Ereignisse veröffentlichen
+//! können. Der Payload wird beim Veröffentlichen serialisiert und in der
+//! Outbox abgelegt; die Zustellung signiert genau diesen Text.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dao::{
+    webhook::{WebhookDao, WebhookDeliveryEntity, WebhookSubscriptionEntity},
+    TransactionDao,
+};
+use service::{
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceStatus, DayFraction},
+    audit_log::{entity_type, AuditChange, AuditLogService},
+    booking::Booking,
+    clock::ClockService,
+    permission::Authentication,
+    uuid_service::UuidService,
+    webhook::{
+        WebhookDelivery, WebhookDeliveryReport, WebhookDeliveryState, WebhookEvent, WebhookService,
+        WebhookSubscription,
+    },
+    week_status::WeekStatus,
+    PermissionService, ServiceError, ValidationFailureItem,
+};
+use time::{macros::format_description, Duration, PrimitiveDateTime};
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::gen_service_impl;
+use crate::webhook_client::{sign, WebhookError, WebhookRequest, WebhookSender};
+
+const WEBHOOK_SERVICE_PROCESS: &str = "webhook-service";
+const ADMIN_PRIVILEGE: &str = "admin";
+
+/// Nach so vielen transienten Fehlversuchen bleibt eine Zustellung `Failed`.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 8;
+/// Wartezeit vor dem zweiten Versuch; verdoppelt sich mit jedem weiteren
+/// (1, 2, 4, … 64 Minuten, zusammen gut zwei Stunden).
+const RETRY_BASE_DELAY_MINUTES: i64 = 1;
+/// Maximale Anzahl Zustellungen pro Lauf.
+const DELIVERY_BATCH_SIZE: u32 = 50;
+const MAX_DELIVERY_LIMIT: u32 = 500;
+
+fn format_date_time(value: PrimitiveDateTime) -> Result<String, ServiceError> {
+    Ok(value.format(format_description!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second]"
+    ))?)
+}
+
+fn format_optional_date_time(
+    value: Option<PrimitiveDateTime>,
+) -> Result<Option<String>, ServiceError> {
+    value.map(format_date_time).transpose()
+}
+
+fn booking_data(booking: &Booking) -> Result<serde_json::Value, ServiceError> {
+    Ok(serde_json::json!({
+        "id": booking.id,
+        "sales_person_id": booking.sales_person_id,
+        "slot_id": booking.slot_id,
+        "year": booking.year,
+        "calendar_week": booking.calendar_week,
+        "created": format_optional_date_time(booking.created)?,
+        "deleted": format_optional_date_time(booking.deleted)?,
+        "created_by": booking.created_by.as_deref(),
+        "deleted_by": booking.deleted_by.as_deref(),
+        "version": booking.version,
+    }))
+}
+
+fn absence_period_data(absence: &AbsencePeriod) -> Result<serde_json::Value, ServiceError> {
+    Ok(serde_json::json!({
+        "id": absence.id,
+        "sales_person_id": absence.sales_person_id,
+        "category": match absence.category {
+            AbsenceCategory::Vacation => "Vacation",
+            AbsenceCategory::SickLeave => "SickLeave",
+            AbsenceCategory::UnpaidLeave => "UnpaidLeave",
+        },
+        "from_date": absence.from_date.to_string(),
+        "to_date": absence.to_date.to_string(),
+        "day_fraction": match absence.day_fraction {
+            DayFraction::Full => "Full",
+            DayFraction::Half => "Half",
+        },
+        "status": match absence.status {
+            AbsenceStatus::Requested => "Requested",
+            AbsenceStatus::Approved => "Approved",
+            AbsenceStatus::Rejected => "Rejected",
+            AbsenceStatus::Cancelled => "Cancelled",
+        },
+        "description": absence.description.as_ref(),
+        "created": format_optional_date_time(absence.created)?,
+        "version": absence.version,
+    }))
+}
+
+/// Der JSON-Body eines Ereignisses:
+/// `{"event": "<typ>", "occurred_at": "<utc>", "data": {…}}`.
+pub fn event_payload(
+    event: &WebhookEvent,
+    occurred_at: PrimitiveDateTime,
+) -> Result<serde_json::Value, ServiceError> {
+    let data = match event {
+        WebhookEvent::BookingCreated(booking) | WebhookEvent::BookingDeleted(booking) => {
+            booking_data(booking)?
+        }
+        WebhookEvent::AbsencePeriodCreated(absence)
+        | WebhookEvent::AbsencePeriodUpdated(absence) => absence_period_data(absence)?,
+        WebhookEvent::BillingPeriodCreated {
+            id,
+            start_date,
+            end_date,
+        } => serde_json::json!({
+            "id": id,
+            "start_date": start_date.to_string(),
+            "end_date": end_date.to_string(),
+        }),
+        WebhookEvent::WeekStatusChanged {
+            year,
+            calendar_week,
+            status,
+        } => serde_json::json!({
+            "year": year,
+            "calendar_week": calendar_week,
+            "status": match status {
+                WeekStatus::Unset => "Unset",
+                WeekStatus::InPlanning => "InPlanning",
+                WeekStatus::Planned => "Planned",
+                WeekStatus::Locked => "Locked",
+            },
+        }),
+    };
+    Ok(serde_json::json!({
+        "event": event.event_type().to_string(),
+        "occurred_at": format_date_time(occurred_at)?,
+        "data": data,
+    }))
+}
+
+fn validate_subscription(subscription: &WebhookSubscription) -> Result<(), ServiceError> {
+    let mut errors = Vec::new();
+    let url = subscription.url.trim();
+    if !(url.starts_with("https://") || url.starts_with("http://"))
+        || url.contains(char::is_whitespace)
+    {
+        errors.push(ValidationFailureItem::InvalidValue("url".into()));
+    }
+    if subscription.event_types.is_empty() {
+        errors.push(ValidationFailureItem::InvalidValue("event_types".into()));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ServiceError::ValidationError(errors.into()))
+    }
+}
+
+/// Exponentielles Backoff: 1, 2, 4, … Minuten nach dem n-ten Fehlversuch.
+fn next_attempt(now: PrimitiveDateTime, attempts: u32) -> PrimitiveDateTime {
+    let exponent = attempts.saturating_sub(1).min(10);
+    now + Duration::minutes(RETRY_BASE_DELAY_MINUTES << exponent)
+}
+
+gen_service_impl! {
+    struct WebhookServiceImpl: WebhookService = WebhookServiceDeps {
+        WebhookDao: WebhookDao<Transaction = Self::Transaction> = webhook_dao,
+        PermissionService: PermissionService<Context = Self::Context> = permission_service,
+        ClockService: ClockService = clock_service,
+        UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
+        WebhookSender: WebhookSender = webhook_sender,
+        TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+    }
+}
+
+impl<Deps: WebhookServiceDeps> WebhookServiceImpl<Deps> {
+    /// Zufälliger Schlüssel aus zwei v4-UUIDs (244 Zufallsbits).
+    fn generate_secret(&self) -> Arc<str> {
+        let first = self
+            .uuid_service
+            .new_uuid(&format!("{WEBHOOK_SERVICE_PROCESS}::secret"));
+        let second = self
+            .uuid_service
+            .new_uuid(&format!("{WEBHOOK_SERVICE_PROCESS}::secret"));
+        Arc::from(format!("{}{}", first.simple(), second.simple()))
+    }
+
+    async fn deliver_one(
+        &self,
+        delivery: WebhookDeliveryEntity,
+    ) -> Result<WebhookDeliveryState, ServiceError> {
+        let tx = self.transaction_dao.use_transaction(None).await?;
+        let subscription = self
+            .webhook_dao
+            .find_subscription_by_id(delivery.subscription_id, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+
+        let now = self.clock_service.date_time_now();
+        let attempts = delivery.attempts + 1;
+        let entity = match subscription {
+            Some(subscription) if subscription.active => {
+                let timestamp = now.assume_utc().unix_timestamp();
+                let request = WebhookRequest {
+                    url: subscription.url.clone(),
+                    event_type: Arc::from(delivery.event_type.to_string()),
+                    delivery_id: delivery.id,
+                    timestamp,
+                    signature: Arc::from(sign(&subscription.secret, timestamp, &delivery.payload)),
+                    body: delivery.payload.clone(),
+                };
+                match self.webhook_sender.send(&request).await {
+                    Ok(status) => WebhookDeliveryEntity {
+                        state: dao::webhook::WebhookDeliveryState::Delivered,
+                        attempts,
+                        last_status: Some(status),
+                        last_error: None,
+                        delivered: Some(now),
+                        ..delivery
+                    },
+                    Err(e) => {
+                        warn!("Webhook delivery {} failed: {}", delivery.id, e);
+                        let state = match e {
+                            WebhookError::Transient { .. } if attempts < MAX_DELIVERY_ATTEMPTS => {
+                                dao::webhook::WebhookDeliveryState::Pending
+                            }
+                            _ => dao::webhook::WebhookDeliveryState::Failed,
+                        };
+                        WebhookDeliveryEntity {
+                            state,
+                            attempts,
+                            next_attempt: next_attempt(now, attempts),
+                            last_status: e.status(),
+                            last_error: Some(Arc::from(e.to_string())),
+                            ..delivery
+                        }
+                    }
+                }
+            }
+            // Abonnement gelöscht oder deaktiviert: nicht mehr zustellen,
+            // nach dem Reaktivieren kann ein Admin erneut anstoßen.
+            _ => WebhookDeliveryEntity {
+                state: dao::webhook::WebhookDeliveryState::Failed,
+                last_error: Some(Arc::from("subscription is inactive or deleted")),
+                ..delivery
+            },
+        };
+        let entity = WebhookDeliveryEntity {
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{WEBHOOK_SERVICE_PROCESS}::deliver version")),
+            ..entity
+        };
+
+        let tx = self.transaction_dao.use_transaction(None).await?;
+        self.webhook_dao
+            .update_delivery(&entity, WEBHOOK_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(entity.state.into())
+    }
+}
+
+#[async_trait]
+impl<Deps: WebhookServiceDeps> WebhookService for WebhookServiceImpl<Deps> {
+    type Context = Deps::Context;
+    type Transaction = Deps::Transaction;
+
+    async fn publish(
+        &self,
+        event: &WebhookEvent,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let event_type = event.event_type();
+        let subscriptions: Vec<WebhookSubscription> = self
+            .webhook_dao
+            .all_subscriptions(tx.clone())
+            .await?
+            .iter()
+            .map(WebhookSubscription::from)
+            .filter(|subscription| subscription.is_subscribed(event_type))
+            .collect();
+        if subscriptions.is_empty() {
+            self.transaction_dao.commit(tx).await?;
+            return Ok(());
+        }
+
+        let now = self.clock_service.date_time_now();
+        let payload: Arc<str> = Arc::from(event_payload(event, now)?.to_string());
+        for subscription in subscriptions {
+            let entity = WebhookDeliveryEntity {
+                id: self
+                    .uuid_service
+                    .new_uuid(&format!("{WEBHOOK_SERVICE_PROCESS}::create delivery id")),
+                subscription_id: subscription.id,
+                event_type: event_type.into(),
+                payload: payload.clone(),
+                state: dao::webhook::WebhookDeliveryState::Pending,
+                attempts: 0,
+                next_attempt: now,
+                last_status: None,
+                last_error: None,
+                created: now,
+                delivered: None,
+                version: self.uuid_service.new_uuid(&format!(
+                    "{WEBHOOK_SERVICE_PROCESS}::create delivery version"
+                )),
+            };
+            self.webhook_dao
+                .create_delivery(&entity, WEBHOOK_SERVICE_PROCESS, tx.clone())
+                .await?;
+        }
+
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn get_subscriptions(
+        &self,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[WebhookSubscription]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let subscriptions = self
+            .webhook_dao
+            .all_subscriptions(tx.clone())
+            .await?
+            .iter()
+            .map(WebhookSubscription::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(subscriptions)
+    }
+
+    async fn get_subscription(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookSubscription, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let subscription = self
+            .webhook_dao
+            .find_subscription_by_id(id, tx.clone())
+            .await?
+            .as_ref()
+            .map(WebhookSubscription::from)
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(subscription)
+    }
+
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookSubscription, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        if subscription.id != Uuid::nil() {
+            return Err(ServiceError::IdSetOnCreate);
+        }
+        if subscription.version != Uuid::nil() {
+            return Err(ServiceError::VersionSetOnCreate);
+        }
+        validate_subscription(subscription)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let secret = if subscription.secret.trim().is_empty() {
+            self.generate_secret()
+        } else {
+            Arc::from(subscription.secret.trim())
+        };
+        let entity = WebhookSubscriptionEntity {
+            id: self.uuid_service.new_uuid(&format!(
+                "{WEBHOOK_SERVICE_PROCESS}::create subscription id"
+            )),
+            url: Arc::from(subscription.url.trim()),
+            secret,
+            description: subscription.description.clone(),
+            event_types: subscription
+                .event_types
+                .iter()
+                .map(|event_type| (*event_type).into())
+                .collect(),
+            active: subscription.active,
+            created: self.clock_service.date_time_now(),
+            deleted: None,
+            version: self.uuid_service.new_uuid(&format!(
+                "{WEBHOOK_SERVICE_PROCESS}::create subscription version"
+            )),
+        };
+        self.webhook_dao
+            .create_subscription(&entity, WEBHOOK_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let created = WebhookSubscription::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::WEBHOOK_SUBSCRIPTION, created.id, &created)?,
+                WEBHOOK_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(created)
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookSubscription, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        validate_subscription(subscription)?;
+
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .webhook_dao
+            .find_subscription_by_id(subscription.id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(subscription.id))?;
+        if persisted.version != subscription.version {
+            return Err(ServiceError::EntityConflicts(
+                subscription.id,
+                persisted.version,
+                subscription.version,
+            ));
+        }
+        let entity = WebhookSubscriptionEntity {
+            url: Arc::from(subscription.url.trim()),
+            secret: if subscription.secret.trim().is_empty() {
+                persisted.secret.clone()
+            } else {
+                Arc::from(subscription.secret.trim())
+            },
+            description: subscription.description.clone(),
+            event_types: subscription
+                .event_types
+                .iter()
+                .map(|event_type| (*event_type).into())
+                .collect(),
+            active: subscription.active,
+            version: self.uuid_service.new_uuid(&format!(
+                "{WEBHOOK_SERVICE_PROCESS}::update subscription version"
+            )),
+            ..persisted.clone()
+        };
+        self.webhook_dao
+            .update_subscription(&entity, WEBHOOK_SERVICE_PROCESS, tx.clone())
+            .await?;
+        let updated = WebhookSubscription::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::update(
+                    entity_type::WEBHOOK_SUBSCRIPTION,
+                    updated.id,
+                    &WebhookSubscription::from(&persisted),
+                    &updated,
+                )?,
+                WEBHOOK_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(updated)
+    }
+
+    async fn delete_subscription(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<(), ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context.clone())
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let persisted = self
+            .webhook_dao
+            .find_subscription_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        let entity = WebhookSubscriptionEntity {
+            deleted: Some(self.clock_service.date_time_now()),
+            version: self.uuid_service.new_uuid(&format!(
+                "{WEBHOOK_SERVICE_PROCESS}::delete subscription version"
+            )),
+            ..persisted.clone()
+        };
+        self.webhook_dao
+            .update_subscription(&entity, WEBHOOK_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.audit_log_service
+            .record(
+                &AuditChange::delete(
+                    entity_type::WEBHOOK_SUBSCRIPTION,
+                    id,
+                    &WebhookSubscription::from(&persisted),
+                )?,
+                WEBHOOK_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(())
+    }
+
+    async fn get_deliveries(
+        &self,
+        subscription_id: Uuid,
+        limit: u32,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<Arc<[WebhookDelivery]>, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let deliveries = self
+            .webhook_dao
+            .find_deliveries_by_subscription(
+                subscription_id,
+                limit.min(MAX_DELIVERY_LIMIT),
+                tx.clone(),
+            )
+            .await?
+            .iter()
+            .map(WebhookDelivery::from)
+            .collect();
+        self.transaction_dao.commit(tx).await?;
+        Ok(deliveries)
+    }
+
+    async fn retry_delivery(
+        &self,
+        id: Uuid,
+        context: Authentication<Self::Context>,
+        tx: Option<Self::Transaction>,
+    ) -> Result<WebhookDelivery, ServiceError> {
+        self.permission_service
+            .check_permission(ADMIN_PRIVILEGE, context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(tx).await?;
+        let existing = self
+            .webhook_dao
+            .find_delivery_by_id(id, tx.clone())
+            .await?
+            .ok_or(ServiceError::EntityNotFound(id))?;
+        if existing.state != dao::webhook::WebhookDeliveryState::Failed {
+            return Err(ServiceError::ValidationError(Arc::new([
+                ValidationFailureItem::ModificationNotAllowed("state".into()),
+            ])));
+        }
+        let entity = WebhookDeliveryEntity {
+            state: dao::webhook::WebhookDeliveryState::Pending,
+            attempts: 0,
+            next_attempt: self.clock_service.date_time_now(),
+            version: self
+                .uuid_service
+                .new_uuid(&format!("{WEBHOOK_SERVICE_PROCESS}::retry version")),
+            ..existing
+        };
+        self.webhook_dao
+            .update_delivery(&entity, WEBHOOK_SERVICE_PROCESS, tx.clone())
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+        Ok(WebhookDelivery::from(&entity))
+    }
+
+    async fn deliver_pending(
+        &self,
+        context: Authentication<Self::Context>,
+    ) -> Result<WebhookDeliveryReport, ServiceError> {
+        self.permission_service
+            .check_only_full_authentication(context)
+            .await?;
+        let tx = self.transaction_dao.use_transaction(None).await?;
+        let due = self
+            .webhook_dao
+            .find_due_deliveries(
+                self.clock_service.date_time_now(),
+                DELIVERY_BATCH_SIZE,
+                tx.clone(),
+            )
+            .await?;
+        self.transaction_dao.commit(tx).await?;
+
+        let mut report = WebhookDeliveryReport::default();
+        for delivery in due.iter().cloned() {
+            let id = delivery.id;
+            match self.deliver_one(delivery).await {
+                Ok(WebhookDeliveryState::Delivered) => report.delivered += 1,
+                Ok(WebhookDeliveryState::Pending) => report.retried += 1,
+                Ok(WebhookDeliveryState::Failed) => report.failed += 1,
+                Err(e) => error!("Failed to update webhook delivery {}: {:?}", id, e),
+            }
+        }
+        Ok(report)
+    }
+}
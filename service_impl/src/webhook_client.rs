@@ -0,0 +1,271 @@
+//! HTTP-Client für die Webhook-Zustellung.
+//!
+//! Analog zu [`crate::webdav_client`]: ein schlanker Trait
+//! ([`WebhookSender`]), den der `WebhookServiceImpl` als Dependency bekommt,
+//! plus die Produktions-Implementierung [`WebhookClient`] auf Basis von
+//! `reqwest` (rustls, Timeout). Anders als beim WebDAV-Upload wiederholt der
+//! Client selbst nichts; die Wiederholung mit Backoff übernimmt die Outbox,
+//! damit sie einen Neustart überlebt. Die Klassifikation folgt
+//! `webdav_client`: 5xx, 408, 429 und Netzwerkfehler sind transient, alle
+//! anderen Nicht-2xx-Antworten permanent.
+//!
+//! ## Signatur
+//!
+//! Jeder Request trägt
+//!
+//! - `X-Shifty-Event`: Ereignistyp, z. B. `booking.created`
+//! - `X-Shifty-Delivery`: Id der Zustellung (für Idempotenz beim Empfänger)
+//! - `X-Shifty-Timestamp`: Unix-Sekunden des Versuchs
+//! - `X-Shifty-Signature`: `sha256=<hex>` = HMAC-SHA256 mit dem Secret des
+//!   Abonnements über `<timestamp>.<body>`
+//!
+//! Der Empfänger rechnet die Signatur nach und verwirft zu alte Timestamps.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use mockall::automock;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::StatusCode;
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// Per-Request-Timeout; ein hängender Empfänger blockiert sonst den Job.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Längere Antwort-Bodies werden für `last_error` abgeschnitten.
+const MAX_ERROR_BODY_LEN: usize = 500;
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("transient webhook error: {reason}")]
+    Transient {
+        status: Option<u16>,
+        reason: Arc<str>,
+    },
+
+    #[error("permanent webhook error: {status} {body}")]
+    Permanent { status: u16, body: Arc<str> },
+}
+
+impl WebhookError {
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            WebhookError::Transient { status, .. } => *status,
+            WebhookError::Permanent { status, .. } => Some(*status),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookRequest {
+    pub url: Arc<str>,
+    pub event_type: Arc<str>,
+    pub delivery_id: Uuid,
+    pub timestamp: i64,
+    pub signature: Arc<str>,
+    pub body: Arc<str>,
+}
+
+#[automock]
+#[async_trait]
+pub trait WebhookSender: Send + Sync + 'static {
+    /// Schickt den Request und liefert den HTTP-Status einer 2xx-Antwort.
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookError>;
+}
+
+/// HMAC-SHA256 über `<timestamp>.<body>`, als `sha256=<hex>`.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    status.is_server_error()
+        || status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+fn truncate(body: String) -> Arc<str> {
+    match body.char_indices().nth(MAX_ERROR_BODY_LEN) {
+        Some((index, _)) => Arc::from(&body[..index]),
+        None => Arc::from(body),
+    }
+}
+
+#[derive(Clone)]
+pub struct WebhookClient {
+    client: reqwest::Client,
+}
+
+impl fmt::Debug for WebhookClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookClient").finish_non_exhaustive()
+    }
+}
+
+impl WebhookClient {
+    pub fn new() -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("shifty-webhook/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl WebhookSender for WebhookClient {
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookError> {
+        let response = self
+            .client
+            .post(request.url.as_ref())
+            .header(CONTENT_TYPE, "application/json")
+            .header("X-Shifty-Event", request.event_type.as_ref())
+            .header("X-Shifty-Delivery", request.delivery_id.to_string())
+            .header("X-Shifty-Timestamp", request.timestamp.to_string())
+            .header("X-Shifty-Signature", request.signature.as_ref())
+            .body(request.body.to_string())
+            .send()
+            .await
+            .map_err(|e| WebhookError::Transient {
+                status: None,
+                reason: Arc::from(format!("network error: {e}")),
+            })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(status.as_u16());
+        }
+        let body = truncate(response.text().await.unwrap_or_default());
+        if is_transient(status) {
+            Err(WebhookError::Transient {
+                status: Some(status.as_u16()),
+                reason: Arc::from(format!("{} {}", status.as_u16(), body)),
+            })
+        } else {
+            Err(WebhookError::Permanent {
+                status: status.as_u16(),
+                body,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn request(url: String) -> WebhookRequest {
+        let body = r#"{"event":"booking.created"}"#;
+        WebhookRequest {
+            url: url.into(),
+            event_type: "booking.created".into(),
+            delivery_id: Uuid::nil(),
+            timestamp: 1_700_000_000,
+            signature: sign("secret", 1_700_000_000, body).into(),
+            body: body.into(),
+        }
+    }
+
+    #[test]
+    fn signature_is_hmac_sha256_over_timestamp_and_body() {
+        // echo -n '1700000000.{}' | openssl dgst -sha256 -hmac secret
+        assert_eq!(
+            sign("secret", 1_700_000_000, "{}"),
+            "sha256=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+        assert_ne!(
+            sign("secret", 1_700_000_000, "{}"),
+            sign("secret", 1_700_000_001, "{}")
+        );
+        assert_ne!(
+            sign("secret", 1_700_000_000, "{}"),
+            sign("other", 1_700_000_000, "{}")
+        );
+    }
+
+    #[tokio::test]
+    async fn success_sends_signed_headers() {
+        let server = MockServer::start().await;
+        let expected = request(String::new());
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .and(header("X-Shifty-Event", "booking.created"))
+            .and(header("X-Shifty-Timestamp", "1700000000"))
+            .and(header("X-Shifty-Signature", expected.signature.as_ref()))
+            .and(header("Content-Type", "application/json"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = WebhookClient::new().unwrap();
+        let status = client
+            .send(&request(format!("{}/hook", server.uri())))
+            .await
+            .unwrap();
+        assert_eq!(status, 204);
+    }
+
+    #[tokio::test]
+    async fn server_error_is_transient() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("busy"))
+            .mount(&server)
+            .await;
+
+        let client = WebhookClient::new().unwrap();
+        let err = client.send(&request(server.uri())).await.unwrap_err();
+        assert!(matches!(
+            err,
+            WebhookError::Transient {
+                status: Some(503),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn client_error_is_permanent() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(410).set_body_string("gone"))
+            .mount(&server)
+            .await;
+
+        let client = WebhookClient::new().unwrap();
+        let err = client.send(&request(server.uri())).await.unwrap_err();
+        match err {
+            WebhookError::Permanent { status, body } => {
+                assert_eq!(status, 410);
+                assert_eq!(body.as_ref(), "gone");
+            }
+            other => panic!("expected Permanent, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limit_is_transient() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+
+        let client = WebhookClient::new().unwrap();
+        let err = client.send(&request(server.uri())).await.unwrap_err();
+        assert_eq!(err.status(), Some(429));
+        assert!(matches!(err, WebhookError::Transient { .. }));
+    }
+}
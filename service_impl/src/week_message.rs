@@ -2,6 +2,7 @@ use crate::gen_service_impl;
 use async_trait::async_trait;
 use dao::{week_message::WeekMessageDao, TransactionDao};
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
     permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
     uuid_service::UuidService,
@@ -19,6 +20,7 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
     }
 }
@@ -76,7 +78,7 @@ impl<Deps: WeekMessageServiceDeps> WeekMessageService for WeekMessageServiceImpl
     ) -> Result<WeekMessage, ServiceError> {
         // Check permission - only shiftplanners can create week messages
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         let mut message = message.clone();
@@ -102,9 +104,18 @@ impl<Deps: WeekMessageServiceDeps> WeekMessageService for WeekMessageServiceImpl
         self.week_message_dao
             .create(&entity, WEEK_MESSAGE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let created = WeekMessage::from(&entity);
+        self.audit_log_service
+            .record(
+                &AuditChange::create(entity_type::WEEK_MESSAGE, created.id, &created)?,
+                WEEK_MESSAGE_SERVICE_PROCESS,
+                context,
+                tx.clone().into(),
+            )
+            .await?;
         self.transaction_dao.commit(tx).await?;
 
-        Ok(WeekMessage::from(&entity))
+        Ok(created)
     }
 
     async fn update(
@@ -115,7 +126,7 @@ impl<Deps: WeekMessageServiceDeps> WeekMessageService for WeekMessageServiceImpl
     ) -> Result<WeekMessage, ServiceError> {
         // Check permission - only shiftplanners can update week messages
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         let mut entity: dao::week_message::WeekMessageEntity = message.try_into()?;
@@ -124,12 +135,27 @@ impl<Deps: WeekMessageServiceDeps> WeekMessageService for WeekMessageServiceImpl
             .new_uuid(&format!("{}::update version", WEEK_MESSAGE_SERVICE_PROCESS));
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self
+            .week_message_dao
+            .find_by_id(entity.id, tx.clone())
+            .await?;
         self.week_message_dao
             .update(&entity, WEEK_MESSAGE_SERVICE_PROCESS, tx.clone())
             .await?;
+        let updated = WeekMessage::from(&entity);
+        if let Some(before) = before.as_ref().map(WeekMessage::from) {
+            self.audit_log_service
+                .record(
+                    &AuditChange::update(entity_type::WEEK_MESSAGE, updated.id, &before, &updated)?,
+                    WEEK_MESSAGE_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
         self.transaction_dao.commit(tx).await?;
 
-        Ok(WeekMessage::from(&entity))
+        Ok(updated)
     }
 
     async fn delete(
@@ -140,13 +166,24 @@ impl<Deps: WeekMessageServiceDeps> WeekMessageService for WeekMessageServiceImpl
     ) -> Result<(), ServiceError> {
         // Check permission - only shiftplanners can delete week messages
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         let tx = self.transaction_dao.use_transaction(tx).await?;
+        let before = self.week_message_dao.find_by_id(id, tx.clone()).await?;
         self.week_message_dao
             .delete(id, WEEK_MESSAGE_SERVICE_PROCESS, tx.clone())
             .await?;
+        if let Some(before) = before.as_ref().map(WeekMessage::from) {
+            self.audit_log_service
+                .record(
+                    &AuditChange::delete(entity_type::WEEK_MESSAGE, id, &before)?,
+                    WEEK_MESSAGE_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+        }
         self.transaction_dao.commit(tx).await?;
 
         Ok(())
@@ -5,9 +5,12 @@ use dao::{
     TransactionDao,
 };
 use service::{
+    audit_log::{entity_type, AuditChange, AuditLogService},
     clock::ClockService,
+    notification::{NotificationEvent, NotificationService},
     permission::{Authentication, SHIFTPLANNER_PRIVILEGE},
     uuid_service::UuidService,
+    webhook::{WebhookEvent, WebhookService},
     week_status::{WeekStatus, WeekStatusService},
     PermissionService, ServiceError,
 };
@@ -32,7 +35,11 @@ gen_service_impl! {
         PermissionService: PermissionService<Context = Self::Context> = permission_service,
         ClockService: ClockService = clock_service,
         UuidService: UuidService = uuid_service,
+        AuditLogService: AuditLogService<Context = Self::Context, Transaction = Self::Transaction> = audit_log_service,
         TransactionDao: TransactionDao<Transaction = Self::Transaction> = transaction_dao,
+        // Basic-Tier-Dep: Rundmail an alle Abonnenten, sobald eine Woche `Planned` wird.
+        NotificationService: NotificationService<Context = Self::Context, Transaction = Self::Transaction> = notification_service,
+        WebhookService: WebhookService<Context = Self::Context, Transaction = Self::Transaction> = webhook_service,
     }
 }
 
@@ -71,7 +78,7 @@ impl<Deps: WeekStatusServiceDeps> WeekStatusService for WeekStatusServiceImpl<De
     ) -> Result<WeekStatus, ServiceError> {
         // Permission gate FIRST — before any DAO access (D-39-01, T-39-01).
         self.permission_service
-            .check_permission(SHIFTPLANNER_PRIVILEGE, context)
+            .check_permission(SHIFTPLANNER_PRIVILEGE, context.clone())
             .await?;
 
         // find + write in the SAME transaction (no TOCTOU, T-39-04).
@@ -81,6 +88,14 @@ impl<Deps: WeekStatusServiceDeps> WeekStatusService for WeekStatusServiceImpl<De
             .find_by_year_and_week(year, calendar_week, tx.clone())
             .await?;
 
+        let was_planned = existing
+            .as_ref()
+            .is_some_and(|e| e.status == WeekStatusKind::Planned);
+        let previous: WeekStatus = existing
+            .as_ref()
+            .map(|e| e.status.clone().into())
+            .unwrap_or(WeekStatus::Unset);
+
         match to_kind(&status) {
             // Unset == row absence (D-39-04): soft-delete the active row, else no-op.
             None => {
@@ -125,6 +140,50 @@ impl<Deps: WeekStatusServiceDeps> WeekStatusService for WeekStatusServiceImpl<De
             },
         }
 
+        // Nur beim Übergang nach `Planned` — erneutes Setzen verschickt nichts.
+        if status == WeekStatus::Planned && !was_planned {
+            self.notification_service
+                .notify(
+                    &NotificationEvent::WeekPlanned {
+                        year,
+                        calendar_week,
+                    },
+                    Some(tx.clone()),
+                )
+                .await?;
+        }
+        if status != previous {
+            // Ohne Zeile gilt `Unset`; die Woche wird über Jahr/KW identifiziert.
+            let entity_id = format!("{year}/{calendar_week}");
+            let change = match (&previous, &status) {
+                (WeekStatus::Unset, _) => {
+                    AuditChange::create(entity_type::WEEK_STATUS, entity_id, &status)?
+                }
+                (_, WeekStatus::Unset) => {
+                    AuditChange::delete(entity_type::WEEK_STATUS, entity_id, &previous)?
+                }
+                _ => AuditChange::update(entity_type::WEEK_STATUS, entity_id, &previous, &status)?,
+            };
+            self.audit_log_service
+                .record(
+                    &change,
+                    WEEK_STATUS_SERVICE_PROCESS,
+                    context,
+                    tx.clone().into(),
+                )
+                .await?;
+            self.webhook_service
+                .publish(
+                    &WebhookEvent::WeekStatusChanged {
+                        year,
+                        calendar_week,
+                        status: status.clone(),
+                    },
+                    Some(tx.clone()),
+                )
+                .await?;
+        }
+
         self.transaction_dao.commit(tx).await?;
         Ok(status)
     }
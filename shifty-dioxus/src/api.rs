@@ -7,7 +7,7 @@ use rest_types::{
     CustomExtraHoursTO,
     DayOfWeekTO, EmployeeAttendanceStatisticsTO, EmployeeReportTO, EmployeeWeeklyStatisticsTO,
     EmployeeWorkDetailsTO,
-    ExtraHoursCategoryTO,
+    ExtraHoursCategoryTO, HolidayCandidateTO, HolidayRegionTO,
     ExtraHoursTO, FeatureFlagTO, GenerateInvitationRequest, ImpersonateTO, InvitationResponse,
     PdfExportConfigTO, RoleTO, SalesPersonTO, SalesPersonUnavailableTO, ShiftplanTO,
     ShortEmployeeReportTO, SlotTO, SpecialDayTO, TextTemplateTO, UpdateTextTemplateRequestTO,
@@ -1034,6 +1034,53 @@ pub async fn create_special_day(
     Ok(result)
 }
 
+/// Query-Wert einer Feiertagsregion, wie ihn das Backend serialisiert (`DE-BY`).
+fn holiday_region_param(region: HolidayRegionTO) -> String {
+    serde_json::to_value(region)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| "DE".to_string())
+}
+
+/// GET `/special-days/holiday-calendar/{year}?region=`. Berechnete Feiertage
+/// des Jahres samt bereits vorhandener Sondertage, ohne etwas anzulegen.
+pub async fn get_holiday_calendar_preview(
+    config: Config,
+    year: u32,
+    region: HolidayRegionTO,
+) -> Result<Rc<[HolidayCandidateTO]>, reqwest::Error> {
+    let url = format!(
+        "{}/special-days/holiday-calendar/{}?region={}",
+        config.backend,
+        year,
+        holiday_region_param(region)
+    );
+    let response = reqwest::get(url).await?;
+    response.error_for_status_ref()?;
+    let res = response.json().await?;
+    Ok(res)
+}
+
+/// POST `/special-days/holiday-calendar/{year}?region=`. Legt die fehlenden
+/// Feiertage an und liefert nur die neu angelegten Sondertage.
+pub async fn generate_holiday_calendar(
+    config: Config,
+    year: u32,
+    region: HolidayRegionTO,
+) -> Result<Rc<[SpecialDayTO]>, reqwest::Error> {
+    let url = format!(
+        "{}/special-days/holiday-calendar/{}?region={}",
+        config.backend,
+        year,
+        holiday_region_param(region)
+    );
+    let client = reqwest::Client::new();
+    let response = client.post(url).send().await?;
+    response.error_for_status_ref()?;
+    let res = response.json().await?;
+    Ok(res)
+}
+
 /// DELETE `/special-days/{id}`. Errors on non-2xx (SPD-03).
 pub async fn delete_special_day(config: Config, id: Uuid) -> Result<(), reqwest::Error> {
     let url = format!("{}/special-days/{}", config.backend, id);
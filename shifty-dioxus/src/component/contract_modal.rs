@@ -144,6 +144,8 @@ fn ContractModalBody(props: ContractModalBodyProps) -> Element {
     let dynamic_help = ImStr::from(i18n.t(Key::DynamicHourHelp).as_ref());
     let cap_label = ImStr::from(i18n.t(Key::CapPlannedHoursLabel).as_ref());
     let cap_help = ImStr::from(i18n.t(Key::CapPlannedHoursHelp).as_ref());
+    let actual_hours_label = ImStr::from(i18n.t(Key::UseActualHoursLabel).as_ref());
+    let actual_hours_help = ImStr::from(i18n.t(Key::UseActualHoursHelp).as_ref());
     let holiday_in_hours_label = i18n.t(Key::HolidaysInHoursLabel);
     let workday_in_hours_label = i18n.t(Key::WorkdaysInHoursLabel);
 
@@ -448,6 +450,25 @@ fn ContractModalBody(props: ContractModalBodyProps) -> Element {
                 }
                 span { class: "text-small font-normal text-ink-muted", "{cap_help}" }
             }
+            div { class: "flex flex-col gap-1",
+                FormCheckbox {
+                    value: details.use_actual_hours,
+                    disabled: read_only,
+                    on_change: {
+                        let details = details.clone();
+                        move |v: bool| {
+                            if read_only {
+                                return;
+                            }
+                            let mut next = details.clone();
+                            next.use_actual_hours = v;
+                            dispatch(next);
+                        }
+                    },
+                    label: rsx! { "{actual_hours_label}" },
+                }
+                span { class: "text-small font-normal text-ink-muted", "{actual_hours_help}" }
+            }
 
             // Derived hours info
             div { class: "border-t border-border pt-3 flex flex-col gap-1 text-small font-normal text-ink-muted",
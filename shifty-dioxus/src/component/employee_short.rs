@@ -74,6 +74,7 @@ mod tests {
                 background_color: color.into(),
                 is_paid: true,
                 inactive: false,
+                personnel_number: None,
                 version: Uuid::nil(),
             },
             working_hours_by_week: Rc::from([]),
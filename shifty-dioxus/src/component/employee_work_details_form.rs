@@ -85,6 +85,8 @@ pub fn EmployeeWorkDetailsFormPlain(props: WorkingHoursFormPlainProps) -> Elemen
     let dynamic_hour_label = i18n.t(i18n::Key::DynamicHourLabel);
     let cap_planned_hours_label = i18n.t(i18n::Key::CapPlannedHoursLabel);
     let cap_planned_hours_help = i18n.t(i18n::Key::CapPlannedHoursHelp);
+    let use_actual_hours_label = i18n.t(i18n::Key::UseActualHoursLabel);
+    let use_actual_hours_help = i18n.t(i18n::Key::UseActualHoursHelp);
     let holiday_in_hours_label = i18n.t(i18n::Key::HolidaysInHoursLabel);
     let workday_in_hours_label = i18n.t(i18n::Key::WorkdaysInHoursLabel);
 
@@ -366,6 +368,29 @@ pub fn EmployeeWorkDetailsFormPlain(props: WorkingHoursFormPlainProps) -> Elemen
                     span { class: "text-small font-normal text-gray-500", "{cap_planned_hours_help}" }
                 }
             }
+            FormPair { label: use_actual_hours_label,
+                div { class: "flex flex-col",
+                    Checkbox {
+                        value: employee_work_details.use_actual_hours,
+                        disabled: props.employee_work_details_form_type == EmployeeWorkDetailsFormType::ReadOnly,
+                        on_change: {
+                            to_owned![employee_work_details];
+                            move |value: bool| {
+                                if props.employee_work_details_form_type
+                                    == EmployeeWorkDetailsFormType::ReadOnly
+                                {
+                                    return ;
+                                }
+                                let mut employee_work_details = employee_work_details.clone();
+                                employee_work_details.use_actual_hours = value;
+                                props.on_update_employee_work_details.call(employee_work_details);
+                            }
+                        },
+                        ""
+                    }
+                    span { class: "text-small font-normal text-gray-500", "{use_actual_hours_help}" }
+                }
+            }
             FormGroup {
                 span {
                     "{holiday_in_hours_label}: "
@@ -257,6 +257,7 @@ mod tests {
         use uuid::Uuid;
         Employee {
             sales_person: SalesPerson {
+                personnel_number: None,
                 id: Uuid::new_v4(),
                 name: name.into(),
                 background_color: "#fff".into(),
@@ -159,6 +159,18 @@ pub fn WarningList(props: WarningListProps) -> Element {
                                     .replace("{max}", &max_paid_employees.to_string());
                                 rsx! { "{body}" }
                             }
+                            WarningTO::QualificationNotCovered {
+                                required_count,
+                                qualified_count,
+                                ..
+                            } => {
+                                let body = i18n
+                                    .t(Key::BookingWarningQualificationNotCovered)
+                                    .as_ref()
+                                    .replace("{qualified}", &qualified_count.to_string())
+                                    .replace("{required}", &required_count.to_string());
+                                rsx! { "{body}" }
+                            }
                         }
                     }
                 }
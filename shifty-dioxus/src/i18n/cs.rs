@@ -183,6 +183,16 @@ pub fn add_i18n_cs(i18n: &mut I18n<Key, Locale>) {
         Key::CapPlannedHoursHelp,
         "Hodiny nad rámec očekávaných týdenních hodin se zaznamenávají jako dobrovolnictví a neovlivňují bilanci.",
     );
+    i18n.add_text(
+        Locale::Cs,
+        Key::UseActualHoursLabel,
+        "Použít zaznamenanou pracovní dobu",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::UseActualHoursHelp,
+        "Bilance počítá s odpracovanými hodinami místo naplánovaných směn.",
+    );
 
     i18n.add_text(Locale::Cs, Key::VacationDaysLabel, "Dny dovolené");
     i18n.add_text(
@@ -531,6 +541,7 @@ pub fn add_i18n_cs(i18n: &mut I18n<Key, Locale>) {
         Key::ThisPersonIsInactive,
         "Tato osoba je neaktivní",
     );
+    i18n.add_text(Locale::Cs, Key::PersonnelNumber, "Osobní číslo");
     i18n.add_text(Locale::Cs, Key::UserAccount, "Uživatelský účet");
     i18n.add_text(
         Locale::Cs,
@@ -1023,6 +1034,11 @@ pub fn add_i18n_cs(i18n: &mut I18n<Key, Locale>) {
         Key::BookingWarningPaidLimitExceeded,
         "Překročen limit placených zaměstnanců ({current}/{max}).",
     );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingWarningQualificationNotCovered,
+        "Požadavek na kvalifikaci není splněn ({qualified}/{required} kvalifikovaných).",
+    );
 
     // Phase 23 — Editor kapacity slotu (FUI-02).
     i18n.add_text(
@@ -1223,6 +1239,51 @@ pub fn add_i18n_cs(i18n: &mut I18n<Key, Locale>) {
         Key::SettingsSpecialDaysDeleteError,
         "Smazání se nezdařilo.",
     );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarLabel,
+        "Státní svátky",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarDescription,
+        "Vypočítá státní svátky pro rok {year} a chybějící přidá jako svátky. Stávající zvláštní dny zůstanou beze změny.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarRegionLabel,
+        "Spolková země",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarNationwide,
+        "Pouze celostátní svátky",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarPreviewBtn,
+        "Náhled",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarApplyBtn,
+        "Přidat chybějící svátky",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarNew,
+        "Nový",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarExisting,
+        "Již existuje",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SettingsSpecialDaysHolidayCalendarCreated,
+        "Přidáno svátků: {count}.",
+    );
     i18n.add_text(Locale::Cs, Key::SettingsSpecialDaysCalendarWeekAbbr, "KT");
     i18n.add_text(Locale::Cs, Key::ShiftplanDayTypeHoliday, "Svátek");
     i18n.add_text(
@@ -187,6 +187,16 @@ pub fn add_i18n_de(i18n: &mut I18n<Key, Locale>) {
         Key::CapPlannedHoursHelp,
         "Stunden über dem wöchentlichen Soll werden als Ehrenamt verbucht und beeinflussen das Stundenkonto nicht.",
     );
+    i18n.add_text(
+        Locale::De,
+        Key::UseActualHoursLabel,
+        "Erfasste Arbeitszeit verwenden",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::UseActualHoursHelp,
+        "Das Stundenkonto rechnet mit den gestempelten statt den geplanten Stunden.",
+    );
 
     i18n.add_text(Locale::De, Key::VacationDaysLabel, "Urlaubstage");
     i18n.add_text(Locale::De, Key::VacationCarryoverLabel, "Vorjahresurlaub");
@@ -557,6 +567,7 @@ pub fn add_i18n_de(i18n: &mut I18n<Key, Locale>) {
         Key::ThisPersonIsInactive,
         "Diese Person ist inaktiv",
     );
+    i18n.add_text(Locale::De, Key::PersonnelNumber, "Personalnummer");
     i18n.add_text(Locale::De, Key::UserAccount, "Benutzerkonto");
     i18n.add_text(
         Locale::De,
@@ -1033,6 +1044,11 @@ pub fn add_i18n_de(i18n: &mut I18n<Key, Locale>) {
         Key::BookingWarningPaidLimitExceeded,
         "Bezahlt-Limit überschritten ({current}/{max}).",
     );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingWarningQualificationNotCovered,
+        "Qualifikations-Anforderung nicht erfüllt ({qualified}/{required} qualifiziert).",
+    );
 
     // Phase 23 — Slot-Kapazitäts-Editor (FUI-02).
     i18n.add_text(
@@ -1233,6 +1249,51 @@ pub fn add_i18n_de(i18n: &mut I18n<Key, Locale>) {
         Key::SettingsSpecialDaysDeleteError,
         "Löschen fehlgeschlagen.",
     );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarLabel,
+        "Gesetzliche Feiertage",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarDescription,
+        "Berechnet die Feiertage für {year} und legt fehlende als Feiertag an. Vorhandene Sondertage bleiben unverändert.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarRegionLabel,
+        "Bundesland",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarNationwide,
+        "Nur bundesweite Feiertage",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarPreviewBtn,
+        "Vorschau",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarApplyBtn,
+        "Fehlende Feiertage anlegen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarNew,
+        "Neu",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarExisting,
+        "Bereits vorhanden",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SettingsSpecialDaysHolidayCalendarCreated,
+        "{count} Feiertage angelegt.",
+    );
     i18n.add_text(
         Locale::De,
         Key::SettingsSpecialDaysCalendarWeekAbbr,
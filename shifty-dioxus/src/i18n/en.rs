@@ -159,6 +159,16 @@ pub fn add_i18n_en(i18n: &mut I18n<Key, Locale>) {
         Key::CapPlannedHoursHelp,
         "Hours beyond the expected weekly amount are recorded as volunteer work and do not affect the balance.",
     );
+    i18n.add_text(
+        Locale::En,
+        Key::UseActualHoursLabel,
+        "Use recorded working time",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::UseActualHoursHelp,
+        "The balance uses the clocked hours instead of the planned shifts.",
+    );
 
     i18n.add_text(Locale::En, Key::VacationDaysLabel, "Vacation days");
     i18n.add_text(
@@ -514,6 +524,7 @@ pub fn add_i18n_en(i18n: &mut I18n<Key, Locale>) {
         Key::ThisPersonIsInactive,
         "This person is inactive",
     );
+    i18n.add_text(Locale::En, Key::PersonnelNumber, "Personnel number");
     i18n.add_text(Locale::En, Key::UserAccount, "User Account");
     i18n.add_text(Locale::En, Key::ConnectUserAccount, "Connect User Account");
     // User invitations
@@ -962,6 +973,11 @@ pub fn add_i18n_en(i18n: &mut I18n<Key, Locale>) {
         Key::BookingWarningPaidLimitExceeded,
         "Paid employee limit exceeded ({current}/{max}).",
     );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingWarningQualificationNotCovered,
+        "Qualification requirement not covered ({qualified}/{required} qualified).",
+    );
 
     // Phase 23 — Slot paid-capacity editor (FUI-02).
     i18n.add_text(Locale::En, Key::MaxPaidEmployeesLabel, "Max paid employees");
@@ -1146,6 +1162,51 @@ pub fn add_i18n_en(i18n: &mut I18n<Key, Locale>) {
         Key::SettingsSpecialDaysDeleteError,
         "Delete failed.",
     );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarLabel,
+        "Public holidays",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarDescription,
+        "Calculates the public holidays for {year} and adds the missing ones as holidays. Existing special days are left unchanged.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarRegionLabel,
+        "State",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarNationwide,
+        "Nationwide holidays only",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarPreviewBtn,
+        "Preview",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarApplyBtn,
+        "Add missing holidays",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarNew,
+        "New",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarExisting,
+        "Already set",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SettingsSpecialDaysHolidayCalendarCreated,
+        "{count} holidays added.",
+    );
     i18n.add_text(Locale::En, Key::SettingsSpecialDaysCalendarWeekAbbr, "W");
     i18n.add_text(Locale::En, Key::ShiftplanDayTypeHoliday, "Holiday");
     i18n.add_text(Locale::En, Key::ShiftplanDayTypeShortDay, "Short Day");
@@ -155,6 +155,8 @@ pub enum Key {
 
     CapPlannedHoursLabel,
     CapPlannedHoursHelp,
+    UseActualHoursLabel,
+    UseActualHoursHelp,
 
     VacationDaysLabel,
     VacationCarryoverLabel,
@@ -335,6 +337,7 @@ pub enum Key {
     Settings,
     ThisPersonReceivesPayment,
     ThisPersonIsInactive,
+    PersonnelNumber,
     UserAccount,
     ConnectUserAccount,
     // User invitations
@@ -595,6 +598,8 @@ pub enum Key {
     BookingWarningOnUnavailableDay,
     /// Pro-Item-Text wenn das Bezahlt-Limit überschritten ist (Platzhalter `{current}`, `{max}`).
     BookingWarningPaidLimitExceeded,
+    /// Pro-Item-Text wenn eine Qualifikations-Anforderung des Slots nicht erfüllt ist (Platzhalter `{qualified}`, `{required}`).
+    BookingWarningQualificationNotCovered,
 
     // Phase 22 — HR-only employee statistics block (STAT-01/STAT-02).
     /// Heading of the HR-only statistics block on the employee detail page.
@@ -714,6 +719,24 @@ pub enum Key {
     SettingsSpecialDaysDeleteBtn,
     /// Inline error shown below the list after a failed delete.
     SettingsSpecialDaysDeleteError,
+    /// Heading of the public-holiday generator row in Card 3.
+    SettingsSpecialDaysHolidayCalendarLabel,
+    /// Description of the public-holiday generator; `{year}` placeholder.
+    SettingsSpecialDaysHolidayCalendarDescription,
+    /// Region selector label of the holiday generator ("Bundesland" / "State" / "Spolková země").
+    SettingsSpecialDaysHolidayCalendarRegionLabel,
+    /// Region option for nationwide holidays only.
+    SettingsSpecialDaysHolidayCalendarNationwide,
+    /// Button loading the holiday preview.
+    SettingsSpecialDaysHolidayCalendarPreviewBtn,
+    /// Button creating the missing holidays of the preview.
+    SettingsSpecialDaysHolidayCalendarApplyBtn,
+    /// Preview badge: holiday will be created.
+    SettingsSpecialDaysHolidayCalendarNew,
+    /// Preview badge: a special day already exists on that date and is kept.
+    SettingsSpecialDaysHolidayCalendarExisting,
+    /// Inline result after applying; `{count}` placeholder for created holidays.
+    SettingsSpecialDaysHolidayCalendarCreated,
     /// Calendar-week abbreviation used in the date context string ("KW" / "W" / "KT").
     SettingsSpecialDaysCalendarWeekAbbr,
     /// Shiftplan per-day dropdown label: holiday type ("Feiertag" / "Holiday" / "Svátek").
@@ -1480,6 +1503,7 @@ mod tests {
                 Key::BookingWarningOnAbsenceDay,
                 Key::BookingWarningOnUnavailableDay,
                 Key::BookingWarningPaidLimitExceeded,
+                Key::BookingWarningQualificationNotCovered,
             ] {
                 let value = i18n.t(key);
                 assert!(
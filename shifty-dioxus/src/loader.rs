@@ -745,6 +745,7 @@ pub async fn generate_invitation(
 ) -> Result<InvitationResponse, ShiftyError> {
     let request = GenerateInvitationRequest {
         username: username.to_string(),
+        email: None,
         expiration_hours,
     };
     let invitation = api::generate_invitation(config.clone(), request).await?;
@@ -1023,6 +1024,7 @@ mod working_hours_mini_loader_tests {
                 background_color: Arc::<str>::from(color),
                 is_paid: Some(true),
                 inactive: false,
+                personnel_number: None,
                 deleted: None,
                 version: Uuid::nil(),
             },
@@ -1297,6 +1297,8 @@ pub fn AbsenceModal(props: AbsenceModalProps) -> Element {
             day_fraction: (&*day_fraction.read()).into(),
             // Read-only Anzeige-Feld; auf Create/Update vom Backend ignoriert.
             derived_days: 0.0,
+            // Status setzt das Backend (HR → genehmigt, sonst Antrag).
+            status: Default::default(),
         };
         let action = match &mode_for_submit {
             AbsenceModalMode::Create => AbsenceAction::Create(body),
@@ -3125,6 +3127,7 @@ mod tests {
                 background_color: Rc::<str>::from("#00ff00"),
                 is_paid: true,
                 inactive: false,
+                personnel_number: None,
                 version: Uuid::nil(),
             };
             let other_sp = SalesPerson {
@@ -3133,6 +3136,7 @@ mod tests {
                 background_color: Rc::<str>::from("#ff0000"),
                 is_paid: true,
                 inactive: false,
+                personnel_number: None,
                 version: Uuid::nil(),
             };
             let sales_persons: Rc<[SalesPerson]> = Rc::from([target_sp, other_sp]);
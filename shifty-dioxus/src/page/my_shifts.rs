@@ -340,6 +340,7 @@ mod tests {
             background_color: Arc::<str>::from(color),
             is_paid: None,
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }
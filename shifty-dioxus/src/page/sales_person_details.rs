@@ -186,6 +186,29 @@ pub fn SalesPersonDetails(props: SalesPersonDetailsProps) -> Element {
                                 }
                             }
 
+                            FormPair { label: i18n.t(Key::PersonnelNumber),
+                                TextInput {
+                                    value: sales_person.sales_person.personnel_number.clone().unwrap_or_else(|| "".into()).into(),
+                                    on_change: {
+                                        to_owned![user_management_service, sales_person];
+                                        move |personnel_number: ImStr| {
+                                            let personnel_number = personnel_number.as_rc();
+                                            user_management_service
+                                                .send(
+                                                    UserManagementAction::UpdateSalesPerson(SalesPerson {
+                                                        personnel_number: if personnel_number.trim().is_empty() {
+                                                            None
+                                                        } else {
+                                                            Some(personnel_number)
+                                                        },
+                                                        ..sales_person.sales_person.clone()
+                                                    }),
+                                                );
+                                        }
+                                    },
+                                }
+                            }
+
                             FormPair { label: i18n.t(Key::UserAccount),
                                 if let Some(user_id) = &sales_person.user_id {
                                     div { class: "flex gap-2",
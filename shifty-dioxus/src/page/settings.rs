@@ -10,7 +10,7 @@
 use time::macros::format_description;
 
 use dioxus::prelude::*;
-use rest_types::{DayOfWeekTO, SpecialDayTO, SpecialDayTypeTO};
+use rest_types::{DayOfWeekTO, HolidayCandidateTO, HolidayRegionTO, SpecialDayTO, SpecialDayTypeTO};
 use uuid::Uuid;
 
 use crate::{
@@ -156,6 +156,47 @@ pub(crate) fn sd_year_after_create(date_str: &str) -> Option<u32> {
     Some(date.year() as u32)
 }
 
+/// Regions of the Card-3 holiday generator as `(region, select value, label)`.
+/// The select value is the ISO 3166-2 code the backend expects; the state names
+/// stay German in every locale. `Germany` is labelled via i18n instead.
+pub(crate) const HOLIDAY_REGIONS: [(HolidayRegionTO, &str, &str); 17] = [
+    (HolidayRegionTO::Germany, "DE", ""),
+    (HolidayRegionTO::BadenWuerttemberg, "DE-BW", "Baden-Württemberg"),
+    (HolidayRegionTO::Bavaria, "DE-BY", "Bayern"),
+    (HolidayRegionTO::Berlin, "DE-BE", "Berlin"),
+    (HolidayRegionTO::Brandenburg, "DE-BB", "Brandenburg"),
+    (HolidayRegionTO::Bremen, "DE-HB", "Bremen"),
+    (HolidayRegionTO::Hamburg, "DE-HH", "Hamburg"),
+    (HolidayRegionTO::Hesse, "DE-HE", "Hessen"),
+    (HolidayRegionTO::MecklenburgWesternPomerania, "DE-MV", "Mecklenburg-Vorpommern"),
+    (HolidayRegionTO::LowerSaxony, "DE-NI", "Niedersachsen"),
+    (HolidayRegionTO::NorthRhineWestphalia, "DE-NW", "Nordrhein-Westfalen"),
+    (HolidayRegionTO::RhinelandPalatinate, "DE-RP", "Rheinland-Pfalz"),
+    (HolidayRegionTO::Saarland, "DE-SL", "Saarland"),
+    (HolidayRegionTO::Saxony, "DE-SN", "Sachsen"),
+    (HolidayRegionTO::SaxonyAnhalt, "DE-ST", "Sachsen-Anhalt"),
+    (HolidayRegionTO::SchleswigHolstein, "DE-SH", "Schleswig-Holstein"),
+    (HolidayRegionTO::Thuringia, "DE-TH", "Thüringen"),
+];
+
+/// Select value → region; unknown values fall back to nationwide.
+pub(crate) fn holiday_region_from_select_value(value: &str) -> HolidayRegionTO {
+    HOLIDAY_REGIONS
+        .iter()
+        .find(|(_, code, _)| *code == value)
+        .map(|(region, _, _)| *region)
+        .unwrap_or_default()
+}
+
+/// Region → select value (controlled binding of the region dropdown).
+pub(crate) fn holiday_region_select_value(region: HolidayRegionTO) -> &'static str {
+    HOLIDAY_REGIONS
+        .iter()
+        .find(|(r, _, _)| *r == region)
+        .map(|(_, code, _)| *code)
+        .unwrap_or("DE")
+}
+
 /// Visibility rule for the Card-3 "existiert bereits" duplicate hint (260702-jql).
 ///
 /// Since Phase 42 (D-42-01) the create-form fields are retained after a successful
@@ -451,6 +492,21 @@ mod tests {
         assert_eq!(sd_year_after_create("not-a-date"), None);
     }
 
+    /// Holiday generator: every select value maps back to its region and the
+    /// values match the backend's ISO 3166-2 serialization.
+    #[test]
+    fn holiday_region_select_value_round_trip() {
+        for (region, code, _) in HOLIDAY_REGIONS.iter() {
+            assert_eq!(holiday_region_select_value(*region), *code);
+            assert_eq!(holiday_region_from_select_value(code), *region);
+            assert_eq!(
+                serde_json::to_value(region).unwrap(),
+                serde_json::Value::String(code.to_string())
+            );
+        }
+        assert_eq!(holiday_region_from_select_value("XX"), HolidayRegionTO::Germany);
+    }
+
     // ── SDF-04 (Phase 43-01): duplicate-hint copy signals replace semantics ──
 
     /// SDF-04: the duplicate-hint text in de/en/cs must
@@ -827,6 +883,12 @@ pub fn SettingsPage() -> Element {
     // 260702-jql: suppress the "existiert bereits" hint right after a create (the
     // retained form self-matches). Cleared on the next real field edit.
     let mut sd_dup_hint_suppressed = use_signal(|| false);
+    // Holiday generator: preview list and the number of created days after apply.
+    let mut hc_region: Signal<HolidayRegionTO> = use_signal(HolidayRegionTO::default);
+    let mut hc_preview: Signal<Option<Vec<HolidayCandidateTO>>> = use_signal(|| None);
+    let mut hc_created: Signal<Option<usize>> = use_signal(|| None);
+    let mut hc_busy = use_signal(|| false);
+    let mut hc_error = use_signal(|| false);
 
     // Load year list (restarted after create/delete)
     let config_for_sd = config.clone();
@@ -946,6 +1008,59 @@ pub fn SettingsPage() -> Element {
         });
     };
 
+    // Holiday generator handlers. The preview is dropped whenever year or region
+    // change, so "apply" always acts on what the user has just seen.
+    let config_for_hc_preview = config.clone();
+    let on_holiday_preview = move |_| {
+        if *hc_busy.read() {
+            return;
+        }
+        let year = *sd_year.read();
+        let region = *hc_region.read();
+        hc_busy.set(true);
+        hc_error.set(false);
+        hc_created.set(None);
+        let cfg = config_for_hc_preview.clone();
+        spawn(async move {
+            match api::get_holiday_calendar_preview(cfg, year, region).await {
+                Ok(candidates) => hc_preview.set(Some(candidates.iter().cloned().collect())),
+                Err(_) => {
+                    hc_preview.set(None);
+                    hc_error.set(true);
+                }
+            }
+            hc_busy.set(false);
+        });
+    };
+
+    let config_for_hc_apply = config.clone();
+    let on_holiday_apply = move |_| {
+        if *hc_busy.read() {
+            return;
+        }
+        let year = *sd_year.read();
+        let region = *hc_region.read();
+        hc_busy.set(true);
+        hc_error.set(false);
+        let cfg = config_for_hc_apply.clone();
+        spawn(async move {
+            match api::generate_holiday_calendar(cfg, year, region).await {
+                Ok(created) => {
+                    hc_created.set(Some(created.len()));
+                    hc_preview.set(None);
+                    sd_resource.restart();
+                }
+                Err(_) => hc_error.set(true),
+            }
+            hc_busy.set(false);
+        });
+    };
+
+    let hc_preview_val = hc_preview.read().clone();
+    let hc_has_missing = hc_preview_val
+        .as_ref()
+        .is_some_and(|candidates| candidates.iter().any(|c| c.existing.is_none()));
+
     // ── Card 4: PDF-Export nach Nextcloud (Phase 48-05 EXP-02 / EXP-03) ───────
     // Admin-gated; sichtbar innerhalb des äußeren is_admin-Blocks. Kein
     // zusätzlicher Inner-Gate (D-48-UI-GATE).
@@ -1244,6 +1359,8 @@ pub fn SettingsPage() -> Element {
                                     if let Ok(y) = v.as_str().parse::<u32>() {
                                         if (2020..=2099).contains(&y) {
                                             sd_year.set(y);
+                                            hc_preview.set(None);
+                                            hc_created.set(None);
                                         }
                                     }
                                 },
@@ -1350,6 +1467,82 @@ pub fn SettingsPage() -> Element {
                         None => rsx! { },
                     }}
 
+                    // Row D2: Public-holiday generator (preview → apply for sd_year)
+                    div { class: "flex flex-col gap-2 pt-2 border-t border-border",
+                        span { class: "text-body text-ink font-semibold",
+                            "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarLabel)}"
+                        }
+                        span { class: "text-small text-ink-soft",
+                            {i18n.t(Key::SettingsSpecialDaysHolidayCalendarDescription)
+                                .replace("{year}", &sd_year.read().to_string())}
+                        }
+                        div { class: "flex flex-row items-end gap-2 flex-wrap",
+                            div { class: "flex flex-col gap-1",
+                                label { class: "text-small text-ink-muted",
+                                    "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarRegionLabel)}"
+                                }
+                                SelectInput {
+                                    value: Some(ImStr::from(holiday_region_select_value(*hc_region.read()))),
+                                    on_change: move |v: ImStr| {
+                                        hc_region.set(holiday_region_from_select_value(v.as_str()));
+                                        hc_preview.set(None);
+                                        hc_created.set(None);
+                                    },
+                                    for (_, code, name) in HOLIDAY_REGIONS.iter() {
+                                        if *code == "DE" {
+                                            option { value: "{code}", "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarNationwide)}" }
+                                        } else {
+                                            option { value: "{code}", "{name}" }
+                                        }
+                                    }
+                                }
+                            }
+                            Btn {
+                                variant: BtnVariant::Secondary,
+                                disabled: *hc_busy.read(),
+                                on_click: on_holiday_preview,
+                                "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarPreviewBtn)}"
+                            }
+                            Btn {
+                                variant: BtnVariant::Primary,
+                                disabled: *hc_busy.read() || !hc_has_missing,
+                                on_click: on_holiday_apply,
+                                "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarApplyBtn)}"
+                            }
+                        }
+                        if *hc_error.read() {
+                            span { class: "text-small text-bad",
+                                "{i18n.t(Key::SettingsSaveError)}"
+                            }
+                        }
+                        if let Some(count) = *hc_created.read() {
+                            span { class: "text-small text-ink-muted",
+                                {i18n.t(Key::SettingsSpecialDaysHolidayCalendarCreated)
+                                    .replace("{count}", &count.to_string())}
+                            }
+                        }
+                        if let Some(candidates) = hc_preview_val {
+                            div {
+                                for candidate in candidates.into_iter() {
+                                    div { class: "flex items-center justify-between py-1 border-b border-border",
+                                        span { class: "text-body text-ink",
+                                            "{i18n.format_date(&candidate.date)} — {candidate.name}"
+                                        }
+                                        if candidate.existing.is_some() {
+                                            span { class: "px-2 py-1 bg-surface-alt text-ink-muted text-micro uppercase rounded-full",
+                                                "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarExisting)}"
+                                            }
+                                        } else {
+                                            span { class: "px-2 py-1 bg-accent-soft text-accent text-micro uppercase rounded-full",
+                                                "{i18n.t(Key::SettingsSpecialDaysHolidayCalendarNew)}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     // Row E: Chronological year list (SPD-02 / D-33-08)
                     if sd_list.is_empty() {
                         // Empty state
@@ -576,6 +576,7 @@ mod tests {
 
     fn sample_sp(name: &str, is_paid: bool, inactive: bool) -> SalesPerson {
         SalesPerson {
+            personnel_number: None,
             id: Uuid::new_v4(),
             name: Rc::from(name),
             background_color: Rc::from("#dbe0ff"),
@@ -518,6 +518,7 @@ pub async fn user_management_service(mut rx: UnboundedReceiver<UserManagementAct
                     background_color: "#FFF".into(),
                     is_paid: false,
                     inactive: false,
+                    personnel_number: None,
                     version: Uuid::nil(),
                 };
                 USER_MANAGEMENT_STORE.write().sales_person =
@@ -191,6 +191,7 @@ mod tests {
             version: v,
             day_fraction: rest_types::DayFractionTO::Full,
             derived_days: 10.0,
+            status: rest_types::AbsenceStatusTO::Approved,
         };
         let state: AbsencePeriod = (&to).into();
         assert_eq!(state.id, id);
@@ -222,6 +223,7 @@ mod tests {
             version: Uuid::from_u128(30),
             day_fraction: rest_types::DayFractionTO::Half,
             derived_days: 0.5,
+            status: rest_types::AbsenceStatusTO::Approved,
         };
         let state: AbsencePeriod = (&to).into();
         assert_eq!(state.day_fraction, DayFraction::Half);
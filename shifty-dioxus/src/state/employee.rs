@@ -445,6 +445,7 @@ mod tests {
             background_color: "#fff".into(),
             is_paid: false,
             inactive: false,
+            personnel_number: None,
             version: Uuid::nil(),
         }
     }
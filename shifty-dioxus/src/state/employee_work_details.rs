@@ -63,6 +63,8 @@ pub struct EmployeeWorkDetails {
 
     pub committed_voluntary: f32,
 
+    pub use_actual_hours: bool,
+
     pub vacation_days: u8,
 
     pub created: Option<time::PrimitiveDateTime>,
@@ -93,6 +95,7 @@ impl EmployeeWorkDetails {
             cap_planned_hours_to_expected: false,
 
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
             vacation_days: 0,
 
@@ -180,6 +183,7 @@ impl TryFrom<&EmployeeWorkDetailsTO> for EmployeeWorkDetails {
             cap_planned_hours_to_expected: details.cap_planned_hours_to_expected,
 
             committed_voluntary: details.committed_voluntary,
+            use_actual_hours: details.use_actual_hours,
 
             vacation_days: details.vacation_days,
 
@@ -220,6 +224,7 @@ impl TryFrom<&EmployeeWorkDetails> for EmployeeWorkDetailsTO {
             cap_planned_hours_to_expected: details.cap_planned_hours_to_expected,
             // D-02: threaded in Phase 17 — committed_voluntary round-trips faithfully.
             committed_voluntary: details.committed_voluntary,
+            use_actual_hours: details.use_actual_hours,
 
             vacation_days: details.vacation_days,
 
@@ -254,6 +259,7 @@ mod employee_work_details_tests {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
@@ -127,6 +127,7 @@ pub struct SalesPerson {
     pub background_color: Rc<str>,
     pub is_paid: bool,
     pub inactive: bool,
+    pub personnel_number: Option<Rc<str>>,
     pub version: Uuid,
 }
 impl From<&SalesPersonTO> for SalesPerson {
@@ -137,6 +138,10 @@ impl From<&SalesPersonTO> for SalesPerson {
             background_color: sales_person.background_color.as_ref().into(),
             is_paid: sales_person.is_paid.unwrap_or(false),
             inactive: sales_person.inactive,
+            personnel_number: sales_person
+                .personnel_number
+                .as_ref()
+                .map(|personnel_number| personnel_number.as_ref().into()),
             version: sales_person.version,
         }
     }
@@ -149,6 +154,10 @@ impl From<&SalesPerson> for SalesPersonTO {
             background_color: sales_person.background_color.to_string().into(),
             is_paid: Some(sales_person.is_paid),
             inactive: sales_person.inactive,
+            personnel_number: sales_person
+                .personnel_number
+                .as_ref()
+                .map(|personnel_number| personnel_number.to_string().into()),
             deleted: None,
             version: sales_person.version,
         }
@@ -72,6 +72,7 @@ mod working_hours_mapping_tests {
             background_color: "".into(),
             is_paid: Some(false),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }
@@ -181,6 +182,7 @@ mod employee_work_details_cap_tests {
             // required field at HEAD but this test literal predates it. 0.0 keeps the
             // cap-gating assertions unchanged (Phase 17 owns committed editor wiring).
             committed_voluntary: 0.0,
+            use_actual_hours: false,
             monday: true,
             tuesday: true,
             wednesday: true,
@@ -1,4 +1,5 @@
 use std::fmt::{Display, Formatter};
+use serde::Serialize;
 use thiserror::*;
 
 use time::Weekday;
@@ -86,7 +87,7 @@ impl Iterator for DateRangeIterator {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Serialize)]
 pub enum DayOfWeek {
     Monday,
     Tuesday,
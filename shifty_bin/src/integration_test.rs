@@ -40,6 +40,7 @@ prop_compose! {
             background_color: background_color.into(),
             is_paid,
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::new_v4(),
         }
@@ -75,6 +76,7 @@ prop_compose! {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
             monday: true,
             tuesday: true,
@@ -563,6 +565,7 @@ proptest! {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 workdays_per_week: 6,
                 monday: true,
@@ -693,6 +696,7 @@ proptest! {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 // 31st of December 2005
                 to_year: 2005,
@@ -843,6 +847,7 @@ fn test_vacation_at_end_of_year() {
             background_color: "#000000".into(),
             is_paid: Some(true),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }];
@@ -859,6 +864,7 @@ fn test_vacation_at_end_of_year() {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
             workdays_per_week: 5,
             monday: true,
             tuesday: true,
@@ -979,6 +985,7 @@ fn test_multiple_contracts() {
             background_color: "#000000".into(),
             is_paid: Some(true),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }];
@@ -999,6 +1006,7 @@ fn test_multiple_contracts() {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 workdays_per_week: 6,
                 monday: true,
@@ -1032,6 +1040,7 @@ fn test_multiple_contracts() {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 workdays_per_week: 4,
                 monday: false,
@@ -1131,6 +1140,7 @@ fn test_extra_hours_beginning_of_year() {
             background_color: "#000000".into(),
             is_paid: Some(true),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }];
@@ -1150,6 +1160,7 @@ fn test_extra_hours_beginning_of_year() {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
             workdays_per_week: 6,
             monday: true,
@@ -1242,6 +1253,7 @@ fn test_vacation_entitlement_calculcation() {
             background_color: "#000000".into(),
             is_paid: Some(true),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }];
@@ -1262,6 +1274,7 @@ fn test_vacation_entitlement_calculcation() {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 workdays_per_week: 6,
                 monday: true,
@@ -1295,6 +1308,7 @@ fn test_vacation_entitlement_calculcation() {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 workdays_per_week: 6,
                 monday: true,
@@ -1328,6 +1342,7 @@ fn test_vacation_entitlement_calculcation() {
                 is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
                 workdays_per_week: 4,
                 monday: false,
@@ -1384,6 +1399,7 @@ fn test_shiftyplan_hours_end_of_year() {
             background_color: "#000000".into(),
             is_paid: Some(true),
             inactive: false,
+            personnel_number: None,
             deleted: None,
             version: Uuid::nil(),
         }];
@@ -1403,6 +1419,7 @@ fn test_shiftyplan_hours_end_of_year() {
             is_dynamic: false,
             cap_planned_hours_to_expected: false,
             committed_voluntary: 0.0,
+            use_actual_hours: false,
 
             workdays_per_week: 6,
             monday: true,
@@ -1475,4 +1492,10 @@ mod extra_hours_update;
 mod feature_flag;
 #[cfg(test)]
 mod voluntary_stats;
+#[cfg(test)]
+mod shiftplan_generator;
+#[cfg(test)]
+mod shift_swap;
+#[cfg(test)]
+mod sales_person_availability;
 mod dev_seed;
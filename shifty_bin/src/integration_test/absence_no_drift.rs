@@ -20,7 +20,7 @@
 //! keine In-Memory-SQLite-Tests beherbergen.
 
 use rest::RestStateDef;
-use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction};
+use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction};
 use service::permission::Authentication;
 use service::sales_person::{SalesPerson, SalesPersonService};
 use time::macros::date;
@@ -39,6 +39,7 @@ async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson
                 name: name.into(),
                 background_color: "#000000".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -129,6 +130,7 @@ async fn absence_period_create_and_select_with_half_persists_correctly() {
                 deleted: None,
                 version: Uuid::nil(),
                 day_fraction: DayFraction::Half,
+                status: AbsenceStatus::Approved,
             },
             Authentication::Full,
             None,
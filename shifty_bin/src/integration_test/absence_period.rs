@@ -7,7 +7,7 @@
 //! d.h. der Service-Pfad ist nicht durch die Permission-Middleware gegated.
 
 use rest::RestStateDef;
-use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction};
+use service::absence::{AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction};
 use service::permission::Authentication;
 use service::sales_person::{SalesPerson, SalesPersonService};
 use sqlx::Row;
@@ -27,6 +27,7 @@ async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson
                 name: name.into(),
                 background_color: "#000000".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -53,6 +54,7 @@ async fn create_absence_period(test_setup: &TestSetup, sales_person_id: Uuid) ->
                 deleted: None,
                 version: Uuid::nil(),
                 day_fraction: DayFraction::Full,
+                status: AbsenceStatus::Approved,
             },
             Authentication::Full,
             None,
@@ -243,6 +245,7 @@ async fn test_create_overlapping_same_category_returns_validation_error() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let result = test_setup
         .rest_state
@@ -275,6 +278,7 @@ async fn test_create_overlapping_different_category_succeeds() {
         deleted: None,
         version: Uuid::nil(),
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let result = test_setup
         .rest_state
@@ -307,6 +311,7 @@ async fn test_update_can_extend_range_without_self_collision() {
         deleted: None,
         version: initial.version,
         day_fraction: DayFraction::Full,
+        status: AbsenceStatus::Approved,
     };
     let result = test_setup
         .rest_state
@@ -16,7 +16,7 @@
 
 use rest::RestStateDef;
 use service::{
-    absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction},
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction},
     extra_hours::{ExtraHours, ExtraHoursCategory, ExtraHoursService},
     permission::Authentication,
     sales_person::{SalesPerson, SalesPersonService},
@@ -40,6 +40,7 @@ async fn seed_vacation_marker(test_setup: &TestSetup) -> (Uuid, Uuid) {
                 name: "ProjektionPerson".into(),
                 background_color: "#aabbcc".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -271,6 +272,7 @@ async fn absence_projection_coexists_with_absence_period() {
                 to_date: date!(2026 - 05 - 05),
                 category: AbsenceCategory::Vacation,
                 day_fraction: DayFraction::Full,
+                status: AbsenceStatus::Approved,
                 description: "Nativurlaub".into(),
                 created: None,
                 deleted: None,
@@ -27,6 +27,7 @@ async fn test_custom_report_generation_end_to_end() {
         name: "Natalie".into(),
         background_color: "#000000".into(),
         inactive: false,
+        personnel_number: None,
         is_paid: Some(true),
         deleted: None,
     };
@@ -37,6 +38,7 @@ async fn test_custom_report_generation_end_to_end() {
         name: "Dany".into(),
         background_color: "#000000".into(),
         inactive: false,
+        personnel_number: None,
         is_paid: Some(true),
         deleted: None,
     };
@@ -192,6 +194,7 @@ async fn test_custom_report_with_custom_extra_hours() {
         name: "John".into(),
         background_color: "#000000".into(),
         inactive: false,
+        personnel_number: None,
         is_paid: Some(true),
         deleted: None,
     };
@@ -11,7 +11,7 @@
 
 use rest::RestStateDef;
 use service::{
-    absence::{AbsenceCategory, AbsencePeriod, AbsenceService, DayFraction},
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction},
     booking::{Booking, BookingService},
     permission::Authentication,
     sales_person::{SalesPerson, SalesPersonService},
@@ -42,6 +42,7 @@ async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson
                 name: name.into(),
                 background_color: "#000000".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -122,6 +123,7 @@ async fn create_absence_period_at(
                 deleted: None,
                 version: Uuid::nil(),
                 day_fraction: DayFraction::Full,
+                status: AbsenceStatus::Approved,
             },
             Authentication::Full,
             None,
@@ -45,6 +45,7 @@ async fn seed_vacation(test_setup: &TestSetup, name: &str) -> (Uuid, Uuid) {
                 name: name.into(),
                 background_color: "#112233".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
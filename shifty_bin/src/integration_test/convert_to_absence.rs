@@ -36,6 +36,7 @@ async fn seed_vacation_extra_hours(test_setup: &TestSetup) -> (Uuid, Uuid) {
                 name: "TestPerson".into(),
                 background_color: "#112233".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -36,6 +36,7 @@ async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson
                 name: name.into(),
                 background_color: "#000000".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -61,6 +62,7 @@ fn ewd_template(sales_person_id: Uuid, expected_hours: f32) -> EmployeeWorkDetai
         is_dynamic: false,
         cap_planned_hours_to_expected: false,
         committed_voluntary: 0.0,
+        use_actual_hours: false,
         monday: true,
         tuesday: true,
         wednesday: true,
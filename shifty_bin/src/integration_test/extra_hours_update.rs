@@ -21,6 +21,7 @@ async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson
                 name: name.into(),
                 background_color: "#000000".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -0,0 +1,255 @@
+//! End-to-End-Integrationstests für wiederkehrende Verfügbarkeiten.
+//!
+//! - test_recurring_unavailable_warns_and_marks_day  (ganztägige Regel →
+//!   BookingOnUnavailableDay + ManualUnavailable-Marker)
+//! - test_time_window_outside_slot_no_warning         (Zeitfenster ohne
+//!   Überlappung → keine Warning, PartiallyUnavailable-Marker)
+//! - test_deleted_rule_no_longer_warns                (Soft-Delete)
+//!
+//! Pattern: TestSetup-In-Memory-SQLite + RestStateDef-Service-Calls (analog
+//! `shifty_bin/src/integration_test/shift_swap.rs`).
+
+use rest::RestStateDef;
+use service::{
+    booking::Booking,
+    permission::Authentication,
+    sales_person::{SalesPerson, SalesPersonService},
+    sales_person_availability::{
+        AvailabilityKind, SalesPersonAvailability, SalesPersonAvailabilityService,
+    },
+    shiftplan::{ShiftplanViewService, UnavailabilityMarker},
+    shiftplan_catalog::{Shiftplan, ShiftplanService},
+    shiftplan_edit::{BookingCreateResult, ShiftplanEditService},
+    slot::{Slot, SlotService},
+    warning::Warning,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::date;
+use time::Time;
+use uuid::Uuid;
+
+use crate::integration_test::TestSetup;
+
+// ---- Helpers -------------------------------------------------------------
+
+struct Fixture {
+    shiftplan_id: Uuid,
+    slot_id: Uuid,
+    sales_person_id: Uuid,
+}
+
+/// Schichtplan mit einem Montags-Slot 09:00–13:00 und einer Person.
+async fn setup_fixture(test_setup: &TestSetup) -> Fixture {
+    let shiftplan = test_setup
+        .rest_state
+        .shiftplan_service()
+        .create(
+            &Shiftplan {
+                id: Uuid::nil(),
+                name: "Laden".into(),
+                is_planning: false,
+                deleted: None,
+                version: Uuid::nil(),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    let slot = test_setup
+        .rest_state
+        .slot_service()
+        .create_slot(
+            &Slot {
+                id: Uuid::nil(),
+                day_of_week: DayOfWeek::Monday,
+                from: Time::from_hms(9, 0, 0).unwrap(),
+                to: Time::from_hms(13, 0, 0).unwrap(),
+                min_resources: 1,
+                max_paid_employees: None,
+                valid_from: date!(2024 - 01 - 01),
+                valid_to: None,
+                deleted: None,
+                version: Uuid::nil(),
+                shiftplan_id: Some(shiftplan.id),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    let sales_person = test_setup
+        .rest_state
+        .sales_person_service()
+        .create(
+            &SalesPerson {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                name: "Anna".into(),
+                background_color: "#000000".into(),
+                inactive: false,
+                personnel_number: None,
+                is_paid: Some(false),
+                deleted: None,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    Fixture {
+        shiftplan_id: shiftplan.id,
+        slot_id: slot.id,
+        sales_person_id: sales_person.id,
+    }
+}
+
+async fn create_monday_rule(
+    test_setup: &TestSetup,
+    sales_person_id: Uuid,
+    window: Option<(Time, Time)>,
+) -> SalesPersonAvailability {
+    test_setup
+        .rest_state
+        .sales_person_availability_service()
+        .create(
+            &SalesPersonAvailability {
+                id: Uuid::nil(),
+                sales_person_id,
+                day_of_week: DayOfWeek::Monday,
+                time_from: window.map(|(from, _)| from),
+                time_to: window.map(|(_, to)| to),
+                valid_from: date!(2026 - 01 - 01),
+                valid_to: None,
+                kind: AvailabilityKind::Unavailable,
+                created: None,
+                deleted: None,
+                version: Uuid::nil(),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn book_monday(test_setup: &TestSetup, fixture: &Fixture) -> BookingCreateResult {
+    test_setup
+        .rest_state
+        .shiftplan_edit_service()
+        .book_slot_with_conflict_check(
+            &Booking {
+                id: Uuid::nil(),
+                sales_person_id: fixture.sales_person_id,
+                slot_id: fixture.slot_id,
+                calendar_week: 17,
+                year: 2026,
+                created: None,
+                deleted: None,
+                created_by: None,
+                deleted_by: None,
+                version: Uuid::nil(),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn monday_marker(test_setup: &TestSetup, fixture: &Fixture) -> Option<UnavailabilityMarker> {
+    test_setup
+        .rest_state
+        .shiftplan_view_service()
+        .get_shiftplan_week_for_sales_person(
+            fixture.shiftplan_id,
+            2026,
+            17,
+            fixture.sales_person_id,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+        .days[0]
+        .unavailable
+        .clone()
+}
+
+// ---- Tests ---------------------------------------------------------------
+
+#[tokio::test]
+async fn test_recurring_unavailable_warns_and_marks_day() {
+    let test_setup = TestSetup::new().await;
+    let fixture = setup_fixture(&test_setup).await;
+    create_monday_rule(&test_setup, fixture.sales_person_id, None).await;
+
+    let result = book_monday(&test_setup, &fixture).await;
+    assert_eq!(result.warnings.len(), 1);
+    assert!(matches!(
+        result.warnings[0],
+        Warning::BookingOnUnavailableDay {
+            year: 2026,
+            week: 17,
+            day_of_week: DayOfWeek::Monday,
+            ..
+        }
+    ));
+
+    assert_eq!(
+        monday_marker(&test_setup, &fixture).await,
+        Some(UnavailabilityMarker::ManualUnavailable)
+    );
+}
+
+#[tokio::test]
+async fn test_time_window_outside_slot_no_warning() {
+    let test_setup = TestSetup::new().await;
+    let fixture = setup_fixture(&test_setup).await;
+    create_monday_rule(
+        &test_setup,
+        fixture.sales_person_id,
+        Some((
+            Time::from_hms(14, 0, 0).unwrap(),
+            Time::from_hms(18, 0, 0).unwrap(),
+        )),
+    )
+    .await;
+
+    let result = book_monday(&test_setup, &fixture).await;
+    assert!(result.warnings.is_empty(), "got {:?}", result.warnings);
+
+    match monday_marker(&test_setup, &fixture).await {
+        Some(UnavailabilityMarker::PartiallyUnavailable { windows }) => {
+            assert_eq!(windows.len(), 1);
+            assert_eq!(windows[0].from, Time::from_hms(14, 0, 0).unwrap());
+            assert_eq!(windows[0].to, Time::from_hms(18, 0, 0).unwrap());
+        }
+        other => panic!("expected PartiallyUnavailable marker, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_deleted_rule_no_longer_warns() {
+    let test_setup = TestSetup::new().await;
+    let fixture = setup_fixture(&test_setup).await;
+    let rule = create_monday_rule(&test_setup, fixture.sales_person_id, None).await;
+    test_setup
+        .rest_state
+        .sales_person_availability_service()
+        .delete(rule.id, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    let rules = test_setup
+        .rest_state
+        .sales_person_availability_service()
+        .get_all_for_sales_person(fixture.sales_person_id, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert!(rules.is_empty());
+
+    let result = book_monday(&test_setup, &fixture).await;
+    assert!(result.warnings.is_empty(), "got {:?}", result.warnings);
+    assert_eq!(monday_marker(&test_setup, &fixture).await, None);
+}
@@ -0,0 +1,266 @@
+//! End-to-End-Integrationstests für die Schichttausch-Börse.
+//!
+//! - test_offer_accept_approve_moves_booking    (Happy Path inkl. booking_log)
+//! - test_duplicate_offer_and_self_accept        (Fehlerfälle: 409 / 422)
+//! - test_reject_keeps_booking                   (Ablehnung lässt Booking stehen)
+//!
+//! Pattern: TestSetup-In-Memory-SQLite + RestStateDef-Service-Calls (analog
+//! `shifty_bin/src/integration_test/shiftplan_generator.rs`).
+
+use rest::RestStateDef;
+use service::{
+    booking::{Booking, BookingService},
+    booking_log::BookingLogService,
+    permission::Authentication,
+    sales_person::{SalesPerson, SalesPersonService},
+    shift_swap::{ShiftSwapService, ShiftSwapState},
+    shiftplan_catalog::{Shiftplan, ShiftplanService},
+    slot::{Slot, SlotService},
+    ServiceError,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::date;
+use time::Time;
+use uuid::Uuid;
+
+use crate::integration_test::TestSetup;
+
+// ---- Helpers -------------------------------------------------------------
+
+async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson {
+    test_setup
+        .rest_state
+        .sales_person_service()
+        .create(
+            &SalesPerson {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                name: name.into(),
+                background_color: "#000000".into(),
+                inactive: false,
+                personnel_number: None,
+                is_paid: Some(false),
+                deleted: None,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_shiftplan(test_setup: &TestSetup, name: &str) -> Shiftplan {
+    test_setup
+        .rest_state
+        .shiftplan_service()
+        .create(
+            &Shiftplan {
+                id: Uuid::nil(),
+                name: name.into(),
+                is_planning: false,
+                deleted: None,
+                version: Uuid::nil(),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_monday_slot(test_setup: &TestSetup, shiftplan_id: Uuid) -> Slot {
+    test_setup
+        .rest_state
+        .slot_service()
+        .create_slot(
+            &Slot {
+                id: Uuid::nil(),
+                day_of_week: DayOfWeek::Monday,
+                from: Time::from_hms(9, 0, 0).unwrap(),
+                to: Time::from_hms(13, 0, 0).unwrap(),
+                min_resources: 1,
+                max_paid_employees: None,
+                valid_from: date!(2024 - 01 - 01),
+                valid_to: None,
+                deleted: None,
+                version: Uuid::nil(),
+                shiftplan_id: Some(shiftplan_id),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_booking(test_setup: &TestSetup, sales_person_id: Uuid, slot_id: Uuid) -> Booking {
+    test_setup
+        .rest_state
+        .booking_service()
+        .create(
+            &Booking {
+                id: Uuid::nil(),
+                sales_person_id,
+                slot_id,
+                calendar_week: 17,
+                year: 2026,
+                created: None,
+                deleted: None,
+                created_by: None,
+                deleted_by: None,
+                version: Uuid::nil(),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+// ---- Tests ---------------------------------------------------------------
+
+#[tokio::test]
+async fn test_offer_accept_approve_moves_booking() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    let slot = create_monday_slot(&test_setup, shiftplan.id).await;
+    let anna = create_sales_person(&test_setup, "Anna").await;
+    let bert = create_sales_person(&test_setup, "Bert").await;
+    let booking = create_booking(&test_setup, anna.id, slot.id).await;
+
+    let swap = test_setup.rest_state.shift_swap_service();
+    let offered = swap
+        .offer(booking.id, "Arzttermin".into(), Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(offered.state, ShiftSwapState::Open);
+    assert_eq!(offered.offering_sales_person_id, anna.id);
+
+    let accepted = swap
+        .accept(offered.id, bert.id, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(accepted.state, ShiftSwapState::Accepted);
+
+    let result = swap
+        .approve(offered.id, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(result.request.state, ShiftSwapState::Approved);
+    assert_eq!(result.request.new_booking_id, Some(result.booking.id));
+    assert_eq!(result.booking.sales_person_id, bert.id);
+    assert_eq!(result.booking.slot_id, slot.id);
+
+    // Altes Booking ist gelöscht, das neue gehört Bert.
+    let bookings = test_setup
+        .rest_state
+        .booking_service()
+        .get_for_week(17, 2026, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings[0].id, result.booking.id);
+    assert_eq!(bookings[0].sales_person_id, bert.id);
+    let old = test_setup
+        .rest_state
+        .booking_service()
+        .get(booking.id, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert!(old.deleted.is_some());
+
+    // booking_log enthält Löschung (Anna) und Neuanlage (Bert).
+    let logs = test_setup
+        .rest_state
+        .booking_log_service()
+        .get_booking_logs_for_week(2026, 17, Authentication::Full, None)
+        .await
+        .unwrap();
+    let anna_log = logs.iter().find(|log| &*log.name == "Anna").unwrap();
+    assert!(anna_log.deleted.is_some());
+    let bert_log = logs.iter().find(|log| &*log.name == "Bert").unwrap();
+    assert!(bert_log.deleted.is_none());
+
+    // Abgeschlossene Anfragen verschwinden aus der Börse.
+    let pending = swap.get_pending(Authentication::Full, None).await.unwrap();
+    assert!(pending.is_empty());
+    let for_bert = swap
+        .get_for_sales_person(bert.id, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(for_bert.len(), 1);
+}
+
+#[tokio::test]
+async fn test_duplicate_offer_and_self_accept() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    let slot = create_monday_slot(&test_setup, shiftplan.id).await;
+    let anna = create_sales_person(&test_setup, "Anna").await;
+    let booking = create_booking(&test_setup, anna.id, slot.id).await;
+
+    let swap = test_setup.rest_state.shift_swap_service();
+    let offered = swap
+        .offer(booking.id, "".into(), Authentication::Full, None)
+        .await
+        .unwrap();
+
+    let duplicate = swap
+        .offer(booking.id, "".into(), Authentication::Full, None)
+        .await;
+    assert!(
+        matches!(duplicate, Err(ServiceError::EntityAlreadyExists(id)) if id == offered.id),
+        "expected EntityAlreadyExists, got {duplicate:?}"
+    );
+
+    let self_accept = swap
+        .accept(offered.id, anna.id, Authentication::Full, None)
+        .await;
+    assert!(
+        matches!(self_accept, Err(ServiceError::ValidationError(_))),
+        "expected ValidationError, got {self_accept:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_reject_keeps_booking() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    let slot = create_monday_slot(&test_setup, shiftplan.id).await;
+    let anna = create_sales_person(&test_setup, "Anna").await;
+    let bert = create_sales_person(&test_setup, "Bert").await;
+    let booking = create_booking(&test_setup, anna.id, slot.id).await;
+
+    let swap = test_setup.rest_state.shift_swap_service();
+    let offered = swap
+        .offer(booking.id, "".into(), Authentication::Full, None)
+        .await
+        .unwrap();
+    swap.accept(offered.id, bert.id, Authentication::Full, None)
+        .await
+        .unwrap();
+    let rejected = swap
+        .reject(
+            offered.id,
+            "Zu kurzfristig".into(),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(rejected.state, ShiftSwapState::Rejected);
+
+    let bookings = test_setup
+        .rest_state
+        .booking_service()
+        .get_for_week(17, 2026, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings[0].sales_person_id, anna.id);
+
+    // Nach der Ablehnung kann das Booking erneut angeboten werden.
+    swap.offer(booking.id, "".into(), Authentication::Full, None)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,256 @@
+//! End-to-End-Integrationstests für den Schichtplan-Generator.
+//!
+//! - test_propose_does_not_persist_and_accept_books   (Happy Path, Trockenlauf-Rollback)
+//! - test_propose_with_caller_transaction_does_not_persist (fremde Transaktion)
+//! - test_propose_skips_absent_sales_person           (AbsencePeriod-Filter)
+//! - test_accept_rejects_slot_of_other_shiftplan      (Fehlerfall: 422)
+//!
+//! Pattern: TestSetup-In-Memory-SQLite + RestStateDef-Service-Calls (analog
+//! `shifty_bin/src/integration_test/booking_absence_conflict.rs`).
+
+use std::sync::Arc;
+
+use dao::TransactionDao;
+use dao_impl_sqlite::TransactionDaoImpl;
+use rest::RestStateDef;
+use service::{
+    absence::{AbsenceCategory, AbsencePeriod, AbsenceService, AbsenceStatus, DayFraction},
+    booking::BookingService,
+    permission::Authentication,
+    sales_person::{SalesPerson, SalesPersonService},
+    shiftplan_catalog::{Shiftplan, ShiftplanService},
+    shiftplan_generator::ShiftplanGeneratorService,
+    slot::{Slot, SlotService},
+    ServiceError,
+};
+use shifty_utils::DayOfWeek;
+use time::macros::date;
+use time::Time;
+use uuid::Uuid;
+
+use crate::integration_test::TestSetup;
+
+// ---- Helpers -------------------------------------------------------------
+
+async fn create_sales_person(test_setup: &TestSetup, name: &str) -> SalesPerson {
+    test_setup
+        .rest_state
+        .sales_person_service()
+        .create(
+            &SalesPerson {
+                id: Uuid::nil(),
+                version: Uuid::nil(),
+                name: name.into(),
+                background_color: "#000000".into(),
+                inactive: false,
+                personnel_number: None,
+                is_paid: Some(false),
+                deleted: None,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_shiftplan(test_setup: &TestSetup, name: &str) -> Shiftplan {
+    test_setup
+        .rest_state
+        .shiftplan_service()
+        .create(
+            &Shiftplan {
+                id: Uuid::nil(),
+                name: name.into(),
+                is_planning: false,
+                deleted: None,
+                version: Uuid::nil(),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_monday_slot(test_setup: &TestSetup, shiftplan_id: Uuid) -> Slot {
+    test_setup
+        .rest_state
+        .slot_service()
+        .create_slot(
+            &Slot {
+                id: Uuid::nil(),
+                day_of_week: DayOfWeek::Monday,
+                from: Time::from_hms(9, 0, 0).unwrap(),
+                to: Time::from_hms(13, 0, 0).unwrap(),
+                min_resources: 1,
+                max_paid_employees: None,
+                valid_from: date!(2024 - 01 - 01),
+                valid_to: None,
+                deleted: None,
+                version: Uuid::nil(),
+                shiftplan_id: Some(shiftplan_id),
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+// ---- Tests ---------------------------------------------------------------
+
+#[tokio::test]
+async fn test_propose_does_not_persist_and_accept_books() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    let slot = create_monday_slot(&test_setup, shiftplan.id).await;
+    let anna = create_sales_person(&test_setup, "Anna").await;
+
+    let generator = test_setup.rest_state.shiftplan_generator_service();
+    let proposal = generator
+        .propose_week(shiftplan.id, 2026, 17, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(proposal.bookings.len(), 1);
+    assert_eq!(proposal.bookings[0].sales_person_id, anna.id);
+    assert_eq!(proposal.bookings[0].slot_id, slot.id);
+    assert!(proposal.unfilled_slots.is_empty());
+
+    // Trockenlauf wurde zurückgerollt.
+    let bookings = test_setup
+        .rest_state
+        .booking_service()
+        .get_for_week(17, 2026, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert!(bookings.is_empty());
+
+    let result = generator
+        .accept_proposal(
+            shiftplan.id,
+            2026,
+            17,
+            proposal.bookings.clone(),
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.copied_bookings.len(), 1);
+
+    let bookings = test_setup
+        .rest_state
+        .booking_service()
+        .get_for_week(17, 2026, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings[0].sales_person_id, anna.id);
+}
+
+#[tokio::test]
+async fn test_propose_with_caller_transaction_does_not_persist() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    create_monday_slot(&test_setup, shiftplan.id).await;
+    create_sales_person(&test_setup, "Anna").await;
+
+    let transaction_dao = TransactionDaoImpl::new(test_setup.pool.clone());
+    let tx = transaction_dao.new_transaction().await.unwrap();
+    let proposal = test_setup
+        .rest_state
+        .shiftplan_generator_service()
+        .propose_week(
+            shiftplan.id,
+            2026,
+            17,
+            Authentication::Full,
+            Some(tx.clone()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(proposal.bookings.len(), 1);
+    transaction_dao.commit(tx).await.unwrap();
+
+    let bookings = test_setup
+        .rest_state
+        .booking_service()
+        .get_for_week(17, 2026, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert!(bookings.is_empty());
+}
+
+#[tokio::test]
+async fn test_propose_skips_absent_sales_person() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    create_monday_slot(&test_setup, shiftplan.id).await;
+    let anna = create_sales_person(&test_setup, "Anna").await;
+    let bert = create_sales_person(&test_setup, "Bert").await;
+
+    // 2026-W17 Montag = 2026-04-20.
+    test_setup
+        .rest_state
+        .absence_service()
+        .create(
+            &AbsencePeriod {
+                id: Uuid::nil(),
+                sales_person_id: anna.id,
+                category: AbsenceCategory::Vacation,
+                from_date: date!(2026 - 04 - 20),
+                to_date: date!(2026 - 04 - 24),
+                description: "Urlaub".into(),
+                created: None,
+                deleted: None,
+                version: Uuid::nil(),
+                day_fraction: DayFraction::Full,
+                status: AbsenceStatus::Approved,
+            },
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let proposal = test_setup
+        .rest_state
+        .shiftplan_generator_service()
+        .propose_week(shiftplan.id, 2026, 17, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert_eq!(proposal.bookings.len(), 1);
+    assert_eq!(proposal.bookings[0].sales_person_id, bert.id);
+    assert!(proposal.warnings.is_empty());
+}
+
+#[tokio::test]
+async fn test_accept_rejects_slot_of_other_shiftplan() {
+    let test_setup = TestSetup::new().await;
+    let shiftplan = create_shiftplan(&test_setup, "Laden").await;
+    let other_shiftplan = create_shiftplan(&test_setup, "Lager").await;
+    create_monday_slot(&test_setup, shiftplan.id).await;
+    let other_slot = create_monday_slot(&test_setup, other_shiftplan.id).await;
+    create_sales_person(&test_setup, "Anna").await;
+
+    let generator = test_setup.rest_state.shiftplan_generator_service();
+    let proposal = generator
+        .propose_week(other_shiftplan.id, 2026, 17, Authentication::Full, None)
+        .await
+        .unwrap();
+    assert_eq!(proposal.bookings[0].slot_id, other_slot.id);
+
+    let result = generator
+        .accept_proposal(
+            shiftplan.id,
+            2026,
+            17,
+            Arc::clone(&proposal.bookings),
+            Authentication::Full,
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(ServiceError::ValidationError(_))));
+}
@@ -30,8 +30,11 @@ use service::extra_hours::{
     ExtraHours, ExtraHoursCategory, ExtraHoursService, ExtraHoursSource,
 };
 use service::permission::Authentication;
+use service::rebooking_reconciliation::RebookingReconciliationService;
+use service::reporting::ReportingService;
 use service::sales_person::{SalesPerson, SalesPersonService};
-use shifty_utils::DayOfWeek;
+use service::voluntary_stats::VoluntaryStatsService;
+use shifty_utils::{DayOfWeek, ShiftyDate};
 use time::macros::date;
 use time::PrimitiveDateTime;
 use tower::ServiceExt;
@@ -60,6 +63,7 @@ async fn seed_voluntary_scenario(test_setup: &TestSetup) -> Uuid {
                 name: "VOL-TESTPERSON".into(),
                 background_color: "#112233".into(),
                 inactive: false,
+                personnel_number: None,
                 is_paid: Some(true),
                 deleted: None,
             },
@@ -94,6 +98,7 @@ async fn seed_voluntary_scenario(test_setup: &TestSetup) -> Uuid {
                 is_dynamic: false,
                 cap_planned_hours_to_expected: false,
                 committed_voluntary: 2.0,
+                use_actual_hours: false,
                 vacation_days: 25,
                 created: None,
                 deleted: None,
@@ -274,3 +279,76 @@ async fn rest_voluntary_stats_non_hr_returns_all_null() {
         "Non-HR response body must serialize null-fields explicitly; got: {body_str}"
     );
 }
+
+/// VOL-ACCT-03: Ein genehmigter Rebooking-Batch schreibt `-h VolunteerWork` +
+/// `+h ExtraWork` (beide `source = Rebooking`). Die Balance steigt um `h`,
+/// `volunteer_hours` und F1/F2 bleiben dabei unveraendert.
+#[tokio::test]
+async fn approved_rebooking_batch_moves_hours_into_balance() {
+    let test_setup = TestSetup::new().await;
+    let sales_person_id = seed_voluntary_scenario(&test_setup).await;
+    let from_date = ShiftyDate::new(2026, 1, DayOfWeek::Monday).unwrap();
+    let to_date = ShiftyDate::new(2026, 13, DayOfWeek::Sunday).unwrap();
+
+    let reporting = test_setup.rest_state.reporting_service();
+    let voluntary_stats = test_setup.rest_state.voluntary_stats_service();
+    let report_before = reporting
+        .get_report_for_employee_range(
+            &sales_person_id,
+            from_date,
+            to_date,
+            true,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    let stats_before = voluntary_stats
+        .get_voluntary_stats(sales_person_id, from_date, to_date, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    let rebooking = test_setup.rest_state.rebooking_reconciliation_service();
+    let batch = rebooking
+        .suggest(sales_person_id, 2026, 11, Some(4.0), Authentication::Full, None)
+        .await
+        .unwrap();
+    let hours: f32 = batch.entries.iter().map(|entry| entry.hours).sum();
+    assert!(hours > 0.0, "batch must rebook hours");
+    rebooking
+        .approve(batch.id, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    let report_after = reporting
+        .get_report_for_employee_range(
+            &sales_person_id,
+            from_date,
+            to_date,
+            true,
+            Authentication::Full,
+            None,
+        )
+        .await
+        .unwrap();
+    let stats_after = voluntary_stats
+        .get_voluntary_stats(sales_person_id, from_date, to_date, Authentication::Full, None)
+        .await
+        .unwrap();
+
+    assert!(
+        (report_before.balance_hours + hours - report_after.balance_hours).abs() < 1e-3,
+        "balance must grow by {hours}: {} -> {}",
+        report_before.balance_hours,
+        report_after.balance_hours
+    );
+    assert!(
+        (report_before.volunteer_hours - report_after.volunteer_hours).abs() < 1e-3,
+        "volunteer_hours must stay unchanged: {} != {}",
+        report_before.volunteer_hours,
+        report_after.volunteer_hours
+    );
+    assert_eq!(stats_before.ist_total, stats_after.ist_total);
+    assert_eq!(stats_before.soll_total, stats_after.soll_total);
+    assert_eq!(stats_before.delta, stats_after.delta);
+}
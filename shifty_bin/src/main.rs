@@ -1,9 +1,18 @@
-#[cfg(test)]
+// Die Integrationstests arbeiten mit einer In-Memory-SQLite-Datenbank.
+#[cfg(all(test, not(feature = "postgres")))]
 mod integration_test;
 
 use std::sync::Arc;
 
-use dao_impl_sqlite::{
+// Das DAO-Backend wird per Cargo-Feature gewählt: SQLite (Standard) oder
+// PostgreSQL mit `--features postgres`. Beide Crates exportieren dieselben
+// Typnamen, daher reicht ein Alias.
+#[cfg(feature = "postgres")]
+use dao_impl_postgres as dao_impl;
+#[cfg(not(feature = "postgres"))]
+use dao_impl_sqlite as dao_impl;
+
+use dao_impl::{
     absence::AbsenceDaoImpl,
     billing_period::BillingPeriodDaoImpl,
     billing_period_sales_person::BillingPeriodSalesPersonDaoImpl, booking::BookingDaoImpl,
@@ -14,8 +23,12 @@ use dao_impl_sqlite::{
     shiftplan_report::ShiftplanReportDaoImpl, slot::SlotDaoImpl, special_day::SpecialDayDaoImpl,
     BasicDaoImpl, PermissionDaoImpl, TransactionDaoImpl, TransactionImpl,
 };
+use service::export_target_scheduler::ExportTargetScheduler as _;
 use service::pdf_export::PdfExportScheduler as _;
 use service::scheduler::SchedulerService;
+use service_impl::export_destination::{
+    ExportDestinationFactory, ProductionExportDestinationFactory,
+};
 use service_impl::pdf_export_scheduler::{
     PdfExportSchedulerDeps, PdfExportSchedulerImpl, ProductionWebDavUploadFactory,
     WebDavUploadFactory,
@@ -26,10 +39,19 @@ use service_impl::{
     shiftplan::{ShiftplanViewServiceDeps, ShiftplanViewServiceImpl},
     shiftplan_catalog::{ShiftplanServiceDeps, ShiftplanServiceImpl},
 };
-use sqlx::SqlitePool;
 #[cfg(feature = "json_logging")]
 use tracing_subscriber::fmt::format::FmtSpan;
 
+#[cfg(feature = "postgres")]
+type DbPool = sqlx::PgPool;
+#[cfg(not(feature = "postgres"))]
+type DbPool = sqlx::SqlitePool;
+
+#[cfg(feature = "postgres")]
+const DEFAULT_DATABASE_URL: &str = "postgres://localhost/shifty";
+#[cfg(not(feature = "postgres"))]
+const DEFAULT_DATABASE_URL: &str = "sqlite:./localdb.sqlite3";
+
 type UserService = service_impl::UserServiceImpl;
 type Context = Option<Arc<str>>;
 type Transaction = TransactionImpl;
@@ -45,26 +67,46 @@ type ShiftplanReportDao = ShiftplanReportDaoImpl;
 type AbsenceDao = AbsenceDaoImpl;
 type ExtraHoursDao = ExtraHoursDaoImpl;
 type FeatureFlagDao = FeatureFlagDaoImpl;
-type MigrationSourceDao = dao_impl_sqlite::migration_source::MigrationSourceDaoImpl;
+type MigrationSourceDao = dao_impl::migration_source::MigrationSourceDaoImpl;
 type CarryoverDao = CarryoverDaoImpl;
 type EmployeeWorkDetailsDao = EmployeeWorkDetailsDaoImpl;
-type WeekMessageDao = dao_impl_sqlite::week_message::WeekMessageDaoImpl;
-type WeekStatusDao = dao_impl_sqlite::week_status::WeekStatusDaoImpl;
+type WeekMessageDao = dao_impl::week_message::WeekMessageDaoImpl;
+type WeekStatusDao = dao_impl::week_status::WeekStatusDaoImpl;
 type BillingPeriodDao = BillingPeriodDaoImpl;
 type BillingPeriodSalesPersonDao = BillingPeriodSalesPersonDaoImpl;
-type TextTemplateDao = dao_impl_sqlite::text_template::TextTemplateDaoImpl;
-type UserInvitationDao = dao_impl_sqlite::user_invitation::UserInvitationDaoImpl;
-type ToggleDao = dao_impl_sqlite::toggle::ToggleDaoImpl;
-type ShiftplanDao = dao_impl_sqlite::shiftplan::ShiftplanDaoImpl;
+type TextTemplateDao = dao_impl::text_template::TextTemplateDaoImpl;
+type UserInvitationDao = dao_impl::user_invitation::UserInvitationDaoImpl;
+type ToggleDao = dao_impl::toggle::ToggleDaoImpl;
+type ShiftplanDao = dao_impl::shiftplan::ShiftplanDaoImpl;
 // Phase 28 (VAC-OFFSET-01): Basic-Offset-DAO für den Urlaubsanspruch-Offset.
 type VacationEntitlementOffsetDao =
-    dao_impl_sqlite::vacation_entitlement_offset::VacationEntitlementOffsetDaoImpl;
+    dao_impl::vacation_entitlement_offset::VacationEntitlementOffsetDaoImpl;
 // Phase 54 (D-54-DM-01): Basic-Tier Rebooking-Batch-DAO.
-type RebookingBatchDao = dao_impl_sqlite::rebooking_batch::RebookingBatchDaoImpl;
+type RebookingBatchDao = dao_impl::rebooking_batch::RebookingBatchDaoImpl;
 // Phase 48 (EXP-02/EXP-03): Basic-Config-DAO für den Nextcloud-PDF-Export.
-type PdfExportConfigDao = dao_impl_sqlite::pdf_export_config::PdfExportConfigDaoImpl;
+type PdfExportConfigDao = dao_impl::pdf_export_config::PdfExportConfigDaoImpl;
+// Benannte Export-Ziele und ihre Lauf-Historie.
+type ExportTargetDao = dao_impl::export_target::ExportTargetDaoImpl;
+// Registry der Hintergrund-Jobs (Cron-Ausdruck, aktiv, Status des letzten Laufs).
+type SchedulerJobDao = dao_impl::scheduler_job::SchedulerJobDaoImpl;
+// Qualifikations-Katalog, Zuordnung zu Mitarbeitern, Anforderungen pro Slot.
+type QualificationDao = dao_impl::qualification::QualificationDaoImpl;
+// Geheime, widerrufbare Tokens für den öffentlichen iCal-Feed.
+type IcalFeedTokenDao = dao_impl::ical_feed_token::IcalFeedTokenDaoImpl;
+// Schichttausch-Börse: Basic-DAO für die Tausch-/Abgabe-Anfragen.
+type ShiftSwapRequestDao = dao_impl::shift_swap::ShiftSwapRequestDaoImpl;
+type SalesPersonAvailabilityDao =
+    dao_impl::sales_person_availability::SalesPersonAvailabilityDaoImpl;
+type NotificationDao = dao_impl::notification::NotificationDaoImpl;
+type WebhookDao = dao_impl::webhook::WebhookDaoImpl;
+type TimeEntryDao = dao_impl::time_entry::TimeEntryDaoImpl;
+type PayrollWageTypeDao = dao_impl::payroll_wage_type::PayrollWageTypeDaoImpl;
+type AuditLogDao = dao_impl::audit_log::AuditLogDaoImpl;
 
 type ConfigService = service_impl::config::ConfigServiceImpl;
+type MailTransport = service_impl::mail_transport::SmtpMailTransport;
+type WebhookSender = service_impl::webhook_client::WebhookClient;
+type IcalFetcher = service_impl::ical_client::IcalClient;
 
 pub struct PermissionServiceDependencies;
 impl PermissionServiceDeps for PermissionServiceDependencies {
@@ -72,6 +114,10 @@ impl PermissionServiceDeps for PermissionServiceDependencies {
     type Transaction = Transaction;
     type PermissionDao = PermissionDao;
     type UserService = UserService;
+    type AuditLogDao = AuditLogDao;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type TransactionDao = TransactionDao;
 }
 type PermissionService = service_impl::PermissionServiceImpl<PermissionServiceDependencies>;
 
@@ -95,6 +141,8 @@ impl service_impl::user_invitation::UserInvitationServiceDeps for UserInvitation
     type SessionService = SessionService;
     type UuidService = service_impl::uuid_service::UuidServiceImpl;
     type TransactionDao = TransactionDao;
+    type NotificationService = NotificationService;
+    type AuditLogService = AuditLogService;
 }
 type UserInvitationService = service_impl::user_invitation::UserInvitationServiceImpl<UserInvitationServiceDependencies>;
 
@@ -105,6 +153,7 @@ type SlotService = service_impl::slot::SlotServiceImpl<
     PermissionService,
     ClockService,
     UuidService,
+    AuditLogService,
     TransactionDao,
 >;
 
@@ -116,6 +165,7 @@ impl service_impl::sales_person::SalesPersonServiceDeps for SalesPersonServiceDe
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type SalesPersonService =
@@ -125,8 +175,23 @@ type SpecialDayService = service_impl::special_days::SpecialDayServiceImpl<
     PermissionService,
     ClockService,
     UuidService,
+    AuditLogService,
+    SalesPersonService,
+    CarryoverService,
 >;
 
+pub struct HolidayCalendarServiceDependencies;
+impl service_impl::holiday_calendar::HolidayCalendarServiceDeps
+    for HolidayCalendarServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type SpecialDayService = SpecialDayService;
+    type PermissionService = PermissionService;
+}
+type HolidayCalendarService =
+    service_impl::holiday_calendar::HolidayCalendarServiceImpl<HolidayCalendarServiceDependencies>;
+
 pub struct SalesPersonUnavailableServiceDependencies;
 impl service_impl::sales_person_unavailable::SalesPersonUnavailableServiceDeps
     for SalesPersonUnavailableServiceDependencies
@@ -138,13 +203,33 @@ impl service_impl::sales_person_unavailable::SalesPersonUnavailableServiceDeps
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type SalesPersonUnavailableService =
     service_impl::sales_person_unavailable::SalesPersonUnavailableServiceImpl<
         SalesPersonUnavailableServiceDependencies,
     >;
-type SalesPersonShiftplanDao = dao_impl_sqlite::sales_person_shiftplan::SalesPersonShiftplanDaoImpl;
+
+pub struct SalesPersonAvailabilityServiceDependencies;
+impl service_impl::sales_person_availability::SalesPersonAvailabilityServiceDeps
+    for SalesPersonAvailabilityServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type SalesPersonAvailabilityDao = SalesPersonAvailabilityDao;
+    type SalesPersonService = SalesPersonService;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type SalesPersonAvailabilityService =
+    service_impl::sales_person_availability::SalesPersonAvailabilityServiceImpl<
+        SalesPersonAvailabilityServiceDependencies,
+    >;
+type SalesPersonShiftplanDao = dao_impl::sales_person_shiftplan::SalesPersonShiftplanDaoImpl;
 
 pub struct SalesPersonShiftplanServiceDependencies;
 impl service_impl::sales_person_shiftplan::SalesPersonShiftplanServiceDeps
@@ -155,6 +240,7 @@ impl service_impl::sales_person_shiftplan::SalesPersonShiftplanServiceDeps
     type SalesPersonShiftplanDao = SalesPersonShiftplanDao;
     type SalesPersonService = SalesPersonService;
     type PermissionService = PermissionService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type SalesPersonShiftplanService = service_impl::sales_person_shiftplan::SalesPersonShiftplanServiceImpl<SalesPersonShiftplanServiceDependencies>;
@@ -170,6 +256,9 @@ impl service_impl::booking::BookingServiceDeps for BookingServiceDependencies {
     type SalesPersonService = SalesPersonService;
     type SlotService = SlotService;
     type SalesPersonShiftplanService = SalesPersonShiftplanService;
+    type WebhookService = WebhookService;
+    type AuditLogService = AuditLogService;
+    type CarryoverService = CarryoverService;
     type TransactionDao = TransactionDao;
 }
 type BookingService = service_impl::booking::BookingServiceImpl<BookingServiceDependencies>;
@@ -180,11 +269,12 @@ impl service_impl::custom_extra_hours::CustomExtraHoursDeps
 {
     type Context = Context;
     type Transaction = Transaction;
-    type CustomExtraHoursDao = dao_impl_sqlite::custom_extra_hours::CustomExtraHoursDaoImpl;
+    type CustomExtraHoursDao = dao_impl::custom_extra_hours::CustomExtraHoursDaoImpl;
     type SalesPersonService = SalesPersonService;
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type CustomExtraHoursService = service_impl::custom_extra_hours::CustomExtraHoursServiceImpl<
@@ -222,6 +312,7 @@ impl service_impl::booking_information::BookingInformationServiceDeps
     type BookingService = BookingService;
     type SalesPersonService = SalesPersonService;
     type SalesPersonUnavailableService = SalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = SalesPersonAvailabilityService;
     type ReportingService = ReportingService;
     type SpecialDayService = SpecialDayService;
     // Phase 51 (D-51-06 Chain C + D-51-07): Stichtag-Toggle für pro-Slot-Clip
@@ -238,6 +329,8 @@ impl service_impl::booking_information::BookingInformationServiceDeps
     // — no construction-order change needed. No DI cycle: AbsenceService does not consume
     // BookingInformationService (D-Phase3-18 regression-lock).
     type AbsenceService = AbsenceService;
+    // Qualifikationslücken in `get_booking_conflicts_for_week`.
+    type QualificationService = QualificationService;
 }
 type BookingInformationService = service_impl::booking_information::BookingInformationServiceImpl<
     BookingInformationServiceDependencies,
@@ -247,7 +340,7 @@ pub struct BookingLogServiceDependencies;
 impl service_impl::booking_log::BookingLogServiceDeps for BookingLogServiceDependencies {
     type Context = Context;
     type Transaction = Transaction;
-    type BookingLogDao = dao_impl_sqlite::booking_log::BookingLogDaoImpl;
+    type BookingLogDao = dao_impl::booking_log::BookingLogDaoImpl;
     type PermissionService = PermissionService;
     type TransactionDao = TransactionDao;
 }
@@ -268,6 +361,8 @@ impl service_impl::absence::AbsenceServiceDeps for AbsenceServiceDependencies {
     // werden fuer derive_hours_for_range benoetigt.
     type SpecialDayService = SpecialDayService;
     type EmployeeWorkDetailsService = WorkingHoursService;
+    type AuditLogService = AuditLogService;
+    type CarryoverService = CarryoverService;
     type TransactionDao = TransactionDao;
     // Phase 3 plan 03-03 (D-Phase3-08): AbsenceService konsumiert
     // BookingService + SalesPersonUnavailableService + SlotService für den
@@ -278,7 +373,11 @@ impl service_impl::absence::AbsenceServiceDeps for AbsenceServiceDependencies {
     // Regression-Lock).
     type BookingService = BookingService;
     type SalesPersonUnavailableService = SalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = SalesPersonAvailabilityService;
     type SlotService = SlotService;
+    // E-Mail an die betroffene Person bei neuer Abwesenheit.
+    type NotificationService = NotificationService;
+    type WebhookService = WebhookService;
 }
 // type AbsenceService = service_impl::absence::AbsenceServiceImpl<AbsenceServiceDependencies>;
 type AbsenceService =
@@ -298,6 +397,7 @@ impl service_impl::vacation_entitlement_offset::VacationEntitlementOffsetService
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type VacationEntitlementOffsetService =
@@ -350,9 +450,37 @@ impl service_impl::voluntary_stats::VoluntaryStatsServiceDeps
 type VoluntaryStatsService =
     service_impl::voluntary_stats::VoluntaryStatsServiceImpl<VoluntaryStatsServiceDependencies>;
 
+// Phase 55 (F3/F5): RebookingReconciliationServiceImpl ist Business-Logic-Tier
+// ueber RebookingBatchService (Basic), VoluntaryStatsService + ReportingService
+// (Vorschlag), ExtraHoursService (Paar bei Genehmigung) und WeekStatusService
+// (Wochensperre); fuer den Auto-Cron (F4) zusaetzlich ToggleService und
+// WorkingHoursService. Konstruktion NACH week_status_service.
+pub struct RebookingReconciliationServiceDependencies;
+impl service_impl::rebooking_reconciliation::RebookingReconciliationServiceDeps
+    for RebookingReconciliationServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type RebookingBatchService = RebookingBatchService;
+    type VoluntaryStatsService = VoluntaryStatsService;
+    type ReportingService = ReportingService;
+    type ExtraHoursService = ExtraHoursService;
+    type WeekStatusService = WeekStatusService;
+    type ToggleService = ToggleService;
+    type EmployeeWorkDetailsService = WorkingHoursService;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type RebookingReconciliationService =
+    service_impl::rebooking_reconciliation::RebookingReconciliationServiceImpl<
+        RebookingReconciliationServiceDependencies,
+    >;
+
 // Phase 48 (EXP-02/EXP-03, D-48-BASIC): PdfExportConfigServiceImpl ist
 // Basic-Tier — konsumiert AUSSCHLIESSLICH PdfExportConfigDao + Permission +
-// Clock + Uuid + Transaction. Kein Domain-Service als Dep.
+// Clock + Uuid + AuditLog + Transaction. Kein Domain-Service als Dep.
 pub struct PdfExportConfigServiceDependencies;
 impl service_impl::pdf_export_config::PdfExportConfigServiceDeps
     for PdfExportConfigServiceDependencies
@@ -363,12 +491,45 @@ impl service_impl::pdf_export_config::PdfExportConfigServiceDeps
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type PdfExportConfigService = service_impl::pdf_export_config::PdfExportConfigServiceImpl<
     PdfExportConfigServiceDependencies,
 >;
 
+// Basic-Tier — admin-gated Registry der Hintergrund-Jobs für `/scheduler-job`.
+pub struct SchedulerJobServiceDependencies;
+impl service_impl::scheduler_job::SchedulerJobServiceDeps for SchedulerJobServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type SchedulerJobDao = SchedulerJobDao;
+    type PermissionService = PermissionService;
+    type AuditLogService = AuditLogService;
+    type UuidService = UuidService;
+    type TransactionDao = TransactionDao;
+}
+type SchedulerJobService =
+    service_impl::scheduler_job::SchedulerJobServiceImpl<SchedulerJobServiceDependencies>;
+
+// Basic-Tier — Qualifikationen; konsumiert von BookingInformation (Konflikte)
+// und ShiftplanEdit (Warning beim Buchen).
+pub struct QualificationServiceDependencies;
+impl service_impl::qualification::QualificationServiceDeps for QualificationServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type QualificationDao = QualificationDao;
+    type SalesPersonService = SalesPersonService;
+    type SlotService = SlotService;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type QualificationService =
+    service_impl::qualification::QualificationServiceImpl<QualificationServiceDependencies>;
+
 // Phase 48 Plan 04 (EXP-01/EXP-03): PdfExportSchedulerImpl ist BL-Tier —
 // konsumiert PdfExportConfigService (Basic) + ShiftplanViewService +
 // ShiftplanService (catalog) + SalesPersonService + PermissionService +
@@ -390,6 +551,44 @@ impl PdfExportSchedulerDeps for PdfExportSchedulerDependencies {
 }
 type PdfExportSchedulerService = PdfExportSchedulerImpl<PdfExportSchedulerDependencies>;
 
+// Basic-Tier — admin-gated CRUD der Export-Ziele plus Lauf-Historie.
+pub struct ExportTargetServiceDependencies;
+impl service_impl::export_target::ExportTargetServiceDeps for ExportTargetServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type ExportTargetDao = ExportTargetDao;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type ExportTargetService =
+    service_impl::export_target::ExportTargetServiceImpl<ExportTargetServiceDependencies>;
+
+// BL-Tier — führt die Export-Ziele aus: Wochen-PDF über PdfShiftplanService,
+// Abrechnungs-CSV über ReportExportService, iCal über TeamIcalService. Die
+// Ablage baut eine `ExportDestinationFactory` (Tests injizieren einen Mock).
+pub struct ExportTargetSchedulerDependencies;
+impl service_impl::export_target_scheduler::ExportTargetSchedulerDeps
+    for ExportTargetSchedulerDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type ExportTargetService = ExportTargetService;
+    type PdfShiftplanService = PdfShiftplanService;
+    type ShiftplanService = ShiftplanCatalogService;
+    type PdfExportConfigService = PdfExportConfigService;
+    type BillingPeriodService = BillingPeriodService;
+    type ReportExportService = ReportExportService;
+    type TeamIcalService = TeamIcalService;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+}
+type ExportTargetSchedulerService = service_impl::export_target_scheduler::ExportTargetSchedulerImpl<
+    ExportTargetSchedulerDependencies,
+>;
+
 // Phase 49 (PDF-03/PDF-04/PDF-05): PdfShiftplanServiceImpl ist BL-Tier —
 // konsumiert ShiftplanViewService (Read-Aggregat) + SalesPersonService
 // (Basic) + WeekStatusService (Basic) + PermissionService + TransactionDao.
@@ -434,6 +633,21 @@ type VacationBalanceService = service_impl::vacation_balance::VacationBalanceSer
     VacationBalanceServiceDependencies,
 >;
 
+// Antrags-Übersicht (BL-Tier über AbsenceService + VacationBalanceService).
+pub struct AbsenceRequestServiceDependencies;
+impl service_impl::absence_request::AbsenceRequestServiceDeps
+    for AbsenceRequestServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type AbsenceService = AbsenceService;
+    type VacationBalanceService = VacationBalanceService;
+    type PermissionService = PermissionService;
+    type TransactionDao = TransactionDao;
+}
+type AbsenceRequestService =
+    service_impl::absence_request::AbsenceRequestServiceImpl<AbsenceRequestServiceDependencies>;
+
 pub struct ExtraHoursServiceDependencies;
 impl service_impl::extra_hours::ExtraHoursServiceDeps for ExtraHoursServiceDependencies {
     type Context = Context;
@@ -444,6 +658,8 @@ impl service_impl::extra_hours::ExtraHoursServiceDeps for ExtraHoursServiceDepen
     type CustomExtraHoursService = CustomExtraHoursService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type CarryoverService = CarryoverService;
     type TransactionDao = TransactionDao;
 }
 type ExtraHoursService =
@@ -454,6 +670,9 @@ impl CarryoverServiceDeps for CarryoverServiceDependencies {
     type Context = Context;
     type Transaction = Transaction;
     type CarryoverDao = CarryoverDao;
+    type AuditLogService = AuditLogService;
+    type PermissionService = PermissionService;
+    type UuidService = UuidService;
     type TransactionDao = TransactionDao;
 }
 
@@ -477,6 +696,8 @@ impl ShiftplanViewServiceDeps for ShiftplanViewServiceDependencies {
     type SalesPersonUnavailableService = SalesPersonUnavailableService;
     // NEU für Phase 51 (D-51-07): Stichtag-Gate für ShortDay-Slot-Kürzung.
     type ToggleService = ToggleService;
+    // Wiederkehrende Unavailable-Regeln als Marker in der per-sales-person-Sicht.
+    type SalesPersonAvailabilityService = SalesPersonAvailabilityService;
 }
 
 pub struct BlockServiceDependencies;
@@ -495,9 +716,67 @@ impl service_impl::block::BlockServiceDeps for BlockServiceDependencies {
     // konsumiert ShortDay-Lookup + Stichtag-Toggle.
     type SpecialDayService = SpecialDayService;
     type ToggleService = ToggleService;
+    // iCal-Feed: Wochennachricht in der DESCRIPTION + Rechteprüfung.
+    type WeekMessageService = WeekMessageService;
+    type PermissionService = PermissionService;
 }
 type BlockService = service_impl::block::BlockServiceImpl<BlockServiceDependencies>;
 
+// BL-Tier — Schichtplan- und Abwesenheits-Feeds für Planer/HR.
+pub struct TeamIcalServiceDependencies;
+impl service_impl::team_ical::TeamIcalServiceDeps for TeamIcalServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type ShiftplanViewService = ShiftplanViewServiceImpl<ShiftplanViewServiceDependencies>;
+    type ShiftplanService = ShiftplanCatalogService;
+    type BlockService = BlockService;
+    type BookingService = BookingService;
+    type AbsenceService = AbsenceService;
+    type SalesPersonService = SalesPersonService;
+    type SpecialDayService = SpecialDayService;
+    type ConfigService = ConfigService;
+    type ClockService = ClockService;
+    type IcalService = IcalService;
+    type PermissionService = PermissionService;
+    type TransactionDao = TransactionDao;
+}
+type TeamIcalService = service_impl::team_ical::TeamIcalServiceImpl<TeamIcalServiceDependencies>;
+
+// BL-Tier — liefert die iCal-Feeds über BlockService/TeamIcalService ohne Session.
+pub struct IcalFeedTokenServiceDependencies;
+impl service_impl::ical_feed_token::IcalFeedTokenServiceDeps for IcalFeedTokenServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type IcalFeedTokenDao = IcalFeedTokenDao;
+    type BlockService = BlockService;
+    type TeamIcalService = TeamIcalService;
+    type ShiftplanService = ShiftplanCatalogService;
+    type SalesPersonService = SalesPersonService;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type IcalFeedTokenService =
+    service_impl::ical_feed_token::IcalFeedTokenServiceImpl<IcalFeedTokenServiceDependencies>;
+
+pub struct IcalImportServiceDependencies;
+impl service_impl::ical_import::IcalImportServiceDeps for IcalImportServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type AbsenceService = AbsenceService;
+    type SalesPersonUnavailableService = SalesPersonUnavailableService;
+    type SalesPersonService = SalesPersonService;
+    type PermissionService = PermissionService;
+    type ConfigService = ConfigService;
+    type ClockService = ClockService;
+    type IcalFetcher = IcalFetcher;
+    type TransactionDao = TransactionDao;
+}
+type IcalImportService =
+    service_impl::ical_import::IcalImportServiceImpl<IcalImportServiceDependencies>;
+
 pub struct ReportingServiceDependencies;
 impl service_impl::reporting::ReportingServiceDeps for ReportingServiceDependencies {
     type Context = Context;
@@ -518,9 +797,31 @@ impl service_impl::reporting::ReportingServiceDeps for ReportingServiceDependenc
     // Phase 25: holiday derive-on-read deps.
     type SpecialDayService = SpecialDayService;
     type ToggleService = ToggleService;
+    type TimeEntryService = TimeEntryService;
 }
 type ReportingService = service_impl::reporting::ReportingServiceImpl<ReportingServiceDependencies>;
 
+// Zeiterfassung (Business-Logic): Buchung und Schichtplan-Report für die
+// Verknüpfung und den Soll/Ist-Vergleich; wird vom ReportingService
+// konsumiert, daher vor diesem konstruiert.
+pub struct TimeEntryServiceDependencies;
+impl service_impl::time_entry::TimeEntryServiceDeps for TimeEntryServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type TimeEntryDao = TimeEntryDao;
+    type BookingService = BookingService;
+    type SalesPersonService = SalesPersonService;
+    type ShiftplanReportService = ShiftplanReportService;
+    type PermissionService = PermissionService;
+    type ConfigService = ConfigService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type CarryoverService = CarryoverService;
+    type TransactionDao = TransactionDao;
+}
+type TimeEntryService = service_impl::time_entry::TimeEntryServiceImpl<TimeEntryServiceDependencies>;
+
 pub struct WorkingHoursServiceDependencies;
 impl service_impl::employee_work_details::EmployeeWorkDetailsServiceDeps
     for WorkingHoursServiceDependencies
@@ -532,6 +833,8 @@ impl service_impl::employee_work_details::EmployeeWorkDetailsServiceDeps
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type CarryoverService = CarryoverService;
     type TransactionDao = TransactionDao;
 }
 type WorkingHoursService = service_impl::employee_work_details::EmployeeWorkDetailsServiceImpl<
@@ -546,11 +849,64 @@ impl service_impl::week_message::WeekMessageServiceDeps for WeekMessageServiceDe
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type WeekMessageService =
     service_impl::week_message::WeekMessageServiceImpl<WeekMessageServiceDependencies>;
 
+// Basic-tier E-Mail-Benachrichtigungen: nur DAOs (Notification, TextTemplate,
+// SalesPerson) + Permission + Clock + Uuid + Config + Transaction, dazu der
+// SMTP-Transport. Dadurch dürfen auch Basic-Services wie WeekStatus notifyen.
+pub struct NotificationServiceDependencies;
+impl service_impl::notification::NotificationServiceDeps for NotificationServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type NotificationDao = NotificationDao;
+    type TextTemplateDao = TextTemplateDao;
+    type SalesPersonDao = SalesPersonDao;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type ConfigService = ConfigService;
+    type MailTransport = MailTransport;
+    type TransactionDao = TransactionDao;
+}
+type NotificationService =
+    service_impl::notification::NotificationServiceImpl<NotificationServiceDependencies>;
+
+// Basic-tier Webhooks: nur der eigene DAO + Permission + Clock + Uuid + AuditLog +
+// Transaction und der HTTP-Client, damit Booking, Absence, BillingPeriod und
+// WeekStatus Ereignisse in ihrer Transaktion veröffentlichen können.
+pub struct WebhookServiceDependencies;
+impl service_impl::webhook::WebhookServiceDeps for WebhookServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type WebhookDao = WebhookDao;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type WebhookSender = WebhookSender;
+    type TransactionDao = TransactionDao;
+}
+type WebhookService = service_impl::webhook::WebhookServiceImpl<WebhookServiceDependencies>;
+
+// Basic-tier Änderungsprotokoll: nur der eigene DAO + Permission + Clock +
+// Uuid + Transaction, damit jeder schreibende Service es konsumieren kann.
+pub struct AuditLogServiceDependencies;
+impl service_impl::audit_log::AuditLogServiceDeps for AuditLogServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type AuditLogDao = AuditLogDao;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type TransactionDao = TransactionDao;
+}
+type AuditLogService = service_impl::audit_log::AuditLogServiceImpl<AuditLogServiceDependencies>;
+
 // Basic-tier KW status service (D-39-12): DAO + Permission + Clock + Uuid +
 // Transaction only, no domain-service dependency. Wired next to week_message,
 // before the business-logic layer.
@@ -562,7 +918,11 @@ impl service_impl::week_status::WeekStatusServiceDeps for WeekStatusServiceDepen
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
+    // „Woche geplant“-Mail an alle Abonnenten.
+    type NotificationService = NotificationService;
+    type WebhookService = WebhookService;
 }
 type WeekStatusService =
     service_impl::week_status::WeekStatusServiceImpl<WeekStatusServiceDependencies>;
@@ -588,15 +948,68 @@ impl service_impl::shiftplan_edit::ShiftplanEditServiceDeps for ShiftplanEditSer
     type ToggleService = ToggleService;
     // NEU für Phase 40 (D-40-01): Wochen-Sperre-Gate liest den Lock-Status.
     type WeekStatusService = WeekStatusService;
+    // Wiederkehrende Unavailable-Regeln → BookingOnUnavailableDay-Warning.
+    type SalesPersonAvailabilityService = SalesPersonAvailabilityService;
+    // E-Mail bei gelöschtem Booking.
+    type NotificationService = NotificationService;
+    // QualificationNotCovered-Warning beim Buchen.
+    type QualificationService = QualificationService;
 }
 type ShiftplanEditService =
     service_impl::shiftplan_edit::ShiftplanEditServiceImpl<ShiftplanEditServiceDependencies>;
 
+pub struct ShiftplanGeneratorServiceDependencies;
+impl service_impl::shiftplan_generator::ShiftplanGeneratorServiceDeps
+    for ShiftplanGeneratorServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type PermissionService = PermissionService;
+    type SlotService = SlotService;
+    type BookingService = BookingService;
+    type SalesPersonService = SalesPersonService;
+    type SalesPersonShiftplanService = SalesPersonShiftplanService;
+    type SalesPersonUnavailableService = SalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = SalesPersonAvailabilityService;
+    type AbsenceService = AbsenceService;
+    type EmployeeWorkDetailsService = WorkingHoursService;
+    type ShiftplanEditService = ShiftplanEditService;
+    type TransactionDao = TransactionDao;
+}
+type ShiftplanGeneratorService = service_impl::shiftplan_generator::ShiftplanGeneratorServiceImpl<
+    ShiftplanGeneratorServiceDependencies,
+>;
+
+pub struct ShiftSwapServiceDependencies;
+impl service_impl::shift_swap::ShiftSwapServiceDeps for ShiftSwapServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type ShiftSwapRequestDao = ShiftSwapRequestDao;
+    type PermissionService = PermissionService;
+    type BookingService = BookingService;
+    type SlotService = SlotService;
+    type SalesPersonService = SalesPersonService;
+    type SalesPersonShiftplanService = SalesPersonShiftplanService;
+    type WeekStatusService = WeekStatusService;
+    type ShiftplanEditService = ShiftplanEditService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type ShiftSwapService = service_impl::shift_swap::ShiftSwapServiceImpl<ShiftSwapServiceDependencies>;
+
 pub struct SchedulerServiceDependencies;
 impl service_impl::scheduler::SchedulerServiceDeps for SchedulerServiceDependencies {
     type Context = Context;
     type Transaction = Transaction;
     type ShiftplanEditService = ShiftplanEditService;
+    type NotificationService = NotificationService;
+    type WebhookService = WebhookService;
+    type RebookingReconciliationService = RebookingReconciliationService;
+    type SchedulerJobService = SchedulerJobService;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
 }
 type SchedulerServiceImpl =
     service_impl::scheduler::SchedulerServiceImpl<SchedulerServiceDependencies>;
@@ -611,6 +1024,8 @@ impl service_impl::billing_period::BillingPeriodServiceDeps for BillingPeriodSer
     type PermissionService = PermissionService;
     type UuidService = UuidService;
     type ClockService = ClockService;
+    type WebhookService = WebhookService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type BillingPeriodService =
@@ -637,6 +1052,62 @@ type BillingPeriodReportService =
         BillingPeriodReportServiceDependencies,
     >;
 
+pub struct ReportExportServiceDependencies;
+impl service_impl::report_export::ReportExportServiceDeps for ReportExportServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type ReportingService = ReportingService;
+    type BillingPeriodService = BillingPeriodService;
+    type SalesPersonService = SalesPersonService;
+    type PermissionService = PermissionService;
+    type TransactionDao = TransactionDao;
+}
+type ReportExportService =
+    service_impl::report_export::ReportExportServiceImpl<ReportExportServiceDependencies>;
+
+pub struct PdfPersonalServiceDependencies;
+impl service_impl::pdf_personal::PdfPersonalServiceDeps for PdfPersonalServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type BlockService = BlockService;
+    type ReportingService = ReportingService;
+    type SalesPersonService = SalesPersonService;
+    type PermissionService = PermissionService;
+    type TransactionDao = TransactionDao;
+}
+type PdfPersonalService =
+    service_impl::pdf_personal::PdfPersonalServiceImpl<PdfPersonalServiceDependencies>;
+
+pub struct PayrollWageTypeServiceDependencies;
+impl service_impl::payroll_wage_type::PayrollWageTypeServiceDeps
+    for PayrollWageTypeServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type PayrollWageTypeDao = PayrollWageTypeDao;
+    type PermissionService = PermissionService;
+    type ClockService = ClockService;
+    type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
+    type TransactionDao = TransactionDao;
+}
+type PayrollWageTypeService =
+    service_impl::payroll_wage_type::PayrollWageTypeServiceImpl<PayrollWageTypeServiceDependencies>;
+
+pub struct PayrollExportServiceDependencies;
+impl service_impl::payroll_export::PayrollExportServiceDeps for PayrollExportServiceDependencies {
+    type Context = Context;
+    type Transaction = Transaction;
+    type BillingPeriodService = BillingPeriodService;
+    type SalesPersonService = SalesPersonService;
+    type PayrollWageTypeService = PayrollWageTypeService;
+    type ConfigService = ConfigService;
+    type PermissionService = PermissionService;
+    type TransactionDao = TransactionDao;
+}
+type PayrollExportService =
+    service_impl::payroll_export::PayrollExportServiceImpl<PayrollExportServiceDependencies>;
+
 pub struct BlockReportServiceDependencies;
 impl service_impl::block_report::BlockReportServiceDeps for BlockReportServiceDependencies {
     type Context = Context;
@@ -655,16 +1126,33 @@ impl service_impl::text_template::TextTemplateServiceDeps for TextTemplateServic
     type Transaction = Transaction;
     type TextTemplateDao = TextTemplateDao;
     type PermissionService = PermissionService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type TextTemplateService = service_impl::text_template::TextTemplateServiceImpl<TextTemplateServiceDependencies>;
 
+pub struct TextTemplatePreviewServiceDependencies;
+impl service_impl::text_template_preview::TextTemplatePreviewServiceDeps
+    for TextTemplatePreviewServiceDependencies
+{
+    type Context = Context;
+    type Transaction = Transaction;
+    type BillingPeriodReportService = BillingPeriodReportService;
+    type BlockReportService = BlockReportService;
+    type PermissionService = PermissionService;
+    type TransactionDao = TransactionDao;
+}
+type TextTemplatePreviewService = service_impl::text_template_preview::TextTemplatePreviewServiceImpl<
+    TextTemplatePreviewServiceDependencies,
+>;
+
 pub struct ToggleServiceDependencies;
 impl service_impl::toggle::ToggleServiceDeps for ToggleServiceDependencies {
     type Context = Context;
     type Transaction = Transaction;
     type ToggleDao = ToggleDao;
     type PermissionService = PermissionService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type ToggleService = service_impl::toggle::ToggleServiceImpl<ToggleServiceDependencies>;
@@ -675,6 +1163,7 @@ impl service_impl::feature_flag::FeatureFlagServiceDeps for FeatureFlagServiceDe
     type Transaction = Transaction;
     type FeatureFlagDao = FeatureFlagDao;
     type PermissionService = PermissionService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type FeatureFlagService =
@@ -695,6 +1184,7 @@ impl service_impl::absence_conversion::AbsenceConversionServiceDeps
     type MigrationSourceDao = MigrationSourceDao;
     type ExtraHoursService = ExtraHoursService;
     type PermissionService = PermissionService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type AbsenceConversionService = service_impl::absence_conversion::AbsenceConversionServiceImpl<
@@ -709,6 +1199,7 @@ impl ShiftplanServiceDeps for ShiftplanCatalogServiceDependencies {
     type PermissionService = PermissionService;
     type ClockService = ClockService;
     type UuidService = UuidService;
+    type AuditLogService = AuditLogService;
     type TransactionDao = TransactionDao;
 }
 type ShiftplanCatalogService = ShiftplanServiceImpl<ShiftplanCatalogServiceDependencies>;
@@ -721,7 +1212,9 @@ pub struct RestStateImpl {
     slot_service: Arc<SlotService>,
     sales_person_service: Arc<SalesPersonService>,
     special_day_service: Arc<SpecialDayService>,
+    holiday_calendar_service: Arc<HolidayCalendarService>,
     sales_person_unavailable_service: Arc<SalesPersonUnavailableService>,
+    sales_person_availability_service: Arc<SalesPersonAvailabilityService>,
     booking_service: Arc<BookingService>,
     custom_extra_hours_service: Arc<CustomExtraHoursService>,
     booking_information_service: Arc<BookingInformationService>,
@@ -730,8 +1223,11 @@ pub struct RestStateImpl {
     working_hours_service: Arc<WorkingHoursService>,
     absence_service: Arc<AbsenceService>,
     vacation_balance_service: Arc<VacationBalanceService>,
+    absence_request_service: Arc<AbsenceRequestService>,
     extra_hours_service: Arc<ExtraHoursService>,
     shiftplan_edit_service: Arc<ShiftplanEditService>,
+    shiftplan_generator_service: Arc<ShiftplanGeneratorService>,
+    shift_swap_service: Arc<ShiftSwapService>,
     block_service: Arc<BlockService>,
     shiftplan_service: Arc<ShiftplanCatalogService>,
     shiftplan_view_service: Arc<ShiftplanViewServiceImpl<ShiftplanViewServiceDependencies>>,
@@ -741,6 +1237,7 @@ pub struct RestStateImpl {
     billing_period_report_service: Arc<BillingPeriodReportService>,
     block_report_service: Arc<BlockReportService>,
     text_template_service: Arc<TextTemplateService>,
+    text_template_preview_service: Arc<TextTemplatePreviewService>,
     user_invitation_service: Arc<UserInvitationService>,
     toggle_service: Arc<ToggleService>,
     sales_person_shiftplan_service: Arc<SalesPersonShiftplanService>,
@@ -760,14 +1257,31 @@ pub struct RestStateImpl {
     // HR-only Voluntary-Stundenkonto-Sicht. Kein REST-Endpoint in Phase 54
     // Plan 03 — der Endpoint kommt in Plan 04.
     voluntary_stats_service: Arc<VoluntaryStatsService>,
+    // Phase 55 (F3/F5): HR-Workflow Vorschlag/Genehmigung/Ablehnung.
+    rebooking_reconciliation_service: Arc<RebookingReconciliationService>,
     // Phase 48 (EXP-02/EXP-03): admin-gated REST-CRUD für die PDF-Export-Konfig.
     pdf_export_config_service: Arc<PdfExportConfigService>,
     // Phase 48 Plan 04 (EXP-01/EXP-03): Cron-getriebener Nextcloud-Push.
     pdf_export_scheduler: Arc<PdfExportSchedulerService>,
+    export_target_service: Arc<ExportTargetService>,
+    export_target_scheduler: Arc<ExportTargetSchedulerService>,
     // Phase 49 (PDF-03/PDF-04/PDF-05): BL-Tier PDF-Shiftplan-Assembler;
     // konsumiert vom On-Demand-Download-Endpoint (Wave 2) und vom
     // Scheduler-Refactor (Plan 03).
     pdf_shiftplan_service: Arc<PdfShiftplanService>,
+    notification_service: Arc<NotificationService>,
+    webhook_service: Arc<WebhookService>,
+    time_entry_service: Arc<TimeEntryService>,
+    audit_log_service: Arc<AuditLogService>,
+    report_export_service: Arc<ReportExportService>,
+    pdf_personal_service: Arc<PdfPersonalService>,
+    payroll_wage_type_service: Arc<PayrollWageTypeService>,
+    payroll_export_service: Arc<PayrollExportService>,
+    scheduler_job_service: Arc<SchedulerJobService>,
+    scheduler_service: Arc<SchedulerServiceImpl>,
+    qualification_service: Arc<QualificationService>,
+    ical_feed_token_service: Arc<IcalFeedTokenService>,
+    ical_import_service: Arc<IcalImportService>,
     basic_dao: Arc<BasicDaoImpl>,
 }
 impl rest::RestStateDef for RestStateImpl {
@@ -777,7 +1291,9 @@ impl rest::RestStateDef for RestStateImpl {
     type SlotService = SlotService;
     type SalesPersonService = SalesPersonService;
     type SpecialDayService = SpecialDayService;
+    type HolidayCalendarService = HolidayCalendarService;
     type SalesPersonUnavailableService = SalesPersonUnavailableService;
+    type SalesPersonAvailabilityService = SalesPersonAvailabilityService;
     type BookingService = BookingService;
     type CustomExtraHoursService = CustomExtraHoursService;
     type BookingInformationService = BookingInformationService;
@@ -786,8 +1302,11 @@ impl rest::RestStateDef for RestStateImpl {
     type WorkingHoursService = WorkingHoursService;
     type AbsenceService = AbsenceService;
     type VacationBalanceService = VacationBalanceService;
+    type AbsenceRequestService = AbsenceRequestService;
     type ExtraHoursService = ExtraHoursService;
     type ShiftplanEditService = ShiftplanEditService;
+    type ShiftplanGeneratorService = ShiftplanGeneratorService;
+    type ShiftSwapService = ShiftSwapService;
     type BlockService = BlockService;
     type ShiftplanService = ShiftplanCatalogService;
     type ShiftplanViewService = ShiftplanViewServiceImpl<ShiftplanViewServiceDependencies>;
@@ -797,6 +1316,7 @@ impl rest::RestStateDef for RestStateImpl {
     type BillingPeriodReportService = BillingPeriodReportService;
     type BlockReportService = BlockReportService;
     type TextTemplateService = TextTemplateService;
+    type TextTemplatePreviewService = TextTemplatePreviewService;
     type UserInvitationService = UserInvitationService;
     type ToggleService = ToggleService;
     type SalesPersonShiftplanService = SalesPersonShiftplanService;
@@ -805,9 +1325,25 @@ impl rest::RestStateDef for RestStateImpl {
     type VacationEntitlementOffsetService = VacationEntitlementOffsetService;
     type RebookingBatchService = RebookingBatchService;
     type VoluntaryStatsService = VoluntaryStatsService;
+    type RebookingReconciliationService = RebookingReconciliationService;
     type PdfExportConfigService = PdfExportConfigService;
     type PdfExportScheduler = PdfExportSchedulerService;
+    type ExportTargetService = ExportTargetService;
+    type ExportTargetScheduler = ExportTargetSchedulerService;
     type PdfShiftplanService = PdfShiftplanService;
+    type SchedulerJobService = SchedulerJobService;
+    type SchedulerService = SchedulerServiceImpl;
+    type QualificationService = QualificationService;
+    type IcalFeedTokenService = IcalFeedTokenService;
+    type IcalImportService = IcalImportService;
+    type NotificationService = NotificationService;
+    type WebhookService = WebhookService;
+    type TimeEntryService = TimeEntryService;
+    type AuditLogService = AuditLogService;
+    type ReportExportService = ReportExportService;
+    type PdfPersonalService = PdfPersonalService;
+    type PayrollWageTypeService = PayrollWageTypeService;
+    type PayrollExportService = PayrollExportService;
     type BasicDao = BasicDaoImpl;
 
     fn backend_version(&self) -> Arc<str> {
@@ -832,9 +1368,15 @@ impl rest::RestStateDef for RestStateImpl {
     fn special_day_service(&self) -> Arc<Self::SpecialDayService> {
         self.special_day_service.clone()
     }
+    fn holiday_calendar_service(&self) -> Arc<Self::HolidayCalendarService> {
+        self.holiday_calendar_service.clone()
+    }
     fn sales_person_unavailable_service(&self) -> Arc<Self::SalesPersonUnavailableService> {
         self.sales_person_unavailable_service.clone()
     }
+    fn sales_person_availability_service(&self) -> Arc<Self::SalesPersonAvailabilityService> {
+        self.sales_person_availability_service.clone()
+    }
     fn booking_service(&self) -> Arc<Self::BookingService> {
         self.booking_service.clone()
     }
@@ -859,12 +1401,21 @@ impl rest::RestStateDef for RestStateImpl {
     fn vacation_balance_service(&self) -> Arc<Self::VacationBalanceService> {
         self.vacation_balance_service.clone()
     }
+    fn absence_request_service(&self) -> Arc<Self::AbsenceRequestService> {
+        self.absence_request_service.clone()
+    }
     fn extra_hours_service(&self) -> Arc<Self::ExtraHoursService> {
         self.extra_hours_service.clone()
     }
     fn shiftplan_edit_service(&self) -> Arc<Self::ShiftplanEditService> {
         self.shiftplan_edit_service.clone()
     }
+    fn shiftplan_generator_service(&self) -> Arc<Self::ShiftplanGeneratorService> {
+        self.shiftplan_generator_service.clone()
+    }
+    fn shift_swap_service(&self) -> Arc<Self::ShiftSwapService> {
+        self.shift_swap_service.clone()
+    }
     fn block_service(&self) -> Arc<Self::BlockService> {
         self.block_service.clone()
     }
@@ -893,6 +1444,9 @@ impl rest::RestStateDef for RestStateImpl {
     fn text_template_service(&self) -> Arc<Self::TextTemplateService> {
         self.text_template_service.clone()
     }
+    fn text_template_preview_service(&self) -> Arc<Self::TextTemplatePreviewService> {
+        self.text_template_preview_service.clone()
+    }
     fn user_invitation_service(&self) -> Arc<Self::UserInvitationService> {
         self.user_invitation_service.clone()
     }
@@ -917,21 +1471,69 @@ impl rest::RestStateDef for RestStateImpl {
     fn voluntary_stats_service(&self) -> Arc<Self::VoluntaryStatsService> {
         self.voluntary_stats_service.clone()
     }
+    fn rebooking_reconciliation_service(&self) -> Arc<Self::RebookingReconciliationService> {
+        self.rebooking_reconciliation_service.clone()
+    }
     fn pdf_export_config_service(&self) -> Arc<Self::PdfExportConfigService> {
         self.pdf_export_config_service.clone()
     }
     fn pdf_export_scheduler(&self) -> Arc<Self::PdfExportScheduler> {
         self.pdf_export_scheduler.clone()
     }
+    fn export_target_service(&self) -> Arc<Self::ExportTargetService> {
+        self.export_target_service.clone()
+    }
+    fn export_target_scheduler(&self) -> Arc<Self::ExportTargetScheduler> {
+        self.export_target_scheduler.clone()
+    }
     fn pdf_shiftplan_service(&self) -> Arc<Self::PdfShiftplanService> {
         self.pdf_shiftplan_service.clone()
     }
+    fn scheduler_job_service(&self) -> Arc<Self::SchedulerJobService> {
+        self.scheduler_job_service.clone()
+    }
+    fn scheduler_service(&self) -> Arc<Self::SchedulerService> {
+        self.scheduler_service.clone()
+    }
+    fn qualification_service(&self) -> Arc<Self::QualificationService> {
+        self.qualification_service.clone()
+    }
+    fn ical_feed_token_service(&self) -> Arc<Self::IcalFeedTokenService> {
+        self.ical_feed_token_service.clone()
+    }
+    fn ical_import_service(&self) -> Arc<Self::IcalImportService> {
+        self.ical_import_service.clone()
+    }
+    fn notification_service(&self) -> Arc<Self::NotificationService> {
+        self.notification_service.clone()
+    }
+    fn webhook_service(&self) -> Arc<Self::WebhookService> {
+        self.webhook_service.clone()
+    }
+    fn time_entry_service(&self) -> Arc<Self::TimeEntryService> {
+        self.time_entry_service.clone()
+    }
+    fn audit_log_service(&self) -> Arc<Self::AuditLogService> {
+        self.audit_log_service.clone()
+    }
+    fn report_export_service(&self) -> Arc<Self::ReportExportService> {
+        self.report_export_service.clone()
+    }
+    fn pdf_personal_service(&self) -> Arc<Self::PdfPersonalService> {
+        self.pdf_personal_service.clone()
+    }
+    fn payroll_wage_type_service(&self) -> Arc<Self::PayrollWageTypeService> {
+        self.payroll_wage_type_service.clone()
+    }
+    fn payroll_export_service(&self) -> Arc<Self::PayrollExportService> {
+        self.payroll_export_service.clone()
+    }
     fn basic_dao(&self) -> Arc<Self::BasicDao> {
         self.basic_dao.clone()
     }
 }
 impl RestStateImpl {
-    pub fn new(pool: Arc<sqlx::Pool<sqlx::Sqlite>>) -> Self {
+    pub fn new(pool: Arc<DbPool>) -> Self {
         let transaction_dao = Arc::new(TransactionDao::new(pool.clone()));
         let permission_dao = Arc::new(PermissionDao::new(pool.clone()));
         let slot_dao = SlotDao::new(pool.clone());
@@ -941,9 +1543,12 @@ impl RestStateImpl {
         // Phase 54 (D-54-DM-01): Basic-Tier Rebooking-Batch-DAO.
         let rebooking_batch_dao = Arc::new(RebookingBatchDao::new(pool.clone()));
         let pdf_export_config_dao = Arc::new(PdfExportConfigDao::new(pool.clone()));
-        let sales_person_dao = SalesPersonDao::new(pool.clone());
+        let scheduler_job_dao = Arc::new(SchedulerJobDao::new(pool.clone()));
+        let qualification_dao = Arc::new(QualificationDao::new(pool.clone()));
+        let ical_feed_token_dao = Arc::new(IcalFeedTokenDao::new(pool.clone()));
+        let sales_person_dao = Arc::new(SalesPersonDao::new(pool.clone()));
         let booking_dao = BookingDao::new(pool.clone());
-        let booking_log_dao = Arc::new(dao_impl_sqlite::booking_log::BookingLogDaoImpl);
+        let booking_log_dao = Arc::new(dao_impl::booking_log::BookingLogDaoImpl);
         let absence_dao = Arc::new(AbsenceDao::new(pool.clone()));
         let extra_hours_dao = Arc::new(ExtraHoursDao::new(pool.clone()));
         let shiftplan_report_dao = Arc::new(ShiftplanReportDao::new(pool.clone()));
@@ -951,7 +1556,7 @@ impl RestStateImpl {
         let special_day_dao = SpecialDayDao::new(pool.clone());
         let session_dao = SessionDao::new(pool.clone());
         let custom_extra_hours_dao =
-            Arc::new(dao_impl_sqlite::custom_extra_hours::CustomExtraHoursDaoImpl);
+            Arc::new(dao_impl::custom_extra_hours::CustomExtraHoursDaoImpl);
         let text_template_dao = Arc::new(TextTemplateDao::new(pool.clone()));
         let user_invitation_dao = Arc::new(UserInvitationDao::new(pool.clone()));
 
@@ -960,30 +1565,91 @@ impl RestStateImpl {
         //
         let user_service = service_impl::UserServiceImpl;
         let user_service = Arc::new(user_service);
+        let clock_service = Arc::new(service_impl::clock::ClockServiceImpl);
+        let uuid_service = Arc::new(service_impl::uuid_service::UuidServiceImpl);
+        let audit_log_dao = Arc::new(AuditLogDao::new(pool.clone()));
         let permission_service = Arc::new(service_impl::PermissionServiceImpl {
             permission_dao: permission_dao.clone(),
             user_service: user_service.clone(),
+            audit_log_dao: audit_log_dao.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            transaction_dao: transaction_dao.clone(),
         });
-        let clock_service = Arc::new(service_impl::clock::ClockServiceImpl);
-        let uuid_service = Arc::new(service_impl::uuid_service::UuidServiceImpl);
         let session_service = Arc::new(service_impl::session::SessionServiceImpl {
             session_dao: Arc::new(session_dao),
             clock_service: clock_service.clone(),
             uuid_service: uuid_service.clone(),
         });
+        let audit_log_service = Arc::new(AuditLogService {
+            audit_log_dao,
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
         let config_service = Arc::new(service_impl::config::ConfigServiceImpl);
+        // Basic-Tier: wird von Absence, WeekStatus, ShiftplanEdit und
+        // UserInvitation konsumiert, daher ganz vorne.
+        let notification_service = Arc::new(NotificationService {
+            notification_dao: Arc::new(NotificationDao::new(pool.clone())),
+            text_template_dao: text_template_dao.clone(),
+            sales_person_dao: sales_person_dao.clone(),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            config_service: config_service.clone(),
+            mail_transport: Arc::new(
+                MailTransport::from_env().expect("Invalid SMTP configuration"),
+            ),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let webhook_service = Arc::new(WebhookService {
+            webhook_dao: Arc::new(WebhookDao::new(pool.clone())),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            webhook_sender: Arc::new(
+                WebhookSender::new().expect("Failed to build the webhook HTTP client"),
+            ),
+            transaction_dao: transaction_dao.clone(),
+        });
+        // Basic-Tier; wird VOR booking/absence/extra_hours/working_hours
+        // konstruiert, weil diese geänderte Jahre als Carryover-veraltet
+        // markieren.
+        let carryover_service = Arc::new(service_impl::carryover::CarryoverServiceImpl {
+            carryover_dao,
+            audit_log_service: audit_log_service.clone(),
+            permission_service: permission_service.clone(),
+            uuid_service: uuid_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
         let slot_service = Arc::new(service_impl::slot::SlotServiceImpl::new(
             slot_dao.into(),
             permission_service.clone(),
             clock_service.clone(),
             uuid_service.clone(),
+            audit_log_service.clone(),
             transaction_dao.clone(),
         ));
         let sales_person_service = Arc::new(service_impl::sales_person::SalesPersonServiceImpl {
-            sales_person_dao: sales_person_dao.into(),
+            sales_person_dao: sales_person_dao.clone(),
             permission_service: permission_service.clone(),
             clock_service: clock_service.clone(),
             uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let qualification_service = Arc::new(QualificationService {
+            qualification_dao,
+            sales_person_service: sales_person_service.clone(),
+            slot_service: slot_service.clone(),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
         let special_day_service = Arc::new(service_impl::special_days::SpecialDayServiceImpl::new(
@@ -991,7 +1657,16 @@ impl RestStateImpl {
             permission_service.clone(),
             clock_service.clone(),
             uuid_service.clone(),
+            audit_log_service.clone(),
+            sales_person_service.clone(),
+            carryover_service.clone(),
         ));
+        let holiday_calendar_service = Arc::new(
+            service_impl::holiday_calendar::HolidayCalendarServiceImpl {
+                special_day_service: special_day_service.clone(),
+                permission_service: permission_service.clone(),
+            },
+        );
         let sales_person_unavailable_service = Arc::new(
             service_impl::sales_person_unavailable::SalesPersonUnavailableServiceImpl {
                 sales_person_unavailable_dao: Arc::new(SalesPersonUnavailableDao::new(
@@ -1001,6 +1676,20 @@ impl RestStateImpl {
                 permission_service: permission_service.clone(),
                 clock_service: clock_service.clone(),
                 uuid_service: uuid_service.clone(),
+                audit_log_service: audit_log_service.clone(),
+                transaction_dao: transaction_dao.clone(),
+            },
+        );
+        let sales_person_availability_service = Arc::new(
+            service_impl::sales_person_availability::SalesPersonAvailabilityServiceImpl {
+                sales_person_availability_dao: Arc::new(SalesPersonAvailabilityDao::new(
+                    pool.clone(),
+                )),
+                sales_person_service: sales_person_service.clone(),
+                permission_service: permission_service.clone(),
+                clock_service: clock_service.clone(),
+                uuid_service: uuid_service.clone(),
+                audit_log_service: audit_log_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
@@ -1010,10 +1699,13 @@ impl RestStateImpl {
                 sales_person_shiftplan_dao,
                 sales_person_service: sales_person_service.clone(),
                 permission_service: permission_service.clone(),
+                audit_log_service: audit_log_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
         let booking_service = Arc::new(service_impl::booking::BookingServiceImpl {
+            audit_log_service: audit_log_service.clone(),
+            carryover_service: carryover_service.clone(),
             transaction_dao: transaction_dao.clone(),
             booking_dao: booking_dao.into(),
             permission_service: permission_service.clone(),
@@ -1022,6 +1714,7 @@ impl RestStateImpl {
             sales_person_service: sales_person_service.clone(),
             slot_service: slot_service.clone(),
             sales_person_shiftplan_service: sales_person_shiftplan_service.clone(),
+            webhook_service: webhook_service.clone(),
         });
         let booking_log_service = Arc::new(service_impl::booking_log::BookingLogServiceImpl {
             booking_log_dao,
@@ -1035,6 +1728,7 @@ impl RestStateImpl {
                 permission_service: permission_service.clone(),
                 clock_service: clock_service.clone(),
                 uuid_service: uuid_service.clone(),
+                audit_log_service: audit_log_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
@@ -1048,6 +1742,8 @@ impl RestStateImpl {
                 permission_service: permission_service.clone(),
                 clock_service: clock_service.clone(),
                 uuid_service: uuid_service.clone(),
+                audit_log_service: audit_log_service.clone(),
+                carryover_service: carryover_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
@@ -1059,6 +1755,8 @@ impl RestStateImpl {
             uuid_service: uuid_service.clone(),
             special_day_service: special_day_service.clone(),
             employee_work_details_service: working_hours_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            carryover_service: carryover_service.clone(),
             transaction_dao: transaction_dao.clone(),
             // Phase 3 plan 03-03: Forward-Warning-Loop-Deps (D-Phase3-08).
             // Konstruktionsreihenfolge: booking_service (Z. 699),
@@ -1067,13 +1765,17 @@ impl RestStateImpl {
             // Tier-Konform (Basic vor Business-Logic).
             booking_service: booking_service.clone(),
             sales_person_unavailable_service: sales_person_unavailable_service.clone(),
+            sales_person_availability_service: sales_person_availability_service.clone(),
             slot_service: slot_service.clone(),
+            notification_service: notification_service.clone(),
+            webhook_service: webhook_service.clone(),
         });
         let feature_flag_dao = Arc::new(FeatureFlagDao::new(pool.clone()));
         let feature_flag_service: Arc<FeatureFlagService> =
             Arc::new(service_impl::feature_flag::FeatureFlagServiceImpl {
                 feature_flag_dao: feature_flag_dao.clone(),
                 permission_service: permission_service.clone(),
+                audit_log_service: audit_log_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             });
         let extra_hours_service = Arc::new(service_impl::extra_hours::ExtraHoursServiceImpl {
@@ -1083,6 +1785,8 @@ impl RestStateImpl {
             custom_extra_hours_service: custom_extra_hours_service.clone(),
             clock_service: clock_service.clone(),
             uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            carryover_service: carryover_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
         // D-24-08 / Phase 51 (D-51-06 Chain D): ToggleService ist Basic-Tier
@@ -1095,6 +1799,7 @@ impl RestStateImpl {
         let toggle_service = Arc::new(service_impl::toggle::ToggleServiceImpl {
             toggle_dao,
             permission_service: permission_service.clone(),
+            audit_log_service: audit_log_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
 
@@ -1105,10 +1810,6 @@ impl RestStateImpl {
             toggle_service: toggle_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
-        let carryover_service = Arc::new(service_impl::carryover::CarryoverServiceImpl {
-            carryover_dao,
-            transaction_dao: transaction_dao.clone(),
-        });
         // Phase 28 (VAC-OFFSET-01, D-28-06): Basic-Offset-Service NACH
         // carryover_service und VOR vacation_balance_service konstruiert —
         // Business-Logic konsumiert Basic, kein Forward-Reference, kein Cycle.
@@ -1120,6 +1821,7 @@ impl RestStateImpl {
                 permission_service: permission_service.clone(),
                 clock_service: clock_service.clone(),
                 uuid_service: uuid_service.clone(),
+                audit_log_service: audit_log_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
@@ -1148,6 +1850,18 @@ impl RestStateImpl {
                 permission_service: permission_service.clone(),
                 clock_service: clock_service.clone(),
                 uuid_service: uuid_service.clone(),
+                audit_log_service: audit_log_service.clone(),
+                transaction_dao: transaction_dao.clone(),
+            },
+        );
+        let scheduler_job_service = Arc::new(
+            service_impl::scheduler_job::SchedulerJobServiceImpl::<
+                SchedulerJobServiceDependencies,
+            > {
+                scheduler_job_dao,
+                permission_service: permission_service.clone(),
+                audit_log_service: audit_log_service.clone(),
+                uuid_service: uuid_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
@@ -1170,6 +1884,25 @@ impl RestStateImpl {
                 transaction_dao: transaction_dao.clone(),
             },
         );
+        let absence_request_service = Arc::new(AbsenceRequestService {
+            absence_service: absence_service.clone(),
+            vacation_balance_service: vacation_balance_service.clone(),
+            permission_service: permission_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let time_entry_service = Arc::new(TimeEntryService {
+            time_entry_dao: Arc::new(TimeEntryDao::new(pool.clone())),
+            booking_service: booking_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            shiftplan_report_service: shiftplan_report_service.clone(),
+            permission_service: permission_service.clone(),
+            config_service: config_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            carryover_service: carryover_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
         // D-24-08 / Phase 51: ToggleService wurde vor `shiftplan_report_service`
         // (oben) konstruiert. Reporting/ShiftplanEdit konsumieren denselben
         // Handle.
@@ -1188,6 +1921,7 @@ impl RestStateImpl {
             // constructed at ~line 753; toggle_service constructed just above).
             special_day_service: special_day_service.clone(),
             toggle_service: toggle_service.clone(),
+            time_entry_service: time_entry_service.clone(),
         });
 
         // Phase 54 Plan 03 + Gap-Closure 54-09-Ist-Fix (VOL-STAT + VOL-ACCT):
@@ -1224,6 +1958,7 @@ impl RestStateImpl {
             permission_service: permission_service.clone(),
             clock_service: clock_service.clone(),
             uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
 
@@ -1237,6 +1972,7 @@ impl RestStateImpl {
                 booking_service: booking_service.clone(),
                 sales_person_service: sales_person_service.clone(),
                 sales_person_unavailable_service: sales_person_unavailable_service.clone(),
+                sales_person_availability_service: sales_person_availability_service.clone(),
                 reporting_service: reporting_service.clone(),
                 special_day_service: special_day_service.clone(),
                 // Phase 51 (D-51-06 Chain C + D-51-07): pro-Slot-Clip vor Filter
@@ -1246,6 +1982,7 @@ impl RestStateImpl {
                 employee_work_details_service: working_hours_service.clone(),
                 // VFA-01: absence_service already in scope (built at line ~821, before this point).
                 absence_service: absence_service.clone(),
+                qualification_service: qualification_service.clone(),
                 permission_service: permission_service.clone(),
                 clock_service: clock_service.clone(),
                 uuid_service: uuid_service.clone(),
@@ -1262,6 +1999,23 @@ impl RestStateImpl {
             permission_service: permission_service.clone(),
             clock_service: clock_service.clone(),
             uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+            notification_service: notification_service.clone(),
+            webhook_service: webhook_service.clone(),
+        });
+
+        let rebooking_reconciliation_service = Arc::new(RebookingReconciliationService {
+            rebooking_batch_service: rebooking_batch_service.clone(),
+            voluntary_stats_service: voluntary_stats_service.clone(),
+            reporting_service: reporting_service.clone(),
+            extra_hours_service: extra_hours_service.clone(),
+            week_status_service: week_status_service.clone(),
+            toggle_service: toggle_service.clone(),
+            employee_work_details_service: working_hours_service.clone(),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            audit_log_service: audit_log_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
 
@@ -1284,7 +2038,41 @@ impl RestStateImpl {
                 toggle_service: toggle_service.clone(),
                 // NEU für Phase 40 (D-40-01): Wochen-Sperre-Gate.
                 week_status_service: week_status_service.clone(),
+                sales_person_availability_service: sales_person_availability_service.clone(),
+                notification_service: notification_service.clone(),
+                qualification_service: qualification_service.clone(),
             });
+        // Schichtplan-Generator (Business-Logic-Tier): konsumiert den
+        // ShiftplanEditService für Trockenlauf und Annehmen, daher danach.
+        let shiftplan_generator_service = Arc::new(ShiftplanGeneratorService {
+            permission_service: permission_service.clone(),
+            slot_service: slot_service.clone(),
+            booking_service: booking_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            sales_person_shiftplan_service: sales_person_shiftplan_service.clone(),
+            sales_person_unavailable_service: sales_person_unavailable_service.clone(),
+            sales_person_availability_service: sales_person_availability_service.clone(),
+            absence_service: absence_service.clone(),
+            employee_work_details_service: working_hours_service.clone(),
+            shiftplan_edit_service: shiftplan_edit_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        // Schichttausch-Börse (Business-Logic-Tier): Genehmigung bucht über
+        // den ShiftplanEditService um, daher ebenfalls danach.
+        let shift_swap_service = Arc::new(ShiftSwapService {
+            shift_swap_request_dao: Arc::new(ShiftSwapRequestDao::new(pool.clone())),
+            permission_service: permission_service.clone(),
+            booking_service: booking_service.clone(),
+            slot_service: slot_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            sales_person_shiftplan_service: sales_person_shiftplan_service.clone(),
+            week_status_service: week_status_service.clone(),
+            shiftplan_edit_service: shiftplan_edit_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
         // Phase 52 (WOP-01, D-52-01): `shiftplan_dao` / `shiftplan_service`
         // sind jetzt weiter oben (VOR `booking_information_service`) konstruiert
         // — hier keine Doppel-Konstruktion.
@@ -1303,6 +2091,16 @@ impl RestStateImpl {
             sales_person_unavailable_service: sales_person_unavailable_service.clone(),
             // NEU für Phase 51 (D-51-07): Stichtag-Gate für ShortDay-Slot-Kürzung.
             toggle_service: toggle_service.clone(),
+            sales_person_availability_service: sales_person_availability_service.clone(),
+        });
+
+        let week_message_service = Arc::new(WeekMessageService {
+            week_message_dao: Arc::new(WeekMessageDao::new(pool.clone())),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
         });
 
         let block_service = Arc::new(service_impl::block::BlockServiceImpl {
@@ -1318,13 +2116,46 @@ impl RestStateImpl {
             // ShortDay-Lookup für Slot-Clip vor Block-Merge.
             special_day_service: special_day_service.clone(),
             toggle_service: toggle_service.clone(),
+            week_message_service: week_message_service.clone(),
+            permission_service: permission_service.clone(),
         });
 
-        let week_message_service = Arc::new(WeekMessageService {
-            week_message_dao: Arc::new(WeekMessageDao::new(pool.clone())),
+        let team_ical_service = Arc::new(TeamIcalService {
+            shiftplan_view_service: shiftplan_view_service.clone(),
+            shiftplan_service: shiftplan_service.clone(),
+            block_service: block_service.clone(),
+            booking_service: booking_service.clone(),
+            absence_service: absence_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            special_day_service: special_day_service.clone(),
+            config_service: config_service.clone(),
+            clock_service: clock_service.clone(),
+            ical_service: Arc::new(service_impl::ical::IcalServiceImpl),
+            permission_service: permission_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let ical_feed_token_service = Arc::new(IcalFeedTokenService {
+            ical_feed_token_dao,
+            block_service: block_service.clone(),
+            team_ical_service: team_ical_service.clone(),
+            shiftplan_service: shiftplan_service.clone(),
+            sales_person_service: sales_person_service.clone(),
             permission_service: permission_service.clone(),
             clock_service: clock_service.clone(),
             uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let ical_import_service = Arc::new(IcalImportService {
+            absence_service: absence_service.clone(),
+            sales_person_unavailable_service: sales_person_unavailable_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            permission_service: permission_service.clone(),
+            config_service: config_service.clone(),
+            clock_service: clock_service.clone(),
+            ical_fetcher: Arc::new(
+                IcalFetcher::new().expect("Failed to build the iCal import HTTP client"),
+            ),
             transaction_dao: transaction_dao.clone(),
         });
 
@@ -1337,12 +2168,15 @@ impl RestStateImpl {
             )),
             uuid_service: uuid_service.clone(),
             clock_service: clock_service.clone(),
+            webhook_service: webhook_service.clone(),
+            audit_log_service: audit_log_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
 
         let text_template_service = Arc::new(TextTemplateService {
             text_template_dao: text_template_dao.clone(),
             permission_service: permission_service.clone(),
+            audit_log_service: audit_log_service.clone(),
             transaction_dao: transaction_dao.clone(),
         });
 
@@ -1358,6 +2192,39 @@ impl RestStateImpl {
             transaction_dao: transaction_dao.clone(),
         });
 
+        let report_export_service = Arc::new(ReportExportService {
+            reporting_service: reporting_service.clone(),
+            billing_period_service: billing_period_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            permission_service: permission_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+
+        let pdf_personal_service = Arc::new(PdfPersonalService {
+            block_service: block_service.clone(),
+            reporting_service: reporting_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            permission_service: permission_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+
+        let payroll_wage_type_service = Arc::new(PayrollWageTypeService {
+            payroll_wage_type_dao: Arc::new(PayrollWageTypeDao::new(pool.clone())),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let payroll_export_service = Arc::new(PayrollExportService {
+            billing_period_service: billing_period_service.clone(),
+            sales_person_service: sales_person_service.clone(),
+            payroll_wage_type_service: payroll_wage_type_service.clone(),
+            config_service: config_service.clone(),
+            permission_service: permission_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+
         let block_report_service = Arc::new(BlockReportService {
             block_service: block_service.clone(),
             text_template_service: text_template_service.clone(),
@@ -1366,6 +2233,13 @@ impl RestStateImpl {
             transaction_dao: transaction_dao.clone(),
         });
 
+        let text_template_preview_service = Arc::new(TextTemplatePreviewService {
+            billing_period_report_service: billing_period_report_service.clone(),
+            block_report_service: block_report_service.clone(),
+            permission_service: permission_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+
         let user_invitation_service = Arc::new(service_impl::user_invitation::UserInvitationServiceImpl {
             user_invitation_dao,
             permission_dao: permission_dao.clone(),
@@ -1373,6 +2247,8 @@ impl RestStateImpl {
             session_service: session_service.clone(),
             uuid_service: uuid_service.clone(),
             transaction_dao: transaction_dao.clone(),
+            notification_service: notification_service.clone(),
+            audit_log_service: audit_log_service.clone(),
         });
 
         // Phase-2 Plan-04: FeatureFlagService wird oben (vor reporting_service)
@@ -1416,6 +2292,27 @@ impl RestStateImpl {
             Arc::new(ProductionWebDavUploadFactory) as Arc<dyn WebDavUploadFactory>,
         ));
 
+        let export_target_service = Arc::new(ExportTargetService {
+            export_target_dao: Arc::new(ExportTargetDao::new(pool.clone())),
+            permission_service: permission_service.clone(),
+            clock_service: clock_service.clone(),
+            uuid_service: uuid_service.clone(),
+            audit_log_service: audit_log_service.clone(),
+            transaction_dao: transaction_dao.clone(),
+        });
+        let export_target_scheduler = Arc::new(ExportTargetSchedulerService::new(
+            export_target_service.clone(),
+            pdf_shiftplan_service.clone(),
+            shiftplan_service.clone(),
+            pdf_export_config_service.clone(),
+            billing_period_service.clone(),
+            report_export_service.clone(),
+            team_ical_service.clone(),
+            permission_service.clone(),
+            clock_service.clone(),
+            Arc::new(ProductionExportDestinationFactory) as Arc<dyn ExportDestinationFactory>,
+        ));
+
         // Phase 8.5 (Plan 03) — AbsenceConversionService (BL-Tier nach Basic-Services).
         // Konsumiert: extra_hours_dao (Basic-DAO), absence_dao (Basic-DAO),
         // migration_source_dao (Basic-DAO), extra_hours_service (Basic),
@@ -1430,10 +2327,24 @@ impl RestStateImpl {
                 migration_source_dao: migration_source_dao.clone(),
                 extra_hours_service: extra_hours_service.clone(),
                 permission_service: permission_service.clone(),
+                audit_log_service: audit_log_service.clone(),
                 transaction_dao: transaction_dao.clone(),
             },
         );
 
+        // BL-Tier Scheduler; gestartet wird er erst in `main` nach den
+        // Migrationen, der REST-Layer braucht ihn für Reload und „Jetzt
+        // ausführen".
+        let scheduler_service = Arc::new(SchedulerServiceImpl::new(
+            shiftplan_edit_service.clone(),
+            notification_service.clone(),
+            webhook_service.clone(),
+            rebooking_reconciliation_service.clone(),
+            scheduler_job_service.clone(),
+            permission_service.clone(),
+            clock_service.clone(),
+        ));
+
         Self {
             user_service,
             session_service,
@@ -1441,7 +2352,9 @@ impl RestStateImpl {
             slot_service,
             sales_person_service,
             special_day_service,
+            holiday_calendar_service,
             sales_person_unavailable_service,
+            sales_person_availability_service,
             booking_service,
             custom_extra_hours_service,
             booking_information_service,
@@ -1450,8 +2363,11 @@ impl RestStateImpl {
             working_hours_service,
             absence_service,
             vacation_balance_service,
+            absence_request_service,
             extra_hours_service,
             shiftplan_edit_service,
+            shiftplan_generator_service,
+            shift_swap_service,
             block_service,
             shiftplan_service,
             shiftplan_view_service,
@@ -1461,6 +2377,7 @@ impl RestStateImpl {
             billing_period_report_service,
             block_report_service,
             text_template_service,
+            text_template_preview_service,
             user_invitation_service,
             toggle_service,
             sales_person_shiftplan_service,
@@ -1469,15 +2386,39 @@ impl RestStateImpl {
             vacation_entitlement_offset_service,
             rebooking_batch_service,
             voluntary_stats_service,
+            rebooking_reconciliation_service,
             pdf_export_config_service,
             pdf_export_scheduler,
+            export_target_service,
+            export_target_scheduler,
             pdf_shiftplan_service,
+            notification_service,
+            webhook_service,
+            time_entry_service,
+            audit_log_service,
+            report_export_service,
+            pdf_personal_service,
+            payroll_wage_type_service,
+            payroll_export_service,
+            scheduler_job_service,
+            scheduler_service,
+            qualification_service,
+            ical_feed_token_service,
+            ical_import_service,
             basic_dao: Arc::new(BasicDaoImpl::new(pool)),
         }
     }
 }
 
-async fn create_admin_user(pool: Arc<SqlitePool>, username: &str) {
+async fn run_migrations(pool: &DbPool) {
+    #[cfg(feature = "postgres")]
+    let migrator = sqlx::migrate!("../migrations/postgres");
+    #[cfg(not(feature = "postgres"))]
+    let migrator = sqlx::migrate!("../migrations/sqlite");
+    migrator.run(pool).await.expect("Failed to run migrations");
+}
+
+async fn create_admin_user(pool: Arc<DbPool>, username: &str) {
     use dao::PermissionDao;
     // On development create the DEVUSER and give it admin permissions.
     let permission_dao = PermissionDaoImpl::new(pool.clone());
@@ -1533,24 +2474,21 @@ async fn main() {
 
     tracing::info!("Shifty backend version: {}", version);
     dotenvy::dotenv().ok();
+    let database_url =
+        std::env::var("DATABASE_URL").unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_string());
     let pool = Arc::new(
-        SqlitePool::connect("sqlite:./localdb.sqlite3")
+        DbPool::connect(&database_url)
             .await
             .expect("Could not connect to database"),
     );
-
-    // Apply SQLite-specific migrations
-    sqlx::migrate!("../migrations/sqlite")
-        .run(pool.as_ref())
-        .await
-        .expect("Failed to run migrations");
+    run_migrations(pool.as_ref()).await;
 
     let rest_state = RestStateImpl::new(pool.clone());
     create_admin_user(pool.clone(), "DEVUSER").await;
     create_admin_user(pool.clone(), "admin").await;
 
-    let scheduler_service = SchedulerServiceImpl::new(rest_state.shiftplan_edit_service.clone());
-    scheduler_service
+    rest_state
+        .scheduler_service
         .start()
         .await
         .expect("Expected the scheduler to start");
@@ -1565,5 +2503,13 @@ async fn main() {
         .await
         .expect("Expected the pdf-export scheduler to start");
 
+    // Export-Ziele: ein Cron-Job pro aktiviertem Ziel; Änderungen über
+    // `/export-target` laden neu.
+    rest_state
+        .export_target_scheduler
+        .start()
+        .await
+        .expect("Expected the export-target scheduler to start");
+
     rest::start_server(rest_state).await
 }